@@ -0,0 +1,66 @@
+use peace_core::{FlowId, Profile};
+
+use crate::ExecutionId;
+
+/// Information about the execution that an item is applied in.
+///
+/// This is passed to [`Item::apply_check`], and is available to
+/// [`Item::apply`] and [`Item::apply_dry`] through [`FnCtx::apply_ctx`], so
+/// that items can alter their behaviour, such as including the profile name in
+/// resource tags, without it being passed through params.
+///
+/// [`FnCtx::apply_ctx`]: crate::FnCtx::apply_ctx
+/// [`Item::apply`]: crate::Item::apply
+/// [`Item::apply_check`]: crate::Item::apply_check
+/// [`Item::apply_dry`]: crate::Item::apply_dry
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ApplyCtx<'exec> {
+    /// Profile that the item is applied for.
+    pub profile: &'exec Profile,
+    /// ID of the flow that the item is applied in.
+    pub flow_id: &'exec FlowId,
+    /// Whether this is a dry run.
+    pub dry_run: bool,
+    /// ID of the command execution, if it is recorded.
+    ///
+    /// This is `None` when the command is not run through a `CmdExecution`,
+    /// and on WASM.
+    pub execution_id: Option<&'exec ExecutionId>,
+}
+
+impl<'exec> ApplyCtx<'exec> {
+    /// Returns a new `ApplyCtx`.
+    pub fn new(
+        profile: &'exec Profile,
+        flow_id: &'exec FlowId,
+        dry_run: bool,
+        execution_id: Option<&'exec ExecutionId>,
+    ) -> Self {
+        Self {
+            profile,
+            flow_id,
+            dry_run,
+            execution_id,
+        }
+    }
+
+    /// Returns the profile that the item is applied for.
+    pub fn profile(&self) -> &'exec Profile {
+        self.profile
+    }
+
+    /// Returns the ID of the flow that the item is applied in.
+    pub fn flow_id(&self) -> &'exec FlowId {
+        self.flow_id
+    }
+
+    /// Returns whether this is a dry run.
+    pub fn dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Returns the ID of the command execution, if it is recorded.
+    pub fn execution_id(&self) -> Option<&'exec ExecutionId> {
+        self.execution_id
+    }
+}
@@ -0,0 +1,26 @@
+use std::fmt;
+
+/// ID of a command execution, e.g. `"2024-01-31T12_34_56.789"`.
+///
+/// This is inserted into `Resources` when a `CmdExecution` runs, and is also
+/// the name of the directory that the execution's item logs are written to.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ExecutionId(String);
+
+impl ExecutionId {
+    /// Returns a new `ExecutionId`.
+    pub fn new(execution_id: String) -> Self {
+        Self(execution_id)
+    }
+
+    /// Returns the execution ID as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ExecutionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -2,6 +2,8 @@ use std::marker::PhantomData;
 
 use peace_core::ItemId;
 
+use crate::ApplyCtx;
+
 #[cfg(feature = "output_progress")]
 use peace_core::progress::ProgressSender;
 
@@ -13,6 +15,8 @@ pub struct FnCtx<'exec> {
     /// For items to submit progress updates.
     #[cfg(feature = "output_progress")]
     pub progress_sender: ProgressSender<'exec>,
+    /// Information about the execution, when the item is applied.
+    pub apply_ctx: Option<ApplyCtx<'exec>>,
     /// Marker.
     pub marker: PhantomData<&'exec ()>,
 }
@@ -27,13 +31,27 @@ impl<'exec> FnCtx<'exec> {
             item_id,
             #[cfg(feature = "output_progress")]
             progress_sender,
+            apply_ctx: None,
             marker: PhantomData,
         }
     }
 
+    /// Sets the information about the execution that the item is applied in.
+    pub fn with_apply_ctx(mut self, apply_ctx: ApplyCtx<'exec>) -> Self {
+        self.apply_ctx = Some(apply_ctx);
+        self
+    }
+
     /// Returns the `ProgressTracker` for items to send progress to.
     #[cfg(feature = "output_progress")]
     pub fn progress_sender(&self) -> &ProgressSender<'exec> {
         &self.progress_sender
     }
+
+    /// Returns information about the execution that the item is applied in.
+    ///
+    /// This is `Some` when passed to `Item::apply` and `Item::apply_dry`.
+    pub fn apply_ctx(&self) -> Option<&ApplyCtx<'exec>> {
+        self.apply_ctx.as_ref()
+    }
 }
@@ -2,13 +2,13 @@ use std::fmt::{Debug, Display};
 
 use async_trait::async_trait;
 use dyn_clone::DynClone;
-use peace_core::{ApplyCheck, ItemId};
+use peace_core::{ApplyCheck, DiffSeverity, ItemId, Profile};
 use peace_data::Data;
 use peace_params::{Params, ParamsSpec};
 use peace_resources::{resources::ts::Empty, Resources};
 use serde::{de::DeserializeOwned, Serialize};
 
-use crate::FnCtx;
+use crate::{ApplyCtx, FnCtx, ItemInputs, ItemOutputs};
 
 /// Defines all of the data and logic to manage an item.
 ///
@@ -57,7 +57,7 @@ use crate::FnCtx;
 #[async_trait(?Send)]
 pub trait Item: DynClone {
     /// Consumer provided error type.
-    type Error: std::error::Error + Send + Sync;
+    type Error: std::error::Error + Send + Sync + 'static;
 
     /// Summary of the managed item's state.
     ///
@@ -178,6 +178,49 @@ pub trait Item: DynClone {
     /// [`apply`]: crate::ApplyFns::apply
     async fn setup(&self, resources: &mut Resources<Empty>) -> Result<(), Self::Error>;
 
+    /// Returns whether this item applies to the given profile.
+    ///
+    /// Items that are not applicable are skipped when discovering and applying
+    /// states, and their progress is reported as skipped instead of being
+    /// executed. This allows a single flow to contain items that only
+    /// apply to certain profiles or platforms, e.g. a Windows-only item in a
+    /// flow that is also used for Linux profiles.
+    ///
+    /// The default implementation returns `true`.
+    ///
+    /// # Implementors
+    ///
+    /// This function call is intended to be cheap and fast.
+    fn applicable(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _profile: &Profile,
+    ) -> bool {
+        true
+    }
+
+    /// Returns the typed values this item provides to its successors.
+    ///
+    /// Each output is computed from this item's state, and stored as an
+    /// `ItemOutput<T>` in [`Resources`] whenever this item's current state is
+    /// discovered or applied. Successors read it through
+    /// `R<'exec, ItemOutput<T>>` in their [`Data`].
+    ///
+    /// The default implementation returns no outputs.
+    fn outputs() -> ItemOutputs<Self::State> {
+        ItemOutputs::new()
+    }
+
+    /// Returns the typed values this item reads from its predecessors'
+    /// outputs.
+    ///
+    /// When the item graph is built, each input must be output by one of this
+    /// item's predecessors, otherwise building the graph fails.
+    ///
+    /// The default implementation returns no inputs.
+    fn inputs() -> ItemInputs {
+        ItemInputs::new()
+    }
+
     /// Returns the current state of the managed item, if possible.
     ///
     /// This should return `Ok(None)` if the state is not able to be queried,
@@ -253,6 +296,61 @@ pub trait Item: DynClone {
         state_b: &Self::State,
     ) -> Result<Self::StateDiff, Self::Error>;
 
+    /// Returns how disruptive it is to apply the diff from `state_a` to
+    /// `state_b`.
+    ///
+    /// This is used to summarize diffs, e.g. for CLI apps to return a
+    /// different exit code when applying a diff would lose data.
+    ///
+    /// The default implementation returns [`DiffSeverity::InSync`] if the
+    /// states are equal, and [`DiffSeverity::Additive`] otherwise.
+    ///
+    /// # Implementors
+    ///
+    /// Items that remove or replace resources should return
+    /// [`DiffSeverity::Destructive`] for those diffs.
+    fn diff_severity(
+        state_a: &Self::State,
+        state_b: &Self::State,
+        _state_diff: &Self::StateDiff,
+    ) -> DiffSeverity {
+        if state_a == state_b {
+            DiffSeverity::InSync
+        } else {
+            DiffSeverity::Additive
+        }
+    }
+
+    /// Returns suggestions to help the user recover from an error that this
+    /// item returned.
+    ///
+    /// These are shown alongside the error when item errors are reported,
+    /// e.g. "Check that your AWS credentials are valid.".
+    ///
+    /// The default implementation returns no suggestions.
+    fn error_help(_error: &Self::Error) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Returns the JSON Schemas of this item's `Params`, `State`, and
+    /// `StateDiff` types.
+    ///
+    /// These are combined for all items in a flow by `FlowSchemaCmd`, so that
+    /// external tooling such as web UIs and validators can be generated
+    /// against the flow's data model.
+    ///
+    /// The default implementation returns `None`, meaning this item does not
+    /// provide a schema.
+    ///
+    /// # Implementors
+    ///
+    /// Derive [`schemars::JsonSchema`] for the `Params`, `State`, and
+    /// `StateDiff` types, then return `Some(ItemSchema::new::<Self>(generator))`.
+    #[cfg(feature = "item_schema")]
+    fn schema(_generator: &mut schemars::gen::SchemaGenerator) -> Option<crate::ItemSchema> {
+        None
+    }
+
     /// Returns the representation of a clean `State`.
     ///
     /// # Implementors
@@ -283,9 +381,13 @@ pub trait Item: DynClone {
     ///
     /// This function call is intended to be cheap and fast.
     ///
+    /// When `ExecRequired` is returned, an [`ApplyCheckReason`] may be included
+    /// to explain why, e.g. which fields differ. This is shown to users in
+    /// apply plans and progress messages.
+    ///
     /// # Parameters
     ///
-    /// * `fn_ctx`: Context to send progress updates.
+    /// * `apply_ctx`: Profile, flow ID, and dry run flag of the execution.
     /// * `params`: Parameters to the item.
     /// * `data`: Runtime data that the function reads from or writes to.
     /// * `state_current`: Current [`State`] of the managed item, returned from
@@ -295,12 +397,14 @@ pub trait Item: DynClone {
     /// * `state_diff`: Goal [`State`] of the managed item, returned from
     ///   [`state_diff`].
     ///
+    /// [`ApplyCheckReason`]: crate::ApplyCheckReason
     /// [`state_clean`]: crate::Item::state_clean
     /// [`state_current`]: crate::Item::state_current
     /// [`state_goal`]: crate::Item::state_goal
     /// [`State`]: Self::State
     /// [`state_diff`]: crate::Item::state_diff
     async fn apply_check(
+        apply_ctx: ApplyCtx<'_>,
         params: &Self::Params<'_>,
         data: Self::Data<'_>,
         state_current: &Self::State,
@@ -328,7 +432,8 @@ pub trait Item: DynClone {
     ///
     /// # Parameters
     ///
-    /// * `fn_ctx`: Context to send progress updates.
+    /// * `fn_ctx`: Context to send progress updates, and information about the
+    ///   execution through [`FnCtx::apply_ctx`].
     /// * `params`: Parameters to the item.
     /// * `data`: Runtime data that the function reads from or writes to.
     /// * `state_current`: Current [`State`] of the managed item, returned from
@@ -360,7 +465,8 @@ pub trait Item: DynClone {
     ///
     /// # Parameters
     ///
-    /// * `fn_ctx`: Context to send progress updates.
+    /// * `fn_ctx`: Context to send progress updates, and information about the
+    ///   execution through [`FnCtx::apply_ctx`].
     /// * `params`: Parameters to the item.
     /// * `data`: Runtime data that the function reads from or writes to.
     /// * `state_current`: Current [`State`] of the managed item, returned from
@@ -0,0 +1,47 @@
+use crate::ItemIoType;
+
+/// Typed values that an item reads from its predecessors' outputs.
+///
+/// Each input `T` is read through `R<'exec, ItemOutput<T>>` in the item's
+/// `Data`. Declaring it here allows the item graph to verify that a
+/// predecessor outputs `T`, instead of the value silently being `None`.
+///
+/// # Examples
+///
+/// ```rust
+/// use peace_cfg::ItemInputs;
+///
+/// #[derive(Debug)]
+/// struct ServerAddr(String);
+///
+/// let item_inputs = ItemInputs::new().with_input::<ServerAddr>();
+/// assert_eq!(1, item_inputs.item_io_types().len());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ItemInputs(Vec<ItemIoType>);
+
+impl ItemInputs {
+    /// Returns new `ItemInputs` with no inputs.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `T` as an input.
+    pub fn with_input<T>(mut self) -> Self
+    where
+        T: 'static,
+    {
+        self.0.push(ItemIoType::of::<T>());
+        self
+    }
+
+    /// Returns the types of the inputs.
+    pub fn item_io_types(&self) -> &[ItemIoType] {
+        &self.0
+    }
+
+    /// Returns the inner `Vec<ItemIoType>`.
+    pub fn into_inner(self) -> Vec<ItemIoType> {
+        self.0
+    }
+}
@@ -0,0 +1,38 @@
+use std::any::TypeId;
+
+/// Type of a value passed from an item to its successors.
+///
+/// This is the `T` in an [`ItemOutput<T>`], and is used to verify that each
+/// item's inputs are provided by one of its predecessors.
+///
+/// [`ItemOutput<T>`]: peace_data::ItemOutput
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ItemIoType {
+    /// `TypeId` of the value.
+    type_id: TypeId,
+    /// Name of the value's type, used in error messages.
+    type_name: &'static str,
+}
+
+impl ItemIoType {
+    /// Returns the `ItemIoType` for `T`.
+    pub fn of<T>() -> Self
+    where
+        T: 'static,
+    {
+        Self {
+            type_id: TypeId::of::<T>(),
+            type_name: std::any::type_name::<T>(),
+        }
+    }
+
+    /// Returns the `TypeId` of the value.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Returns the name of the value's type.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+}
@@ -0,0 +1,114 @@
+use std::fmt::{self, Debug};
+
+use peace_data::ItemOutput;
+use peace_resources::{
+    resources::ts::{Empty, SetUp},
+    Resources,
+};
+
+use crate::ItemIoType;
+
+/// Typed values that an item provides to its successors.
+///
+/// Each output `T` is computed from the item's state, and stored as an
+/// [`ItemOutput<T>`] in `Resources` whenever the item's current state is
+/// discovered or applied.
+///
+/// # Examples
+///
+/// ```rust
+/// use peace_cfg::ItemOutputs;
+///
+/// #[derive(Debug)]
+/// struct ServerAddr(String);
+///
+/// let item_outputs =
+///     ItemOutputs::<String>::new().with_output(|host: &String| ServerAddr(host.clone()));
+/// assert_eq!(1, item_outputs.item_io_types().count());
+/// ```
+pub struct ItemOutputs<State>(Vec<ItemOutputEntry<State>>);
+
+/// Computes an output from the item's state, and stores it in `Resources`.
+type OutputSetFn<State> = Box<dyn Fn(&State, &Resources<SetUp>) + Send + Sync>;
+
+/// Type and functions to insert and set one output.
+struct ItemOutputEntry<State> {
+    /// Type of the output.
+    item_io_type: ItemIoType,
+    /// Inserts `ItemOutput<T>(None)` into `Resources`.
+    insert_fn: fn(&mut Resources<Empty>),
+    /// Computes the output from the item's state, and stores it.
+    set_fn: OutputSetFn<State>,
+}
+
+impl<State> ItemOutputs<State>
+where
+    State: 'static,
+{
+    /// Returns new `ItemOutputs` with no outputs.
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Adds an output computed from the item's state.
+    pub fn with_output<T>(mut self, output_fn: fn(&State) -> T) -> Self
+    where
+        T: Debug + Send + Sync + 'static,
+    {
+        self.0.push(ItemOutputEntry {
+            item_io_type: ItemIoType::of::<T>(),
+            insert_fn: |resources| resources.insert(ItemOutput::<T>(None)),
+            set_fn: Box::new(move |state, resources| {
+                resources.borrow_mut::<ItemOutput<T>>().0 = Some(output_fn(state));
+            }),
+        });
+        self
+    }
+
+    /// Returns the types of the outputs.
+    pub fn item_io_types(&self) -> impl ExactSizeIterator<Item = ItemIoType> + '_ {
+        self.0
+            .iter()
+            .map(|item_output_entry| item_output_entry.item_io_type)
+    }
+
+    /// Inserts an `ItemOutput<T>(None)` into `Resources` for each output.
+    ///
+    /// This allows successor items to borrow the output before it is set.
+    pub fn insert(&self, resources: &mut Resources<Empty>) {
+        self.0
+            .iter()
+            .for_each(|item_output_entry| (item_output_entry.insert_fn)(resources));
+    }
+
+    /// Computes each output from the item's state, and stores it in
+    /// `Resources`.
+    pub fn set(&self, state: &State, resources: &Resources<SetUp>) {
+        self.0
+            .iter()
+            .for_each(|item_output_entry| (item_output_entry.set_fn)(state, resources));
+    }
+}
+
+impl<State> Default for ItemOutputs<State>
+where
+    State: 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<State> Debug for ItemOutputs<State> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ItemOutputs")
+            .field(
+                &self
+                    .0
+                    .iter()
+                    .map(|item_output_entry| item_output_entry.item_io_type)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
@@ -0,0 +1,47 @@
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::{Deserialize, Serialize};
+
+use crate::Item;
+
+/// JSON Schemas of an item's `Params`, `State`, and `StateDiff` types.
+///
+/// Schemas are generated with a shared [`SchemaGenerator`], so types used by
+/// multiple items are referenced from the generator's definitions instead of
+/// being repeated.
+///
+/// See [`Item::schema`].
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ItemSchema {
+    /// Schema of the item's `Params`.
+    pub params: Schema,
+    /// Schema of the item's `State`.
+    pub state: Schema,
+    /// Schema of the item's `StateDiff`.
+    pub state_diff: Schema,
+}
+
+impl ItemSchema {
+    /// Returns the schemas of the given item's `Params`, `State`, and
+    /// `StateDiff` types.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// fn schema(generator: &mut SchemaGenerator) -> Option<ItemSchema> {
+    ///     Some(ItemSchema::new::<Self>(generator))
+    /// }
+    /// ```
+    pub fn new<I>(generator: &mut SchemaGenerator) -> Self
+    where
+        I: Item,
+        I::Params<'static>: JsonSchema,
+        I::State: JsonSchema,
+        I::StateDiff: JsonSchema,
+    {
+        Self {
+            params: generator.subschema_for::<I::Params<'static>>(),
+            state: generator.subschema_for::<I::State>(),
+            state_diff: generator.subschema_for::<I::StateDiff>(),
+        }
+    }
+}
@@ -10,10 +10,24 @@ pub use peace_core::progress;
 
 pub use peace_core::*;
 
-pub use crate::{fn_ctx::FnCtx, item::Item, state::State};
+#[cfg(feature = "item_schema")]
+pub use crate::item_schema::ItemSchema;
+
+pub use crate::{
+    apply_ctx::ApplyCtx, execution_id::ExecutionId, fn_ctx::FnCtx, item::Item,
+    item_inputs::ItemInputs, item_io_type::ItemIoType, item_outputs::ItemOutputs, state::State,
+};
 
 pub mod accessors;
 pub mod state;
 
+mod apply_ctx;
+mod execution_id;
 mod fn_ctx;
 mod item;
+mod item_inputs;
+mod item_io_type;
+mod item_outputs;
+
+#[cfg(feature = "item_schema")]
+mod item_schema;
@@ -2,11 +2,17 @@ pub use self::{
     external::{External, Fetched, Generated, Timestamped},
     external_opt::{ExternalOpt, FetchedOpt, GeneratedOpt, TimestampedOpt},
     nothing::Nothing,
+    sensitive::Sensitive,
+    sensitive_cipher::{SensitiveCipher, SensitiveCipherError},
+    sensitive_serde_mode::SensitiveSerdeMode,
 };
 
 mod external;
 mod external_opt;
 mod nothing;
+mod sensitive;
+mod sensitive_cipher;
+mod sensitive_serde_mode;
 
 use std::{any::TypeId, fmt};
 
@@ -0,0 +1,158 @@
+use std::fmt;
+
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::state::SensitiveSerdeMode;
+
+/// State field whose value is encrypted when stored, and redacted when shown.
+///
+/// Wrap fields such as tokens or passwords in this type, so the rest of the
+/// state is stored in plain text and remains diffable:
+///
+/// ```rust
+/// use peace_cfg::state::Sensitive;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+/// pub struct ServerState {
+///     pub host: String,
+///     pub api_token: Sensitive<String>,
+/// }
+/// ```
+///
+/// How the value is serialized depends on the [`SensitiveSerdeMode`] of the
+/// current thread:
+///
+/// * When states are stored in a workspace with a sensitive field key, the
+///   value is encrypted and stored as `"encrypted:<ciphertext>"`.
+/// * When states are stored in an encrypted workspace, the value is stored in
+///   plain text, as the whole file is encrypted.
+/// * When states are stored in a workspace without either, serialization
+///   fails, as the value would otherwise be stored in plain text.
+/// * When states are presented to the user, the value is replaced with
+///   [`Sensitive::REDACTED`].
+/// * Otherwise, serialization fails, so that the value is not stored redacted
+///   by mistake.
+///
+/// Redacted values cannot be deserialized.
+#[derive(Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Sensitive<T>(T);
+
+impl<T> Sensitive<T> {
+    /// Prefix of serialized encrypted values.
+    pub const ENCRYPTED_PREFIX: &'static str = "encrypted:";
+    /// Text shown in place of a sensitive value.
+    pub const REDACTED: &'static str = "********";
+
+    /// Returns a new `Sensitive` value.
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns a reference to the sensitive value.
+    ///
+    /// Take care not to log or display the returned value.
+    pub fn expose(&self) -> &T {
+        &self.0
+    }
+
+    /// Returns the sensitive value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Sensitive<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> fmt::Debug for Sensitive<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Sensitive").field(&Self::REDACTED).finish()
+    }
+}
+
+impl<T> fmt::Display for Sensitive<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::REDACTED)
+    }
+}
+
+impl<T> Serialize for Sensitive<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match SensitiveSerdeMode::current() {
+            SensitiveSerdeMode::Unscoped => Err(serde::ser::Error::custom(
+                "Sensitive value cannot be serialized outside of `SensitiveSerdeMode::scope`. \
+                Store it through `Storage`, or serialize it within \
+                `SensitiveSerdeMode::Redact.scope` to present it.",
+            )),
+            SensitiveSerdeMode::Redact => serializer.serialize_str(Self::REDACTED),
+            SensitiveSerdeMode::KeyRequired => Err(serde::ser::Error::custom(
+                "Sensitive value cannot be stored, as the workspace has no sensitive field key. \
+                Create the workspace with `Workspace::with_sensitive_key_provider`, or use \
+                `WorkspaceSpec::PathEncrypted`, to be able to store it.",
+            )),
+            SensitiveSerdeMode::Plain => self.0.serialize(serializer),
+            SensitiveSerdeMode::Encrypt(sensitive_cipher) => {
+                let plaintext =
+                    serde_yaml::to_string(&self.0).map_err(serde::ser::Error::custom)?;
+                let ciphertext = sensitive_cipher
+                    .encrypt(plaintext.as_bytes())
+                    .map_err(serde::ser::Error::custom)?;
+
+                serializer.serialize_str(&format!("{}{ciphertext}", Self::ENCRYPTED_PREFIX))
+            }
+        }
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Sensitive<T>
+where
+    T: DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_yaml::Value::deserialize(deserializer)?;
+        let ciphertext = match value.as_str() {
+            Some(Self::REDACTED) => {
+                return Err(serde::de::Error::custom(
+                    "Sensitive value was redacted when it was stored. \
+                    Store states in an encrypted workspace, or a workspace with a \
+                    sensitive field key, to be able to read them back.",
+                ));
+            }
+            Some(s) => s.strip_prefix(Self::ENCRYPTED_PREFIX),
+            None => None,
+        };
+
+        let Some(ciphertext) = ciphertext else {
+            return serde_yaml::from_value::<T>(value)
+                .map(Self)
+                .map_err(serde::de::Error::custom);
+        };
+
+        let SensitiveSerdeMode::Encrypt(sensitive_cipher) = SensitiveSerdeMode::current() else {
+            return Err(serde::de::Error::custom(
+                "Sensitive value is encrypted, but no sensitive field key is configured \
+                for the workspace.",
+            ));
+        };
+        let plaintext = sensitive_cipher
+            .decrypt(ciphertext)
+            .map_err(serde::de::Error::custom)?;
+
+        serde_yaml::from_slice::<T>(&plaintext)
+            .map(Self)
+            .map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,17 @@
+/// Error returned when a [`SensitiveCipher`] fails to encrypt or decrypt a
+/// value.
+pub type SensitiveCipherError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// Encrypts and decrypts [`Sensitive`] state fields when they are stored.
+///
+/// Implementations should return ciphertext that is safe to embed in a YAML
+/// string, e.g. base64 encoded.
+///
+/// [`Sensitive`]: crate::state::Sensitive
+pub trait SensitiveCipher: Send + Sync {
+    /// Returns the encrypted `plaintext`.
+    fn encrypt(&self, plaintext: &[u8]) -> Result<String, SensitiveCipherError>;
+
+    /// Returns the decrypted `ciphertext`.
+    fn decrypt(&self, ciphertext: &str) -> Result<Vec<u8>, SensitiveCipherError>;
+}
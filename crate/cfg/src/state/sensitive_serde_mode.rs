@@ -0,0 +1,86 @@
+use std::{cell::RefCell, fmt, sync::Arc};
+
+use crate::state::SensitiveCipher;
+
+thread_local! {
+    static SENSITIVE_SERDE_MODE: RefCell<SensitiveSerdeMode> =
+        const { RefCell::new(SensitiveSerdeMode::Unscoped) };
+}
+
+/// How [`Sensitive`] values are serialized and deserialized on the current
+/// thread.
+///
+/// Outside of [`SensitiveSerdeMode::scope`], values fail to serialize, so
+/// that they are neither stored in plain text nor lost to redaction. States
+/// that are presented to the user -- including as YAML or JSON -- are
+/// serialized within [`SensitiveSerdeMode::Redact`].
+///
+/// [`Sensitive`]: crate::state::Sensitive
+#[derive(Clone, Default)]
+pub enum SensitiveSerdeMode {
+    /// Values cannot be serialized, as no mode is set for the current thread.
+    #[default]
+    Unscoped,
+    /// Values are serialized as [`Sensitive::REDACTED`], and cannot be
+    /// deserialized.
+    ///
+    /// This is used when values are presented to the user.
+    ///
+    /// [`Sensitive::REDACTED`]: crate::state::Sensitive::REDACTED
+    Redact,
+    /// Values cannot be serialized, as there is no key to encrypt them with.
+    ///
+    /// This is used when a file is stored without encryption, so that
+    /// sensitive values are neither stored in plain text nor lost to
+    /// redaction.
+    KeyRequired,
+    /// Values are serialized in plain text.
+    ///
+    /// This is used when the whole file is already encrypted.
+    Plain,
+    /// Values are encrypted with the given cipher.
+    Encrypt(Arc<dyn SensitiveCipher>),
+}
+
+impl SensitiveSerdeMode {
+    /// Runs `f` with `Sensitive` values serialized and deserialized using this
+    /// mode on the current thread.
+    ///
+    /// The previous mode is restored when `f` returns.
+    pub fn scope<F, R>(self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        struct ModeRestore(Option<SensitiveSerdeMode>);
+
+        impl Drop for ModeRestore {
+            fn drop(&mut self) {
+                if let Some(mode) = self.0.take() {
+                    SENSITIVE_SERDE_MODE.with(|mode_current| *mode_current.borrow_mut() = mode);
+                }
+            }
+        }
+
+        let mode_previous = SENSITIVE_SERDE_MODE.with(|mode_current| mode_current.replace(self));
+        let _mode_restore = ModeRestore(Some(mode_previous));
+
+        f()
+    }
+
+    /// Returns the mode for the current thread.
+    pub fn current() -> Self {
+        SENSITIVE_SERDE_MODE.with(|mode_current| mode_current.borrow().clone())
+    }
+}
+
+impl fmt::Debug for SensitiveSerdeMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unscoped => f.write_str("Unscoped"),
+            Self::Redact => f.write_str("Redact"),
+            Self::KeyRequired => f.write_str("KeyRequired"),
+            Self::Plain => f.write_str("Plain"),
+            Self::Encrypt(_) => f.write_str("Encrypt(..)"),
+        }
+    }
+}
@@ -2,4 +2,8 @@
 //!
 //! This is enabled though the `"cli"` feature on the `peace` crate.
 
+// Re-exports
+#[cfg(feature = "tui")]
+pub use ratatui;
+
 pub mod output;
@@ -21,8 +21,20 @@ cfg_if::cfg_if! {
             cli_progress_format_opt_parse_error::CliProgressFormatOptParseError,
         };
 
+        pub(crate) use self::cli_item_group_progress::CliItemGroupProgress;
+
+        mod cli_item_group_progress;
         mod cli_progress_format;
         mod cli_progress_format_opt;
         mod cli_progress_format_opt_parse_error;
     }
 }
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "tui")] {
+        pub use self::tui_output::TuiOutput;
+
+        mod tui_item_progress;
+        mod tui_output;
+    }
+}
@@ -0,0 +1,128 @@
+use peace_core::{
+    progress::{ProgressComplete, ProgressLimit, ProgressStatus, ProgressTracker},
+    ItemGroupId, ItemId,
+};
+use peace_rt_model_core::indicatif::ProgressBar;
+
+/// Progress of a group of items, rendered by `CliOutput` as one progress bar.
+#[derive(Debug)]
+pub(crate) struct CliItemGroupProgress {
+    /// ID of the item group.
+    pub(crate) item_group_id: ItemGroupId,
+    /// Tracker for the group's aggregate progress.
+    pub(crate) progress_tracker: ProgressTracker,
+    /// Last known status of each item in the group.
+    pub(crate) member_statuses: Vec<(ItemId, ProgressStatus)>,
+}
+
+impl CliItemGroupProgress {
+    /// Returns a new `CliItemGroupProgress`.
+    ///
+    /// The progress limit is the number of items in the group, and progress
+    /// is incremented as each item completes.
+    pub(crate) fn new(
+        item_group_id: ItemGroupId,
+        progress_bar: ProgressBar,
+        item_ids: Vec<ItemId>,
+    ) -> Self {
+        let mut progress_tracker = ProgressTracker::new(progress_bar);
+        let item_count = u64::try_from(item_ids.len()).unwrap_or(u64::MAX);
+        progress_tracker.set_progress_limit(ProgressLimit::Steps(item_count));
+
+        let member_statuses = item_ids
+            .into_iter()
+            .map(|item_id| (item_id, ProgressStatus::Initialized))
+            .collect::<Vec<_>>();
+
+        Self {
+            item_group_id,
+            progress_tracker,
+            member_statuses,
+        }
+    }
+
+    /// Returns whether the given item belongs to this group.
+    pub(crate) fn contains(&self, item_id: &ItemId) -> bool {
+        self.member_statuses
+            .iter()
+            .any(|(member_item_id, _)| member_item_id == item_id)
+    }
+
+    /// Records an item's status, and recomputes the group's progress.
+    pub(crate) fn member_status_update(
+        &mut self,
+        item_id: &ItemId,
+        progress_status: ProgressStatus,
+    ) {
+        if let Some((_, member_status)) = self
+            .member_statuses
+            .iter_mut()
+            .find(|(member_item_id, _)| member_item_id == item_id)
+        {
+            *member_status = progress_status;
+        }
+
+        let complete_count = self
+            .member_statuses
+            .iter()
+            .filter(|(_, member_status)| matches!(member_status, ProgressStatus::Complete(_)))
+            .count();
+        let complete_count = u64::try_from(complete_count).unwrap_or(u64::MAX);
+
+        let progress_status = Self::group_status(&self.member_statuses);
+
+        self.progress_tracker.set_progress_status(progress_status);
+        self.progress_tracker
+            .progress_bar()
+            .set_position(complete_count);
+    }
+
+    /// Returns the status of the group, given the status of each item.
+    ///
+    /// * The group is complete when all items are complete, and failed if any
+    ///   item failed.
+    /// * Otherwise, the group takes the most active status of its items.
+    fn group_status(member_statuses: &[(ItemId, ProgressStatus)]) -> ProgressStatus {
+        let all_complete = member_statuses
+            .iter()
+            .all(|(_, member_status)| matches!(member_status, ProgressStatus::Complete(_)));
+        if all_complete {
+            let any_failed = member_statuses.iter().any(|(_, member_status)| {
+                matches!(
+                    member_status,
+                    ProgressStatus::Complete(ProgressComplete::Fail)
+                )
+            });
+            return if any_failed {
+                ProgressStatus::Complete(ProgressComplete::Fail)
+            } else {
+                ProgressStatus::Complete(ProgressComplete::Success)
+            };
+        }
+
+        let any_status = |predicate: fn(&ProgressStatus) -> bool| {
+            member_statuses
+                .iter()
+                .any(|(_, member_status)| predicate(member_status))
+        };
+        if any_status(|status| matches!(status, ProgressStatus::UserPending)) {
+            ProgressStatus::UserPending
+        } else if any_status(|status| matches!(status, ProgressStatus::Running)) {
+            ProgressStatus::Running
+        } else if any_status(|status| matches!(status, ProgressStatus::RunningStalled)) {
+            ProgressStatus::RunningStalled
+        } else if any_status(|status| matches!(status, ProgressStatus::Interrupted)) {
+            ProgressStatus::Interrupted
+        } else if any_status(|status| {
+            matches!(status, ProgressStatus::Queued | ProgressStatus::Complete(_))
+        }) {
+            // Some items are complete, and the remaining items are waiting
+            // their turn.
+            ProgressStatus::Queued
+        } else if any_status(|status| matches!(status, ProgressStatus::ExecPending)) {
+            ProgressStatus::ExecPending
+        } else {
+            ProgressStatus::Initialized
+        }
+    }
+}
@@ -437,6 +437,91 @@ where
     {
         self.list_aligned_with(ListType::Bulleted, iter, f).await
     }
+
+    async fn table<I, R>(&mut self, header: &[&str], rows: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = R>,
+        R: AsRef<[String]>,
+    {
+        // Cells are rendered on a single line, so line breaks and pipes within
+        // cells are replaced.
+        let cell_escape = |cell: &str| cell.replace('\n', " ").replace('|', "\\|");
+        let rows = rows
+            .into_iter()
+            .map(|row| {
+                row.as_ref()
+                    .iter()
+                    .map(|cell| cell_escape(cell))
+                    .collect::<Vec<String>>()
+            })
+            .collect::<Vec<Vec<String>>>();
+        let header = header
+            .iter()
+            .map(|cell| cell_escape(cell))
+            .collect::<Vec<String>>();
+
+        let column_widths = rows.iter().fold(
+            header
+                .iter()
+                .map(|cell| console::measure_text_width(cell))
+                .collect::<Vec<usize>>(),
+            |mut column_widths, row| {
+                row.iter().enumerate().for_each(|(column_index, cell)| {
+                    let width = console::measure_text_width(cell);
+                    match column_widths.get_mut(column_index) {
+                        Some(column_width) => *column_width = (*column_width).max(width),
+                        None => column_widths.push(width),
+                    }
+                });
+                column_widths
+            },
+        );
+
+        let pipe_style = &console::Style::new().color256(243); // grey
+        let header_style = &console::Style::new().bold();
+
+        // Header
+        for (column_index, column_width) in column_widths.iter().enumerate() {
+            let cell = header.get(column_index).map(String::as_str).unwrap_or("");
+            self.colorize_maybe("|", pipe_style).await?;
+            self.output.writer.write_all(b" ").await?;
+            self.colorize_maybe(cell, header_style).await?;
+            let padding = column_width.saturating_sub(console::measure_text_width(cell));
+            self.output
+                .writer
+                .write_all(format!("{:padding$} ", "").as_bytes())
+                .await?;
+        }
+        self.colorize_maybe("|", pipe_style).await?;
+        self.output.writer.write_all(b"\n").await?;
+
+        // Separator
+        for column_width in column_widths.iter() {
+            let separator = format!("|{}", "-".repeat(column_width + 2));
+            self.colorize_maybe(&separator, pipe_style).await?;
+        }
+        self.colorize_maybe("|", pipe_style).await?;
+        self.output.writer.write_all(b"\n").await?;
+
+        // Rows
+        for row in rows.iter() {
+            for (column_index, column_width) in column_widths.iter().enumerate() {
+                let cell = row.get(column_index).map(String::as_str).unwrap_or("");
+                self.colorize_maybe("|", pipe_style).await?;
+                self.output.writer.write_all(b" ").await?;
+                self.text(cell).await?;
+                let padding = column_width.saturating_sub(console::measure_text_width(cell));
+                self.output
+                    .writer
+                    .write_all(format!("{:padding$} ", "").as_bytes())
+                    .await?;
+            }
+            self.colorize_maybe("|", pipe_style).await?;
+            self.output.writer.write_all(b"\n").await?;
+        }
+
+        Ok(())
+    }
 }
 
 /// Whether to render text in ANSI bold.
@@ -1,8 +1,9 @@
 use std::fmt::{self, Debug};
 
+use peace_cfg::state::SensitiveSerdeMode;
 use peace_cli_model::OutputFormat;
-use peace_fmt::Presentable;
-use peace_rt_model_core::{async_trait, output::OutputWrite, Error, NativeError};
+use peace_fmt::{MessageCatalog, Presentable};
+use peace_rt_model_core::{async_trait, output::OutputWrite, CmdOutcomeReport, Error, NativeError};
 use serde::Serialize;
 use tokio::io::{AsyncWrite, AsyncWriteExt, Stdout};
 
@@ -19,11 +20,11 @@ cfg_if::cfg_if! {
             ProgressUpdateAndId,
         };
         use peace_rt_model_core::{
-            indicatif::{ProgressDrawTarget, ProgressStyle},
+            indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle},
             CmdProgressTracker,
         };
 
-        use crate::output::{CliOutputTarget, CliProgressFormat};
+        use crate::output::{CliItemGroupProgress, CliOutputTarget, CliProgressFormat};
     }
 }
 
@@ -64,6 +65,9 @@ pub struct CliOutput<W> {
     pub(crate) outcome_format: OutputFormat,
     /// Whether output should be colorized.
     pub(crate) colorize: CliColorize,
+    /// Catalog that text added to the output, such as error report labels, is
+    /// rendered through.
+    pub(crate) message_catalog: MessageCatalog,
     #[cfg(feature = "output_progress")]
     /// Where to output progress updates to -- stdout or stderr.
     pub(crate) progress_target: CliOutputTarget,
@@ -75,6 +79,9 @@ pub struct CliOutput<W> {
     /// Width of the item ID column for progress bars
     #[cfg(feature = "output_progress")]
     pub(crate) pb_item_id_width: Option<usize>,
+    /// Progress of item groups, each rendered as a single progress bar.
+    #[cfg(feature = "output_progress")]
+    pub(crate) pb_item_groups: Vec<CliItemGroupProgress>,
     /// The TTY guard that restores the terminal mode when `CliOutput` is
     /// dropped.
     ///
@@ -94,14 +101,16 @@ where
         debug_struct
             .field("writer", &self.writer)
             .field("outcome_format", &self.outcome_format)
-            .field("colorize", &self.colorize);
+            .field("colorize", &self.colorize)
+            .field("message_catalog", &self.message_catalog);
 
         #[cfg(feature = "output_progress")]
         {
             debug_struct
                 .field("progress_target", &self.progress_target)
                 .field("progress_format", &self.progress_format)
-                .field("pb_item_id_width", &self.pb_item_id_width);
+                .field("pb_item_id_width", &self.pb_item_id_width)
+                .field("pb_item_groups", &self.pb_item_groups);
         }
 
         debug_struct.field(
@@ -229,7 +238,9 @@ where
         T: Serialize + ?Sized,
         F: FnOnce(serde_yaml::Error) -> Error,
     {
-        let t_serialized = serde_yaml::to_string(t).map_err(fn_error)?;
+        let t_serialized = SensitiveSerdeMode::Redact
+            .scope(|| serde_yaml::to_string(t))
+            .map_err(fn_error)?;
 
         self.writer
             .write_all(t_serialized.as_bytes())
@@ -246,7 +257,9 @@ where
         T: Serialize + ?Sized,
         F: FnOnce(serde_json::Error) -> Error,
     {
-        let t_serialized = serde_json::to_string(t).map_err(fn_error)?;
+        let t_serialized = SensitiveSerdeMode::Redact
+            .scope(|| serde_json::to_string(t))
+            .map_err(fn_error)?;
 
         self.writer
             .write_all(t_serialized.as_bytes())
@@ -257,9 +270,116 @@ where
         Ok(())
     }
 
+    /// Returns the error text to write, rendering any [`CmdOutcomeReport`] in
+    /// the error's source chain in full.
+    fn error_text(&self, error: &(dyn std::error::Error + 'static)) -> String {
+        let cmd_outcome_report = std::iter::successors(Some(error), |error| error.source())
+            .find_map(|error| error.downcast_ref::<CmdOutcomeReport>());
+
+        let Some(cmd_outcome_report) = cmd_outcome_report else {
+            return format!("{error}\n");
+        };
+
+        let mut error_text = String::new();
+
+        #[cfg(feature = "error_reporting")]
+        {
+            use miette::{GraphicalReportHandler, GraphicalTheme};
+            use peace_rt_model_core::LocalizedDiagnostic;
+
+            let theme = match self.colorize {
+                CliColorize::Colored => GraphicalTheme::unicode(),
+                CliColorize::Uncolored => GraphicalTheme::unicode_nocolor(),
+            };
+            let (Ok(()) | Err(_)) = GraphicalReportHandler::new_themed(theme).render_report(
+                &mut error_text,
+                &LocalizedDiagnostic::new(cmd_outcome_report, &self.message_catalog),
+            );
+        }
+
+        #[cfg(not(feature = "error_reporting"))]
+        {
+            let (Ok(()) | Err(_)) =
+                cmd_outcome_report.fmt_text_localized(&mut error_text, &self.message_catalog);
+        }
+
+        error_text
+    }
+
+    /// Returns the progress tracker and update for the innermost sub-step
+    /// that the progress update is for, and how deeply it is nested.
+    #[cfg(feature = "output_progress")]
+    fn progress_update_leaf<'t>(
+        mut progress_tracker: &'t ProgressTracker,
+        mut progress_update: &'t ProgressUpdate,
+    ) -> (&'t ProgressTracker, &'t ProgressUpdate, usize) {
+        let mut depth = 0;
+        while let ProgressUpdate::SubStep {
+            name,
+            progress_update: sub_progress_update,
+        } = progress_update
+        {
+            let Some(sub_tracker) = progress_tracker.sub_tracker(name) else {
+                break;
+            };
+            progress_tracker = sub_tracker;
+            progress_update = sub_progress_update;
+            depth += 1;
+        }
+
+        (progress_tracker, progress_update, depth)
+    }
+
+    /// Updates an item group's progress bar with the progress of one of its
+    /// items.
     #[cfg(feature = "output_progress")]
-    fn progress_bar_style_update(&self, progress_tracker: &ProgressTracker) {
-        let template = self.progress_bar_template(progress_tracker);
+    fn progress_update_item_group(
+        &mut self,
+        item_group_index: usize,
+        progress_tracker: &ProgressTracker,
+        progress_update_and_id: &ProgressUpdateAndId,
+    ) {
+        // Sub-step progress bars of grouped items are hidden, as the group is
+        // rendered as one unit.
+        let (sub_tracker, _progress_update, depth) =
+            Self::progress_update_leaf(progress_tracker, &progress_update_and_id.progress_update);
+        if depth > 0 {
+            let progress_style = ProgressStyle::with_template("").unwrap_or_else(|error| {
+                panic!("`ProgressStyle` template was invalid. Template: `\"\"`. Error: {error}")
+            });
+            sub_tracker.progress_bar().set_style(progress_style);
+        }
+
+        let Some(item_group) = self.pb_item_groups.get_mut(item_group_index) else {
+            return;
+        };
+        item_group.member_status_update(
+            &progress_update_and_id.item_id,
+            progress_tracker.progress_status().clone(),
+        );
+
+        let item_group = &self.pb_item_groups[item_group_index];
+        let group_tracker = &item_group.progress_tracker;
+        self.progress_bar_style_update(group_tracker, 0);
+        match group_tracker.progress_status() {
+            ProgressStatus::Complete(ProgressComplete::Success | ProgressComplete::Skipped) => {
+                group_tracker.progress_bar().finish();
+            }
+            ProgressStatus::Complete(ProgressComplete::Fail) | ProgressStatus::Interrupted => {
+                group_tracker.progress_bar().abandon();
+            }
+            ProgressStatus::Initialized
+            | ProgressStatus::ExecPending
+            | ProgressStatus::Queued
+            | ProgressStatus::Running
+            | ProgressStatus::RunningStalled
+            | ProgressStatus::UserPending => {}
+        }
+    }
+
+    #[cfg(feature = "output_progress")]
+    fn progress_bar_style_update(&self, progress_tracker: &ProgressTracker, depth: usize) {
+        let template = self.progress_bar_template(progress_tracker, depth);
         let progress_bar = progress_tracker.progress_bar();
         progress_bar.set_style(
             ProgressStyle::with_template(template.as_str())
@@ -326,7 +446,7 @@ where
     }
 
     #[cfg(feature = "output_progress")]
-    fn progress_bar_template(&self, progress_tracker: &ProgressTracker) -> String {
+    fn progress_bar_template(&self, progress_tracker: &ProgressTracker, depth: usize) -> String {
         let icon = match progress_tracker.progress_status() {
             ProgressStatus::Initialized => "⚫",
             ProgressStatus::ExecPending => "⚪",
@@ -337,6 +457,7 @@ where
             ProgressStatus::UserPending => "👤",
             ProgressStatus::Complete(ProgressComplete::Success) => "✅",
             ProgressStatus::Complete(ProgressComplete::Fail) => "❌",
+            ProgressStatus::Complete(ProgressComplete::Skipped) => "⏭️",
         };
 
         // These are used to tell `indicatif` how to style the computed bar.
@@ -385,6 +506,9 @@ where
                                 console::style(BAR_FULL).color256(GREEN_LIGHT)
                             }
                             ProgressComplete::Fail => console::style("{bar:40.160}"),
+                            ProgressComplete::Skipped => {
+                                console::style(BAR_EMPTY).color256(GRAY_MED)
+                            }
                         },
                     }
                 } else {
@@ -411,6 +535,9 @@ where
                             ProgressComplete::Fail => {
                                 console::style(SPINNER_FULL).color256(RED_DIM)
                             }
+                            ProgressComplete::Skipped => {
+                                console::style(SPINNER_EMPTY).color256(GRAY_MED)
+                            }
                         },
                     }
                 }
@@ -432,13 +559,19 @@ where
                 }
             }
         };
-        let prefix_width = self.pb_item_id_width.unwrap_or(20);
+        // Sub-step progress bars are indented under their parent, and the
+        // prefix is narrowed so that the bars stay aligned.
+        let indent = "  ".repeat(depth);
+        let prefix_width = self
+            .pb_item_id_width
+            .unwrap_or(20)
+            .saturating_sub(indent.len());
         let prefix = format!("{{prefix:{prefix_width}}}");
 
         let (progress_is_complete, completion_is_successful) =
             match progress_tracker.progress_status() {
                 ProgressStatus::Complete(progress_complete) => {
-                    (true, progress_complete.is_successful())
+                    (true, !progress_complete.is_failure())
                 }
                 _ => (false, false),
             };
@@ -474,7 +607,7 @@ where
         // ```
 
         // `prefix` is the item ID.
-        let mut format_str = format!("{icon} {prefix} {bar_or_spinner}");
+        let mut format_str = format!("{indent}{icon} {prefix} {bar_or_spinner}");
         if let Some(units) = units {
             format_str.push_str(units);
         }
@@ -500,7 +633,7 @@ impl Default for CliOutput<Stdout> {
 #[async_trait(?Send)]
 impl<E, W> OutputWrite<E> for CliOutput<W>
 where
-    E: std::error::Error + From<Error>,
+    E: std::error::Error + From<Error> + 'static,
     W: AsyncWrite + Debug + Unpin,
 {
     #[cfg(feature = "output_progress")]
@@ -523,13 +656,94 @@ where
                     .multi_progress()
                     .set_draw_target(progress_draw_target);
 
+                // Items in an item group are rendered as a single progress bar
+                // for the group, inserted before the group's first item.
+                //
+                // The items' own progress bars are kept in the `MultiProgress`
+                // so that sub-step progress bars can be inserted after them,
+                // but are styled with an empty template to hide them.
+                self.pb_item_groups = Vec::new();
+                let progress_style_hidden =
+                    ProgressStyle::with_template("").unwrap_or_else(|error| {
+                        panic!(
+                            "`ProgressStyle` template was invalid. Template: `\"\"`. Error: {error}"
+                        )
+                    });
+                cmd_progress_tracker.progress_trackers().iter().for_each(
+                    |(item_id, progress_tracker)| {
+                        let Some((item_group_id, item_ids)) = cmd_progress_tracker
+                            .item_groups()
+                            .iter()
+                            .find(|(_item_group_id, item_ids)| item_ids.contains(item_id))
+                        else {
+                            return;
+                        };
+
+                        progress_tracker
+                            .progress_bar()
+                            .set_style(progress_style_hidden.clone());
+
+                        let item_group_exists = self
+                            .pb_item_groups
+                            .iter()
+                            .any(|item_group| &item_group.item_group_id == item_group_id);
+                        if !item_group_exists {
+                            let progress_bar = cmd_progress_tracker.multi_progress().insert_before(
+                                progress_tracker.progress_bar(),
+                                ProgressBar::hidden(),
+                            );
+                            let item_ids = item_ids
+                                .iter()
+                                .filter(|item_id| {
+                                    cmd_progress_tracker
+                                        .progress_trackers()
+                                        .contains_key(*item_id)
+                                })
+                                .cloned()
+                                .collect::<Vec<_>>();
+                            self.pb_item_groups.push(CliItemGroupProgress::new(
+                                item_group_id.clone(),
+                                progress_bar,
+                                item_ids,
+                            ));
+                        }
+                    },
+                );
+
+                // Each entry is either an ungrouped item, or an item group.
+                let pb_entries = cmd_progress_tracker
+                    .progress_trackers()
+                    .iter()
+                    .filter_map(|(item_id, progress_tracker)| {
+                        match self
+                            .pb_item_groups
+                            .iter()
+                            .find(|item_group| item_group.contains(item_id))
+                        {
+                            Some(item_group) => {
+                                let is_first_item = item_group
+                                    .member_statuses
+                                    .first()
+                                    .is_some_and(|(first_item_id, _)| first_item_id == item_id);
+                                is_first_item.then(|| {
+                                    (
+                                        item_group.item_group_id.to_string(),
+                                        &item_group.progress_tracker,
+                                    )
+                                })
+                            }
+                            None => Some((item_id.to_string(), progress_tracker)),
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
                 // TODO: test with multiple item IDs of varying length
                 self.pb_item_id_width = {
-                    if cmd_progress_tracker.progress_trackers().is_empty() {
+                    if pb_entries.is_empty() {
                         Some(0)
                     } else {
                         let list_digit_width = {
-                            usize::checked_ilog10(cmd_progress_tracker.progress_trackers().len())
+                            usize::checked_ilog10(pb_entries.len())
                                 .and_then(|digit_width_u32| usize::try_from(digit_width_u32).ok())
                                 .map(|digit_width| {
                                     let dot_width = 1;
@@ -540,21 +754,18 @@ where
                                 })
                                 .unwrap_or(0)
                         };
-                        let item_id_width = cmd_progress_tracker.progress_trackers().iter().fold(
+                        let item_id_width = pb_entries.iter().fold(
                             0,
-                            |pb_item_id_width, (item_id, _progress_tracker)| {
-                                std::cmp::max(item_id.len(), pb_item_id_width)
+                            |pb_item_id_width, (pb_entry_id, _progress_tracker)| {
+                                std::cmp::max(pb_entry_id.len(), pb_item_id_width)
                             },
                         );
 
                         Some(list_digit_width + item_id_width)
                     }
                 };
-                cmd_progress_tracker
-                    .progress_trackers()
-                    .iter()
-                    .enumerate()
-                    .for_each(|(index, (item_id, progress_tracker))| {
+                pb_entries.iter().enumerate().for_each(
+                    |(index, (pb_entry_id, progress_tracker))| {
                         let progress_bar = progress_tracker.progress_bar();
 
                         // Hack: colourization done in `progress_begin` to get
@@ -567,23 +778,23 @@ where
                                     .color256(15)
                                     .apply_to(format!("{index}."));
                                 // blue
-                                let item_id_colorized = console::Style::new()
-                                    .color256(75)
-                                    .apply_to(format!("{item_id}"));
+                                let item_id_colorized =
+                                    console::Style::new().color256(75).apply_to(pb_entry_id);
                                 progress_bar
                                     .set_prefix(format!("{index_colorized} {item_id_colorized}"));
                             }
                             CliColorize::Uncolored => {
-                                progress_bar.set_prefix(format!("{index}. {item_id}"));
+                                progress_bar.set_prefix(format!("{index}. {pb_entry_id}"));
                             }
                         }
 
-                        self.progress_bar_style_update(progress_tracker);
+                        self.progress_bar_style_update(progress_tracker, 0);
 
                         // Hack: This should be done with a timer in `ApplyCmd`.
                         // This uses threads, which is not WASM compatible.
                         progress_bar.enable_steady_tick(std::time::Duration::from_millis(100));
-                    });
+                    },
+                );
             }
             CliProgressFormat::Outcome => {
                 cmd_progress_tracker
@@ -620,24 +831,43 @@ where
                 // * Need to update progress bar colour on finish (blue to green)
                 // * Need to update progress bar colour on error (blue to red)
 
+                let item_id = &progress_update_and_id.item_id;
+                if let Some(item_group_index) = self
+                    .pb_item_groups
+                    .iter()
+                    .position(|item_group| item_group.contains(item_id))
+                {
+                    self.progress_update_item_group(
+                        item_group_index,
+                        progress_tracker,
+                        progress_update_and_id,
+                    );
+                    return;
+                }
+
+                let (progress_tracker, progress_update, depth) = Self::progress_update_leaf(
+                    progress_tracker,
+                    &progress_update_and_id.progress_update,
+                );
+
                 if let Some(message) = progress_tracker.message().cloned() {
                     progress_tracker.progress_bar().set_message(message);
                 }
 
-                match &progress_update_and_id.progress_update {
+                match progress_update {
                     ProgressUpdate::Reset
                     | ProgressUpdate::ResetToPending
                     | ProgressUpdate::Queued => {
-                        self.progress_bar_style_update(progress_tracker);
+                        self.progress_bar_style_update(progress_tracker, depth);
                     }
                     ProgressUpdate::Interrupt => {
-                        self.progress_bar_style_update(progress_tracker);
+                        self.progress_bar_style_update(progress_tracker, depth);
                         let progress_bar = progress_tracker.progress_bar();
                         progress_bar.abandon();
                     }
                     ProgressUpdate::Limit(_progress_limit) => {
                         // Note: `progress_tracker` also carries the `progress_limit`
-                        self.progress_bar_style_update(progress_tracker);
+                        self.progress_bar_style_update(progress_tracker, depth);
                     }
                     ProgressUpdate::Delta(_delta) => {
                         // Status may have changed from `ExecPending` to
@@ -647,25 +877,45 @@ where
                         //
                         // TODO: Is this too much of a performance hit, and we send another message
                         // for spinners?
-                        self.progress_bar_style_update(progress_tracker);
+                        self.progress_bar_style_update(progress_tracker, depth);
                     }
                     ProgressUpdate::Complete(progress_complete) => match progress_complete {
-                        ProgressComplete::Success => {
-                            self.progress_bar_style_update(progress_tracker);
+                        ProgressComplete::Success | ProgressComplete::Skipped => {
+                            self.progress_bar_style_update(progress_tracker, depth);
 
                             let progress_bar = progress_tracker.progress_bar();
                             progress_bar.finish();
                         }
                         ProgressComplete::Fail => {
-                            self.progress_bar_style_update(progress_tracker);
+                            self.progress_bar_style_update(progress_tracker, depth);
 
                             let progress_bar = progress_tracker.progress_bar();
                             progress_bar.abandon();
                         }
                     },
+                    ProgressUpdate::SubStep { .. } => {
+                        // The sub-step's progress tracker has not been created.
+                    }
                 }
             }
             CliProgressFormat::Outcome => {
+                // Sub-step progress bars are added to the `MultiProgress` as
+                // updates are received, so they are not styled in
+                // `progress_begin`.
+                let (sub_tracker, _progress_update, depth) = Self::progress_update_leaf(
+                    progress_tracker,
+                    &progress_update_and_id.progress_update,
+                );
+                if depth > 0 {
+                    let progress_style =
+                        ProgressStyle::with_template("").unwrap_or_else(|error| {
+                            panic!(
+                                "`ProgressStyle` template was invalid. Template: `\"\"`. Error: {error}"
+                            )
+                        });
+                    sub_tracker.progress_bar().set_style(progress_style);
+                }
+
                 let progress_bar = progress_tracker.progress_bar();
                 match self.outcome_format {
                     // Note: outputting yaml for Text output, because we aren't sending much
@@ -700,19 +950,32 @@ where
 
                 // Hack: This should be done with a timer in `ApplyCmd`.
                 // This uses threads, which is not WASM compatible.
-                cmd_progress_tracker.progress_trackers().iter().for_each(
-                    |(_item_id, progress_tracker)| {
+                cmd_progress_tracker
+                    .progress_trackers()
+                    .values()
+                    .chain(
+                        self.pb_item_groups
+                            .iter()
+                            .map(|item_group| &item_group.progress_tracker),
+                    )
+                    .for_each(|progress_tracker| {
                         let progress_bar = progress_tracker.progress_bar();
                         progress_bar.disable_steady_tick();
                         progress_bar.tick();
-                    },
-                );
+                    });
 
                 // Prevents progress bars from drawing over error messages.
                 cmd_progress_tracker
                     .multi_progress
                     .set_draw_target(ProgressDrawTarget::hidden());
 
+                // Item group progress bars are added per command execution.
+                self.pb_item_groups.drain(..).for_each(|item_group| {
+                    cmd_progress_tracker
+                        .multi_progress
+                        .remove(item_group.progress_tracker.progress_bar());
+                });
+
                 // Add spacing between end of progress bars and next output.
                 //
                 // For some reason it needs two newlines, `indicatif` possibly
@@ -723,6 +986,10 @@ where
         }
     }
 
+    fn message_catalog_set(&mut self, message_catalog: MessageCatalog) {
+        self.message_catalog = message_catalog;
+    }
+
     async fn present<P>(&mut self, presentable: P) -> Result<(), E>
     where
         P: Presentable,
@@ -740,8 +1007,9 @@ where
     async fn write_err(&mut self, error: &E) -> Result<(), E> {
         match self.outcome_format {
             OutputFormat::Text => {
+                let error_text = self.error_text(error);
                 self.writer
-                    .write_all(format!("{error}\n").as_bytes())
+                    .write_all(error_text.as_bytes())
                     .await
                     .map_err(NativeError::StdoutWrite)
                     .map_err(Error::Native)?;
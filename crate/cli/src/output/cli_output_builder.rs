@@ -1,6 +1,7 @@
 use std::io::IsTerminal;
 
 use peace_cli_model::OutputFormat;
+use peace_fmt::MessageCatalog;
 use tokio::io::{AsyncWrite, Stdout};
 
 use crate::output::{CliColorize, CliColorizeOpt, CliOutput};
@@ -284,12 +285,15 @@ where
             writer,
             outcome_format,
             colorize,
+            message_catalog: MessageCatalog::new(),
             #[cfg(feature = "output_progress")]
             progress_target,
             #[cfg(feature = "output_progress")]
             progress_format,
             #[cfg(feature = "output_progress")]
             pb_item_id_width: None,
+            #[cfg(feature = "output_progress")]
+            pb_item_groups: Vec::new(),
             #[cfg(unix)]
             stdin_tty_with_guard,
         }
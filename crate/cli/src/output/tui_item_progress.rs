@@ -0,0 +1,79 @@
+use peace_core::progress::{ProgressComplete, ProgressStatus, ProgressTracker};
+use ratatui::style::{Color, Style};
+
+/// Snapshot of an item's progress, rendered as a gauge by `TuiOutput`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct TuiItemProgress {
+    /// Status of the item's execution.
+    pub(crate) progress_status: ProgressStatus,
+    /// Number of units of progress made.
+    pub(crate) units_current: u64,
+    /// Total number of units of progress, if known.
+    pub(crate) units_total: Option<u64>,
+    /// Progress message, if any.
+    pub(crate) message: Option<String>,
+}
+
+impl TuiItemProgress {
+    /// Returns the ratio of progress made, between `0.0` and `1.0`.
+    pub(crate) fn ratio(&self) -> f64 {
+        match (&self.progress_status, self.units_total) {
+            (ProgressStatus::Complete(ProgressComplete::Success), _) => 1.0,
+            (_, Some(units_total)) if units_total > 0 => {
+                (self.units_current as f64 / units_total as f64).min(1.0)
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// Returns the gauge label, e.g. `"running 3/10 downloading"`.
+    pub(crate) fn label(&self) -> String {
+        let status = match &self.progress_status {
+            ProgressStatus::Initialized => "initialized",
+            ProgressStatus::Interrupted => "interrupted",
+            ProgressStatus::ExecPending => "pending",
+            ProgressStatus::Queued => "queued",
+            ProgressStatus::Running => "running",
+            ProgressStatus::RunningStalled => "stalled",
+            ProgressStatus::UserPending => "awaiting input",
+            ProgressStatus::Complete(ProgressComplete::Success) => "done",
+            ProgressStatus::Complete(ProgressComplete::Fail) => "failed",
+            ProgressStatus::Complete(ProgressComplete::Skipped) => "skipped",
+        };
+        let mut label = String::from(status);
+        if let Some(units_total) = self.units_total {
+            label.push_str(&format!(" {}/{units_total}", self.units_current));
+        }
+        if let Some(message) = self.message.as_deref() {
+            label.push(' ');
+            label.push_str(message);
+        }
+        label
+    }
+
+    /// Returns the gauge style for the item's status.
+    pub(crate) fn style(&self) -> Style {
+        let color = match &self.progress_status {
+            ProgressStatus::Initialized | ProgressStatus::ExecPending | ProgressStatus::Queued => {
+                Color::DarkGray
+            }
+            ProgressStatus::Interrupted | ProgressStatus::UserPending => Color::Yellow,
+            ProgressStatus::Running | ProgressStatus::RunningStalled => Color::Blue,
+            ProgressStatus::Complete(ProgressComplete::Success) => Color::Green,
+            ProgressStatus::Complete(ProgressComplete::Fail) => Color::Red,
+            ProgressStatus::Complete(ProgressComplete::Skipped) => Color::Gray,
+        };
+        Style::default().fg(color)
+    }
+}
+
+impl From<&ProgressTracker> for TuiItemProgress {
+    fn from(progress_tracker: &ProgressTracker) -> Self {
+        Self {
+            progress_status: progress_tracker.progress_status().clone(),
+            units_current: progress_tracker.units_current(),
+            units_total: progress_tracker.units_total(),
+            message: progress_tracker.message().cloned(),
+        }
+    }
+}
@@ -0,0 +1,341 @@
+use std::io::Stdout;
+
+use peace_cli_model::OutputFormat;
+use peace_core::{
+    progress::{ProgressTracker, ProgressUpdateAndId},
+    ItemId,
+};
+use peace_fmt::{MessageCatalog, Presentable};
+use peace_rt_model_core::{
+    async_trait, indicatif::ProgressDrawTarget, output::OutputWrite, CmdProgressTracker, Error,
+    IndexMap, NativeError,
+};
+use ratatui::{
+    backend::{Backend, CrosstermBackend},
+    crossterm::{
+        cursor, execute,
+        terminal::{EnterAlternateScreen, LeaveAlternateScreen},
+    },
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Gauge, Paragraph, Wrap},
+    Frame, Terminal,
+};
+
+use crate::output::{
+    tui_item_progress::TuiItemProgress, CliColorize, CliOutput, CliOutputTarget, CliProgressFormat,
+};
+
+/// Height of the error pane when there are errors, including borders.
+const ERROR_PANE_HEIGHT: u16 = 8;
+
+/// An `OutputWrite` implementation that renders a full screen terminal UI.
+///
+/// The screen is split into:
+///
+/// * A progress pane, with a gauge per item.
+/// * A log pane, which scrolls to show the latest presented output.
+/// * An error pane, which is shown when an error is written.
+///
+/// This is intended for interactive usage of long running flows with many
+/// items, and is enabled through the `"tui"` feature.
+///
+/// When created with [`TuiOutput::new`], the terminal's alternate screen is
+/// used while the `TuiOutput` exists. When it is dropped, the alternate screen
+/// is left, and the logged output and errors are written to `stdout` and
+/// `stderr`, so that they remain visible after the command ends.
+#[derive(Debug)]
+pub struct TuiOutput<B>
+where
+    B: Backend,
+{
+    /// Terminal to render the UI to.
+    terminal: Terminal<B>,
+    /// Renders presentables and errors as text for the log and error panes.
+    text_output: CliOutput<Vec<u8>>,
+    /// Progress of each item, in flow order.
+    item_progresses: IndexMap<ItemId, TuiItemProgress>,
+    /// Lines of presented output.
+    log_lines: Vec<String>,
+    /// Lines of written errors.
+    error_lines: Vec<String>,
+    /// Whether the alternate screen was entered, and should be left on drop.
+    alternate_screen_entered: bool,
+}
+
+impl TuiOutput<CrosstermBackend<Stdout>> {
+    /// Returns a new `TuiOutput` that renders to `stdout`, using the
+    /// terminal's alternate screen.
+    pub fn new() -> Result<Self, std::io::Error> {
+        let mut stdout = std::io::stdout();
+        execute!(stdout, EnterAlternateScreen, cursor::Hide)?;
+
+        let terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+        let mut tui_output = Self::new_with_terminal(terminal);
+        tui_output.alternate_screen_entered = true;
+
+        Ok(tui_output)
+    }
+}
+
+impl<B> TuiOutput<B>
+where
+    B: Backend,
+{
+    /// Returns a new `TuiOutput` that renders to the given terminal.
+    ///
+    /// The terminal is used as is, e.g. the alternate screen is not entered.
+    /// This is useful for rendering to a `ratatui::backend::TestBackend`.
+    pub fn new_with_terminal(terminal: Terminal<B>) -> Self {
+        let text_output = CliOutput {
+            writer: Vec::new(),
+            outcome_format: OutputFormat::Text,
+            colorize: CliColorize::Uncolored,
+            message_catalog: MessageCatalog::new(),
+            progress_target: CliOutputTarget::Stderr,
+            progress_format: CliProgressFormat::None,
+            pb_item_id_width: None,
+            pb_item_groups: Vec::new(),
+            #[cfg(unix)]
+            stdin_tty_with_guard: None,
+        };
+
+        Self {
+            terminal,
+            text_output,
+            item_progresses: IndexMap::new(),
+            log_lines: Vec::new(),
+            error_lines: Vec::new(),
+            alternate_screen_entered: false,
+        }
+    }
+
+    /// Returns the terminal that the UI is rendered to.
+    pub fn terminal(&self) -> &Terminal<B> {
+        &self.terminal
+    }
+
+    /// Returns the lines of presented output.
+    pub fn log_lines(&self) -> &[String] {
+        &self.log_lines
+    }
+
+    /// Returns the lines of written errors.
+    pub fn error_lines(&self) -> &[String] {
+        &self.error_lines
+    }
+
+    /// Renders the UI to the terminal.
+    pub fn draw(&mut self) -> Result<(), std::io::Error> {
+        let Self {
+            terminal,
+            item_progresses,
+            log_lines,
+            error_lines,
+            ..
+        } = self;
+
+        terminal
+            .draw(|frame| Self::render(frame, item_progresses, log_lines, error_lines))
+            .map(|_completed_frame| ())
+    }
+
+    fn render(
+        frame: &mut Frame<'_>,
+        item_progresses: &IndexMap<ItemId, TuiItemProgress>,
+        log_lines: &[String],
+        error_lines: &[String],
+    ) {
+        let progress_pane_height =
+            u16::try_from(item_progresses.len().max(1) + 2).unwrap_or(u16::MAX);
+        let error_pane_height = if error_lines.is_empty() {
+            0
+        } else {
+            ERROR_PANE_HEIGHT
+        };
+        let [progress_area, log_area, error_area] = Layout::vertical([
+            Constraint::Length(progress_pane_height),
+            Constraint::Min(3),
+            Constraint::Length(error_pane_height),
+        ])
+        .areas(frame.area());
+
+        Self::render_progress(frame, progress_area, item_progresses);
+        Self::render_lines(frame, log_area, " Log ", log_lines, Style::default());
+        if !error_lines.is_empty() {
+            Self::render_lines(
+                frame,
+                error_area,
+                " Errors ",
+                error_lines,
+                Style::default().fg(Color::Red),
+            );
+        }
+    }
+
+    fn render_progress(
+        frame: &mut Frame<'_>,
+        area: Rect,
+        item_progresses: &IndexMap<ItemId, TuiItemProgress>,
+    ) {
+        let block = Block::bordered().title(" Progress ");
+        let inner_area = block.inner(area);
+        frame.render_widget(block, area);
+
+        let item_id_width = item_progresses
+            .keys()
+            .map(|item_id| item_id.len())
+            .max()
+            .unwrap_or(0);
+        let row_areas = Layout::vertical(
+            item_progresses
+                .iter()
+                .map(|_| Constraint::Length(1))
+                .collect::<Vec<Constraint>>(),
+        )
+        .split(inner_area);
+
+        item_progresses.iter().zip(row_areas.iter()).for_each(
+            |((item_id, item_progress), row_area)| {
+                let [item_id_area, gauge_area] = Layout::horizontal([
+                    Constraint::Length(u16::try_from(item_id_width + 1).unwrap_or(u16::MAX)),
+                    Constraint::Min(0),
+                ])
+                .areas(*row_area);
+
+                frame.render_widget(Line::raw(item_id.to_string()), item_id_area);
+                frame.render_widget(
+                    Gauge::default()
+                        .gauge_style(item_progress.style())
+                        .ratio(item_progress.ratio())
+                        .label(item_progress.label()),
+                    gauge_area,
+                );
+            },
+        );
+    }
+
+    /// Renders the lines in a bordered pane, scrolled to the last line.
+    fn render_lines(
+        frame: &mut Frame<'_>,
+        area: Rect,
+        title: &str,
+        lines: &[String],
+        style: Style,
+    ) {
+        let block = Block::bordered().title(title.to_string());
+        let visible_height = usize::from(block.inner(area).height);
+        let scroll_offset =
+            u16::try_from(lines.len().saturating_sub(visible_height)).unwrap_or(u16::MAX);
+        let lines = lines
+            .iter()
+            .map(|line| Line::raw(line.as_str()))
+            .collect::<Vec<Line<'_>>>();
+
+        frame.render_widget(
+            Paragraph::new(lines)
+                .style(style)
+                .block(block)
+                .wrap(Wrap { trim: false })
+                .scroll((scroll_offset, 0)),
+            area,
+        );
+    }
+
+    /// Takes the text written to `text_output`, and returns it as lines.
+    fn text_output_lines_take(&mut self) -> impl Iterator<Item = String> {
+        let text = std::mem::take(&mut self.text_output.writer);
+        String::from_utf8_lossy(&text)
+            .lines()
+            .map(String::from)
+            .collect::<Vec<String>>()
+            .into_iter()
+    }
+}
+
+impl<B> Drop for TuiOutput<B>
+where
+    B: Backend,
+{
+    fn drop(&mut self) {
+        if self.alternate_screen_entered {
+            let (Ok(()) | Err(_)) = execute!(std::io::stdout(), LeaveAlternateScreen, cursor::Show);
+
+            self.log_lines.iter().for_each(|line| println!("{line}"));
+            self.error_lines.iter().for_each(|line| eprintln!("{line}"));
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<E, B> OutputWrite<E> for TuiOutput<B>
+where
+    E: std::error::Error + From<Error> + 'static,
+    B: Backend + std::fmt::Debug + Unpin,
+{
+    async fn progress_begin(&mut self, cmd_progress_tracker: &CmdProgressTracker) {
+        // Progress is rendered by this `TuiOutput` instead of `indicatif`.
+        cmd_progress_tracker
+            .multi_progress()
+            .set_draw_target(ProgressDrawTarget::hidden());
+
+        self.item_progresses = cmd_progress_tracker
+            .progress_trackers()
+            .iter()
+            .map(|(item_id, progress_tracker)| {
+                (item_id.clone(), TuiItemProgress::from(progress_tracker))
+            })
+            .collect();
+
+        let (Ok(()) | Err(_)) = self.draw();
+    }
+
+    async fn progress_update(
+        &mut self,
+        progress_tracker: &ProgressTracker,
+        progress_update_and_id: &ProgressUpdateAndId,
+    ) {
+        self.item_progresses.insert(
+            progress_update_and_id.item_id.clone(),
+            TuiItemProgress::from(progress_tracker),
+        );
+
+        let (Ok(()) | Err(_)) = self.draw();
+    }
+
+    async fn progress_end(&mut self, _cmd_progress_tracker: &CmdProgressTracker) {
+        let (Ok(()) | Err(_)) = self.draw();
+    }
+
+    fn message_catalog_set(&mut self, message_catalog: MessageCatalog) {
+        OutputWrite::<E>::message_catalog_set(&mut self.text_output, message_catalog);
+    }
+
+    async fn present<P>(&mut self, presentable: P) -> Result<(), E>
+    where
+        P: Presentable,
+    {
+        OutputWrite::<E>::present(&mut self.text_output, presentable).await?;
+        let lines = self.text_output_lines_take();
+        self.log_lines.extend(lines);
+
+        self.draw()
+            .map_err(NativeError::CliOutputPresent)
+            .map_err(Error::Native)?;
+
+        Ok(())
+    }
+
+    async fn write_err(&mut self, error: &E) -> Result<(), E> {
+        OutputWrite::<E>::write_err(&mut self.text_output, error).await?;
+        let lines = self.text_output_lines_take();
+        self.error_lines.extend(lines);
+
+        self.draw()
+            .map_err(NativeError::CliOutputPresent)
+            .map_err(Error::Native)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,197 @@
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use clap::{builder::PossibleValue, Command, CommandFactory};
+use clap_complete::Shell;
+use peace_cfg::{FlowId, ItemId, Profile};
+use peace_rt_model::Flow;
+
+/// Generates shell completions and man pages for an app's command line
+/// interface.
+///
+/// The profiles and flows passed in are added as possible values of the
+/// `--profile` and `--flow` arguments from [`CmdArgs`], so shells can complete
+/// them. Each flow's item IDs are included in the flow's description, and in
+/// a "Flows" section of the man page.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use peace::cli_args::{clap_complete::Shell, CliDocsGen};
+///
+/// let cli_docs_gen = CliDocsGen::new::<AppArgs>()
+///     .with_profiles(cmd_ctx.profiles().iter().cloned())
+///     .with_flows(&flows);
+///
+/// cli_docs_gen.completions_write(Shell::Bash, &mut std::io::stdout());
+/// cli_docs_gen.man_pages_generate_to("target/man")?;
+/// ```
+///
+/// [`CmdArgs`]: crate::CmdArgs
+#[derive(Clone, Debug)]
+pub struct CliDocsGen {
+    /// The app's command.
+    cmd: Command,
+    /// Profiles in the workspace.
+    profiles: Vec<Profile>,
+    /// Flows in the app, and the IDs of their items.
+    flows: Vec<(FlowId, Vec<ItemId>)>,
+}
+
+impl CliDocsGen {
+    /// Name of the argument to select a flow.
+    const ARG_FLOW: &'static str = "flow";
+    /// Name of the argument to select a profile.
+    const ARG_PROFILE: &'static str = "profile";
+
+    /// Returns a new `CliDocsGen` for the app's arguments type.
+    pub fn new<AppArgsT>() -> Self
+    where
+        AppArgsT: CommandFactory,
+    {
+        Self::from_command(AppArgsT::command())
+    }
+
+    /// Returns a new `CliDocsGen` for the given command.
+    pub fn from_command(cmd: Command) -> Self {
+        Self {
+            cmd,
+            profiles: Vec::new(),
+            flows: Vec::new(),
+        }
+    }
+
+    /// Adds the profiles to complete for the `--profile` argument.
+    ///
+    /// These are usually the profiles in the workspace, which are accessible
+    /// from a `MultiProfileNoFlow` scope.
+    pub fn with_profiles<I>(mut self, profiles: I) -> Self
+    where
+        I: IntoIterator<Item = Profile>,
+    {
+        self.profiles.extend(profiles);
+        self
+    }
+
+    /// Adds the flow to complete for the `--flow` argument.
+    pub fn with_flow<E>(mut self, flow: &Flow<E>) -> Self
+    where
+        E: 'static,
+    {
+        let item_ids = flow
+            .graph()
+            .iter_insertion()
+            .map(|item| item.id().clone())
+            .collect::<Vec<ItemId>>();
+        self.flows.push((flow.flow_id().clone(), item_ids));
+        self
+    }
+
+    /// Adds the flows to complete for the `--flow` argument.
+    pub fn with_flows<E>(self, flows: &[Flow<E>]) -> Self
+    where
+        E: 'static,
+    {
+        flows.iter().fold(self, Self::with_flow)
+    }
+
+    /// Returns the app's command, with profiles and flows as possible values
+    /// of the `--profile` and `--flow` arguments.
+    ///
+    /// This command is only intended for generating documentation, as the
+    /// arguments' value parsers are replaced.
+    pub fn command(&self) -> Command {
+        let mut cmd = self.cmd.clone();
+
+        if !self.profiles.is_empty() && Self::arg_exists(&cmd, Self::ARG_PROFILE) {
+            let profiles = self
+                .profiles
+                .iter()
+                .map(|profile| PossibleValue::new(profile.to_string()))
+                .collect::<Vec<_>>();
+            cmd = cmd.mut_arg(Self::ARG_PROFILE, |arg| arg.value_parser(profiles));
+        }
+
+        if !self.flows.is_empty() {
+            if Self::arg_exists(&cmd, Self::ARG_FLOW) {
+                let flows = self
+                    .flows
+                    .iter()
+                    .map(|(flow_id, item_ids)| {
+                        PossibleValue::new(flow_id.to_string()).help(Self::item_ids_join(item_ids))
+                    })
+                    .collect::<Vec<_>>();
+                cmd = cmd.mut_arg(Self::ARG_FLOW, |arg| arg.value_parser(flows));
+            }
+
+            let flows_section = self.flows_section();
+            let after_long_help = match cmd.get_after_long_help() {
+                Some(after_long_help) => format!("{after_long_help}\n\n{flows_section}"),
+                None => flows_section,
+            };
+            cmd = cmd.after_long_help(after_long_help);
+        }
+
+        cmd
+    }
+
+    /// Writes the completion script for the given shell.
+    pub fn completions_write(&self, shell: Shell, buf: &mut dyn Write) {
+        let mut cmd = self.command();
+        let bin_name = cmd.get_name().to_string();
+        clap_complete::generate(shell, &mut cmd, bin_name, buf);
+    }
+
+    /// Writes the completion script for the given shell to a file in
+    /// `out_dir`, returning the path to the file.
+    pub fn completions_generate_to<P>(&self, shell: Shell, out_dir: P) -> std::io::Result<PathBuf>
+    where
+        P: AsRef<Path>,
+    {
+        let mut cmd = self.command();
+        let bin_name = cmd.get_name().to_string();
+        clap_complete::generate_to(shell, &mut cmd, bin_name, out_dir.as_ref())
+    }
+
+    /// Writes the man page for the app's top level command.
+    pub fn man_page_write(&self, buf: &mut dyn Write) -> std::io::Result<()> {
+        clap_mangen::Man::new(self.command()).render(buf)
+    }
+
+    /// Writes man pages for the app's command and each of its subcommands to
+    /// files in `out_dir`.
+    pub fn man_pages_generate_to<P>(&self, out_dir: P) -> std::io::Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        clap_mangen::generate_to(self.command(), out_dir.as_ref())
+    }
+
+    fn arg_exists(cmd: &Command, arg_id: &str) -> bool {
+        cmd.get_arguments().any(|arg| arg.get_id() == arg_id)
+    }
+
+    fn item_ids_join(item_ids: &[ItemId]) -> String {
+        item_ids
+            .iter()
+            .map(ItemId::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Returns the "Flows" section listing each flow and its item IDs.
+    fn flows_section(&self) -> String {
+        self.flows.iter().fold(
+            String::from("Flows:"),
+            |mut flows_section, (flow_id, item_ids)| {
+                flows_section.push_str(&format!(
+                    "\n  {flow_id}: {item_ids}",
+                    item_ids = Self::item_ids_join(item_ids)
+                ));
+                flows_section
+            },
+        )
+    }
+}
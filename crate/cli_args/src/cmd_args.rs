@@ -0,0 +1,94 @@
+use clap::Args;
+use peace_cfg::{FlowId, Profile};
+use peace_cli::output::{CliColorizeOpt, CliOutputBuilder};
+use peace_cli_model::OutputFormat;
+use peace_rt_model::Flow;
+use tokio::io::Stdout;
+
+/// Common arguments to select the profile, flow, and output format.
+///
+/// This is intended to be `#[command(flatten)]`ed into an app's arguments.
+/// The profile and flow IDs are validated when parsed, so an invalid ID is
+/// reported by `clap` before any command is run.
+#[derive(Args, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CmdArgs {
+    /// Profile to run the command against.
+    #[arg(long, global(true))]
+    pub profile: Option<Profile>,
+    /// Flow to run the command against.
+    ///
+    /// This may be omitted if the app only has one flow.
+    #[arg(long, global(true))]
+    pub flow: Option<FlowId>,
+    /// The format of the command output.
+    #[arg(long, global(true))]
+    pub format: Option<OutputFormat>,
+    /// Whether output should be colorized.
+    ///
+    /// * "auto" (default): Colorize when used interactively.
+    /// * "always": Always colorize output.
+    /// * "never": Never colorize output.
+    #[arg(long, default_value = "auto", global(true))]
+    pub color: CliColorizeOpt,
+}
+
+impl CmdArgs {
+    /// Returns the profile passed in, or `profile_default` if none was passed
+    /// in.
+    pub fn profile_or(&self, profile_default: Profile) -> Profile {
+        self.profile.clone().unwrap_or(profile_default)
+    }
+
+    /// Returns the flow whose ID was passed in.
+    ///
+    /// If no flow ID was passed in and there is exactly one flow, that flow is
+    /// returned.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::FlowIdNotFound`] if the flow ID does not match any of the
+    ///   `flows`.
+    /// * [`Error::FlowIdNotSpecified`] if no flow ID was passed in, and there
+    ///   is not exactly one flow.
+    ///
+    /// [`Error::FlowIdNotFound`]: peace_rt_model::Error::FlowIdNotFound
+    /// [`Error::FlowIdNotSpecified`]: peace_rt_model::Error::FlowIdNotSpecified
+    pub fn flow_select<'f, E>(
+        &self,
+        flows: &'f [Flow<E>],
+    ) -> Result<&'f Flow<E>, peace_rt_model::Error> {
+        let flow_ids = || {
+            flows
+                .iter()
+                .map(|flow| flow.flow_id().clone())
+                .collect::<Vec<_>>()
+        };
+
+        match self.flow.as_ref() {
+            Some(flow_id) => flows
+                .iter()
+                .find(|flow| flow.flow_id() == flow_id)
+                .ok_or_else(|| peace_rt_model::Error::FlowIdNotFound {
+                    flow_id: flow_id.clone(),
+                    flow_ids: flow_ids(),
+                }),
+            None => match flows {
+                [flow] => Ok(flow),
+                _ => Err(peace_rt_model::Error::FlowIdNotSpecified {
+                    flow_ids: flow_ids(),
+                }),
+            },
+        }
+    }
+
+    /// Returns a [`CliOutputBuilder`] with the output format and colorization
+    /// passed in.
+    pub fn cli_output_builder(&self) -> CliOutputBuilder<Stdout> {
+        let mut builder = CliOutputBuilder::new().with_colorize(self.color);
+        if let Some(format) = self.format {
+            builder = builder.with_outcome_format(format);
+        }
+
+        builder
+    }
+}
@@ -0,0 +1,111 @@
+use clap::Subcommand;
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::SingleProfileSingleFlow,
+};
+use peace_cmd_model::CmdOutcome;
+use peace_fmt::Presentable;
+use peace_rt::cmds::{
+    CleanCmd, DiffCmd, EnsureCmd, StatesCurrentStoredDisplayCmd, StatesDiscoverCmd,
+    StatesGoalDisplayCmd,
+};
+use peace_rt_model::output::OutputWrite;
+
+/// Standard subcommands to run against an app's flow.
+///
+/// This is intended to be used as an app's `#[command(subcommand)]`, or
+/// `#[command(flatten)]`ed into an app's own subcommand enum.
+#[derive(Subcommand, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FlowCmd {
+    /// Discovers the current and goal states of each item.
+    Discover,
+    /// Shows the current state of each item.
+    Status,
+    /// Shows the goal state of each item.
+    Goal,
+    /// Shows the difference between the stored current and goal states.
+    Diff,
+    /// Dry-run to ensure each item is in its goal state.
+    EnsureDry,
+    /// Ensures each item is in its goal state.
+    Ensure,
+    /// Dry-run to clean each item.
+    CleanDry,
+    /// Cleans each item.
+    Clean,
+}
+
+impl FlowCmd {
+    /// Runs the command against the flow in the given `CmdCtx`.
+    ///
+    /// The command's outcome is presented to the `OutputWrite`, and the
+    /// returned `CmdOutcome` contains any item errors.
+    pub async fn run<'ctx, CmdCtxTypesT>(
+        self,
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+    ) -> Result<
+        CmdOutcome<(), <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+    >
+    where
+        CmdCtxTypesT: CmdCtxTypesConstrained + 'ctx,
+    {
+        match self {
+            Self::Discover => {
+                let cmd_outcome = StatesDiscoverCmd::current_and_goal(cmd_ctx).await?;
+                Self::outcome_present(cmd_ctx, cmd_outcome).await
+            }
+            // These commands present their own outcomes.
+            Self::Status => StatesCurrentStoredDisplayCmd::exec(cmd_ctx)
+                .await
+                .map(|cmd_outcome| cmd_outcome.map(|_| ())),
+            Self::Goal => StatesGoalDisplayCmd::exec(cmd_ctx)
+                .await
+                .map(|cmd_outcome| cmd_outcome.map(|_| ())),
+            Self::Diff => {
+                let cmd_outcome = DiffCmd::stored(cmd_ctx).await?;
+                Self::outcome_present(cmd_ctx, cmd_outcome).await
+            }
+            Self::EnsureDry => {
+                let cmd_outcome = EnsureCmd::exec_dry(cmd_ctx).await?;
+                Self::outcome_present(cmd_ctx, cmd_outcome).await
+            }
+            Self::Ensure => {
+                // The apply summary is presented as a footer after the ensured states.
+                let (cmd_outcome, apply_summary) = EnsureCmd::exec_with_summary(cmd_ctx).await?;
+                let cmd_outcome = Self::outcome_present(cmd_ctx, cmd_outcome).await?;
+                if let Some(apply_summary) = apply_summary.as_ref() {
+                    cmd_ctx.output_mut().present(apply_summary).await?;
+                }
+                Ok(cmd_outcome)
+            }
+            Self::CleanDry => {
+                let cmd_outcome = CleanCmd::exec_dry(cmd_ctx).await?;
+                Self::outcome_present(cmd_ctx, cmd_outcome).await
+            }
+            Self::Clean => {
+                let cmd_outcome = CleanCmd::exec(cmd_ctx).await?;
+                Self::outcome_present(cmd_ctx, cmd_outcome).await
+            }
+        }
+    }
+
+    /// Presents the outcome's value, if any.
+    async fn outcome_present<'ctx, CmdCtxTypesT, T>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+        cmd_outcome: CmdOutcome<T, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+    ) -> Result<
+        CmdOutcome<(), <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+    >
+    where
+        CmdCtxTypesT: CmdCtxTypesConstrained + 'ctx,
+        T: Presentable,
+    {
+        if let Some(value) = cmd_outcome.value() {
+            cmd_ctx.output_mut().present(value).await?;
+        }
+
+        Ok(cmd_outcome.map(|_| ()))
+    }
+}
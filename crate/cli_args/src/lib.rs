@@ -0,0 +1,39 @@
+//! Command line argument parsing helpers for the peace automation framework.
+//!
+//! This provides [`clap`] argument types that map to peace's [`Profile`],
+//! [`FlowId`], and [`OutputFormat`], as well as the standard subcommands to
+//! run against an app's flow.
+//!
+//! This is enabled though the `"cli"` feature on the `peace` crate.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use clap::Parser;
+//! use peace::cli_args::{CmdArgs, FlowCmd};
+//!
+//! #[derive(Parser)]
+//! pub struct AppArgs {
+//!     /// Command to run.
+//!     #[command(subcommand)]
+//!     pub command: FlowCmd,
+//!     /// Profile, flow, and output format to use.
+//!     #[command(flatten)]
+//!     pub cmd_args: CmdArgs,
+//! }
+//! ```
+//!
+//! [`Profile`]: peace_cfg::Profile
+//! [`FlowId`]: peace_cfg::FlowId
+//! [`OutputFormat`]: peace_cli_model::OutputFormat
+
+// Re-exports
+pub use clap;
+pub use clap_complete;
+pub use clap_mangen;
+
+pub use crate::{cli_docs_gen::CliDocsGen, cmd_args::CmdArgs, flow_cmd::FlowCmd};
+
+mod cli_docs_gen;
+mod cmd_args;
+mod flow_cmd;
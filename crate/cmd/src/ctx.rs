@@ -7,9 +7,15 @@ pub use self::{
         CmdCtxBuilderTypes, CmdCtxBuilderTypesCollector, CmdCtxTypesCollectorEmpty,
     },
     cmd_ctx_types::{CmdCtxTypes, CmdCtxTypesCollector, CmdCtxTypesConstrained},
+    interrupt_handle::InterruptHandle,
+};
+
+pub(crate) use self::cmd_ctx_builder::{
+    params_and_states_type_reg, params_digests, params_specs_validate,
 };
 
 mod cmd_ctx;
 mod cmd_ctx_builder;
 mod cmd_ctx_builder_types;
 mod cmd_ctx_types;
+mod interrupt_handle;
@@ -4,19 +4,29 @@ use std::{fmt::Debug, hash::Hash};
 
 use futures::stream::{StreamExt, TryStreamExt};
 use interruptible::Interruptibility;
-use peace_cfg::ItemId;
-use peace_params::ParamsSpecs;
+use peace_cfg::{state::SensitiveSerdeMode, FlowId, ItemId};
+use peace_params::{
+    params_key_name, ParamsSpecs, ParamsValidationError, ProfileParamsByKey,
+    ProfileParamsSerialized, WorkspaceParamsByKey, WorkspaceParamsSerialized,
+};
 use peace_resources::{
     internal::{FlowParamsFile, ProfileParamsFile, WorkspaceParamsFile},
-    paths::ParamsSpecsFile,
+    paths::{
+        FlowDir, ItemsDisabledFile, ParamsDigestsFile, ParamsHistoryFile, ParamsRevisionDir,
+        ParamsSpecsFile, ProfileDir, ProfileHistoryDir, StatesCurrentFile,
+    },
     resources::ts::{Empty, SetUp},
+    states::StatesCurrentStored,
     Resources,
 };
 use peace_rt_model::{
     fn_graph::resman::Resource,
-    params::{FlowParams, ProfileParams, WorkspaceParams},
-    Flow, ItemGraph, ParamsSpecsSerializer, ParamsSpecsTypeReg, StatesTypeReg, Storage, Workspace,
-    WorkspaceInitializer,
+    output::OutputWrite,
+    params::{FlowParams, ParamsOverlays, ParamsResolution, ProfileParams, WorkspaceParams},
+    Flow, IndexMap, ItemGraph, ItemsDisabled, ItemsDisabledSerializer, ItemsParamsChanged,
+    ParamsChangePolicy, ParamsDigests, ParamsDigestsSerializer, ParamsHistory,
+    ParamsHistorySerializer, ParamsRevision, ParamsSpecsSerializer, ParamsSpecsTypeReg,
+    StatesSerializer, StatesTypeReg, Storage, Workspace, WorkspaceInitializer,
 };
 use serde::{de::DeserializeOwned, Serialize};
 
@@ -51,6 +61,13 @@ where
     output: &'ctx mut CmdCtxBuilderTypesT::Output,
     /// The interrupt channel receiver if this `CmdExecution` is interruptible.
     interruptibility: Interruptibility<'static>,
+    /// Tracing subscriber to record spans to while `CmdExecution`s run.
+    tracing_dispatch: Option<tracing::Dispatch>,
+    /// Catalog that user-facing messages are rendered through.
+    message_catalog: peace_fmt::MessageCatalog,
+    /// Meter to record metrics to while `CmdExecution`s run.
+    #[cfg(feature = "telemetry")]
+    meter: Option<opentelemetry::metrics::Meter>,
     /// Workspace that the `peace` tool runs in.
     workspace: &'ctx Workspace,
     /// Data held while building `CmdCtx`.
@@ -78,6 +95,9 @@ where
 }
 
 /// Inserts workspace params into the `Resources` map.
+///
+/// Each param is inserted by its type, as well as by its key in
+/// `WorkspaceParamsByKey` and `WorkspaceParamsSerialized`.
 fn workspace_params_insert<WorkspaceParamsK>(
     mut workspace_params: WorkspaceParams<WorkspaceParamsK>,
     resources: &mut Resources<Empty>,
@@ -85,13 +105,30 @@ fn workspace_params_insert<WorkspaceParamsK>(
     WorkspaceParamsK:
         Clone + Debug + Eq + Hash + DeserializeOwned + Serialize + Send + Sync + 'static,
 {
+    let mut workspace_params_by_key = WorkspaceParamsByKey::with_capacity(workspace_params.len());
+    let mut workspace_params_serialized =
+        WorkspaceParamsSerialized::with_capacity(workspace_params.len());
     workspace_params
         .drain(..)
-        .for_each(|(_key, workspace_param)| {
+        .for_each(|(key, workspace_param)| {
+            let key_name = params_key_name(&key);
+            // Params that fail to serialize would have already failed to be stored.
+            //
+            // Serialized params are not stored, so sensitive values are redacted.
+            if let Ok(workspace_param_serialized) =
+                SensitiveSerdeMode::Redact.scope(|| serde_yaml::to_value(&workspace_param))
+            {
+                workspace_params_serialized.insert(key_name.clone(), workspace_param_serialized);
+            }
+            workspace_params_by_key
+                .insert(key_name, workspace_param.clone().into_inner().upcast());
+
             let workspace_param = workspace_param.into_inner().upcast();
             let type_id = Resource::type_id(&*workspace_param);
             resources.insert_raw(type_id, workspace_param);
         });
+    resources.insert(workspace_params_by_key);
+    resources.insert(workspace_params_serialized);
 }
 
 /// Serializes profile params to storage.
@@ -111,6 +148,9 @@ where
 }
 
 /// Inserts profile params into the `Resources` map.
+///
+/// Each param is inserted by its type, as well as by its key in
+/// `ProfileParamsByKey` and `ProfileParamsSerialized`.
 fn profile_params_insert<ProfileParamsK>(
     mut profile_params: ProfileParams<ProfileParamsK>,
     resources: &mut Resources<Empty>,
@@ -118,11 +158,43 @@ fn profile_params_insert<ProfileParamsK>(
     ProfileParamsK:
         Clone + Debug + Eq + Hash + DeserializeOwned + Serialize + Send + Sync + 'static,
 {
-    profile_params.drain(..).for_each(|(_key, profile_param)| {
+    let mut profile_params_by_key = ProfileParamsByKey::with_capacity(profile_params.len());
+    let mut profile_params_serialized =
+        ProfileParamsSerialized::with_capacity(profile_params.len());
+    profile_params.drain(..).for_each(|(key, profile_param)| {
+        let key_name = params_key_name(&key);
+        // Params that fail to serialize would have already failed to be stored.
+        //
+        // Serialized params are not stored, so sensitive values are redacted.
+        if let Ok(profile_param_serialized) =
+            SensitiveSerdeMode::Redact.scope(|| serde_yaml::to_value(&profile_param))
+        {
+            profile_params_serialized.insert(key_name.clone(), profile_param_serialized);
+        }
+        profile_params_by_key.insert(key_name, profile_param.clone().into_inner().upcast());
+
         let profile_param = profile_param.into_inner().upcast();
         let type_id = Resource::type_id(&*profile_param);
         resources.insert_raw(type_id, profile_param);
     });
+    resources.insert(profile_params_by_key);
+    resources.insert(profile_params_serialized);
+}
+
+/// Applies the overlays to the profile params, returning the resolved profile
+/// params and the layer each value came from.
+fn profile_params_resolve<ProfileParamsK>(
+    profile_params: ProfileParams<ProfileParamsK>,
+    profile_params_overlays: &ParamsOverlays<ProfileParamsK>,
+) -> (ProfileParams<ProfileParamsK>, ParamsResolution<ProfileParamsK>)
+where
+    ProfileParamsK:
+        Clone + Debug + Eq + Hash + DeserializeOwned + Serialize + Send + Sync + 'static,
+{
+    let (profile_params, profile_params_resolution) =
+        profile_params_overlays.resolve(&profile_params);
+
+    (ProfileParams::from(profile_params), profile_params_resolution)
 }
 
 /// Serializes flow params to storage.
@@ -162,6 +234,211 @@ fn flow_params_insert<FlowParamsK>(
     });
 }
 
+/// Records the params in the profile's params history, if they differ from the
+/// most recent revision recorded for the flow.
+///
+/// The params files are copied into the revision's `ParamsRevisionDir`, so that
+/// they can be loaded by `with_params_at`, and compared by `ParamsHistoryCmd`.
+///
+/// The params files are written before the history, so if recording is
+/// interrupted, the history is unchanged, and the partially written revision
+/// is overwritten when the next revision is recorded.
+async fn params_history_record<WorkspaceParamsK, ProfileParamsK, FlowParamsK>(
+    storage: &Storage,
+    profile_history_dir: &ProfileHistoryDir,
+    flow_id: Option<&FlowId>,
+    workspace_params: &WorkspaceParams<WorkspaceParamsK>,
+    profile_params: &ProfileParams<ProfileParamsK>,
+    flow_params: &FlowParams<FlowParamsK>,
+) -> Result<(), peace_rt_model::Error>
+where
+    WorkspaceParamsK:
+        Clone + Debug + Eq + Hash + DeserializeOwned + Serialize + Send + Sync + 'static,
+    ProfileParamsK:
+        Clone + Debug + Eq + Hash + DeserializeOwned + Serialize + Send + Sync + 'static,
+    FlowParamsK: Clone + Debug + Eq + Hash + DeserializeOwned + Serialize + Send + Sync + 'static,
+{
+    let params_digest = |params_serialized: Result<String, serde_yaml::Error>| {
+        params_serialized
+            .map(|params_serialized| ParamsDigests::digest(params_serialized.as_bytes()))
+            .map_err(peace_rt_model::Error::ParamsHistorySerialize)
+    };
+    // Sensitive values are redacted, so that digests do not depend on them.
+    let (workspace_params_serialized, profile_params_serialized, flow_params_serialized) =
+        SensitiveSerdeMode::Redact.scope(|| {
+            (
+                serde_yaml::to_string(workspace_params),
+                serde_yaml::to_string(profile_params),
+                serde_yaml::to_string(flow_params),
+            )
+        });
+    let workspace_params_digest = params_digest(workspace_params_serialized)?;
+    let profile_params_digest = params_digest(profile_params_serialized)?;
+    let flow_params_digest = params_digest(flow_params_serialized)?;
+
+    let params_history_file = ParamsHistoryFile::from(profile_history_dir);
+    let mut params_history =
+        ParamsHistorySerializer::<peace_rt_model::Error>::deserialize_opt(
+            storage,
+            &params_history_file,
+        )
+        .await?
+        .unwrap_or_else(ParamsHistory::new);
+
+    let params_unchanged = params_history
+        .revision_latest(flow_id)
+        .map(|params_revision| {
+            params_revision.params_digests_eq(
+                &workspace_params_digest,
+                &profile_params_digest,
+                &flow_params_digest,
+            )
+        })
+        .unwrap_or(false);
+    if params_unchanged {
+        return Ok(());
+    }
+
+    let revision = params_history.revision_next();
+    let params_revision_dir = ParamsRevisionDir::from((profile_history_dir, revision));
+    let dirs_to_create = [AsRef::<std::path::Path>::as_ref(&params_revision_dir)];
+    #[cfg(target_arch = "wasm32")]
+    WorkspaceInitializer::dirs_create(storage, dirs_to_create).await?;
+    #[cfg(not(target_arch = "wasm32"))]
+    WorkspaceInitializer::dirs_create(dirs_to_create).await?;
+
+    WorkspaceInitializer::workspace_params_serialize(
+        storage,
+        workspace_params,
+        &WorkspaceParamsFile::from(&params_revision_dir),
+    )
+    .await?;
+    WorkspaceInitializer::profile_params_serialize(
+        storage,
+        profile_params,
+        &ProfileParamsFile::from(&params_revision_dir),
+    )
+    .await?;
+    WorkspaceInitializer::flow_params_serialize(
+        storage,
+        flow_params,
+        &FlowParamsFile::from(&params_revision_dir),
+    )
+    .await?;
+
+    let params_revision = ParamsRevision {
+        revision,
+        recorded_at: chrono::Utc::now(),
+        recorded_by: params_recorded_by(),
+        flow_id: flow_id.cloned(),
+        workspace_params_digest,
+        profile_params_digest,
+        flow_params_digest,
+    };
+    let params_revisions_pruned = params_history.push(params_revision);
+
+    ParamsHistorySerializer::<peace_rt_model::Error>::serialize(
+        storage,
+        &params_history,
+        &params_history_file,
+    )
+    .await?;
+
+    // Pruned revisions are only removed after the history no longer references
+    // them.
+    #[cfg(not(target_arch = "wasm32"))]
+    for params_revision_pruned in params_revisions_pruned {
+        let params_revision_dir =
+            ParamsRevisionDir::from((profile_history_dir, params_revision_pruned.revision));
+        tokio::fs::remove_dir_all(&params_revision_dir)
+            .await
+            .map_err(|error| {
+                peace_rt_model::Error::Native(peace_rt_model::NativeError::DirRemove {
+                    path: params_revision_dir.into_inner(),
+                    error,
+                })
+            })?;
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _params_revisions_pruned = params_revisions_pruned;
+
+    Ok(())
+}
+
+/// Returns the user that is recording a params revision, if known.
+fn params_recorded_by() -> Option<String> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        std::env::var("USER")
+            .or_else(|_| std::env::var("USERNAME"))
+            .ok()
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        None
+    }
+}
+
+/// Returns the `ParamsRevisionDir` of the given revision, after checking that
+/// it was recorded for the flow.
+async fn params_revision_dir_read(
+    storage: &Storage,
+    profile_history_dir: &ProfileHistoryDir,
+    flow_id: &FlowId,
+    revision: u64,
+) -> Result<ParamsRevisionDir, peace_rt_model::Error> {
+    let params_history_file = ParamsHistoryFile::from(profile_history_dir);
+    let params_history = ParamsHistorySerializer::<peace_rt_model::Error>::deserialize_opt(
+        storage,
+        &params_history_file,
+    )
+    .await?
+    .unwrap_or_else(ParamsHistory::new);
+
+    let params_revision = params_history
+        .revision(revision)
+        .ok_or(peace_rt_model::Error::ParamsRevisionNotFound { revision })?;
+    if params_revision.flow_id.as_ref() != Some(flow_id) {
+        return Err(peace_rt_model::Error::ParamsRevisionFlowMismatch {
+            revision,
+            flow_id: flow_id.clone(),
+            flow_id_recorded: params_revision.flow_id.clone(),
+        });
+    }
+
+    Ok(ParamsRevisionDir::from((profile_history_dir, revision)))
+}
+
+/// Returns the items disabled for the profile.
+///
+/// When `items_disabled` is provided, it is stored in the profile directory,
+/// otherwise the stored items disabled are returned.
+async fn items_disabled_load_save(
+    storage: &Storage,
+    profile_dir: &ProfileDir,
+    items_disabled: Option<ItemsDisabled>,
+) -> Result<ItemsDisabled, peace_rt_model::Error> {
+    let items_disabled_file = ItemsDisabledFile::from(profile_dir);
+    match items_disabled {
+        Some(items_disabled) => {
+            ItemsDisabledSerializer::<peace_rt_model::Error>::serialize(
+                storage,
+                &items_disabled,
+                &items_disabled_file,
+            )
+            .await?;
+
+            Ok(items_disabled)
+        }
+        None => ItemsDisabledSerializer::<peace_rt_model::Error>::deserialize_opt(
+            storage,
+            &items_disabled_file,
+        )
+        .await
+        .map(Option::unwrap_or_default),
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub(crate) async fn profiles_from_peace_app_dir(
     peace_app_dir: &peace_resources::paths::PeaceAppDir,
@@ -249,7 +526,7 @@ pub(crate) async fn profiles_from_peace_app_dir(
 
 /// Registers each item's `Params` and `State` for stateful
 /// deserialization.
-fn params_and_states_type_reg<E>(item_graph: &ItemGraph<E>) -> (ParamsSpecsTypeReg, StatesTypeReg)
+pub(crate) fn params_and_states_type_reg<E>(item_graph: &ItemGraph<E>) -> (ParamsSpecsTypeReg, StatesTypeReg)
 where
     E: 'static,
 {
@@ -368,6 +645,9 @@ where
         && params_specs_not_usable.is_empty();
 
     if params_no_issues {
+        // Mapping functions that read an item's state must run after that item.
+        item_graph.params_specs_mapping_fns_validate(&params_specs)?;
+
         Ok(params_specs)
     } else {
         Err(peace_rt_model::Error::ParamsSpecsMismatch {
@@ -379,6 +659,217 @@ where
     }
 }
 
+/// Reads the stored current states of other flows in the same profile, and
+/// inserts each state into the `Resources` map.
+///
+/// This allows items in the current flow to map their params from the states
+/// of items in other flows. States are inserted by their type, so if multiple
+/// items across the given flows share the same `State` type, the state from
+/// the last item is the one that is inserted.
+///
+/// Flows that do not have any stored current states are skipped.
+async fn states_from_flows_insert<E>(
+    profile_dir: &ProfileDir,
+    storage: &Storage,
+    states_from_flows: &[Flow<E>],
+    resources: &mut Resources<Empty>,
+) -> Result<(), peace_rt_model::Error>
+where
+    E: 'static,
+{
+    for flow in states_from_flows {
+        let flow_id = flow.flow_id();
+        let (_params_specs_type_reg, states_type_reg) = params_and_states_type_reg(flow.graph());
+
+        let flow_dir = FlowDir::from((profile_dir, flow_id));
+        let states_current_file = StatesCurrentFile::from(&flow_dir);
+        let states_current_stored =
+            StatesSerializer::<peace_rt_model::Error>::deserialize_stored_opt(
+                flow_id,
+                storage,
+                &states_type_reg,
+                &states_current_file,
+            )
+            .await?;
+
+        if let Some(states_current_stored) = states_current_stored {
+            states_current_stored
+                .into_inner()
+                .into_inner()
+                .into_iter()
+                .for_each(|(_item_id, state)| {
+                    let state = state.into_inner().upcast();
+                    let type_id = Resource::type_id(&*state);
+                    resources.insert_raw(type_id, state);
+                });
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates each item's resolved params, returning all validation failures
+/// together.
+pub(crate) fn params_specs_validate<E>(
+    item_graph: &ItemGraph<E>,
+    params_specs: &ParamsSpecs,
+    resources: &Resources<SetUp>,
+) -> Result<(), E>
+where
+    E: std::error::Error + From<peace_rt_model::Error> + 'static,
+{
+    let item_params_validation_errors = item_graph.iter_insertion().try_fold(
+        IndexMap::<ItemId, Vec<ParamsValidationError>>::new(),
+        |mut item_params_validation_errors, item| {
+            let params_validation_errors = item.params_validate(params_specs, resources)?;
+            if !params_validation_errors.is_empty() {
+                item_params_validation_errors.insert(item.id().clone(), params_validation_errors);
+            }
+
+            Ok::<_, E>(item_params_validation_errors)
+        },
+    )?;
+
+    if item_params_validation_errors.is_empty() {
+        Ok(())
+    } else {
+        Err(E::from(peace_rt_model::Error::ParamsValidation {
+            item_params_validation_errors,
+        }))
+    }
+}
+
+/// Returns the digest of each item's resolved params.
+///
+/// Items whose params cannot be fully resolved yet are not included.
+pub(crate) fn params_digests<E>(
+    item_graph: &ItemGraph<E>,
+    params_specs: &ParamsSpecs,
+    resources: &Resources<SetUp>,
+) -> Result<ParamsDigests, E>
+where
+    E: std::error::Error + From<peace_rt_model::Error> + 'static,
+{
+    item_graph.iter_insertion().try_fold(
+        ParamsDigests::with_capacity(item_graph.node_count()),
+        |mut params_digests, item| {
+            if let Some(params_digest) = item.params_digest(params_specs, resources)? {
+                params_digests.insert(item.id().clone(), params_digest);
+            }
+
+            Ok(params_digests)
+        },
+    )
+}
+
+/// Checks whether item params changed since the items' current states were
+/// stored, and warns or returns an error according to `params_change_policy`.
+///
+/// Only items that have a stored current state are reported.
+async fn params_change_check<E, O>(
+    output: &mut O,
+    storage: &Storage,
+    flow_dir: &FlowDir,
+    params_change_policy: ParamsChangePolicy,
+    params_digests: &ParamsDigests,
+    resources: &Resources<SetUp>,
+) -> Result<(), E>
+where
+    E: std::error::Error + From<peace_rt_model::Error> + 'static,
+    O: OutputWrite<E>,
+{
+    if params_change_policy == ParamsChangePolicy::Ignore {
+        return Ok(());
+    }
+    let Ok(states_current_stored) = resources.try_borrow::<StatesCurrentStored>() else {
+        return Ok(());
+    };
+
+    let params_digests_file = ParamsDigestsFile::from(flow_dir);
+    let Some(params_digests_stored) =
+        ParamsDigestsSerializer::<peace_rt_model::Error>::deserialize_opt(
+            storage,
+            &params_digests_file,
+        )
+        .await?
+    else {
+        return Ok(());
+    };
+
+    let item_ids = params_digests
+        .items_changed(&params_digests_stored)
+        .into_iter()
+        .filter(|item_id| states_current_stored.contains_key(item_id))
+        .collect::<Vec<ItemId>>();
+    if item_ids.is_empty() {
+        return Ok(());
+    }
+
+    match params_change_policy {
+        ParamsChangePolicy::Ignore => Ok(()),
+        ParamsChangePolicy::Warn => output.present(ItemsParamsChanged::new(item_ids)).await,
+        ParamsChangePolicy::Fail => Err(E::from(peace_rt_model::Error::ParamsChanged {
+            item_ids,
+        })),
+    }
+}
+
+/// Returns an `Interruptibility` that is also interrupted when the execution
+/// deadline is reached.
+///
+/// Interrupt signals received through the given `interruptibility` are
+/// forwarded, so the command may still be interrupted before the deadline. If
+/// the command is not interruptible, in-progress items are allowed to finish
+/// when the deadline is reached.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn interruptibility_with_deadline(
+    interruptibility: Interruptibility<'static>,
+    execution_deadline: peace_rt_model::ExecutionDeadline,
+) -> Interruptibility<'static> {
+    let (interrupt_rx_app, interrupt_strategy) = match interruptibility {
+        Interruptibility::NonInterruptible => {
+            (None, interruptible::InterruptStrategy::FinishCurrent)
+        }
+        Interruptibility::Interruptible {
+            interrupt_rx,
+            interrupt_strategy,
+        } => (Some(interrupt_rx), interrupt_strategy),
+    };
+
+    let (interrupt_tx, interrupt_rx) =
+        tokio::sync::mpsc::channel::<interruptible::InterruptSignal>(1);
+    // A deadline that has already passed interrupts the command before the
+    // first `CmdBlock` is executed.
+    if execution_deadline.is_exceeded() {
+        let _interrupt_send_result = interrupt_tx.try_send(interruptible::InterruptSignal);
+        return Interruptibility::new(interrupt_rx.into(), interrupt_strategy);
+    }
+
+    let deadline = tokio::time::Instant::from_std(execution_deadline.deadline());
+    tokio::spawn(async move {
+        let deadline_reached = tokio::time::sleep_until(deadline);
+        match interrupt_rx_app {
+            Some(mut interrupt_rx_app) => {
+                let interrupt_received = async move {
+                    // When the app's interrupt sender is dropped, only the deadline
+                    // interrupts the command.
+                    if interrupt_rx_app.recv().await.is_none() {
+                        futures::future::pending::<()>().await;
+                    }
+                };
+                futures::pin_mut!(deadline_reached, interrupt_received);
+                futures::future::select(deadline_reached, interrupt_received).await;
+            }
+            None => deadline_reached.await,
+        }
+
+        // The receiver is dropped if the command context was dropped.
+        let _interrupt_send_result = interrupt_tx.send(interruptible::InterruptSignal).await;
+    });
+
+    Interruptibility::new(interrupt_rx.into(), interrupt_strategy)
+}
+
 async fn item_graph_setup<E>(
     item_graph: &ItemGraph<E>,
     resources: Resources<Empty>,
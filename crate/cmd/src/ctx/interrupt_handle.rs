@@ -0,0 +1,69 @@
+use interruptible::{InterruptSignal, InterruptStrategy, Interruptibility};
+use tokio::sync::mpsc::{self, error::TrySendError};
+
+/// Handle to interrupt a command context's `CmdExecution`s from another task.
+///
+/// This is useful for applications that embed a command, such as GUIs and
+/// servers, where interruption is requested programmatically instead of
+/// through a process signal.
+///
+/// Interrupting an execution behaves the same as any other interruption: no
+/// new items are started, and states of items that have already been applied
+/// are stored. The [`InterruptStrategy`] determines whether in-progress items
+/// are allowed to finish.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(output, workspace)
+///     .with_interrupt_handle(InterruptStrategy::FinishCurrent)
+///     // ..
+///     .await?;
+///
+/// let interrupt_handle = cmd_ctx
+///     .interrupt_handle()
+///     .cloned()
+///     .expect("Expected `interrupt_handle` to be set.");
+/// tokio::spawn(async move {
+///     cancel_button_clicked().await;
+///     interrupt_handle.interrupt();
+/// });
+///
+/// let cmd_outcome = EnsureCmd::exec(&mut cmd_ctx).await?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct InterruptHandle {
+    /// Sender for the command context's interrupt channel.
+    interrupt_tx: mpsc::Sender<InterruptSignal>,
+}
+
+impl InterruptHandle {
+    /// Returns a new `InterruptHandle` and the `Interruptibility` that
+    /// receives its interrupt signals.
+    pub fn new(interrupt_strategy: InterruptStrategy) -> (Self, Interruptibility<'static>) {
+        let (interrupt_tx, interrupt_rx) = mpsc::channel::<InterruptSignal>(1);
+        let interruptibility = Interruptibility::new(interrupt_rx.into(), interrupt_strategy);
+
+        (Self { interrupt_tx }, interruptibility)
+    }
+
+    /// Requests interruption of the in-flight `CmdExecution`.
+    ///
+    /// Returns `true` if interruption was requested, or was already pending,
+    /// and `false` if the command context has been dropped.
+    ///
+    /// This does not wait for the execution to stop. The execution returns an
+    /// interrupted `CmdOutcome` once it has stopped.
+    pub fn interrupt(&self) -> bool {
+        match self.interrupt_tx.try_send(InterruptSignal) {
+            Ok(()) | Err(TrySendError::Full(InterruptSignal)) => true,
+            Err(TrySendError::Closed(InterruptSignal)) => false,
+        }
+    }
+
+    /// Returns whether the command context that receives interrupts has been
+    /// dropped.
+    pub fn is_closed(&self) -> bool {
+        self.interrupt_tx.is_closed()
+    }
+}
@@ -5,8 +5,8 @@ use peace_core::Profile;
 use peace_resources::paths::{PeaceAppDir, PeaceDir, ProfileDir, ProfileHistoryDir, WorkspaceDir};
 use peace_rt_model::{
     params::{
-        KeyKnown, KeyMaybe, ParamsKeys, ParamsKeysImpl, ParamsTypeRegs, ProfileParams,
-        WorkspaceParams,
+        KeyKnown, KeyMaybe, ParamsKeys, ParamsKeysImpl, ParamsResolution, ParamsTypeRegs,
+        ProfileParams, WorkspaceParams,
     },
     Workspace,
 };
@@ -81,6 +81,10 @@ where
     profile_params: ProfileParams<
         <<CmdCtxTypesT::ParamsKeys as ParamsKeys>::ProfileParamsKMaybe as KeyMaybe>::Key,
     >,
+    /// Layer that each profile param's value came from.
+    profile_params_resolution: ParamsResolution<
+        <<CmdCtxTypesT::ParamsKeys as ParamsKeys>::ProfileParamsKMaybe as KeyMaybe>::Key,
+    >,
 }
 
 /// A command that works with a single profile, without any items.
@@ -172,6 +176,9 @@ where
         profile_params: ProfileParams<
             <<CmdCtxTypesT::ParamsKeys as ParamsKeys>::ProfileParamsKMaybe as KeyMaybe>::Key,
         >,
+        profile_params_resolution: ParamsResolution<
+            <<CmdCtxTypesT::ParamsKeys as ParamsKeys>::ProfileParamsKMaybe as KeyMaybe>::Key,
+        >,
     ) -> Self {
         Self {
             output,
@@ -183,6 +190,7 @@ where
             params_type_regs,
             workspace_params,
             profile_params,
+            profile_params_resolution,
         }
     }
 
@@ -198,6 +206,7 @@ where
             params_type_regs,
             workspace_params,
             profile_params,
+            profile_params_resolution: _,
         } = self;
 
         let interruptibility_state = interruptibility_state.reborrow();
@@ -316,4 +325,14 @@ where
     pub fn profile_params(&self) -> &ProfileParams<ProfileParamsK> {
         &self.profile_params
     }
+
+    /// Returns the layer that each profile param's value came from.
+    ///
+    /// This is useful to tell why a profile param has a particular value,
+    /// when values are provided in multiple [`ParamsLayer`]s.
+    ///
+    /// [`ParamsLayer`]: peace_rt_model::params::ParamsLayer
+    pub fn profile_params_resolution(&self) -> &ParamsResolution<ProfileParamsK> {
+        &self.profile_params_resolution
+    }
 }
@@ -1,23 +1,29 @@
-use std::{fmt::Debug, hash::Hash};
+use std::{fmt::Debug, hash::Hash, sync::Arc};
 
 use interruptible::InterruptibilityState;
 use peace_core::Profile;
 use peace_params::ParamsSpecs;
 use peace_resources::{
-    paths::{FlowDir, PeaceAppDir, PeaceDir, ProfileDir, ProfileHistoryDir, WorkspaceDir},
+    paths::{
+        FlowDir, ParamsSpecsFile, PeaceAppDir, PeaceDir, ProfileDir, ProfileHistoryDir,
+        StatesCurrentFile, WorkspaceDir,
+    },
     resources::ts::SetUp,
+    states::StatesCurrentStored,
     Resources,
 };
 use peace_rt_model::{
     params::{
-        FlowParams, KeyKnown, KeyMaybe, ParamsKeys, ParamsKeysImpl, ParamsTypeRegs, ProfileParams,
-        WorkspaceParams,
+        FlowParams, KeyKnown, KeyMaybe, ParamsKeys, ParamsKeysImpl, ParamsResolution,
+        ParamsTypeRegs, ProfileParams, WorkspaceParams,
     },
-    Flow, ParamsSpecsTypeReg, StatesTypeReg, Workspace,
+    ApplyHooks, ApprovalPolicy, ExecutionDeadline, Flow, HeartbeatPolicy, ItemExecutor,
+    ParamsSpecsSerializer, ParamsSpecsTypeReg, StatesSerializer, StatesTypeReg, Workspace,
 };
 use serde::{de::DeserializeOwned, Serialize};
+use tracing::Dispatch;
 
-use crate::ctx::CmdCtxTypes;
+use crate::ctx::{CmdCtxTypes, InterruptHandle};
 
 /// A command that works with one profile and one flow.
 ///
@@ -72,6 +78,11 @@ where
     /// Tracks progress of each function execution.
     #[cfg(feature = "output_progress")]
     cmd_progress_tracker: peace_rt_model::CmdProgressTracker,
+    /// Tracing subscriber to record spans to while `CmdExecution`s run.
+    tracing_dispatch: Option<Dispatch>,
+    /// Records OpenTelemetry metrics while `CmdExecution`s run.
+    #[cfg(feature = "telemetry")]
+    cmd_metrics: peace_rt_model::CmdMetrics,
     /// The profile this command operates on.
     profile: Profile,
     /// Profile directory that stores params and flows.
@@ -97,6 +108,10 @@ where
     profile_params: ProfileParams<
         <<CmdCtxTypesT::ParamsKeys as ParamsKeys>::ProfileParamsKMaybe as KeyMaybe>::Key,
     >,
+    /// Layer that each profile param's value came from.
+    profile_params_resolution: ParamsResolution<
+        <<CmdCtxTypesT::ParamsKeys as ParamsKeys>::ProfileParamsKMaybe as KeyMaybe>::Key,
+    >,
     /// Flow params for the selected flow.
     flow_params:
         FlowParams<<<CmdCtxTypesT::ParamsKeys as ParamsKeys>::FlowParamsKMaybe as KeyMaybe>::Key>,
@@ -119,6 +134,18 @@ where
     states_type_reg: StatesTypeReg,
     /// `Resources` for flow execution.
     resources: Resources<SetUp>,
+    /// Hooks invoked around each item's apply.
+    apply_hooks: ApplyHooks<CmdCtxTypesT::AppError>,
+    /// Which items require approval before they are applied.
+    approval_policy: ApprovalPolicy,
+    /// How long items may go without sending progress while they are applied.
+    heartbeat_policy: HeartbeatPolicy,
+    /// How items are run concurrently within command blocks.
+    item_executor: Arc<dyn ItemExecutor>,
+    /// Instant by which `CmdExecution`s should complete.
+    execution_deadline: Option<ExecutionDeadline>,
+    /// Handle to interrupt `CmdExecution`s from another task.
+    interrupt_handle: Option<InterruptHandle>,
 }
 
 /// A command that works with one profile and one flow.
@@ -212,6 +239,19 @@ where
     pub states_type_reg: &'view StatesTypeReg,
     /// `Resources` for flow execution.
     pub resources: &'view mut Resources<SetUp>,
+    /// Hooks invoked around each item's apply.
+    pub apply_hooks: &'view ApplyHooks<CmdCtxTypesT::AppError>,
+    /// Which items require approval before they are applied.
+    pub approval_policy: &'view ApprovalPolicy,
+    /// How long items may go without sending progress while they are applied.
+    pub heartbeat_policy: HeartbeatPolicy,
+    /// How items are run concurrently within command blocks.
+    pub item_executor: &'view Arc<dyn ItemExecutor>,
+    /// Instant by which `CmdExecution`s should complete.
+    pub execution_deadline: Option<ExecutionDeadline>,
+    /// Records OpenTelemetry metrics while `CmdExecution`s run.
+    #[cfg(feature = "telemetry")]
+    pub cmd_metrics: &'view peace_rt_model::CmdMetrics,
 }
 
 /// Split the output related parameters and the flow information.
@@ -235,6 +275,8 @@ where
     /// Tracks progress of each function execution.
     #[cfg(feature = "output_progress")]
     pub cmd_progress_tracker: &'view mut peace_rt_model::CmdProgressTracker,
+    /// Tracing subscriber to record spans to while `CmdExecution`s run.
+    pub tracing_dispatch: Option<&'view Dispatch>,
     /// Flow and parameter related information.
     pub cmd_view: SingleProfileSingleFlowView<'view, CmdCtxTypesT>,
 }
@@ -251,6 +293,8 @@ where
         workspace: &'ctx Workspace,
         #[cfg(feature = "output_progress")]
         cmd_progress_tracker: peace_rt_model::CmdProgressTracker,
+        tracing_dispatch: Option<Dispatch>,
+        #[cfg(feature = "telemetry")] cmd_metrics: peace_rt_model::CmdMetrics,
         profile: Profile,
         profile_dir: ProfileDir,
         profile_history_dir: ProfileHistoryDir,
@@ -263,6 +307,9 @@ where
         profile_params: ProfileParams<
             <<CmdCtxTypesT::ParamsKeys as ParamsKeys>::ProfileParamsKMaybe as KeyMaybe>::Key,
         >,
+        profile_params_resolution: ParamsResolution<
+            <<CmdCtxTypesT::ParamsKeys as ParamsKeys>::ProfileParamsKMaybe as KeyMaybe>::Key,
+        >,
         flow_params: FlowParams<
             <<CmdCtxTypesT::ParamsKeys as ParamsKeys>::FlowParamsKMaybe as KeyMaybe>::Key,
         >,
@@ -270,6 +317,12 @@ where
         params_specs: ParamsSpecs,
         states_type_reg: StatesTypeReg,
         resources: Resources<SetUp>,
+        apply_hooks: ApplyHooks<CmdCtxTypesT::AppError>,
+        approval_policy: ApprovalPolicy,
+        heartbeat_policy: HeartbeatPolicy,
+        item_executor: Arc<dyn ItemExecutor>,
+        execution_deadline: Option<ExecutionDeadline>,
+        interrupt_handle: Option<InterruptHandle>,
     ) -> Self {
         Self {
             output,
@@ -277,6 +330,9 @@ where
             workspace,
             #[cfg(feature = "output_progress")]
             cmd_progress_tracker,
+            tracing_dispatch,
+            #[cfg(feature = "telemetry")]
+            cmd_metrics,
             profile,
             profile_dir,
             profile_history_dir,
@@ -285,12 +341,120 @@ where
             params_type_regs,
             workspace_params,
             profile_params,
+            profile_params_resolution,
             flow_params,
             params_specs_type_reg,
             params_specs,
             states_type_reg,
             resources,
+            apply_hooks,
+            approval_policy,
+            heartbeat_policy,
+            item_executor,
+            execution_deadline,
+            interrupt_handle,
+        }
+    }
+}
+
+impl<'ctx, CmdCtxTypesT> SingleProfileSingleFlow<'ctx, CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypes,
+    CmdCtxTypesT::AppError: std::error::Error + From<peace_rt_model::Error> + 'static,
+{
+    /// Re-reads the stored item params specs and current states, so that
+    /// this scope can be reused across multiple command executions.
+    ///
+    /// This is intended for long-running processes such as servers, which
+    /// build the `CmdCtx` once, and refresh it before each command instead of
+    /// rebuilding it. Changes written to storage by other processes are
+    /// picked up, and the item params are re-validated.
+    ///
+    /// The params specs and states type registries are rebuilt from the
+    /// flow's items, so registries cached from a previous execution are never
+    /// used to deserialize newer files.
+    ///
+    /// Workspace, profile, and flow params, as well as stored states from
+    /// other flows, are not re-read, and keep the values from when the
+    /// `CmdCtx` was built.
+    ///
+    /// # Errors
+    ///
+    /// If the stored files cannot be deserialized, or the item params are
+    /// no longer valid, an error is returned. The previous item params specs
+    /// are retained if this happens.
+    pub async fn refresh(&mut self) -> Result<(), CmdCtxTypesT::AppError> {
+        let flow_id = self.flow.flow_id();
+        let item_graph = self.flow.graph();
+        let storage = self.workspace.storage();
+
+        let (params_specs_type_reg, states_type_reg) =
+            crate::ctx::params_and_states_type_reg(item_graph);
+
+        let params_specs_file = ParamsSpecsFile::from(&self.flow_dir);
+        let params_specs_stored = ParamsSpecsSerializer::<peace_rt_model::Error>::deserialize_opt(
+            &self.profile,
+            flow_id,
+            storage,
+            &params_specs_type_reg,
+            &params_specs_file,
+        )
+        .await?;
+
+        let states_current_file = StatesCurrentFile::from(&self.flow_dir);
+        let states_current_stored =
+            StatesSerializer::<peace_rt_model::Error>::deserialize_stored_opt(
+                flow_id,
+                storage,
+                &states_type_reg,
+                &states_current_file,
+            )
+            .await?;
+        match states_current_stored {
+            Some(states_current_stored) => self.resources.insert(states_current_stored),
+            None => {
+                // There may not be any previously stored states to remove.
+                let _states_current_stored_removed =
+                    self.resources.try_remove::<StatesCurrentStored>();
+            }
         }
+
+        // Stored params specs take precedence, except where they are not usable,
+        // such as mapping functions, which cannot be deserialized. For those, the
+        // params spec that this `CmdCtx` was built with is kept.
+        let params_specs = match params_specs_stored {
+            Some(mut params_specs_stored) => {
+                let mut params_specs_current = self.params_specs.clone();
+                let mut params_specs = ParamsSpecs::with_capacity(item_graph.node_count());
+                item_graph.iter_insertion().for_each(|item_rt| {
+                    let item_id = item_rt.id();
+                    let params_spec_current = params_specs_current.shift_remove_entry(item_id);
+                    let params_spec_stored = params_specs_stored.shift_remove_entry(item_id);
+
+                    let params_spec_to_use = match params_spec_stored {
+                        Some(params_spec_stored) if params_spec_stored.1.is_usable() => {
+                            Some(params_spec_stored)
+                        }
+                        Some(_) | None => params_spec_current,
+                    };
+                    if let Some((item_id, params_spec_boxed)) = params_spec_to_use {
+                        params_specs.insert_raw(item_id, params_spec_boxed);
+                    }
+                });
+                params_specs
+            }
+            None => self.params_specs.clone(),
+        };
+        crate::ctx::params_specs_validate(item_graph, &params_specs, &self.resources)?;
+        let params_digests =
+            crate::ctx::params_digests(item_graph, &params_specs, &self.resources)?;
+        self.resources.insert(params_digests);
+
+        self.params_specs_type_reg = params_specs_type_reg;
+        self.params_specs = params_specs;
+        self.states_type_reg = states_type_reg;
+
+        Ok(())
     }
 }
 
@@ -308,6 +472,9 @@ where
             workspace,
             #[cfg(feature = "output_progress")]
                 cmd_progress_tracker: _,
+            tracing_dispatch: _,
+            #[cfg(feature = "telemetry")]
+            cmd_metrics,
             profile,
             profile_dir,
             profile_history_dir,
@@ -316,11 +483,18 @@ where
             params_type_regs,
             workspace_params,
             profile_params,
+            profile_params_resolution: _,
             flow_params,
             params_specs_type_reg,
             params_specs,
             states_type_reg,
             resources,
+            apply_hooks,
+            approval_policy,
+            heartbeat_policy,
+            item_executor,
+            execution_deadline,
+            interrupt_handle: _,
         } = self;
 
         let interruptibility_state = interruptibility_state.reborrow();
@@ -341,6 +515,13 @@ where
             params_specs,
             states_type_reg,
             resources,
+            apply_hooks,
+            approval_policy,
+            heartbeat_policy: *heartbeat_policy,
+            item_executor,
+            execution_deadline: *execution_deadline,
+            #[cfg(feature = "telemetry")]
+            cmd_metrics,
         }
     }
 
@@ -354,6 +535,9 @@ where
             workspace,
             #[cfg(feature = "output_progress")]
             cmd_progress_tracker,
+            tracing_dispatch,
+            #[cfg(feature = "telemetry")]
+            cmd_metrics,
             profile,
             profile_dir,
             profile_history_dir,
@@ -362,11 +546,18 @@ where
             params_type_regs,
             workspace_params,
             profile_params,
+            profile_params_resolution: _,
             flow_params,
             params_specs_type_reg,
             params_specs,
             states_type_reg,
             resources,
+            apply_hooks,
+            approval_policy,
+            heartbeat_policy,
+            item_executor,
+            execution_deadline,
+            interrupt_handle: _,
         } = self;
 
         let interruptibility_state = interruptibility_state.reborrow();
@@ -375,6 +566,7 @@ where
             output,
             #[cfg(feature = "output_progress")]
             cmd_progress_tracker,
+            tracing_dispatch: tracing_dispatch.as_ref(),
             cmd_view: SingleProfileSingleFlowView {
                 interruptibility_state,
                 workspace,
@@ -391,6 +583,13 @@ where
                 params_specs,
                 states_type_reg,
                 resources,
+                apply_hooks,
+                approval_policy,
+                heartbeat_policy: *heartbeat_policy,
+                item_executor,
+                execution_deadline: *execution_deadline,
+                #[cfg(feature = "telemetry")]
+                cmd_metrics,
             },
         }
     }
@@ -443,6 +642,19 @@ where
         &mut self.cmd_progress_tracker
     }
 
+    /// Returns the tracing subscriber to record spans to while `CmdExecution`s
+    /// run, if any.
+    pub fn tracing_dispatch(&self) -> Option<&Dispatch> {
+        self.tracing_dispatch.as_ref()
+    }
+
+    /// Returns the `CmdMetrics` that record OpenTelemetry metrics while
+    /// `CmdExecution`s run.
+    #[cfg(feature = "telemetry")]
+    pub fn cmd_metrics(&self) -> &peace_rt_model::CmdMetrics {
+        &self.cmd_metrics
+    }
+
     /// Returns a reference to the profile.
     pub fn profile(&self) -> &Profile {
         &self.profile
@@ -514,6 +726,40 @@ where
     pub fn resources_mut(&mut self) -> &mut Resources<SetUp> {
         &mut self.resources
     }
+
+    /// Returns the hooks invoked around each item's apply.
+    pub fn apply_hooks(&self) -> &ApplyHooks<CmdCtxTypesT::AppError> {
+        &self.apply_hooks
+    }
+
+    /// Returns which items require approval before they are applied.
+    pub fn approval_policy(&self) -> &ApprovalPolicy {
+        &self.approval_policy
+    }
+
+    /// Returns how long items may go without sending progress while they are
+    /// applied.
+    pub fn heartbeat_policy(&self) -> HeartbeatPolicy {
+        self.heartbeat_policy
+    }
+
+    /// Returns how items are run concurrently within command blocks.
+    pub fn item_executor(&self) -> &Arc<dyn ItemExecutor> {
+        &self.item_executor
+    }
+
+    /// Returns the instant by which `CmdExecution`s should complete, if set.
+    pub fn execution_deadline(&self) -> Option<ExecutionDeadline> {
+        self.execution_deadline
+    }
+
+    /// Returns the handle to interrupt `CmdExecution`s from another task, if
+    /// set.
+    ///
+    /// See `CmdCtxBuilder::with_interrupt_handle`.
+    pub fn interrupt_handle(&self) -> Option<&InterruptHandle> {
+        self.interrupt_handle.as_ref()
+    }
 }
 
 impl<'ctx, CmdCtxTypesT, WorkspaceParamsK, ProfileParamsKMaybe, FlowParamsKMaybe>
@@ -556,6 +802,16 @@ where
     pub fn profile_params(&self) -> &ProfileParams<ProfileParamsK> {
         &self.profile_params
     }
+
+    /// Returns the layer that each profile param's value came from.
+    ///
+    /// This is useful to tell why a profile param has a particular value,
+    /// when values are provided in multiple [`ParamsLayer`]s.
+    ///
+    /// [`ParamsLayer`]: peace_rt_model::params::ParamsLayer
+    pub fn profile_params_resolution(&self) -> &ParamsResolution<ProfileParamsK> {
+        &self.profile_params_resolution
+    }
 }
 
 impl<'ctx, CmdCtxTypesT, WorkspaceParamsKMaybe, ProfileParamsKMaybe, FlowParamsK>
@@ -1,7 +1,7 @@
 use std::{collections::BTreeMap, fmt::Debug, hash::Hash};
 
 use peace_core::Profile;
-use peace_rt_model::params::ProfileParams;
+use peace_rt_model::params::{ParamsOverlays, ProfileParams};
 use serde::{de::DeserializeOwned, Serialize};
 
 /// The application does not use any profile parameters.
@@ -9,8 +9,14 @@ use serde::{de::DeserializeOwned, Serialize};
 pub struct ProfileParamsNone;
 
 /// The application has profile parameters.
+///
+/// This also holds profile parameter values provided in other layers, such as
+/// workspace defaults and command line overrides.
 #[derive(Debug)]
-pub struct ProfileParamsSome<ProfileParamsK>(pub(crate) ProfileParams<ProfileParamsK>)
+pub struct ProfileParamsSome<ProfileParamsK>(
+    pub(crate) ProfileParams<ProfileParamsK>,
+    pub(crate) ParamsOverlays<ProfileParamsK>,
+)
 where
     ProfileParamsK:
         Clone + Debug + Eq + Hash + DeserializeOwned + Serialize + Send + Sync + 'static;
@@ -21,7 +27,7 @@ where
         Clone + Debug + Eq + Hash + DeserializeOwned + Serialize + Send + Sync + 'static,
 {
     fn default() -> Self {
-        ProfileParamsSome(ProfileParams::default())
+        ProfileParamsSome(ProfileParams::default(), ParamsOverlays::default())
     }
 }
 
@@ -1,6 +1,6 @@
 use std::{fmt::Debug, hash::Hash};
 
-use peace_rt_model::params::WorkspaceParams;
+use peace_rt_model::params::{ParamsFns, WorkspaceParams, WorkspaceParamsRegistry};
 use serde::{de::DeserializeOwned, Serialize};
 
 /// The application does not use any workspace parameters.
@@ -8,8 +8,16 @@ use serde::{de::DeserializeOwned, Serialize};
 pub struct WorkspaceParamsNone;
 
 /// The application has workspace parameters.
+///
+/// This also holds functions that compute workspace parameter values when the
+/// command context is built, and the registry used to migrate stored workspace
+/// parameters.
 #[derive(Debug)]
-pub struct WorkspaceParamsSome<WorkspaceParamsK>(pub(crate) WorkspaceParams<WorkspaceParamsK>)
+pub struct WorkspaceParamsSome<WorkspaceParamsK>(
+    pub(crate) WorkspaceParams<WorkspaceParamsK>,
+    pub(crate) ParamsFns<WorkspaceParamsK>,
+    pub(crate) WorkspaceParamsRegistry<WorkspaceParamsK>,
+)
 where
     WorkspaceParamsK:
         Clone + Debug + Eq + Hash + DeserializeOwned + Serialize + Send + Sync + 'static;
@@ -20,6 +28,10 @@ where
         Clone + Debug + Eq + Hash + DeserializeOwned + Serialize + Send + Sync + 'static,
 {
     fn default() -> Self {
-        WorkspaceParamsSome(WorkspaceParams::default())
+        WorkspaceParamsSome(
+            WorkspaceParams::default(),
+            ParamsFns::default(),
+            WorkspaceParamsRegistry::default(),
+        )
     }
 }
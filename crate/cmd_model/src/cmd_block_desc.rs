@@ -1,3 +1,6 @@
+#[cfg(feature = "profiling")]
+use crate::TimingBreakdown;
+
 /// String representation of the `CmdBlock` in a `CmdExecution`.
 ///
 /// This is used to provide a well-formatted error message so that developers
@@ -20,6 +23,9 @@ pub struct CmdBlockDesc {
     /// * If `Outcome` is a tuple, this should contain one `String` per type
     ///   within the tuple.
     cmd_block_outcome_names: Vec<String>,
+    /// Time spent in item functions while the command block executed.
+    #[cfg(feature = "profiling")]
+    timing_breakdown: TimingBreakdown,
 }
 
 impl CmdBlockDesc {
@@ -33,9 +39,19 @@ impl CmdBlockDesc {
             cmd_block_name,
             cmd_block_input_names,
             cmd_block_outcome_names,
+            #[cfg(feature = "profiling")]
+            timing_breakdown: TimingBreakdown::new(),
         }
     }
 
+    /// Sets the time spent in item functions while the command block
+    /// executed.
+    #[cfg(feature = "profiling")]
+    pub fn with_timing_breakdown(mut self, timing_breakdown: TimingBreakdown) -> Self {
+        self.timing_breakdown = timing_breakdown;
+        self
+    }
+
     /// Returns the short name of the command block, e.g.
     /// `"StatesCurrentReadCmdBlock"`.
     pub fn cmd_block_name(&self) -> &str {
@@ -63,4 +79,13 @@ impl CmdBlockDesc {
     pub fn cmd_block_outcome_names(&self) -> &[String] {
         self.cmd_block_outcome_names.as_ref()
     }
+
+    /// Returns the time spent in item functions while the command block
+    /// executed.
+    ///
+    /// This is empty for command blocks that were not executed.
+    #[cfg(feature = "profiling")]
+    pub fn timing_breakdown(&self) -> &TimingBreakdown {
+        &self.timing_breakdown
+    }
 }
@@ -0,0 +1,14 @@
+/// What a `CmdBlock` does with each item.
+///
+/// This is used to categorize the [`CmdEvent`]s emitted when an item
+/// completes within the block.
+///
+/// [`CmdEvent`]: crate::CmdEvent
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CmdBlockItemAction {
+    /// The block discovers or reads each item's state.
+    #[default]
+    Discover,
+    /// The block applies each item's state to its target.
+    Apply,
+}
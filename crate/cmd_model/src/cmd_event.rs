@@ -0,0 +1,60 @@
+use peace_cfg::ItemId;
+
+use crate::CmdBlockDesc;
+
+/// Event emitted while a `CmdExecution` runs.
+///
+/// These are emitted by `CmdExecution::exec_with_events`, so that frontends
+/// can render an execution without implementing `OutputWrite`.
+///
+/// Item events are derived from item progress updates, so they are only
+/// emitted when the `"output_progress"` feature is enabled.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CmdEvent {
+    /// A `CmdBlock` has started executing.
+    BlockStarted {
+        /// Index of the `CmdBlock` within the `CmdExecution`.
+        cmd_block_index: usize,
+        /// Description of the `CmdBlock`.
+        cmd_block_desc: CmdBlockDesc,
+    },
+    /// A `CmdBlock` was skipped, as its predicate returned `false`.
+    BlockSkipped {
+        /// Index of the `CmdBlock` within the `CmdExecution`.
+        cmd_block_index: usize,
+        /// Description of the `CmdBlock`.
+        cmd_block_desc: CmdBlockDesc,
+    },
+    /// An item has started executing within the current `CmdBlock`.
+    ItemStarted {
+        /// ID of the item.
+        item_id: ItemId,
+    },
+    /// An item's state was discovered within the current `CmdBlock`.
+    ItemStateDiscovered {
+        /// ID of the item.
+        item_id: ItemId,
+    },
+    /// An item's state was applied within the current `CmdBlock`.
+    ItemApplied {
+        /// ID of the item.
+        item_id: ItemId,
+    },
+    /// An item failed within the current `CmdBlock`.
+    ItemFailed {
+        /// ID of the item.
+        item_id: ItemId,
+        /// Progress message of the item when it failed, if any.
+        message: Option<String>,
+    },
+    /// The execution was interrupted.
+    ///
+    /// This is the last event emitted for an interrupted execution.
+    Interrupted,
+    /// The execution has completed.
+    ///
+    /// This is the last event emitted for an execution that was not
+    /// interrupted. It is also emitted when items have failed, but not when
+    /// the execution returns an error.
+    Completed,
+}
@@ -1,9 +1,14 @@
+use std::path::PathBuf;
+
 use futures::Future;
 use indexmap::IndexMap;
 use peace_cfg::ItemId;
 
 use crate::{CmdBlockDesc, ItemStreamOutcome};
 
+#[cfg(feature = "profiling")]
+use crate::TimingBreakdown;
+
 /// Outcome of a [`CmdExecution`].
 ///
 /// The variants indicate whether execution was successful, interrupted, or
@@ -34,6 +39,9 @@ pub enum CmdOutcome<T, E> {
         ///
         /// The first block in this list is the one that was interrupted.
         cmd_blocks_not_processed: Vec<CmdBlockDesc>,
+        /// Whether the interruption was due to the execution deadline being
+        /// exceeded.
+        deadline_exceeded: bool,
     },
     /// Execution ended due to an interruption between command blocks.
     ExecutionInterrupted {
@@ -43,6 +51,9 @@ pub enum CmdOutcome<T, E> {
         cmd_blocks_processed: Vec<CmdBlockDesc>,
         /// Descriptors of the `CmdBlock`s that were not processed.
         cmd_blocks_not_processed: Vec<CmdBlockDesc>,
+        /// Whether the interruption was due to the execution deadline being
+        /// exceeded.
+        deadline_exceeded: bool,
     },
     /// Execution ended due to one or more item errors.
     ///
@@ -61,6 +72,11 @@ pub enum CmdOutcome<T, E> {
         cmd_blocks_not_processed: Vec<CmdBlockDesc>,
         /// Item error(s) from the last command block's execution.
         errors: IndexMap<ItemId, E>,
+        /// Paths to the log files of items that errored, if their log output
+        /// was captured.
+        ///
+        /// See `ItemLogLayer` in `peace_rt_model`.
+        item_log_paths: IndexMap<ItemId, PathBuf>,
     },
 }
 
@@ -75,17 +91,20 @@ impl<T, E> CmdOutcome<T, E> {
                 item_stream_outcome,
                 cmd_blocks_processed: _,
                 cmd_blocks_not_processed: _,
+                deadline_exceeded: _,
             } => Some(item_stream_outcome.value()),
             CmdOutcome::ExecutionInterrupted {
                 value,
                 cmd_blocks_processed: _,
                 cmd_blocks_not_processed: _,
+                deadline_exceeded: _,
             } => value.as_ref(),
             CmdOutcome::ItemError {
                 item_stream_outcome,
                 cmd_blocks_processed: _,
                 cmd_blocks_not_processed: _,
                 errors: _,
+                item_log_paths: _,
             } => Some(item_stream_outcome.value()),
         }
     }
@@ -103,11 +122,66 @@ impl<T, E> CmdOutcome<T, E> {
         )
     }
 
+    /// Returns whether the command was interrupted because the execution
+    /// deadline was exceeded.
+    pub fn is_deadline_exceeded(&self) -> bool {
+        matches!(
+            self,
+            Self::BlockInterrupted {
+                deadline_exceeded: true,
+                ..
+            } | Self::ExecutionInterrupted {
+                deadline_exceeded: true,
+                ..
+            }
+        )
+    }
+
     /// Returns whether the command encountered item errors during execution.
     pub fn is_err(&self) -> bool {
         matches!(self, Self::ItemError { .. })
     }
 
+    /// Returns the time spent in each item's functions across all executed
+    /// `CmdBlock`s.
+    ///
+    /// This includes the time spent in the interrupted or erred `CmdBlock`.
+    #[cfg(feature = "profiling")]
+    pub fn timing_breakdown(&self) -> TimingBreakdown {
+        let (cmd_blocks_processed, cmd_blocks_not_processed) = match self {
+            CmdOutcome::Complete {
+                value: _,
+                cmd_blocks_processed,
+            } => (cmd_blocks_processed, None),
+            CmdOutcome::BlockInterrupted {
+                item_stream_outcome: _,
+                cmd_blocks_processed,
+                cmd_blocks_not_processed,
+                deadline_exceeded: _,
+            }
+            | CmdOutcome::ExecutionInterrupted {
+                value: _,
+                cmd_blocks_processed,
+                cmd_blocks_not_processed,
+                deadline_exceeded: _,
+            }
+            | CmdOutcome::ItemError {
+                item_stream_outcome: _,
+                cmd_blocks_processed,
+                cmd_blocks_not_processed,
+                errors: _,
+                item_log_paths: _,
+            } => (cmd_blocks_processed, Some(cmd_blocks_not_processed)),
+        };
+
+        let mut timing_breakdown = TimingBreakdown::new();
+        cmd_blocks_processed
+            .iter()
+            .chain(cmd_blocks_not_processed.into_iter().flatten())
+            .for_each(|cmd_block_desc| timing_breakdown.merge(cmd_block_desc.timing_breakdown()));
+        timing_breakdown
+    }
+
     /// Maps the inner value to another, maintaining any collected errors.
     pub fn map<F, U>(self, f: F) -> CmdOutcome<U, E>
     where
@@ -128,24 +202,28 @@ impl<T, E> CmdOutcome<T, E> {
                 item_stream_outcome,
                 cmd_blocks_processed,
                 cmd_blocks_not_processed,
+                deadline_exceeded,
             } => {
                 let item_stream_outcome = item_stream_outcome.map(f);
                 CmdOutcome::BlockInterrupted {
                     item_stream_outcome,
                     cmd_blocks_processed,
                     cmd_blocks_not_processed,
+                    deadline_exceeded,
                 }
             }
             Self::ExecutionInterrupted {
                 value: t,
                 cmd_blocks_processed,
                 cmd_blocks_not_processed,
+                deadline_exceeded,
             } => {
                 let u = t.map(f);
                 CmdOutcome::ExecutionInterrupted {
                     value: u,
                     cmd_blocks_processed,
                     cmd_blocks_not_processed,
+                    deadline_exceeded,
                 }
             }
             Self::ItemError {
@@ -153,6 +231,7 @@ impl<T, E> CmdOutcome<T, E> {
                 cmd_blocks_processed,
                 cmd_blocks_not_processed,
                 errors,
+                item_log_paths,
             } => {
                 let item_stream_outcome = item_stream_outcome.map(f);
                 CmdOutcome::ItemError {
@@ -160,6 +239,7 @@ impl<T, E> CmdOutcome<T, E> {
                     cmd_blocks_processed,
                     cmd_blocks_not_processed,
                     errors,
+                    item_log_paths,
                 }
             }
         }
@@ -187,6 +267,7 @@ impl<T, E> CmdOutcome<T, E> {
                 item_stream_outcome,
                 cmd_blocks_processed,
                 cmd_blocks_not_processed,
+                deadline_exceeded,
             } => {
                 let (item_stream_outcome, value) = item_stream_outcome.replace(());
                 let value = f(value).await;
@@ -195,12 +276,14 @@ impl<T, E> CmdOutcome<T, E> {
                     item_stream_outcome,
                     cmd_blocks_processed,
                     cmd_blocks_not_processed,
+                    deadline_exceeded,
                 }
             }
             Self::ExecutionInterrupted {
                 value: t,
                 cmd_blocks_processed,
                 cmd_blocks_not_processed,
+                deadline_exceeded,
             } => {
                 let u = match t {
                     Some(t) => Some(f(t).await),
@@ -210,6 +293,7 @@ impl<T, E> CmdOutcome<T, E> {
                     value: u,
                     cmd_blocks_processed,
                     cmd_blocks_not_processed,
+                    deadline_exceeded,
                 }
             }
             Self::ItemError {
@@ -217,6 +301,7 @@ impl<T, E> CmdOutcome<T, E> {
                 cmd_blocks_processed,
                 cmd_blocks_not_processed,
                 errors,
+                item_log_paths,
             } => {
                 let (item_stream_outcome, value) = item_stream_outcome.replace(());
                 let value = f(value).await;
@@ -226,6 +311,7 @@ impl<T, E> CmdOutcome<T, E> {
                     cmd_blocks_processed,
                     cmd_blocks_not_processed,
                     errors,
+                    item_log_paths,
                 }
             }
         }
@@ -251,6 +337,7 @@ impl<T, E> CmdOutcome<Result<T, E>, E> {
                 item_stream_outcome,
                 cmd_blocks_processed,
                 cmd_blocks_not_processed,
+                deadline_exceeded,
             } => {
                 let (item_stream_outcome, value) = item_stream_outcome.replace(());
                 match value {
@@ -260,6 +347,7 @@ impl<T, E> CmdOutcome<Result<T, E>, E> {
                             item_stream_outcome,
                             cmd_blocks_processed,
                             cmd_blocks_not_processed,
+                            deadline_exceeded,
                         })
                     }
                     Err(e) => Err(e),
@@ -269,11 +357,13 @@ impl<T, E> CmdOutcome<Result<T, E>, E> {
                 value,
                 cmd_blocks_processed,
                 cmd_blocks_not_processed,
+                deadline_exceeded,
             } => match value.transpose() {
                 Ok(value) => Ok(CmdOutcome::ExecutionInterrupted {
                     value,
                     cmd_blocks_processed,
                     cmd_blocks_not_processed,
+                    deadline_exceeded,
                 }),
                 Err(e) => Err(e),
             },
@@ -282,6 +372,7 @@ impl<T, E> CmdOutcome<Result<T, E>, E> {
                 cmd_blocks_processed,
                 cmd_blocks_not_processed,
                 errors,
+                item_log_paths,
             } => {
                 let (item_stream_outcome, value) = item_stream_outcome.replace(());
                 match value {
@@ -292,6 +383,7 @@ impl<T, E> CmdOutcome<Result<T, E>, E> {
                             cmd_blocks_processed,
                             cmd_blocks_not_processed,
                             errors,
+                            item_log_paths,
                         })
                     }
                     Err(e) => Err(e),
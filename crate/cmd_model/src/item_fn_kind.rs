@@ -0,0 +1,32 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Item function whose execution time is recorded in a [`TimingBreakdown`].
+///
+/// [`TimingBreakdown`]: crate::TimingBreakdown
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize, Serialize)]
+pub enum ItemFnKind {
+    /// `Item::state_current` or `Item::try_state_current`.
+    StateCurrent,
+    /// `Item::state_goal` or `Item::try_state_goal`.
+    StateGoal,
+    /// `Item::state_diff`.
+    StateDiff,
+    /// `Item::apply_check`.
+    ApplyCheck,
+    /// `Item::apply` or `Item::apply_dry`.
+    ApplyExec,
+}
+
+impl fmt::Display for ItemFnKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StateCurrent => "state_current".fmt(f),
+            Self::StateGoal => "state_goal".fmt(f),
+            Self::StateDiff => "state_diff".fmt(f),
+            Self::ApplyCheck => "apply_check".fmt(f),
+            Self::ApplyExec => "apply_exec".fmt(f),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use std::time::Duration;
+
+use peace_cfg::ItemId;
+use serde::{Deserialize, Serialize};
+
+use crate::ItemFnKind;
+
+/// Total time spent in one function of an item.
+///
+/// See [`TimingBreakdown`].
+///
+/// [`TimingBreakdown`]: crate::TimingBreakdown
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ItemFnTiming {
+    /// ID of the item whose function was called.
+    pub item_id: ItemId,
+    /// The function that was called.
+    pub item_fn_kind: ItemFnKind,
+    /// Total time spent across all calls to the function.
+    pub duration: Duration,
+    /// Number of times the function was called.
+    pub calls: usize,
+}
+
+impl ItemFnTiming {
+    /// Returns a new `ItemFnTiming` for a single call.
+    pub fn new(item_id: ItemId, item_fn_kind: ItemFnKind, duration: Duration) -> Self {
+        Self {
+            item_id,
+            item_fn_kind,
+            duration,
+            calls: 1,
+        }
+    }
+}
@@ -7,16 +7,31 @@ pub use fn_graph;
 pub use indexmap;
 
 pub use crate::{
-    cmd_block_desc::CmdBlockDesc, cmd_block_outcome::CmdBlockOutcome,
+    cmd_block_desc::CmdBlockDesc, cmd_block_item_action::CmdBlockItemAction,
+    cmd_block_outcome::CmdBlockOutcome, cmd_event::CmdEvent,
     cmd_execution_error::CmdExecutionError, cmd_outcome::CmdOutcome,
     item_stream_outcome::ItemStreamOutcome, stream_outcome_and_errors::StreamOutcomeAndErrors,
     value_and_stream_outcome::ValueAndStreamOutcome,
 };
 
 mod cmd_block_desc;
+mod cmd_block_item_action;
 mod cmd_block_outcome;
+mod cmd_event;
 mod cmd_execution_error;
 mod cmd_outcome;
 mod item_stream_outcome;
 mod stream_outcome_and_errors;
 mod value_and_stream_outcome;
+
+#[cfg(feature = "profiling")]
+pub use crate::{
+    item_fn_kind::ItemFnKind, item_fn_timing::ItemFnTiming, timing_breakdown::TimingBreakdown,
+};
+
+#[cfg(feature = "profiling")]
+mod item_fn_kind;
+#[cfg(feature = "profiling")]
+mod item_fn_timing;
+#[cfg(feature = "profiling")]
+mod timing_breakdown;
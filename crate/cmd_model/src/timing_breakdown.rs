@@ -0,0 +1,158 @@
+use std::{ops::Deref, time::Duration};
+
+use peace_cfg::ItemId;
+use peace_fmt::{presentable::HeadingLevel, Presentable, Presenter};
+use serde::{Deserialize, Serialize};
+
+use crate::{ItemFnKind, ItemFnTiming};
+
+/// Time spent in each item's functions during a command execution.
+///
+/// Calls to the same function of the same item are summed into one
+/// [`ItemFnTiming`], in the order that each function was first called.
+///
+/// This is recorded when the `"profiling"` feature is enabled, and is
+/// retrieved using `CmdOutcome::timing_breakdown`. When presented, the slowest
+/// functions are rendered, so that the items that make a flow slow can be
+/// found.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TimingBreakdown(Vec<ItemFnTiming>);
+
+impl TimingBreakdown {
+    /// Number of functions rendered when this is presented.
+    pub const SLOWEST_COUNT: usize = 10;
+
+    /// Returns a new empty `TimingBreakdown`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a call to an item's function.
+    pub fn record(&mut self, item_id: &ItemId, item_fn_kind: ItemFnKind, duration: Duration) {
+        match self.0.iter_mut().find(|item_fn_timing| {
+            &item_fn_timing.item_id == item_id && item_fn_timing.item_fn_kind == item_fn_kind
+        }) {
+            Some(item_fn_timing) => {
+                item_fn_timing.duration += duration;
+                item_fn_timing.calls += 1;
+            }
+            None => self
+                .0
+                .push(ItemFnTiming::new(item_id.clone(), item_fn_kind, duration)),
+        }
+    }
+
+    /// Adds the timings from another `TimingBreakdown` to this one.
+    pub fn merge(&mut self, timing_breakdown: &TimingBreakdown) {
+        timing_breakdown.0.iter().for_each(|item_fn_timing_other| {
+            match self.0.iter_mut().find(|item_fn_timing| {
+                item_fn_timing.item_id == item_fn_timing_other.item_id
+                    && item_fn_timing.item_fn_kind == item_fn_timing_other.item_fn_kind
+            }) {
+                Some(item_fn_timing) => {
+                    item_fn_timing.duration += item_fn_timing_other.duration;
+                    item_fn_timing.calls += item_fn_timing_other.calls;
+                }
+                None => self.0.push(item_fn_timing_other.clone()),
+            }
+        });
+    }
+
+    /// Returns the underlying timings.
+    pub fn into_inner(self) -> Vec<ItemFnTiming> {
+        self.0
+    }
+
+    /// Returns the total time spent in item functions.
+    ///
+    /// Items may be executed concurrently, so this may be longer than the
+    /// command execution took.
+    pub fn duration_total(&self) -> Duration {
+        self.0
+            .iter()
+            .map(|item_fn_timing| item_fn_timing.duration)
+            .sum()
+    }
+
+    /// Returns the total time spent in the given item's functions.
+    pub fn item_duration(&self, item_id: &ItemId) -> Duration {
+        self.0
+            .iter()
+            .filter(|item_fn_timing| &item_fn_timing.item_id == item_id)
+            .map(|item_fn_timing| item_fn_timing.duration)
+            .sum()
+    }
+
+    /// Returns the timing of the given item's function, if it was called.
+    pub fn get(&self, item_id: &ItemId, item_fn_kind: ItemFnKind) -> Option<&ItemFnTiming> {
+        self.0.iter().find(|item_fn_timing| {
+            &item_fn_timing.item_id == item_id && item_fn_timing.item_fn_kind == item_fn_kind
+        })
+    }
+
+    /// Returns up to `n` functions that took the longest, slowest first.
+    pub fn slowest(&self, n: usize) -> Vec<&ItemFnTiming> {
+        let mut item_fn_timings = self.0.iter().collect::<Vec<_>>();
+        item_fn_timings.sort_by_key(|item_fn_timing| std::cmp::Reverse(item_fn_timing.duration));
+        item_fn_timings.truncate(n);
+        item_fn_timings
+    }
+}
+
+/// Returns the duration in seconds, with millisecond precision.
+fn duration_fmt(duration: Duration) -> String {
+    format!("{:.3}s", duration.as_secs_f64())
+}
+
+impl Deref for TimingBreakdown {
+    type Target = Vec<ItemFnTiming>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Vec<ItemFnTiming>> for TimingBreakdown {
+    fn from(item_fn_timings: Vec<ItemFnTiming>) -> Self {
+        Self(item_fn_timings)
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for TimingBreakdown {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        presenter
+            .heading(HeadingLevel::Level1, "Timing breakdown")
+            .await?;
+
+        if self.0.is_empty() {
+            return presenter.text("No item functions were executed.").await;
+        }
+
+        let rows = self
+            .slowest(Self::SLOWEST_COUNT)
+            .into_iter()
+            .map(|item_fn_timing| {
+                vec![
+                    item_fn_timing.item_id.to_string(),
+                    item_fn_timing.item_fn_kind.to_string(),
+                    item_fn_timing.calls.to_string(),
+                    duration_fmt(item_fn_timing.duration),
+                ]
+            });
+        presenter
+            .table(&["Item", "Function", "Calls", "Duration"], rows)
+            .await?;
+
+        presenter
+            .text(&format!(
+                "\nTotal: {} across {} functions\n",
+                duration_fmt(self.duration_total()),
+                self.0.len()
+            ))
+            .await
+    }
+}
@@ -2,7 +2,7 @@ use std::fmt::Debug;
 
 use async_trait::async_trait;
 use peace_cmd::{ctx::CmdCtxTypesConstrained, scopes::SingleProfileSingleFlowView};
-use peace_cmd_model::CmdBlockOutcome;
+use peace_cmd_model::{CmdBlockItemAction, CmdBlockOutcome};
 use peace_resources::{resources::ts::SetUp, Resource, ResourceFetchError, Resources};
 
 cfg_if::cfg_if! {
@@ -160,6 +160,17 @@ pub trait CmdBlock: Debug {
         vec![tynm::type_name::<Self::Outcome>()]
     }
 
+    /// Returns what this `CmdBlock` does with each item.
+    ///
+    /// This is used to categorize the [`CmdEvent`]s emitted when an item
+    /// completes within this block, and defaults to
+    /// [`CmdBlockItemAction::Discover`].
+    ///
+    /// [`CmdEvent`]: peace_cmd_model::CmdEvent
+    fn item_action(&self) -> CmdBlockItemAction {
+        CmdBlockItemAction::Discover
+    }
+
     /// Producer function to process all items.
     ///
     /// This is infallible because errors are expected to be returned associated
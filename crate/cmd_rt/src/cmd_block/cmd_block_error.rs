@@ -1,4 +1,4 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, time::Duration};
 
 use fn_graph::StreamOutcome;
 use indexmap::IndexMap;
@@ -52,4 +52,25 @@ where
         /// The stream outcome of the interrupted command block.
         stream_outcome: StreamOutcome<T>,
     },
+    /// The `CmdBlock` did not complete within its configured timeout.
+    ///
+    /// See `CmdExecutionBuilder::with_cmd_block_timeout`.
+    #[error("`CmdBlock` timed out after {timeout:?}.")]
+    Timeout {
+        /// Short type names of the timed out block's `CmdBlock::InputT`.
+        input_type_names: Vec<String>,
+        /// The timeout that elapsed.
+        timeout: Duration,
+    },
+    /// The `CmdBlock` was still running when the execution deadline's grace
+    /// period elapsed.
+    ///
+    /// See `CmdCtxBuilder::with_execution_deadline`.
+    #[error("`CmdBlock` was cancelled {grace_period:?} after the execution deadline.")]
+    DeadlineExceeded {
+        /// Short type names of the cancelled block's `CmdBlock::InputT`.
+        input_type_names: Vec<String>,
+        /// The grace period that elapsed after the deadline.
+        grace_period: Duration,
+    },
 }
@@ -3,7 +3,7 @@ use std::fmt::Debug;
 use async_trait::async_trait;
 
 use peace_cmd::{ctx::CmdCtxTypesConstrained, scopes::SingleProfileSingleFlowView};
-use peace_cmd_model::CmdBlockDesc;
+use peace_cmd_model::{CmdBlockDesc, CmdBlockItemAction};
 
 use crate::CmdBlockError;
 
@@ -43,4 +43,7 @@ pub trait CmdBlockRt: Debug + Unpin {
     /// This is used to provide a well-formatted error message so that
     /// developers can identify where a bug lies more easily.
     fn cmd_block_desc(&self) -> CmdBlockDesc;
+
+    /// Returns what the `CmdBlock` does with each item.
+    fn cmd_block_item_action(&self) -> CmdBlockItemAction;
 }
@@ -3,7 +3,7 @@ use std::{fmt::Debug, marker::PhantomData};
 use async_trait::async_trait;
 use fn_graph::StreamOutcomeState;
 use peace_cmd::{ctx::CmdCtxTypesConstrained, scopes::SingleProfileSingleFlowView};
-use peace_cmd_model::{CmdBlockDesc, CmdBlockOutcome};
+use peace_cmd_model::{CmdBlockDesc, CmdBlockItemAction, CmdBlockOutcome};
 use peace_resources::Resource;
 
 use tynm::TypeParamsFmtOpts;
@@ -172,4 +172,8 @@ where
             cmd_block_outcome_names,
         )
     }
+
+    fn cmd_block_item_action(&self) -> CmdBlockItemAction {
+        self.cmd_block.item_action()
+    }
 }
@@ -1,6 +1,19 @@
-use std::{collections::VecDeque, fmt::Debug};
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Debug,
+    ops::Range,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
+
+#[cfg(not(target_arch = "wasm32"))]
+use peace_cfg::ExecutionId;
+#[cfg(not(target_arch = "wasm32"))]
+use peace_rt_model::{ExecutionDeadline, ItemLogLayer};
 
 use futures::{future, stream, Future, StreamExt, TryStreamExt};
+use indexmap::IndexMap;
 use interruptible::InterruptSignal;
 use peace_cmd::{
     ctx::{CmdCtx, CmdCtxTypes, CmdCtxTypesConstrained},
@@ -8,21 +21,39 @@ use peace_cmd::{
         SingleProfileSingleFlow, SingleProfileSingleFlowView, SingleProfileSingleFlowViewAndOutput,
     },
 };
-use peace_cmd_model::{CmdBlockDesc, CmdOutcome};
-use peace_resources::{resources::ts::SetUp, Resources};
+use peace_cmd_model::{CmdBlockDesc, CmdEvent, CmdOutcome};
+use peace_resources::{
+    paths::{ExecutionTimelineFile, ItemLogsDir},
+    resources::ts::SetUp,
+    Resources,
+};
+use peace_rt_model::{ExecutionTimelineRecorder, ExecutionTimelineSerializer};
+use tokio::sync::mpsc::{self, Receiver, Sender};
+use tracing::{instrument::WithSubscriber, Instrument};
 
 use crate::{CmdBlockError, CmdBlockRtBox, ItemStreamOutcomeMapper};
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "output_progress")] {
         use peace_cfg::progress::CmdProgressUpdate;
-        use peace_rt_model::{output::OutputWrite, CmdProgressTracker};
-        use tokio::sync::mpsc::{self, Sender};
+        use peace_resources::paths::ExecutionProgressFile;
+        use peace_rt_model::{
+            output::OutputWrite, CmdProgressTracker, ExecutionProgress,
+            ExecutionProgressSerializer,
+        };
 
+        use self::cmd_item_event_emitter::CmdItemEventEmitter;
         use crate::Progress;
     }
 }
 
+cfg_if::cfg_if! {
+    if #[cfg(feature = "profiling")] {
+        use peace_cmd_model::TimingBreakdown;
+        use peace_rt_model::TimingBreakdownRecorder;
+    }
+}
+
 pub use self::{
     cmd_execution_builder::CmdExecutionBuilder,
     cmd_execution_error_builder::CmdExecutionErrorBuilder,
@@ -30,6 +61,11 @@ pub use self::{
 
 mod cmd_execution_builder;
 mod cmd_execution_error_builder;
+#[cfg(feature = "output_progress")]
+mod cmd_item_event_emitter;
+
+/// Maximum number of `CmdEvent`s to buffer.
+const CMD_EVENT_COUNT_MAX: usize = 256;
 
 /// List of [`CmdBlock`]s to run for a `*Cmd`.
 ///
@@ -42,6 +78,10 @@ mod cmd_execution_error_builder;
 /// feature flag as well; I don't know if developers want certain command
 /// executions to be interruptible, and others not.
 ///
+/// Progress updates are sent over `tokio::sync::mpsc` channels, which do not
+/// depend on the tokio runtime, so a `CmdExecution` may be run on `wasm32`
+/// using an executor such as `wasm_bindgen_futures::spawn_local`.
+///
 /// [`CmdBlock`]: crate::CmdBlock
 #[derive(Debug)]
 pub struct CmdExecution<'types, ExecutionOutcome, CmdCtxTypesT>
@@ -55,6 +95,12 @@ where
     /// Whether or not to render progress.
     #[cfg(feature = "output_progress")]
     progress_render_enabled: bool,
+    /// Maximum duration each `CmdBlock` may run for, keyed by block index.
+    #[cfg(not(target_arch = "wasm32"))]
+    cmd_block_timeouts: HashMap<usize, Duration>,
+    /// Predicates that determine whether each `CmdBlock` is run, keyed by
+    /// block index.
+    cmd_block_predicates: HashMap<usize, fn(&Resources<SetUp>) -> bool>,
 }
 
 impl<'types, ExecutionOutcome, CmdCtxTypesT> CmdExecution<'types, ExecutionOutcome, CmdCtxTypesT>
@@ -73,12 +119,58 @@ where
     ) -> Result<
         CmdOutcome<ExecutionOutcome, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
         <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+    > {
+        self.exec_with_cmd_event_tx(cmd_ctx, None).await
+    }
+
+    /// Returns a receiver of [`CmdEvent`]s, and the task that executes the
+    /// command.
+    ///
+    /// The receiver should be polled concurrently with the task, e.g. using
+    /// `futures::join!`, as the task waits for buffer space when the receiver
+    /// falls behind. Events are discarded if the receiver is dropped.
+    ///
+    /// The receiver ends after [`CmdEvent::Completed`] or
+    /// [`CmdEvent::Interrupted`] is received, or when the task returns an
+    /// error.
+    #[allow(clippy::type_complexity)]
+    pub fn exec_with_events<'exec, 'ctx>(
+        &'exec mut self,
+        cmd_ctx: &'exec mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+    ) -> (
+        Receiver<CmdEvent>,
+        impl Future<
+            Output = Result<
+                CmdOutcome<ExecutionOutcome, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+                <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+            >,
+        > + use<'exec, 'ctx, 'types, ExecutionOutcome, CmdCtxTypesT>,
+    )
+    where
+        'ctx: 'exec,
+    {
+        let (cmd_event_tx, cmd_event_rx) = mpsc::channel::<CmdEvent>(CMD_EVENT_COUNT_MAX);
+        let exec_task = self.exec_with_cmd_event_tx(cmd_ctx, Some(cmd_event_tx));
+
+        (cmd_event_rx, exec_task)
+    }
+
+    async fn exec_with_cmd_event_tx<'ctx>(
+        &mut self,
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+        cmd_event_tx: Option<Sender<CmdEvent>>,
+    ) -> Result<
+        CmdOutcome<ExecutionOutcome, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
     > {
         let Self {
             cmd_blocks,
             execution_outcome_fetch,
             #[cfg(feature = "output_progress")]
             progress_render_enabled,
+            #[cfg(not(target_arch = "wasm32"))]
+            cmd_block_timeouts,
+            cmd_block_predicates,
         } = self;
         #[cfg(feature = "output_progress")]
         let progress_render_enabled = *progress_render_enabled;
@@ -88,8 +180,8 @@ where
                 let SingleProfileSingleFlowViewAndOutput {
                     output,
                     cmd_progress_tracker,
+                    tracing_dispatch,
                     mut cmd_view,
-                    ..
                 } = cmd_ctx.view_and_output();
 
                 let (cmd_progress_tx, cmd_progress_rx) =
@@ -107,36 +199,160 @@ where
                     }));
             } else {
                 let SingleProfileSingleFlowViewAndOutput {
+                    tracing_dispatch,
                     mut cmd_view,
                     ..
                 } = cmd_ctx.view_and_output();
             }
         }
 
+        // The span is created with the command context's subscriber, so that it is
+        // recorded by that subscriber instead of the global default.
+        let cmd_execution_span = || {
+            tracing::info_span!(
+                "cmd_execution",
+                flow_id = %cmd_view.flow.flow_id(),
+                profile = %cmd_view.profile,
+                item_logs_dir = tracing::field::Empty,
+            )
+        };
+        let cmd_execution_span = match tracing_dispatch {
+            Some(tracing_dispatch) => {
+                tracing::dispatcher::with_default(tracing_dispatch, cmd_execution_span)
+            }
+            None => cmd_execution_span(),
+        };
+        let tracing_dispatch = tracing_dispatch.cloned();
+
+        // Each item's log output is captured in this execution's logs directory,
+        // when an `ItemLogLayer` is used by the subscriber. The execution ID is also
+        // passed to items in their `ApplyCtx`.
+        //
+        // Log files are not written through `Storage`, so they are not captured
+        // when the workspace is encrypted.
+        #[cfg(not(target_arch = "wasm32"))]
+        #[cfg_attr(not(feature = "output_progress"), allow(unused_variables))]
+        let item_logs_dir = {
+            let execution_id = chrono::Utc::now()
+                .format("%Y-%m-%dT%H_%M_%S%.3f")
+                .to_string();
+            let item_logs_dir = ItemLogsDir::from((cmd_view.flow_dir, execution_id.as_str()));
+            cmd_view.resources.insert(ExecutionId::new(execution_id));
+
+            let storage_codec = cmd_view.workspace.storage().storage_codec();
+            if storage_codec.is_encrypted(&item_logs_dir) {
+                None
+            } else {
+                cmd_execution_span.record(
+                    ItemLogLayer::FIELD_ITEM_LOGS_DIR,
+                    tracing::field::display(item_logs_dir.display()),
+                );
+                cmd_view.resources.insert(item_logs_dir.clone());
+                Some(item_logs_dir)
+            }
+        };
+
+        cmd_view
+            .resources
+            .insert(ExecutionTimelineRecorder::new());
+        #[cfg(feature = "profiling")]
+        cmd_view
+            .resources
+            .insert(TimingBreakdownRecorder::new());
+
         let cmd_outcome_task = cmd_outcome_task(
             cmd_blocks,
             execution_outcome_fetch,
+            #[cfg(not(target_arch = "wasm32"))]
+            cmd_block_timeouts,
+            cmd_block_predicates,
             &mut cmd_view,
+            cmd_event_tx.as_ref(),
             #[cfg(feature = "output_progress")]
             cmd_progress_tx,
         );
 
         #[cfg(not(feature = "output_progress"))]
-        {
-            exec_internal(cmd_outcome_task).await
-        }
+        let exec_task = exec_internal(cmd_outcome_task).instrument(cmd_execution_span);
 
         #[cfg(feature = "output_progress")]
-        {
-            exec_internal(
-                cmd_outcome_task,
-                progress_render_enabled,
-                output,
-                cmd_progress_tracker,
-                cmd_progress_rx,
+        let exec_task = exec_internal(
+            cmd_outcome_task,
+            progress_render_enabled,
+            output,
+            cmd_progress_tracker,
+            cmd_progress_rx,
+            #[cfg(not(target_arch = "wasm32"))]
+            item_logs_dir.as_ref(),
+            #[cfg(target_arch = "wasm32")]
+            None,
+        )
+        .instrument(cmd_execution_span);
+
+        let cmd_outcome_result = match tracing_dispatch {
+            Some(tracing_dispatch) => exec_task.with_subscriber(tracing_dispatch).await,
+            None => exec_task.await,
+        };
+
+        // Store each item's progress, so that the last run can be summarized by
+        // later commands. Commands that don't render progress don't update
+        // progress trackers, so their progress is not stored.
+        #[cfg(feature = "output_progress")]
+        let cmd_outcome_result = if progress_render_enabled {
+            let execution_progress = ExecutionProgress::from(&*cmd_progress_tracker);
+            let execution_progress_file = ExecutionProgressFile::from(cmd_view.flow_dir);
+            let execution_progress_serialize_result = ExecutionProgressSerializer::<
+                <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+            >::serialize(
+                cmd_view.workspace.storage(),
+                &execution_progress,
+                &execution_progress_file,
             )
-            .await
+            .await;
+
+            cmd_outcome_result.and_then(|cmd_outcome| {
+                execution_progress_serialize_result.map(|()| cmd_outcome)
+            })
+        } else {
+            cmd_outcome_result
+        };
+
+        // Store when each item was executed, so that the timeline can be rendered
+        // by later commands. Commands that don't discover or apply items don't
+        // record any spans, so the previous timeline is kept.
+        let execution_timeline = cmd_view
+            .resources
+            .try_borrow::<ExecutionTimelineRecorder>()
+            .map(|execution_timeline_recorder| execution_timeline_recorder.take())
+            .unwrap_or_default();
+        let cmd_outcome_result = if execution_timeline.is_empty() {
+            cmd_outcome_result
+        } else {
+            let execution_timeline_file = ExecutionTimelineFile::from(cmd_view.flow_dir);
+            let execution_timeline_serialize_result = ExecutionTimelineSerializer::<
+                <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+            >::serialize(
+                cmd_view.workspace.storage(),
+                &execution_timeline,
+                &execution_timeline_file,
+            )
+            .await;
+
+            cmd_outcome_result.and_then(|cmd_outcome| {
+                execution_timeline_serialize_result.map(|()| cmd_outcome)
+            })
+        };
+
+        if let (Some(cmd_event_tx), Ok(cmd_outcome)) = (cmd_event_tx, &cmd_outcome_result) {
+            let cmd_event = if cmd_outcome.is_interrupted() {
+                CmdEvent::Interrupted
+            } else {
+                CmdEvent::Completed
+            };
+            let _cmd_event_send_result = cmd_event_tx.send(cmd_event).await;
         }
+
+        cmd_outcome_result
     }
 
     // pub fn exec_bg -> CmdExecId
@@ -151,7 +367,8 @@ async fn exec_internal<ExecutionOutcome, E, #[cfg(feature = "output_progress")]
     #[cfg(feature = "output_progress")] progress_render_enabled: bool,
     #[cfg(feature = "output_progress")] output: &mut O,
     #[cfg(feature = "output_progress")] cmd_progress_tracker: &mut CmdProgressTracker,
-    #[cfg(feature = "output_progress")] mut cmd_progress_rx: mpsc::Receiver<CmdProgressUpdate>,
+    #[cfg(feature = "output_progress")] mut cmd_progress_rx: Receiver<CmdProgressUpdate>,
+    #[cfg(feature = "output_progress")] item_logs_dir: Option<&ItemLogsDir>,
 ) -> Result<CmdOutcome<ExecutionOutcome, E>, E>
 where
     ExecutionOutcome: Debug + Send + Sync + Unpin + 'static,
@@ -165,9 +382,18 @@ where
     #[cfg(feature = "output_progress")]
     if progress_render_enabled {
         output.progress_begin(cmd_progress_tracker).await;
-        let progress_trackers = &mut cmd_progress_tracker.progress_trackers;
-        let progress_render_task =
-            Progress::progress_render(output, progress_trackers, cmd_progress_rx);
+        let CmdProgressTracker {
+            multi_progress,
+            progress_trackers,
+            ..
+        } = &mut *cmd_progress_tracker;
+        let progress_render_task = Progress::progress_render(
+            output,
+            multi_progress,
+            progress_trackers,
+            cmd_progress_rx,
+            item_logs_dir,
+        );
 
         let (cmd_outcome, ()) = futures::join!(cmd_outcome_task, progress_render_task);
 
@@ -188,7 +414,10 @@ where
 async fn cmd_outcome_task<'types: 'view, 'view, 'view_ref, ExecutionOutcome, CmdCtxTypesT>(
     cmd_blocks: &VecDeque<CmdBlockRtBox<'types, CmdCtxTypesT, ExecutionOutcome>>,
     execution_outcome_fetch: &mut fn(&mut Resources<SetUp>) -> Option<ExecutionOutcome>,
+    #[cfg(not(target_arch = "wasm32"))] cmd_block_timeouts: &HashMap<usize, Duration>,
+    cmd_block_predicates: &HashMap<usize, fn(&Resources<SetUp>) -> bool>,
     cmd_view: &mut SingleProfileSingleFlowView<'view, CmdCtxTypesT>,
+    cmd_event_tx: Option<&Sender<CmdEvent>>,
     #[cfg(feature = "output_progress")] cmd_progress_tx: Sender<CmdProgressUpdate>,
 ) -> Result<
     CmdOutcome<ExecutionOutcome, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
@@ -198,6 +427,9 @@ where
     ExecutionOutcome: Debug + Send + Sync + Unpin + 'static,
     CmdCtxTypesT: CmdCtxTypesConstrained,
 {
+    #[cfg(not(target_arch = "wasm32"))]
+    let execution_deadline = cmd_view.execution_deadline;
+
     let cmd_view_and_progress_result: Result<
         CmdViewAndProgress<'_, '_, _>,
         CmdBlockStreamBreak<'_, '_, _, _>,
@@ -249,13 +481,116 @@ where
                 });
             }
 
-            let block_cmd_outcome_result = cmd_block_rt
-                .exec(
+            let cmd_block_desc = cmd_block_rt.cmd_block_desc();
+
+            // Blocks whose predicate returns `false` are skipped, and their outcome is
+            // not inserted into `resources`.
+            let cmd_block_skip = cmd_block_predicates
+                .get(&cmd_block_index)
+                .is_some_and(|predicate| !predicate(cmd_view.resources));
+            if cmd_block_skip {
+                if let Some(cmd_event_tx) = cmd_event_tx {
+                    let cmd_event = CmdEvent::BlockSkipped {
+                        cmd_block_index,
+                        cmd_block_desc,
+                    };
+                    let _cmd_event_send_result = cmd_event_tx.send(cmd_event).await;
+                }
+
+                return Ok(CmdViewAndProgress {
                     cmd_view,
                     #[cfg(feature = "output_progress")]
-                    cmd_progress_tx.clone(),
-                )
-                .await;
+                    cmd_progress_tx,
+                });
+            }
+
+            if let Some(cmd_event_tx) = cmd_event_tx {
+                let cmd_event = CmdEvent::BlockStarted {
+                    cmd_block_index,
+                    cmd_block_desc: cmd_block_desc.clone(),
+                };
+                let _cmd_event_send_result = cmd_event_tx.send(cmd_event).await;
+            }
+            #[cfg(feature = "profiling")]
+            if let Ok(timing_breakdown_recorder) =
+                cmd_view.resources.try_borrow::<TimingBreakdownRecorder>()
+            {
+                timing_breakdown_recorder.cmd_block_start(cmd_block_index);
+            }
+            let cmd_block_span = tracing::info_span!(
+                "cmd_block",
+                cmd_block_index,
+                cmd_block_name = cmd_block_desc.cmd_block_name(),
+            );
+            #[cfg(not(target_arch = "wasm32"))]
+            let cmd_block_timeout = cmd_block_timeouts.get(&cmd_block_index).copied();
+
+            #[cfg(not(feature = "output_progress"))]
+            let block_cmd_outcome_result = cmd_block_exec(
+                cmd_block_rt.exec(cmd_view).instrument(cmd_block_span),
+                &cmd_block_desc,
+                #[cfg(not(target_arch = "wasm32"))]
+                cmd_block_timeout,
+                #[cfg(not(target_arch = "wasm32"))]
+                execution_deadline,
+            )
+            .await;
+
+            // When sending `CmdEvent`s, progress updates are passed through a
+            // `CmdItemEventEmitter` that ends with the `CmdBlock`, so item events for
+            // this block are sent before the next `BlockStarted` event.
+            #[cfg(feature = "output_progress")]
+            let block_cmd_outcome_result = match cmd_event_tx {
+                Some(cmd_event_tx) => {
+                    let (block_progress_tx, block_progress_rx) =
+                        mpsc::channel::<CmdProgressUpdate>(crate::CMD_PROGRESS_COUNT_MAX);
+                    let cmd_item_event_emitter = CmdItemEventEmitter::new(
+                        cmd_event_tx.clone(),
+                        cmd_block_rt.cmd_block_item_action(),
+                    );
+                    let block_exec_task = cmd_block_exec(
+                        cmd_block_rt
+                            .exec(cmd_view, block_progress_tx)
+                            .instrument(cmd_block_span),
+                        &cmd_block_desc,
+                        #[cfg(not(target_arch = "wasm32"))]
+                        cmd_block_timeout,
+                        #[cfg(not(target_arch = "wasm32"))]
+                        execution_deadline,
+                    );
+                    let progress_forward_task =
+                        cmd_item_event_emitter.progress_forward(block_progress_rx, &cmd_progress_tx);
+
+                    let (block_cmd_outcome_result, ()) =
+                        futures::join!(block_exec_task, progress_forward_task);
+                    block_cmd_outcome_result
+                }
+                None => {
+                    cmd_block_exec(
+                        cmd_block_rt
+                            .exec(cmd_view, cmd_progress_tx.clone())
+                            .instrument(cmd_block_span),
+                        &cmd_block_desc,
+                        #[cfg(not(target_arch = "wasm32"))]
+                        cmd_block_timeout,
+                        #[cfg(not(target_arch = "wasm32"))]
+                        execution_deadline,
+                    )
+                    .await
+                }
+            };
+
+            // Progress bars of items that were running when the block timed out
+            // are marked as interrupted. The block's progress senders have already
+            // been dropped with the cancelled block.
+            #[cfg(feature = "output_progress")]
+            if matches!(
+                block_cmd_outcome_result,
+                Err(CmdBlockError::Timeout { .. } | CmdBlockError::DeadlineExceeded { .. })
+            ) {
+                let _cmd_progress_send_result =
+                    cmd_progress_tx.send(CmdProgressUpdate::Interrupt).await;
+            }
 
             // `CmdBlock` block logic errors are propagated.
             let cmd_view_and_progress = CmdViewAndProgress {
@@ -276,13 +611,82 @@ where
     )
     .await;
 
+    #[cfg(not(target_arch = "wasm32"))]
+    let deadline_exceeded = execution_deadline
+        .as_ref()
+        .is_some_and(ExecutionDeadline::is_exceeded);
+    #[cfg(target_arch = "wasm32")]
+    let deadline_exceeded = false;
+
     outcome_extract::<ExecutionOutcome, CmdCtxTypesT>(
         cmd_view_and_progress_result,
         cmd_blocks,
         execution_outcome_fetch,
+        deadline_exceeded,
     )
 }
 
+/// Runs a `CmdBlock`'s execution, cancelling it if it exceeds its timeout, or
+/// is still running when the execution deadline's grace period elapses.
+///
+/// Cancellation drops the block's future, which drops any progress senders
+/// held by the block.
+async fn cmd_block_exec<T, E>(
+    block_exec_task: impl Future<Output = Result<(), CmdBlockError<T, E>>>,
+    #[cfg_attr(target_arch = "wasm32", allow(unused_variables))] cmd_block_desc: &CmdBlockDesc,
+    #[cfg(not(target_arch = "wasm32"))] cmd_block_timeout: Option<Duration>,
+    #[cfg(not(target_arch = "wasm32"))] execution_deadline: Option<ExecutionDeadline>,
+) -> Result<(), CmdBlockError<T, E>>
+where
+    T: Debug,
+    E: Debug,
+{
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let timeout_cancel = cmd_block_timeout.map(|timeout| {
+            let cancel_at = tokio::time::Instant::now() + timeout;
+            let cmd_block_error = CmdBlockError::Timeout {
+                input_type_names: cmd_block_desc.cmd_block_input_names().to_vec(),
+                timeout,
+            };
+            (cancel_at, cmd_block_error)
+        });
+        let deadline_cancel = execution_deadline.and_then(|execution_deadline| {
+            execution_deadline
+                .grace_period_end()
+                .map(|grace_period_end| {
+                    let cancel_at = tokio::time::Instant::from_std(grace_period_end);
+                    let cmd_block_error = CmdBlockError::DeadlineExceeded {
+                        input_type_names: cmd_block_desc.cmd_block_input_names().to_vec(),
+                        grace_period: execution_deadline.grace_period(),
+                    };
+                    (cancel_at, cmd_block_error)
+                })
+        });
+
+        // Whichever cancellation comes first applies.
+        let cancel = match (timeout_cancel, deadline_cancel) {
+            (Some(timeout_cancel), Some(deadline_cancel)) => {
+                if deadline_cancel.0 < timeout_cancel.0 {
+                    Some(deadline_cancel)
+                } else {
+                    Some(timeout_cancel)
+                }
+            }
+            (timeout_cancel, deadline_cancel) => timeout_cancel.or(deadline_cancel),
+        };
+
+        if let Some((cancel_at, cmd_block_error)) = cancel {
+            return match tokio::time::timeout_at(cancel_at, block_exec_task).await {
+                Ok(block_cmd_outcome_result) => block_cmd_outcome_result,
+                Err(_elapsed) => Err(cmd_block_error),
+            };
+        }
+    }
+
+    block_exec_task.await
+}
+
 /// Extracts the `ExecutionOutcome` from the intermediate outcome collating
 /// types.
 ///
@@ -293,6 +697,8 @@ where
 /// * `cmd_blocks`: `CmdBlock`s in this execution, used to build a useful error
 ///   message if needed.
 /// * `execution_outcome_fetch`: Logic to extract the `ExecutionOutcome` type.
+/// * `deadline_exceeded`: Whether the execution deadline had passed, used to
+///   mark interrupted outcomes.
 fn outcome_extract<'types: 'view, 'view, 'view_ref, ExecutionOutcome, CmdCtxTypesT>(
     cmd_view_and_progress_result: Result<
         CmdViewAndProgress<'view, 'view_ref, CmdCtxTypesT>,
@@ -300,6 +706,7 @@ fn outcome_extract<'types: 'view, 'view, 'view_ref, ExecutionOutcome, CmdCtxType
     >,
     cmd_blocks: &'view_ref VecDeque<CmdBlockRtBox<'types, CmdCtxTypesT, ExecutionOutcome>>,
     execution_outcome_fetch: &mut fn(&mut Resources<SetUp>) -> Option<ExecutionOutcome>,
+    deadline_exceeded: bool,
 ) -> Result<
     CmdOutcome<ExecutionOutcome, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
     <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
@@ -340,6 +747,12 @@ where
     #[cfg(feature = "output_progress")]
     drop(cmd_progress_tx);
 
+    #[cfg(feature = "profiling")]
+    let mut timing_breakdowns = resources
+        .try_borrow::<TimingBreakdownRecorder>()
+        .map(|timing_breakdown_recorder| timing_breakdown_recorder.take())
+        .unwrap_or_default();
+
     if let Some((cmd_block_index, cmd_block_error)) = cmd_block_index_and_error {
         match cmd_block_error {
             CmdBlockError::InputFetch(resource_fetch_error) => {
@@ -352,21 +765,58 @@ where
                 ))
             }
             CmdBlockError::Exec(error) => Err(error),
+            CmdBlockError::Timeout {
+                input_type_names,
+                timeout,
+            } => {
+                let cmd_block_name = cmd_blocks
+                    .get(cmd_block_index)
+                    .map(|cmd_block_rt| cmd_block_rt.cmd_block_desc().cmd_block_name().to_string())
+                    .unwrap_or_default();
+                Err(<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError::from(
+                    peace_rt_model::Error::CmdBlockTimeout {
+                        cmd_block_name,
+                        input_type_names,
+                        timeout,
+                    },
+                ))
+            }
+            CmdBlockError::DeadlineExceeded {
+                input_type_names,
+                grace_period,
+            } => {
+                let cmd_block_name = cmd_blocks
+                    .get(cmd_block_index)
+                    .map(|cmd_block_rt| cmd_block_rt.cmd_block_desc().cmd_block_name().to_string())
+                    .unwrap_or_default();
+                Err(<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError::from(
+                    peace_rt_model::Error::ExecutionDeadlineExceeded {
+                        cmd_block_name,
+                        input_type_names,
+                        grace_period,
+                    },
+                ))
+            }
             CmdBlockError::Interrupt { stream_outcome } => {
                 let item_stream_outcome = ItemStreamOutcomeMapper::map(flow, stream_outcome);
-                let cmd_blocks_processed = cmd_blocks
-                    .range(0..cmd_block_index)
-                    .map(|cmd_block_rt| cmd_block_rt.cmd_block_desc())
-                    .collect::<Vec<CmdBlockDesc>>();
-
-                let cmd_blocks_not_processed = cmd_blocks
-                    .range(cmd_block_index..)
-                    .map(|cmd_block_rt| cmd_block_rt.cmd_block_desc())
-                    .collect::<Vec<CmdBlockDesc>>();
+                let cmd_blocks_processed = cmd_block_descs(
+                    cmd_blocks,
+                    0..cmd_block_index,
+                    #[cfg(feature = "profiling")]
+                    &mut timing_breakdowns,
+                );
+
+                let cmd_blocks_not_processed = cmd_block_descs(
+                    cmd_blocks,
+                    cmd_block_index..cmd_blocks.len(),
+                    #[cfg(feature = "profiling")]
+                    &mut timing_breakdowns,
+                );
                 let cmd_outcome = CmdOutcome::BlockInterrupted {
                     item_stream_outcome,
                     cmd_blocks_processed,
                     cmd_blocks_not_processed,
+                    deadline_exceeded,
                 };
 
                 Ok(cmd_outcome)
@@ -376,21 +826,42 @@ where
                 errors,
             } => {
                 let item_stream_outcome = ItemStreamOutcomeMapper::map(flow, stream_outcome);
-                let cmd_blocks_processed = cmd_blocks
-                    .range(0..cmd_block_index)
-                    .map(|cmd_block_rt| cmd_block_rt.cmd_block_desc())
-                    .collect::<Vec<CmdBlockDesc>>();
-
-                let cmd_blocks_not_processed = cmd_blocks
-                    .range(cmd_block_index..)
-                    .map(|cmd_block_rt| cmd_block_rt.cmd_block_desc())
-                    .collect::<Vec<CmdBlockDesc>>();
+                let cmd_blocks_processed = cmd_block_descs(
+                    cmd_blocks,
+                    0..cmd_block_index,
+                    #[cfg(feature = "profiling")]
+                    &mut timing_breakdowns,
+                );
+
+                let cmd_blocks_not_processed = cmd_block_descs(
+                    cmd_blocks,
+                    cmd_block_index..cmd_blocks.len(),
+                    #[cfg(feature = "profiling")]
+                    &mut timing_breakdowns,
+                );
+
+                // Only items whose log output was captured have a log file.
+                let item_log_paths = resources
+                    .try_borrow::<ItemLogsDir>()
+                    .map(|item_logs_dir| {
+                        errors
+                            .keys()
+                            .filter_map(|item_id| {
+                                let item_log_file = item_logs_dir.item_log_file(item_id);
+                                item_log_file
+                                    .exists()
+                                    .then(|| (item_id.clone(), item_log_file))
+                            })
+                            .collect::<IndexMap<_, _>>()
+                    })
+                    .unwrap_or_default();
 
                 let cmd_outcome = CmdOutcome::ItemError {
                     item_stream_outcome,
                     cmd_blocks_processed,
                     cmd_blocks_not_processed,
                     errors,
+                    item_log_paths,
                 };
 
                 Ok(cmd_outcome)
@@ -399,26 +870,33 @@ where
     } else {
         let execution_outcome = execution_outcome_fetch(resources);
         let cmd_outcome = if let Some(cmd_block_index_next) = cmd_block_index_next {
-            let cmd_blocks_processed = cmd_blocks
-                .range(0..cmd_block_index_next)
-                .map(|cmd_block_rt| cmd_block_rt.cmd_block_desc())
-                .collect::<Vec<CmdBlockDesc>>();
-
-            let cmd_blocks_not_processed = cmd_blocks
-                .range(cmd_block_index_next..)
-                .map(|cmd_block_rt| cmd_block_rt.cmd_block_desc())
-                .collect::<Vec<CmdBlockDesc>>();
+            let cmd_blocks_processed = cmd_block_descs(
+                cmd_blocks,
+                0..cmd_block_index_next,
+                #[cfg(feature = "profiling")]
+                &mut timing_breakdowns,
+            );
+
+            let cmd_blocks_not_processed = cmd_block_descs(
+                cmd_blocks,
+                cmd_block_index_next..cmd_blocks.len(),
+                #[cfg(feature = "profiling")]
+                &mut timing_breakdowns,
+            );
 
             CmdOutcome::ExecutionInterrupted {
                 value: execution_outcome,
                 cmd_blocks_processed,
                 cmd_blocks_not_processed,
+                deadline_exceeded,
             }
         } else {
-            let cmd_blocks_processed = cmd_blocks
-                .iter()
-                .map(|cmd_block_rt| cmd_block_rt.cmd_block_desc())
-                .collect::<Vec<CmdBlockDesc>>();
+            let cmd_blocks_processed = cmd_block_descs(
+                cmd_blocks,
+                0..cmd_blocks.len(),
+                #[cfg(feature = "profiling")]
+                &mut timing_breakdowns,
+            );
 
             CmdOutcome::Complete {
                 value: execution_outcome.unwrap_or_else(|| {
@@ -438,6 +916,34 @@ where
     }
 }
 
+/// Returns the descriptors of the `CmdBlock`s within the given index range.
+///
+/// When the `"profiling"` feature is enabled, each descriptor includes the time
+/// spent in item functions while its `CmdBlock` executed.
+fn cmd_block_descs<CmdCtxTypesT, ExecutionOutcome>(
+    cmd_blocks: &VecDeque<CmdBlockRtBox<'_, CmdCtxTypesT, ExecutionOutcome>>,
+    cmd_block_indices: Range<usize>,
+    #[cfg(feature = "profiling")] timing_breakdowns: &mut IndexMap<usize, TimingBreakdown>,
+) -> Vec<CmdBlockDesc>
+where
+    ExecutionOutcome: Debug + 'static,
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    cmd_block_indices
+        .filter_map(|cmd_block_index| {
+            let cmd_block_desc = cmd_blocks.get(cmd_block_index)?.cmd_block_desc();
+
+            #[cfg(feature = "profiling")]
+            let cmd_block_desc = match timing_breakdowns.swap_remove(&cmd_block_index) {
+                Some(timing_breakdown) => cmd_block_desc.with_timing_breakdown(timing_breakdown),
+                None => cmd_block_desc,
+            };
+
+            Some(cmd_block_desc)
+        })
+        .collect::<Vec<CmdBlockDesc>>()
+}
+
 struct CmdViewAndProgress<'view, 'view_ref, CmdCtxTypesT>
 where
     CmdCtxTypesT: CmdCtxTypesConstrained,
@@ -1,4 +1,10 @@
-use std::{collections::VecDeque, fmt::Debug};
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Debug,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
 
 use peace_cmd::ctx::CmdCtxTypesConstrained;
 use peace_resources::{resources::ts::SetUp, Resource, Resources};
@@ -28,6 +34,12 @@ where
     /// Defaults to `true`.
     #[cfg(feature = "output_progress")]
     progress_render_enabled: bool,
+    /// Maximum duration each `CmdBlock` may run for, keyed by block index.
+    #[cfg(not(target_arch = "wasm32"))]
+    cmd_block_timeouts: HashMap<usize, Duration>,
+    /// Predicates that determine whether each `CmdBlock` is run, keyed by
+    /// block index.
+    cmd_block_predicates: HashMap<usize, fn(&Resources<SetUp>) -> bool>,
 }
 
 impl<'types, ExecutionOutcome, CmdCtxTypesT>
@@ -58,6 +70,9 @@ where
             execution_outcome_fetch,
             #[cfg(feature = "output_progress")]
             progress_render_enabled,
+            #[cfg(not(target_arch = "wasm32"))]
+            cmd_block_timeouts,
+            cmd_block_predicates,
         } = self;
 
         cmd_blocks.push_back(Box::pin(cmd_block));
@@ -67,9 +82,85 @@ where
             execution_outcome_fetch,
             #[cfg(feature = "output_progress")]
             progress_render_enabled,
+            #[cfg(not(target_arch = "wasm32"))]
+            cmd_block_timeouts,
+            cmd_block_predicates,
         }
     }
 
+    /// Adds a `CmdBlock` to this execution, which is only run if `predicate`
+    /// returns `true`.
+    ///
+    /// The predicate is evaluated with the `Resources` at the time the block
+    /// would run, so it can check the outcomes of earlier blocks. This allows
+    /// a command to skip blocks, such as skipping state sync checks when there
+    /// are no stored states, without building separate executions.
+    ///
+    /// When the block is skipped, its outcome is not inserted into
+    /// `Resources`, so later blocks and the execution outcome fetch must not
+    /// require it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// CmdExecution::<StatesEnsured, _>::builder()
+    ///     .with_cmd_block_if(
+    ///         |resources| resources.contains::<StatesCurrentStored>(),
+    ///         CmdBlockWrapper::new(
+    ///             ApplyStateSyncCheckCmdBlock::current(),
+    ///             |_states_current_stored_and_current| StatesEnsured::new(),
+    ///         ),
+    ///     )
+    ///     // ..
+    ///     .build();
+    /// ```
+    pub fn with_cmd_block_if<CB, BlockOutcomeNext, InputT>(
+        self,
+        predicate: fn(&Resources<SetUp>) -> bool,
+        cmd_block: CmdBlockWrapper<CB, CmdCtxTypesT, ExecutionOutcome, BlockOutcomeNext, InputT>,
+    ) -> CmdExecutionBuilder<'types, ExecutionOutcome, CmdCtxTypesT>
+    where
+        CB: CmdBlock<CmdCtxTypes = CmdCtxTypesT, Outcome = BlockOutcomeNext, InputT = InputT>
+            + Unpin
+            + 'types,
+        ExecutionOutcome: Debug + Resource + Unpin + 'static,
+        BlockOutcomeNext: Debug + Resource + Unpin + 'static,
+        InputT: Debug + Resource + Unpin + 'static,
+    {
+        let cmd_block_index = self.cmd_blocks.len();
+        let mut cmd_execution_builder = self.with_cmd_block(cmd_block);
+        cmd_execution_builder
+            .cmd_block_predicates
+            .insert(cmd_block_index, predicate);
+        cmd_execution_builder
+    }
+
+    /// Specifies the maximum duration the most recently added `CmdBlock` may
+    /// run for.
+    ///
+    /// If the block does not complete within `duration`, it is cancelled and
+    /// the execution returns a [`CmdBlockTimeout`] error, which includes the
+    /// block's name and input type names for diagnosis.
+    ///
+    /// When this method is called multiple times for the same block, the last
+    /// call wins.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no `CmdBlock` has been added yet.
+    ///
+    /// [`CmdBlockTimeout`]: peace_rt_model::Error::CmdBlockTimeout
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_cmd_block_timeout(mut self, duration: Duration) -> Self {
+        let cmd_block_index = self
+            .cmd_blocks
+            .len()
+            .checked_sub(1)
+            .expect("Expected `with_cmd_block` to be called before `with_cmd_block_timeout`.");
+        self.cmd_block_timeouts.insert(cmd_block_index, duration);
+        self
+    }
+
     /// Specifies the logic to fetch the `ExecutionOutcome` from `Resources`.
     ///
     /// By default, the `CmdExecution` will run
@@ -110,6 +201,9 @@ where
             execution_outcome_fetch,
             #[cfg(feature = "output_progress")]
             progress_render_enabled,
+            #[cfg(not(target_arch = "wasm32"))]
+            cmd_block_timeouts,
+            cmd_block_predicates,
         } = self;
 
         CmdExecution {
@@ -117,6 +211,9 @@ where
             execution_outcome_fetch,
             #[cfg(feature = "output_progress")]
             progress_render_enabled,
+            #[cfg(not(target_arch = "wasm32"))]
+            cmd_block_timeouts,
+            cmd_block_predicates,
         }
     }
 }
@@ -133,6 +230,9 @@ where
             execution_outcome_fetch,
             #[cfg(feature = "output_progress")]
             progress_render_enabled: true,
+            #[cfg(not(target_arch = "wasm32"))]
+            cmd_block_timeouts: HashMap::new(),
+            cmd_block_predicates: HashMap::new(),
         }
     }
 }
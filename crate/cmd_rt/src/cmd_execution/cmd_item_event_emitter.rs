@@ -0,0 +1,135 @@
+use std::collections::HashSet;
+
+use peace_cfg::{
+    progress::{
+        CmdProgressUpdate, ProgressComplete, ProgressMsgUpdate, ProgressUpdate, ProgressUpdateAndId,
+    },
+    ItemId,
+};
+use peace_cmd_model::{CmdBlockItemAction, CmdEvent};
+use tokio::sync::mpsc::{Receiver, Sender};
+
+/// Sends item [`CmdEvent`]s for the progress updates of one `CmdBlock`.
+///
+/// In a [`CmdBlockItemAction::Discover`] block, progress is only made once an
+/// item's state is discovered, so the first tick or completion is sent as
+/// [`CmdEvent::ItemStateDiscovered`]. In a [`CmdBlockItemAction::Apply`]
+/// block, progress is made while the item is applied, so only completion is
+/// sent as [`CmdEvent::ItemApplied`].
+#[derive(Debug)]
+pub(crate) struct CmdItemEventEmitter {
+    /// Channel to send `CmdEvent`s to.
+    cmd_event_tx: Sender<CmdEvent>,
+    /// What the `CmdBlock` does with each item.
+    cmd_block_item_action: CmdBlockItemAction,
+    /// Items that have started within the `CmdBlock`.
+    item_ids_started: HashSet<ItemId>,
+    /// Items that have completed within the `CmdBlock`.
+    item_ids_completed: HashSet<ItemId>,
+}
+
+impl CmdItemEventEmitter {
+    /// Returns a new `CmdItemEventEmitter`.
+    pub(crate) fn new(
+        cmd_event_tx: Sender<CmdEvent>,
+        cmd_block_item_action: CmdBlockItemAction,
+    ) -> Self {
+        Self {
+            cmd_event_tx,
+            cmd_block_item_action,
+            item_ids_started: HashSet::new(),
+            item_ids_completed: HashSet::new(),
+        }
+    }
+
+    /// Sends the `CmdEvent`s for each progress update, then forwards the
+    /// update to `cmd_progress_tx`.
+    ///
+    /// This returns when the `CmdBlock` drops its progress sender. Events are
+    /// discarded if the `CmdEvent` receiver has been dropped.
+    pub(crate) async fn progress_forward(
+        mut self,
+        mut block_progress_rx: Receiver<CmdProgressUpdate>,
+        cmd_progress_tx: &Sender<CmdProgressUpdate>,
+    ) {
+        while let Some(cmd_progress_update) = block_progress_rx.recv().await {
+            if let CmdProgressUpdate::Item {
+                progress_update_and_id,
+            } = &cmd_progress_update
+            {
+                self.progress_update_handle(progress_update_and_id).await;
+            }
+
+            let _cmd_progress_send_result = cmd_progress_tx.send(cmd_progress_update).await;
+        }
+    }
+
+    async fn progress_update_handle(&mut self, progress_update_and_id: &ProgressUpdateAndId) {
+        let ProgressUpdateAndId {
+            item_id,
+            progress_update,
+            msg_update,
+        } = progress_update_and_id;
+        match progress_update {
+            ProgressUpdate::Limit(_) | ProgressUpdate::SubStep { .. } => {
+                self.item_started_send(item_id).await;
+            }
+            ProgressUpdate::Delta(_) => {
+                self.item_started_send(item_id).await;
+                if self.cmd_block_item_action == CmdBlockItemAction::Discover {
+                    self.item_completed_send(item_id).await;
+                }
+            }
+            ProgressUpdate::Complete(ProgressComplete::Success) => {
+                self.item_started_send(item_id).await;
+                self.item_completed_send(item_id).await;
+            }
+            ProgressUpdate::Complete(ProgressComplete::Fail) => {
+                self.item_started_send(item_id).await;
+
+                let message = match msg_update {
+                    ProgressMsgUpdate::Set(message) => Some(message.clone()),
+                    ProgressMsgUpdate::Clear | ProgressMsgUpdate::NoChange => None,
+                };
+                self.item_ids_completed.insert(item_id.clone());
+                self.cmd_event_send(CmdEvent::ItemFailed {
+                    item_id: item_id.clone(),
+                    message,
+                })
+                .await;
+            }
+            ProgressUpdate::Reset
+            | ProgressUpdate::ResetToPending
+            | ProgressUpdate::Queued
+            | ProgressUpdate::Interrupt
+            | ProgressUpdate::Complete(ProgressComplete::Skipped) => {}
+        }
+    }
+
+    /// Sends `CmdEvent::ItemStarted` if the item has not started.
+    async fn item_started_send(&mut self, item_id: &ItemId) {
+        if self.item_ids_started.insert(item_id.clone()) {
+            self.cmd_event_send(CmdEvent::ItemStarted {
+                item_id: item_id.clone(),
+            })
+            .await;
+        }
+    }
+
+    /// Sends the completion `CmdEvent` for the `CmdBlock` if the item has not
+    /// completed.
+    async fn item_completed_send(&mut self, item_id: &ItemId) {
+        if self.item_ids_completed.insert(item_id.clone()) {
+            let item_id = item_id.clone();
+            let cmd_event = match self.cmd_block_item_action {
+                CmdBlockItemAction::Discover => CmdEvent::ItemStateDiscovered { item_id },
+                CmdBlockItemAction::Apply => CmdEvent::ItemApplied { item_id },
+            };
+            self.cmd_event_send(cmd_event).await;
+        }
+    }
+
+    async fn cmd_event_send(&self, cmd_event: CmdEvent) {
+        let _cmd_event_send_result = self.cmd_event_tx.send(cmd_event).await;
+    }
+}
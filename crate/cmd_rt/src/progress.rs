@@ -3,37 +3,54 @@ use std::ops::ControlFlow;
 use futures::stream::{self, StreamExt};
 use peace_cfg::{
     progress::{
-        CmdProgressUpdate, ProgressDelta, ProgressMsgUpdate, ProgressStatus, ProgressTracker,
-        ProgressUpdate, ProgressUpdateAndId,
+        CmdProgressUpdate, ProgressComplete, ProgressDelta, ProgressMsgUpdate, ProgressStatus,
+        ProgressTracker, ProgressUpdate, ProgressUpdateAndId,
     },
     ItemId,
 };
-use peace_rt_model::{output::OutputWrite, IndexMap};
+use peace_resources::paths::ItemLogsDir;
+use peace_rt_model::{
+    indicatif::{MultiProgress, ProgressBar},
+    output::OutputWrite,
+    IndexMap,
+};
 use tokio::sync::mpsc::Receiver;
 
 pub struct Progress;
 
 impl Progress {
     /// Receives progress updates and updates `output` to render it.
+    ///
+    /// When `item_logs_dir` is provided, failure messages of items that have a
+    /// log file include the path to that file.
     // TODO: write test for this
     pub async fn progress_render<E, O>(
         output: &mut O,
+        multi_progress: &MultiProgress,
         progress_trackers: &mut IndexMap<ItemId, ProgressTracker>,
         mut cmd_progress_rx: Receiver<CmdProgressUpdate>,
+        item_logs_dir: Option<&ItemLogsDir>,
     ) where
         O: OutputWrite<E>,
     {
         while let Some(cmd_progress_update) = cmd_progress_rx.recv().await {
-            let _control_flow =
-                Self::handle_cmd_progress_update(output, progress_trackers, cmd_progress_update)
-                    .await;
+            let _control_flow = Self::handle_cmd_progress_update(
+                output,
+                multi_progress,
+                progress_trackers,
+                cmd_progress_update,
+                item_logs_dir,
+            )
+            .await;
         }
     }
 
     async fn handle_cmd_progress_update<E, O>(
         output: &mut O,
+        multi_progress: &MultiProgress,
         progress_trackers: &mut IndexMap<ItemId, ProgressTracker>,
         cmd_progress_update: CmdProgressUpdate,
+        item_logs_dir: Option<&ItemLogsDir>,
     ) -> ControlFlow<()>
     where
         O: OutputWrite<E>,
@@ -44,8 +61,10 @@ impl Progress {
             } => {
                 Self::handle_progress_update_and_id(
                     output,
+                    multi_progress,
                     progress_trackers,
                     progress_update_and_id,
+                    item_logs_dir,
                 )
                 .await;
 
@@ -63,6 +82,7 @@ impl Progress {
 
                         Self::handle_progress_tracker_progress_update(
                             output,
+                            multi_progress,
                             progress_tracker,
                             progress_update_and_id,
                         )
@@ -86,6 +106,7 @@ impl Progress {
 
                         Self::handle_progress_tracker_progress_update(
                             output,
+                            multi_progress,
                             progress_tracker,
                             progress_update_and_id,
                         )
@@ -102,8 +123,10 @@ impl Progress {
 
     async fn handle_progress_update_and_id<E, O>(
         output: &mut O,
+        multi_progress: &MultiProgress,
         progress_trackers: &mut IndexMap<ItemId, ProgressTracker>,
-        progress_update_and_id: ProgressUpdateAndId,
+        mut progress_update_and_id: ProgressUpdateAndId,
+        item_logs_dir: Option<&ItemLogsDir>,
     ) where
         O: OutputWrite<E>,
     {
@@ -112,8 +135,25 @@ impl Progress {
             panic!("Expected `progress_tracker` to exist for item: `{item_id}`.");
         };
 
+        // Point the user to the item's log output when it fails.
+        if let (
+            ProgressUpdate::Complete(ProgressComplete::Fail),
+            ProgressMsgUpdate::Set(message),
+            Some(item_logs_dir),
+        ) = (
+            &progress_update_and_id.progress_update,
+            &mut progress_update_and_id.msg_update,
+            item_logs_dir,
+        ) {
+            let item_log_file = item_logs_dir.item_log_file(item_id);
+            if item_log_file.exists() {
+                message.push_str(&format!(" (logs: {})", item_log_file.display()));
+            }
+        }
+
         Self::handle_progress_tracker_progress_update(
             output,
+            multi_progress,
             progress_tracker,
             progress_update_and_id,
         )
@@ -122,6 +162,7 @@ impl Progress {
 
     async fn handle_progress_tracker_progress_update<E, O>(
         output: &mut O,
+        multi_progress: &MultiProgress,
         progress_tracker: &mut ProgressTracker,
         progress_update_and_id: ProgressUpdateAndId,
     ) where
@@ -132,6 +173,26 @@ impl Progress {
             progress_update,
             msg_update,
         } = &progress_update_and_id;
+        Self::progress_tracker_update(
+            multi_progress,
+            progress_tracker,
+            progress_update,
+            msg_update,
+        );
+
+        output
+            .progress_update(progress_tracker, &progress_update_and_id)
+            .await;
+    }
+
+    /// Applies the progress update to the progress tracker, or the tracker of
+    /// the sub-step that the update is for.
+    fn progress_tracker_update(
+        multi_progress: &MultiProgress,
+        progress_tracker: &mut ProgressTracker,
+        progress_update: &ProgressUpdate,
+        msg_update: &ProgressMsgUpdate,
+    ) {
         match progress_update {
             ProgressUpdate::Reset => progress_tracker.reset(),
             ProgressUpdate::ResetToPending => progress_tracker.reset_to_pending(),
@@ -152,6 +213,38 @@ impl Progress {
                 progress_tracker
                     .set_progress_status(ProgressStatus::Complete(progress_complete.clone()));
             }
+            ProgressUpdate::SubStep {
+                name,
+                progress_update,
+            } => {
+                // A sub-step making progress means its parent is running.
+                if matches!(
+                    progress_tracker.progress_status(),
+                    ProgressStatus::ExecPending | ProgressStatus::Queued
+                ) {
+                    progress_tracker.set_progress_status(ProgressStatus::Running);
+                }
+
+                if progress_tracker.sub_tracker(name).is_none() {
+                    let progress_bar = multi_progress.insert_after(
+                        progress_tracker.progress_bar_last(),
+                        ProgressBar::hidden().with_prefix(name.clone()),
+                    );
+                    progress_tracker
+                        .sub_tracker_insert(name.clone(), ProgressTracker::new(progress_bar));
+                }
+                if let Some(sub_tracker) = progress_tracker.sub_tracker_mut(name) {
+                    Self::progress_tracker_update(
+                        multi_progress,
+                        sub_tracker,
+                        progress_update,
+                        msg_update,
+                    );
+                }
+
+                // The message update applies to the sub-step's tracker.
+                return;
+            }
         }
 
         match msg_update {
@@ -159,9 +252,5 @@ impl Progress {
             ProgressMsgUpdate::NoChange => {}
             ProgressMsgUpdate::Set(message) => progress_tracker.set_message(Some(message.clone())),
         }
-
-        output
-            .progress_update(progress_tracker, &progress_update_and_id)
-            .await;
     }
 }
@@ -8,9 +8,12 @@ pub use self::{
     impl_build::impl_build, impl_common_fns::impl_common_fns, impl_constructor::impl_constructor,
     impl_params_deserialize::impl_params_deserialize, impl_params_merge::impl_params_merge,
     impl_with_flow::impl_with_flow, impl_with_param::impl_with_param,
-    impl_with_params_k::impl_with_params_k, impl_with_profile::impl_with_profile,
-    impl_with_profile_filter::impl_with_profile_filter, params_scope::ParamsScope,
-    profile_count::ProfileCount, scope::Scope, struct_definition::struct_definition,
+    impl_with_param_fn::impl_with_param_fn, impl_with_param_overlay::impl_with_param_overlay,
+    impl_with_params_file::impl_with_params_file, impl_with_params_k::impl_with_params_k,
+    impl_with_profile::impl_with_profile, impl_with_profile_filter::impl_with_profile_filter,
+    impl_with_workspace_params_registry::impl_with_workspace_params_registry,
+    params_scope::ParamsScope, profile_count::ProfileCount, scope::Scope,
+    struct_definition::struct_definition,
 };
 
 pub(crate) use impl_header_builder::ImplHeaderBuilder;
@@ -23,9 +26,13 @@ mod impl_params_deserialize;
 mod impl_params_merge;
 mod impl_with_flow;
 mod impl_with_param;
+mod impl_with_param_fn;
+mod impl_with_param_overlay;
+mod impl_with_params_file;
 mod impl_with_params_k;
 mod impl_with_profile;
 mod impl_with_profile_filter;
+mod impl_with_workspace_params_registry;
 mod params_scope;
 mod profile_count;
 mod scope;
@@ -46,7 +53,11 @@ pub fn cmd_ctx_builder_impl(input: proc_macro::TokenStream) -> proc_macro::Token
     let impl_constructor = impl_constructor(&scope_struct);
     let impl_common_fns = impl_common_fns(&scope_struct);
     let impl_with_param = impl_with_param(&scope_struct);
+    let impl_with_param_fn = impl_with_param_fn(&scope_struct);
+    let impl_with_workspace_params_registry = impl_with_workspace_params_registry(&scope_struct);
+    let impl_with_param_overlay = impl_with_param_overlay(&scope_struct);
     let impl_with_params_k = impl_with_params_k(&scope_struct);
+    let impl_with_params_file = impl_with_params_file(&scope_struct);
 
     let impl_with_profile = impl_with_profile(&scope_struct);
     let impl_with_profile_filter = impl_with_profile_filter(&scope_struct);
@@ -67,8 +78,16 @@ pub fn cmd_ctx_builder_impl(input: proc_macro::TokenStream) -> proc_macro::Token
 
         #impl_with_param
 
+        #impl_with_param_fn
+
+        #impl_with_workspace_params_registry
+
+        #impl_with_param_overlay
+
         #impl_with_params_k
 
+        #impl_with_params_file
+
         #impl_with_profile
 
         #impl_with_profile_filter
@@ -170,6 +170,11 @@ fn impl_build_for(
     let scope_fields = scope_fields(scope);
     let states_and_params_read_and_pg_init = states_and_params_read_and_pg_init(scope);
     let resources_insert = resources_insert(scope);
+    let params_revision_dir_read = params_revision_dir_read(scope);
+    let params_history_record = params_history_record(scope);
+    let items_disabled_load_save = items_disabled_load_save(scope);
+    let item_executor_limit = item_executor_limit(scope);
+    let interruptibility_with_deadline = interruptibility_with_deadline(scope);
 
     let scope_builder_deconstruct = scope_builder_deconstruct(
         scope_struct,
@@ -405,6 +410,21 @@ fn impl_build_for(
                     #dirs_to_create
                 ];
 
+                // === Single Profile Single Flow === //
+                // let params_revision_dir = match self.scope_builder.params_at {
+                //     Some(revision) => Some(
+                //         crate::ctx::cmd_ctx_builder::params_revision_dir_read(
+                //             storage,
+                //             &profile_history_dir,
+                //             self.scope_builder.flow_selection.0.flow_id(),
+                //             revision,
+                //         )
+                //         .await?,
+                //     ),
+                //     None => None,
+                // };
+                #params_revision_dir_read
+
                 // === Profile Params === //
                 // --- Single --- //
                 // let profile_params_file = ProfileParamsFile::from(&profile_dir);
@@ -492,16 +512,50 @@ fn impl_build_for(
                 //         flow_selection: FlowSelected(flow),
                 //         params_type_regs_builder,
                 //         workspace_params_selection: WorkspaceParamsSome(workspace_params),
-                //         profile_params_selection: ProfileParamsSome(profile_params),
+                //         profile_params_selection: ProfileParamsSome(profile_params, profile_params_overlays),
                 //         flow_params_selection: FlowParamsNone,
                 //
                 //         // === SingleProfileSingleFlow === //
                 //         params_specs_provided,
+                //         states_from_flows,
+                //         apply_hooks,
+                //         approval_policy,
+                //         heartbeat_policy,
+                //         items_disabled,
+                //         item_executor,
+                //         max_concurrency,
+                //         states_prune,
+                //         execution_deadline,
+                //         interrupt_handle,
+                //         params_change_policy,
+                //         params_at,
                 //     },
                 // } = self;
                 #scope_builder_deconstruct
+
+                // === SingleProfileSingleFlow === //
+                // #[cfg(not(target_arch = "wasm32"))]
+                // let interruptibility = match execution_deadline {
+                //     Some(execution_deadline) => {
+                //         crate::ctx::cmd_ctx_builder::interruptibility_with_deadline(
+                //             interruptibility,
+                //             execution_deadline,
+                //         )
+                //     }
+                //     None => interruptibility,
+                // };
+                #interruptibility_with_deadline
                 let interruptibility_state = interruptibility.into();
 
+                // === SingleProfileSingleFlow === //
+                // let item_executor = match max_concurrency {
+                //     Some(concurrency_max) => std::sync::Arc::new(
+                //         peace_rt_model::ItemExecutorLimited::new(item_executor, concurrency_max),
+                //     ),
+                //     None => item_executor,
+                // };
+                #item_executor_limit
+
                 // Serialize params to `PeaceAppDir`.
 
                 // crate::ctx::cmd_ctx_builder::workspace_params_serialize(
@@ -528,8 +582,30 @@ fn impl_build_for(
                 // .await?;
                 #flow_params_serialize
 
+                // === Single Profile === //
+                // crate::ctx::cmd_ctx_builder::params_history_record(
+                //     storage,
+                //     &profile_history_dir,
+                //     Some(flow.flow_id()),
+                //     &workspace_params,
+                //     &profile_params,
+                //     &flow_params,
+                // )
+                // .await?;
+                #params_history_record
+
+                #items_disabled_load_save
+
                 // Track items in memory.
                 let mut resources = peace_resources::Resources::new();
+
+                // Framework messages are rendered through the catalog, both in
+                // `CmdBlock`s and by the `OutputWrite`.
+                resources.insert(message_catalog.clone());
+                peace_rt_model::output::OutputWrite::<AppError>::message_catalog_set(
+                    &mut *output,
+                    message_catalog,
+                );
                 // === WorkspaceParamsSelected === //
                 // crate::ctx::cmd_ctx_builder::workspace_params_insert(workspace_params, &mut resources);
                 // resources.insert(workspace_params_file);
@@ -720,6 +796,15 @@ fn impl_build_for(
                 //     resources.insert(states_current_stored);
                 // }
                 //
+                // // Stored current states from other flows in the same profile.
+                // crate::ctx::cmd_ctx_builder::states_from_flows_insert(
+                //     &profile_dir,
+                //     storage,
+                //     &states_from_flows,
+                //     &mut resources,
+                // )
+                // .await?;
+                //
                 // // Call each `Item`'s initialization function.
                 // let resources = crate::ctx::cmd_ctx_builder::item_graph_setup(
                 //     item_graph,
@@ -727,6 +812,13 @@ fn impl_build_for(
                 // )
                 // .await?;
                 //
+                // // Validate each `Item`'s resolved params.
+                // crate::ctx::cmd_ctx_builder::params_specs_validate(
+                //     item_graph,
+                //     &params_specs,
+                //     &resources,
+                // )?;
+                //
                 // // output_progress CmdProgressTracker initialization
                 // #[cfg(feature = "output_progress")]
                 // let cmd_progress_tracker = {
@@ -797,6 +889,9 @@ fn impl_build_for(
                     // params_specs,
                     // states_type_reg,
                     // resources,
+                    // apply_hooks,
+                    // approval_policy,
+                    // heartbeat_policy,
 
                     #scope_fields
                 );
@@ -892,23 +987,105 @@ fn scope_builder_deconstruct(
         scope_builder_fields.push(flow_params_selection.deconstruct(scope.profile_count()));
     }
 
+    scope_builder_fields.push(parse_quote! {
+        params_files_provided: _
+    });
     if scope.flow_count() == FlowCount::One {
         scope_builder_fields.push(parse_quote! {
             params_specs_provided
         });
     }
+    if scope.states_from_flows_supported() {
+        scope_builder_fields.push(parse_quote! {
+            states_from_flows
+        });
+    }
+    if scope.apply_hooks_supported() {
+        scope_builder_fields.push(parse_quote! {
+            apply_hooks
+        });
+    }
+    if scope.approval_policy_supported() {
+        scope_builder_fields.push(parse_quote! {
+            approval_policy
+        });
+    }
+    if scope.heartbeat_policy_supported() {
+        scope_builder_fields.push(parse_quote! {
+            heartbeat_policy
+        });
+    }
+    if scope.items_disabled_supported() {
+        scope_builder_fields.push(parse_quote! {
+            items_disabled
+        });
+    }
+    if scope.item_executor_supported() {
+        scope_builder_fields.push(parse_quote! {
+            item_executor
+        });
+        scope_builder_fields.push(parse_quote! {
+            max_concurrency
+        });
+    }
+    if scope.states_prune_supported() {
+        scope_builder_fields.push(parse_quote! {
+            states_prune
+        });
+    }
+    if scope.execution_deadline_supported() {
+        scope_builder_fields.push(parse_quote! {
+            execution_deadline
+        });
+    }
+    if scope.interrupt_handle_supported() {
+        scope_builder_fields.push(parse_quote! {
+            interrupt_handle
+        });
+    }
+    if scope.params_change_policy_supported() {
+        scope_builder_fields.push(parse_quote! {
+            params_change_policy
+        });
+    }
+    if scope.params_at_supported() {
+        scope_builder_fields.push(parse_quote! {
+            params_at
+        });
+    }
+
+    // Only `CmdExecution`s record spans to the tracing subscriber, which require
+    // a single profile and flow.
+    let tracing_dispatch_field = match scope {
+        Scope::SingleProfileSingleFlow => quote!(tracing_dispatch),
+        Scope::MultiProfileNoFlow
+        | Scope::MultiProfileSingleFlow
+        | Scope::NoProfileNoFlow
+        | Scope::SingleProfileNoFlow => quote!(tracing_dispatch: _),
+    };
+    let meter_field = match scope {
+        Scope::SingleProfileSingleFlow => quote!(meter),
+        Scope::MultiProfileNoFlow
+        | Scope::MultiProfileSingleFlow
+        | Scope::NoProfileNoFlow
+        | Scope::SingleProfileNoFlow => quote!(meter: _),
+    };
 
     quote! {
         let crate::ctx::CmdCtxBuilder {
             output,
             interruptibility,
+            #tracing_dispatch_field,
+            message_catalog,
+            #[cfg(feature = "telemetry")]
+            #meter_field,
             workspace,
             scope_builder: #scope_builder_name {
                 // profile_selection: ProfileSelected(profile),
                 // flow_selection: FlowSelected(flow),
                 // params_type_regs_builder,
                 // workspace_params_selection: WorkspaceParamsSome(workspace_params),
-                // profile_params_selection: ProfileParamsSome(profile_params),
+                // profile_params_selection: ProfileParamsSome(profile_params, profile_params_overlays),
                 // flow_params_selection: FlowParamsNone,
 
                 // // === SingleProfileSingleFlow === //
@@ -920,6 +1097,125 @@ fn scope_builder_deconstruct(
     }
 }
 
+/// Reads the params revision to load profile and flow params from, when
+/// `with_params_at` is supported.
+fn params_revision_dir_read(scope: Scope) -> proc_macro2::TokenStream {
+    if scope.params_at_supported() {
+        quote! {
+            let params_revision_dir = match self.scope_builder.params_at {
+                Some(revision) => Some(
+                    crate::ctx::cmd_ctx_builder::params_revision_dir_read(
+                        storage,
+                        &profile_history_dir,
+                        self.scope_builder.flow_selection.0.flow_id(),
+                        revision,
+                    )
+                    .await?,
+                ),
+                None => None,
+            };
+        }
+    } else {
+        proc_macro2::TokenStream::new()
+    }
+}
+
+/// Limits the item executor's concurrency when `with_max_concurrency` is
+/// used.
+fn item_executor_limit(scope: Scope) -> proc_macro2::TokenStream {
+    if scope.item_executor_supported() {
+        quote! {
+            let item_executor: std::sync::Arc<dyn peace_rt_model::ItemExecutor> =
+                match max_concurrency {
+                    Some(concurrency_max) => std::sync::Arc::new(
+                        peace_rt_model::ItemExecutorLimited::new(item_executor, concurrency_max),
+                    ),
+                    None => item_executor,
+                };
+        }
+    } else {
+        proc_macro2::TokenStream::new()
+    }
+}
+
+/// Interrupts the command execution when the deadline set by
+/// `with_execution_deadline` is reached.
+fn interruptibility_with_deadline(scope: Scope) -> proc_macro2::TokenStream {
+    if scope.execution_deadline_supported() {
+        quote! {
+            #[cfg(not(target_arch = "wasm32"))]
+            let interruptibility = match execution_deadline {
+                Some(execution_deadline) => {
+                    crate::ctx::cmd_ctx_builder::interruptibility_with_deadline(
+                        interruptibility,
+                        execution_deadline,
+                    )
+                }
+                None => interruptibility,
+            };
+        }
+    } else {
+        proc_macro2::TokenStream::new()
+    }
+}
+
+/// Records the params in the profile's params history for single profile
+/// scopes.
+///
+/// Params loaded from a revision are not recorded, as they are already in the
+/// history.
+fn params_history_record(scope: Scope) -> proc_macro2::TokenStream {
+    match scope.profile_count() {
+        ProfileCount::None | ProfileCount::Multiple => proc_macro2::TokenStream::new(),
+        ProfileCount::One => {
+            if scope.params_at_supported() {
+                quote! {
+                    if params_at.is_none() {
+                        crate::ctx::cmd_ctx_builder::params_history_record(
+                            storage,
+                            &profile_history_dir,
+                            Some(flow.flow_id()),
+                            &workspace_params,
+                            &profile_params,
+                            &flow_params,
+                        )
+                        .await?;
+                    }
+                }
+            } else {
+                quote! {
+                    crate::ctx::cmd_ctx_builder::params_history_record(
+                        storage,
+                        &profile_history_dir,
+                        None,
+                        &workspace_params,
+                        &profile_params,
+                        &flow_params,
+                    )
+                    .await?;
+                }
+            }
+        }
+    }
+}
+
+/// Stores the items disabled for the profile when `with_items_disabled` is
+/// used, otherwise reads the stored items disabled.
+fn items_disabled_load_save(scope: Scope) -> proc_macro2::TokenStream {
+    if scope.items_disabled_supported() {
+        quote! {
+            let items_disabled = crate::ctx::cmd_ctx_builder::items_disabled_load_save(
+                storage,
+                &profile_dir,
+                items_disabled,
+            )
+            .await?;
+        }
+    } else {
+        proc_macro2::TokenStream::new()
+    }
+}
+
 /// Load from `workspace_params_file` and serialize when
 /// `WorkspaceParamsSelection` is `Some`.
 fn workspace_params_load_save(
@@ -954,6 +1250,42 @@ fn workspace_params_load_save(
                     workspace_dirs.peace_app_dir()
                 );
 
+                // Values computed by `with_workspace_param_fn` functions are
+                // resolved concurrently, and take precedence over stored values.
+                let workspace_params_fns = std::mem::take(
+                    &mut self.scope_builder.workspace_params_selection.1
+                );
+                if !workspace_params_fns.is_empty() {
+                    let workspace_params_computed = workspace_params_fns
+                        .resolve()
+                        .await
+                        .map_err(|(key, error)| peace_rt_model::Error::WorkspaceParamFnError {
+                            key: format!("{key:?}"),
+                            error,
+                        })?;
+                    let workspace_params = &mut self.scope_builder.workspace_params_selection.0;
+                    workspace_params_computed
+                        .into_inner()
+                        .into_iter()
+                        .for_each(|(key, param)| {
+                            workspace_params.insert_raw(key, param);
+                        });
+                }
+
+                // Params stored by earlier versions of the application are
+                // migrated to their current types before they are deserialized.
+                let workspace_params_registry = std::mem::take(
+                    &mut self.scope_builder.workspace_params_selection.2
+                );
+                if !workspace_params_registry.is_empty() {
+                    peace_rt_model::WorkspaceInitializer::workspace_params_migrate(
+                        storage,
+                        &workspace_params_registry,
+                        &workspace_params_file,
+                    )
+                    .await?;
+                }
+
                 self.workspace_params_merge(&workspace_params_file).await?;
             };
             let workspace_params_serialize = quote! {
@@ -1005,6 +1337,12 @@ fn profile_params_load_save(
                             peace_rt_model::params::KeyMaybe
                         >::Key
                     >::new();
+                    let profile_params_resolution = peace_rt_model::params::ParamsResolution::<
+                        <
+                            #profile_params_k_maybe_type_param as
+                            peace_rt_model::params::KeyMaybe
+                        >::Key
+                    >::new();
                 };
                 (
                     profile_params_deserialize,
@@ -1013,12 +1351,28 @@ fn profile_params_load_save(
                 )
             }
             ProfileParamsSelection::Some => {
-                let profile_params_deserialize = quote! {
-                    let profile_params_file = peace_resources::internal::ProfileParamsFile::from(
-                        &profile_dir
-                    );
+                let profile_params_deserialize = if scope.params_at_supported() {
+                    quote! {
+                        let profile_params_file = peace_resources::internal::ProfileParamsFile::from(
+                            &profile_dir
+                        );
+                        let profile_params_file_load = match params_revision_dir.as_ref() {
+                            Some(params_revision_dir) => {
+                                peace_resources::internal::ProfileParamsFile::from(params_revision_dir)
+                            }
+                            None => profile_params_file.clone(),
+                        };
+
+                        self.profile_params_merge(&profile_params_file_load).await?;
+                    }
+                } else {
+                    quote! {
+                        let profile_params_file = peace_resources::internal::ProfileParamsFile::from(
+                            &profile_dir
+                        );
 
-                    self.profile_params_merge(&profile_params_file).await?;
+                        self.profile_params_merge(&profile_params_file).await?;
+                    }
                 };
                 let profile_params_serialize = quote! {
                     crate::ctx::cmd_ctx_builder::profile_params_serialize(
@@ -1028,7 +1382,22 @@ fn profile_params_load_save(
                     )
                     .await?;
                 };
+                // Params loaded from a revision are not written over the current params.
+                let profile_params_serialize = if scope.params_at_supported() {
+                    quote! {
+                        if params_at.is_none() {
+                            #profile_params_serialize
+                        }
+                    }
+                } else {
+                    profile_params_serialize
+                };
                 let profile_params_insert = quote! {
+                    let (profile_params, profile_params_resolution) =
+                        crate::ctx::cmd_ctx_builder::profile_params_resolve(
+                            profile_params,
+                            &profile_params_overlays,
+                        );
                     crate::ctx::cmd_ctx_builder::profile_params_insert(profile_params.clone(), &mut resources);
                     resources.insert(profile_params_file);
                 };
@@ -1146,12 +1515,28 @@ fn flow_params_load_save(
                 )
             }
             FlowParamsSelection::Some => {
-                let flow_params_deserialize = quote! {
-                    let flow_params_file = peace_resources::internal::FlowParamsFile::from(
-                        &flow_dir
-                    );
+                let flow_params_deserialize = if scope.params_at_supported() {
+                    quote! {
+                        let flow_params_file = peace_resources::internal::FlowParamsFile::from(
+                            &flow_dir
+                        );
+                        let flow_params_file_load = match params_revision_dir.as_ref() {
+                            Some(params_revision_dir) => {
+                                peace_resources::internal::FlowParamsFile::from(params_revision_dir)
+                            }
+                            None => flow_params_file.clone(),
+                        };
+
+                        self.flow_params_merge(&flow_params_file_load).await?;
+                    }
+                } else {
+                    quote! {
+                        let flow_params_file = peace_resources::internal::FlowParamsFile::from(
+                            &flow_dir
+                        );
 
-                    self.flow_params_merge(&flow_params_file).await?;
+                        self.flow_params_merge(&flow_params_file).await?;
+                    }
                 };
                 let flow_params_serialize = quote! {
                     crate::ctx::cmd_ctx_builder::flow_params_serialize(
@@ -1161,6 +1546,16 @@ fn flow_params_load_save(
                     )
                     .await?;
                 };
+                // Params loaded from a revision are not written over the current params.
+                let flow_params_serialize = if scope.params_at_supported() {
+                    quote! {
+                        if params_at.is_none() {
+                            #flow_params_serialize
+                        }
+                    }
+                } else {
+                    flow_params_serialize
+                };
                 let flow_params_insert = quote! {
                     crate::ctx::cmd_ctx_builder::flow_params_insert(flow_params.clone(), &mut resources);
                     resources.insert(flow_params_file);
@@ -1455,6 +1850,11 @@ fn scope_fields(scope: Scope) -> Punctuated<FieldValue, Comma> {
                 #[cfg(feature = "output_progress")]
                 cmd_progress_tracker
             });
+            scope_fields.push(parse_quote!(tracing_dispatch));
+            scope_fields.push(parse_quote! {
+                #[cfg(feature = "telemetry")]
+                cmd_metrics
+            });
         }
     }
 
@@ -1495,6 +1895,7 @@ fn scope_fields(scope: Scope) -> Punctuated<FieldValue, Comma> {
         ProfileCount::One => {
             scope_fields.push(parse_quote!(workspace_params));
             scope_fields.push(parse_quote!(profile_params));
+            scope_fields.push(parse_quote!(profile_params_resolution));
         }
         ProfileCount::Multiple => {
             scope_fields.push(parse_quote!(workspace_params));
@@ -1528,6 +1929,12 @@ fn scope_fields(scope: Scope) -> Punctuated<FieldValue, Comma> {
             scope_fields.push(parse_quote!(params_specs));
             scope_fields.push(parse_quote!(states_type_reg));
             scope_fields.push(parse_quote!(resources));
+            scope_fields.push(parse_quote!(apply_hooks));
+            scope_fields.push(parse_quote!(approval_policy));
+            scope_fields.push(parse_quote!(heartbeat_policy));
+            scope_fields.push(parse_quote!(item_executor));
+            scope_fields.push(parse_quote!(execution_deadline));
+            scope_fields.push(parse_quote!(interrupt_handle));
         }
     }
 
@@ -1676,6 +2083,24 @@ fn states_and_params_read_and_pg_init(scope: Scope) -> proc_macro2::TokenStream
                 )
                 .await?;
 
+                // Params specs of items removed from the flow are read before the
+                // params specs file is rewritten with only the flow's items.
+                let states_prune = match states_prune {
+                    Some(item_graph_removed) => {
+                        let params_specs_removed =
+                            peace_rt_model::StatesPruner::params_specs_deserialize(
+                                &profile,
+                                flow_id,
+                                &flow_dir,
+                                storage,
+                                &item_graph_removed,
+                            )
+                            .await?;
+                        Some((item_graph_removed, params_specs_removed))
+                    }
+                    None => None,
+                };
+
                 let params_specs = crate::ctx::cmd_ctx_builder::params_specs_merge(
                     &flow,
                     params_specs_provided,
@@ -1706,6 +2131,15 @@ fn states_and_params_read_and_pg_init(scope: Scope) -> proc_macro2::TokenStream
                     resources.insert(states_current_stored);
                 }
 
+                // Stored current states from other flows in the same profile.
+                crate::ctx::cmd_ctx_builder::states_from_flows_insert(
+                    &profile_dir,
+                    storage,
+                    &states_from_flows,
+                    &mut resources,
+                )
+                .await?;
+
                 // Call each `Item`'s initialization function.
                 let resources = crate::ctx::cmd_ctx_builder::item_graph_setup(
                     item_graph,
@@ -1713,6 +2147,52 @@ fn states_and_params_read_and_pg_init(scope: Scope) -> proc_macro2::TokenStream
                 )
                 .await?;
 
+                // Validate each `Item`'s resolved params.
+                crate::ctx::cmd_ctx_builder::params_specs_validate(
+                    item_graph,
+                    &params_specs,
+                    &resources,
+                )?;
+
+                // Warn about item params that changed since current states were stored.
+                let params_digests = crate::ctx::cmd_ctx_builder::params_digests(
+                    item_graph,
+                    &params_specs,
+                    &resources,
+                )?;
+                crate::ctx::cmd_ctx_builder::params_change_check(
+                    &mut *output,
+                    storage,
+                    &flow_dir,
+                    params_change_policy,
+                    &params_digests,
+                    &resources,
+                )
+                .await?;
+                let mut resources = resources;
+                resources.insert(params_digests);
+
+                // Prune stored states of items that were removed from the flow.
+                if let Some((item_graph_removed, params_specs_removed)) = states_prune.as_ref() {
+                    let states_prune_outcome = peace_rt_model::StatesPruner::prune(
+                        flow_id,
+                        &flow_dir,
+                        storage,
+                        &states_type_reg,
+                        item_graph_removed,
+                        params_specs_removed,
+                        &mut resources,
+                    )
+                    .await?;
+                    if !states_prune_outcome.is_empty() {
+                        peace_rt_model::output::OutputWrite::present(
+                            &mut *output,
+                            &states_prune_outcome,
+                        )
+                        .await?;
+                    }
+                }
+
                 // output_progress CmdProgressTracker initialization
                 #[cfg(feature = "output_progress")]
                 let cmd_progress_tracker = {
@@ -1730,7 +2210,15 @@ fn states_and_params_read_and_pg_init(scope: Scope) -> proc_macro2::TokenStream
                     );
 
                     peace_rt_model::CmdProgressTracker::new(multi_progress, progress_trackers)
+                        .with_item_groups(flow.item_groups().clone())
                 };
+
+                #[cfg(feature = "telemetry")]
+                let cmd_metrics = peace_rt_model::CmdMetrics::new(
+                    meter.as_ref(),
+                    flow_id,
+                    &profile,
+                );
             }
         }
     }
@@ -1769,6 +2257,7 @@ fn resources_insert(scope: Scope) -> proc_macro2::TokenStream {
                     resources.insert(profile.clone());
                     resources.insert(flow_dir.clone());
                     resources.insert(flow.flow_id().clone());
+                    resources.insert(items_disabled);
                 }
             }
         }
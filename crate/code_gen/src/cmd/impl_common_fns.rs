@@ -19,6 +19,10 @@ pub fn impl_common_fns(scope_struct: &ScopeStruct) -> proc_macro2::TokenStream {
             let crate::ctx::CmdCtxBuilder {
                 output,
                 interruptibility: _,
+                tracing_dispatch,
+                message_catalog,
+                #[cfg(feature = "telemetry")]
+                meter,
                 workspace,
                 scope_builder,
             } = self;
@@ -26,10 +30,84 @@ pub fn impl_common_fns(scope_struct: &ScopeStruct) -> proc_macro2::TokenStream {
             crate::ctx::CmdCtxBuilder {
                 output,
                 interruptibility,
+                tracing_dispatch,
+                message_catalog,
+                #[cfg(feature = "telemetry")]
+                meter,
                 workspace,
                 scope_builder,
             }
         }
+
+        /// Sets the `tracing` subscriber to record spans to while `CmdExecution`s run.
+        ///
+        /// Spans are recorded for each command execution, `CmdBlock`, and item
+        /// function, with the flow ID, profile, `CmdBlock` index, and item ID as
+        /// fields. The subscriber is only used for the built command context, and
+        /// is not set as the global default.
+        pub fn with_tracing_subscriber<S>(
+            mut self,
+            subscriber: S,
+        ) -> #return_type
+        where
+            S: tracing::Subscriber + Send + Sync + 'static,
+        {
+            self.tracing_dispatch = Some(tracing::Dispatch::new(subscriber));
+            self
+        }
+
+        /// Sets the catalog that user-facing messages are rendered through.
+        ///
+        /// The catalog is passed to the `OutputWrite`, and inserted into
+        /// `Resources` for `CmdBlock`s to render progress messages with. The
+        /// catalog's locale is kept unless a locale is selected with
+        /// [`with_locale`].
+        ///
+        /// [`with_locale`]: Self::with_locale
+        pub fn with_message_catalog(
+            mut self,
+            message_catalog: peace_fmt::MessageCatalog,
+        ) -> #return_type {
+            let locale = self.message_catalog.locale().to_string();
+            self.message_catalog = if locale == peace_fmt::MessageCatalog::LOCALE_DEFAULT {
+                message_catalog
+            } else {
+                message_catalog.with_locale(locale)
+            };
+            self
+        }
+
+        /// Selects the locale that user-facing messages are rendered in, e.g.
+        /// `"fr-FR"`.
+        ///
+        /// Messages without a translation in the locale are rendered in
+        /// English.
+        pub fn with_locale(
+            mut self,
+            locale: impl Into<String>,
+        ) -> #return_type {
+            self.message_catalog = self.message_catalog.with_locale(locale);
+            self
+        }
+
+        /// Sets the OpenTelemetry meter provider to record metrics to while
+        /// `CmdExecution`s run.
+        ///
+        /// Counts of items applied, failed, and skipped, and the durations of
+        /// each item's apply and state discovery are recorded, with the flow ID,
+        /// profile, and item ID as attributes. Metrics are exported by the
+        /// exporters configured on the meter provider.
+        #[cfg(feature = "telemetry")]
+        pub fn with_meter_provider<P>(
+            mut self,
+            meter_provider: &P,
+        ) -> #return_type
+        where
+            P: opentelemetry::metrics::MeterProvider + ?Sized,
+        {
+            self.meter = Some(meter_provider.meter(peace_rt_model::CmdMetrics::METER_NAME));
+            self
+        }
     };
 
     if scope.flow_count() == FlowCount::One {
@@ -49,9 +127,312 @@ pub fn impl_common_fns(scope_struct: &ScopeStruct) -> proc_macro2::TokenStream {
                 self.scope_builder.params_specs_provided.insert(item_id, params_spec);
                 self
             }
+
+            /// Sets the parameters of each instance of an item added with
+            /// [`ItemGraphBuilder::add_fn_for_each`].
+            ///
+            /// Each instance's ID is derived from `item_id` and the instance
+            /// key using [`ItemId::instance`].
+            ///
+            /// [`ItemGraphBuilder::add_fn_for_each`]: peace_rt_model::ItemGraphBuilder::add_fn_for_each
+            /// [`ItemId::instance`]: peace_cfg::ItemId::instance
+            pub fn with_item_params_for_each<'params, I, K, ParamsSpecsIter>(
+                mut self,
+                item_id: &peace_cfg::ItemId,
+                instance_params_specs: ParamsSpecsIter,
+            ) -> Self
+            where
+                I: peace_cfg::Item,
+                AppError: From<I::Error>,
+                K: AsRef<str>,
+                ParamsSpecsIter:
+                    IntoIterator<Item = (K, <I::Params<'params> as peace_params::Params>::Spec)>,
+            {
+                instance_params_specs
+                    .into_iter()
+                    .for_each(|(instance_key, params_spec)| {
+                        let item_id_instance = item_id.instance(instance_key.as_ref());
+                        self.scope_builder
+                            .params_specs_provided
+                            .insert(item_id_instance, params_spec);
+                    });
+                self
+            }
         });
     };
 
+    if scope.states_from_flows_supported() {
+        common_fns.extend(quote! {
+            /// Loads the stored current states of another flow in the same
+            /// profile into `Resources`.
+            ///
+            /// This allows item params in this command's flow to be mapped from
+            /// states produced by items in the other flow. States are read from
+            /// the other flow's `StatesCurrentFile`, and are inserted by their
+            /// `State` type, so mapping functions may borrow them directly.
+            ///
+            /// If the other flow has not stored any current states, nothing is
+            /// inserted.
+            pub fn with_states_from_flow(
+                mut self,
+                flow: &peace_rt_model::Flow<AppError>,
+            ) -> Self {
+                self.scope_builder.states_from_flows.push(flow.clone());
+                self
+            }
+        });
+    }
+
+    if scope.params_change_policy_supported() {
+        common_fns.extend(quote! {
+            /// Sets how to handle item params that changed since the items'
+            /// current states were stored.
+            ///
+            /// Defaults to [`ParamsChangePolicy::Warn`], which presents the
+            /// affected item IDs to the output.
+            ///
+            /// [`ParamsChangePolicy::Warn`]: peace_rt_model::ParamsChangePolicy::Warn
+            pub fn with_params_change_policy(
+                mut self,
+                params_change_policy: peace_rt_model::ParamsChangePolicy,
+            ) -> Self {
+                self.scope_builder.params_change_policy = params_change_policy;
+                self
+            }
+        });
+    }
+
+    if scope.params_at_supported() {
+        common_fns.extend(quote! {
+            /// Loads profile and flow params from a revision in the profile's
+            /// params history, instead of the current params files.
+            ///
+            /// This is used to reproduce a previous run. Params provided to
+            /// the builder still take precedence over the revision's params,
+            /// and the current params files are not overwritten.
+            ///
+            /// Workspace params are not loaded from the revision, as they are
+            /// shared by all profiles, and are read before the profile is
+            /// known.
+            ///
+            /// Revisions are listed by `ParamsHistoryCmd::list`.
+            pub fn with_params_at(mut self, revision: u64) -> Self {
+                self.scope_builder.params_at = Some(revision);
+                self
+            }
+        });
+    }
+
+    if scope.apply_hooks_supported() {
+        common_fns.extend(quote! {
+            /// Adds a hook that is invoked before each item is applied.
+            ///
+            /// The hook receives the item ID and the item's state diff. If it
+            /// returns an error, the item is not applied, and the error is
+            /// returned as the item's apply error.
+            ///
+            /// Hooks are invoked for both ensure and clean, but not for dry
+            /// runs.
+            pub fn with_pre_apply_hook<F>(mut self, f: F) -> Self
+            where
+                F: Fn(
+                    &peace_cfg::ItemId,
+                    &peace_resources::type_reg::untagged::BoxDtDisplay,
+                ) -> Result<(), AppError>
+                    + Send
+                    + Sync
+                    + 'static,
+            {
+                self.scope_builder
+                    .apply_hooks
+                    .pre_apply_hook_push(peace_rt_model::PreApplyHook::new(f));
+                self
+            }
+
+            /// Adds a hook that is invoked after each item is applied.
+            ///
+            /// The hook receives the item ID, the item's state diff, and the
+            /// apply outcome. It is not invoked for items that did not need to
+            /// be applied, were not approved, or were prevented by a pre apply
+            /// hook.
+            ///
+            /// Hooks are invoked for both ensure and clean, but not for dry
+            /// runs.
+            pub fn with_post_apply_hook<F>(mut self, f: F) -> Self
+            where
+                F: Fn(
+                    &peace_cfg::ItemId,
+                    &peace_resources::type_reg::untagged::BoxDtDisplay,
+                    &peace_rt_model::ApplyHookOutcome<'_, AppError>,
+                )
+                    + Send
+                    + Sync
+                    + 'static,
+            {
+                self.scope_builder
+                    .apply_hooks
+                    .post_apply_hook_push(peace_rt_model::PostApplyHook::new(f));
+                self
+            }
+        });
+    }
+
+    if scope.approval_policy_supported() {
+        common_fns.extend(quote! {
+            /// Sets which items require approval before they are applied.
+            ///
+            /// Items that require approval wait for the policy's approver to
+            /// approve the change, or fail to be applied if there is no
+            /// approver. Dry runs do not require approval.
+            pub fn with_approval_policy(
+                mut self,
+                approval_policy: peace_rt_model::ApprovalPolicy,
+            ) -> Self {
+                self.scope_builder.approval_policy = approval_policy;
+                self
+            }
+        });
+    }
+
+    if scope.heartbeat_policy_supported() {
+        common_fns.extend(quote! {
+            /// Sets how long items may go without sending progress while they
+            /// are applied.
+            ///
+            /// Items that do not send a progress update within the interval
+            /// are marked as stalled, and are cancelled if the policy
+            /// requires it. Dry runs are not checked.
+            pub fn with_heartbeat_policy(
+                mut self,
+                heartbeat_policy: peace_rt_model::HeartbeatPolicy,
+            ) -> Self {
+                self.scope_builder.heartbeat_policy = heartbeat_policy;
+                self
+            }
+        });
+    }
+
+    if scope.items_disabled_supported() {
+        common_fns.extend(quote! {
+            /// Sets the items that are disabled for the profile.
+            ///
+            /// Disabled items are skipped when discovering and applying
+            /// states. These are stored in the profile directory, and the
+            /// stored items are used when this is not called.
+            ///
+            /// Pass an empty [`ItemsDisabled`] to enable all items.
+            ///
+            /// [`ItemsDisabled`]: peace_rt_model::ItemsDisabled
+            pub fn with_items_disabled(
+                mut self,
+                items_disabled: peace_rt_model::ItemsDisabled,
+            ) -> Self {
+                self.scope_builder.items_disabled = Some(items_disabled);
+                self
+            }
+        });
+    }
+
+    if scope.item_executor_supported() {
+        common_fns.extend(quote! {
+            /// Sets how items are run concurrently within command blocks.
+            ///
+            /// Defaults to [`ItemExecutorConcurrent`], which runs up to
+            /// [`CONCURRENCY_MAX_DEFAULT`] items at a time.
+            ///
+            /// [`ItemExecutorConcurrent`]: peace_rt_model::ItemExecutorConcurrent
+            /// [`CONCURRENCY_MAX_DEFAULT`]: peace_rt_model::ItemExecutorConcurrent::CONCURRENCY_MAX_DEFAULT
+            pub fn with_item_executor<IE>(mut self, item_executor: IE) -> Self
+            where
+                IE: peace_rt_model::ItemExecutor,
+            {
+                self.scope_builder.item_executor = std::sync::Arc::new(item_executor);
+                self
+            }
+
+            /// Sets the maximum number of items to run concurrently.
+            ///
+            /// This overrides the limit of the item executor. Use `1` to run
+            /// items one at a time, which is useful when debugging.
+            ///
+            /// A `max_concurrency` of `0` is treated as `1`.
+            pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+                self.scope_builder.max_concurrency = Some(max_concurrency);
+                self
+            }
+        });
+    }
+
+    if scope.states_prune_supported() {
+        common_fns.extend(quote! {
+            /// Prunes stored states of items that were removed from the flow
+            /// when the command context is built.
+            ///
+            /// Each removed item in `item_graph_removed` that has a stored
+            /// state is discovered using its stored params spec. Its stored
+            /// states are removed if its current state is its clean state,
+            /// otherwise the item ID is presented to the output as a warning.
+            ///
+            /// See [`StatesPruner`] for details.
+            ///
+            /// [`StatesPruner`]: peace_rt_model::StatesPruner
+            pub fn with_states_prune(
+                mut self,
+                item_graph_removed: &peace_rt_model::ItemGraph<AppError>,
+            ) -> Self {
+                self.scope_builder.states_prune = Some(item_graph_removed.clone());
+                self
+            }
+        });
+    }
+
+    if scope.execution_deadline_supported() {
+        common_fns.extend(quote! {
+            /// Sets the instant by which `CmdExecution`s should complete.
+            ///
+            /// When the deadline is reached, the command execution is
+            /// interrupted: no new items are started, and items that are in
+            /// progress may finish until the deadline's grace period elapses.
+            /// `CmdBlock`s still running after that are cancelled, and the
+            /// execution returns an `ExecutionDeadlineExceeded` error.
+            ///
+            /// Interrupted outcomes are marked as deadline exceeded, see
+            /// `CmdOutcome::is_deadline_exceeded`.
+            ///
+            /// An `Instant` may be passed in to use the default grace period.
+            /// The deadline is not enforced on `wasm32`.
+            pub fn with_execution_deadline<D>(mut self, execution_deadline: D) -> Self
+            where
+                D: Into<peace_rt_model::ExecutionDeadline>,
+            {
+                self.scope_builder.execution_deadline = Some(execution_deadline.into());
+                self
+            }
+        });
+    }
+
+    if scope.interrupt_handle_supported() {
+        common_fns.extend(quote! {
+            /// Makes `CmdExecution`s interruptible through an `InterruptHandle`.
+            ///
+            /// The handle is returned by `CmdCtx::interrupt_handle`, and may be
+            /// cloned and sent to another task, such as a GUI or server request
+            /// handler, to interrupt an in-flight execution.
+            ///
+            /// This replaces the interruptibility set by `with_interruptibility`.
+            pub fn with_interrupt_handle(
+                mut self,
+                interrupt_strategy: interruptible::InterruptStrategy,
+            ) -> Self {
+                let (interrupt_handle, interruptibility) =
+                    crate::ctx::InterruptHandle::new(interrupt_strategy);
+                self.interruptibility = interruptibility;
+                self.scope_builder.interrupt_handle = Some(interrupt_handle);
+                self
+            }
+        });
+    }
+
     let builder_type = CmdCtxBuilderTypeBuilder::new(scope_builder_name.clone()).build();
     let impl_header = ImplHeaderBuilder::new(builder_type).build();
 
@@ -16,11 +16,59 @@ pub fn impl_constructor(scope_struct: &ScopeStruct) -> proc_macro2::TokenStream
         scope_field_values::profile_and_flow_selection_push(&mut type_params, scope);
         scope_field_values::params_selection_push(&mut type_params, scope);
 
+        type_params.push(parse_quote!(
+            params_files_provided: peace_rt_model::params::ParamsFilesProvided::new()
+        ));
+
         if scope.flow_count() == FlowCount::One {
             type_params.push(parse_quote!(
                 params_specs_provided: peace_params::ParamsSpecs::new()
             ));
         }
+        if scope.states_from_flows_supported() {
+            type_params.push(parse_quote!(states_from_flows: Vec::new()));
+        }
+        if scope.apply_hooks_supported() {
+            type_params.push(parse_quote!(apply_hooks: peace_rt_model::ApplyHooks::new()));
+        }
+        if scope.approval_policy_supported() {
+            type_params.push(parse_quote!(
+                approval_policy: peace_rt_model::ApprovalPolicy::new()
+            ));
+        }
+        if scope.heartbeat_policy_supported() {
+            type_params.push(parse_quote!(
+                heartbeat_policy: peace_rt_model::HeartbeatPolicy::new()
+            ));
+        }
+        if scope.items_disabled_supported() {
+            type_params.push(parse_quote!(items_disabled: None));
+        }
+        if scope.item_executor_supported() {
+            type_params.push(parse_quote!(
+                item_executor: std::sync::Arc::new(
+                    peace_rt_model::ItemExecutorConcurrent::default()
+                )
+            ));
+            type_params.push(parse_quote!(max_concurrency: None));
+        }
+        if scope.states_prune_supported() {
+            type_params.push(parse_quote!(states_prune: None));
+        }
+        if scope.execution_deadline_supported() {
+            type_params.push(parse_quote!(execution_deadline: None));
+        }
+        if scope.interrupt_handle_supported() {
+            type_params.push(parse_quote!(interrupt_handle: None));
+        }
+        if scope.params_change_policy_supported() {
+            type_params.push(parse_quote!(
+                params_change_policy: peace_rt_model::ParamsChangePolicy::default()
+            ));
+        }
+        if scope.params_at_supported() {
+            type_params.push(parse_quote!(params_at: None));
+        }
 
         type_params
     };
@@ -100,6 +148,10 @@ pub fn impl_constructor(scope_struct: &ScopeStruct) -> proc_macro2::TokenStream
                 Self {
                     output,
                     interruptibility: interruptible::Interruptibility::NonInterruptible,
+                    tracing_dispatch: None,
+                    message_catalog: peace_fmt::MessageCatalog::new(),
+                    #[cfg(feature = "telemetry")]
+                    meter: None,
                     workspace,
                     scope_builder,
                 }
@@ -8,8 +8,9 @@ use crate::cmd::{
 
 /// Generates the `CmdCtxBuilder::*_params_merge` methods for each params type.
 ///
-/// The generated method attempts to load params from storage, and if it is
-/// present, merges it with the params passed to the command context builder.
+/// The generated method attempts to load params from the user supplied params
+/// file and from storage, and if they are present, merges them with the params
+/// passed to the command context builder.
 pub fn impl_params_merge(scope_struct: &ScopeStruct) -> proc_macro2::TokenStream {
     ParamsScope::iter().fold(
         proc_macro2::TokenStream::new(),
@@ -45,6 +46,7 @@ fn impl_params_merge_for(
     let params_file_name = params_scope.params_file_name();
     let params_file_type = params_scope.params_file_type();
     let params_selection_name = params_scope.params_selection_name();
+    let params_file_provided_method_name = params_scope.params_file_provided_method_name();
 
     let doc_summary = {
         let params_scope_str = params_scope.to_str();
@@ -85,6 +87,38 @@ fn impl_params_merge_for(
                 #params_file_name: &peace_resources::internal::#params_file_type,
             ) -> Result<(), peace_rt_model::Error> {
                 let storage = self.workspace.storage();
+
+                // Params from the user supplied params file are used where the
+                // caller did not provide a value.
+                #[cfg(not(target_arch = "wasm32"))]
+                if let Some(params_file_provided) = self
+                    .scope_builder
+                    .params_files_provided
+                    // .workspace_params_file()
+                    .#params_file_provided_method_name()
+                {
+                    let params_from_file = peace_rt_model::ParamsFileDeserializer::deserialize::<
+                        // WorkspaceParamsK,
+                        #p_keys_key_maybe_key
+                    >(
+                        storage,
+                        // self.scope_builder.params_type_regs_builder.workspace_params_type_reg(),
+                        self.scope_builder.params_type_regs_builder.#params_type_reg_method_name(),
+                        params_file_provided,
+                    )
+                    .await?;
+
+                    let params = &mut self.scope_builder.#params_selection_name.0;
+                    params_from_file
+                        .into_inner()
+                        .into_iter()
+                        .for_each(|(key, param)| {
+                            if !params.contains_key(&key) {
+                                params.insert_raw(key, param);
+                            }
+                        });
+                }
+
                 let params_deserialized = peace_rt_model::WorkspaceInitializer::#params_deserialize_method_name::<
                     // WorkspaceParamsK,
                     #p_keys_key_maybe_key
@@ -81,6 +81,10 @@ pub fn impl_with_flow(scope_struct: &ScopeStruct) -> proc_macro2::TokenStream {
                 let Self {
                     output,
                     interruptibility,
+                    tracing_dispatch,
+                    message_catalog,
+                    #[cfg(feature = "telemetry")]
+                    meter,
                     workspace,
                     scope_builder:
                         #scope_builder_name {
@@ -109,6 +113,10 @@ pub fn impl_with_flow(scope_struct: &ScopeStruct) -> proc_macro2::TokenStream {
                 crate::ctx::CmdCtxBuilder {
                     output,
                     interruptibility,
+                    tracing_dispatch,
+                    message_catalog,
+                    #[cfg(feature = "telemetry")]
+                    meter,
                     workspace,
                     scope_builder,
                 }
@@ -131,9 +139,46 @@ fn scope_builder_fields_flow_not_selected(scope: Scope) -> Punctuated<FieldValue
     if scope.flow_params_supported() {
         field_values.push(parse_quote!(flow_params_selection));
     }
+    field_values.push(parse_quote!(params_files_provided));
     if scope.flow_count() == FlowCount::One {
         field_values.push(parse_quote!(params_specs_provided));
     }
+    if scope.states_from_flows_supported() {
+        field_values.push(parse_quote!(states_from_flows));
+    }
+    if scope.apply_hooks_supported() {
+        field_values.push(parse_quote!(apply_hooks));
+    }
+    if scope.approval_policy_supported() {
+        field_values.push(parse_quote!(approval_policy));
+    }
+    if scope.heartbeat_policy_supported() {
+        field_values.push(parse_quote!(heartbeat_policy));
+    }
+    if scope.items_disabled_supported() {
+        field_values.push(parse_quote!(items_disabled));
+    }
+    if scope.item_executor_supported() {
+        field_values.push(parse_quote!(item_executor));
+    }
+    if scope.item_executor_supported() {
+        field_values.push(parse_quote!(max_concurrency));
+    }
+    if scope.states_prune_supported() {
+        field_values.push(parse_quote!(states_prune));
+    }
+    if scope.execution_deadline_supported() {
+        field_values.push(parse_quote!(execution_deadline));
+    }
+    if scope.interrupt_handle_supported() {
+        field_values.push(parse_quote!(interrupt_handle));
+    }
+    if scope.params_change_policy_supported() {
+        field_values.push(parse_quote!(params_change_policy));
+    }
+    if scope.params_at_supported() {
+        field_values.push(parse_quote!(params_at));
+    }
 
     field_values
 }
@@ -152,9 +197,46 @@ fn scope_builder_fields_flow_selected(scope: Scope) -> Punctuated<FieldValue, Co
     if scope.flow_params_supported() {
         field_values.push(parse_quote!(flow_params_selection));
     }
+    field_values.push(parse_quote!(params_files_provided));
     if scope.flow_count() == FlowCount::One {
         field_values.push(parse_quote!(params_specs_provided));
     }
+    if scope.states_from_flows_supported() {
+        field_values.push(parse_quote!(states_from_flows));
+    }
+    if scope.apply_hooks_supported() {
+        field_values.push(parse_quote!(apply_hooks));
+    }
+    if scope.approval_policy_supported() {
+        field_values.push(parse_quote!(approval_policy));
+    }
+    if scope.heartbeat_policy_supported() {
+        field_values.push(parse_quote!(heartbeat_policy));
+    }
+    if scope.items_disabled_supported() {
+        field_values.push(parse_quote!(items_disabled));
+    }
+    if scope.item_executor_supported() {
+        field_values.push(parse_quote!(item_executor));
+    }
+    if scope.item_executor_supported() {
+        field_values.push(parse_quote!(max_concurrency));
+    }
+    if scope.states_prune_supported() {
+        field_values.push(parse_quote!(states_prune));
+    }
+    if scope.execution_deadline_supported() {
+        field_values.push(parse_quote!(execution_deadline));
+    }
+    if scope.interrupt_handle_supported() {
+        field_values.push(parse_quote!(interrupt_handle));
+    }
+    if scope.params_change_policy_supported() {
+        field_values.push(parse_quote!(params_change_policy));
+    }
+    if scope.params_at_supported() {
+        field_values.push(parse_quote!(params_at));
+    }
 
     field_values
 }
@@ -187,6 +187,10 @@ fn impl_with_param_key_unknown(
                 let Self {
                     output,
                     interruptibility,
+                    tracing_dispatch,
+                    message_catalog,
+                    #[cfg(feature = "telemetry")]
+                    meter,
                     workspace,
                     scope_builder:
                         #scope_builder_name {
@@ -230,6 +234,10 @@ fn impl_with_param_key_unknown(
                 crate::ctx::CmdCtxBuilder {
                     output,
                     interruptibility,
+                    tracing_dispatch,
+                    message_catalog,
+                    #[cfg(feature = "telemetry")]
+                    meter,
                     workspace,
                     scope_builder,
                 }
@@ -345,6 +353,10 @@ fn impl_with_param_key_known(
                 let Self {
                     output,
                     interruptibility,
+                    tracing_dispatch,
+                    message_catalog,
+                    #[cfg(feature = "telemetry")]
+                    meter,
                     workspace,
                     scope_builder:
                         #scope_builder_name {
@@ -384,6 +396,10 @@ fn impl_with_param_key_known(
                 crate::ctx::CmdCtxBuilder {
                     output,
                     interruptibility,
+                    tracing_dispatch,
+                    message_catalog,
+                    #[cfg(feature = "telemetry")]
+                    meter,
                     workspace,
                     scope_builder,
                 }
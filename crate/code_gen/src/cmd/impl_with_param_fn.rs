@@ -0,0 +1,186 @@
+use quote::quote;
+use syn::parse_quote;
+
+use crate::cmd::{
+    scope_builder_fields, with_params::cmd_ctx_builder_with_params_selected,
+    CmdCtxBuilderTypeBuilder, ImplHeaderBuilder, ParamsScope, ScopeStruct,
+};
+
+/// Generates the `with_workspace_param_fn` method.
+///
+/// All scopes support workspace params, so this is generated for every scope.
+pub fn impl_with_param_fn(scope_struct: &ScopeStruct) -> proc_macro2::TokenStream {
+    let mut impl_tokens = impl_with_param_fn_key_unknown(scope_struct, ParamsScope::Workspace);
+    impl_tokens.extend(impl_with_param_fn_key_known(
+        scope_struct,
+        ParamsScope::Workspace,
+    ));
+    impl_tokens
+}
+
+fn impl_with_param_fn_key_unknown(
+    scope_struct: &ScopeStruct,
+    params_scope: ParamsScope,
+) -> proc_macro2::TokenStream {
+    let scope_builder_name = &scope_struct.item_struct().ident;
+
+    let param_type_param = params_scope.param_type_param();
+    let params_k_method_name = params_scope.params_k_method_name();
+    let params_k_type_param = params_scope.params_k_type_param();
+
+    let builder_type = CmdCtxBuilderTypeBuilder::new(scope_builder_name.clone())
+        .with_workspace_params_k_maybe(parse_quote!(peace_rt_model::params::KeyUnknown))
+        .with_workspace_params_selection(parse_quote!(
+            crate::scopes::type_params::WorkspaceParamsNone
+        ))
+        .build();
+    let impl_header = ImplHeaderBuilder::new(builder_type)
+        .with_workspace_params_k_maybe(None)
+        .with_workspace_params_selection(None)
+        .build();
+    let return_type =
+        cmd_ctx_builder_with_params_selected(scope_builder_name, scope_struct, params_scope);
+
+    quote! {
+        #impl_header
+        {
+            /// Adds a workspace parameter whose value is computed when the
+            /// command context is built.
+            ///
+            /// See the `with_workspace_param_fn` method on the builder with
+            /// workspace params for how the value is computed.
+            ///
+            /// # Parameters
+            ///
+            /// * `k`: Key to store the parameter with.
+            /// * `f`: Function that computes the workspace parameter value.
+            pub fn with_workspace_param_fn<#params_k_type_param, #param_type_param, F, Fut, ErrT>(
+                self,
+                k: #params_k_type_param,
+                f: F,
+            ) -> #return_type
+            where
+                #params_k_type_param:
+                    Clone + std::fmt::Debug + Eq + std::hash::Hash + serde::de::DeserializeOwned + serde::Serialize + Send + Sync + Unpin + 'static,
+                #param_type_param: Clone + std::fmt::Debug + serde::de::DeserializeOwned + serde::Serialize + Send + Sync + 'static,
+                F: FnOnce() -> Fut + 'static,
+                Fut: std::future::Future<Output = Result<#param_type_param, ErrT>> + 'static,
+                ErrT: std::error::Error + Send + Sync + 'static,
+            {
+                // self.with_workspace_params_k::<WorkspaceParamsK>()
+                self.#params_k_method_name::<#params_k_type_param>()
+                    .with_workspace_param_fn(k, f)
+            }
+        }
+    }
+}
+
+fn impl_with_param_fn_key_known(
+    scope_struct: &ScopeStruct,
+    params_scope: ParamsScope,
+) -> proc_macro2::TokenStream {
+    let scope = scope_struct.scope();
+    let scope_builder_name = &scope_struct.item_struct().ident;
+
+    let param_type_param = params_scope.param_type_param();
+    let params_k_type_param = params_scope.params_k_type_param();
+    let params_selection_name = params_scope.params_selection_name();
+    let scope_builder_fields_params_some = scope_builder_fields::params_some(scope, params_scope);
+    let scope_builder_fields_passthrough = scope_builder_fields::passthrough(scope, params_scope);
+    let params_type_reg_method_name = params_scope.params_type_reg_mut_method_name();
+
+    let builder_type =
+        cmd_ctx_builder_with_params_selected(scope_builder_name, scope_struct, params_scope);
+    let return_type = builder_type.clone();
+    let impl_header = ImplHeaderBuilder::new(builder_type)
+        .with_workspace_params_k_maybe(None)
+        .with_workspace_params_k(Some(parse_quote!(WorkspaceParamsK)))
+        .with_workspace_params_selection(None)
+        .build();
+
+    quote! {
+        #impl_header
+        {
+            /// Adds a workspace parameter whose value is computed when the
+            /// command context is built.
+            ///
+            /// This is useful for values that are fetched asynchronously, such
+            /// as an account ID or a token. The functions for all workspace
+            /// params are run concurrently when `build()` is called, and the
+            /// computed values take precedence over values provided through
+            /// `with_workspace_param_value` and values stored from previous
+            /// executions.
+            ///
+            /// If a function returns an error, `build()` returns
+            /// `Error::WorkspaceParamFnError`, which names the key of the
+            /// failed function.
+            ///
+            /// # Parameters
+            ///
+            /// * `k`: Key to store the parameter with.
+            /// * `f`: Function that computes the workspace parameter value.
+            pub fn with_workspace_param_fn<#param_type_param, F, Fut, ErrT>(
+                self,
+                k: #params_k_type_param,
+                f: F,
+            ) -> #return_type
+            where
+                #param_type_param: Clone + std::fmt::Debug + serde::de::DeserializeOwned + serde::Serialize + Send + Sync + 'static,
+                F: FnOnce() -> Fut + 'static,
+                Fut: std::future::Future<Output = Result<#param_type_param, ErrT>> + 'static,
+                ErrT: std::error::Error + Send + Sync + 'static,
+            {
+                let Self {
+                    output,
+                    interruptibility,
+                    tracing_dispatch,
+                    message_catalog,
+                    #[cfg(feature = "telemetry")]
+                    meter,
+                    workspace,
+                    scope_builder:
+                        #scope_builder_name {
+                            // profile_selection,
+                            // flow_selection,
+                            // mut params_type_regs_builder,
+                            // mut workspace_params_selection,
+                            // profile_params_selection,
+                            // flow_params_selection,
+                            // params_specs_provided,
+
+                            #scope_builder_fields_params_some
+                        },
+                } = self;
+
+                params_type_regs_builder
+                    .#params_type_reg_method_name()
+                    .register::<#param_type_param>(k.clone());
+                // let workspace_params_fns = &mut workspace_params_selection.1;
+                #params_selection_name.1.insert(k, f);
+
+                let scope_builder = #scope_builder_name {
+                    // profile_selection,
+                    // flow_selection,
+                    // params_type_regs_builder,
+                    // workspace_params_selection,
+                    // profile_params_selection,
+                    // flow_params_selection,
+                    // params_specs_provided,
+
+                    #scope_builder_fields_passthrough
+                };
+
+                crate::ctx::CmdCtxBuilder {
+                    output,
+                    interruptibility,
+                    tracing_dispatch,
+                    message_catalog,
+                    #[cfg(feature = "telemetry")]
+                    meter,
+                    workspace,
+                    scope_builder,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use quote::quote;
+use syn::parse_quote;
+
+use crate::cmd::{
+    scope_builder_fields, with_params::cmd_ctx_builder_with_params_selected,
+    CmdCtxBuilderTypeBuilder, ImplHeaderBuilder, ParamsScope, Scope, ScopeStruct,
+};
+
+/// Generates the `with_profile_param_overlay` method.
+///
+/// Only single profile commands support layered profile params, as multi
+/// profile commands may read, but not write profile params.
+pub fn impl_with_param_overlay(scope_struct: &ScopeStruct) -> proc_macro2::TokenStream {
+    match scope_struct.scope() {
+        Scope::MultiProfileNoFlow | Scope::MultiProfileSingleFlow | Scope::NoProfileNoFlow => {
+            proc_macro2::TokenStream::new()
+        }
+        Scope::SingleProfileNoFlow | Scope::SingleProfileSingleFlow => {
+            let mut impl_tokens =
+                impl_with_param_overlay_key_unknown(scope_struct, ParamsScope::Profile);
+            impl_tokens.extend(impl_with_param_overlay_key_known(
+                scope_struct,
+                ParamsScope::Profile,
+            ));
+            impl_tokens
+        }
+    }
+}
+
+fn impl_with_param_overlay_key_unknown(
+    scope_struct: &ScopeStruct,
+    params_scope: ParamsScope,
+) -> proc_macro2::TokenStream {
+    let scope_builder_name = &scope_struct.item_struct().ident;
+
+    let param_type_param = params_scope.param_type_param();
+    let params_k_method_name = params_scope.params_k_method_name();
+    let params_k_type_param = params_scope.params_k_type_param();
+    let param_name = params_scope.param_name();
+
+    let builder_type = CmdCtxBuilderTypeBuilder::new(scope_builder_name.clone())
+        .with_profile_params_k_maybe(parse_quote!(peace_rt_model::params::KeyUnknown))
+        .with_profile_params_selection(parse_quote!(crate::scopes::type_params::ProfileParamsNone))
+        .build();
+    let impl_header = ImplHeaderBuilder::new(builder_type)
+        .with_profile_params_k_maybe(None)
+        .with_profile_params_selection(None)
+        .build();
+    let return_type =
+        cmd_ctx_builder_with_params_selected(scope_builder_name, scope_struct, params_scope);
+
+    quote! {
+        #impl_header
+        {
+            /// Adds a profile parameter value in the given layer.
+            ///
+            /// See the `with_profile_param_overlay` method on the builder with
+            /// profile params for how layers are resolved.
+            ///
+            /// # Parameters
+            ///
+            /// * `params_layer`: Layer to add the value to.
+            /// * `k`: Key to store the parameter with.
+            /// * `profile_param`: The profile parameter value.
+            pub fn with_profile_param_overlay<#params_k_type_param, #param_type_param>(
+                self,
+                params_layer: peace_rt_model::params::ParamsLayer,
+                k: #params_k_type_param,
+                #param_name: #param_type_param,
+            ) -> #return_type
+            where
+                #params_k_type_param:
+                    Clone + std::fmt::Debug + Eq + std::hash::Hash + serde::de::DeserializeOwned + serde::Serialize + Send + Sync + Unpin + 'static,
+                #param_type_param: Clone + std::fmt::Debug + serde::de::DeserializeOwned + serde::Serialize + Send + Sync + 'static,
+            {
+                // self.with_profile_params_k::<ProfileParamsK>()
+                self.#params_k_method_name::<#params_k_type_param>()
+                    .with_profile_param_overlay(params_layer, k, #param_name)
+            }
+        }
+    }
+}
+
+fn impl_with_param_overlay_key_known(
+    scope_struct: &ScopeStruct,
+    params_scope: ParamsScope,
+) -> proc_macro2::TokenStream {
+    let scope = scope_struct.scope();
+    let scope_builder_name = &scope_struct.item_struct().ident;
+
+    let param_type_param = params_scope.param_type_param();
+    let params_k_type_param = params_scope.params_k_type_param();
+    let param_name = params_scope.param_name();
+    let params_selection_name = params_scope.params_selection_name();
+    let scope_builder_fields_params_some = scope_builder_fields::params_some(scope, params_scope);
+    let scope_builder_fields_passthrough = scope_builder_fields::passthrough(scope, params_scope);
+    let params_type_reg_method_name = params_scope.params_type_reg_mut_method_name();
+
+    let builder_type =
+        cmd_ctx_builder_with_params_selected(scope_builder_name, scope_struct, params_scope);
+    let return_type = builder_type.clone();
+    let impl_header = ImplHeaderBuilder::new(builder_type)
+        .with_profile_params_k_maybe(None)
+        .with_profile_params_k(Some(parse_quote!(ProfileParamsK)))
+        .with_profile_params_selection(None)
+        .build();
+
+    quote! {
+        #impl_header
+        {
+            /// Adds a profile parameter value in the given layer.
+            ///
+            /// When a profile parameter has a value in multiple layers, the value
+            /// from the layer with the highest precedence is used:
+            ///
+            /// 1. `ParamsLayer::Workspace`: Defaults, used when the profile has no
+            ///    value for the parameter. These are not stored.
+            /// 2. `ParamsLayer::Profile`: Equivalent to `with_profile_param_value`,
+            ///    and stored in the profile params file.
+            /// 3. `ParamsLayer::Override`: Values for this command only, such as
+            ///    command line arguments. These are not stored.
+            ///
+            /// The layer each resolved value came from is available through
+            /// `profile_params_resolution()` on the built command context's scope.
+            ///
+            /// # Parameters
+            ///
+            /// * `params_layer`: Layer to add the value to.
+            /// * `k`: Key to store the parameter with.
+            /// * `profile_param`: The profile parameter value.
+            pub fn with_profile_param_overlay<#param_type_param>(
+                self,
+                params_layer: peace_rt_model::params::ParamsLayer,
+                k: #params_k_type_param,
+                #param_name: #param_type_param,
+            ) -> #return_type
+            where
+                #param_type_param: Clone + std::fmt::Debug + serde::de::DeserializeOwned + serde::Serialize + Send + Sync + 'static,
+            {
+                let Self {
+                    output,
+                    interruptibility,
+                    tracing_dispatch,
+                    message_catalog,
+                    #[cfg(feature = "telemetry")]
+                    meter,
+                    workspace,
+                    scope_builder:
+                        #scope_builder_name {
+                            // profile_selection,
+                            // flow_selection,
+                            // mut params_type_regs_builder,
+                            // workspace_params_selection,
+                            // mut profile_params_selection,
+                            // flow_params_selection,
+                            // params_specs_provided,
+
+                            #scope_builder_fields_params_some
+                        },
+                } = self;
+
+                params_type_regs_builder
+                    .#params_type_reg_method_name()
+                    .register::<#param_type_param>(k.clone());
+                match params_layer {
+                    peace_rt_model::params::ParamsLayer::Profile => {
+                        // let profile_params = &mut profile_params_selection.0;
+                        #params_selection_name.0.insert(k, #param_name);
+                    }
+                    peace_rt_model::params::ParamsLayer::Workspace
+                    | peace_rt_model::params::ParamsLayer::Override => {
+                        // let profile_params_overlays = &mut profile_params_selection.1;
+                        #params_selection_name.1.insert(params_layer, k, #param_name);
+                    }
+                }
+
+                let scope_builder = #scope_builder_name {
+                    // profile_selection,
+                    // flow_selection,
+                    // params_type_regs_builder,
+                    // workspace_params_selection,
+                    // profile_params_selection,
+                    // flow_params_selection,
+                    // params_specs_provided,
+
+                    #scope_builder_fields_passthrough
+                };
+
+                crate::ctx::CmdCtxBuilder {
+                    output,
+                    interruptibility,
+                    tracing_dispatch,
+                    message_catalog,
+                    #[cfg(feature = "telemetry")]
+                    meter,
+                    workspace,
+                    scope_builder,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use quote::quote;
+use syn::parse_quote;
+
+use crate::cmd::{
+    with_params::cmd_ctx_builder_with_params_selected, ImplHeaderBuilder, ParamsScope,
+    ProfileCount, ScopeStruct,
+};
+
+/// Generates the `with_workspace_params_file` / `with_profile_params_file` /
+/// `with_flow_params_file` methods.
+///
+/// These are only generated for params that are merged with stored params, as
+/// multi profile commands may read, but not write profile and flow params.
+pub fn impl_with_params_file(scope_struct: &ScopeStruct) -> proc_macro2::TokenStream {
+    ParamsScope::iter().fold(
+        proc_macro2::TokenStream::new(),
+        |mut impl_tokens, params_scope| {
+            let scope = scope_struct.scope();
+            if ((scope.profile_count() == ProfileCount::Multiple
+                && matches!(params_scope, ParamsScope::Profile | ParamsScope::Flow))
+                || !scope.profile_params_supported() && params_scope == ParamsScope::Profile)
+                || (!scope.flow_params_supported() && params_scope == ParamsScope::Flow)
+            {
+                // Skip `with_*_params_file` implementation if it is not supported.
+                return impl_tokens;
+            }
+
+            impl_tokens.extend(impl_with_params_file_for(scope_struct, params_scope));
+
+            impl_tokens
+        },
+    )
+}
+
+fn impl_with_params_file_for(
+    scope_struct: &ScopeStruct,
+    params_scope: ParamsScope,
+) -> proc_macro2::TokenStream {
+    let scope_builder_name = &scope_struct.item_struct().ident;
+
+    let with_params_file_method_name = params_scope.with_params_file_method_name();
+    let set_params_file_provided_method_name = params_scope.set_params_file_provided_method_name();
+
+    let params_scope_str = params_scope.to_str();
+    let doc_summary = format!(
+        "Reads {params_scope_str} params from the given YAML or JSON file when the command \
+        context is built."
+    );
+    let doc_body = {
+        let with_param_value_method_name = params_scope.with_param_value_method_name();
+        let with_param_method_name = params_scope.with_param_method_name();
+        format!(
+            "The file is a map of {params_scope_str} params key to value. The type of each \
+            key in the file must be registered using `{with_param_value_method_name}` or \
+            `{with_param_method_name}`.\n\
+            \n\
+            Values provided in code take precedence over values in the file, which take \
+            precedence over stored values. Values from the file are stored when the command \
+            context is built."
+        )
+    };
+
+    let builder_type =
+        cmd_ctx_builder_with_params_selected(scope_builder_name, scope_struct, params_scope);
+    let impl_header = {
+        let impl_header_builder = ImplHeaderBuilder::new(builder_type);
+        match params_scope {
+            ParamsScope::Workspace => impl_header_builder
+                .with_workspace_params_k_maybe(None)
+                .with_workspace_params_k(Some(parse_quote!(WorkspaceParamsK)))
+                .with_workspace_params_selection(None),
+            ParamsScope::Profile => impl_header_builder
+                .with_profile_params_k_maybe(None)
+                .with_profile_params_k(Some(parse_quote!(ProfileParamsK)))
+                .with_profile_params_selection(None),
+            ParamsScope::Flow => impl_header_builder
+                .with_flow_params_k_maybe(None)
+                .with_flow_params_k(Some(parse_quote!(FlowParamsK)))
+                .with_flow_params_selection(None),
+        }
+        .build()
+    };
+
+    quote! {
+        #impl_header
+        {
+            #[doc = #doc_summary]
+            ///
+            #[doc = #doc_body]
+            ///
+            /// # Parameters
+            ///
+            /// * `params_file`: Path to the params file.
+            #[cfg(not(target_arch = "wasm32"))]
+            // pub fn with_workspace_params_file<P>
+            pub fn #with_params_file_method_name<P>(mut self, params_file: P) -> Self
+            where
+                P: Into<std::path::PathBuf>,
+            {
+                self.scope_builder
+                    .params_files_provided
+                    // .set_workspace_params_file(params_file.into());
+                    .#set_params_file_provided_method_name(params_file.into());
+
+                self
+            }
+        }
+    }
+}
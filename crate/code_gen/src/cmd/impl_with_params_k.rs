@@ -172,6 +172,10 @@ fn impl_with_params_k_key_unknown(
                 let Self {
                     output,
                     interruptibility,
+                    tracing_dispatch,
+                    message_catalog,
+                    #[cfg(feature = "telemetry")]
+                    meter,
                     workspace,
                     scope_builder:
                         #scope_builder_name {
@@ -208,6 +212,10 @@ fn impl_with_params_k_key_unknown(
                 crate::ctx::CmdCtxBuilder {
                     output,
                     interruptibility,
+                    tracing_dispatch,
+                    message_catalog,
+                    #[cfg(feature = "telemetry")]
+                    meter,
                     workspace,
                     scope_builder,
                 }
@@ -282,6 +290,10 @@ fn impl_with_param_key_known(
                 let Self {
                     output,
                     interruptibility,
+                    tracing_dispatch,
+                    message_catalog,
+                    #[cfg(feature = "telemetry")]
+                    meter,
                     workspace,
                     scope_builder:
                         #scope_builder_name {
@@ -314,6 +326,10 @@ fn impl_with_param_key_known(
                 crate::ctx::CmdCtxBuilder {
                     output,
                     interruptibility,
+                    tracing_dispatch,
+                    message_catalog,
+                    #[cfg(feature = "telemetry")]
+                    meter,
                     workspace,
                     scope_builder,
                 }
@@ -78,6 +78,10 @@ pub fn impl_with_profile(scope_struct: &ScopeStruct) -> proc_macro2::TokenStream
                 let Self {
                     output,
                     interruptibility,
+                    tracing_dispatch,
+                    message_catalog,
+                    #[cfg(feature = "telemetry")]
+                    meter,
                     workspace,
                     scope_builder:
                         #scope_builder_name {
@@ -106,6 +110,10 @@ pub fn impl_with_profile(scope_struct: &ScopeStruct) -> proc_macro2::TokenStream
                 crate::ctx::CmdCtxBuilder {
                     output,
                     interruptibility,
+                    tracing_dispatch,
+                    message_catalog,
+                    #[cfg(feature = "telemetry")]
+                    meter,
                     workspace,
                     scope_builder,
                 }
@@ -206,6 +214,10 @@ pub fn impl_with_profile_from_workspace_param(
                 let Self {
                     output,
                     interruptibility,
+                    tracing_dispatch,
+                    message_catalog,
+                    #[cfg(feature = "telemetry")]
+                    meter,
                     workspace,
                     scope_builder:
                         #scope_builder_name {
@@ -234,6 +246,10 @@ pub fn impl_with_profile_from_workspace_param(
                 crate::ctx::CmdCtxBuilder {
                     output,
                     interruptibility,
+                    tracing_dispatch,
+                    message_catalog,
+                    #[cfg(feature = "telemetry")]
+                    meter,
                     workspace,
                     scope_builder,
                 }
@@ -287,7 +303,44 @@ fn scope_builder_fields_remainder_push(
     if scope.flow_params_supported() {
         field_values.push(parse_quote!(flow_params_selection));
     }
+    field_values.push(parse_quote!(params_files_provided));
     if scope.flow_count() == FlowCount::One {
         field_values.push(parse_quote!(params_specs_provided));
     }
+    if scope.states_from_flows_supported() {
+        field_values.push(parse_quote!(states_from_flows));
+    }
+    if scope.apply_hooks_supported() {
+        field_values.push(parse_quote!(apply_hooks));
+    }
+    if scope.approval_policy_supported() {
+        field_values.push(parse_quote!(approval_policy));
+    }
+    if scope.heartbeat_policy_supported() {
+        field_values.push(parse_quote!(heartbeat_policy));
+    }
+    if scope.items_disabled_supported() {
+        field_values.push(parse_quote!(items_disabled));
+    }
+    if scope.item_executor_supported() {
+        field_values.push(parse_quote!(item_executor));
+    }
+    if scope.item_executor_supported() {
+        field_values.push(parse_quote!(max_concurrency));
+    }
+    if scope.states_prune_supported() {
+        field_values.push(parse_quote!(states_prune));
+    }
+    if scope.execution_deadline_supported() {
+        field_values.push(parse_quote!(execution_deadline));
+    }
+    if scope.interrupt_handle_supported() {
+        field_values.push(parse_quote!(interrupt_handle));
+    }
+    if scope.params_change_policy_supported() {
+        field_values.push(parse_quote!(params_change_policy));
+    }
+    if scope.params_at_supported() {
+        field_values.push(parse_quote!(params_at));
+    }
 }
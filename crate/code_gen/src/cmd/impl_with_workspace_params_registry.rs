@@ -0,0 +1,175 @@
+use quote::quote;
+use syn::parse_quote;
+
+use crate::cmd::{
+    scope_builder_fields, with_params::cmd_ctx_builder_with_params_selected,
+    CmdCtxBuilderTypeBuilder, ImplHeaderBuilder, ParamsScope, ScopeStruct,
+};
+
+/// Generates the `with_workspace_params_registry` method.
+///
+/// All scopes support workspace params, so this is generated for every scope.
+pub fn impl_with_workspace_params_registry(scope_struct: &ScopeStruct) -> proc_macro2::TokenStream {
+    let mut impl_tokens = impl_with_workspace_params_registry_key_unknown(scope_struct);
+    impl_tokens.extend(impl_with_workspace_params_registry_key_known(scope_struct));
+    impl_tokens
+}
+
+fn impl_with_workspace_params_registry_key_unknown(
+    scope_struct: &ScopeStruct,
+) -> proc_macro2::TokenStream {
+    let scope_builder_name = &scope_struct.item_struct().ident;
+    let params_scope = ParamsScope::Workspace;
+
+    let params_k_method_name = params_scope.params_k_method_name();
+    let params_k_type_param = params_scope.params_k_type_param();
+
+    let builder_type = CmdCtxBuilderTypeBuilder::new(scope_builder_name.clone())
+        .with_workspace_params_k_maybe(parse_quote!(peace_rt_model::params::KeyUnknown))
+        .with_workspace_params_selection(parse_quote!(
+            crate::scopes::type_params::WorkspaceParamsNone
+        ))
+        .build();
+    let impl_header = ImplHeaderBuilder::new(builder_type)
+        .with_workspace_params_k_maybe(None)
+        .with_workspace_params_selection(None)
+        .build();
+    let return_type =
+        cmd_ctx_builder_with_params_selected(scope_builder_name, scope_struct, params_scope);
+
+    quote! {
+        #impl_header
+        {
+            /// Sets the registry of workspace parameter types, and migrations
+            /// from the shapes they were stored in by earlier versions of the
+            /// application.
+            ///
+            /// See the `with_workspace_params_registry` method on the builder
+            /// with workspace params for when migrations are applied.
+            ///
+            /// # Parameters
+            ///
+            /// * `workspace_params_registry`: Workspace param types and
+            ///   migrations.
+            pub fn with_workspace_params_registry<#params_k_type_param>(
+                self,
+                workspace_params_registry: peace_rt_model::params::WorkspaceParamsRegistry<
+                    #params_k_type_param
+                >,
+            ) -> #return_type
+            where
+                #params_k_type_param:
+                    Clone + std::fmt::Debug + Eq + std::hash::Hash + serde::de::DeserializeOwned + serde::Serialize + Send + Sync + Unpin + 'static,
+            {
+                // self.with_workspace_params_k::<WorkspaceParamsK>()
+                self.#params_k_method_name::<#params_k_type_param>()
+                    .with_workspace_params_registry(workspace_params_registry)
+            }
+        }
+    }
+}
+
+fn impl_with_workspace_params_registry_key_known(
+    scope_struct: &ScopeStruct,
+) -> proc_macro2::TokenStream {
+    let scope = scope_struct.scope();
+    let scope_builder_name = &scope_struct.item_struct().ident;
+    let params_scope = ParamsScope::Workspace;
+
+    let params_k_type_param = params_scope.params_k_type_param();
+    let params_selection_name = params_scope.params_selection_name();
+    let scope_builder_fields_params_some = scope_builder_fields::params_some(scope, params_scope);
+    let scope_builder_fields_passthrough = scope_builder_fields::passthrough(scope, params_scope);
+    let params_type_reg_method_name = params_scope.params_type_reg_mut_method_name();
+
+    let builder_type =
+        cmd_ctx_builder_with_params_selected(scope_builder_name, scope_struct, params_scope);
+    let return_type = builder_type.clone();
+    let impl_header = ImplHeaderBuilder::new(builder_type)
+        .with_workspace_params_k_maybe(None)
+        .with_workspace_params_k(Some(parse_quote!(WorkspaceParamsK)))
+        .with_workspace_params_selection(None)
+        .build();
+
+    quote! {
+        #impl_header
+        {
+            /// Sets the registry of workspace parameter types, and migrations
+            /// from the shapes they were stored in by earlier versions of the
+            /// application.
+            ///
+            /// The types in the registry are used to deserialize stored
+            /// workspace params, even if no value is provided for them.
+            ///
+            /// Stored params that are not in their current shape are migrated
+            /// and written back to storage when the command context is built,
+            /// before they are deserialized.
+            ///
+            /// If a migrated value fails to be serialized, `build()` returns
+            /// `Error::WorkspaceParamMigrate`, which names the key of the
+            /// param.
+            ///
+            /// # Parameters
+            ///
+            /// * `workspace_params_registry`: Workspace param types and
+            ///   migrations.
+            pub fn with_workspace_params_registry(
+                self,
+                workspace_params_registry: peace_rt_model::params::WorkspaceParamsRegistry<
+                    #params_k_type_param
+                >,
+            ) -> #return_type {
+                let Self {
+                    output,
+                    interruptibility,
+                    tracing_dispatch,
+                    message_catalog,
+                    #[cfg(feature = "telemetry")]
+                    meter,
+                    workspace,
+                    scope_builder:
+                        #scope_builder_name {
+                            // profile_selection,
+                            // flow_selection,
+                            // mut params_type_regs_builder,
+                            // mut workspace_params_selection,
+                            // profile_params_selection,
+                            // flow_params_selection,
+                            // params_specs_provided,
+
+                            #scope_builder_fields_params_some
+                        },
+                } = self;
+
+                workspace_params_registry.register_types(
+                    params_type_regs_builder.#params_type_reg_method_name()
+                );
+                // workspace_params_selection.2 = workspace_params_registry;
+                #params_selection_name.2 = workspace_params_registry;
+
+                let scope_builder = #scope_builder_name {
+                    // profile_selection,
+                    // flow_selection,
+                    // params_type_regs_builder,
+                    // workspace_params_selection,
+                    // profile_params_selection,
+                    // flow_params_selection,
+                    // params_specs_provided,
+
+                    #scope_builder_fields_passthrough
+                };
+
+                crate::ctx::CmdCtxBuilder {
+                    output,
+                    interruptibility,
+                    tracing_dispatch,
+                    message_catalog,
+                    #[cfg(feature = "telemetry")]
+                    meter,
+                    workspace,
+                    scope_builder,
+                }
+            }
+        }
+    }
+}
@@ -107,6 +107,35 @@ impl ParamsScope {
         }
     }
 
+    /// Returns the name to use for the `with_*_params_file` method.
+    pub fn with_params_file_method_name(self) -> Ident {
+        match self {
+            Self::Workspace => Ident::new("with_workspace_params_file", Span::call_site()),
+            Self::Profile => Ident::new("with_profile_params_file", Span::call_site()),
+            Self::Flow => Ident::new("with_flow_params_file", Span::call_site()),
+        }
+    }
+
+    /// Returns the name of the `ParamsFilesProvided` method that returns the
+    /// `*_params_file`.
+    pub fn params_file_provided_method_name(self) -> Ident {
+        match self {
+            Self::Workspace => Ident::new("workspace_params_file", Span::call_site()),
+            Self::Profile => Ident::new("profile_params_file", Span::call_site()),
+            Self::Flow => Ident::new("flow_params_file", Span::call_site()),
+        }
+    }
+
+    /// Returns the name of the `ParamsFilesProvided` method that sets the
+    /// `*_params_file`.
+    pub fn set_params_file_provided_method_name(self) -> Ident {
+        match self {
+            Self::Workspace => Ident::new("set_workspace_params_file", Span::call_site()),
+            Self::Profile => Ident::new("set_profile_params_file", Span::call_site()),
+            Self::Flow => Ident::new("set_flow_params_file", Span::call_site()),
+        }
+    }
+
     /// Returns the name to use for the `*_param` variable.
     pub fn param_name(self) -> Ident {
         match self {
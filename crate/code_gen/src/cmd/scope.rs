@@ -82,4 +82,132 @@ impl Scope {
             FlowCount::One => true,
         }
     }
+
+    /// Returns whether this scope supports loading states from other flows.
+    pub fn states_from_flows_supported(self) -> bool {
+        match self {
+            Scope::MultiProfileNoFlow
+            | Scope::MultiProfileSingleFlow
+            | Scope::NoProfileNoFlow
+            | Scope::SingleProfileNoFlow => false,
+            Scope::SingleProfileSingleFlow => true,
+        }
+    }
+
+    /// Returns whether this scope supports hooks around each item's apply.
+    pub fn apply_hooks_supported(self) -> bool {
+        match self {
+            Scope::MultiProfileNoFlow
+            | Scope::MultiProfileSingleFlow
+            | Scope::NoProfileNoFlow
+            | Scope::SingleProfileNoFlow => false,
+            Scope::SingleProfileSingleFlow => true,
+        }
+    }
+
+    /// Returns whether this scope supports requiring approval before items are
+    /// applied.
+    pub fn approval_policy_supported(self) -> bool {
+        match self {
+            Scope::MultiProfileNoFlow
+            | Scope::MultiProfileSingleFlow
+            | Scope::NoProfileNoFlow
+            | Scope::SingleProfileNoFlow => false,
+            Scope::SingleProfileSingleFlow => true,
+        }
+    }
+
+    /// Returns whether this scope supports requiring items to send progress
+    /// updates while they are applied.
+    pub fn heartbeat_policy_supported(self) -> bool {
+        match self {
+            Scope::MultiProfileNoFlow
+            | Scope::MultiProfileSingleFlow
+            | Scope::NoProfileNoFlow
+            | Scope::SingleProfileNoFlow => false,
+            Scope::SingleProfileSingleFlow => true,
+        }
+    }
+
+    /// Returns whether this scope supports disabling items for the profile.
+    pub fn items_disabled_supported(self) -> bool {
+        match self {
+            Scope::MultiProfileNoFlow
+            | Scope::MultiProfileSingleFlow
+            | Scope::NoProfileNoFlow
+            | Scope::SingleProfileNoFlow => false,
+            Scope::SingleProfileSingleFlow => true,
+        }
+    }
+
+    /// Returns whether this scope supports controlling how items are run
+    /// concurrently.
+    pub fn item_executor_supported(self) -> bool {
+        match self {
+            Scope::MultiProfileNoFlow
+            | Scope::MultiProfileSingleFlow
+            | Scope::NoProfileNoFlow
+            | Scope::SingleProfileNoFlow => false,
+            Scope::SingleProfileSingleFlow => true,
+        }
+    }
+
+    /// Returns whether this scope supports pruning stored states of items that
+    /// were removed from the flow.
+    pub fn states_prune_supported(self) -> bool {
+        match self {
+            Scope::MultiProfileNoFlow
+            | Scope::MultiProfileSingleFlow
+            | Scope::NoProfileNoFlow
+            | Scope::SingleProfileNoFlow => false,
+            Scope::SingleProfileSingleFlow => true,
+        }
+    }
+
+    /// Returns whether this scope supports a deadline for command execution.
+    pub fn execution_deadline_supported(self) -> bool {
+        match self {
+            Scope::MultiProfileNoFlow
+            | Scope::MultiProfileSingleFlow
+            | Scope::NoProfileNoFlow
+            | Scope::SingleProfileNoFlow => false,
+            Scope::SingleProfileSingleFlow => true,
+        }
+    }
+
+    /// Returns whether this scope supports interrupting command execution
+    /// through an `InterruptHandle`.
+    pub fn interrupt_handle_supported(self) -> bool {
+        match self {
+            Scope::MultiProfileNoFlow
+            | Scope::MultiProfileSingleFlow
+            | Scope::NoProfileNoFlow
+            | Scope::SingleProfileNoFlow => false,
+            Scope::SingleProfileSingleFlow => true,
+        }
+    }
+
+    /// Returns whether this scope checks for item params that changed since
+    /// current states were stored.
+    pub fn params_change_policy_supported(self) -> bool {
+        match self {
+            Scope::MultiProfileNoFlow
+            | Scope::MultiProfileSingleFlow
+            | Scope::NoProfileNoFlow
+            | Scope::SingleProfileNoFlow => false,
+            Scope::SingleProfileSingleFlow => true,
+        }
+    }
+
+    /// Returns whether this scope supports loading profile and flow params
+    /// from a revision in the params history.
+    pub fn params_at_supported(self) -> bool {
+        match self {
+            Scope::MultiProfileNoFlow
+            | Scope::MultiProfileSingleFlow
+            | Scope::NoProfileNoFlow
+            | Scope::SingleProfileNoFlow => false,
+            Scope::SingleProfileSingleFlow => true,
+        }
+    }
 }
@@ -49,9 +49,46 @@ pub(crate) fn passthrough(
         }
     }
 
+    field_values.push(parse_quote!(params_files_provided));
     if scope.flow_count() == FlowCount::One {
         field_values.push(parse_quote!(params_specs_provided));
     }
+    if scope.states_from_flows_supported() {
+        field_values.push(parse_quote!(states_from_flows));
+    }
+    if scope.apply_hooks_supported() {
+        field_values.push(parse_quote!(apply_hooks));
+    }
+    if scope.approval_policy_supported() {
+        field_values.push(parse_quote!(approval_policy));
+    }
+    if scope.heartbeat_policy_supported() {
+        field_values.push(parse_quote!(heartbeat_policy));
+    }
+    if scope.items_disabled_supported() {
+        field_values.push(parse_quote!(items_disabled));
+    }
+    if scope.item_executor_supported() {
+        field_values.push(parse_quote!(item_executor));
+    }
+    if scope.item_executor_supported() {
+        field_values.push(parse_quote!(max_concurrency));
+    }
+    if scope.states_prune_supported() {
+        field_values.push(parse_quote!(states_prune));
+    }
+    if scope.execution_deadline_supported() {
+        field_values.push(parse_quote!(execution_deadline));
+    }
+    if scope.interrupt_handle_supported() {
+        field_values.push(parse_quote!(interrupt_handle));
+    }
+    if scope.params_change_policy_supported() {
+        field_values.push(parse_quote!(params_change_policy));
+    }
+    if scope.params_at_supported() {
+        field_values.push(parse_quote!(params_at));
+    }
 
     field_values
 }
@@ -109,9 +146,46 @@ pub(crate) fn params_none(
         }
     }
 
+    field_values.push(parse_quote!(params_files_provided));
     if scope.flow_count() == FlowCount::One {
         field_values.push(parse_quote!(params_specs_provided));
     }
+    if scope.states_from_flows_supported() {
+        field_values.push(parse_quote!(states_from_flows));
+    }
+    if scope.apply_hooks_supported() {
+        field_values.push(parse_quote!(apply_hooks));
+    }
+    if scope.approval_policy_supported() {
+        field_values.push(parse_quote!(approval_policy));
+    }
+    if scope.heartbeat_policy_supported() {
+        field_values.push(parse_quote!(heartbeat_policy));
+    }
+    if scope.items_disabled_supported() {
+        field_values.push(parse_quote!(items_disabled));
+    }
+    if scope.item_executor_supported() {
+        field_values.push(parse_quote!(item_executor));
+    }
+    if scope.item_executor_supported() {
+        field_values.push(parse_quote!(max_concurrency));
+    }
+    if scope.states_prune_supported() {
+        field_values.push(parse_quote!(states_prune));
+    }
+    if scope.execution_deadline_supported() {
+        field_values.push(parse_quote!(execution_deadline));
+    }
+    if scope.interrupt_handle_supported() {
+        field_values.push(parse_quote!(interrupt_handle));
+    }
+    if scope.params_change_policy_supported() {
+        field_values.push(parse_quote!(params_change_policy));
+    }
+    if scope.params_at_supported() {
+        field_values.push(parse_quote!(params_at));
+    }
 
     field_values
 }
@@ -160,9 +234,46 @@ pub(crate) fn params_some(scope: Scope, params_scope: ParamsScope) -> Punctuated
         }
     }
 
+    field_values.push(parse_quote!(params_files_provided));
     if scope.flow_count() == FlowCount::One {
         field_values.push(parse_quote!(params_specs_provided));
     }
+    if scope.states_from_flows_supported() {
+        field_values.push(parse_quote!(states_from_flows));
+    }
+    if scope.apply_hooks_supported() {
+        field_values.push(parse_quote!(apply_hooks));
+    }
+    if scope.approval_policy_supported() {
+        field_values.push(parse_quote!(approval_policy));
+    }
+    if scope.heartbeat_policy_supported() {
+        field_values.push(parse_quote!(heartbeat_policy));
+    }
+    if scope.items_disabled_supported() {
+        field_values.push(parse_quote!(items_disabled));
+    }
+    if scope.item_executor_supported() {
+        field_values.push(parse_quote!(item_executor));
+    }
+    if scope.item_executor_supported() {
+        field_values.push(parse_quote!(max_concurrency));
+    }
+    if scope.states_prune_supported() {
+        field_values.push(parse_quote!(states_prune));
+    }
+    if scope.execution_deadline_supported() {
+        field_values.push(parse_quote!(execution_deadline));
+    }
+    if scope.interrupt_handle_supported() {
+        field_values.push(parse_quote!(interrupt_handle));
+    }
+    if scope.params_change_policy_supported() {
+        field_values.push(parse_quote!(params_change_policy));
+    }
+    if scope.params_at_supported() {
+        field_values.push(parse_quote!(params_at));
+    }
 
     field_values
 }
@@ -188,7 +299,11 @@ pub(crate) fn params_some_new(
         ParamsScope::Workspace => {
             field_values.push(parse_quote! {
                 workspace_params_selection:
-                    crate::scopes::type_params::WorkspaceParamsSome(params_map)
+                    crate::scopes::type_params::WorkspaceParamsSome(
+                        params_map,
+                        peace_rt_model::params::ParamsFns::new(),
+                        peace_rt_model::params::WorkspaceParamsRegistry::new(),
+                    )
             });
             if scope.profile_params_supported() {
                 field_values.push(parse_quote!(profile_params_selection));
@@ -205,7 +320,10 @@ pub(crate) fn params_some_new(
                     ProfileCount::One => {
                         field_values.push(parse_quote! {
                             profile_params_selection:
-                                crate::scopes::type_params::ProfileParamsSome(params_map)
+                                crate::scopes::type_params::ProfileParamsSome(
+                                    params_map,
+                                    peace_rt_model::params::ParamsOverlays::new(),
+                                )
                         });
                     }
                     ProfileCount::Multiple => {
@@ -245,9 +363,46 @@ pub(crate) fn params_some_new(
         }
     }
 
+    field_values.push(parse_quote!(params_files_provided));
     if scope.flow_count() == FlowCount::One {
         field_values.push(parse_quote!(params_specs_provided));
     }
+    if scope.states_from_flows_supported() {
+        field_values.push(parse_quote!(states_from_flows));
+    }
+    if scope.apply_hooks_supported() {
+        field_values.push(parse_quote!(apply_hooks));
+    }
+    if scope.approval_policy_supported() {
+        field_values.push(parse_quote!(approval_policy));
+    }
+    if scope.heartbeat_policy_supported() {
+        field_values.push(parse_quote!(heartbeat_policy));
+    }
+    if scope.items_disabled_supported() {
+        field_values.push(parse_quote!(items_disabled));
+    }
+    if scope.item_executor_supported() {
+        field_values.push(parse_quote!(item_executor));
+    }
+    if scope.item_executor_supported() {
+        field_values.push(parse_quote!(max_concurrency));
+    }
+    if scope.states_prune_supported() {
+        field_values.push(parse_quote!(states_prune));
+    }
+    if scope.execution_deadline_supported() {
+        field_values.push(parse_quote!(execution_deadline));
+    }
+    if scope.interrupt_handle_supported() {
+        field_values.push(parse_quote!(interrupt_handle));
+    }
+    if scope.params_change_policy_supported() {
+        field_values.push(parse_quote!(params_change_policy));
+    }
+    if scope.params_at_supported() {
+        field_values.push(parse_quote!(params_at));
+    }
 
     field_values
 }
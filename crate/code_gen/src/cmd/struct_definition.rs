@@ -37,8 +37,26 @@ use crate::cmd::scope_struct::ScopeStruct;
 ///     pub(crate) profile_params_selection: CmdCtxBuilderTypesT::ProfileParamsSelection,
 ///     /// Flow parameters.
 ///     pub(crate) flow_params_selection: CmdCtxBuilderTypesT::FlowParamsSelection,
+///     /// Paths to user supplied files to read params from.
+///     pub(crate) params_files_provided: peace_rt_model::params::ParamsFilesProvided,
 ///     /// Map of item ID to its parameters. `TypeMap<ItemId, AnySpecRtBoxed>` newtype.
 ///     pub(crate) params_specs_provided: peace_params::ParamsSpecs,
+///     /// Other flows in the same profile whose stored current states are
+///     /// loaded into `Resources`.
+///     pub(crate) states_from_flows:
+///         Vec<peace_rt_model::Flow<CmdCtxBuilderTypesT::AppError>>,
+///     /// Hooks invoked around each item's apply.
+///     pub(crate) apply_hooks: peace_rt_model::ApplyHooks<CmdCtxBuilderTypesT::AppError>,
+///     /// Which items require approval before they are applied.
+///     pub(crate) approval_policy: peace_rt_model::ApprovalPolicy,
+///     /// How long items may go without sending progress while they are
+///     /// applied.
+///     pub(crate) heartbeat_policy: peace_rt_model::HeartbeatPolicy,
+///     /// Items to disable for the profile, replacing the stored items.
+///     pub(crate) items_disabled: Option<peace_rt_model::ItemsDisabled>,
+///     /// How to handle item params that changed since current states were
+///     /// stored.
+///     pub(crate) params_change_policy: peace_rt_model::ParamsChangePolicy,
 /// }
 /// ```
 pub fn struct_definition(scope_struct: &mut ScopeStruct) -> proc_macro2::TokenStream {
@@ -49,7 +67,20 @@ pub fn struct_definition(scope_struct: &mut ScopeStruct) -> proc_macro2::TokenSt
 
         fields::profile_and_flow_selection_push(&mut fields, scope);
         fields::params_selection_push(&mut fields, scope);
+        fields::params_files_provided_push(&mut fields);
         fields::params_specs_push(&mut fields, scope);
+        fields::states_from_flows_push(&mut fields, scope);
+        fields::apply_hooks_push(&mut fields, scope);
+        fields::approval_policy_push(&mut fields, scope);
+        fields::heartbeat_policy_push(&mut fields, scope);
+        fields::items_disabled_push(&mut fields, scope);
+        fields::item_executor_push(&mut fields, scope);
+        fields::max_concurrency_push(&mut fields, scope);
+        fields::states_prune_push(&mut fields, scope);
+        fields::execution_deadline_push(&mut fields, scope);
+        fields::interrupt_handle_push(&mut fields, scope);
+        fields::params_change_policy_push(&mut fields, scope);
+        fields::params_at_push(&mut fields, scope);
 
         Fields::from(fields)
     };
@@ -124,6 +155,17 @@ mod fields {
         }
     }
 
+    /// Appends a `params_files_provided: ParamsFilesProvided` field to the
+    /// given fields.
+    pub fn params_files_provided_push(fields_named: &mut FieldsNamed) {
+        // Params files are supported by all scopes.
+        let fields: FieldsNamed = parse_quote!({
+            /// Paths to user supplied files to read params from.
+            pub(crate) params_files_provided: peace_rt_model::params::ParamsFilesProvided
+        });
+        fields_named.named.extend(fields.named);
+    }
+
     /// Appends a `params_specs_provided: ParamsSpecs` field to the given
     /// fields.
     pub fn params_specs_push(fields_named: &mut FieldsNamed, scope: Scope) {
@@ -135,4 +177,154 @@ mod fields {
             fields_named.named.extend(fields_params_specs.named);
         }
     }
+
+    /// Appends a `states_from_flows: Vec<Flow<AppError>>` field to the given
+    /// fields.
+    pub fn states_from_flows_push(fields_named: &mut FieldsNamed, scope: Scope) {
+        if scope.states_from_flows_supported() {
+            let fields_states_from_flows: FieldsNamed = parse_quote!({
+                /// Other flows in the same profile whose stored current states are
+                /// loaded into `Resources`.
+                pub(crate) states_from_flows:
+                    Vec<peace_rt_model::Flow<CmdCtxBuilderTypesT::AppError>>
+            });
+            fields_named.named.extend(fields_states_from_flows.named);
+        }
+    }
+
+    /// Appends an `apply_hooks: ApplyHooks<AppError>` field to the given
+    /// fields.
+    pub fn apply_hooks_push(fields_named: &mut FieldsNamed, scope: Scope) {
+        if scope.apply_hooks_supported() {
+            let fields_apply_hooks: FieldsNamed = parse_quote!({
+                /// Hooks invoked around each item's apply.
+                pub(crate) apply_hooks: peace_rt_model::ApplyHooks<CmdCtxBuilderTypesT::AppError>
+            });
+            fields_named.named.extend(fields_apply_hooks.named);
+        }
+    }
+
+    /// Appends an `approval_policy: ApprovalPolicy` field to the given fields.
+    pub fn approval_policy_push(fields_named: &mut FieldsNamed, scope: Scope) {
+        if scope.approval_policy_supported() {
+            let fields_approval_policy: FieldsNamed = parse_quote!({
+                /// Which items require approval before they are applied.
+                pub(crate) approval_policy: peace_rt_model::ApprovalPolicy
+            });
+            fields_named.named.extend(fields_approval_policy.named);
+        }
+    }
+
+    /// Appends a `heartbeat_policy: HeartbeatPolicy` field to the given
+    /// fields.
+    pub fn heartbeat_policy_push(fields_named: &mut FieldsNamed, scope: Scope) {
+        if scope.heartbeat_policy_supported() {
+            let fields_heartbeat_policy: FieldsNamed = parse_quote!({
+                /// How long items may go without sending progress while they
+                /// are applied.
+                pub(crate) heartbeat_policy: peace_rt_model::HeartbeatPolicy
+            });
+            fields_named.named.extend(fields_heartbeat_policy.named);
+        }
+    }
+
+    /// Appends an `items_disabled: Option<ItemsDisabled>` field to the given
+    /// fields.
+    pub fn items_disabled_push(fields_named: &mut FieldsNamed, scope: Scope) {
+        if scope.items_disabled_supported() {
+            let fields_items_disabled: FieldsNamed = parse_quote!({
+                /// Items to disable for the profile, replacing the stored
+                /// items.
+                pub(crate) items_disabled: Option<peace_rt_model::ItemsDisabled>
+            });
+            fields_named.named.extend(fields_items_disabled.named);
+        }
+    }
+
+    /// Appends an `item_executor: Arc<dyn ItemExecutor>` field to the given
+    /// fields.
+    pub fn item_executor_push(fields_named: &mut FieldsNamed, scope: Scope) {
+        if scope.item_executor_supported() {
+            let fields_item_executor: FieldsNamed = parse_quote!({
+                /// How items are run concurrently within command blocks.
+                pub(crate) item_executor: std::sync::Arc<dyn peace_rt_model::ItemExecutor>
+            });
+            fields_named.named.extend(fields_item_executor.named);
+        }
+    }
+
+    /// Appends a `max_concurrency: Option<usize>` field to the given fields.
+    pub fn max_concurrency_push(fields_named: &mut FieldsNamed, scope: Scope) {
+        if scope.item_executor_supported() {
+            let fields_max_concurrency: FieldsNamed = parse_quote!({
+                /// Maximum number of items to run concurrently, overriding the
+                /// item executor's limit.
+                pub(crate) max_concurrency: Option<usize>
+            });
+            fields_named.named.extend(fields_max_concurrency.named);
+        }
+    }
+
+    /// Appends a `states_prune: Option<ItemGraph<AppError>>` field to the
+    /// given fields.
+    pub fn states_prune_push(fields_named: &mut FieldsNamed, scope: Scope) {
+        if scope.states_prune_supported() {
+            let fields_states_prune: FieldsNamed = parse_quote!({
+                /// Items that were removed from the flow, whose stored states
+                /// are pruned when the command context is built.
+                pub(crate) states_prune:
+                    Option<peace_rt_model::ItemGraph<CmdCtxBuilderTypesT::AppError>>
+            });
+            fields_named.named.extend(fields_states_prune.named);
+        }
+    }
+
+    /// Appends an `execution_deadline: Option<ExecutionDeadline>` field to the
+    /// given fields.
+    pub fn execution_deadline_push(fields_named: &mut FieldsNamed, scope: Scope) {
+        if scope.execution_deadline_supported() {
+            let fields_execution_deadline: FieldsNamed = parse_quote!({
+                /// Instant by which `CmdExecution`s should complete.
+                pub(crate) execution_deadline: Option<peace_rt_model::ExecutionDeadline>
+            });
+            fields_named.named.extend(fields_execution_deadline.named);
+        }
+    }
+
+    /// Appends an `interrupt_handle: Option<InterruptHandle>` field to the given
+    /// fields.
+    pub fn interrupt_handle_push(fields_named: &mut FieldsNamed, scope: Scope) {
+        if scope.interrupt_handle_supported() {
+            let fields_interrupt_handle: FieldsNamed = parse_quote!({
+                /// Handle to interrupt `CmdExecution`s from another task.
+                pub(crate) interrupt_handle: Option<crate::ctx::InterruptHandle>
+            });
+            fields_named.named.extend(fields_interrupt_handle.named);
+        }
+    }
+
+    /// Appends a `params_change_policy: ParamsChangePolicy` field to the given
+    /// fields.
+    pub fn params_change_policy_push(fields_named: &mut FieldsNamed, scope: Scope) {
+        if scope.params_change_policy_supported() {
+            let fields_params_change_policy: FieldsNamed = parse_quote!({
+                /// How to handle item params that changed since current states
+                /// were stored.
+                pub(crate) params_change_policy: peace_rt_model::ParamsChangePolicy
+            });
+            fields_named.named.extend(fields_params_change_policy.named);
+        }
+    }
+
+    /// Appends a `params_at: Option<u64>` field to the given fields.
+    pub fn params_at_push(fields_named: &mut FieldsNamed, scope: Scope) {
+        if scope.params_at_supported() {
+            let fields_params_at: FieldsNamed = parse_quote!({
+                /// Revision in the params history to load profile and flow
+                /// params from.
+                pub(crate) params_at: Option<u64>
+            });
+            fields_named.named.extend(fields_params_at.named);
+        }
+    }
 }
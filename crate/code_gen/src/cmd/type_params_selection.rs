@@ -93,7 +93,11 @@ impl WorkspaceParamsSelection {
             ),
             Self::Some => parse_quote! {
                 workspace_params_selection:
-                    crate::scopes::type_params::WorkspaceParamsSome(workspace_params)
+                    crate::scopes::type_params::WorkspaceParamsSome(
+                        workspace_params,
+                        _workspace_params_fns,
+                        _workspace_params_registry,
+                    )
             },
         }
     }
@@ -147,7 +151,10 @@ impl ProfileParamsSelection {
                 },
                 ProfileCount::One => parse_quote! {
                     profile_params_selection:
-                        crate::scopes::type_params::ProfileParamsSome(profile_params)
+                        crate::scopes::type_params::ProfileParamsSome(
+                            profile_params,
+                            profile_params_overlays,
+                        )
                 },
                 ProfileCount::Multiple => {
                     // The `profile_to_profile_params` in `ProfileParamsSomeMulti` is not used.
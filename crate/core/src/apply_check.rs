@@ -2,19 +2,51 @@ use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "output_progress")]
 use crate::progress::ProgressLimit;
+use crate::ApplyCheckReason;
 
 /// Whether the `apply` function needs to be executed.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub enum ApplyCheck {
     /// Item is not in goal state.
     #[cfg(not(feature = "output_progress"))]
-    ExecRequired,
+    ExecRequired {
+        /// Why the `apply` function needs to be executed, if known.
+        reason: Option<ApplyCheckReason>,
+    },
     /// Item is not in goal state.
     #[cfg(feature = "output_progress")]
     ExecRequired {
         /// Unit of measurement and limit to indicate progress.
         progress_limit: ProgressLimit,
+        /// Why the `apply` function needs to be executed, if known.
+        reason: Option<ApplyCheckReason>,
     },
     /// Item is already in goal state.
     ExecNotRequired,
 }
+
+impl ApplyCheck {
+    /// Returns why the `apply` function needs to be executed, if known.
+    ///
+    /// This is always `None` for `ExecNotRequired`.
+    pub fn reason(&self) -> Option<&ApplyCheckReason> {
+        match self {
+            Self::ExecRequired { reason, .. } => reason.as_ref(),
+            Self::ExecNotRequired => None,
+        }
+    }
+
+    /// Sets the reason why the `apply` function needs to be executed.
+    ///
+    /// This has no effect on `ExecNotRequired`.
+    pub fn with_reason(mut self, reason: ApplyCheckReason) -> Self {
+        if let Self::ExecRequired {
+            reason: reason_existing,
+            ..
+        } = &mut self
+        {
+            *reason_existing = Some(reason);
+        }
+        self
+    }
+}
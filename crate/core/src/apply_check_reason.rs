@@ -0,0 +1,82 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Machine readable reason why an item's `apply` function needs to be
+/// executed.
+///
+/// This is returned by `Item::apply_check` within
+/// [`ApplyCheck::ExecRequired`], so that tools can explain to users why an
+/// item will be re-applied.
+///
+/// # Examples
+///
+/// ```rust
+/// use peace_core::ApplyCheckReason;
+///
+/// let reason = ApplyCheckReason::new("content_changed").with_fields(["content"]);
+///
+/// assert_eq!("content_changed", reason.code());
+/// assert_eq!(&["content"], reason.fields());
+/// assert_eq!("content_changed: content", reason.to_string());
+/// ```
+///
+/// [`ApplyCheck::ExecRequired`]: crate::ApplyCheck::ExecRequired
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ApplyCheckReason {
+    /// Identifier for the reason, e.g. `"content_changed"`.
+    code: String,
+    /// Names of the fields that differ between the current and target states.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    fields: Vec<String>,
+}
+
+impl ApplyCheckReason {
+    /// Returns a new `ApplyCheckReason` with the given code.
+    ///
+    /// Codes are conventionally `snake_case`, e.g. `"content_changed"`, or
+    /// `"not_exists"`.
+    pub fn new(code: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Sets the names of the fields that differ between the current and target
+    /// states.
+    pub fn with_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.fields = fields.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Returns the identifier for the reason.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Returns the names of the fields that differ between the current and
+    /// target states.
+    pub fn fields(&self) -> &[String] {
+        &self.fields
+    }
+}
+
+impl fmt::Display for ApplyCheckReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.code.fmt(f)?;
+
+        if let Some((first, remaining)) = self.fields.split_first() {
+            write!(f, ": {first}")?;
+            remaining
+                .iter()
+                .try_for_each(|field| write!(f, ", {field}"))?;
+        }
+
+        Ok(())
+    }
+}
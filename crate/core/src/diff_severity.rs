@@ -0,0 +1,45 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// How disruptive it is to apply an item's state diff.
+///
+/// Variants are ordered from least to most disruptive, so the most severe diff
+/// across a flow is the maximum severity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub enum DiffSeverity {
+    /// The item is already in the target state.
+    InSync,
+    /// Applying the diff creates or updates resources without losing data.
+    Additive,
+    /// Applying the diff removes or replaces resources, and may lose data.
+    Destructive,
+}
+
+impl DiffSeverity {
+    /// Returns the process exit code to use for this severity.
+    ///
+    /// This is similar to `terraform plan -detailed-exitcode`, where `1` is
+    /// reserved for errors:
+    ///
+    /// * `InSync`: `0`
+    /// * `Additive`: `2`
+    /// * `Destructive`: `3`
+    pub fn detailed_exit_code(self) -> i32 {
+        match self {
+            Self::InSync => 0,
+            Self::Additive => 2,
+            Self::Destructive => 3,
+        }
+    }
+}
+
+impl fmt::Display for DiffSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InSync => "in sync".fmt(f),
+            Self::Additive => "additive".fmt(f),
+            Self::Destructive => "destructive".fmt(f),
+        }
+    }
+}
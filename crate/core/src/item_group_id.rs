@@ -0,0 +1,34 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+/// Identifier for a group of [`Item`]s, `Cow<'static, str>` newtype.
+///
+/// Items in a group are still executed individually, but their progress is
+/// rendered as one unit by outputs that support it.
+///
+/// Must begin with a letter or underscore, and contain only letters, numbers,
+/// and underscores.
+///
+/// # Examples
+///
+/// The following are all examples of valid `ItemGroupId`s:
+///
+/// ```rust
+/// # use peace_core::{item_group_id, ItemGroupId};
+/// #
+/// let _snake = item_group_id!("snake_case");
+/// let _camel = item_group_id!("camelCase");
+/// let _pascal = item_group_id!("PascalCase");
+/// ```
+///
+/// [`Item`]: https://docs.rs/peace_cfg/latest/peace_cfg/trait.Item.html
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ItemGroupId(Cow<'static, str>);
+
+crate::id_newtype!(
+    ItemGroupId,
+    ItemGroupIdInvalidFmt,
+    item_group_id,
+    code_inline
+);
@@ -36,3 +36,37 @@ use serde::{Deserialize, Serialize};
 pub struct ItemId(Cow<'static, str>);
 
 crate::id_newtype!(ItemId, ItemIdInvalidFmt, item_id, code_inline);
+
+impl ItemId {
+    /// Returns the ID of an instance of this item, `{item_id}_{instance_key}`.
+    ///
+    /// Characters in `instance_key` that are not valid in an `ItemId` are
+    /// replaced with `_`, so the returned ID is always valid.
+    ///
+    /// This is used to derive an ID for each item that is instantiated from a
+    /// collection, so each instance's state is stored separately.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use peace_core::{ItemId, ItemIdInvalidFmt};
+    /// #
+    /// # fn main() -> Result<(), ItemIdInvalidFmt<'static>> {
+    /// let item_id = ItemId::new("file_download")?;
+    ///
+    /// assert_eq!(
+    ///     "file_download_app_tar_gz",
+    ///     &**item_id.instance("app.tar.gz")
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn instance(&self, instance_key: &str) -> ItemId {
+        let instance_key = instance_key
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect::<String>();
+
+        ItemId(Cow::Owned(format!("{self}_{instance_key}")))
+    }
+}
@@ -13,12 +13,15 @@
 //! [peace#67]: https://github.com/azriel91/peace/issues/67
 
 // Re-exports
-pub use peace_static_check_macros::{app_name, flow_id, item_id, profile};
+pub use peace_static_check_macros::{app_name, flow_id, item_group_id, item_id, profile};
 
 pub use crate::{
     app_name::{AppName, AppNameInvalidFmt},
     apply_check::ApplyCheck,
+    apply_check_reason::ApplyCheckReason,
+    diff_severity::DiffSeverity,
     flow_id::{FlowId, FlowIdInvalidFmt},
+    item_group_id::{ItemGroupId, ItemGroupIdInvalidFmt},
     item_id::{ItemId, ItemIdInvalidFmt},
     profile::{Profile, ProfileInvalidFmt},
 };
@@ -28,7 +31,10 @@ pub mod progress;
 
 mod app_name;
 mod apply_check;
+mod apply_check_reason;
+mod diff_severity;
 mod flow_id;
+mod item_group_id;
 mod item_id;
 mod profile;
 
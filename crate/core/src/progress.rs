@@ -3,6 +3,7 @@ pub use self::{
     progress_delta::ProgressDelta, progress_limit::ProgressLimit,
     progress_msg_update::ProgressMsgUpdate, progress_sender::ProgressSender,
     progress_status::ProgressStatus, progress_tracker::ProgressTracker,
+    progress_tracker_snapshot::ProgressTrackerSnapshot,
     progress_update::ProgressUpdate, progress_update_and_id::ProgressUpdateAndId,
 };
 
@@ -14,5 +15,6 @@ mod progress_msg_update;
 mod progress_sender;
 mod progress_status;
 mod progress_tracker;
+mod progress_tracker_snapshot;
 mod progress_update;
 mod progress_update_and_id;
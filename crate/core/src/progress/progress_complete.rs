@@ -21,6 +21,8 @@ pub enum ProgressComplete {
     Success,
     /// Execution did not complete.
     Fail,
+    /// Execution was skipped, as the item is not applicable.
+    Skipped,
 }
 
 impl ProgressComplete {
@@ -28,15 +30,23 @@ impl ProgressComplete {
     pub fn is_successful(&self) -> bool {
         match self {
             Self::Success => true,
-            Self::Fail => false,
+            Self::Fail | Self::Skipped => false,
         }
     }
 
     /// Returns whether this is a failure outcome.
     pub fn is_failure(&self) -> bool {
         match self {
-            Self::Success => false,
+            Self::Success | Self::Skipped => false,
             Self::Fail => true,
         }
     }
+
+    /// Returns whether execution was skipped.
+    pub fn is_skipped(&self) -> bool {
+        match self {
+            Self::Success | Self::Fail => false,
+            Self::Skipped => true,
+        }
+    }
 }
@@ -2,7 +2,8 @@ use tokio::sync::mpsc::Sender;
 
 use crate::{
     progress::{
-        CmdProgressUpdate, ProgressDelta, ProgressMsgUpdate, ProgressUpdate, ProgressUpdateAndId,
+        CmdProgressUpdate, ProgressComplete, ProgressDelta, ProgressLimit, ProgressMsgUpdate,
+        ProgressUpdate, ProgressUpdateAndId,
     },
     ItemId,
 };
@@ -14,6 +15,10 @@ pub struct ProgressSender<'exec> {
     item_id: &'exec ItemId,
     /// Channel sender to send progress updates to.
     progress_tx: &'exec Sender<CmdProgressUpdate>,
+    /// Sender for the parent step, if this sender is for a sub-step.
+    parent: Option<&'exec ProgressSender<'exec>>,
+    /// Name of the sub-step, if this sender is for a sub-step.
+    sub_step_name: Option<&'exec str>,
 }
 
 impl<'exec> ProgressSender<'exec> {
@@ -22,18 +27,42 @@ impl<'exec> ProgressSender<'exec> {
         Self {
             item_id,
             progress_tx,
+            parent: None,
+            sub_step_name: None,
+        }
+    }
+
+    /// Returns a `ProgressSender` for a sub-step of this sender's step.
+    ///
+    /// Updates sent through the returned sender are rendered on a progress
+    /// tracker nested under this sender's tracker. Sub-steps may themselves
+    /// have sub-steps.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let download = progress_sender.sub_step("download");
+    /// download.set_progress_limit(ProgressLimit::Bytes(byte_count));
+    /// download.inc(chunk_len, ProgressMsgUpdate::NoChange);
+    /// download.complete(ProgressComplete::Success, ProgressMsgUpdate::NoChange);
+    ///
+    /// let extract = progress_sender.sub_step("extract");
+    /// extract.tick(ProgressMsgUpdate::Set(String::from("extracting")));
+    /// ```
+    pub fn sub_step<'s>(&'s self, name: &'s str) -> ProgressSender<'s> {
+        ProgressSender {
+            item_id: self.item_id,
+            progress_tx: self.progress_tx,
+            parent: Some(self),
+            sub_step_name: Some(name),
         }
     }
 
     /// Increments the progress by the given unit count.
     pub fn inc(&self, unit_count: u64, msg_update: ProgressMsgUpdate) {
-        let _progress_send_unused = self.progress_tx.try_send(
-            ProgressUpdateAndId {
-                item_id: self.item_id.clone(),
-                progress_update: ProgressUpdate::Delta(ProgressDelta::Inc(unit_count)),
-                msg_update,
-            }
-            .into(),
+        self.progress_update_send(
+            ProgressUpdate::Delta(ProgressDelta::Inc(unit_count)),
+            msg_update,
         );
     }
 
@@ -46,37 +75,62 @@ impl<'exec> ProgressSender<'exec> {
     /// spinner, this should only be called when there is actually a detected
     /// change.
     pub fn tick(&self, msg_update: ProgressMsgUpdate) {
-        let _progress_send_unused = self.progress_tx.try_send(
-            ProgressUpdateAndId {
-                item_id: self.item_id.clone(),
-                progress_update: ProgressUpdate::Delta(ProgressDelta::Tick),
-                msg_update,
-            }
-            .into(),
+        self.progress_update_send(ProgressUpdate::Delta(ProgressDelta::Tick), msg_update);
+    }
+
+    /// Sets the progress limit of the tracker.
+    ///
+    /// This is mainly used for sub-steps, as an item's progress limit is
+    /// returned from `Item::apply_check`.
+    pub fn set_progress_limit(&self, progress_limit: ProgressLimit) {
+        self.progress_update_send(
+            ProgressUpdate::Limit(progress_limit),
+            ProgressMsgUpdate::NoChange,
         );
     }
 
+    /// Marks the tracker as complete.
+    ///
+    /// This is mainly used for sub-steps, as an item's tracker is marked
+    /// complete when its `ApplyFns::exec` returns.
+    pub fn complete(&self, progress_complete: ProgressComplete, msg_update: ProgressMsgUpdate) {
+        self.progress_update_send(ProgressUpdate::Complete(progress_complete), msg_update);
+    }
+
     /// Resets the progress tracker to a clean state.
     pub fn reset(&self) {
-        let _progress_send_unused = self.progress_tx.try_send(
-            ProgressUpdateAndId {
-                item_id: self.item_id.clone(),
-                progress_update: ProgressUpdate::Reset,
-                msg_update: ProgressMsgUpdate::Clear,
-            }
-            .into(),
-        );
+        self.progress_update_send(ProgressUpdate::Reset, ProgressMsgUpdate::Clear);
     }
 
     /// Resets the progress tracker to a clean state.
     pub fn reset_to_pending(&self) {
+        self.progress_update_send(ProgressUpdate::ResetToPending, ProgressMsgUpdate::Clear);
+    }
+
+    fn progress_update_send(&self, progress_update: ProgressUpdate, msg_update: ProgressMsgUpdate) {
         let _progress_send_unused = self.progress_tx.try_send(
             ProgressUpdateAndId {
                 item_id: self.item_id.clone(),
-                progress_update: ProgressUpdate::ResetToPending,
-                msg_update: ProgressMsgUpdate::Clear,
+                progress_update: self.progress_update_scoped(progress_update),
+                msg_update,
             }
             .into(),
         );
     }
+
+    /// Wraps the progress update in the sub-steps of this sender and its
+    /// parents.
+    fn progress_update_scoped(&self, progress_update: ProgressUpdate) -> ProgressUpdate {
+        let Some(name) = self.sub_step_name else {
+            return progress_update;
+        };
+        let progress_update = ProgressUpdate::SubStep {
+            name: name.to_string(),
+            progress_update: Box::new(progress_update),
+        };
+        match self.parent {
+            Some(parent) => parent.progress_update_scoped(progress_update),
+            None => progress_update,
+        }
+    }
 }
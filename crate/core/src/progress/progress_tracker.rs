@@ -1,6 +1,7 @@
 use std::time::Duration;
 
 use chrono::{DateTime, Utc};
+use indexmap::IndexMap;
 use indicatif::ProgressBar;
 
 use crate::progress::{ProgressLimit, ProgressStatus};
@@ -20,6 +21,8 @@ pub struct ProgressTracker {
     ///
     /// This is useful to determine if execution has stalled.
     last_update_dt: DateTime<Utc>,
+    /// Progress trackers for sub-steps of the execution.
+    sub_trackers: IndexMap<String, ProgressTracker>,
 }
 
 impl ProgressTracker {
@@ -33,6 +36,7 @@ impl ProgressTracker {
             progress_limit: None,
             message: None,
             last_update_dt,
+            sub_trackers: IndexMap::new(),
         }
     }
 
@@ -45,6 +49,7 @@ impl ProgressTracker {
         self.progress_bar.set_length(0);
         self.progress_bar.set_position(0);
         self.progress_bar.reset();
+        self.sub_trackers_clear();
         self.last_update_dt = Utc::now();
     }
 
@@ -57,6 +62,7 @@ impl ProgressTracker {
         self.progress_bar.set_length(0);
         self.progress_bar.set_position(0);
         self.progress_bar.reset();
+        self.sub_trackers_clear();
         self.last_update_dt = Utc::now();
     }
 
@@ -177,6 +183,50 @@ impl ProgressTracker {
         self.last_update_dt
     }
 
+    /// Returns the progress trackers for sub-steps of the execution.
+    pub fn sub_trackers(&self) -> &IndexMap<String, ProgressTracker> {
+        &self.sub_trackers
+    }
+
+    /// Returns the progress tracker for the given sub-step, if it exists.
+    pub fn sub_tracker(&self, name: &str) -> Option<&ProgressTracker> {
+        self.sub_trackers.get(name)
+    }
+
+    /// Returns a mutable reference to the progress tracker for the given
+    /// sub-step, if it exists.
+    pub fn sub_tracker_mut(&mut self, name: &str) -> Option<&mut ProgressTracker> {
+        self.sub_trackers.get_mut(name)
+    }
+
+    /// Inserts a progress tracker for a sub-step of the execution.
+    pub fn sub_tracker_insert(&mut self, name: String, progress_tracker: ProgressTracker) {
+        self.sub_trackers.insert(name, progress_tracker);
+    }
+
+    /// Returns the progress bar that is rendered last for this tracker.
+    ///
+    /// This is the progress bar of the last nested sub-step, or this tracker's
+    /// progress bar if there are no sub-steps. New sub-step progress bars are
+    /// inserted after this one.
+    pub fn progress_bar_last(&self) -> &ProgressBar {
+        self.sub_trackers
+            .values()
+            .last()
+            .map(ProgressTracker::progress_bar_last)
+            .unwrap_or(&self.progress_bar)
+    }
+
+    /// Clears the progress bars of sub-steps, and removes their trackers.
+    fn sub_trackers_clear(&mut self) {
+        self.sub_trackers
+            .drain(..)
+            .for_each(|(_name, mut sub_tracker)| {
+                sub_tracker.sub_trackers_clear();
+                sub_tracker.progress_bar.finish_and_clear();
+            });
+    }
+
     /// Returns the timestamp a progress update was last made.
     #[inline]
     fn last_update_dt_update(&mut self) {
@@ -0,0 +1,48 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::progress::{ProgressLimit, ProgressStatus, ProgressTracker};
+
+/// Serializable snapshot of a [`ProgressTracker`].
+///
+/// This is used to record the progress of a command's items after the command
+/// has finished, so that it can be rendered later.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ProgressTrackerSnapshot {
+    /// Status of the item's execution progress.
+    pub progress_status: ProgressStatus,
+    /// Progress limit for the execution, if known.
+    pub progress_limit: Option<ProgressLimit>,
+    /// Number of progress units completed.
+    pub units_current: u64,
+    /// Duration elapsed since the progress tracker was started.
+    pub elapsed: Duration,
+    /// Message that was displayed.
+    pub message: Option<String>,
+    /// Timestamp of the last progress update.
+    pub last_update_dt: DateTime<Utc>,
+    /// Snapshots of the progress trackers for sub-steps of the execution.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub sub_trackers: IndexMap<String, ProgressTrackerSnapshot>,
+}
+
+impl From<&ProgressTracker> for ProgressTrackerSnapshot {
+    fn from(progress_tracker: &ProgressTracker) -> Self {
+        Self {
+            progress_status: progress_tracker.progress_status().clone(),
+            progress_limit: progress_tracker.progress_limit(),
+            units_current: progress_tracker.units_current(),
+            elapsed: progress_tracker.elapsed(),
+            message: progress_tracker.message().cloned(),
+            last_update_dt: progress_tracker.last_update_dt(),
+            sub_trackers: progress_tracker
+                .sub_trackers()
+                .iter()
+                .map(|(name, sub_tracker)| (name.clone(), Self::from(sub_tracker)))
+                .collect(),
+        }
+    }
+}
@@ -49,4 +49,16 @@ pub enum ProgressUpdate {
     /// Execution has completed.
     #[serde(with = "serde_yaml::with::singleton_map")]
     Complete(ProgressComplete),
+    /// Progress update for a sub-step of the execution.
+    ///
+    /// Each sub-step has its own progress tracker, nested under the tracker
+    /// that this update is sent to, e.g. `download`, `verify`, and `extract`
+    /// under an item's progress tracker.
+    SubStep {
+        /// Name of the sub-step.
+        name: String,
+        /// Update for the sub-step's progress tracker.
+        #[serde(with = "serde_yaml::with::singleton_map")]
+        progress_update: Box<ProgressUpdate>,
+    },
 }
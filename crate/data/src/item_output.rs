@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+/// Typed output of an item, for successor items to read.
+///
+/// An `ItemOutput<T>(None)` is inserted into `Resources` for each output that
+/// an item declares in `Item::outputs`, and is set to `Some` whenever the
+/// item's current state is discovered or applied.
+///
+/// Successor items read the value by declaring `R<'exec, ItemOutput<T>>` in
+/// their `Data`, and `T` in `Item::inputs` so that the item graph can verify
+/// that a predecessor provides the output.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ItemOutput<T>(pub Option<T>);
+
+impl<T> std::ops::Deref for ItemOutput<T> {
+    type Target = Option<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> std::ops::DerefMut for ItemOutput<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
@@ -4,9 +4,10 @@
 pub use fn_graph::{self, resman, DataAccess, DataAccessDyn, Resources, TypeIds};
 pub use peace_data_derive::Data;
 
-pub use crate::data::Data;
+pub use crate::{data::Data, item_output::ItemOutput};
 
 pub mod accessors;
 pub mod marker;
 
 mod data;
+mod item_output;
@@ -0,0 +1,303 @@
+use std::fmt;
+
+use fn_graph::{daggy::Walker, GraphInfo};
+use peace_core::ItemId;
+use serde::{Deserialize, Serialize};
+
+use crate::{FlowSpecInfo, ItemSpecInfo};
+
+pub use self::{flow_edge::FlowEdge, item_rename::ItemRename};
+
+mod flow_edge;
+mod item_rename;
+
+/// Differences between two versions of a flow's definition.
+///
+/// This is used by application authors to check that an upgrade of their
+/// automation does not leave users' stored states behind. Typically the old
+/// version's [`FlowSpecInfo`] is serialized and compared with the new
+/// version's.
+///
+/// Items whose IDs only exist in one version are added or removed, unless an
+/// added item takes the place of a removed item in the graph, in which case it
+/// is reported as renamed. An added item takes the place of a removed item if
+/// both have the same predecessors and successors, or if neither has any
+/// dependencies and both are at the same position in the flow.
+///
+/// Edges between renamed items are compared using their new IDs, so a rename
+/// by itself does not produce edge changes.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FlowDiff {
+    /// IDs of items that are only in the new flow.
+    pub items_added: Vec<ItemId>,
+    /// IDs of items that are only in the old flow.
+    pub items_removed: Vec<ItemId>,
+    /// Items that are likely to have been renamed.
+    pub items_renamed: Vec<ItemRename>,
+    /// Dependencies that are only in the new flow.
+    pub edges_added: Vec<FlowEdge>,
+    /// Dependencies that are only in the old flow.
+    pub edges_removed: Vec<FlowEdge>,
+}
+
+impl FlowDiff {
+    /// Returns the differences between the old and new versions of a flow.
+    pub fn new(flow_spec_info_old: &FlowSpecInfo, flow_spec_info_new: &FlowSpecInfo) -> Self {
+        let graph_info_old = &flow_spec_info_old.graph_info;
+        let graph_info_new = &flow_spec_info_new.graph_info;
+
+        let item_ids_old = item_ids(graph_info_old);
+        let item_ids_new = item_ids(graph_info_new);
+        let edges_old = edges(graph_info_old);
+        let edges_new = edges(graph_info_new);
+
+        let mut items_removed = item_ids_old
+            .iter()
+            .filter(|item_id| !item_ids_new.contains(item_id))
+            .cloned()
+            .collect::<Vec<ItemId>>();
+        let mut items_added = item_ids_new
+            .iter()
+            .filter(|item_id| !item_ids_old.contains(item_id))
+            .cloned()
+            .collect::<Vec<ItemId>>();
+
+        let items_renamed = items_renamed(
+            &item_ids_old,
+            &item_ids_new,
+            &edges_old,
+            &edges_new,
+            &items_removed,
+            &items_added,
+        );
+        items_removed.retain(|item_id| {
+            !items_renamed
+                .iter()
+                .any(|item_rename| &item_rename.item_id_old == item_id)
+        });
+        items_added.retain(|item_id| {
+            !items_renamed
+                .iter()
+                .any(|item_rename| &item_rename.item_id_new == item_id)
+        });
+
+        // Compare edges using the new IDs of renamed items.
+        let edges_old = edges_old
+            .into_iter()
+            .map(|flow_edge| {
+                let FlowEdge {
+                    item_id_from,
+                    item_id_to,
+                    edge,
+                } = flow_edge;
+                FlowEdge::new(
+                    item_id_renamed(&items_renamed, item_id_from),
+                    item_id_renamed(&items_renamed, item_id_to),
+                    edge,
+                )
+            })
+            .collect::<Vec<FlowEdge>>();
+        let edges_added = edges_new
+            .iter()
+            .filter(|flow_edge| !edges_old.contains(flow_edge))
+            .cloned()
+            .collect::<Vec<FlowEdge>>();
+        let edges_removed = edges_old
+            .iter()
+            .filter(|flow_edge| !edges_new.contains(flow_edge))
+            .cloned()
+            .collect::<Vec<FlowEdge>>();
+
+        Self {
+            items_added,
+            items_removed,
+            items_renamed,
+            edges_added,
+            edges_removed,
+        }
+    }
+
+    /// Returns whether there are no differences between the flows.
+    pub fn is_empty(&self) -> bool {
+        self.items_added.is_empty()
+            && self.items_removed.is_empty()
+            && self.items_renamed.is_empty()
+            && self.edges_added.is_empty()
+            && self.edges_removed.is_empty()
+    }
+
+    /// Returns the IDs of items whose stored states are orphaned by the new
+    /// flow.
+    ///
+    /// These are the removed items, as well as the old IDs of renamed items.
+    /// The resources for removed items should be cleaned with the old version
+    /// before upgrading, and the stored states of renamed items should be
+    /// migrated to their new IDs.
+    pub fn states_orphaned(&self) -> Vec<&ItemId> {
+        self.items_removed
+            .iter()
+            .chain(
+                self.items_renamed
+                    .iter()
+                    .map(|item_rename| &item_rename.item_id_old),
+            )
+            .collect()
+    }
+}
+
+impl fmt::Display for FlowDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "Flows are the same.");
+        }
+
+        self.items_added
+            .iter()
+            .try_for_each(|item_id| writeln!(f, "+ item `{item_id}`"))?;
+        self.items_removed.iter().try_for_each(|item_id| {
+            writeln!(
+                f,
+                "- item `{item_id}`: stored state is orphaned, \
+                clean it with the old version before upgrading"
+            )
+        })?;
+        self.items_renamed.iter().try_for_each(|item_rename| {
+            let ItemRename {
+                item_id_old,
+                item_id_new,
+            } = item_rename;
+            writeln!(
+                f,
+                "~ item `{item_id_old}` -> `{item_id_new}`: \
+                migrate stored state from `{item_id_old}` to `{item_id_new}`"
+            )
+        })?;
+        self.edges_added.iter().try_for_each(|flow_edge| {
+            let FlowEdge {
+                item_id_from,
+                item_id_to,
+                edge,
+            } = flow_edge;
+            writeln!(f, "+ edge `{item_id_from}` -> `{item_id_to}` ({edge:?})")
+        })?;
+        self.edges_removed.iter().try_for_each(|flow_edge| {
+            let FlowEdge {
+                item_id_from,
+                item_id_to,
+                edge,
+            } = flow_edge;
+            writeln!(f, "- edge `{item_id_from}` -> `{item_id_to}` ({edge:?})")
+        })
+    }
+}
+
+/// Returns the item IDs in insertion order.
+fn item_ids(graph_info: &GraphInfo<ItemSpecInfo>) -> Vec<ItemId> {
+    graph_info
+        .iter_insertion_with_indices()
+        .map(|(_node_index, item_spec_info)| item_spec_info.item_id.clone())
+        .collect()
+}
+
+/// Returns the edges between items in the graph.
+fn edges(graph_info: &GraphInfo<ItemSpecInfo>) -> Vec<FlowEdge> {
+    graph_info
+        .iter_insertion_with_indices()
+        .flat_map(|(node_index, item_spec_info)| {
+            graph_info
+                .children(node_index)
+                .iter(graph_info)
+                .filter_map(|(edge_index, child_node_index)| {
+                    graph_info.edge_weight(edge_index).copied().map(|edge| {
+                        FlowEdge::new(
+                            item_spec_info.item_id.clone(),
+                            graph_info[child_node_index].item_id.clone(),
+                            edge,
+                        )
+                    })
+                })
+                .collect::<Vec<FlowEdge>>()
+        })
+        .collect()
+}
+
+/// Returns the items that were likely renamed.
+///
+/// Each removed item is matched with at most one added item, in insertion
+/// order.
+fn items_renamed(
+    item_ids_old: &[ItemId],
+    item_ids_new: &[ItemId],
+    edges_old: &[FlowEdge],
+    edges_new: &[FlowEdge],
+    items_removed: &[ItemId],
+    items_added: &[ItemId],
+) -> Vec<ItemRename> {
+    let mut items_added_unmatched = items_added.iter().collect::<Vec<&ItemId>>();
+
+    items_removed
+        .iter()
+        .filter_map(|item_id_old| {
+            let neighbours_old = neighbours(edges_old, item_id_old);
+            let position_old = item_ids_old
+                .iter()
+                .position(|item_id| item_id == item_id_old);
+
+            let match_index = items_added_unmatched.iter().position(|item_id_new| {
+                let neighbours_new = neighbours(edges_new, item_id_new);
+                if neighbours_old.is_empty() && neighbours_new.is_empty() {
+                    let position_new = item_ids_new
+                        .iter()
+                        .position(|item_id| item_id == *item_id_new);
+                    position_old == position_new
+                } else {
+                    neighbours_old == neighbours_new
+                }
+            })?;
+            let item_id_new = items_added_unmatched.remove(match_index);
+
+            Some(ItemRename::new(item_id_old.clone(), item_id_new.clone()))
+        })
+        .collect()
+}
+
+/// Returns the predecessors and successors of an item, with the edge kind.
+///
+/// Predecessors are returned as `(Some(item_id), None, edge)`, and successors
+/// as `(None, Some(item_id), edge)`, sorted for comparison.
+fn neighbours<'f>(
+    flow_edges: &'f [FlowEdge],
+    item_id: &ItemId,
+) -> Vec<(Option<&'f str>, Option<&'f str>, String)> {
+    let mut neighbours = flow_edges
+        .iter()
+        .filter_map(|flow_edge| {
+            if &flow_edge.item_id_to == item_id {
+                Some((
+                    Some(&**flow_edge.item_id_from),
+                    None,
+                    format!("{:?}", flow_edge.edge),
+                ))
+            } else if &flow_edge.item_id_from == item_id {
+                Some((
+                    None,
+                    Some(&**flow_edge.item_id_to),
+                    format!("{:?}", flow_edge.edge),
+                ))
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+    neighbours.sort();
+    neighbours
+}
+
+/// Returns the new ID of the item if it was renamed.
+fn item_id_renamed(items_renamed: &[ItemRename], item_id: ItemId) -> ItemId {
+    items_renamed
+        .iter()
+        .find(|item_rename| item_rename.item_id_old == item_id)
+        .map(|item_rename| item_rename.item_id_new.clone())
+        .unwrap_or(item_id)
+}
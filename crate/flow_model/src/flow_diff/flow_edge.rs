@@ -0,0 +1,25 @@
+use fn_graph::Edge;
+use peace_core::ItemId;
+use serde::{Deserialize, Serialize};
+
+/// A dependency between two items in a flow.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FlowEdge {
+    /// ID of the item that is applied first.
+    pub item_id_from: ItemId,
+    /// ID of the item that is applied after `item_id_from`.
+    pub item_id_to: ItemId,
+    /// Kind of dependency between the items.
+    pub edge: Edge,
+}
+
+impl FlowEdge {
+    /// Returns a new `FlowEdge`.
+    pub fn new(item_id_from: ItemId, item_id_to: ItemId, edge: Edge) -> Self {
+        Self {
+            item_id_from,
+            item_id_to,
+            edge,
+        }
+    }
+}
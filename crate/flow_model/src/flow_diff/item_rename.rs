@@ -0,0 +1,21 @@
+use peace_core::ItemId;
+use serde::{Deserialize, Serialize};
+
+/// An item that is likely to have been renamed between two versions of a flow.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ItemRename {
+    /// ID of the item in the old flow.
+    pub item_id_old: ItemId,
+    /// ID of the item in the new flow.
+    pub item_id_new: ItemId,
+}
+
+impl ItemRename {
+    /// Returns a new `ItemRename`.
+    pub fn new(item_id_old: ItemId, item_id_new: ItemId) -> Self {
+        Self {
+            item_id_old,
+            item_id_new,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use peace_core::FlowId;
+use serde::{Deserialize, Serialize};
+
+use crate::FlowSpecItem;
+
+/// Declarative definition of a [`Flow`], e.g. read from a YAML file.
+///
+/// A `FlowSpec` is turned into a `Flow` by a [`FlowLoader`], which looks up
+/// each item's type in a registry of item constructors.
+///
+/// Since this is a `serde` type, it may be deserialized from any format, such
+/// as YAML or TOML.
+///
+/// # Examples
+///
+/// ```yaml
+/// flow_id: app_deploy
+/// items:
+///   - id: app_download
+///     type: file_download
+///   - id: app_extract
+///     type: tar_x
+///     depends_on: [app_download]
+/// ```
+///
+/// [`Flow`]: https://docs.rs/peace_rt_model/latest/peace_rt_model/struct.Flow.html
+/// [`FlowLoader`]: https://docs.rs/peace_rt_model/latest/peace_rt_model/struct.FlowLoader.html
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FlowSpec {
+    /// ID of the flow.
+    pub flow_id: FlowId,
+    /// Items in the flow, in the order they are added to the graph.
+    pub items: Vec<FlowSpecItem>,
+}
+
+impl FlowSpec {
+    /// Returns a new `FlowSpec`.
+    pub fn new(flow_id: FlowId, items: Vec<FlowSpecItem>) -> Self {
+        Self { flow_id, items }
+    }
+}
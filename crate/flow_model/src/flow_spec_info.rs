@@ -1,11 +1,11 @@
 use std::collections::HashSet;
 
 use dot_ix::model::{
-    common::{EdgeId, NodeHierarchy, NodeId},
+    common::{EdgeId, NodeHierarchy, NodeId, TailwindClasses, TailwindKey},
     info_graph::{GraphDir, IndexMap, InfoGraph, NodeInfo},
 };
 use fn_graph::{daggy::Walker, Edge, FnId, GraphInfo};
-use peace_core::FlowId;
+use peace_core::{FlowId, ItemId};
 
 use serde::{Deserialize, Serialize};
 
@@ -20,6 +20,12 @@ pub struct FlowSpecInfo {
     pub flow_id: FlowId,
     /// Serialized representation of the flow graph.
     pub graph_info: GraphInfo<ItemSpecInfo>,
+    /// Edges between items that only order the items, and are not data
+    /// dependencies.
+    ///
+    /// These are rendered as dashed edges in the info graphs.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub edges_ordering_only: Vec<[ItemId; 2]>,
 }
 
 impl FlowSpecInfo {
@@ -28,9 +34,17 @@ impl FlowSpecInfo {
         Self {
             flow_id,
             graph_info,
+            edges_ordering_only: Vec::new(),
         }
     }
 
+    /// Sets the edges between items that only order the items, and are not
+    /// data dependencies.
+    pub fn with_edges_ordering_only(mut self, edges_ordering_only: Vec<[ItemId; 2]>) -> Self {
+        self.edges_ordering_only = edges_ordering_only;
+        self
+    }
+
     /// Returns an [`InfoGraph`] that represents the progress of the flow's
     /// execution.
     pub fn to_progress_info_graph(&self) -> InfoGraph {
@@ -49,12 +63,14 @@ impl FlowSpecInfo {
 
         let edges = progress_node_edges(graph_info);
         let node_infos = node_infos(graph_info);
+        let tailwind_classes = edges_ordering_only_tailwind_classes(&self.edges_ordering_only);
 
         InfoGraph::builder()
             .with_direction(GraphDir::Vertical)
             .with_hierarchy(hierarchy)
             .with_edges(edges)
             .with_node_infos(node_infos)
+            .with_tailwind_classes(tailwind_classes)
             .build()
     }
 
@@ -83,12 +99,14 @@ impl FlowSpecInfo {
 
         let edges = outcome_node_edges(graph_info);
         let node_infos = node_infos(graph_info);
+        let tailwind_classes = edges_ordering_only_tailwind_classes(&self.edges_ordering_only);
 
         InfoGraph::builder()
             .with_direction(GraphDir::Vertical)
             .with_hierarchy(hierarchy)
             .with_edges(edges)
             .with_node_infos(node_infos)
+            .with_tailwind_classes(tailwind_classes)
             .build()
     }
 }
@@ -221,6 +239,31 @@ fn progress_node_edges(graph_info: &GraphInfo<ItemSpecInfo>) -> IndexMap<EdgeId,
     )
 }
 
+/// Returns the styles for edges that only order items, which are rendered as
+/// dashed lines.
+fn edges_ordering_only_tailwind_classes(edges_ordering_only: &[[ItemId; 2]]) -> TailwindClasses {
+    let mut tailwind_classes = TailwindClasses::new();
+    if edges_ordering_only.is_empty() {
+        return tailwind_classes;
+    }
+
+    let edge_classes = format!(
+        "{} [&>path]:[stroke-dasharray:4,2]",
+        tailwind_classes.edge_defaults()
+    );
+    edges_ordering_only
+        .iter()
+        .for_each(|[item_id_from, item_id_to]| {
+            let edge_id = EdgeId::try_from(format!("{item_id_from}__{item_id_to}")).expect(
+                "Expected `peace` `ItemId`s concatenated \
+                to be valid `dot_ix` `EdgeId`s.",
+            );
+            tailwind_classes.insert(TailwindKey::AnyId(edge_id.into()), edge_classes.clone());
+        });
+
+    tailwind_classes
+}
+
 /// Returns the list of edges between items in the graph.
 fn node_infos(graph_info: &GraphInfo<ItemSpecInfo>) -> IndexMap<NodeId, NodeInfo> {
     graph_info.iter_insertion_with_indices().fold(
@@ -0,0 +1,34 @@
+use peace_core::ItemId;
+use serde::{Deserialize, Serialize};
+
+/// Declarative definition of an item in a [`FlowSpec`].
+///
+/// [`FlowSpec`]: crate::FlowSpec
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FlowSpecItem {
+    /// ID of the item.
+    pub id: ItemId,
+    /// Name of the item's type, used to look up its constructor.
+    #[serde(rename = "type")]
+    pub item_type: String,
+    /// IDs of the items that must be applied before this item.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<ItemId>,
+}
+
+impl FlowSpecItem {
+    /// Returns a new `FlowSpecItem` with no dependencies.
+    pub fn new(id: ItemId, item_type: String) -> Self {
+        Self {
+            id,
+            item_type,
+            depends_on: Vec::new(),
+        }
+    }
+
+    /// Sets the IDs of the items that must be applied before this item.
+    pub fn with_depends_on(mut self, depends_on: Vec<ItemId>) -> Self {
+        self.depends_on = depends_on;
+        self
+    }
+}
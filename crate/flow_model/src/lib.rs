@@ -8,11 +8,15 @@ pub use dot_ix;
 pub use fn_graph::GraphInfo;
 
 pub use crate::{
-    flow_info::FlowInfo, flow_spec_info::FlowSpecInfo, item_info::ItemInfo,
-    item_spec_info::ItemSpecInfo,
+    flow_diff::{FlowDiff, FlowEdge, ItemRename},
+    flow_info::FlowInfo, flow_spec::FlowSpec, flow_spec_info::FlowSpecInfo,
+    flow_spec_item::FlowSpecItem, item_info::ItemInfo, item_spec_info::ItemSpecInfo,
 };
 
+mod flow_diff;
 mod flow_info;
+mod flow_spec;
 mod flow_spec_info;
+mod flow_spec_item;
 mod item_info;
 mod item_spec_info;
@@ -8,12 +8,21 @@
 pub use async_trait::async_trait;
 
 pub use crate::{
-    either::Either, presentable::Presentable, presentable_ext::PresentableExt, presenter::Presenter,
+    either::Either, message::Message, message_catalog::MessageCatalog,
+    presentable::Presentable, presentable_ext::PresentableExt, presenter::Presenter,
 };
 
+#[cfg(feature = "i18n")]
+pub use crate::message_catalog_error::MessageCatalogError;
+
+pub mod messages;
 pub mod presentable;
 
 mod either;
+mod message;
+mod message_catalog;
+#[cfg(feature = "i18n")]
+mod message_catalog_error;
 mod presentable_ext;
 mod presenter;
 
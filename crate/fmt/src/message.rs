@@ -0,0 +1,79 @@
+/// A user-facing message, identified by its ID in a [`MessageCatalog`].
+///
+/// The default text is English, and is used when the catalog has no
+/// translation for the message. Text may contain [Fluent] placeables such as
+/// `{ $item_id }`, which are replaced with the arguments the message is
+/// rendered with.
+///
+/// # Examples
+///
+/// ```rust
+/// use peace_fmt::Message;
+///
+/// const BLOCKED_BY: Message = Message::new("app-blocked-by", "blocked by `{ $item_id }`");
+///
+/// assert_eq!("blocked by `a`", BLOCKED_BY.text(&[("item_id", "a")]));
+/// ```
+///
+/// [`MessageCatalog`]: crate::MessageCatalog
+/// [Fluent]: https://projectfluent.org/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Message {
+    /// ID of the message in the catalog, e.g. `"peace-progress-done"`.
+    id: &'static str,
+    /// English text of the message.
+    text_default: &'static str,
+}
+
+impl Message {
+    /// Returns a new `Message`.
+    ///
+    /// # Parameters
+    ///
+    /// * `id`: ID of the message in the catalog, e.g. `"app-deploy-done"`.
+    /// * `text_default`: English text of the message.
+    pub const fn new(id: &'static str, text_default: &'static str) -> Self {
+        Self { id, text_default }
+    }
+
+    /// Returns the ID of the message in the catalog.
+    pub fn id(&self) -> &'static str {
+        self.id
+    }
+
+    /// Returns the English text of the message, before arguments are
+    /// substituted.
+    pub fn text_default(&self) -> &'static str {
+        self.text_default
+    }
+
+    /// Returns the English text of the message, with each `{ $name }`
+    /// placeable replaced by the argument with that name.
+    ///
+    /// Placeables without a matching argument are left as is.
+    pub fn text(&self, args: &[(&str, &str)]) -> String {
+        let mut text = String::with_capacity(self.text_default.len());
+        let mut remainder = self.text_default;
+        while let Some(start) = remainder.find('{') {
+            let Some(len) = remainder[start..].find('}') else {
+                break;
+            };
+            let placeable = &remainder[start..=start + len];
+            let arg_value = placeable[1..len]
+                .trim()
+                .strip_prefix('$')
+                .and_then(|name| {
+                    args.iter()
+                        .find(|(arg_name, _arg_value)| *arg_name == name)
+                        .map(|(_arg_name, arg_value)| *arg_value)
+                });
+
+            text.push_str(&remainder[..start]);
+            text.push_str(arg_value.unwrap_or(placeable));
+            remainder = &remainder[start + len + 1..];
+        }
+        text.push_str(remainder);
+
+        text
+    }
+}
@@ -0,0 +1,187 @@
+use std::fmt::{self, Debug};
+
+use crate::Message;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "i18n")] {
+        use std::{collections::BTreeMap, sync::Arc};
+
+        use fluent_bundle::{concurrent::FluentBundle, FluentArgs, FluentResource};
+        use unic_langid::LanguageIdentifier;
+
+        use crate::MessageCatalogError;
+    }
+}
+
+/// Translations of user-facing messages, and the locale to render them in.
+///
+/// Framework messages, such as progress messages and error help text, are
+/// rendered through this catalog, so that tools built on peace can show them
+/// in the user's language. Messages without a translation in the selected
+/// locale are rendered in English.
+///
+/// The catalog is set on the command context with
+/// `CmdCtxBuilder::with_message_catalog`, and the locale is selected with
+/// `CmdCtxBuilder::with_locale`.
+///
+/// # Features
+///
+/// ## `"i18n"`
+///
+/// Translations are added from [Fluent] sources with
+/// [`MessageCatalog::with_translations`]. Without this feature, all messages
+/// are rendered in English.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let message_catalog = MessageCatalog::new()
+///     .with_translations("fr-FR", include_str!("../locales/fr-FR.ftl"))?
+///     .with_locale("fr-FR");
+///
+/// let text = message_catalog.text(messages::PROGRESS_DONE, &[]);
+/// ```
+///
+/// [Fluent]: https://projectfluent.org/
+#[derive(Clone)]
+pub struct MessageCatalog {
+    /// Locale that messages are rendered in, e.g. `"fr-FR"`.
+    locale: String,
+    /// Translations for each locale.
+    #[cfg(feature = "i18n")]
+    bundles: BTreeMap<String, Arc<FluentBundle<FluentResource>>>,
+}
+
+impl MessageCatalog {
+    /// Locale of the messages' default text.
+    pub const LOCALE_DEFAULT: &'static str = "en-US";
+
+    /// Returns a new `MessageCatalog` without any translations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Selects the locale that messages are rendered in, e.g. `"fr-FR"`.
+    ///
+    /// If there are no translations for the locale, translations for its
+    /// language -- e.g. `"fr"` -- are used.
+    pub fn with_locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = locale.into();
+        self
+    }
+
+    /// Adds translations for a locale from a [Fluent] source.
+    ///
+    /// Translations previously added for the locale are replaced.
+    ///
+    /// # Parameters
+    ///
+    /// * `locale`: Locale of the translations, e.g. `"fr-FR"`.
+    /// * `ftl_source`: Fluent source, with one message per message ID.
+    ///
+    /// [Fluent]: https://projectfluent.org/
+    #[cfg(feature = "i18n")]
+    pub fn with_translations(
+        mut self,
+        locale: &str,
+        ftl_source: impl Into<String>,
+    ) -> Result<Self, MessageCatalogError> {
+        let language_identifier = locale.parse::<LanguageIdentifier>().map_err(|error| {
+            MessageCatalogError::LocaleParse {
+                locale: locale.to_string(),
+                error,
+            }
+        })?;
+        let resource = FluentResource::try_new(ftl_source.into()).map_err(
+            |(_resource, errors)| MessageCatalogError::FtlParse {
+                locale: locale.to_string(),
+                errors: errors.iter().map(ToString::to_string).collect(),
+            },
+        )?;
+
+        let mut bundle = FluentBundle::new_concurrent(vec![language_identifier]);
+        // Unicode isolation marks are rendered as-is by most terminals.
+        bundle.set_use_isolating(false);
+        bundle
+            .add_resource(resource)
+            .map_err(|errors| MessageCatalogError::FtlMessagesConflict {
+                locale: locale.to_string(),
+                errors: errors.iter().map(ToString::to_string).collect(),
+            })?;
+
+        self.bundles.insert(locale.to_string(), Arc::new(bundle));
+
+        Ok(self)
+    }
+
+    /// Returns the locale that messages are rendered in.
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Returns the text of the message in the selected locale, with each
+    /// placeable replaced by the argument with that name.
+    ///
+    /// The message's English text is returned if it has no translation.
+    pub fn text(&self, message: Message, args: &[(&str, &str)]) -> String {
+        self.translation(message.id(), args)
+            .unwrap_or_else(|| message.text(args))
+    }
+
+    /// Returns the translation of the message with the given ID in the
+    /// selected locale, if any.
+    ///
+    /// This is useful for messages that do not have default text, such as
+    /// the help text for an error code.
+    #[cfg(feature = "i18n")]
+    pub fn translation(&self, message_id: &str, args: &[(&str, &str)]) -> Option<String> {
+        let language = self.locale.split(['-', '_']).next().unwrap_or_default();
+        let bundle = self
+            .bundles
+            .get(&self.locale)
+            .or_else(|| self.bundles.get(language))?;
+        let pattern = bundle.get_message(message_id)?.value()?;
+
+        let fluent_args = args
+            .iter()
+            .fold(FluentArgs::new(), |mut fluent_args, (name, value)| {
+                fluent_args.set(*name, *value);
+                fluent_args
+            });
+        let mut errors = Vec::new();
+        let text = bundle.format_pattern(pattern, Some(&fluent_args), &mut errors);
+
+        Some(text.into_owned())
+    }
+
+    /// Returns the translation of the message with the given ID in the
+    /// selected locale, if any.
+    ///
+    /// Without the `"i18n"` feature, there are no translations.
+    #[cfg(not(feature = "i18n"))]
+    pub fn translation(&self, _message_id: &str, _args: &[(&str, &str)]) -> Option<String> {
+        None
+    }
+}
+
+impl Debug for MessageCatalog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct("MessageCatalog");
+        debug_struct.field("locale", &self.locale);
+
+        #[cfg(feature = "i18n")]
+        debug_struct.field("translations", &self.bundles.keys().collect::<Vec<_>>());
+
+        debug_struct.finish()
+    }
+}
+
+impl Default for MessageCatalog {
+    fn default() -> Self {
+        Self {
+            locale: String::from(Self::LOCALE_DEFAULT),
+            #[cfg(feature = "i18n")]
+            bundles: BTreeMap::new(),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+/// Failed to add translations to a [`MessageCatalog`].
+///
+/// [`MessageCatalog`]: crate::MessageCatalog
+#[derive(Debug, thiserror::Error)]
+pub enum MessageCatalogError {
+    /// The locale is not a valid language identifier.
+    #[error("Failed to parse locale: `{locale}`.")]
+    LocaleParse {
+        /// The locale that failed to be parsed.
+        locale: String,
+        /// The underlying error.
+        #[source]
+        error: unic_langid::LanguageIdentifierError,
+    },
+
+    /// The Fluent source for the locale could not be parsed.
+    #[error("Failed to parse translations for `{locale}`: {}", errors.join(", "))]
+    FtlParse {
+        /// Locale of the translations.
+        locale: String,
+        /// Each parse error.
+        errors: Vec<String>,
+    },
+
+    /// The Fluent source for the locale defines a message more than once.
+    #[error("Translations for `{locale}` conflict: {}", errors.join(", "))]
+    FtlMessagesConflict {
+        /// Locale of the translations.
+        locale: String,
+        /// Each conflicting message.
+        errors: Vec<String>,
+    },
+}
@@ -0,0 +1,142 @@
+//! Framework messages that may be translated in a [`MessageCatalog`].
+//!
+//! Each message's ID is the ID to use in a translation's [Fluent] source, and
+//! each placeable -- such as `{ $item_id }` -- is an argument that the message
+//! is rendered with.
+//!
+//! Help text for framework errors does not have default text here, as it is
+//! part of each error. It may be translated using the message ID returned by
+//! [`help_message_id`].
+//!
+//! [`MessageCatalog`]: crate::MessageCatalog
+//! [Fluent]: https://projectfluent.org/
+
+use crate::Message;
+
+/// Progress message when an item is not applicable to be discovered or
+/// applied.
+pub const PROGRESS_NOT_APPLICABLE: Message =
+    Message::new("peace-progress-not-applicable", "not applicable");
+
+/// Progress message when an item is disabled for the profile.
+pub const PROGRESS_DISABLED: Message = Message::new("peace-progress-disabled", "disabled");
+
+/// Progress message when an item's stored state is used instead of
+/// discovering it.
+pub const PROGRESS_STORED_STATE_USED: Message =
+    Message::new("peace-progress-stored-state-used", "stored state used");
+
+/// Progress message when an item is being applied.
+pub const PROGRESS_IN_PROGRESS: Message = Message::new("peace-progress-in-progress", "in progress");
+
+/// Progress message when an item is being applied, with the reason it is
+/// applied.
+pub const PROGRESS_IN_PROGRESS_REASON: Message = Message::new(
+    "peace-progress-in-progress-reason",
+    "in progress: { $reason }",
+);
+
+/// Progress message when an item is already in its target state.
+pub const PROGRESS_NOTHING_TO_DO: Message =
+    Message::new("peace-progress-nothing-to-do", "nothing to do!");
+
+/// Progress message when an item has been applied.
+pub const PROGRESS_DONE: Message = Message::new("peace-progress-done", "done!");
+
+/// Progress message when an item is not applied because a predecessor
+/// failed.
+pub const PROGRESS_BLOCKED_BY: Message =
+    Message::new("peace-progress-blocked-by", "blocked by `{ $item_id }`");
+
+/// Progress message when an item is waiting to be approved.
+pub const PROGRESS_AWAITING_APPROVAL: Message =
+    Message::new("peace-progress-awaiting-approval", "awaiting approval");
+
+/// Progress message when an item has not sent progress within the heartbeat
+/// interval.
+pub const PROGRESS_STALLED: Message = Message::new(
+    "peace-progress-stalled",
+    "stalled: no progress for { $seconds }s",
+);
+
+/// Progress message when an item is being rolled back.
+pub const PROGRESS_ROLLING_BACK: Message =
+    Message::new("peace-progress-rolling-back", "rolling back");
+
+/// Progress message when an item has been rolled back because another item in
+/// its atomic group failed.
+pub const PROGRESS_ROLLED_BACK: Message = Message::new(
+    "peace-progress-rolled-back",
+    "rolled back as `{ $item_id }` failed",
+);
+
+/// Progress message when an item's stored state matches its discovered
+/// state.
+pub const PROGRESS_STATE_IN_SYNC: Message =
+    Message::new("peace-progress-state-in-sync", "State { $state_type } in sync");
+
+/// Progress message when an item's stored state differs from its discovered
+/// state.
+pub const PROGRESS_STATE_OUT_OF_SYNC: Message = Message::new(
+    "peace-progress-state-out-of-sync",
+    "State { $state_type } out of sync",
+);
+
+/// Progress message when an item's stored state is older than the flow's
+/// maximum staleness.
+pub const PROGRESS_STATE_EXPIRED: Message = Message::new(
+    "peace-progress-state-expired",
+    "State { $state_type } expired, using discovered state",
+);
+
+/// Heading of a command outcome report when one item failed.
+pub const REPORT_ITEM_FAILED: Message = Message::new("peace-report-item-failed", "1 item failed.");
+
+/// Heading of a command outcome report when multiple items failed.
+pub const REPORT_ITEMS_FAILED: Message =
+    Message::new("peace-report-items-failed", "{ $count } items failed.");
+
+/// Line in a command outcome report for each cause of an item's error.
+pub const REPORT_CAUSED_BY: Message =
+    Message::new("peace-report-caused-by", "caused by: { $cause }");
+
+/// Line in a command outcome report for each suggestion to fix an item's
+/// error.
+pub const REPORT_HELP: Message = Message::new("peace-report-help", "help: { $help }");
+
+/// Line in a command outcome report for the state an item reached.
+pub const REPORT_STATE_REACHED: Message =
+    Message::new("peace-report-state-reached", "state reached: { $state }");
+
+/// All framework messages, e.g. to generate a translation template.
+pub const ALL: &[Message] = &[
+    PROGRESS_NOT_APPLICABLE,
+    PROGRESS_DISABLED,
+    PROGRESS_STORED_STATE_USED,
+    PROGRESS_IN_PROGRESS,
+    PROGRESS_IN_PROGRESS_REASON,
+    PROGRESS_NOTHING_TO_DO,
+    PROGRESS_DONE,
+    PROGRESS_BLOCKED_BY,
+    PROGRESS_AWAITING_APPROVAL,
+    PROGRESS_STALLED,
+    PROGRESS_ROLLING_BACK,
+    PROGRESS_ROLLED_BACK,
+    PROGRESS_STATE_IN_SYNC,
+    PROGRESS_STATE_OUT_OF_SYNC,
+    PROGRESS_STATE_EXPIRED,
+    REPORT_ITEM_FAILED,
+    REPORT_ITEMS_FAILED,
+    REPORT_CAUSED_BY,
+    REPORT_HELP,
+    REPORT_STATE_REACHED,
+];
+
+/// Returns the message ID of the help text for an error's diagnostic code.
+///
+/// Path separators are not valid in message IDs, so `::` is replaced with
+/// `-`, e.g. `peace_rt_model::states_deserialize` becomes
+/// `peace_rt_model-states_deserialize-help`.
+pub fn help_message_id(code: &str) -> String {
+    format!("{}-help", code.replace("::", "-"))
+}
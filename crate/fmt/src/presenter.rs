@@ -246,4 +246,25 @@ pub trait Presenter<'output> {
         I: IntoIterator<Item = T>,
         T: 'f,
         F: Fn(T) -> &'f (P0, P1);
+
+    /// Presents a table.
+    ///
+    /// Each row should have the same number of cells as the `header`. The
+    /// table will be rendered with each column aligned to its widest cell.
+    /// i.e.
+    ///
+    /// ```md
+    /// | Item        | Action |
+    /// |-------------|--------|
+    /// | short_item  | ensure |
+    /// | longer_item | none   |
+    /// ```
+    ///
+    /// # Purposes
+    ///
+    /// * Comparing the same attributes across multiple entries.
+    async fn table<I, R>(&mut self, header: &[&str], rows: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = R>,
+        R: AsRef<[String]>;
 }
@@ -1,3 +1,5 @@
+use std::any::TypeId;
+
 use crate::AnySpecDataType;
 
 /// Runtime logic of how to look up values for each field in this struct.
@@ -28,6 +30,12 @@ pub trait AnySpecRt {
     /// safe. Adding a `where: Self: Sized` bound prevents the method from being
     /// called from `cmd_ctx_builder`.
     fn merge(&mut self, other: &dyn AnySpecDataType);
+    /// Returns the `TypeId`s of the data read by `*Spec::MappingFn`s within
+    /// this `Spec`.
+    ///
+    /// This is used to verify that an item whose params are mapped from
+    /// another item's `State` runs after that item.
+    fn mapping_fn_arg_type_ids(&self) -> Vec<TypeId>;
 }
 
 impl<T> AnySpecRt for Box<T>
@@ -44,4 +52,8 @@ where
     {
         self.as_mut().merge(other)
     }
+
+    fn mapping_fn_arg_type_ids(&self) -> Vec<TypeId> {
+        self.as_ref().mapping_fn_arg_type_ids()
+    }
 }
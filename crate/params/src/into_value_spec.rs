@@ -0,0 +1,75 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use crate::{FromFunc, Func, MappingFn, MappingFnImpl, ValueSpec};
+
+/// Converts a value, `ValueSpec`, or mapping function into a `ValueSpec<T>`.
+///
+/// This is used by the generated `with_*_spec` builder setters, so that a
+/// field's spec can be provided by name regardless of how its value is
+/// determined:
+///
+/// ```rust,ignore
+/// let params_spec = FileDownloadParams::<WebApp>::field_wise_spec()
+///     .with_src_spec(Url::parse("https://../web_app.tar")?) // direct value
+///     .with_dest_spec(|workspace_dir: &WorkspaceDir| {
+///         Some(workspace_dir.join("web_app.tar"))
+///     }) // mapping function
+///     .build();
+/// ```
+///
+/// The `Marker` type parameter distinguishes the implementations, and is
+/// inferred by the compiler.
+pub trait IntoValueSpec<T, Marker>
+where
+    T: Clone + Debug + Send + Sync + 'static,
+{
+    /// Returns the `ValueSpec` for the field.
+    ///
+    /// # Parameters
+    ///
+    /// * `field_name`: Name of the field within its parent struct, used when
+    ///   constructing a mapping function.
+    fn into_value_spec(self, field_name: Option<String>) -> ValueSpec<T>;
+}
+
+/// Marker for `IntoValueSpec` implemented for a direct value.
+#[derive(Debug)]
+pub struct IntoValueSpecValue;
+
+/// Marker for `IntoValueSpec` implemented for a `ValueSpec`.
+#[derive(Debug)]
+pub struct IntoValueSpecSpec;
+
+/// Marker for `IntoValueSpec` implemented for a mapping function.
+#[derive(Debug)]
+pub struct IntoValueSpecMappingFn<Args>(PhantomData<Args>);
+
+impl<T> IntoValueSpec<T, IntoValueSpecValue> for T
+where
+    T: Clone + Debug + Send + Sync + 'static,
+{
+    fn into_value_spec(self, _field_name: Option<String>) -> ValueSpec<T> {
+        ValueSpec::Value { value: self }
+    }
+}
+
+impl<T> IntoValueSpec<T, IntoValueSpecSpec> for ValueSpec<T>
+where
+    T: Clone + Debug + Send + Sync + 'static,
+{
+    fn into_value_spec(self, _field_name: Option<String>) -> ValueSpec<T> {
+        self
+    }
+}
+
+impl<T, F, Args> IntoValueSpec<T, IntoValueSpecMappingFn<Args>> for F
+where
+    T: Clone + Debug + Send + Sync + 'static,
+    F: Func<Option<T>, Args>,
+    MappingFnImpl<T, F, Args>: FromFunc<F> + MappingFn<Output = T>,
+{
+    fn into_value_spec(self, field_name: Option<String>) -> ValueSpec<T> {
+        let mapping_fn = <MappingFnImpl<T, F, Args> as FromFunc<F>>::from_func(field_name, self);
+        ValueSpec::MappingFn(Box::new(mapping_fn))
+    }
+}
@@ -72,21 +72,35 @@ pub use crate::{
     field_name_and_type::FieldNameAndType,
     field_wise_spec_rt::FieldWiseSpecRt,
     func::{FromFunc, Func},
+    into_value_spec::{
+        IntoValueSpec, IntoValueSpecMappingFn, IntoValueSpecSpec, IntoValueSpecValue,
+    },
+    mapping_expr::MappingExpr,
+    mapping_expr_error::MappingExprError,
     mapping_fn::MappingFn,
     mapping_fn_impl::MappingFnImpl,
     params::Params,
     params_fieldless::ParamsFieldless,
+    params_key_name::params_key_name,
     params_resolve_error::ParamsResolveError,
     params_spec::ParamsSpec,
     params_spec_de::ParamsSpecDe,
     params_spec_fieldless::ParamsSpecFieldless,
     params_spec_fieldless_de::ParamsSpecFieldlessDe,
     params_specs::ParamsSpecs,
+    params_validation_error::ParamsValidationError,
+    params_validation_fn::ParamsValidationFn,
+    profile_params_by_key::ProfileParamsByKey,
+    profile_params_serialized::ProfileParamsSerialized,
+    value_resolution::ValueResolution,
     value_resolution_ctx::ValueResolutionCtx,
     value_resolution_mode::ValueResolutionMode,
+    value_resolution_source::ValueResolutionSource,
     value_spec::ValueSpec,
     value_spec_de::ValueSpecDe,
     value_spec_rt::ValueSpecRt,
+    workspace_params_by_key::WorkspaceParamsByKey,
+    workspace_params_serialized::WorkspaceParamsSerialized,
 };
 
 mod any_spec_data_type;
@@ -95,19 +109,31 @@ mod any_spec_rt_boxed;
 mod field_name_and_type;
 mod field_wise_spec_rt;
 mod func;
+mod into_value_spec;
+mod mapping_expr;
+mod mapping_expr_error;
 mod mapping_fn;
 mod mapping_fn_impl;
 mod params;
 mod params_fieldless;
+mod params_key_name;
 mod params_resolve_error;
 mod params_spec;
 mod params_spec_de;
 mod params_spec_fieldless;
 mod params_spec_fieldless_de;
 mod params_specs;
+mod params_validation_error;
+mod params_validation_fn;
+mod profile_params_by_key;
+mod profile_params_serialized;
 mod std_impl;
+mod value_resolution;
 mod value_resolution_ctx;
 mod value_resolution_mode;
+mod value_resolution_source;
 mod value_spec;
 mod value_spec_de;
 mod value_spec_rt;
+mod workspace_params_by_key;
+mod workspace_params_serialized;
@@ -0,0 +1,248 @@
+use std::fmt::{self, Debug};
+
+use peace_resources::{resources::ts::SetUp, Resources};
+use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
+
+use crate::{MappingExprError, ProfileParamsSerialized, WorkspaceParamsSerialized};
+
+/// Serializable expression that computes a field's value from workspace and
+/// profile params.
+///
+/// Unlike mapping functions, expressions are stored in the
+/// `params_specs_file` and re-loaded in subsequent command context builds, so
+/// they do not need to be provided on every invocation.
+///
+/// Params are read through their serialized form, so a value within a param
+/// can be referenced using a [JSON pointer], e.g. `/dir` for the `dir` field of
+/// a struct param.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// // "${repo_path}/target/${profile}/app.tar", lowercased
+/// let expr = MappingExpr::concat(vec![
+///     MappingExpr::workspace_param(&WorkspaceParamsKey::Repo, "/path"),
+///     MappingExpr::literal("/target/"),
+///     MappingExpr::profile_param(&ProfileParamsKey::Profile, ""),
+///     MappingExpr::literal("/app.tar"),
+/// ])
+/// .lowercase();
+///
+/// let params_spec = FileDownloadParams::field_wise_spec()
+///     .with_dest_from_expr(expr)
+///     // ..
+///     .build();
+/// ```
+///
+/// [JSON pointer]: https://datatracker.ietf.org/doc/html/rfc6901
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MappingExpr {
+    /// A literal string.
+    Literal(String),
+    /// Value within the workspace param with the given key.
+    WorkspaceParam {
+        /// Name of the workspace params key.
+        key: String,
+        /// JSON pointer to the value within the param.
+        ///
+        /// An empty pointer references the whole param.
+        pointer: String,
+    },
+    /// Value within the profile param with the given key.
+    ProfileParam {
+        /// Name of the profile params key.
+        key: String,
+        /// JSON pointer to the value within the param.
+        ///
+        /// An empty pointer references the whole param.
+        pointer: String,
+    },
+    /// Concatenates the string value of each expression.
+    Concat(Vec<MappingExpr>),
+    /// Lowercases the string value of the expression.
+    Lowercase(Box<MappingExpr>),
+    /// Uppercases the string value of the expression.
+    Uppercase(Box<MappingExpr>),
+    /// Trims leading and trailing whitespace from the string value of the
+    /// expression.
+    Trim(Box<MappingExpr>),
+    /// Replaces all occurrences of `from` with `to` in the string value of the
+    /// expression.
+    Replace {
+        /// The expression whose value to replace within.
+        expr: Box<MappingExpr>,
+        /// The string to replace.
+        from: String,
+        /// The string to replace with.
+        to: String,
+    },
+}
+
+impl MappingExpr {
+    /// Returns an expression for a literal string.
+    pub fn literal(value: impl Into<String>) -> Self {
+        Self::Literal(value.into())
+    }
+
+    /// Returns an expression for a value within the workspace param with the
+    /// given key.
+    pub fn workspace_param<K>(key: &K, pointer: impl Into<String>) -> Self
+    where
+        K: Debug + Serialize,
+    {
+        Self::WorkspaceParam {
+            key: crate::params_key_name(key),
+            pointer: pointer.into(),
+        }
+    }
+
+    /// Returns an expression for a value within the profile param with the
+    /// given key.
+    pub fn profile_param<K>(key: &K, pointer: impl Into<String>) -> Self
+    where
+        K: Debug + Serialize,
+    {
+        Self::ProfileParam {
+            key: crate::params_key_name(key),
+            pointer: pointer.into(),
+        }
+    }
+
+    /// Returns an expression that concatenates the string value of each
+    /// expression.
+    pub fn concat(exprs: Vec<MappingExpr>) -> Self {
+        Self::Concat(exprs)
+    }
+
+    /// Returns an expression that lowercases this expression's string value.
+    pub fn lowercase(self) -> Self {
+        Self::Lowercase(Box::new(self))
+    }
+
+    /// Returns an expression that uppercases this expression's string value.
+    pub fn uppercase(self) -> Self {
+        Self::Uppercase(Box::new(self))
+    }
+
+    /// Returns an expression that trims this expression's string value.
+    pub fn trim(self) -> Self {
+        Self::Trim(Box::new(self))
+    }
+
+    /// Returns an expression that replaces all occurrences of `from` with `to`
+    /// in this expression's string value.
+    pub fn replace(self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        Self::Replace {
+            expr: Box::new(self),
+            from: from.into(),
+            to: to.into(),
+        }
+    }
+
+    /// Evaluates this expression against the params in `resources`.
+    pub fn eval(&self, resources: &Resources<SetUp>) -> Result<Value, MappingExprError> {
+        match self {
+            Self::Literal(value) => Ok(Value::String(value.clone())),
+            Self::WorkspaceParam { key, pointer } => {
+                let workspace_params_serialized =
+                    resources.try_borrow::<WorkspaceParamsSerialized>().ok();
+                let param = workspace_params_serialized
+                    .as_ref()
+                    .and_then(|workspace_params_serialized| workspace_params_serialized.get(key))
+                    .ok_or_else(|| MappingExprError::WorkspaceParamNotFound { key: key.clone() })?;
+
+                Self::pointer_resolve(param, key, pointer)
+            }
+            Self::ProfileParam { key, pointer } => {
+                let profile_params_serialized =
+                    resources.try_borrow::<ProfileParamsSerialized>().ok();
+                let param = profile_params_serialized
+                    .as_ref()
+                    .and_then(|profile_params_serialized| profile_params_serialized.get(key))
+                    .ok_or_else(|| MappingExprError::ProfileParamNotFound { key: key.clone() })?;
+
+                Self::pointer_resolve(param, key, pointer)
+            }
+            Self::Concat(exprs) => exprs
+                .iter()
+                .map(|expr| expr.eval_string(resources))
+                .collect::<Result<String, MappingExprError>>()
+                .map(Value::String),
+            Self::Lowercase(expr) => expr
+                .eval_string(resources)
+                .map(|value| Value::String(value.to_lowercase())),
+            Self::Uppercase(expr) => expr
+                .eval_string(resources)
+                .map(|value| Value::String(value.to_uppercase())),
+            Self::Trim(expr) => expr
+                .eval_string(resources)
+                .map(|value| Value::String(value.trim().to_string())),
+            Self::Replace { expr, from, to } => expr
+                .eval_string(resources)
+                .map(|value| Value::String(value.replace(from.as_str(), to))),
+        }
+    }
+
+    /// Evaluates this expression, and returns its value as a string.
+    fn eval_string(&self, resources: &Resources<SetUp>) -> Result<String, MappingExprError> {
+        match self.eval(resources)? {
+            Value::String(value) => Ok(value),
+            Value::Number(value) => Ok(value.to_string()),
+            Value::Bool(value) => Ok(value.to_string()),
+            value => Err(MappingExprError::ValueNotScalar { value }),
+        }
+    }
+
+    /// Returns the value at the JSON pointer within the param.
+    fn pointer_resolve(param: &Value, key: &str, pointer: &str) -> Result<Value, MappingExprError> {
+        let pointer_not_found = || MappingExprError::PointerNotFound {
+            key: key.to_string(),
+            pointer: pointer.to_string(),
+        };
+
+        if pointer.is_empty() {
+            return Ok(param.clone());
+        }
+
+        pointer
+            .strip_prefix('/')
+            .ok_or_else(pointer_not_found)?
+            .split('/')
+            .map(|token| token.replace("~1", "/").replace("~0", "~"))
+            .try_fold(param, |value, token| match value {
+                Value::Mapping(mapping) => mapping.get(token.as_str()),
+                Value::Sequence(sequence) => token
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|index| sequence.get(index)),
+                _ => None,
+            })
+            .cloned()
+            .ok_or_else(pointer_not_found)
+    }
+}
+
+impl fmt::Display for MappingExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(value) => write!(f, "{value:?}"),
+            Self::WorkspaceParam { key, pointer } => write!(f, "workspace_param({key:?}){pointer}"),
+            Self::ProfileParam { key, pointer } => write!(f, "profile_param({key:?}){pointer}"),
+            Self::Concat(exprs) => {
+                write!(f, "concat(")?;
+                if let Some((first, remainder)) = exprs.split_first() {
+                    write!(f, "{first}")?;
+                    remainder
+                        .iter()
+                        .try_for_each(|expr| write!(f, ", {expr}"))?;
+                }
+                write!(f, ")")
+            }
+            Self::Lowercase(expr) => write!(f, "lowercase({expr})"),
+            Self::Uppercase(expr) => write!(f, "uppercase({expr})"),
+            Self::Trim(expr) => write!(f, "trim({expr})"),
+            Self::Replace { expr, from, to } => write!(f, "replace({expr}, {from:?}, {to:?})"),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+/// Failed to evaluate a [`MappingExpr`].
+///
+/// [`MappingExpr`]: crate::MappingExpr
+#[derive(Debug, thiserror::Error)]
+pub enum MappingExprError {
+    /// The workspace param referenced by the expression is not set.
+    #[error("Workspace param `{key}` is not set.")]
+    WorkspaceParamNotFound {
+        /// Name of the workspace params key.
+        key: String,
+    },
+
+    /// The profile param referenced by the expression is not set.
+    #[error("Profile param `{key}` is not set.")]
+    ProfileParamNotFound {
+        /// Name of the profile params key.
+        key: String,
+    },
+
+    /// The pointer does not reference a value within the param.
+    #[error("Param `{key}` does not contain a value at `{pointer}`.")]
+    PointerNotFound {
+        /// Name of the params key.
+        key: String,
+        /// The JSON pointer that was used.
+        pointer: String,
+    },
+
+    /// A string operation was applied to a value that is not a string, number,
+    /// or boolean.
+    #[error("Expected a string, number, or boolean, but the value was `{value:?}`.")]
+    ValueNotScalar {
+        /// The value that was evaluated.
+        value: serde_yaml::Value,
+    },
+
+    /// The evaluated value could not be deserialized into the field's type.
+    #[error("Failed to deserialize the evaluated value into `{type_name}`.")]
+    ValueDeserialize {
+        /// Name of the field's type.
+        type_name: String,
+        /// The underlying error.
+        #[source]
+        error: serde_yaml::Error,
+    },
+}
@@ -1,4 +1,4 @@
-use std::fmt::Debug;
+use std::{any::TypeId, fmt::Debug};
 
 use peace_resources::{resources::ts::SetUp, type_reg::untagged::DataType, Resources};
 use serde::{Serialize, Serializer};
@@ -48,6 +48,12 @@ pub trait MappingFn: Debug + DataType {
     /// Deserialized mapping functions will not hold any function logic, and
     /// Peace uses this function to determine if this is an empty `MappingFn`.
     fn is_valued(&self) -> bool;
+
+    /// Returns the `TypeId`s of the data that this mapping function reads.
+    ///
+    /// These are the `T` in the `Current<T>` / `Goal<T>` markers, or direct
+    /// resources, that the function's arguments are resolved from.
+    fn arg_type_ids(&self) -> Vec<TypeId>;
 }
 
 impl<T> Clone for Box<dyn MappingFn<Output = T>> {
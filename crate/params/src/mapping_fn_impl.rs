@@ -1,4 +1,5 @@
 use std::{
+    any::TypeId,
     fmt::{self, Debug},
     marker::PhantomData,
 };
@@ -9,6 +10,7 @@ use serde::{Deserialize, Serialize, Serializer};
 
 use crate::{
     FromFunc, Func, MappingFn, ParamsResolveError, ValueResolutionCtx, ValueResolutionMode,
+    ValueResolutionSource,
 };
 
 /// Wrapper around a mapping function so that it can be serialized.
@@ -144,7 +146,7 @@ macro_rules! impl_mapping_fn_impl {
                     ValueResolutionMode::ApplyDry => {
                         $(arg_resolve!(resources, value_resolution_ctx, ApplyDry, $var, $Arg);)+
 
-                        fn_map($(&$var,)+).ok_or(ParamsResolveError::FromMap {
+                        fn_map($(&$var,)+).ok_or_else(|| ParamsResolveError::FromMapFnNone {
                             value_resolution_ctx: value_resolution_ctx.clone(),
                             from_type_name: tynm::type_name::<($($Arg,)+)>(),
                         })
@@ -152,7 +154,7 @@ macro_rules! impl_mapping_fn_impl {
                     ValueResolutionMode::Current => {
                         $(arg_resolve!(resources, value_resolution_ctx, Current, $var, $Arg);)+
 
-                        fn_map($(&$var,)+).ok_or(ParamsResolveError::FromMap {
+                        fn_map($(&$var,)+).ok_or_else(|| ParamsResolveError::FromMapFnNone {
                             value_resolution_ctx: value_resolution_ctx.clone(),
                             from_type_name: tynm::type_name::<($($Arg,)+)>(),
                         })
@@ -160,7 +162,7 @@ macro_rules! impl_mapping_fn_impl {
                     ValueResolutionMode::Goal => {
                         $(arg_resolve!(resources, value_resolution_ctx, Goal, $var, $Arg);)+
 
-                        fn_map($(&$var,)+).ok_or(ParamsResolveError::FromMap {
+                        fn_map($(&$var,)+).ok_or_else(|| ParamsResolveError::FromMapFnNone {
                             value_resolution_ctx: value_resolution_ctx.clone(),
                             from_type_name: tynm::type_name::<($($Arg,)+)>(),
                         })
@@ -168,7 +170,7 @@ macro_rules! impl_mapping_fn_impl {
                     ValueResolutionMode::Clean => {
                         $(arg_resolve!(resources, value_resolution_ctx, Clean, $var, $Arg);)+
 
-                        fn_map($(&$var,)+).ok_or(ParamsResolveError::FromMap {
+                        fn_map($(&$var,)+).ok_or_else(|| ParamsResolveError::FromMapFnNone {
                             value_resolution_ctx: value_resolution_ctx.clone(),
                             from_type_name: tynm::type_name::<($($Arg,)+)>(),
                         })
@@ -273,7 +275,16 @@ macro_rules! impl_mapping_fn_impl {
                 resources: &Resources<SetUp>,
                 value_resolution_ctx: &mut ValueResolutionCtx,
             ) -> Result<<Self as MappingFn>::Output, ParamsResolveError> {
-                MappingFnImpl::<T, F, ($($Arg,)+)>::map(self, resources, value_resolution_ctx)
+                let value =
+                    MappingFnImpl::<T, F, ($($Arg,)+)>::map(self, resources, value_resolution_ctx)?;
+                value_resolution_ctx.value_resolution_push(
+                    ValueResolutionSource::MappingFn {
+                        from_type_names: vec![$(tynm::type_name::<$Arg>(),)+],
+                    },
+                    Some(&value),
+                );
+
+                Ok(value)
             }
 
             fn try_map(
@@ -281,12 +292,28 @@ macro_rules! impl_mapping_fn_impl {
                 resources: &Resources<SetUp>,
                 value_resolution_ctx: &mut ValueResolutionCtx,
             ) -> Result<Option<<Self as MappingFn>::Output>, ParamsResolveError> {
-                MappingFnImpl::<T, F, ($($Arg,)+)>::try_map(self, resources, value_resolution_ctx)
+                let value = MappingFnImpl::<T, F, ($($Arg,)+)>::try_map(
+                    self,
+                    resources,
+                    value_resolution_ctx,
+                )?;
+                value_resolution_ctx.value_resolution_push(
+                    ValueResolutionSource::MappingFn {
+                        from_type_names: vec![$(tynm::type_name::<$Arg>(),)+],
+                    },
+                    value.as_ref(),
+                );
+
+                Ok(value)
             }
 
             fn is_valued(&self) -> bool {
                 self.fn_map.is_some()
             }
+
+            fn arg_type_ids(&self) -> Vec<TypeId> {
+                vec![$(TypeId::of::<$Arg>(),)+]
+            }
         }
     };
 }
@@ -351,9 +378,10 @@ macro_rules! arg_resolve {
             BorrowedData::Marked(marked_data) => match marked_data.as_ref() {
                 Some(data) => data,
                 None => {
-                    return Err(ParamsResolveError::FromMap {
+                    return Err(ParamsResolveError::FromMapMarkerNone {
                         value_resolution_ctx: $value_resolution_ctx.clone(),
                         from_type_name: tynm::type_name::<$Arg>(),
+                        marker_type_name: tynm::type_name::<$value_resolution_mode<$Arg>>(),
                     });
                 }
             },
@@ -2,7 +2,7 @@ use std::fmt::Debug;
 
 use serde::{de::DeserializeOwned, Serialize};
 
-use crate::FieldWiseSpecRt;
+use crate::{FieldWiseSpecRt, ParamsValidationError};
 
 /// Input parameters to an item.
 ///
@@ -30,4 +30,24 @@ pub trait Params: Clone + Debug + Serialize + DeserializeOwned + Send + Sync + '
 
     /// Returns a builder to construct the `FieldWise` spec.
     fn field_wise_spec() -> Self::FieldWiseBuilder;
+
+    /// Validates the resolved params.
+    ///
+    /// This returns `Ok(())` by default. `#[derive(Params)]` implements this
+    /// by calling the function provided in `#[params(validate = "..")]`, if
+    /// any.
+    fn validate(&self) -> Result<(), ParamsValidationError> {
+        Ok(())
+    }
+
+    /// Validates constraints across multiple fields of the resolved params,
+    /// returning all failures.
+    ///
+    /// This returns no failures by default. `#[derive(Params)]` implements
+    /// this by calling each function provided in
+    /// `#[params(validate_with = "..")]`, e.g. to check that `min` is not
+    /// greater than `max`, or that mutually exclusive fields are not both set.
+    fn validate_constraints(&self) -> Vec<ParamsValidationError> {
+        Vec::new()
+    }
 }
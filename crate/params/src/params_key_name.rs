@@ -0,0 +1,27 @@
+use std::fmt::Debug;
+
+use serde::Serialize;
+
+/// Returns the name of a workspace or profile params key.
+///
+/// This is the key's serialized form, e.g. `"username"` for
+/// `String::from("username")`, and `"Username"` for `Key::Username`. Keys that
+/// do not serialize to a string are represented by their serialized YAML.
+///
+/// The name is used to reference a param by key in
+/// [`ValueSpec::FromWorkspaceParam`] and [`ValueSpec::FromProfileParam`].
+///
+/// [`ValueSpec::FromWorkspaceParam`]: crate::ValueSpec::FromWorkspaceParam
+/// [`ValueSpec::FromProfileParam`]: crate::ValueSpec::FromProfileParam
+pub fn params_key_name<K>(key: &K) -> String
+where
+    K: Debug + Serialize,
+{
+    match serde_yaml::to_value(key) {
+        Ok(serde_yaml::Value::String(key_name)) => key_name,
+        Ok(key_value) => serde_yaml::to_string(&key_value)
+            .map(|key_name| key_name.trim_end().to_string())
+            .unwrap_or_else(|_| format!("{key:?}")),
+        Err(_) => format!("{key:?}"),
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{FieldNameAndType, ValueResolutionCtx};
+use std::path::PathBuf;
+
+use peace_core::ItemId;
+
+use crate::{MappingExpr, MappingExprError, ValueResolutionCtx, ValueResolutionMode};
 
 /// Failed to resolve values for a `Params` object from `resources`.
 //
@@ -13,11 +17,7 @@ pub enum ParamsResolveError {
         diagnostic(
             code(peace_params::params_resolve_error::from),
             help("Make sure `{field_type_name}` has been inserted into `resources`.",
-                field_type_name = value_resolution_ctx
-                    .resolution_chain()
-                    .last()
-                    .map(FieldNameAndType::type_name)
-                    .unwrap_or(value_resolution_ctx.params_type_name())
+                field_type_name = value_resolution_ctx.field_type_name()
             )
         )
     )]
@@ -26,11 +26,7 @@ pub enum ParamsResolveError {
         ```rust\n\
         {value_resolution_ctx}\n\
         ```",
-        field_type_name = value_resolution_ctx
-            .resolution_chain()
-            .last()
-            .map(FieldNameAndType::type_name)
-            .unwrap_or(value_resolution_ctx.params_type_name()))]
+        field_type_name = value_resolution_ctx.field_type_name())]
     InMemory {
         /// Hierarchy of fields traversed to resolve the value.
         value_resolution_ctx: ValueResolutionCtx,
@@ -42,11 +38,7 @@ pub enum ParamsResolveError {
         diagnostic(
             code(peace_params::params_resolve_error::from_borrow_conflict),
             help("By design `{field_type_name}` must not be borrowed mutably.",
-                field_type_name = value_resolution_ctx
-                    .resolution_chain()
-                    .last()
-                    .map(FieldNameAndType::type_name)
-                    .unwrap_or(value_resolution_ctx.params_type_name())
+                field_type_name = value_resolution_ctx.field_type_name()
             )
         )
     )]
@@ -55,11 +47,7 @@ pub enum ParamsResolveError {
         ```rust\n\
         {value_resolution_ctx}\n\
         ```",
-        field_type_name = value_resolution_ctx
-            .resolution_chain()
-            .last()
-            .map(FieldNameAndType::type_name)
-            .unwrap_or(value_resolution_ctx.params_type_name())
+        field_type_name = value_resolution_ctx.field_type_name()
         )
     ]
     InMemoryBorrowConflict {
@@ -95,6 +83,78 @@ pub enum ParamsResolveError {
         from_type_name: String,
     },
 
+    /// A value to map to a field is `None` in its data marker in `resources`.
+    ///
+    /// This usually means the predecessor item that produces the value has
+    /// not had its state discovered or applied, e.g. `Current<T>` is `None`
+    /// because the predecessor does not exist yet.
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_params::params_resolve_error::from_map_marker_none),
+            help(
+                "`{marker_type_name}` is `None`, so the item that produces \
+                `{from_type_name}` has not been discovered or applied in this mode.\n\
+                Make sure the predecessor item's state is discovered or applied first.\n\
+                Value resolution mode is: {value_resolution_mode:?}",
+                value_resolution_mode = value_resolution_ctx.value_resolution_mode()
+            )
+        )
+    )]
+    #[error(
+        "`{marker_type_name}` is `None` when resolving `{field_type_name}` \
+        for item `{item_id}` to populate:\n\
+        \n\
+        ```rust\n\
+        {value_resolution_ctx}\n\
+        ```",
+        field_type_name = value_resolution_ctx.field_type_name(),
+        item_id = value_resolution_ctx.item_id()
+    )]
+    FromMapMarkerNone {
+        /// Hierarchy of fields traversed to resolve the value.
+        value_resolution_ctx: ValueResolutionCtx,
+        /// Name of the type from which to map the field value from.
+        ///
+        /// Corresponds to `U` in `Fn(&U) -> T`.
+        from_type_name: String,
+        /// Name of the data marker that is `None`, e.g. `Current<U>`.
+        marker_type_name: String,
+    },
+
+    /// The mapping function returned `None` for a field value.
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_params::params_resolve_error::from_map_fn_none),
+            help(
+                "The mapping function from `{from_type_name}` did not return a value.\n\
+                Make sure the function returns `Some` for the values that the \
+                predecessors produce.\n\
+                Value resolution mode is: {value_resolution_mode:?}",
+                value_resolution_mode = value_resolution_ctx.value_resolution_mode()
+            )
+        )
+    )]
+    #[error(
+        "Mapping function returned `None` when resolving `{field_type_name}` \
+        for item `{item_id}` to populate:\n\
+        \n\
+        ```rust\n\
+        {value_resolution_ctx}\n\
+        ```",
+        field_type_name = value_resolution_ctx.field_type_name(),
+        item_id = value_resolution_ctx.item_id()
+    )]
+    FromMapFnNone {
+        /// Hierarchy of fields traversed to resolve the value.
+        value_resolution_ctx: ValueResolutionCtx,
+        /// Names of the types that are passed to the mapping function.
+        ///
+        /// Corresponds to `(U0, U1, ..)` in `Fn(&U0, &U1, ..) -> T`.
+        from_type_name: String,
+    },
+
     /// Failed to borrow a value to map to a field from `resources`.
     #[cfg_attr(
         feature = "error_reporting",
@@ -118,4 +178,207 @@ pub enum ParamsResolveError {
         /// Corresponds to `U` in `Fn(&U) -> T`.
         from_type_name: String,
     },
+
+    /// Failed to resolve a field value from the workspace params.
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_params::params_resolve_error::from_workspace_param),
+            help(
+                "Make sure the workspace param `{key}` is set, \
+                and its value is a `{value_type_name}`."
+            )
+        )
+    )]
+    #[error(
+        "Failed to resolve workspace param `{key}` to populate:\n\
+        \n\
+        ```rust\n\
+        {value_resolution_ctx}\n\
+        ```"
+    )]
+    FromWorkspaceParam {
+        /// Hierarchy of fields traversed to resolve the value.
+        value_resolution_ctx: ValueResolutionCtx,
+        /// Name of the workspace params key.
+        key: String,
+        /// Name of the type of the field value.
+        value_type_name: String,
+    },
+
+    /// Failed to resolve a field value from the profile params.
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_params::params_resolve_error::from_profile_param),
+            help(
+                "Make sure the profile param `{key}` is set, \
+                and its value is a `{value_type_name}`."
+            )
+        )
+    )]
+    #[error(
+        "Failed to resolve profile param `{key}` to populate:\n\
+        \n\
+        ```rust\n\
+        {value_resolution_ctx}\n\
+        ```"
+    )]
+    FromProfileParam {
+        /// Hierarchy of fields traversed to resolve the value.
+        value_resolution_ctx: ValueResolutionCtx,
+        /// Name of the profile params key.
+        key: String,
+        /// Name of the type of the field value.
+        value_type_name: String,
+    },
+
+    /// Failed to evaluate a mapping expression.
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_params::params_resolve_error::mapping_expr),
+            help(
+                "Make sure the params referenced by `{expr}` are set, \
+                and the evaluated value matches the field's type."
+            )
+        )
+    )]
+    #[error(
+        "Failed to evaluate `{expr}` to populate:\n\
+        \n\
+        ```rust\n\
+        {value_resolution_ctx}\n\
+        ```"
+    )]
+    MappingExpr {
+        /// Hierarchy of fields traversed to resolve the value.
+        value_resolution_ctx: ValueResolutionCtx,
+        /// The expression that failed to evaluate.
+        expr: MappingExpr,
+        /// The underlying error.
+        #[source]
+        error: MappingExprError,
+    },
+
+    /// A `WorkspacePath` resolved outside the workspace directory.
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_params::params_resolve_error::workspace_path_outside_workspace),
+            help(
+                "Use a path within `{}`, or use `WorkspacePath::external` \
+                if the path is intentionally outside the workspace.",
+                workspace_dir.display()
+            )
+        )
+    )]
+    #[error(
+        "`{}` resolves outside the workspace directory `{}`, to populate:\n\
+        \n\
+        ```rust\n\
+        {value_resolution_ctx}\n\
+        ```",
+        workspace_path.display(),
+        workspace_dir.display()
+    )]
+    WorkspacePathOutsideWorkspace {
+        /// Hierarchy of fields traversed to resolve the value.
+        value_resolution_ctx: ValueResolutionCtx,
+        /// The path that was provided.
+        workspace_path: PathBuf,
+        /// The workspace directory.
+        workspace_dir: PathBuf,
+    },
+}
+
+impl ParamsResolveError {
+    /// Returns the hierarchy of fields traversed to resolve the value.
+    pub fn value_resolution_ctx(&self) -> &ValueResolutionCtx {
+        match self {
+            Self::InMemory {
+                value_resolution_ctx,
+            }
+            | Self::InMemoryBorrowConflict {
+                value_resolution_ctx,
+            }
+            | Self::FromMap {
+                value_resolution_ctx,
+                ..
+            }
+            | Self::FromMapMarkerNone {
+                value_resolution_ctx,
+                ..
+            }
+            | Self::FromMapFnNone {
+                value_resolution_ctx,
+                ..
+            }
+            | Self::FromMapBorrowConflict {
+                value_resolution_ctx,
+                ..
+            }
+            | Self::FromWorkspaceParam {
+                value_resolution_ctx,
+                ..
+            }
+            | Self::FromProfileParam {
+                value_resolution_ctx,
+                ..
+            }
+            | Self::MappingExpr {
+                value_resolution_ctx,
+                ..
+            }
+            | Self::WorkspacePathOutsideWorkspace {
+                value_resolution_ctx,
+                ..
+            } => value_resolution_ctx,
+        }
+    }
+
+    /// Returns the ID of the item whose params failed to resolve.
+    pub fn item_id(&self) -> &ItemId {
+        self.value_resolution_ctx().item_id()
+    }
+
+    /// Returns the path of the field that failed to resolve, e.g. `src.path`.
+    ///
+    /// This is empty when the whole `Params` value failed to resolve.
+    pub fn field_path(&self) -> String {
+        self.value_resolution_ctx().field_path()
+    }
+
+    /// Returns the name of the type of the field that failed to resolve.
+    pub fn field_type_name(&self) -> &str {
+        self.value_resolution_ctx().field_type_name()
+    }
+
+    /// Returns whether `Current<T>` or `Goal<T>` values were being resolved.
+    pub fn value_resolution_mode(&self) -> ValueResolutionMode {
+        self.value_resolution_ctx().value_resolution_mode()
+    }
+
+    /// Returns the name of the resource, data marker, or params key that was
+    /// missing, e.g. `Current<ServerIp>`.
+    ///
+    /// This is `None` when the failure is not caused by a missing value, such
+    /// as a borrow conflict.
+    pub fn missing_type_name(&self) -> Option<&str> {
+        match self {
+            Self::InMemory {
+                value_resolution_ctx,
+            } => Some(value_resolution_ctx.field_type_name()),
+            Self::FromMap { from_type_name, .. } => Some(from_type_name),
+            Self::FromMapMarkerNone {
+                marker_type_name, ..
+            } => Some(marker_type_name),
+            Self::FromWorkspaceParam { key, .. } | Self::FromProfileParam { key, .. } => Some(key),
+            Self::InMemoryBorrowConflict { .. }
+            | Self::FromMapFnNone { .. }
+            | Self::FromMapBorrowConflict { .. }
+            | Self::MappingExpr { .. }
+            | Self::WorkspacePathOutsideWorkspace { .. } => None,
+        }
+    }
 }
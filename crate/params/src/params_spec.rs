@@ -1,11 +1,15 @@
-use std::fmt::{self, Debug};
+use std::{
+    any::TypeId,
+    fmt::{self, Debug},
+};
 
 use peace_resources::{resources::ts::SetUp, BorrowFail, Resources};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use crate::{
     AnySpecDataType, AnySpecRt, FieldWiseSpecRt, MappingFn, MappingFnImpl, Params,
-    ParamsResolveError, ValueResolutionCtx, ValueSpecRt,
+    ParamsResolveError, ParamsValidationError, ParamsValidationFn, ValueResolutionCtx,
+    ValueResolutionSource, ValueSpecRt,
 };
 
 /// How to populate a field's value in an item's params.
@@ -91,6 +95,19 @@ where
         /// The field wise spec.
         field_wise_spec: T::FieldWiseSpec,
     },
+    /// Resolves this value through the wrapped `ParamsSpec`, and validates
+    /// the resolved value when the command context is built.
+    ///
+    /// This is serialized as the wrapped `ParamsSpec`. Similar to
+    /// `MappingFn`, the validation function cannot be deserialized, so the
+    /// user must provide it in subsequent command context builds.
+    #[serde(untagged, serialize_with = "params_spec_validated_serialize")]
+    Validated {
+        /// The spec to resolve the value with.
+        params_spec: Box<ParamsSpec<T>>,
+        /// Function to validate the resolved value.
+        validation_fn: ParamsValidationFn<T>,
+    },
 }
 
 impl<T> ParamsSpec<T>
@@ -104,6 +121,74 @@ where
         let mapping_fn = MappingFnImpl::from((field_name, f));
         Self::MappingFn(Box::new(mapping_fn))
     }
+
+    /// Returns this spec with a function to validate the resolved value.
+    ///
+    /// Validation is run when the command context is built, and failures
+    /// across all items are returned together, instead of failing during
+    /// apply.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let params_spec = ParamsSpec::from(MyParams::new(port)).with_validation(|my_params| {
+    ///     if my_params.port() >= 1024 {
+    ///         Ok(())
+    ///     } else {
+    ///         Err(ParamsValidationError::field("port", "Must be 1024 or above."))
+    ///     }
+    /// });
+    /// ```
+    pub fn with_validation<F>(self, f: F) -> Self
+    where
+        F: Fn(&T) -> Result<(), ParamsValidationError> + Send + Sync + 'static,
+    {
+        Self::Validated {
+            params_spec: Box::new(self),
+            validation_fn: ParamsValidationFn::new(f),
+        }
+    }
+
+    /// Validates the given resolved value, returning all validation failures.
+    ///
+    /// This runs [`Params::validate`], [`Params::validate_constraints`], as
+    /// well as each validation function provided through
+    /// [`ParamsSpec::with_validation`].
+    pub fn validate(&self, params: &T) -> Vec<ParamsValidationError> {
+        let mut params_validation_errors = Vec::new();
+        if let Err(params_validation_error) = params.validate() {
+            params_validation_errors.push(params_validation_error);
+        }
+        params_validation_errors.extend(params.validate_constraints());
+
+        let mut params_spec = self;
+        while let Self::Validated {
+            params_spec: params_spec_inner,
+            validation_fn,
+        } = params_spec
+        {
+            if let Err(params_validation_error) = validation_fn.validate(params) {
+                params_validation_errors.push(params_validation_error);
+            }
+            params_spec = params_spec_inner;
+        }
+
+        params_validation_errors
+    }
+}
+
+/// Serializes the wrapped `ParamsSpec` of a `ParamsSpec::Validated`.
+#[allow(clippy::borrowed_box)] // Signature is determined by `serde`.
+fn params_spec_validated_serialize<T, S>(
+    params_spec: &Box<ParamsSpec<T>>,
+    _validation_fn: &ParamsValidationFn<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    T: Params,
+    S: serde::Serializer,
+{
+    params_spec.serialize(serializer)
 }
 
 impl<T> Debug for ParamsSpec<T>
@@ -119,6 +204,14 @@ where
             Self::FieldWise { field_wise_spec } => {
                 f.debug_tuple("FieldWise").field(field_wise_spec).finish()
             }
+            Self::Validated {
+                params_spec,
+                validation_fn,
+            } => f
+                .debug_struct("Validated")
+                .field("params_spec", params_spec)
+                .field("validation_fn", validation_fn)
+                .finish(),
         }
     }
 }
@@ -143,9 +236,17 @@ where
         value_resolution_ctx: &mut ValueResolutionCtx,
     ) -> Result<T, ParamsResolveError> {
         match self {
-            ParamsSpec::Value { value } => Ok(value.clone()),
+            ParamsSpec::Value { value } => {
+                value_resolution_ctx
+                    .value_resolution_push(ValueResolutionSource::Value, Some(value));
+                Ok(value.clone())
+            }
             ParamsSpec::Stored | ParamsSpec::InMemory => match resources.try_borrow::<T>() {
-                Ok(value) => Ok((*value).clone()),
+                Ok(value) => {
+                    value_resolution_ctx
+                        .value_resolution_push(self.resources_resolution_source(), Some(&*value));
+                    Ok((*value).clone())
+                }
                 Err(borrow_fail) => match borrow_fail {
                     BorrowFail::ValueNotFound => Err(ParamsResolveError::InMemory {
                         value_resolution_ctx: value_resolution_ctx.clone(),
@@ -161,6 +262,9 @@ where
             ParamsSpec::FieldWise { field_wise_spec } => {
                 field_wise_spec.resolve(resources, value_resolution_ctx)
             }
+            ParamsSpec::Validated { params_spec, .. } => {
+                params_spec.resolve(resources, value_resolution_ctx)
+            }
         }
     }
 
@@ -170,11 +274,23 @@ where
         value_resolution_ctx: &mut ValueResolutionCtx,
     ) -> Result<T::Partial, ParamsResolveError> {
         match self {
-            ParamsSpec::Value { value } => Ok(T::Partial::from((*value).clone())),
+            ParamsSpec::Value { value } => {
+                value_resolution_ctx
+                    .value_resolution_push(ValueResolutionSource::Value, Some(value));
+                Ok(T::Partial::from((*value).clone()))
+            }
             ParamsSpec::Stored | ParamsSpec::InMemory => match resources.try_borrow::<T>() {
-                Ok(value) => Ok(T::Partial::from((*value).clone())),
+                Ok(value) => {
+                    value_resolution_ctx
+                        .value_resolution_push(self.resources_resolution_source(), Some(&*value));
+                    Ok(T::Partial::from((*value).clone()))
+                }
                 Err(borrow_fail) => match borrow_fail {
-                    BorrowFail::ValueNotFound => Ok(T::Partial::default()),
+                    BorrowFail::ValueNotFound => {
+                        value_resolution_ctx
+                            .value_resolution_push::<T>(self.resources_resolution_source(), None);
+                        Ok(T::Partial::default())
+                    }
                     BorrowFail::BorrowConflictImm | BorrowFail::BorrowConflictMut => {
                         Err(ParamsResolveError::InMemoryBorrowConflict {
                             value_resolution_ctx: value_resolution_ctx.clone(),
@@ -188,6 +304,22 @@ where
             ParamsSpec::FieldWise { field_wise_spec } => {
                 field_wise_spec.resolve_partial(resources, value_resolution_ctx)
             }
+            ParamsSpec::Validated { params_spec, .. } => {
+                params_spec.resolve_partial(resources, value_resolution_ctx)
+            }
+        }
+    }
+
+    /// Returns the `ValueResolutionSource` for a value read from `resources`.
+    fn resources_resolution_source(&self) -> ValueResolutionSource {
+        let type_name = tynm::type_name::<T>();
+        match self {
+            ParamsSpec::Stored => ValueResolutionSource::Stored { type_name },
+            ParamsSpec::Value { .. }
+            | ParamsSpec::InMemory
+            | ParamsSpec::MappingFn(_)
+            | ParamsSpec::FieldWise { .. }
+            | ParamsSpec::Validated { .. } => ValueResolutionSource::InMemory { type_name },
         }
     }
 }
@@ -209,6 +341,7 @@ where
             Self::Value { .. } | Self::InMemory => true,
             Self::MappingFn(mapping_fn) => mapping_fn.is_valued(),
             Self::FieldWise { field_wise_spec } => field_wise_spec.is_usable(),
+            Self::Validated { params_spec, .. } => params_spec.is_usable(),
         }
     }
 
@@ -238,7 +371,11 @@ where
             Self::FieldWise { field_wise_spec } => {
                 match other {
                     // Don't merge stored field wise specs over provided specs.
-                    Self::Stored | Self::Value { .. } | Self::InMemory | Self::MappingFn(_) => {}
+                    Self::Stored
+                    | Self::Value { .. }
+                    | Self::InMemory
+                    | Self::MappingFn(_)
+                    | Self::Validated { .. } => {}
 
                     // Merge specs fieldwise.
                     Self::FieldWise {
@@ -246,6 +383,20 @@ where
                     } => AnySpecRt::merge(field_wise_spec, field_wise_spec_other),
                 }
             }
+
+            // Merge the wrapped spec, keeping the validation function.
+            Self::Validated { params_spec, .. } => {
+                AnySpecRt::merge(params_spec.as_mut(), other_boxed)
+            }
+        }
+    }
+
+    fn mapping_fn_arg_type_ids(&self) -> Vec<TypeId> {
+        match self {
+            Self::Stored | Self::Value { .. } | Self::InMemory => Vec::new(),
+            Self::MappingFn(mapping_fn) => mapping_fn.arg_type_ids(),
+            Self::FieldWise { field_wise_spec } => field_wise_spec.mapping_fn_arg_type_ids(),
+            Self::Validated { params_spec, .. } => params_spec.mapping_fn_arg_type_ids(),
         }
     }
 }
@@ -1,11 +1,14 @@
-use std::fmt::{self, Debug};
+use std::{
+    any::TypeId,
+    fmt::{self, Debug},
+};
 
 use peace_resources::{resources::ts::SetUp, BorrowFail, Resources};
 use serde::{Deserialize, Serialize};
 
 use crate::{
     AnySpecDataType, AnySpecRt, MappingFn, MappingFnImpl, ParamsFieldless, ParamsResolveError,
-    ValueResolutionCtx, ValueSpecRt,
+    ValueResolutionCtx, ValueResolutionSource, ValueSpecRt,
 };
 
 /// How to populate a field's value in an item's params.
@@ -121,10 +124,20 @@ where
         value_resolution_ctx: &mut ValueResolutionCtx,
     ) -> Result<T, ParamsResolveError> {
         match self {
-            ParamsSpecFieldless::Value { value } => Ok(value.clone()),
+            ParamsSpecFieldless::Value { value } => {
+                value_resolution_ctx
+                    .value_resolution_push(ValueResolutionSource::Value, Some(value));
+                Ok(value.clone())
+            }
             ParamsSpecFieldless::Stored | ParamsSpecFieldless::InMemory => {
                 match resources.try_borrow::<T>() {
-                    Ok(value) => Ok((*value).clone()),
+                    Ok(value) => {
+                        value_resolution_ctx.value_resolution_push(
+                            self.resources_resolution_source(),
+                            Some(&*value),
+                        );
+                        Ok((*value).clone())
+                    }
                     Err(borrow_fail) => match borrow_fail {
                         BorrowFail::ValueNotFound => Err(ParamsResolveError::InMemory {
                             value_resolution_ctx: value_resolution_ctx.clone(),
@@ -149,12 +162,28 @@ where
         value_resolution_ctx: &mut ValueResolutionCtx,
     ) -> Result<T::Partial, ParamsResolveError> {
         match self {
-            ParamsSpecFieldless::Value { value } => Ok(T::Partial::from((*value).clone())),
+            ParamsSpecFieldless::Value { value } => {
+                value_resolution_ctx
+                    .value_resolution_push(ValueResolutionSource::Value, Some(value));
+                Ok(T::Partial::from((*value).clone()))
+            }
             ParamsSpecFieldless::Stored | ParamsSpecFieldless::InMemory => {
                 match resources.try_borrow::<T>() {
-                    Ok(value) => Ok(T::Partial::from((*value).clone())),
+                    Ok(value) => {
+                        value_resolution_ctx.value_resolution_push(
+                            self.resources_resolution_source(),
+                            Some(&*value),
+                        );
+                        Ok(T::Partial::from((*value).clone()))
+                    }
                     Err(borrow_fail) => match borrow_fail {
-                        BorrowFail::ValueNotFound => Ok(T::Partial::default()),
+                        BorrowFail::ValueNotFound => {
+                            value_resolution_ctx.value_resolution_push::<T>(
+                                self.resources_resolution_source(),
+                                None,
+                            );
+                            Ok(T::Partial::default())
+                        }
                         BorrowFail::BorrowConflictImm | BorrowFail::BorrowConflictMut => {
                             Err(ParamsResolveError::InMemoryBorrowConflict {
                                 value_resolution_ctx: value_resolution_ctx.clone(),
@@ -168,6 +197,17 @@ where
                 .map(|t| t.map(T::Partial::from).unwrap_or_default()),
         }
     }
+
+    /// Returns the `ValueResolutionSource` for a value read from `resources`.
+    fn resources_resolution_source(&self) -> ValueResolutionSource {
+        let type_name = tynm::type_name::<T>();
+        match self {
+            ParamsSpecFieldless::Stored => ValueResolutionSource::Stored { type_name },
+            ParamsSpecFieldless::Value { .. }
+            | ParamsSpecFieldless::InMemory
+            | ParamsSpecFieldless::MappingFn(_) => ValueResolutionSource::InMemory { type_name },
+        }
+    }
 }
 
 impl<T> AnySpecRt for ParamsSpecFieldless<T>
@@ -211,6 +251,13 @@ where
             Self::Value { .. } | Self::InMemory | Self::MappingFn(_) => {}
         }
     }
+
+    fn mapping_fn_arg_type_ids(&self) -> Vec<TypeId> {
+        match self {
+            Self::Stored | Self::Value { .. } | Self::InMemory => Vec::new(),
+            Self::MappingFn(mapping_fn) => mapping_fn.arg_type_ids(),
+        }
+    }
 }
 
 impl<T> ValueSpecRt for ParamsSpecFieldless<T>
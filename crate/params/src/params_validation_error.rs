@@ -0,0 +1,86 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// A resolved item params value failed validation.
+///
+/// This is returned by validation functions provided through
+/// [`ParamsSpec::with_validation`], `#[params(validate = "..")]`, or
+/// `#[params(validate_with = "..")]`.
+///
+/// [`ParamsSpec::with_validation`]: crate::ParamsSpec::with_validation
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParamsValidationError {
+    /// Names of the fields that failed validation, if applicable.
+    field_names: Vec<String>,
+    /// Reason the value is invalid.
+    message: String,
+}
+
+impl ParamsValidationError {
+    /// Returns a new `ParamsValidationError` for the params as a whole.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            field_names: Vec::new(),
+            message: message.into(),
+        }
+    }
+
+    /// Returns a new `ParamsValidationError` for a particular field.
+    pub fn field(field_name: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field_names: vec![field_name.into()],
+            message: message.into(),
+        }
+    }
+
+    /// Returns a new `ParamsValidationError` for a constraint across multiple
+    /// fields, e.g. `min` must not be greater than `max`.
+    pub fn fields<I, S>(field_names: I, message: impl Into<String>) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            field_names: field_names.into_iter().map(Into::into).collect(),
+            message: message.into(),
+        }
+    }
+
+    /// Returns the name of the first field that failed validation, if
+    /// applicable.
+    pub fn field_name(&self) -> Option<&str> {
+        self.field_names.first().map(String::as_str)
+    }
+
+    /// Returns the names of the fields that failed validation.
+    pub fn field_names(&self) -> &[String] {
+        &self.field_names
+    }
+
+    /// Returns the reason the value is invalid.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ParamsValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.field_names.is_empty() {
+            return self.message.fmt(f);
+        }
+
+        self.field_names
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, field_name)| {
+                if index != 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "`{field_name}`")
+            })?;
+        write!(f, ": {}", self.message)
+    }
+}
+
+impl std::error::Error for ParamsValidationError {}
@@ -0,0 +1,45 @@
+use std::{fmt, sync::Arc};
+
+use crate::ParamsValidationError;
+
+/// Function to validate resolved item params.
+///
+/// This is provided through [`ParamsSpec::with_validation`], and is run when
+/// the `CmdCtx` is built.
+///
+/// [`ParamsSpec::with_validation`]: crate::ParamsSpec::with_validation
+#[allow(clippy::type_complexity)]
+pub struct ParamsValidationFn<T>(
+    Arc<dyn Fn(&T) -> Result<(), ParamsValidationError> + Send + Sync + 'static>,
+);
+
+impl<T> ParamsValidationFn<T> {
+    /// Returns a new `ParamsValidationFn`.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&T) -> Result<(), ParamsValidationError> + Send + Sync + 'static,
+    {
+        Self(Arc::new(f))
+    }
+
+    /// Runs the validation function against the given params.
+    pub fn validate(&self, params: &T) -> Result<(), ParamsValidationError> {
+        (self.0)(params)
+    }
+}
+
+impl<T> Clone for ParamsValidationFn<T> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T> fmt::Debug for ParamsValidationFn<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let t_name = tynm::type_name::<T>();
+        write!(
+            f,
+            "ParamsValidationFn(Fn(&{t_name}) -> Result<(), ParamsValidationError>)"
+        )
+    }
+}
@@ -0,0 +1,46 @@
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+};
+
+/// Serialized profile params values, keyed by their [`params_key_name`].
+///
+/// This is inserted into `resources` when building a command context, so
+/// that [`MappingExpr`]s can read profile params without knowing their types.
+///
+/// [`params_key_name`]: crate::params_key_name
+/// [`MappingExpr`]: crate::MappingExpr
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProfileParamsSerialized(HashMap<String, serde_yaml::Value>);
+
+impl ProfileParamsSerialized {
+    /// Returns a new `ProfileParamsSerialized` map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty `ProfileParamsSerialized` map with the specified
+    /// capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(HashMap::with_capacity(capacity))
+    }
+
+    /// Returns the inner map.
+    pub fn into_inner(self) -> HashMap<String, serde_yaml::Value> {
+        self.0
+    }
+}
+
+impl Deref for ProfileParamsSerialized {
+    type Target = HashMap<String, serde_yaml::Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ProfileParamsSerialized {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
@@ -6,6 +6,7 @@ use std::ffi::OsString;
 use std::path::PathBuf;
 
 use peace_params_derive::value_impl;
+use peace_resources::paths::WorkspacePath;
 
 // IMPORTANT!
 //
@@ -16,7 +17,22 @@ use peace_params_derive::value_impl;
 // `#[value_spec(fieldless)]`, but will be treated as such.
 
 impl_value_for!(
-    bool, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, usize, isize, String, PathBuf,
+    bool,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    usize,
+    isize,
+    String,
+    PathBuf,
+    WorkspacePath,
 );
 
 // WASM doesn't support serialization of `OsString`s.
@@ -0,0 +1,73 @@
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::{FieldNameAndType, ValueResolutionSource};
+
+/// How a single value was resolved, and what it resolved to.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ValueResolution {
+    /// Hierarchy of fields traversed to reach this value.
+    ///
+    /// This is empty when the value is the whole `Item::Params`.
+    field_chain: Vec<FieldNameAndType>,
+    /// Where the value came from.
+    source: ValueResolutionSource,
+    /// `Debug` representation of the resolved value.
+    ///
+    /// `None` if no value was resolved, e.g. when a mapping function returns
+    /// `None`.
+    value: Option<String>,
+}
+
+impl ValueResolution {
+    /// Returns a new `ValueResolution`.
+    pub fn new(
+        field_chain: Vec<FieldNameAndType>,
+        source: ValueResolutionSource,
+        value: Option<String>,
+    ) -> Self {
+        Self {
+            field_chain,
+            source,
+            value,
+        }
+    }
+
+    /// Returns the hierarchy of fields traversed to reach this value.
+    pub fn field_chain(&self) -> &[FieldNameAndType] {
+        &self.field_chain
+    }
+
+    /// Returns the field names traversed to reach this value, joined with
+    /// `.`.
+    ///
+    /// This is empty when the value is the whole `Item::Params`.
+    pub fn field_path(&self) -> String {
+        self.field_chain
+            .iter()
+            .map(FieldNameAndType::field_name)
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    /// Returns where the value came from.
+    pub fn source(&self) -> &ValueResolutionSource {
+        &self.source
+    }
+
+    /// Returns the `Debug` representation of the resolved value, if any.
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+}
+
+impl fmt::Display for ValueResolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let source = &self.source;
+        match self.value.as_deref() {
+            Some(value) => write!(f, "{source}: {value}"),
+            None => write!(f, "{source}: no value"),
+        }
+    }
+}
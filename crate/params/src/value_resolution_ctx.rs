@@ -2,7 +2,7 @@ use std::fmt;
 
 use peace_core::ItemId;
 
-use crate::{FieldNameAndType, ValueResolutionMode};
+use crate::{FieldNameAndType, ValueResolution, ValueResolutionMode, ValueResolutionSource};
 
 /// Collects information about how a value is resolved.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -16,6 +16,9 @@ pub struct ValueResolutionCtx {
     params_type_name: String,
     /// Hierarchy of fields traversed to resolve this value.
     resolution_chain: Vec<FieldNameAndType>,
+    /// How each value was resolved, in resolution order.
+    #[allow(clippy::box_collection)] // Keeps `ParamsResolveError` small.
+    value_resolutions: Box<Vec<ValueResolution>>,
 }
 
 impl ValueResolutionCtx {
@@ -29,6 +32,7 @@ impl ValueResolutionCtx {
             item_id,
             params_type_name,
             resolution_chain: Vec::new(),
+            value_resolutions: Box::default(),
         }
     }
 
@@ -48,6 +52,28 @@ impl ValueResolutionCtx {
         self.resolution_chain.as_ref()
     }
 
+    /// Returns the path of the field being resolved, e.g. `src.path`.
+    ///
+    /// This is empty when the whole `Params` value is resolved.
+    pub fn field_path(&self) -> String {
+        self.resolution_chain
+            .iter()
+            .map(FieldNameAndType::field_name)
+            .collect::<Vec<&str>>()
+            .join(".")
+    }
+
+    /// Returns the name of the type of the field being resolved.
+    ///
+    /// This is the `Params` type name when the whole `Params` value is
+    /// resolved.
+    pub fn field_type_name(&self) -> &str {
+        self.resolution_chain
+            .last()
+            .map(FieldNameAndType::type_name)
+            .unwrap_or(&self.params_type_name)
+    }
+
     /// Appends a field name and type to the resolution chain.
     pub fn push(&mut self, field_name_and_type: FieldNameAndType) {
         self.resolution_chain.push(field_name_and_type);
@@ -57,6 +83,32 @@ impl ValueResolutionCtx {
     pub fn pop(&mut self) {
         self.resolution_chain.pop();
     }
+
+    /// Returns how each value was resolved, in resolution order.
+    pub fn value_resolutions(&self) -> &[ValueResolution] {
+        &self.value_resolutions
+    }
+
+    /// Returns how each value was resolved, in resolution order.
+    pub fn into_value_resolutions(self) -> Vec<ValueResolution> {
+        *self.value_resolutions
+    }
+
+    /// Records how the value at the current resolution chain was resolved.
+    ///
+    /// `value` is `None` if no value was resolved, e.g. when a mapping
+    /// function returns `None`.
+    pub fn value_resolution_push<T>(&mut self, source: ValueResolutionSource, value: Option<&T>)
+    where
+        T: fmt::Debug,
+    {
+        let value_resolution = ValueResolution::new(
+            self.resolution_chain.clone(),
+            source,
+            value.map(|value| format!("{value:?}")),
+        );
+        self.value_resolutions.push(value_resolution);
+    }
 }
 
 impl fmt::Display for ValueResolutionCtx {
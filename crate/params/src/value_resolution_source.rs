@@ -0,0 +1,71 @@
+use std::fmt;
+
+use serde::Serialize;
+
+/// Where a resolved value came from.
+///
+/// This is recorded in [`ValueResolutionCtx`] for each value that is resolved,
+/// so that users can tell how a param's value was determined.
+///
+/// [`ValueResolutionCtx`]: crate::ValueResolutionCtx
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub enum ValueResolutionSource {
+    /// The value was provided directly.
+    Value,
+    /// The value was read from `resources`, because the spec was `Stored`.
+    ///
+    /// This is only recorded when no stored spec replaced the `Stored`
+    /// spec, otherwise the stored spec's source is recorded.
+    Stored {
+        /// Name of the type read from `resources`.
+        type_name: String,
+    },
+    /// The value was read from `resources`.
+    InMemory {
+        /// Name of the type read from `resources`.
+        type_name: String,
+    },
+    /// The value was read from the workspace params with the given key.
+    WorkspaceParam {
+        /// Name of the workspace params key.
+        key: String,
+    },
+    /// The value was read from the profile params with the given key.
+    ProfileParam {
+        /// Name of the profile params key.
+        key: String,
+    },
+    /// The value was computed by a mapping function.
+    MappingFn {
+        /// Names of the types passed to the mapping function.
+        from_type_names: Vec<String>,
+    },
+    /// The value was computed by a mapping expression.
+    MappingExpr {
+        /// The expression that was evaluated.
+        expr: String,
+    },
+}
+
+impl fmt::Display for ValueResolutionSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Value => write!(f, "value"),
+            Self::Stored { type_name } => write!(f, "stored `{type_name}`"),
+            Self::InMemory { type_name } => write!(f, "in memory `{type_name}`"),
+            Self::WorkspaceParam { key } => write!(f, "workspace param `{key}`"),
+            Self::ProfileParam { key } => write!(f, "profile param `{key}`"),
+            Self::MappingFn { from_type_names } => {
+                write!(f, "mapping fn from (")?;
+                if let Some((first, remainder)) = from_type_names.split_first() {
+                    write!(f, "`{first}`")?;
+                    remainder
+                        .iter()
+                        .try_for_each(|type_name| write!(f, ", `{type_name}`"))?;
+                }
+                write!(f, ")")
+            }
+            Self::MappingExpr { expr } => write!(f, "mapping expr `{expr}`"),
+        }
+    }
+}
@@ -1,11 +1,21 @@
-use std::fmt::{self, Debug};
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt::{self, Debug},
+    ops::Deref,
+};
 
-use peace_resources::{resources::ts::SetUp, BorrowFail, Resources};
-use serde::{Deserialize, Serialize};
+use peace_resources::{
+    paths::{WorkspaceDir, WorkspacePath},
+    resources::ts::SetUp,
+    BorrowFail, Resource, Resources,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use crate::{
-    AnySpecDataType, AnySpecRt, MappingFn, MappingFnImpl, ParamsResolveError, ValueResolutionCtx,
-    ValueSpecRt,
+    AnySpecDataType, AnySpecRt, MappingExpr, MappingExprError, MappingFn, MappingFnImpl,
+    ParamsResolveError, ProfileParamsByKey, ValueResolutionCtx, ValueResolutionSource,
+    ValueSpecRt, WorkspaceParamsByKey,
 };
 
 /// How to populate a field's value in an item's params.
@@ -28,7 +38,10 @@ use crate::{
 /// 4. These `AnySpecRtBoxed`s are downcasted back to `ValueSpec<T>` when
 ///    resolving values for item params and params partials.
 #[derive(Clone, Serialize, Deserialize)]
-#[serde(from = "crate::ValueSpecDe<T>")]
+#[serde(
+    from = "crate::ValueSpecDe<T>",
+    bound(deserialize = "T: Clone + Debug + DeserializeOwned + Send + Sync + 'static")
+)]
 pub enum ValueSpec<T>
 where
     T: Clone + Debug + Send + Sync + 'static,
@@ -60,6 +73,34 @@ where
     /// The value may have been provided by workspace params, or
     /// inserted by a predecessor at runtime.
     InMemory,
+    /// Uses the value of the workspace param with the given key.
+    ///
+    /// The key is the [`params_key_name`] of the workspace params key. Unlike
+    /// `MappingFn`, this variant is serialized with its key, so it is
+    /// re-loaded in subsequent command context builds.
+    ///
+    /// [`params_key_name`]: crate::params_key_name
+    FromWorkspaceParam(String),
+    /// Uses the value of the profile param with the given key.
+    ///
+    /// The key is the [`params_key_name`] of the profile params key. Unlike
+    /// `MappingFn`, this variant is serialized with its key, so it is
+    /// re-loaded in subsequent command context builds.
+    ///
+    /// [`params_key_name`]: crate::params_key_name
+    FromProfileParam(String),
+    /// Uses the value computed by a [`MappingExpr`] from workspace and profile
+    /// params.
+    ///
+    /// Unlike `MappingFn`, this variant is serialized with its expression, so
+    /// it is re-loaded in subsequent command context builds.
+    MappingExpr {
+        /// The expression to evaluate.
+        expr: MappingExpr,
+        /// Deserializes the evaluated value into `T`.
+        #[serde(skip_serializing)]
+        value_deserialize: fn(serde_yaml::Value) -> Result<T, serde_yaml::Error>,
+    },
     /// Uses a mapped value loaded from `resources` at runtime.
     ///
     /// The value may have been provided by workspace params, or
@@ -84,6 +125,36 @@ where
         let mapping_fn = MappingFnImpl::from((field_name, f));
         Self::MappingFn(Box::new(mapping_fn))
     }
+
+    /// Returns a `ValueSpec` that uses the value of the workspace param with
+    /// the given key.
+    pub fn from_workspace_param<K>(key: &K) -> Self
+    where
+        K: Debug + Serialize,
+    {
+        Self::FromWorkspaceParam(crate::params_key_name(key))
+    }
+
+    /// Returns a `ValueSpec` that uses the value of the profile param with the
+    /// given key.
+    pub fn from_profile_param<K>(key: &K) -> Self
+    where
+        K: Debug + Serialize,
+    {
+        Self::FromProfileParam(crate::params_key_name(key))
+    }
+
+    /// Returns a `ValueSpec` that uses the value computed by the given
+    /// [`MappingExpr`].
+    pub fn from_expr(expr: MappingExpr) -> Self
+    where
+        T: DeserializeOwned,
+    {
+        Self::MappingExpr {
+            expr,
+            value_deserialize: serde_yaml::from_value::<T>,
+        }
+    }
 }
 
 impl<T> Debug for ValueSpec<T>
@@ -95,6 +166,11 @@ where
             Self::Stored => f.write_str("Stored"),
             Self::Value { value } => f.debug_tuple("Value").field(value).finish(),
             Self::InMemory => f.write_str("InMemory"),
+            Self::FromWorkspaceParam(key) => {
+                f.debug_tuple("FromWorkspaceParam").field(key).finish()
+            }
+            Self::FromProfileParam(key) => f.debug_tuple("FromProfileParam").field(key).finish(),
+            Self::MappingExpr { expr, .. } => f.debug_tuple("MappingExpr").field(expr).finish(),
             Self::MappingFn(mapping_fn) => f.debug_tuple("MappingFn").field(mapping_fn).finish(),
         }
     }
@@ -118,10 +194,18 @@ where
         resources: &Resources<peace_resources::resources::ts::SetUp>,
         value_resolution_ctx: &mut ValueResolutionCtx,
     ) -> Result<T, ParamsResolveError> {
-        match self {
-            ValueSpec::Value { value } => Ok(value.clone()),
+        let value = match self {
+            ValueSpec::Value { value } => {
+                value_resolution_ctx
+                    .value_resolution_push(ValueResolutionSource::Value, Some(value));
+                Ok(value.clone())
+            }
             ValueSpec::Stored | ValueSpec::InMemory => match resources.try_borrow::<T>() {
-                Ok(value) => Ok((*value).clone()),
+                Ok(value) => {
+                    value_resolution_ctx
+                        .value_resolution_push(self.resources_resolution_source(), Some(&*value));
+                    Ok((*value).clone())
+                }
                 Err(borrow_fail) => match borrow_fail {
                     BorrowFail::ValueNotFound => Err(ParamsResolveError::InMemory {
                         value_resolution_ctx: value_resolution_ctx.clone(),
@@ -133,8 +217,59 @@ where
                     }
                 },
             },
+            ValueSpec::FromWorkspaceParam(key) => {
+                match Self::param_by_key::<WorkspaceParamsByKey>(resources, key) {
+                    Some(value) => {
+                        value_resolution_ctx.value_resolution_push(
+                            ValueResolutionSource::WorkspaceParam { key: key.clone() },
+                            Some(&value),
+                        );
+                        Ok(value)
+                    }
+                    None => Err(ParamsResolveError::FromWorkspaceParam {
+                        value_resolution_ctx: value_resolution_ctx.clone(),
+                        key: key.clone(),
+                        value_type_name: tynm::type_name::<T>(),
+                    }),
+                }
+            }
+            ValueSpec::FromProfileParam(key) => {
+                match Self::param_by_key::<ProfileParamsByKey>(resources, key) {
+                    Some(value) => {
+                        value_resolution_ctx.value_resolution_push(
+                            ValueResolutionSource::ProfileParam { key: key.clone() },
+                            Some(&value),
+                        );
+                        Ok(value)
+                    }
+                    None => Err(ParamsResolveError::FromProfileParam {
+                        value_resolution_ctx: value_resolution_ctx.clone(),
+                        key: key.clone(),
+                        value_type_name: tynm::type_name::<T>(),
+                    }),
+                }
+            }
+            ValueSpec::MappingExpr {
+                expr,
+                value_deserialize,
+            } => Self::mapping_expr_eval(expr, *value_deserialize, resources)
+                .inspect(|value| {
+                    value_resolution_ctx.value_resolution_push(
+                        ValueResolutionSource::MappingExpr {
+                            expr: expr.to_string(),
+                        },
+                        Some(value),
+                    );
+                })
+                .map_err(|error| ParamsResolveError::MappingExpr {
+                    value_resolution_ctx: value_resolution_ctx.clone(),
+                    expr: expr.clone(),
+                    error,
+                }),
             ValueSpec::MappingFn(mapping_fn) => mapping_fn.map(resources, value_resolution_ctx),
-        }
+        }?;
+
+        Self::workspace_path_resolve(value, resources, value_resolution_ctx)
     }
 
     pub fn resolve_partial(
@@ -142,12 +277,24 @@ where
         resources: &Resources<SetUp>,
         value_resolution_ctx: &mut ValueResolutionCtx,
     ) -> Result<Option<T>, ParamsResolveError> {
-        match self {
-            ValueSpec::Value { value } => Ok(Some((*value).clone())),
+        let value = match self {
+            ValueSpec::Value { value } => {
+                value_resolution_ctx
+                    .value_resolution_push(ValueResolutionSource::Value, Some(value));
+                Ok(Some((*value).clone()))
+            }
             ValueSpec::Stored | ValueSpec::InMemory => match resources.try_borrow::<T>() {
-                Ok(value) => Ok(Some((*value).clone())),
+                Ok(value) => {
+                    value_resolution_ctx
+                        .value_resolution_push(self.resources_resolution_source(), Some(&*value));
+                    Ok(Some((*value).clone()))
+                }
                 Err(borrow_fail) => match borrow_fail {
-                    BorrowFail::ValueNotFound => Ok(None),
+                    BorrowFail::ValueNotFound => {
+                        value_resolution_ctx
+                            .value_resolution_push::<T>(self.resources_resolution_source(), None);
+                        Ok(None)
+                    }
                     BorrowFail::BorrowConflictImm | BorrowFail::BorrowConflictMut => {
                         Err(ParamsResolveError::InMemoryBorrowConflict {
                             value_resolution_ctx: value_resolution_ctx.clone(),
@@ -155,7 +302,127 @@ where
                     }
                 },
             },
+            ValueSpec::FromWorkspaceParam(key) => {
+                let value = Self::param_by_key::<WorkspaceParamsByKey>(resources, key);
+                value_resolution_ctx.value_resolution_push(
+                    ValueResolutionSource::WorkspaceParam { key: key.clone() },
+                    value.as_ref(),
+                );
+                Ok(value)
+            }
+            ValueSpec::FromProfileParam(key) => {
+                let value = Self::param_by_key::<ProfileParamsByKey>(resources, key);
+                value_resolution_ctx.value_resolution_push(
+                    ValueResolutionSource::ProfileParam { key: key.clone() },
+                    value.as_ref(),
+                );
+                Ok(value)
+            }
+            ValueSpec::MappingExpr {
+                expr,
+                value_deserialize,
+            } => match Self::mapping_expr_eval(expr, *value_deserialize, resources) {
+                Ok(value) => {
+                    value_resolution_ctx.value_resolution_push(
+                        ValueResolutionSource::MappingExpr {
+                            expr: expr.to_string(),
+                        },
+                        Some(&value),
+                    );
+                    Ok(Some(value))
+                }
+                Err(
+                    MappingExprError::WorkspaceParamNotFound { .. }
+                    | MappingExprError::ProfileParamNotFound { .. },
+                ) => {
+                    value_resolution_ctx.value_resolution_push::<T>(
+                        ValueResolutionSource::MappingExpr {
+                            expr: expr.to_string(),
+                        },
+                        None,
+                    );
+                    Ok(None)
+                }
+                Err(error) => Err(ParamsResolveError::MappingExpr {
+                    value_resolution_ctx: value_resolution_ctx.clone(),
+                    expr: expr.clone(),
+                    error,
+                }),
+            },
             ValueSpec::MappingFn(mapping_fn) => mapping_fn.try_map(resources, value_resolution_ctx),
+        }?;
+
+        value
+            .map(|value| Self::workspace_path_resolve(value, resources, value_resolution_ctx))
+            .transpose()
+    }
+
+    /// Returns a clone of the param with the given key, from the
+    /// `ParamsByKey` map in `resources`.
+    ///
+    /// Returns `None` if the map is not in `resources`, the key is not in the
+    /// map, or the param is not a `T`.
+    fn param_by_key<ParamsByKey>(resources: &Resources<SetUp>, key: &str) -> Option<T>
+    where
+        ParamsByKey: Resource + Deref<Target = HashMap<String, Box<dyn Resource>>>,
+    {
+        let params_by_key = resources.try_borrow::<ParamsByKey>().ok()?;
+        params_by_key
+            .get(key)
+            .and_then(|param| param.downcast_ref::<T>())
+            .cloned()
+    }
+
+    /// Evaluates the mapping expression, and deserializes its value into `T`.
+    fn mapping_expr_eval(
+        expr: &MappingExpr,
+        value_deserialize: fn(serde_yaml::Value) -> Result<T, serde_yaml::Error>,
+        resources: &Resources<SetUp>,
+    ) -> Result<T, MappingExprError> {
+        let value = expr.eval(resources)?;
+        value_deserialize(value).map_err(|error| MappingExprError::ValueDeserialize {
+            type_name: tynm::type_name::<T>(),
+            error,
+        })
+    }
+
+    /// Resolves the value against the `WorkspaceDir` if it is a
+    /// `WorkspacePath`, and returns other values unchanged.
+    ///
+    /// The value is also returned unchanged if `WorkspaceDir` is not present
+    /// in `resources`.
+    fn workspace_path_resolve(
+        mut value: T,
+        resources: &Resources<SetUp>,
+        value_resolution_ctx: &ValueResolutionCtx,
+    ) -> Result<T, ParamsResolveError> {
+        let value_any: &mut dyn Any = &mut value;
+        if let Some(workspace_path) = value_any.downcast_mut::<WorkspacePath>() {
+            if let Ok(workspace_dir) = resources.try_borrow::<WorkspaceDir>() {
+                *workspace_path = workspace_path.resolve(&workspace_dir).ok_or_else(|| {
+                    ParamsResolveError::WorkspacePathOutsideWorkspace {
+                        value_resolution_ctx: value_resolution_ctx.clone(),
+                        workspace_path: workspace_path.path().to_path_buf(),
+                        workspace_dir: workspace_dir.to_path_buf(),
+                    }
+                })?;
+            }
+        }
+
+        Ok(value)
+    }
+
+    /// Returns the `ValueResolutionSource` for a value read from `resources`.
+    fn resources_resolution_source(&self) -> ValueResolutionSource {
+        let type_name = tynm::type_name::<T>();
+        match self {
+            ValueSpec::Stored => ValueResolutionSource::Stored { type_name },
+            ValueSpec::Value { .. }
+            | ValueSpec::InMemory
+            | ValueSpec::FromWorkspaceParam(_)
+            | ValueSpec::FromProfileParam(_)
+            | ValueSpec::MappingExpr { .. }
+            | ValueSpec::MappingFn(_) => ValueResolutionSource::InMemory { type_name },
         }
     }
 }
@@ -167,7 +434,11 @@ where
     fn is_usable(&self) -> bool {
         match self {
             Self::Stored => false,
-            Self::Value { .. } | Self::InMemory => true,
+            Self::Value { .. }
+            | Self::InMemory
+            | Self::FromWorkspaceParam(_)
+            | Self::FromProfileParam(_)
+            | Self::MappingExpr { .. } => true,
             Self::MappingFn(mapping_fn) => mapping_fn.is_valued(),
         }
     }
@@ -192,7 +463,24 @@ where
             Self::Stored => *self = other.clone(),
 
             // Use set value / no change on these variants
-            Self::Value { .. } | Self::InMemory | Self::MappingFn(_) => {}
+            Self::Value { .. }
+            | Self::InMemory
+            | Self::FromWorkspaceParam(_)
+            | Self::FromProfileParam(_)
+            | Self::MappingExpr { .. }
+            | Self::MappingFn(_) => {}
+        }
+    }
+
+    fn mapping_fn_arg_type_ids(&self) -> Vec<TypeId> {
+        match self {
+            Self::Stored
+            | Self::Value { .. }
+            | Self::InMemory
+            | Self::FromWorkspaceParam(_)
+            | Self::FromProfileParam(_)
+            | Self::MappingExpr { .. } => Vec::new(),
+            Self::MappingFn(mapping_fn) => mapping_fn.arg_type_ids(),
         }
     }
 }
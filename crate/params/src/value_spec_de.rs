@@ -1,8 +1,8 @@
 use std::fmt::{self, Debug};
 
-use serde::Deserialize;
+use serde::{de::DeserializeOwned, Deserialize};
 
-use crate::{MappingFnImpl, ValueSpec};
+use crate::{MappingExpr, MappingFnImpl, ValueSpec};
 
 type FnPlaceholder<T> = fn(&()) -> Option<T>;
 
@@ -36,6 +36,15 @@ pub enum ValueSpecDe<T> {
     /// The value may have been provided by workspace params, or
     /// inserted by a predecessor at runtime.
     InMemory,
+    /// Uses the value of the workspace param with the given key.
+    FromWorkspaceParam(String),
+    /// Uses the value of the profile param with the given key.
+    FromProfileParam(String),
+    /// Uses the value computed by a mapping expression.
+    MappingExpr {
+        /// The expression to evaluate.
+        expr: MappingExpr,
+    },
     /// Look up some data populated by a predecessor, and compute the value
     /// from that data.
     MappingFn(MappingFnImpl<T, FnPlaceholder<T>, ((),)>),
@@ -50,6 +59,11 @@ where
             Self::Stored => f.write_str("Stored"),
             Self::Value { value } => f.debug_tuple("Value").field(value).finish(),
             Self::InMemory => f.write_str("InMemory"),
+            Self::FromWorkspaceParam(key) => {
+                f.debug_tuple("FromWorkspaceParam").field(key).finish()
+            }
+            Self::FromProfileParam(key) => f.debug_tuple("FromProfileParam").field(key).finish(),
+            Self::MappingExpr { expr } => f.debug_tuple("MappingExpr").field(expr).finish(),
             Self::MappingFn(mapping_fn_impl) => {
                 f.debug_tuple("MappingFn").field(&mapping_fn_impl).finish()
             }
@@ -59,13 +73,16 @@ where
 
 impl<T> From<ValueSpecDe<T>> for ValueSpec<T>
 where
-    T: Clone + Debug + Send + Sync + 'static,
+    T: Clone + Debug + DeserializeOwned + Send + Sync + 'static,
 {
     fn from(value_spec_de: ValueSpecDe<T>) -> Self {
         match value_spec_de {
             ValueSpecDe::Stored => ValueSpec::Stored,
             ValueSpecDe::Value { value } => ValueSpec::Value { value },
             ValueSpecDe::InMemory => ValueSpec::InMemory,
+            ValueSpecDe::FromWorkspaceParam(key) => ValueSpec::FromWorkspaceParam(key),
+            ValueSpecDe::FromProfileParam(key) => ValueSpec::FromProfileParam(key),
+            ValueSpecDe::MappingExpr { expr } => ValueSpec::from_expr(expr),
             ValueSpecDe::MappingFn(mapping_fn_impl) => {
                 ValueSpec::MappingFn(Box::new(mapping_fn_impl))
             }
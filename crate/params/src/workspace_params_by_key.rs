@@ -0,0 +1,49 @@
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+};
+
+use peace_resources::Resource;
+
+/// Workspace params values, keyed by their [`params_key_name`].
+///
+/// This is inserted into `resources` when building a command context, so
+/// that item params can reference workspace params by key through
+/// [`ValueSpec::FromWorkspaceParam`].
+///
+/// [`params_key_name`]: crate::params_key_name
+/// [`ValueSpec::FromWorkspaceParam`]: crate::ValueSpec::FromWorkspaceParam
+#[derive(Debug, Default)]
+pub struct WorkspaceParamsByKey(HashMap<String, Box<dyn Resource>>);
+
+impl WorkspaceParamsByKey {
+    /// Returns a new `WorkspaceParamsByKey` map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty `WorkspaceParamsByKey` map with the specified
+    /// capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(HashMap::with_capacity(capacity))
+    }
+
+    /// Returns the inner map.
+    pub fn into_inner(self) -> HashMap<String, Box<dyn Resource>> {
+        self.0
+    }
+}
+
+impl Deref for WorkspaceParamsByKey {
+    type Target = HashMap<String, Box<dyn Resource>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for WorkspaceParamsByKey {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
@@ -0,0 +1,46 @@
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+};
+
+/// Serialized workspace params values, keyed by their [`params_key_name`].
+///
+/// This is inserted into `resources` when building a command context, so
+/// that [`MappingExpr`]s can read workspace params without knowing their types.
+///
+/// [`params_key_name`]: crate::params_key_name
+/// [`MappingExpr`]: crate::MappingExpr
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WorkspaceParamsSerialized(HashMap<String, serde_yaml::Value>);
+
+impl WorkspaceParamsSerialized {
+    /// Returns a new `WorkspaceParamsSerialized` map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty `WorkspaceParamsSerialized` map with the specified
+    /// capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(HashMap::with_capacity(capacity))
+    }
+
+    /// Returns the inner map.
+    pub fn into_inner(self) -> HashMap<String, serde_yaml::Value> {
+        self.0
+    }
+}
+
+impl Deref for WorkspaceParamsSerialized {
+    type Target = HashMap<String, serde_yaml::Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for WorkspaceParamsSerialized {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
@@ -1,9 +1,13 @@
 use syn::{DeriveInput, Ident, ImplGenerics, Path, TypeGenerics, WhereClause};
 
-use crate::{spec_is_usable::is_usable_body, spec_merge::spec_merge};
+use crate::{
+    spec_is_usable::is_usable_body, spec_mapping_fn_arg_type_ids::mapping_fn_arg_type_ids_body,
+    spec_merge::spec_merge,
+};
 
 /// `impl AnySpecRt for ValueSpec`, so that Peace can tell if a spec is usable,
-/// and merge provided and stored params together.
+/// merge provided and stored params together, and find the data read by
+/// mapping functions.
 pub fn impl_any_spec_rt_for_field_wise(
     ast: &DeriveInput,
     generics_split: &(ImplGenerics, TypeGenerics, Option<&WhereClause>),
@@ -14,6 +18,8 @@ pub fn impl_any_spec_rt_for_field_wise(
 
     let is_usable_body = is_usable_body(ast, params_field_wise_name, peace_params_path);
     let spec_merge = spec_merge(ast, params_field_wise_name, peace_params_path);
+    let mapping_fn_arg_type_ids_body =
+        mapping_fn_arg_type_ids_body(ast, params_field_wise_name, peace_params_path);
 
     quote! {
         impl #impl_generics #peace_params_path::AnySpecRt
@@ -25,6 +31,10 @@ pub fn impl_any_spec_rt_for_field_wise(
             }
 
             #spec_merge
+
+            fn mapping_fn_arg_type_ids(&self) -> Vec<::std::any::TypeId> {
+                #mapping_fn_arg_type_ids_body
+            }
         }
     }
 }
@@ -10,9 +10,10 @@ use crate::{
     impl_default,
     type_gen::TypeGen,
     util::{
-        field_spec_ty_deconstruct, field_spec_ty_path, fields_deconstruct, fields_vars_map,
-        is_phantom_data, tuple_ident_from_field_index, tuple_index_from_field_index, value_spec_ty,
-        value_spec_ty_path, variant_generics_intersect, variant_generics_where_clause, ImplMode,
+        field_name_attr, field_spec_ty_deconstruct, field_spec_ty_path, fields_deconstruct,
+        fields_vars_map, is_phantom_data, tuple_field_name, tuple_index_from_field_index,
+        value_spec_ty, value_spec_ty_path, variant_generics_intersect,
+        variant_generics_where_clause, ImplMode,
     },
 };
 
@@ -374,17 +375,40 @@ fn builder_field_methods(
                 (self_field_name, field_name)
             } else {
                 let self_field_name = tuple_index_from_field_index(field_index).to_token_stream();
-                let field_name = tuple_ident_from_field_index(field_index);
+                let field_name = tuple_field_name(field_index, field);
                 (self_field_name, field_name)
             };
+            // Tuple fields without `#[params(field_name = "..")]` use the field
+            // index in method names, e.g. `with_0_from_map`.
+            let method_field_name = match (field.ident.as_ref(), field_name_attr(field)) {
+                (None, None) => self_field_name.to_string(),
+                _ => field_name.to_string(),
+            };
             let field_spec_ty_path = field_spec_ty_path(peace_params_path, field_ty);
-            let with_field_name = Ident::new(&format!("with_{self_field_name}"), Span::call_site());
+            let with_field_name =
+                Ident::new(&format!("with_{method_field_name}"), Span::call_site());
             let with_field_name_in_memory = Ident::new(
-                &format!("with_{self_field_name}_in_memory"),
+                &format!("with_{method_field_name}_in_memory"),
+                Span::call_site(),
+            );
+            let with_field_name_from_workspace_param = Ident::new(
+                &format!("with_{method_field_name}_from_workspace_param"),
+                Span::call_site(),
+            );
+            let with_field_name_from_profile_param = Ident::new(
+                &format!("with_{method_field_name}_from_profile_param"),
+                Span::call_site(),
+            );
+            let with_field_name_from_expr = Ident::new(
+                &format!("with_{method_field_name}_from_expr"),
                 Span::call_site(),
             );
             let with_field_name_from_map = Ident::new(
-                &format!("with_{self_field_name}_from_map"),
+                &format!("with_{method_field_name}_from_map"),
+                Span::call_site(),
+            );
+            let with_field_name_spec = Ident::new(
+                &format!("with_{method_field_name}_spec"),
                 Span::call_site(),
             );
 
@@ -418,6 +442,35 @@ fn builder_field_methods(
                     self
                 }
 
+                pub fn #with_field_name_from_workspace_param<K>(mut self, key: &K) -> Self
+                where
+                    K: std::fmt::Debug + serde::Serialize,
+                {
+                    self #proxy_call.#self_field_name =
+                        Some(#field_spec_ty_path::from_workspace_param(key));
+                    self
+                }
+
+                pub fn #with_field_name_from_profile_param<K>(mut self, key: &K) -> Self
+                where
+                    K: std::fmt::Debug + serde::Serialize,
+                {
+                    self #proxy_call.#self_field_name =
+                        Some(#field_spec_ty_path::from_profile_param(key));
+                    self
+                }
+
+                pub fn #with_field_name_from_expr(
+                    mut self,
+                    expr: #peace_params_path::MappingExpr,
+                ) -> Self
+                where
+                    #field_ty: serde::de::DeserializeOwned,
+                {
+                    self #proxy_call.#self_field_name = Some(#field_spec_ty_path::from_expr(expr));
+                    self
+                }
+
                 pub fn #with_field_name_from_map<F, Args>(mut self, f: F) -> Self
                 where
                     F: #peace_params_path::Func<
@@ -441,6 +494,18 @@ fn builder_field_methods(
                     self #proxy_call.#self_field_name = Some(spec);
                     self
                 }
+
+                pub fn #with_field_name_spec<IntoSpec, Marker>(mut self, spec: IntoSpec) -> Self
+                where
+                    IntoSpec: #peace_params_path::IntoValueSpec<#field_ty, Marker>,
+                {
+                    let spec = #peace_params_path::IntoValueSpec::<#field_ty, Marker>::into_value_spec(
+                        spec,
+                        Some(String::from(stringify!(#field_name))),
+                    );
+                    self #proxy_call.#self_field_name = Some(spec);
+                    self
+                }
             }
         })
         .collect::<Vec<proc_macro2::TokenStream>>();
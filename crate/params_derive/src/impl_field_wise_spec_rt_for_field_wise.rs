@@ -1,10 +1,12 @@
 use proc_macro2::Span;
 use syn::{
-    punctuated::Punctuated, Data, DeriveInput, Field, Fields, Ident, ImplGenerics, LitInt, Path,
+    punctuated::Punctuated, Data, DeriveInput, Field, Fields, Ident, ImplGenerics, Path,
     TypeGenerics, Variant, WhereClause,
 };
 
-use crate::util::{field_spec_ty_path, fields_deconstruct, is_phantom_data, variant_match_arm};
+use crate::util::{
+    field_name_attr, field_spec_ty_path, fields_deconstruct, is_phantom_data, variant_match_arm,
+};
 
 /// `impl FieldWiseSpecRt for ValueSpec`, so that Peace can resolve the params
 /// type as well as its values from the spec.
@@ -346,7 +348,7 @@ fn fields_resolution(
             //
             // ```rust
             // value_resolution_ctx.push(#peace_params_path::FieldNameAndType::new(
-            //     String::from(stringify!(1)),
+            //     String::from("1"),
             //     String::from(#peace_params_path::tynm::type_name::<#field_ty>())),
             // );
             // let _1 = _1.resolve(resources, value_resolution_ctx)?;
@@ -361,16 +363,17 @@ fn fields_resolution(
                     proc_macro2::TokenStream::new(),
                     |mut tokens, (field_index, field)| {
                         let field_ident = Ident::new(&format!("_{field_index}"), Span::call_site());
-                        // Need to convert this to a `LitInt`,
-                        // because `quote` outputs the index as `0usize` instead of `0`
-                        let field_index = LitInt::new(&format!("{field_index}"), Span::call_site());
+                        // Tuple fields are named by `#[params(field_name = "..")]` if present.
+                        let field_name = field_name_attr(field)
+                            .map(|field_name| field_name.to_string())
+                            .unwrap_or_else(|| field_index.to_string());
                         let field_ty = &field.ty;
                         let resolve_method =
                             resolve_mode.resolve_method(peace_params_path, &field_ident, field);
 
                         tokens.extend(quote! {
                             value_resolution_ctx.push(#peace_params_path::FieldNameAndType::new(
-                                String::from(stringify!(#field_index)),
+                                String::from(#field_name),
                                 String::from(#peace_params_path::tynm::type_name::<#field_ty>())),
                             );
                             #resolve_method
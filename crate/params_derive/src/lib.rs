@@ -44,6 +44,7 @@ mod impl_from_params_for_params_partial;
 mod impl_try_from_params_partial_for_params;
 mod impl_value_spec_rt_for_field_wise;
 mod spec_is_usable;
+mod spec_mapping_fn_arg_type_ids;
 mod spec_merge;
 mod type_gen;
 mod type_gen_external;
@@ -62,8 +63,10 @@ mod util;
 /// * Generate getters and mut getters for non-`pub`, non-`PhantomData` fields.
 /// * Generate a constructor if not all fields are `pub`.
 ///
-/// Maybe we should also generate a `SpecBuilder` -- see commit `10f63611` which
-/// removed builder generation.
+/// For `struct` `Params`, a `MyParamsSpecBuilder` type alias is generated for
+/// the field wise builder. Each field has a typed `with_<field>_spec` setter
+/// which accepts a value, a `ValueSpec<T>`, or a mapping function, so field
+/// specs are set by name instead of by position.
 ///
 /// # Attributes:
 ///
@@ -83,9 +86,26 @@ mod util;
 ///
 /// * `default`: Enum variant attribute to indicate which variant to instantiate
 ///   for `ParamsPartial::default()`.
+///
+/// * `params(validate = "path::to::fn")`: Type level attribute to validate the
+///   resolved params with the given function when the command context is
+///   built. The function must have the signature `fn(&Self) -> Result<(),
+///   ParamsValidationError>`.
+///
+/// * `params(validate_with = "path::to::fn")`: Type level attribute to check a
+///   constraint across multiple fields of the resolved params, such as `min <=
+///   max`, or mutually exclusive options. This may be specified multiple
+///   times; every function is run, and all failures are reported together for
+///   the item. Each function must have the signature `fn(&Self) -> Result<(),
+///   ParamsValidationError>`.
+///
+/// * `params(field_name = "name")`: Tuple field attribute to name the field in
+///   generated methods, e.g. `with_name_from_map` and `name()` instead of
+///   `with_0_from_map` and `_0()`. The name is also used in
+///   `ParamsResolveError`s.
 #[proc_macro_derive(
     Params,
-    attributes(peace_internal, crate_internal, value_spec, default, serde)
+    attributes(peace_internal, crate_internal, value_spec, default, serde, params)
 )]
 pub fn value_spec(input: TokenStream) -> TokenStream {
     let mut ast = syn::parse(input)
@@ -181,6 +201,13 @@ fn impl_value(ast: &mut DeriveInput, impl_mode: ImplMode) -> proc_macro2::TokenS
         Ident::new(&t_field_wise_builder_name, ast.ident.span())
     };
 
+    // MyValue -> MyValueSpecBuilder
+    let t_spec_builder_name = {
+        let mut t_spec_builder_name = ast.ident.to_string();
+        t_spec_builder_name.push_str("SpecBuilder");
+        Ident::new(&t_spec_builder_name, ast.ident.span())
+    };
+
     let field_wise_enum_builder_ctx = {
         // `EnumParams`' generics with `VariantSelection` inserted beforehand.
         let generics = {
@@ -267,10 +294,51 @@ fn impl_value(ast: &mut DeriveInput, impl_mode: ImplMode) -> proc_macro2::TokenS
                 &field_wise_enum_builder_ctx,
             );
 
+            let t_spec_builder = match (&ast.data, impl_mode) {
+                (Data::Struct(_), ImplMode::Fieldwise) => Some(t_spec_builder_alias(
+                    ast,
+                    &t_field_wise_builder_name,
+                    &t_spec_builder_name,
+                )),
+                _ => None,
+            };
+            let t_field_wise_builder = quote! {
+                #t_field_wise_builder
+
+                #t_spec_builder
+            };
+
             (t_partial, t_field_wise, Some(t_field_wise_builder))
         };
     let (impl_generics, ty_generics, where_clause) = &generics_split;
 
+    let ParamsValidateFnPaths {
+        validate: params_validate_fn_path,
+        validate_with: params_validate_with_fn_paths,
+    } = params_validate_fn_paths(ast);
+    let validate_fn = params_validate_fn_path.map(|params_validate_fn_path| {
+        quote! {
+            fn validate(&self) -> Result<(), #peace_params_path::ParamsValidationError> {
+                #params_validate_fn_path(self)
+            }
+        }
+    });
+    let validate_constraints_fn = if params_validate_with_fn_paths.is_empty() {
+        None
+    } else {
+        Some(quote! {
+            fn validate_constraints(&self) -> Vec<#peace_params_path::ParamsValidationError> {
+                let mut params_validation_errors = Vec::new();
+                #(
+                    if let Err(params_validation_error) = #params_validate_with_fn_paths(self) {
+                        params_validation_errors.push(params_validation_error);
+                    }
+                )*
+                params_validation_errors
+            }
+        })
+    };
+
     let mut impl_value_tokens = proc_macro2::TokenStream::new();
     match impl_mode {
         ImplMode::Fieldwise => impl_value_tokens.extend(quote! {
@@ -286,6 +354,10 @@ fn impl_value(ast: &mut DeriveInput, impl_mode: ImplMode) -> proc_macro2::TokenS
                 fn field_wise_spec() -> Self::FieldWiseBuilder {
                     Self::FieldWiseBuilder::default()
                 }
+
+                #validate_fn
+
+                #validate_constraints_fn
             }
         }),
         ImplMode::Fieldless => {}
@@ -310,6 +382,96 @@ fn impl_value(ast: &mut DeriveInput, impl_mode: ImplMode) -> proc_macro2::TokenS
     impl_value_tokens
 }
 
+/// Returns the `MyParamsSpecBuilder` type alias for `MyParamsFieldWiseBuilder`.
+///
+/// Type aliases do not enforce bounds, so only the generic parameter names are
+/// declared.
+fn t_spec_builder_alias(
+    ast: &DeriveInput,
+    t_field_wise_builder_name: &Ident,
+    t_spec_builder_name: &Ident,
+) -> proc_macro2::TokenStream {
+    let (alias_generics, builder_generics) = ast.generics.params.iter().fold(
+        (Vec::new(), Vec::new()),
+        |(mut alias_generics, mut builder_generics), generic_param| {
+            match generic_param {
+                GenericParam::Lifetime(_) => {
+                    panic!("Lifetime generics are not supported in Params derive.")
+                }
+                GenericParam::Type(type_param) => {
+                    let ident = &type_param.ident;
+                    alias_generics.push(quote!(#ident));
+                    builder_generics.push(quote!(#ident));
+                }
+                GenericParam::Const(const_param) => {
+                    let ident = &const_param.ident;
+                    let ty = &const_param.ty;
+                    alias_generics.push(quote!(const #ident: #ty));
+                    builder_generics.push(quote!(#ident));
+                }
+            }
+            (alias_generics, builder_generics)
+        },
+    );
+    let doc = format!("Builder for `{}`'s field wise spec.", ast.ident);
+
+    if alias_generics.is_empty() {
+        quote! {
+            #[doc = #doc]
+            pub type #t_spec_builder_name = #t_field_wise_builder_name;
+        }
+    } else {
+        quote! {
+            #[doc = #doc]
+            pub type #t_spec_builder_name<#(#alias_generics),*> =
+                #t_field_wise_builder_name<#(#builder_generics),*>;
+        }
+    }
+}
+
+/// Paths to the validation functions in the type level `#[params(..)]`
+/// attributes.
+#[derive(Default)]
+struct ParamsValidateFnPaths {
+    /// Function in `#[params(validate = "..")]`, if any.
+    validate: Option<Path>,
+    /// Functions in each `#[params(validate_with = "..")]`, in the order they
+    /// are declared.
+    validate_with: Vec<Path>,
+}
+
+/// Returns the paths to the validation functions in `#[params(validate =
+/// "..")]` and `#[params(validate_with = "..")]`.
+fn params_validate_fn_paths(ast: &DeriveInput) -> ParamsValidateFnPaths {
+    let mut params_validate_fn_paths = ParamsValidateFnPaths::default();
+    ast.attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("params"))
+        .for_each(|attr| {
+            attr.parse_nested_meta(|parse_nested_meta| {
+                let fn_lit: syn::LitStr = if parse_nested_meta.path.is_ident("validate")
+                    || parse_nested_meta.path.is_ident("validate_with")
+                {
+                    parse_nested_meta.value()?.parse()?
+                } else {
+                    return Err(parse_nested_meta.error("Unsupported `params` attribute."));
+                };
+                let fn_path = fn_lit.parse::<Path>()?;
+                if parse_nested_meta.path.is_ident("validate") {
+                    params_validate_fn_paths.validate = Some(fn_path);
+                } else {
+                    params_validate_fn_paths.validate_with.push(fn_path);
+                }
+                Ok(())
+            })
+            .unwrap_or_else(|error| {
+                panic!("`Params` derive: Failed to parse `#[params(..)]` attribute: {error}")
+            });
+        });
+
+    params_validate_fn_paths
+}
+
 /// Adds trait bounds on each of the type parameters.
 ///
 /// * `Send + Sync + 'static` is always added
@@ -0,0 +1,182 @@
+use syn::{punctuated::Punctuated, DeriveInput, Fields, Ident, Path, Variant};
+
+use crate::util::{fields_deconstruct, fields_stmt_map, variant_match_arm};
+
+pub fn mapping_fn_arg_type_ids_body(
+    ast: &DeriveInput,
+    params_field_wise_name: &Ident,
+    peace_params_path: &Path,
+) -> proc_macro2::TokenStream {
+    match &ast.data {
+        syn::Data::Struct(data_struct) => {
+            let fields = &data_struct.fields;
+
+            struct_fields_mapping_fn_arg_type_ids(params_field_wise_name, fields, peace_params_path)
+        }
+        syn::Data::Enum(data_enum) => {
+            let variants = &data_enum.variants;
+
+            variants_mapping_fn_arg_type_ids(params_field_wise_name, variants, peace_params_path)
+        }
+        syn::Data::Union(data_union) => {
+            let fields = Fields::from(data_union.fields.clone());
+
+            struct_fields_mapping_fn_arg_type_ids(
+                params_field_wise_name,
+                &fields,
+                peace_params_path,
+            )
+        }
+    }
+}
+
+/// Returns the `TypeId`s read by mapping functions of the fields within this
+/// struct.
+pub fn struct_fields_mapping_fn_arg_type_ids(
+    params_field_wise_name: &Ident,
+    fields: &Fields,
+    peace_params_path: &Path,
+) -> proc_macro2::TokenStream {
+    let fields_mapping_fn_arg_type_ids = fields_mapping_fn_arg_type_ids(fields, peace_params_path);
+    let fields_deconstructed = fields_deconstruct(fields);
+
+    match fields {
+        Fields::Named(_fields_named) => {
+            // Generates:
+            //
+            // ```rust
+            // let #params_field_wise_name {
+            //     field_1,
+            //     field_2,
+            //     marker: PhantomData,
+            // } = self;
+            //
+            // let mut mapping_fn_arg_type_ids = Vec::new();
+            // mapping_fn_arg_type_ids.extend(AnySpecRt::mapping_fn_arg_type_ids(field_1));
+            // mapping_fn_arg_type_ids.extend(AnySpecRt::mapping_fn_arg_type_ids(field_2));
+            //
+            // mapping_fn_arg_type_ids
+            // ```
+
+            quote! {
+                let #params_field_wise_name {
+                    #(#fields_deconstructed),*
+                } = self;
+
+                let mut mapping_fn_arg_type_ids = Vec::new();
+                #fields_mapping_fn_arg_type_ids
+
+                mapping_fn_arg_type_ids
+            }
+        }
+        Fields::Unnamed(_fields_unnamed) => {
+            // Generates:
+            //
+            // ```rust
+            // let #params_name(_0, _1, PhantomData,) = self;
+            //
+            // let mut mapping_fn_arg_type_ids = Vec::new();
+            // mapping_fn_arg_type_ids.extend(AnySpecRt::mapping_fn_arg_type_ids(_0));
+            // mapping_fn_arg_type_ids.extend(AnySpecRt::mapping_fn_arg_type_ids(_1));
+            //
+            // mapping_fn_arg_type_ids
+            // ```
+
+            quote! {
+                let #params_field_wise_name(#(#fields_deconstructed),*) = self;
+
+                let mut mapping_fn_arg_type_ids = Vec::new();
+                #fields_mapping_fn_arg_type_ids
+
+                mapping_fn_arg_type_ids
+            }
+        }
+        Fields::Unit => quote!(Vec::new()),
+    }
+}
+
+/// Returns the `TypeId`s read by mapping functions of the fields within this
+/// enum.
+pub fn variants_mapping_fn_arg_type_ids(
+    params_field_wise_name: &Ident,
+    variants: &Punctuated<Variant, Token![,]>,
+    peace_params_path: &Path,
+) -> proc_macro2::TokenStream {
+    // Generates:
+    //
+    // ```rust
+    // match self {
+    //     ValueSpec::Variant1 => Vec::new(),
+    //     ValueSpec::Variant2(_0, _1, PhantomData) => {
+    //         let mut mapping_fn_arg_type_ids = Vec::new();
+    //         mapping_fn_arg_type_ids.extend(AnySpecRt::mapping_fn_arg_type_ids(_0));
+    //         mapping_fn_arg_type_ids.extend(AnySpecRt::mapping_fn_arg_type_ids(_1));
+    //         mapping_fn_arg_type_ids
+    //     }
+    //     ValueSpec::Variant3 {
+    //         field_1,
+    //         field_2,
+    //         marker: PhantomData,
+    //     } => {
+    //         let mut mapping_fn_arg_type_ids = Vec::new();
+    //         mapping_fn_arg_type_ids.extend(AnySpecRt::mapping_fn_arg_type_ids(field_1));
+    //         mapping_fn_arg_type_ids.extend(AnySpecRt::mapping_fn_arg_type_ids(field_2));
+    //         mapping_fn_arg_type_ids
+    //     }
+    // }
+    // ```
+
+    let variant_resolve_arms =
+        variants
+            .iter()
+            .fold(proc_macro2::TokenStream::new(), |mut tokens, variant| {
+                let fields = &variant.fields;
+                let fields_deconstructed = fields_deconstruct(fields);
+
+                let variant_fields_mapping_fn_arg_type_ids = {
+                    let fields_mapping_fn_arg_type_ids =
+                        fields_mapping_fn_arg_type_ids(fields, peace_params_path);
+
+                    quote! {
+                        let mut mapping_fn_arg_type_ids = Vec::new();
+                        #fields_mapping_fn_arg_type_ids
+
+                        mapping_fn_arg_type_ids
+                    }
+                };
+                tokens.extend(variant_match_arm(
+                    params_field_wise_name,
+                    variant,
+                    &fields_deconstructed,
+                    variant_fields_mapping_fn_arg_type_ids,
+                ));
+
+                tokens
+            });
+
+    quote! {
+        match self {
+            #variant_resolve_arms
+        }
+    }
+}
+
+fn fields_mapping_fn_arg_type_ids(
+    fields: &Fields,
+    peace_params_path: &Path,
+) -> proc_macro2::TokenStream {
+    fields_stmt_map(fields, move |_field, field_name, _field_index| {
+        quote! {
+            mapping_fn_arg_type_ids.extend(
+                #peace_params_path::AnySpecRt::mapping_fn_arg_type_ids(#field_name)
+            );
+        }
+    })
+    .fold(
+        proc_macro2::TokenStream::new(),
+        |mut tokens, next_tokens| {
+            tokens.extend(next_tokens);
+            tokens
+        },
+    )
+}
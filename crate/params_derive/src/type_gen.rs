@@ -7,8 +7,8 @@ use syn::{
 
 use crate::util::{
     field_ty_to_ref_ty, fields_deconstruct, fields_deconstruct_retain, is_phantom_data,
-    is_serde_bound_attr, tuple_ident_from_field_index, tuple_index_from_field_index,
-    variant_match_arm, RefTypeAndExpr,
+    is_serde_bound_attr, tuple_field_name, tuple_ident_from_field_index,
+    tuple_index_from_field_index, variant_match_arm, RefTypeAndExpr,
 };
 
 pub struct TypeGen;
@@ -71,7 +71,8 @@ impl TypeGen {
                 let struct_constructor = Self::struct_constructor(type_name, &fields);
                 let struct_fields_clone = Self::struct_fields_clone(type_name, &fields);
                 let struct_fields_debug = Self::struct_fields_debug(type_name, &fields);
-                let struct_getters_and_mut_getters = Self::struct_getters_and_mut_getters(&fields);
+                let struct_getters_and_mut_getters =
+                    Self::struct_getters_and_mut_getters(&fields, &data_struct.fields);
 
                 quote! {
                     #(#attrs_to_add)*
@@ -201,6 +202,7 @@ impl TypeGen {
 
             Some(quote! {
                 #[doc = #constructor_doc]
+                #[allow(clippy::too_many_arguments)]
                 pub fn new(#(#fields_as_params),*) -> Self {
                     #constructor
                 }
@@ -219,15 +221,23 @@ impl TypeGen {
     /// * `PathBuf`: returns `&Path`.
     /// * `Vec<T>`: returns `&[T]`.
     /// * `String`: returns `&str`.
-    pub fn struct_getters_and_mut_getters(fields: &Fields) -> proc_macro2::TokenStream {
+    ///
+    /// Tuple fields are named using `#[params(field_name = "..")]` on the
+    /// `Params` type's fields in `fields_original`, as attributes are not
+    /// copied to the generated type.
+    pub fn struct_getters_and_mut_getters(
+        fields: &Fields,
+        fields_original: &Fields,
+    ) -> proc_macro2::TokenStream {
         let fields_as_params = fields
             .iter()
+            .zip(fields_original.iter())
             .enumerate()
-            .filter(|(_field_index, field)| {
+            .filter(|(_field_index, (field, _field_original))| {
                 !is_phantom_data(&field.ty)
                     && matches!(field.vis, Visibility::Restricted(_) | Visibility::Inherited)
             })
-            .map(|(field_index, field)| {
+            .map(|(field_index, (field, field_original))| {
                 let (self_field_name, field_name) = if let Some(field_ident) = field.ident.as_ref()
                 {
                     let self_field_name = field_ident.to_token_stream();
@@ -236,7 +246,8 @@ impl TypeGen {
                 } else {
                     let self_field_name =
                         tuple_index_from_field_index(field_index).to_token_stream();
-                    let field_name = tuple_ident_from_field_index(field_index).to_token_stream();
+                    let field_name =
+                        tuple_field_name(field_index, field_original).to_token_stream();
                     (self_field_name, field_name)
                 };
                 let field_name_mut = Ident::new(&format!("{field_name}_mut"), Span::call_site());
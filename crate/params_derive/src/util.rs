@@ -25,6 +25,7 @@ static STD_LIB_TYPES: &[&str] = &[
     "isize",
     "String",
     "PathBuf",
+    "WorkspacePath",
     #[cfg(not(target_arch = "wasm32"))]
     "OsString",
     "Option",
@@ -250,6 +251,40 @@ pub fn tuple_ident_from_field_index(field_index: usize) -> Ident {
     Ident::new(&format!("_{field_index}"), Span::call_site())
 }
 
+/// Returns the name of a tuple field, used in generated method names.
+///
+/// This is the name in `#[params(field_name = "..")]` if present, otherwise
+/// `_n` where `n` is the index of the field.
+pub fn tuple_field_name(field_index: usize, field: &Field) -> Ident {
+    field_name_attr(field).unwrap_or_else(|| tuple_ident_from_field_index(field_index))
+}
+
+/// Returns the name in the `#[params(field_name = "..")]` field attribute, if
+/// any.
+pub fn field_name_attr(field: &Field) -> Option<Ident> {
+    field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("params"))
+        .find_map(|attr| {
+            let mut field_name = None;
+            attr.parse_nested_meta(|parse_nested_meta| {
+                if parse_nested_meta.path.is_ident("field_name") {
+                    let field_name_lit: syn::LitStr = parse_nested_meta.value()?.parse()?;
+                    field_name = Some(field_name_lit.parse::<Ident>()?);
+                    Ok(())
+                } else {
+                    Err(parse_nested_meta.error("Unsupported `params` field attribute."))
+                }
+            })
+            .unwrap_or_else(|error| {
+                panic!("`Params` derive: Failed to parse `#[params(..)]` attribute: {error}")
+            });
+
+            field_name
+        })
+}
+
 /// Returns tuple idents as `_n` where `n` is the index of the field.
 pub fn tuple_index_from_field_index(field_index: usize) -> LitInt {
     // Need to convert this to a `LitInt`,
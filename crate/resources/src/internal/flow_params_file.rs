@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use crate::paths::FlowDir;
+use crate::paths::{FlowDir, ParamsRevisionDir};
 
 /// Path to the file that stores the flow initialization parameters.
 ///
@@ -26,3 +26,11 @@ impl From<&FlowDir> for FlowParamsFile {
         Self(path)
     }
 }
+
+impl From<&ParamsRevisionDir> for FlowParamsFile {
+    fn from(params_revision_dir: &ParamsRevisionDir) -> Self {
+        let path = params_revision_dir.join(Self::NAME);
+
+        Self(path)
+    }
+}
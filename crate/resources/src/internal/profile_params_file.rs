@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use crate::paths::ProfileDir;
+use crate::paths::{ParamsRevisionDir, ProfileDir};
 
 /// Path to the file that stores the profile initialization parameters.
 ///
@@ -26,3 +26,11 @@ impl From<&ProfileDir> for ProfileParamsFile {
         Self(path)
     }
 }
+
+impl From<&ParamsRevisionDir> for ProfileParamsFile {
+    fn from(params_revision_dir: &ParamsRevisionDir) -> Self {
+        let path = params_revision_dir.join(Self::NAME);
+
+        Self(path)
+    }
+}
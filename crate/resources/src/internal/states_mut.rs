@@ -1,12 +1,16 @@
 use std::{
+    fmt::{self, Debug},
     marker::PhantomData,
     ops::{Deref, DerefMut},
 };
 
+use chrono::{DateTime, Utc};
 use peace_core::ItemId;
 use serde::Serialize;
 use type_reg::untagged::{BoxDtDisplay, TypeMap};
 
+use crate::states::StatesTimestamps;
+
 /// `State`s for all `Item`s. `TypeMap<ItemId, BoxDtDisplay>` newtype.
 ///
 /// # Implementors
@@ -25,8 +29,12 @@ use type_reg::untagged::{BoxDtDisplay, TypeMap};
 /// [`Resources`]: crate::Resources
 /// [`StatesCurrent`]: crate::StatesCurrent
 /// [`StatesRw`]: crate::StatesRw
-#[derive(Debug, Serialize)]
-pub struct StatesMut<TS>(TypeMap<ItemId, BoxDtDisplay>, PhantomData<TS>);
+#[derive(Serialize)]
+pub struct StatesMut<TS>(
+    TypeMap<ItemId, BoxDtDisplay>,
+    PhantomData<TS>,
+    #[serde(skip)] StatesTimestamps,
+);
 
 impl<TS> StatesMut<TS> {
     /// Returns a new `StatesMut` map.
@@ -39,18 +47,49 @@ impl<TS> StatesMut<TS> {
     /// The `StatesMut` will be able to hold at least capacity elements
     /// without reallocating. If capacity is 0, the map will not allocate.
     pub fn with_capacity(capacity: usize) -> Self {
-        Self(TypeMap::with_capacity_typed(capacity), PhantomData)
+        Self(
+            TypeMap::with_capacity_typed(capacity),
+            PhantomData,
+            StatesTimestamps::new(),
+        )
     }
 
     /// Returns the inner map.
     pub fn into_inner(self) -> TypeMap<ItemId, BoxDtDisplay> {
         self.0
     }
+
+    /// Returns the inner map, and when each state was discovered.
+    pub fn into_inner_with_timestamps(self) -> (TypeMap<ItemId, BoxDtDisplay>, StatesTimestamps) {
+        (self.0, self.2)
+    }
+
+    /// Records when the state for the given item was discovered.
+    ///
+    /// This is used when a state is not freshly discovered, such as when a
+    /// stored state is reused. States without a recorded timestamp are
+    /// timestamped when this is converted into [`States`].
+    ///
+    /// [`States`]: crate::states::States
+    pub fn timestamp_insert(&mut self, item_id: ItemId, timestamp: DateTime<Utc>) {
+        self.2.insert(item_id, timestamp);
+    }
+}
+
+// Timestamps are omitted, as they describe when the states were discovered,
+// rather than the states themselves.
+impl<TS> Debug for StatesMut<TS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("StatesMut")
+            .field(&self.0)
+            .field(&self.1)
+            .finish()
+    }
 }
 
 impl<TS> Default for StatesMut<TS> {
     fn default() -> Self {
-        Self(TypeMap::default(), PhantomData)
+        Self(TypeMap::default(), PhantomData, StatesTimestamps::new())
     }
 }
 
@@ -70,7 +109,7 @@ impl<TS> DerefMut for StatesMut<TS> {
 
 impl<TS> From<TypeMap<ItemId, BoxDtDisplay>> for StatesMut<TS> {
     fn from(type_map: TypeMap<ItemId, BoxDtDisplay>) -> Self {
-        Self(type_map, PhantomData)
+        Self(type_map, PhantomData, StatesTimestamps::new())
     }
 }
 
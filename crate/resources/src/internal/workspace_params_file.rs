@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use crate::paths::PeaceAppDir;
+use crate::paths::{ParamsRevisionDir, PeaceAppDir};
 
 /// Path to the file that stores the workspace initialization parameters.
 ///
@@ -26,3 +26,11 @@ impl From<&PeaceAppDir> for WorkspaceParamsFile {
         Self(path)
     }
 }
+
+impl From<&ParamsRevisionDir> for WorkspaceParamsFile {
+    fn from(params_revision_dir: &ParamsRevisionDir) -> Self {
+        let path = params_revision_dir.join(Self::NAME);
+
+        Self(path)
+    }
+}
@@ -14,11 +14,16 @@
 //!         |   |- CmdExecutionN
 //!         |
 //!         |- ProfileParams
+//!         |- ItemsDisabled
 //!         |
 //!         |- FlowDir  # "flow_name", multiple
 //!             |- StatesMeta
 //!             |- StatesCurrent
 //!             |- StatesGoal
+//!             |- StatesTimestamps
+//!             |- ExecutionProgress
+//!             |- ParamsDigests
+//!             |- ItemLogsDir  # "logs/execution_id", multiple
 //! ```
 //!
 //! Concrete folder structure example:
@@ -36,6 +41,9 @@
 //!     |   |   |- 00000005_2022-08-22T08_16_29_dev_env_clean.yaml
 //!     |   |   |- 00000006_2022-08-23T13_02_14_artifact_discover.yaml
 //!     |   |   |- 00000007_2022-08-23T13_07_31_artifact_publish.yaml
+//!     |   |   |- params_history.yaml  # Revisions of workspace / profile / flow params.
+//!     |   |   |- params
+//!     |   |       |- 00000000  # Params files at each revision.
 //!     |   |
 //!     |   |- .meta.yaml  # Store the last discovered time so we can inform the user.
 //!     |   |              # Should time be stored per item, or per invocation?
@@ -43,11 +51,20 @@
 //!     |   |- dev_env  # flow name
 //!     |   |   |- states_goal.yaml
 //!     |   |   |- states_current.yaml
+//!     |   |   |- states_current_timestamps.yaml  # When each state was discovered.
+//!     |   |   |- execution_progress.yaml  # Progress of the last command.
+//!     |   |   |- execution_timeline.yaml  # When each item ran in the last command.
+//!     |   |   |- params_digests.yaml  # Item params when states were stored.
+//!     |   |   |- logs
+//!     |   |       |- 2022-08-21T20_50_43.123  # Execution ID.
+//!     |   |           |- item_id.log  # Log output of each item.
 //!     |   |
 //!     |   |- artifact
 //!     |   |   |- states_goal.yaml
 //!     |   |   |- states_current.yaml
 //!     |   |
+//!     |   |- items_disabled.yaml  # Items that are skipped for this profile.
+//!     |   |
 //!     |   |- profile_params.yaml  # Parameters used to initialize this profile
 //!     |                           # We write to this so that each time the user re-`init`s,
 //!     |                           # if they version control it, they can rediscover the
@@ -70,13 +87,29 @@
 //! ```
 
 pub use self::{
-    flow_dir::FlowDir, params_specs_file::ParamsSpecsFile, peace_app_dir::PeaceAppDir,
-    peace_dir::PeaceDir, profile_dir::ProfileDir, profile_history_dir::ProfileHistoryDir,
-    states_current_file::StatesCurrentFile, states_goal_file::StatesGoalFile,
+    execution_history_file::ExecutionHistoryFile, execution_progress_file::ExecutionProgressFile,
+    execution_states_dir::ExecutionStatesDir,
+    execution_timeline_file::ExecutionTimelineFile, flow_dir::FlowDir,
+    item_logs_dir::ItemLogsDir, items_disabled_file::ItemsDisabledFile,
+    params_digests_file::ParamsDigestsFile, params_history_file::ParamsHistoryFile,
+    params_revision_dir::ParamsRevisionDir, params_specs_file::ParamsSpecsFile,
+    peace_app_dir::PeaceAppDir, peace_dir::PeaceDir, profile_dir::ProfileDir,
+    profile_history_dir::ProfileHistoryDir, states_current_file::StatesCurrentFile,
+    states_goal_file::StatesGoalFile, states_timestamps_file::StatesTimestampsFile,
     workspace_dir::WorkspaceDir,
+    workspace_path::WorkspacePath,
 };
 
+mod execution_history_file;
+mod execution_progress_file;
+mod execution_states_dir;
+mod execution_timeline_file;
 mod flow_dir;
+mod item_logs_dir;
+mod items_disabled_file;
+mod params_digests_file;
+mod params_history_file;
+mod params_revision_dir;
 mod params_specs_file;
 mod peace_app_dir;
 mod peace_dir;
@@ -84,7 +117,9 @@ mod profile_dir;
 mod profile_history_dir;
 mod states_current_file;
 mod states_goal_file;
+mod states_timestamps_file;
 mod workspace_dir;
+mod workspace_path;
 
 /// Common impl logic for `PathBuf` newtypes.
 ///
@@ -0,0 +1,28 @@
+use std::path::PathBuf;
+
+use crate::paths::FlowDir;
+
+/// Path to the file that stores the history of command executions for a flow.
+///
+/// Typically `$workspace_dir/.peace/$profile/$flow_id/execution_history.yaml`.
+///
+/// See `ExecutionHistoryFile::from<&FlowDir>` if you want to construct an
+/// `ExecutionHistoryFile` with the conventional
+/// `$flow_dir/execution_history.yaml` path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionHistoryFile(PathBuf);
+
+crate::paths::pathbuf_newtype!(ExecutionHistoryFile);
+
+impl ExecutionHistoryFile {
+    /// File name of the execution history file.
+    pub const NAME: &'static str = "execution_history.yaml";
+}
+
+impl From<&FlowDir> for ExecutionHistoryFile {
+    fn from(flow_dir: &FlowDir) -> Self {
+        let path = flow_dir.join(Self::NAME);
+
+        Self(path)
+    }
+}
@@ -0,0 +1,29 @@
+use std::path::PathBuf;
+
+use crate::paths::FlowDir;
+
+/// Path to the file that stores items' progress from the last command
+/// execution.
+///
+/// Typically `$workspace_dir/.peace/$profile/$flow_id/execution_progress.yaml`.
+///
+/// See `ExecutionProgressFile::from<&FlowDir>` if you want to construct an
+/// `ExecutionProgressFile` with the conventional
+/// `$flow_dir/execution_progress.yaml` path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionProgressFile(PathBuf);
+
+crate::paths::pathbuf_newtype!(ExecutionProgressFile);
+
+impl ExecutionProgressFile {
+    /// File name of the execution progress file.
+    pub const NAME: &'static str = "execution_progress.yaml";
+}
+
+impl From<&FlowDir> for ExecutionProgressFile {
+    fn from(flow_dir: &FlowDir) -> Self {
+        let path = flow_dir.join(Self::NAME);
+
+        Self(path)
+    }
+}
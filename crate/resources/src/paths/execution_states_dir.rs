@@ -0,0 +1,34 @@
+use std::path::PathBuf;
+
+use crate::paths::FlowDir;
+
+/// Directory to store the states of a flow after a particular command
+/// execution.
+///
+/// Typically `$workspace_dir/.peace/$profile/$flow_id/executions/$execution_id`.
+///
+/// This contains a copy of the current and goal states files from when the
+/// execution was recorded.
+///
+/// See `ExecutionStatesDir::from<(&FlowDir, u64)>` if you want to construct an
+/// `ExecutionStatesDir` with the conventional
+/// `$flow_dir/executions/$execution_id` path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionStatesDir(PathBuf);
+
+crate::paths::pathbuf_newtype!(ExecutionStatesDir);
+
+impl ExecutionStatesDir {
+    /// Name of the directory that contains each execution states directory.
+    pub const PARENT_NAME: &'static str = "executions";
+}
+
+impl From<(&FlowDir, u64)> for ExecutionStatesDir {
+    fn from((flow_dir, execution_id): (&FlowDir, u64)) -> Self {
+        let path = flow_dir
+            .join(Self::PARENT_NAME)
+            .join(format!("{execution_id:08}"));
+
+        Self(path)
+    }
+}
@@ -0,0 +1,29 @@
+use std::path::PathBuf;
+
+use crate::paths::FlowDir;
+
+/// Path to the file that stores items' execution timeline from the last command
+/// execution.
+///
+/// Typically `$workspace_dir/.peace/$profile/$flow_id/execution_timeline.yaml`.
+///
+/// See `ExecutionTimelineFile::from<&FlowDir>` if you want to construct an
+/// `ExecutionTimelineFile` with the conventional
+/// `$flow_dir/execution_timeline.yaml` path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionTimelineFile(PathBuf);
+
+crate::paths::pathbuf_newtype!(ExecutionTimelineFile);
+
+impl ExecutionTimelineFile {
+    /// File name of the execution timeline file.
+    pub const NAME: &'static str = "execution_timeline.yaml";
+}
+
+impl From<&FlowDir> for ExecutionTimelineFile {
+    fn from(flow_dir: &FlowDir) -> Self {
+        let path = flow_dir.join(Self::NAME);
+
+        Self(path)
+    }
+}
@@ -0,0 +1,37 @@
+use std::path::PathBuf;
+
+use peace_core::ItemId;
+
+use crate::paths::FlowDir;
+
+/// Directory to store each item's log output from a command execution.
+///
+/// Typically `$workspace_dir/.peace/$profile/$flow_id/logs/$execution_id`.
+///
+/// Each item's log output is written to `$item_id.log` within this directory,
+/// see [`ItemLogsDir::item_log_file`].
+///
+/// See `ItemLogsDir::from<(&FlowDir, &str)>` if you want to construct an
+/// `ItemLogsDir` with the conventional `$flow_dir/logs/$execution_id` path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ItemLogsDir(PathBuf);
+
+crate::paths::pathbuf_newtype!(ItemLogsDir);
+
+impl ItemLogsDir {
+    /// Name of the directory that contains each execution's logs directory.
+    pub const PARENT_NAME: &'static str = "logs";
+
+    /// Returns the path to the log file of the given item.
+    pub fn item_log_file(&self, item_id: &ItemId) -> PathBuf {
+        self.0.join(format!("{item_id}.log"))
+    }
+}
+
+impl From<(&FlowDir, &str)> for ItemLogsDir {
+    fn from((flow_dir, execution_id): (&FlowDir, &str)) -> Self {
+        let path = flow_dir.join(Self::PARENT_NAME).join(execution_id);
+
+        Self(path)
+    }
+}
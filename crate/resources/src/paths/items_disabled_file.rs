@@ -0,0 +1,29 @@
+use std::path::PathBuf;
+
+use crate::paths::ProfileDir;
+
+/// Path to the file that stores the IDs of items that are disabled for a
+/// profile.
+///
+/// Typically `$workspace_dir/.peace/$app/$profile/items_disabled.yaml`.
+///
+/// See `ItemsDisabledFile::from<&ProfileDir>` if you want to construct an
+/// `ItemsDisabledFile` with the conventional `$profile_dir/items_disabled.yaml`
+/// path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ItemsDisabledFile(PathBuf);
+
+crate::paths::pathbuf_newtype!(ItemsDisabledFile);
+
+impl ItemsDisabledFile {
+    /// File name of the items disabled file.
+    pub const NAME: &'static str = "items_disabled.yaml";
+}
+
+impl From<&ProfileDir> for ItemsDisabledFile {
+    fn from(profile_dir: &ProfileDir) -> Self {
+        let path = profile_dir.join(Self::NAME);
+
+        Self(path)
+    }
+}
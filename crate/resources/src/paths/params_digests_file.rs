@@ -0,0 +1,29 @@
+use std::path::PathBuf;
+
+use crate::paths::FlowDir;
+
+/// Path to the file that stores digests of item params from when current
+/// states were last stored.
+///
+/// Typically `$workspace_dir/.peace/$profile/$flow_id/params_digests.yaml`.
+///
+/// See `ParamsDigestsFile::from<&FlowDir>` if you want to construct a
+/// `ParamsDigestsFile` with the conventional `$flow_dir/params_digests.yaml`
+/// path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParamsDigestsFile(PathBuf);
+
+crate::paths::pathbuf_newtype!(ParamsDigestsFile);
+
+impl ParamsDigestsFile {
+    /// File name of the params digests file.
+    pub const NAME: &'static str = "params_digests.yaml";
+}
+
+impl From<&FlowDir> for ParamsDigestsFile {
+    fn from(flow_dir: &FlowDir) -> Self {
+        let path = flow_dir.join(Self::NAME);
+
+        Self(path)
+    }
+}
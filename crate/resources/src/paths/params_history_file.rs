@@ -0,0 +1,28 @@
+use std::path::PathBuf;
+
+use crate::paths::ProfileHistoryDir;
+
+/// Path to the file that stores the revisions of a profile's params.
+///
+/// Typically `$workspace_dir/.peace/$app/$profile/.history/params_history.yaml`.
+///
+/// See `ParamsHistoryFile::from<&ProfileHistoryDir>` if you want to construct a
+/// `ParamsHistoryFile` with the conventional
+/// `$profile_history_dir/params_history.yaml` path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParamsHistoryFile(PathBuf);
+
+crate::paths::pathbuf_newtype!(ParamsHistoryFile);
+
+impl ParamsHistoryFile {
+    /// File name of the params history file.
+    pub const NAME: &'static str = "params_history.yaml";
+}
+
+impl From<&ProfileHistoryDir> for ParamsHistoryFile {
+    fn from(profile_history_dir: &ProfileHistoryDir) -> Self {
+        let path = profile_history_dir.join(Self::NAME);
+
+        Self(path)
+    }
+}
@@ -0,0 +1,33 @@
+use std::path::PathBuf;
+
+use crate::paths::ProfileHistoryDir;
+
+/// Directory to store the params of a profile at a particular revision.
+///
+/// Typically `$workspace_dir/.peace/$app/$profile/.history/params/$revision`.
+///
+/// This contains a copy of the workspace, profile, and flow params files from
+/// when the revision was recorded.
+///
+/// See `ParamsRevisionDir::from<(&ProfileHistoryDir, u64)>` if you want to
+/// construct a `ParamsRevisionDir` with the conventional
+/// `$profile_history_dir/params/$revision` path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParamsRevisionDir(PathBuf);
+
+crate::paths::pathbuf_newtype!(ParamsRevisionDir);
+
+impl ParamsRevisionDir {
+    /// Name of the directory that contains each params revision directory.
+    pub const PARENT_NAME: &'static str = "params";
+}
+
+impl From<(&ProfileHistoryDir, u64)> for ParamsRevisionDir {
+    fn from((profile_history_dir, revision): (&ProfileHistoryDir, u64)) -> Self {
+        let path = profile_history_dir
+            .join(Self::PARENT_NAME)
+            .join(format!("{revision:08}"));
+
+        Self(path)
+    }
+}
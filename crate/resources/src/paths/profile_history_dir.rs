@@ -7,7 +7,8 @@ use crate::paths::ProfileDir;
 /// Typically `$workspace_dir/.peace/$app/$profile/.history`.
 ///
 /// This directory is intended to contain significant command execution
-/// summaries. Currently it is not written to yet.
+/// summaries. Currently it contains the history of the workspace, profile, and
+/// flow params used with the profile.
 ///
 /// See `ProfileHistoryDir::from<&ProfileDir>` if you want to construct a
 /// `ProfileHistoryDir` with the conventional `$profile_dir/.history` path.
@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use crate::paths::FlowDir;
+use crate::paths::{ExecutionStatesDir, FlowDir};
 
 /// Path to the file that stores items' states.
 ///
@@ -26,3 +26,11 @@ impl From<&FlowDir> for StatesCurrentFile {
         Self(path)
     }
 }
+
+impl From<&ExecutionStatesDir> for StatesCurrentFile {
+    fn from(execution_states_dir: &ExecutionStatesDir) -> Self {
+        let path = execution_states_dir.join(Self::NAME);
+
+        Self(path)
+    }
+}
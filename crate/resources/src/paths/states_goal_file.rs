@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use crate::paths::FlowDir;
+use crate::paths::{ExecutionStatesDir, FlowDir};
 
 /// Path to the file that stores items' states.
 ///
@@ -26,3 +26,11 @@ impl From<&FlowDir> for StatesGoalFile {
         Self(path)
     }
 }
+
+impl From<&ExecutionStatesDir> for StatesGoalFile {
+    fn from(execution_states_dir: &ExecutionStatesDir) -> Self {
+        let path = execution_states_dir.join(Self::NAME);
+
+        Self(path)
+    }
+}
@@ -0,0 +1,33 @@
+use std::path::{Path, PathBuf};
+
+/// Path to the file that stores when each item's state in a states file was
+/// discovered.
+///
+/// Typically `$workspace_dir/.peace/$profile/$flow_id/states_current_timestamps.yaml`
+/// for the `StatesCurrentFile`.
+///
+/// See `StatesTimestampsFile::from<&Path>` if you want to construct a
+/// `StatesTimestampsFile` with the conventional `${states_file}_timestamps.yaml`
+/// path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatesTimestampsFile(PathBuf);
+
+crate::paths::pathbuf_newtype!(StatesTimestampsFile);
+
+impl StatesTimestampsFile {
+    /// Suffix appended to the states file's name.
+    pub const SUFFIX: &'static str = "_timestamps.yaml";
+}
+
+impl From<&Path> for StatesTimestampsFile {
+    fn from(states_file_path: &Path) -> Self {
+        let states_file_stem = states_file_path
+            .file_stem()
+            .map(|file_stem| file_stem.to_string_lossy())
+            .unwrap_or_default();
+        let path =
+            states_file_path.with_file_name(format!("{states_file_stem}{}", Self::SUFFIX));
+
+        Self(path)
+    }
+}
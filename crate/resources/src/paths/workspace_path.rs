@@ -0,0 +1,139 @@
+use std::path::{Component, Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::paths::WorkspaceDir;
+
+/// Path in item params that resolves within the workspace directory.
+///
+/// Relative paths are resolved against the [`WorkspaceDir`], and absolute
+/// paths must already be within it. Paths that traverse outside the workspace
+/// directory, e.g. `../../etc/passwd`, fail to resolve unless they are
+/// explicitly constructed with [`WorkspacePath::external`].
+///
+/// When a `WorkspacePath` field's value is resolved from its `ValueSpec`, it is
+/// resolved to an absolute path, and traversal outside the workspace is
+/// returned as a `ParamsResolveError`. Items that receive a `WorkspacePath`
+/// by other means may call [`WorkspacePath::resolve`] directly.
+///
+/// Resolution is lexical -- `.` and `..` components are normalized without
+/// accessing the file system, so symlinks are not followed.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct WorkspacePath {
+    /// The path, relative to the workspace directory, or absolute.
+    path: PathBuf,
+    /// Whether this path is allowed to resolve outside the workspace
+    /// directory.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    external: bool,
+}
+
+impl WorkspacePath {
+    /// Returns a new `WorkspacePath` that must resolve within the workspace
+    /// directory.
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            external: false,
+        }
+    }
+
+    /// Returns a new `WorkspacePath` that is allowed to resolve outside the
+    /// workspace directory.
+    ///
+    /// Relative paths are still resolved against the workspace directory.
+    pub fn external(path: PathBuf) -> Self {
+        Self {
+            path,
+            external: true,
+        }
+    }
+
+    /// Returns the path.
+    ///
+    /// This is absolute once the path has been resolved.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns whether this path is allowed to resolve outside the workspace
+    /// directory.
+    pub fn is_external(&self) -> bool {
+        self.external
+    }
+
+    /// Returns the inner [`PathBuf`].
+    pub fn into_inner(self) -> PathBuf {
+        self.path
+    }
+
+    /// Returns this path resolved against the workspace directory.
+    ///
+    /// The returned path is absolute if `workspace_dir` is absolute, and has
+    /// `.` and `..` components normalized. Resolving an already resolved
+    /// path returns the same path.
+    ///
+    /// Returns `None` if the path is not [`external`] and resolves outside the
+    /// workspace directory.
+    ///
+    /// [`external`]: Self::external
+    pub fn resolve(&self, workspace_dir: &WorkspaceDir) -> Option<WorkspacePath> {
+        let workspace_dir = path_normalize(workspace_dir);
+        let path = path_normalize(&workspace_dir.join(&self.path));
+
+        if self.external || path.starts_with(&workspace_dir) {
+            Some(Self {
+                path,
+                external: self.external,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the path with `.` and `..` components lexically normalized.
+///
+/// `..` at the root of an absolute path stays at the root. Leading `..`
+/// components of relative paths are retained.
+fn path_normalize(path: &Path) -> PathBuf {
+    path.components()
+        .fold(PathBuf::new(), |mut path_normalized, component| {
+            match component {
+                Component::Prefix(_) | Component::RootDir | Component::Normal(_) => {
+                    path_normalized.push(component)
+                }
+                Component::CurDir => {}
+                Component::ParentDir => match path_normalized.components().next_back() {
+                    Some(Component::Normal(_)) => {
+                        path_normalized.pop();
+                    }
+                    Some(Component::Prefix(_) | Component::RootDir) => {}
+                    Some(Component::CurDir | Component::ParentDir) | None => {
+                        path_normalized.push(component)
+                    }
+                },
+            }
+            path_normalized
+        })
+}
+
+impl From<PathBuf> for WorkspacePath {
+    fn from(path: PathBuf) -> Self {
+        Self::new(path)
+    }
+}
+
+impl AsRef<Path> for WorkspacePath {
+    fn as_ref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl std::ops::Deref for WorkspacePath {
+    type Target = Path;
+
+    fn deref(&self) -> &Self::Target {
+        &self.path
+    }
+}
@@ -1,25 +1,33 @@
 //! Resources that track current and goal states, and state diffs.
 
 pub use self::{
-    state_diffs::StateDiffs, states_clean::StatesClean, states_cleaned::StatesCleaned,
-    states_cleaned_dry::StatesCleanedDry, states_current::StatesCurrent,
-    states_current_stored::StatesCurrentStored, states_ensured::StatesEnsured,
-    states_ensured_dry::StatesEnsuredDry, states_goal::StatesGoal,
+    diff_summary::DiffSummary, state_diffs::StateDiffs, states_clean::StatesClean,
+    states_cleaned::StatesCleaned, states_cleaned_dry::StatesCleanedDry,
+    states_current::StatesCurrent, states_current_stored::StatesCurrentStored,
+    states_ensured::StatesEnsured, states_ensured_dry::StatesEnsuredDry, states_goal::StatesGoal,
     states_goal_stored::StatesGoalStored, states_previous::StatesPrevious,
-    states_serde::StatesSerde,
+    states_serde::StatesSerde, states_timestamps::StatesTimestamps, states_view::StatesView,
+    states_view_error::StatesViewError,
 };
 
 pub mod ts;
 
-use std::{marker::PhantomData, ops::Deref};
+use std::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+    ops::Deref,
+    time::Duration,
+};
 
+use chrono::{DateTime, Utc};
 use peace_core::ItemId;
 use peace_fmt::{Presentable, Presenter};
 use serde::Serialize;
-use type_reg::untagged::{BoxDtDisplay, TypeMap};
+use type_reg::untagged::{BoxDataTypeDowncast, BoxDtDisplay, TypeMap};
 
 use crate::internal::StatesMut;
 
+mod diff_summary;
 mod state_diffs;
 mod states_clean;
 mod states_cleaned;
@@ -32,6 +40,9 @@ mod states_goal;
 mod states_goal_stored;
 mod states_previous;
 mod states_serde;
+mod states_timestamps;
+mod states_view;
+mod states_view_error;
 
 /// Map of `State`s for all `Item`s. `TypeMap<ItemId, Item::State>` newtype.
 ///
@@ -142,12 +153,19 @@ mod states_serde;
 ///   be cleaned, forcing successors to be cleaned, and reensured after. Unless,
 ///   `peace` supports backup and restore.
 ///
+/// # Freshness
+///
+/// Each state is recorded with when it was discovered, in
+/// [`StatesTimestamps`]. These are not part of the serialized states, and are
+/// stored alongside each states file.
+///
 /// [`flatten()`]: std::option::Option::flatten
-#[derive(Debug, Serialize)]
+#[derive(Serialize)]
 #[serde(transparent)] // Needed to serialize as a map instead of a list.
 pub struct States<TS>(
     pub(crate) TypeMap<ItemId, BoxDtDisplay>,
     pub(crate) PhantomData<TS>,
+    #[serde(skip)] pub(crate) StatesTimestamps,
 );
 
 impl<TS> States<TS> {
@@ -161,18 +179,96 @@ impl<TS> States<TS> {
     /// The `States` will be able to hold at least capacity elements
     /// without reallocating. If capacity is 0, the map will not allocate.
     pub fn with_capacity(capacity: usize) -> Self {
-        Self(TypeMap::with_capacity_typed(capacity), PhantomData)
+        Self(
+            TypeMap::with_capacity_typed(capacity),
+            PhantomData,
+            StatesTimestamps::with_capacity(capacity),
+        )
     }
 
     /// Returns the inner map.
     pub fn into_inner(self) -> TypeMap<ItemId, BoxDtDisplay> {
         self.0
     }
+
+    /// Returns when the state for the given item was discovered, if known.
+    ///
+    /// This is `None` if there is no state for the item, or the state was
+    /// stored before timestamps were recorded.
+    pub fn freshness(&self, item_id: &ItemId) -> Option<DateTime<Utc>> {
+        self.2.get(item_id).copied()
+    }
+
+    /// Returns when each item's state was discovered.
+    pub fn timestamps(&self) -> &StatesTimestamps {
+        &self.2
+    }
+
+    /// Sets when each item's state was discovered.
+    ///
+    /// Timestamps for items that do not have a state are ignored.
+    pub fn with_timestamps(mut self, timestamps: StatesTimestamps) -> Self {
+        self.2 = timestamps
+            .into_inner()
+            .into_iter()
+            .filter(|(item_id, _timestamp)| self.0.contains_key(item_id))
+            .collect();
+        self
+    }
+
+    /// Returns the IDs of items whose state was discovered more than
+    /// `max_staleness` before `now`.
+    ///
+    /// Items whose discovery time is not known are not included.
+    pub fn item_ids_older_than(&self, max_staleness: Duration, now: DateTime<Utc>) -> Vec<ItemId> {
+        self.2
+            .iter()
+            .filter(|(_item_id, timestamp)| {
+                now.signed_duration_since(**timestamp)
+                    .to_std()
+                    .is_ok_and(|staleness| staleness > max_staleness)
+            })
+            .map(|(item_id, _timestamp)| item_id.clone())
+            .collect()
+    }
+
+    /// Returns the state of the given item, or a [`StatesViewError`] if the
+    /// item has no state, or its state is not of type `T`.
+    pub fn try_get<T>(&self, item_id: &ItemId) -> Result<&T, StatesViewError>
+    where
+        T: Clone + Debug + Serialize + Send + Sync + 'static,
+        BoxDtDisplay: BoxDataTypeDowncast<T>,
+    {
+        self.0
+            .get::<T, _>(item_id)
+            .ok_or_else(|| StatesViewError::new(vec![item_id.clone()]))
+    }
+
+    /// Returns a strongly typed view of the states, with one field per item.
+    ///
+    /// The view type is declared with the [`states_view!`] macro.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StatesViewError`] with every item in the view whose state
+    /// is missing, or is not of the declared type.
+    ///
+    /// [`states_view!`]: crate::states_view
+    pub fn view<'states, V>(&'states self) -> Result<V, StatesViewError>
+    where
+        V: StatesView<'states>,
+    {
+        V::try_from_states(self)
+    }
 }
 
 impl<TS> Clone for States<TS> {
     fn clone(&self) -> Self {
-        let mut clone = Self(TypeMap::with_capacity_typed(self.0.len()), PhantomData);
+        let mut clone = Self(
+            TypeMap::with_capacity_typed(self.0.len()),
+            PhantomData,
+            self.2.clone(),
+        );
         clone.0.extend(
             self.0
                 .iter()
@@ -183,9 +279,20 @@ impl<TS> Clone for States<TS> {
     }
 }
 
+// Timestamps are omitted, as they describe when the states were discovered,
+// rather than the states themselves.
+impl<TS> Debug for States<TS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("States")
+            .field(&self.0)
+            .field(&self.1)
+            .finish()
+    }
+}
+
 impl<TS> Default for States<TS> {
     fn default() -> Self {
-        Self(TypeMap::default(), PhantomData)
+        Self(TypeMap::default(), PhantomData, StatesTimestamps::new())
     }
 }
 
@@ -199,13 +306,25 @@ impl<TS> Deref for States<TS> {
 
 impl<TS> From<TypeMap<ItemId, BoxDtDisplay>> for States<TS> {
     fn from(type_map: TypeMap<ItemId, BoxDtDisplay>) -> Self {
-        Self(type_map, PhantomData)
+        Self(type_map, PhantomData, StatesTimestamps::new())
     }
 }
 
+/// States that do not have a timestamp recorded in the `StatesMut` are
+/// timestamped with the current time.
 impl<TS> From<StatesMut<TS>> for States<TS> {
     fn from(states_mut: StatesMut<TS>) -> Self {
-        Self(states_mut.into_inner(), PhantomData)
+        let (type_map, mut timestamps) = states_mut.into_inner_with_timestamps();
+        let now = Utc::now();
+        let timestamps = type_map
+            .keys()
+            .map(|item_id| {
+                let timestamp = timestamps.swap_remove(item_id).unwrap_or(now);
+                (item_id.clone(), timestamp)
+            })
+            .collect::<StatesTimestamps>();
+
+        Self(type_map, PhantomData, timestamps)
     }
 }
 
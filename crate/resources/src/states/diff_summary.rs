@@ -0,0 +1,111 @@
+use std::ops::Deref;
+
+use indexmap::IndexMap;
+use peace_core::{DiffSeverity, ItemId};
+use peace_fmt::{Presentable, Presenter};
+use serde::{Deserialize, Serialize};
+
+/// Severity of each `Item`'s state diff. `IndexMap<ItemId, DiffSeverity>`
+/// newtype.
+///
+/// This summarizes [`StateDiffs`], so that the number of in sync, additive,
+/// and destructive changes can be shown, and mapped to an exit code.
+///
+/// [`StateDiffs`]: crate::states::StateDiffs
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DiffSummary(IndexMap<ItemId, DiffSeverity>);
+
+impl DiffSummary {
+    /// Returns a new `DiffSummary` map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty `DiffSummary` map with the specified capacity.
+    ///
+    /// The `DiffSummary` will be able to hold at least capacity elements
+    /// without reallocating. If capacity is 0, the map will not allocate.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(IndexMap::with_capacity(capacity))
+    }
+
+    /// Returns the inner map.
+    pub fn into_inner(self) -> IndexMap<ItemId, DiffSeverity> {
+        self.0
+    }
+
+    /// Inserts the diff severity for an item.
+    pub fn insert(&mut self, item_id: ItemId, diff_severity: DiffSeverity) {
+        self.0.insert(item_id, diff_severity);
+    }
+
+    /// Returns the number of items that are in sync.
+    pub fn in_sync_count(&self) -> usize {
+        self.count(DiffSeverity::InSync)
+    }
+
+    /// Returns the number of items with additive changes.
+    pub fn additive_count(&self) -> usize {
+        self.count(DiffSeverity::Additive)
+    }
+
+    /// Returns the number of items with destructive changes.
+    pub fn destructive_count(&self) -> usize {
+        self.count(DiffSeverity::Destructive)
+    }
+
+    /// Returns the most severe diff severity, or [`DiffSeverity::InSync`] if
+    /// there are no diffs.
+    pub fn severity_max(&self) -> DiffSeverity {
+        self.0
+            .values()
+            .copied()
+            .max()
+            .unwrap_or(DiffSeverity::InSync)
+    }
+
+    /// Returns the exit code for the most severe diff.
+    ///
+    /// See [`DiffSeverity::detailed_exit_code`].
+    pub fn detailed_exit_code(&self) -> i32 {
+        self.severity_max().detailed_exit_code()
+    }
+
+    fn count(&self, diff_severity: DiffSeverity) -> usize {
+        self.0
+            .values()
+            .filter(|severity| **severity == diff_severity)
+            .count()
+    }
+}
+
+impl Deref for DiffSummary {
+    type Target = IndexMap<ItemId, DiffSeverity>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<IndexMap<ItemId, DiffSeverity>> for DiffSummary {
+    fn from(index_map: IndexMap<ItemId, DiffSeverity>) -> Self {
+        Self(index_map)
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for DiffSummary {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        presenter
+            .text(&format!(
+                "{} in sync, {} additive, {} destructive",
+                self.in_sync_count(),
+                self.additive_count(),
+                self.destructive_count()
+            ))
+            .await
+    }
+}
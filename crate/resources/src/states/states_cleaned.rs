@@ -18,6 +18,8 @@ pub type StatesCleaned = States<Cleaned>;
 
 impl From<StatesCurrent> for StatesCleaned {
     fn from(states: StatesCurrent) -> Self {
-        Self(states.into_inner(), PhantomData)
+        let States(type_map, PhantomData, timestamps) = states;
+
+        Self(type_map, PhantomData, timestamps)
     }
 }
@@ -17,6 +17,8 @@ pub type StatesCleanedDry = States<CleanedDry>;
 
 impl From<StatesCurrent> for StatesCleanedDry {
     fn from(states: StatesCurrent) -> Self {
-        Self(states.into_inner(), PhantomData)
+        let States(type_map, PhantomData, timestamps) = states;
+
+        Self(type_map, PhantomData, timestamps)
     }
 }
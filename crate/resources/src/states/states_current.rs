@@ -52,8 +52,8 @@ pub type StatesCurrent = States<Current>;
 
 impl From<States<CurrentStored>> for States<Current> {
     fn from(states_current_stored: States<CurrentStored>) -> Self {
-        let States(type_map, PhantomData) = states_current_stored;
+        let States(type_map, PhantomData, timestamps) = states_current_stored;
 
-        Self(type_map, PhantomData)
+        Self(type_map, PhantomData, timestamps)
     }
 }
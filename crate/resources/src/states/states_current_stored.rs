@@ -56,8 +56,8 @@ pub type StatesCurrentStored = States<CurrentStored>;
 
 impl From<States<Current>> for States<CurrentStored> {
     fn from(states_current: States<Current>) -> Self {
-        let States(type_map, PhantomData) = states_current;
+        let States(type_map, PhantomData, timestamps) = states_current;
 
-        Self(type_map, PhantomData)
+        Self(type_map, PhantomData, timestamps)
     }
 }
@@ -15,6 +15,8 @@ pub type StatesEnsured = States<Ensured>;
 
 impl From<StatesCurrent> for StatesEnsured {
     fn from(states_current: StatesCurrent) -> Self {
-        Self(states_current.into_inner(), PhantomData)
+        let States(type_map, PhantomData, timestamps) = states_current;
+
+        Self(type_map, PhantomData, timestamps)
     }
 }
@@ -17,6 +17,8 @@ pub type StatesEnsuredDry = States<EnsuredDry>;
 
 impl From<StatesCurrent> for StatesEnsuredDry {
     fn from(states_current: StatesCurrent) -> Self {
-        Self(states_current.into_inner(), PhantomData)
+        let States(type_map, PhantomData, timestamps) = states_current;
+
+        Self(type_map, PhantomData, timestamps)
     }
 }
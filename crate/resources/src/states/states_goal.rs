@@ -49,8 +49,8 @@ pub type StatesGoal = States<Goal>;
 
 impl From<States<GoalStored>> for States<Goal> {
     fn from(states_goal_stored: States<GoalStored>) -> Self {
-        let States(type_map, PhantomData) = states_goal_stored;
+        let States(type_map, PhantomData, timestamps) = states_goal_stored;
 
-        Self(type_map, PhantomData)
+        Self(type_map, PhantomData, timestamps)
     }
 }
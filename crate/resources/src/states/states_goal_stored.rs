@@ -25,8 +25,8 @@ pub type StatesGoalStored = States<GoalStored>;
 
 impl From<States<Goal>> for States<GoalStored> {
     fn from(states_goal: States<Goal>) -> Self {
-        let States(type_map, PhantomData) = states_goal;
+        let States(type_map, PhantomData, timestamps) = states_goal;
 
-        Self(type_map, PhantomData)
+        Self(type_map, PhantomData, timestamps)
     }
 }
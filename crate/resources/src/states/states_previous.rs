@@ -10,6 +10,8 @@ pub type StatesPrevious = States<Previous>;
 
 impl From<StatesCurrent> for StatesPrevious {
     fn from(states_current: StatesCurrent) -> Self {
-        Self(states_current.into_inner(), PhantomData)
+        let States(type_map, PhantomData, timestamps) = states_current;
+
+        Self(type_map, PhantomData, timestamps)
     }
 }
@@ -0,0 +1,61 @@
+use std::ops::{Deref, DerefMut};
+
+use chrono::{DateTime, Utc};
+use indexmap::IndexMap;
+use peace_core::ItemId;
+use serde::{Deserialize, Serialize};
+
+/// When each `Item`'s state was discovered. `IndexMap<ItemId, DateTime<Utc>>`
+/// newtype.
+///
+/// This is stored alongside each states file, so that the age of stored
+/// states can be shown, and states older than a maximum staleness can be
+/// rediscovered.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct StatesTimestamps(IndexMap<ItemId, DateTime<Utc>>);
+
+impl StatesTimestamps {
+    /// Returns a new `StatesTimestamps` map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty `StatesTimestamps` map with the specified capacity.
+    ///
+    /// The `StatesTimestamps` will be able to hold at least capacity elements
+    /// without reallocating. If capacity is 0, the map will not allocate.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(IndexMap::with_capacity(capacity))
+    }
+
+    /// Returns the inner map.
+    pub fn into_inner(self) -> IndexMap<ItemId, DateTime<Utc>> {
+        self.0
+    }
+}
+
+impl Deref for StatesTimestamps {
+    type Target = IndexMap<ItemId, DateTime<Utc>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for StatesTimestamps {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<IndexMap<ItemId, DateTime<Utc>>> for StatesTimestamps {
+    fn from(timestamps: IndexMap<ItemId, DateTime<Utc>>) -> Self {
+        Self(timestamps)
+    }
+}
+
+impl FromIterator<(ItemId, DateTime<Utc>)> for StatesTimestamps {
+    fn from_iter<T: IntoIterator<Item = (ItemId, DateTime<Utc>)>>(iter: T) -> Self {
+        Self(IndexMap::from_iter(iter))
+    }
+}
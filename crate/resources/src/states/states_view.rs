@@ -0,0 +1,86 @@
+use crate::states::{States, StatesViewError};
+
+/// Strongly typed view of the states of items in a flow.
+///
+/// Instead of looking up each item's state with
+/// `states.get::<MyItemState, _>(&item_id)`, a view is declared once with the
+/// [`states_view!`] macro, and retrieved using [`States::view`]:
+///
+/// ```rust,ignore
+/// peace::resources::states_view! {
+///     /// States of the items in the app deploy flow.
+///     pub struct AppDeployStates {
+///         pub app_download: FileDownloadState = item_id!("app_download"),
+///         pub app_extract: TarXState = item_id!("app_extract"),
+///     }
+/// }
+///
+/// let app_deploy_states = states_current_stored.view::<AppDeployStates>()?;
+/// println!("{}", app_deploy_states.app_download);
+/// ```
+///
+/// [`states_view!`]: crate::states_view
+pub trait StatesView<'states>: Sized {
+    /// Returns the typed view of the given states.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StatesViewError`] with every item whose state is missing,
+    /// or is not of the type declared in the view.
+    fn try_from_states<TS>(states: &'states States<TS>) -> Result<Self, StatesViewError>;
+}
+
+/// Declares a [`StatesView`] struct, with one field per item's state.
+///
+/// Each field is declared as `name: StateType = item_id_expr`, where the item
+/// ID expression evaluates to an `ItemId` or `&ItemId`. The generated struct
+/// has a `'states` lifetime parameter, and each field is a `&'states
+/// StateType`.
+///
+/// See [`StatesView`] for an example.
+///
+/// [`StatesView`]: crate::states::StatesView
+#[macro_export]
+macro_rules! states_view {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_attr:meta])*
+                $field_vis:vis $field:ident: $state_ty:ty = $item_id:expr
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug)]
+        $vis struct $name<'states> {
+            $(
+                $(#[$field_attr])*
+                $field_vis $field: &'states $state_ty,
+            )*
+        }
+
+        impl<'states> $crate::states::StatesView<'states> for $name<'states> {
+            fn try_from_states<TS>(
+                states: &'states $crate::states::States<TS>,
+            ) -> Result<Self, $crate::states::StatesViewError> {
+                let mut item_ids_missing = ::std::vec::Vec::new();
+                $(
+                    let $field = match states.try_get::<$state_ty>(&$item_id) {
+                        Ok(state) => Some(state),
+                        Err(states_view_error) => {
+                            item_ids_missing.extend(states_view_error.into_item_ids_missing());
+                            None
+                        }
+                    };
+                )*
+
+                match ($($field,)*) {
+                    ($(Some($field),)*) => Ok(Self { $($field,)* }),
+                    #[allow(unreachable_patterns)]
+                    _ => Err($crate::states::StatesViewError::new(item_ids_missing)),
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,49 @@
+use std::fmt;
+
+use peace_core::ItemId;
+
+/// States are missing for items in a [`StatesView`].
+///
+/// This is returned when an item in the view has no recorded state, or its
+/// state is not of the type declared in the view.
+///
+/// [`StatesView`]: crate::states::StatesView
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatesViewError {
+    /// IDs of the items whose states are missing.
+    item_ids_missing: Vec<ItemId>,
+}
+
+impl StatesViewError {
+    /// Returns a new `StatesViewError`.
+    pub fn new(item_ids_missing: Vec<ItemId>) -> Self {
+        Self { item_ids_missing }
+    }
+
+    /// Returns the IDs of the items whose states are missing.
+    pub fn item_ids_missing(&self) -> &[ItemId] {
+        &self.item_ids_missing
+    }
+
+    /// Returns the IDs of the items whose states are missing.
+    pub fn into_item_ids_missing(self) -> Vec<ItemId> {
+        self.item_ids_missing
+    }
+}
+
+impl fmt::Display for StatesViewError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "States are missing for items: ")?;
+        self.item_ids_missing
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, item_id)| {
+                if index != 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "`{item_id}`")
+            })
+    }
+}
+
+impl std::error::Error for StatesViewError {}
@@ -6,6 +6,7 @@ pub use self::{
     apply_exec_cmd_block::ApplyExecCmdBlock,
     apply_state_sync_check_cmd_block::ApplyStateSyncCheckCmdBlock,
     diff_cmd_block::{DiffCmdBlock, DiffCmdBlockStatesTsExt},
+    preflight_check_cmd_block::PreflightCheckCmdBlock,
     states_clean_insertion_cmd_block::StatesCleanInsertionCmdBlock,
     states_current_read_cmd_block::StatesCurrentReadCmdBlock,
     states_discover_cmd_block::StatesDiscoverCmdBlock,
@@ -15,7 +16,37 @@ pub use self::{
 pub mod apply_exec_cmd_block;
 mod apply_state_sync_check_cmd_block;
 mod diff_cmd_block;
+mod preflight_check_cmd_block;
 mod states_clean_insertion_cmd_block;
 mod states_current_read_cmd_block;
 mod states_discover_cmd_block;
 mod states_goal_read_cmd_block;
+
+/// Returns the text of a framework message in the locale of the
+/// `MessageCatalog` in `resources`.
+///
+/// The message's English text is returned if there is no catalog.
+#[cfg(feature = "output_progress")]
+fn message_text(
+    resources: &peace_resources::Resources<peace_resources::resources::ts::SetUp>,
+    message: peace_fmt::Message,
+    args: &[(&str, &str)],
+) -> String {
+    match resources.try_borrow::<peace_fmt::MessageCatalog>() {
+        Ok(message_catalog) => message_catalog.text(message, args),
+        Err(_) => message.text(args),
+    }
+}
+
+/// Returns whether the item is disabled for the profile.
+///
+/// Items are not disabled if there is no `ItemsDisabled` in `resources`.
+fn item_disabled(
+    resources: &peace_resources::Resources<peace_resources::resources::ts::SetUp>,
+    item_id: &peace_cfg::ItemId,
+) -> bool {
+    resources
+        .try_borrow::<peace_rt_model::ItemsDisabled>()
+        .map(|items_disabled| items_disabled.contains(item_id))
+        .unwrap_or(false)
+}
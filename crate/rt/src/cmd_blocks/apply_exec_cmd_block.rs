@@ -1,10 +1,11 @@
-use std::{fmt::Debug, marker::PhantomData};
+use std::{cell::RefCell, fmt::Debug, marker::PhantomData};
 
-use fn_graph::{StreamOpts, StreamOutcome};
+use chrono::Utc;
+use fn_graph::{daggy::petgraph::Direction, FnGraph, FnId, StreamOpts, StreamOutcome};
 use futures::join;
-use peace_cfg::{ApplyCheck, FnCtx, ItemId};
+use peace_cfg::{ApplyCheck, ApplyCtx, ExecutionId, FnCtx, ItemGroupId, ItemId};
 use peace_cmd::{ctx::CmdCtxTypesConstrained, scopes::SingleProfileSingleFlowView};
-use peace_cmd_model::CmdBlockOutcome;
+use peace_cmd_model::{CmdBlockItemAction, CmdBlockOutcome};
 use peace_cmd_rt::{async_trait, CmdBlock};
 use peace_params::ParamsSpecs;
 use peace_resources::{
@@ -18,14 +19,33 @@ use peace_resources::{
 };
 use peace_rt_model::{
     outcomes::{ItemApplyBoxed, ItemApplyPartialBoxed},
-    ItemBoxed, ItemRt,
+    ApplyHookOutcome, ApplyHooks, ApprovalPolicy, ExecutionPhase, ExecutionTimelineRecorder,
+    ItemBoxed, ItemGraph, ItemRt,
 };
 use tokio::sync::mpsc::{self, Receiver};
 
 use peace_rt_model_core::IndexMap;
 use tokio::sync::mpsc::Sender;
 
-use crate::BUFFERED_FUTURES_MAX;
+use crate::{
+    cmd_blocks::item_disabled,
+    cmds::{ApplyErrorPolicy, ApplyPlan, ApplyPlanAction, ItemApplyPlan},
+};
+
+#[cfg(feature = "telemetry")]
+use std::time::Instant;
+
+#[cfg(feature = "telemetry")]
+use peace_rt_model::CmdMetrics;
+
+#[cfg(all(feature = "output_progress", not(target_arch = "wasm32")))]
+use std::future::Future;
+
+#[cfg(all(feature = "output_progress", not(target_arch = "wasm32")))]
+use futures::future::Either;
+
+#[cfg(all(feature = "output_progress", not(target_arch = "wasm32")))]
+use peace_rt_model::HeartbeatPolicy;
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "output_progress")] {
@@ -35,22 +55,34 @@ cfg_if::cfg_if! {
             progress::{
                 CmdProgressUpdate,
                 ProgressComplete,
+                ProgressDelta,
                 ProgressMsgUpdate,
                 ProgressUpdate,
                 ProgressUpdateAndId,
                 ProgressSender,
             },
         };
+        use peace_fmt::messages;
+
+        use crate::cmd_blocks::message_text;
     }
 }
 
 /// Stops a `CmdExecution` if stored states and discovered states are not in
 /// sync.
-pub struct ApplyExecCmdBlock<CmdCtxTypesT, StatesTs>(PhantomData<(CmdCtxTypesT, StatesTs)>);
+pub struct ApplyExecCmdBlock<CmdCtxTypesT, StatesTs> {
+    /// How to proceed when an item fails.
+    apply_error_policy: ApplyErrorPolicy,
+    /// Marker.
+    marker: PhantomData<(CmdCtxTypesT, StatesTs)>,
+}
 
 impl<CmdCtxTypesT, StatesTs> Debug for ApplyExecCmdBlock<CmdCtxTypesT, StatesTs> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_tuple("ApplyExecCmdBlock").field(&self.0).finish()
+        f.debug_struct("ApplyExecCmdBlock")
+            .field("apply_error_policy", &self.apply_error_policy)
+            .field("marker", &self.marker)
+            .finish()
     }
 }
 
@@ -60,13 +92,24 @@ impl<CmdCtxTypesT, StatesTs> ApplyExecCmdBlock<CmdCtxTypesT, StatesTs> {
     /// This is a generic constructor where `StatesTs` determines whether the
     /// goal state or clean state is the target state.
     pub fn new() -> Self {
-        Self(PhantomData)
+        Self::default()
+    }
+
+    /// Sets how to proceed when an item fails.
+    ///
+    /// Defaults to [`ApplyErrorPolicy::Abort`].
+    pub fn with_apply_error_policy(mut self, apply_error_policy: ApplyErrorPolicy) -> Self {
+        self.apply_error_policy = apply_error_policy;
+        self
     }
 }
 
 impl<CmdCtxTypesT, StatesTs> Default for ApplyExecCmdBlock<CmdCtxTypesT, StatesTs> {
     fn default() -> Self {
-        Self(PhantomData)
+        Self {
+            apply_error_policy: ApplyErrorPolicy::default(),
+            marker: PhantomData,
+        }
     }
 }
 
@@ -76,7 +119,7 @@ where
 {
     /// Returns an `ApplyExecCmdBlock` with the goal state as the target state.
     pub fn ensure() -> Self {
-        Self(PhantomData)
+        Self::default()
     }
 }
 
@@ -86,7 +129,7 @@ where
 {
     /// Returns an `ApplyExecCmdBlock` with the goal state as the target state.
     pub fn ensure_dry() -> Self {
-        Self(PhantomData)
+        Self::default()
     }
 }
 
@@ -96,7 +139,7 @@ where
 {
     /// Returns an `ApplyExecCmdBlock` with the clean state as the target state.
     pub fn clean() -> Self {
-        Self(PhantomData)
+        Self::default()
     }
 }
 
@@ -106,7 +149,7 @@ where
 {
     /// Returns an `ApplyExecCmdBlock` with the clean state as the target state.
     pub fn clean_dry() -> Self {
-        Self(PhantomData)
+        Self::default()
     }
 }
 
@@ -143,13 +186,23 @@ where
         let ItemApplyExecCtx {
             params_specs,
             resources,
+            apply_ctx,
+            apply_hooks,
+            approval_policy,
+            #[cfg(all(feature = "output_progress", not(target_arch = "wasm32")))]
+            heartbeat_policy,
             apply_for_internal,
             #[cfg(feature = "output_progress")]
             progress_tx,
             outcomes_tx,
+            #[cfg(feature = "telemetry")]
+            cmd_metrics,
         } = item_apply_exec_ctx;
 
         let item_id = item.id();
+        #[cfg(feature = "telemetry")]
+        let apply_start = Instant::now();
+        let apply_start_dt = Utc::now();
 
         // Indicate this item is running, so that an `Interrupt` message from
         // `CmdExecution` does not cause it to be rendered as `Interrupted`.
@@ -163,6 +216,96 @@ where
             .into(),
         );
 
+        if item_disabled(resources, item_id) {
+            #[cfg(feature = "output_progress")]
+            let _progress_send_unused = progress_tx.try_send(
+                ProgressUpdateAndId {
+                    item_id: item_id.clone(),
+                    progress_update: ProgressUpdate::Complete(ProgressComplete::Skipped),
+                    msg_update: ProgressMsgUpdate::Set(message_text(
+                        resources,
+                        messages::PROGRESS_DISABLED,
+                        &[],
+                    )),
+                }
+                .into(),
+            );
+
+            #[cfg(feature = "telemetry")]
+            cmd_metrics.item_skipped(item_id);
+
+            outcomes_tx
+                .send(ItemApplyOutcome::Skipped {
+                    item_id: item_id.clone(),
+                })
+                .await
+                .expect("unreachable: `outcomes_rx` is in a sibling task.");
+
+            return Ok(());
+        }
+
+        match ItemRt::applicable(&**item, params_specs, resources) {
+            Ok(true) => {}
+            Ok(false) => {
+                #[cfg(feature = "output_progress")]
+                let _progress_send_unused = progress_tx.try_send(
+                    ProgressUpdateAndId {
+                        item_id: item_id.clone(),
+                        progress_update: ProgressUpdate::Complete(ProgressComplete::Skipped),
+                        msg_update: ProgressMsgUpdate::Set(message_text(
+                            resources,
+                            messages::PROGRESS_NOT_APPLICABLE,
+                            &[],
+                        )),
+                    }
+                    .into(),
+                );
+
+                #[cfg(feature = "telemetry")]
+                cmd_metrics.item_skipped(item_id);
+
+                outcomes_tx
+                    .send(ItemApplyOutcome::Skipped {
+                        item_id: item_id.clone(),
+                    })
+                    .await
+                    .expect("unreachable: `outcomes_rx` is in a sibling task.");
+
+                return Ok(());
+            }
+            Err(error) => {
+                #[cfg(feature = "output_progress")]
+                let _progress_send_unused = progress_tx.try_send(
+                    ProgressUpdateAndId {
+                        item_id: item_id.clone(),
+                        progress_update: ProgressUpdate::Complete(ProgressComplete::Fail),
+                        msg_update: ProgressMsgUpdate::Set(format!("{error}")),
+                    }
+                    .into(),
+                );
+
+                ExecutionTimelineRecorder::record_in(
+                    resources,
+                    item_id,
+                    ExecutionPhase::Apply,
+                    apply_start_dt,
+                );
+
+                #[cfg(feature = "telemetry")]
+                cmd_metrics.item_apply_failed(item_id, apply_start.elapsed());
+
+                outcomes_tx
+                    .send(ItemApplyOutcome::ApplicableFail {
+                        item_id: item_id.clone(),
+                        error,
+                    })
+                    .await
+                    .expect("unreachable: `outcomes_rx` is in a sibling task.");
+
+                return Err(());
+            }
+        }
+
         let apply_fn = if StatesTs::dry_run() {
             ItemRt::apply_exec_dry
         } else {
@@ -173,13 +316,15 @@ where
             item_id,
             #[cfg(feature = "output_progress")]
             ProgressSender::new(item_id, progress_tx),
-        );
+        )
+        .with_apply_ctx(apply_ctx);
         let item_apply = match apply_for_internal {
             ApplyForInternal::Ensure => {
-                ItemRt::ensure_prepare(&**item, params_specs, resources, fn_ctx).await
+                ItemRt::ensure_prepare(&**item, params_specs, resources, fn_ctx, apply_ctx).await
             }
             ApplyForInternal::Clean { states_current } => {
-                ItemRt::clean_prepare(&**item, states_current, params_specs, resources).await
+                ItemRt::clean_prepare(&**item, states_current, params_specs, resources, apply_ctx)
+                    .await
             }
         };
 
@@ -187,15 +332,27 @@ where
             Ok(mut item_apply) => {
                 match item_apply.apply_check() {
                     #[cfg(not(feature = "output_progress"))]
-                    ApplyCheck::ExecRequired => {}
+                    ApplyCheck::ExecRequired { .. } => {}
                     #[cfg(feature = "output_progress")]
-                    ApplyCheck::ExecRequired { progress_limit } => {
-                        // Update `OutputWrite`s with progress limit.
+                    ApplyCheck::ExecRequired {
+                        progress_limit,
+                        reason,
+                    } => {
+                        // Update `OutputWrite`s with progress limit, and why the item is being
+                        // applied.
+                        let msg = match reason {
+                            Some(reason) => message_text(
+                                resources,
+                                messages::PROGRESS_IN_PROGRESS_REASON,
+                                &[("reason", reason.to_string().as_str())],
+                            ),
+                            None => message_text(resources, messages::PROGRESS_IN_PROGRESS, &[]),
+                        };
                         let _progress_send_unused = progress_tx.try_send(
                             ProgressUpdateAndId {
                                 item_id: item_id.clone(),
                                 progress_update: ProgressUpdate::Limit(progress_limit),
-                                msg_update: ProgressMsgUpdate::Set(String::from("in progress")),
+                                msg_update: ProgressMsgUpdate::Set(msg),
                             }
                             .into(),
                         );
@@ -208,11 +365,25 @@ where
                                 progress_update: ProgressUpdate::Complete(
                                     ProgressComplete::Success,
                                 ),
-                                msg_update: ProgressMsgUpdate::Set(String::from("nothing to do!")),
+                                msg_update: ProgressMsgUpdate::Set(message_text(
+                                    resources,
+                                    messages::PROGRESS_NOTHING_TO_DO,
+                                    &[],
+                                )),
                             }
                             .into(),
                         );
 
+                        ExecutionTimelineRecorder::record_in(
+                            resources,
+                            item_id,
+                            ExecutionPhase::Apply,
+                            apply_start_dt,
+                        );
+
+                        #[cfg(feature = "telemetry")]
+                        cmd_metrics.item_applied(item_id, apply_start.elapsed());
+
                         // TODO: write test for this case
                         // In case of an interrupt or power failure, we may not have written states
                         // to disk.
@@ -228,7 +399,75 @@ where
                         return Ok(());
                     }
                 }
-                match apply_fn(&**item, params_specs, resources, fn_ctx, &mut item_apply).await {
+                // Dry runs do not change anything, so they do not require approval.
+                let approval_result = if StatesTs::dry_run() || approval_policy.is_empty() {
+                    Ok(())
+                } else {
+                    Self::item_apply_approve(
+                        approval_policy,
+                        item,
+                        &item_apply,
+                        #[cfg(feature = "output_progress")]
+                        resources,
+                        #[cfg(feature = "output_progress")]
+                        progress_tx,
+                    )
+                    .await
+                };
+
+                // Only compute the state diff for hooks if there are any.
+                //
+                // Dry runs do not change anything, so hooks are not run.
+                let state_diff = (!StatesTs::dry_run() && !apply_hooks.is_empty())
+                    .then(|| item_apply.state_diff());
+                let pre_apply_result = match (approval_result, state_diff.as_ref()) {
+                    (Err(error), _) => Err(error),
+                    (Ok(()), Some(state_diff)) => apply_hooks.pre_apply(item_id, state_diff),
+                    (Ok(()), None) => Ok(()),
+                };
+                let apply_attempted = pre_apply_result.is_ok();
+                let apply_result = match pre_apply_result {
+                    // Dry runs do not change anything, so they are not checked for heartbeats.
+                    #[cfg(all(feature = "output_progress", not(target_arch = "wasm32")))]
+                    Ok(()) if heartbeat_policy.interval().is_some() && !StatesTs::dry_run() => {
+                        // Progress is relayed through this channel, so that the time since the
+                        // item's last progress update can be tracked.
+                        let (heartbeat_tx, heartbeat_rx) =
+                            mpsc::channel::<CmdProgressUpdate>(progress_tx.max_capacity());
+                        let fn_ctx =
+                            FnCtx::new(item_id, ProgressSender::new(item_id, &heartbeat_tx))
+                                .with_apply_ctx(apply_ctx);
+                        let apply_exec =
+                            apply_fn(&**item, params_specs, resources, fn_ctx, &mut item_apply);
+
+                        Self::item_apply_exec_heartbeat(
+                            item_id,
+                            apply_exec,
+                            heartbeat_rx,
+                            resources,
+                            progress_tx,
+                            heartbeat_policy,
+                        )
+                        .await
+                    }
+                    Ok(()) => {
+                        apply_fn(&**item, params_specs, resources, fn_ctx, &mut item_apply).await
+                    }
+                    Err(error) => Err(error),
+                };
+                // Post apply hooks are only invoked for items that were applied, i.e. that were
+                // approved and not prevented by a pre apply hook.
+                if let Some(state_diff) = state_diff.as_ref().filter(|_| apply_attempted) {
+                    let apply_hook_outcome = match &apply_result {
+                        Ok(()) => ApplyHookOutcome::Success {
+                            state_applied: item_apply.state_applied(),
+                        },
+                        Err(error) => ApplyHookOutcome::Fail { error },
+                    };
+                    apply_hooks.post_apply(item_id, state_diff, &apply_hook_outcome);
+                }
+
+                match apply_result {
                     Ok(()) => {
                         // apply succeeded
 
@@ -239,11 +478,25 @@ where
                                 progress_update: ProgressUpdate::Complete(
                                     ProgressComplete::Success,
                                 ),
-                                msg_update: ProgressMsgUpdate::Set(String::from("done!")),
+                                msg_update: ProgressMsgUpdate::Set(message_text(
+                                    resources,
+                                    messages::PROGRESS_DONE,
+                                    &[],
+                                )),
                             }
                             .into(),
                         );
 
+                        ExecutionTimelineRecorder::record_in(
+                            resources,
+                            item_id,
+                            ExecutionPhase::Apply,
+                            apply_start_dt,
+                        );
+
+                        #[cfg(feature = "telemetry")]
+                        cmd_metrics.item_applied(item_id, apply_start.elapsed());
+
                         outcomes_tx
                             .send(ItemApplyOutcome::Success {
                                 item_id: item.id().clone(),
@@ -272,6 +525,16 @@ where
                             .into(),
                         );
 
+                        ExecutionTimelineRecorder::record_in(
+                            resources,
+                            item_id,
+                            ExecutionPhase::Apply,
+                            apply_start_dt,
+                        );
+
+                        #[cfg(feature = "telemetry")]
+                        cmd_metrics.item_apply_failed(item_id, apply_start.elapsed());
+
                         outcomes_tx
                             .send(ItemApplyOutcome::Fail {
                                 item_id: item.id().clone(),
@@ -302,6 +565,16 @@ where
                     .into(),
                 );
 
+                ExecutionTimelineRecorder::record_in(
+                    resources,
+                    item_id,
+                    ExecutionPhase::Apply,
+                    apply_start_dt,
+                );
+
+                #[cfg(feature = "telemetry")]
+                cmd_metrics.item_apply_failed(item_id, apply_start.elapsed());
+
                 outcomes_tx
                     .send(ItemApplyOutcome::PrepareFail {
                         item_id: item.id().clone(),
@@ -316,6 +589,271 @@ where
         }
     }
 
+    /// Applies the item, unless an item it is applied after has failed or was
+    /// blocked.
+    ///
+    /// Used for `ApplyErrorPolicy::ContinueOnError`.
+    async fn item_apply_exec_continue_on_error(
+        item_apply_exec_ctx: ItemApplyExecCtx<
+            '_,
+            <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+        >,
+        item: &ItemBoxed<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        item_id_to_applied_after: &IndexMap<ItemId, Vec<ItemId>>,
+        item_id_to_item_id_blocking: &RefCell<IndexMap<ItemId, ItemId>>,
+    ) {
+        let item_id = item.id();
+        let item_id_blocking =
+            item_id_to_applied_after
+                .get(item_id)
+                .and_then(|item_ids_applied_after| {
+                    let item_id_to_item_id_blocking = item_id_to_item_id_blocking.borrow();
+                    item_ids_applied_after
+                        .iter()
+                        .find_map(|item_id_applied_after| {
+                            item_id_to_item_id_blocking
+                                .get(item_id_applied_after)
+                                .cloned()
+                        })
+                });
+
+        match item_id_blocking {
+            Some(item_id_blocking) => {
+                item_id_to_item_id_blocking
+                    .borrow_mut()
+                    .insert(item_id.clone(), item_id_blocking.clone());
+                Self::item_apply_blocked(item_apply_exec_ctx, item, item_id_blocking).await;
+            }
+            None => {
+                if Self::item_apply_exec(item_apply_exec_ctx, item)
+                    .await
+                    .is_err()
+                {
+                    item_id_to_item_id_blocking
+                        .borrow_mut()
+                        .insert(item_id.clone(), item_id.clone());
+                }
+            }
+        }
+    }
+
+    /// Records that an item was not applied because `item_id_blocking` failed.
+    async fn item_apply_blocked(
+        item_apply_exec_ctx: ItemApplyExecCtx<
+            '_,
+            <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+        >,
+        item: &ItemBoxed<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        item_id_blocking: ItemId,
+    ) {
+        let ItemApplyExecCtx {
+            #[cfg(feature = "output_progress")]
+            resources,
+            #[cfg(feature = "output_progress")]
+            progress_tx,
+            outcomes_tx,
+            #[cfg(feature = "telemetry")]
+            cmd_metrics,
+            ..
+        } = item_apply_exec_ctx;
+
+        let item_id = item.id();
+
+        #[cfg(feature = "output_progress")]
+        let _progress_send_unused = progress_tx.try_send(
+            ProgressUpdateAndId {
+                item_id: item_id.clone(),
+                progress_update: ProgressUpdate::Complete(ProgressComplete::Skipped),
+                msg_update: ProgressMsgUpdate::Set(message_text(
+                    resources,
+                    messages::PROGRESS_BLOCKED_BY,
+                    &[("item_id", &**item_id_blocking)],
+                )),
+            }
+            .into(),
+        );
+
+        #[cfg(feature = "telemetry")]
+        cmd_metrics.item_skipped(item_id);
+
+        outcomes_tx
+            .send(ItemApplyOutcome::Blocked {
+                item_id: item_id.clone(),
+                item_id_blocking,
+            })
+            .await
+            .expect("unreachable: `outcomes_rx` is in a sibling task.");
+    }
+
+    /// Returns `Ok(())` if the item may be applied under the `ApprovalPolicy`.
+    ///
+    /// This waits for the policy's approver if the item requires approval.
+    async fn item_apply_approve(
+        approval_policy: &ApprovalPolicy,
+        item: &ItemBoxed<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        item_apply: &ItemApplyBoxed,
+        #[cfg(feature = "output_progress")] resources: &Resources<SetUp>,
+        #[cfg(feature = "output_progress")] progress_tx: &Sender<CmdProgressUpdate>,
+    ) -> Result<(), <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError> {
+        let item_id = item.id();
+        let state_diff = item_apply.state_diff();
+        let diff_severity = ItemRt::diff_severity(
+            &**item,
+            &item_apply.state_current(),
+            &item_apply.state_target(),
+            &state_diff,
+        )?;
+
+        #[cfg(feature = "output_progress")]
+        if approval_policy.approval_required(item_id, diff_severity)
+            && approval_policy.approver().is_some()
+        {
+            let _progress_send_unused = progress_tx.try_send(
+                ProgressUpdateAndId {
+                    item_id: item_id.clone(),
+                    progress_update: ProgressUpdate::Delta(ProgressDelta::Tick),
+                    msg_update: ProgressMsgUpdate::Set(message_text(
+                        resources,
+                        messages::PROGRESS_AWAITING_APPROVAL,
+                        &[],
+                    )),
+                }
+                .into(),
+            );
+        }
+
+        approval_policy
+            .approve(item_id, diff_severity, &state_diff)
+            .await
+            .map_err(<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError::from)
+    }
+
+    /// Runs an item's `apply_exec`, marking it as stalled if it does not send
+    /// a progress update within the `HeartbeatPolicy`'s interval.
+    ///
+    /// Progress updates received on `heartbeat_rx` are forwarded to
+    /// `progress_tx`.
+    #[cfg(all(feature = "output_progress", not(target_arch = "wasm32")))]
+    async fn item_apply_exec_heartbeat<Fut>(
+        item_id: &ItemId,
+        apply_exec: Fut,
+        mut heartbeat_rx: Receiver<CmdProgressUpdate>,
+        resources: &Resources<SetUp>,
+        progress_tx: &Sender<CmdProgressUpdate>,
+        heartbeat_policy: HeartbeatPolicy,
+    ) -> Result<(), <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        Fut: Future<Output = Result<(), <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>>,
+    {
+        let Some(interval) = heartbeat_policy.interval() else {
+            return apply_exec.await;
+        };
+
+        futures::pin_mut!(apply_exec);
+        let mut stalled = false;
+        let apply_result = loop {
+            let heartbeat_rx = &mut heartbeat_rx;
+            let heartbeat_received = async move {
+                // Once stalled, the item is only waited on until it sends progress again.
+                if stalled {
+                    Ok(heartbeat_rx.recv().await)
+                } else {
+                    tokio::time::timeout(interval, heartbeat_rx.recv()).await
+                }
+            };
+            futures::pin_mut!(heartbeat_received);
+
+            match futures::future::select(apply_exec.as_mut(), heartbeat_received).await {
+                Either::Left((apply_result, _)) => break apply_result,
+                Either::Right((Ok(Some(progress_update)), _)) => {
+                    stalled = false;
+                    let _progress_send_unused = progress_tx.try_send(progress_update);
+                }
+                // `heartbeat_tx` outlives `apply_exec`, so this is not expected.
+                Either::Right((Ok(None), _)) => break apply_exec.as_mut().await,
+                Either::Right((Err(_elapsed), _)) => {
+                    stalled = true;
+                    let _progress_send_unused = progress_tx.try_send(
+                        ProgressUpdateAndId {
+                            item_id: item_id.clone(),
+                            progress_update: ProgressUpdate::Delta(ProgressDelta::Tick),
+                            msg_update: ProgressMsgUpdate::Set(message_text(
+                                resources,
+                                messages::PROGRESS_STALLED,
+                                &[("seconds", interval.as_secs_f64().to_string().as_str())],
+                            )),
+                        }
+                        .into(),
+                    );
+
+                    if heartbeat_policy.cancel_on_stall() {
+                        return Err(peace_rt_model::Error::ItemApplyStalled {
+                            item_id: item_id.clone(),
+                            interval,
+                        }
+                        .into());
+                    }
+                }
+            }
+        };
+
+        // Forward progress that was sent just before the apply completed.
+        while let Ok(progress_update) = heartbeat_rx.try_recv() {
+            let _progress_send_unused = progress_tx.try_send(progress_update);
+        }
+
+        apply_result
+    }
+
+    /// Returns the IDs of the items that each item is applied after.
+    ///
+    /// For `ApplyFor::Ensure` these are the item's predecessors, and for
+    /// `ApplyFor::Clean` these are the item's successors.
+    fn item_id_to_applied_after(
+        graph: &FnGraph<ItemBoxed<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>>,
+        apply_for: ApplyFor,
+    ) -> IndexMap<ItemId, Vec<ItemId>> {
+        let direction = match apply_for {
+            ApplyFor::Ensure => Direction::Incoming,
+            ApplyFor::Clean => Direction::Outgoing,
+        };
+        let dag = graph.graph.graph();
+        dag.node_indices()
+            .map(|node_index| {
+                let item_ids_applied_after = dag
+                    .neighbors_directed(node_index, direction)
+                    .map(|neighbour_index| dag[neighbour_index].id().clone())
+                    .collect::<Vec<ItemId>>();
+                (dag[node_index].id().clone(), item_ids_applied_after)
+            })
+            .collect()
+    }
+
+    /// Returns the item IDs in the order that they are cleaned in.
+    ///
+    /// This is the reverse of insertion order, except that items are moved
+    /// after the items that they must be cleaned after.
+    fn item_ids_clean_order(
+        graph: &FnGraph<ItemBoxed<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>>,
+    ) -> Vec<ItemId> {
+        let dag = graph.graph.graph();
+        let mut node_indices_remaining = dag.node_indices().collect::<Vec<FnId>>();
+        node_indices_remaining.reverse();
+
+        let mut node_indices_cleaned = Vec::with_capacity(node_indices_remaining.len());
+        while let Some(position) = node_indices_remaining.iter().position(|node_index| {
+            dag.neighbors_directed(*node_index, Direction::Outgoing)
+                .all(|successor_index| node_indices_cleaned.contains(&successor_index))
+        }) {
+            node_indices_cleaned.push(node_indices_remaining.remove(position));
+        }
+
+        node_indices_cleaned
+            .into_iter()
+            .map(|node_index| dag[node_index].id().clone())
+            .collect()
+    }
+
     async fn outcome_collate_task(
         mut outcomes_rx: Receiver<
             ItemApplyOutcome<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
@@ -327,15 +865,21 @@ where
             States<StatesTs>,
             States<StatesTs::TsTarget>,
             IndexMap<ItemId, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+            IndexMap<ItemId, ItemApplyPlan>,
+            IndexMap<ItemId, ItemApplyBoxed>,
         ),
         <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
     > {
         let mut errors = IndexMap::new();
+        let mut item_apply_plans = IndexMap::new();
+        let mut item_applies_exec = IndexMap::new();
         while let Some(item_outcome) = outcomes_rx.recv().await {
             Self::outcome_collate(
                 &mut states_applied_mut,
                 &mut states_target_mut,
                 &mut errors,
+                &mut item_apply_plans,
+                &mut item_applies_exec,
                 item_outcome,
             )?;
         }
@@ -343,18 +887,45 @@ where
         let states_applied = States::<StatesTs>::from(states_applied_mut);
         let states_target = States::<StatesTs::TsTarget>::from(states_target_mut);
 
-        Ok((states_applied, states_target, errors))
+        Ok((
+            states_applied,
+            states_target,
+            errors,
+            item_apply_plans,
+            item_applies_exec,
+        ))
     }
 
     fn outcome_collate(
         states_applied_mut: &mut StatesMut<StatesTs>,
         states_target_mut: &mut StatesMut<StatesTs::TsTarget>,
         errors: &mut IndexMap<ItemId, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        item_apply_plans: &mut IndexMap<ItemId, ItemApplyPlan>,
+        item_applies_exec: &mut IndexMap<ItemId, ItemApplyBoxed>,
         outcome_partial: ItemApplyOutcome<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
     ) -> Result<(), <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError> {
         let apply_for = StatesTs::apply_for();
 
         match outcome_partial {
+            ItemApplyOutcome::Skipped { item_id: _ } => {
+                // Item is not applicable or is disabled, so its current and
+                // target states are left unchanged.
+            }
+            ItemApplyOutcome::Blocked {
+                item_id,
+                item_id_blocking,
+            } => {
+                // Item was not applied, so its current and target states are
+                // left unchanged.
+                let error = peace_rt_model::Error::ItemApplyBlocked {
+                    item_id: item_id.clone(),
+                    item_id_blocking,
+                };
+                errors.insert(item_id, error.into());
+            }
+            ItemApplyOutcome::ApplicableFail { item_id, error } => {
+                errors.insert(item_id, error);
+            }
             ItemApplyOutcome::PrepareFail {
                 item_id,
                 item_apply_partial,
@@ -377,6 +948,11 @@ where
                 item_id,
                 item_apply,
             } => {
+                if StatesTs::dry_run() {
+                    let item_apply_plan = Self::item_apply_plan(&item_id, &item_apply, apply_for);
+                    item_apply_plans.insert(item_id.clone(), item_apply_plan);
+                }
+
                 if let Some(state_applied) = item_apply.state_applied() {
                     states_applied_mut.insert_raw(item_id.clone(), state_applied);
                 } else {
@@ -389,10 +965,18 @@ where
                 match apply_for {
                     ApplyFor::Ensure => {
                         let state_target = item_apply.state_target();
-                        states_target_mut.insert_raw(item_id, state_target);
+                        states_target_mut.insert_raw(item_id.clone(), state_target);
                     }
                     ApplyFor::Clean => {}
                 }
+
+                // Items that were changed are kept in the order they were
+                // applied, in case they need to be rolled back.
+                if !StatesTs::dry_run()
+                    && matches!(item_apply.apply_check(), ApplyCheck::ExecRequired { .. })
+                {
+                    item_applies_exec.insert(item_id, item_apply);
+                }
             }
             ItemApplyOutcome::Fail {
                 item_id,
@@ -418,6 +1002,201 @@ where
 
         Ok(())
     }
+
+    /// Rolls back the applied items in each atomic group that has a failed
+    /// item.
+    ///
+    /// Items are rolled back in the reverse order they were applied in, to
+    /// the state they were in before the apply.
+    async fn atomic_groups_roll_back(
+        atomic_group_roll_back_ctx: AtomicGroupRollBackCtx<
+            '_,
+            <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+        >,
+        states_applied: States<StatesTs>,
+        errors: &mut IndexMap<ItemId, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        mut item_applies_exec: IndexMap<ItemId, ItemApplyBoxed>,
+    ) -> States<StatesTs> {
+        let AtomicGroupRollBackCtx {
+            item_graph,
+            atomic_groups,
+            params_specs,
+            resources,
+            apply_ctx,
+            #[cfg(feature = "output_progress")]
+            progress_tx,
+        } = atomic_group_roll_back_ctx;
+
+        let mut states_applied_mut = StatesMut::<StatesTs>::from(states_applied.into_inner());
+        for (item_group_id, item_ids) in atomic_groups.iter() {
+            let Some(item_id_failed) = errors
+                .keys()
+                .find(|item_id| item_ids.contains(item_id))
+                .cloned()
+            else {
+                continue;
+            };
+
+            let item_ids_roll_back = item_applies_exec
+                .keys()
+                .rev()
+                .filter(|item_id| item_ids.contains(item_id))
+                .cloned()
+                .collect::<Vec<ItemId>>();
+            for item_id in item_ids_roll_back {
+                let Some(item_apply) = item_applies_exec.swap_remove(&item_id) else {
+                    continue;
+                };
+                let Some(item) = item_graph
+                    .iter_insertion()
+                    .find(|item| item.id() == &item_id)
+                else {
+                    continue;
+                };
+
+                #[cfg(feature = "output_progress")]
+                let _progress_send_unused = progress_tx.try_send(
+                    ProgressUpdateAndId {
+                        item_id: item_id.clone(),
+                        progress_update: ProgressUpdate::Reset,
+                        msg_update: ProgressMsgUpdate::Set(message_text(
+                            resources,
+                            messages::PROGRESS_ROLLING_BACK,
+                            &[],
+                        )),
+                    }
+                    .into(),
+                );
+
+                let roll_back_result = Self::item_roll_back(
+                    item,
+                    &item_apply,
+                    params_specs,
+                    resources,
+                    apply_ctx,
+                    #[cfg(feature = "output_progress")]
+                    progress_tx,
+                )
+                .await;
+                match roll_back_result {
+                    Ok(state_rolled_back) => {
+                        #[cfg(feature = "output_progress")]
+                        let _progress_send_unused = progress_tx.try_send(
+                            ProgressUpdateAndId {
+                                item_id: item_id.clone(),
+                                progress_update: ProgressUpdate::Complete(
+                                    ProgressComplete::Skipped,
+                                ),
+                                msg_update: ProgressMsgUpdate::Set(message_text(
+                                    resources,
+                                    messages::PROGRESS_ROLLED_BACK,
+                                    &[("item_id", &**item_id_failed)],
+                                )),
+                            }
+                            .into(),
+                        );
+
+                        if let Some(state_rolled_back) = state_rolled_back {
+                            states_applied_mut.insert_raw(item_id.clone(), state_rolled_back);
+                        }
+                        let error = peace_rt_model::Error::ItemApplyRolledBack {
+                            item_id: item_id.clone(),
+                            item_group_id: item_group_id.clone(),
+                            item_id_failed: item_id_failed.clone(),
+                        };
+                        errors.insert(item_id, error.into());
+                    }
+                    Err(error) => {
+                        #[cfg(feature = "output_progress")]
+                        let _progress_send_unused = progress_tx.try_send(
+                            ProgressUpdateAndId {
+                                item_id: item_id.clone(),
+                                progress_update: ProgressUpdate::Complete(ProgressComplete::Fail),
+                                msg_update: ProgressMsgUpdate::Set(
+                                    error
+                                        .source()
+                                        .map(|source| format!("{source}"))
+                                        .unwrap_or_else(|| format!("{error}")),
+                                ),
+                            }
+                            .into(),
+                        );
+
+                        errors.insert(item_id, error);
+                    }
+                }
+            }
+        }
+
+        States::<StatesTs>::from(states_applied_mut)
+    }
+
+    /// Applies an item back to the state it was in before it was applied.
+    ///
+    /// Returns the rolled back state, `None` if execution was not required.
+    async fn item_roll_back(
+        item: &ItemBoxed<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        item_apply: &ItemApplyBoxed,
+        params_specs: &ParamsSpecs,
+        resources: &Resources<SetUp>,
+        apply_ctx: ApplyCtx<'_>,
+        #[cfg(feature = "output_progress")] progress_tx: &Sender<CmdProgressUpdate>,
+    ) -> Result<
+        Option<peace_resources::type_reg::untagged::BoxDtDisplay>,
+        <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+    > {
+        let item_id = item.id();
+        let mut item_apply_roll_back =
+            ItemRt::rollback_prepare(&**item, item_apply, params_specs, resources, apply_ctx)
+                .await
+                .map_err(|(error, _item_apply_partial)| error)?;
+
+        if matches!(
+            item_apply_roll_back.apply_check(),
+            ApplyCheck::ExecRequired { .. }
+        ) {
+            let fn_ctx = FnCtx::new(
+                item_id,
+                #[cfg(feature = "output_progress")]
+                ProgressSender::new(item_id, progress_tx),
+            )
+            .with_apply_ctx(apply_ctx);
+            ItemRt::apply_exec(
+                &**item,
+                params_specs,
+                resources,
+                fn_ctx,
+                &mut item_apply_roll_back,
+            )
+            .await?;
+        }
+
+        Ok(item_apply_roll_back.state_applied())
+    }
+
+    /// Returns what the apply is predicted to do for an item, from its dry run
+    /// outcome.
+    fn item_apply_plan(
+        item_id: &ItemId,
+        item_apply: &ItemApplyBoxed,
+        apply_for: ApplyFor,
+    ) -> ItemApplyPlan {
+        let apply_check = item_apply.apply_check();
+        let action = match (&apply_check, apply_for) {
+            (ApplyCheck::ExecNotRequired, _) => ApplyPlanAction::None,
+            (_, ApplyFor::Ensure) => ApplyPlanAction::Ensure,
+            (_, ApplyFor::Clean) => ApplyPlanAction::Clean,
+        };
+
+        ItemApplyPlan {
+            item_id: item_id.clone(),
+            state_current: item_apply.state_current().to_string(),
+            state_target: item_apply.state_target().to_string(),
+            state_diff: item_apply.state_diff().to_string(),
+            apply_check,
+            action,
+        }
+    }
 }
 
 #[async_trait(?Send)]
@@ -463,6 +1242,10 @@ where
         ]
     }
 
+    fn item_action(&self) -> CmdBlockItemAction {
+        CmdBlockItemAction::Apply
+    }
+
     async fn exec(
         &self,
         input: Self::InputT,
@@ -487,18 +1270,53 @@ where
 
         let SingleProfileSingleFlowView {
             interruptibility_state,
+            profile,
             flow,
             params_specs,
             resources,
+            apply_hooks,
+            approval_policy,
+            #[cfg(all(feature = "output_progress", not(target_arch = "wasm32")))]
+            heartbeat_policy,
+            item_executor,
+            #[cfg(feature = "telemetry")]
+            cmd_metrics,
             ..
         } = cmd_view;
+        #[cfg(all(feature = "output_progress", not(target_arch = "wasm32")))]
+        let heartbeat_policy = *heartbeat_policy;
 
         let item_graph = flow.graph();
-        let resources_ref = &*resources;
         let apply_for = StatesTs::apply_for();
+        // Items are cleaned in the reverse order of this graph's edges, which
+        // differ from the flow's edges when clean ordering is overridden.
+        let graph_clean = match apply_for {
+            ApplyFor::Clean if !flow.clean_ordering_overrides().is_empty() => Some(
+                item_graph
+                    .graph_clean_build(flow.clean_ordering_overrides())
+                    .map_err(<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError::from)?,
+            ),
+            ApplyFor::Ensure | ApplyFor::Clean => None,
+        };
+        let graph_apply = graph_clean.as_ref().unwrap_or(&**item_graph);
+        let execution_id = resources
+            .try_borrow::<ExecutionId>()
+            .ok()
+            .as_deref()
+            .cloned();
+        let apply_ctx = ApplyCtx::new(
+            profile,
+            flow.flow_id(),
+            StatesTs::dry_run(),
+            execution_id.as_ref(),
+        );
+        let resources_ref = &*resources;
+        let params_specs_ref = &**params_specs;
         let apply_for_internal = match apply_for {
             ApplyFor::Ensure => ApplyForInternal::Ensure,
-            ApplyFor::Clean => ApplyForInternal::Clean { states_current },
+            ApplyFor::Clean => ApplyForInternal::Clean {
+                states_current: Box::new(states_current),
+            },
         };
 
         let (outcomes_tx, outcomes_rx) = mpsc::channel::<
@@ -516,20 +1334,69 @@ where
         };
 
         let (stream_outcome_result, outcome_collate) = {
+            let apply_error_policy = self.apply_error_policy;
             let item_apply_exec_task = async move {
-                let stream_outcome = item_graph
-                    .try_for_each_concurrent_with(BUFFERED_FUTURES_MAX, stream_opts, |item| {
-                        let item_apply_exec_ctx = ItemApplyExecCtx {
-                            params_specs,
-                            resources: resources_ref,
-                            apply_for_internal: &apply_for_internal,
-                            #[cfg(feature = "output_progress")]
-                            progress_tx,
-                            outcomes_tx: &outcomes_tx,
-                        };
-                        Self::item_apply_exec(item_apply_exec_ctx, item)
-                    })
-                    .await;
+                let item_apply_exec_ctx = || ItemApplyExecCtx {
+                    params_specs,
+                    resources: resources_ref,
+                    apply_ctx,
+                    apply_hooks,
+                    approval_policy,
+                    #[cfg(all(feature = "output_progress", not(target_arch = "wasm32")))]
+                    heartbeat_policy,
+                    apply_for_internal: &apply_for_internal,
+                    #[cfg(feature = "output_progress")]
+                    progress_tx,
+                    outcomes_tx: &outcomes_tx,
+                    #[cfg(feature = "telemetry")]
+                    cmd_metrics,
+                };
+                let stream_outcome = match apply_error_policy {
+                    ApplyErrorPolicy::Abort => {
+                        graph_apply
+                            .try_for_each_concurrent_with(
+                                item_executor.concurrency_max(),
+                                stream_opts,
+                                |item| {
+                                    let item_begin = item_executor.item_begin();
+                                    let item_apply_exec =
+                                        Self::item_apply_exec(item_apply_exec_ctx(), item);
+                                    async move {
+                                        item_begin.await;
+                                        item_apply_exec.await
+                                    }
+                                },
+                            )
+                            .await
+                    }
+                    ApplyErrorPolicy::ContinueOnError => {
+                        let item_id_to_applied_after =
+                            Self::item_id_to_applied_after(graph_apply, apply_for);
+                        // Failed items map to themselves, and blocked items map
+                        // to the failed item that blocked them.
+                        let item_id_to_item_id_blocking = RefCell::new(IndexMap::new());
+                        let stream_outcome = graph_apply
+                            .for_each_concurrent_with(
+                                item_executor.concurrency_max(),
+                                stream_opts,
+                                |item| {
+                                    let item_begin = item_executor.item_begin();
+                                    let item_apply_exec = Self::item_apply_exec_continue_on_error(
+                                        item_apply_exec_ctx(),
+                                        item,
+                                        &item_id_to_applied_after,
+                                        &item_id_to_item_id_blocking,
+                                    );
+                                    async move {
+                                        item_begin.await;
+                                        item_apply_exec.await
+                                    }
+                                },
+                            )
+                            .await;
+                        Ok(stream_outcome)
+                    }
+                };
 
                 drop(outcomes_tx);
 
@@ -540,7 +1407,58 @@ where
 
             join!(item_apply_exec_task, outcome_collate_task)
         };
-        let (states_applied, states_target, errors) = outcome_collate?;
+        let (states_applied, states_target, mut errors, mut item_apply_plans, item_applies_exec) =
+            outcome_collate?;
+
+        // Items in an atomic group are rolled back if another item in the
+        // group failed.
+        let states_applied = if flow.atomic_groups().is_empty() || errors.is_empty() {
+            states_applied
+        } else {
+            let atomic_group_roll_back_ctx = AtomicGroupRollBackCtx {
+                item_graph,
+                atomic_groups: flow.atomic_groups(),
+                params_specs: params_specs_ref,
+                resources: resources_ref,
+                apply_ctx,
+                #[cfg(feature = "output_progress")]
+                progress_tx,
+            };
+            Self::atomic_groups_roll_back(
+                atomic_group_roll_back_ctx,
+                states_applied,
+                &mut errors,
+                item_applies_exec,
+            )
+            .await
+        };
+
+        // Dry runs record what the apply would do, in the order items would be
+        // applied in.
+        if StatesTs::dry_run() {
+            let item_ids = match graph_clean.as_ref() {
+                Some(graph_clean) => Self::item_ids_clean_order(graph_clean),
+                None => {
+                    let mut item_ids = item_graph
+                        .iter_insertion()
+                        .map(|item| item.id().clone())
+                        .collect::<Vec<ItemId>>();
+                    if apply_for == ApplyFor::Clean {
+                        item_ids.reverse();
+                    }
+                    item_ids
+                }
+            };
+            let apply_plan = item_ids
+                .into_iter()
+                .filter_map(|item_id| {
+                    item_apply_plans
+                        .swap_remove(&item_id)
+                        .map(|item_apply_plan| (item_id, item_apply_plan))
+                })
+                .collect::<IndexMap<ItemId, ItemApplyPlan>>();
+            resources.insert(ApplyPlan::from(apply_plan));
+        }
 
         let stream_outcome = {
             let (Ok(stream_outcome) | Err((stream_outcome, ()))) = stream_outcome_result.map_err(
@@ -570,7 +1488,7 @@ pub enum ApplyFor {
 #[derive(Debug)]
 enum ApplyForInternal {
     Ensure,
-    Clean { states_current: StatesCurrent },
+    Clean { states_current: Box<StatesCurrent> },
 }
 
 struct ItemApplyExecCtx<'f, E> {
@@ -578,16 +1496,53 @@ struct ItemApplyExecCtx<'f, E> {
     params_specs: &'f ParamsSpecs,
     /// Map of all types at runtime.
     resources: &'f Resources<SetUp>,
+    /// Profile, flow ID, and dry run flag passed to each item.
+    apply_ctx: ApplyCtx<'f>,
+    /// Hooks invoked around each item's apply.
+    apply_hooks: &'f ApplyHooks<E>,
+    /// Which items require approval before they are applied.
+    approval_policy: &'f ApprovalPolicy,
+    /// How long items may go without sending progress while they are applied.
+    #[cfg(all(feature = "output_progress", not(target_arch = "wasm32")))]
+    heartbeat_policy: HeartbeatPolicy,
     /// Whether the `ApplyCmd` is for `Ensure` or `Clean`.
     apply_for_internal: &'f ApplyForInternal,
     /// Channel sender for `CmdBlock` item outcomes.
     #[cfg(feature = "output_progress")]
     progress_tx: &'f Sender<CmdProgressUpdate>,
     outcomes_tx: &'f Sender<ItemApplyOutcome<E>>,
+    /// Records OpenTelemetry metrics for each item's apply.
+    #[cfg(feature = "telemetry")]
+    cmd_metrics: &'f CmdMetrics,
+}
+
+struct AtomicGroupRollBackCtx<'f, E> {
+    /// Graph of items in the flow.
+    item_graph: &'f ItemGraph<E>,
+    /// Groups of items that are applied all-or-nothing.
+    atomic_groups: &'f IndexMap<ItemGroupId, Vec<ItemId>>,
+    /// Map of item ID to its params' specs.
+    params_specs: &'f ParamsSpecs,
+    /// Map of all types at runtime.
+    resources: &'f Resources<SetUp>,
+    /// Profile, flow ID, and dry run flag passed to each item.
+    apply_ctx: ApplyCtx<'f>,
+    /// Channel sender for `CmdBlock` item outcomes.
+    #[cfg(feature = "output_progress")]
+    progress_tx: &'f Sender<CmdProgressUpdate>,
 }
 
 #[derive(Debug)]
 pub enum ItemApplyOutcome<E> {
+    /// Item is not applicable or is disabled, so it was not applied.
+    Skipped { item_id: ItemId },
+    /// Item was not applied because an item it depends on failed.
+    Blocked {
+        item_id: ItemId,
+        item_id_blocking: ItemId,
+    },
+    /// Error occurred when determining if the item is applicable.
+    ApplicableFail { item_id: ItemId, error: E },
     /// Error occurred when discovering current state, goal states, state
     /// diff, or `ApplyCheck`.
     PrepareFail {
@@ -1,5 +1,6 @@
 use std::{fmt::Debug, marker::PhantomData};
 
+use chrono::Utc;
 use peace_cmd::{ctx::CmdCtxTypesConstrained, scopes::SingleProfileSingleFlowView};
 use peace_cmd_model::CmdBlockOutcome;
 use peace_cmd_rt::{async_trait, CmdBlock};
@@ -11,6 +12,8 @@ use peace_resources::{
 use peace_rt_model::Error;
 use peace_rt_model_core::{ApplyCmdError, ItemsStateStoredStale, StateStoredAndDiscovered};
 
+use crate::cmd_blocks::item_disabled;
+
 cfg_if::cfg_if! {
     if #[cfg(feature = "output_progress")] {
         use peace_cfg::{
@@ -23,7 +26,10 @@ cfg_if::cfg_if! {
                 ProgressUpdateAndId,
             },
         };
+        use peace_fmt::messages;
         use tokio::sync::mpsc::Sender;
+
+        use crate::cmd_blocks::message_text;
     }
 }
 
@@ -128,10 +134,48 @@ where
         states_discovered: &States<StatesTs>,
         #[cfg(feature = "output_progress")] progress_tx: &Sender<CmdProgressUpdate>,
     ) -> Result<ItemsStateStoredStale, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError> {
+        // Stored states older than the flow's maximum staleness are replaced by
+        // the discovered states, so they do not need to be in sync.
+        let item_ids_expired = cmd_view
+            .flow
+            .state_max_staleness()
+            .map(|state_max_staleness| {
+                states_stored.item_ids_older_than(state_max_staleness, Utc::now())
+            })
+            .unwrap_or_default();
+
+        let resources = &*cmd_view.resources;
+
         let items_state_stored_stale = cmd_view.flow.graph().iter_insertion().try_fold(
             ItemsStateStoredStale::new(),
             |mut items_state_stored_stale, item_rt| {
                 let item_id = item_rt.id();
+                if item_ids_expired.contains(item_id) {
+                    #[cfg(feature = "output_progress")]
+                    {
+                        let state_type = tynm::type_name::<StatesTs>();
+                        let _progress_send_unused = progress_tx.try_send(
+                            ProgressUpdateAndId {
+                                item_id: item_id.clone(),
+                                progress_update: ProgressUpdate::Delta(ProgressDelta::Tick),
+                                msg_update: ProgressMsgUpdate::Set(message_text(
+                                    resources,
+                                    messages::PROGRESS_STATE_EXPIRED,
+                                    &[("state_type", state_type.as_str())],
+                                )),
+                            }
+                            .into(),
+                        );
+                    }
+
+                    return Ok(items_state_stored_stale);
+                }
+
+                // Disabled items are not discovered, so they are not checked.
+                if item_disabled(resources, item_id) {
+                    return Ok(items_state_stored_stale);
+                }
+
                 let state_stored = states_stored.get_raw(item_id);
                 let state_discovered = states_discovered.get_raw(item_id);
 
@@ -169,8 +213,10 @@ where
                                             progress_update: ProgressUpdate::Delta(
                                                 ProgressDelta::Tick,
                                             ),
-                                            msg_update: ProgressMsgUpdate::Set(format!(
-                                                "State {state_type} in sync"
+                                            msg_update: ProgressMsgUpdate::Set(message_text(
+                                                resources,
+                                                messages::PROGRESS_STATE_IN_SYNC,
+                                                &[("state_type", state_type.as_str())],
                                             )),
                                         }
                                         .into(),
@@ -187,8 +233,10 @@ where
                                             progress_update: ProgressUpdate::Complete(
                                                 ProgressComplete::Fail,
                                             ),
-                                            msg_update: ProgressMsgUpdate::Set(format!(
-                                                "State {state_type} out of sync"
+                                            msg_update: ProgressMsgUpdate::Set(message_text(
+                                                resources,
+                                                messages::PROGRESS_STATE_OUT_OF_SYNC,
+                                                &[("state_type", state_type.as_str())],
                                             )),
                                         }
                                         .into(),
@@ -22,7 +22,7 @@ use peace_resources::{
 };
 use peace_rt_model::Flow;
 
-use crate::cmds::DiffStateSpec;
+use crate::{cmd_blocks::item_disabled, cmds::DiffStateSpec};
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "output_progress")] {
@@ -81,6 +81,11 @@ where
                     async move {
                         let _params_specs = &params_specs;
 
+                        // Disabled items are not discovered, so they are not diffed.
+                        if item_disabled(resources, item.id()) {
+                            return Ok(state_diffs_mut);
+                        }
+
                         let state_diff_opt = item
                             .state_diff_exec(params_specs, resources, states_a, states_b)
                             .await?;
@@ -98,7 +103,21 @@ where
             )
             .await;
 
-        stream_outcome_result.map(|stream_outcome| stream_outcome.map(StateDiffs::from))
+        // Items are diffed concurrently, so diffs are reordered to the order
+        // that items were added to the graph, for stable output.
+        stream_outcome_result.map(|stream_outcome| {
+            stream_outcome.map(|mut state_diffs_mut| {
+                let mut state_diffs_ordered = StateDiffsMut::with_capacity(state_diffs_mut.len());
+                state_diffs_ordered.extend(flow.graph().iter_insertion().filter_map(|item| {
+                    let item_id = item.id();
+                    state_diffs_mut
+                        .swap_remove(item_id)
+                        .map(|state_diff| (item_id.clone(), state_diff))
+                }));
+
+                StateDiffs::from(state_diffs_ordered)
+            })
+        })
     }
 }
 
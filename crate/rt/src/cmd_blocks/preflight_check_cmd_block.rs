@@ -0,0 +1,101 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use peace_cmd::{ctx::CmdCtxTypesConstrained, scopes::SingleProfileSingleFlowView};
+use peace_cmd_model::CmdBlockOutcome;
+use peace_cmd_rt::{async_trait, CmdBlock};
+use peace_resources::{resources::ts::SetUp, ResourceFetchError, Resources};
+use peace_rt_model::{Error, PreflightCheck, PreflightCheckFailure};
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "output_progress")] {
+        use peace_cfg::progress::CmdProgressUpdate;
+        use tokio::sync::mpsc::Sender;
+    }
+}
+
+/// Runs the flow's preflight checks, and stops the `CmdExecution` if any of
+/// them fail.
+///
+/// All checks are run concurrently, and every failure is returned in
+/// [`Error::PreflightChecksFailed`].
+pub struct PreflightCheckCmdBlock<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> Debug for PreflightCheckCmdBlock<CmdCtxTypesT> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("PreflightCheckCmdBlock")
+            .field(&self.0)
+            .finish()
+    }
+}
+
+impl<CmdCtxTypesT> PreflightCheckCmdBlock<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Returns a block that runs the flow's preflight checks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<CmdCtxTypesT> Default for PreflightCheckCmdBlock<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[async_trait(?Send)]
+impl<CmdCtxTypesT> CmdBlock for PreflightCheckCmdBlock<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    type CmdCtxTypes = CmdCtxTypesT;
+    type InputT = ();
+    type Outcome = Self::InputT;
+
+    fn input_fetch(&self, _resources: &mut Resources<SetUp>) -> Result<(), ResourceFetchError> {
+        Ok(())
+    }
+
+    fn input_type_names(&self) -> Vec<String> {
+        vec![]
+    }
+
+    fn outcome_insert(&self, _resources: &mut Resources<SetUp>, _outcome: Self::Outcome) {}
+
+    fn outcome_type_names(&self) -> Vec<String> {
+        vec![]
+    }
+
+    async fn exec(
+        &self,
+        input: Self::InputT,
+        cmd_view: &mut SingleProfileSingleFlowView<'_, Self::CmdCtxTypes>,
+        #[cfg(feature = "output_progress")] _progress_tx: &Sender<CmdProgressUpdate>,
+    ) -> Result<
+        CmdBlockOutcome<Self::Outcome, <Self::CmdCtxTypes as CmdCtxTypesConstrained>::AppError>,
+        <Self::CmdCtxTypes as CmdCtxTypesConstrained>::AppError,
+    > {
+        let preflight_check_failures = futures::future::join_all(
+            cmd_view
+                .flow
+                .preflight_checks()
+                .iter()
+                .map(PreflightCheck::check),
+        )
+        .await
+        .into_iter()
+        .filter_map(Result::err)
+        .collect::<Vec<PreflightCheckFailure>>();
+
+        if preflight_check_failures.is_empty() {
+            Ok(CmdBlockOutcome::Single(input))
+        } else {
+            Err(<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError::from(
+                Error::PreflightChecksFailed {
+                    preflight_check_failures,
+                },
+            ))
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use std::{fmt::Debug, marker::PhantomData};
 
-use futures::join;
+use chrono::Utc;
+use futures::{future::Either, join};
 use peace_cfg::{FnCtx, ItemId};
 use peace_cmd::{ctx::CmdCtxTypesConstrained, scopes::SingleProfileSingleFlowView};
 use peace_cmd_model::CmdBlockOutcome;
@@ -10,16 +11,22 @@ use peace_resources::{
     resources::ts::SetUp,
     states::{
         ts::{Current, Goal},
-        States, StatesCurrent, StatesGoal,
+        States, StatesCurrent, StatesCurrentStored, StatesGoal,
     },
     type_reg::untagged::BoxDtDisplay,
     ResourceFetchError, Resources,
 };
-use peace_rt_model::{fn_graph::StreamOpts, ItemBoxed};
+use peace_rt_model::{fn_graph::StreamOpts, ExecutionPhase, ExecutionTimelineRecorder, ItemBoxed};
 use peace_rt_model_core::IndexMap;
 use tokio::sync::mpsc::{self, Receiver};
 
-use crate::BUFFERED_FUTURES_MAX;
+use crate::cmd_blocks::item_disabled;
+
+#[cfg(feature = "telemetry")]
+use std::time::Instant;
+
+#[cfg(feature = "telemetry")]
+use peace_rt_model::CmdMetrics;
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "output_progress")] {
@@ -34,7 +41,10 @@ cfg_if::cfg_if! {
                 ProgressUpdateAndId,
             },
         };
+        use peace_fmt::messages;
         use tokio::sync::mpsc::Sender;
+
+        use crate::cmd_blocks::message_text;
     }
 }
 
@@ -55,6 +65,10 @@ pub struct StatesDiscoverCmdBlock<CmdCtxTypesT, DiscoverFor> {
     /// Whether or not to mark progress bars complete on success.
     #[cfg(feature = "output_progress")]
     progress_complete_on_success: bool,
+    /// IDs of items to rediscover, with stored states used for other items.
+    ///
+    /// `None` means all items are discovered.
+    item_ids_rediscover: Option<Vec<ItemId>>,
     /// Marker.
     marker: PhantomData<(CmdCtxTypesT, DiscoverFor)>,
 }
@@ -68,7 +82,10 @@ impl<CmdCtxTypesT, DiscoverFor> Debug for StatesDiscoverCmdBlock<CmdCtxTypesT, D
             &self.progress_complete_on_success,
         );
 
-        debug_struct.field("marker", &self.marker).finish()
+        debug_struct
+            .field("item_ids_rediscover", &self.item_ids_rediscover)
+            .field("marker", &self.marker)
+            .finish()
     }
 }
 
@@ -81,6 +98,23 @@ where
         Self {
             #[cfg(feature = "output_progress")]
             progress_complete_on_success: false,
+            item_ids_rediscover: None,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a block that discovers current states for the given items,
+    /// and uses the stored current states for all other items.
+    ///
+    /// Items without a stored current state are always discovered.
+    ///
+    /// This requires [`StatesCurrentStored`] to be in `resources` for stored
+    /// states to be used.
+    pub fn current_incremental(item_ids_rediscover: Vec<ItemId>) -> Self {
+        Self {
+            #[cfg(feature = "output_progress")]
+            progress_complete_on_success: false,
+            item_ids_rediscover: Some(item_ids_rediscover),
             marker: PhantomData,
         }
     }
@@ -95,6 +129,7 @@ where
         Self {
             #[cfg(feature = "output_progress")]
             progress_complete_on_success: false,
+            item_ids_rediscover: None,
             marker: PhantomData,
         }
     }
@@ -109,6 +144,7 @@ where
         Self {
             #[cfg(feature = "output_progress")]
             progress_complete_on_success: false,
+            item_ids_rediscover: None,
             marker: PhantomData,
         }
     }
@@ -132,6 +168,7 @@ where
     async fn item_states_discover(
         #[cfg(feature = "output_progress")] progress_tx: &Sender<CmdProgressUpdate>,
         #[cfg(feature = "output_progress")] progress_complete_on_success: bool,
+        #[cfg(feature = "telemetry")] cmd_metrics: &CmdMetrics,
         params_specs: &peace_params::ParamsSpecs,
         resources: &Resources<SetUp>,
         outcomes_tx: &tokio::sync::mpsc::Sender<
@@ -146,9 +183,100 @@ where
             ProgressSender::new(item_id, progress_tx),
         );
 
+        if item_disabled(resources, item_id) {
+            #[cfg(feature = "output_progress")]
+            let _progress_send_unused = progress_tx.try_send(
+                ProgressUpdateAndId {
+                    item_id: item_id.clone(),
+                    progress_update: ProgressUpdate::Complete(ProgressComplete::Skipped),
+                    msg_update: ProgressMsgUpdate::Set(message_text(
+                        resources,
+                        messages::PROGRESS_DISABLED,
+                        &[],
+                    )),
+                }
+                .into(),
+            );
+
+            outcomes_tx
+                .send(ItemDiscoverOutcome::Success {
+                    item_id: item_id.clone(),
+                    state_current: None,
+                    state_goal: None,
+                })
+                .await
+                .expect("unreachable: `outcomes_rx` is in a sibling task.");
+            return;
+        }
+
+        match item.applicable(params_specs, resources) {
+            Ok(true) => {}
+            Ok(false) => {
+                #[cfg(feature = "output_progress")]
+                let _progress_send_unused = progress_tx.try_send(
+                    ProgressUpdateAndId {
+                        item_id: item_id.clone(),
+                        progress_update: ProgressUpdate::Complete(ProgressComplete::Skipped),
+                        msg_update: ProgressMsgUpdate::Set(message_text(
+                            resources,
+                            messages::PROGRESS_NOT_APPLICABLE,
+                            &[],
+                        )),
+                    }
+                    .into(),
+                );
+
+                outcomes_tx
+                    .send(ItemDiscoverOutcome::Success {
+                        item_id: item_id.clone(),
+                        state_current: None,
+                        state_goal: None,
+                    })
+                    .await
+                    .expect("unreachable: `outcomes_rx` is in a sibling task.");
+                return;
+            }
+            Err(error) => {
+                #[cfg(feature = "output_progress")]
+                let _progress_send_unused = progress_tx.try_send(
+                    ProgressUpdateAndId {
+                        item_id: item_id.clone(),
+                        progress_update: ProgressUpdate::Complete(ProgressComplete::Fail),
+                        msg_update: ProgressMsgUpdate::Set(format!("{error}")),
+                    }
+                    .into(),
+                );
+
+                outcomes_tx
+                    .send(ItemDiscoverOutcome::Fail {
+                        item_id: item_id.clone(),
+                        state_current: None,
+                        state_goal: None,
+                        error,
+                    })
+                    .await
+                    .expect("unreachable: `outcomes_rx` is in a sibling task.");
+                return;
+            }
+        }
+
+        #[cfg(feature = "telemetry")]
+        let discover_start = Instant::now();
+        let discover_start_dt = Utc::now();
+
         let (states_current_result, states_goal_result) =
             DiscoverFor::discover(item, params_specs, resources, fn_ctx).await;
 
+        ExecutionTimelineRecorder::record_in(
+            resources,
+            item_id,
+            ExecutionPhase::Discover,
+            discover_start_dt,
+        );
+
+        #[cfg(feature = "telemetry")]
+        cmd_metrics.item_discovered(item_id, discover_start.elapsed());
+
         // Send progress update.
         #[cfg(feature = "output_progress")]
         Self::discover_progress_update(
@@ -288,6 +416,9 @@ where
             flow,
             params_specs,
             resources,
+            item_executor,
+            #[cfg(feature = "telemetry")]
+            cmd_metrics,
             ..
         } = cmd_view;
 
@@ -295,28 +426,77 @@ where
             ItemDiscoverOutcome<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
         >(flow.graph().node_count());
 
+        let item_ids_rediscover = self.item_ids_rediscover.as_deref();
+
         let (stream_outcome, outcome_collate) = {
-            let states_current_mut = StatesMut::<Current>::with_capacity(flow.graph().node_count());
+            let mut states_current_mut =
+                StatesMut::<Current>::with_capacity(flow.graph().node_count());
+
+            // Reused states keep the time they were originally discovered.
+            if let Some(item_ids_rediscover) = item_ids_rediscover {
+                if let Ok(states_current_stored) = resources.try_borrow::<StatesCurrentStored>() {
+                    states_current_stored
+                        .timestamps()
+                        .iter()
+                        .filter(|(item_id, _timestamp)| !item_ids_rediscover.contains(*item_id))
+                        .for_each(|(item_id, timestamp)| {
+                            states_current_mut.timestamp_insert(item_id.clone(), *timestamp);
+                        });
+                }
+            }
 
             let item_states_discover_task = async move {
+                let states_current_stored = item_ids_rediscover
+                    .and_then(|_| resources.try_borrow::<StatesCurrentStored>().ok());
                 let stream_outcome = flow
                     .graph()
+                    .graph_discover()
                     .for_each_concurrent_with(
-                        BUFFERED_FUTURES_MAX,
+                        item_executor.concurrency_max(),
                         StreamOpts::new()
                             .interruptibility_state(interruptibility_state.reborrow())
                             .interrupted_next_item_include(false),
                         |item| {
-                            Self::item_states_discover(
-                                #[cfg(feature = "output_progress")]
-                                progress_tx,
-                                #[cfg(feature = "output_progress")]
-                                self.progress_complete_on_success,
-                                params_specs,
-                                resources,
-                                &outcomes_tx,
-                                item,
-                            )
+                            let item_begin = item_executor.item_begin();
+                            let state_current_stored = item_ids_rediscover
+                                .filter(|item_ids_rediscover| {
+                                    !item_ids_rediscover.contains(item.id())
+                                })
+                                .and(states_current_stored.as_ref())
+                                .and_then(|states_current_stored| {
+                                    states_current_stored.get_raw(item.id())
+                                })
+                                .cloned();
+                            let item_states_discover = match state_current_stored {
+                                Some(state_current_stored) => {
+                                    Either::Left(Self::item_state_current_reuse(
+                                        #[cfg(feature = "output_progress")]
+                                        progress_tx,
+                                        #[cfg(feature = "output_progress")]
+                                        self.progress_complete_on_success,
+                                        resources,
+                                        &outcomes_tx,
+                                        item,
+                                        state_current_stored,
+                                    ))
+                                }
+                                None => Either::Right(Self::item_states_discover(
+                                    #[cfg(feature = "output_progress")]
+                                    progress_tx,
+                                    #[cfg(feature = "output_progress")]
+                                    self.progress_complete_on_success,
+                                    #[cfg(feature = "telemetry")]
+                                    cmd_metrics,
+                                    params_specs,
+                                    resources,
+                                    &outcomes_tx,
+                                    item,
+                                )),
+                            };
+                            async move {
+                                item_begin.await;
+                                item_states_discover.await
+                            }
                         },
                     )
                     .await;
@@ -345,6 +525,77 @@ impl<CmdCtxTypesT> StatesDiscoverCmdBlock<CmdCtxTypesT, DiscoverForCurrent>
 where
     CmdCtxTypesT: CmdCtxTypesConstrained,
 {
+    /// Uses the stored current state for an item instead of discovering it.
+    ///
+    /// The state is inserted into `resources` so that successors can
+    /// reference it.
+    async fn item_state_current_reuse(
+        #[cfg(feature = "output_progress")] progress_tx: &Sender<CmdProgressUpdate>,
+        #[cfg(feature = "output_progress")] progress_complete_on_success: bool,
+        resources: &Resources<SetUp>,
+        outcomes_tx: &tokio::sync::mpsc::Sender<
+            ItemDiscoverOutcome<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        >,
+        item: &ItemBoxed<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        state_current_stored: BoxDtDisplay,
+    ) {
+        let item_id = item.id();
+
+        let item_outcome = match item.state_current_set(resources, &state_current_stored) {
+            Ok(()) => {
+                #[cfg(feature = "output_progress")]
+                {
+                    let progress_update = if progress_complete_on_success {
+                        ProgressUpdate::Complete(ProgressComplete::Success)
+                    } else {
+                        ProgressUpdate::Delta(ProgressDelta::Tick)
+                    };
+                    let _progress_send_unused = progress_tx.try_send(
+                        ProgressUpdateAndId {
+                            item_id: item_id.clone(),
+                            progress_update,
+                            msg_update: ProgressMsgUpdate::Set(message_text(
+                                resources,
+                                messages::PROGRESS_STORED_STATE_USED,
+                                &[],
+                            )),
+                        }
+                        .into(),
+                    );
+                }
+
+                ItemDiscoverOutcome::Success {
+                    item_id: item_id.clone(),
+                    state_current: Some(state_current_stored),
+                    state_goal: None,
+                }
+            }
+            Err(error) => {
+                #[cfg(feature = "output_progress")]
+                let _progress_send_unused = progress_tx.try_send(
+                    ProgressUpdateAndId {
+                        item_id: item_id.clone(),
+                        progress_update: ProgressUpdate::Complete(ProgressComplete::Fail),
+                        msg_update: ProgressMsgUpdate::Set(format!("{error}")),
+                    }
+                    .into(),
+                );
+
+                ItemDiscoverOutcome::Fail {
+                    item_id: item_id.clone(),
+                    state_current: None,
+                    state_goal: None,
+                    error,
+                }
+            }
+        };
+
+        outcomes_tx
+            .send(item_outcome)
+            .await
+            .expect("unreachable: `outcomes_rx` is in a sibling task.");
+    }
+
     async fn outcome_collate_task(
         mut outcomes_rx: Receiver<
             ItemDiscoverOutcome<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
@@ -431,6 +682,9 @@ where
             flow,
             params_specs,
             resources,
+            item_executor,
+            #[cfg(feature = "telemetry")]
+            cmd_metrics,
             ..
         } = cmd_view;
 
@@ -444,22 +698,30 @@ where
             let item_states_discover_task = async move {
                 let stream_outcome = flow
                     .graph()
+                    .graph_discover()
                     .for_each_concurrent_with(
-                        BUFFERED_FUTURES_MAX,
+                        item_executor.concurrency_max(),
                         StreamOpts::new()
                             .interruptibility_state(interruptibility_state.reborrow())
                             .interrupted_next_item_include(false),
                         |item| {
-                            Self::item_states_discover(
+                            let item_begin = item_executor.item_begin();
+                            let item_states_discover = Self::item_states_discover(
                                 #[cfg(feature = "output_progress")]
                                 progress_tx,
                                 #[cfg(feature = "output_progress")]
                                 self.progress_complete_on_success,
+                                #[cfg(feature = "telemetry")]
+                                cmd_metrics,
                                 params_specs,
                                 resources,
                                 &outcomes_tx,
                                 item,
-                            )
+                            );
+                            async move {
+                                item_begin.await;
+                                item_states_discover.await
+                            }
                         },
                     )
                     .await;
@@ -587,6 +849,9 @@ where
             flow,
             params_specs,
             resources,
+            item_executor,
+            #[cfg(feature = "telemetry")]
+            cmd_metrics,
             ..
         } = cmd_view;
 
@@ -601,22 +866,30 @@ where
             let item_states_discover_task = async move {
                 let stream_outcome = flow
                     .graph()
+                    .graph_discover()
                     .for_each_concurrent_with(
-                        BUFFERED_FUTURES_MAX,
+                        item_executor.concurrency_max(),
                         StreamOpts::new()
                             .interruptibility_state(interruptibility_state.reborrow())
                             .interrupted_next_item_include(false),
                         |item| {
-                            Self::item_states_discover(
+                            let item_begin = item_executor.item_begin();
+                            let item_states_discover = Self::item_states_discover(
                                 #[cfg(feature = "output_progress")]
                                 progress_tx,
                                 #[cfg(feature = "output_progress")]
                                 self.progress_complete_on_success,
+                                #[cfg(feature = "telemetry")]
+                                cmd_metrics,
                                 params_specs,
                                 resources,
                                 &outcomes_tx,
                                 item,
-                            )
+                            );
+                            async move {
+                                item_begin.await;
+                                item_states_discover.await
+                            }
                         },
                     )
                     .await;
@@ -9,23 +9,86 @@
 //! [`CmdContext`]: crate::CmdContext
 
 pub use self::{
+    apply_error_policy::ApplyErrorPolicy,
+    apply_plan::{ApplyPlan, ApplyPlanAction, ItemApplyPlan},
     apply_stored_state_sync::ApplyStoredStateSync,
+    clean_check_cmd::{CleanCheckCmd, ResourcesDangling},
     clean_cmd::CleanCmd,
-    diff_cmd::{DiffCmd, DiffInfoSpec, DiffStateSpec},
-    ensure_cmd::EnsureCmd,
+    diff_cmd::{DiffCmd, DiffInfoSpec, DiffStateSpec, StateDiffsStored},
+    ensure_cmd::{ApplySummary, EnsureCmd},
+    execution_report_cmd::ExecutionReportCmd,
+    flow_info_cmd::FlowInfoCmd,
+    multi_workspace_cmd::{
+        MultiWorkspaceCmd, MultiWorkspaceCmdOutcome, WorkspaceSetProgress, WorkspaceStatus,
+    },
+    params_explain_cmd::{ParamsExplainCmd, ParamsExplanations},
+    params_history_cmd::ParamsHistoryCmd,
+    params_reconcile_cmd::{
+        ParamsReconcileCmd, ParamsReconcileOutcome, ParamsReconciler, ParamsReconciliation,
+        ParamsSpecOrphan, ParamsSpecOrphanResolution,
+    },
+    profile_diff_matrix_cmd::{ProfileDiffMatrix, ProfileDiffMatrixCmd},
+    profile_list_cmd::{ProfileExecutionSummary, ProfileInfo, ProfileInfos, ProfileListCmd},
+    states_checkout_cmd::{StatesCheckoutCmd, StatesCheckoutOutcome},
+    states_clean_display_cmd::StatesCleanDisplayCmd,
     states_current_read_cmd::StatesCurrentReadCmd,
     states_current_stored_display_cmd::StatesCurrentStoredDisplayCmd,
     states_discover_cmd::StatesDiscoverCmd,
+    states_discover_incremental::StatesDiscoverIncremental,
     states_goal_display_cmd::StatesGoalDisplayCmd,
     states_goal_read_cmd::StatesGoalReadCmd,
+    states_prune_cmd::StatesPruneCmd,
+    states_reserialize_cmd::{StatesReserializeCmd, StatesReserializeOutcome},
 };
 
+#[cfg(feature = "output_progress")]
+pub use self::execution_progress_read_cmd::ExecutionProgressReadCmd;
+
+#[cfg(feature = "item_schema")]
+pub use self::flow_schema_cmd::FlowSchemaCmd;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use self::{
+    states_export_cmd::{StatesArchive, StatesExportCmd},
+    states_import_cmd::StatesImportCmd,
+    states_watch_cmd::{StatesDrift, StatesWatchCmd, StatesWatchOpts},
+    workspace_doctor_cmd::{WorkspaceDoctorCmd, WorkspaceDoctorReport, WorkspaceIssue},
+};
+
+mod apply_error_policy;
+mod apply_plan;
 mod apply_stored_state_sync;
+mod clean_check_cmd;
 mod clean_cmd;
 mod diff_cmd;
 mod ensure_cmd;
+mod execution_report_cmd;
+#[cfg(feature = "output_progress")]
+mod execution_progress_read_cmd;
+mod flow_info_cmd;
+#[cfg(feature = "item_schema")]
+mod flow_schema_cmd;
+mod multi_workspace_cmd;
+mod params_explain_cmd;
+mod params_history_cmd;
+mod params_reconcile_cmd;
+mod profile_diff_matrix_cmd;
+mod profile_list_cmd;
+mod states_checkout_cmd;
+mod states_clean_display_cmd;
 mod states_current_read_cmd;
 mod states_current_stored_display_cmd;
 mod states_discover_cmd;
+mod states_discover_incremental;
+#[cfg(not(target_arch = "wasm32"))]
+mod states_export_cmd;
 mod states_goal_display_cmd;
 mod states_goal_read_cmd;
+#[cfg(not(target_arch = "wasm32"))]
+mod states_import_cmd;
+mod states_prune_cmd;
+mod states_reserialize_cmd;
+#[cfg(not(target_arch = "wasm32"))]
+mod states_watch_cmd;
+#[cfg(not(target_arch = "wasm32"))]
+mod workspace_doctor_cmd;
@@ -0,0 +1,15 @@
+/// How an apply operation proceeds when an item fails.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ApplyErrorPolicy {
+    /// Stop queuing items once any item fails.
+    ///
+    /// Items that are already in progress are allowed to complete.
+    #[default]
+    Abort,
+    /// Continue applying items that do not depend on a failed item.
+    ///
+    /// Items that depend on a failed item -- directly or transitively -- are
+    /// not applied, and are recorded in the outcome's errors as
+    /// `ItemApplyBlocked`, with the ID of the failed item that blocked them.
+    ContinueOnError,
+}
@@ -0,0 +1,106 @@
+use std::ops::Deref;
+
+use indexmap::IndexMap;
+use peace_cfg::{ApplyCheck, ItemId};
+use peace_fmt::{presentable::HeadingLevel, Presentable, Presenter};
+use serde::{Deserialize, Serialize};
+
+pub use self::{apply_plan_action::ApplyPlanAction, item_apply_plan::ItemApplyPlan};
+
+mod apply_plan_action;
+mod item_apply_plan;
+
+/// What a dry run predicts an apply would do, per item.
+///
+/// Items are in the order they would be applied in. Items that are not
+/// applicable, or that failed while being prepared, are not included.
+///
+/// Returned by [`EnsureCmd::plan`] and [`CleanCmd::plan`].
+///
+/// [`EnsureCmd::plan`]: crate::cmds::EnsureCmd::plan
+/// [`CleanCmd::plan`]: crate::cmds::CleanCmd::plan
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApplyPlan(IndexMap<ItemId, ItemApplyPlan>);
+
+impl ApplyPlan {
+    /// Returns a new empty `ApplyPlan`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the items that the apply would change.
+    pub fn items_to_apply(&self) -> impl Iterator<Item = &ItemApplyPlan> {
+        self.0
+            .values()
+            .filter(|item_apply_plan| item_apply_plan.action != ApplyPlanAction::None)
+    }
+
+    /// Returns the underlying map.
+    pub fn into_inner(self) -> IndexMap<ItemId, ItemApplyPlan> {
+        self.0
+    }
+}
+
+impl Deref for ApplyPlan {
+    type Target = IndexMap<ItemId, ItemApplyPlan>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<IndexMap<ItemId, ItemApplyPlan>> for ApplyPlan {
+    fn from(item_apply_plans: IndexMap<ItemId, ItemApplyPlan>) -> Self {
+        Self(item_apply_plans)
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for ApplyPlan {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        presenter
+            .heading(HeadingLevel::Level1, "Apply plan")
+            .await?;
+
+        if self.0.is_empty() {
+            return presenter.text("No items to apply.").await;
+        }
+
+        let rows = self.0.values().map(|item_apply_plan| {
+            let ItemApplyPlan {
+                item_id,
+                state_current,
+                state_target,
+                state_diff,
+                apply_check,
+                action,
+            } = item_apply_plan;
+            let apply_check = match apply_check {
+                ApplyCheck::ExecNotRequired => String::from("not required"),
+                ApplyCheck::ExecRequired { reason: None, .. } => String::from("required"),
+                ApplyCheck::ExecRequired {
+                    reason: Some(reason),
+                    ..
+                } => format!("required ({reason})"),
+            };
+
+            vec![
+                item_id.to_string(),
+                action.to_string(),
+                apply_check,
+                state_current.clone(),
+                state_target.clone(),
+                state_diff.clone(),
+            ]
+        });
+        presenter
+            .table(
+                &["Item", "Action", "Apply check", "Current", "Target", "Diff"],
+                rows,
+            )
+            .await
+    }
+}
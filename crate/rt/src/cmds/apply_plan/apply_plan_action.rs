@@ -0,0 +1,24 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// What an apply is predicted to do for an item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ApplyPlanAction {
+    /// The item is already in its target state.
+    None,
+    /// The item would be brought to its goal state.
+    Ensure,
+    /// The item would be cleaned up.
+    Clean,
+}
+
+impl fmt::Display for ApplyPlanAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+            Self::Ensure => write!(f, "ensure"),
+            Self::Clean => write!(f, "clean"),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use peace_cfg::{ApplyCheck, ItemId};
+use serde::{Deserialize, Serialize};
+
+use crate::cmds::ApplyPlanAction;
+
+/// What a dry run predicts an apply would do for one item.
+///
+/// States are stored as their display strings, so that the plan can be
+/// presented and serialized without knowing each item's state types.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemApplyPlan {
+    /// ID of the item.
+    pub item_id: ItemId,
+    /// Summary of the item's current state.
+    pub state_current: String,
+    /// Summary of the state the item would be applied to.
+    pub state_target: String,
+    /// Summary of the difference between the current and target states.
+    pub state_diff: String,
+    /// Whether the item's `apply_exec` function would be run.
+    pub apply_check: ApplyCheck,
+    /// What the apply would do for the item.
+    pub action: ApplyPlanAction,
+}
@@ -0,0 +1,113 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::SingleProfileSingleFlow,
+};
+use peace_cmd_model::CmdOutcome;
+use peace_resources::states::ts::{Current, CurrentStored};
+use peace_rt_model_core::output::OutputWrite;
+
+use crate::cmds::{ApplyStoredStateSync, CleanCmd, DiffCmd};
+
+pub use self::resources_dangling::ResourcesDangling;
+
+mod resources_dangling;
+
+/// Detects real-world resources that still exist after a [`CleanCmd`].
+///
+/// After a successful `CleanCmd`, the stored current states are the clean
+/// states. This command discovers the current states, and reports items whose
+/// discovered state differs from the stored state -- such as resources whose
+/// delete reported success but did not take effect, or that are only
+/// eventually deleted.
+///
+/// Items whose clean returned an error are reported by `CleanCmd` itself.
+#[derive(Debug)]
+pub struct CleanCheckCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> CleanCheckCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Returns the items whose real-world resources still exist.
+    ///
+    /// The returned [`ResourcesDangling`] are also presented to the
+    /// `OutputWrite`. Discovered current states are not serialized.
+    ///
+    /// [`CleanCmd`] must have run prior to this command to store the cleaned
+    /// states.
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+    ) -> Result<
+        CmdOutcome<ResourcesDangling, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+    >
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let cmd_outcome = DiffCmd::diff_with_summary::<CurrentStored, Current>(cmd_ctx).await?;
+        let cmd_outcome = cmd_outcome.map(|(state_diffs, diff_summary)| {
+            ResourcesDangling::from_diffs(&state_diffs, &diff_summary)
+        });
+
+        if let CmdOutcome::Complete {
+            value: resources_dangling,
+            cmd_blocks_processed: _,
+        } = &cmd_outcome
+        {
+            cmd_ctx.output_mut().present(resources_dangling).await?;
+        }
+
+        Ok(cmd_outcome)
+    }
+
+    /// Returns the items whose real-world resources still exist, cleaning
+    /// them again up to `retry_limit` times.
+    ///
+    /// Each retry runs [`CleanCmd`] without checking whether stored states are
+    /// in sync. Items that are already clean are not applied again, so only
+    /// the dangling items are cleaned.
+    ///
+    /// If a retry does not complete, its outcome is returned with the
+    /// resources that were dangling before the retry.
+    ///
+    /// See [`Self::exec`] for full documentation.
+    pub async fn exec_with_retry<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+        retry_limit: u32,
+    ) -> Result<
+        CmdOutcome<ResourcesDangling, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+    >
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let mut retry_count = 0;
+        loop {
+            let cmd_outcome = Self::exec(cmd_ctx).await?;
+            let resources_dangling = match cmd_outcome {
+                CmdOutcome::Complete {
+                    value: resources_dangling,
+                    cmd_blocks_processed: _,
+                } if !resources_dangling.is_empty() && retry_count < retry_limit => {
+                    resources_dangling
+                }
+                cmd_outcome => return Ok(cmd_outcome),
+            };
+
+            retry_count += 1;
+            let clean_cmd_outcome =
+                CleanCmd::exec_with(cmd_ctx, ApplyStoredStateSync::None).await?;
+            if !clean_cmd_outcome.is_complete() {
+                return Ok(clean_cmd_outcome.map(|_states_cleaned| resources_dangling));
+            }
+        }
+    }
+}
+
+impl<CmdCtxTypesT> Default for CleanCheckCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
@@ -0,0 +1,84 @@
+use peace_cfg::{DiffSeverity, ItemId};
+use peace_fmt::{presentable::HeadingLevel, Presentable, Presenter};
+use peace_resources::{
+    internal::StateDiffsMut,
+    states::{DiffSummary, StateDiffs},
+};
+use serde::Serialize;
+
+/// Items whose real-world resources still exist after being cleaned, returned
+/// by [`CleanCheckCmd`].
+///
+/// [`CleanCheckCmd`]: crate::cmds::CleanCheckCmd
+#[derive(Debug, Serialize)]
+pub struct ResourcesDangling {
+    /// Severity of each dangling item's diff.
+    diff_summary: DiffSummary,
+    /// State diff between each dangling item's stored and discovered current
+    /// state.
+    state_diffs: StateDiffs,
+}
+
+impl ResourcesDangling {
+    /// Returns the items whose discovered current state differs from their
+    /// stored current state.
+    pub(crate) fn from_diffs(state_diffs: &StateDiffs, diff_summary: &DiffSummary) -> Self {
+        let mut diff_summary_dangling = DiffSummary::new();
+        let mut state_diffs_dangling = StateDiffsMut::new();
+        diff_summary
+            .iter()
+            .filter(|(_item_id, diff_severity)| **diff_severity != DiffSeverity::InSync)
+            .for_each(|(item_id, diff_severity)| {
+                diff_summary_dangling.insert(item_id.clone(), *diff_severity);
+                if let Some(state_diff) = state_diffs.get_raw(item_id) {
+                    state_diffs_dangling.insert_raw(item_id.clone(), state_diff.clone());
+                }
+            });
+
+        Self {
+            diff_summary: diff_summary_dangling,
+            state_diffs: StateDiffs::from(state_diffs_dangling),
+        }
+    }
+
+    /// Returns whether no item's resources are dangling.
+    pub fn is_empty(&self) -> bool {
+        self.diff_summary.is_empty()
+    }
+
+    /// Returns the IDs of the items whose resources are dangling.
+    pub fn item_ids(&self) -> impl Iterator<Item = &ItemId> {
+        self.diff_summary.keys()
+    }
+
+    /// Returns the severity of each dangling item's diff.
+    pub fn diff_summary(&self) -> &DiffSummary {
+        &self.diff_summary
+    }
+
+    /// Returns the state diff between each dangling item's stored and
+    /// discovered current state.
+    pub fn state_diffs(&self) -> &StateDiffs {
+        &self.state_diffs
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for ResourcesDangling {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        if self.is_empty() {
+            return presenter
+                .heading(HeadingLevel::Level1, "No dangling resources")
+                .await;
+        }
+
+        presenter
+            .heading(HeadingLevel::Level1, "Dangling resources")
+            .await?;
+        self.state_diffs.present(presenter).await?;
+        self.diff_summary.present(presenter).await
+    }
+}
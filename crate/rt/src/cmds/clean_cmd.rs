@@ -7,19 +7,20 @@ use peace_cmd::{
 use peace_cmd_model::CmdOutcome;
 use peace_cmd_rt::{CmdBlockWrapper, CmdExecution};
 use peace_resources::{
-    paths::{FlowDir, StatesCurrentFile},
+    paths::{FlowDir, ParamsDigestsFile, StatesCurrentFile},
     resources::ts::SetUp,
     states::{States, StatesCleaned, StatesCleanedDry, StatesPrevious},
     Resources,
 };
-use peace_rt_model::{ItemGraph, Storage};
+use peace_rt_model::{output::OutputWrite, ExecutionHistorySerializer, ItemGraph, Storage};
 
 use crate::{
     cmd_blocks::{
         apply_exec_cmd_block::StatesTsApplyExt, ApplyExecCmdBlock, ApplyStateSyncCheckCmdBlock,
-        StatesCleanInsertionCmdBlock, StatesCurrentReadCmdBlock, StatesDiscoverCmdBlock,
+        PreflightCheckCmdBlock, StatesCleanInsertionCmdBlock, StatesCurrentReadCmdBlock,
+        StatesDiscoverCmdBlock,
     },
-    cmds::ApplyStoredStateSync,
+    cmds::{ApplyPlan, ApplyStoredStateSync},
 };
 
 #[derive(Debug)]
@@ -106,6 +107,46 @@ where
         Ok(cmd_outcome)
     }
 
+    /// Returns what [`Self::exec`] would do for each [`Item`], without
+    /// applying any changes.
+    ///
+    /// This runs [`Self::exec_dry`], and records each item's current state,
+    /// target state, [`ApplyCheck`], and whether it would be cleaned up.
+    ///
+    /// When the dry run completes, the returned [`ApplyPlan`] is also
+    /// presented to the `OutputWrite`.
+    ///
+    /// [`ApplyCheck`]: peace_cfg::ApplyCheck
+    /// [`Item`]: peace_cfg::Item
+    pub async fn plan<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+    ) -> Result<
+        CmdOutcome<ApplyPlan, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+    >
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let cmd_outcome = Self::exec_dry(cmd_ctx).await?;
+
+        let apply_plan = cmd_ctx
+            .view()
+            .resources
+            .try_remove::<ApplyPlan>()
+            .unwrap_or_default();
+        let cmd_outcome = cmd_outcome.map(|_states_cleaned_dry| apply_plan);
+
+        if let CmdOutcome::Complete {
+            value: apply_plan,
+            cmd_blocks_processed: _,
+        } = &cmd_outcome
+        {
+            cmd_ctx.output_mut().present(apply_plan).await?;
+        }
+
+        Ok(cmd_outcome)
+    }
+
     /// Conditionally runs [`Item::apply_exec`] for each [`Item`].
     ///
     /// In practice this runs [`Item::apply_check`], and only runs
@@ -180,7 +221,24 @@ where
                     CleanExecChange::None => Ok(Default::default()),
                     CleanExecChange::Some(states_previous_and_cleaned) => {
                         let (states_previous, states_cleaned) = *states_previous_and_cleaned;
-                        Self::serialize_current(item_graph, resources, &states_cleaned).await?;
+                        let storage_transaction = resources.borrow::<Storage>().transaction();
+                        Self::serialize_current(
+                            item_graph,
+                            resources,
+                            &storage_transaction,
+                            &states_cleaned,
+                        )
+                        .await?;
+                        storage_transaction.commit().await?;
+
+                        ExecutionHistorySerializer::<
+                            <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+                        >::record_in(
+                            flow.flow_id(),
+                            resources,
+                            states_cleaned.keys().cloned().collect(),
+                        )
+                        .await?;
 
                         resources.insert::<StatesPrevious>(states_previous);
 
@@ -213,7 +271,16 @@ where
         StatesTs: StatesTsApplyExt + Debug + Send + Sync + Unpin + 'static,
     {
         let mut cmd_execution = {
-            let mut cmd_execution_builder = CmdExecution::<CleanExecChange<StatesTs>, _>::builder()
+            let mut cmd_execution_builder =
+                CmdExecution::<CleanExecChange<StatesTs>, _>::builder();
+            if !cmd_ctx.scope().flow().preflight_checks().is_empty() {
+                cmd_execution_builder = cmd_execution_builder.with_cmd_block(
+                    CmdBlockWrapper::new(PreflightCheckCmdBlock::new(), |()| {
+                        CleanExecChange::None
+                    }),
+                );
+            }
+            cmd_execution_builder = cmd_execution_builder
                 .with_cmd_block(CmdBlockWrapper::new(
                     StatesCurrentReadCmdBlock::new(),
                     |_states_current_stored| CleanExecChange::None,
@@ -282,19 +349,31 @@ where
     async fn serialize_current(
         item_graph: &ItemGraph<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
         resources: &Resources<SetUp>,
+        storage: &Storage,
         states_cleaned: &StatesCleaned,
     ) -> Result<(), <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError> {
-        use peace_rt_model::StatesSerializer;
+        use peace_rt_model::{ParamsDigests, ParamsDigestsSerializer, StatesSerializer};
 
         let flow_dir = resources.borrow::<FlowDir>();
-        let storage = resources.borrow::<Storage>();
         let states_current_file = StatesCurrentFile::from(&*flow_dir);
 
-        StatesSerializer::serialize(&storage, item_graph, states_cleaned, &states_current_file)
-            .await?;
+        // Only write the states that changed from those in the file.
+        StatesSerializer::serialize_delta(
+            storage,
+            item_graph,
+            states_cleaned,
+            &states_current_file,
+        )
+        .await?;
+
+        // Record the params that the current states were stored with.
+        if let Ok(params_digests) = resources.try_borrow::<ParamsDigests>() {
+            let params_digests_file = ParamsDigestsFile::from(&*flow_dir);
+            ParamsDigestsSerializer::serialize(storage, &params_digests, &params_digests_file)
+                .await?;
+        }
 
         drop(flow_dir);
-        drop(storage);
 
         Ok(())
     }
@@ -4,7 +4,10 @@ use futures::{StreamExt, TryStreamExt};
 use peace_cfg::{ItemId, Profile};
 use peace_cmd::{
     ctx::{CmdCtx, CmdCtxTypesConstrained},
-    scopes::{MultiProfileSingleFlow, MultiProfileSingleFlowView, SingleProfileSingleFlow},
+    scopes::{
+        MultiProfileSingleFlow, MultiProfileSingleFlowView, SingleProfileSingleFlow,
+        SingleProfileSingleFlowView,
+    },
 };
 use peace_cmd_model::CmdOutcome;
 use peace_cmd_rt::{CmdBlockWrapper, CmdExecution, CmdExecutionBuilder};
@@ -14,7 +17,7 @@ use peace_resources::{
     resources::ts::SetUp,
     states::{
         ts::{CurrentStored, GoalStored},
-        StateDiffs,
+        DiffSummary, StateDiffs, States, StatesTimestamps,
     },
     type_reg::untagged::{BoxDtDisplay, TypeMap},
     Resources,
@@ -26,10 +29,14 @@ use crate::cmd_blocks::{
     StatesGoalReadCmdBlock,
 };
 
-pub use self::{diff_info_spec::DiffInfoSpec, diff_state_spec::DiffStateSpec};
+pub use self::{
+    diff_info_spec::DiffInfoSpec, diff_state_spec::DiffStateSpec,
+    state_diffs_stored::StateDiffsStored,
+};
 
 mod diff_info_spec;
 mod diff_state_spec;
+mod state_diffs_stored;
 
 pub struct DiffCmd<CmdCtxTypesT, Scope>(PhantomData<(CmdCtxTypesT, Scope)>);
 
@@ -66,6 +73,47 @@ where
         Self::diff::<CurrentStored, GoalStored>(cmd_ctx).await
     }
 
+    /// Returns the [`state_diff`]`s between the stored current and goal
+    /// states, without discovering states.
+    ///
+    /// This only reads the `StatesCurrentFile` and `StatesGoalFile`, so it may
+    /// be used offline, or on targets where discovery is not possible. The
+    /// stored states may not reflect the items' actual states, which is
+    /// indicated when the returned [`StateDiffsStored`] is presented, along
+    /// with how long ago each item's stored states were discovered.
+    ///
+    /// Both current and goal states must have been discovered prior to
+    /// running this. See [`StatesDiscoverCmd::current_and_goal`].
+    ///
+    /// [`state_diff`]: peace_cfg::Item::state_diff
+    /// [`StatesDiscoverCmd::current_and_goal`]: crate::cmds::StatesDiscoverCmd::current_and_goal
+    pub async fn stored(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+    ) -> Result<
+        CmdOutcome<StateDiffsStored, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+    > {
+        let cmd_outcome = Self::diff::<CurrentStored, GoalStored>(cmd_ctx).await?;
+
+        let (states_current_timestamps, states_goal_timestamps) = if cmd_outcome.is_complete() {
+            let SingleProfileSingleFlowView { resources, .. } = cmd_ctx.view();
+            let states_current_stored = resources.borrow::<States<CurrentStored>>();
+            let states_goal_stored = resources.borrow::<States<GoalStored>>();
+
+            (
+                states_current_stored.timestamps().clone(),
+                states_goal_stored.timestamps().clone(),
+            )
+        } else {
+            (StatesTimestamps::new(), StatesTimestamps::new())
+        };
+
+        Ok(cmd_outcome.map(|state_diffs| {
+            StateDiffsStored::new(state_diffs)
+                .with_timestamps(states_current_timestamps, states_goal_timestamps)
+        }))
+    }
+
     /// Returns the [`state_diff`]`s between two states.
     ///
     /// For `CurrentStored` and `GoalStored`, states must have been discovered
@@ -107,6 +155,64 @@ where
         cmd_execution_builder.build().exec(cmd_ctx).await
     }
 
+    /// Returns the [`state_diff`]`s between the stored current and goal
+    /// states, along with a [`DiffSummary`] of each diff's severity.
+    ///
+    /// This is equivalent to calling:
+    ///
+    /// ```rust,ignore
+    /// DiffCmd::diff_with_summary::<CurrentStored, GoalStored>(cmd_ctx).await?;
+    /// ```
+    ///
+    /// See [`DiffCmd::diff_with_summary`] for details.
+    ///
+    /// [`state_diff`]: peace_cfg::Item::state_diff
+    pub async fn diff_stored_summary(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+    ) -> Result<
+        CmdOutcome<(StateDiffs, DiffSummary), <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+    > {
+        Self::diff_with_summary::<CurrentStored, GoalStored>(cmd_ctx).await
+    }
+
+    /// Returns the [`state_diff`]`s between two states, along with a
+    /// [`DiffSummary`] of each diff's severity.
+    ///
+    /// The summary's [`DiffSummary::detailed_exit_code`] may be used as the
+    /// process exit code, similar to `terraform plan -detailed-exitcode`.
+    ///
+    /// If the command did not complete, the summary is empty.
+    ///
+    /// [`state_diff`]: peace_cfg::Item::state_diff
+    pub async fn diff_with_summary<StatesTs0, StatesTs1>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+    ) -> Result<
+        CmdOutcome<(StateDiffs, DiffSummary), <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+    >
+    where
+        StatesTs0: Debug + DiffCmdBlockStatesTsExt + Send + Sync + Unpin + 'static,
+        StatesTs1: Debug + DiffCmdBlockStatesTsExt + Send + Sync + Unpin + 'static,
+    {
+        let cmd_outcome = Self::diff::<StatesTs0, StatesTs1>(cmd_ctx).await?;
+
+        let diff_summary = match cmd_outcome.value() {
+            Some(state_diffs) if cmd_outcome.is_complete() => {
+                let SingleProfileSingleFlowView {
+                    flow, resources, ..
+                } = cmd_ctx.view();
+                let states_a = resources.borrow::<States<StatesTs0>>();
+                let states_b = resources.borrow::<States<StatesTs1>>();
+
+                Self::diff_summary_any(flow, &states_a, &states_b, state_diffs)?
+            }
+            _ => DiffSummary::new(),
+        };
+
+        Ok(cmd_outcome.map(|state_diffs| (state_diffs, diff_summary)))
+    }
+
     fn states_fetch_cmd_block_append(
         cmd_execution_builder: CmdExecutionBuilder<'ctx, StateDiffs, CmdCtxTypesT>,
         diff_state_spec: DiffStateSpec,
@@ -244,6 +350,37 @@ where
 
         Ok(state_diffs)
     }
+
+    /// Returns the [`DiffSeverity`] of each [`Item`]'s state diff.
+    ///
+    /// Items that do not have both states, or do not have a state diff, are
+    /// not included in the summary.
+    ///
+    /// [`DiffSeverity`]: peace_cfg::DiffSeverity
+    /// [`Item`]: peace_cfg::Item
+    pub fn diff_summary_any(
+        flow: &Flow<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        states_a: &TypeMap<ItemId, BoxDtDisplay>,
+        states_b: &TypeMap<ItemId, BoxDtDisplay>,
+        state_diffs: &StateDiffs,
+    ) -> Result<DiffSummary, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError> {
+        flow.graph().iter_insertion().try_fold(
+            DiffSummary::with_capacity(state_diffs.len()),
+            |mut diff_summary, item| {
+                let item_id = item.id();
+                if let (Some(state_a), Some(state_b), Some(state_diff)) = (
+                    states_a.get_raw(item_id),
+                    states_b.get_raw(item_id),
+                    state_diffs.get_raw(item_id),
+                ) {
+                    let diff_severity = item.diff_severity(state_a, state_b, state_diff)?;
+                    diff_summary.insert(item_id.clone(), diff_severity);
+                }
+
+                Ok(diff_summary)
+            },
+        )
+    }
 }
 
 impl<CmdCtxTypesT, Scope> Default for DiffCmd<CmdCtxTypesT, Scope> {
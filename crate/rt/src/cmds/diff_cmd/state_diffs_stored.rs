@@ -0,0 +1,147 @@
+use std::{
+    fmt::{self, Debug},
+    ops::Deref,
+};
+
+use chrono::{DateTime, Utc};
+use peace_cfg::ItemId;
+use peace_fmt::{presentable::HeadingLevel, Presentable, Presenter};
+use peace_resources::states::{StateDiffs, StatesTimestamps};
+use serde::Serialize;
+
+/// [`StateDiffs`] computed from stored current and goal states.
+///
+/// Stored states are only as recent as the last discovery, so these diffs may
+/// be stale. This is indicated when the diffs are presented, along with how
+/// long ago each item's current and goal states were discovered, if known.
+///
+/// Returned by [`DiffCmd::stored`].
+///
+/// [`DiffCmd::stored`]: crate::cmds::DiffCmd::stored
+#[derive(Default, Serialize)]
+#[serde(transparent)]
+pub struct StateDiffsStored(
+    StateDiffs,
+    /// When each item's stored current state was discovered.
+    #[serde(skip)]
+    StatesTimestamps,
+    /// When each item's stored goal state was discovered.
+    #[serde(skip)]
+    StatesTimestamps,
+);
+
+impl StateDiffsStored {
+    /// Returns a new `StateDiffsStored`.
+    pub fn new(state_diffs: StateDiffs) -> Self {
+        Self(
+            state_diffs,
+            StatesTimestamps::new(),
+            StatesTimestamps::new(),
+        )
+    }
+
+    /// Sets when each item's stored current and goal states were discovered.
+    pub fn with_timestamps(
+        mut self,
+        states_current_timestamps: StatesTimestamps,
+        states_goal_timestamps: StatesTimestamps,
+    ) -> Self {
+        self.1 = states_current_timestamps;
+        self.2 = states_goal_timestamps;
+        self
+    }
+
+    /// Returns when each item's stored current state was discovered.
+    pub fn states_current_timestamps(&self) -> &StatesTimestamps {
+        &self.1
+    }
+
+    /// Returns when each item's stored goal state was discovered.
+    pub fn states_goal_timestamps(&self) -> &StatesTimestamps {
+        &self.2
+    }
+
+    /// Returns the inner `StateDiffs`.
+    pub fn into_inner(self) -> StateDiffs {
+        self.0
+    }
+
+    /// Returns how long ago each side of an item's diff was discovered, e.g.
+    /// ` (current: 5m old, goal: 2d old)`, or an empty string if neither is
+    /// known.
+    fn staleness_annotation(&self, item_id: &ItemId, now: DateTime<Utc>) -> String {
+        let sides = [
+            ("current", self.1.get(item_id)),
+            ("goal", self.2.get(item_id)),
+        ]
+        .into_iter()
+        .filter_map(|(side, timestamp)| {
+            timestamp.map(|timestamp| format!("{side}: {} old", Self::age_fmt(now, *timestamp)))
+        })
+        .collect::<Vec<String>>();
+
+        if sides.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", sides.join(", "))
+        }
+    }
+
+    /// Returns the time since `timestamp` in its largest whole unit, e.g. `5m`.
+    fn age_fmt(now: DateTime<Utc>, timestamp: DateTime<Utc>) -> String {
+        let age = now.signed_duration_since(timestamp);
+        if age.num_days() > 0 {
+            format!("{}d", age.num_days())
+        } else if age.num_hours() > 0 {
+            format!("{}h", age.num_hours())
+        } else if age.num_minutes() > 0 {
+            format!("{}m", age.num_minutes())
+        } else {
+            format!("{}s", age.num_seconds().max(0))
+        }
+    }
+}
+
+// Timestamps are omitted, consistent with `States`.
+impl Debug for StateDiffsStored {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("StateDiffsStored").field(&self.0).finish()
+    }
+}
+
+impl Deref for StateDiffsStored {
+    type Target = StateDiffs;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<StateDiffs> for StateDiffsStored {
+    fn from(state_diffs: StateDiffs) -> Self {
+        Self::new(state_diffs)
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for StateDiffsStored {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        presenter
+            .heading(
+                HeadingLevel::Level1,
+                "State diffs from stored states (may be stale)",
+            )
+            .await?;
+
+        let now = Utc::now();
+        presenter
+            .list_numbered_with(self.0.iter(), |(item_id, state_diff)| {
+                let staleness_annotation = self.staleness_annotation(item_id, now);
+                (item_id, format!(": {state_diff}{staleness_annotation}"))
+            })
+            .await
+    }
+}
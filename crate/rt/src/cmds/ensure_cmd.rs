@@ -1,27 +1,34 @@
 use std::{fmt::Debug, marker::PhantomData};
 
+use peace_cfg::ItemId;
 use peace_cmd::{
     ctx::{CmdCtx, CmdCtxTypesConstrained},
     scopes::{SingleProfileSingleFlow, SingleProfileSingleFlowView},
 };
 use peace_cmd_model::CmdOutcome;
 use peace_cmd_rt::{CmdBlockWrapper, CmdExecution};
+use peace_params::ParamsSpecs;
 use peace_resources::{
-    paths::{FlowDir, StatesCurrentFile, StatesGoalFile},
+    paths::{FlowDir, ParamsDigestsFile, StatesCurrentFile, StatesGoalFile},
     resources::ts::SetUp,
     states::{States, StatesEnsured, StatesEnsuredDry, StatesGoal, StatesPrevious},
     Resources,
 };
-use peace_rt_model::{ItemGraph, Storage};
+use peace_rt_model::{output::OutputWrite, ExecutionHistorySerializer, ItemGraph, Storage};
 
 use crate::{
     cmd_blocks::{
         apply_exec_cmd_block::StatesTsApplyExt, ApplyExecCmdBlock, ApplyStateSyncCheckCmdBlock,
-        StatesCurrentReadCmdBlock, StatesDiscoverCmdBlock, StatesGoalReadCmdBlock,
+        PreflightCheckCmdBlock, StatesCurrentReadCmdBlock, StatesDiscoverCmdBlock,
+        StatesGoalReadCmdBlock,
     },
-    cmds::ApplyStoredStateSync,
+    cmds::{ApplyErrorPolicy, ApplyPlan, ApplyStoredStateSync},
 };
 
+pub use self::apply_summary::ApplySummary;
+
+mod apply_summary;
+
 #[derive(Debug)]
 pub struct EnsureCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
 
@@ -80,7 +87,8 @@ where
     where
         CmdCtxTypesT: 'ctx,
     {
-        let cmd_outcome = Self::exec_internal(cmd_ctx, apply_stored_state_sync).await?;
+        let cmd_outcome =
+            Self::exec_internal(cmd_ctx, apply_stored_state_sync, ApplyErrorPolicy::Abort).await?;
 
         let cmd_outcome = cmd_outcome.map(|ensure_exec_change| match ensure_exec_change {
             EnsureExecChange::None => Default::default(),
@@ -98,6 +106,46 @@ where
         Ok(cmd_outcome)
     }
 
+    /// Returns what [`Self::exec`] would do for each [`Item`], without
+    /// applying any changes.
+    ///
+    /// This runs [`Self::exec_dry`], and records each item's current state,
+    /// target state, [`ApplyCheck`], and whether it would be brought to its goal state.
+    ///
+    /// When the dry run completes, the returned [`ApplyPlan`] is also
+    /// presented to the `OutputWrite`.
+    ///
+    /// [`ApplyCheck`]: peace_cfg::ApplyCheck
+    /// [`Item`]: peace_cfg::Item
+    pub async fn plan<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+    ) -> Result<
+        CmdOutcome<ApplyPlan, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+    >
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let cmd_outcome = Self::exec_dry(cmd_ctx).await?;
+
+        let apply_plan = cmd_ctx
+            .view()
+            .resources
+            .try_remove::<ApplyPlan>()
+            .unwrap_or_default();
+        let cmd_outcome = cmd_outcome.map(|_states_ensured_dry| apply_plan);
+
+        if let CmdOutcome::Complete {
+            value: apply_plan,
+            cmd_blocks_processed: _,
+        } = &cmd_outcome
+        {
+            cmd_ctx.output_mut().present(apply_plan).await?;
+        }
+
+        Ok(cmd_outcome)
+    }
+
     /// Conditionally runs [`Item::apply_exec`] for each [`Item`].
     ///
     /// In practice this runs [`Item::apply_check`], and only runs
@@ -117,6 +165,11 @@ where
     /// 2. For `Item`s that return `ApplyCheck::ExecRequired`, run
     ///    `Item::apply_exec`.
     ///
+    /// When items have been applied, an [`ApplySummary`] of the items that
+    /// were added, changed, unchanged, or failed is presented to the
+    /// `OutputWrite`. Use [`Self::exec_with_summary`] to present it after the
+    /// ensured states instead.
+    ///
     /// [`apply_exec`]: peace_cfg::Item::apply_exec
     /// [`Item::apply_check`]: peace_cfg::Item::apply_check
     /// [`Item::apply_exec`]: peace_cfg::ItemRt::apply_exec
@@ -149,10 +202,135 @@ where
     where
         CmdCtxTypesT: 'ctx,
     {
-        let cmd_outcome = Self::exec_internal(cmd_ctx, apply_stored_state_sync).await?;
+        Self::exec_with_error_policy(cmd_ctx, apply_stored_state_sync, ApplyErrorPolicy::Abort)
+            .await
+    }
+
+    /// Conditionally runs [`Item::apply_exec`] for each [`Item`], returning
+    /// the [`ApplySummary`] instead of presenting it.
+    ///
+    /// This allows the summary to be presented as a footer, after the
+    /// ensured states.
+    ///
+    /// See [`Self::exec`] for full documentation.
+    ///
+    /// [`Item::apply_exec`]: peace_cfg::ItemRt::apply_exec
+    /// [`Item`]: peace_cfg::Item
+    pub async fn exec_with_summary<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+    ) -> Result<
+        (
+            CmdOutcome<StatesEnsured, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+            Option<ApplySummary>,
+        ),
+        <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+    >
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        Self::exec_with_summary_and_error_policy(
+            cmd_ctx,
+            ApplyStoredStateSync::Both,
+            ApplyErrorPolicy::Abort,
+        )
+        .await
+    }
+
+    /// Conditionally runs [`Item::apply_exec`] for each [`Item`], continuing
+    /// with independent items when an item fails.
+    ///
+    /// Items that depend on a failed item are not applied, and are recorded in
+    /// the outcome's errors as [`ItemApplyBlocked`], with the ID of the failed
+    /// item that blocked them.
+    ///
+    /// See [`Self::exec`] for full documentation.
+    ///
+    /// [`Item::apply_exec`]: peace_cfg::ItemRt::apply_exec
+    /// [`Item`]: peace_cfg::Item
+    /// [`ItemApplyBlocked`]: peace_rt_model::Error::ItemApplyBlocked
+    pub async fn exec_continue_on_error<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+    ) -> Result<
+        CmdOutcome<StatesEnsured, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+    >
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        Self::exec_with_error_policy(
+            cmd_ctx,
+            ApplyStoredStateSync::Both,
+            ApplyErrorPolicy::ContinueOnError,
+        )
+        .await
+    }
+
+    /// Conditionally runs [`Item::apply_exec`] for each [`Item`].
+    ///
+    /// See [`Self::exec`] for full documentation.
+    ///
+    /// This function exists so that this command can be executed as sub
+    /// functionality of another command.
+    ///
+    /// [`Item::apply_exec`]: peace_cfg::ItemRt::apply_exec
+    /// [`Item`]: peace_cfg::Item
+    pub async fn exec_with_error_policy<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+        apply_stored_state_sync: ApplyStoredStateSync,
+        apply_error_policy: ApplyErrorPolicy,
+    ) -> Result<
+        CmdOutcome<StatesEnsured, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+    >
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let (cmd_outcome, apply_summary) = Self::exec_with_summary_and_error_policy(
+            cmd_ctx,
+            apply_stored_state_sync,
+            apply_error_policy,
+        )
+        .await?;
+        if let Some(apply_summary) = apply_summary.as_ref() {
+            cmd_ctx.output_mut().present(apply_summary).await?;
+        }
+
+        Ok(cmd_outcome)
+    }
+
+    /// Conditionally runs [`Item::apply_exec`] for each [`Item`], returning
+    /// the [`ApplySummary`] if items were applied.
+    ///
+    /// [`Item::apply_exec`]: peace_cfg::ItemRt::apply_exec
+    /// [`Item`]: peace_cfg::Item
+    async fn exec_with_summary_and_error_policy<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+        apply_stored_state_sync: ApplyStoredStateSync,
+        apply_error_policy: ApplyErrorPolicy,
+    ) -> Result<
+        (
+            CmdOutcome<StatesEnsured, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+            Option<ApplySummary>,
+        ),
+        <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+    >
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let cmd_outcome =
+            Self::exec_internal(cmd_ctx, apply_stored_state_sync, apply_error_policy).await?;
+        let item_ids_failed = match &cmd_outcome {
+            CmdOutcome::ItemError { errors, .. } => errors.keys().cloned().collect(),
+            CmdOutcome::Complete { .. }
+            | CmdOutcome::BlockInterrupted { .. }
+            | CmdOutcome::ExecutionInterrupted { .. } => Vec::new(),
+        };
 
         let SingleProfileSingleFlowView {
-            flow, resources, ..
+            flow,
+            params_specs,
+            resources,
+            ..
         } = cmd_ctx.view();
         let (item_graph, resources) = (flow.graph(), resources);
 
@@ -161,21 +339,120 @@ where
         let cmd_outcome = cmd_outcome
             .map_async(|ensure_exec_change| async move {
                 match ensure_exec_change {
-                    EnsureExecChange::None => Ok(Default::default()),
+                    EnsureExecChange::None => Ok((Default::default(), None)),
                     EnsureExecChange::Some(stateses_boxed) => {
                         let (states_previous, states_applied, states_goal) = *stateses_boxed;
-                        Self::serialize_current(item_graph, resources, &states_applied).await?;
-                        Self::serialize_goal(item_graph, resources, &states_goal).await?;
 
+                        let apply_summary = Self::apply_summary(
+                            item_graph,
+                            params_specs,
+                            resources,
+                            &states_previous,
+                            &states_applied,
+                            item_ids_failed,
+                        )
+                        .await;
+
+                        // Commit current and goal states together, so that they are
+                        // consistent with each other even if one of the writes fails.
+                        let storage_transaction = resources.borrow::<Storage>().transaction();
+                        Self::serialize_current(
+                            item_graph,
+                            resources,
+                            &storage_transaction,
+                            &states_applied,
+                        )
+                        .await?;
+                        Self::serialize_goal(
+                            item_graph,
+                            resources,
+                            &storage_transaction,
+                            &states_goal,
+                        )
+                        .await?;
+                        storage_transaction.commit().await?;
+
+                        ExecutionHistorySerializer::<
+                            <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+                        >::record_in(
+                            flow.flow_id(),
+                            resources,
+                            states_applied.keys().cloned().collect(),
+                        )
+                        .await?;
                         resources.insert::<StatesPrevious>(states_previous);
 
-                        Ok(states_applied)
+                        Ok((states_applied, Some(apply_summary)))
                     }
                 }
             })
-            .await;
+            .await
+            .transpose()?;
+
+        let mut apply_summary = None;
+        let cmd_outcome = cmd_outcome.map(|(states_applied, apply_summary_applied)| {
+            apply_summary = apply_summary_applied;
+            states_applied
+        });
 
-        cmd_outcome.transpose()
+        Ok((cmd_outcome, apply_summary))
+    }
+
+    /// Returns which items were added, changed, unchanged, or failed by an
+    /// apply.
+    ///
+    /// An item is added if its previous state was its clean state, and its
+    /// applied state is not.
+    ///
+    /// The states have already been applied when this is called, so if an
+    /// item's states cannot be compared, the item is counted as changed
+    /// instead of failing the command.
+    async fn apply_summary(
+        item_graph: &ItemGraph<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        params_specs: &ParamsSpecs,
+        resources: &Resources<SetUp>,
+        states_previous: &StatesPrevious,
+        states_applied: &StatesEnsured,
+        item_ids_failed: Vec<ItemId>,
+    ) -> ApplySummary {
+        let mut item_ids_added = Vec::new();
+        let mut item_ids_changed = Vec::new();
+        let mut item_ids_unchanged = Vec::new();
+
+        for item in item_graph.iter_insertion() {
+            let item_id = item.id();
+            if item_ids_failed.contains(item_id) {
+                continue;
+            }
+            let (Some(state_previous), Some(state_applied)) = (
+                states_previous.get_raw(item_id),
+                states_applied.get_raw(item_id),
+            ) else {
+                continue;
+            };
+
+            if matches!(item.state_eq(state_previous, state_applied), Ok(true)) {
+                item_ids_unchanged.push(item_id.clone());
+                continue;
+            }
+
+            let state_previous_is_clean = match item.state_clean(params_specs, resources).await {
+                Ok(state_clean) => matches!(item.state_eq(state_previous, &state_clean), Ok(true)),
+                Err(_error) => false,
+            };
+            if state_previous_is_clean {
+                item_ids_added.push(item_id.clone());
+            } else {
+                item_ids_changed.push(item_id.clone());
+            }
+        }
+
+        ApplySummary::new(
+            item_ids_added,
+            item_ids_changed,
+            item_ids_unchanged,
+            item_ids_failed,
+        )
     }
 
     /// Conditionally runs [`ApplyFns`]`::`[`exec`] for each [`Item`].
@@ -189,6 +466,7 @@ where
     async fn exec_internal<'ctx, StatesTs>(
         cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
         apply_stored_state_sync: ApplyStoredStateSync,
+        apply_error_policy: ApplyErrorPolicy,
     ) -> Result<
         CmdOutcome<EnsureExecChange<StatesTs>, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
         <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
@@ -199,24 +477,32 @@ where
     {
         let mut cmd_execution = {
             let mut cmd_execution_builder =
-                CmdExecution::<EnsureExecChange<StatesTs>, _>::builder()
-                    .with_cmd_block(CmdBlockWrapper::new(
-                        StatesCurrentReadCmdBlock::new(),
-                        |_states_current_stored| EnsureExecChange::None,
-                    ))
-                    .with_cmd_block(CmdBlockWrapper::new(
-                        StatesGoalReadCmdBlock::new(),
-                        |_states_goal_stored| EnsureExecChange::None,
-                    ))
-                    // Always discover current and goal states, because they are read whether or not
-                    // we are checking for state sync.
-                    //
-                    // Exception: current states are not used for `ApplyStoredStateSync::None`,
-                    // since we have to discover the new current state after every apply.
-                    .with_cmd_block(CmdBlockWrapper::new(
-                        StatesDiscoverCmdBlock::current_and_goal(),
-                        |_states_current_and_goal_mut| EnsureExecChange::None,
-                    ));
+                CmdExecution::<EnsureExecChange<StatesTs>, _>::builder();
+            if !cmd_ctx.scope().flow().preflight_checks().is_empty() {
+                cmd_execution_builder = cmd_execution_builder.with_cmd_block(
+                    CmdBlockWrapper::new(PreflightCheckCmdBlock::new(), |()| {
+                        EnsureExecChange::None
+                    }),
+                );
+            }
+            cmd_execution_builder = cmd_execution_builder
+                .with_cmd_block(CmdBlockWrapper::new(
+                    StatesCurrentReadCmdBlock::new(),
+                    |_states_current_stored| EnsureExecChange::None,
+                ))
+                .with_cmd_block(CmdBlockWrapper::new(
+                    StatesGoalReadCmdBlock::new(),
+                    |_states_goal_stored| EnsureExecChange::None,
+                ))
+                // Always discover current and goal states, because they are read whether or not
+                // we are checking for state sync.
+                //
+                // Exception: current states are not used for `ApplyStoredStateSync::None`,
+                // since we have to discover the new current state after every apply.
+                .with_cmd_block(CmdBlockWrapper::new(
+                    StatesDiscoverCmdBlock::current_and_goal(),
+                    |_states_current_and_goal_mut| EnsureExecChange::None,
+                ));
 
             cmd_execution_builder = match apply_stored_state_sync {
                 ApplyStoredStateSync::None => cmd_execution_builder,
@@ -239,7 +525,8 @@ where
 
             cmd_execution_builder
                 .with_cmd_block(CmdBlockWrapper::new(
-                    ApplyExecCmdBlock::<CmdCtxTypesT, StatesTs>::new(),
+                    ApplyExecCmdBlock::<CmdCtxTypesT, StatesTs>::new()
+                        .with_apply_error_policy(apply_error_policy),
                     |(states_previous, states_applied, states_target): (
                         StatesPrevious,
                         States<StatesTs>,
@@ -294,19 +581,31 @@ where
     async fn serialize_current(
         item_graph: &ItemGraph<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
         resources: &Resources<SetUp>,
+        storage: &Storage,
         states_applied: &StatesEnsured,
     ) -> Result<(), <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError> {
-        use peace_rt_model::StatesSerializer;
+        use peace_rt_model::{ParamsDigests, ParamsDigestsSerializer, StatesSerializer};
 
         let flow_dir = resources.borrow::<FlowDir>();
-        let storage = resources.borrow::<Storage>();
         let states_current_file = StatesCurrentFile::from(&*flow_dir);
 
-        StatesSerializer::serialize(&storage, item_graph, states_applied, &states_current_file)
-            .await?;
+        // Only write the states that changed from those in the file.
+        StatesSerializer::serialize_delta(
+            storage,
+            item_graph,
+            states_applied,
+            &states_current_file,
+        )
+        .await?;
+
+        // Record the params that the current states were stored with.
+        if let Ok(params_digests) = resources.try_borrow::<ParamsDigests>() {
+            let params_digests_file = ParamsDigestsFile::from(&*flow_dir);
+            ParamsDigestsSerializer::serialize(storage, &params_digests, &params_digests_file)
+                .await?;
+        }
 
         drop(flow_dir);
-        drop(storage);
 
         Ok(())
     }
@@ -314,18 +613,17 @@ where
     async fn serialize_goal(
         item_graph: &ItemGraph<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
         resources: &Resources<SetUp>,
+        storage: &Storage,
         states_goal: &StatesGoal,
     ) -> Result<(), <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError> {
         use peace_rt_model::StatesSerializer;
 
         let flow_dir = resources.borrow::<FlowDir>();
-        let storage = resources.borrow::<Storage>();
         let states_goal_file = StatesGoalFile::from(&*flow_dir);
 
-        StatesSerializer::serialize(&storage, item_graph, states_goal, &states_goal_file).await?;
+        StatesSerializer::serialize(storage, item_graph, states_goal, &states_goal_file).await?;
 
         drop(flow_dir);
-        drop(storage);
 
         Ok(())
     }
@@ -0,0 +1,104 @@
+use peace_cfg::ItemId;
+use peace_fmt::{presentable::HeadingLevel, Presentable, Presenter};
+use serde::{Deserialize, Serialize};
+
+/// What an [`EnsureCmd::exec`] did to each item, grouped by outcome.
+///
+/// This is computed from each item's state before and after the apply, and
+/// is presented to the `OutputWrite` when the apply finishes.
+///
+/// [`EnsureCmd::exec`]: crate::cmds::EnsureCmd::exec
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApplySummary {
+    /// Item IDs that were in their clean state before the apply, and now exist.
+    item_ids_added: Vec<ItemId>,
+    /// Item IDs whose state was changed by the apply.
+    item_ids_changed: Vec<ItemId>,
+    /// Item IDs that were already in their goal state.
+    item_ids_unchanged: Vec<ItemId>,
+    /// Item IDs that failed to apply, or were blocked by a failed item.
+    item_ids_failed: Vec<ItemId>,
+}
+
+impl ApplySummary {
+    /// Returns a new `ApplySummary`.
+    pub fn new(
+        item_ids_added: Vec<ItemId>,
+        item_ids_changed: Vec<ItemId>,
+        item_ids_unchanged: Vec<ItemId>,
+        item_ids_failed: Vec<ItemId>,
+    ) -> Self {
+        Self {
+            item_ids_added,
+            item_ids_changed,
+            item_ids_unchanged,
+            item_ids_failed,
+        }
+    }
+
+    /// Returns the item IDs that were in their clean state before the apply,
+    /// and now exist.
+    pub fn item_ids_added(&self) -> &[ItemId] {
+        &self.item_ids_added
+    }
+
+    /// Returns the item IDs whose state was changed by the apply.
+    pub fn item_ids_changed(&self) -> &[ItemId] {
+        &self.item_ids_changed
+    }
+
+    /// Returns the item IDs that were already in their goal state.
+    pub fn item_ids_unchanged(&self) -> &[ItemId] {
+        &self.item_ids_unchanged
+    }
+
+    /// Returns the item IDs that failed to apply, or were blocked by a failed
+    /// item.
+    pub fn item_ids_failed(&self) -> &[ItemId] {
+        &self.item_ids_failed
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for ApplySummary {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        presenter
+            .heading(HeadingLevel::Level1, "Apply summary")
+            .await?;
+
+        let outcome = if self.item_ids_failed.is_empty() {
+            "Apply complete!"
+        } else {
+            "Apply finished with errors!"
+        };
+        presenter
+            .text(&format!(
+                "{outcome} Items: {} added, {} changed, {} unchanged, {} failed.\n\n",
+                self.item_ids_added.len(),
+                self.item_ids_changed.len(),
+                self.item_ids_unchanged.len(),
+                self.item_ids_failed.len(),
+            ))
+            .await?;
+
+        let sections = [
+            ("Added", &self.item_ids_added),
+            ("Changed", &self.item_ids_changed),
+            ("Unchanged", &self.item_ids_unchanged),
+            ("Failed", &self.item_ids_failed),
+        ];
+        for (heading, item_ids) in sections {
+            if item_ids.is_empty() {
+                continue;
+            }
+            presenter.heading(HeadingLevel::Level2, heading).await?;
+            presenter.list_bulleted(item_ids.iter()).await?;
+            presenter.text("\n").await?;
+        }
+
+        Ok(())
+    }
+}
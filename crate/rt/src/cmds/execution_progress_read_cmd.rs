@@ -0,0 +1,62 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::SingleProfileSingleFlow,
+};
+use peace_resources::paths::ExecutionProgressFile;
+use peace_rt_model::{ExecutionProgress, ExecutionProgressSerializer};
+use peace_rt_model_core::output::OutputWrite;
+
+/// Reads the progress of each item from the last command execution.
+///
+/// This is useful to render a "last run" summary in status commands, such as
+/// which items failed and how long each item took.
+#[derive(Debug)]
+pub struct ExecutionProgressReadCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> ExecutionProgressReadCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Reads the [`ExecutionProgress`] stored by the last command that
+    /// rendered progress for this flow, and presents it to the `OutputWrite`.
+    ///
+    /// Returns `None` if no command has stored its progress for this flow.
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+    ) -> Result<Option<ExecutionProgress>, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let execution_progress_result = {
+            let cmd_view = cmd_ctx.view();
+            let execution_progress_file = ExecutionProgressFile::from(cmd_view.flow_dir);
+
+            ExecutionProgressSerializer::deserialize_opt(
+                cmd_view.workspace.storage(),
+                &execution_progress_file,
+            )
+            .await
+        };
+        let output = cmd_ctx.output_mut();
+
+        match execution_progress_result {
+            Ok(Some(execution_progress)) => {
+                output.present(&execution_progress).await?;
+                Ok(Some(execution_progress))
+            }
+            Ok(None) => Ok(None),
+            Err(error) => {
+                output.write_err(&error).await?;
+                Err(error)
+            }
+        }
+    }
+}
+
+impl<CmdCtxTypesT> Default for ExecutionProgressReadCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
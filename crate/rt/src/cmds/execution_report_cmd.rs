@@ -0,0 +1,68 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::SingleProfileSingleFlow,
+};
+use peace_resources::paths::ExecutionTimelineFile;
+use peace_rt_model::{ExecutionTimeline, ExecutionTimelineSerializer};
+use peace_rt_model_core::output::OutputWrite;
+
+/// Reports when each item was executed in the last command execution.
+///
+/// This renders a Gantt-style timeline of each item's state discovery and
+/// apply, with how many items ran in parallel, the chain of items that
+/// determined how long the command took, and the slowest items. This helps
+/// to find items that could be restructured to run in parallel.
+#[derive(Debug)]
+pub struct ExecutionReportCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> ExecutionReportCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Reads the [`ExecutionTimeline`] stored by the last command that
+    /// discovered or applied items in this flow, and presents it to the
+    /// `OutputWrite`.
+    ///
+    /// Returns `None` if no command has stored a timeline for this flow.
+    ///
+    /// Use [`ExecutionTimeline::to_html`] on the returned timeline to render
+    /// it as HTML.
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+    ) -> Result<Option<ExecutionTimeline>, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let execution_timeline_result = {
+            let cmd_view = cmd_ctx.view();
+            let execution_timeline_file = ExecutionTimelineFile::from(cmd_view.flow_dir);
+
+            ExecutionTimelineSerializer::deserialize_opt(
+                cmd_view.workspace.storage(),
+                &execution_timeline_file,
+            )
+            .await
+        };
+        let output = cmd_ctx.output_mut();
+
+        match execution_timeline_result {
+            Ok(Some(execution_timeline)) => {
+                output.present(&execution_timeline).await?;
+                Ok(Some(execution_timeline))
+            }
+            Ok(None) => Ok(None),
+            Err(error) => {
+                output.write_err(&error).await?;
+                Err(error)
+            }
+        }
+    }
+}
+
+impl<CmdCtxTypesT> Default for ExecutionReportCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
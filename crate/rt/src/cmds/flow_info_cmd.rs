@@ -0,0 +1,43 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::SingleProfileSingleFlow,
+};
+use peace_rt_model::FlowMetadata;
+use peace_rt_model_core::output::OutputWrite;
+
+/// Emits a serializable description of the flow's items and the dependencies
+/// between them.
+///
+/// Web frontends and documentation tooling can render the flow from this
+/// document without linking the item types.
+#[derive(Debug)]
+pub struct FlowInfoCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> FlowInfoCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Presents the [`FlowMetadata`] of the flow to the `OutputWrite`.
+    ///
+    /// Params schemas are only included when the `item_schema` feature is
+    /// enabled.
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+    ) -> Result<FlowMetadata, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let flow_metadata = cmd_ctx.view().flow.metadata();
+        cmd_ctx.output_mut().present(&flow_metadata).await?;
+
+        Ok(flow_metadata)
+    }
+}
+
+impl<CmdCtxTypesT> Default for FlowInfoCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
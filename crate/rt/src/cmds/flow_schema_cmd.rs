@@ -0,0 +1,45 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::SingleProfileSingleFlow,
+};
+use peace_rt_model::FlowSchema;
+use peace_rt_model_core::output::OutputWrite;
+
+/// Emits the JSON Schema of each item's `Params`, `State`, and `StateDiff` in
+/// the flow.
+///
+/// The schemas are combined into a single document, so that web UIs and
+/// validation tooling can be generated against the flow's data model.
+#[derive(Debug)]
+pub struct FlowSchemaCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> FlowSchemaCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Presents the [`FlowSchema`] of the flow to the `OutputWrite`.
+    ///
+    /// Items that do not implement [`Item::schema`] accept any value in the
+    /// schema document.
+    ///
+    /// [`Item::schema`]: peace_cfg::Item::schema
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+    ) -> Result<FlowSchema, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let flow_schema = cmd_ctx.view().flow.schema();
+        cmd_ctx.output_mut().present(&flow_schema).await?;
+
+        Ok(flow_schema)
+    }
+}
+
+impl<CmdCtxTypesT> Default for FlowSchemaCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
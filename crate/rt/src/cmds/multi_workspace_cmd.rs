@@ -0,0 +1,78 @@
+use std::future::Future;
+
+use futures::future;
+use indexmap::IndexMap;
+use peace_cmd_model::CmdOutcome;
+use peace_rt_model::{Workspace, WorkspaceSet};
+
+pub use self::{
+    multi_workspace_cmd_outcome::MultiWorkspaceCmdOutcome,
+    workspace_set_progress::WorkspaceSetProgress, workspace_status::WorkspaceStatus,
+};
+
+mod multi_workspace_cmd_outcome;
+mod workspace_set_progress;
+mod workspace_status;
+
+/// Runs the same command in each workspace of a [`WorkspaceSet`]
+/// concurrently.
+///
+/// The command for each workspace is run by `cmd_fn`, which receives the
+/// workspace name and [`Workspace`]. It should build a `CmdCtx` for that
+/// workspace -- with its own `OutputWrite` -- and run the command, e.g.
+/// `EnsureCmd::exec`.
+///
+/// Outcomes are collected per workspace; an error in one workspace does not
+/// stop the command in other workspaces.
+#[derive(Debug)]
+pub struct MultiWorkspaceCmd;
+
+impl MultiWorkspaceCmd {
+    /// Runs the command in each workspace, returning each workspace's outcome.
+    pub async fn exec<'ws, T, E, F, Fut>(
+        workspace_set: &'ws WorkspaceSet,
+        cmd_fn: F,
+    ) -> MultiWorkspaceCmdOutcome<T, E>
+    where
+        F: Fn(&'ws str, &'ws Workspace) -> Fut,
+        Fut: Future<Output = Result<CmdOutcome<T, E>, E>>,
+    {
+        let workspace_set_progress = WorkspaceSetProgress::new(workspace_set);
+        Self::exec_with_progress(workspace_set, &workspace_set_progress, cmd_fn).await
+    }
+
+    /// Runs the command in each workspace, updating `workspace_set_progress`
+    /// as each workspace starts and finishes.
+    ///
+    /// `workspace_set_progress` should be created from the same
+    /// `workspace_set`; statuses of workspaces that it does not track are not
+    /// recorded.
+    pub async fn exec_with_progress<'ws, T, E, F, Fut>(
+        workspace_set: &'ws WorkspaceSet,
+        workspace_set_progress: &WorkspaceSetProgress,
+        cmd_fn: F,
+    ) -> MultiWorkspaceCmdOutcome<T, E>
+    where
+        F: Fn(&'ws str, &'ws Workspace) -> Fut,
+        Fut: Future<Output = Result<CmdOutcome<T, E>, E>>,
+    {
+        let cmd_fn = &cmd_fn;
+        let cmd_outcomes = future::join_all(workspace_set.iter().map(
+            |(workspace_name, workspace)| async move {
+                workspace_set_progress.status_set(workspace_name, WorkspaceStatus::Running);
+
+                let cmd_outcome_result = cmd_fn(workspace_name, workspace).await;
+
+                workspace_set_progress
+                    .status_set(workspace_name, WorkspaceStatus::from(&cmd_outcome_result));
+
+                (workspace_name.clone(), cmd_outcome_result)
+            },
+        ))
+        .await
+        .into_iter()
+        .collect::<IndexMap<String, Result<CmdOutcome<T, E>, E>>>();
+
+        MultiWorkspaceCmdOutcome::new(cmd_outcomes)
+    }
+}
@@ -0,0 +1,58 @@
+use std::ops::Deref;
+
+use indexmap::IndexMap;
+use peace_cmd_model::CmdOutcome;
+
+/// Outcome of running a command in each workspace of a `WorkspaceSet`,
+/// returned by [`MultiWorkspaceCmd`].
+///
+/// This derefs to the map of workspace name to that workspace's command
+/// outcome, in the order the workspaces were added to the set.
+///
+/// [`MultiWorkspaceCmd`]: crate::cmds::MultiWorkspaceCmd
+#[derive(Debug)]
+pub struct MultiWorkspaceCmdOutcome<T, E>(IndexMap<String, Result<CmdOutcome<T, E>, E>>);
+
+impl<T, E> MultiWorkspaceCmdOutcome<T, E> {
+    /// Returns a new `MultiWorkspaceCmdOutcome`.
+    pub fn new(cmd_outcomes: IndexMap<String, Result<CmdOutcome<T, E>, E>>) -> Self {
+        Self(cmd_outcomes)
+    }
+
+    /// Returns whether the command completed successfully in every workspace.
+    pub fn is_complete(&self) -> bool {
+        self.0.values().all(|cmd_outcome_result| {
+            cmd_outcome_result
+                .as_ref()
+                .is_ok_and(CmdOutcome::is_complete)
+        })
+    }
+
+    /// Returns the names of workspaces whose command returned an error, or had
+    /// item errors.
+    pub fn workspace_names_errored(&self) -> Vec<&str> {
+        self.0
+            .iter()
+            .filter_map(|(workspace_name, cmd_outcome_result)| {
+                let errored = match cmd_outcome_result {
+                    Ok(cmd_outcome) => cmd_outcome.is_err(),
+                    Err(_error) => true,
+                };
+                errored.then_some(workspace_name.as_str())
+            })
+            .collect()
+    }
+
+    /// Returns the underlying map.
+    pub fn into_inner(self) -> IndexMap<String, Result<CmdOutcome<T, E>, E>> {
+        self.0
+    }
+}
+
+impl<T, E> Deref for MultiWorkspaceCmdOutcome<T, E> {
+    type Target = IndexMap<String, Result<CmdOutcome<T, E>, E>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
@@ -0,0 +1,64 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use indexmap::IndexMap;
+use peace_rt_model::WorkspaceSet;
+
+use crate::cmds::WorkspaceStatus;
+
+/// Tracks the execution status of a command in each workspace of a
+/// [`WorkspaceSet`].
+///
+/// This is cheap to clone -- clones share the same statuses -- so it may be
+/// polled while [`MultiWorkspaceCmd::exec_with_progress`] is running to render
+/// which workspaces are still in progress.
+///
+/// [`MultiWorkspaceCmd::exec_with_progress`]: crate::cmds::MultiWorkspaceCmd::exec_with_progress
+#[derive(Clone, Debug)]
+pub struct WorkspaceSetProgress(Arc<Mutex<IndexMap<String, WorkspaceStatus>>>);
+
+impl WorkspaceSetProgress {
+    /// Returns a new `WorkspaceSetProgress` with each workspace in the set
+    /// marked as [`WorkspaceStatus::Pending`].
+    pub fn new(workspace_set: &WorkspaceSet) -> Self {
+        let workspace_statuses = workspace_set
+            .keys()
+            .map(|workspace_name| (workspace_name.clone(), WorkspaceStatus::Pending))
+            .collect::<IndexMap<String, WorkspaceStatus>>();
+
+        Self(Arc::new(Mutex::new(workspace_statuses)))
+    }
+
+    /// Returns the status of the named workspace, if it is tracked.
+    pub fn status(&self, workspace_name: &str) -> Option<WorkspaceStatus> {
+        self.lock().get(workspace_name).copied()
+    }
+
+    /// Returns a snapshot of the status of every workspace.
+    pub fn statuses(&self) -> IndexMap<String, WorkspaceStatus> {
+        self.lock().clone()
+    }
+
+    /// Returns whether every workspace has finished running the command,
+    /// successfully or otherwise.
+    pub fn is_finished(&self) -> bool {
+        self.lock().values().all(|workspace_status| {
+            !matches!(
+                workspace_status,
+                WorkspaceStatus::Pending | WorkspaceStatus::Running
+            )
+        })
+    }
+
+    /// Sets the status of the named workspace.
+    pub(crate) fn status_set(&self, workspace_name: &str, workspace_status: WorkspaceStatus) {
+        if let Some(status) = self.lock().get_mut(workspace_name) {
+            *status = workspace_status;
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, IndexMap<String, WorkspaceStatus>> {
+        self.0
+            .lock()
+            .expect("Expected `WorkspaceSetProgress` lock to not be poisoned.")
+    }
+}
@@ -0,0 +1,36 @@
+use peace_cmd_model::CmdOutcome;
+use serde::{Deserialize, Serialize};
+
+/// Execution status of a command within one workspace of a `WorkspaceSet`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WorkspaceStatus {
+    /// The command has not started in this workspace.
+    Pending,
+    /// The command is running in this workspace.
+    Running,
+    /// The command completed successfully.
+    Complete,
+    /// The command was interrupted.
+    Interrupted,
+    /// The command ran, but one or more items failed.
+    ItemError,
+    /// The command returned an error.
+    Error,
+}
+
+impl<T, E> From<&Result<CmdOutcome<T, E>, E>> for WorkspaceStatus {
+    fn from(cmd_outcome_result: &Result<CmdOutcome<T, E>, E>) -> Self {
+        match cmd_outcome_result {
+            Ok(cmd_outcome) => {
+                if cmd_outcome.is_complete() {
+                    WorkspaceStatus::Complete
+                } else if cmd_outcome.is_interrupted() {
+                    WorkspaceStatus::Interrupted
+                } else {
+                    WorkspaceStatus::ItemError
+                }
+            }
+            Err(_error) => WorkspaceStatus::Error,
+        }
+    }
+}
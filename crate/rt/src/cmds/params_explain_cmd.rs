@@ -0,0 +1,84 @@
+use std::marker::PhantomData;
+
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::{SingleProfileSingleFlow, SingleProfileSingleFlowViewAndOutput},
+};
+use peace_params::ValueResolutionMode;
+use peace_rt_model_core::output::OutputWrite;
+
+pub use self::params_explanations::ParamsExplanations;
+
+mod params_explanations;
+
+/// Displays each item's resolved params, and where each value came from.
+///
+/// Values are resolved from the params specs stored for the profile and flow,
+/// and from the resources inserted into the `CmdCtx`.
+#[derive(Debug)]
+pub struct ParamsExplainCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> ParamsExplainCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Returns each item's resolved params, resolved for the goal state.
+    ///
+    /// The returned [`ParamsExplanations`] are also presented to the
+    /// `OutputWrite`.
+    ///
+    /// Values mapped from another item's state are only resolved if that state
+    /// has been inserted into `resources`, such as after running
+    /// `StatesDiscoverCmd`. Otherwise they are listed without a value.
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+    ) -> Result<ParamsExplanations, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        Self::exec_with_value_resolution_mode(cmd_ctx, ValueResolutionMode::Goal).await
+    }
+
+    /// Returns each item's resolved params, resolved for the given
+    /// [`ValueResolutionMode`].
+    ///
+    /// See [`ParamsExplainCmd::exec`] for details.
+    pub async fn exec_with_value_resolution_mode<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+        value_resolution_mode: ValueResolutionMode,
+    ) -> Result<ParamsExplanations, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let SingleProfileSingleFlowViewAndOutput {
+            output, cmd_view, ..
+        } = cmd_ctx.view_and_output();
+
+        let flow = cmd_view.flow;
+        let params_specs = cmd_view.params_specs;
+        let resources = &*cmd_view.resources;
+        let params_explanations_result = flow
+            .graph()
+            .iter_insertion()
+            .map(|item| item.params_explain(params_specs, resources, value_resolution_mode))
+            .collect::<Result<Vec<_>, _>>();
+
+        match params_explanations_result {
+            Ok(item_params_explanations) => {
+                let params_explanations = ParamsExplanations::new(item_params_explanations);
+                output.present(&params_explanations).await?;
+                Ok(params_explanations)
+            }
+            Err(error) => {
+                output.write_err(&error).await?;
+                Err(error)
+            }
+        }
+    }
+}
+
+impl<CmdCtxTypesT> Default for ParamsExplainCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
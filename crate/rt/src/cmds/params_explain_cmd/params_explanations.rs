@@ -0,0 +1,47 @@
+use std::ops::Deref;
+
+use peace_fmt::{presentable::HeadingLevel, Presentable, Presenter};
+use peace_rt_model::ItemParamsExplanation;
+use serde::Serialize;
+
+/// Resolved params for each item in a flow, returned by [`ParamsExplainCmd`].
+///
+/// This derefs to the list of [`ItemParamsExplanation`]s.
+///
+/// [`ParamsExplainCmd`]: crate::cmds::ParamsExplainCmd
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ParamsExplanations(Vec<ItemParamsExplanation>);
+
+impl ParamsExplanations {
+    /// Returns a new `ParamsExplanations`.
+    pub fn new(item_params_explanations: Vec<ItemParamsExplanation>) -> Self {
+        Self(item_params_explanations)
+    }
+
+    /// Returns the inner `Vec<ItemParamsExplanation>`.
+    pub fn into_inner(self) -> Vec<ItemParamsExplanation> {
+        self.0
+    }
+}
+
+impl Deref for ParamsExplanations {
+    type Target = [ItemParamsExplanation];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for ParamsExplanations {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        presenter.heading(HeadingLevel::Level1, "Params").await?;
+        for item_params_explanation in self.0.iter() {
+            item_params_explanation.present(presenter).await?;
+        }
+        Ok(())
+    }
+}
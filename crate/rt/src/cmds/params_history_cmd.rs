@@ -0,0 +1,184 @@
+use std::marker::PhantomData;
+
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::{SingleProfileNoFlow, SingleProfileNoFlowView},
+};
+use peace_resources::{
+    internal::{FlowParamsFile, ProfileParamsFile, WorkspaceParamsFile},
+    paths::{ParamsHistoryFile, ParamsRevisionDir, ProfileHistoryDir},
+};
+use peace_rt_model::{
+    ParamChange, ParamsHistory, ParamsHistorySerializer, ParamsKind, ParamsRevisionDiff, Storage,
+};
+use peace_rt_model_core::output::OutputWrite;
+
+/// Lists the revisions of a profile's params, and shows the changes between
+/// them.
+///
+/// A revision is recorded whenever a command context is built for the profile
+/// with params that differ from the most recent revision. Revisions can be
+/// loaded with `SingleProfileSingleFlowBuilder::with_params_at` to reproduce a
+/// previous run.
+#[derive(Debug)]
+pub struct ParamsHistoryCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> ParamsHistoryCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Returns the params revisions recorded for the profile.
+    ///
+    /// The returned [`ParamsHistory`] is also presented to the `OutputWrite`.
+    pub async fn list<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileNoFlow<'ctx, CmdCtxTypesT>>,
+    ) -> Result<ParamsHistory, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let SingleProfileNoFlowView {
+            output,
+            workspace,
+            profile_history_dir,
+            ..
+        } = cmd_ctx.view();
+
+        let params_history_result =
+            Self::params_history_read(workspace.storage(), profile_history_dir).await;
+
+        match params_history_result {
+            Ok(params_history) => {
+                output.present(&params_history).await?;
+                Ok(params_history)
+            }
+            Err(error) => {
+                let error = <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError::from(error);
+                output.write_err(&error).await?;
+                Err(error)
+            }
+        }
+    }
+
+    /// Returns the changes to params from `revision_from` to `revision_to`.
+    ///
+    /// The returned [`ParamsRevisionDiff`] is also presented to the
+    /// `OutputWrite`.
+    pub async fn diff<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileNoFlow<'ctx, CmdCtxTypesT>>,
+        revision_from: u64,
+        revision_to: u64,
+    ) -> Result<ParamsRevisionDiff, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let SingleProfileNoFlowView {
+            output,
+            workspace,
+            profile_history_dir,
+            ..
+        } = cmd_ctx.view();
+
+        let params_revision_diff_result = Self::params_revision_diff(
+            workspace.storage(),
+            profile_history_dir,
+            revision_from,
+            revision_to,
+        )
+        .await;
+
+        match params_revision_diff_result {
+            Ok(params_revision_diff) => {
+                output.present(&params_revision_diff).await?;
+                Ok(params_revision_diff)
+            }
+            Err(error) => {
+                let error = <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError::from(error);
+                output.write_err(&error).await?;
+                Err(error)
+            }
+        }
+    }
+
+    async fn params_history_read(
+        storage: &Storage,
+        profile_history_dir: &ProfileHistoryDir,
+    ) -> Result<ParamsHistory, peace_rt_model::Error> {
+        let params_history_file = ParamsHistoryFile::from(profile_history_dir);
+        let params_history = ParamsHistorySerializer::<peace_rt_model::Error>::deserialize_opt(
+            storage,
+            &params_history_file,
+        )
+        .await?
+        .unwrap_or_default();
+
+        Ok(params_history)
+    }
+
+    async fn params_revision_diff(
+        storage: &Storage,
+        profile_history_dir: &ProfileHistoryDir,
+        revision_from: u64,
+        revision_to: u64,
+    ) -> Result<ParamsRevisionDiff, peace_rt_model::Error> {
+        let params_history = Self::params_history_read(storage, profile_history_dir).await?;
+        [revision_from, revision_to]
+            .into_iter()
+            .try_for_each(|revision| {
+                params_history
+                    .revision(revision)
+                    .map(|_| ())
+                    .ok_or(peace_rt_model::Error::ParamsRevisionNotFound { revision })
+            })?;
+
+        let params_revision_dir_from =
+            ParamsRevisionDir::from((profile_history_dir, revision_from));
+        let params_revision_dir_to = ParamsRevisionDir::from((profile_history_dir, revision_to));
+
+        let mut param_changes = Vec::new();
+        for params_kind in [ParamsKind::Workspace, ParamsKind::Profile, ParamsKind::Flow] {
+            let params_from = Self::revision_params_read(
+                storage,
+                &params_revision_dir_from,
+                revision_from,
+                params_kind,
+            )
+            .await?;
+            let params_to = Self::revision_params_read(
+                storage,
+                &params_revision_dir_to,
+                revision_to,
+                params_kind,
+            )
+            .await?;
+
+            param_changes.extend(ParamChange::changes(params_kind, &params_from, &params_to));
+        }
+
+        Ok(ParamsRevisionDiff::new(
+            revision_from,
+            revision_to,
+            param_changes,
+        ))
+    }
+
+    async fn revision_params_read(
+        storage: &Storage,
+        params_revision_dir: &ParamsRevisionDir,
+        revision: u64,
+        params_kind: ParamsKind,
+    ) -> Result<serde_yaml::Mapping, peace_rt_model::Error> {
+        let params_file = match params_kind {
+            ParamsKind::Workspace => WorkspaceParamsFile::from(params_revision_dir).into_inner(),
+            ParamsKind::Profile => ProfileParamsFile::from(params_revision_dir).into_inner(),
+            ParamsKind::Flow => FlowParamsFile::from(params_revision_dir).into_inner(),
+        };
+
+        ParamsHistorySerializer::revision_params_deserialize(storage, revision, &params_file).await
+    }
+}
+
+impl<CmdCtxTypesT> Default for ParamsHistoryCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
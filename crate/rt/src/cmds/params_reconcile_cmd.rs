@@ -0,0 +1,254 @@
+use std::marker::PhantomData;
+
+use peace_cfg::{ItemId, Profile};
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::{SingleProfileNoFlow, SingleProfileNoFlowView},
+};
+use peace_resources::paths::{FlowDir, ParamsSpecsFile, ProfileDir};
+use peace_rt_model::{Flow, ParamsSpecsSerializer, Storage};
+use peace_rt_model_core::{output::OutputWrite, IndexMap};
+
+pub use self::{
+    params_reconcile_outcome::ParamsReconcileOutcome, params_reconciler::ParamsReconciler,
+    params_reconciliation::ParamsReconciliation, params_spec_orphan::ParamsSpecOrphan,
+    params_spec_orphan_resolution::ParamsSpecOrphanResolution,
+};
+
+mod params_reconcile_outcome;
+mod params_reconciler;
+mod params_reconciliation;
+mod params_spec_orphan;
+mod params_spec_orphan_resolution;
+
+/// Reconciles a flow's stored params specs with the items in the flow.
+///
+/// When an item is renamed or removed, its stored params spec no longer
+/// corresponds to any item in the flow, and building a
+/// `SingleProfileSingleFlow` command context returns a `ParamsSpecsMismatch`
+/// error. This command rewrites the `ParamsSpecsFile` so that:
+///
+/// * Params specs for renamed items are moved to the new item ID.
+/// * Params specs for removed items are dropped.
+/// * Items without a params spec are given one.
+///
+/// How each mismatch is resolved is given by the [`ParamsReconciliation`],
+/// which may prompt the user through a [`ParamsReconciler`]. Passing
+/// `ParamsReconciliation::new()` leaves the file unchanged, and returns the
+/// mismatches.
+///
+/// This uses a `SingleProfileNoFlow` command context, as a
+/// `SingleProfileSingleFlow` command context cannot be built while the params
+/// specs are mismatched.
+#[derive(Debug)]
+pub struct ParamsReconcileCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> ParamsReconcileCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Reconciles the flow's stored params specs with the items in the flow.
+    ///
+    /// The returned [`ParamsReconcileOutcome`] is also presented to the
+    /// `OutputWrite`.
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileNoFlow<'ctx, CmdCtxTypesT>>,
+        flow: &Flow<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        params_reconciliation: &ParamsReconciliation,
+    ) -> Result<ParamsReconcileOutcome, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let SingleProfileNoFlowView {
+            output,
+            workspace,
+            profile,
+            profile_dir,
+            ..
+        } = cmd_ctx.view();
+
+        let params_reconcile_outcome_result = Self::reconcile(
+            workspace.storage(),
+            profile,
+            profile_dir,
+            flow,
+            params_reconciliation,
+        )
+        .await;
+
+        match params_reconcile_outcome_result {
+            Ok(params_reconcile_outcome) => {
+                output.present(&params_reconcile_outcome).await?;
+                Ok(params_reconcile_outcome)
+            }
+            Err(error) => {
+                let error = <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError::from(error);
+                output.write_err(&error).await?;
+                Err(error)
+            }
+        }
+    }
+
+    async fn reconcile(
+        storage: &Storage,
+        profile: &Profile,
+        profile_dir: &ProfileDir,
+        flow: &Flow<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        params_reconciliation: &ParamsReconciliation,
+    ) -> Result<ParamsReconcileOutcome, peace_rt_model::Error> {
+        let flow_id = flow.flow_id();
+        let flow_dir = FlowDir::from((profile_dir, flow_id));
+        let params_specs_file = ParamsSpecsFile::from(&flow_dir);
+
+        let item_ids_flow = flow
+            .graph()
+            .iter_insertion()
+            .map(|item| item.id().clone())
+            .collect::<Vec<ItemId>>();
+        let mut params_specs_stored =
+            ParamsSpecsSerializer::<peace_rt_model::Error>::deserialize_raw_opt(
+                profile,
+                flow_id,
+                storage,
+                &params_specs_file,
+            )
+            .await?
+            .unwrap_or_default();
+
+        Self::params_reconciliation_validate(
+            &item_ids_flow,
+            &params_specs_stored,
+            params_reconciliation,
+        )?;
+
+        let mut params_reconcile_outcome = ParamsReconcileOutcome::default();
+        let mut item_ids_missing = item_ids_flow
+            .iter()
+            .filter(|item_id| !params_specs_stored.contains_key(*item_id))
+            .cloned()
+            .collect::<Vec<ItemId>>();
+
+        // Params specs for items that did not have a stored params spec.
+        let mut params_specs_new = IndexMap::<ItemId, serde_yaml::Value>::new();
+        // Params specs whose item IDs are not in the flow, which are kept.
+        let mut params_specs_orphaned = IndexMap::<ItemId, serde_yaml::Value>::new();
+
+        let item_ids_orphaned = params_specs_stored
+            .keys()
+            .filter(|item_id| !item_ids_flow.contains(item_id))
+            .cloned()
+            .collect::<Vec<ItemId>>();
+        for item_id in item_ids_orphaned {
+            let Some(params_spec) = params_specs_stored.shift_remove(&item_id) else {
+                continue;
+            };
+            let params_spec_orphan_resolution = params_reconciliation
+                .orphan_resolve(&item_id, &item_ids_missing)
+                .await;
+
+            match params_spec_orphan_resolution {
+                ParamsSpecOrphanResolution::Rename(item_id_to) => {
+                    if !item_ids_flow.contains(&item_id_to) {
+                        return Err(peace_rt_model::Error::ParamsReconcileItemIdNotInFlow {
+                            item_id: item_id_to,
+                        });
+                    }
+                    if !item_ids_missing.contains(&item_id_to) {
+                        return Err(peace_rt_model::Error::ParamsReconcileRenameConflict {
+                            item_id_from: item_id,
+                            item_id_to,
+                        });
+                    }
+
+                    item_ids_missing.retain(|item_id_missing| item_id_missing != &item_id_to);
+                    params_specs_new.insert(item_id_to.clone(), params_spec);
+                    params_reconcile_outcome
+                        .item_ids_renamed
+                        .insert(item_id, item_id_to);
+                }
+                ParamsSpecOrphanResolution::Drop => {
+                    params_reconcile_outcome.item_ids_dropped.push(item_id);
+                }
+                ParamsSpecOrphanResolution::Keep => {
+                    params_specs_orphaned.insert(item_id.clone(), params_spec);
+                    params_reconcile_outcome.item_ids_orphaned.push(item_id);
+                }
+            }
+        }
+
+        // Provided params specs are stored in the params specs file.
+        let mut params_specs_provided = storage
+            .sensitive_serde_mode(&params_specs_file)
+            .scope(|| serde_yaml::to_value(params_reconciliation.params_specs()))
+            .and_then(serde_yaml::from_value::<IndexMap<ItemId, serde_yaml::Value>>)
+            .map_err(peace_rt_model::Error::ParamsSpecsSerialize)?;
+        item_ids_missing.retain(|item_id| {
+            if let Some(params_spec) = params_specs_provided.shift_remove(item_id) {
+                params_specs_new.insert(item_id.clone(), params_spec);
+                params_reconcile_outcome
+                    .item_ids_filled
+                    .push(item_id.clone());
+                false
+            } else {
+                true
+            }
+        });
+        params_reconcile_outcome.item_ids_missing = item_ids_missing;
+
+        if params_reconcile_outcome.is_changed() {
+            // Params specs are written in the order of items in the flow, followed by
+            // params specs that were kept.
+            let params_specs_reconciled = item_ids_flow
+                .iter()
+                .filter_map(|item_id| {
+                    params_specs_stored
+                        .shift_remove_entry(item_id)
+                        .or_else(|| params_specs_new.shift_remove_entry(item_id))
+                })
+                .chain(params_specs_orphaned)
+                .collect::<IndexMap<ItemId, serde_yaml::Value>>();
+
+            ParamsSpecsSerializer::<peace_rt_model::Error>::serialize_raw(
+                storage,
+                &params_specs_reconciled,
+                &params_specs_file,
+            )
+            .await?;
+        }
+
+        Ok(params_reconcile_outcome)
+    }
+
+    /// Returns an error if the reconciliation refers to item IDs that are not
+    /// in the flow, or renames item IDs that are not stored.
+    fn params_reconciliation_validate(
+        item_ids_flow: &[ItemId],
+        params_specs_stored: &IndexMap<ItemId, serde_yaml::Value>,
+        params_reconciliation: &ParamsReconciliation,
+    ) -> Result<(), peace_rt_model::Error> {
+        let item_id_not_in_flow = params_reconciliation
+            .item_id_renames()
+            .values()
+            .chain(params_reconciliation.params_specs().keys())
+            .find(|item_id| !item_ids_flow.contains(item_id));
+        if let Some(item_id) = item_id_not_in_flow {
+            return Err(peace_rt_model::Error::ParamsReconcileItemIdNotInFlow {
+                item_id: item_id.clone(),
+            });
+        }
+
+        let item_id_not_stored = params_reconciliation
+            .item_id_renames()
+            .keys()
+            .find(|item_id| {
+                !params_specs_stored.contains_key(*item_id) || item_ids_flow.contains(item_id)
+            });
+        if let Some(item_id) = item_id_not_stored {
+            return Err(peace_rt_model::Error::ParamsReconcileItemIdNotStored {
+                item_id: item_id.clone(),
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use peace_cfg::ItemId;
+use peace_fmt::{presentable::HeadingLevel, Presentable, Presenter};
+use peace_rt_model_core::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// Changes made to the stored params specs by [`ParamsReconcileCmd`], and
+/// mismatches that remain.
+///
+/// [`ParamsReconcileCmd`]: crate::cmds::ParamsReconcileCmd
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParamsReconcileOutcome {
+    /// Stored item IDs that were renamed, and the item IDs they were renamed
+    /// to.
+    pub item_ids_renamed: IndexMap<ItemId, ItemId>,
+    /// Stored item IDs whose params specs were dropped.
+    pub item_ids_dropped: Vec<ItemId>,
+    /// Item IDs whose params specs were filled in.
+    pub item_ids_filled: Vec<ItemId>,
+    /// Stored item IDs that are still not in the flow.
+    pub item_ids_orphaned: Vec<ItemId>,
+    /// Item IDs in the flow that still do not have a stored params spec.
+    pub item_ids_missing: Vec<ItemId>,
+}
+
+impl ParamsReconcileOutcome {
+    /// Returns whether the stored params specs were changed.
+    pub fn is_changed(&self) -> bool {
+        !self.item_ids_renamed.is_empty()
+            || !self.item_ids_dropped.is_empty()
+            || !self.item_ids_filled.is_empty()
+    }
+
+    /// Returns whether every stored params spec corresponds to an item in the
+    /// flow, and every item in the flow has a stored params spec.
+    pub fn is_reconciled(&self) -> bool {
+        self.item_ids_orphaned.is_empty() && self.item_ids_missing.is_empty()
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for ParamsReconcileOutcome {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        if !self.is_changed() && self.is_reconciled() {
+            return presenter
+                .text("Params specs match the items in the flow.")
+                .await;
+        }
+
+        if !self.item_ids_renamed.is_empty() {
+            presenter.heading(HeadingLevel::Level1, "Renamed").await?;
+            presenter
+                .list_bulleted_with(
+                    self.item_ids_renamed.iter(),
+                    |(item_id_from, item_id_to)| format!("{item_id_from} -> {item_id_to}"),
+                )
+                .await?;
+        }
+
+        let sections = [
+            ("Dropped", &self.item_ids_dropped),
+            ("Filled", &self.item_ids_filled),
+            ("Not in flow", &self.item_ids_orphaned),
+            ("Missing params specs", &self.item_ids_missing),
+        ];
+        for (heading, item_ids) in sections {
+            if item_ids.is_empty() {
+                continue;
+            }
+            presenter.heading(HeadingLevel::Level1, heading).await?;
+            presenter.list_bulleted(item_ids.iter()).await?;
+        }
+
+        Ok(())
+    }
+}
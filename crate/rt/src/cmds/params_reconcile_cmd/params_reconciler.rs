@@ -0,0 +1,58 @@
+use std::{
+    fmt::{self, Debug},
+    sync::Arc,
+};
+
+use futures::future::BoxFuture;
+
+use crate::cmds::{ParamsSpecOrphan, ParamsSpecOrphanResolution};
+
+/// Decides what to do with each stored params spec whose item ID is not in the
+/// flow.
+///
+/// The reconciliation waits for the returned future, so this may prompt the
+/// user interactively.
+pub struct ParamsReconciler(
+    #[allow(clippy::type_complexity)]
+    Arc<dyn Fn(ParamsSpecOrphan) -> BoxFuture<'static, ParamsSpecOrphanResolution> + Send + Sync>,
+);
+
+impl ParamsReconciler {
+    /// Returns a new `ParamsReconciler`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let params_reconciler = ParamsReconciler::new(|params_spec_orphan| {
+    ///     Box::pin(async move { prompt_rename_or_drop(&params_spec_orphan).await })
+    /// });
+    /// ```
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(ParamsSpecOrphan) -> BoxFuture<'static, ParamsSpecOrphanResolution>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self(Arc::new(f))
+    }
+
+    /// Invokes this reconciler.
+    pub async fn call(&self, params_spec_orphan: ParamsSpecOrphan) -> ParamsSpecOrphanResolution {
+        (self.0)(params_spec_orphan).await
+    }
+}
+
+impl Clone for ParamsReconciler {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl Debug for ParamsReconciler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ParamsReconciler")
+            .field(&"Fn(ParamsSpecOrphan) -> BoxFuture<'static, ParamsSpecOrphanResolution>")
+            .finish()
+    }
+}
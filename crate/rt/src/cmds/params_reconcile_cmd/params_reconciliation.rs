@@ -0,0 +1,111 @@
+use peace_cfg::{Item, ItemId};
+use peace_params::{Params, ParamsSpecs};
+use peace_rt_model_core::IndexMap;
+
+use crate::cmds::{ParamsReconciler, ParamsSpecOrphanResolution};
+
+/// How to reconcile stored params specs with the items in a flow.
+///
+/// Each stored params spec whose item ID is not in the flow is resolved in the
+/// following order:
+///
+/// 1. If it is in [`with_item_id_rename`], it is renamed.
+/// 2. If there is a [`ParamsReconciler`], the reconciler decides.
+/// 3. If [`with_orphans_drop`] is `true`, it is dropped.
+/// 4. Otherwise it is kept.
+///
+/// Items in the flow that have no params spec may be given one through
+/// [`with_item_params`].
+///
+/// [`with_item_id_rename`]: Self::with_item_id_rename
+/// [`with_item_params`]: Self::with_item_params
+/// [`with_orphans_drop`]: Self::with_orphans_drop
+#[derive(Debug, Default)]
+pub struct ParamsReconciliation {
+    /// Stored item IDs to rename, and the item IDs to rename them to.
+    item_id_renames: IndexMap<ItemId, ItemId>,
+    /// Whether to drop params specs that are not renamed.
+    orphans_drop: bool,
+    /// Decides what to do with params specs that are not renamed.
+    params_reconciler: Option<ParamsReconciler>,
+    /// Params specs for items that do not have a stored params spec.
+    params_specs: ParamsSpecs,
+}
+
+impl ParamsReconciliation {
+    /// Returns a new `ParamsReconciliation` that keeps all params specs.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves the stored params spec for `item_id_from` to `item_id_to`.
+    pub fn with_item_id_rename(mut self, item_id_from: ItemId, item_id_to: ItemId) -> Self {
+        self.item_id_renames.insert(item_id_from, item_id_to);
+        self
+    }
+
+    /// Sets whether to drop stored params specs whose item IDs are not in the
+    /// flow, and are not renamed.
+    pub fn with_orphans_drop(mut self, orphans_drop: bool) -> Self {
+        self.orphans_drop = orphans_drop;
+        self
+    }
+
+    /// Sets the reconciler that decides what to do with stored params specs
+    /// whose item IDs are not in the flow.
+    ///
+    /// This is used to prompt the user interactively.
+    pub fn with_params_reconciler(mut self, params_reconciler: ParamsReconciler) -> Self {
+        self.params_reconciler = Some(params_reconciler);
+        self
+    }
+
+    /// Sets the params spec for an item that does not have a stored params
+    /// spec.
+    pub fn with_item_params<I>(
+        mut self,
+        item_id: ItemId,
+        params_spec: <I::Params<'_> as Params>::Spec,
+    ) -> Self
+    where
+        I: Item,
+    {
+        self.params_specs.insert(item_id, params_spec);
+        self
+    }
+
+    /// Returns the stored item IDs to rename, and the item IDs to rename them
+    /// to.
+    pub fn item_id_renames(&self) -> &IndexMap<ItemId, ItemId> {
+        &self.item_id_renames
+    }
+
+    /// Returns the params specs for items that do not have a stored params
+    /// spec.
+    pub fn params_specs(&self) -> &ParamsSpecs {
+        &self.params_specs
+    }
+
+    /// Returns how to resolve the given stored params spec whose item ID is
+    /// not in the flow.
+    pub(crate) async fn orphan_resolve(
+        &self,
+        item_id: &ItemId,
+        item_ids_missing: &[ItemId],
+    ) -> ParamsSpecOrphanResolution {
+        if let Some(item_id_to) = self.item_id_renames.get(item_id) {
+            ParamsSpecOrphanResolution::Rename(item_id_to.clone())
+        } else if let Some(params_reconciler) = self.params_reconciler.as_ref() {
+            params_reconciler
+                .call(crate::cmds::ParamsSpecOrphan {
+                    item_id: item_id.clone(),
+                    item_ids_missing: item_ids_missing.to_vec(),
+                })
+                .await
+        } else if self.orphans_drop {
+            ParamsSpecOrphanResolution::Drop
+        } else {
+            ParamsSpecOrphanResolution::Keep
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use peace_cfg::ItemId;
+use serde::{Deserialize, Serialize};
+
+/// A stored params spec whose item ID is not in the flow.
+///
+/// This is passed to a [`ParamsReconciler`] to decide what to do with the
+/// params spec.
+///
+/// [`ParamsReconciler`]: crate::cmds::ParamsReconciler
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParamsSpecOrphan {
+    /// ID of the item that the params spec was stored for.
+    pub item_id: ItemId,
+    /// IDs of items in the flow that do not have a params spec, which the
+    /// params spec may be renamed to.
+    pub item_ids_missing: Vec<ItemId>,
+}
@@ -0,0 +1,13 @@
+use peace_cfg::ItemId;
+use serde::{Deserialize, Serialize};
+
+/// What to do with a stored params spec whose item ID is not in the flow.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ParamsSpecOrphanResolution {
+    /// Move the params spec to the given item ID, as the item was renamed.
+    Rename(ItemId),
+    /// Remove the params spec, as the item was removed from the flow.
+    Drop,
+    /// Leave the params spec in the file.
+    Keep,
+}
@@ -0,0 +1,143 @@
+use std::marker::PhantomData;
+
+use indexmap::IndexMap;
+use peace_cfg::Profile;
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::{MultiProfileSingleFlow, MultiProfileSingleFlowView},
+};
+use peace_rt_model::Error;
+use peace_rt_model_core::output::OutputWrite;
+
+use crate::cmds::DiffCmd;
+
+pub use self::profile_diff_matrix::ProfileDiffMatrix;
+
+mod profile_diff_matrix;
+
+/// `DiffCmd` for the `MultiProfileSingleFlow` scope.
+type DiffCmdMulti<'ctx, CmdCtxTypesT> =
+    DiffCmd<CmdCtxTypesT, MultiProfileSingleFlow<'ctx, CmdCtxTypesT>>;
+
+/// Diffs each profile's stored current states against a baseline profile.
+///
+/// This gives a single view of drift across environments -- each item's
+/// [`DiffSeverity`] for each profile, relative to the baseline.
+///
+/// The profiles compared are the ones accessible by the
+/// [`MultiProfileSingleFlow`] scope, so any profile filter passed to the
+/// `CmdCtxBuilder` applies.
+///
+/// [`DiffSeverity`]: peace_cfg::DiffSeverity
+#[derive(Debug)]
+pub struct ProfileDiffMatrixCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> ProfileDiffMatrixCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Returns the sync status of each item in each profile, relative to the
+    /// `baseline` profile.
+    ///
+    /// The returned [`ProfileDiffMatrix`] is also presented to the
+    /// `OutputWrite`.
+    ///
+    /// The baseline profile's current states must have been discovered prior
+    /// to running this. Profiles whose current states have not been discovered
+    /// are included in the matrix without any diffs. See
+    /// [`StatesDiscoverCmd::current`].
+    ///
+    /// [`StatesDiscoverCmd::current`]: crate::cmds::StatesDiscoverCmd::current
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<MultiProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+        baseline: &Profile,
+    ) -> Result<ProfileDiffMatrix, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let MultiProfileSingleFlowView {
+            output,
+            flow,
+            profiles,
+            profile_to_params_specs,
+            profile_to_states_current_stored,
+            resources,
+            ..
+        } = cmd_ctx.view();
+
+        let states_baseline = profile_to_states_current_stored
+            .get(baseline)
+            .ok_or_else(|| {
+                let profile = baseline.clone();
+                let profiles_in_scope = profiles.to_vec();
+                Error::ProfileNotInScope {
+                    profile,
+                    profiles_in_scope,
+                }
+            })?
+            .as_ref()
+            .ok_or_else(|| {
+                let profile = baseline.clone();
+                Error::ProfileStatesCurrentNotDiscovered { profile }
+            })?;
+
+        let mut profile_to_diff_summary = IndexMap::with_capacity(profiles.len());
+        for profile in profiles.iter().filter(|profile| *profile != baseline) {
+            let Some(Some(states_profile)) = profile_to_states_current_stored.get(profile) else {
+                profile_to_diff_summary.insert(profile.clone(), None);
+                continue;
+            };
+
+            let params_specs = profile_to_params_specs
+                .get(profile)
+                .and_then(Option::as_ref)
+                .or_else(|| {
+                    profile_to_params_specs
+                        .get(baseline)
+                        .and_then(Option::as_ref)
+                })
+                .ok_or_else(|| Error::ParamsSpecsNotDefinedForDiff {
+                    profile_a: profile.clone(),
+                    profile_b: baseline.clone(),
+                })?;
+
+            // The diff is from the profile to the baseline, so the severity is
+            // how disruptive it is to bring the profile in line with the
+            // baseline.
+            let state_diffs = DiffCmdMulti::<'ctx, CmdCtxTypesT>::diff_any(
+                flow,
+                params_specs,
+                resources,
+                states_profile,
+                states_baseline,
+            )
+            .await?;
+            let diff_summary = DiffCmdMulti::<'ctx, CmdCtxTypesT>::diff_summary_any(
+                flow,
+                states_profile,
+                states_baseline,
+                &state_diffs,
+            )?;
+
+            profile_to_diff_summary.insert(profile.clone(), Some(diff_summary));
+        }
+
+        let item_ids = flow
+            .graph()
+            .iter_insertion()
+            .map(|item| item.id().clone())
+            .collect::<Vec<_>>();
+        let profile_diff_matrix =
+            ProfileDiffMatrix::new(baseline.clone(), item_ids, profile_to_diff_summary);
+
+        output.present(&profile_diff_matrix).await?;
+
+        Ok(profile_diff_matrix)
+    }
+}
+
+impl<CmdCtxTypesT> Default for ProfileDiffMatrixCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
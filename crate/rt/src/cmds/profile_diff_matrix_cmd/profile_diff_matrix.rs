@@ -0,0 +1,117 @@
+use indexmap::IndexMap;
+use peace_cfg::{DiffSeverity, ItemId, Profile};
+use peace_fmt::{presentable::HeadingLevel, Presentable, Presenter};
+use peace_resources::states::DiffSummary;
+use serde::{Deserialize, Serialize};
+
+/// Sync status of each item in each profile, relative to a baseline profile.
+///
+/// Returned by [`ProfileDiffMatrixCmd`].
+///
+/// [`ProfileDiffMatrixCmd`]: crate::cmds::ProfileDiffMatrixCmd
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ProfileDiffMatrix {
+    /// The profile that other profiles are compared against.
+    baseline: Profile,
+    /// IDs of the items in the flow, in insertion order.
+    item_ids: Vec<ItemId>,
+    /// Diff severity of each item, for each profile other than the baseline.
+    ///
+    /// This is `None` for profiles whose current states have not been
+    /// discovered.
+    profile_to_diff_summary: IndexMap<Profile, Option<DiffSummary>>,
+}
+
+impl ProfileDiffMatrix {
+    /// Returns a new `ProfileDiffMatrix`.
+    pub fn new(
+        baseline: Profile,
+        item_ids: Vec<ItemId>,
+        profile_to_diff_summary: IndexMap<Profile, Option<DiffSummary>>,
+    ) -> Self {
+        Self {
+            baseline,
+            item_ids,
+            profile_to_diff_summary,
+        }
+    }
+
+    /// Returns the profile that other profiles are compared against.
+    pub fn baseline(&self) -> &Profile {
+        &self.baseline
+    }
+
+    /// Returns the IDs of the items in the flow, in insertion order.
+    pub fn item_ids(&self) -> &[ItemId] {
+        &self.item_ids
+    }
+
+    /// Returns the diff summary of each profile other than the baseline.
+    ///
+    /// The diff summary is `None` for profiles whose current states have not
+    /// been discovered.
+    pub fn profile_to_diff_summary(&self) -> &IndexMap<Profile, Option<DiffSummary>> {
+        &self.profile_to_diff_summary
+    }
+
+    /// Returns the diff severity of an item in a profile, relative to the
+    /// baseline.
+    ///
+    /// Returns `None` if the item's current state has not been discovered for
+    /// either profile.
+    pub fn diff_severity(&self, item_id: &ItemId, profile: &Profile) -> Option<DiffSeverity> {
+        self.profile_to_diff_summary
+            .get(profile)?
+            .as_ref()?
+            .get(item_id)
+            .copied()
+    }
+
+    /// Returns the profiles with at least one item that is not in sync with
+    /// the baseline.
+    pub fn profiles_out_of_sync(&self) -> impl Iterator<Item = &Profile> + '_ {
+        self.profile_to_diff_summary
+            .iter()
+            .filter_map(|(profile, diff_summary)| {
+                diff_summary
+                    .as_ref()
+                    .filter(|diff_summary| diff_summary.severity_max() != DiffSeverity::InSync)
+                    .map(|_| profile)
+            })
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for ProfileDiffMatrix {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        presenter
+            .heading(HeadingLevel::Level1, "Profile diff matrix")
+            .await?;
+        presenter
+            .text(&format!("Baseline: {}", self.baseline))
+            .await?;
+
+        if self.profile_to_diff_summary.is_empty() {
+            return presenter.text("No other profiles to compare.").await;
+        }
+
+        let header = std::iter::once(String::from("Item"))
+            .chain(self.profile_to_diff_summary.keys().map(Profile::to_string))
+            .collect::<Vec<_>>();
+        let header = header.iter().map(String::as_str).collect::<Vec<_>>();
+        let rows = self.item_ids.iter().map(|item_id| {
+            std::iter::once(item_id.to_string())
+                .chain(self.profile_to_diff_summary.keys().map(|profile| {
+                    self.diff_severity(item_id, profile)
+                        .map(|diff_severity| diff_severity.to_string())
+                        .unwrap_or_else(|| String::from("unknown"))
+                }))
+                .collect::<Vec<_>>()
+        });
+
+        presenter.table(&header, rows).await
+    }
+}
@@ -0,0 +1,126 @@
+use std::{collections::BTreeMap, fmt::Debug, marker::PhantomData};
+
+use peace_cfg::Profile;
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::{MultiProfileNoFlow, MultiProfileNoFlowView},
+};
+use peace_resources::paths::ProfileDir;
+use peace_rt_model::{
+    params::{KeyMaybe, ParamsKeys},
+    Storage,
+};
+use peace_rt_model_core::output::OutputWrite;
+
+pub use self::{
+    profile_execution_summary::ProfileExecutionSummary, profile_info::ProfileInfo,
+    profile_infos::ProfileInfos,
+};
+
+mod profile_execution_summary;
+mod profile_info;
+mod profile_infos;
+
+/// Workspace params key type for a `CmdCtxTypes`.
+type WorkspaceParamsK<CmdCtxTypesT> =
+    <<<CmdCtxTypesT as CmdCtxTypesConstrained>::ParamsKeys as ParamsKeys>::WorkspaceParamsKMaybe as KeyMaybe>::Key;
+
+/// Profile params key type for a `CmdCtxTypes`.
+type ProfileParamsK<CmdCtxTypesT> =
+    <<<CmdCtxTypesT as CmdCtxTypesConstrained>::ParamsKeys as ParamsKeys>::ProfileParamsKMaybe as KeyMaybe>::Key;
+
+/// Lists the profiles in a workspace, with their params and last execution.
+///
+/// The profiles listed are the ones accessible by the [`MultiProfileNoFlow`]
+/// scope, so any profile filter passed to the `CmdCtxBuilder` applies.
+#[derive(Debug)]
+pub struct ProfileListCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> ProfileListCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Returns information about each profile in the workspace.
+    ///
+    /// The returned [`ProfileInfos`] are also presented to the `OutputWrite`.
+    ///
+    /// A profile's last execution is the most recent execution recorded in a
+    /// flow's execution history, which is updated by `StatesDiscoverCmd`,
+    /// `EnsureCmd`, and `CleanCmd`.
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<MultiProfileNoFlow<'ctx, CmdCtxTypesT>>,
+    ) -> Result<
+        ProfileInfos<WorkspaceParamsK<CmdCtxTypesT>, ProfileParamsK<CmdCtxTypesT>>,
+        <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+    >
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let MultiProfileNoFlowView {
+            output,
+            workspace,
+            profiles,
+            profile_dirs,
+            workspace_params,
+            profile_to_profile_params,
+            ..
+        } = cmd_ctx.view();
+
+        let profile_infos_result = Self::profile_infos(
+            workspace.storage(),
+            profiles,
+            profile_dirs,
+            profile_to_profile_params,
+        )
+        .await;
+
+        match profile_infos_result {
+            Ok(profile_infos) => {
+                let profile_infos = ProfileInfos::new(workspace_params.clone(), profile_infos);
+                output.present(&profile_infos).await?;
+                Ok(profile_infos)
+            }
+            Err(error) => {
+                let error = <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError::from(error);
+                output.write_err(&error).await?;
+                Err(error)
+            }
+        }
+    }
+
+    async fn profile_infos(
+        storage: &Storage,
+        profiles: &[Profile],
+        profile_dirs: &BTreeMap<Profile, ProfileDir>,
+        profile_to_profile_params: &BTreeMap<
+            Profile,
+            peace_rt_model::params::ProfileParams<ProfileParamsK<CmdCtxTypesT>>,
+        >,
+    ) -> Result<Vec<ProfileInfo<ProfileParamsK<CmdCtxTypesT>>>, peace_rt_model::Error> {
+        let mut profile_infos = Vec::with_capacity(profiles.len());
+        for profile in profiles {
+            let profile_params = profile_to_profile_params
+                .get(profile)
+                .cloned()
+                .unwrap_or_default();
+            let last_execution = match profile_dirs.get(profile) {
+                Some(profile_dir) => ProfileExecutionSummary::last_in(storage, profile_dir).await?,
+                None => None,
+            };
+
+            profile_infos.push(ProfileInfo::new(
+                profile.clone(),
+                profile_params,
+                last_execution,
+            ));
+        }
+
+        Ok(profile_infos)
+    }
+}
+
+impl<CmdCtxTypesT> Default for ProfileListCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
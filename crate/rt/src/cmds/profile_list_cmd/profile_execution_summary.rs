@@ -0,0 +1,151 @@
+use std::{ffi::OsStr, str::FromStr};
+
+use chrono::{DateTime, Utc};
+use peace_cfg::FlowId;
+use peace_fmt::{Presentable, Presenter};
+use peace_resources::paths::{ExecutionHistoryFile, FlowDir, ProfileDir};
+use peace_rt_model::{ExecutionHistorySerializer, Storage};
+use serde::{Deserialize, Serialize};
+
+/// Summary of the most recent execution within a profile.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProfileExecutionSummary {
+    /// ID of the flow that was last executed.
+    pub flow_id: FlowId,
+    /// When the flow's last execution was recorded.
+    pub executed_at: DateTime<Utc>,
+}
+
+impl ProfileExecutionSummary {
+    /// Returns a new `ProfileExecutionSummary`.
+    pub fn new(flow_id: FlowId, executed_at: DateTime<Utc>) -> Self {
+        Self {
+            flow_id,
+            executed_at,
+        }
+    }
+
+    /// Returns the summary of the flow whose execution was most recently
+    /// recorded within the profile directory.
+    ///
+    /// Execution times are read from each flow's [`ExecutionHistory`] through
+    /// `Storage`, so they are preserved when a workspace is copied or
+    /// imported.
+    ///
+    /// Returns `None` if no flow in the profile has a recorded execution.
+    ///
+    /// [`ExecutionHistory`]: peace_rt_model::ExecutionHistory
+    pub async fn last_in(
+        storage: &Storage,
+        profile_dir: &ProfileDir,
+    ) -> Result<Option<Self>, peace_rt_model::Error> {
+        let flow_ids = Self::flow_ids_in(
+            #[cfg(target_arch = "wasm32")]
+            storage,
+            profile_dir,
+        )
+        .await?;
+
+        let mut profile_execution_summary = None::<Self>;
+        for flow_id in flow_ids {
+            let flow_dir = FlowDir::from((profile_dir, &flow_id));
+            let execution_history_file = ExecutionHistoryFile::from(&flow_dir);
+            let execution_history =
+                ExecutionHistorySerializer::<peace_rt_model::Error>::deserialize_opt(
+                    storage,
+                    &execution_history_file,
+                )
+                .await?;
+
+            // Flows without recorded executions have not been executed.
+            let Some(executed_at) = execution_history.and_then(|execution_history| {
+                execution_history
+                    .last()
+                    .map(|execution_record| execution_record.recorded_at)
+            }) else {
+                continue;
+            };
+
+            let is_more_recent = profile_execution_summary
+                .as_ref()
+                .map(|summary| executed_at > summary.executed_at)
+                .unwrap_or(true);
+            if is_more_recent {
+                profile_execution_summary = Some(Self::new(flow_id, executed_at));
+            }
+        }
+
+        Ok(profile_execution_summary)
+    }
+
+    /// Returns the IDs of the flows with a directory in the profile directory.
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn flow_ids_in(profile_dir: &ProfileDir) -> Result<Vec<FlowId>, peace_rt_model::Error> {
+        let mut profile_read_dir = tokio::fs::read_dir(profile_dir).await.map_err(|error| {
+            peace_rt_model::Error::Native(peace_rt_model::NativeError::ProfileDirRead {
+                profile_dir: profile_dir.to_path_buf(),
+                error,
+            })
+        })?;
+
+        let mut flow_ids = Vec::new();
+        while let Some(entry) = profile_read_dir.next_entry().await.map_err(|error| {
+            peace_rt_model::Error::Native(peace_rt_model::NativeError::ProfileDirEntryRead {
+                profile_dir: profile_dir.to_path_buf(),
+                error,
+            })
+        })? {
+            let entry_path = entry.path();
+            let flow_id = entry_path
+                .file_name()
+                .and_then(OsStr::to_str)
+                .and_then(|dir_name| FlowId::from_str(dir_name).ok());
+            if let Some(flow_id) = flow_id {
+                flow_ids.push(flow_id);
+            }
+        }
+
+        Ok(flow_ids)
+    }
+
+    /// Returns the IDs of the flows with an execution history in the profile
+    /// directory.
+    #[cfg(target_arch = "wasm32")]
+    async fn flow_ids_in(
+        storage: &Storage,
+        profile_dir: &ProfileDir,
+    ) -> Result<Vec<FlowId>, peace_rt_model::Error> {
+        let flow_ids = storage
+            .keys_with_prefix(profile_dir)?
+            .into_iter()
+            .filter_map(|key| {
+                let flow_dir_relative = key.strip_prefix(profile_dir).ok()?;
+                let mut components = flow_dir_relative.components();
+                let flow_dir_name = components.next()?.as_os_str().to_str()?;
+                let file_name = components.next()?.as_os_str();
+                let is_execution_history_file = components.next().is_none()
+                    && file_name == OsStr::new(ExecutionHistoryFile::NAME);
+
+                is_execution_history_file
+                    .then(|| FlowId::from_str(flow_dir_name).ok())
+                    .flatten()
+            })
+            .collect::<Vec<FlowId>>();
+
+        Ok(flow_ids)
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for ProfileExecutionSummary {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        presenter.id(&self.flow_id).await?;
+        presenter.text(" at ").await?;
+        presenter
+            .text(&self.executed_at.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+            .await
+    }
+}
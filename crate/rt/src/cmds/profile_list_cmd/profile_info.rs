@@ -0,0 +1,62 @@
+use std::hash::Hash;
+
+use peace_cfg::Profile;
+use peace_fmt::{Presentable, Presenter};
+use peace_rt_model::params::ProfileParams;
+use serde::Serialize;
+
+use crate::cmds::ProfileExecutionSummary;
+
+/// Information about a profile, returned by [`ProfileListCmd`].
+///
+/// [`ProfileListCmd`]: crate::cmds::ProfileListCmd
+#[derive(Clone, Debug, Serialize)]
+pub struct ProfileInfo<ProfileParamsK>
+where
+    ProfileParamsK: Eq + Hash,
+{
+    /// The profile.
+    pub profile: Profile,
+    /// Params stored for the profile.
+    pub profile_params: ProfileParams<ProfileParamsK>,
+    /// The most recent execution within the profile, if any.
+    pub last_execution: Option<ProfileExecutionSummary>,
+}
+
+impl<ProfileParamsK> ProfileInfo<ProfileParamsK>
+where
+    ProfileParamsK: Eq + Hash,
+{
+    /// Returns a new `ProfileInfo`.
+    pub fn new(
+        profile: Profile,
+        profile_params: ProfileParams<ProfileParamsK>,
+        last_execution: Option<ProfileExecutionSummary>,
+    ) -> Self {
+        Self {
+            profile,
+            profile_params,
+            last_execution,
+        }
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl<ProfileParamsK> Presentable for ProfileInfo<ProfileParamsK>
+where
+    ProfileParamsK: Eq + Hash + Serialize,
+{
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        presenter.name(&self.profile).await?;
+        match self.last_execution.as_ref() {
+            Some(last_execution) => {
+                presenter.text(": last executed ").await?;
+                last_execution.present(presenter).await
+            }
+            None => presenter.text(": not executed").await,
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::{hash::Hash, ops::Deref};
+
+use peace_fmt::{presentable::HeadingLevel, Presentable, Presenter};
+use peace_rt_model::params::WorkspaceParams;
+use serde::Serialize;
+
+use crate::cmds::ProfileInfo;
+
+/// Information about each profile in a workspace, returned by
+/// [`ProfileListCmd`].
+///
+/// This derefs to the list of [`ProfileInfo`]s.
+///
+/// [`ProfileListCmd`]: crate::cmds::ProfileListCmd
+#[derive(Clone, Debug, Serialize)]
+pub struct ProfileInfos<WorkspaceParamsK, ProfileParamsK>
+where
+    WorkspaceParamsK: Eq + Hash,
+    ProfileParamsK: Eq + Hash,
+{
+    /// Params stored for the workspace.
+    workspace_params: WorkspaceParams<WorkspaceParamsK>,
+    /// Information about each profile.
+    profile_infos: Vec<ProfileInfo<ProfileParamsK>>,
+}
+
+impl<WorkspaceParamsK, ProfileParamsK> ProfileInfos<WorkspaceParamsK, ProfileParamsK>
+where
+    WorkspaceParamsK: Eq + Hash,
+    ProfileParamsK: Eq + Hash,
+{
+    /// Returns a new `ProfileInfos`.
+    pub fn new(
+        workspace_params: WorkspaceParams<WorkspaceParamsK>,
+        profile_infos: Vec<ProfileInfo<ProfileParamsK>>,
+    ) -> Self {
+        Self {
+            workspace_params,
+            profile_infos,
+        }
+    }
+
+    /// Returns the params stored for the workspace.
+    pub fn workspace_params(&self) -> &WorkspaceParams<WorkspaceParamsK> {
+        &self.workspace_params
+    }
+
+    /// Returns the information about each profile.
+    pub fn profile_infos(&self) -> &[ProfileInfo<ProfileParamsK>] {
+        &self.profile_infos
+    }
+
+    /// Returns the inner workspace params and profile infos.
+    pub fn into_inner(
+        self,
+    ) -> (
+        WorkspaceParams<WorkspaceParamsK>,
+        Vec<ProfileInfo<ProfileParamsK>>,
+    ) {
+        (self.workspace_params, self.profile_infos)
+    }
+}
+
+impl<WorkspaceParamsK, ProfileParamsK> Deref for ProfileInfos<WorkspaceParamsK, ProfileParamsK>
+where
+    WorkspaceParamsK: Eq + Hash,
+    ProfileParamsK: Eq + Hash,
+{
+    type Target = [ProfileInfo<ProfileParamsK>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.profile_infos
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl<WorkspaceParamsK, ProfileParamsK> Presentable
+    for ProfileInfos<WorkspaceParamsK, ProfileParamsK>
+where
+    WorkspaceParamsK: Eq + Hash + Serialize,
+    ProfileParamsK: Eq + Hash + Serialize,
+{
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        presenter.heading(HeadingLevel::Level1, "Profiles").await?;
+        presenter.list_bulleted(self.profile_infos.iter()).await
+    }
+}
@@ -0,0 +1,193 @@
+use std::marker::PhantomData;
+
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::{SingleProfileSingleFlow, SingleProfileSingleFlowView},
+};
+use peace_resources::paths::{
+    ExecutionHistoryFile, ExecutionStatesDir, StatesCurrentFile, StatesGoalFile,
+};
+use peace_rt_model::{ExecutionHistory, ExecutionHistorySerializer, StatesSerializer};
+use peace_rt_model_core::output::OutputWrite;
+
+pub use self::states_checkout_outcome::StatesCheckoutOutcome;
+
+mod states_checkout_outcome;
+
+/// Application error type for a `CmdCtxTypes`.
+type AppError<CmdCtxTypesT> = <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError;
+
+/// Restores a flow's stored states to the states recorded after a previous
+/// command execution.
+///
+/// An execution is recorded in the flow's [`ExecutionHistory`] whenever
+/// [`StatesDiscoverCmd`], [`EnsureCmd`], or [`CleanCmd`] writes the flow's
+/// states. Checking out an execution only rewrites the `StatesCurrentFile`
+/// and `StatesGoalFile`, and does not apply any changes to the items'
+/// resources.
+///
+/// After checking out an execution, [`DiffCmd::stored`] shows the changes
+/// that were pending at that time, and diffing the discovered current states
+/// against the checked out goal states shows what it takes to roll back to
+/// that execution.
+///
+/// [`CleanCmd`]: crate::cmds::CleanCmd
+/// [`DiffCmd::stored`]: crate::cmds::DiffCmd::stored
+/// [`EnsureCmd`]: crate::cmds::EnsureCmd
+/// [`StatesDiscoverCmd`]: crate::cmds::StatesDiscoverCmd
+#[derive(Debug)]
+pub struct StatesCheckoutCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> StatesCheckoutCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Returns the executions recorded for the flow.
+    ///
+    /// The returned [`ExecutionHistory`] is also presented to the
+    /// `OutputWrite`.
+    pub async fn list<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+    ) -> Result<ExecutionHistory, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let SingleProfileSingleFlowView {
+            workspace,
+            flow_dir,
+            ..
+        } = cmd_ctx.view();
+
+        let execution_history_file = ExecutionHistoryFile::from(flow_dir);
+        let execution_history_result =
+            ExecutionHistorySerializer::<AppError<CmdCtxTypesT>>::deserialize_opt(
+                workspace.storage(),
+                &execution_history_file,
+            )
+            .await
+            .map(|execution_history| execution_history.unwrap_or_else(ExecutionHistory::new));
+        let output = cmd_ctx.output_mut();
+
+        match execution_history_result {
+            Ok(execution_history) => {
+                output.present(&execution_history).await?;
+                Ok(execution_history)
+            }
+            Err(error) => {
+                output.write_err(&error).await?;
+                Err(error)
+            }
+        }
+    }
+
+    /// Restores the flow's stored states to the states recorded after the
+    /// execution with the given ID.
+    ///
+    /// The recorded states are deserialized before the flow's states files are
+    /// written, so a failure does not leave the flow directory partially
+    /// restored. The restored states are also inserted into this `CmdCtx`'s
+    /// resources.
+    ///
+    /// The [`StatesCheckoutOutcome`] is also presented to the `OutputWrite`.
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+        execution_id: u64,
+    ) -> Result<StatesCheckoutOutcome, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let states_checkout_outcome_result = Self::checkout(cmd_ctx.view(), execution_id).await;
+        let output = cmd_ctx.output_mut();
+
+        match states_checkout_outcome_result {
+            Ok(states_checkout_outcome) => {
+                output.present(&states_checkout_outcome).await?;
+                Ok(states_checkout_outcome)
+            }
+            Err(error) => {
+                output.write_err(&error).await?;
+                Err(error)
+            }
+        }
+    }
+
+    async fn checkout(
+        cmd_view: SingleProfileSingleFlowView<'_, CmdCtxTypesT>,
+        execution_id: u64,
+    ) -> Result<StatesCheckoutOutcome, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError> {
+        let SingleProfileSingleFlowView {
+            workspace,
+            flow,
+            flow_dir,
+            states_type_reg,
+            resources,
+            ..
+        } = cmd_view;
+        let storage = workspace.storage();
+        let flow_id = flow.flow_id();
+
+        let execution_history_file = ExecutionHistoryFile::from(flow_dir);
+        let execution_history =
+            ExecutionHistorySerializer::<AppError<CmdCtxTypesT>>::deserialize_opt(
+                storage,
+                &execution_history_file,
+            )
+            .await?
+            .unwrap_or_else(ExecutionHistory::new);
+        let execution_record = execution_history
+            .execution(execution_id)
+            .cloned()
+            .ok_or(peace_rt_model::Error::ExecutionNotFound { execution_id })?;
+
+        let execution_states_dir = ExecutionStatesDir::from((flow_dir, execution_id));
+        let execution_states_current_file = StatesCurrentFile::from(&execution_states_dir);
+        let execution_states_goal_file = StatesGoalFile::from(&execution_states_dir);
+
+        // Deserialize everything before writing, so that a failure does not leave
+        // the flow directory partially restored.
+        let states_current_stored = StatesSerializer::<AppError<CmdCtxTypesT>>::deserialize_stored(
+            flow_id,
+            storage,
+            states_type_reg,
+            &execution_states_current_file,
+        )
+        .await?;
+        let states_goal_stored = StatesSerializer::<AppError<CmdCtxTypesT>>::deserialize_goal(
+            flow_id,
+            storage,
+            states_type_reg,
+            &execution_states_goal_file,
+        )
+        .await?;
+
+        StatesSerializer::<AppError<CmdCtxTypesT>>::copy(
+            flow_id,
+            storage,
+            &execution_states_current_file,
+            &StatesCurrentFile::from(flow_dir),
+        )
+        .await?;
+        StatesSerializer::<AppError<CmdCtxTypesT>>::copy(
+            flow_id,
+            storage,
+            &execution_states_goal_file,
+            &StatesGoalFile::from(flow_dir),
+        )
+        .await?;
+
+        resources.insert(states_current_stored.clone());
+        resources.insert(states_goal_stored.clone());
+
+        Ok(StatesCheckoutOutcome {
+            execution_record,
+            states_current_stored,
+            states_goal_stored,
+        })
+    }
+}
+
+impl<CmdCtxTypesT> Default for StatesCheckoutCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
@@ -0,0 +1,31 @@
+use peace_fmt::{Presentable, Presenter};
+use peace_resources::states::{StatesCurrentStored, StatesGoalStored};
+use peace_rt_model::ExecutionRecord;
+use serde::Serialize;
+
+/// States restored by [`StatesCheckoutCmd`].
+///
+/// [`StatesCheckoutCmd`]: crate::cmds::StatesCheckoutCmd
+#[derive(Debug, Serialize)]
+pub struct StatesCheckoutOutcome {
+    /// Record of the execution whose states were restored.
+    pub execution_record: ExecutionRecord,
+    /// Current states recorded after the execution.
+    pub states_current_stored: StatesCurrentStored,
+    /// Goal states recorded after the execution.
+    pub states_goal_stored: StatesGoalStored,
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for StatesCheckoutOutcome {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        presenter.text("Checked out states from execution ").await?;
+        presenter
+            .code_inline(&self.execution_record.to_string())
+            .await?;
+        presenter.text(".\n").await
+    }
+}
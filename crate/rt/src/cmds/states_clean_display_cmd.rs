@@ -0,0 +1,70 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::SingleProfileSingleFlow,
+};
+use peace_cmd_model::CmdOutcome;
+use peace_cmd_rt::{CmdBlockWrapper, CmdExecution};
+use peace_resources::states::StatesClean;
+
+use peace_rt_model_core::output::OutputWrite;
+
+use crate::cmd_blocks::StatesCleanInsertionCmdBlock;
+
+/// Displays [`StatesClean`]s, i.e. what each item looks like when cleaned.
+#[derive(Debug)]
+pub struct StatesCleanDisplayCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> StatesCleanDisplayCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Displays [`StatesClean`]s for all items.
+    ///
+    /// This runs [`Item::state_clean`] for each item, without discovering the
+    /// current state or running [`CleanCmd::exec_dry`]. Nothing is written to
+    /// storage.
+    ///
+    /// [`Item::state_clean`]: peace_cfg::Item::state_clean
+    /// [`CleanCmd::exec_dry`]: crate::cmds::CleanCmd::exec_dry
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+    ) -> Result<
+        CmdOutcome<StatesClean, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+    >
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let cmd_execution_builder = CmdExecution::<StatesClean, _>::builder().with_cmd_block(
+            CmdBlockWrapper::new(StatesCleanInsertionCmdBlock::new(), std::convert::identity),
+        );
+
+        #[cfg(feature = "output_progress")]
+        let cmd_execution_builder = cmd_execution_builder.with_progress_render_enabled(false);
+
+        let states_clean_result = cmd_execution_builder.build().exec(cmd_ctx).await;
+        let output = cmd_ctx.output_mut();
+
+        match states_clean_result {
+            Ok(states_clean_cmd_outcome) => {
+                if let Some(states_clean) = states_clean_cmd_outcome.value() {
+                    output.present(states_clean).await?;
+                }
+
+                Ok(states_clean_cmd_outcome)
+            }
+            Err(e) => {
+                output.write_err(&e).await?;
+                Err(e)
+            }
+        }
+    }
+}
+
+impl<CmdCtxTypesT> Default for StatesCleanDisplayCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
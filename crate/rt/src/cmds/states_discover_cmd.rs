@@ -1,5 +1,6 @@
 use std::{fmt::Debug, marker::PhantomData};
 
+use peace_cfg::ItemId;
 use peace_cmd::{
     ctx::{CmdCtx, CmdCtxTypesConstrained},
     scopes::{SingleProfileSingleFlow, SingleProfileSingleFlowView},
@@ -7,14 +8,20 @@ use peace_cmd::{
 use peace_cmd_model::CmdOutcome;
 use peace_cmd_rt::{CmdBlockWrapper, CmdExecution};
 use peace_resources::{
-    paths::{FlowDir, StatesCurrentFile, StatesGoalFile},
+    paths::{FlowDir, ParamsDigestsFile, StatesCurrentFile, StatesGoalFile},
     resources::ts::SetUp,
-    states::{StatesCurrent, StatesGoal},
+    states::{StatesCurrent, StatesCurrentStored, StatesGoal},
     Resources,
 };
-use peace_rt_model::{ItemGraph, Storage};
+use peace_rt_model::{
+    ExecutionHistorySerializer, ItemGraph, ItemsStateFreshness, ParamsDigests,
+    ParamsDigestsSerializer, StateFreshness, Storage,
+};
 
-use crate::cmd_blocks::StatesDiscoverCmdBlock;
+use crate::{
+    cmd_blocks::{PreflightCheckCmdBlock, StatesDiscoverCmdBlock},
+    cmds::StatesDiscoverIncremental,
+};
 
 pub struct StatesDiscoverCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
 
@@ -82,7 +89,14 @@ where
     where
         CmdCtxTypesT: 'ctx,
     {
-        let mut cmd_execution = CmdExecution::<StatesCurrent, _>::builder()
+        let mut cmd_execution_builder = CmdExecution::<StatesCurrent, _>::builder();
+        if !cmd_ctx.scope().flow().preflight_checks().is_empty() {
+            cmd_execution_builder = cmd_execution_builder.with_cmd_block(CmdBlockWrapper::new(
+                PreflightCheckCmdBlock::new(),
+                |()| StatesCurrent::new(),
+            ));
+        }
+        let mut cmd_execution = cmd_execution_builder
             .with_cmd_block(CmdBlockWrapper::new(
                 #[cfg(not(feature = "output_progress"))]
                 StatesDiscoverCmdBlock::current(),
@@ -101,13 +115,179 @@ where
             let (item_graph, resources) = (flow.graph(), resources);
 
             if serialize_to_storage {
-                Self::serialize_current(item_graph, resources, states_current).await?;
+                let storage_transaction = resources.borrow::<Storage>().transaction();
+                Self::serialize_current(
+                    item_graph,
+                    resources,
+                    &storage_transaction,
+                    states_current,
+                )
+                .await?;
+                storage_transaction.commit().await?;
+
+                ExecutionHistorySerializer::<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>::record_in(
+                    flow.flow_id(),
+                    resources,
+                    states_current.keys().cloned().collect(),
+                )
+                .await?;
             }
         }
 
         Ok(cmd_outcome)
     }
 
+    /// Runs [`try_state_current`] for the items affected by a change, and uses
+    /// the stored current states for all other items.
+    ///
+    /// The items in `states_discover_incremental` and their successors are
+    /// rediscovered. Items without a stored current state are always
+    /// discovered.
+    ///
+    /// At the end of this function, [`Resources`] will be populated with the
+    /// merged [`StatesCurrent`], which will be serialized to
+    /// `$flow_dir/states_current.yaml`. Items whose stored states were used
+    /// keep their stored params digests, so that a later
+    /// [`StatesDiscoverIncremental::ParamsChanged`] still detects their
+    /// change.
+    ///
+    /// The returned [`ItemsStateFreshness`] records whether each item's state
+    /// was discovered, or read from the stored states.
+    ///
+    /// [`try_state_current`]: peace_cfg::Item::try_state_current
+    pub async fn current_incremental<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+        states_discover_incremental: StatesDiscoverIncremental,
+    ) -> Result<
+        CmdOutcome<
+            (StatesCurrent, ItemsStateFreshness),
+            <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+        >,
+        <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+    >
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let (item_ids_rediscover, item_ids_reused, params_digests_stored) = {
+            let SingleProfileSingleFlowView {
+                flow, resources, ..
+            } = cmd_ctx.view();
+
+            let params_digests_stored = {
+                let storage = resources.borrow::<Storage>();
+                let flow_dir = resources.borrow::<FlowDir>();
+                let params_digests_file = ParamsDigestsFile::from(&*flow_dir);
+
+                ParamsDigestsSerializer::<
+                    <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+                >::deserialize_opt(&storage, &params_digests_file)
+                .await?
+            };
+
+            let item_ids_changed = match states_discover_incremental {
+                StatesDiscoverIncremental::ItemIds(item_ids) => item_ids,
+                StatesDiscoverIncremental::ParamsChanged => {
+                    let params_digests = resources.try_borrow::<ParamsDigests>().ok();
+                    match (params_digests, params_digests_stored.as_ref()) {
+                        (Some(params_digests), Some(params_digests_stored)) => {
+                            params_digests.items_changed(params_digests_stored)
+                        }
+                        _ => flow
+                            .graph()
+                            .iter_insertion()
+                            .map(|item| item.id().clone())
+                            .collect(),
+                    }
+                }
+            };
+            let item_ids_rediscover = flow.graph().item_ids_with_successors(&item_ids_changed);
+
+            let item_ids_reused = match resources.try_borrow::<StatesCurrentStored>() {
+                Ok(states_current_stored) => flow
+                    .graph()
+                    .iter_insertion()
+                    .map(|item| item.id())
+                    .filter(|item_id| {
+                        !item_ids_rediscover.contains(item_id)
+                            && states_current_stored.contains_key(*item_id)
+                    })
+                    .cloned()
+                    .collect::<Vec<_>>(),
+                Err(_) => Vec::new(),
+            };
+
+            (item_ids_rediscover, item_ids_reused, params_digests_stored)
+        };
+
+        let mut cmd_execution_builder = CmdExecution::<StatesCurrent, _>::builder();
+        if !cmd_ctx.scope().flow().preflight_checks().is_empty() {
+            cmd_execution_builder = cmd_execution_builder.with_cmd_block(CmdBlockWrapper::new(
+                PreflightCheckCmdBlock::new(),
+                |()| StatesCurrent::new(),
+            ));
+        }
+        let mut cmd_execution = cmd_execution_builder
+            .with_cmd_block(CmdBlockWrapper::new(
+                #[cfg(not(feature = "output_progress"))]
+                StatesDiscoverCmdBlock::current_incremental(item_ids_rediscover),
+                #[cfg(feature = "output_progress")]
+                StatesDiscoverCmdBlock::current_incremental(item_ids_rediscover)
+                    .progress_complete_on_success(),
+                StatesCurrent::from,
+            ))
+            .build();
+
+        let cmd_outcome = cmd_execution.exec(cmd_ctx).await?;
+
+        if let Some(states_current) = cmd_outcome.value() {
+            let SingleProfileSingleFlowView {
+                flow, resources, ..
+            } = cmd_ctx.view();
+            let (item_graph, resources) = (flow.graph(), resources);
+
+            let storage_transaction = resources.borrow::<Storage>().transaction();
+            Self::serialize_current_incremental(
+                item_graph,
+                resources,
+                &storage_transaction,
+                states_current,
+                params_digests_stored.as_ref(),
+                &item_ids_reused,
+            )
+            .await?;
+            storage_transaction.commit().await?;
+
+            ExecutionHistorySerializer::<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>::record_in(
+                flow.flow_id(),
+                resources,
+                states_current
+                    .keys()
+                    .filter(|item_id| !item_ids_reused.contains(item_id))
+                    .cloned()
+                    .collect(),
+            )
+            .await?;
+        }
+
+        let cmd_outcome = cmd_outcome.map(|states_current| {
+            let items_state_freshness = states_current
+                .keys()
+                .map(|item_id| {
+                    let state_freshness = if item_ids_reused.contains(item_id) {
+                        StateFreshness::Stored
+                    } else {
+                        StateFreshness::Discovered
+                    };
+                    (item_id.clone(), state_freshness)
+                })
+                .collect::<ItemsStateFreshness>();
+
+            (states_current, items_state_freshness)
+        });
+
+        Ok(cmd_outcome)
+    }
+
     /// Runs [`try_state_goal`] for each [`Item`].
     ///
     /// At the end of this function, [`Resources`] will be populated with
@@ -162,7 +342,14 @@ where
     where
         CmdCtxTypesT: 'ctx,
     {
-        let mut cmd_execution = CmdExecution::<StatesGoal, _>::builder()
+        let mut cmd_execution_builder = CmdExecution::<StatesGoal, _>::builder();
+        if !cmd_ctx.scope().flow().preflight_checks().is_empty() {
+            cmd_execution_builder = cmd_execution_builder.with_cmd_block(CmdBlockWrapper::new(
+                PreflightCheckCmdBlock::new(),
+                |()| StatesGoal::new(),
+            ));
+        }
+        let mut cmd_execution = cmd_execution_builder
             .with_cmd_block(CmdBlockWrapper::new(
                 #[cfg(not(feature = "output_progress"))]
                 StatesDiscoverCmdBlock::goal(),
@@ -181,7 +368,17 @@ where
             let (item_graph, resources) = (flow.graph(), resources);
 
             if serialize_to_storage {
-                Self::serialize_goal(item_graph, resources, states_goal).await?;
+                let storage_transaction = resources.borrow::<Storage>().transaction();
+                Self::serialize_goal(item_graph, resources, &storage_transaction, states_goal)
+                    .await?;
+                storage_transaction.commit().await?;
+
+                ExecutionHistorySerializer::<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>::record_in(
+                    flow.flow_id(),
+                    resources,
+                    states_goal.keys().cloned().collect(),
+                )
+                .await?;
             }
         }
 
@@ -253,7 +450,14 @@ where
     where
         CmdCtxTypesT: 'ctx,
     {
-        let mut cmd_execution = CmdExecution::<(StatesCurrent, StatesGoal), _>::builder()
+        let mut cmd_execution_builder = CmdExecution::<(StatesCurrent, StatesGoal), _>::builder();
+        if !cmd_ctx.scope().flow().preflight_checks().is_empty() {
+            cmd_execution_builder = cmd_execution_builder.with_cmd_block(CmdBlockWrapper::new(
+                PreflightCheckCmdBlock::new(),
+                |()| (StatesCurrent::new(), StatesGoal::new()),
+            ));
+        }
+        let mut cmd_execution = cmd_execution_builder
             .with_cmd_block(CmdBlockWrapper::new(
                 #[cfg(not(feature = "output_progress"))]
                 StatesDiscoverCmdBlock::current_and_goal(),
@@ -285,8 +489,26 @@ where
             let (item_graph, resources) = (flow.graph(), resources);
 
             if serialize_to_storage {
-                Self::serialize_current(item_graph, resources, states_current).await?;
-                Self::serialize_goal(item_graph, resources, states_goal).await?;
+                // Commit current and goal states together, so that they are consistent
+                // with each other even if one of the writes fails.
+                let storage_transaction = resources.borrow::<Storage>().transaction();
+                Self::serialize_current(
+                    item_graph,
+                    resources,
+                    &storage_transaction,
+                    states_current,
+                )
+                .await?;
+                Self::serialize_goal(item_graph, resources, &storage_transaction, states_goal)
+                    .await?;
+                storage_transaction.commit().await?;
+
+                ExecutionHistorySerializer::<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>::record_in(
+                    flow.flow_id(),
+                    resources,
+                    states_current.keys().cloned().collect(),
+                )
+                .await?;
             }
         }
 
@@ -297,19 +519,70 @@ where
     async fn serialize_current(
         item_graph: &ItemGraph<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
         resources: &mut Resources<SetUp>,
+        storage: &Storage,
         states_current: &StatesCurrent,
     ) -> Result<(), <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError> {
         use peace_rt_model::StatesSerializer;
 
         let flow_dir = resources.borrow::<FlowDir>();
-        let storage = resources.borrow::<Storage>();
         let states_current_file = StatesCurrentFile::from(&*flow_dir);
 
-        StatesSerializer::serialize(&storage, item_graph, states_current, &states_current_file)
+        StatesSerializer::serialize(storage, item_graph, states_current, &states_current_file)
             .await?;
 
+        // Record the params that the current states were stored with.
+        if let Ok(params_digests) = resources.try_borrow::<ParamsDigests>() {
+            let params_digests_file = ParamsDigestsFile::from(&*flow_dir);
+            ParamsDigestsSerializer::serialize(storage, &params_digests, &params_digests_file)
+                .await?;
+        }
+
+        drop(flow_dir);
+
+        resources.insert(states_current_file);
+
+        Ok(())
+    }
+
+    /// Serializes current states, keeping the stored params digests for items
+    /// whose stored states were used.
+    async fn serialize_current_incremental(
+        item_graph: &ItemGraph<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        resources: &mut Resources<SetUp>,
+        storage: &Storage,
+        states_current: &StatesCurrent,
+        params_digests_stored: Option<&ParamsDigests>,
+        item_ids_reused: &[ItemId],
+    ) -> Result<(), <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError> {
+        use peace_rt_model::StatesSerializer;
+
+        let flow_dir = resources.borrow::<FlowDir>();
+        let states_current_file = StatesCurrentFile::from(&*flow_dir);
+
+        StatesSerializer::serialize(storage, item_graph, states_current, &states_current_file)
+            .await?;
+
+        if let Ok(params_digests) = resources.try_borrow::<ParamsDigests>() {
+            let params_digests = params_digests
+                .iter()
+                .filter_map(|(item_id, params_digest)| {
+                    if item_ids_reused.contains(item_id) {
+                        params_digests_stored
+                            .and_then(|params_digests_stored| params_digests_stored.get(item_id))
+                            .map(|params_digest_stored| {
+                                (item_id.clone(), params_digest_stored.clone())
+                            })
+                    } else {
+                        Some((item_id.clone(), params_digest.clone()))
+                    }
+                })
+                .collect::<ParamsDigests>();
+            let params_digests_file = ParamsDigestsFile::from(&*flow_dir);
+            ParamsDigestsSerializer::serialize(storage, &params_digests, &params_digests_file)
+                .await?;
+        }
+
         drop(flow_dir);
-        drop(storage);
 
         resources.insert(states_current_file);
 
@@ -319,18 +592,17 @@ where
     async fn serialize_goal(
         item_graph: &ItemGraph<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
         resources: &mut Resources<SetUp>,
+        storage: &Storage,
         states_goal: &StatesGoal,
     ) -> Result<(), <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError> {
         use peace_rt_model::StatesSerializer;
 
         let flow_dir = resources.borrow::<FlowDir>();
-        let storage = resources.borrow::<Storage>();
         let states_goal_file = StatesGoalFile::from(&*flow_dir);
 
-        StatesSerializer::serialize(&storage, item_graph, states_goal, &states_goal_file).await?;
+        StatesSerializer::serialize(storage, item_graph, states_goal, &states_goal_file).await?;
 
         drop(flow_dir);
-        drop(storage);
 
         resources.insert(states_goal_file);
 
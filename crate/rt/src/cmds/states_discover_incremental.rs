@@ -0,0 +1,19 @@
+use peace_cfg::ItemId;
+
+/// Which items to rediscover in [`StatesDiscoverCmd::current_incremental`].
+///
+/// The successors of these items are always rediscovered, as their states may
+/// depend on the rediscovered items' states. Stored current states are used for
+/// all other items.
+///
+/// [`StatesDiscoverCmd::current_incremental`]: crate::cmds::StatesDiscoverCmd::current_incremental
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StatesDiscoverIncremental {
+    /// Rediscover the items with the given IDs.
+    ItemIds(Vec<ItemId>),
+    /// Rediscover the items whose params have changed since their current
+    /// states were stored.
+    ///
+    /// If the params digests were not stored, all items are rediscovered.
+    ParamsChanged,
+}
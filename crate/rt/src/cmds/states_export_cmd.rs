@@ -0,0 +1,189 @@
+use std::{fmt::Debug, marker::PhantomData, path::Path};
+
+use chrono::{DateTime, Utc};
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::{SingleProfileSingleFlow, SingleProfileSingleFlowView},
+};
+use peace_resources::paths::{ParamsSpecsFile, StatesCurrentFile, StatesGoalFile};
+use peace_rt_model::{ParamsSpecsSerializer, StatesSerializer};
+use peace_rt_model_core::output::OutputWrite;
+
+pub use self::states_archive::StatesArchive;
+
+mod states_archive;
+
+/// Exports a flow's stored states, params specs, and execution metadata to a
+/// single archive file.
+///
+/// The archive can be restored into another workspace with
+/// [`StatesImportCmd`], such as when moving automation state between CI
+/// runners and developer machines.
+///
+/// [`StatesImportCmd`]: crate::cmds::StatesImportCmd
+#[derive(Debug)]
+pub struct StatesExportCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> StatesExportCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Writes the stored current states, goal states, and params specs to
+    /// `archive_path`.
+    ///
+    /// Files that do not exist are recorded as absent in the archive. If the
+    /// workspace storage is encrypted, the archive holds the decrypted values.
+    /// `Sensitive` fields are stored in the archive the same way as in the
+    /// workspace, so fields encrypted with a sensitive field key remain
+    /// encrypted.
+    ///
+    /// The [`StatesArchive`] is also presented to the `OutputWrite`.
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+        archive_path: &Path,
+    ) -> Result<StatesArchive, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let states_archive_result = Self::export(cmd_ctx.view(), archive_path).await;
+        let output = cmd_ctx.output_mut();
+
+        match states_archive_result {
+            Ok(states_archive) => {
+                output.present(&states_archive).await?;
+                Ok(states_archive)
+            }
+            Err(error) => {
+                output.write_err(&error).await?;
+                Err(error)
+            }
+        }
+    }
+
+    async fn export(
+        cmd_view: SingleProfileSingleFlowView<'_, CmdCtxTypesT>,
+        archive_path: &Path,
+    ) -> Result<StatesArchive, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError> {
+        let SingleProfileSingleFlowView {
+            workspace,
+            profile,
+            flow,
+            flow_dir,
+            params_specs_type_reg,
+            states_type_reg,
+            ..
+        } = cmd_view;
+        let storage = workspace.storage();
+        let flow_id = flow.flow_id();
+        let item_graph = flow.graph();
+
+        let states_current_file = StatesCurrentFile::from(flow_dir);
+        let states_current_stored = StatesSerializer::<
+            <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+        >::deserialize_stored_opt(
+            flow_id, storage, states_type_reg, &states_current_file
+        )
+        .await?;
+        let states_current = match states_current_stored {
+            Some(states_current_stored) => {
+                let states_serde =
+                    item_graph.states_serde::<serde_yaml::Value, _>(&states_current_stored);
+                let states_current = storage
+                    .storage_codec()
+                    .sensitive_serde_mode(&states_current_file)
+                    .scope(|| serde_yaml::to_value(&states_serde))
+                    .map_err(peace_rt_model::Error::StatesSerialize)?;
+                Some(states_current)
+            }
+            None => None,
+        };
+
+        let states_goal_file = StatesGoalFile::from(flow_dir);
+        let states_goal = if states_goal_file.exists() {
+            let states_goal_stored = StatesSerializer::<
+                <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+            >::deserialize_goal(
+                flow_id, storage, states_type_reg, &states_goal_file
+            )
+            .await?;
+            let states_serde = item_graph.states_serde::<serde_yaml::Value, _>(&states_goal_stored);
+            let states_goal = storage
+                .storage_codec()
+                .sensitive_serde_mode(&states_goal_file)
+                .scope(|| serde_yaml::to_value(&states_serde))
+                .map_err(peace_rt_model::Error::StatesSerialize)?;
+            Some(states_goal)
+        } else {
+            None
+        };
+
+        let params_specs_file = ParamsSpecsFile::from(flow_dir);
+        let params_specs = ParamsSpecsSerializer::<
+            <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+        >::deserialize_opt(
+            profile,
+            flow_id,
+            storage,
+            params_specs_type_reg,
+            &params_specs_file,
+        )
+        .await?;
+        let params_specs = match params_specs {
+            Some(params_specs) => {
+                let params_specs = storage
+                    .storage_codec()
+                    .sensitive_serde_mode(&params_specs_file)
+                    .scope(|| serde_yaml::to_value(&params_specs))
+                    .map_err(peace_rt_model::Error::ParamsSpecsSerialize)?;
+                Some(params_specs)
+            }
+            None => None,
+        };
+
+        // Flows without stored current states have not been executed.
+        let executed_at = tokio::fs::metadata(&states_current_file)
+            .await
+            .ok()
+            .filter(std::fs::Metadata::is_file)
+            .and_then(|metadata| metadata.modified().ok())
+            .map(DateTime::<Utc>::from);
+        let item_state_types = item_graph
+            .iter_insertion()
+            .map(|item| {
+                let state_type = item.state_type().type_name().to_string();
+                (item.id().clone(), state_type)
+            })
+            .collect();
+
+        let states_archive = StatesArchive {
+            flow_id: flow_id.clone(),
+            profile: profile.clone(),
+            exported_at: Utc::now(),
+            executed_at,
+            item_state_types,
+            states_current,
+            states_goal,
+            params_specs,
+        };
+
+        storage
+            .serialized_write(
+                "StatesExportCmd::export".to_string(),
+                archive_path,
+                &states_archive,
+                |error| peace_rt_model::Error::StatesArchiveSerialize {
+                    archive_path: archive_path.to_path_buf(),
+                    error,
+                },
+            )
+            .await?;
+
+        Ok(states_archive)
+    }
+}
+
+impl<CmdCtxTypesT> Default for StatesExportCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
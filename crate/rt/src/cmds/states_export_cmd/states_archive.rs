@@ -0,0 +1,79 @@
+use chrono::{DateTime, Utc};
+use peace_cfg::{FlowId, ItemId, Profile};
+use peace_fmt::{presentable::HeadingLevel, Presentable, Presenter};
+use peace_rt_model::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// A flow's stored states, params specs, and execution metadata for a profile.
+///
+/// This is written by [`StatesExportCmd`] and read by [`StatesImportCmd`].
+///
+/// States and params specs are held as YAML values, and are only deserialized
+/// into each item's types when imported.
+///
+/// [`StatesExportCmd`]: crate::cmds::StatesExportCmd
+/// [`StatesImportCmd`]: crate::cmds::StatesImportCmd
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StatesArchive {
+    /// ID of the flow whose states are archived.
+    pub flow_id: FlowId,
+    /// Profile that the states were exported from.
+    pub profile: Profile,
+    /// When the archive was exported.
+    pub exported_at: DateTime<Utc>,
+    /// When the flow's current states were last stored, if ever.
+    pub executed_at: Option<DateTime<Utc>>,
+    /// Type name of each item's `State`, in flow order.
+    pub item_state_types: IndexMap<ItemId, String>,
+    /// Stored current states, if the flow has been discovered.
+    pub states_current: Option<serde_yaml::Value>,
+    /// Stored goal states, if the flow has been discovered.
+    pub states_goal: Option<serde_yaml::Value>,
+    /// Stored params specs, if any.
+    pub params_specs: Option<serde_yaml::Value>,
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for StatesArchive {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        presenter
+            .heading(
+                HeadingLevel::Level1,
+                &format!("States archive: {}/{}", self.profile, self.flow_id),
+            )
+            .await?;
+
+        let executed_at = self
+            .executed_at
+            .map(|executed_at| executed_at.to_rfc3339())
+            .unwrap_or_else(|| String::from("never"));
+        let stored_flags = [
+            ("current states", self.states_current.is_some()),
+            ("goal states", self.states_goal.is_some()),
+            ("params specs", self.params_specs.is_some()),
+        ];
+        let stored = stored_flags
+            .iter()
+            .filter_map(|(name, is_stored)| is_stored.then_some(*name))
+            .collect::<Vec<&str>>();
+        let stored = if stored.is_empty() {
+            String::from("none")
+        } else {
+            stored.join(", ")
+        };
+
+        presenter
+            .list_bulleted_aligned(&[
+                ("exported at", self.exported_at.to_rfc3339()),
+                ("executed at", executed_at),
+                ("stored", stored),
+            ])
+            .await?;
+
+        presenter.heading(HeadingLevel::Level2, "Items").await?;
+        presenter.list_bulleted(self.item_state_types.keys()).await
+    }
+}
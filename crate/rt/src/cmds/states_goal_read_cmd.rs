@@ -1,12 +1,18 @@
 use std::{fmt::Debug, marker::PhantomData};
 
+use peace_cfg::ItemId;
 use peace_cmd::{
     ctx::{CmdCtx, CmdCtxTypesConstrained},
     scopes::SingleProfileSingleFlow,
 };
 use peace_cmd_model::CmdOutcome;
 use peace_cmd_rt::{CmdBlockWrapper, CmdExecution};
-use peace_resources::states::StatesGoalStored;
+use peace_resources::{
+    states::StatesGoalStored,
+    type_reg::untagged::{BoxDataTypeDowncast, BoxDtDisplay},
+};
+use peace_rt_model::StateQuery;
+use serde::Serialize;
 
 use crate::cmd_blocks::StatesGoalReadCmdBlock;
 
@@ -42,6 +48,52 @@ where
 
         cmd_execution_builder.build().exec(cmd_ctx).await
     }
+
+    /// Returns the stored goal state of a single item.
+    ///
+    /// The returned value is `None` if there is no stored goal state for the
+    /// item, or the state is not of type `S`.
+    pub async fn get<'ctx, S>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+        item_id: &ItemId,
+    ) -> Result<
+        CmdOutcome<Option<S>, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+    >
+    where
+        CmdCtxTypesT: 'ctx,
+        S: Clone + Debug + Serialize + Send + Sync + 'static,
+        BoxDtDisplay: BoxDataTypeDowncast<S>,
+    {
+        let cmd_outcome = Self::exec(cmd_ctx).await?;
+
+        Ok(cmd_outcome.map(|states_goal_stored| states_goal_stored.get::<S, _>(item_id).cloned()))
+    }
+
+    /// Returns the stored goal state of a single item, or a field within it,
+    /// as JSON.
+    ///
+    /// See [`StateQuery`] for how fields are specified.
+    pub async fn query<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+        state_query: &StateQuery,
+    ) -> Result<
+        CmdOutcome<serde_json::Value, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+    >
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let cmd_outcome = Self::exec(cmd_ctx).await?;
+
+        cmd_outcome
+            .map(|states_goal_stored| {
+                state_query
+                    .query(&states_goal_stored)
+                    .map_err(<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError::from)
+            })
+            .transpose()
+    }
 }
 
 impl<CmdCtxTypesT> Default for StatesGoalReadCmd<CmdCtxTypesT> {
@@ -0,0 +1,242 @@
+use std::{fmt::Debug, marker::PhantomData, path::Path};
+
+use peace_cfg::{state::SensitiveSerdeMode, ItemId};
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::{SingleProfileSingleFlow, SingleProfileSingleFlowView},
+};
+use peace_params::ParamsSpecs;
+use peace_resources::{
+    paths::{ParamsSpecsFile, StatesCurrentFile, StatesGoalFile},
+    states::{
+        ts::{CurrentStored, GoalStored},
+        States,
+    },
+    type_reg::untagged::{DataTypeWrapper, TypeMap, TypeReg},
+};
+use peace_rt_model::{ParamsSpecsSerializer, StatesSerializer};
+use peace_rt_model_core::output::OutputWrite;
+
+use crate::cmds::StatesArchive;
+
+/// Application error type for a `CmdCtxTypes`.
+type AppError<CmdCtxTypesT> = <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError;
+
+/// Imports a flow's stored states and params specs from an archive written by
+/// [`StatesExportCmd`].
+///
+/// [`StatesExportCmd`]: crate::cmds::StatesExportCmd
+#[derive(Debug)]
+pub struct StatesImportCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> StatesImportCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Reads the archive at `archive_path`, and writes its current states, goal
+    /// states, and params specs to the profile's flow directory.
+    ///
+    /// The archive must be exported from the same flow, and each archived item
+    /// must exist in the flow with the same `State` type. Nothing is written
+    /// unless every part of the archive is deserialized successfully. If the
+    /// workspace storage is encrypted, the files are encrypted with the
+    /// workspace key. Encrypted `Sensitive` fields can only be imported into a
+    /// workspace with the same sensitive field key.
+    ///
+    /// The imported states are not inserted into this `CmdCtx`'s resources, so
+    /// a new `CmdCtx` should be built to use them.
+    ///
+    /// The [`StatesArchive`] is also presented to the `OutputWrite`.
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+        archive_path: &Path,
+    ) -> Result<StatesArchive, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let states_archive_result = Self::import(cmd_ctx.view(), archive_path).await;
+        let output = cmd_ctx.output_mut();
+
+        match states_archive_result {
+            Ok(states_archive) => {
+                output.present(&states_archive).await?;
+                Ok(states_archive)
+            }
+            Err(error) => {
+                output.write_err(&error).await?;
+                Err(error)
+            }
+        }
+    }
+
+    async fn import(
+        cmd_view: SingleProfileSingleFlowView<'_, CmdCtxTypesT>,
+        archive_path: &Path,
+    ) -> Result<StatesArchive, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError> {
+        let SingleProfileSingleFlowView {
+            workspace,
+            flow,
+            flow_dir,
+            params_specs_type_reg,
+            states_type_reg,
+            ..
+        } = cmd_view;
+        let storage = workspace.storage();
+        let flow_id = flow.flow_id();
+        let item_graph = flow.graph();
+
+        let states_archive = storage
+            .serialized_read::<StatesArchive, _>(
+                "StatesImportCmd::import".to_string(),
+                archive_path,
+                |error, _| peace_rt_model::Error::StatesArchiveDeserialize {
+                    archive_path: archive_path.to_path_buf(),
+                    error,
+                },
+            )
+            .await?;
+
+        if &states_archive.flow_id != flow_id {
+            return Err(peace_rt_model::Error::StatesArchiveFlowIdMismatch {
+                archive_path: archive_path.to_path_buf(),
+                flow_id_archive: states_archive.flow_id.clone(),
+                flow_id: flow_id.clone(),
+            }
+            .into());
+        }
+
+        let mut item_ids_unknown = Vec::<ItemId>::new();
+        let mut item_ids_state_type_mismatch = Vec::<ItemId>::new();
+        states_archive
+            .item_state_types
+            .iter()
+            .for_each(|(item_id, state_type)| {
+                match item_graph
+                    .iter_insertion()
+                    .find(|item| item.id() == item_id)
+                {
+                    Some(item) => {
+                        if item.state_type().type_name() != state_type {
+                            item_ids_state_type_mismatch.push(item_id.clone());
+                        }
+                    }
+                    None => item_ids_unknown.push(item_id.clone()),
+                }
+            });
+        if !item_ids_unknown.is_empty() || !item_ids_state_type_mismatch.is_empty() {
+            return Err(peace_rt_model::Error::StatesArchiveIncompatible {
+                archive_path: archive_path.to_path_buf(),
+                item_ids_unknown,
+                item_ids_state_type_mismatch,
+            }
+            .into());
+        }
+
+        // Deserialize everything before writing, so that a failure does not leave
+        // the flow directory partially imported.
+        let storage_codec = storage.storage_codec();
+        let states_current_file = StatesCurrentFile::from(flow_dir);
+        let states_goal_file = StatesGoalFile::from(flow_dir);
+        let params_specs_file = ParamsSpecsFile::from(flow_dir);
+        let states_current = match states_archive.states_current.as_ref() {
+            Some(states_current) => {
+                let type_map = type_map_deserialize::<_, AppError<CmdCtxTypesT>>(
+                    archive_path,
+                    storage_codec.sensitive_serde_mode(&states_current_file),
+                    states_type_reg,
+                    states_current,
+                )?;
+                Some(States::<CurrentStored>::from(type_map))
+            }
+            None => None,
+        };
+        let states_goal = match states_archive.states_goal.as_ref() {
+            Some(states_goal) => {
+                let type_map = type_map_deserialize::<_, AppError<CmdCtxTypesT>>(
+                    archive_path,
+                    storage_codec.sensitive_serde_mode(&states_goal_file),
+                    states_type_reg,
+                    states_goal,
+                )?;
+                Some(States::<GoalStored>::from(type_map))
+            }
+            None => None,
+        };
+        let params_specs = match states_archive.params_specs.as_ref() {
+            Some(params_specs) => {
+                let type_map = type_map_deserialize::<_, AppError<CmdCtxTypesT>>(
+                    archive_path,
+                    storage_codec.sensitive_serde_mode(&params_specs_file),
+                    params_specs_type_reg,
+                    params_specs,
+                )?;
+                Some(ParamsSpecs::from(type_map))
+            }
+            None => None,
+        };
+
+        if let Some(states_current) = states_current {
+            StatesSerializer::<AppError<CmdCtxTypesT>>::serialize(
+                storage,
+                item_graph,
+                &states_current,
+                &states_current_file,
+            )
+            .await?;
+        }
+        if let Some(states_goal) = states_goal {
+            StatesSerializer::<AppError<CmdCtxTypesT>>::serialize(
+                storage,
+                item_graph,
+                &states_goal,
+                &states_goal_file,
+            )
+            .await?;
+        }
+        if let Some(params_specs) = params_specs {
+            ParamsSpecsSerializer::<AppError<CmdCtxTypesT>>::serialize(
+                storage,
+                &params_specs,
+                &params_specs_file,
+            )
+            .await?;
+        }
+
+        Ok(states_archive)
+    }
+}
+
+/// Deserializes an archived map of item ID to value using the given type
+/// registry.
+///
+/// Entries for items that are not in the type registry are ignored, as the
+/// archive's item IDs are validated beforehand. `Sensitive` fields are
+/// deserialized with the mode of the file that the map is imported into.
+fn type_map_deserialize<BoxDT, E>(
+    archive_path: &Path,
+    sensitive_serde_mode: SensitiveSerdeMode,
+    type_reg: &TypeReg<ItemId, BoxDT>,
+    value: &serde_yaml::Value,
+) -> Result<TypeMap<ItemId, BoxDT>, E>
+where
+    BoxDT: DataTypeWrapper + 'static,
+    E: From<peace_rt_model::Error>,
+{
+    sensitive_serde_mode
+        .scope(|| {
+            type_reg.deserialize_map_opt_with_unknowns::<'_, serde_yaml::Value, _, _>(value.clone())
+        })
+        .map(|type_map_opt| type_map_opt.into_type_map())
+        .map_err(|error| {
+            E::from(peace_rt_model::Error::StatesArchiveDeserialize {
+                archive_path: archive_path.to_path_buf(),
+                error,
+            })
+        })
+}
+
+impl<CmdCtxTypesT> Default for StatesImportCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
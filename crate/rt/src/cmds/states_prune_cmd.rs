@@ -0,0 +1,87 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::{SingleProfileSingleFlow, SingleProfileSingleFlowView},
+};
+use peace_params::ParamsSpecs;
+use peace_rt_model::{ItemGraph, StatesPruneOutcome, StatesPruner};
+use peace_rt_model_core::output::OutputWrite;
+
+/// Removes stored states of items that are no longer in the flow.
+///
+/// Unlike [`StatesReserializeCmd`], which removes these entries
+/// unconditionally, this only removes an item's stored states after
+/// discovering that its resource is cleaned up. Items whose resources still
+/// exist, or could not be discovered, are kept and presented as a warning.
+///
+/// To prune stored states whenever the command context is built, use
+/// `with_states_prune` on the command context builder.
+///
+/// [`StatesReserializeCmd`]: crate::cmds::StatesReserializeCmd
+#[derive(Debug)]
+pub struct StatesPruneCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> StatesPruneCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Prunes stored states of items that are not in the flow.
+    ///
+    /// `item_graph_removed` contains the items that were removed from the
+    /// flow, which are used to discover whether their resources are cleaned
+    /// up, and `params_specs_removed` contains their params specs. See
+    /// [`StatesPruner`] for details.
+    ///
+    /// The params specs must be provided, as params specs for items that are
+    /// not in the flow are dropped from the `ParamsSpecsFile` when the command
+    /// context is built.
+    ///
+    /// The [`StatesPruneOutcome`] is also presented to the `OutputWrite`.
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+        item_graph_removed: &ItemGraph<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        params_specs_removed: &ParamsSpecs,
+    ) -> Result<StatesPruneOutcome, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let SingleProfileSingleFlowView {
+            workspace,
+            flow,
+            flow_dir,
+            states_type_reg,
+            resources,
+            ..
+        } = cmd_ctx.view();
+
+        let states_prune_outcome_result = StatesPruner::prune(
+            flow.flow_id(),
+            flow_dir,
+            workspace.storage(),
+            states_type_reg,
+            item_graph_removed,
+            params_specs_removed,
+            resources,
+        )
+        .await;
+        let output = cmd_ctx.output_mut();
+
+        match states_prune_outcome_result {
+            Ok(states_prune_outcome) => {
+                output.present(&states_prune_outcome).await?;
+                Ok(states_prune_outcome)
+            }
+            Err(error) => {
+                output.write_err(&error).await?;
+                Err(error)
+            }
+        }
+    }
+}
+
+impl<CmdCtxTypesT> Default for StatesPruneCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
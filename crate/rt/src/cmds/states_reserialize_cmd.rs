@@ -0,0 +1,117 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::{SingleProfileSingleFlow, SingleProfileSingleFlowView},
+};
+use peace_resources::paths::{ParamsSpecsFile, StatesCurrentFile, StatesGoalFile};
+use peace_rt_model::{ParamsSpecsSerializer, StatesSerializer};
+use peace_rt_model_core::output::OutputWrite;
+
+pub use self::states_reserialize_outcome::StatesReserializeOutcome;
+
+mod states_reserialize_outcome;
+
+/// Rewrites stored states and params specs using the current type registries.
+///
+/// This is useful after items are removed from or renamed in a flow, as the
+/// stored files otherwise keep entries for those items indefinitely.
+#[derive(Debug)]
+pub struct StatesReserializeCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> StatesReserializeCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Re-reads the stored current states, goal states, and params specs, and
+    /// rewrites them with the current type registries.
+    ///
+    /// Entries for items that are not in the flow are removed, and their IDs
+    /// are returned in the [`StatesReserializeOutcome`], which is also
+    /// presented to the `OutputWrite`. Files that do not exist are skipped.
+    ///
+    /// If the workspace storage is encrypted, the files are re-encrypted with
+    /// the workspace key.
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+    ) -> Result<StatesReserializeOutcome, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let outcome_result = Self::reserialize(cmd_ctx.view()).await;
+        let output = cmd_ctx.output_mut();
+
+        match outcome_result {
+            Ok(states_reserialize_outcome) => {
+                output.present(&states_reserialize_outcome).await?;
+                Ok(states_reserialize_outcome)
+            }
+            Err(error) => {
+                output.write_err(&error).await?;
+                Err(error)
+            }
+        }
+    }
+
+    async fn reserialize(
+        cmd_view: SingleProfileSingleFlowView<'_, CmdCtxTypesT>,
+    ) -> Result<StatesReserializeOutcome, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError> {
+        let SingleProfileSingleFlowView {
+            workspace,
+            profile,
+            flow,
+            flow_dir,
+            params_specs_type_reg,
+            states_type_reg,
+            ..
+        } = cmd_view;
+        let storage = workspace.storage();
+        let flow_id = flow.flow_id();
+        let item_graph = flow.graph();
+
+        let states_current_file = StatesCurrentFile::from(flow_dir);
+        let states_current_item_ids_removed = StatesSerializer::reserialize(
+            flow_id,
+            storage,
+            item_graph,
+            states_type_reg,
+            &states_current_file,
+        )
+        .await?
+        .unwrap_or_default();
+
+        let states_goal_file = StatesGoalFile::from(flow_dir);
+        let states_goal_item_ids_removed = StatesSerializer::reserialize(
+            flow_id,
+            storage,
+            item_graph,
+            states_type_reg,
+            &states_goal_file,
+        )
+        .await?
+        .unwrap_or_default();
+
+        let params_specs_file = ParamsSpecsFile::from(flow_dir);
+        let params_specs_item_ids_removed = ParamsSpecsSerializer::reserialize(
+            profile,
+            flow_id,
+            storage,
+            params_specs_type_reg,
+            &params_specs_file,
+        )
+        .await?
+        .unwrap_or_default();
+
+        Ok(StatesReserializeOutcome::new(
+            states_current_item_ids_removed,
+            states_goal_item_ids_removed,
+            params_specs_item_ids_removed,
+        ))
+    }
+}
+
+impl<CmdCtxTypesT> Default for StatesReserializeCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
@@ -0,0 +1,80 @@
+use peace_cfg::ItemId;
+use peace_fmt::{presentable::HeadingLevel, Presentable, Presenter};
+use serde::{Deserialize, Serialize};
+
+/// Item IDs whose entries were removed by [`StatesReserializeCmd`].
+///
+/// [`StatesReserializeCmd`]: crate::cmds::StatesReserializeCmd
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatesReserializeOutcome {
+    /// Item IDs removed from the current states file.
+    states_current_item_ids_removed: Vec<ItemId>,
+    /// Item IDs removed from the goal states file.
+    states_goal_item_ids_removed: Vec<ItemId>,
+    /// Item IDs removed from the params specs file.
+    params_specs_item_ids_removed: Vec<ItemId>,
+}
+
+impl StatesReserializeOutcome {
+    /// Returns a new `StatesReserializeOutcome`.
+    pub fn new(
+        states_current_item_ids_removed: Vec<ItemId>,
+        states_goal_item_ids_removed: Vec<ItemId>,
+        params_specs_item_ids_removed: Vec<ItemId>,
+    ) -> Self {
+        Self {
+            states_current_item_ids_removed,
+            states_goal_item_ids_removed,
+            params_specs_item_ids_removed,
+        }
+    }
+
+    /// Returns the item IDs removed from the current states file.
+    pub fn states_current_item_ids_removed(&self) -> &[ItemId] {
+        &self.states_current_item_ids_removed
+    }
+
+    /// Returns the item IDs removed from the goal states file.
+    pub fn states_goal_item_ids_removed(&self) -> &[ItemId] {
+        &self.states_goal_item_ids_removed
+    }
+
+    /// Returns the item IDs removed from the params specs file.
+    pub fn params_specs_item_ids_removed(&self) -> &[ItemId] {
+        &self.params_specs_item_ids_removed
+    }
+
+    /// Returns whether no entries were removed from any file.
+    pub fn is_empty(&self) -> bool {
+        self.states_current_item_ids_removed.is_empty()
+            && self.states_goal_item_ids_removed.is_empty()
+            && self.params_specs_item_ids_removed.is_empty()
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for StatesReserializeOutcome {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        if self.is_empty() {
+            return presenter.text("No stored entries removed.").await;
+        }
+
+        let sections = [
+            ("Current states", &self.states_current_item_ids_removed),
+            ("Goal states", &self.states_goal_item_ids_removed),
+            ("Params specs", &self.params_specs_item_ids_removed),
+        ];
+        for (heading, item_ids_removed) in sections {
+            if item_ids_removed.is_empty() {
+                continue;
+            }
+            presenter.heading(HeadingLevel::Level1, heading).await?;
+            presenter.list_bulleted(item_ids_removed.iter()).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::SingleProfileSingleFlow,
+};
+use peace_cmd_model::CmdOutcome;
+use peace_resources::states::{
+    ts::{Current, GoalStored},
+    DiffSummary, StateDiffs,
+};
+use peace_rt_model_core::output::OutputWrite;
+
+use crate::cmds::DiffCmd;
+
+pub use self::{states_drift::StatesDrift, states_watch_opts::StatesWatchOpts};
+
+mod states_drift;
+mod states_watch_opts;
+
+/// Repeatedly discovers current states, and reports drift from the stored
+/// goal states.
+#[derive(Debug)]
+pub struct StatesWatchCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> StatesWatchCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Discovers current states every [`interval`], and compares them against
+    /// the stored goal states until interrupted.
+    ///
+    /// Whenever any item's current state differs from its goal state, a
+    /// [`StatesDrift`] is presented to the `OutputWrite`, and sent to the
+    /// [`drift_tx`] channel if one is set. Item errors are written to the
+    /// `OutputWrite`, and watching continues.
+    ///
+    /// Watching stops when:
+    ///
+    /// * The command is interrupted, in which case the interrupted outcome is
+    ///   returned.
+    /// * The [`iteration_limit`] is reached, in which case the last outcome is
+    ///   returned.
+    ///
+    /// [`StatesDiscoverCmd::goal`] must have run prior to this command to
+    /// store the goal states. Discovered current states are not serialized.
+    ///
+    /// [`drift_tx`]: StatesWatchOpts::with_drift_tx
+    /// [`interval`]: StatesWatchOpts::new
+    /// [`iteration_limit`]: StatesWatchOpts::with_iteration_limit
+    /// [`StatesDiscoverCmd::goal`]: crate::cmds::StatesDiscoverCmd::goal
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+        states_watch_opts: StatesWatchOpts,
+    ) -> Result<
+        CmdOutcome<(StateDiffs, DiffSummary), <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+    >
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let StatesWatchOpts {
+            interval,
+            iteration_limit,
+            drift_tx,
+        } = states_watch_opts;
+
+        let mut iteration = 0;
+        loop {
+            iteration += 1;
+
+            let cmd_outcome = DiffCmd::diff_with_summary::<Current, GoalStored>(cmd_ctx).await?;
+            let output = cmd_ctx.output_mut();
+            match &cmd_outcome {
+                CmdOutcome::Complete {
+                    value: (state_diffs, diff_summary),
+                    cmd_blocks_processed: _,
+                } => {
+                    if let Some(states_drift) =
+                        StatesDrift::from_diffs(iteration, state_diffs, diff_summary)
+                    {
+                        output.present(&states_drift).await?;
+                        if let Some(drift_tx) = drift_tx.as_ref() {
+                            // The receiver may have been dropped, but we still present
+                            // drift to the output.
+                            let _drift_send_unused = drift_tx.send(states_drift).await;
+                        }
+                    }
+                }
+                CmdOutcome::ItemError { errors, .. } => {
+                    for error in errors.values() {
+                        output.write_err(error).await?;
+                    }
+                }
+                CmdOutcome::BlockInterrupted { .. } | CmdOutcome::ExecutionInterrupted { .. } => {
+                    return Ok(cmd_outcome);
+                }
+            }
+
+            if iteration_limit.is_some_and(|iteration_limit| iteration >= iteration_limit) {
+                return Ok(cmd_outcome);
+            }
+
+            tokio::time::sleep(interval).await;
+
+            // Interruptions received while sleeping are only detected here, so
+            // we do not run another discovery.
+            let interrupt_signal = cmd_ctx
+                .view()
+                .interruptibility_state
+                .item_interrupt_poll(false);
+            if interrupt_signal.is_some() {
+                return Ok(cmd_outcome);
+            }
+        }
+    }
+}
+
+impl<CmdCtxTypesT> Default for StatesWatchCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
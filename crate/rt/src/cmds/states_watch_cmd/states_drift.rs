@@ -0,0 +1,82 @@
+use peace_cfg::DiffSeverity;
+use peace_fmt::{presentable::HeadingLevel, Presentable, Presenter};
+use peace_resources::{
+    internal::StateDiffsMut,
+    states::{DiffSummary, StateDiffs},
+};
+use serde::Serialize;
+
+/// Items whose current state differs from their goal state, emitted by
+/// [`StatesWatchCmd`].
+///
+/// [`StatesWatchCmd`]: crate::cmds::StatesWatchCmd
+#[derive(Debug, Serialize)]
+pub struct StatesDrift {
+    /// The discovery iteration that detected the drift, starting from 1.
+    iteration: u32,
+    /// Severity of each drifted item's diff.
+    diff_summary: DiffSummary,
+    /// State diff of each drifted item.
+    state_diffs: StateDiffs,
+}
+
+impl StatesDrift {
+    /// Returns the drift between current and goal states, if any item is not
+    /// in sync.
+    pub(crate) fn from_diffs(
+        iteration: u32,
+        state_diffs: &StateDiffs,
+        diff_summary: &DiffSummary,
+    ) -> Option<Self> {
+        let mut diff_summary_drifted = DiffSummary::new();
+        let mut state_diffs_drifted = StateDiffsMut::new();
+        diff_summary
+            .iter()
+            .filter(|(_item_id, diff_severity)| **diff_severity != DiffSeverity::InSync)
+            .for_each(|(item_id, diff_severity)| {
+                diff_summary_drifted.insert(item_id.clone(), *diff_severity);
+                if let Some(state_diff) = state_diffs.get_raw(item_id) {
+                    state_diffs_drifted.insert_raw(item_id.clone(), state_diff.clone());
+                }
+            });
+        if diff_summary_drifted.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            iteration,
+            diff_summary: diff_summary_drifted,
+            state_diffs: StateDiffs::from(state_diffs_drifted),
+        })
+    }
+
+    /// Returns the discovery iteration that detected the drift, starting from
+    /// 1.
+    pub fn iteration(&self) -> u32 {
+        self.iteration
+    }
+
+    /// Returns the severity of each drifted item's diff.
+    pub fn diff_summary(&self) -> &DiffSummary {
+        &self.diff_summary
+    }
+
+    /// Returns the state diff of each drifted item.
+    pub fn state_diffs(&self) -> &StateDiffs {
+        &self.state_diffs
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for StatesDrift {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        presenter
+            .heading(HeadingLevel::Level1, "Drift detected")
+            .await?;
+        self.state_diffs.present(presenter).await?;
+        self.diff_summary.present(presenter).await
+    }
+}
@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+use tokio::sync::mpsc::Sender;
+
+use crate::cmds::StatesDrift;
+
+/// Options for [`StatesWatchCmd`].
+///
+/// [`StatesWatchCmd`]: crate::cmds::StatesWatchCmd
+#[derive(Clone, Debug)]
+pub struct StatesWatchOpts {
+    /// Duration to wait between each discovery.
+    pub(crate) interval: Duration,
+    /// Number of discoveries to run before returning, if any.
+    pub(crate) iteration_limit: Option<u32>,
+    /// Channel to send drift events to, for programmatic consumers.
+    pub(crate) drift_tx: Option<Sender<StatesDrift>>,
+}
+
+impl StatesWatchOpts {
+    /// Returns new `StatesWatchOpts` that discovers states every `interval`.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            iteration_limit: None,
+            drift_tx: None,
+        }
+    }
+
+    /// Sets the number of discoveries to run before returning.
+    ///
+    /// By default, states are watched until the command is interrupted.
+    pub fn with_iteration_limit(mut self, iteration_limit: u32) -> Self {
+        self.iteration_limit = Some(iteration_limit);
+        self
+    }
+
+    /// Sets the channel to send [`StatesDrift`] events to.
+    pub fn with_drift_tx(mut self, drift_tx: Sender<StatesDrift>) -> Self {
+        self.drift_tx = Some(drift_tx);
+        self
+    }
+
+    /// Returns the duration to wait between each discovery.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Returns the number of discoveries to run before returning, if any.
+    pub fn iteration_limit(&self) -> Option<u32> {
+        self.iteration_limit
+    }
+}
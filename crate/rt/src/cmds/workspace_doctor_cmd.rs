@@ -0,0 +1,311 @@
+use std::{
+    ffi::OsStr,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::{SingleProfileSingleFlow, SingleProfileSingleFlowView},
+};
+use peace_resources::paths::{FlowDir, ParamsSpecsFile, StatesCurrentFile, StatesGoalFile};
+use peace_rt_model::{NativeError, ParamsSpecsSerializer, StatesSerializer};
+use peace_rt_model_core::output::OutputWrite;
+
+pub use self::{workspace_doctor_report::WorkspaceDoctorReport, workspace_issue::WorkspaceIssue};
+
+mod workspace_doctor_report;
+mod workspace_issue;
+
+/// Inspects the workspace for inconsistencies, such as those left behind by a
+/// crashed run.
+///
+/// The following are checked within the flow directory:
+///
+/// * Temporary files left behind by interrupted writes.
+/// * Goal states that are missing or older than the current states.
+/// * Stored states and params specs with entries for items not in the flow.
+/// * Stored states and params specs that cannot be read with the flow's type
+///   registries.
+///
+/// See [`WorkspaceIssue`] for which issues may be fixed automatically.
+#[derive(Debug)]
+pub struct WorkspaceDoctorCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> WorkspaceDoctorCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Returns the issues found in the workspace, without fixing them.
+    ///
+    /// The returned [`WorkspaceDoctorReport`] is also presented to the
+    /// `OutputWrite`.
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+    ) -> Result<WorkspaceDoctorReport, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        Self::exec_internal(cmd_ctx, false).await
+    }
+
+    /// Returns the issues found in the workspace, fixing the issues that are
+    /// safe to fix.
+    ///
+    /// Fixed issues are returned in [`WorkspaceDoctorReport::issues_fixed`],
+    /// and issues that need manual intervention are returned in
+    /// [`WorkspaceDoctorReport::issues`]. The report is also presented to the
+    /// `OutputWrite`.
+    pub async fn exec_with_fix<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+    ) -> Result<WorkspaceDoctorReport, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        Self::exec_internal(cmd_ctx, true).await
+    }
+
+    async fn exec_internal<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+        fix: bool,
+    ) -> Result<WorkspaceDoctorReport, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let report_result = async {
+            let cmd_view = cmd_ctx.view();
+            let issues = Self::diagnose(&cmd_view).await?;
+            if fix {
+                Self::fix(&cmd_view, issues).await
+            } else {
+                Ok(WorkspaceDoctorReport::new(issues, Vec::new()))
+            }
+        }
+        .await;
+        let output = cmd_ctx.output_mut();
+
+        match report_result {
+            Ok(workspace_doctor_report) => {
+                output.present(&workspace_doctor_report).await?;
+                Ok(workspace_doctor_report)
+            }
+            Err(error) => {
+                output.write_err(&error).await?;
+                Err(error)
+            }
+        }
+    }
+
+    async fn diagnose(
+        cmd_view: &SingleProfileSingleFlowView<'_, CmdCtxTypesT>,
+    ) -> Result<Vec<WorkspaceIssue>, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError> {
+        let SingleProfileSingleFlowView {
+            workspace,
+            profile,
+            flow,
+            flow_dir,
+            params_specs_type_reg,
+            states_type_reg,
+            ..
+        } = cmd_view;
+        let storage = workspace.storage();
+        let flow_id = flow.flow_id();
+
+        let mut issues = Self::tmp_files_leftover(flow_dir)
+            .await?
+            .into_iter()
+            .map(|path| WorkspaceIssue::TmpFileLeftover { path })
+            .collect::<Vec<WorkspaceIssue>>();
+
+        let states_current_file = StatesCurrentFile::from(*flow_dir);
+        let states_goal_file = StatesGoalFile::from(*flow_dir);
+        if Self::states_goal_outdated(&states_current_file, &states_goal_file).await {
+            issues.push(WorkspaceIssue::StatesGoalOutdated {
+                path: states_goal_file.to_path_buf(),
+            });
+        }
+
+        for states_file_path in [states_current_file.as_ref(), states_goal_file.as_ref()] {
+            let item_ids_unknown_result =
+                StatesSerializer::<peace_rt_model::Error>::item_ids_unknown(
+                    flow_id,
+                    storage,
+                    states_type_reg,
+                    states_file_path,
+                )
+                .await;
+            issues.extend(Self::item_ids_unknown_issue(
+                states_file_path,
+                item_ids_unknown_result,
+            ));
+        }
+
+        let params_specs_file = ParamsSpecsFile::from(*flow_dir);
+        let item_ids_unknown_result =
+            ParamsSpecsSerializer::<peace_rt_model::Error>::item_ids_unknown(
+                profile,
+                flow_id,
+                storage,
+                params_specs_type_reg,
+                &params_specs_file,
+            )
+            .await;
+        issues.extend(Self::item_ids_unknown_issue(
+            &params_specs_file,
+            item_ids_unknown_result,
+        ));
+
+        Ok(issues)
+    }
+
+    async fn fix(
+        cmd_view: &SingleProfileSingleFlowView<'_, CmdCtxTypesT>,
+        issues: Vec<WorkspaceIssue>,
+    ) -> Result<WorkspaceDoctorReport, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError> {
+        let SingleProfileSingleFlowView {
+            workspace,
+            profile,
+            flow,
+            flow_dir,
+            params_specs_type_reg,
+            states_type_reg,
+            ..
+        } = cmd_view;
+        let storage = workspace.storage();
+        let flow_id = flow.flow_id();
+        let params_specs_file = ParamsSpecsFile::from(*flow_dir);
+
+        let (issues_fixable, issues) = issues
+            .into_iter()
+            .partition::<Vec<WorkspaceIssue>, _>(WorkspaceIssue::is_fixable);
+
+        for issue in issues_fixable.iter() {
+            match issue {
+                WorkspaceIssue::TmpFileLeftover { path } => {
+                    tokio::fs::remove_file(path).await.map_err(|error| {
+                        peace_rt_model::Error::Native(NativeError::FileRemove {
+                            path: path.clone(),
+                            error,
+                        })
+                    })?;
+                }
+                WorkspaceIssue::ItemIdsUnknown { path, .. } => {
+                    if path.as_path() == AsRef::<Path>::as_ref(&params_specs_file) {
+                        ParamsSpecsSerializer::<
+                            <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+                        >::reserialize(
+                            profile,
+                            flow_id,
+                            storage,
+                            params_specs_type_reg,
+                            &params_specs_file,
+                        )
+                        .await?;
+                    } else {
+                        StatesSerializer::reserialize(
+                            flow_id,
+                            storage,
+                            flow.graph(),
+                            states_type_reg,
+                            path,
+                        )
+                        .await?;
+                    }
+                }
+                WorkspaceIssue::StatesGoalOutdated { .. }
+                | WorkspaceIssue::StoredFileInvalid { .. } => {}
+            }
+        }
+
+        Ok(WorkspaceDoctorReport::new(issues, issues_fixable))
+    }
+
+    /// Returns the paths of temporary files left behind by interrupted atomic
+    /// writes.
+    async fn tmp_files_leftover(flow_dir: &FlowDir) -> Result<Vec<PathBuf>, peace_rt_model::Error> {
+        let mut tmp_file_paths = Vec::new();
+        if !tokio::fs::try_exists(flow_dir).await.unwrap_or(false) {
+            return Ok(tmp_file_paths);
+        }
+
+        let mut flow_read_dir = tokio::fs::read_dir(flow_dir).await.map_err(|error| {
+            peace_rt_model::Error::Native(NativeError::FlowDirRead {
+                flow_dir: flow_dir.to_path_buf(),
+                error,
+            })
+        })?;
+        while let Some(entry) = flow_read_dir.next_entry().await.map_err(|error| {
+            peace_rt_model::Error::Native(NativeError::FlowDirEntryRead {
+                flow_dir: flow_dir.to_path_buf(),
+                error,
+            })
+        })? {
+            let entry_path = entry.path();
+            let is_tmp_file = entry_path.extension().and_then(OsStr::to_str) == Some("tmp")
+                && entry
+                    .file_type()
+                    .await
+                    .map(|file_type| file_type.is_file())
+                    .unwrap_or(false);
+            if is_tmp_file {
+                tmp_file_paths.push(entry_path);
+            }
+        }
+        tmp_file_paths.sort();
+
+        Ok(tmp_file_paths)
+    }
+
+    /// Returns whether the goal states file is missing or older than the
+    /// current states file.
+    ///
+    /// Returns `false` if the current states file does not exist.
+    async fn states_goal_outdated(
+        states_current_file: &StatesCurrentFile,
+        states_goal_file: &StatesGoalFile,
+    ) -> bool {
+        let modified = |path: PathBuf| async move {
+            tokio::fs::metadata(path)
+                .await
+                .ok()
+                .and_then(|metadata| metadata.modified().ok())
+        };
+        let Some(states_current_modified) = modified(states_current_file.to_path_buf()).await
+        else {
+            return false;
+        };
+
+        modified(states_goal_file.to_path_buf())
+            .await
+            .is_none_or(|states_goal_modified| states_goal_modified < states_current_modified)
+    }
+
+    fn item_ids_unknown_issue(
+        path: &Path,
+        item_ids_unknown_result: Result<Option<Vec<peace_cfg::ItemId>>, peace_rt_model::Error>,
+    ) -> Option<WorkspaceIssue> {
+        match item_ids_unknown_result {
+            Ok(Some(item_ids)) if !item_ids.is_empty() => Some(WorkspaceIssue::ItemIdsUnknown {
+                path: path.to_path_buf(),
+                item_ids,
+            }),
+            Ok(_) => None,
+            Err(error) => {
+                let error = match std::error::Error::source(&error) {
+                    Some(source) => format!("{error} {source}"),
+                    None => error.to_string(),
+                };
+                Some(WorkspaceIssue::StoredFileInvalid {
+                    path: path.to_path_buf(),
+                    error,
+                })
+            }
+        }
+    }
+}
+
+impl<CmdCtxTypesT> Default for WorkspaceDoctorCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
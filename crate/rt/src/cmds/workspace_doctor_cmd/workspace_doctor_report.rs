@@ -0,0 +1,63 @@
+use peace_fmt::{presentable::HeadingLevel, Presentable, Presenter};
+use serde::{Deserialize, Serialize};
+
+use crate::cmds::WorkspaceIssue;
+
+/// Inconsistencies found in the workspace by [`WorkspaceDoctorCmd`].
+///
+/// [`WorkspaceDoctorCmd`]: crate::cmds::WorkspaceDoctorCmd
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkspaceDoctorReport {
+    /// Issues that remain in the workspace.
+    issues: Vec<WorkspaceIssue>,
+    /// Issues that were fixed.
+    issues_fixed: Vec<WorkspaceIssue>,
+}
+
+impl WorkspaceDoctorReport {
+    /// Returns a new `WorkspaceDoctorReport`.
+    pub fn new(issues: Vec<WorkspaceIssue>, issues_fixed: Vec<WorkspaceIssue>) -> Self {
+        Self {
+            issues,
+            issues_fixed,
+        }
+    }
+
+    /// Returns the issues that remain in the workspace.
+    pub fn issues(&self) -> &[WorkspaceIssue] {
+        &self.issues
+    }
+
+    /// Returns the issues that were fixed.
+    pub fn issues_fixed(&self) -> &[WorkspaceIssue] {
+        &self.issues_fixed
+    }
+
+    /// Returns whether no issues were found.
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty() && self.issues_fixed.is_empty()
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for WorkspaceDoctorReport {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        if self.is_empty() {
+            return presenter.text("No workspace issues found.").await;
+        }
+
+        let sections = [("Issues", &self.issues), ("Fixed", &self.issues_fixed)];
+        for (heading, issues) in sections {
+            if issues.is_empty() {
+                continue;
+            }
+            presenter.heading(HeadingLevel::Level1, heading).await?;
+            presenter.list_bulleted(issues.iter()).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+use std::{fmt, path::PathBuf};
+
+use peace_cfg::ItemId;
+use peace_fmt::{Presentable, Presenter};
+use serde::{Deserialize, Serialize};
+
+/// An inconsistency found in the workspace by [`WorkspaceDoctorCmd`].
+///
+/// [`WorkspaceDoctorCmd`]: crate::cmds::WorkspaceDoctorCmd
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WorkspaceIssue {
+    /// A temporary file left behind by a write that was interrupted, e.g. by a
+    /// crashed run.
+    ///
+    /// This is fixed by removing the file, as the original file is only
+    /// replaced after the temporary file is fully written.
+    TmpFileLeftover {
+        /// Path to the temporary file.
+        path: PathBuf,
+    },
+    /// The stored goal states are missing, or older than the stored current
+    /// states.
+    ///
+    /// This happens when a run stops after discovering or applying current
+    /// states, but before goal states are stored. This is not fixed
+    /// automatically, as goal states need to be discovered.
+    StatesGoalOutdated {
+        /// Path to the goal states file.
+        path: PathBuf,
+    },
+    /// A stored file contains entries for items that are not in the flow.
+    ///
+    /// This is fixed by rewriting the file without those entries, the same as
+    /// [`StatesReserializeCmd`].
+    ///
+    /// [`StatesReserializeCmd`]: crate::cmds::StatesReserializeCmd
+    ItemIdsUnknown {
+        /// Path to the stored file.
+        path: PathBuf,
+        /// IDs of the items that are not in the flow.
+        item_ids: Vec<ItemId>,
+    },
+    /// A stored file could not be read using the flow's type registries.
+    ///
+    /// This usually means an item's state or params type has changed since the
+    /// file was written. This is not fixed automatically, as the stored
+    /// information would be lost.
+    StoredFileInvalid {
+        /// Path to the stored file.
+        path: PathBuf,
+        /// Error that occurred when reading the file.
+        error: String,
+    },
+}
+
+impl WorkspaceIssue {
+    /// Returns whether this issue may be fixed automatically.
+    pub fn is_fixable(&self) -> bool {
+        match self {
+            Self::TmpFileLeftover { .. } | Self::ItemIdsUnknown { .. } => true,
+            Self::StatesGoalOutdated { .. } | Self::StoredFileInvalid { .. } => false,
+        }
+    }
+
+    /// Returns the path to the file with the issue.
+    pub fn path(&self) -> &PathBuf {
+        match self {
+            Self::TmpFileLeftover { path }
+            | Self::StatesGoalOutdated { path }
+            | Self::ItemIdsUnknown { path, .. }
+            | Self::StoredFileInvalid { path, .. } => path,
+        }
+    }
+}
+
+impl fmt::Display for WorkspaceIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TmpFileLeftover { path } => {
+                write!(f, "`{}` was left behind by an interrupted write", path.display())
+            }
+            Self::StatesGoalOutdated { path } => write!(
+                f,
+                "`{}` is missing or older than the current states, discover goal states to update it",
+                path.display()
+            ),
+            Self::ItemIdsUnknown { path, item_ids } => {
+                write!(f, "`{}` has entries for items not in the flow: ", path.display())?;
+                item_ids.iter().enumerate().try_for_each(|(index, item_id)| {
+                    if index != 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "`{item_id}`")
+                })
+            }
+            Self::StoredFileInvalid { path, error } => {
+                write!(f, "`{}` could not be read: {error}", path.display())
+            }
+        }
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for WorkspaceIssue {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        presenter
+            .code_inline(&self.path().display().to_string())
+            .await?;
+        match self {
+            Self::TmpFileLeftover { .. } => {
+                presenter
+                    .text(" was left behind by an interrupted write")
+                    .await
+            }
+            Self::StatesGoalOutdated { .. } => {
+                presenter
+                    .text(" is missing or older than the current states, discover goal states to update it")
+                    .await
+            }
+            Self::ItemIdsUnknown { item_ids, .. } => {
+                presenter
+                    .text(" has entries for items not in the flow: ")
+                    .await?;
+                for (index, item_id) in item_ids.iter().enumerate() {
+                    if index != 0 {
+                        presenter.text(", ").await?;
+                    }
+                    presenter.id(item_id).await?;
+                }
+                Ok(())
+            }
+            Self::StoredFileInvalid { error, .. } => {
+                presenter.text(" could not be read: ").await?;
+                presenter.text(error).await
+            }
+        }
+    }
+}
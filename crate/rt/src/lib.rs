@@ -4,7 +4,14 @@
 ///
 /// 64 is arbitrarily chosen, as there is not enough data to inform us what a
 /// suitable number is.
-pub const BUFFERED_FUTURES_MAX: usize = 64;
+///
+/// Command blocks use the command context's [`ItemExecutor`], which defaults
+/// to this value. The limit can be changed at runtime with
+/// `CmdCtxBuilder::with_max_concurrency`.
+///
+/// [`ItemExecutor`]: peace_rt_model::ItemExecutor
+pub const BUFFERED_FUTURES_MAX: usize =
+    peace_rt_model::ItemExecutorConcurrent::CONCURRENCY_MAX_DEFAULT;
 
 pub mod cmd_blocks;
 pub mod cmds;
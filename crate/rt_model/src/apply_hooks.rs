@@ -0,0 +1,115 @@
+use std::fmt::{self, Debug};
+
+use peace_cfg::ItemId;
+use peace_resources::type_reg::untagged::BoxDtDisplay;
+
+pub use self::{
+    apply_hook_outcome::ApplyHookOutcome, post_apply_hook::PostApplyHook,
+    pre_apply_hook::PreApplyHook,
+};
+
+mod apply_hook_outcome;
+mod post_apply_hook;
+mod pre_apply_hook;
+
+/// Callbacks invoked around each item's apply.
+///
+/// These are invoked by `ApplyExecCmdBlock` when ensuring or cleaning items,
+/// so applications can add notifications, auditing, or custom gating.
+///
+/// * [`PreApplyHook`]s are invoked before an item is applied, and may prevent
+///   the item from being applied by returning an error.
+/// * [`PostApplyHook`]s are invoked after an item is applied, whether it
+///   succeeded or failed.
+///
+/// Hooks are not invoked for items whose apply could not be prepared, as there
+/// is no state diff for them, for items that are already in their target
+/// state, nor for dry runs, as nothing is applied.
+///
+/// [`PostApplyHook`]s are only invoked for items that the [`PreApplyHook`]s
+/// were invoked for and allowed, so they are not invoked for items that were
+/// not approved, or were prevented by a [`PreApplyHook`].
+pub struct ApplyHooks<E> {
+    /// Hooks invoked before each item is applied.
+    pre_apply_hooks: Vec<PreApplyHook<E>>,
+    /// Hooks invoked after each item is applied.
+    post_apply_hooks: Vec<PostApplyHook<E>>,
+}
+
+impl<E> ApplyHooks<E> {
+    /// Returns a new `ApplyHooks` with no hooks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a hook to invoke before each item is applied.
+    pub fn pre_apply_hook_push(&mut self, pre_apply_hook: PreApplyHook<E>) {
+        self.pre_apply_hooks.push(pre_apply_hook);
+    }
+
+    /// Adds a hook to invoke after each item is applied.
+    pub fn post_apply_hook_push(&mut self, post_apply_hook: PostApplyHook<E>) {
+        self.post_apply_hooks.push(post_apply_hook);
+    }
+
+    /// Returns the hooks invoked before each item is applied.
+    pub fn pre_apply_hooks(&self) -> &[PreApplyHook<E>] {
+        &self.pre_apply_hooks
+    }
+
+    /// Returns the hooks invoked after each item is applied.
+    pub fn post_apply_hooks(&self) -> &[PostApplyHook<E>] {
+        &self.post_apply_hooks
+    }
+
+    /// Returns whether there are no hooks registered.
+    pub fn is_empty(&self) -> bool {
+        self.pre_apply_hooks.is_empty() && self.post_apply_hooks.is_empty()
+    }
+
+    /// Invokes each [`PreApplyHook`] in order, stopping at the first error.
+    pub fn pre_apply(&self, item_id: &ItemId, state_diff: &BoxDtDisplay) -> Result<(), E> {
+        self.pre_apply_hooks
+            .iter()
+            .try_for_each(|pre_apply_hook| pre_apply_hook.call(item_id, state_diff))
+    }
+
+    /// Invokes each [`PostApplyHook`] in order.
+    pub fn post_apply(
+        &self,
+        item_id: &ItemId,
+        state_diff: &BoxDtDisplay,
+        apply_hook_outcome: &ApplyHookOutcome<'_, E>,
+    ) {
+        self.post_apply_hooks.iter().for_each(|post_apply_hook| {
+            post_apply_hook.call(item_id, state_diff, apply_hook_outcome)
+        });
+    }
+}
+
+impl<E> Clone for ApplyHooks<E> {
+    fn clone(&self) -> Self {
+        Self {
+            pre_apply_hooks: self.pre_apply_hooks.clone(),
+            post_apply_hooks: self.post_apply_hooks.clone(),
+        }
+    }
+}
+
+impl<E> Debug for ApplyHooks<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ApplyHooks")
+            .field("pre_apply_hooks", &self.pre_apply_hooks)
+            .field("post_apply_hooks", &self.post_apply_hooks)
+            .finish()
+    }
+}
+
+impl<E> Default for ApplyHooks<E> {
+    fn default() -> Self {
+        Self {
+            pre_apply_hooks: Vec::new(),
+            post_apply_hooks: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use peace_resources::type_reg::untagged::BoxDtDisplay;
+
+/// Outcome of an item's apply, passed to [`PostApplyHook`]s.
+///
+/// [`PostApplyHook`]: crate::PostApplyHook
+#[derive(Debug)]
+pub enum ApplyHookOutcome<'f, E> {
+    /// The item was applied successfully.
+    Success {
+        /// State of the item after it was applied.
+        state_applied: Option<BoxDtDisplay>,
+    },
+    /// The item failed to be applied.
+    Fail {
+        /// The apply error.
+        error: &'f E,
+    },
+}
@@ -0,0 +1,50 @@
+use std::{
+    fmt::{self, Debug},
+    sync::Arc,
+};
+
+use peace_cfg::ItemId;
+use peace_resources::type_reg::untagged::BoxDtDisplay;
+
+use crate::ApplyHookOutcome;
+
+/// Callback invoked after an item is applied, with the item's state diff and
+/// apply outcome.
+pub struct PostApplyHook<E>(
+    #[allow(clippy::type_complexity)]
+    Arc<dyn Fn(&ItemId, &BoxDtDisplay, &ApplyHookOutcome<'_, E>) + Send + Sync>,
+);
+
+impl<E> PostApplyHook<E> {
+    /// Returns a new `PostApplyHook`.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&ItemId, &BoxDtDisplay, &ApplyHookOutcome<'_, E>) + Send + Sync + 'static,
+    {
+        Self(Arc::new(f))
+    }
+
+    /// Invokes this hook.
+    pub fn call(
+        &self,
+        item_id: &ItemId,
+        state_diff: &BoxDtDisplay,
+        apply_hook_outcome: &ApplyHookOutcome<'_, E>,
+    ) {
+        (self.0)(item_id, state_diff, apply_hook_outcome)
+    }
+}
+
+impl<E> Clone for PostApplyHook<E> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<E> Debug for PostApplyHook<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PostApplyHook")
+            .field(&"Fn(&ItemId, &BoxDtDisplay, &ApplyHookOutcome<'_, E>)")
+            .finish()
+    }
+}
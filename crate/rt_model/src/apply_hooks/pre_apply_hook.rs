@@ -0,0 +1,45 @@
+use std::{
+    fmt::{self, Debug},
+    sync::Arc,
+};
+
+use peace_cfg::ItemId;
+use peace_resources::type_reg::untagged::BoxDtDisplay;
+
+/// Callback invoked before an item is applied, with the item's state diff.
+///
+/// Returning an error prevents the item from being applied, and the error is
+/// returned as the item's apply error.
+pub struct PreApplyHook<E>(
+    #[allow(clippy::type_complexity)]
+    Arc<dyn Fn(&ItemId, &BoxDtDisplay) -> Result<(), E> + Send + Sync>,
+);
+
+impl<E> PreApplyHook<E> {
+    /// Returns a new `PreApplyHook`.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&ItemId, &BoxDtDisplay) -> Result<(), E> + Send + Sync + 'static,
+    {
+        Self(Arc::new(f))
+    }
+
+    /// Invokes this hook.
+    pub fn call(&self, item_id: &ItemId, state_diff: &BoxDtDisplay) -> Result<(), E> {
+        (self.0)(item_id, state_diff)
+    }
+}
+
+impl<E> Clone for PreApplyHook<E> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<E> Debug for PreApplyHook<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PreApplyHook")
+            .field(&"Fn(&ItemId, &BoxDtDisplay) -> Result<(), E>")
+            .finish()
+    }
+}
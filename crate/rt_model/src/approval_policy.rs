@@ -0,0 +1,128 @@
+use peace_cfg::{DiffSeverity, ItemId};
+use peace_resources::type_reg::untagged::BoxDtDisplay;
+
+use crate::Error;
+
+pub use self::{approval_request::ApprovalRequest, approver::Approver};
+
+mod approval_request;
+mod approver;
+
+/// Which items require explicit approval before they are applied.
+///
+/// This is checked by `ApplyExecCmdBlock` before each item is applied, so
+/// destructive changes, such as resource replacement, are not made without
+/// someone signing off on them. Dry runs do not require approval.
+///
+/// An item requires approval if its ID is listed, or if its diff severity is
+/// at least the configured minimum severity. When approval is required:
+///
+/// * If an [`Approver`] is set, the item's apply waits for the approver's
+///   decision, e.g. an interactive prompt. If it is denied,
+///   [`Error::ApprovalDenied`] is returned as the item's apply error.
+/// * Otherwise, [`Error::ApprovalRequired`] is returned as the item's apply
+///   error.
+#[derive(Clone, Debug, Default)]
+pub struct ApprovalPolicy {
+    /// IDs of items that always require approval.
+    item_ids: Vec<ItemId>,
+    /// Minimum diff severity that requires approval.
+    diff_severity_min: Option<DiffSeverity>,
+    /// Decides whether an item may be applied.
+    approver: Option<Approver>,
+}
+
+impl ApprovalPolicy {
+    /// Returns a new `ApprovalPolicy` that does not require approval for any
+    /// item.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requires approval to apply the given item.
+    pub fn with_item_id(mut self, item_id: ItemId) -> Self {
+        self.item_ids.push(item_id);
+        self
+    }
+
+    /// Requires approval to apply items whose diff severity is at least
+    /// `diff_severity_min`.
+    ///
+    /// For example, `DiffSeverity::Destructive` requires approval for items
+    /// whose resources would be removed or replaced.
+    pub fn with_diff_severity_min(mut self, diff_severity_min: DiffSeverity) -> Self {
+        self.diff_severity_min = Some(diff_severity_min);
+        self
+    }
+
+    /// Sets the approver that decides whether an item may be applied.
+    ///
+    /// Without an approver, items that require approval fail to be applied,
+    /// which is the desired behaviour for non-interactive usage.
+    pub fn with_approver(mut self, approver: Approver) -> Self {
+        self.approver = Some(approver);
+        self
+    }
+
+    /// Returns the IDs of items that always require approval.
+    pub fn item_ids(&self) -> &[ItemId] {
+        &self.item_ids
+    }
+
+    /// Returns the minimum diff severity that requires approval.
+    pub fn diff_severity_min(&self) -> Option<DiffSeverity> {
+        self.diff_severity_min
+    }
+
+    /// Returns the approver that decides whether an item may be applied.
+    pub fn approver(&self) -> Option<&Approver> {
+        self.approver.as_ref()
+    }
+
+    /// Returns whether this policy may require approval for any item.
+    pub fn is_empty(&self) -> bool {
+        self.item_ids.is_empty() && self.diff_severity_min.is_none()
+    }
+
+    /// Returns whether applying the given item requires approval.
+    pub fn approval_required(&self, item_id: &ItemId, diff_severity: DiffSeverity) -> bool {
+        self.item_ids.contains(item_id)
+            || self
+                .diff_severity_min
+                .is_some_and(|diff_severity_min| diff_severity >= diff_severity_min)
+    }
+
+    /// Returns `Ok(())` if the given item may be applied.
+    ///
+    /// If approval is required, the approver is asked to decide. Returns an
+    /// error if there is no approver, or if the approver denied the apply.
+    pub async fn approve(
+        &self,
+        item_id: &ItemId,
+        diff_severity: DiffSeverity,
+        state_diff: &BoxDtDisplay,
+    ) -> Result<(), Error> {
+        if !self.approval_required(item_id, diff_severity) {
+            return Ok(());
+        }
+
+        match self.approver.as_ref() {
+            Some(approver) => {
+                let approval_request =
+                    ApprovalRequest::new(item_id.clone(), diff_severity, state_diff.clone());
+                if approver.call(approval_request).await {
+                    Ok(())
+                } else {
+                    Err(Error::ApprovalDenied {
+                        item_id: item_id.clone(),
+                        diff_severity,
+                    })
+                }
+            }
+            None => Err(Error::ApprovalRequired {
+                item_id: item_id.clone(),
+                diff_severity,
+            }),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use peace_cfg::{DiffSeverity, ItemId};
+use peace_resources::type_reg::untagged::BoxDtDisplay;
+
+/// An item's apply that is waiting for approval, passed to the [`Approver`].
+///
+/// [`Approver`]: crate::Approver
+#[derive(Clone, Debug)]
+pub struct ApprovalRequest {
+    /// ID of the item to apply.
+    item_id: ItemId,
+    /// How disruptive it is to apply the item's state diff.
+    diff_severity: DiffSeverity,
+    /// The item's state diff.
+    state_diff: BoxDtDisplay,
+}
+
+impl ApprovalRequest {
+    /// Returns a new `ApprovalRequest`.
+    pub fn new(item_id: ItemId, diff_severity: DiffSeverity, state_diff: BoxDtDisplay) -> Self {
+        Self {
+            item_id,
+            diff_severity,
+            state_diff,
+        }
+    }
+
+    /// Returns the ID of the item to apply.
+    pub fn item_id(&self) -> &ItemId {
+        &self.item_id
+    }
+
+    /// Returns how disruptive it is to apply the item's state diff.
+    pub fn diff_severity(&self) -> DiffSeverity {
+        self.diff_severity
+    }
+
+    /// Returns the item's state diff.
+    pub fn state_diff(&self) -> &BoxDtDisplay {
+        &self.state_diff
+    }
+}
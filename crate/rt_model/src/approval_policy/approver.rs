@@ -0,0 +1,54 @@
+use std::{
+    fmt::{self, Debug},
+    sync::Arc,
+};
+
+use futures::future::BoxFuture;
+
+use crate::ApprovalRequest;
+
+/// Decides whether an item that requires approval may be applied.
+///
+/// The item's apply waits for the returned future, so this may prompt the user
+/// interactively. Returning `false` denies the apply.
+pub struct Approver(
+    #[allow(clippy::type_complexity)]
+    Arc<dyn Fn(ApprovalRequest) -> BoxFuture<'static, bool> + Send + Sync>,
+);
+
+impl Approver {
+    /// Returns a new `Approver`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let approver = Approver::new(|approval_request| {
+    ///     Box::pin(async move { prompt_yes_no(&approval_request).await })
+    /// });
+    /// ```
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(ApprovalRequest) -> BoxFuture<'static, bool> + Send + Sync + 'static,
+    {
+        Self(Arc::new(f))
+    }
+
+    /// Invokes this approver.
+    pub async fn call(&self, approval_request: ApprovalRequest) -> bool {
+        (self.0)(approval_request).await
+    }
+}
+
+impl Clone for Approver {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl Debug for Approver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Approver")
+            .field(&"Fn(ApprovalRequest) -> BoxFuture<'static, bool>")
+            .finish()
+    }
+}
@@ -0,0 +1,48 @@
+use peace_cfg::ItemId;
+
+/// Cleans one item before another, overriding the flow's edges.
+///
+/// By default, items are cleaned in the reverse order of the flow's edges,
+/// which is not always the correct teardown order. For example, a DNS record
+/// that points to a server is applied after the server, but should still be
+/// cleaned before the server -- even if the flow's edges are from the record
+/// to the server -- so that clients are not directed to a removed server.
+///
+/// When cleaning, any edge from `item_id_before` to `item_id_after` is
+/// ignored, and `item_id_before` is cleaned before `item_id_after`. Edges
+/// through other items are still honoured, so an override that contradicts
+/// them returns [`Error::CleanOrderingOverrideCyclic`] when items are
+/// cleaned.
+///
+/// Overrides are only used when items are cleaned; items are still applied in
+/// the order of the flow's edges.
+///
+/// [`Error::CleanOrderingOverrideCyclic`]: crate::Error::CleanOrderingOverrideCyclic
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CleanOrderingOverride {
+    /// ID of the item to clean first.
+    item_id_before: ItemId,
+    /// ID of the item to clean after `item_id_before`.
+    item_id_after: ItemId,
+}
+
+impl CleanOrderingOverride {
+    /// Returns a new `CleanOrderingOverride` that cleans `item_id_before`
+    /// before `item_id_after`.
+    pub fn new(item_id_before: ItemId, item_id_after: ItemId) -> Self {
+        Self {
+            item_id_before,
+            item_id_after,
+        }
+    }
+
+    /// Returns the ID of the item to clean first.
+    pub fn item_id_before(&self) -> &ItemId {
+        &self.item_id_before
+    }
+
+    /// Returns the ID of the item to clean after `item_id_before`.
+    pub fn item_id_after(&self) -> &ItemId {
+        &self.item_id_after
+    }
+}
@@ -0,0 +1,122 @@
+use std::time::Duration;
+
+use opentelemetry::{
+    metrics::{Counter, Histogram, Meter, MeterProvider, NoopMeterProvider},
+    KeyValue,
+};
+use peace_cfg::{FlowId, ItemId, Profile};
+
+/// Records OpenTelemetry metrics for `CmdBlock`s that execute items.
+///
+/// Each measurement is recorded with the `flow_id`, `profile`, and `item_id`
+/// attributes.
+///
+/// When no meter is provided, measurements are recorded to a no-op meter.
+#[derive(Clone, Debug)]
+pub struct CmdMetrics {
+    /// `flow_id` and `profile` attributes recorded with each measurement.
+    attributes: Vec<KeyValue>,
+    /// Number of items whose apply succeeded.
+    items_applied: Counter<u64>,
+    /// Number of items whose apply failed.
+    items_failed: Counter<u64>,
+    /// Number of items skipped because they were not applicable.
+    items_skipped: Counter<u64>,
+    /// Duration of each item's apply, in seconds.
+    item_apply_duration: Histogram<f64>,
+    /// Duration of each item's state discovery, in seconds.
+    item_discover_duration: Histogram<f64>,
+}
+
+impl CmdMetrics {
+    /// Name of the meter that `peace` records metrics to.
+    pub const METER_NAME: &'static str = "peace";
+
+    /// Returns new `CmdMetrics` that record to the given meter.
+    ///
+    /// # Parameters
+    ///
+    /// * `meter`: Meter to record measurements to, no-op if `None`.
+    /// * `flow_id`: ID of the flow that the command executes.
+    /// * `profile`: Profile that the command executes in.
+    pub fn new(meter: Option<&Meter>, flow_id: &FlowId, profile: &Profile) -> Self {
+        let meter = meter
+            .cloned()
+            .unwrap_or_else(|| NoopMeterProvider::new().meter(Self::METER_NAME));
+
+        let attributes = vec![
+            KeyValue::new("flow_id", flow_id.to_string()),
+            KeyValue::new("profile", profile.to_string()),
+        ];
+        let items_applied = meter
+            .u64_counter("peace.items.applied")
+            .with_description("Number of items whose apply succeeded.")
+            .build();
+        let items_failed = meter
+            .u64_counter("peace.items.failed")
+            .with_description("Number of items whose apply failed.")
+            .build();
+        let items_skipped = meter
+            .u64_counter("peace.items.skipped")
+            .with_description("Number of items skipped because they were not applicable.")
+            .build();
+        let item_apply_duration = meter
+            .f64_histogram("peace.item.apply.duration")
+            .with_description("Duration of each item's apply.")
+            .with_unit("s")
+            .build();
+        let item_discover_duration = meter
+            .f64_histogram("peace.item.discover.duration")
+            .with_description("Duration of each item's state discovery.")
+            .with_unit("s")
+            .build();
+
+        Self {
+            attributes,
+            items_applied,
+            items_failed,
+            items_skipped,
+            item_apply_duration,
+            item_discover_duration,
+        }
+    }
+
+    /// Records that an item's apply succeeded, and how long it took.
+    ///
+    /// This includes items that were already in their target state.
+    pub fn item_applied(&self, item_id: &ItemId, duration: Duration) {
+        let attributes = self.item_attributes(item_id);
+        self.items_applied.add(1, &attributes);
+        self.item_apply_duration
+            .record(duration.as_secs_f64(), &attributes);
+    }
+
+    /// Records that an item's apply failed, and how long it took.
+    pub fn item_apply_failed(&self, item_id: &ItemId, duration: Duration) {
+        let attributes = self.item_attributes(item_id);
+        self.items_failed.add(1, &attributes);
+        self.item_apply_duration
+            .record(duration.as_secs_f64(), &attributes);
+    }
+
+    /// Records that an item was skipped because it was not applicable.
+    pub fn item_skipped(&self, item_id: &ItemId) {
+        let attributes = self.item_attributes(item_id);
+        self.items_skipped.add(1, &attributes);
+    }
+
+    /// Records how long an item's state discovery took.
+    pub fn item_discovered(&self, item_id: &ItemId, duration: Duration) {
+        let attributes = self.item_attributes(item_id);
+        self.item_discover_duration
+            .record(duration.as_secs_f64(), &attributes);
+    }
+
+    /// Returns the `flow_id`, `profile`, and `item_id` attributes.
+    fn item_attributes(&self, item_id: &ItemId) -> Vec<KeyValue> {
+        let mut attributes = Vec::with_capacity(self.attributes.len() + 1);
+        attributes.extend(self.attributes.iter().cloned());
+        attributes.push(KeyValue::new("item_id", item_id.to_string()));
+        attributes
+    }
+}
@@ -0,0 +1,71 @@
+use std::time::{Duration, Instant};
+
+/// Instant by which a command's execution should complete.
+///
+/// When the deadline is exceeded, the command execution is interrupted: no
+/// new items are started, and items that are in progress may continue until
+/// the grace period elapses. `CmdBlock`s that are still running after the
+/// grace period are cancelled.
+///
+/// An `Instant` may be used wherever an `ExecutionDeadline` is accepted, in
+/// which case the [default grace period] is used.
+///
+/// [default grace period]: Self::GRACE_PERIOD_DEFAULT
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExecutionDeadline {
+    /// Instant after which no new items are started.
+    deadline: Instant,
+    /// Duration after the deadline that in-progress items may continue for.
+    grace_period: Duration,
+}
+
+impl ExecutionDeadline {
+    /// Default duration that in-progress items may continue for after the
+    /// deadline.
+    pub const GRACE_PERIOD_DEFAULT: Duration = Duration::from_secs(30);
+
+    /// Returns a new `ExecutionDeadline` with the default grace period.
+    pub fn new(deadline: Instant) -> Self {
+        Self {
+            deadline,
+            grace_period: Self::GRACE_PERIOD_DEFAULT,
+        }
+    }
+
+    /// Sets the duration that in-progress items may continue for after the
+    /// deadline.
+    pub fn with_grace_period(mut self, grace_period: Duration) -> Self {
+        self.grace_period = grace_period;
+        self
+    }
+
+    /// Returns the instant after which no new items are started.
+    pub fn deadline(&self) -> Instant {
+        self.deadline
+    }
+
+    /// Returns the duration that in-progress items may continue for after the
+    /// deadline.
+    pub fn grace_period(&self) -> Duration {
+        self.grace_period
+    }
+
+    /// Returns whether the deadline has passed.
+    pub fn is_exceeded(&self) -> bool {
+        Instant::now() >= self.deadline
+    }
+
+    /// Returns the instant after which running `CmdBlock`s are cancelled.
+    ///
+    /// This is `None` if the grace period is too large to be represented as an
+    /// `Instant`, in which case `CmdBlock`s are not cancelled.
+    pub fn grace_period_end(&self) -> Option<Instant> {
+        self.deadline.checked_add(self.grace_period)
+    }
+}
+
+impl From<Instant> for ExecutionDeadline {
+    fn from(deadline: Instant) -> Self {
+        Self::new(deadline)
+    }
+}
@@ -0,0 +1,173 @@
+use std::marker::PhantomData;
+
+use chrono::Utc;
+use peace_cfg::{FlowId, ItemId};
+use peace_resources::{
+    paths::{ExecutionHistoryFile, ExecutionStatesDir, FlowDir, StatesCurrentFile, StatesGoalFile},
+    Resources,
+};
+
+use crate::{
+    Error, ExecutionHistory, ExecutionRecord, StatesSerializer, Storage, WorkspaceInitializer,
+};
+
+/// Reads and writes [`ExecutionHistory`] to and from storage.
+pub struct ExecutionHistorySerializer<E>(PhantomData<E>);
+
+impl<E> ExecutionHistorySerializer<E>
+where
+    E: std::error::Error + From<Error> + Send + 'static,
+{
+    /// Serializes the [`ExecutionHistory`] of a flow to disk.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to write to.
+    /// * `execution_history`: `ExecutionHistory` to serialize.
+    /// * `execution_history_file`: Path to save the serialized history to.
+    pub async fn serialize(
+        storage: &Storage,
+        execution_history: &ExecutionHistory,
+        execution_history_file: &ExecutionHistoryFile,
+    ) -> Result<(), E> {
+        storage
+            .serialized_write_atomic(
+                #[cfg(not(target_arch = "wasm32"))]
+                "ExecutionHistorySerializer::serialize".to_string(),
+                execution_history_file,
+                execution_history,
+                Error::ExecutionHistorySerialize,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Returns the [`ExecutionHistory`] of a flow, if it exists on disk.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to read from.
+    /// * `execution_history_file`: `ExecutionHistoryFile` to deserialize.
+    pub async fn deserialize_opt(
+        storage: &Storage,
+        execution_history_file: &ExecutionHistoryFile,
+    ) -> Result<Option<ExecutionHistory>, E> {
+        let execution_history = storage
+            .serialized_read_opt(
+                #[cfg(not(target_arch = "wasm32"))]
+                "ExecutionHistorySerializer::deserialize_opt".to_string(),
+                execution_history_file,
+                |error, _| Error::ExecutionHistoryDeserialize(error),
+            )
+            .await?;
+
+        Ok(execution_history)
+    }
+
+    /// Records an execution in the flow's execution history, using the
+    /// `Storage` and `FlowDir` in `resources`.
+    ///
+    /// See [`ExecutionHistorySerializer::record`].
+    ///
+    /// # Parameters:
+    ///
+    /// * `flow_id`: ID of the flow that was executed.
+    /// * `resources`: `Resources` containing the `Storage` and `FlowDir`.
+    /// * `item_ids`: IDs of the items whose states were written by the
+    ///   execution.
+    pub async fn record_in<TS>(
+        flow_id: &FlowId,
+        resources: &Resources<TS>,
+        item_ids: Vec<ItemId>,
+    ) -> Result<ExecutionRecord, E> {
+        let storage = resources.borrow::<Storage>();
+        let flow_dir = resources.borrow::<FlowDir>();
+
+        Self::record(&storage, flow_id, &flow_dir, item_ids).await
+    }
+
+    /// Records an execution in the flow's execution history, and returns its
+    /// record.
+    ///
+    /// This is called by commands after they write the flow's states.
+    ///
+    /// The flow's current and goal states files are copied into the
+    /// execution's `ExecutionStatesDir`, so that they can be restored by
+    /// `StatesCheckoutCmd`.
+    ///
+    /// The states files are written before the history, so if recording is
+    /// interrupted, the history is unchanged, and the partially written
+    /// execution is overwritten when the next execution is recorded.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to read from and write to.
+    /// * `flow_id`: ID of the flow that was executed.
+    /// * `flow_dir`: Directory of the flow that was executed.
+    /// * `item_ids`: IDs of the items whose states were written by the
+    ///   execution.
+    pub async fn record(
+        storage: &Storage,
+        flow_id: &FlowId,
+        flow_dir: &FlowDir,
+        item_ids: Vec<ItemId>,
+    ) -> Result<ExecutionRecord, E> {
+        let execution_history_file = ExecutionHistoryFile::from(flow_dir);
+        let mut execution_history = Self::deserialize_opt(storage, &execution_history_file)
+            .await?
+            .unwrap_or_else(ExecutionHistory::new);
+
+        let execution_id = execution_history.execution_id_next();
+        let execution_states_dir = ExecutionStatesDir::from((flow_dir, execution_id));
+        let dirs_to_create = [AsRef::<std::path::Path>::as_ref(&execution_states_dir)];
+        #[cfg(target_arch = "wasm32")]
+        WorkspaceInitializer::dirs_create(storage, dirs_to_create).await?;
+        #[cfg(not(target_arch = "wasm32"))]
+        WorkspaceInitializer::dirs_create(dirs_to_create).await?;
+
+        StatesSerializer::<E>::copy(
+            flow_id,
+            storage,
+            &StatesCurrentFile::from(flow_dir),
+            &StatesCurrentFile::from(&execution_states_dir),
+        )
+        .await?;
+        StatesSerializer::<E>::copy(
+            flow_id,
+            storage,
+            &StatesGoalFile::from(flow_dir),
+            &StatesGoalFile::from(&execution_states_dir),
+        )
+        .await?;
+
+        let execution_record = ExecutionRecord {
+            execution_id,
+            recorded_at: Utc::now(),
+            item_ids,
+        };
+        let execution_records_pruned = execution_history.push(execution_record.clone());
+
+        Self::serialize(storage, &execution_history, &execution_history_file).await?;
+
+        // Pruned executions are only removed after the history no longer
+        // references them.
+        #[cfg(not(target_arch = "wasm32"))]
+        for execution_record_pruned in execution_records_pruned {
+            let execution_states_dir =
+                ExecutionStatesDir::from((flow_dir, execution_record_pruned.execution_id));
+            tokio::fs::remove_dir_all(&execution_states_dir)
+                .await
+                .map_err(|error| {
+                    Error::Native(crate::NativeError::DirRemove {
+                        path: execution_states_dir.into_inner(),
+                        error,
+                    })
+                })?;
+        }
+        #[cfg(target_arch = "wasm32")]
+        let _execution_records_pruned = execution_records_pruned;
+
+        Ok(execution_record)
+    }
+}
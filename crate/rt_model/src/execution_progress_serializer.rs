@@ -0,0 +1,62 @@
+use std::marker::PhantomData;
+
+use peace_resources::paths::ExecutionProgressFile;
+
+use crate::{Error, ExecutionProgress, Storage};
+
+/// Reads and writes [`ExecutionProgress`] to and from storage.
+pub struct ExecutionProgressSerializer<E>(PhantomData<E>);
+
+impl<E> ExecutionProgressSerializer<E>
+where
+    E: std::error::Error + From<Error> + Send,
+{
+    /// Serializes the [`ExecutionProgress`] of a command execution to disk,
+    /// replacing the previous execution's progress.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to write to.
+    /// * `execution_progress`: `ExecutionProgress` to serialize.
+    /// * `execution_progress_file`: Path to save the serialized progress to.
+    pub async fn serialize(
+        storage: &Storage,
+        execution_progress: &ExecutionProgress,
+        execution_progress_file: &ExecutionProgressFile,
+    ) -> Result<(), E> {
+        storage
+            .serialized_write_atomic(
+                #[cfg(not(target_arch = "wasm32"))]
+                "ExecutionProgressSerializer::serialize".to_string(),
+                execution_progress_file,
+                execution_progress,
+                Error::ExecutionProgressSerialize,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Returns the [`ExecutionProgress`] of the last command execution, if it
+    /// exists on disk.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to read from.
+    /// * `execution_progress_file`: `ExecutionProgressFile` to deserialize.
+    pub async fn deserialize_opt(
+        storage: &Storage,
+        execution_progress_file: &ExecutionProgressFile,
+    ) -> Result<Option<ExecutionProgress>, E> {
+        let execution_progress = storage
+            .serialized_read_opt(
+                #[cfg(not(target_arch = "wasm32"))]
+                "ExecutionProgressSerializer::deserialize_opt".to_string(),
+                execution_progress_file,
+                |error, _| Error::ExecutionProgressDeserialize(error),
+            )
+            .await?;
+
+        Ok(execution_progress)
+    }
+}
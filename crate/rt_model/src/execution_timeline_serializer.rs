@@ -0,0 +1,62 @@
+use std::marker::PhantomData;
+
+use peace_resources::paths::ExecutionTimelineFile;
+
+use crate::{Error, ExecutionTimeline, Storage};
+
+/// Reads and writes [`ExecutionTimeline`] to and from storage.
+pub struct ExecutionTimelineSerializer<E>(PhantomData<E>);
+
+impl<E> ExecutionTimelineSerializer<E>
+where
+    E: std::error::Error + From<Error> + Send,
+{
+    /// Serializes the [`ExecutionTimeline`] of a command execution to disk,
+    /// replacing the previous execution's timeline.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to write to.
+    /// * `execution_timeline`: `ExecutionTimeline` to serialize.
+    /// * `execution_timeline_file`: Path to save the serialized timeline to.
+    pub async fn serialize(
+        storage: &Storage,
+        execution_timeline: &ExecutionTimeline,
+        execution_timeline_file: &ExecutionTimelineFile,
+    ) -> Result<(), E> {
+        storage
+            .serialized_write_atomic(
+                #[cfg(not(target_arch = "wasm32"))]
+                "ExecutionTimelineSerializer::serialize".to_string(),
+                execution_timeline_file,
+                execution_timeline,
+                Error::ExecutionTimelineSerialize,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Returns the [`ExecutionTimeline`] of the last command execution, if it
+    /// exists on disk.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to read from.
+    /// * `execution_timeline_file`: `ExecutionTimelineFile` to deserialize.
+    pub async fn deserialize_opt(
+        storage: &Storage,
+        execution_timeline_file: &ExecutionTimelineFile,
+    ) -> Result<Option<ExecutionTimeline>, E> {
+        let execution_timeline = storage
+            .serialized_read_opt(
+                #[cfg(not(target_arch = "wasm32"))]
+                "ExecutionTimelineSerializer::deserialize_opt".to_string(),
+                execution_timeline_file,
+                |error, _| Error::ExecutionTimelineDeserialize(error),
+            )
+            .await?;
+
+        Ok(execution_timeline)
+    }
+}
@@ -1,8 +1,12 @@
-use peace_cfg::FlowId;
-use peace_data::fn_graph::GraphInfo;
-use peace_flow_model::{FlowSpecInfo, ItemSpecInfo};
+use std::time::Duration;
 
-use crate::ItemGraph;
+use indexmap::IndexMap;
+use peace_cfg::{FlowId, ItemGroupId, ItemId};
+use peace_data::fn_graph::{daggy::petgraph::visit::EdgeRef, GraphInfo};
+use peace_flow_model::{FlowEdge, FlowSpecInfo, ItemSpecInfo};
+use serde::{Serialize, Serializer};
+
+use crate::{CleanOrderingOverride, FlowMetadata, ItemGraph, ItemMetadata, PreflightCheck};
 
 /// A flow to manage items.
 ///
@@ -18,6 +22,19 @@ pub struct Flow<E> {
     ///
     /// [`Item`]: peace_cfg::Item
     graph: ItemGraph<E>,
+    /// Groups of items whose progress is rendered as one unit.
+    item_groups: IndexMap<ItemGroupId, Vec<ItemId>>,
+    /// Groups of items that are applied all-or-nothing.
+    atomic_groups: IndexMap<ItemGroupId, Vec<ItemId>>,
+    /// Environment checks run before states are discovered or items are
+    /// applied.
+    preflight_checks: Vec<PreflightCheck>,
+    /// Maximum age of stored states before they are rediscovered instead of
+    /// being required to be in sync.
+    state_max_staleness: Option<Duration>,
+    /// Items to clean before other items, regardless of the edges between
+    /// them.
+    clean_ordering_overrides: Vec<CleanOrderingOverride>,
 }
 
 impl<E> PartialEq for Flow<E>
@@ -25,7 +42,13 @@ where
     E: 'static,
 {
     fn eq(&self, other: &Flow<E>) -> bool {
-        self.flow_id == other.flow_id && self.graph == other.graph
+        self.flow_id == other.flow_id
+            && self.graph == other.graph
+            && self.item_groups == other.item_groups
+            && self.atomic_groups == other.atomic_groups
+            && self.preflight_checks == other.preflight_checks
+            && self.state_max_staleness == other.state_max_staleness
+            && self.clean_ordering_overrides == other.clean_ordering_overrides
     }
 }
 
@@ -34,6 +57,11 @@ impl<E> Clone for Flow<E> {
         Self {
             flow_id: self.flow_id.clone(),
             graph: self.graph.clone(),
+            item_groups: self.item_groups.clone(),
+            atomic_groups: self.atomic_groups.clone(),
+            preflight_checks: self.preflight_checks.clone(),
+            state_max_staleness: self.state_max_staleness,
+            clean_ordering_overrides: self.clean_ordering_overrides.clone(),
         }
     }
 }
@@ -43,7 +71,125 @@ impl<E> Eq for Flow<E> where E: 'static {}
 impl<E> Flow<E> {
     /// Returns a new `Flow`.
     pub fn new(flow_id: FlowId, graph: ItemGraph<E>) -> Self {
-        Self { flow_id, graph }
+        Self {
+            flow_id,
+            graph,
+            item_groups: IndexMap::new(),
+            atomic_groups: IndexMap::new(),
+            preflight_checks: Vec::new(),
+            state_max_staleness: None,
+            clean_ordering_overrides: Vec::new(),
+        }
+    }
+
+    /// Groups the given items so that their progress is rendered as one unit.
+    ///
+    /// The items are still executed individually. Outputs that support
+    /// groups, such as `CliOutput`, render one progress bar for the group,
+    /// which completes when all of its items complete.
+    ///
+    /// An item may only be in one group. If an item is already in another
+    /// group, it is moved to this group. Item IDs that are not in this flow
+    /// are ignored.
+    pub fn with_item_group(
+        mut self,
+        item_group_id: ItemGroupId,
+        item_ids: impl IntoIterator<Item = ItemId>,
+    ) -> Self {
+        Self::item_group_insert(&mut self.item_groups, item_group_id, item_ids);
+        self
+    }
+
+    /// Groups the given items so that they are applied all-or-nothing.
+    ///
+    /// If any item in the group fails to apply, the items in the group that
+    /// were already applied are rolled back to the state they were in before
+    /// the apply, before the command returns.
+    ///
+    /// An item may only be in one atomic group. If an item is already in
+    /// another atomic group, it is moved to this group.
+    pub fn with_atomic_group(
+        mut self,
+        item_group_id: ItemGroupId,
+        item_ids: impl IntoIterator<Item = ItemId>,
+    ) -> Self {
+        Self::item_group_insert(&mut self.atomic_groups, item_group_id, item_ids);
+        self
+    }
+
+    /// Adds a check that is run before states are discovered or items are
+    /// applied.
+    ///
+    /// All of the flow's checks are run, and if any fail, the command returns
+    /// [`Error::PreflightChecksFailed`] with every failure, before any item is
+    /// discovered or applied.
+    ///
+    /// [`Error::PreflightChecksFailed`]: crate::Error::PreflightChecksFailed
+    pub fn with_preflight_check(mut self, preflight_check: PreflightCheck) -> Self {
+        self.preflight_checks.push(preflight_check);
+        self
+    }
+
+    /// Adds checks that are run before states are discovered or items are
+    /// applied.
+    ///
+    /// See [`Flow::with_preflight_check`].
+    pub fn with_preflight_checks(
+        mut self,
+        preflight_checks: impl IntoIterator<Item = PreflightCheck>,
+    ) -> Self {
+        self.preflight_checks.extend(preflight_checks);
+        self
+    }
+
+    /// Sets the maximum age of stored states before they are rediscovered.
+    ///
+    /// When an apply requires stored states to be in sync with discovered
+    /// states -- see `ApplyStoredStateSync` -- items whose stored state was
+    /// discovered longer ago than this are not required to be in sync. Their
+    /// stored state is replaced by the newly discovered state, instead of
+    /// failing the apply.
+    ///
+    /// By default, stored states do not expire.
+    pub fn with_state_max_staleness(mut self, state_max_staleness: Duration) -> Self {
+        self.state_max_staleness = Some(state_max_staleness);
+        self
+    }
+
+    /// Adds overrides to the order that items are cleaned in.
+    ///
+    /// Items are cleaned in the reverse order of the flow's edges, except that
+    /// each override's `item_id_before` is cleaned before its
+    /// `item_id_after`. See [`CleanOrderingOverride`].
+    ///
+    /// Overrides whose item IDs are not in this flow are ignored.
+    pub fn with_clean_ordering_overrides(
+        mut self,
+        clean_ordering_overrides: impl IntoIterator<Item = CleanOrderingOverride>,
+    ) -> Self {
+        self.clean_ordering_overrides.extend(clean_ordering_overrides);
+        self
+    }
+
+    /// Inserts a group, removing its items from any other group.
+    fn item_group_insert(
+        item_groups: &mut IndexMap<ItemGroupId, Vec<ItemId>>,
+        item_group_id: ItemGroupId,
+        item_ids: impl IntoIterator<Item = ItemId>,
+    ) {
+        let item_ids = item_ids
+            .into_iter()
+            .fold(Vec::new(), |mut item_ids, item_id| {
+                if !item_ids.contains(&item_id) {
+                    item_ids.push(item_id);
+                }
+                item_ids
+            });
+        item_groups.values_mut().for_each(|item_ids_existing| {
+            item_ids_existing.retain(|item_id| !item_ids.contains(item_id))
+        });
+        item_groups.retain(|_item_group_id, item_ids_existing| !item_ids_existing.is_empty());
+        item_groups.insert(item_group_id, item_ids);
     }
 
     /// Returns the flow ID.
@@ -61,6 +207,51 @@ impl<E> Flow<E> {
         &self.graph
     }
 
+    /// Returns the groups of items whose progress is rendered as one unit.
+    pub fn item_groups(&self) -> &IndexMap<ItemGroupId, Vec<ItemId>> {
+        &self.item_groups
+    }
+
+    /// Returns the groups of items that are applied all-or-nothing.
+    pub fn atomic_groups(&self) -> &IndexMap<ItemGroupId, Vec<ItemId>> {
+        &self.atomic_groups
+    }
+
+    /// Returns the checks run before states are discovered or items are
+    /// applied.
+    pub fn preflight_checks(&self) -> &[PreflightCheck] {
+        &self.preflight_checks
+    }
+
+    /// Returns the maximum age of stored states before they are
+    /// rediscovered, if any.
+    pub fn state_max_staleness(&self) -> Option<Duration> {
+        self.state_max_staleness
+    }
+
+    /// Returns the overrides to the order that items are cleaned in.
+    pub fn clean_ordering_overrides(&self) -> &[CleanOrderingOverride] {
+        &self.clean_ordering_overrides
+    }
+
+    /// Returns suggestions to recover from an error that the given item
+    /// returned.
+    ///
+    /// Returns an empty `Vec` if the item is not in this flow, or the item has
+    /// no suggestions for the error. See [`Item::error_help`].
+    ///
+    /// [`Item::error_help`]: peace_cfg::Item::error_help
+    pub fn error_help(&self, item_id: &ItemId, error: &E) -> Vec<String>
+    where
+        E: std::error::Error + 'static,
+    {
+        self.graph
+            .iter()
+            .find(|item| item.id() == item_id)
+            .map(|item| item.error_help(error))
+            .unwrap_or_default()
+    }
+
     /// Generates a `FlowSpecInfo` from this `Flow`'s information.
     pub fn flow_spec_info(&self) -> FlowSpecInfo
     where
@@ -72,6 +263,115 @@ impl<E> Flow<E> {
             ItemSpecInfo { item_id }
         });
 
-        FlowSpecInfo::new(flow_id, graph_info)
+        let edges_ordering_only = self.edges_ordering_only_item_ids();
+
+        FlowSpecInfo::new(flow_id, graph_info).with_edges_ordering_only(edges_ordering_only)
+    }
+
+    /// Returns a serializable description of this flow's items and the
+    /// dependencies between them.
+    ///
+    /// When the `item_schema` feature is enabled, each item's params schema is
+    /// included if the item provides one. See [`Item::schema`].
+    ///
+    /// [`Item::schema`]: peace_cfg::Item::schema
+    pub fn metadata(&self) -> FlowMetadata
+    where
+        E: 'static,
+    {
+        #[cfg(feature = "item_schema")]
+        let mut generator =
+            schemars::gen::SchemaGenerator::new(schemars::gen::SchemaSettings::draft07());
+        let items = self
+            .graph
+            .iter_insertion()
+            .map(|item| ItemMetadata {
+                item_id: item.id().clone(),
+                item_type_name: item.item_type_name(),
+                #[cfg(feature = "item_schema")]
+                params_schema: item
+                    .schema(&mut generator)
+                    .map(|item_schema| item_schema.params),
+            })
+            .collect::<Vec<ItemMetadata>>();
+
+        let dag = self.graph.graph();
+        let edges = dag
+            .edge_references()
+            .map(|edge| {
+                FlowEdge::new(
+                    dag[edge.source()].id().clone(),
+                    dag[edge.target()].id().clone(),
+                    *edge.weight(),
+                )
+            })
+            .collect::<Vec<FlowEdge>>();
+
+        let edges_ordering_only = self.edges_ordering_only_item_ids();
+
+        FlowMetadata {
+            flow_id: self.flow_id.clone(),
+            items,
+            edges,
+            edges_ordering_only,
+            #[cfg(feature = "item_schema")]
+            definitions: generator.take_definitions(),
+        }
+    }
+
+    /// Returns the item IDs of the edges that only order items.
+    fn edges_ordering_only_item_ids(&self) -> Vec<[ItemId; 2]>
+    where
+        E: 'static,
+    {
+        self.graph
+            .edges_ordering_only()
+            .iter()
+            .map(|(item_index_from, item_index_to)| {
+                [
+                    self.graph[*item_index_from].id().clone(),
+                    self.graph[*item_index_to].id().clone(),
+                ]
+            })
+            .collect::<Vec<[ItemId; 2]>>()
+    }
+
+    /// Returns the JSON Schemas of the `Params`, `State`, and `StateDiff`
+    /// types of each item in this flow.
+    ///
+    /// See [`Item::schema`].
+    ///
+    /// [`Item::schema`]: peace_cfg::Item::schema
+    #[cfg(feature = "item_schema")]
+    pub fn schema(&self) -> crate::FlowSchema
+    where
+        E: 'static,
+    {
+        let mut generator =
+            schemars::gen::SchemaGenerator::new(schemars::gen::SchemaSettings::draft07());
+        let item_schemas = self
+            .graph
+            .iter()
+            .map(|item| (item.id().clone(), item.schema(&mut generator)))
+            .collect();
+
+        crate::FlowSchema::new(
+            self.flow_id.clone(),
+            item_schemas,
+            generator.take_definitions(),
+        )
+    }
+}
+
+/// Serializes the flow's [`FlowMetadata`].
+impl<E> Serialize for Flow<E>
+where
+    E: 'static,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.metadata().serialize(serializer)
     }
 }
@@ -0,0 +1,109 @@
+use peace_cfg::ItemId;
+use peace_data::fn_graph::FnId;
+use peace_flow_model::FlowSpec;
+use peace_rt_model_core::{Error, IndexMap};
+
+use crate::{Flow, ItemGraphBuilder, ItemRegistry};
+
+/// Constructs a [`Flow`] from a declarative [`FlowSpec`].
+///
+/// Each item in the `FlowSpec` is constructed using the constructor registered
+/// for its type in the [`ItemRegistry`], and each `depends_on` entry is added
+/// as a logic edge in the item graph.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let flow_loader = FlowLoader::new(item_registry);
+/// let flow = flow_loader.load_yaml(&std::fs::read_to_string("app_deploy.yaml")?)?;
+/// ```
+#[derive(Debug)]
+pub struct FlowLoader<E> {
+    /// Constructors for each item type.
+    item_registry: ItemRegistry<E>,
+}
+
+impl<E> FlowLoader<E>
+where
+    E: 'static,
+{
+    /// Returns a new `FlowLoader` that constructs items from the given
+    /// registry.
+    pub fn new(item_registry: ItemRegistry<E>) -> Self {
+        Self { item_registry }
+    }
+
+    /// Returns the registry of item constructors.
+    pub fn item_registry(&self) -> &ItemRegistry<E> {
+        &self.item_registry
+    }
+
+    /// Deserializes a [`FlowSpec`] from YAML, and returns the [`Flow`] it
+    /// defines.
+    pub fn load_yaml(&self, flow_spec_yaml: &str) -> Result<Flow<E>, Error> {
+        let flow_spec =
+            serde_yaml::from_str::<FlowSpec>(flow_spec_yaml).map_err(Error::FlowSpecDeserialize)?;
+
+        self.load(&flow_spec)
+    }
+
+    /// Returns the [`Flow`] defined by the given [`FlowSpec`].
+    pub fn load(&self, flow_spec: &FlowSpec) -> Result<Flow<E>, Error> {
+        let mut graph_builder = ItemGraphBuilder::<E>::new();
+        let mut item_id_to_fn_id = IndexMap::<&ItemId, FnId>::with_capacity(flow_spec.items.len());
+
+        flow_spec.items.iter().try_for_each(|flow_spec_item| {
+            let item_id = &flow_spec_item.id;
+            if item_id_to_fn_id.contains_key(item_id) {
+                return Err(Error::FlowSpecItemIdDuplicate {
+                    item_id: item_id.clone(),
+                });
+            }
+
+            let item_type = flow_spec_item.item_type.as_str();
+            let item_boxed = self
+                .item_registry
+                .item_create(item_type, item_id.clone())
+                .ok_or_else(|| Error::FlowSpecItemTypeNotRegistered {
+                    item_id: item_id.clone(),
+                    item_type: item_type.to_string(),
+                    item_types_registered: self
+                        .item_registry
+                        .item_types()
+                        .map(String::from)
+                        .collect(),
+                })?;
+            item_id_to_fn_id.insert(item_id, graph_builder.add_fn(item_boxed));
+
+            Ok(())
+        })?;
+
+        flow_spec.items.iter().try_for_each(|flow_spec_item| {
+            let item_id = &flow_spec_item.id;
+            let fn_id = item_id_to_fn_id[item_id];
+
+            flow_spec_item
+                .depends_on
+                .iter()
+                .try_for_each(|dependency_id| {
+                    let dependency_fn_id = item_id_to_fn_id
+                        .get(dependency_id)
+                        .copied()
+                        .ok_or_else(|| Error::FlowSpecItemDependencyNotFound {
+                            item_id: item_id.clone(),
+                            dependency_id: dependency_id.clone(),
+                        })?;
+
+                    graph_builder
+                        .add_logic_edge(dependency_fn_id, fn_id)
+                        .map(|_edge_id| ())
+                        .map_err(|_would_cycle| Error::FlowSpecItemDependencyCycle {
+                            item_id: item_id.clone(),
+                            dependency_id: dependency_id.clone(),
+                        })
+                })
+        })?;
+
+        Ok(Flow::new(flow_spec.flow_id.clone(), graph_builder.build()))
+    }
+}
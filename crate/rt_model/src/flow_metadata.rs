@@ -0,0 +1,69 @@
+use peace_cfg::{FlowId, ItemId};
+use peace_flow_model::FlowEdge;
+use peace_fmt::{Presentable, Presenter};
+use serde::Serialize;
+
+#[cfg(feature = "item_schema")]
+use schemars::{schema::Schema, Map};
+
+/// Serializable description of a flow's items and the dependencies between
+/// them.
+///
+/// This lets web frontends and documentation tooling render a flow without
+/// linking the item types. Params schemas are included when the `item_schema`
+/// feature is enabled, and are referenced from the document's `definitions`.
+///
+/// See [`Flow::metadata`].
+///
+/// [`Flow::metadata`]: crate::Flow::metadata
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct FlowMetadata {
+    /// ID of the flow.
+    pub flow_id: FlowId,
+    /// Items in the flow, in insertion order.
+    pub items: Vec<ItemMetadata>,
+    /// Dependencies between items in the flow.
+    pub edges: Vec<FlowEdge>,
+    /// Edges between items that only order the items, and are not data
+    /// dependencies.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub edges_ordering_only: Vec<[ItemId; 2]>,
+    /// Definitions referenced by the items' params schemas.
+    #[cfg(feature = "item_schema")]
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    pub definitions: Map<String, Schema>,
+}
+
+/// Serializable description of an item in a flow.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ItemMetadata {
+    /// ID of the item.
+    pub item_id: ItemId,
+    /// Name of the item's type, e.g. `FileDownloadItem<WebApp>`.
+    pub item_type_name: String,
+    /// JSON Schema of the item's `Params`, if the item provides one.
+    ///
+    /// See [`Item::schema`].
+    ///
+    /// [`Item::schema`]: peace_cfg::Item::schema
+    #[cfg(feature = "item_schema")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params_schema: Option<Schema>,
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for FlowMetadata {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        match serde_yaml::to_string(self) {
+            Ok(flow_metadata_yaml) => presenter.text(&flow_metadata_yaml).await,
+            Err(error) => {
+                presenter
+                    .text(&format!("Failed to serialize flow metadata: {error}"))
+                    .await
+            }
+        }
+    }
+}
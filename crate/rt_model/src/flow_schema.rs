@@ -0,0 +1,151 @@
+use indexmap::IndexMap;
+use peace_cfg::{FlowId, ItemId, ItemSchema};
+use peace_fmt::{Presentable, Presenter};
+use schemars::{
+    gen::SchemaSettings,
+    schema::{InstanceType, Metadata, ObjectValidation, RootSchema, Schema, SchemaObject},
+    Map,
+};
+use serde::{Serialize, Serializer};
+
+/// JSON Schema of the `Params`, `State`, and `StateDiff` types of each item
+/// in a flow.
+///
+/// This serializes as a single JSON Schema document, with a property for each
+/// item. Each item's property has `params`, `state`, and `state_diff`
+/// properties, and types shared between items are referenced from the
+/// document's `definitions`.
+///
+/// Items that do not provide a schema accept any value.
+///
+/// See [`Item::schema`].
+///
+/// [`Item::schema`]: peace_cfg::Item::schema
+#[derive(Clone, Debug, PartialEq)]
+pub struct FlowSchema {
+    /// ID of the flow.
+    flow_id: FlowId,
+    /// Schema of each item, `None` if the item does not provide one.
+    item_schemas: IndexMap<ItemId, Option<ItemSchema>>,
+    /// Definitions referenced by the item schemas.
+    definitions: Map<String, Schema>,
+}
+
+impl FlowSchema {
+    /// Returns a new `FlowSchema`.
+    pub fn new(
+        flow_id: FlowId,
+        item_schemas: IndexMap<ItemId, Option<ItemSchema>>,
+        definitions: Map<String, Schema>,
+    ) -> Self {
+        Self {
+            flow_id,
+            item_schemas,
+            definitions,
+        }
+    }
+
+    /// Returns the ID of the flow.
+    pub fn flow_id(&self) -> &FlowId {
+        &self.flow_id
+    }
+
+    /// Returns the schema of each item, `None` if the item does not provide
+    /// one.
+    pub fn item_schemas(&self) -> &IndexMap<ItemId, Option<ItemSchema>> {
+        &self.item_schemas
+    }
+
+    /// Returns the schema of the given item, if it provides one.
+    pub fn item_schema(&self, item_id: &ItemId) -> Option<&ItemSchema> {
+        self.item_schemas.get(item_id).and_then(Option::as_ref)
+    }
+
+    /// Returns the definitions referenced by the item schemas.
+    pub fn definitions(&self) -> &Map<String, Schema> {
+        &self.definitions
+    }
+
+    /// Returns the combined schema document for the flow.
+    pub fn to_root_schema(&self) -> RootSchema {
+        let properties = self
+            .item_schemas
+            .iter()
+            .map(|(item_id, item_schema)| {
+                let schema = match item_schema {
+                    Some(item_schema) => {
+                        let properties = Map::from_iter([
+                            (String::from("params"), item_schema.params.clone()),
+                            (String::from("state"), item_schema.state.clone()),
+                            (String::from("state_diff"), item_schema.state_diff.clone()),
+                        ]);
+                        Schema::Object(object_schema(None, properties, true))
+                    }
+                    None => Schema::Bool(true),
+                };
+                (item_id.to_string(), schema)
+            })
+            .collect::<Map<_, _>>();
+
+        RootSchema {
+            meta_schema: SchemaSettings::draft07().meta_schema,
+            schema: object_schema(Some(self.flow_id.to_string()), properties, false),
+            definitions: self.definitions.clone(),
+        }
+    }
+}
+
+/// Returns a schema for an object with the given properties.
+fn object_schema(
+    title: Option<String>,
+    properties: Map<String, Schema>,
+    all_required: bool,
+) -> SchemaObject {
+    let required = if all_required {
+        properties.keys().cloned().collect()
+    } else {
+        Default::default()
+    };
+
+    SchemaObject {
+        metadata: title.map(|title| {
+            Box::new(Metadata {
+                title: Some(title),
+                ..Default::default()
+            })
+        }),
+        instance_type: Some(InstanceType::Object.into()),
+        object: Some(Box::new(ObjectValidation {
+            properties,
+            required,
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+}
+
+impl Serialize for FlowSchema {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_root_schema().serialize(serializer)
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for FlowSchema {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        match serde_json::to_string_pretty(&self.to_root_schema()) {
+            Ok(schema_json) => presenter.text(&schema_json).await,
+            Err(error) => {
+                presenter
+                    .text(&format!("Failed to serialize flow schema: {error}"))
+                    .await
+            }
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use std::time::Duration;
+
+/// Requires items to send progress updates while they are applied, so that hung
+/// applies can be told apart from slow ones.
+///
+/// When an item's `ApplyFns::exec` does not send a progress update -- such as
+/// a `tick` or `inc` -- within the interval, the item is marked as stalled in
+/// progress output, and is optionally cancelled.
+///
+/// Heartbeats are progress updates, so this requires the `output_progress`
+/// feature, and is not supported on WASM. Dry runs are not checked.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let heartbeat_policy = HeartbeatPolicy::new()
+///     .with_interval(Duration::from_secs(30))
+///     .with_cancel_on_stall(true);
+///
+/// let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(output, workspace)
+///     .with_heartbeat_policy(heartbeat_policy)
+///     // ..
+///     .await?;
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HeartbeatPolicy {
+    /// Maximum duration between progress updates before an item is stalled.
+    ///
+    /// `None` means heartbeats are not required.
+    interval: Option<Duration>,
+    /// Whether stalled items are cancelled.
+    cancel_on_stall: bool,
+}
+
+impl HeartbeatPolicy {
+    /// Returns a new `HeartbeatPolicy` that does not require heartbeats.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum duration between progress updates before an item is
+    /// stalled.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Sets whether stalled items are cancelled.
+    ///
+    /// Cancelled items fail with [`Error::ItemApplyStalled`].
+    ///
+    /// [`Error::ItemApplyStalled`]: crate::Error::ItemApplyStalled
+    pub fn with_cancel_on_stall(mut self, cancel_on_stall: bool) -> Self {
+        self.cancel_on_stall = cancel_on_stall;
+        self
+    }
+
+    /// Returns the maximum duration between progress updates before an item
+    /// is stalled, if heartbeats are required.
+    pub fn interval(&self) -> Option<Duration> {
+        self.interval
+    }
+
+    /// Returns whether stalled items are cancelled.
+    pub fn cancel_on_stall(&self) -> bool {
+        self.cancel_on_stall
+    }
+}
@@ -0,0 +1,166 @@
+use peace_cfg::state::SensitiveSerdeMode;
+use peace_fmt::Presentable;
+use peace_rt_model_core::{async_trait, output::OutputWrite};
+use serde::de::DeserializeOwned;
+
+use crate::Error;
+
+pub use self::in_memory_output_event::InMemoryOutputEvent;
+
+mod in_memory_output_event;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "output_progress")] {
+        use peace_cfg::progress::{ProgressTracker, ProgressUpdateAndId};
+
+        use crate::CmdProgressTracker;
+    }
+}
+
+/// An `OutputWrite` implementation that records output in memory.
+///
+/// Each value presented, error written, and progress update is recorded as
+/// an [`InMemoryOutputEvent`], so tests can assert on what was output without
+/// parsing rendered text. The last presented value or error is also kept as
+/// YAML text.
+#[derive(Debug, Default)]
+pub struct InMemoryOutput {
+    /// Rendered text of the last presented value or error.
+    buffer: String,
+    /// Events recorded in the order they were output.
+    events: Vec<InMemoryOutputEvent>,
+}
+
+impl InMemoryOutput {
+    /// Returns a new `InMemoryOutput`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the rendered text of the last presented value or error.
+    pub fn text(&self) -> &str {
+        &self.buffer
+    }
+
+    /// Returns the rendered text of the last presented value or error.
+    pub fn into_inner(self) -> String {
+        self.buffer
+    }
+
+    /// Returns the events recorded in the order they were output.
+    pub fn events(&self) -> &[InMemoryOutputEvent] {
+        &self.events
+    }
+
+    /// Returns the serialized values of each presented `T`.
+    ///
+    /// This is useful for types that cannot be deserialized without a type
+    /// registry, such as `StatesCurrent`.
+    pub fn presented_values<T>(&self) -> impl Iterator<Item = &serde_yaml::Value>
+    where
+        T: ?Sized,
+    {
+        let type_name = std::any::type_name::<T>();
+        self.events.iter().filter_map(move |event| match event {
+            InMemoryOutputEvent::Presented {
+                type_name: type_name_presented,
+                value,
+            } if type_name_presented == type_name => Some(value),
+            _ => None,
+        })
+    }
+
+    /// Returns each presented `T`, deserialized from its serialized value.
+    pub fn presented<T>(&self) -> Result<Vec<T>, serde_yaml::Error>
+    where
+        T: DeserializeOwned,
+    {
+        self.presented_values::<T>()
+            .map(|value| serde_yaml::from_value(value.clone()))
+            .collect()
+    }
+
+    /// Returns the last presented `T`, deserialized from its serialized value.
+    pub fn presented_last<T>(&self) -> Result<Option<T>, serde_yaml::Error>
+    where
+        T: DeserializeOwned,
+    {
+        self.presented_values::<T>()
+            .last()
+            .map(|value| serde_yaml::from_value(value.clone()))
+            .transpose()
+    }
+
+    /// Returns the messages of each written error.
+    pub fn errors(&self) -> impl Iterator<Item = &str> {
+        self.events.iter().filter_map(|event| match event {
+            InMemoryOutputEvent::Error { message } => Some(message.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Returns each progress update.
+    #[cfg(feature = "output_progress")]
+    pub fn progress_updates(&self) -> impl Iterator<Item = &ProgressUpdateAndId> {
+        self.events.iter().filter_map(|event| match event {
+            InMemoryOutputEvent::ProgressUpdate(progress_update_and_id) => {
+                Some(progress_update_and_id)
+            }
+            _ => None,
+        })
+    }
+}
+
+/// Simple serialization implementations for now.
+///
+/// See <https://github.com/azriel91/peace/issues/28> for further improvements.
+#[async_trait(?Send)]
+impl<E> OutputWrite<E> for InMemoryOutput
+where
+    E: std::error::Error + From<Error>,
+{
+    #[cfg(feature = "output_progress")]
+    async fn progress_begin(&mut self, _cmd_progress_tracker: &CmdProgressTracker) {
+        self.events.push(InMemoryOutputEvent::ProgressBegin);
+    }
+
+    #[cfg(feature = "output_progress")]
+    async fn progress_update(
+        &mut self,
+        _progress_tracker: &ProgressTracker,
+        progress_update_and_id: &ProgressUpdateAndId,
+    ) {
+        self.events.push(InMemoryOutputEvent::ProgressUpdate(
+            progress_update_and_id.clone(),
+        ));
+    }
+
+    #[cfg(feature = "output_progress")]
+    async fn progress_end(&mut self, _cmd_progress_tracker: &CmdProgressTracker) {
+        self.events.push(InMemoryOutputEvent::ProgressEnd);
+    }
+
+    async fn present<P>(&mut self, presentable: P) -> Result<(), E>
+    where
+        P: Presentable,
+    {
+        let value = SensitiveSerdeMode::Redact
+            .scope(|| serde_yaml::to_value(&presentable))
+            .map_err(Error::StatesSerialize)?;
+        self.buffer = serde_yaml::to_string(&value).map_err(Error::StatesSerialize)?;
+        self.events.push(InMemoryOutputEvent::Presented {
+            type_name: InMemoryOutputEvent::type_name::<P>(),
+            value,
+        });
+
+        Ok(())
+    }
+
+    async fn write_err(&mut self, error: &E) -> Result<(), E> {
+        let message = error.to_string();
+        self.buffer = format!("{message}\n");
+        self.events.push(InMemoryOutputEvent::Error { message });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+#[cfg(feature = "output_progress")]
+use peace_cfg::progress::ProgressUpdateAndId;
+
+/// Output recorded by [`InMemoryOutput`].
+///
+/// [`InMemoryOutput`]: crate::InMemoryOutput
+#[derive(Clone, Debug, PartialEq)]
+pub enum InMemoryOutputEvent {
+    /// A value was presented.
+    Presented {
+        /// Type name of the presented value, without references.
+        type_name: String,
+        /// The presented value, serialized.
+        value: serde_yaml::Value,
+    },
+    /// An error was written.
+    Error {
+        /// `Display` string of the error.
+        message: String,
+    },
+    /// Progress output began.
+    #[cfg(feature = "output_progress")]
+    ProgressBegin,
+    /// An item's progress was updated.
+    #[cfg(feature = "output_progress")]
+    ProgressUpdate(ProgressUpdateAndId),
+    /// Progress output ended.
+    #[cfg(feature = "output_progress")]
+    ProgressEnd,
+}
+
+impl InMemoryOutputEvent {
+    /// Returns the type name of `T`, without leading references.
+    ///
+    /// Values are often presented by reference, so this allows them to be
+    /// queried by their owned type.
+    pub(crate) fn type_name<T>() -> String
+    where
+        T: ?Sized,
+    {
+        std::any::type_name::<T>()
+            .trim_start_matches('&')
+            .to_string()
+    }
+}
@@ -0,0 +1,32 @@
+/// Kind of dependency between two items in an [`ItemGraph`].
+///
+/// # `DataDependency` vs `OrderingOnly`
+///
+/// Both kinds order the successor after the predecessor when items are
+/// applied, and in reverse when items are cleaned.
+///
+/// The difference is whether the successor needs the predecessor's state to
+/// discover its own state. For example, a file downloaded to a server needs
+/// the server's address, so it cannot be discovered until the server is
+/// discovered. However, an application that should only be started after a
+/// database migration has run can have its state discovered independently.
+///
+/// When discovering states, `OrderingOnly` edges are not honoured, so items
+/// that are only ordered after other items may be discovered concurrently
+/// with them.
+///
+/// [`ItemGraph`]: crate::ItemGraph
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ItemEdgeKind {
+    /// The successor reads data that the predecessor produces.
+    ///
+    /// The predecessor is run before the successor for all operations,
+    /// including state discovery.
+    #[default]
+    DataDependency,
+    /// The successor is applied after the predecessor, but does not need its
+    /// data.
+    ///
+    /// State discovery for the successor is not blocked by the predecessor.
+    OrderingOnly,
+}
@@ -0,0 +1,33 @@
+use std::fmt::Debug;
+
+use futures::future::BoxFuture;
+
+pub use self::{
+    item_executor_concurrent::ItemExecutorConcurrent, item_executor_limited::ItemExecutorLimited,
+    item_executor_yielding::ItemExecutorYielding,
+};
+
+mod item_executor_concurrent;
+mod item_executor_limited;
+mod item_executor_yielding;
+mod yield_now;
+
+/// Controls how items are run concurrently within a command block.
+///
+/// Command blocks poll each item's future within the same task, so items only
+/// run concurrently when they await, e.g. on network requests. This works
+/// across runtimes, including `wasm_bindgen_futures::spawn_local` on the
+/// browser's main thread, but items that do a lot of work between awaits hold
+/// up the other items, as well as anything else on the main thread.
+///
+/// Implementations may yield back to the runtime before each item is run, so
+/// that other tasks -- such as progress rendering, or the UI -- are not starved.
+///
+/// Defaults to [`ItemExecutorConcurrent`].
+pub trait ItemExecutor: Debug + Send + Sync + 'static {
+    /// Returns the maximum number of items to run concurrently.
+    fn concurrency_max(&self) -> usize;
+
+    /// Returns a future that is awaited before each item is run.
+    fn item_begin(&self) -> BoxFuture<'static, ()>;
+}
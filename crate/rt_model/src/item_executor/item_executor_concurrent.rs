@@ -0,0 +1,43 @@
+use futures::{future::BoxFuture, FutureExt};
+
+use crate::ItemExecutor;
+
+/// Runs items concurrently, without yielding to the runtime between items.
+///
+/// This is the default [`ItemExecutor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ItemExecutorConcurrent {
+    /// Maximum number of items to run concurrently.
+    concurrency_max: usize,
+}
+
+impl ItemExecutorConcurrent {
+    /// Default maximum number of items to run concurrently.
+    pub const CONCURRENCY_MAX_DEFAULT: usize = 64;
+
+    /// Returns a new `ItemExecutorConcurrent` that runs at most
+    /// `concurrency_max` items concurrently.
+    ///
+    /// A `concurrency_max` of `0` is treated as `1`.
+    pub fn new(concurrency_max: usize) -> Self {
+        Self {
+            concurrency_max: concurrency_max.max(1),
+        }
+    }
+}
+
+impl Default for ItemExecutorConcurrent {
+    fn default() -> Self {
+        Self::new(Self::CONCURRENCY_MAX_DEFAULT)
+    }
+}
+
+impl ItemExecutor for ItemExecutorConcurrent {
+    fn concurrency_max(&self) -> usize {
+        self.concurrency_max
+    }
+
+    fn item_begin(&self) -> BoxFuture<'static, ()> {
+        futures::future::ready(()).boxed()
+    }
+}
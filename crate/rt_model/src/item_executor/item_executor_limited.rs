@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+
+use crate::ItemExecutor;
+
+/// Limits the number of items run concurrently by another [`ItemExecutor`].
+///
+/// This is used by `CmdCtxBuilder::with_max_concurrency`, so that the limit
+/// can be set independently of the item executor.
+#[derive(Clone, Debug)]
+pub struct ItemExecutorLimited {
+    /// Item executor whose `item_begin` is used.
+    item_executor: Arc<dyn ItemExecutor>,
+    /// Maximum number of items to run concurrently.
+    concurrency_max: usize,
+}
+
+impl ItemExecutorLimited {
+    /// Returns a new `ItemExecutorLimited` that runs at most `concurrency_max`
+    /// items concurrently using the given item executor.
+    ///
+    /// A `concurrency_max` of `0` is treated as `1`.
+    pub fn new(item_executor: Arc<dyn ItemExecutor>, concurrency_max: usize) -> Self {
+        Self {
+            item_executor,
+            concurrency_max: concurrency_max.max(1),
+        }
+    }
+
+    /// Returns the item executor whose `item_begin` is used.
+    pub fn item_executor(&self) -> &Arc<dyn ItemExecutor> {
+        &self.item_executor
+    }
+}
+
+impl ItemExecutor for ItemExecutorLimited {
+    fn concurrency_max(&self) -> usize {
+        self.concurrency_max
+    }
+
+    fn item_begin(&self) -> BoxFuture<'static, ()> {
+        self.item_executor.item_begin()
+    }
+}
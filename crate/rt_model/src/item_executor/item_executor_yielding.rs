@@ -0,0 +1,42 @@
+use futures::{future::BoxFuture, FutureExt};
+
+use crate::{item_executor::yield_now::YieldNow, ItemExecutor, ItemExecutorConcurrent};
+
+/// Runs items concurrently, yielding to the runtime before each item is run.
+///
+/// This is intended for single threaded runtimes, such as
+/// `wasm_bindgen_futures::spawn_local` on the browser's main thread, so that
+/// other tasks are able to make progress while items are being run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ItemExecutorYielding {
+    /// Maximum number of items to run concurrently.
+    concurrency_max: usize,
+}
+
+impl ItemExecutorYielding {
+    /// Returns a new `ItemExecutorYielding` that runs at most
+    /// `concurrency_max` items concurrently.
+    ///
+    /// A `concurrency_max` of `0` is treated as `1`.
+    pub fn new(concurrency_max: usize) -> Self {
+        Self {
+            concurrency_max: concurrency_max.max(1),
+        }
+    }
+}
+
+impl Default for ItemExecutorYielding {
+    fn default() -> Self {
+        Self::new(ItemExecutorConcurrent::CONCURRENCY_MAX_DEFAULT)
+    }
+}
+
+impl ItemExecutor for ItemExecutorYielding {
+    fn concurrency_max(&self) -> usize {
+        self.concurrency_max
+    }
+
+    fn item_begin(&self) -> BoxFuture<'static, ()> {
+        YieldNow::new().boxed()
+    }
+}
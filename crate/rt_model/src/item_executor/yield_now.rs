@@ -0,0 +1,36 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Future that returns `Pending` once, so that the runtime polls other tasks
+/// before this one is polled again.
+///
+/// This does not depend on a particular runtime.
+#[derive(Debug, Default)]
+pub(crate) struct YieldNow {
+    /// Whether this future has already yielded.
+    yielded: bool,
+}
+
+impl YieldNow {
+    /// Returns a new `YieldNow` future.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.yielded {
+            Poll::Ready(())
+        } else {
+            self.yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
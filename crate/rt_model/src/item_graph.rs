@@ -3,22 +3,42 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use peace_data::fn_graph::FnGraph;
+use peace_cfg::ItemId;
+use peace_data::fn_graph::{
+    daggy::petgraph::{visit::EdgeRef, Direction},
+    Edge, FnGraph, FnGraphBuilder, FnId,
+};
+use peace_params::ParamsSpecs;
 use peace_resources::states::{States, StatesSerde};
 
-use crate::ItemBoxed;
+use crate::{CleanOrderingOverride, Error, ItemBoxed};
 
 /// Graph of all [`Item`]s, `FnGraph<ItemBoxed<E>>` newtype.
 ///
 /// [`Item`]: peace_cfg::Item
 #[derive(Debug)]
-pub struct ItemGraph<E>(FnGraph<ItemBoxed<E>>);
+pub struct ItemGraph<E> {
+    /// Graph of all items, with all edges.
+    graph: FnGraph<ItemBoxed<E>>,
+    /// Graph of all items, without [`ItemEdgeKind::OrderingOnly`] edges.
+    ///
+    /// [`ItemEdgeKind::OrderingOnly`]: crate::ItemEdgeKind::OrderingOnly
+    graph_discover: FnGraph<ItemBoxed<E>>,
+    /// Edges added with [`ItemEdgeKind::OrderingOnly`].
+    ///
+    /// [`ItemEdgeKind::OrderingOnly`]: crate::ItemEdgeKind::OrderingOnly
+    edges_ordering_only: Vec<(FnId, FnId)>,
+}
 
 // Manual implementation because derive requires `E` to be `Clone`,
 // which causes `graph.clone()` to call `FnGraph::clone`.
 impl<E> Clone for ItemGraph<E> {
     fn clone(&self) -> Self {
-        Self(self.0.clone())
+        Self {
+            graph: self.graph.clone(),
+            graph_discover: self.graph_discover.clone(),
+            edges_ordering_only: self.edges_ordering_only.clone(),
+        }
     }
 }
 
@@ -27,16 +47,152 @@ where
     E: 'static,
 {
     fn eq(&self, other: &Self) -> bool {
-        self.0 == other.0
+        self.graph == other.graph && self.edges_ordering_only == other.edges_ordering_only
     }
 }
 
 impl<E> Eq for ItemGraph<E> where E: 'static {}
 
 impl<E> ItemGraph<E> {
+    /// Returns a new `ItemGraph`, where the given edges are
+    /// [`ItemEdgeKind::OrderingOnly`] edges.
+    ///
+    /// [`ItemEdgeKind::OrderingOnly`]: crate::ItemEdgeKind::OrderingOnly
+    pub(crate) fn new(
+        graph: FnGraph<ItemBoxed<E>>,
+        edges_ordering_only: Vec<(FnId, FnId)>,
+    ) -> Self {
+        let graph_discover = Self::graph_discover_build(&graph, &edges_ordering_only);
+
+        Self {
+            graph,
+            graph_discover,
+            edges_ordering_only,
+        }
+    }
+
     /// Returns the inner [`FnGraph`].
     pub fn into_inner(self) -> FnGraph<ItemBoxed<E>> {
-        self.0
+        self.graph
+    }
+
+    /// Returns the graph to use when discovering states.
+    ///
+    /// This contains the same items as this graph, but excludes
+    /// [`ItemEdgeKind::OrderingOnly`] edges, so that items are only blocked
+    /// on the items whose data they depend on.
+    ///
+    /// [`ItemEdgeKind::OrderingOnly`]: crate::ItemEdgeKind::OrderingOnly
+    pub fn graph_discover(&self) -> &FnGraph<ItemBoxed<E>> {
+        &self.graph_discover
+    }
+
+    /// Returns the edges that were added with
+    /// [`ItemEdgeKind::OrderingOnly`].
+    ///
+    /// [`ItemEdgeKind::OrderingOnly`]: crate::ItemEdgeKind::OrderingOnly
+    pub fn edges_ordering_only(&self) -> &[(FnId, FnId)] {
+        &self.edges_ordering_only
+    }
+
+    /// Returns the graph to use when cleaning items, with the given overrides
+    /// applied.
+    ///
+    /// Items are cleaned in the reverse order of the returned graph's edges.
+    /// For each override, the edge from `item_id_before` to `item_id_after`
+    /// is removed, and an edge from `item_id_after` to `item_id_before` is
+    /// added, so that `item_id_before` is cleaned first. Overrides whose item
+    /// IDs are not in this graph are ignored.
+    ///
+    /// `Edge::Data` edges, which order items whose data conflict, are not
+    /// copied from this graph. They are recalculated when the clean graph is
+    /// built, and only added between items that are not already ordered, so
+    /// they follow the overridden order instead of restoring the original one.
+    ///
+    /// Returns [`Error::CleanOrderingOverrideCyclic`] if an override
+    /// contradicts the order of edges through other items.
+    pub fn graph_clean_build(
+        &self,
+        clean_ordering_overrides: &[CleanOrderingOverride],
+    ) -> Result<FnGraph<ItemBoxed<E>>, Error>
+    where
+        E: 'static,
+    {
+        let dag = self.graph.graph.graph();
+        let item_index = |item_id: &ItemId| {
+            dag.node_indices()
+                .find(|node_index| dag[*node_index].id() == item_id)
+        };
+        let edges_override = clean_ordering_overrides
+            .iter()
+            .filter_map(|clean_ordering_override| {
+                let item_index_before = item_index(clean_ordering_override.item_id_before())?;
+                let item_index_after = item_index(clean_ordering_override.item_id_after())?;
+                Some((item_index_before, item_index_after, clean_ordering_override))
+            })
+            .collect::<Vec<_>>();
+
+        let mut graph_builder = Self::graph_builder_with_edges(&self.graph, |edge| {
+            !edges_override
+                .iter()
+                .any(|(item_index_before, item_index_after, _)| {
+                    edge == (*item_index_before, *item_index_after)
+                })
+        });
+        edges_override.iter().try_for_each(
+            |(item_index_before, item_index_after, clean_ordering_override)| {
+                graph_builder
+                    .add_logic_edge(*item_index_after, *item_index_before)
+                    .map(|_edge_id| ())
+                    .map_err(|_would_cycle| Error::CleanOrderingOverrideCyclic {
+                        item_id_before: clean_ordering_override.item_id_before().clone(),
+                        item_id_after: clean_ordering_override.item_id_after().clone(),
+                    })
+            },
+        )?;
+
+        Ok(graph_builder.build())
+    }
+
+    /// Returns a copy of the graph without the given ordering only edges.
+    fn graph_discover_build(
+        graph: &FnGraph<ItemBoxed<E>>,
+        edges_ordering_only: &[(FnId, FnId)],
+    ) -> FnGraph<ItemBoxed<E>> {
+        if edges_ordering_only.is_empty() {
+            return graph.clone();
+        }
+
+        Self::graph_builder_with_edges(graph, |edge| !edges_ordering_only.contains(&edge)).build()
+    }
+
+    /// Returns a builder with the graph's items, and the edges that
+    /// `edge_include` returns `true` for.
+    ///
+    /// `Edge::Data` edges are not copied, as they are recalculated when the
+    /// graph is built.
+    fn graph_builder_with_edges(
+        graph: &FnGraph<ItemBoxed<E>>,
+        edge_include: impl Fn((FnId, FnId)) -> bool,
+    ) -> FnGraphBuilder<ItemBoxed<E>> {
+        let mut graph_builder = FnGraphBuilder::new();
+        graph.iter_insertion().for_each(|item| {
+            graph_builder.add_fn(item.clone());
+        });
+        graph
+            .graph()
+            .edge_references()
+            .filter(|edge| edge_include((edge.source(), edge.target())))
+            .for_each(|edge| {
+                let edge_added = match edge.weight() {
+                    Edge::Logic => graph_builder.add_logic_edge(edge.source(), edge.target()),
+                    Edge::Contains => graph_builder.add_contains_edge(edge.source(), edge.target()),
+                    Edge::Data => return,
+                };
+                edge_added.expect("Expected a subset of an acyclic graph's edges to be acyclic.");
+            });
+
+        graph_builder
     }
 
     /// Returns a user-friendly serializable states map.
@@ -48,30 +204,143 @@ impl<E> ItemGraph<E> {
         ValueT: Clone + Debug + PartialEq + Eq,
         E: 'static,
     {
-        StatesSerde::from_iter(self.0.iter_insertion().map(|item| {
+        StatesSerde::from_iter(self.graph.iter_insertion().map(|item| {
             let item_id = item.id();
             (item_id.clone(), states.get_raw(item_id).cloned())
         }))
     }
+
+    /// Returns an error if any item's params are mapped from the state of an
+    /// item that does not run before it.
+    ///
+    /// When a `*Spec::MappingFn` reads another item's `State`, that item must
+    /// be one of the mapping item's predecessors through data dependency
+    /// edges, otherwise the state may not be discovered when the params are
+    /// resolved.
+    pub fn params_specs_mapping_fns_validate(&self, params_specs: &ParamsSpecs) -> Result<(), Error>
+    where
+        E: 'static,
+    {
+        let dag = self.graph.graph.graph();
+        dag.node_indices().try_for_each(|node_index| {
+            let item = &dag[node_index];
+            let Some(params_spec) = params_specs.get_raw(item.id()) else {
+                return Ok(());
+            };
+            let mapping_fn_arg_type_ids = params_spec.mapping_fn_arg_type_ids();
+            if mapping_fn_arg_type_ids.is_empty() {
+                return Ok(());
+            }
+
+            let predecessors = self.predecessors(node_index);
+            mapping_fn_arg_type_ids
+                .into_iter()
+                .try_for_each(|mapping_fn_arg_type_id| {
+                    let item_indices_from = dag
+                        .node_indices()
+                        .filter(|item_index_from| {
+                            *item_index_from != node_index
+                                && dag[*item_index_from].state_type().type_id()
+                                    == mapping_fn_arg_type_id
+                        })
+                        .collect::<Vec<FnId>>();
+
+                    // Multiple items may share the same `State` type, so the mapping is
+                    // valid as long as one of them runs before this item.
+                    let runs_before = item_indices_from.is_empty()
+                        || item_indices_from
+                            .iter()
+                            .any(|item_index_from| predecessors.contains(item_index_from));
+                    if runs_before {
+                        Ok(())
+                    } else {
+                        let item_from = &dag[item_indices_from[0]];
+                        Err(Error::ParamsMappingFnEdgeMissing {
+                            item_id: item.id().clone(),
+                            item_id_from: item_from.id().clone(),
+                            state_type: item_from.state_type().type_name().to_string(),
+                        })
+                    }
+                })
+        })
+    }
+
+    /// Returns the node indices of all items that the given item transitively
+    /// depends on for data.
+    ///
+    /// [`ItemEdgeKind::OrderingOnly`] edges are not followed, as the
+    /// successor may be discovered before the predecessor.
+    ///
+    /// [`ItemEdgeKind::OrderingOnly`]: crate::ItemEdgeKind::OrderingOnly
+    pub(crate) fn predecessors(&self, node_index: FnId) -> Vec<FnId> {
+        let dag = self.graph_discover.graph.graph();
+        let mut predecessors = Vec::new();
+        let mut to_visit = vec![node_index];
+        while let Some(node_index) = to_visit.pop() {
+            dag.neighbors_directed(node_index, Direction::Incoming)
+                .for_each(|predecessor_index| {
+                    if !predecessors.contains(&predecessor_index) {
+                        predecessors.push(predecessor_index);
+                        to_visit.push(predecessor_index);
+                    }
+                });
+        }
+        predecessors
+    }
+
+    /// Returns the given item IDs, and the IDs of all items that transitively
+    /// depend on them for data, in insertion order.
+    ///
+    /// [`ItemEdgeKind::OrderingOnly`] edges are not followed, as the
+    /// successor's state does not depend on the predecessor's state.
+    ///
+    /// Item IDs that are not in the graph are ignored.
+    ///
+    /// [`ItemEdgeKind::OrderingOnly`]: crate::ItemEdgeKind::OrderingOnly
+    pub fn item_ids_with_successors(&self, item_ids: &[ItemId]) -> Vec<ItemId>
+    where
+        E: 'static,
+    {
+        let dag = self.graph_discover.graph.graph();
+        let mut node_indices = dag
+            .node_indices()
+            .filter(|node_index| item_ids.contains(dag[*node_index].id()))
+            .collect::<Vec<FnId>>();
+        let mut to_visit = node_indices.clone();
+        while let Some(node_index) = to_visit.pop() {
+            dag.neighbors_directed(node_index, Direction::Outgoing)
+                .for_each(|successor_index| {
+                    if !node_indices.contains(&successor_index) {
+                        node_indices.push(successor_index);
+                        to_visit.push(successor_index);
+                    }
+                });
+        }
+
+        dag.node_indices()
+            .filter(|node_index| node_indices.contains(node_index))
+            .map(|node_index| dag[node_index].id().clone())
+            .collect()
+    }
 }
 
 impl<E> Deref for ItemGraph<E> {
     type Target = FnGraph<ItemBoxed<E>>;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.graph
     }
 }
 
 impl<E> DerefMut for ItemGraph<E> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+        &mut self.graph
     }
 }
 
 impl<E> From<FnGraph<ItemBoxed<E>>> for ItemGraph<E> {
     fn from(graph: FnGraph<ItemBoxed<E>>) -> Self {
-        Self(graph)
+        Self::new(graph, Vec::new())
     }
 }
 
@@ -1,13 +1,19 @@
 use std::ops::{Deref, DerefMut};
 
-use peace_data::fn_graph::FnGraphBuilder;
+use peace_cfg::ItemId;
+use peace_data::fn_graph::{Edge, EdgeId, FnGraphBuilder, FnId, WouldCycle};
 
-use crate::{ItemBoxed, ItemGraph};
+use crate::{Error, ItemBoxed, ItemEdgeKind, ItemGraph};
 
 /// Builder for an [`ItemGraph`], `FnGraphBuilder<ItemBoxed<E>>`
 /// newtype.
 #[derive(Debug)]
-pub struct ItemGraphBuilder<E>(FnGraphBuilder<ItemBoxed<E>>);
+pub struct ItemGraphBuilder<E> {
+    /// Builder for the underlying function graph.
+    graph_builder: FnGraphBuilder<ItemBoxed<E>>,
+    /// Edges added with [`ItemEdgeKind::OrderingOnly`].
+    edges_ordering_only: Vec<(FnId, FnId)>,
+}
 
 impl<E> ItemGraphBuilder<E> {
     /// Returns a new `ItemGraphBuilder`.
@@ -17,18 +23,153 @@ impl<E> ItemGraphBuilder<E> {
 
     /// Returns the inner [`FnGraphBuilder`].
     pub fn into_inner(self) -> FnGraphBuilder<ItemBoxed<E>> {
-        self.0
+        self.graph_builder
+    }
+
+    /// Adds an edge from one item to another, annotated with the kind of
+    /// dependency between them.
+    ///
+    /// Edges added through [`add_logic_edge`] are
+    /// [`ItemEdgeKind::DataDependency`] edges.
+    ///
+    /// Like [`add_logic_edge`], only one edge is retained between two items.
+    /// When this is called multiple times with the same items, the last
+    /// call's kind is used.
+    ///
+    /// [`add_logic_edge`]: FnGraphBuilder::add_logic_edge
+    pub fn add_edge(
+        &mut self,
+        item_from: FnId,
+        item_to: FnId,
+        item_edge_kind: ItemEdgeKind,
+    ) -> Result<EdgeId, WouldCycle<Edge>> {
+        let edge_id = self.graph_builder.add_logic_edge(item_from, item_to)?;
+
+        self.edges_ordering_only
+            .retain(|edge| *edge != (item_from, item_to));
+        if item_edge_kind == ItemEdgeKind::OrderingOnly {
+            self.edges_ordering_only.push((item_from, item_to));
+        }
+
+        Ok(edge_id)
+    }
+
+    /// Adds an item for each instance key, returning the [`FnId`]s of the
+    /// added items in the same order as the keys.
+    ///
+    /// Each item is constructed by `item_fn` with the instance's ID, which is
+    /// derived using [`ItemId::instance`]. Parameters for each instance are
+    /// provided using `with_item_params_for_each` on the command context
+    /// builder, with the same `item_id` and keys.
+    ///
+    /// Since each instance has its own ID, states are stored separately for
+    /// each instance. However, data that items insert into `Resources` by
+    /// type, such as `Current<State>`, is shared between instances.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let file_names = ["app.tar.gz", "config.yaml"];
+    /// let fn_ids = graph_builder.add_fn_for_each(
+    ///     &item_id!("file_download"),
+    ///     file_names,
+    ///     FileDownloadItem::<WebApp>::new,
+    /// );
+    /// ```
+    pub fn add_fn_for_each<I, K, F>(
+        &mut self,
+        item_id: &ItemId,
+        instance_keys: impl IntoIterator<Item = K>,
+        mut item_fn: F,
+    ) -> Vec<FnId>
+    where
+        I: Into<ItemBoxed<E>>,
+        K: AsRef<str>,
+        F: FnMut(ItemId) -> I,
+    {
+        instance_keys
+            .into_iter()
+            .map(|instance_key| {
+                let item_id_instance = item_id.instance(instance_key.as_ref());
+                self.graph_builder.add_fn(item_fn(item_id_instance).into())
+            })
+            .collect()
     }
 
     /// Builds and returns the [`ItemGraph`].
-    pub fn build(self) -> ItemGraph<E> {
-        ItemGraph::from(self.0.build())
+    ///
+    /// # Panics
+    ///
+    /// Panics if an item's [`inputs`] are not output by any of its
+    /// predecessors. Use [`try_build`] to return an error instead.
+    ///
+    /// [`inputs`]: peace_cfg::Item::inputs
+    /// [`try_build`]: Self::try_build
+    pub fn build(self) -> ItemGraph<E>
+    where
+        E: 'static,
+    {
+        self.try_build()
+            .unwrap_or_else(|error| panic!("Failed to build item graph: {error}"))
+    }
+
+    /// Builds and returns the [`ItemGraph`], verifying that each item's
+    /// [`inputs`] are output by one of its predecessors.
+    ///
+    /// [`inputs`]: peace_cfg::Item::inputs
+    pub fn try_build(self) -> Result<ItemGraph<E>, Error>
+    where
+        E: 'static,
+    {
+        let Self {
+            graph_builder,
+            edges_ordering_only,
+        } = self;
+        let item_graph = ItemGraph::new(graph_builder.build(), edges_ordering_only);
+        Self::inputs_validate(&item_graph)?;
+
+        Ok(item_graph)
+    }
+
+    /// Returns an error if any item's inputs are not output by one of its
+    /// predecessors.
+    fn inputs_validate(item_graph: &ItemGraph<E>) -> Result<(), Error>
+    where
+        E: 'static,
+    {
+        let dag = item_graph.graph.graph();
+        dag.node_indices().try_for_each(|node_index| {
+            let item = &dag[node_index];
+            let input_types = item.input_types();
+            if input_types.is_empty() {
+                return Ok(());
+            }
+
+            let predecessor_output_types = item_graph
+                .predecessors(node_index)
+                .into_iter()
+                .flat_map(|predecessor_index| dag[predecessor_index].output_types())
+                .collect::<Vec<_>>();
+            input_types.into_iter().try_for_each(|input_type| {
+                if predecessor_output_types.contains(&input_type) {
+                    Ok(())
+                } else {
+                    Err(Error::ItemInputNotProvided {
+                        item_id: item.id().clone(),
+                        input_type: input_type.type_name().to_string(),
+                    })
+                }
+            })
+        })
     }
 }
 
 impl<E> Default for ItemGraphBuilder<E> {
     fn default() -> Self {
-        Self(FnGraphBuilder::default())
+        Self {
+            graph_builder: FnGraphBuilder::default(),
+            edges_ordering_only: Vec::new(),
+        }
     }
 }
 
@@ -36,18 +177,21 @@ impl<E> Deref for ItemGraphBuilder<E> {
     type Target = FnGraphBuilder<ItemBoxed<E>>;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.graph_builder
     }
 }
 
 impl<E> DerefMut for ItemGraphBuilder<E> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+        &mut self.graph_builder
     }
 }
 
 impl<E> From<FnGraphBuilder<ItemBoxed<E>>> for ItemGraphBuilder<E> {
-    fn from(graph: FnGraphBuilder<ItemBoxed<E>>) -> Self {
-        Self(graph)
+    fn from(graph_builder: FnGraphBuilder<ItemBoxed<E>>) -> Self {
+        Self {
+            graph_builder,
+            edges_ordering_only: Vec::new(),
+        }
     }
 }
@@ -0,0 +1,264 @@
+use std::{
+    collections::{hash_map::Entry, HashMap, HashSet},
+    fmt::{self, Write as _},
+    fs::{File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use chrono::{SecondsFormat, Utc};
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Event, Subscriber,
+};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+
+/// `tracing` layer that captures each item's log output into a file.
+///
+/// Events recorded within an item's span are appended to that item's log file
+/// in the command execution's `ItemLogsDir`, typically
+/// `$flow_dir/logs/$execution_id/$item_id.log`. Events outside of an item's
+/// span, or outside of a command execution, are not captured.
+///
+/// Add this layer to the subscriber passed to
+/// `CmdCtxBuilder::with_tracing_subscriber` to capture item logs:
+///
+/// ```rust,ignore
+/// use peace::rt_model::ItemLogLayer;
+/// use tracing_subscriber::layer::SubscriberExt;
+///
+/// let subscriber = tracing_subscriber::registry().with(ItemLogLayer::new());
+/// let cmd_ctx = CmdCtx::builder_single_profile_single_flow(output, workspace)
+///     .with_tracing_subscriber(subscriber)
+///     // ..
+///     .await?;
+/// ```
+///
+/// Log files are written directly to the file system, not through `Storage`,
+/// so they are not encrypted. Commands in a `WorkspaceSpec::PathEncrypted`
+/// workspace do not capture item logs.
+///
+/// Failing to write a log file does not fail the command. Failing to create a
+/// log file is reported once per file on `stderr`.
+#[derive(Debug, Default)]
+pub struct ItemLogLayer {
+    /// Open log files, keyed by their path.
+    item_log_files: Mutex<HashMap<PathBuf, File>>,
+    /// Log files that failed to be created, so that they are not retried.
+    item_log_files_failed: Mutex<HashSet<PathBuf>>,
+}
+
+impl ItemLogLayer {
+    /// Name of the span field that holds the ID of the item that the span is
+    /// for.
+    pub const FIELD_ITEM_ID: &'static str = "item_id";
+    /// Name of the span field that holds the directory to write item log files
+    /// to.
+    pub const FIELD_ITEM_LOGS_DIR: &'static str = "item_logs_dir";
+
+    /// Returns a new `ItemLogLayer`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends the line to the log file, creating the file if necessary.
+    fn line_append(&self, item_log_file: PathBuf, line: &str) {
+        let Ok(mut item_log_files) = self.item_log_files.lock() else {
+            return;
+        };
+
+        let file = match item_log_files.entry(item_log_file) {
+            Entry::Occupied(occupied_entry) => occupied_entry.into_mut(),
+            Entry::Vacant(vacant_entry) => {
+                let item_log_file = vacant_entry.key();
+                if self.file_open_has_failed(item_log_file) {
+                    return;
+                }
+                match Self::file_open(item_log_file) {
+                    Ok(file) => vacant_entry.insert(file),
+                    Err(error) => {
+                        self.file_open_failed(item_log_file, error);
+                        return;
+                    }
+                }
+            }
+        };
+
+        let _write_result = file.write_all(line.as_bytes());
+    }
+
+    fn file_open(item_log_file: &Path) -> io::Result<File> {
+        if let Some(item_logs_dir) = item_log_file.parent() {
+            std::fs::create_dir_all(item_logs_dir)?;
+        }
+
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(item_log_file)
+    }
+
+    fn file_open_has_failed(&self, item_log_file: &Path) -> bool {
+        self.item_log_files_failed
+            .lock()
+            .map(|item_log_files_failed| item_log_files_failed.contains(item_log_file))
+            .unwrap_or(true)
+    }
+
+    /// Reports that the log file could not be created, the first time it
+    /// fails.
+    ///
+    /// This is written to `stderr` instead of being logged, as events logged
+    /// here would be captured by this layer.
+    fn file_open_failed(&self, item_log_file: &Path, error: io::Error) {
+        let Ok(mut item_log_files_failed) = self.item_log_files_failed.lock() else {
+            return;
+        };
+
+        if item_log_files_failed.insert(item_log_file.to_path_buf()) {
+            eprintln!(
+                "warn: Failed to create item log file `{}`:\n{error}",
+                item_log_file.display()
+            );
+        }
+    }
+}
+
+impl<S> Layer<S> for ItemLogLayer
+where
+    S: Subscriber + for<'lookup> LookupSpan<'lookup>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut span_log_fields = SpanLogFields::default();
+        attrs.record(&mut span_log_fields);
+
+        if !span_log_fields.is_empty() {
+            if let Some(span) = ctx.span(id) {
+                span.extensions_mut().insert(span_log_fields);
+            }
+        }
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+
+        let mut extensions = span.extensions_mut();
+        match extensions.get_mut::<SpanLogFields>() {
+            Some(span_log_fields) => values.record(span_log_fields),
+            None => {
+                let mut span_log_fields = SpanLogFields::default();
+                values.record(&mut span_log_fields);
+                if !span_log_fields.is_empty() {
+                    extensions.insert(span_log_fields);
+                }
+            }
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let Some(scope) = ctx.event_scope(event) else {
+            return;
+        };
+
+        // The innermost item ID and logs directory apply.
+        let mut item_id = None;
+        let mut item_logs_dir = None;
+        for span in scope {
+            if let Some(span_log_fields) = span.extensions().get::<SpanLogFields>() {
+                if item_id.is_none() {
+                    item_id = span_log_fields.item_id.clone();
+                }
+                if item_logs_dir.is_none() {
+                    item_logs_dir = span_log_fields.item_logs_dir.clone();
+                }
+            }
+            if item_id.is_some() && item_logs_dir.is_some() {
+                break;
+            }
+        }
+        let (Some(item_id), Some(item_logs_dir)) = (item_id, item_logs_dir) else {
+            return;
+        };
+
+        let mut event_fields = EventFields::default();
+        event.record(&mut event_fields);
+
+        let metadata = event.metadata();
+        let line = format!(
+            "{timestamp} {level:>5} {target}: {message}{fields}\n",
+            timestamp = Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+            level = metadata.level(),
+            target = metadata.target(),
+            message = event_fields.message,
+            fields = event_fields.fields,
+        );
+
+        self.line_append(item_logs_dir.join(format!("{item_id}.log")), &line);
+    }
+}
+
+/// Span fields that determine which log file events are written to.
+#[derive(Debug, Default)]
+struct SpanLogFields {
+    /// ID of the item that the span is for.
+    item_id: Option<String>,
+    /// Directory to write item log files to.
+    item_logs_dir: Option<PathBuf>,
+}
+
+impl SpanLogFields {
+    fn is_empty(&self) -> bool {
+        self.item_id.is_none() && self.item_logs_dir.is_none()
+    }
+}
+
+impl Visit for SpanLogFields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            ItemLogLayer::FIELD_ITEM_ID => self.item_id = Some(value.to_string()),
+            ItemLogLayer::FIELD_ITEM_LOGS_DIR => self.item_logs_dir = Some(PathBuf::from(value)),
+            _ => {}
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        match field.name() {
+            ItemLogLayer::FIELD_ITEM_ID => self.item_id = Some(format!("{value:?}")),
+            ItemLogLayer::FIELD_ITEM_LOGS_DIR => {
+                self.item_logs_dir = Some(PathBuf::from(format!("{value:?}")))
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Message and fields of an event, formatted for a log line.
+#[derive(Debug, Default)]
+struct EventFields {
+    /// The event's message.
+    message: String,
+    /// The event's other fields, formatted as ` name=value` pairs.
+    fields: String,
+}
+
+impl Visit for EventFields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            let _write_result = write!(self.fields, " {}={value:?}", field.name());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            let _write_result = write!(self.message, "{value:?}");
+        } else {
+            let _write_result = write!(self.fields, " {}={value:?}", field.name());
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use std::borrow::Cow;
+
+use peace_cfg::ItemId;
+use peace_fmt::{
+    presentable::{CodeInline, HeadingLevel},
+    Presentable, Presenter,
+};
+use peace_params::ValueResolution;
+use serde::Serialize;
+
+/// An item's resolved params, and how each value was resolved.
+///
+/// See [`ItemRt::params_explain`].
+///
+/// [`ItemRt::params_explain`]: crate::ItemRt::params_explain
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ItemParamsExplanation {
+    /// ID of the item whose params were resolved.
+    item_id: ItemId,
+    /// Name of the `Item::Params` type.
+    params_type_name: String,
+    /// `Debug` representation of the resolved params.
+    ///
+    /// If the params could not be fully resolved, this is the `Debug`
+    /// representation of the params partial.
+    params: String,
+    /// Whether every field of the params was resolved.
+    params_complete: bool,
+    /// How each value was resolved, in resolution order.
+    value_resolutions: Vec<ValueResolution>,
+}
+
+impl ItemParamsExplanation {
+    /// Returns a new `ItemParamsExplanation`.
+    pub fn new(
+        item_id: ItemId,
+        params_type_name: String,
+        params: String,
+        params_complete: bool,
+        value_resolutions: Vec<ValueResolution>,
+    ) -> Self {
+        Self {
+            item_id,
+            params_type_name,
+            params,
+            params_complete,
+            value_resolutions,
+        }
+    }
+
+    /// Returns the ID of the item whose params were resolved.
+    pub fn item_id(&self) -> &ItemId {
+        &self.item_id
+    }
+
+    /// Returns the name of the `Item::Params` type.
+    pub fn params_type_name(&self) -> &str {
+        &self.params_type_name
+    }
+
+    /// Returns the `Debug` representation of the resolved params.
+    ///
+    /// If the params could not be fully resolved, this is the `Debug`
+    /// representation of the params partial.
+    pub fn params(&self) -> &str {
+        &self.params
+    }
+
+    /// Returns whether every field of the params was resolved.
+    pub fn params_complete(&self) -> bool {
+        self.params_complete
+    }
+
+    /// Returns how each value was resolved, in resolution order.
+    pub fn value_resolutions(&self) -> &[ValueResolution] {
+        &self.value_resolutions
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for ItemParamsExplanation {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        let params_type_name = CodeInline::new(Cow::Borrowed(self.params_type_name.as_str()));
+        let params = if self.params_complete {
+            self.params.clone()
+        } else {
+            format!("{} (incomplete)", self.params)
+        };
+
+        let entries = std::iter::once((params_type_name.clone(), params))
+            .chain(self.value_resolutions.iter().map(|value_resolution| {
+                let field_path = value_resolution.field_path();
+                let field_path = if field_path.is_empty() {
+                    params_type_name.clone()
+                } else {
+                    CodeInline::new(Cow::Owned(field_path))
+                };
+                (field_path, value_resolution.to_string())
+            }))
+            .collect::<Vec<_>>();
+
+        presenter
+            .heading(HeadingLevel::Level2, &self.item_id)
+            .await?;
+        presenter.list_bulleted_aligned(entries.iter()).await
+    }
+}
@@ -0,0 +1,71 @@
+use std::fmt;
+
+use peace_cfg::ItemId;
+use peace_rt_model_core::IndexMap;
+
+use crate::ItemBoxed;
+
+/// Constructor for an item, given its ID.
+type ItemCtor<E> = Box<dyn Fn(ItemId) -> ItemBoxed<E>>;
+
+/// Registry of item constructors, keyed by item type name.
+///
+/// This is used by [`FlowLoader`] to construct the items declared in a
+/// [`FlowSpec`].
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let mut item_registry = ItemRegistry::<AppError>::new();
+/// item_registry.register("file_download", |item_id| {
+///     FileDownloadItem::<WebApp>::new(item_id).into()
+/// });
+/// item_registry.register("tar_x", |item_id| TarXItem::<WebApp>::new(item_id).into());
+/// ```
+///
+/// [`FlowLoader`]: crate::FlowLoader
+/// [`FlowSpec`]: peace_flow_model::FlowSpec
+pub struct ItemRegistry<E>(IndexMap<String, ItemCtor<E>>);
+
+impl<E> ItemRegistry<E> {
+    /// Returns a new empty `ItemRegistry`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the constructor for an item type.
+    ///
+    /// If a constructor was already registered for the item type, it is
+    /// replaced.
+    pub fn register<F>(&mut self, item_type: impl Into<String>, item_ctor: F)
+    where
+        F: Fn(ItemId) -> ItemBoxed<E> + 'static,
+    {
+        self.0.insert(item_type.into(), Box::new(item_ctor));
+    }
+
+    /// Returns a new item of the given type, or `None` if the type is not
+    /// registered.
+    pub fn item_create(&self, item_type: &str, item_id: ItemId) -> Option<ItemBoxed<E>> {
+        self.0.get(item_type).map(|item_ctor| item_ctor(item_id))
+    }
+
+    /// Returns the names of the registered item types.
+    pub fn item_types(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+}
+
+impl<E> Default for ItemRegistry<E> {
+    fn default() -> Self {
+        Self(IndexMap::new())
+    }
+}
+
+impl<E> fmt::Debug for ItemRegistry<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ItemRegistry")
+            .field(&self.0.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
@@ -1,9 +1,9 @@
 use std::{any::Any, fmt::Debug};
 
 use dyn_clone::DynClone;
-use peace_cfg::{async_trait, FnCtx, ItemId};
+use peace_cfg::{async_trait, ApplyCtx, DiffSeverity, FnCtx, ItemId, ItemIoType};
 use peace_data::fn_graph::{DataAccess, DataAccessDyn};
-use peace_params::ParamsSpecs;
+use peace_params::{ParamsSpecs, ParamsValidationError, ValueResolutionMode};
 use peace_resources::{
     resources::ts::{Empty, SetUp},
     states::StatesCurrent,
@@ -13,7 +13,7 @@ use peace_resources::{
 
 use crate::{
     outcomes::{ItemApplyBoxed, ItemApplyPartialBoxed},
-    ParamsSpecsTypeReg, StatesTypeReg,
+    ItemParamsExplanation, ParamsSpecsTypeReg, StatesTypeReg,
 };
 
 /// Internal trait that erases the types from [`Item`]
@@ -58,6 +58,97 @@ pub trait ItemRt<E>:
         states_type_reg: &mut StatesTypeReg,
     );
 
+    /// Validates this item's resolved params.
+    ///
+    /// Params that cannot be fully resolved yet -- such as values mapped from
+    /// a predecessor's state that has not been discovered -- are not
+    /// validated, and an empty list is returned.
+    ///
+    /// See [`ParamsSpec::validate`].
+    ///
+    /// [`ParamsSpec::validate`]: peace_params::ParamsSpec::validate
+    fn params_validate(
+        &self,
+        params_specs: &ParamsSpecs,
+        resources: &Resources<SetUp>,
+    ) -> Result<Vec<ParamsValidationError>, E>
+    where
+        E: Debug + std::error::Error;
+
+    /// Resolves this item's params, recording how each value was resolved.
+    ///
+    /// Values that cannot be resolved yet -- such as values mapped from a
+    /// predecessor's state that has not been discovered -- are recorded
+    /// without a value, instead of returning an error.
+    fn params_explain(
+        &self,
+        params_specs: &ParamsSpecs,
+        resources: &Resources<SetUp>,
+        value_resolution_mode: ValueResolutionMode,
+    ) -> Result<ItemParamsExplanation, E>
+    where
+        E: Debug + std::error::Error;
+
+    /// Returns a digest of this item's resolved params.
+    ///
+    /// Returns `None` if the params cannot be fully resolved yet, such as
+    /// values mapped from a predecessor's state that has not been discovered.
+    ///
+    /// See [`ParamsDigests`].
+    ///
+    /// [`ParamsDigests`]: crate::ParamsDigests
+    fn params_digest(
+        &self,
+        params_specs: &ParamsSpecs,
+        resources: &Resources<SetUp>,
+    ) -> Result<Option<String>, E>
+    where
+        E: Debug + std::error::Error;
+
+    /// Returns whether this item applies to the current profile.
+    ///
+    /// If there is no [`Profile`] in `resources`, such as in scopes without a
+    /// profile, the item is treated as applicable.
+    ///
+    /// See [`Item::applicable`].
+    ///
+    /// [`Item::applicable`]: peace_cfg::Item::applicable
+    /// [`Profile`]: peace_cfg::Profile
+    fn applicable(
+        &self,
+        params_specs: &ParamsSpecs,
+        resources: &Resources<SetUp>,
+    ) -> Result<bool, E>
+    where
+        E: Debug + std::error::Error;
+
+    /// Returns the types of values this item provides to its successors.
+    ///
+    /// See [`Item::outputs`].
+    ///
+    /// [`Item::outputs`]: peace_cfg::Item::outputs
+    fn output_types(&self) -> Vec<ItemIoType>;
+
+    /// Returns the types of values this item reads from its predecessors.
+    ///
+    /// See [`Item::inputs`].
+    ///
+    /// [`Item::inputs`]: peace_cfg::Item::inputs
+    fn input_types(&self) -> Vec<ItemIoType>;
+
+    /// Returns the type of this item's [`State`].
+    ///
+    /// This is used to verify that items whose params are mapped from this
+    /// item's state run after this item.
+    ///
+    /// [`State`]: peace_cfg::Item::State
+    fn state_type(&self) -> ItemIoType;
+
+    /// Returns the name of this item's type, e.g. `FileDownloadItem<WebApp>`.
+    ///
+    /// This is used to describe the flow without linking the item types.
+    fn item_type_name(&self) -> String;
+
     /// Returns if the given two states equal.
     ///
     /// This returns an error if the boxed states could not be downcasted to
@@ -76,6 +167,61 @@ pub trait ItemRt<E>:
     where
         E: Debug + std::error::Error;
 
+    /// Sets this item's `Current<State>` in `resources` to the given state.
+    ///
+    /// This is used when a stored state is used instead of discovering the
+    /// item's current state, so that successors may still reference it.
+    ///
+    /// This returns an error if the boxed state could not be downcasted to this
+    /// item's state type. See [`ItemRt::state_eq`].
+    fn state_current_set(
+        &self,
+        resources: &Resources<SetUp>,
+        state_current: &BoxDtDisplay,
+    ) -> Result<(), E>;
+
+    /// Returns how disruptive it is to apply the diff between two states.
+    ///
+    /// This returns an error if the boxed states or state diff could not be
+    /// downcasted to this item's types. See [`ItemRt::state_eq`].
+    ///
+    /// See [`Item::diff_severity`].
+    ///
+    /// [`Item::diff_severity`]: peace_cfg::Item::diff_severity
+    fn diff_severity(
+        &self,
+        state_a: &BoxDtDisplay,
+        state_b: &BoxDtDisplay,
+        state_diff: &BoxDtDisplay,
+    ) -> Result<DiffSeverity, E>
+    where
+        E: Debug + std::error::Error;
+
+    /// Returns suggestions to recover from `error`, if it was returned by this
+    /// item.
+    ///
+    /// The error's source chain is searched for this item's error type.
+    /// Returns an empty `Vec` if it is not found.
+    ///
+    /// See [`Item::error_help`].
+    ///
+    /// [`Item::error_help`]: peace_cfg::Item::error_help
+    fn error_help(&self, error: &E) -> Vec<String>
+    where
+        E: std::error::Error + 'static;
+
+    /// Returns the JSON Schemas of this item's `Params`, `State`, and
+    /// `StateDiff` types, if the item provides them.
+    ///
+    /// See [`Item::schema`].
+    ///
+    /// [`Item::schema`]: peace_cfg::Item::schema
+    #[cfg(feature = "item_schema")]
+    fn schema(
+        &self,
+        generator: &mut schemars::gen::SchemaGenerator,
+    ) -> Option<peace_cfg::ItemSchema>;
+
     /// Runs [`Item::state_clean`].
     ///
     /// [`Item::state_clean`]: peace_cfg::Item::state_clean
@@ -180,6 +326,7 @@ pub trait ItemRt<E>:
         params_specs: &ParamsSpecs,
         resources: &Resources<SetUp>,
         fn_ctx: FnCtx<'_>,
+        apply_ctx: ApplyCtx<'_>,
     ) -> Result<ItemApplyBoxed, (E, ItemApplyPartialBoxed)>
     where
         E: Debug + std::error::Error;
@@ -202,6 +349,33 @@ pub trait ItemRt<E>:
         states_current: &StatesCurrent,
         params_specs: &ParamsSpecs,
         resources: &Resources<SetUp>,
+        apply_ctx: ApplyCtx<'_>,
+    ) -> Result<ItemApplyBoxed, (E, ItemApplyPartialBoxed)>
+    where
+        E: Debug + std::error::Error;
+
+    /// Discovers the information needed to roll back an applied item.
+    ///
+    /// The applied state is the current state, and the state before the apply
+    /// is the target state. This runs the following functions in order:
+    ///
+    /// * [`Item::state_diff`]
+    /// * [`ApplyFns::check`]
+    ///
+    /// # Parameters
+    ///
+    /// * `item_apply`: The information from the item's successful apply.
+    /// * `apply_ctx`: Information about the execution the item is rolled back
+    ///   in.
+    ///
+    /// [`Item::state_diff`]: peace_cfg::Item::state_diff
+    /// [`ApplyFns::check`]: peace_cfg::Item::ApplyFns
+    async fn rollback_prepare(
+        &self,
+        item_apply: &ItemApplyBoxed,
+        params_specs: &ParamsSpecs,
+        resources: &Resources<SetUp>,
+        apply_ctx: ApplyCtx<'_>,
     ) -> Result<ItemApplyBoxed, (E, ItemApplyPartialBoxed)>
     where
         E: Debug + std::error::Error;
@@ -216,6 +390,8 @@ pub trait ItemRt<E>:
     /// # Parameters
     ///
     /// * `resources`: The resources in the current execution.
+    /// * `fn_ctx`: Context to send progress updates, which should include the
+    ///   execution's [`ApplyCtx`].
     /// * `item_apply`: The information collected in `self.ensure_prepare`.
     ///
     /// [`ApplyFns::exec_dry`]: peace_cfg::Item::ApplyFns
@@ -239,6 +415,8 @@ pub trait ItemRt<E>:
     /// # Parameters
     ///
     /// * `resources`: The resources in the current execution.
+    /// * `fn_ctx`: Context to send progress updates, which should include the
+    ///   execution's [`ApplyCtx`].
     /// * `item_apply`: The information collected in `self.ensure_prepare`.
     ///
     /// [`ApplyFns::exec`]: peace_cfg::Item::ApplyFns
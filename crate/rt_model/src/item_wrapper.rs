@@ -5,13 +5,18 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use peace_cfg::{async_trait, ApplyCheck, FnCtx, Item, ItemId};
+use peace_cfg::{
+    async_trait, state::SensitiveSerdeMode, ApplyCheck, ApplyCtx, DiffSeverity, FnCtx, Item,
+    ItemId, ItemIoType, Profile,
+};
 use peace_data::{
     fn_graph::{DataAccess, DataAccessDyn, TypeIds},
     marker::{ApplyDry, Clean, Current, Goal},
     Data,
 };
-use peace_params::{Params, ParamsSpec, ParamsSpecs, ValueResolutionCtx, ValueResolutionMode};
+use peace_params::{
+    Params, ParamsSpec, ParamsSpecs, ParamsValidationError, ValueResolutionCtx, ValueResolutionMode,
+};
 use peace_resources::{
     resources::ts::{Empty, SetUp},
     states::StatesCurrent,
@@ -22,9 +27,20 @@ use type_reg::untagged::BoxDataTypeDowncast;
 
 use crate::{
     outcomes::{ItemApply, ItemApplyBoxed, ItemApplyPartial, ItemApplyPartialBoxed},
-    ItemRt, ParamsSpecsTypeReg, StateDowncastError, StatesTypeReg,
+    ItemParamsExplanation, ItemRt, ParamsDigests, ParamsSpecsTypeReg, StateDowncastError,
+    StatesTypeReg,
 };
 
+cfg_if::cfg_if! {
+    if #[cfg(feature = "profiling")] {
+        use std::time::Instant;
+
+        use peace_cmd_model::ItemFnKind;
+
+        use crate::TimingBreakdownRecorder;
+    }
+}
+
 /// Wraps a type implementing [`Item`].
 ///
 /// # Type Parameters
@@ -123,10 +139,21 @@ where
                     .map_err(crate::Error::ParamsResolveError)?
             };
             let data = <I::Data<'_> as Data>::borrow(self.id(), resources);
-            I::try_state_current(fn_ctx, &params_partial, data).await?
+            #[cfg(feature = "profiling")]
+            let item_fn_start = Instant::now();
+            let state_current_result = I::try_state_current(fn_ctx, &params_partial, data).await;
+            #[cfg(feature = "profiling")]
+            TimingBreakdownRecorder::record_in(
+                resources,
+                self.id(),
+                ItemFnKind::StateCurrent,
+                item_fn_start.elapsed(),
+            );
+            state_current_result?
         };
         if let Some(state_current) = state_current.as_ref() {
             resources.borrow_mut::<Current<I::State>>().0 = Some(state_current.clone());
+            I::outputs().set(state_current, resources);
         }
 
         Ok(state_current)
@@ -156,9 +183,20 @@ where
                     .map_err(crate::Error::ParamsResolveError)?
             };
             let data = <I::Data<'_> as Data>::borrow(self.id(), resources);
-            I::state_current(fn_ctx, &params, data).await?
+            #[cfg(feature = "profiling")]
+            let item_fn_start = Instant::now();
+            let state_current_result = I::state_current(fn_ctx, &params, data).await;
+            #[cfg(feature = "profiling")]
+            TimingBreakdownRecorder::record_in(
+                resources,
+                self.id(),
+                ItemFnKind::StateCurrent,
+                item_fn_start.elapsed(),
+            );
+            state_current_result?
         };
         resources.borrow_mut::<Current<I::State>>().0 = Some(state_current.clone());
+        I::outputs().set(&state_current, resources);
 
         Ok(state_current)
     }
@@ -186,7 +224,17 @@ where
                 .map_err(crate::Error::ParamsResolveError)?
         };
         let data = <I::Data<'_> as Data>::borrow(self.id(), resources);
-        let state_goal = I::try_state_goal(fn_ctx, &params_partial, data).await?;
+        #[cfg(feature = "profiling")]
+        let item_fn_start = Instant::now();
+        let state_goal_result = I::try_state_goal(fn_ctx, &params_partial, data).await;
+        #[cfg(feature = "profiling")]
+        TimingBreakdownRecorder::record_in(
+            resources,
+            self.id(),
+            ItemFnKind::StateGoal,
+            item_fn_start.elapsed(),
+        );
+        let state_goal = state_goal_result?;
         if let Some(state_goal) = state_goal.as_ref() {
             resources.borrow_mut::<Goal<I::State>>().0 = Some(state_goal.clone());
         }
@@ -233,7 +281,17 @@ where
                 .map_err(crate::Error::ParamsResolveError)?
         };
         let data = <I::Data<'_> as Data>::borrow(self.id(), resources);
-        let state_goal = I::state_goal(fn_ctx, &params, data).await?;
+        #[cfg(feature = "profiling")]
+        let item_fn_start = Instant::now();
+        let state_goal_result = I::state_goal(fn_ctx, &params, data).await;
+        #[cfg(feature = "profiling")]
+        TimingBreakdownRecorder::record_in(
+            resources,
+            self.id(),
+            ItemFnKind::StateGoal,
+            item_fn_start.elapsed(),
+        );
+        let state_goal = state_goal_result?;
         resources.borrow_mut::<Goal<I::State>>().0 = Some(state_goal.clone());
 
         Ok(state_goal)
@@ -307,18 +365,28 @@ where
                     .map_err(crate::Error::ParamsResolveError)?
             };
             let data = <I::Data<'_> as Data>::borrow(self.id(), resources);
-            I::state_diff(&params_partial, data, state_a, state_b)
-                .await
-                .map_err(Into::<E>::into)?
+            #[cfg(feature = "profiling")]
+            let item_fn_start = Instant::now();
+            let state_diff_result = I::state_diff(&params_partial, data, state_a, state_b).await;
+            #[cfg(feature = "profiling")]
+            TimingBreakdownRecorder::record_in(
+                resources,
+                self.id(),
+                ItemFnKind::StateDiff,
+                item_fn_start.elapsed(),
+            );
+            state_diff_result.map_err(Into::<E>::into)?
         };
 
         Ok(state_diff)
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn apply_check(
         &self,
         params_specs: &ParamsSpecs,
         resources: &Resources<SetUp>,
+        apply_ctx: ApplyCtx<'_>,
         state_current: &I::State,
         state_target: &I::State,
         state_diff: &I::StateDiff,
@@ -350,9 +418,25 @@ where
         };
         let data = <I::Data<'_> as Data>::borrow(self.id(), resources);
         if let Ok(params) = params_partial.try_into() {
-            I::apply_check(&params, data, state_current, state_target, state_diff)
-                .await
-                .map_err(Into::<E>::into)
+            #[cfg(feature = "profiling")]
+            let item_fn_start = Instant::now();
+            let apply_check_result = I::apply_check(
+                apply_ctx,
+                &params,
+                data,
+                state_current,
+                state_target,
+                state_diff,
+            )
+            .await;
+            #[cfg(feature = "profiling")]
+            TimingBreakdownRecorder::record_in(
+                resources,
+                self.id(),
+                ItemFnKind::ApplyCheck,
+                item_fn_start.elapsed(),
+            );
+            apply_check_result.map_err(Into::<E>::into)
         } else {
             // > If we cannot resolve parameters, then this item, and its predecessor are
             // > cleaned up.
@@ -389,10 +473,18 @@ where
                 .map_err(crate::Error::ParamsResolveError)?
         };
         let data = <I::Data<'_> as Data>::borrow(self.id(), resources);
-        let state_ensured_dry =
-            I::apply_dry(fn_ctx, &params, data, state_current, state_goal, state_diff)
-                .await
-                .map_err(Into::<E>::into)?;
+        #[cfg(feature = "profiling")]
+        let item_fn_start = Instant::now();
+        let state_ensured_dry_result =
+            I::apply_dry(fn_ctx, &params, data, state_current, state_goal, state_diff).await;
+        #[cfg(feature = "profiling")]
+        TimingBreakdownRecorder::record_in(
+            resources,
+            self.id(),
+            ItemFnKind::ApplyExec,
+            item_fn_start.elapsed(),
+        );
+        let state_ensured_dry = state_ensured_dry_result.map_err(Into::<E>::into)?;
 
         resources.borrow_mut::<ApplyDry<I::State>>().0 = Some(state_ensured_dry.clone());
 
@@ -425,11 +517,21 @@ where
                 .map_err(crate::Error::ParamsResolveError)?
         };
         let data = <I::Data<'_> as Data>::borrow(self.id(), resources);
-        let state_ensured = I::apply(fn_ctx, &params, data, state_current, state_goal, state_diff)
-            .await
-            .map_err(Into::<E>::into)?;
+        #[cfg(feature = "profiling")]
+        let item_fn_start = Instant::now();
+        let state_ensured_result =
+            I::apply(fn_ctx, &params, data, state_current, state_goal, state_diff).await;
+        #[cfg(feature = "profiling")]
+        TimingBreakdownRecorder::record_in(
+            resources,
+            self.id(),
+            ItemFnKind::ApplyExec,
+            item_fn_start.elapsed(),
+        );
+        let state_ensured = state_ensured_result.map_err(Into::<E>::into)?;
 
         resources.borrow_mut::<Current<I::State>>().0 = Some(state_ensured.clone());
+        I::outputs().set(&state_ensured, resources);
 
         Ok(state_ensured)
     }
@@ -541,6 +643,7 @@ where
         self
     }
 
+    #[tracing::instrument(skip_all, fields(item_id = %self.id()))]
     async fn setup(&self, resources: &mut Resources<Empty>) -> Result<(), E> {
         // Insert `XMarker<I::State>` to create entries in `Resources`.
         // This is used for referential param values (#94)
@@ -548,6 +651,7 @@ where
         resources.insert(Current::<I::State>(None));
         resources.insert(Goal::<I::State>(None));
         resources.insert(ApplyDry::<I::State>(None));
+        I::outputs().insert(resources);
 
         // Run user defined setup.
         <I as Item>::setup(self, resources)
@@ -564,33 +668,207 @@ where
         states_type_reg.register::<I::State>(I::id(self).clone());
     }
 
+    fn params_validate(
+        &self,
+        params_specs: &ParamsSpecs,
+        resources: &Resources<SetUp>,
+    ) -> Result<Vec<ParamsValidationError>, E> {
+        let item_id = self.id();
+        let params_spec = params_specs
+            .get::<ParamsSpec<I::Params<'_>>, _>(item_id)
+            .ok_or_else(|| crate::Error::ParamsSpecNotFound {
+                item_id: item_id.clone(),
+            })?;
+        let mut value_resolution_ctx = ValueResolutionCtx::new(
+            ValueResolutionMode::Goal,
+            item_id.clone(),
+            tynm::type_name::<I::Params<'_>>(),
+        );
+        let params_partial = params_spec
+            .resolve_partial(resources, &mut value_resolution_ctx)
+            .map_err(crate::Error::ParamsResolveError)?;
+
+        // Params that are not fully resolvable yet are not validated.
+        let params_validation_errors = I::Params::try_from(params_partial)
+            .map(|params| params_spec.validate(&params))
+            .unwrap_or_default();
+
+        Ok(params_validation_errors)
+    }
+
+    fn params_explain(
+        &self,
+        params_specs: &ParamsSpecs,
+        resources: &Resources<SetUp>,
+        value_resolution_mode: ValueResolutionMode,
+    ) -> Result<ItemParamsExplanation, E> {
+        let item_id = self.id();
+        let params_spec = params_specs
+            .get::<ParamsSpec<I::Params<'_>>, _>(item_id)
+            .ok_or_else(|| crate::Error::ParamsSpecNotFound {
+                item_id: item_id.clone(),
+            })?;
+        let params_type_name = tynm::type_name::<I::Params<'_>>();
+        let mut value_resolution_ctx = ValueResolutionCtx::new(
+            value_resolution_mode,
+            item_id.clone(),
+            params_type_name.clone(),
+        );
+        let params_partial = params_spec
+            .resolve_partial(resources, &mut value_resolution_ctx)
+            .map_err(crate::Error::ParamsResolveError)?;
+
+        let (params, params_complete) = match I::Params::try_from(params_partial.clone()) {
+            Ok(params) => (format!("{params:?}"), true),
+            Err(_) => (format!("{params_partial:?}"), false),
+        };
+
+        Ok(ItemParamsExplanation::new(
+            item_id.clone(),
+            params_type_name,
+            params,
+            params_complete,
+            value_resolution_ctx.into_value_resolutions(),
+        ))
+    }
+
+    #[tracing::instrument(skip_all, fields(item_id = %self.id()))]
+    fn params_digest(
+        &self,
+        params_specs: &ParamsSpecs,
+        resources: &Resources<SetUp>,
+    ) -> Result<Option<String>, E> {
+        let item_id = self.id();
+        let params_spec = params_specs
+            .get::<ParamsSpec<I::Params<'_>>, _>(item_id)
+            .ok_or_else(|| crate::Error::ParamsSpecNotFound {
+                item_id: item_id.clone(),
+            })?;
+        let mut value_resolution_ctx = ValueResolutionCtx::new(
+            ValueResolutionMode::Goal,
+            item_id.clone(),
+            tynm::type_name::<I::Params<'_>>(),
+        );
+        let params_partial = params_spec
+            .resolve_partial(resources, &mut value_resolution_ctx)
+            .map_err(crate::Error::ParamsResolveError)?;
+
+        let Ok(params) = I::Params::try_from(params_partial) else {
+            return Ok(None);
+        };
+        // Sensitive values are redacted, so that digests do not depend on them.
+        let params_serialized = SensitiveSerdeMode::Redact
+            .scope(|| serde_yaml::to_string(&params))
+            .map_err(crate::Error::ParamsDigestsSerialize)?;
+
+        Ok(Some(ParamsDigests::digest(params_serialized.as_bytes())))
+    }
+
+    fn applicable(
+        &self,
+        params_specs: &ParamsSpecs,
+        resources: &Resources<SetUp>,
+    ) -> Result<bool, E> {
+        let Ok(profile) = resources.try_borrow::<Profile>() else {
+            return Ok(true);
+        };
+
+        let item_id = self.id();
+        let params_spec = params_specs
+            .get::<ParamsSpec<I::Params<'_>>, _>(item_id)
+            .ok_or_else(|| crate::Error::ParamsSpecNotFound {
+                item_id: item_id.clone(),
+            })?;
+        let mut value_resolution_ctx = ValueResolutionCtx::new(
+            ValueResolutionMode::Goal,
+            item_id.clone(),
+            tynm::type_name::<I::Params<'_>>(),
+        );
+        let params_partial = params_spec
+            .resolve_partial(resources, &mut value_resolution_ctx)
+            .map_err(crate::Error::ParamsResolveError)?;
+
+        Ok(I::applicable(&params_partial, &profile))
+    }
+
+    fn output_types(&self) -> Vec<ItemIoType> {
+        I::outputs().item_io_types().collect()
+    }
+
+    fn input_types(&self) -> Vec<ItemIoType> {
+        I::inputs().into_inner()
+    }
+
+    fn state_type(&self) -> ItemIoType {
+        ItemIoType::of::<I::State>()
+    }
+
+    fn item_type_name(&self) -> String {
+        tynm::type_name::<I>()
+    }
+
     fn state_eq(&self, state_a: &BoxDtDisplay, state_b: &BoxDtDisplay) -> Result<bool, E> {
-        let state_a_downcasted = BoxDataTypeDowncast::<I::State>::downcast_ref(state_a);
-        let state_b_downcasted = BoxDataTypeDowncast::<I::State>::downcast_ref(state_b);
+        let (state_a, state_b) = states_downcast::<I, E>(state_a, state_b)?;
 
-        match (state_a_downcasted, state_b_downcasted) {
-            (None, None) => Err(crate::Error::StateDowncastError(StateDowncastError::Both {
-                ty_name: tynm::type_name::<I::State>(),
-                state_a: state_a.clone(),
-                state_b: state_b.clone(),
-            })
-            .into()),
-            (None, Some(_)) => Err(crate::Error::StateDowncastError(StateDowncastError::First {
-                ty_name: tynm::type_name::<I::State>(),
-                state_a: state_a.clone(),
-            })
-            .into()),
-            (Some(_), None) => Err(
-                crate::Error::StateDowncastError(StateDowncastError::Second {
+        Ok(state_a == state_b)
+    }
+
+    fn state_current_set(
+        &self,
+        resources: &Resources<SetUp>,
+        state_current: &BoxDtDisplay,
+    ) -> Result<(), E> {
+        let state_current = BoxDataTypeDowncast::<I::State>::downcast_ref(state_current)
+            .ok_or_else(|| {
+                crate::Error::StateDowncastError(StateDowncastError::First {
                     ty_name: tynm::type_name::<I::State>(),
-                    state_b: state_b.clone(),
+                    state_a: state_current.clone(),
                 })
-                .into(),
-            ),
-            (Some(state_a), Some(state_b)) => Ok(state_a == state_b),
-        }
+            })?;
+        resources.borrow_mut::<Current<I::State>>().0 = Some(state_current.clone());
+
+        Ok(())
+    }
+
+    fn diff_severity(
+        &self,
+        state_a: &BoxDtDisplay,
+        state_b: &BoxDtDisplay,
+        state_diff: &BoxDtDisplay,
+    ) -> Result<DiffSeverity, E> {
+        let (state_a, state_b) = states_downcast::<I, E>(state_a, state_b)?;
+        let state_diff =
+            BoxDataTypeDowncast::<I::StateDiff>::downcast_ref(state_diff).ok_or_else(|| {
+                crate::Error::StateDowncastError(StateDowncastError::StateDiff {
+                    ty_name: tynm::type_name::<I::StateDiff>(),
+                    state_diff: state_diff.clone(),
+                })
+            })?;
+
+        Ok(I::diff_severity(state_a, state_b, state_diff))
+    }
+
+    fn error_help(&self, error: &E) -> Vec<String>
+    where
+        E: std::error::Error + 'static,
+    {
+        std::iter::successors(Some(error as &(dyn std::error::Error + 'static)), |error| {
+            error.source()
+        })
+        .find_map(|error| error.downcast_ref::<<I as Item>::Error>())
+        .map(I::error_help)
+        .unwrap_or_default()
+    }
+
+    #[cfg(feature = "item_schema")]
+    fn schema(
+        &self,
+        generator: &mut schemars::gen::SchemaGenerator,
+    ) -> Option<peace_cfg::ItemSchema> {
+        I::schema(generator)
     }
 
+    #[tracing::instrument(skip_all, fields(item_id = %self.id()))]
     async fn state_clean(
         &self,
         params_specs: &ParamsSpecs,
@@ -602,6 +880,7 @@ where
             .map_err(Into::<E>::into)
     }
 
+    #[tracing::instrument(skip_all, fields(item_id = %self.id()))]
     async fn state_current_try_exec(
         &self,
         params_specs: &ParamsSpecs,
@@ -614,6 +893,7 @@ where
             .map_err(Into::<E>::into)
     }
 
+    #[tracing::instrument(skip_all, fields(item_id = %self.id()))]
     async fn state_current_exec(
         &self,
         params_specs: &ParamsSpecs,
@@ -626,6 +906,7 @@ where
             .map_err(Into::<E>::into)
     }
 
+    #[tracing::instrument(skip_all, fields(item_id = %self.id()))]
     async fn state_goal_try_exec(
         &self,
         params_specs: &ParamsSpecs,
@@ -638,6 +919,7 @@ where
             .map_err(Into::<E>::into)
     }
 
+    #[tracing::instrument(skip_all, fields(item_id = %self.id()))]
     async fn state_goal_exec(
         &self,
         params_specs: &ParamsSpecs,
@@ -662,6 +944,7 @@ where
         .map_err(Into::<E>::into)
     }
 
+    #[tracing::instrument(skip_all, fields(item_id = %self.id()))]
     async fn state_diff_exec(
         &self,
         params_specs: &ParamsSpecs,
@@ -675,11 +958,13 @@ where
             .map_err(Into::<E>::into)
     }
 
+    #[tracing::instrument(skip_all, fields(item_id = %self.id()))]
     async fn ensure_prepare(
         &self,
         params_specs: &ParamsSpecs,
         resources: &Resources<SetUp>,
         fn_ctx: FnCtx<'_>,
+        apply_ctx: ApplyCtx<'_>,
     ) -> Result<ItemApplyBoxed, (E, ItemApplyPartialBoxed)> {
         let mut item_apply_partial = ItemApplyPartial::<I::State, I::StateDiff>::new();
 
@@ -738,6 +1023,7 @@ where
             .apply_check(
                 params_specs,
                 resources,
+                apply_ctx,
                 state_current,
                 state_goal,
                 state_diff,
@@ -747,16 +1033,101 @@ where
             .await;
         let state_applied = match apply_check {
             Ok(apply_check) => {
+                // TODO: write test for this case
+                let state_applied = match apply_check {
+                    ApplyCheck::ExecRequired { .. } => None,
+                    ApplyCheck::ExecNotRequired => item_apply_partial.state_current.clone(),
+                };
                 item_apply_partial.apply_check = Some(apply_check);
 
-                // TODO: write test for this case
-                match apply_check {
-                    #[cfg(not(feature = "output_progress"))]
-                    ApplyCheck::ExecRequired => None,
-                    #[cfg(feature = "output_progress")]
+                state_applied
+            }
+            Err(error) => return Err((error, item_apply_partial.into())),
+        };
+
+        Ok(ItemApply::try_from((item_apply_partial, state_applied))
+            .expect("unreachable: All the fields are set above.")
+            .into())
+    }
+
+    #[tracing::instrument(skip_all, fields(item_id = %self.id()))]
+    async fn rollback_prepare(
+        &self,
+        item_apply_boxed: &ItemApplyBoxed,
+        params_specs: &ParamsSpecs,
+        resources: &Resources<SetUp>,
+        apply_ctx: ApplyCtx<'_>,
+    ) -> Result<ItemApplyBoxed, (E, ItemApplyPartialBoxed)> {
+        let Some(item_apply) = item_apply_boxed
+            .as_data_type()
+            .downcast_ref::<ItemApply<I::State, I::StateDiff>>()
+        else {
+            panic!(
+                "Failed to downcast `ItemApplyBoxed` to `{concrete_type}`.\n\
+                    This is a bug in the Peace framework.",
+                concrete_type = std::any::type_name::<ItemApply<I::State, I::StateDiff>>()
+            )
+        };
+
+        let mut item_apply_partial = ItemApplyPartial::<I::State, I::StateDiff>::new();
+
+        // The state the item was applied to is now its current state, and the
+        // state it was in before the apply is the target state.
+        item_apply_partial.state_current = Some(
+            item_apply
+                .state_applied
+                .clone()
+                .unwrap_or_else(|| item_apply.state_current.clone()),
+        );
+        item_apply_partial.state_target = Some(item_apply.state_current.clone());
+
+        match self
+            .state_diff_exec_with(
+                params_specs,
+                resources,
+                item_apply_partial
+                    .state_current
+                    .as_ref()
+                    .expect("unreachable: This is set just above."),
+                item_apply_partial
+                    .state_target
+                    .as_ref()
+                    .expect("unreachable: This is set just above."),
+            )
+            .await
+        {
+            Ok(state_diff) => item_apply_partial.state_diff = Some(state_diff),
+            Err(error) => return Err((error, item_apply_partial.into())),
+        }
+
+        let (Some(state_current), Some(state_target), Some(state_diff)) = (
+            item_apply_partial.state_current.as_ref(),
+            item_apply_partial.state_target.as_ref(),
+            item_apply_partial.state_diff.as_ref(),
+        ) else {
+            unreachable!("These are set just above.");
+        };
+
+        let apply_check = self
+            .apply_check(
+                params_specs,
+                resources,
+                apply_ctx,
+                state_current,
+                state_target,
+                state_diff,
+                ValueResolutionMode::Current,
+            )
+            .await;
+        let state_applied = match apply_check {
+            Ok(apply_check) => {
+                let state_applied = match apply_check {
                     ApplyCheck::ExecRequired { .. } => None,
                     ApplyCheck::ExecNotRequired => item_apply_partial.state_current.clone(),
-                }
+                };
+                item_apply_partial.apply_check = Some(apply_check);
+
+                state_applied
             }
             Err(error) => return Err((error, item_apply_partial.into())),
         };
@@ -766,6 +1137,7 @@ where
             .into())
     }
 
+    #[tracing::instrument(skip_all, fields(item_id = %self.id()))]
     async fn apply_exec_dry(
         &self,
         params_specs: &ParamsSpecs,
@@ -795,7 +1167,7 @@ where
 
         match apply_check {
             #[cfg(not(feature = "output_progress"))]
-            ApplyCheck::ExecRequired => {
+            ApplyCheck::ExecRequired { .. } => {
                 let state_applied_dry = self
                     .apply_exec_dry(
                         params_specs,
@@ -810,7 +1182,7 @@ where
                 *state_applied = Some(state_applied_dry);
             }
             #[cfg(feature = "output_progress")]
-            ApplyCheck::ExecRequired { progress_limit: _ } => {
+            ApplyCheck::ExecRequired { .. } => {
                 let state_applied_dry = self
                     .apply_exec_dry(
                         params_specs,
@@ -830,11 +1202,13 @@ where
         Ok(())
     }
 
+    #[tracing::instrument(skip_all, fields(item_id = %self.id()))]
     async fn clean_prepare(
         &self,
         states_current: &StatesCurrent,
         params_specs: &ParamsSpecs,
         resources: &Resources<SetUp>,
+        apply_ctx: ApplyCtx<'_>,
     ) -> Result<ItemApplyBoxed, (E, ItemApplyPartialBoxed)> {
         let mut item_apply_partial = ItemApplyPartial::<I::State, I::StateDiff>::new();
 
@@ -887,6 +1261,7 @@ where
             .apply_check(
                 params_specs,
                 resources,
+                apply_ctx,
                 state_current,
                 state_clean,
                 state_diff,
@@ -897,16 +1272,14 @@ where
 
         let state_applied = match apply_check {
             Ok(apply_check) => {
-                item_apply_partial.apply_check = Some(apply_check);
-
                 // TODO: write test for this case
-                match apply_check {
-                    #[cfg(not(feature = "output_progress"))]
-                    ApplyCheck::ExecRequired => None,
-                    #[cfg(feature = "output_progress")]
+                let state_applied = match apply_check {
                     ApplyCheck::ExecRequired { .. } => None,
                     ApplyCheck::ExecNotRequired => item_apply_partial.state_current.clone(),
-                }
+                };
+                item_apply_partial.apply_check = Some(apply_check);
+
+                state_applied
             }
             Err(error) => return Err((error, item_apply_partial.into())),
         };
@@ -916,6 +1289,7 @@ where
             .into())
     }
 
+    #[tracing::instrument(skip_all, fields(item_id = %self.id()))]
     async fn apply_exec(
         &self,
         params_specs: &ParamsSpecs,
@@ -945,7 +1319,7 @@ where
 
         match apply_check {
             #[cfg(not(feature = "output_progress"))]
-            ApplyCheck::ExecRequired => {
+            ApplyCheck::ExecRequired { .. } => {
                 let state_applied_next = self
                     .apply_exec(
                         params_specs,
@@ -960,7 +1334,7 @@ where
                 *state_applied = Some(state_applied_next);
             }
             #[cfg(feature = "output_progress")]
-            ApplyCheck::ExecRequired { progress_limit: _ } => {
+            ApplyCheck::ExecRequired { .. } => {
                 let state_applied_next = self
                     .apply_exec(
                         params_specs,
@@ -980,3 +1354,38 @@ where
         Ok(())
     }
 }
+
+/// Downcasts both boxed states to the item's concrete state type.
+fn states_downcast<'state, I, E>(
+    state_a: &'state BoxDtDisplay,
+    state_b: &'state BoxDtDisplay,
+) -> Result<(&'state I::State, &'state I::State), E>
+where
+    I: Item,
+    E: From<crate::Error>,
+{
+    let state_a_downcasted = BoxDataTypeDowncast::<I::State>::downcast_ref(state_a);
+    let state_b_downcasted = BoxDataTypeDowncast::<I::State>::downcast_ref(state_b);
+
+    match (state_a_downcasted, state_b_downcasted) {
+        (None, None) => Err(crate::Error::StateDowncastError(StateDowncastError::Both {
+            ty_name: tynm::type_name::<I::State>(),
+            state_a: state_a.clone(),
+            state_b: state_b.clone(),
+        })
+        .into()),
+        (None, Some(_)) => Err(crate::Error::StateDowncastError(StateDowncastError::First {
+            ty_name: tynm::type_name::<I::State>(),
+            state_a: state_a.clone(),
+        })
+        .into()),
+        (Some(_), None) => Err(
+            crate::Error::StateDowncastError(StateDowncastError::Second {
+                ty_name: tynm::type_name::<I::State>(),
+                state_b: state_b.clone(),
+            })
+            .into(),
+        ),
+        (Some(state_a), Some(state_b)) => Ok((state_a, state_b)),
+    }
+}
@@ -0,0 +1,60 @@
+use std::marker::PhantomData;
+
+use peace_resources::paths::ItemsDisabledFile;
+
+use crate::{Error, ItemsDisabled, Storage};
+
+/// Reads and writes [`ItemsDisabled`] to and from storage.
+pub struct ItemsDisabledSerializer<E>(PhantomData<E>);
+
+impl<E> ItemsDisabledSerializer<E>
+where
+    E: std::error::Error + From<Error> + Send,
+{
+    /// Serializes the [`ItemsDisabled`] for a profile to disk.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to write to.
+    /// * `items_disabled`: `ItemsDisabled` to serialize.
+    /// * `items_disabled_file`: Path to save the serialized item IDs to.
+    pub async fn serialize(
+        storage: &Storage,
+        items_disabled: &ItemsDisabled,
+        items_disabled_file: &ItemsDisabledFile,
+    ) -> Result<(), E> {
+        storage
+            .serialized_write_atomic(
+                #[cfg(not(target_arch = "wasm32"))]
+                "ItemsDisabledSerializer::serialize".to_string(),
+                items_disabled_file,
+                items_disabled,
+                Error::ItemsDisabledSerialize,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Returns the [`ItemsDisabled`] for a profile, if it exists on disk.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to read from.
+    /// * `items_disabled_file`: `ItemsDisabledFile` to deserialize.
+    pub async fn deserialize_opt(
+        storage: &Storage,
+        items_disabled_file: &ItemsDisabledFile,
+    ) -> Result<Option<ItemsDisabled>, E> {
+        let items_disabled = storage
+            .serialized_read_opt(
+                #[cfg(not(target_arch = "wasm32"))]
+                "ItemsDisabledSerializer::deserialize_opt".to_string(),
+                items_disabled_file,
+                |error, _| Error::ItemsDisabledDeserialize(error),
+            )
+            .await?;
+
+        Ok(items_disabled)
+    }
+}
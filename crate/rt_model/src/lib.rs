@@ -14,26 +14,89 @@ pub use peace_rt_model_native::*;
 pub use peace_rt_model_web::*;
 
 pub use crate::{
-    flow::Flow, in_memory_text_output::InMemoryTextOutput, item_boxed::ItemBoxed,
-    item_graph::ItemGraph, item_graph_builder::ItemGraphBuilder, item_rt::ItemRt,
-    item_wrapper::ItemWrapper, params_specs_serializer::ParamsSpecsSerializer,
-    params_specs_type_reg::ParamsSpecsTypeReg, states_serializer::StatesSerializer,
-    states_type_reg::StatesTypeReg,
+    apply_hooks::{ApplyHookOutcome, ApplyHooks, PostApplyHook, PreApplyHook},
+    approval_policy::{ApprovalPolicy, ApprovalRequest, Approver},
+    clean_ordering_override::CleanOrderingOverride,
+    execution_deadline::ExecutionDeadline,
+    execution_history_serializer::ExecutionHistorySerializer,
+    execution_timeline_serializer::ExecutionTimelineSerializer,
+    flow::Flow, flow_loader::FlowLoader,
+    flow_metadata::{FlowMetadata, ItemMetadata},
+    heartbeat_policy::HeartbeatPolicy,
+    in_memory_output::{InMemoryOutput, InMemoryOutputEvent},
+    item_boxed::ItemBoxed,
+    item_edge_kind::ItemEdgeKind,
+    item_executor::{ItemExecutor, ItemExecutorConcurrent, ItemExecutorLimited, ItemExecutorYielding},
+    item_graph::ItemGraph, item_graph_builder::ItemGraphBuilder,
+    item_params_explanation::ItemParamsExplanation, item_registry::ItemRegistry, item_rt::ItemRt,
+    item_wrapper::ItemWrapper, items_disabled_serializer::ItemsDisabledSerializer,
+    params_digests_serializer::ParamsDigestsSerializer,
+    params_history_serializer::ParamsHistorySerializer,
+    params_specs_serializer::ParamsSpecsSerializer,
+    params_specs_type_reg::ParamsSpecsTypeReg, preflight_check::PreflightCheck,
+    states_pruner::StatesPruner,
+    states_serializer::StatesSerializer,
+    states_type_reg::StatesTypeReg, workspace_set::WorkspaceSet,
 };
 
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::{item_log_layer::ItemLogLayer, params_file_deserializer::ParamsFileDeserializer};
+
+#[cfg(feature = "output_progress")]
+pub use crate::execution_progress_serializer::ExecutionProgressSerializer;
+
+#[cfg(feature = "telemetry")]
+pub use crate::cmd_metrics::CmdMetrics;
+
+#[cfg(feature = "item_schema")]
+pub use crate::flow_schema::FlowSchema;
+
 pub mod outcomes;
 
+mod apply_hooks;
+mod approval_policy;
+mod clean_ordering_override;
+mod execution_deadline;
+mod execution_history_serializer;
+mod execution_timeline_serializer;
 mod flow;
-mod in_memory_text_output;
+mod flow_loader;
+mod flow_metadata;
+mod heartbeat_policy;
+mod in_memory_output;
 mod item_boxed;
+mod item_edge_kind;
+mod item_executor;
 mod item_graph;
 mod item_graph_builder;
+mod item_params_explanation;
+mod item_registry;
 mod item_rt;
 mod item_wrapper;
+mod items_disabled_serializer;
+mod params_digests_serializer;
+mod params_history_serializer;
 mod params_specs_serializer;
 mod params_specs_type_reg;
+mod preflight_check;
+mod states_pruner;
 mod states_serializer;
 mod states_type_reg;
+mod workspace_set;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod item_log_layer;
+#[cfg(not(target_arch = "wasm32"))]
+mod params_file_deserializer;
+
+#[cfg(feature = "output_progress")]
+mod execution_progress_serializer;
+
+#[cfg(feature = "telemetry")]
+mod cmd_metrics;
+
+#[cfg(feature = "item_schema")]
+mod flow_schema;
 
 #[cfg(feature = "error_reporting")]
 mod yaml_error_context_hack;
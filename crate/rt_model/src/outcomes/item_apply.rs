@@ -95,7 +95,7 @@ where
     }
 
     fn apply_check(&self) -> ApplyCheck {
-        self.apply_check
+        self.apply_check.clone()
     }
 
     fn state_applied(&self) -> Option<BoxDtDisplay> {
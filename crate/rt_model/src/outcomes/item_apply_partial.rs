@@ -86,7 +86,7 @@ where
     }
 
     fn apply_check(&self) -> Option<ApplyCheck> {
-        self.apply_check
+        self.apply_check.clone()
     }
 
     fn as_data_type(&self) -> &dyn DataType {
@@ -0,0 +1,62 @@
+use std::marker::PhantomData;
+
+use peace_resources::paths::ParamsDigestsFile;
+
+use crate::{Error, ParamsDigests, Storage};
+
+/// Reads and writes [`ParamsDigests`] to and from storage.
+pub struct ParamsDigestsSerializer<E>(PhantomData<E>);
+
+impl<E> ParamsDigestsSerializer<E>
+where
+    E: std::error::Error + From<Error> + Send,
+{
+    /// Serializes the [`ParamsDigests`] of the params that current states
+    /// were stored with to disk.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to write to.
+    /// * `params_digests`: `ParamsDigests` to serialize.
+    /// * `params_digests_file`: Path to save the serialized digests to.
+    pub async fn serialize(
+        storage: &Storage,
+        params_digests: &ParamsDigests,
+        params_digests_file: &ParamsDigestsFile,
+    ) -> Result<(), E> {
+        storage
+            .serialized_write_atomic(
+                #[cfg(not(target_arch = "wasm32"))]
+                "ParamsDigestsSerializer::serialize".to_string(),
+                params_digests_file,
+                params_digests,
+                Error::ParamsDigestsSerialize,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Returns the [`ParamsDigests`] of the params that current states were
+    /// last stored with, if it exists on disk.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to read from.
+    /// * `params_digests_file`: `ParamsDigestsFile` to deserialize.
+    pub async fn deserialize_opt(
+        storage: &Storage,
+        params_digests_file: &ParamsDigestsFile,
+    ) -> Result<Option<ParamsDigests>, E> {
+        let params_digests = storage
+            .serialized_read_opt(
+                #[cfg(not(target_arch = "wasm32"))]
+                "ParamsDigestsSerializer::deserialize_opt".to_string(),
+                params_digests_file,
+                |error, _| Error::ParamsDigestsDeserialize(error),
+            )
+            .await?;
+
+        Ok(params_digests)
+    }
+}
@@ -0,0 +1,177 @@
+use std::{fmt::Debug, hash::Hash, io::Read, path::Path};
+
+use peace_resources::type_reg::untagged::{BoxDt, TypeMap, TypeReg};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{Error, NativeError, Storage};
+
+/// Reads params from user supplied YAML or JSON files.
+///
+/// Each file is a map of params key to value. JSON files are read as YAML, as
+/// YAML is a superset of JSON.
+#[derive(Debug)]
+pub struct ParamsFileDeserializer;
+
+impl ParamsFileDeserializer {
+    /// Returns the params deserialized from the given file.
+    ///
+    /// Each key in the file must be registered in the type registry, otherwise
+    /// [`Error::ParamsFileKeysUnknown`] is returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `storage`: `Storage` to read from.
+    /// * `type_reg`: Type registry with functions to deserialize each param.
+    /// * `params_file_path`: Path to the user supplied params file.
+    pub async fn deserialize<K>(
+        storage: &Storage,
+        type_reg: &TypeReg<K, BoxDt>,
+        params_file_path: &Path,
+    ) -> Result<TypeMap<K, BoxDt>, Error>
+    where
+        K: Clone + Debug + Eq + Hash + DeserializeOwned + Serialize + Send + Sync + 'static,
+    {
+        let file_contents = storage
+            .read_with_sync_api(
+                "ParamsFileDeserializer::deserialize".to_string(),
+                params_file_path,
+                |reader| {
+                    let mut file_contents = String::new();
+                    reader
+                        .read_to_string(&mut file_contents)
+                        .map_err(|error| NativeError::FileRead {
+                            path: params_file_path.to_path_buf(),
+                            error,
+                        })
+                        .map_err(Error::Native)?;
+                    Result::<_, Error>::Ok(file_contents)
+                },
+            )
+            .await?;
+
+        let deserializer = serde_yaml::Deserializer::from_str(&file_contents);
+        let type_map = type_reg
+            .deserialize_map_with_unknowns::<'_, serde_yaml::Value, _, _>(deserializer)
+            .map_err(|error| Self::deserialize_error(params_file_path, &file_contents, error))?;
+
+        let (params, unknown_entries) = type_map.into_inner();
+        if !unknown_entries.is_empty() {
+            let keys = unknown_entries
+                .keys()
+                .map(|key| match serde_yaml::to_string(key) {
+                    Ok(key) => key.trim_end().to_string(),
+                    Err(_) => format!("{key:?}"),
+                })
+                .collect::<Vec<String>>();
+
+            return Err(Self::keys_unknown_error(
+                params_file_path,
+                file_contents,
+                keys,
+            ));
+        }
+
+        let params =
+            params
+                .into_iter()
+                .fold(TypeMap::new_typed(), |mut params_type_map, (key, param)| {
+                    params_type_map.insert_raw(key, param);
+                    params_type_map
+                });
+
+        Ok(params)
+    }
+
+    fn deserialize_error(
+        params_file_path: &Path,
+        #[cfg_attr(not(feature = "error_reporting"), allow(unused_variables))] file_contents: &str,
+        error: serde_yaml::Error,
+    ) -> Error {
+        #[cfg(not(feature = "error_reporting"))]
+        {
+            Error::ParamsFileDeserialize {
+                params_file_path: params_file_path.to_path_buf(),
+                error,
+            }
+        }
+        #[cfg(feature = "error_reporting")]
+        {
+            use miette::NamedSource;
+
+            let (error_span, error_message, context_span) =
+                crate::yaml_error_context_hack::error_and_context(file_contents, &error);
+            let params_file_source = NamedSource::new(
+                params_file_path.to_string_lossy(),
+                file_contents.to_string(),
+            );
+
+            Error::ParamsFileDeserialize {
+                params_file_path: params_file_path.to_path_buf(),
+                params_file_source,
+                error_span,
+                error_message,
+                context_span,
+                error,
+            }
+        }
+    }
+
+    fn keys_unknown_error(
+        params_file_path: &Path,
+        #[cfg_attr(not(feature = "error_reporting"), allow(unused_variables))]
+        file_contents: String,
+        keys: Vec<String>,
+    ) -> Error {
+        #[cfg(not(feature = "error_reporting"))]
+        {
+            Error::ParamsFileKeysUnknown {
+                params_file_path: params_file_path.to_path_buf(),
+                keys,
+            }
+        }
+        #[cfg(feature = "error_reporting")]
+        {
+            use miette::{LabeledSpan, NamedSource};
+
+            let key_spans = keys
+                .iter()
+                .filter_map(|key| key_span(&file_contents, key))
+                .map(|key_span| LabeledSpan::new_with_span(None, key_span))
+                .collect::<Vec<LabeledSpan>>();
+            let params_file_source =
+                NamedSource::new(params_file_path.to_string_lossy(), file_contents);
+
+            Error::ParamsFileKeysUnknown {
+                params_file_path: params_file_path.to_path_buf(),
+                keys,
+                params_file_source,
+                key_spans,
+            }
+        }
+    }
+}
+
+/// Returns the span of the given key within the file contents.
+///
+/// The key may be unquoted, or quoted with single or double quotes, and must be
+/// followed by a `:`.
+#[cfg(feature = "error_reporting")]
+fn key_span(file_contents: &str, key: &str) -> Option<miette::SourceSpan> {
+    let key_candidates = [format!("\"{key}\""), format!("'{key}'"), key.to_string()];
+    key_candidates.iter().find_map(|key_candidate| {
+        file_contents
+            .match_indices(key_candidate.as_str())
+            .find(|(index, _)| {
+                let preceded_by_delimiter = file_contents[..*index]
+                    .chars()
+                    .next_back()
+                    .is_none_or(|c| c.is_whitespace() || c == '{' || c == ',');
+                let followed_by_colon = file_contents[index + key_candidate.len()..]
+                    .trim_start_matches([' ', '\t'])
+                    .starts_with(':');
+
+                preceded_by_delimiter && followed_by_colon
+            })
+            .map(|(index, _)| miette::SourceSpan::from((index, key_candidate.len())))
+    })
+}
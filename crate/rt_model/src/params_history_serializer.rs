@@ -0,0 +1,91 @@
+use std::{marker::PhantomData, path::Path};
+
+use peace_resources::paths::ParamsHistoryFile;
+use serde_yaml::Mapping;
+
+use crate::{Error, ParamsHistory, Storage};
+
+/// Reads and writes [`ParamsHistory`] to and from storage.
+pub struct ParamsHistorySerializer<E>(PhantomData<E>);
+
+impl<E> ParamsHistorySerializer<E>
+where
+    E: std::error::Error + From<Error> + Send,
+{
+    /// Serializes the [`ParamsHistory`] of a profile to disk.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to write to.
+    /// * `params_history`: `ParamsHistory` to serialize.
+    /// * `params_history_file`: Path to save the serialized history to.
+    pub async fn serialize(
+        storage: &Storage,
+        params_history: &ParamsHistory,
+        params_history_file: &ParamsHistoryFile,
+    ) -> Result<(), E> {
+        storage
+            .serialized_write_atomic(
+                #[cfg(not(target_arch = "wasm32"))]
+                "ParamsHistorySerializer::serialize".to_string(),
+                params_history_file,
+                params_history,
+                Error::ParamsHistorySerialize,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Returns the [`ParamsHistory`] of a profile, if it exists on disk.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to read from.
+    /// * `params_history_file`: `ParamsHistoryFile` to deserialize.
+    pub async fn deserialize_opt(
+        storage: &Storage,
+        params_history_file: &ParamsHistoryFile,
+    ) -> Result<Option<ParamsHistory>, E> {
+        let params_history = storage
+            .serialized_read_opt(
+                #[cfg(not(target_arch = "wasm32"))]
+                "ParamsHistorySerializer::deserialize_opt".to_string(),
+                params_history_file,
+                |error, _| Error::ParamsHistoryDeserialize(error),
+            )
+            .await?;
+
+        Ok(params_history)
+    }
+
+    /// Returns the params stored for a revision, without deserializing each
+    /// param into its type.
+    ///
+    /// An empty map is returned if the params file does not exist, which is
+    /// the case when no params of that kind were used.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to read from.
+    /// * `revision`: Number of the revision that the params file belongs to.
+    /// * `params_file`: Params file within the revision's `ParamsRevisionDir`.
+    pub async fn revision_params_deserialize(
+        storage: &Storage,
+        revision: u64,
+        params_file: &Path,
+    ) -> Result<Mapping, E> {
+        let params = storage
+            .serialized_read_opt::<Option<Mapping>, _>(
+                #[cfg(not(target_arch = "wasm32"))]
+                "ParamsHistorySerializer::revision_params_deserialize".to_string(),
+                params_file,
+                |error, _| Error::ParamsRevisionDeserialize { revision, error },
+            )
+            .await?
+            .flatten()
+            .unwrap_or_default();
+
+        Ok(params)
+    }
+}
@@ -1,11 +1,21 @@
 use std::marker::PhantomData;
 
-use peace_cfg::{FlowId, Profile};
-use peace_params::ParamsSpecs;
-use peace_resources::{paths::ParamsSpecsFile, type_reg::untagged::TypeMapOpt};
+use peace_cfg::{FlowId, ItemId, Profile};
+use peace_params::{AnySpecRtBoxed, ParamsSpecs};
+use peace_resources::{
+    paths::ParamsSpecsFile,
+    type_reg::{common::UnknownEntriesSome, untagged::TypeMapOpt},
+};
+
+use indexmap::IndexMap;
 
 use crate::{Error, ParamsSpecsTypeReg, Storage};
 
+/// Deserialized params specs, including entries for items not in the type
+/// registry.
+type ParamsSpecsTypeMapOpt =
+    TypeMapOpt<ItemId, AnySpecRtBoxed, UnknownEntriesSome<serde_yaml::Value>>;
+
 /// Reads and writes [`ParamsSpecs`] to and from storage.
 pub struct ParamsSpecsSerializer<E>(PhantomData<E>);
 
@@ -57,7 +67,7 @@ where
         params_specs_type_reg: &ParamsSpecsTypeReg,
         params_specs_file: &ParamsSpecsFile,
     ) -> Result<Option<ParamsSpecs>, E> {
-        Self::deserialize_internal(
+        let type_map_opt = Self::deserialize_type_map_opt(
             #[cfg(not(target_arch = "wasm32"))]
             "ParamsSpecsSerializer::deserialize_opt".to_string(),
             profile,
@@ -66,10 +76,172 @@ where
             params_specs_type_reg,
             params_specs_file,
         )
-        .await
+        .await?;
+
+        Ok(type_map_opt
+            .map(TypeMapOpt::into_type_map)
+            .map(ParamsSpecs::from))
     }
 
-    /// Returns the [`ParamsSpecs`] of all [`Item`]s if it exists on disk.
+    /// Re-reads the params specs file, and rewrites it using the current type
+    /// registry.
+    ///
+    /// Entries for items that are not in the type registry are removed from
+    /// the file. If the storage is encrypted, the file is re-encrypted.
+    ///
+    /// Returns the IDs of the removed items, or `None` if the file does not
+    /// exist.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to read from and write to.
+    /// * `params_specs_type_reg`: Type registry with functions to deserialize
+    ///   each params spec.
+    /// * `params_specs_file`: `ParamsSpecsFile` to reserialize.
+    pub async fn reserialize(
+        profile: &Profile,
+        flow_id: &FlowId,
+        storage: &Storage,
+        params_specs_type_reg: &ParamsSpecsTypeReg,
+        params_specs_file: &ParamsSpecsFile,
+    ) -> Result<Option<Vec<ItemId>>, E> {
+        let type_map_opt = Self::deserialize_type_map_opt(
+            #[cfg(not(target_arch = "wasm32"))]
+            "ParamsSpecsSerializer::reserialize".to_string(),
+            profile,
+            flow_id,
+            storage,
+            params_specs_type_reg,
+            params_specs_file,
+        )
+        .await?;
+        let Some(type_map_opt) = type_map_opt else {
+            return Ok(None);
+        };
+
+        let item_ids_removed = type_map_opt
+            .unknown_entries()
+            .keys()
+            .cloned()
+            .collect::<Vec<ItemId>>();
+        let params_specs = ParamsSpecs::from(type_map_opt.into_type_map());
+
+        storage
+            .serialized_write_atomic(
+                #[cfg(not(target_arch = "wasm32"))]
+                "ParamsSpecsSerializer::reserialize".to_string(),
+                params_specs_file,
+                &params_specs,
+                Error::ParamsSpecsSerialize,
+            )
+            .await?;
+
+        Ok(Some(item_ids_removed))
+    }
+
+    /// Returns the IDs of items in the params specs file that are not in the
+    /// type registry, or `None` if the file does not exist.
+    ///
+    /// The file is not modified. See [`ParamsSpecsSerializer::reserialize`] to
+    /// remove these entries.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to read from.
+    /// * `params_specs_type_reg`: Type registry with functions to deserialize
+    ///   each params spec.
+    /// * `params_specs_file`: `ParamsSpecsFile` to read.
+    pub async fn item_ids_unknown(
+        profile: &Profile,
+        flow_id: &FlowId,
+        storage: &Storage,
+        params_specs_type_reg: &ParamsSpecsTypeReg,
+        params_specs_file: &ParamsSpecsFile,
+    ) -> Result<Option<Vec<ItemId>>, E> {
+        let type_map_opt = Self::deserialize_type_map_opt(
+            #[cfg(not(target_arch = "wasm32"))]
+            "ParamsSpecsSerializer::item_ids_unknown".to_string(),
+            profile,
+            flow_id,
+            storage,
+            params_specs_type_reg,
+            params_specs_file,
+        )
+        .await?;
+
+        Ok(type_map_opt.map(|type_map_opt| {
+            type_map_opt
+                .unknown_entries()
+                .keys()
+                .cloned()
+                .collect::<Vec<ItemId>>()
+        }))
+    }
+
+    /// Returns the params specs file's entries if it exists on disk, without
+    /// deserializing each params spec into its type.
+    ///
+    /// This allows entries for items that are no longer in the flow to be
+    /// renamed or removed.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to read from.
+    /// * `params_specs_file`: `ParamsSpecsFile` to deserialize.
+    pub async fn deserialize_raw_opt(
+        profile: &Profile,
+        flow_id: &FlowId,
+        storage: &Storage,
+        params_specs_file: &ParamsSpecsFile,
+    ) -> Result<Option<IndexMap<ItemId, serde_yaml::Value>>, E> {
+        let params_specs_raw = storage
+            .serialized_read_opt::<Option<IndexMap<ItemId, serde_yaml::Value>>, _>(
+                #[cfg(not(target_arch = "wasm32"))]
+                "ParamsSpecsSerializer::deserialize_raw_opt".to_string(),
+                params_specs_file,
+                |error, file_contents| {
+                    Self::deserialize_error(
+                        profile,
+                        flow_id,
+                        params_specs_file,
+                        file_contents,
+                        error,
+                    )
+                },
+            )
+            .await?;
+
+        Ok(params_specs_raw.map(Option::unwrap_or_default))
+    }
+
+    /// Writes the params specs file's entries to disk, replacing the file
+    /// atomically.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to write to.
+    /// * `params_specs_raw`: Entries of the params specs file.
+    /// * `params_specs_file`: Path to save the entries to.
+    pub async fn serialize_raw(
+        storage: &Storage,
+        params_specs_raw: &IndexMap<ItemId, serde_yaml::Value>,
+        params_specs_file: &ParamsSpecsFile,
+    ) -> Result<(), E> {
+        storage
+            .serialized_write_atomic(
+                #[cfg(not(target_arch = "wasm32"))]
+                "ParamsSpecsSerializer::serialize_raw".to_string(),
+                params_specs_file,
+                params_specs_raw,
+                Error::ParamsSpecsSerialize,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Returns the params specs of all [`Item`]s if it exists on disk,
+    /// including entries for items that are not in the type registry.
     ///
     /// # Parameters:
     ///
@@ -80,65 +252,36 @@ where
     ///
     /// [`Item`]: peace_cfg::Item
     #[cfg(not(target_arch = "wasm32"))]
-    async fn deserialize_internal(
+    async fn deserialize_type_map_opt(
         thread_name: String,
         profile: &Profile,
         flow_id: &FlowId,
         storage: &Storage,
         params_specs_type_reg: &ParamsSpecsTypeReg,
         params_specs_file: &ParamsSpecsFile,
-    ) -> Result<Option<ParamsSpecs>, E> {
-        let params_specs_opt = storage
+    ) -> Result<Option<ParamsSpecsTypeMapOpt>, E> {
+        let type_map_opt = storage
             .serialized_typemap_read_opt(
                 thread_name,
                 params_specs_type_reg,
                 params_specs_file,
-                |error| {
-                    #[cfg(not(feature = "error_reporting"))]
-                    {
-                        Error::ParamsSpecsDeserialize {
-                            profile: profile.clone(),
-                            flow_id: flow_id.clone(),
-                            error,
-                        }
-                    }
-                    #[cfg(feature = "error_reporting")]
-                    {
-                        use miette::NamedSource;
-
-                        let file_contents = std::fs::read_to_string(params_specs_file).unwrap();
-
-                        let (error_span, error_message, context_span) =
-                            crate::yaml_error_context_hack::error_and_context(
-                                &file_contents,
-                                &error,
-                            );
-                        let params_specs_file_source =
-                            NamedSource::new(params_specs_file.to_string_lossy(), file_contents);
-
-                        Error::ParamsSpecsDeserialize {
-                            profile: profile.clone(),
-                            flow_id: flow_id.clone(),
-                            params_specs_file_source,
-                            error_span,
-                            error_message,
-                            context_span,
-                            error,
-                        }
-                    }
+                |error, file_contents| {
+                    Self::deserialize_error(
+                        profile,
+                        flow_id,
+                        params_specs_file,
+                        file_contents,
+                        error,
+                    )
                 },
             )
-            .await
-            .map(|type_map_opt| {
-                type_map_opt
-                    .map(TypeMapOpt::into_type_map)
-                    .map(ParamsSpecs::from)
-            })?;
-
-        Ok(params_specs_opt)
+            .await?;
+
+        Ok(type_map_opt)
     }
 
-    /// Returns the [`ParamsSpecs`] of all [`Item`]s if it exists on disk.
+    /// Returns the params specs of all [`Item`]s if it exists on disk,
+    /// including entries for items that are not in the type registry.
     ///
     /// # Parameters:
     ///
@@ -149,52 +292,75 @@ where
     ///
     /// [`Item`]: peace_cfg::Item
     #[cfg(target_arch = "wasm32")]
-    async fn deserialize_internal(
+    async fn deserialize_type_map_opt(
         profile: &Profile,
         flow_id: &FlowId,
         storage: &Storage,
         params_specs_type_reg: &ParamsSpecsTypeReg,
         params_specs_file: &ParamsSpecsFile,
-    ) -> Result<Option<ParamsSpecs>, E> {
-        let params_specs_opt = storage
-            .serialized_typemap_read_opt(params_specs_type_reg, params_specs_file, |error| {
-                #[cfg(not(feature = "error_reporting"))]
-                {
-                    Error::ParamsSpecsDeserialize {
-                        profile: profile.clone(),
-                        flow_id: flow_id.clone(),
-                        error,
-                    }
-                }
-                #[cfg(feature = "error_reporting")]
-                {
-                    use miette::NamedSource;
-
-                    let file_contents = std::fs::read_to_string(params_specs_file).unwrap();
-
-                    let (error_span, error_message, context_span) =
-                        crate::yaml_error_context_hack::error_and_context(&file_contents, &error);
-                    let params_specs_file_source =
-                        NamedSource::new(params_specs_file.to_string_lossy(), file_contents);
-
-                    Error::ParamsSpecsDeserialize {
-                        profile: profile.clone(),
-                        flow_id: flow_id.clone(),
-                        params_specs_file_source,
-                        error_span,
-                        error_message,
-                        context_span,
+    ) -> Result<Option<ParamsSpecsTypeMapOpt>, E> {
+        let type_map_opt = storage
+            .serialized_typemap_read_opt(
+                params_specs_type_reg,
+                params_specs_file,
+                |error, file_contents| {
+                    Self::deserialize_error(
+                        profile,
+                        flow_id,
+                        params_specs_file,
+                        file_contents,
                         error,
-                    }
-                }
-            })
-            .await
-            .map(|type_map_opt| {
-                type_map_opt
-                    .map(TypeMapOpt::into_type_map)
-                    .map(ParamsSpecs::from)
-            })?;
-
-        Ok(params_specs_opt)
+                    )
+                },
+            )
+            .await?;
+
+        Ok(type_map_opt)
+    }
+
+    /// Returns the error for when the params specs file fails to be
+    /// deserialized.
+    ///
+    /// `file_contents` are the decrypted and decompressed contents that were
+    /// parsed, rather than the bytes stored in the file.
+    fn deserialize_error(
+        profile: &Profile,
+        flow_id: &FlowId,
+        params_specs_file: &ParamsSpecsFile,
+        file_contents: &[u8],
+        error: serde_yaml::Error,
+    ) -> Error {
+        #[cfg(not(feature = "error_reporting"))]
+        {
+            let _params_specs_file = params_specs_file;
+            let _file_contents = file_contents;
+
+            Error::ParamsSpecsDeserialize {
+                profile: profile.clone(),
+                flow_id: flow_id.clone(),
+                error,
+            }
+        }
+        #[cfg(feature = "error_reporting")]
+        {
+            use miette::NamedSource;
+
+            let file_contents = String::from_utf8_lossy(file_contents).into_owned();
+
+            let (error_span, error_message, context_span) =
+                crate::yaml_error_context_hack::error_and_context(&file_contents, &error);
+            let params_specs_file_source =
+                NamedSource::new(params_specs_file.to_string_lossy(), file_contents);
+
+            Error::ParamsSpecsDeserialize {
+                profile: profile.clone(),
+                flow_id: flow_id.clone(),
+                params_specs_file_source,
+                error_span,
+                error_message,
+                context_span,
+                error,
+            }
+        }
     }
 }
@@ -0,0 +1,130 @@
+use std::{
+    fmt::{self, Debug},
+    sync::Arc,
+};
+
+use futures::future::BoxFuture;
+
+use crate::PreflightCheckFailure;
+
+/// Environment check that is run before a flow's states are discovered or its
+/// items are applied.
+///
+/// Checks such as whether a binary is installed, credentials are valid, or
+/// there is enough disk space, are run once for the flow instead of within
+/// each item, so a command fails fast before any item is changed.
+///
+/// The check returns `Err(reason)` if it fails. Checks are compared by name,
+/// as their functions cannot be compared.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let flow = Flow::new(flow_id, graph)
+///     .with_preflight_check(PreflightCheck::binary_exists("docker"))
+///     .with_preflight_check(PreflightCheck::new("aws credentials", || {
+///         Box::pin(async move {
+///             aws_credentials_load()
+///                 .await
+///                 .map(|_credentials| ())
+///                 .map_err(|error| format!("{error}"))
+///         })
+///     }));
+/// ```
+pub struct PreflightCheck {
+    /// Name of the check, e.g. `"docker installed"`.
+    name: String,
+    /// Runs the check.
+    #[allow(clippy::type_complexity)]
+    check_fn: Arc<dyn Fn() -> BoxFuture<'static, Result<(), String>> + Send + Sync>,
+}
+
+impl PreflightCheck {
+    /// Returns a new `PreflightCheck`.
+    ///
+    /// # Parameters
+    ///
+    /// * `name`: Name of the check, shown when the check fails.
+    /// * `f`: Runs the check, returning `Err(reason)` if the check fails.
+    pub fn new<F>(name: impl Into<String>, f: F) -> Self
+    where
+        F: Fn() -> BoxFuture<'static, Result<(), String>> + Send + Sync + 'static,
+    {
+        Self {
+            name: name.into(),
+            check_fn: Arc::new(f),
+        }
+    }
+
+    /// Returns a check that the given binary is in one of the `PATH`
+    /// directories.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn binary_exists(binary_name: impl Into<String>) -> Self {
+        let binary_name = binary_name.into();
+        let name = format!("`{binary_name}` installed");
+
+        Self::new(name, move || {
+            let binary_name = binary_name.clone();
+            Box::pin(async move {
+                let binary_file_names = if cfg!(windows) {
+                    vec![binary_name.clone(), format!("{binary_name}.exe")]
+                } else {
+                    vec![binary_name.clone()]
+                };
+                let binary_exists = std::env::var_os("PATH")
+                    .map(|path| {
+                        std::env::split_paths(&path).any(|dir| {
+                            binary_file_names
+                                .iter()
+                                .any(|binary_file_name| dir.join(binary_file_name).is_file())
+                        })
+                    })
+                    .unwrap_or(false);
+
+                if binary_exists {
+                    Ok(())
+                } else {
+                    Err(format!("`{binary_name}` was not found in `PATH`."))
+                }
+            })
+        })
+    }
+
+    /// Returns the name of the check.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Runs the check.
+    pub async fn check(&self) -> Result<(), PreflightCheckFailure> {
+        (self.check_fn)()
+            .await
+            .map_err(|reason| PreflightCheckFailure::new(self.name.clone(), reason))
+    }
+}
+
+impl Clone for PreflightCheck {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            check_fn: Arc::clone(&self.check_fn),
+        }
+    }
+}
+
+impl Debug for PreflightCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PreflightCheck")
+            .field("name", &self.name)
+            .field("check_fn", &"Fn() -> BoxFuture<'static, Result<(), String>>")
+            .finish()
+    }
+}
+
+impl PartialEq for PreflightCheck {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for PreflightCheck {}
@@ -0,0 +1,191 @@
+use std::marker::PhantomData;
+
+use peace_cfg::{FlowId, FnCtx, Profile};
+use peace_params::ParamsSpecs;
+use peace_resources::{
+    paths::{FlowDir, ParamsSpecsFile, StatesCurrentFile, StatesGoalFile},
+    resources::ts::{Empty, SetUp},
+    Resources,
+};
+
+use crate::{
+    Error, ItemBoxed, ItemGraph, ParamsSpecsSerializer, ParamsSpecsTypeReg, StatesPruneOutcome,
+    StatesSerializer, StatesTypeReg, Storage,
+};
+
+/// Removes stored states of items that are no longer in the flow, once their
+/// resources are confirmed to be cleaned up.
+///
+/// Stored states for removed items otherwise remain in the
+/// `StatesCurrentFile`, and are shown in diffs even though the flow no longer
+/// manages them.
+///
+/// Since the removed items are not in the flow, they are provided separately
+/// in an [`ItemGraph`], along with their params specs. For each removed item
+/// that has a stored state, the item's current state is discovered and
+/// compared with its clean state:
+///
+/// * If they are equal, the stored current and goal states are removed.
+/// * If they are not equal, the stored states are kept.
+/// * If the item or its params spec is not provided, or its current state
+///   cannot be discovered, the stored states are kept.
+///
+/// Params specs for items that are not in the flow are dropped from the
+/// `ParamsSpecsFile` when a command context is built, so they must be read
+/// with [`StatesPruner::params_specs_deserialize`] beforehand, or be provided
+/// by the caller.
+pub struct StatesPruner<E>(PhantomData<E>);
+
+impl<E> StatesPruner<E>
+where
+    E: std::error::Error + From<Error> + Send + 'static,
+{
+    /// Prunes stored states of items that are not in the flow.
+    ///
+    /// The removed items' setup functions are run with `resources`, so data
+    /// they insert remains in `resources` afterwards.
+    ///
+    /// # Parameters:
+    ///
+    /// * `states_type_reg`: Type registry of the states of items in the flow,
+    ///   used to find stored states of items that are not in the flow.
+    /// * `item_graph_removed`: Items that were removed from the flow, used to
+    ///   discover whether their resources are cleaned up.
+    /// * `params_specs_removed`: Params specs of the removed items.
+    /// * `resources`: `Resources` of the flow, which the removed items are set
+    ///   up with.
+    pub async fn prune(
+        flow_id: &FlowId,
+        flow_dir: &FlowDir,
+        storage: &Storage,
+        states_type_reg: &StatesTypeReg,
+        item_graph_removed: &ItemGraph<E>,
+        params_specs_removed: &ParamsSpecs,
+        resources: &mut Resources<SetUp>,
+    ) -> Result<StatesPruneOutcome, E> {
+        let states_current_file = StatesCurrentFile::from(flow_dir);
+        let item_ids_removed = StatesSerializer::<E>::item_ids_unknown(
+            flow_id,
+            storage,
+            states_type_reg,
+            &states_current_file,
+        )
+        .await?
+        .unwrap_or_default();
+        if item_ids_removed.is_empty() {
+            return Ok(StatesPruneOutcome::default());
+        }
+
+        let params_specs = params_specs_removed;
+        let items_discoverable = item_graph_removed
+            .iter_insertion()
+            .filter(|item| {
+                item_ids_removed.contains(item.id()) && params_specs.contains_key(item.id())
+            })
+            .collect::<Vec<&ItemBoxed<E>>>();
+        Self::items_setup(&items_discoverable, resources).await?;
+
+        #[cfg(feature = "output_progress")]
+        let (progress_tx, _progress_rx) = tokio::sync::mpsc::channel(64);
+
+        let mut states_prune_outcome = StatesPruneOutcome::default();
+        for item_id in item_ids_removed {
+            let item = items_discoverable.iter().find(|item| item.id() == &item_id);
+            let Some(item) = item else {
+                states_prune_outcome.item_ids_unconfirmed.push(item_id);
+                continue;
+            };
+
+            let fn_ctx = FnCtx::new(
+                &item_id,
+                #[cfg(feature = "output_progress")]
+                peace_cfg::progress::ProgressSender::new(&item_id, &progress_tx),
+            );
+            let state_current = item
+                .state_current_try_exec(params_specs, resources, fn_ctx)
+                .await?;
+            let Some(state_current) = state_current else {
+                states_prune_outcome.item_ids_unconfirmed.push(item_id);
+                continue;
+            };
+            let state_clean = item.state_clean(params_specs, resources).await?;
+
+            if item.state_eq(&state_current, &state_clean)? {
+                states_prune_outcome.item_ids_pruned.push(item_id);
+            } else {
+                states_prune_outcome.item_ids_exist.push(item_id);
+            }
+        }
+
+        if !states_prune_outcome.item_ids_pruned.is_empty() {
+            StatesSerializer::<E>::entries_remove(
+                flow_id,
+                storage,
+                &states_prune_outcome.item_ids_pruned,
+                &states_current_file,
+            )
+            .await?;
+            StatesSerializer::<E>::entries_remove(
+                flow_id,
+                storage,
+                &states_prune_outcome.item_ids_pruned,
+                &StatesGoalFile::from(flow_dir),
+            )
+            .await?;
+        }
+
+        Ok(states_prune_outcome)
+    }
+
+    /// Returns the stored params specs of the removed items.
+    ///
+    /// Params specs that are not stored are not returned.
+    pub async fn params_specs_deserialize(
+        profile: &Profile,
+        flow_id: &FlowId,
+        flow_dir: &FlowDir,
+        storage: &Storage,
+        item_graph_removed: &ItemGraph<E>,
+    ) -> Result<ParamsSpecs, E> {
+        let mut params_specs_type_reg = ParamsSpecsTypeReg::new();
+        let mut states_type_reg = StatesTypeReg::new();
+        item_graph_removed.iter_insertion().for_each(|item| {
+            item.params_and_state_register(&mut params_specs_type_reg, &mut states_type_reg)
+        });
+
+        let params_specs_file = ParamsSpecsFile::from(flow_dir);
+        let params_specs = ParamsSpecsSerializer::<E>::deserialize_opt(
+            profile,
+            flow_id,
+            storage,
+            &params_specs_type_reg,
+            &params_specs_file,
+        )
+        .await?
+        .unwrap_or_default();
+
+        Ok(params_specs)
+    }
+
+    /// Runs the removed items' setup functions with the flow's resources.
+    async fn items_setup(
+        items: &[&ItemBoxed<E>],
+        resources: &mut Resources<SetUp>,
+    ) -> Result<(), E> {
+        // `Item::setup` takes `Resources<Empty>`, so the flow's resources are
+        // swapped in, and swapped back out after the items are set up.
+        let mut resources_empty = Resources::<Empty>::new();
+        std::mem::swap(&mut *resources_empty, &mut **resources);
+
+        let mut setup_result = Ok(());
+        for item in items {
+            setup_result = item.setup(&mut resources_empty).await;
+            if setup_result.is_err() {
+                break;
+            }
+        }
+
+        std::mem::swap(&mut *resources_empty, &mut **resources);
+        setup_result
+    }
+}
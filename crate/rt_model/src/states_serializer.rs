@@ -2,18 +2,38 @@ use std::{marker::PhantomData, path::Path};
 
 use peace_cfg::{FlowId, ItemId};
 use peace_resources::{
-    paths::{StatesCurrentFile, StatesGoalFile},
+    paths::{StatesCurrentFile, StatesGoalFile, StatesTimestampsFile},
     states::{
         ts::{CurrentStored, GoalStored},
-        States, StatesCurrentStored, StatesGoalStored,
+        States, StatesCurrentStored, StatesGoalStored, StatesTimestamps,
+    },
+    type_reg::{
+        common::UnknownEntriesSome,
+        untagged::{BoxDtDisplay, TypeMapOpt, TypeReg},
     },
-    type_reg::untagged::{BoxDtDisplay, TypeMapOpt, TypeReg},
 };
 
-use crate::{Error, ItemGraph, Storage};
+use crate::{Error, IndexMap, ItemGraph, Storage, StorageFormat, StorageFormatError};
+
+use self::{state_delta::StateDelta, states_ordered::StatesOrdered};
+
+mod state_delta;
+mod states_ordered;
+
+/// Deserialized states, including entries for items not in the type registry.
+type StatesTypeMapOpt = TypeMapOpt<ItemId, BoxDtDisplay, UnknownEntriesSome<serde_yaml::Value>>;
 
 /// Reads and writes [`StatesCurrentStored`] and [`StatesGoalStored`] to and
 /// from storage.
+///
+/// States are serialized in the storage's [`StorageFormat`], with entries in
+/// the storage's [`StatesOrdering`].
+///
+/// When each state was discovered is written to a [`StatesTimestampsFile`]
+/// alongside each states file, and is read back into the deserialized
+/// [`States`].
+///
+/// [`StatesOrdering`]: crate::StatesOrdering
 pub struct StatesSerializer<E>(PhantomData<E>);
 
 impl<E> StatesSerializer<E>
@@ -39,18 +59,124 @@ where
     where
         TS: Send + Sync,
     {
+        let storage_format = storage.storage_format();
         let states_serde = item_graph.states_serde::<serde_yaml::Value, _>(states);
         storage
-            .serialized_write(
+            .formatted_write(
                 #[cfg(not(target_arch = "wasm32"))]
                 "StatesSerializer::serialize".to_string(),
                 states_file_path,
-                &states_serde,
-                Error::StatesSerialize,
+                &StatesOrdered::new(storage.states_ordering(), &states_serde),
+                |error| Self::states_serialize_error(storage_format, error),
             )
             .await?;
 
-        Ok(())
+        Self::timestamps_serialize(storage, states.timestamps(), states_file_path).await
+    }
+
+    /// Serializes only the states that differ from those in the existing
+    /// states file, merging them into the file.
+    ///
+    /// Each state is compared with its entry in the file as it is read here,
+    /// so states written by another command since this command's context was
+    /// built are not mixed with stale ones. States that are equal to their
+    /// entry are copied from the file without being re-serialized, and the
+    /// file is replaced atomically. If nothing changed, the file is not
+    /// written, though the states' timestamps are still updated.
+    ///
+    /// If the file does not exist or cannot be read, all states are
+    /// serialized.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to read from and write to.
+    /// * `states`: States to serialize.
+    /// * `states_file_path`: Path to save the serialized states to.
+    pub async fn serialize_delta<TS>(
+        storage: &Storage,
+        item_graph: &ItemGraph<E>,
+        states: &States<TS>,
+        states_file_path: &Path,
+    ) -> Result<(), E>
+    where
+        TS: Send + Sync,
+    {
+        let storage_format = storage.storage_format();
+        let states_serde_existing = storage
+            .formatted_read_opt::<serde_yaml::Mapping, _>(
+                #[cfg(not(target_arch = "wasm32"))]
+                "StatesSerializer::serialize_delta".to_string(),
+                states_file_path,
+                |error, _| Self::states_serialize_error(storage_format, error),
+            )
+            .await
+            .ok()
+            .flatten();
+        let Some(mut states_serde_existing) = states_serde_existing else {
+            return Self::serialize(storage, item_graph, states, states_file_path).await;
+        };
+
+        // States are compared in their serialized form, with `Sensitive` fields
+        // serialized the same way as they are stored.
+        let item_ids_changed = storage
+            .sensitive_serde_mode(states_file_path)
+            .scope(|| {
+                item_graph
+                    .iter_insertion()
+                    .try_fold(Vec::new(), |mut item_ids_changed, item| {
+                        let item_id = item.id();
+                        let state_existing = states_serde_existing
+                            .get(serde_yaml::Value::String(item_id.to_string()));
+                        let state_changed = match (state_existing, states.get_raw(item_id)) {
+                            (Some(state_existing), Some(state)) => {
+                                *state_existing != serde_yaml::to_value(state)?
+                            }
+                            (None, None) => false,
+                            (Some(_), None) | (None, Some(_)) => true,
+                        };
+                        if state_changed {
+                            item_ids_changed.push(item_id.clone());
+                        }
+
+                        Ok(item_ids_changed)
+                    })
+            })
+            .map_err(Error::StatesSerialize)?;
+
+        if item_ids_changed.is_empty() {
+            return Self::timestamps_serialize(storage, states.timestamps(), states_file_path)
+                .await;
+        }
+
+        let states_serde = item_graph
+            .iter_insertion()
+            .map(|item| {
+                let item_id = item.id();
+                let state_existing = if item_ids_changed.contains(item_id) {
+                    None
+                } else {
+                    states_serde_existing.remove(serde_yaml::Value::String(item_id.to_string()))
+                };
+                let state = match state_existing {
+                    Some(state_existing) => StateDelta::Unchanged(state_existing),
+                    None => StateDelta::Changed(states.get_raw(item_id)),
+                };
+
+                (item_id.to_string(), state)
+            })
+            .collect::<IndexMap<String, StateDelta<'_>>>();
+
+        storage
+            .formatted_write_atomic(
+                #[cfg(not(target_arch = "wasm32"))]
+                "StatesSerializer::serialize_delta".to_string(),
+                states_file_path,
+                &StatesOrdered::new(storage.states_ordering(), &states_serde),
+                |error| Self::states_serialize_error(storage_format, error),
+            )
+            .await?;
+
+        Self::timestamps_serialize(storage, states.timestamps(), states_file_path).await
     }
 
     /// Returns the [`StatesCurrentStored`] of all [`Item`]s if it exists on
@@ -140,6 +266,226 @@ where
         .await
     }
 
+    /// Re-reads the states file, and rewrites it using the current type
+    /// registry.
+    ///
+    /// Entries for items that are not in the type registry -- usually items
+    /// that have been removed from the flow or renamed -- are removed from the
+    /// file. If the storage is encrypted, the file is re-encrypted.
+    ///
+    /// Returns the IDs of the removed items, or `None` if the file does not
+    /// exist.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to read from and write to.
+    /// * `states_type_reg`: Type registry with functions to deserialize each
+    ///   item state.
+    /// * `states_file_path`: Path to the states file to reserialize.
+    pub async fn reserialize(
+        flow_id: &FlowId,
+        storage: &Storage,
+        item_graph: &ItemGraph<E>,
+        states_type_reg: &TypeReg<ItemId, BoxDtDisplay>,
+        states_file_path: &Path,
+    ) -> Result<Option<Vec<ItemId>>, E> {
+        let type_map_opt = Self::deserialize_type_map_opt(
+            #[cfg(not(target_arch = "wasm32"))]
+            "StatesSerializer::reserialize".to_string(),
+            flow_id,
+            storage,
+            states_type_reg,
+            states_file_path,
+        )
+        .await?;
+        let Some(type_map_opt) = type_map_opt else {
+            return Ok(None);
+        };
+
+        let item_ids_removed = type_map_opt
+            .unknown_entries()
+            .keys()
+            .cloned()
+            .collect::<Vec<ItemId>>();
+        let states = States::<CurrentStored>::from(type_map_opt.into_type_map());
+        let storage_format = storage.storage_format();
+        let states_serde = item_graph.states_serde::<serde_yaml::Value, _>(&states);
+
+        storage
+            .formatted_write_atomic(
+                #[cfg(not(target_arch = "wasm32"))]
+                "StatesSerializer::reserialize".to_string(),
+                states_file_path,
+                &StatesOrdered::new(storage.states_ordering(), &states_serde),
+                |error| Self::states_serialize_error(storage_format, error),
+            )
+            .await?;
+
+        Ok(Some(item_ids_removed))
+    }
+
+    /// Copies a states file to another path, without deserializing each state.
+    ///
+    /// This may be used for items that are not in the type registry. If the
+    /// source file does not exist, an empty states file is written, so the
+    /// destination always reflects the source.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to read from and write to.
+    /// * `states_file_path_src`: Path to the states file to copy.
+    /// * `states_file_path_dest`: Path to write the copy to.
+    pub async fn copy(
+        flow_id: &FlowId,
+        storage: &Storage,
+        states_file_path_src: &Path,
+        states_file_path_dest: &Path,
+    ) -> Result<(), E> {
+        let storage_format = storage.storage_format();
+        let states_raw = storage
+            .formatted_read_opt::<Option<IndexMap<ItemId, serde_yaml::Value>>, _>(
+                #[cfg(not(target_arch = "wasm32"))]
+                "StatesSerializer::copy".to_string(),
+                states_file_path_src,
+                |error, file_contents| {
+                    Self::states_deserialize_error(
+                        flow_id,
+                        storage_format,
+                        states_file_path_src,
+                        file_contents,
+                        error,
+                    )
+                },
+            )
+            .await?
+            .flatten()
+            .unwrap_or_default();
+
+        storage
+            .formatted_write_atomic(
+                #[cfg(not(target_arch = "wasm32"))]
+                "StatesSerializer::copy".to_string(),
+                states_file_path_dest,
+                &states_raw,
+                |error| Self::states_serialize_error(storage_format, error),
+            )
+            .await?;
+
+        let states_timestamps = Self::timestamps_deserialize_opt(storage, states_file_path_src)
+            .await?
+            .unwrap_or_default();
+        Self::timestamps_serialize(storage, &states_timestamps, states_file_path_dest).await
+    }
+
+    /// Removes the entries for the given items from the states file.
+    ///
+    /// Other entries are copied without being deserialized, so this may be
+    /// used for items that are not in the type registry. If none of the items
+    /// have an entry, or the file does not exist, the file is not written.
+    ///
+    /// Returns the IDs of the removed items.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to read from and write to.
+    /// * `item_ids`: IDs of the items whose entries to remove.
+    /// * `states_file_path`: Path to the states file to remove entries from.
+    pub async fn entries_remove(
+        flow_id: &FlowId,
+        storage: &Storage,
+        item_ids: &[ItemId],
+        states_file_path: &Path,
+    ) -> Result<Vec<ItemId>, E> {
+        let storage_format = storage.storage_format();
+        let states_raw = storage
+            .formatted_read_opt::<Option<IndexMap<ItemId, serde_yaml::Value>>, _>(
+                #[cfg(not(target_arch = "wasm32"))]
+                "StatesSerializer::entries_remove".to_string(),
+                states_file_path,
+                |error, file_contents| {
+                    Self::states_deserialize_error(
+                        flow_id,
+                        storage_format,
+                        states_file_path,
+                        file_contents,
+                        error,
+                    )
+                },
+            )
+            .await?
+            .flatten();
+        let Some(mut states_raw) = states_raw else {
+            return Ok(Vec::new());
+        };
+
+        let item_ids_removed = item_ids
+            .iter()
+            .filter(|item_id| states_raw.shift_remove(*item_id).is_some())
+            .cloned()
+            .collect::<Vec<ItemId>>();
+        if item_ids_removed.is_empty() {
+            return Ok(item_ids_removed);
+        }
+
+        storage
+            .formatted_write_atomic(
+                #[cfg(not(target_arch = "wasm32"))]
+                "StatesSerializer::entries_remove".to_string(),
+                states_file_path,
+                &states_raw,
+                |error| Self::states_serialize_error(storage_format, error),
+            )
+            .await?;
+
+        if let Some(mut states_timestamps) =
+            Self::timestamps_deserialize_opt(storage, states_file_path).await?
+        {
+            item_ids_removed.iter().for_each(|item_id| {
+                states_timestamps.shift_remove(item_id);
+            });
+            Self::timestamps_serialize(storage, &states_timestamps, states_file_path).await?;
+        }
+
+        Ok(item_ids_removed)
+    }
+
+    /// Returns the IDs of items in the states file that are not in the type
+    /// registry, or `None` if the file does not exist.
+    ///
+    /// The file is not modified. See [`StatesSerializer::reserialize`] to
+    /// remove these entries.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to read from.
+    /// * `states_type_reg`: Type registry with functions to deserialize each
+    ///   item state.
+    /// * `states_file_path`: Path to the states file to read.
+    pub async fn item_ids_unknown(
+        flow_id: &FlowId,
+        storage: &Storage,
+        states_type_reg: &TypeReg<ItemId, BoxDtDisplay>,
+        states_file_path: &Path,
+    ) -> Result<Option<Vec<ItemId>>, E> {
+        let type_map_opt = Self::deserialize_type_map_opt(
+            #[cfg(not(target_arch = "wasm32"))]
+            "StatesSerializer::item_ids_unknown".to_string(),
+            flow_id,
+            storage,
+            states_type_reg,
+            states_file_path,
+        )
+        .await?;
+
+        Ok(type_map_opt.map(|type_map_opt| {
+            type_map_opt
+                .unknown_entries()
+                .keys()
+                .cloned()
+                .collect::<Vec<ItemId>>()
+        }))
+    }
+
     /// Returns the [`States`] of all [`Item`]s if it exists on disk.
     ///
     /// # Parameters:
@@ -147,7 +493,7 @@ where
     /// * `storage`: `Storage` to read from.
     /// * `states_type_reg`: Type registry with functions to deserialize each
     ///   item state.
-    /// * `states_current_file`: `StatesCurrentFile` to deserialize.
+    /// * `states_file_path`: Path to the states file to deserialize.
     ///
     /// # Type Parameters
     ///
@@ -157,9 +503,8 @@ where
     /// [`Item`]: peace_cfg::Item
     /// [`ts::Current`]: peace_resources::states::ts::Current
     /// [`ts::CurrentStored`]: peace_resources::states::ts::CurrentStored
-    #[cfg(not(target_arch = "wasm32"))]
     async fn deserialize_internal<TS>(
-        thread_name: String,
+        #[cfg(not(target_arch = "wasm32"))] thread_name: String,
         flow_id: &FlowId,
         storage: &Storage,
         states_type_reg: &TypeReg<ItemId, BoxDtDisplay>,
@@ -168,110 +513,218 @@ where
     where
         TS: Send + Sync,
     {
-        let states_opt = storage
-            .serialized_typemap_read_opt(thread_name, states_type_reg, states_file_path, |error| {
-                #[cfg(not(feature = "error_reporting"))]
-                {
-                    Error::StatesDeserialize {
-                        flow_id: flow_id.clone(),
-                        error,
-                    }
-                }
-                #[cfg(feature = "error_reporting")]
-                {
-                    use miette::NamedSource;
-
-                    let file_contents = std::fs::read_to_string(states_file_path).unwrap();
-
-                    let (error_span, error_message, context_span) =
-                        crate::yaml_error_context_hack::error_and_context(&file_contents, &error);
-                    let states_file_source =
-                        NamedSource::new(states_file_path.to_string_lossy(), file_contents);
-
-                    Error::StatesDeserialize {
-                        flow_id: flow_id.clone(),
-                        states_file_source,
-                        error_span,
-                        error_message,
-                        context_span,
-                        error,
-                    }
-                }
-            })
-            .await
-            .map(|type_map_opt| {
-                type_map_opt
-                    .map(TypeMapOpt::into_type_map)
-                    .map(States::from)
-            })?;
+        let type_map_opt = Self::deserialize_type_map_opt(
+            #[cfg(not(target_arch = "wasm32"))]
+            thread_name,
+            flow_id,
+            storage,
+            states_type_reg,
+            states_file_path,
+        )
+        .await?;
+        let Some(type_map_opt) = type_map_opt else {
+            return Ok(None);
+        };
+
+        let states_timestamps = Self::timestamps_deserialize_opt(storage, states_file_path)
+            .await?
+            .unwrap_or_default();
 
-        Ok(states_opt)
+        Ok(Some(
+            States::from(type_map_opt.into_type_map()).with_timestamps(states_timestamps),
+        ))
     }
 
-    /// Returns the [`States`] of all [`Item`]s if it exists on disk.
+    /// Writes when each state was discovered to the [`StatesTimestampsFile`]
+    /// for the states file.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to write to.
+    /// * `states_timestamps`: When each state was discovered.
+    /// * `states_file_path`: Path of the states file the timestamps are for.
+    async fn timestamps_serialize(
+        storage: &Storage,
+        states_timestamps: &StatesTimestamps,
+        states_file_path: &Path,
+    ) -> Result<(), E> {
+        let states_timestamps_file = StatesTimestampsFile::from(states_file_path);
+        storage
+            .serialized_write_atomic(
+                #[cfg(not(target_arch = "wasm32"))]
+                "StatesSerializer::timestamps_serialize".to_string(),
+                &states_timestamps_file,
+                states_timestamps,
+                Error::StatesTimestampsSerialize,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Returns when each state was discovered from the
+    /// [`StatesTimestampsFile`] for the states file, if it exists.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to read from.
+    /// * `states_file_path`: Path of the states file the timestamps are for.
+    async fn timestamps_deserialize_opt(
+        storage: &Storage,
+        states_file_path: &Path,
+    ) -> Result<Option<StatesTimestamps>, E> {
+        let states_timestamps_file = StatesTimestampsFile::from(states_file_path);
+        let states_timestamps = storage
+            .serialized_read_opt(
+                #[cfg(not(target_arch = "wasm32"))]
+                "StatesSerializer::timestamps_deserialize_opt".to_string(),
+                &states_timestamps_file,
+                |error, _| Error::StatesTimestampsDeserialize(error),
+            )
+            .await?;
+
+        Ok(states_timestamps)
+    }
+
+    /// Returns the states of all [`Item`]s if it exists on disk, including
+    /// entries for items that are not in the type registry.
     ///
     /// # Parameters:
     ///
     /// * `storage`: `Storage` to read from.
     /// * `states_type_reg`: Type registry with functions to deserialize each
     ///   item state.
-    /// * `states_current_file`: `StatesCurrentFile` to deserialize.
+    /// * `states_file_path`: Path to the states file to deserialize.
     ///
-    /// # Type Parameters
+    /// [`Item`]: peace_cfg::Item
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn deserialize_type_map_opt(
+        thread_name: String,
+        flow_id: &FlowId,
+        storage: &Storage,
+        states_type_reg: &TypeReg<ItemId, BoxDtDisplay>,
+        states_file_path: &Path,
+    ) -> Result<Option<StatesTypeMapOpt>, E> {
+        let storage_format = storage.storage_format();
+        let type_map_opt = storage
+            .formatted_typemap_read_opt(
+                thread_name,
+                states_type_reg,
+                states_file_path,
+                |error, file_contents| {
+                    Self::states_deserialize_error(
+                        flow_id,
+                        storage_format,
+                        states_file_path,
+                        file_contents,
+                        error,
+                    )
+                },
+            )
+            .await?;
+
+        Ok(type_map_opt)
+    }
+
+    /// Returns the states of all [`Item`]s if it exists on disk, including
+    /// entries for items that are not in the type registry.
     ///
-    /// * `TS`: The states type state to use, such as [`ts::Current`] or
-    ///   [`ts::CurrentStored`].
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to read from.
+    /// * `states_type_reg`: Type registry with functions to deserialize each
+    ///   item state.
+    /// * `states_file_path`: Path to the states file to deserialize.
     ///
     /// [`Item`]: peace_cfg::Item
-    /// [`ts::Current`]: peace_resources::states::ts::Current
-    /// [`ts::CurrentStored`]: peace_resources::states::ts::CurrentStored
     #[cfg(target_arch = "wasm32")]
-    async fn deserialize_internal<TS>(
+    async fn deserialize_type_map_opt(
         flow_id: &FlowId,
         storage: &Storage,
         states_type_reg: &TypeReg<ItemId, BoxDtDisplay>,
         states_file_path: &Path,
-    ) -> Result<Option<States<TS>>, E>
-    where
-        TS: Send + Sync,
-    {
-        let states_opt = storage
-            .serialized_typemap_read_opt(states_type_reg, states_file_path, |error| {
-                #[cfg(not(feature = "error_reporting"))]
-                {
-                    Error::StatesDeserialize {
-                        flow_id: flow_id.clone(),
-                        error,
-                    }
-                }
-                #[cfg(feature = "error_reporting")]
-                {
-                    use miette::NamedSource;
-
-                    let file_contents = std::fs::read_to_string(states_file_path).unwrap();
-
-                    let (error_span, error_message, context_span) =
-                        crate::yaml_error_context_hack::error_and_context(&file_contents, &error);
-                    let states_file_source =
-                        NamedSource::new(states_file_path.to_string_lossy(), file_contents);
-
-                    Error::StatesDeserialize {
-                        flow_id: flow_id.clone(),
-                        states_file_source,
-                        error_span,
-                        error_message,
-                        context_span,
+    ) -> Result<Option<StatesTypeMapOpt>, E> {
+        let storage_format = storage.storage_format();
+        let type_map_opt = storage
+            .formatted_typemap_read_opt(
+                states_type_reg,
+                states_file_path,
+                |error, file_contents| {
+                    Self::states_deserialize_error(
+                        flow_id,
+                        storage_format,
+                        states_file_path,
+                        file_contents,
                         error,
-                    }
-                }
-            })
-            .await
-            .map(|type_map_opt| {
-                type_map_opt
-                    .map(TypeMapOpt::into_type_map)
-                    .map(States::from)
-            })?;
+                    )
+                },
+            )
+            .await?;
+
+        Ok(type_map_opt)
+    }
+
+    /// Returns the error for when states fail to be serialized.
+    fn states_serialize_error(storage_format: StorageFormat, error: StorageFormatError) -> Error {
+        match error {
+            StorageFormatError::Yaml(error) => Error::StatesSerialize(error),
+            error => Error::StatesSerializeFormat {
+                storage_format,
+                error,
+            },
+        }
+    }
+
+    /// Returns the error for when states fail to be deserialized.
+    ///
+    /// YAML errors include the location of the error within `file_contents`,
+    /// which are the decrypted and decompressed contents that were parsed.
+    fn states_deserialize_error(
+        flow_id: &FlowId,
+        storage_format: StorageFormat,
+        #[cfg_attr(not(feature = "error_reporting"), allow(unused_variables))]
+        states_file_path: &Path,
+        #[cfg_attr(not(feature = "error_reporting"), allow(unused_variables))]
+        file_contents: &[u8],
+        error: StorageFormatError,
+    ) -> Error {
+        let error = match error {
+            StorageFormatError::Yaml(error) => error,
+            error => {
+                return Error::StatesDeserializeFormat {
+                    flow_id: flow_id.clone(),
+                    storage_format,
+                    error,
+                };
+            }
+        };
+
+        #[cfg(not(feature = "error_reporting"))]
+        {
+            Error::StatesDeserialize {
+                flow_id: flow_id.clone(),
+                error,
+            }
+        }
+        #[cfg(feature = "error_reporting")]
+        {
+            use miette::NamedSource;
+
+            let file_contents = String::from_utf8_lossy(file_contents).into_owned();
+
+            let (error_span, error_message, context_span) =
+                crate::yaml_error_context_hack::error_and_context(&file_contents, &error);
+            let states_file_source =
+                NamedSource::new(states_file_path.to_string_lossy(), file_contents);
 
-        Ok(states_opt)
+            Error::StatesDeserialize {
+                flow_id: flow_id.clone(),
+                states_file_source,
+                error_span,
+                error_message,
+                context_span,
+                error,
+            }
+        }
     }
 }
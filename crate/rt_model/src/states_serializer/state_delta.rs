@@ -0,0 +1,16 @@
+use peace_resources::type_reg::untagged::BoxDtDisplay;
+use serde::Serialize;
+
+/// State of an item when serializing states as a delta.
+///
+/// Changed states are serialized when the states file is written rather than
+/// beforehand, so that `Sensitive` fields are serialized within the storage's
+/// sensitive field scope.
+#[derive(Serialize)]
+#[serde(untagged)]
+pub(crate) enum StateDelta<'state> {
+    /// State copied from the existing states file.
+    Unchanged(serde_yaml::Value),
+    /// State that differs from its entry in the existing states file.
+    Changed(Option<&'state BoxDtDisplay>),
+}
@@ -0,0 +1,72 @@
+use std::cmp::Ordering;
+
+use serde::{Serialize, Serializer};
+use serde_yaml::Value;
+
+use crate::StatesOrdering;
+
+/// Serializes states in the given [`StatesOrdering`].
+///
+/// Entries are sorted when this is serialized rather than beforehand, so that
+/// `Sensitive` fields are serialized within the storage's sensitive field
+/// scope.
+pub(crate) struct StatesOrdered<'states, T> {
+    /// Order to serialize the entries in.
+    states_ordering: StatesOrdering,
+    /// States to serialize.
+    states: &'states T,
+}
+
+impl<'states, T> StatesOrdered<'states, T> {
+    /// Returns a new `StatesOrdered`.
+    pub(crate) fn new(states_ordering: StatesOrdering, states: &'states T) -> Self {
+        Self {
+            states_ordering,
+            states,
+        }
+    }
+}
+
+impl<T> Serialize for StatesOrdered<'_, T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.states_ordering {
+            StatesOrdering::Graph => self.states.serialize(serializer),
+            StatesOrdering::Lexicographic => {
+                let mut value =
+                    serde_yaml::to_value(self.states).map_err(serde::ser::Error::custom)?;
+                value_keys_sort(&mut value);
+                value.serialize(serializer)
+            }
+        }
+    }
+}
+
+/// Sorts the keys of all mappings within `value`.
+///
+/// Sequences keep their order, as the order of elements is significant.
+fn value_keys_sort(value: &mut Value) {
+    match value {
+        Value::Mapping(mapping) => {
+            let mut entries = std::mem::take(mapping).into_iter().collect::<Vec<_>>();
+            entries.sort_by(|(key_a, _), (key_b, _)| {
+                key_a.partial_cmp(key_b).unwrap_or(Ordering::Equal)
+            });
+            *mapping = entries
+                .into_iter()
+                .map(|(key, mut value)| {
+                    value_keys_sort(&mut value);
+                    (key, value)
+                })
+                .collect();
+        }
+        Value::Sequence(sequence) => sequence.iter_mut().for_each(value_keys_sort),
+        Value::Tagged(tagged_value) => value_keys_sort(&mut tagged_value.value),
+        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => {}
+    }
+}
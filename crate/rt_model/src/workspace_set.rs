@@ -0,0 +1,73 @@
+use std::ops::Deref;
+
+use indexmap::IndexMap;
+
+use crate::Workspace;
+
+/// Named [`Workspace`]s to run the same command in, e.g. one per region or
+/// tenant.
+///
+/// Workspaces are kept in insertion order, which is the order that outcomes
+/// are returned in by [`MultiWorkspaceCmd`].
+///
+/// This derefs to the map of workspace name to [`Workspace`].
+///
+/// [`MultiWorkspaceCmd`]: https://docs.rs/peace_rt/latest/peace_rt/cmds/struct.MultiWorkspaceCmd.html
+#[derive(Clone, Debug, Default)]
+pub struct WorkspaceSet(IndexMap<String, Workspace>);
+
+impl WorkspaceSet {
+    /// Returns a new empty `WorkspaceSet`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a workspace to this set, replacing any workspace with the same
+    /// name.
+    pub fn with_workspace(
+        mut self,
+        workspace_name: impl Into<String>,
+        workspace: Workspace,
+    ) -> Self {
+        self.insert(workspace_name, workspace);
+        self
+    }
+
+    /// Inserts a workspace into this set, returning the workspace previously
+    /// inserted with the same name.
+    pub fn insert(
+        &mut self,
+        workspace_name: impl Into<String>,
+        workspace: Workspace,
+    ) -> Option<Workspace> {
+        self.0.insert(workspace_name.into(), workspace)
+    }
+
+    /// Returns the underlying map.
+    pub fn into_inner(self) -> IndexMap<String, Workspace> {
+        self.0
+    }
+}
+
+impl Deref for WorkspaceSet {
+    type Target = IndexMap<String, Workspace>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<IndexMap<String, Workspace>> for WorkspaceSet {
+    fn from(workspaces: IndexMap<String, Workspace>) -> Self {
+        Self(workspaces)
+    }
+}
+
+impl FromIterator<(String, Workspace)> for WorkspaceSet {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (String, Workspace)>,
+    {
+        Self(IndexMap::from_iter(iter))
+    }
+}
@@ -0,0 +1,108 @@
+use std::fmt::{self, Write};
+
+use peace_fmt::{messages, MessageCatalog};
+
+use crate::ItemErrorReport;
+
+/// Errors of all items that failed during a command's execution.
+///
+/// This is constructed from a `CmdOutcome` using [`CmdOutcomeReporter`], and
+/// renders each failed item's ID, error chain, and the state the item managed
+/// to reach as a single diagnostic.
+///
+/// [`CmdOutcomeReporter`]: crate::CmdOutcomeReporter
+#[cfg_attr(feature = "error_reporting", derive(miette::Diagnostic))]
+#[cfg_attr(
+    feature = "error_reporting",
+    diagnostic(code(peace_rt_model::cmd_outcome_report))
+)]
+#[derive(Debug, thiserror::Error)]
+#[error("{}", items_failed_message(.item_error_reports.len()))]
+pub struct CmdOutcomeReport {
+    /// Errors of each item that failed.
+    #[cfg_attr(feature = "error_reporting", related)]
+    item_error_reports: Vec<ItemErrorReport>,
+}
+
+impl CmdOutcomeReport {
+    /// Returns a new `CmdOutcomeReport`.
+    pub fn new(item_error_reports: Vec<ItemErrorReport>) -> Self {
+        Self { item_error_reports }
+    }
+
+    /// Returns the errors of each item that failed.
+    pub fn item_error_reports(&self) -> &[ItemErrorReport] {
+        &self.item_error_reports
+    }
+
+    /// Returns the errors of each item that failed.
+    pub fn into_item_error_reports(self) -> Vec<ItemErrorReport> {
+        self.item_error_reports
+    }
+
+    /// Writes this report as plain text, one item per section.
+    ///
+    /// This is used when `miette` is not available to render the report.
+    pub fn fmt_text<W>(&self, w: &mut W) -> fmt::Result
+    where
+        W: Write,
+    {
+        self.fmt_text_localized(w, &MessageCatalog::new())
+    }
+
+    /// Writes this report as plain text, with its labels rendered through the
+    /// given catalog.
+    pub fn fmt_text_localized<W>(&self, w: &mut W, message_catalog: &MessageCatalog) -> fmt::Result
+    where
+        W: Write,
+    {
+        let item_count = self.item_error_reports.len().to_string();
+        let items_failed = if self.item_error_reports.len() == 1 {
+            message_catalog.text(messages::REPORT_ITEM_FAILED, &[])
+        } else {
+            message_catalog.text(
+                messages::REPORT_ITEMS_FAILED,
+                &[("count", item_count.as_str())],
+            )
+        };
+        writeln!(w, "{items_failed}")?;
+        self.item_error_reports
+            .iter()
+            .try_for_each(|item_error_report| {
+                writeln!(w)?;
+                writeln!(w, "{item_error_report}")?;
+
+                let mut cause = item_error_report.cause();
+                while let Some(error_cause) = cause {
+                    let caused_by = message_catalog.text(
+                        messages::REPORT_CAUSED_BY,
+                        &[("cause", error_cause.message())],
+                    );
+                    writeln!(w, "  {caused_by}")?;
+                    cause = error_cause.cause();
+                }
+
+                item_error_report.help().iter().try_for_each(|help| {
+                    let help =
+                        message_catalog.text(messages::REPORT_HELP, &[("help", help.as_str())]);
+                    writeln!(w, "  {help}")
+                })?;
+
+                if let Some(state) = item_error_report.state() {
+                    let state_reached =
+                        message_catalog.text(messages::REPORT_STATE_REACHED, &[("state", state)]);
+                    writeln!(w, "  {state_reached}")?;
+                }
+
+                Ok(())
+            })
+    }
+}
+
+fn items_failed_message(item_count: usize) -> String {
+    if item_count == 1 {
+        messages::REPORT_ITEM_FAILED.text(&[])
+    } else {
+        messages::REPORT_ITEMS_FAILED.text(&[("count", item_count.to_string().as_str())])
+    }
+}
@@ -0,0 +1,110 @@
+use peace_cmd_model::CmdOutcome;
+use peace_core::ItemId;
+use peace_resources::states::States;
+
+use crate::{CmdOutcomeReport, ItemErrorReport};
+
+/// Converts the item errors in a `CmdOutcome` into a [`CmdOutcomeReport`].
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let states_ensured_outcome = EnsureCmd::exec(&mut cmd_ctx).await?;
+/// if let Some(cmd_outcome_report) = CmdOutcomeReporter::report(&states_ensured_outcome) {
+///     let error = AppError::from(peace::rt_model::Error::from(cmd_outcome_report));
+///     output.write_err(&error).await?;
+/// }
+/// ```
+#[derive(Debug)]
+pub struct CmdOutcomeReporter;
+
+impl CmdOutcomeReporter {
+    /// Returns a report of the item errors in the `CmdOutcome`, including the
+    /// state each failed item managed to reach.
+    ///
+    /// Returns `None` if the `CmdOutcome` is not `CmdOutcome::ItemError`.
+    pub fn report<TS, E>(cmd_outcome: &CmdOutcome<States<TS>, E>) -> Option<CmdOutcomeReport>
+    where
+        E: std::error::Error,
+    {
+        Self::report_with(cmd_outcome, |states, item_id| {
+            states.get_raw(item_id).map(|state| state.to_string())
+        })
+    }
+
+    /// Returns a report of the item errors in the `CmdOutcome`, including the
+    /// state each failed item managed to reach, and suggestions to recover
+    /// from each error.
+    ///
+    /// `error_help_fn` is usually `|item_id, error| flow.error_help(item_id,
+    /// error)`, which returns the suggestions from [`Item::error_help`].
+    ///
+    /// Returns `None` if the `CmdOutcome` is not `CmdOutcome::ItemError`.
+    ///
+    /// [`Item::error_help`]: https://docs.rs/peace_cfg/latest/peace_cfg/trait.Item.html#method.error_help
+    pub fn report_with_error_help<TS, E, F>(
+        cmd_outcome: &CmdOutcome<States<TS>, E>,
+        error_help_fn: F,
+    ) -> Option<CmdOutcomeReport>
+    where
+        E: std::error::Error,
+        F: Fn(&ItemId, &E) -> Vec<String>,
+    {
+        let cmd_outcome_report = Self::report(cmd_outcome)?;
+        let CmdOutcome::ItemError { errors, .. } = cmd_outcome else {
+            return None;
+        };
+
+        let item_error_reports = cmd_outcome_report
+            .into_item_error_reports()
+            .into_iter()
+            .map(|item_error_report| {
+                let help = errors
+                    .get(item_error_report.item_id())
+                    .map(|error| error_help_fn(item_error_report.item_id(), error))
+                    .unwrap_or_default();
+                item_error_report.with_help(help)
+            })
+            .collect::<Vec<_>>();
+
+        Some(CmdOutcomeReport::new(item_error_reports))
+    }
+
+    /// Returns a report of the item errors in the `CmdOutcome`, using
+    /// `state_fn` to look up the state each failed item managed to reach.
+    ///
+    /// This is useful when the `CmdOutcome`'s value is not a single `States`,
+    /// such as `(StatesCurrent, StatesGoal)`.
+    ///
+    /// Returns `None` if the `CmdOutcome` is not `CmdOutcome::ItemError`.
+    pub fn report_with<T, E, F>(
+        cmd_outcome: &CmdOutcome<T, E>,
+        state_fn: F,
+    ) -> Option<CmdOutcomeReport>
+    where
+        E: std::error::Error,
+        F: Fn(&T, &ItemId) -> Option<String>,
+    {
+        let CmdOutcome::ItemError {
+            item_stream_outcome,
+            cmd_blocks_processed: _,
+            cmd_blocks_not_processed: _,
+            errors,
+            item_log_paths,
+        } = cmd_outcome
+        else {
+            return None;
+        };
+
+        let value = item_stream_outcome.value();
+        let item_error_reports = errors
+            .iter()
+            .map(|(item_id, error)| {
+                ItemErrorReport::new(item_id.clone(), error, state_fn(value, item_id))
+                    .with_log_path(item_log_paths.get(item_id).cloned())
+            })
+            .collect::<Vec<_>>();
+
+        Some(CmdOutcomeReport::new(item_error_reports))
+    }
+}
@@ -1,6 +1,6 @@
 use indexmap::IndexMap;
 use indicatif::MultiProgress;
-use peace_core::{progress::ProgressTracker, ItemId};
+use peace_core::{progress::ProgressTracker, ItemGroupId, ItemId};
 
 /// Tracks command execution progress for all items.
 ///
@@ -19,6 +19,8 @@ pub struct CmdProgressTracker {
     pub multi_progress: MultiProgress,
     /// Tracks progress for each item.
     pub progress_trackers: IndexMap<ItemId, ProgressTracker>,
+    /// Groups of items whose progress is rendered as one unit.
+    pub item_groups: IndexMap<ItemGroupId, Vec<ItemId>>,
 }
 
 impl CmdProgressTracker {
@@ -30,9 +32,16 @@ impl CmdProgressTracker {
         Self {
             multi_progress,
             progress_trackers,
+            item_groups: IndexMap::new(),
         }
     }
 
+    /// Sets the groups of items whose progress is rendered as one unit.
+    pub fn with_item_groups(mut self, item_groups: IndexMap<ItemGroupId, Vec<ItemId>>) -> Self {
+        self.item_groups = item_groups;
+        self
+    }
+
     /// Returns the `MultiProgress` that tracks the remaining progress bars.
     pub fn multi_progress(&self) -> &MultiProgress {
         &self.multi_progress
@@ -54,4 +63,9 @@ impl CmdProgressTracker {
     pub fn progress_trackers_mut(&mut self) -> &mut IndexMap<ItemId, ProgressTracker> {
         &mut self.progress_trackers
     }
+
+    /// Returns the groups of items whose progress is rendered as one unit.
+    pub fn item_groups(&self) -> &IndexMap<ItemGroupId, Vec<ItemId>> {
+        &self.item_groups
+    }
 }
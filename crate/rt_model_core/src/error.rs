@@ -1,14 +1,21 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, time::Duration};
 
+use indexmap::IndexMap;
 use peace_cmd_model::CmdExecutionError;
-use peace_core::{FlowId, ItemId, Profile};
-use peace_params::{ParamsResolveError, ParamsSpecs};
+use peace_core::{DiffSeverity, FlowId, ItemGroupId, ItemId, Profile};
+use peace_params::{ParamsResolveError, ParamsSpecs, ParamsValidationError};
 use peace_resources::paths::ParamsSpecsFile;
 
-pub use self::{apply_cmd_error::ApplyCmdError, state_downcast_error::StateDowncastError};
+use crate::{CmdOutcomeReport, PreflightCheckFailure, StorageCompression, StorageFormat};
+
+pub use self::{
+    apply_cmd_error::ApplyCmdError, state_downcast_error::StateDowncastError,
+    storage_format_error::StorageFormatError,
+};
 
 mod apply_cmd_error;
 mod state_downcast_error;
+mod storage_format_error;
 
 cfg_if::cfg_if! {
     if #[cfg(not(target_arch = "wasm32"))] {
@@ -55,6 +62,68 @@ pub enum Error {
         CmdExecutionError,
     ),
 
+    /// A `CmdBlock` did not complete within its configured timeout.
+    #[error(
+        "`{cmd_block_name}` did not complete within {timeout:?}.\n\
+        Inputs: {input_type_names:?}"
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::cmd_block_timeout),
+            help(
+                "Check whether the items run by this block are waiting on an unreachable resource,\n\
+                or increase the timeout passed to `CmdExecutionBuilder::with_cmd_block_timeout`."
+            )
+        )
+    )]
+    CmdBlockTimeout {
+        /// Short name of the command block that timed out.
+        cmd_block_name: String,
+        /// Short type names of the command block's `CmdBlock::InputT`.
+        input_type_names: Vec<String>,
+        /// The timeout that elapsed.
+        timeout: Duration,
+    },
+
+    /// A `CmdBlock` was still running when the execution deadline's grace
+    /// period elapsed.
+    #[error(
+        "`{cmd_block_name}` was cancelled as it did not complete within {grace_period:?} after the execution deadline.\n\
+        Inputs: {input_type_names:?}"
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::execution_deadline_exceeded),
+            help(
+                "Items that were in progress may not have completed.\n\
+                Consider a later deadline, or a longer grace period passed to `ExecutionDeadline::with_grace_period`."
+            )
+        )
+    )]
+    ExecutionDeadlineExceeded {
+        /// Short name of the command block that was cancelled.
+        cmd_block_name: String,
+        /// Short type names of the command block's `CmdBlock::InputT`.
+        input_type_names: Vec<String>,
+        /// The grace period that elapsed after the deadline.
+        grace_period: Duration,
+    },
+
+    /// One or more items failed during command execution.
+    #[error("One or more items failed during command execution.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::cmd_outcome_item_errors))
+    )]
+    CmdOutcomeItemErrors(
+        #[cfg_attr(feature = "error_reporting", diagnostic_source)]
+        #[source]
+        #[from]
+        CmdOutcomeReport,
+    ),
+
     /// Failed to serialize error.
     #[error("Failed to serialize error.")]
     #[cfg_attr(
@@ -67,7 +136,11 @@ pub enum Error {
     ///
     /// This possibly indicates the user has provided a `Params::Spec` with
     /// `InMemory` or `MappingFn`, but no predecessor populates that type.
-    #[error("Failed to resolve values for a `Params` object from `resources`.")]
+    #[error(
+        "Failed to resolve params for item `{item_id}` in `{value_resolution_mode:?}` mode.",
+        item_id = .0.item_id(),
+        value_resolution_mode = .0.value_resolution_mode()
+    )]
     #[cfg_attr(
         feature = "error_reporting",
         diagnostic(
@@ -163,6 +236,56 @@ pub enum Error {
         params_specs_not_usable: Vec<ItemId>,
     },
 
+    /// A params spec reconciliation refers to an item that is not in the
+    /// flow.
+    #[error("Item `{item_id}` is not in the flow, so params specs cannot be reconciled to it.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::params_reconcile_item_id_not_in_flow),
+            help("Check that the item ID matches an item in the flow.")
+        )
+    )]
+    ParamsReconcileItemIdNotInFlow {
+        /// ID of the item that is not in the flow.
+        item_id: ItemId,
+    },
+
+    /// A params spec reconciliation renames an item ID that does not have a
+    /// stored params spec.
+    #[error("No stored params spec exists for item `{item_id}` to be renamed.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::params_reconcile_item_id_not_stored),
+            help("Check that the item ID matches a stored params spec that is not in the flow.")
+        )
+    )]
+    ParamsReconcileItemIdNotStored {
+        /// ID of the item that does not have a stored params spec.
+        item_id: ItemId,
+    },
+
+    /// A params spec reconciliation renames a params spec to an item that
+    /// already has a params spec.
+    #[error(
+        "Cannot rename the params spec for `{item_id_from}` to `{item_id_to}`, \
+        as `{item_id_to}` already has a params spec."
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::params_reconcile_rename_conflict),
+            help("Drop one of the params specs instead of renaming it.")
+        )
+    )]
+    ParamsReconcileRenameConflict {
+        /// ID of the item whose params spec is renamed.
+        item_id_from: ItemId,
+        /// ID of the item that already has a params spec.
+        item_id_to: ItemId,
+    },
+
     /// In a `MultiProfileSingleFlow` diff, neither profile had `Params::Specs`
     /// defined.
     #[error("Params specifications not defined for `{profile_a}` or `{profile_b}`.")]
@@ -183,6 +306,252 @@ pub enum Error {
         profile_b: Profile,
     },
 
+    /// Resolved item params failed validation.
+    ///
+    /// Validation functions are provided through
+    /// `ParamsSpec::with_validation`, `#[params(validate = "..")]`, or
+    /// `#[params(validate_with = "..")]`.
+    #[error(
+        "Item params failed validation:\n\n{}",
+        params_validation_display(item_params_validation_errors)
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::params_validation),
+            help("Update the params provided for the items listed above.")
+        )
+    )]
+    ParamsValidation {
+        /// Validation failures for each item whose params are invalid.
+        item_params_validation_errors: IndexMap<ItemId, Vec<ParamsValidationError>>,
+    },
+
+    /// Item params changed since the items' current states were stored.
+    ///
+    /// This is only returned when the command context is built with
+    /// [`ParamsChangePolicy::Fail`].
+    ///
+    /// [`ParamsChangePolicy::Fail`]: crate::ParamsChangePolicy::Fail
+    #[error(
+        "Params changed since the stored state of these items was recorded:\n\n{}",
+        item_ids_display(item_ids)
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::params_changed),
+            help(
+                "Revert the params, or discover the current states to record them with the new params."
+            )
+        )
+    )]
+    ParamsChanged {
+        /// IDs of items whose params changed.
+        item_ids: Vec<ItemId>,
+    },
+
+    /// One or more of the flow's preflight checks failed.
+    ///
+    /// Preflight checks are run before states are discovered or items are
+    /// applied, so no items were changed.
+    #[error(
+        "Preflight checks failed:\n\n{}",
+        preflight_check_failures_display(preflight_check_failures)
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::preflight_checks_failed),
+            help("Resolve the failed checks, then run the command again.")
+        )
+    )]
+    PreflightChecksFailed {
+        /// The checks that failed.
+        preflight_check_failures: Vec<PreflightCheckFailure>,
+    },
+
+    /// An item requires approval to be applied, but no approver is set.
+    ///
+    /// This is returned when the command context is built with an
+    /// `ApprovalPolicy` that applies to this item, and is run
+    /// non-interactively.
+    #[error("Applying `{item_id}` requires approval, as its change is {diff_severity}.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::approval_required),
+            help(
+                "Run the command interactively to approve the change, \
+                or update the approval policy to allow this change."
+            )
+        )
+    )]
+    ApprovalRequired {
+        /// ID of the item that requires approval.
+        item_id: ItemId,
+        /// How disruptive it is to apply the item's state diff.
+        diff_severity: DiffSeverity,
+    },
+
+    /// Applying an item was denied by the `ApprovalPolicy`'s approver.
+    #[error("Applying `{item_id}` was not approved. Its change is {diff_severity}.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::approval_denied))
+    )]
+    ApprovalDenied {
+        /// ID of the item whose apply was denied.
+        item_id: ItemId,
+        /// How disruptive it is to apply the item's state diff.
+        diff_severity: DiffSeverity,
+    },
+
+    /// An item did not send a progress update within the heartbeat interval,
+    /// and was cancelled.
+    #[error(
+        "Applying `{item_id}` stalled, as no progress was received within {}s.",
+        interval.as_secs_f64()
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::item_apply_stalled),
+            help(
+                "Check whether the item is waiting on an unresponsive service, \
+                or increase the heartbeat interval if the item is slow to send progress."
+            )
+        )
+    )]
+    ItemApplyStalled {
+        /// ID of the item that stalled.
+        item_id: ItemId,
+        /// Maximum duration between progress updates.
+        interval: Duration,
+    },
+
+    /// Failed to serialize states timestamps.
+    #[error("Failed to serialize states timestamps.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::states_timestamps_serialize))
+    )]
+    StatesTimestampsSerialize(#[source] serde_yaml::Error),
+
+    /// Failed to deserialize states timestamps.
+    #[error("Failed to deserialize states timestamps.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::states_timestamps_deserialize),
+            help("The states timestamps file may be from an incompatible version, and can be deleted.")
+        )
+    )]
+    StatesTimestampsDeserialize(#[source] serde_yaml::Error),
+
+    /// Failed to serialize params digests.
+    #[error("Failed to serialize params digests.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::params_digests_serialize))
+    )]
+    ParamsDigestsSerialize(#[source] serde_yaml::Error),
+
+    /// Failed to deserialize params digests.
+    #[error("Failed to deserialize params digests.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::params_digests_deserialize),
+            help("The params digests file may be from an incompatible version, and can be deleted.")
+        )
+    )]
+    ParamsDigestsDeserialize(#[source] serde_yaml::Error),
+
+    /// Failed to serialize items disabled.
+    #[error("Failed to serialize items disabled.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::items_disabled_serialize))
+    )]
+    ItemsDisabledSerialize(#[source] serde_yaml::Error),
+
+    /// Failed to deserialize items disabled.
+    #[error("Failed to deserialize items disabled.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::items_disabled_deserialize),
+            help("Make sure the items disabled file is a list of item IDs.")
+        )
+    )]
+    ItemsDisabledDeserialize(#[source] serde_yaml::Error),
+
+    /// Failed to serialize params history.
+    #[error("Failed to serialize params history.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::params_history_serialize))
+    )]
+    ParamsHistorySerialize(#[source] serde_yaml::Error),
+
+    /// Failed to deserialize params history.
+    #[error("Failed to deserialize params history.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::params_history_deserialize),
+            help("The params history file may be from an incompatible version, and can be deleted.")
+        )
+    )]
+    ParamsHistoryDeserialize(#[source] serde_yaml::Error),
+
+    /// Failed to deserialize the params of a params revision.
+    #[error("Failed to deserialize the params of params revision {revision}.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::params_revision_deserialize))
+    )]
+    ParamsRevisionDeserialize {
+        /// Number of the revision.
+        revision: u64,
+        /// Underlying error.
+        #[source]
+        error: serde_yaml::Error,
+    },
+
+    /// Params revision does not exist in the profile's params history.
+    #[error("Params revision {revision} does not exist in the params history.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::params_revision_not_found),
+            help("Revisions may have been pruned, list the available revisions with `ParamsHistoryCmd::list`.")
+        )
+    )]
+    ParamsRevisionNotFound {
+        /// Number of the revision.
+        revision: u64,
+    },
+
+    /// Params revision was recorded for a different flow.
+    #[error("Params revision {revision} was not recorded for flow `{flow_id}`.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::params_revision_flow_mismatch),
+            help("Select a revision that was recorded for the `{flow_id}` flow.")
+        )
+    )]
+    ParamsRevisionFlowMismatch {
+        /// Number of the revision.
+        revision: u64,
+        /// ID of the flow that the command context is built with.
+        flow_id: FlowId,
+        /// ID of the flow that the revision was recorded for.
+        flow_id_recorded: Option<FlowId>,
+    },
+
     /// Failed to serialize a presentable type.
     #[error("Failed to serialize a presentable type.")]
     #[cfg_attr(
@@ -251,6 +620,42 @@ pub enum Error {
     )]
     StatesSerialize(#[source] serde_yaml::Error),
 
+    /// Failed to deserialize states stored in a non-YAML `StorageFormat`.
+    #[error("Failed to deserialize {storage_format} states for flow: `{flow_id}`.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::states_deserialize_format),
+            help(
+                "Make sure that the workspace is read with the same `StorageFormat` that it was written with,\n\
+                and that all commands using the `{flow_id}` flow also use the same item graph."
+            )
+        )
+    )]
+    StatesDeserializeFormat {
+        /// Flow ID whose states are being deserialized.
+        flow_id: FlowId,
+        /// Format that the states were deserialized from.
+        storage_format: StorageFormat,
+        /// Underlying error.
+        #[source]
+        error: StorageFormatError,
+    },
+
+    /// Failed to serialize states in a non-YAML `StorageFormat`.
+    #[error("Failed to serialize states as {storage_format}.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::states_serialize_format))
+    )]
+    StatesSerializeFormat {
+        /// Format that the states were serialized to.
+        storage_format: StorageFormat,
+        /// Underlying error.
+        #[source]
+        error: StorageFormatError,
+    },
+
     /// Failed to deserialize params specs.
     #[error("Failed to deserialize params specs for `{profile}/{flow_id}`.")]
     #[cfg_attr(
@@ -361,6 +766,77 @@ pub enum Error {
     )]
     StateDiffsSerialize(#[source] serde_yaml::Error),
 
+    /// Failed to serialize execution progress.
+    #[error("Failed to serialize execution progress.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::execution_progress_serialize))
+    )]
+    ExecutionProgressSerialize(#[source] serde_yaml::Error),
+
+    /// Failed to deserialize execution progress.
+    #[error("Failed to deserialize execution progress.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::execution_progress_deserialize),
+            help("The execution progress file may be from an incompatible version, and can be deleted.")
+        )
+    )]
+    ExecutionProgressDeserialize(#[source] serde_yaml::Error),
+
+    /// Failed to serialize execution timeline.
+    #[error("Failed to serialize execution timeline.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::execution_timeline_serialize))
+    )]
+    ExecutionTimelineSerialize(#[source] serde_yaml::Error),
+
+    /// Failed to deserialize execution timeline.
+    #[error("Failed to deserialize execution timeline.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::execution_timeline_deserialize),
+            help("The execution timeline file may be from an incompatible version, and can be deleted.")
+        )
+    )]
+    ExecutionTimelineDeserialize(#[source] serde_yaml::Error),
+
+    /// Failed to serialize execution history.
+    #[error("Failed to serialize execution history.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::execution_history_serialize))
+    )]
+    ExecutionHistorySerialize(#[source] serde_yaml::Error),
+
+    /// Failed to deserialize execution history.
+    #[error("Failed to deserialize execution history.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::execution_history_deserialize),
+            help("The execution history file may be from an incompatible version, and can be deleted.")
+        )
+    )]
+    ExecutionHistoryDeserialize(#[source] serde_yaml::Error),
+
+    /// Execution does not exist in the flow's execution history.
+    #[error("Execution {execution_id} does not exist in the execution history.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::execution_not_found),
+            help("Executions may have been pruned, list the available executions with `StatesCheckoutCmd::list`.")
+        )
+    )]
+    ExecutionNotFound {
+        /// ID of the execution.
+        execution_id: u64,
+    },
+
     /// Failed to serialize error as JSON.
     #[error("Failed to serialize error as JSON.")]
     #[cfg_attr(
@@ -385,6 +861,130 @@ pub enum Error {
     )]
     StateDiffsSerializeJson(#[source] serde_json::Error),
 
+    /// No state exists for the item being queried.
+    #[error("No state exists for item: `{item_id}`.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::state_query_item_not_found),
+            help(
+                "Check that `{item_id}` is an item in the flow, and that its state has been discovered."
+            )
+        )
+    )]
+    StateQueryItemNotFound {
+        /// ID of the item whose state was queried.
+        item_id: ItemId,
+    },
+
+    /// Failed to serialize queried state as JSON.
+    #[error("Failed to serialize state for item `{item_id}` as JSON.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::state_query_serialize_json))
+    )]
+    StateQuerySerializeJson {
+        /// ID of the item whose state was queried.
+        item_id: ItemId,
+        /// Underlying error.
+        #[source]
+        error: serde_json::Error,
+    },
+
+    /// Queried field does not exist in the item's state.
+    #[error("Field `{field_pointer}` does not exist in the state for item: `{item_id}`.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::state_query_field_not_found),
+            help("Fields are JSON pointers, e.g. `/ip_addr` or `/servers/0/name`.")
+        )
+    )]
+    StateQueryFieldNotFound {
+        /// ID of the item whose state was queried.
+        item_id: ItemId,
+        /// JSON pointer to the field that was queried.
+        field_pointer: String,
+    },
+
+    /// Failed to serialize states archive.
+    #[error("Failed to serialize states archive: `{}`.", archive_path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::states_archive_serialize))
+    )]
+    StatesArchiveSerialize {
+        /// Path to the archive file.
+        archive_path: PathBuf,
+        /// Underlying error.
+        #[source]
+        error: serde_yaml::Error,
+    },
+
+    /// Failed to deserialize states archive.
+    #[error("Failed to deserialize states archive: `{}`.", archive_path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::states_archive_deserialize),
+            help("Check that the archive was exported by the same version of the application.")
+        )
+    )]
+    StatesArchiveDeserialize {
+        /// Path to the archive file.
+        archive_path: PathBuf,
+        /// Underlying error.
+        #[source]
+        error: serde_yaml::Error,
+    },
+
+    /// States archive was exported from a different flow.
+    #[error(
+        "States archive `{}` was exported from flow `{flow_id_archive}`, but is being imported into flow `{flow_id}`.",
+        archive_path.display()
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::states_archive_flow_id_mismatch),
+            help("Import the archive into a command context for flow `{flow_id_archive}`.")
+        )
+    )]
+    StatesArchiveFlowIdMismatch {
+        /// Path to the archive file.
+        archive_path: PathBuf,
+        /// ID of the flow the archive was exported from.
+        flow_id_archive: FlowId,
+        /// ID of the flow the archive is imported into.
+        flow_id: FlowId,
+    },
+
+    /// States archive contains items that are not compatible with the flow.
+    #[error(
+        "States archive `{}` is not compatible with the flow.\n\
+        Unknown items: {item_ids_unknown:?}\n\
+        Items with different state types: {item_ids_state_type_mismatch:?}",
+        archive_path.display()
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::states_archive_incompatible),
+            help(
+                "The archive's items must exist in the flow with the same `State` types.\n\
+                Export the archive from a workspace that uses the same version of the application."
+            )
+        )
+    )]
+    StatesArchiveIncompatible {
+        /// Path to the archive file.
+        archive_path: PathBuf,
+        /// IDs of archived items that are not in the flow.
+        item_ids_unknown: Vec<ItemId>,
+        /// IDs of archived items whose `State` type differs from the flow's.
+        item_ids_state_type_mismatch: Vec<ItemId>,
+    },
+
     /// Failed to serialize workspace init params.
     #[error("Failed to serialize workspace init params.")]
     #[cfg_attr(
@@ -401,6 +1001,46 @@ pub enum Error {
     )]
     WorkspaceParamsDeserialize(#[source] serde_yaml::Error),
 
+    /// Failed to compute a workspace parameter value.
+    ///
+    /// This is returned when a function passed to `with_workspace_param_fn`
+    /// returns an error.
+    #[error("Failed to compute workspace param `{key}`.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::workspace_param_fn_error),
+            help("Check the function passed to `with_workspace_param_fn` for `{key}`.")
+        )
+    )]
+    WorkspaceParamFnError {
+        /// Debug representation of the workspace param key.
+        key: String,
+        /// Underlying error.
+        #[source]
+        error: Box<dyn std::error::Error + Send + Sync + 'static>,
+    },
+
+    /// Failed to migrate a stored workspace parameter to its current type.
+    ///
+    /// This is returned when a value returned by a migration in the
+    /// `WorkspaceParamsRegistry` fails to be serialized.
+    #[error("Failed to migrate workspace param `{key}`.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::workspace_param_migrate),
+            help("Check the migration registered for `{key}`.")
+        )
+    )]
+    WorkspaceParamMigrate {
+        /// Debug representation of the workspace param key.
+        key: String,
+        /// Underlying error.
+        #[source]
+        error: serde_yaml::Error,
+    },
+
     /// Workspace params does not exist, so cannot look up `Profile`.
     #[error("Workspace params does not exist, so cannot look up `Profile`.")]
     #[cfg_attr(
@@ -445,6 +1085,229 @@ pub enum Error {
         profiles_in_scope: Vec<Profile>,
     },
 
+    /// Flow ID provided does not match any of the app's flows.
+    #[error("Flow `{flow_id}` does not exist.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::flow_id_not_found),
+            help(
+                "Make sure the flow ID is spelt correctly.\n\
+                Available flows are: [{flow_ids}]",
+                flow_ids = flow_ids
+                    .iter()
+                    .map(|flow_id| format!("{flow_id}"))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            )
+        )
+    )]
+    FlowIdNotFound {
+        /// The flow ID that was provided.
+        flow_id: FlowId,
+        /// The IDs of the app's flows.
+        flow_ids: Vec<FlowId>,
+    },
+
+    /// Flow ID was not provided, and the app has more than one flow.
+    #[error("Flow ID not provided, and it cannot be inferred as there are multiple flows.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::flow_id_not_specified),
+            help(
+                "Pass in the flow ID to use, e.g. `--flow <flow_id>`.\n\
+                Available flows are: [{flow_ids}]",
+                flow_ids = flow_ids
+                    .iter()
+                    .map(|flow_id| format!("{flow_id}"))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            )
+        )
+    )]
+    FlowIdNotSpecified {
+        /// The IDs of the app's flows.
+        flow_ids: Vec<FlowId>,
+    },
+
+    /// Failed to deserialize flow spec.
+    #[error("Failed to deserialize flow spec.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::flow_spec_deserialize))
+    )]
+    FlowSpecDeserialize(#[source] serde_yaml::Error),
+
+    /// Item ID is used by more than one item in a flow spec.
+    #[error("Item `{item_id}` is defined more than once in the flow spec.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::flow_spec_item_id_duplicate),
+            help("Make sure each item in the flow spec has a unique `id`.")
+        )
+    )]
+    FlowSpecItemIdDuplicate {
+        /// ID of the item that is defined more than once.
+        item_id: ItemId,
+    },
+
+    /// Item type in a flow spec has no registered constructor.
+    #[error("Item `{item_id}` has type `{item_type}`, which is not registered.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::flow_spec_item_type_not_registered),
+            help(
+                "Make sure the item type is spelt correctly.\n\
+                Registered item types are: [{item_types}]",
+                item_types = item_types_registered.join(",")
+            )
+        )
+    )]
+    FlowSpecItemTypeNotRegistered {
+        /// ID of the item whose type is not registered.
+        item_id: ItemId,
+        /// The item type that is not registered.
+        item_type: String,
+        /// The item types that are registered.
+        item_types_registered: Vec<String>,
+    },
+
+    /// Item in a flow spec depends on an item that is not in the flow spec.
+    #[error("Item `{item_id}` depends on `{dependency_id}`, which is not in the flow spec.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::flow_spec_item_dependency_not_found),
+            help("Make sure `{dependency_id}` is spelt correctly, and is defined in `items`.")
+        )
+    )]
+    FlowSpecItemDependencyNotFound {
+        /// ID of the item with the dependency.
+        item_id: ItemId,
+        /// ID of the dependency that is not in the flow spec.
+        dependency_id: ItemId,
+    },
+
+    /// Item dependencies in a flow spec form a cycle.
+    #[error("Item `{item_id}` depending on `{dependency_id}` would create a cycle.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::flow_spec_item_dependency_cycle),
+            help("Remove `{dependency_id}` from `{item_id}`'s `depends_on`, or break the cycle elsewhere.")
+        )
+    )]
+    FlowSpecItemDependencyCycle {
+        /// ID of the item with the dependency.
+        item_id: ItemId,
+        /// ID of the dependency that creates the cycle.
+        dependency_id: ItemId,
+    },
+
+    /// Item reads a value that is not output by any of its predecessors.
+    #[error("Item `{item_id}` reads `{input_type}`, which is not output by any of its predecessors.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::item_input_not_provided),
+            help(
+                "Add an item that outputs `{input_type}` to the flow,\n\
+                and add an edge from that item to `{item_id}`."
+            )
+        )
+    )]
+    ItemInputNotProvided {
+        /// ID of the item that reads the value.
+        item_id: ItemId,
+        /// Name of the value's type.
+        input_type: String,
+    },
+
+    /// Item's params are mapped from the state of an item that does not run
+    /// before it.
+    #[error(
+        "Item `{item_id}`'s params are mapped from `{item_id_from}`'s state `{state_type}`, but `{item_id_from}` does not run before `{item_id}`."
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::params_mapping_fn_edge_missing),
+            help(
+                "Add an edge from `{item_id_from}` to `{item_id}`,\n\
+                so that `{state_type}` is discovered before `{item_id}`'s params are resolved."
+            )
+        )
+    )]
+    ParamsMappingFnEdgeMissing {
+        /// ID of the item whose params are mapped.
+        item_id: ItemId,
+        /// ID of the item whose state is mapped from.
+        item_id_from: ItemId,
+        /// Name of the state's type.
+        state_type: String,
+    },
+
+    /// Item was not applied because an item it depends on failed.
+    #[error("Item `{item_id}` was not applied because `{item_id_blocking}` failed.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::item_apply_blocked),
+            help("Fix the error for `{item_id_blocking}`, then run the command again.")
+        )
+    )]
+    ItemApplyBlocked {
+        /// ID of the item that was not applied.
+        item_id: ItemId,
+        /// ID of the failed item that `item_id` depends on.
+        item_id_blocking: ItemId,
+    },
+
+    /// Item was rolled back because another item in its atomic group failed.
+    #[error(
+        "Item `{item_id}` was rolled back because `{item_id_failed}` in atomic group `{item_group_id}` failed."
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::item_apply_rolled_back),
+            help("Fix the error for `{item_id_failed}`, then run the command again.")
+        )
+    )]
+    ItemApplyRolledBack {
+        /// ID of the item that was rolled back.
+        item_id: ItemId,
+        /// ID of the atomic group that both items are in.
+        item_group_id: ItemGroupId,
+        /// ID of the failed item in the atomic group.
+        item_id_failed: ItemId,
+    },
+
+    /// A clean ordering override contradicts the order of the flow's other
+    /// edges.
+    #[error(
+        "Cannot clean `{item_id_before}` before `{item_id_after}`, as `{item_id_after}` is cleaned before `{item_id_before}` through other items."
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::clean_ordering_override_cyclic),
+            help(
+                "Remove the override, or also override the order of the items between\n\
+                `{item_id_before}` and `{item_id_after}`."
+            )
+        )
+    )]
+    CleanOrderingOverrideCyclic {
+        /// ID of the item to clean first.
+        item_id_before: ItemId,
+        /// ID of the item to clean after `item_id_before`.
+        item_id_after: ItemId,
+    },
+
     /// Profile to diff has not had its states current discovered.
     #[error("Profile `{profile}`'s states have not been discovered.")]
     #[cfg_attr(
@@ -491,6 +1354,69 @@ pub enum Error {
     )]
     FlowParamsDeserialize(#[source] serde_yaml::Error),
 
+    /// Failed to deserialize params from a user supplied params file.
+    #[error("Failed to deserialize params from file: `{}`.", params_file_path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::params_file_deserialize),
+            help("Make sure that each value in the file is valid for its parameter type.")
+        )
+    )]
+    ParamsFileDeserialize {
+        /// Path to the params file.
+        params_file_path: PathBuf,
+        /// Source text to be deserialized.
+        #[cfg(feature = "error_reporting")]
+        #[source_code]
+        params_file_source: miette::NamedSource<String>,
+        /// Offset within the source text that the error occurred.
+        #[cfg(feature = "error_reporting")]
+        #[label("{}", error_message)]
+        error_span: Option<miette::SourceOffset>,
+        /// Message explaining the error.
+        #[cfg(feature = "error_reporting")]
+        error_message: String,
+        /// Offset within the source text surrounding the error.
+        #[cfg(feature = "error_reporting")]
+        #[label]
+        context_span: Option<miette::SourceOffset>,
+        /// Underlying error.
+        #[source]
+        error: serde_yaml::Error,
+    },
+
+    /// User supplied params file contains keys that are not registered.
+    #[error(
+        "Params file contains unknown keys: `{}`: {}.",
+        params_file_path.display(),
+        keys.join(", ")
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::params_file_keys_unknown),
+            help(
+                "Register the type of each parameter on the command context builder, \
+                or remove the keys from the file."
+            )
+        )
+    )]
+    ParamsFileKeysUnknown {
+        /// Path to the params file.
+        params_file_path: PathBuf,
+        /// Keys in the file that are not registered.
+        keys: Vec<String>,
+        /// Source text that was deserialized.
+        #[cfg(feature = "error_reporting")]
+        #[source_code]
+        params_file_source: miette::NamedSource<String>,
+        /// Spans of the unknown keys within the source text.
+        #[cfg(feature = "error_reporting")]
+        #[label(collection, "unknown key")]
+        key_spans: Vec<miette::LabeledSpan>,
+    },
+
     /// Item does not exist in storage.
     #[error("Item does not exist in storage: `{}`.", path.display())]
     #[cfg_attr(
@@ -502,6 +1428,39 @@ pub enum Error {
         path: PathBuf,
     },
 
+    /// Failed to compress an item before it is stored.
+    #[error("Failed to {storage_compression} compress item to store at: `{}`.", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::storage_compress))
+    )]
+    StorageCompress {
+        /// Path that the item was to be stored at.
+        path: PathBuf,
+        /// Compression that the item was compressed with.
+        storage_compression: StorageCompression,
+        /// Underlying error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to decompress a stored item.
+    #[error("Failed to decompress item stored at: `{}`.", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::storage_decompress),
+            help("The stored item may be corrupted, or may have been modified outside of peace.")
+        )
+    )]
+    StorageDecompress {
+        /// Path that the item is stored at.
+        path: PathBuf,
+        /// Underlying error.
+        #[source]
+        error: std::io::Error,
+    },
+
     /// Error downcasting a `BoxDtDisplay` into an item's concrete state type.
     #[error("Error downcasting a `BoxDtDisplay` into an item's concrete state type.")]
     StateDowncastError(
@@ -532,6 +1491,39 @@ pub enum Error {
     ),
 }
 
+fn params_validation_display(
+    item_params_validation_errors: &IndexMap<ItemId, Vec<ParamsValidationError>>,
+) -> String {
+    item_params_validation_errors
+        .iter()
+        .map(|(item_id, params_validation_errors)| {
+            let params_validation_errors_list = params_validation_errors
+                .iter()
+                .map(|params_validation_error| format!("  * {params_validation_error}"))
+                .collect::<Vec<String>>()
+                .join("\n");
+            format!("* {item_id}:\n{params_validation_errors_list}")
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn item_ids_display(item_ids: &[ItemId]) -> String {
+    item_ids
+        .iter()
+        .map(|item_id| format!("* {item_id}"))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn preflight_check_failures_display(preflight_check_failures: &[PreflightCheckFailure]) -> String {
+    preflight_check_failures
+        .iter()
+        .map(|preflight_check_failure| format!("* {preflight_check_failure}"))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
 #[cfg(feature = "error_reporting")]
 fn params_specs_mismatch_display(
     item_ids_with_no_params: &[ItemId],
@@ -577,7 +1569,10 @@ fn params_specs_mismatch_display(
             items.push(format!(
                 "The following stored params specs do not correspond to any items in the flow:\n\
                 \n\
-                {params_specs_stored_mismatches_list}\n",
+                {params_specs_stored_mismatches_list}\n\
+                \n\
+                If an item was renamed, use `ParamsReconcileCmd` to move its params spec\n\
+                to the new item ID, or to drop params specs for removed items.\n",
             ));
         }
     }
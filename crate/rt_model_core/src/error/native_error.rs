@@ -28,6 +28,21 @@ pub enum NativeError {
         error: std::io::Error,
     },
 
+    /// Failed to get the key to encrypt the workspace with.
+    #[error("Failed to get the key to encrypt the workspace with.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model_native::encryption_key_provide),
+            help("Make sure the `KeyProvider` passed to `WorkspaceSpec::PathEncrypted` can provide the key.")
+        )
+    )]
+    EncryptionKeyProvide {
+        /// Underlying error.
+        #[source]
+        error: Box<dyn std::error::Error + Send + Sync + 'static>,
+    },
+
     /// Failed to create file for writing.
     #[error("Failed to create file for writing: `{path}`")]
     #[cfg_attr(
@@ -42,6 +57,31 @@ pub enum NativeError {
         error: std::io::Error,
     },
 
+    /// Failed to decrypt file.
+    #[error("Failed to decrypt file: `{path}`")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model_native::file_decrypt),
+            help("Make sure the workspace encryption key is the one that was used to encrypt the file.")
+        )
+    )]
+    FileDecrypt {
+        /// Path to the file.
+        path: PathBuf,
+    },
+
+    /// Failed to encrypt file.
+    #[error("Failed to encrypt file: `{path}`")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_native::file_encrypt))
+    )]
+    FileEncrypt {
+        /// Path to the file.
+        path: PathBuf,
+    },
+
     /// Failed to open file for reading.
     #[error("Failed to open file for reading: `{path}`")]
     #[cfg_attr(
@@ -84,6 +124,84 @@ pub enum NativeError {
         error: std::io::Error,
     },
 
+    /// Failed to remove file.
+    #[error("Failed to remove file: `{}`", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_native::file_remove))
+    )]
+    FileRemove {
+        /// Path to the file to remove.
+        path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to remove directory.
+    #[error("Failed to remove directory: `{}`", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_native::dir_remove))
+    )]
+    DirRemove {
+        /// Path to the directory to remove.
+        path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to rename file.
+    #[error("Failed to rename file: `{}` to `{}`", path_from.display(), path_to.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_native::file_rename))
+    )]
+    FileRename {
+        /// Path to the file to rename.
+        path_from: PathBuf,
+        /// Path to rename the file to.
+        path_to: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to sync file to disk.
+    #[error("Failed to sync file to disk: `{}`", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_native::file_sync))
+    )]
+    FileSync {
+        /// Path to the file.
+        path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to list entries in `FlowDir`.
+    #[error("Failed to list entries in `FlowDir`: {}", flow_dir.display())]
+    FlowDirRead {
+        /// Path to the `FlowDir`.
+        flow_dir: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to read entry in `FlowDir`.
+    #[error("Failed to read entry in `FlowDir`: {}", flow_dir.display())]
+    FlowDirEntryRead {
+        /// Path to the `FlowDir`.
+        flow_dir: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
     /// Failed to list entries in `PeaceAppDir`.
     #[error("Failed to list entries in `PeaceAppDir`: {}", peace_app_dir.display())]
     PeaceAppDirRead {
@@ -114,6 +232,26 @@ pub enum NativeError {
         error: std::io::Error,
     },
 
+    /// Failed to list entries in `ProfileDir`.
+    #[error("Failed to list entries in `ProfileDir`: {}", profile_dir.display())]
+    ProfileDirRead {
+        /// Path to the `ProfileDir`.
+        profile_dir: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to read entry in `ProfileDir`.
+    #[error("Failed to read entry in `ProfileDir`: {}", profile_dir.display())]
+    ProfileDirEntryRead {
+        /// Path to the `ProfileDir`.
+        profile_dir: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
     /// Profile directory name is not a valid profile name.
     #[error("Profile directory name is not a valid profile name: {}, path: {}", dir_name, path.display())]
     #[cfg_attr(
@@ -195,12 +333,37 @@ pub enum NativeError {
         working_dir = working_dir.display())]
     #[cfg_attr(
         feature = "error_reporting",
-        diagnostic(code(peace_rt_model_native::workspace_file_not_found))
+        diagnostic(
+            code(peace_rt_model_native::workspace_file_not_found),
+            help("{}", workspace_file_not_found_help(file_name, searched_dirs))
+        )
     )]
     WorkspaceFileNotFound {
         /// Beginning directory of traversal.
         working_dir: PathBuf,
         /// File or directory name searched for.
         file_name: OsString,
+        /// Directories that were searched, from the working directory up to
+        /// the root directory.
+        searched_dirs: Vec<PathBuf>,
     },
 }
+
+#[cfg(feature = "error_reporting")]
+fn workspace_file_not_found_help(file_name: &OsString, searched_dirs: &[PathBuf]) -> String {
+    let searched_dirs_list = searched_dirs
+        .iter()
+        .map(|searched_dir| format!("* {}", searched_dir.display()))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    format!(
+        "Run the command within the workspace, or create `{file_name}` in the workspace \
+        directory.\n\
+        \n\
+        The following directories were searched:\n\
+        \n\
+        {searched_dirs_list}",
+        file_name = file_name.to_string_lossy(),
+    )
+}
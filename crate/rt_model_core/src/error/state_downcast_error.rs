@@ -107,4 +107,32 @@ pub enum StateDowncastError {
         /// Second state parameter.
         state_b: BoxDtDisplay,
     },
+    /// Item state diff could not be downcasted.
+    #[error(
+        "Item state diff could not be downcasted to `{ty_name}`.\n\
+        Boxed type is `{boxed_ty:?}`.",
+        ty_name = ty_name,
+        boxed_ty = state_diff.type_name(),
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::state_downcast_error::state_diff),
+            help(
+                "\
+                This error happens when the boxed state diff could not be downcasted to
+                this item's state diff, which indicates one of the following:\n\
+                \n\
+                * Peace contains a bug, and passed an incorrect box to this item.\n\
+                * Item IDs were swapped, such that `ItemA`'s state diff is passed to `ItemB`.\n\
+                "
+            ),
+        )
+    )]
+    StateDiff {
+        /// Type name of the state diff type.
+        ty_name: String,
+        /// State diff parameter.
+        state_diff: BoxDtDisplay,
+    },
 }
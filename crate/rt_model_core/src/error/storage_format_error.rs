@@ -0,0 +1,38 @@
+/// Error serializing or deserializing data in a [`StorageFormat`].
+///
+/// [`StorageFormat`]: crate::StorageFormat
+#[cfg_attr(feature = "error_reporting", derive(miette::Diagnostic))]
+#[derive(Debug, thiserror::Error)]
+pub enum StorageFormatError {
+    /// Failed to serialize or deserialize YAML.
+    #[error("Failed to serialize or deserialize YAML.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::storage_format_error::yaml))
+    )]
+    Yaml(#[source] serde_yaml::Error),
+
+    /// Failed to serialize or deserialize JSON.
+    #[error("Failed to serialize or deserialize JSON.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::storage_format_error::json))
+    )]
+    Json(#[source] serde_json::Error),
+
+    /// Failed to serialize CBOR.
+    #[error("Failed to serialize CBOR.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::storage_format_error::cbor_serialize))
+    )]
+    CborSerialize(#[source] ciborium::ser::Error<std::io::Error>),
+
+    /// Failed to deserialize CBOR.
+    #[error("Failed to deserialize CBOR.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::storage_format_error::cbor_deserialize))
+    )]
+    CborDeserialize(#[source] ciborium::de::Error<std::io::Error>),
+}
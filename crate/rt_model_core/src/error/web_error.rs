@@ -138,6 +138,21 @@ pub enum WebError {
         /// Stringified JS error.
         error: String,
     },
+    /// Failed to list the keys in browser storage.
+    ///
+    /// Note: The original `JsValue` error is converted to a `String` to allow
+    /// this type to be `Send`.
+    #[error("Failed to list the keys in browser storage under `{prefix}`. Error: `{error}`")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_web::storage_keys_list))
+    )]
+    StorageKeysList {
+        /// Prefix of the keys to list.
+        prefix: PathBuf,
+        /// Stringified JS error.
+        error: String,
+    },
     /// Failed to fetch browser Window object.
     #[error("Failed to fetch browser Window object.")]
     #[cfg_attr(
@@ -145,4 +160,25 @@ pub enum WebError {
         diagnostic(code(peace_rt_model_web::window_none))
     )]
     WindowNone,
+
+    /// Failed to serialize output to pass to the JS output callback.
+    ///
+    /// Note: The original `serde_wasm_bindgen::Error` is converted to a
+    /// `String` to allow this type to be `Send`.
+    #[error("Failed to serialize output to pass to the JS output callback. Error: `{0}`")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_web::output_serialize))
+    )]
+    OutputSerialize(String),
+    /// JS output callback threw an error.
+    ///
+    /// Note: The original `JsValue` error is converted to a `String` to allow
+    /// this type to be `Send`.
+    #[error("JS output callback threw an error: `{0}`")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_web::output_callback))
+    )]
+    OutputCallback(String),
 }
@@ -0,0 +1,38 @@
+/// Message of an error in an item error's source chain.
+///
+/// This retains the error chain of an application error as text, so that it
+/// can be rendered after the original error is dropped.
+#[cfg_attr(feature = "error_reporting", derive(miette::Diagnostic))]
+#[derive(Debug, thiserror::Error)]
+#[error("{message}")]
+pub struct ErrorCause {
+    /// The error's message.
+    message: String,
+    /// The error's source, if any.
+    #[source]
+    source: Option<Box<ErrorCause>>,
+}
+
+impl ErrorCause {
+    /// Returns the chain of `ErrorCause`s for the given error source.
+    ///
+    /// Returns `None` if there is no source.
+    pub fn from_source(source: Option<&(dyn std::error::Error + 'static)>) -> Option<Box<Self>> {
+        source.map(|source| {
+            Box::new(Self {
+                message: source.to_string(),
+                source: Self::from_source(source.source()),
+            })
+        })
+    }
+
+    /// Returns the error's message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the error's source, if any.
+    pub fn cause(&self) -> Option<&ErrorCause> {
+        self.source.as_deref()
+    }
+}
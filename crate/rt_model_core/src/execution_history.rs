@@ -0,0 +1,91 @@
+use std::ops::Deref;
+
+use peace_fmt::{Presentable, Presenter};
+use serde::{Deserialize, Serialize};
+
+pub use self::execution_record::ExecutionRecord;
+
+mod execution_record;
+
+/// Command executions that wrote a flow's states.
+///
+/// `Vec<ExecutionRecord>` newtype, ordered from oldest to newest.
+///
+/// An execution is recorded whenever a command writes the flow's current or
+/// goal states, together with a copy of both states files after the
+/// execution. At most [`EXECUTIONS_MAX`] executions are kept,
+/// so the oldest executions are pruned when a new one is recorded.
+///
+/// [`EXECUTIONS_MAX`]: Self::EXECUTIONS_MAX
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ExecutionHistory(Vec<ExecutionRecord>);
+
+impl ExecutionHistory {
+    /// Maximum number of executions to keep.
+    pub const EXECUTIONS_MAX: usize = 32;
+
+    /// Returns a new empty `ExecutionHistory`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the underlying execution records.
+    pub fn into_inner(self) -> Vec<ExecutionRecord> {
+        self.0
+    }
+
+    /// Returns the execution with the given ID, if it has not been pruned.
+    pub fn execution(&self, execution_id: u64) -> Option<&ExecutionRecord> {
+        self.0
+            .iter()
+            .find(|execution_record| execution_record.execution_id == execution_id)
+    }
+
+    /// Returns the ID to use for the next execution.
+    pub fn execution_id_next(&self) -> u64 {
+        self.0
+            .last()
+            .map(|execution_record| execution_record.execution_id + 1)
+            .unwrap_or(0)
+    }
+
+    /// Records a new execution, returning the executions that were pruned.
+    ///
+    /// The caller is responsible for removing the states files of the pruned
+    /// executions.
+    pub fn push(&mut self, execution_record: ExecutionRecord) -> Vec<ExecutionRecord> {
+        self.0.push(execution_record);
+
+        let executions_to_prune = self.0.len().saturating_sub(Self::EXECUTIONS_MAX);
+        self.0.drain(..executions_to_prune).collect()
+    }
+}
+
+impl Deref for ExecutionHistory {
+    type Target = Vec<ExecutionRecord>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl FromIterator<ExecutionRecord> for ExecutionHistory {
+    fn from_iter<I: IntoIterator<Item = ExecutionRecord>>(iter: I) -> Self {
+        Self(Vec::from_iter(iter))
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for ExecutionHistory {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        if self.0.is_empty() {
+            return presenter.text("No executions recorded.\n").await;
+        }
+        presenter
+            .list_bulleted_with(self.0.iter(), ExecutionRecord::to_string)
+            .await
+    }
+}
@@ -0,0 +1,36 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use peace_core::ItemId;
+use serde::{Deserialize, Serialize};
+
+/// Record of a command execution that wrote a flow's states.
+///
+/// The flow's states after the execution are stored in the execution's
+/// `ExecutionStatesDir`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ExecutionRecord {
+    /// ID of this execution, unique within the flow.
+    pub execution_id: u64,
+    /// When this execution was recorded.
+    pub recorded_at: DateTime<Utc>,
+    /// IDs of the items whose states were written by the execution.
+    pub item_ids: Vec<ItemId>,
+}
+
+impl fmt::Display for ExecutionRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            execution_id,
+            recorded_at,
+            item_ids,
+        } = self;
+        let item_count = item_ids.len();
+        let items = if item_count == 1 { "item" } else { "items" };
+        write!(
+            f,
+            "{execution_id}: {} ({item_count} {items})",
+            recorded_at.to_rfc3339()
+        )
+    }
+}
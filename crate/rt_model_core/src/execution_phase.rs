@@ -0,0 +1,26 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Phase of a command execution that an item was executed in.
+///
+/// See [`ExecutionTimeline`].
+///
+/// [`ExecutionTimeline`]: crate::ExecutionTimeline
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecutionPhase {
+    /// The item's current and/or goal state was discovered.
+    Discover,
+    /// The item was applied, i.e. ensured or cleaned.
+    Apply,
+}
+
+impl fmt::Display for ExecutionPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Discover => "discover".fmt(f),
+            Self::Apply => "apply".fmt(f),
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use std::ops::Deref;
+
+use indexmap::IndexMap;
+use indicatif::HumanDuration;
+use peace_core::{
+    progress::{ProgressComplete, ProgressLimit, ProgressStatus, ProgressTrackerSnapshot},
+    ItemId,
+};
+use peace_fmt::{presentable::HeadingLevel, Presentable, Presenter};
+use serde::{Deserialize, Serialize};
+
+use crate::CmdProgressTracker;
+
+/// Progress of each item at the end of a command execution.
+///
+/// This is stored in the flow directory after each command that renders
+/// progress, so that the last run can be summarized by later commands.
+///
+/// Items are in the same order as the flow's progress trackers.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ExecutionProgress(IndexMap<ItemId, ProgressTrackerSnapshot>);
+
+impl ExecutionProgress {
+    /// Returns a new empty `ExecutionProgress`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the underlying map.
+    pub fn into_inner(self) -> IndexMap<ItemId, ProgressTrackerSnapshot> {
+        self.0
+    }
+
+    /// Returns a short description of the progress status.
+    fn progress_status_desc(progress_status: &ProgressStatus) -> &'static str {
+        match progress_status {
+            ProgressStatus::Initialized => "initialized",
+            ProgressStatus::Interrupted => "interrupted",
+            ProgressStatus::ExecPending => "pending",
+            ProgressStatus::Queued => "queued",
+            ProgressStatus::Running => "running",
+            ProgressStatus::RunningStalled => "stalled",
+            ProgressStatus::UserPending => "user pending",
+            ProgressStatus::Complete(ProgressComplete::Success) => "success",
+            ProgressStatus::Complete(ProgressComplete::Fail) => "failed",
+            ProgressStatus::Complete(ProgressComplete::Skipped) => "skipped",
+        }
+    }
+}
+
+impl Deref for ExecutionProgress {
+    type Target = IndexMap<ItemId, ProgressTrackerSnapshot>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<IndexMap<ItemId, ProgressTrackerSnapshot>> for ExecutionProgress {
+    fn from(progress_tracker_snapshots: IndexMap<ItemId, ProgressTrackerSnapshot>) -> Self {
+        Self(progress_tracker_snapshots)
+    }
+}
+
+impl From<&CmdProgressTracker> for ExecutionProgress {
+    fn from(cmd_progress_tracker: &CmdProgressTracker) -> Self {
+        Self(
+            cmd_progress_tracker
+                .progress_trackers()
+                .iter()
+                .map(|(item_id, progress_tracker)| {
+                    (
+                        item_id.clone(),
+                        ProgressTrackerSnapshot::from(progress_tracker),
+                    )
+                })
+                .collect(),
+        )
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for ExecutionProgress {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        presenter
+            .heading(HeadingLevel::Level1, "Last execution")
+            .await?;
+
+        if self.0.is_empty() {
+            return presenter.text("No items were executed.").await;
+        }
+
+        let rows = self.0.iter().map(|(item_id, progress_tracker_snapshot)| {
+            let ProgressTrackerSnapshot {
+                progress_status,
+                progress_limit,
+                units_current,
+                elapsed,
+                message,
+                last_update_dt: _,
+                sub_trackers: _,
+            } = progress_tracker_snapshot;
+            let progress = match progress_limit {
+                Some(ProgressLimit::Steps(units_total) | ProgressLimit::Bytes(units_total)) => {
+                    format!("{units_current}/{units_total}")
+                }
+                Some(ProgressLimit::Unknown) | None => units_current.to_string(),
+            };
+
+            vec![
+                item_id.to_string(),
+                Self::progress_status_desc(progress_status).to_string(),
+                progress,
+                HumanDuration(*elapsed).to_string(),
+                message.clone().unwrap_or_default(),
+            ]
+        });
+        presenter
+            .table(&["Item", "Status", "Progress", "Elapsed", "Message"], rows)
+            .await
+    }
+}
@@ -0,0 +1,267 @@
+use std::{fmt::Write, ops::Deref, time::Duration};
+
+use chrono::{DateTime, Utc};
+use peace_fmt::{presentable::HeadingLevel, Presentable, Presenter};
+use serde::{Deserialize, Serialize};
+
+use crate::ItemExecutionSpan;
+
+/// When each item started and finished executing in a command execution.
+///
+/// This is stored in the flow directory after each command that discovers
+/// or applies items, and can be rendered as a Gantt-style timeline to show
+/// how many items ran in parallel, the chain of items that determined how
+/// long the command took, and the slowest items.
+///
+/// Spans are in the order that the items finished executing.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ExecutionTimeline(Vec<ItemExecutionSpan>);
+
+impl ExecutionTimeline {
+    /// Number of characters used to render each item's timeline bar.
+    pub const BAR_WIDTH: usize = 40;
+
+    /// Returns a new empty `ExecutionTimeline`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an item's execution span to this timeline.
+    pub fn push(&mut self, item_execution_span: ItemExecutionSpan) {
+        self.0.push(item_execution_span);
+    }
+
+    /// Returns the underlying spans.
+    pub fn into_inner(self) -> Vec<ItemExecutionSpan> {
+        self.0
+    }
+
+    /// Returns when the first item started executing.
+    pub fn start(&self) -> Option<DateTime<Utc>> {
+        self.0.iter().map(|span| span.start).min()
+    }
+
+    /// Returns when the last item finished executing.
+    pub fn end(&self) -> Option<DateTime<Utc>> {
+        self.0.iter().map(|span| span.end).max()
+    }
+
+    /// Returns the duration from when the first item started, to when the
+    /// last item finished.
+    pub fn duration(&self) -> Duration {
+        match (self.start(), self.end()) {
+            (Some(start), Some(end)) => (end - start).to_std().unwrap_or_default(),
+            _ => Duration::ZERO,
+        }
+    }
+
+    /// Returns the maximum number of items that were executing at the same
+    /// time.
+    pub fn max_parallelism(&self) -> usize {
+        // Ends are sorted before starts at the same instant, so that an item
+        // starting when its predecessor finishes is not counted as parallel.
+        let mut events = self
+            .0
+            .iter()
+            .flat_map(|span| [(span.start, 1i64), (span.end, -1i64)])
+            .collect::<Vec<_>>();
+        events.sort();
+
+        events
+            .into_iter()
+            .scan(0i64, |running, (_, delta)| {
+                *running += delta;
+                Some(*running)
+            })
+            .max()
+            .and_then(|running_max| usize::try_from(running_max).ok())
+            .unwrap_or(0)
+    }
+
+    /// Returns the chain of spans that determined how long the execution took.
+    ///
+    /// This starts from the span that finished last, and repeatedly steps back
+    /// to the span that finished last before the current span started. Spans
+    /// are returned in the order they were executed.
+    pub fn critical_path(&self) -> Vec<&ItemExecutionSpan> {
+        let mut critical_path = Vec::new();
+        let mut span_current = self.0.iter().max_by_key(|span| span.end);
+        while let Some(span) = span_current {
+            critical_path.push(span);
+            span_current = self
+                .0
+                .iter()
+                .filter(|span_prev| span_prev.end <= span.start && !std::ptr::eq(*span_prev, span))
+                .max_by_key(|span_prev| span_prev.end);
+        }
+        critical_path.reverse();
+        critical_path
+    }
+
+    /// Returns up to `n` spans that took the longest, slowest first.
+    pub fn slowest(&self, n: usize) -> Vec<&ItemExecutionSpan> {
+        let mut spans = self.0.iter().collect::<Vec<_>>();
+        spans.sort_by_key(|span| std::cmp::Reverse(span.duration()));
+        spans.truncate(n);
+        spans
+    }
+
+    /// Returns the timeline as an HTML table, with each span drawn as a bar
+    /// positioned relative to the start of the execution.
+    pub fn to_html(&self) -> String {
+        let mut html = String::from(
+            "<table class=\"execution_timeline\">\n\
+            <tr><th>Item</th><th>Phase</th><th>Timeline</th><th>Duration</th></tr>\n",
+        );
+        self.0.iter().for_each(|span| {
+            let (offset, width) = self.span_fractions(span);
+            let _ = writeln!(
+                html,
+                "<tr>\
+                <td>{item_id}</td>\
+                <td>{phase}</td>\
+                <td><div style=\"position: relative; height: 1em;\">\
+                <div style=\"position: absolute; left: {offset:.1}%; width: {width:.1}%; \
+                height: 100%; background: #4a90d9;\"></div></div></td>\
+                <td>{duration}</td>\
+                </tr>",
+                item_id = span.item_id,
+                phase = span.phase,
+                offset = offset * 100.0,
+                width = width * 100.0,
+                duration = duration_fmt(span.duration()),
+            );
+        });
+        html.push_str("</table>\n");
+        html
+    }
+
+    /// Returns the span's bar as text, [`Self::BAR_WIDTH`] characters wide.
+    fn span_bar(&self, span: &ItemExecutionSpan) -> String {
+        let (offset, width) = self.span_fractions(span);
+        let bar_width = Self::BAR_WIDTH as f64;
+        let offset = ((offset * bar_width).floor() as usize).min(Self::BAR_WIDTH - 1);
+        let width = ((width * bar_width).round() as usize).clamp(1, Self::BAR_WIDTH - offset);
+
+        let mut bar = String::with_capacity(Self::BAR_WIDTH * 3);
+        (0..Self::BAR_WIDTH).for_each(|index| {
+            if (offset..offset + width).contains(&index) {
+                bar.push('█');
+            } else {
+                bar.push('·');
+            }
+        });
+        bar
+    }
+
+    /// Returns the span's start offset and width as fractions of the
+    /// execution's duration.
+    fn span_fractions(&self, span: &ItemExecutionSpan) -> (f64, f64) {
+        let Some(start) = self.start() else {
+            return (0.0, 0.0);
+        };
+        let duration_total = self.duration().as_secs_f64();
+        if duration_total == 0.0 {
+            return (0.0, 1.0);
+        }
+
+        let offset = (span.start - start)
+            .to_std()
+            .unwrap_or_default()
+            .as_secs_f64()
+            / duration_total;
+        let width = span.duration().as_secs_f64() / duration_total;
+        (offset, width)
+    }
+}
+
+/// Returns the duration in seconds, with millisecond precision.
+fn duration_fmt(duration: Duration) -> String {
+    format!("{:.3}s", duration.as_secs_f64())
+}
+
+impl Deref for ExecutionTimeline {
+    type Target = Vec<ItemExecutionSpan>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Vec<ItemExecutionSpan>> for ExecutionTimeline {
+    fn from(item_execution_spans: Vec<ItemExecutionSpan>) -> Self {
+        Self(item_execution_spans)
+    }
+}
+
+impl FromIterator<ItemExecutionSpan> for ExecutionTimeline {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = ItemExecutionSpan>,
+    {
+        Self(Vec::from_iter(iter))
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for ExecutionTimeline {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        presenter
+            .heading(HeadingLevel::Level1, "Execution timeline")
+            .await?;
+
+        if self.0.is_empty() {
+            return presenter.text("No items were executed.").await;
+        }
+
+        let rows = self.0.iter().map(|span| {
+            vec![
+                span.item_id.to_string(),
+                span.phase.to_string(),
+                self.span_bar(span),
+                duration_fmt(span.duration()),
+            ]
+        });
+        presenter
+            .table(&["Item", "Phase", "Timeline", "Duration"], rows)
+            .await?;
+
+        presenter
+            .text(&format!(
+                "\nTotal: {}, max parallelism: {}\n",
+                duration_fmt(self.duration()),
+                self.max_parallelism()
+            ))
+            .await?;
+        presenter.text("\n").await?;
+
+        presenter
+            .heading(HeadingLevel::Level2, "Critical path")
+            .await?;
+        presenter
+            .list_numbered_with(self.critical_path(), span_desc)
+            .await?;
+        presenter.text("\n").await?;
+
+        presenter
+            .heading(HeadingLevel::Level2, "Slowest items")
+            .await?;
+        presenter
+            .list_numbered_with(self.slowest(3), span_desc)
+            .await
+    }
+}
+
+/// Returns a one line description of the span, e.g. "`app_file` apply
+/// (1.500s)".
+fn span_desc(span: &ItemExecutionSpan) -> String {
+    format!(
+        "{} {} ({})",
+        span.item_id,
+        span.phase,
+        duration_fmt(span.duration())
+    )
+}
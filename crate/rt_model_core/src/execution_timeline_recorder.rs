@@ -0,0 +1,56 @@
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use peace_core::ItemId;
+use peace_resources::Resources;
+
+use crate::{ExecutionPhase, ExecutionTimeline, ItemExecutionSpan};
+
+/// Records each item's [`ItemExecutionSpan`] while a command executes.
+///
+/// This is inserted into `Resources` at the start of each command execution,
+/// and the recorded [`ExecutionTimeline`] is stored when the command
+/// finishes.
+#[derive(Debug, Default)]
+pub struct ExecutionTimelineRecorder(Mutex<ExecutionTimeline>);
+
+impl ExecutionTimelineRecorder {
+    /// Returns a new `ExecutionTimelineRecorder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records that the item executed from `start` until now, if an
+    /// `ExecutionTimelineRecorder` is in `resources`.
+    pub fn record_in<TS>(
+        resources: &Resources<TS>,
+        item_id: &ItemId,
+        phase: ExecutionPhase,
+        start: DateTime<Utc>,
+    ) {
+        if let Ok(execution_timeline_recorder) = resources.try_borrow::<Self>() {
+            execution_timeline_recorder.record(ItemExecutionSpan::new(
+                item_id.clone(),
+                phase,
+                start,
+                Utc::now(),
+            ));
+        }
+    }
+
+    /// Records an item's execution span.
+    pub fn record(&self, item_execution_span: ItemExecutionSpan) {
+        if let Ok(mut execution_timeline) = self.0.lock() {
+            execution_timeline.push(item_execution_span);
+        }
+    }
+
+    /// Returns the recorded `ExecutionTimeline`, leaving an empty timeline in
+    /// its place.
+    pub fn take(&self) -> ExecutionTimeline {
+        self.0
+            .lock()
+            .map(|mut execution_timeline| std::mem::take(&mut *execution_timeline))
+            .unwrap_or_default()
+    }
+}
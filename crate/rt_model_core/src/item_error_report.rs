@@ -0,0 +1,139 @@
+use std::path::{Path, PathBuf};
+
+use peace_core::ItemId;
+
+use crate::ErrorCause;
+
+/// Error that an item encountered, with the state it managed to reach.
+///
+/// This is part of a [`CmdOutcomeReport`].
+///
+/// [`CmdOutcomeReport`]: crate::CmdOutcomeReport
+#[cfg_attr(feature = "error_reporting", derive(miette::Diagnostic))]
+#[derive(Debug, thiserror::Error)]
+#[error("`{item_id}`: {message}")]
+pub struct ItemErrorReport {
+    /// ID of the item that failed.
+    item_id: ItemId,
+    /// The error's message.
+    message: String,
+    /// The error's source chain.
+    #[source]
+    cause: Option<Box<ErrorCause>>,
+    /// The state that the item managed to reach, if any.
+    state: Option<String>,
+    /// Suggestions to recover from the error.
+    help: Vec<String>,
+    /// Path to the item's log file, if its log output was captured.
+    log_path: Option<PathBuf>,
+    /// Help text showing the recovery suggestions, the state the item managed
+    /// to reach, and the item's log file.
+    #[cfg(feature = "error_reporting")]
+    #[help]
+    help_text: Option<String>,
+}
+
+impl ItemErrorReport {
+    /// Returns a new `ItemErrorReport`.
+    ///
+    /// # Parameters
+    ///
+    /// * `item_id`: ID of the item that failed.
+    /// * `error`: The error that the item returned.
+    /// * `state`: Textual representation of the state the item managed to
+    ///   reach.
+    pub fn new<E>(item_id: ItemId, error: &E, state: Option<String>) -> Self
+    where
+        E: std::error::Error,
+    {
+        #[cfg(feature = "error_reporting")]
+        let help_text = help_text(&[], state.as_deref(), None);
+
+        Self {
+            item_id,
+            message: error.to_string(),
+            cause: ErrorCause::from_source(error.source()),
+            state,
+            help: Vec::new(),
+            log_path: None,
+            #[cfg(feature = "error_reporting")]
+            help_text,
+        }
+    }
+
+    /// Sets the suggestions to recover from the error.
+    ///
+    /// See [`Item::error_help`].
+    ///
+    /// [`Item::error_help`]: https://docs.rs/peace_cfg/latest/peace_cfg/trait.Item.html#method.error_help
+    pub fn with_help(mut self, help: Vec<String>) -> Self {
+        #[cfg(feature = "error_reporting")]
+        {
+            self.help_text = help_text(&help, self.state.as_deref(), self.log_path.as_deref());
+        }
+        self.help = help;
+        self
+    }
+
+    /// Sets the path to the item's log file.
+    ///
+    /// See `CmdOutcome::ItemError`'s `item_log_paths`.
+    pub fn with_log_path(mut self, log_path: Option<PathBuf>) -> Self {
+        #[cfg(feature = "error_reporting")]
+        {
+            self.help_text = help_text(&self.help, self.state.as_deref(), log_path.as_deref());
+        }
+        self.log_path = log_path;
+        self
+    }
+
+    /// Returns the ID of the item that failed.
+    pub fn item_id(&self) -> &ItemId {
+        &self.item_id
+    }
+
+    /// Returns the error's message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the error's source chain.
+    pub fn cause(&self) -> Option<&ErrorCause> {
+        self.cause.as_deref()
+    }
+
+    /// Returns the state that the item managed to reach, if any.
+    pub fn state(&self) -> Option<&str> {
+        self.state.as_deref()
+    }
+
+    /// Returns the suggestions to recover from the error.
+    pub fn help(&self) -> &[String] {
+        &self.help
+    }
+
+    /// Returns the path to the item's log file, if its log output was
+    /// captured.
+    pub fn log_path(&self) -> Option<&Path> {
+        self.log_path.as_deref()
+    }
+}
+
+#[cfg(feature = "error_reporting")]
+fn help_text(help: &[String], state: Option<&str>, log_path: Option<&Path>) -> Option<String> {
+    let state_help = state.map(|state| format!("state reached: {state}"));
+    let log_path_help = log_path.map(|log_path| format!("logs: {}", log_path.display()));
+    let help_text = help
+        .iter()
+        .cloned()
+        .chain(state_help)
+        .chain(log_path_help)
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if help_text.is_empty() {
+        None
+    } else {
+        Some(help_text)
+    }
+}
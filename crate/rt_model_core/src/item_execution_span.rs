@@ -0,0 +1,46 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use peace_core::ItemId;
+use serde::{Deserialize, Serialize};
+
+use crate::ExecutionPhase;
+
+/// When an item started and finished executing in a phase of a command.
+///
+/// See [`ExecutionTimeline`].
+///
+/// [`ExecutionTimeline`]: crate::ExecutionTimeline
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ItemExecutionSpan {
+    /// ID of the item that was executed.
+    pub item_id: ItemId,
+    /// Phase that the item was executed in.
+    pub phase: ExecutionPhase,
+    /// When the item started executing.
+    pub start: DateTime<Utc>,
+    /// When the item finished executing.
+    pub end: DateTime<Utc>,
+}
+
+impl ItemExecutionSpan {
+    /// Returns a new `ItemExecutionSpan`.
+    pub fn new(
+        item_id: ItemId,
+        phase: ExecutionPhase,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            item_id,
+            phase,
+            start,
+            end,
+        }
+    }
+
+    /// Returns how long the item took to execute.
+    pub fn duration(&self) -> Duration {
+        (self.end - self.start).to_std().unwrap_or_default()
+    }
+}
@@ -0,0 +1,50 @@
+use std::ops::{Deref, DerefMut};
+
+use indexmap::IndexSet;
+use peace_core::ItemId;
+use serde::{Deserialize, Serialize};
+
+/// IDs of items that are disabled for a profile. `IndexSet<ItemId>` newtype.
+///
+/// Disabled items are skipped when discovering and applying states, so an
+/// environment can skip specific items in a flow without code changes. Their
+/// progress is reported as skipped with a "disabled" message, and they are
+/// not included in state diffs.
+///
+/// This is stored in the profile directory, and is set using
+/// `CmdCtxBuilder::with_items_disabled`. When it is not set, the stored
+/// value is used.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ItemsDisabled(IndexSet<ItemId>);
+
+impl ItemsDisabled {
+    /// Returns a new `ItemsDisabled` set with no items.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the underlying set.
+    pub fn into_inner(self) -> IndexSet<ItemId> {
+        self.0
+    }
+}
+
+impl Deref for ItemsDisabled {
+    type Target = IndexSet<ItemId>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ItemsDisabled {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl FromIterator<ItemId> for ItemsDisabled {
+    fn from_iter<I: IntoIterator<Item = ItemId>>(iter: I) -> Self {
+        Self(IndexSet::from_iter(iter))
+    }
+}
@@ -0,0 +1,52 @@
+use std::ops::Deref;
+
+use peace_core::ItemId;
+use peace_fmt::{Presentable, Presenter};
+use serde::{Deserialize, Serialize};
+
+/// Items whose params changed since their current states were last stored.
+///
+/// `Vec<ItemId>` newtype.
+///
+/// This is presented as a warning when building a command context with
+/// [`ParamsChangePolicy::Warn`].
+///
+/// [`ParamsChangePolicy::Warn`]: crate::ParamsChangePolicy::Warn
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ItemsParamsChanged(Vec<ItemId>);
+
+impl ItemsParamsChanged {
+    /// Returns a new `ItemsParamsChanged`.
+    pub fn new(item_ids: Vec<ItemId>) -> Self {
+        Self(item_ids)
+    }
+
+    /// Returns the underlying item IDs.
+    pub fn into_inner(self) -> Vec<ItemId> {
+        self.0
+    }
+}
+
+impl Deref for ItemsParamsChanged {
+    type Target = Vec<ItemId>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for ItemsParamsChanged {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        presenter
+            .text("Warning: params changed since the stored state of these items was recorded:\n\n")
+            .await?;
+        presenter.list_bulleted(self.0.iter()).await?;
+        presenter
+            .text("\nDiffs for these items may include changes from the new params.\n")
+            .await
+    }
+}
@@ -0,0 +1,58 @@
+use std::ops::{Deref, DerefMut};
+
+use indexmap::IndexMap;
+use peace_core::ItemId;
+
+use crate::StateFreshness;
+
+/// Whether each item's state was discovered, or reused from stored state.
+///
+/// `IndexMap<ItemId, StateFreshness>` newtype.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ItemsStateFreshness(IndexMap<ItemId, StateFreshness>);
+
+impl ItemsStateFreshness {
+    /// Returns a new `ItemsStateFreshness` map.
+    pub fn new() -> Self {
+        Self(IndexMap::new())
+    }
+
+    /// Returns a new `ItemsStateFreshness` map with the given preallocated
+    /// capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(IndexMap::with_capacity(capacity))
+    }
+
+    /// Returns the underlying map.
+    pub fn into_inner(self) -> IndexMap<ItemId, StateFreshness> {
+        self.0
+    }
+
+    /// Returns the IDs of items whose state was read from the stored states.
+    pub fn item_ids_stored(&self) -> impl Iterator<Item = &ItemId> + '_ {
+        self.0
+            .iter()
+            .filter(|(_item_id, state_freshness)| state_freshness.is_stored())
+            .map(|(item_id, _state_freshness)| item_id)
+    }
+}
+
+impl Deref for ItemsStateFreshness {
+    type Target = IndexMap<ItemId, StateFreshness>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ItemsStateFreshness {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl FromIterator<(ItemId, StateFreshness)> for ItemsStateFreshness {
+    fn from_iter<I: IntoIterator<Item = (ItemId, StateFreshness)>>(iter: I) -> Self {
+        Self(IndexMap::from_iter(iter))
+    }
+}
@@ -13,22 +13,83 @@ pub mod output;
 pub mod params;
 
 pub use crate::{
-    error::{ApplyCmdError, Error, StateDowncastError},
+    cmd_outcome_report::CmdOutcomeReport,
+    cmd_outcome_reporter::CmdOutcomeReporter,
+    error::{ApplyCmdError, Error, StateDowncastError, StorageFormatError},
+    error_cause::ErrorCause,
+    execution_history::{ExecutionHistory, ExecutionRecord},
+    execution_phase::ExecutionPhase,
+    execution_timeline::ExecutionTimeline,
+    execution_timeline_recorder::ExecutionTimelineRecorder,
+    item_error_report::ItemErrorReport,
+    item_execution_span::ItemExecutionSpan,
+    items_disabled::ItemsDisabled,
+    items_params_changed::ItemsParamsChanged,
+    items_state_freshness::ItemsStateFreshness,
     items_state_stored_stale::ItemsStateStoredStale,
+    params_change_policy::ParamsChangePolicy,
+    params_digests::ParamsDigests,
+    params_history::{
+        ParamChange, ParamsHistory, ParamsKind, ParamsRevision, ParamsRevisionDiff,
+    },
+    preflight_check_failure::PreflightCheckFailure,
+    state_freshness::StateFreshness,
+    state_query::StateQuery,
     state_stored_and_discovered::StateStoredAndDiscovered,
+    states_ordering::StatesOrdering,
+    states_prune_outcome::StatesPruneOutcome,
+    storage_compression::StorageCompression,
+    storage_format::StorageFormat,
 };
 
+mod cmd_outcome_report;
+mod cmd_outcome_reporter;
 mod error;
+mod error_cause;
+mod execution_history;
+mod execution_phase;
+mod execution_timeline;
+mod execution_timeline_recorder;
+mod item_error_report;
+mod item_execution_span;
+mod items_disabled;
+mod items_params_changed;
+mod items_state_freshness;
 mod items_state_stored_stale;
+mod params_change_policy;
+mod params_digests;
+mod params_history;
+mod preflight_check_failure;
+mod state_freshness;
+mod state_query;
 mod state_stored_and_discovered;
+mod states_ordering;
+mod states_prune_outcome;
+mod storage_compression;
+mod storage_format;
+
+#[cfg(feature = "error_reporting")]
+pub use crate::localized_diagnostic::LocalizedDiagnostic;
+
+#[cfg(feature = "profiling")]
+pub use crate::timing_breakdown_recorder::TimingBreakdownRecorder;
+
+#[cfg(feature = "profiling")]
+mod timing_breakdown_recorder;
+
+#[cfg(feature = "error_reporting")]
+mod localized_diagnostic;
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "output_progress")] {
         pub use peace_core::progress::ProgressUpdate;
 
-        pub use crate::cmd_progress_tracker::CmdProgressTracker;
+        pub use crate::{
+            cmd_progress_tracker::CmdProgressTracker, execution_progress::ExecutionProgress,
+        };
 
         mod cmd_progress_tracker;
+        mod execution_progress;
     }
 }
 
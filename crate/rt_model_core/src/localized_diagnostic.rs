@@ -0,0 +1,96 @@
+use std::fmt::{self, Debug, Display};
+
+use miette::{Diagnostic, LabeledSpan, Severity, SourceCode};
+use peace_fmt::{messages, MessageCatalog};
+
+/// Renders a diagnostic with its help text translated through a
+/// [`MessageCatalog`].
+///
+/// The translation's message ID is derived from the diagnostic's code using
+/// [`messages::help_message_id`], e.g. `peace_rt_model-states_deserialize-help`.
+/// If the diagnostic has no code, or the catalog has no translation, the
+/// diagnostic's own help text is used. All other parts of the diagnostic are
+/// rendered as is.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let localized_diagnostic = LocalizedDiagnostic::new(&error, &message_catalog);
+///
+/// GraphicalReportHandler::new().render_report(&mut error_text, &localized_diagnostic)?;
+/// ```
+pub struct LocalizedDiagnostic<'d> {
+    /// The diagnostic to render.
+    diagnostic: &'d dyn Diagnostic,
+    /// Catalog to translate the help text through.
+    message_catalog: &'d MessageCatalog,
+}
+
+impl<'d> LocalizedDiagnostic<'d> {
+    /// Returns a new `LocalizedDiagnostic`.
+    pub fn new(diagnostic: &'d dyn Diagnostic, message_catalog: &'d MessageCatalog) -> Self {
+        Self {
+            diagnostic,
+            message_catalog,
+        }
+    }
+}
+
+impl Debug for LocalizedDiagnostic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.diagnostic, f)
+    }
+}
+
+impl Display for LocalizedDiagnostic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.diagnostic, f)
+    }
+}
+
+impl std::error::Error for LocalizedDiagnostic<'_> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.diagnostic.source()
+    }
+}
+
+impl Diagnostic for LocalizedDiagnostic<'_> {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.diagnostic.severity()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic
+            .code()
+            .and_then(|code| {
+                let message_id = messages::help_message_id(&code.to_string());
+                self.message_catalog.translation(&message_id, &[])
+            })
+            .map(|help| Box::new(help) as Box<dyn Display + 'a>)
+            .or_else(|| self.diagnostic.help())
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.diagnostic.source_code()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.diagnostic.labels()
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        self.diagnostic.related()
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        self.diagnostic.diagnostic_source()
+    }
+}
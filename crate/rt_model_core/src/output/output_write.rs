@@ -1,7 +1,7 @@
 use std::fmt::Debug;
 
 use async_trait::async_trait;
-use peace_fmt::Presentable;
+use peace_fmt::{MessageCatalog, Presentable};
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "output_progress")] {
@@ -67,6 +67,18 @@ pub trait OutputWrite<E>: Debug + Unpin {
     #[cfg(feature = "output_progress")]
     async fn progress_end(&mut self, cmd_progress_tracker: &CmdProgressTracker);
 
+    /// Sets the catalog that user-facing messages are rendered through.
+    ///
+    /// # Implementors
+    ///
+    /// This is called when the command context is built. Text that this
+    /// `OutputWrite` adds to the output, such as labels in error reports,
+    /// should be rendered through the catalog, so that it is shown in the
+    /// selected locale.
+    ///
+    /// The default implementation ignores the catalog.
+    fn message_catalog_set(&mut self, _message_catalog: MessageCatalog) {}
+
     /// Writes presentable information to the output.
     async fn present<P>(&mut self, presentable: P) -> Result<(), E>
     where
@@ -25,19 +25,54 @@
 //!
 //! * Server count: applicable to `deploy`
 //! * Force remove: applicable to `clean`
+//!
+//! # Layering
+//!
+//! Profile params may also be provided in other [`ParamsLayer`]s, such as
+//! workspace defaults and command line overrides. [`ParamsOverlays`] holds
+//! these values, and [`ParamsResolution`] records which layer each resolved
+//! value came from.
+//!
+//! # Computed Params
+//!
+//! Workspace params may be computed asynchronously when the command context is
+//! built, such as fetching an account ID. [`ParamsFns`] holds the functions
+//! that compute these values.
+//!
+//! # Migrations
+//!
+//! Workspace params stored by an earlier version of an application may be
+//! migrated to their current types using a [`WorkspaceParamsRegistry`].
+//!
+//! # Params Files
+//!
+//! Params may also be read from user supplied YAML or JSON files, whose paths
+//! are held in [`ParamsFilesProvided`].
 
 pub use self::{
     flow_params::FlowParams,
     params_keys::{KeyKnown, KeyMaybe, KeyUnknown, ParamsKeys, ParamsKeysImpl, ParamsKeysUnknown},
+    params_files_provided::ParamsFilesProvided,
+    params_fns::ParamsFns,
+    params_layer::ParamsLayer,
+    params_overlays::ParamsOverlays,
+    params_resolution::ParamsResolution,
     params_type_regs::ParamsTypeRegs,
     params_type_regs_builder::ParamsTypeRegsBuilder,
     profile_params::ProfileParams,
     workspace_params::WorkspaceParams,
+    workspace_params_registry::WorkspaceParamsRegistry,
 };
 
 mod flow_params;
 mod params_keys;
+mod params_files_provided;
+mod params_fns;
+mod params_layer;
+mod params_overlays;
+mod params_resolution;
 mod params_type_regs;
 mod params_type_regs_builder;
 mod profile_params;
 mod workspace_params;
+mod workspace_params_registry;
@@ -0,0 +1,54 @@
+use std::path::{Path, PathBuf};
+
+/// Paths to user supplied files to read params from when building a
+/// `CmdCtx`.
+///
+/// Each file is a YAML or JSON map of params key to value. Values provided in
+/// code take precedence over values in these files, which take precedence
+/// over stored values.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParamsFilesProvided {
+    /// File to read workspace params from.
+    workspace_params_file: Option<PathBuf>,
+    /// File to read profile params from.
+    profile_params_file: Option<PathBuf>,
+    /// File to read flow params from.
+    flow_params_file: Option<PathBuf>,
+}
+
+impl ParamsFilesProvided {
+    /// Returns a new `ParamsFilesProvided` with no files.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the file to read workspace params from.
+    pub fn workspace_params_file(&self) -> Option<&Path> {
+        self.workspace_params_file.as_deref()
+    }
+
+    /// Sets the file to read workspace params from.
+    pub fn set_workspace_params_file(&mut self, workspace_params_file: PathBuf) {
+        self.workspace_params_file = Some(workspace_params_file);
+    }
+
+    /// Returns the file to read profile params from.
+    pub fn profile_params_file(&self) -> Option<&Path> {
+        self.profile_params_file.as_deref()
+    }
+
+    /// Sets the file to read profile params from.
+    pub fn set_profile_params_file(&mut self, profile_params_file: PathBuf) {
+        self.profile_params_file = Some(profile_params_file);
+    }
+
+    /// Returns the file to read flow params from.
+    pub fn flow_params_file(&self) -> Option<&Path> {
+        self.flow_params_file.as_deref()
+    }
+
+    /// Sets the file to read flow params from.
+    pub fn set_flow_params_file(&mut self, flow_params_file: PathBuf) {
+        self.flow_params_file = Some(flow_params_file);
+    }
+}
@@ -0,0 +1,102 @@
+use std::{fmt, future::Future, hash::Hash, pin::Pin};
+
+use serde::{de::DeserializeOwned, Serialize};
+use type_reg::untagged::{BoxDt, TypeMap};
+
+/// Error returned by a function that computes a parameter value.
+type ParamFnError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// Function that computes a parameter value.
+type ParamFn = Box<dyn FnOnce() -> Pin<Box<dyn Future<Output = Result<BoxDt, ParamFnError>>>>>;
+
+/// Functions that compute parameter values when a command context is built.
+///
+/// This allows values such as an account ID or a token to be fetched while
+/// building the command context, instead of before constructing the builder.
+///
+/// # Type Parameters
+///
+/// * `K`: Type of key for the params map.
+pub struct ParamsFns<K>(Vec<(K, ParamFn)>);
+
+impl<K> ParamsFns<K>
+where
+    K: Clone + fmt::Debug + Eq + Hash,
+{
+    /// Returns a new `ParamsFns` list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether there are no functions.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the number of functions.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Adds a function to compute the parameter value for the given key.
+    ///
+    /// If a function was previously added for the key, it is replaced.
+    pub fn insert<F, Fut, T, ErrT>(&mut self, k: K, f: F)
+    where
+        F: FnOnce() -> Fut + 'static,
+        Fut: Future<Output = Result<T, ErrT>> + 'static,
+        T: Clone + fmt::Debug + DeserializeOwned + Serialize + Send + Sync + 'static,
+        ErrT: std::error::Error + Send + Sync + 'static,
+    {
+        self.0.retain(|(k_existing, _f)| k_existing != &k);
+
+        let param_fn: ParamFn = Box::new(move || {
+            Box::pin(async move {
+                f().await
+                    .map(BoxDt::new)
+                    .map_err(|error| Box::new(error) as ParamFnError)
+            })
+        });
+        self.0.push((k, param_fn));
+    }
+
+    /// Runs all functions concurrently, and returns the computed values.
+    ///
+    /// If any function fails, the key and error of the first failed function
+    /// is returned.
+    pub async fn resolve(self) -> Result<TypeMap<K, BoxDt>, (K, ParamFnError)> {
+        let (ks, param_fns): (Vec<K>, Vec<ParamFn>) = self.0.into_iter().unzip();
+        let results =
+            futures::future::join_all(param_fns.into_iter().map(|param_fn| param_fn())).await;
+
+        ks.into_iter()
+            .zip(results)
+            .try_fold(
+                TypeMap::new_typed(),
+                |mut params, (k, result)| match result {
+                    Ok(param) => {
+                        params.insert_raw(k, param);
+                        Ok(params)
+                    }
+                    Err(error) => Err((k, error)),
+                },
+            )
+    }
+}
+
+impl<K> fmt::Debug for ParamsFns<K>
+where
+    K: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter().map(|(k, _param_fn)| k))
+            .finish()
+    }
+}
+
+impl<K> Default for ParamsFns<K> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
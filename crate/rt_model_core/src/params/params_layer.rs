@@ -0,0 +1,44 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Layer that a parameter value is provided in.
+///
+/// When a parameter has a value in multiple layers, the value from the layer
+/// with the highest precedence is used. Layers are ordered from lowest to
+/// highest precedence:
+///
+/// 1. [`Workspace`]: Defaults shared by all profiles in the workspace.
+/// 2. [`Profile`]: Values stored for, or provided to, the profile.
+/// 3. [`Override`]: Values provided for the current command only, such as
+///    command line arguments.
+///
+/// [`Workspace`]: Self::Workspace
+/// [`Profile`]: Self::Profile
+/// [`Override`]: Self::Override
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub enum ParamsLayer {
+    /// Defaults shared by all profiles in the workspace.
+    ///
+    /// These are not stored, and are used when the profile has no value for
+    /// the parameter.
+    Workspace,
+    /// Values stored for, or provided to, the profile.
+    ///
+    /// These are stored in the profile's params file.
+    Profile,
+    /// Values provided for the current command only.
+    ///
+    /// These are not stored, and take precedence over all other layers.
+    Override,
+}
+
+impl fmt::Display for ParamsLayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Workspace => "workspace".fmt(f),
+            Self::Profile => "profile".fmt(f),
+            Self::Override => "override".fmt(f),
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use std::{collections::BTreeMap, hash::Hash};
+
+use type_reg::untagged::{BoxDt, FromDataType, TypeMap};
+
+use crate::params::{ParamsLayer, ParamsResolution};
+
+/// Parameter values provided in layers other than the stored params.
+///
+/// The stored (or provided) params form the [`ParamsLayer::Profile`] layer,
+/// and values in this map are layered below and above them when resolved.
+///
+/// # Type Parameters
+///
+/// * `K`: Type of key for the params map.
+#[derive(Clone, Debug)]
+pub struct ParamsOverlays<K>(BTreeMap<ParamsLayer, TypeMap<K, BoxDt>>)
+where
+    K: Eq + Hash;
+
+impl<K> ParamsOverlays<K>
+where
+    K: Clone + Eq + Hash,
+{
+    /// Returns a new `ParamsOverlays` map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the inner map.
+    pub fn into_inner(self) -> BTreeMap<ParamsLayer, TypeMap<K, BoxDt>> {
+        self.0
+    }
+
+    /// Returns whether there are no values in any layer.
+    pub fn is_empty(&self) -> bool {
+        self.0.values().all(|params| params.is_empty())
+    }
+
+    /// Returns the values provided in the given layer.
+    pub fn layer(&self, params_layer: ParamsLayer) -> Option<&TypeMap<K, BoxDt>> {
+        self.0.get(&params_layer)
+    }
+
+    /// Inserts a parameter value into the given layer.
+    ///
+    /// Returns the previous value in that layer, if any.
+    pub fn insert<R>(&mut self, params_layer: ParamsLayer, k: K, r: R) -> Option<BoxDt>
+    where
+        BoxDt: FromDataType<R>,
+    {
+        self.0
+            .entry(params_layer)
+            .or_insert_with(TypeMap::new_typed)
+            .insert(k, r)
+    }
+
+    /// Returns the params with the values from each layer applied, and the
+    /// layer each value came from.
+    ///
+    /// # Parameters
+    ///
+    /// * `params`: Values in the [`ParamsLayer::Profile`] layer, typically the
+    ///   stored params merged with the params provided to the command.
+    pub fn resolve(&self, params: &TypeMap<K, BoxDt>) -> (TypeMap<K, BoxDt>, ParamsResolution<K>) {
+        let params_profile = (ParamsLayer::Profile, params);
+        let mut layers = self
+            .0
+            .iter()
+            .map(|(params_layer, params)| (*params_layer, params))
+            .chain(std::iter::once(params_profile))
+            .collect::<Vec<_>>();
+        // Stable sort, so `params` are applied after any `Profile` layer
+        // overlays.
+        layers.sort_by_key(|(params_layer, _params)| *params_layer);
+
+        layers.into_iter().fold(
+            (
+                TypeMap::with_capacity_typed(params.len()),
+                ParamsResolution::with_capacity(params.len()),
+            ),
+            |(mut params_resolved, mut params_resolution), (params_layer, params)| {
+                params.iter().for_each(|(k, param)| {
+                    params_resolved.insert_raw(k.clone(), param.clone());
+                    params_resolution.insert(k.clone(), params_layer);
+                });
+
+                (params_resolved, params_resolution)
+            },
+        )
+    }
+}
+
+impl<K> Default for ParamsOverlays<K>
+where
+    K: Eq + Hash,
+{
+    fn default() -> Self {
+        Self(BTreeMap::default())
+    }
+}
@@ -0,0 +1,79 @@
+use std::{hash::Hash, ops::Deref};
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::params::ParamsLayer;
+
+/// Records which [`ParamsLayer`] each resolved parameter value came from.
+///
+/// This is useful to answer "why is this parameter's value X", when values are
+/// provided in multiple layers.
+///
+/// # Type Parameters
+///
+/// * `K`: Type of key for the params map.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct ParamsResolution<K>(IndexMap<K, ParamsLayer>)
+where
+    K: Eq + Hash;
+
+impl<K> ParamsResolution<K>
+where
+    K: Eq + Hash,
+{
+    /// Returns a new `ParamsResolution` map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty `ParamsResolution` map with the specified capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(IndexMap::with_capacity(capacity))
+    }
+
+    /// Returns the inner map.
+    pub fn into_inner(self) -> IndexMap<K, ParamsLayer> {
+        self.0
+    }
+
+    /// Returns the layer that the given parameter's value came from.
+    pub fn layer(&self, k: &K) -> Option<ParamsLayer> {
+        self.0.get(k).copied()
+    }
+
+    /// Records the layer that the given parameter's value came from.
+    pub fn insert(&mut self, k: K, params_layer: ParamsLayer) -> Option<ParamsLayer> {
+        self.0.insert(k, params_layer)
+    }
+}
+
+impl<K> Default for ParamsResolution<K>
+where
+    K: Eq + Hash,
+{
+    fn default() -> Self {
+        Self(IndexMap::default())
+    }
+}
+
+impl<K> Deref for ParamsResolution<K>
+where
+    K: Eq + Hash,
+{
+    type Target = IndexMap<K, ParamsLayer>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<K> From<IndexMap<K, ParamsLayer>> for ParamsResolution<K>
+where
+    K: Eq + Hash,
+{
+    fn from(params_layers: IndexMap<K, ParamsLayer>) -> Self {
+        Self(params_layers)
+    }
+}
@@ -0,0 +1,215 @@
+use std::{fmt, hash::Hash};
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_yaml::{Mapping, Value};
+use type_reg::untagged::{BoxDt, TypeReg};
+
+/// Function that migrates a serialized param from an older shape.
+///
+/// Returns `None` if the value is not in the older shape.
+type ParamMigration = Box<dyn Fn(&Value) -> Option<Result<Value, serde_yaml::Error>> + Send + Sync>;
+
+/// Types and migrations registered for a workspace param key.
+struct ParamRegistration<K>
+where
+    K: Eq + Hash + fmt::Debug,
+{
+    /// Registers the param's current type with a type registry.
+    type_register: fn(&mut TypeReg<K, BoxDt>, K),
+    /// Returns whether a serialized value is in the param's current shape.
+    type_matches: fn(&Value) -> bool,
+    /// Migrations from older shapes, tried in the order they were added.
+    migrations: Vec<ParamMigration>,
+}
+
+/// Workspace param keys with their types, and migrations from the shapes they
+/// were serialized in by earlier versions of an application.
+///
+/// When a param's type changes between application versions, workspace params
+/// stored by the earlier version can no longer be deserialized. Migrations
+/// registered here are applied to the stored params when the command context
+/// is built, before they are deserialized.
+///
+/// Each param that is not in its current shape is migrated using the first
+/// migration whose older type the stored value deserializes as.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let workspace_params_registry = WorkspaceParamsRegistry::new()
+///     .with_param::<Profile>(WorkspaceParamsKey::Profile)
+///     .with_migration::<String, RepoSpec, _>(WorkspaceParamsKey::Repo, |url| RepoSpec {
+///         url,
+///         branch: None,
+///     });
+///
+/// let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(output, workspace)
+///     .with_workspace_params_registry(workspace_params_registry)
+///     // ..
+///     .await?;
+/// ```
+///
+/// # Type Parameters
+///
+/// * `K`: Type of key for the params map.
+pub struct WorkspaceParamsRegistry<K>(Vec<(K, ParamRegistration<K>)>)
+where
+    K: Eq + Hash + fmt::Debug;
+
+impl<K> WorkspaceParamsRegistry<K>
+where
+    K: Clone + fmt::Debug + Eq + Hash + DeserializeOwned + Serialize + Send + Sync + 'static,
+{
+    /// Returns a new `WorkspaceParamsRegistry`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether there are no registered params.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the number of registered params.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Registers the type of the param with the given key.
+    ///
+    /// If the key was previously registered, its type is replaced, and its
+    /// migrations are removed.
+    pub fn with_param<T>(mut self, k: K) -> Self
+    where
+        T: Clone + fmt::Debug + DeserializeOwned + Serialize + Send + Sync + 'static,
+    {
+        self.0
+            .retain(|(k_existing, _registration)| k_existing != &k);
+        self.0.push((
+            k,
+            ParamRegistration {
+                type_register: Self::type_register::<T>,
+                type_matches: Self::type_matches::<T>,
+                migrations: Vec::new(),
+            },
+        ));
+        self
+    }
+
+    /// Adds a migration for the param with the given key, from a value that
+    /// was serialized as `TOld`.
+    ///
+    /// If the key was not previously registered, it is registered with type
+    /// `T`.
+    ///
+    /// # Parameters
+    ///
+    /// * `k`: Key of the param to migrate.
+    /// * `f`: Converts the older value into the param's current type.
+    pub fn with_migration<TOld, T, F>(mut self, k: K, f: F) -> Self
+    where
+        TOld: DeserializeOwned + 'static,
+        T: Clone + fmt::Debug + DeserializeOwned + Serialize + Send + Sync + 'static,
+        F: Fn(TOld) -> T + Send + Sync + 'static,
+    {
+        if !self
+            .0
+            .iter()
+            .any(|(k_existing, _registration)| k_existing == &k)
+        {
+            self = self.with_param::<T>(k.clone());
+        }
+
+        let migration: ParamMigration = Box::new(move |value| {
+            serde_yaml::from_value::<TOld>(value.clone())
+                .ok()
+                .map(|t_old| serde_yaml::to_value(f(t_old)))
+        });
+        if let Some((_k, registration)) = self
+            .0
+            .iter_mut()
+            .find(|(k_existing, _registration)| k_existing == &k)
+        {
+            registration.migrations.push(migration);
+        }
+
+        self
+    }
+
+    /// Registers the type of each param with the given type registry.
+    pub fn register_types(&self, type_reg: &mut TypeReg<K, BoxDt>) {
+        self.0.iter().for_each(|(k, registration)| {
+            (registration.type_register)(type_reg, k.clone());
+        });
+    }
+
+    /// Migrates serialized params that are not in their current shape.
+    ///
+    /// Returns whether any param was migrated. If a migrated value fails to be
+    /// serialized, the key of the param and the error is returned.
+    ///
+    /// Params without a matching migration are left unchanged, and fail when
+    /// they are deserialized.
+    pub fn migrate(&self, params: &mut Mapping) -> Result<bool, (K, serde_yaml::Error)> {
+        self.0
+            .iter()
+            .try_fold(false, |migrated, (k, registration)| {
+                let key = serde_yaml::to_value(k).map_err(|error| (k.clone(), error))?;
+                let Some(value) = params.get_mut(&key) else {
+                    return Ok(migrated);
+                };
+                if (registration.type_matches)(value) {
+                    return Ok(migrated);
+                }
+
+                let value_migrated = registration
+                    .migrations
+                    .iter()
+                    .find_map(|migration| migration(value))
+                    .transpose()
+                    .map_err(|error| (k.clone(), error))?;
+
+                match value_migrated {
+                    Some(value_migrated) => {
+                        *value = value_migrated;
+                        Ok(true)
+                    }
+                    None => Ok(migrated),
+                }
+            })
+    }
+
+    fn type_register<T>(type_reg: &mut TypeReg<K, BoxDt>, k: K)
+    where
+        T: Clone + fmt::Debug + DeserializeOwned + Serialize + Send + Sync + 'static,
+    {
+        type_reg.register::<T>(k);
+    }
+
+    fn type_matches<T>(value: &Value) -> bool
+    where
+        T: DeserializeOwned,
+    {
+        serde_yaml::from_value::<T>(value.clone()).is_ok()
+    }
+}
+
+impl<K> fmt::Debug for WorkspaceParamsRegistry<K>
+where
+    K: Eq + Hash + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter().map(|(k, _registration)| k))
+            .finish()
+    }
+}
+
+impl<K> Default for WorkspaceParamsRegistry<K>
+where
+    K: Eq + Hash + fmt::Debug,
+{
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// How to handle item params that changed since current states were last
+/// stored.
+///
+/// Params that change silently between runs lead to confusing diffs, as the
+/// stored state was discovered or applied with different params.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ParamsChangePolicy {
+    /// Do not check whether params changed.
+    Ignore,
+    /// Present a warning listing the affected items.
+    #[default]
+    Warn,
+    /// Return [`Error::ParamsChanged`] listing the affected items.
+    ///
+    /// [`Error::ParamsChanged`]: crate::Error::ParamsChanged
+    Fail,
+}
@@ -0,0 +1,82 @@
+use std::ops::{Deref, DerefMut};
+
+use indexmap::IndexMap;
+use peace_core::ItemId;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Digest of each item's resolved params.
+///
+/// `IndexMap<ItemId, String>` newtype.
+///
+/// This is stored in the flow directory whenever current states are stored,
+/// so that later commands can detect params that changed since then.
+///
+/// Workspace, profile, and flow params are not recorded separately. Changes
+/// to them are detected through the item params that are resolved from them,
+/// so only items that are affected by a change are reported.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ParamsDigests(IndexMap<ItemId, String>);
+
+impl ParamsDigests {
+    /// Returns a new empty `ParamsDigests` map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new `ParamsDigests` map with the given preallocated
+    /// capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(IndexMap::with_capacity(capacity))
+    }
+
+    /// Returns the underlying map.
+    pub fn into_inner(self) -> IndexMap<ItemId, String> {
+        self.0
+    }
+
+    /// Returns the hex encoded SHA-256 digest of the given serialized params.
+    pub fn digest(params_serialized: &[u8]) -> String {
+        Sha256::digest(params_serialized)
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+
+    /// Returns the IDs of items whose params digest differs from the stored
+    /// digest.
+    ///
+    /// Items without a digest in either map are not included, as their params
+    /// were not resolvable, or were not recorded.
+    pub fn items_changed(&self, params_digests_stored: &ParamsDigests) -> Vec<ItemId> {
+        self.0
+            .iter()
+            .filter_map(|(item_id, params_digest)| {
+                params_digests_stored
+                    .get(item_id)
+                    .filter(|params_digest_stored| *params_digest_stored != params_digest)
+                    .map(|_| item_id.clone())
+            })
+            .collect()
+    }
+}
+
+impl Deref for ParamsDigests {
+    type Target = IndexMap<ItemId, String>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ParamsDigests {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl FromIterator<(ItemId, String)> for ParamsDigests {
+    fn from_iter<I: IntoIterator<Item = (ItemId, String)>>(iter: I) -> Self {
+        Self(IndexMap::from_iter(iter))
+    }
+}
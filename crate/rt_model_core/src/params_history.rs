@@ -0,0 +1,125 @@
+use std::ops::Deref;
+
+use peace_core::FlowId;
+use peace_fmt::{Presentable, Presenter};
+use serde::{Deserialize, Serialize};
+
+pub use self::{
+    param_change::ParamChange, params_kind::ParamsKind, params_revision::ParamsRevision,
+    params_revision_diff::ParamsRevisionDiff,
+};
+
+mod param_change;
+mod params_kind;
+mod params_revision;
+mod params_revision_diff;
+
+/// Revisions of the workspace, profile, and flow params used with a profile.
+///
+/// `Vec<ParamsRevision>` newtype, ordered from oldest to newest.
+///
+/// A revision is recorded whenever a command context is built with params that
+/// differ from the most recent revision for the same flow. At most
+/// [`REVISIONS_MAX`] revisions are kept, so the oldest revisions are pruned
+/// when a new one is recorded, except for the most recent revision of each
+/// flow.
+///
+/// [`REVISIONS_MAX`]: Self::REVISIONS_MAX
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ParamsHistory(Vec<ParamsRevision>);
+
+impl ParamsHistory {
+    /// Maximum number of revisions to keep.
+    pub const REVISIONS_MAX: usize = 32;
+
+    /// Returns a new empty `ParamsHistory`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the underlying revisions.
+    pub fn into_inner(self) -> Vec<ParamsRevision> {
+        self.0
+    }
+
+    /// Returns the revision with the given number, if it has not been pruned.
+    pub fn revision(&self, revision: u64) -> Option<&ParamsRevision> {
+        self.0
+            .iter()
+            .find(|params_revision| params_revision.revision == revision)
+    }
+
+    /// Returns the most recent revision recorded for the given flow.
+    ///
+    /// Pass `None` for revisions recorded by command contexts without a flow.
+    pub fn revision_latest(&self, flow_id: Option<&FlowId>) -> Option<&ParamsRevision> {
+        self.0
+            .iter()
+            .rev()
+            .find(|params_revision| params_revision.flow_id.as_ref() == flow_id)
+    }
+
+    /// Returns the number to use for the next revision.
+    pub fn revision_next(&self) -> u64 {
+        self.0
+            .last()
+            .map(|params_revision| params_revision.revision + 1)
+            .unwrap_or(0)
+    }
+
+    /// Records a new revision, returning the revisions that were pruned.
+    ///
+    /// The caller is responsible for removing the params files of the pruned
+    /// revisions.
+    pub fn push(&mut self, params_revision: ParamsRevision) -> Vec<ParamsRevision> {
+        self.0.push(params_revision);
+
+        let mut revisions_to_prune = self.0.len().saturating_sub(Self::REVISIONS_MAX);
+        let mut params_revisions_pruned = Vec::with_capacity(revisions_to_prune);
+        let mut index = 0;
+        while revisions_to_prune > 0 && index < self.0.len() {
+            let params_revision = &self.0[index];
+            let is_latest_for_flow = self.0[index + 1..].iter().all(|params_revision_newer| {
+                params_revision_newer.flow_id != params_revision.flow_id
+            });
+
+            if is_latest_for_flow {
+                index += 1;
+            } else {
+                params_revisions_pruned.push(self.0.remove(index));
+                revisions_to_prune -= 1;
+            }
+        }
+
+        params_revisions_pruned
+    }
+}
+
+impl Deref for ParamsHistory {
+    type Target = Vec<ParamsRevision>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl FromIterator<ParamsRevision> for ParamsHistory {
+    fn from_iter<I: IntoIterator<Item = ParamsRevision>>(iter: I) -> Self {
+        Self(Vec::from_iter(iter))
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for ParamsHistory {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        if self.0.is_empty() {
+            return presenter.text("No params revisions recorded.\n").await;
+        }
+        presenter
+            .list_bulleted_with(self.0.iter(), ParamsRevision::to_string)
+            .await
+    }
+}
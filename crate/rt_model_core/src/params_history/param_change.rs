@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use serde_yaml::{Mapping, Value};
+
+use crate::params_history::ParamsKind;
+
+/// Change to a single param between two params revisions.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ParamChange {
+    /// Kind of params that the param belongs to.
+    pub params_kind: ParamsKind,
+    /// Key of the param, serialized.
+    pub key: String,
+    /// Value in the earlier revision, `None` if the param was added.
+    pub value_from: Option<Value>,
+    /// Value in the later revision, `None` if the param was removed.
+    pub value_to: Option<Value>,
+}
+
+impl ParamChange {
+    /// Returns the changes between two serialized params maps.
+    ///
+    /// Params are listed in the order they appear in `params_from`, followed
+    /// by params that are only in `params_to`.
+    pub fn changes(
+        params_kind: ParamsKind,
+        params_from: &Mapping,
+        params_to: &Mapping,
+    ) -> Vec<Self> {
+        let params_changed_or_removed = params_from.iter().filter_map(|(key, value_from)| {
+            let value_to = params_to.get(key);
+            if value_to == Some(value_from) {
+                None
+            } else {
+                Some(Self {
+                    params_kind,
+                    key: Self::key_to_string(key),
+                    value_from: Some(value_from.clone()),
+                    value_to: value_to.cloned(),
+                })
+            }
+        });
+        let params_added = params_to
+            .iter()
+            .filter(|(key, _value_to)| !params_from.contains_key(*key))
+            .map(|(key, value_to)| Self {
+                params_kind,
+                key: Self::key_to_string(key),
+                value_from: None,
+                value_to: Some(value_to.clone()),
+            });
+
+        params_changed_or_removed.chain(params_added).collect()
+    }
+
+    fn key_to_string(key: &Value) -> String {
+        match serde_yaml::to_string(key) {
+            Ok(key) => key.trim_end().to_string(),
+            Err(_) => format!("{key:?}"),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Kind of params recorded in a params revision.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub enum ParamsKind {
+    /// Params shared by all profiles in the workspace.
+    Workspace,
+    /// Params for the profile.
+    Profile,
+    /// Params for the flow within the profile.
+    Flow,
+}
+
+impl fmt::Display for ParamsKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Workspace => "workspace".fmt(f),
+            Self::Profile => "profile".fmt(f),
+            Self::Flow => "flow".fmt(f),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use peace_core::FlowId;
+use serde::{Deserialize, Serialize};
+
+/// Record of the params used with a profile, from when they changed.
+///
+/// The params themselves are stored in the revision's `ParamsRevisionDir`.
+/// Only digests are stored here, so that changes can be detected without
+/// reading each revision's params.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ParamsRevision {
+    /// Number of this revision, unique within the profile.
+    pub revision: u64,
+    /// When this revision was recorded.
+    pub recorded_at: DateTime<Utc>,
+    /// User that recorded this revision, if known.
+    pub recorded_by: Option<String>,
+    /// Flow that the command context was built with, if any.
+    pub flow_id: Option<FlowId>,
+    /// Digest of the serialized workspace params.
+    pub workspace_params_digest: String,
+    /// Digest of the serialized profile params.
+    pub profile_params_digest: String,
+    /// Digest of the serialized flow params.
+    pub flow_params_digest: String,
+}
+
+impl ParamsRevision {
+    /// Returns whether this revision's params have the same digests as the
+    /// given digests.
+    pub fn params_digests_eq(
+        &self,
+        workspace_params_digest: &str,
+        profile_params_digest: &str,
+        flow_params_digest: &str,
+    ) -> bool {
+        self.workspace_params_digest == workspace_params_digest
+            && self.profile_params_digest == profile_params_digest
+            && self.flow_params_digest == flow_params_digest
+    }
+}
+
+impl fmt::Display for ParamsRevision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            revision,
+            recorded_at,
+            recorded_by,
+            flow_id,
+            ..
+        } = self;
+        write!(f, "{revision}: {}", recorded_at.to_rfc3339())?;
+        if let Some(recorded_by) = recorded_by {
+            write!(f, " by {recorded_by}")?;
+        }
+        if let Some(flow_id) = flow_id {
+            write!(f, " for `{flow_id}`")?;
+        }
+        Ok(())
+    }
+}
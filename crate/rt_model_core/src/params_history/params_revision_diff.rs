@@ -0,0 +1,94 @@
+use std::fmt;
+
+use peace_fmt::{Presentable, Presenter};
+use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
+
+use crate::params_history::ParamChange;
+
+/// Changes to params between two params revisions.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ParamsRevisionDiff {
+    /// Number of the earlier revision.
+    pub revision_from: u64,
+    /// Number of the later revision.
+    pub revision_to: u64,
+    /// Params that were added, removed, or changed.
+    pub param_changes: Vec<ParamChange>,
+}
+
+impl ParamsRevisionDiff {
+    /// Returns a new `ParamsRevisionDiff`.
+    pub fn new(revision_from: u64, revision_to: u64, param_changes: Vec<ParamChange>) -> Self {
+        Self {
+            revision_from,
+            revision_to,
+            param_changes,
+        }
+    }
+
+    /// Returns whether the params are the same in both revisions.
+    pub fn is_empty(&self) -> bool {
+        self.param_changes.is_empty()
+    }
+}
+
+impl fmt::Display for ParamsRevisionDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            revision_from,
+            revision_to,
+            param_changes,
+        } = self;
+        writeln!(
+            f,
+            "Params changes from revision {revision_from} to {revision_to}:"
+        )?;
+
+        if param_changes.is_empty() {
+            return writeln!(f, "No changes.");
+        }
+
+        param_changes.iter().try_for_each(|param_change| {
+            let ParamChange {
+                params_kind,
+                key,
+                value_from,
+                value_to,
+            } = param_change;
+            match (value_from, value_to) {
+                (None, Some(value_to)) => {
+                    writeln!(f, "+ {params_kind}.{key}: {}", value_display(value_to))
+                }
+                (Some(value_from), None) => {
+                    writeln!(f, "- {params_kind}.{key}: {}", value_display(value_from))
+                }
+                (Some(value_from), Some(value_to)) => writeln!(
+                    f,
+                    "~ {params_kind}.{key}: {} -> {}",
+                    value_display(value_from),
+                    value_display(value_to)
+                ),
+                (None, None) => Ok(()),
+            }
+        })
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for ParamsRevisionDiff {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        presenter.text(&self.to_string()).await
+    }
+}
+
+/// Returns the value serialized as single line JSON, which is also valid YAML.
+fn value_display(value: &Value) -> String {
+    match serde_json::to_string(value) {
+        Ok(value) => value,
+        Err(_) => format!("{value:?}"),
+    }
+}
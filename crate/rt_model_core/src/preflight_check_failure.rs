@@ -0,0 +1,33 @@
+use std::fmt;
+
+/// A flow preflight check that failed, and the reason it failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreflightCheckFailure {
+    /// Name of the check, e.g. `"docker installed"`.
+    check_name: String,
+    /// Why the check failed.
+    reason: String,
+}
+
+impl PreflightCheckFailure {
+    /// Returns a new `PreflightCheckFailure`.
+    pub fn new(check_name: String, reason: String) -> Self {
+        Self { check_name, reason }
+    }
+
+    /// Returns the name of the check.
+    pub fn check_name(&self) -> &str {
+        &self.check_name
+    }
+
+    /// Returns why the check failed.
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl fmt::Display for PreflightCheckFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.check_name, self.reason)
+    }
+}
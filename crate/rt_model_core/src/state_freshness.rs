@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// Whether an item's state was discovered, or reused from stored state.
+///
+/// This is returned from incremental discovery, where only items affected by a
+/// change are rediscovered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum StateFreshness {
+    /// The state was discovered during this command's execution.
+    Discovered,
+    /// The state was read from the stored states, and may be stale.
+    Stored,
+}
+
+impl StateFreshness {
+    /// Returns `true` if the state was read from the stored states.
+    pub fn is_stored(self) -> bool {
+        matches!(self, Self::Stored)
+    }
+}
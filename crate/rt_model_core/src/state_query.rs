@@ -0,0 +1,105 @@
+use peace_cfg::state::SensitiveSerdeMode;
+use peace_core::ItemId;
+use peace_resources::states::States;
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+
+/// Query for a single item's state, or a field within it.
+///
+/// The queried state is returned as a [`serde_json::Value`], so that it can be
+/// used in scripts without knowing the concrete state type, e.g.:
+///
+/// ```bash
+/// myapp state get server --field ip_addr --format json
+/// ```
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let state_query = StateQuery::new(item_id!("server")).with_field("ip_addr");
+/// let ip_addr = state_query.query(&states_current_stored)?;
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateQuery {
+    /// ID of the item whose state to query.
+    item_id: ItemId,
+    /// JSON pointer to the field within the state to query.
+    field_pointer: Option<String>,
+}
+
+impl StateQuery {
+    /// Returns a new `StateQuery` for the whole state of an item.
+    pub fn new(item_id: ItemId) -> Self {
+        Self {
+            item_id,
+            field_pointer: None,
+        }
+    }
+
+    /// Sets the field within the state to query.
+    ///
+    /// This is a [JSON pointer], e.g. `/ip_addr` or `/servers/0/name`. The
+    /// leading `/` may be omitted.
+    ///
+    /// [JSON pointer]: https://datatracker.ietf.org/doc/html/rfc6901
+    pub fn with_field<F>(mut self, field: F) -> Self
+    where
+        F: Into<String>,
+    {
+        let field = field.into();
+        let field_pointer = if field.is_empty() || field.starts_with('/') {
+            field
+        } else {
+            format!("/{field}")
+        };
+        self.field_pointer = Some(field_pointer);
+        self
+    }
+
+    /// Returns the ID of the item whose state to query.
+    pub fn item_id(&self) -> &ItemId {
+        &self.item_id
+    }
+
+    /// Returns the JSON pointer to the field within the state to query.
+    pub fn field_pointer(&self) -> Option<&str> {
+        self.field_pointer.as_deref()
+    }
+
+    /// Returns the queried state or state field from the given states.
+    pub fn query<TS>(&self, states: &States<TS>) -> Result<serde_json::Value, Error> {
+        let Self {
+            item_id,
+            field_pointer,
+        } = self;
+
+        let state = states
+            .get_raw(item_id)
+            .ok_or_else(|| Error::StateQueryItemNotFound {
+                item_id: item_id.clone(),
+            })?;
+        // Queried values are presented to the user, so sensitive values are
+        // redacted.
+        let state = SensitiveSerdeMode::Redact
+            .scope(|| serde_json::to_value(state))
+            .map_err(|error| Error::StateQuerySerializeJson {
+                item_id: item_id.clone(),
+                error,
+            })?;
+
+        match field_pointer {
+            Some(field_pointer) => {
+                let mut state = state;
+                state
+                    .pointer_mut(field_pointer)
+                    .map(serde_json::Value::take)
+                    .ok_or_else(|| Error::StateQueryFieldNotFound {
+                        item_id: item_id.clone(),
+                        field_pointer: field_pointer.clone(),
+                    })
+            }
+            None => Ok(state),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// Order of entries when states are written to storage.
+///
+/// Both orderings are deterministic, so states files only change when the
+/// states themselves change, which keeps diffs of committed states files
+/// small.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum StatesOrdering {
+    /// Items are written in the order they were added to the item graph.
+    ///
+    /// Each item's state is written as the state type serializes itself.
+    #[default]
+    Graph,
+    /// Items are written sorted by item ID.
+    ///
+    /// Map keys within each item's state are sorted as well, so states that
+    /// contain `HashMap`s are written in the same order every time.
+    Lexicographic,
+}
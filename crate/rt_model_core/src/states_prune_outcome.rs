@@ -0,0 +1,86 @@
+use peace_core::ItemId;
+use peace_fmt::{presentable::HeadingLevel, Presentable, Presenter};
+use serde::{Deserialize, Serialize};
+
+/// Stored states of items that are no longer in the flow, and whether they
+/// were pruned.
+///
+/// A stored state is only pruned when the item's resource is confirmed to be
+/// cleaned up. Item IDs in [`item_ids_exist`] and [`item_ids_unconfirmed`]
+/// are presented as a warning.
+///
+/// [`item_ids_exist`]: Self::item_ids_exist
+/// [`item_ids_unconfirmed`]: Self::item_ids_unconfirmed
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct StatesPruneOutcome {
+    /// Items whose resources are cleaned up, and whose stored states were
+    /// removed.
+    pub item_ids_pruned: Vec<ItemId>,
+    /// Items whose resources still exist, whose stored states were kept.
+    pub item_ids_exist: Vec<ItemId>,
+    /// Items whose resources could not be discovered, whose stored states
+    /// were kept.
+    ///
+    /// This includes items that were not provided to discover the resource
+    /// with, and items whose params spec is not stored.
+    pub item_ids_unconfirmed: Vec<ItemId>,
+}
+
+impl StatesPruneOutcome {
+    /// Returns whether there are no stored states for items that are not in
+    /// the flow.
+    pub fn is_empty(&self) -> bool {
+        self.item_ids_pruned.is_empty()
+            && self.item_ids_exist.is_empty()
+            && self.item_ids_unconfirmed.is_empty()
+    }
+
+    /// Returns whether any stored states were kept.
+    pub fn has_warnings(&self) -> bool {
+        !self.item_ids_exist.is_empty() || !self.item_ids_unconfirmed.is_empty()
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for StatesPruneOutcome {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        presenter
+            .heading(HeadingLevel::Level1, "States Prune")
+            .await?;
+
+        if self.is_empty() {
+            return presenter
+                .text("There are no stored states for items that are not in the flow.\n")
+                .await;
+        }
+
+        if !self.item_ids_pruned.is_empty() {
+            presenter
+                .text("Removed stored states for cleaned up items:\n\n")
+                .await?;
+            presenter.list_bulleted(self.item_ids_pruned.iter()).await?;
+        }
+        if !self.item_ids_exist.is_empty() {
+            presenter
+                .text("\nWarning: resources for these removed items still exist:\n\n")
+                .await?;
+            presenter.list_bulleted(self.item_ids_exist.iter()).await?;
+            presenter
+                .text("\nClean up these resources before removing the items from the flow.\n")
+                .await?;
+        }
+        if !self.item_ids_unconfirmed.is_empty() {
+            presenter
+                .text("\nWarning: could not confirm that resources for these removed items are cleaned up:\n\n")
+                .await?;
+            presenter
+                .list_bulleted(self.item_ids_unconfirmed.iter())
+                .await?;
+        }
+
+        Ok(())
+    }
+}
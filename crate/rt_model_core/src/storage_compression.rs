@@ -0,0 +1,127 @@
+use std::{
+    fmt,
+    io::{self, Cursor, Read, Write},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Compression applied to stored states and params files.
+///
+/// Flows whose states embed large manifests can produce large states files,
+/// which compress well. Compressed files are detected by their leading magic
+/// bytes when read, so a workspace can be read regardless of the compression
+/// it was written with, and switching compression does not require existing
+/// files to be migrated.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum StorageCompression {
+    /// Files are stored uncompressed.
+    #[default]
+    None,
+    /// Files are compressed with [gzip](https://www.gzip.org/).
+    Gzip,
+    /// Files are compressed with [zstd](https://facebook.github.io/zstd/).
+    ///
+    /// zstd compressed files are detected and decompressed when read, but
+    /// files cannot be written with zstd yet, as there is no pure Rust zstd
+    /// encoder available. Storing files with this compression returns an
+    /// [`io::ErrorKind::Unsupported`] error.
+    Zstd,
+}
+
+impl StorageCompression {
+    /// Leading bytes of gzip compressed data.
+    const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+    /// Leading bytes of a zstd frame.
+    const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+    /// Returns the compression of the given bytes, detected from their leading
+    /// magic bytes.
+    ///
+    /// Bytes that do not begin with a known magic number are treated as
+    /// uncompressed.
+    pub fn detect(bytes: &[u8]) -> Self {
+        if bytes.starts_with(&Self::ZSTD_MAGIC) {
+            Self::Zstd
+        } else if bytes.starts_with(&Self::GZIP_MAGIC) {
+            Self::Gzip
+        } else {
+            Self::None
+        }
+    }
+
+    /// Compresses the given bytes with this compression.
+    ///
+    /// The bytes are returned as is for [`StorageCompression::None`], and an
+    /// [`io::ErrorKind::Unsupported`] error is returned for
+    /// [`StorageCompression::Zstd`].
+    pub fn compress(self, bytes: Vec<u8>) -> Result<Vec<u8>, io::Error> {
+        match self {
+            Self::None => Ok(bytes),
+            Self::Gzip => {
+                let mut encoder =
+                    flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder.write_all(&bytes)?;
+                encoder.finish()
+            }
+            Self::Zstd => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "zstd compression is only supported when reading files; use gzip to store files",
+            )),
+        }
+    }
+
+    /// Decompresses the given bytes, with the compression detected from their
+    /// leading magic bytes.
+    pub fn decompress(bytes: Vec<u8>) -> Result<Vec<u8>, io::Error> {
+        if Self::detect(&bytes) == Self::None {
+            return Ok(bytes);
+        }
+
+        let mut decompressed = Vec::new();
+        Self::decompressing_reader(bytes.as_slice())?.read_to_end(&mut decompressed)?;
+        Ok(decompressed)
+    }
+
+    /// Returns a reader that decompresses the given reader's bytes, with the
+    /// compression detected from their leading magic bytes.
+    ///
+    /// Uncompressed bytes are read through unchanged.
+    pub fn decompressing_reader<'r, R>(mut reader: R) -> Result<Box<dyn Read + 'r>, io::Error>
+    where
+        R: Read + 'r,
+    {
+        let mut magic = [0u8; Self::ZSTD_MAGIC.len()];
+        let mut magic_len = 0;
+        while magic_len < magic.len() {
+            match reader.read(&mut magic[magic_len..]) {
+                Ok(0) => break,
+                Ok(n) => magic_len += n,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => return Err(error),
+            }
+        }
+
+        let magic = &magic[..magic_len];
+        let reader = Cursor::new(magic.to_vec()).chain(reader);
+        let reader: Box<dyn Read + 'r> = match Self::detect(magic) {
+            Self::None => Box::new(reader),
+            Self::Gzip => Box::new(flate2::read::GzDecoder::new(reader)),
+            Self::Zstd => Box::new(
+                ruzstd::StreamingDecoder::new(reader)
+                    .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?,
+            ),
+        };
+
+        Ok(reader)
+    }
+}
+
+impl fmt::Display for StorageCompression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+            Self::Gzip => write!(f, "gzip"),
+            Self::Zstd => write!(f, "zstd"),
+        }
+    }
+}
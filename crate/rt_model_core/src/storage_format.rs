@@ -0,0 +1,138 @@
+use std::{
+    fmt::{self, Debug},
+    hash::Hash,
+    io::{Read, Write},
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use type_reg::{
+    common::UnknownEntriesSome,
+    untagged::{DataTypeWrapper, TypeMapOpt, TypeReg},
+};
+
+use crate::StorageFormatError;
+
+/// Format that states are serialized in when stored in a workspace.
+///
+/// YAML is the default, as it is easy to read and edit by hand. JSON and CBOR
+/// are faster to read and write, and CBOR is more compact, which matters for
+/// flows with large state maps.
+///
+/// The states file names are the same regardless of the format, so a
+/// workspace should be read with the same format that it was written with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum StorageFormat {
+    /// Human readable YAML.
+    #[default]
+    Yaml,
+    /// JSON.
+    Json,
+    /// Compact binary [CBOR](https://cbor.io/).
+    Cbor,
+}
+
+impl StorageFormat {
+    /// Returns whether this format is binary, as opposed to text.
+    pub fn is_binary(self) -> bool {
+        match self {
+            Self::Yaml | Self::Json => false,
+            Self::Cbor => true,
+        }
+    }
+
+    /// Serializes `t` to the given writer in this format.
+    pub fn to_writer<W, T>(self, writer: W, t: &T) -> Result<(), StorageFormatError>
+    where
+        W: Write,
+        T: Serialize + ?Sized,
+    {
+        match self {
+            Self::Yaml => serde_yaml::to_writer(writer, t).map_err(StorageFormatError::Yaml),
+            Self::Json => serde_json::to_writer(writer, t).map_err(StorageFormatError::Json),
+            Self::Cbor => {
+                ciborium::into_writer(t, writer).map_err(StorageFormatError::CborSerialize)
+            }
+        }
+    }
+
+    /// Serializes `t` to bytes in this format.
+    pub fn to_vec<T>(self, t: &T) -> Result<Vec<u8>, StorageFormatError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let mut bytes = Vec::new();
+        self.to_writer(&mut bytes, t)?;
+        Ok(bytes)
+    }
+
+    /// Deserializes a `T` from the given reader in this format.
+    pub fn from_reader<R, T>(self, reader: R) -> Result<T, StorageFormatError>
+    where
+        R: Read,
+        T: DeserializeOwned,
+    {
+        match self {
+            Self::Yaml => serde_yaml::from_reader(reader).map_err(StorageFormatError::Yaml),
+            Self::Json => serde_json::from_reader(reader).map_err(StorageFormatError::Json),
+            Self::Cbor => {
+                ciborium::from_reader(reader).map_err(StorageFormatError::CborDeserialize)
+            }
+        }
+    }
+
+    /// Deserializes a typemap from the given reader in this format.
+    ///
+    /// Entries whose keys are not in the type registry are deserialized as
+    /// `serde_yaml::Value`s.
+    pub fn typemap_from_reader<R, K, BoxDT>(
+        self,
+        type_reg: &TypeReg<K, BoxDT>,
+        reader: R,
+    ) -> Result<TypeMapOpt<K, BoxDT, UnknownEntriesSome<serde_yaml::Value>>, StorageFormatError>
+    where
+        R: Read,
+        K: Clone + Debug + DeserializeOwned + Eq + Hash + 'static,
+        BoxDT: DataTypeWrapper + 'static,
+    {
+        match self {
+            Self::Yaml => {
+                let deserializer = serde_yaml::Deserializer::from_reader(reader);
+                type_reg
+                    .deserialize_map_opt_with_unknowns::<'_, serde_yaml::Value, _, _>(deserializer)
+                    .map_err(StorageFormatError::Yaml)
+            }
+            Self::Json => {
+                let mut deserializer = serde_json::Deserializer::from_reader(reader);
+                type_reg
+                    .deserialize_map_opt_with_unknowns::<'_, serde_yaml::Value, _, _>(
+                        &mut deserializer,
+                    )
+                    .map_err(StorageFormatError::Json)
+            }
+            Self::Cbor => {
+                // `ciborium` does not expose its `Deserializer`, so the map is read into
+                // an intermediate value before being deserialized by the type registry.
+                let value = ciborium::from_reader::<serde_yaml::Value, _>(reader)
+                    .map_err(StorageFormatError::CborDeserialize)?;
+                type_reg
+                    .deserialize_map_opt_with_unknowns::<'_, serde_yaml::Value, _, _>(value)
+                    .map_err(|error| {
+                        StorageFormatError::CborDeserialize(ciborium::de::Error::semantic(
+                            None,
+                            error.to_string(),
+                        ))
+                    })
+            }
+        }
+    }
+}
+
+impl fmt::Display for StorageFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Yaml => write!(f, "YAML"),
+            Self::Json => write!(f, "JSON"),
+            Self::Cbor => write!(f, "CBOR"),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use indexmap::IndexMap;
+use peace_cmd_model::{ItemFnKind, TimingBreakdown};
+use peace_core::ItemId;
+use peace_resources::Resources;
+
+/// Records the time spent in each item's functions while a command executes.
+///
+/// This is inserted into `Resources` at the start of each command execution,
+/// and the [`TimingBreakdown`] of each `CmdBlock` is attached to the
+/// `CmdOutcome` when the command finishes.
+#[derive(Debug, Default)]
+pub struct TimingBreakdownRecorder {
+    /// Index of the `CmdBlock` that is executing.
+    cmd_block_index: AtomicUsize,
+    /// Timings recorded for each `CmdBlock`, keyed by its index.
+    timing_breakdowns: Mutex<IndexMap<usize, TimingBreakdown>>,
+}
+
+impl TimingBreakdownRecorder {
+    /// Returns a new `TimingBreakdownRecorder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records subsequent timings against the `CmdBlock` with the given index.
+    pub fn cmd_block_start(&self, cmd_block_index: usize) {
+        self.cmd_block_index
+            .store(cmd_block_index, Ordering::SeqCst);
+    }
+
+    /// Records a call to an item's function, if a `TimingBreakdownRecorder` is
+    /// in `resources`.
+    pub fn record_in<TS>(
+        resources: &Resources<TS>,
+        item_id: &ItemId,
+        item_fn_kind: ItemFnKind,
+        duration: Duration,
+    ) {
+        if let Ok(timing_breakdown_recorder) = resources.try_borrow::<Self>() {
+            timing_breakdown_recorder.record(item_id, item_fn_kind, duration);
+        }
+    }
+
+    /// Records a call to an item's function against the executing `CmdBlock`.
+    pub fn record(&self, item_id: &ItemId, item_fn_kind: ItemFnKind, duration: Duration) {
+        let cmd_block_index = self.cmd_block_index.load(Ordering::SeqCst);
+        if let Ok(mut timing_breakdowns) = self.timing_breakdowns.lock() {
+            timing_breakdowns
+                .entry(cmd_block_index)
+                .or_default()
+                .record(item_id, item_fn_kind, duration);
+        }
+    }
+
+    /// Returns the recorded timings for each `CmdBlock`, leaving no timings in
+    /// their place.
+    pub fn take(&self) -> IndexMap<usize, TimingBreakdown> {
+        self.timing_breakdowns
+            .lock()
+            .map(|mut timing_breakdowns| std::mem::take(&mut *timing_breakdowns))
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,115 @@
+use std::{fmt, sync::Arc};
+
+use ring::{
+    aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN},
+    error::Unspecified,
+    hmac,
+    rand::{SecureRandom, SystemRandom},
+};
+
+/// Encrypts and decrypts bytes with AES-256-GCM.
+///
+/// Encrypted bytes are the nonce followed by the ciphertext. This is shared by
+/// [`EncryptedStorage`] and [`SensitiveFieldCipher`].
+///
+/// [`EncryptedStorage`]: crate::EncryptedStorage
+/// [`SensitiveFieldCipher`]: crate::SensitiveFieldCipher
+#[derive(Clone)]
+pub(crate) struct AeadCipher {
+    /// Key to encrypt and decrypt with.
+    key: Arc<LessSafeKey>,
+    /// Key to derive nonces from plaintexts with, for deterministic
+    /// encryption.
+    nonce_key: hmac::Key,
+    /// Source of randomness for random nonces.
+    rng: SystemRandom,
+}
+
+impl AeadCipher {
+    /// Context that the nonce key is derived with, so that it differs from the
+    /// encryption key.
+    const NONCE_KEY_CONTEXT: &'static [u8] = b"peace_rt_model_native::AeadCipher::nonce_key";
+
+    /// Returns a new `AeadCipher` that encrypts with the given key.
+    pub(crate) fn new(key: &[u8; 32]) -> Self {
+        let nonce_key = {
+            let nonce_key = hmac::sign(
+                &hmac::Key::new(hmac::HMAC_SHA256, key),
+                Self::NONCE_KEY_CONTEXT,
+            );
+            hmac::Key::new(hmac::HMAC_SHA256, nonce_key.as_ref())
+        };
+        let key = UnboundKey::new(&AES_256_GCM, key)
+            .expect("unreachable: `AES_256_GCM` key length is 32 bytes.");
+
+        Self {
+            key: Arc::new(LessSafeKey::new(key)),
+            nonce_key,
+            rng: SystemRandom::new(),
+        }
+    }
+
+    /// Returns the plaintext encrypted with a random nonce.
+    pub(crate) fn seal(&self, plaintext: &[u8]) -> Result<Vec<u8>, Unspecified> {
+        let mut nonce_bytes = [0u8; NONCE_LEN];
+        self.rng.fill(&mut nonce_bytes)?;
+
+        self.seal_with_nonce(nonce_bytes, plaintext)
+    }
+
+    /// Returns the plaintext encrypted with a nonce derived from the
+    /// plaintext.
+    ///
+    /// The same plaintext is always encrypted to the same bytes, so unchanged
+    /// values are stored unchanged. This reveals whether two encrypted values
+    /// are equal, but nothing else about them.
+    pub(crate) fn seal_deterministic(&self, plaintext: &[u8]) -> Result<Vec<u8>, Unspecified> {
+        let mut nonce_bytes = [0u8; NONCE_LEN];
+        nonce_bytes.copy_from_slice(&hmac::sign(&self.nonce_key, plaintext).as_ref()[..NONCE_LEN]);
+
+        self.seal_with_nonce(nonce_bytes, plaintext)
+    }
+
+    fn seal_with_nonce(
+        &self,
+        nonce_bytes: [u8; NONCE_LEN],
+        plaintext: &[u8],
+    ) -> Result<Vec<u8>, Unspecified> {
+        let nonce = Nonce::assume_unique_for_key(nonce_bytes);
+
+        let mut ciphertext = plaintext.to_vec();
+        self.key
+            .seal_in_place_append_tag(nonce, Aad::empty(), &mut ciphertext)?;
+
+        let mut contents = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+        contents.extend_from_slice(&nonce_bytes);
+        contents.extend_from_slice(&ciphertext);
+
+        Ok(contents)
+    }
+
+    /// Returns the decrypted bytes encrypted by [`AeadCipher::seal`] or
+    /// [`AeadCipher::seal_deterministic`].
+    pub(crate) fn open(&self, mut contents: Vec<u8>) -> Result<Vec<u8>, Unspecified> {
+        if contents.len() < NONCE_LEN {
+            return Err(Unspecified);
+        }
+        let mut ciphertext = contents.split_off(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(&contents)?;
+
+        let plaintext_len = self
+            .key
+            .open_in_place(nonce, Aad::empty(), &mut ciphertext)?
+            .len();
+        ciphertext.truncate(plaintext_len);
+
+        Ok(ciphertext)
+    }
+}
+
+impl fmt::Debug for AeadCipher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Don't print the keys.
+        f.debug_struct("AeadCipher").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,129 @@
+use std::{fmt, path::Path};
+
+use peace_resources::paths::PeaceDir;
+use peace_rt_model_core::{Error, NativeError};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::aead_cipher::AeadCipher;
+
+/// Encrypts and decrypts files under the `.peace` directory.
+///
+/// Files are encrypted with AES-256-GCM, and are stored as a random nonce
+/// followed by the ciphertext.
+///
+/// This is used by [`Storage`] when the workspace is created with
+/// [`WorkspaceSpec::PathEncrypted`], so states and params are never stored in
+/// plain text.
+///
+/// [`Storage`]: crate::Storage
+/// [`WorkspaceSpec::PathEncrypted`]: crate::WorkspaceSpec::PathEncrypted
+#[derive(Clone)]
+pub struct EncryptedStorage {
+    /// Directory whose files are encrypted.
+    peace_dir: PeaceDir,
+    /// Cipher to encrypt and decrypt files with.
+    aead_cipher: AeadCipher,
+}
+
+impl EncryptedStorage {
+    /// Returns a new `EncryptedStorage` that encrypts files under `peace_dir`.
+    pub fn new(peace_dir: PeaceDir, key: &[u8; 32]) -> Self {
+        Self {
+            peace_dir,
+            aead_cipher: AeadCipher::new(key),
+        }
+    }
+
+    /// Returns the directory whose files are encrypted.
+    pub fn peace_dir(&self) -> &PeaceDir {
+        &self.peace_dir
+    }
+
+    /// Returns whether the file at the given path is encrypted.
+    pub fn is_encrypted(&self, file_path: &Path) -> bool {
+        file_path.starts_with(&self.peace_dir)
+    }
+
+    /// Reads and decrypts a serializable item from the given path.
+    ///
+    /// # Parameters
+    ///
+    /// * `file_path`: Path to the file to read the serialized item.
+    /// * `f_map_err`: Maps the deserialization error (if any) to an [`Error`].
+    pub async fn serialized_read<T, F>(&self, file_path: &Path, f_map_err: F) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+        F: FnOnce(serde_yaml::Error) -> Error,
+    {
+        let plaintext = self.read(file_path).await?;
+        serde_yaml::from_slice::<T>(&plaintext).map_err(f_map_err)
+    }
+
+    /// Serializes and encrypts an item to the given path.
+    ///
+    /// # Parameters
+    ///
+    /// * `file_path`: Path to the file to store the serialized item.
+    /// * `t`: Item to serialize.
+    /// * `f_map_err`: Maps the serialization error (if any) to an [`Error`].
+    pub async fn serialized_write<T, F>(
+        &self,
+        file_path: &Path,
+        t: &T,
+        f_map_err: F,
+    ) -> Result<(), Error>
+    where
+        T: Serialize,
+        F: FnOnce(serde_yaml::Error) -> Error,
+    {
+        let plaintext = serde_yaml::to_string(t).map_err(f_map_err)?;
+        self.write(file_path, plaintext.as_bytes()).await
+    }
+
+    /// Reads and decrypts the contents of the file at the given path.
+    pub async fn read(&self, file_path: &Path) -> Result<Vec<u8>, Error> {
+        let contents = tokio::fs::read(file_path).await.map_err(|error| {
+            let path = file_path.to_path_buf();
+            Error::Native(NativeError::FileRead { path, error })
+        })?;
+
+        self.decrypt(file_path, contents)
+    }
+
+    /// Encrypts and writes the contents to the file at the given path.
+    pub async fn write(&self, file_path: &Path, plaintext: &[u8]) -> Result<(), Error> {
+        let contents = self.encrypt(file_path, plaintext)?;
+
+        tokio::fs::write(file_path, contents)
+            .await
+            .map_err(|error| {
+                let path = file_path.to_path_buf();
+                Error::Native(NativeError::FileWrite { path, error })
+            })
+    }
+
+    fn encrypt(&self, file_path: &Path, plaintext: &[u8]) -> Result<Vec<u8>, Error> {
+        self.aead_cipher.seal(plaintext).map_err(|_| {
+            Error::Native(NativeError::FileEncrypt {
+                path: file_path.to_path_buf(),
+            })
+        })
+    }
+
+    fn decrypt(&self, file_path: &Path, contents: Vec<u8>) -> Result<Vec<u8>, Error> {
+        self.aead_cipher.open(contents).map_err(|_| {
+            Error::Native(NativeError::FileDecrypt {
+                path: file_path.to_path_buf(),
+            })
+        })
+    }
+}
+
+impl fmt::Debug for EncryptedStorage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Don't print the key.
+        f.debug_struct("EncryptedStorage")
+            .field("peace_dir", &self.peace_dir)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,80 @@
+use std::{fmt, sync::Arc};
+
+use base64::Engine;
+
+/// Error returned when a [`KeyProvider`] cannot provide a key.
+pub type KeyProviderError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// Provides the 256 bit key used to encrypt files in an encrypted workspace.
+///
+/// The key is requested once, when the [`Workspace`] is created.
+///
+/// # Examples
+///
+/// ```rust
+/// use peace_rt_model_native::KeyProvider;
+///
+/// // Key read from an environment variable, encoded as base64.
+/// let key_provider = KeyProvider::from_env("APP_WORKSPACE_KEY");
+///
+/// // Key fetched from a secret store.
+/// let key_provider = KeyProvider::new(|| {
+///     // e.g. read from the OS keychain.
+///     Ok([0u8; 32])
+/// });
+/// ```
+///
+/// [`Workspace`]: crate::Workspace
+#[derive(Clone)]
+pub struct KeyProvider(Arc<dyn Fn() -> Result<[u8; 32], KeyProviderError> + Send + Sync>);
+
+impl KeyProvider {
+    /// Returns a new `KeyProvider` that calls `f` to provide the key.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn() -> Result<[u8; 32], KeyProviderError> + Send + Sync + 'static,
+    {
+        Self(Arc::new(f))
+    }
+
+    /// Returns a new `KeyProvider` that always provides the given key.
+    pub fn from_key(key: [u8; 32]) -> Self {
+        Self::new(move || Ok(key))
+    }
+
+    /// Returns a new `KeyProvider` that reads the base64 encoded key from an
+    /// environment variable.
+    pub fn from_env(env_var_name: &'static str) -> Self {
+        Self::new(move || {
+            let key_base64 = std::env::var(env_var_name)?;
+            let key = base64::engine::general_purpose::STANDARD.decode(key_base64.trim())?;
+            let key = <[u8; 32]>::try_from(key.as_slice()).map_err(|_| {
+                format!(
+                    "Expected `{env_var_name}` to contain a 32 byte key, but it contained {} bytes.",
+                    key.len()
+                )
+            })?;
+
+            Ok(key)
+        })
+    }
+
+    /// Returns the key used to encrypt the workspace.
+    pub fn key(&self) -> Result<[u8; 32], KeyProviderError> {
+        (self.0)()
+    }
+}
+
+impl fmt::Debug for KeyProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("KeyProvider").field(&"..").finish()
+    }
+}
+
+impl PartialEq for KeyProvider {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for KeyProvider {}
@@ -9,13 +9,27 @@
 pub use tokio_util::io::SyncIoBridge;
 
 pub use crate::{
-    storage::Storage, workspace::Workspace, workspace_dirs_builder::WorkspaceDirsBuilder,
-    workspace_initializer::WorkspaceInitializer, workspace_spec::WorkspaceSpec,
+    encrypted_storage::EncryptedStorage,
+    key_provider::{KeyProvider, KeyProviderError},
+    sensitive_field_cipher::SensitiveFieldCipher,
+    storage::Storage,
+    storage_codec::StorageCodec,
+    storage_transaction::StorageTransaction,
+    workspace::Workspace,
+    workspace_dirs_builder::WorkspaceDirsBuilder,
+    workspace_initializer::WorkspaceInitializer,
+    workspace_spec::WorkspaceSpec,
 };
 
 pub mod workspace;
 
+mod aead_cipher;
+mod encrypted_storage;
+mod key_provider;
+mod sensitive_field_cipher;
 mod storage;
+mod storage_codec;
+mod storage_transaction;
 mod workspace_dirs_builder;
 mod workspace_initializer;
 mod workspace_spec;
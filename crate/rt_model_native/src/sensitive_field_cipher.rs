@@ -0,0 +1,67 @@
+use std::fmt;
+
+use base64::Engine;
+use peace_cfg::state::{SensitiveCipher, SensitiveCipherError};
+
+use crate::aead_cipher::AeadCipher;
+
+/// Encrypts and decrypts [`Sensitive`] state fields.
+///
+/// Values are encrypted with AES-256-GCM, and are stored as the base64
+/// encoding of a nonce followed by the ciphertext.
+///
+/// The nonce is derived from the value, so an unchanged value is stored
+/// unchanged, and state files remain diffable. This reveals whether two
+/// sensitive values are equal, but nothing else about them.
+///
+/// This is used by [`Storage`] when the workspace is created with
+/// [`Workspace::with_sensitive_key_provider`].
+///
+/// [`Sensitive`]: peace_cfg::state::Sensitive
+/// [`Storage`]: crate::Storage
+/// [`Workspace::with_sensitive_key_provider`]: crate::Workspace::with_sensitive_key_provider
+#[derive(Clone)]
+pub struct SensitiveFieldCipher {
+    /// Cipher to encrypt and decrypt values with.
+    aead_cipher: AeadCipher,
+}
+
+impl SensitiveFieldCipher {
+    /// Returns a new `SensitiveFieldCipher` that encrypts values with the
+    /// given key.
+    pub fn new(key: &[u8; 32]) -> Self {
+        Self {
+            aead_cipher: AeadCipher::new(key),
+        }
+    }
+}
+
+impl SensitiveCipher for SensitiveFieldCipher {
+    fn encrypt(&self, plaintext: &[u8]) -> Result<String, SensitiveCipherError> {
+        let contents = self
+            .aead_cipher
+            .seal_deterministic(plaintext)
+            .map_err(|_| "Failed to encrypt sensitive value.")?;
+
+        Ok(base64::engine::general_purpose::STANDARD.encode(contents))
+    }
+
+    fn decrypt(&self, ciphertext: &str) -> Result<Vec<u8>, SensitiveCipherError> {
+        let contents = base64::engine::general_purpose::STANDARD.decode(ciphertext)?;
+
+        let plaintext = self.aead_cipher.open(contents).map_err(|_| {
+            "Failed to decrypt sensitive value. \
+            Make sure the sensitive field key is the one used to store the value."
+        })?;
+
+        Ok(plaintext)
+    }
+}
+
+impl fmt::Debug for SensitiveFieldCipher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Don't print the key.
+        f.debug_struct("SensitiveFieldCipher")
+            .finish_non_exhaustive()
+    }
+}
@@ -1,29 +1,157 @@
-use std::{fmt::Debug, hash::Hash, io::Write, path::Path, sync::Mutex};
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    io::{Read, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard},
+};
 
+use peace_cfg::state::SensitiveSerdeMode;
 use peace_resources::type_reg::{
     common::UnknownEntriesSome,
     untagged::{DataTypeWrapper, TypeMapOpt, TypeReg},
 };
-use peace_rt_model_core::{Error, NativeError};
+use peace_rt_model_core::{Error, NativeError, StatesOrdering, StorageFormat, StorageFormatError};
 use serde::{de::DeserializeOwned, Serialize};
+use tempfile::TempPath;
 use tokio::{
-    fs::File,
+    fs::{File, OpenOptions},
     io::{BufReader, BufWriter},
 };
 use tokio_util::io::SyncIoBridge;
 
+use crate::{StorageCodec, StorageTransaction};
+
+/// Destination paths of files written within a transaction, and the temporary
+/// files written for them.
+type StagedFilePaths = Vec<(PathBuf, TempPath)>;
+
 /// Wrapper around file system operations.
-#[derive(Clone, Debug)]
-pub struct Storage;
+///
+/// Items are encoded with this storage's [`StorageCodec`], which holds the
+/// encryption, [`StorageFormat`], and [`StorageCompression`] settings:
+///
+/// * When the workspace is encrypted, serialized items under the `.peace`
+///   directory are read and written through [`EncryptedStorage`].
+/// * [`Sensitive`] fields of serialized items are encrypted with the
+///   [`SensitiveFieldCipher`] if one is set, and stored in plain text within
+///   encrypted files. Otherwise they fail to serialize, rather than being
+///   stored in plain text.
+/// * The `formatted_*` functions serialize items in the codec's
+///   `StorageFormat`, while the `serialized_*` functions always use YAML.
+/// * Both families of functions compress written files with the codec's
+///   `StorageCompression`, and detect whether a file is compressed when
+///   reading it.
+///
+/// Writes to multiple files can be committed together with a
+/// [`StorageTransaction`], see [`Storage::transaction`].
+///
+/// [`EncryptedStorage`]: crate::EncryptedStorage
+/// [`SensitiveFieldCipher`]: crate::SensitiveFieldCipher
+/// [`Sensitive`]: peace_cfg::state::Sensitive
+/// [`StorageCompression`]: peace_rt_model_core::StorageCompression
+#[derive(Clone, Debug, Default)]
+pub struct Storage {
+    /// How items are encoded when they are stored in files.
+    storage_codec: StorageCodec,
+    /// Order of entries when states are written.
+    states_ordering: StatesOrdering,
+    /// Destination paths of files written within a transaction, and the
+    /// temporary files written for them.
+    ///
+    /// When this is `Some`, files are written to temporary files, which are
+    /// renamed to these paths when the transaction is committed.
+    staged_file_paths: Option<Arc<Mutex<StagedFilePaths>>>,
+}
 
 impl Storage {
+    /// Returns a new `Storage` that stores YAML files in plain text.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how items are encoded when they are stored in files.
+    pub fn with_storage_codec(mut self, storage_codec: StorageCodec) -> Self {
+        self.storage_codec = storage_codec;
+        self
+    }
+
+    /// Returns how items are encoded when they are stored in files.
+    pub fn storage_codec(&self) -> &StorageCodec {
+        &self.storage_codec
+    }
+
+    /// Returns the format that the `formatted_*` functions serialize items in.
+    pub fn storage_format(&self) -> StorageFormat {
+        self.storage_codec.storage_format()
+    }
+
+    /// Returns how [`Sensitive`] values are serialized for the given file.
+    ///
+    /// Values that are serialized outside of this storage's functions, and
+    /// then stored in the file, should be serialized within this mode's
+    /// [`SensitiveSerdeMode::scope`].
+    ///
+    /// [`Sensitive`]: peace_cfg::state::Sensitive
+    pub fn sensitive_serde_mode(&self, file_path: &Path) -> SensitiveSerdeMode {
+        self.storage_codec.sensitive_serde_mode(file_path)
+    }
+
+    /// Sets the order of entries when states are written.
+    pub fn with_states_ordering(mut self, states_ordering: StatesOrdering) -> Self {
+        self.states_ordering = states_ordering;
+        self
+    }
+
+    /// Returns the order of entries when states are written.
+    pub fn states_ordering(&self) -> StatesOrdering {
+        self.states_ordering
+    }
+
+    /// Returns a [`StorageTransaction`] to stage writes to multiple files, and
+    /// commit them together.
+    ///
+    /// Items written through the transaction's storage are written to
+    /// temporary files, and the destination files are only replaced when the
+    /// transaction is committed. If a write fails, or the process stops before
+    /// the transaction is committed, none of the destination files are changed.
+    pub fn transaction(&self) -> StorageTransaction {
+        let storage = Self {
+            staged_file_paths: Some(Arc::new(Mutex::new(Vec::new()))),
+            ..self.clone()
+        };
+
+        StorageTransaction::new(storage)
+    }
+
+    /// Returns the destination paths of files written within this storage's
+    /// transaction and their temporary files, leaving an empty list in its
+    /// place.
+    pub(crate) fn staged_file_paths_take(&self) -> StagedFilePaths {
+        self.staged_file_paths
+            .as_ref()
+            .map(|staged_file_paths| {
+                std::mem::take(&mut *Self::staged_file_paths_lock(staged_file_paths))
+            })
+            .unwrap_or_default()
+    }
+
+    fn staged_file_paths_lock(
+        staged_file_paths: &Mutex<StagedFilePaths>,
+    ) -> MutexGuard<'_, StagedFilePaths> {
+        staged_file_paths
+            .lock()
+            .expect("Expected `Storage` staged file paths lock to not be poisoned.")
+    }
+
     /// Reads a serializable item from the given path.
     ///
     /// # Parameters
     ///
     /// * `thread_name`: Name of the thread to use to do the read operation.
     /// * `file_path`: Path to the file to read the serialized item.
-    /// * `f_map_err`: Maps the deserialization error (if any) to an [`Error`].
+    /// * `f_map_err`: Maps the deserialization error (if any) to an [`Error`],
+    ///   given the decrypted and decompressed file contents that were parsed.
     pub async fn serialized_read<T, F>(
         &self,
         thread_name: String,
@@ -32,16 +160,14 @@ impl Storage {
     ) -> Result<T, Error>
     where
         T: Serialize + DeserializeOwned + Send + Sync,
-        F: FnOnce(serde_yaml::Error) -> Error + Send,
+        F: FnOnce(serde_yaml::Error, &[u8]) -> Error + Send,
     {
         if file_path.exists() {
-            let t = self
-                .read_with_sync_api(thread_name, file_path, |file| {
-                    serde_yaml::from_reader::<_, T>(file).map_err(f_map_err)
-                })
-                .await?;
-
-            Ok(t)
+            let sensitive_serde_mode = self.storage_codec.sensitive_serde_mode(file_path);
+            let bytes = self.decoded_read(thread_name, file_path).await?;
+            sensitive_serde_mode
+                .scope(|| serde_yaml::from_slice::<T>(&bytes))
+                .map_err(|error| f_map_err(error, &bytes))
         } else {
             Err(Error::ItemNotExists {
                 path: file_path.to_path_buf(),
@@ -55,7 +181,8 @@ impl Storage {
     ///
     /// * `thread_name`: Name of the thread to use to do the read operation.
     /// * `file_path`: Path to the file to read the serialized item.
-    /// * `f_map_err`: Maps the deserialization error (if any) to an [`Error`].
+    /// * `f_map_err`: Maps the deserialization error (if any) to an [`Error`],
+    ///   given the decrypted and decompressed file contents that were parsed.
     pub async fn serialized_read_opt<T, F>(
         &self,
         thread_name: String,
@@ -64,14 +191,14 @@ impl Storage {
     ) -> Result<Option<T>, Error>
     where
         T: DeserializeOwned + Send + Sync,
-        F: FnOnce(serde_yaml::Error) -> Error + Send,
+        F: FnOnce(serde_yaml::Error, &[u8]) -> Error + Send,
     {
         if file_path.exists() {
-            let t = self
-                .read_with_sync_api(thread_name, file_path, |file| {
-                    serde_yaml::from_reader::<_, T>(file).map_err(f_map_err)
-                })
-                .await?;
+            let sensitive_serde_mode = self.storage_codec.sensitive_serde_mode(file_path);
+            let bytes = self.decoded_read(thread_name, file_path).await?;
+            let t = sensitive_serde_mode
+                .scope(|| serde_yaml::from_slice::<T>(&bytes))
+                .map_err(|error| f_map_err(error, &bytes))?;
 
             Ok(Some(t))
         } else {
@@ -86,7 +213,8 @@ impl Storage {
     /// * `thread_name`: Name of the thread to use to do the read operation.
     /// * `type_reg`: Type registry with the stateful deserialization mappings.
     /// * `file_path`: Path to the file to read the serialized item.
-    /// * `f_map_err`: Maps the deserialization error (if any) to an [`Error`].
+    /// * `f_map_err`: Maps the deserialization error (if any) to an [`Error`],
+    ///   given the decrypted and decompressed file contents that were parsed.
     pub async fn serialized_typemap_read_opt<K, BoxDT, F>(
         &self,
         thread_name: String,
@@ -97,21 +225,19 @@ impl Storage {
     where
         K: Clone + Debug + DeserializeOwned + Eq + Hash + Send + Sync + 'static,
         BoxDT: DataTypeWrapper + Send + 'static,
-        F: FnOnce(serde_yaml::Error) -> Error + Send,
+        F: FnOnce(serde_yaml::Error, &[u8]) -> Error + Send,
     {
         if file_path.exists() {
-            let type_map_opt = self
-                .read_with_sync_api(thread_name, file_path, |file| {
-                    let deserializer = serde_yaml::Deserializer::from_reader(file);
-                    let type_map_opt = type_reg
-                        .deserialize_map_opt_with_unknowns::<'_, serde_yaml::Value, _, _>(
-                            deserializer,
-                        )
-                        .map_err(f_map_err)?;
-
-                    Result::<_, Error>::Ok(type_map_opt)
+            let sensitive_serde_mode = self.storage_codec.sensitive_serde_mode(file_path);
+            let bytes = self.decoded_read(thread_name, file_path).await?;
+            let deserializer = serde_yaml::Deserializer::from_slice(&bytes);
+            let type_map_opt = sensitive_serde_mode
+                .scope(|| {
+                    type_reg.deserialize_map_opt_with_unknowns::<'_, serde_yaml::Value, _, _>(
+                        deserializer,
+                    )
                 })
-                .await?;
+                .map_err(|error| f_map_err(error, &bytes))?;
 
             Ok(Some(type_map_opt))
         } else {
@@ -138,10 +264,369 @@ impl Storage {
         T: Serialize + Send + Sync,
         F: FnOnce(serde_yaml::Error) -> Error + Send,
     {
+        if self.staged_file_paths.is_some() {
+            return self
+                .serialized_write_atomic(thread_name, file_path, t, f_map_err)
+                .await;
+        }
+
+        self.serialized_write_direct(thread_name, file_path, t, f_map_err)
+            .await
+    }
+
+    /// Writes a serializable item to the given path, even within a
+    /// transaction.
+    async fn serialized_write_direct<T, F>(
+        &self,
+        thread_name: String,
+        file_path: &Path,
+        t: &T,
+        f_map_err: F,
+    ) -> Result<(), Error>
+    where
+        T: Serialize + Send + Sync,
+        F: FnOnce(serde_yaml::Error) -> Error + Send,
+    {
+        // The item is serialized before the file is opened, so that the file is
+        // left untouched if serialization fails, e.g. for `Sensitive` fields
+        // without a sensitive field key.
+        let bytes = self
+            .storage_codec
+            .sensitive_serde_mode(file_path)
+            .scope(|| serde_yaml::to_string(t))
+            .map_err(f_map_err)?;
+        let bytes = self.storage_codec.compress(file_path, bytes.into_bytes())?;
+
+        match self.storage_codec.encrypted_storage_for(file_path) {
+            Some(encrypted_storage) => encrypted_storage.write(file_path, &bytes).await,
+            None => self.bytes_write(thread_name, file_path, &bytes).await,
+        }
+    }
+
+    /// Writes a serializable item to the given path, replacing the file
+    /// atomically.
+    ///
+    /// The item is written to a uniquely named temporary file next to
+    /// `file_path`, which is synced to disk and then renamed to `file_path`,
+    /// so the file is never partially written.
+    ///
+    /// # Parameters
+    ///
+    /// * `thread_name`: Name of the thread to use to do the write operation.
+    /// * `file_path`: Path to the file to store the serialized item.
+    /// * `t`: Item to serialize.
+    /// * `f_map_err`: Maps the serialization error (if any) to an [`Error`].
+    pub async fn serialized_write_atomic<T, F>(
+        &self,
+        thread_name: String,
+        file_path: &Path,
+        t: &T,
+        f_map_err: F,
+    ) -> Result<(), Error>
+    where
+        T: Serialize + Send + Sync,
+        F: FnOnce(serde_yaml::Error) -> Error + Send,
+    {
+        let file_path_tmp = Self::file_path_tmp(file_path)?;
+
+        self.serialized_write_direct(thread_name, &file_path_tmp, t, f_map_err)
+            .await?;
+        self.file_rename_or_stage(file_path_tmp, file_path).await
+    }
+
+    /// Reads an item serialized in this storage's [`StorageFormat`] from the
+    /// given path if the file exists.
+    ///
+    /// # Parameters
+    ///
+    /// * `thread_name`: Name of the thread to use to do the read operation.
+    /// * `file_path`: Path to the file to read the serialized item.
+    /// * `f_map_err`: Maps the deserialization error (if any) to an [`Error`],
+    ///   given the decrypted and decompressed file contents that were parsed.
+    pub async fn formatted_read_opt<T, F>(
+        &self,
+        thread_name: String,
+        file_path: &Path,
+        f_map_err: F,
+    ) -> Result<Option<T>, Error>
+    where
+        T: DeserializeOwned + Send + Sync,
+        F: FnOnce(StorageFormatError, &[u8]) -> Error + Send,
+    {
+        if file_path.exists() {
+            let storage_format = self.storage_codec.storage_format();
+            let sensitive_serde_mode = self.storage_codec.sensitive_serde_mode(file_path);
+            let bytes = self.decoded_read(thread_name, file_path).await?;
+            let t = sensitive_serde_mode
+                .scope(|| storage_format.from_reader::<_, T>(bytes.as_slice()))
+                .map_err(|error| f_map_err(error, &bytes))?;
+
+            Ok(Some(t))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Deserializes a typemap serialized in this storage's [`StorageFormat`]
+    /// from the given path if the file exists.
+    ///
+    /// # Parameters
+    ///
+    /// * `thread_name`: Name of the thread to use to do the read operation.
+    /// * `type_reg`: Type registry with the stateful deserialization mappings.
+    /// * `file_path`: Path to the file to read the serialized item.
+    /// * `f_map_err`: Maps the deserialization error (if any) to an [`Error`],
+    ///   given the decrypted and decompressed file contents that were parsed.
+    pub async fn formatted_typemap_read_opt<K, BoxDT, F>(
+        &self,
+        thread_name: String,
+        type_reg: &TypeReg<K, BoxDT>,
+        file_path: &Path,
+        f_map_err: F,
+    ) -> Result<Option<TypeMapOpt<K, BoxDT, UnknownEntriesSome<serde_yaml::Value>>>, Error>
+    where
+        K: Clone + Debug + DeserializeOwned + Eq + Hash + Send + Sync + 'static,
+        BoxDT: DataTypeWrapper + Send + 'static,
+        F: FnOnce(StorageFormatError, &[u8]) -> Error + Send,
+    {
+        if file_path.exists() {
+            let storage_format = self.storage_codec.storage_format();
+            let sensitive_serde_mode = self.storage_codec.sensitive_serde_mode(file_path);
+            let bytes = self.decoded_read(thread_name, file_path).await?;
+            let type_map_opt = sensitive_serde_mode
+                .scope(|| storage_format.typemap_from_reader(type_reg, bytes.as_slice()))
+                .map_err(|error| f_map_err(error, &bytes))?;
+
+            Ok(Some(type_map_opt))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Writes an item to the given path, serialized in this storage's
+    /// [`StorageFormat`].
+    ///
+    /// # Parameters
+    ///
+    /// * `thread_name`: Name of the thread to use to do the write operation.
+    /// * `file_path`: Path to the file to store the serialized item.
+    /// * `t`: Item to serialize.
+    /// * `f_map_err`: Maps the serialization error (if any) to an [`Error`].
+    pub async fn formatted_write<T, F>(
+        &self,
+        thread_name: String,
+        file_path: &Path,
+        t: &T,
+        f_map_err: F,
+    ) -> Result<(), Error>
+    where
+        T: Serialize + Send + Sync,
+        F: FnOnce(StorageFormatError) -> Error + Send,
+    {
+        if self.staged_file_paths.is_some() {
+            return self
+                .formatted_write_atomic(thread_name, file_path, t, f_map_err)
+                .await;
+        }
+
+        self.formatted_write_direct(thread_name, file_path, t, f_map_err)
+            .await
+    }
+
+    /// Writes an item to the given path, serialized in this storage's
+    /// [`StorageFormat`], even within a transaction.
+    async fn formatted_write_direct<T, F>(
+        &self,
+        thread_name: String,
+        file_path: &Path,
+        t: &T,
+        f_map_err: F,
+    ) -> Result<(), Error>
+    where
+        T: Serialize + Send + Sync,
+        F: FnOnce(StorageFormatError) -> Error + Send,
+    {
+        // See `serialized_write_direct` for why the item is serialized first.
+        let storage_format = self.storage_codec.storage_format();
+        let bytes = self
+            .storage_codec
+            .sensitive_serde_mode(file_path)
+            .scope(|| storage_format.to_vec(t))
+            .map_err(f_map_err)?;
+        let bytes = self.storage_codec.compress(file_path, bytes)?;
+
+        match self.storage_codec.encrypted_storage_for(file_path) {
+            Some(encrypted_storage) => encrypted_storage.write(file_path, &bytes).await,
+            None => self.bytes_write(thread_name, file_path, &bytes).await,
+        }
+    }
+
+    /// Writes an item to the given path, serialized in this storage's
+    /// [`StorageFormat`], replacing the file atomically.
+    ///
+    /// See [`Storage::serialized_write_atomic`] for how the file is replaced.
+    ///
+    /// # Parameters
+    ///
+    /// * `thread_name`: Name of the thread to use to do the write operation.
+    /// * `file_path`: Path to the file to store the serialized item.
+    /// * `t`: Item to serialize.
+    /// * `f_map_err`: Maps the serialization error (if any) to an [`Error`].
+    pub async fn formatted_write_atomic<T, F>(
+        &self,
+        thread_name: String,
+        file_path: &Path,
+        t: &T,
+        f_map_err: F,
+    ) -> Result<(), Error>
+    where
+        T: Serialize + Send + Sync,
+        F: FnOnce(StorageFormatError) -> Error + Send,
+    {
+        let file_path_tmp = Self::file_path_tmp(file_path)?;
+
+        self.formatted_write_direct(thread_name, &file_path_tmp, t, f_map_err)
+            .await?;
+        self.file_rename_or_stage(file_path_tmp, file_path).await
+    }
+
+    /// Reads the given file, decrypting and decompressing it if needed.
+    ///
+    /// The file is read in full, so that the contents that were parsed can be
+    /// shown when deserialization fails.
+    async fn decoded_read(&self, thread_name: String, file_path: &Path) -> Result<Vec<u8>, Error> {
+        if let Some(plaintext) = self.storage_codec.encrypted_read(file_path).await? {
+            return Ok(plaintext);
+        }
+
+        self.read_with_sync_api(thread_name, file_path, |file| {
+            let mut reader = StorageCodec::decompressing_reader(file_path, file)?;
+            let mut bytes = Vec::new();
+            reader
+                .read_to_end(&mut bytes)
+                .map_err(|error| Error::StorageDecompress {
+                    path: file_path.to_path_buf(),
+                    error,
+                })?;
+
+            Result::<_, Error>::Ok(bytes)
+        })
+        .await
+    }
+
+    /// Writes already serialized bytes to the given path.
+    async fn bytes_write(
+        &self,
+        thread_name: String,
+        file_path: &Path,
+        bytes: &[u8],
+    ) -> Result<(), Error> {
         self.write_with_sync_api(thread_name, file_path, |file| {
-            serde_yaml::to_writer(file, t).map_err(f_map_err)
+            file.write_all(bytes).map_err(
+                // Tests currently don't cover file system failure cases,
+                // e.g. disk space limits.
+                #[cfg_attr(coverage_nightly, coverage(off))]
+                |error| {
+                    let path = file_path.to_path_buf();
+                    Error::Native(NativeError::FileWrite { path, error })
+                },
+            )
         })
-        .await?;
+        .await
+    }
+
+    /// Creates a uniquely named temporary file to write to before atomically
+    /// replacing `file_path`.
+    ///
+    /// The temporary file is created next to `file_path`, so that it can be
+    /// renamed to `file_path`, and is removed when the returned `TempPath` is
+    /// dropped without being persisted.
+    fn file_path_tmp(file_path: &Path) -> Result<TempPath, NativeError> {
+        let dir = file_path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or_else(|| Path::new("."));
+        let mut prefix = file_path.file_name().unwrap_or_default().to_owned();
+        prefix.push(".");
+
+        let file_tmp = tempfile::Builder::new()
+            .prefix(&prefix)
+            .suffix(".tmp")
+            .tempfile_in(dir)
+            .map_err(
+                // Tests currently don't cover file system failure cases,
+                // e.g. disk space limits.
+                #[cfg_attr(coverage_nightly, coverage(off))]
+                |error| {
+                    let path = file_path.to_path_buf();
+                    NativeError::FileCreate { path, error }
+                },
+            )?;
+
+        Ok(file_tmp.into_temp_path())
+    }
+
+    /// Syncs the temporary file to disk, then renames it to `file_path`, or
+    /// records it to be renamed when the transaction is committed.
+    async fn file_rename_or_stage(
+        &self,
+        file_path_tmp: TempPath,
+        file_path: &Path,
+    ) -> Result<(), Error> {
+        Self::file_sync(&file_path_tmp).await?;
+
+        match self.staged_file_paths.as_ref() {
+            Some(staged_file_paths) => {
+                let mut staged_file_paths = Self::staged_file_paths_lock(staged_file_paths);
+                match staged_file_paths
+                    .iter_mut()
+                    .find(|(staged_file_path, _)| staged_file_path == file_path)
+                {
+                    // The previous temporary file is removed when it is dropped.
+                    Some((_, staged_file_path_tmp)) => *staged_file_path_tmp = file_path_tmp,
+                    None => staged_file_paths.push((file_path.to_path_buf(), file_path_tmp)),
+                }
+                Ok(())
+            }
+            None => Self::file_rename(file_path_tmp, file_path).map_err(Error::Native),
+        }
+    }
+
+    /// Flushes the file's contents to disk, so that they are not lost if the
+    /// system stops after the file is renamed.
+    async fn file_sync(file_path: &Path) -> Result<(), Error> {
+        let file_sync = async {
+            let file = OpenOptions::new().write(true).open(file_path).await?;
+            file.sync_all().await
+        };
+        file_sync.await.map_err(
+            // Tests currently don't cover file system failure cases,
+            // e.g. disk space limits.
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |error| {
+                let path = file_path.to_path_buf();
+                NativeError::FileSync { path, error }
+            },
+        )?;
+
+        Ok(())
+    }
+
+    /// Renames the temporary file to `file_path`.
+    pub(crate) fn file_rename(
+        file_path_tmp: TempPath,
+        file_path: &Path,
+    ) -> Result<(), NativeError> {
+        file_path_tmp.persist(file_path).map_err(
+            // Tests currently don't cover file system failure cases,
+            // e.g. disk space limits.
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |error| NativeError::FileRename {
+                path_from: error.path.to_path_buf(),
+                path_to: file_path.to_path_buf(),
+                error: error.error,
+            },
+        )?;
 
         Ok(())
     }
@@ -0,0 +1,157 @@
+use std::{io::Read, path::Path, sync::Arc};
+
+use peace_cfg::state::SensitiveSerdeMode;
+use peace_rt_model_core::{Error, StorageCompression, StorageFormat};
+
+use crate::{EncryptedStorage, SensitiveFieldCipher};
+
+/// How items are encoded when they are stored in files.
+///
+/// This holds the [`StorageFormat`], [`StorageCompression`], and encryption
+/// settings that [`Storage`] applies to the items it reads and writes, so
+/// that `Storage` itself is only concerned with file access.
+///
+/// [`Storage`]: crate::Storage
+#[derive(Clone, Debug, Default)]
+pub struct StorageCodec {
+    /// Encrypts files under the `.peace` directory, if the workspace is
+    /// encrypted.
+    encrypted_storage: Option<EncryptedStorage>,
+    /// Encrypts `Sensitive` fields of serialized items.
+    sensitive_field_cipher: Option<SensitiveFieldCipher>,
+    /// Format that the `formatted_*` functions serialize items in.
+    storage_format: StorageFormat,
+    /// Compression applied to written files.
+    storage_compression: StorageCompression,
+}
+
+impl StorageCodec {
+    /// Returns a new `StorageCodec` that stores YAML files in plain text.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `EncryptedStorage` to encrypt files under the `.peace`
+    /// directory with.
+    pub fn with_encrypted_storage(mut self, encrypted_storage: EncryptedStorage) -> Self {
+        self.encrypted_storage = Some(encrypted_storage);
+        self
+    }
+
+    /// Sets the cipher to encrypt `Sensitive` fields of serialized items with.
+    pub fn with_sensitive_field_cipher(
+        mut self,
+        sensitive_field_cipher: SensitiveFieldCipher,
+    ) -> Self {
+        self.sensitive_field_cipher = Some(sensitive_field_cipher);
+        self
+    }
+
+    /// Sets the format that the `formatted_*` functions serialize items in.
+    pub fn with_storage_format(mut self, storage_format: StorageFormat) -> Self {
+        self.storage_format = storage_format;
+        self
+    }
+
+    /// Sets the compression applied to written files.
+    pub fn with_storage_compression(mut self, storage_compression: StorageCompression) -> Self {
+        self.storage_compression = storage_compression;
+        self
+    }
+
+    /// Returns the `EncryptedStorage`, if the workspace is encrypted.
+    pub fn encrypted_storage(&self) -> Option<&EncryptedStorage> {
+        self.encrypted_storage.as_ref()
+    }
+
+    /// Returns the cipher to encrypt `Sensitive` fields with, if set.
+    pub fn sensitive_field_cipher(&self) -> Option<&SensitiveFieldCipher> {
+        self.sensitive_field_cipher.as_ref()
+    }
+
+    /// Returns the format that the `formatted_*` functions serialize items in.
+    pub fn storage_format(&self) -> StorageFormat {
+        self.storage_format
+    }
+
+    /// Returns the compression applied to written files.
+    pub fn storage_compression(&self) -> StorageCompression {
+        self.storage_compression
+    }
+
+    /// Returns whether the given file is encrypted when stored.
+    pub fn is_encrypted(&self, file_path: &Path) -> bool {
+        self.encrypted_storage_for(file_path).is_some()
+    }
+
+    /// Returns the `EncryptedStorage` if the given file is encrypted.
+    pub(crate) fn encrypted_storage_for(&self, file_path: &Path) -> Option<&EncryptedStorage> {
+        self.encrypted_storage
+            .as_ref()
+            .filter(|encrypted_storage| encrypted_storage.is_encrypted(file_path))
+    }
+
+    /// Returns how `Sensitive` fields are serialized for the given file.
+    ///
+    /// If the file is not encrypted and there is no sensitive field cipher,
+    /// `Sensitive` fields fail to serialize instead of being redacted, so that
+    /// they are not lost when the file is read back.
+    pub fn sensitive_serde_mode(&self, file_path: &Path) -> SensitiveSerdeMode {
+        if let Some(sensitive_field_cipher) = self.sensitive_field_cipher.as_ref() {
+            SensitiveSerdeMode::Encrypt(Arc::new(sensitive_field_cipher.clone()))
+        } else if self.encrypted_storage_for(file_path).is_some() {
+            SensitiveSerdeMode::Plain
+        } else {
+            SensitiveSerdeMode::KeyRequired
+        }
+    }
+
+    /// Compresses serialized bytes with this codec's compression.
+    pub(crate) fn compress(&self, file_path: &Path, bytes: Vec<u8>) -> Result<Vec<u8>, Error> {
+        let storage_compression = self.storage_compression;
+        storage_compression
+            .compress(bytes)
+            .map_err(|error| Error::StorageCompress {
+                path: file_path.to_path_buf(),
+                storage_compression,
+                error,
+            })
+    }
+
+    /// Decompresses stored bytes, detecting whether they are compressed.
+    pub(crate) fn decompress(file_path: &Path, bytes: Vec<u8>) -> Result<Vec<u8>, Error> {
+        StorageCompression::decompress(bytes).map_err(|error| Error::StorageDecompress {
+            path: file_path.to_path_buf(),
+            error,
+        })
+    }
+
+    /// Returns a reader that decompresses the given file, detecting whether it
+    /// is compressed.
+    pub(crate) fn decompressing_reader<'r, R>(
+        file_path: &Path,
+        reader: R,
+    ) -> Result<Box<dyn Read + 'r>, Error>
+    where
+        R: Read + 'r,
+    {
+        StorageCompression::decompressing_reader(reader).map_err(|error| Error::StorageDecompress {
+            path: file_path.to_path_buf(),
+            error,
+        })
+    }
+
+    /// Reads, decrypts, and decompresses the given file, if it is encrypted.
+    ///
+    /// Returns `None` if the file is not encrypted, in which case it should be
+    /// read directly.
+    pub(crate) async fn encrypted_read(&self, file_path: &Path) -> Result<Option<Vec<u8>>, Error> {
+        match self.encrypted_storage_for(file_path) {
+            Some(encrypted_storage) => {
+                let bytes = encrypted_storage.read(file_path).await?;
+                Self::decompress(file_path, bytes).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use std::ops::Deref;
+
+use peace_rt_model_core::Error;
+
+use crate::Storage;
+
+/// Writes to multiple files through [`Storage`], committed together.
+///
+/// Items written through this transaction are written to uniquely named
+/// temporary files next to their destination files. When the transaction is
+/// committed, each temporary file is renamed over its destination file. Until
+/// then, the destination files are unchanged, so a failure while writing any
+/// item does not leave some files updated and others not.
+///
+/// Dropping the transaction without committing it removes the temporary
+/// files.
+///
+/// Reads through the transaction's storage return the destination files'
+/// contents, not the uncommitted writes.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let storage_transaction = storage.transaction();
+/// StatesSerializer::serialize(&storage_transaction, item_graph, states_current, &states_current_file).await?;
+/// StatesSerializer::serialize(&storage_transaction, item_graph, states_goal, &states_goal_file).await?;
+/// storage_transaction.commit().await?;
+/// ```
+#[derive(Debug)]
+pub struct StorageTransaction {
+    /// Storage whose writes are staged.
+    storage: Storage,
+}
+
+impl StorageTransaction {
+    /// Returns a new `StorageTransaction`.
+    pub(crate) fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+
+    /// Returns the storage to write items through.
+    pub fn storage(&self) -> &Storage {
+        &self.storage
+    }
+
+    /// Replaces each destination file with the item written to it in this
+    /// transaction.
+    ///
+    /// Files are renamed in the order they were first written.
+    pub async fn commit(self) -> Result<(), Error> {
+        let staged_file_paths = self.storage.staged_file_paths_take();
+        for (file_path, file_path_tmp) in staged_file_paths {
+            Storage::file_rename(file_path_tmp, &file_path)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deref for StorageTransaction {
+    type Target = Storage;
+
+    fn deref(&self) -> &Self::Target {
+        &self.storage
+    }
+}
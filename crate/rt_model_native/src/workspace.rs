@@ -9,9 +9,12 @@
 
 use peace_core::AppName;
 use peace_resources::internal::WorkspaceDirs;
-use peace_rt_model_core::Error;
+use peace_rt_model_core::{Error, NativeError, StatesOrdering, StorageCompression, StorageFormat};
 
-use crate::{Storage, WorkspaceDirsBuilder, WorkspaceSpec};
+use crate::{
+    EncryptedStorage, KeyProvider, SensitiveFieldCipher, Storage, StorageCodec,
+    WorkspaceDirsBuilder, WorkspaceSpec,
+};
 
 /// Workspace that the `peace` tool runs in.
 #[derive(Clone, Debug)]
@@ -32,8 +35,27 @@ impl Workspace {
     /// * `app_name`: Name of the final application.
     /// * `workspace_spec`: Defines how to discover the workspace.
     pub fn new(app_name: AppName, workspace_spec: WorkspaceSpec) -> Result<Self, Error> {
+        let key_provider = match &workspace_spec {
+            WorkspaceSpec::PathEncrypted { key_provider, .. } => Some(key_provider.clone()),
+            WorkspaceSpec::WorkingDir
+            | WorkspaceSpec::Path(_)
+            | WorkspaceSpec::FirstDirWithFile(_)
+            | WorkspaceSpec::ParentSearch => None,
+        };
         let dirs = WorkspaceDirsBuilder::build(&app_name, workspace_spec)?;
-        let storage = Storage;
+        let storage = match key_provider {
+            Some(key_provider) => {
+                let key = key_provider
+                    .key()
+                    .map_err(|error| NativeError::EncryptionKeyProvide { error })
+                    .map_err(Error::Native)?;
+                let encrypted_storage = EncryptedStorage::new(dirs.peace_dir().clone(), &key);
+                Storage::new().with_storage_codec(
+                    StorageCodec::new().with_encrypted_storage(encrypted_storage),
+                )
+            }
+            None => Storage::new(),
+        };
 
         Ok(Self {
             app_name,
@@ -42,6 +64,63 @@ impl Workspace {
         })
     }
 
+    /// Encrypts `Sensitive` state fields with the key from `key_provider`
+    /// when states are stored.
+    ///
+    /// The rest of each state is stored in plain text, so it can still be
+    /// read and diffed. The same key must be provided to read the stored
+    /// states back.
+    pub fn with_sensitive_key_provider(self, key_provider: KeyProvider) -> Result<Self, Error> {
+        let key = key_provider
+            .key()
+            .map_err(|error| NativeError::EncryptionKeyProvide { error })
+            .map_err(Error::Native)?;
+        let sensitive_field_cipher = SensitiveFieldCipher::new(&key);
+
+        Ok(self.with_storage_codec_updated(|storage_codec| {
+            storage_codec.with_sensitive_field_cipher(sensitive_field_cipher)
+        }))
+    }
+
+    /// Sets the format that states are stored in.
+    ///
+    /// Defaults to [`StorageFormat::Yaml`]. The same format must be used to
+    /// read the stored states back.
+    pub fn with_storage_format(self, storage_format: StorageFormat) -> Self {
+        self.with_storage_codec_updated(|storage_codec| {
+            storage_codec.with_storage_format(storage_format)
+        })
+    }
+
+    /// Sets the compression applied to stored states and params files.
+    ///
+    /// Defaults to [`StorageCompression::None`]. Compressed files are detected
+    /// when read, so this does not need to match the compression that existing
+    /// files were written with.
+    pub fn with_storage_compression(self, storage_compression: StorageCompression) -> Self {
+        self.with_storage_codec_updated(|storage_codec| {
+            storage_codec.with_storage_compression(storage_compression)
+        })
+    }
+
+    /// Sets the order of entries when states are stored.
+    ///
+    /// Defaults to [`StatesOrdering::Graph`].
+    pub fn with_states_ordering(mut self, states_ordering: StatesOrdering) -> Self {
+        self.storage = self.storage.with_states_ordering(states_ordering);
+        self
+    }
+
+    /// Replaces the storage's `StorageCodec` with the one returned by `f`.
+    fn with_storage_codec_updated<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(StorageCodec) -> StorageCodec,
+    {
+        let storage_codec = f(self.storage.storage_codec().clone());
+        self.storage = self.storage.with_storage_codec(storage_codec);
+        self
+    }
+
     /// Returns the underlying data.
     pub fn into_inner(self) -> (AppName, WorkspaceDirs, Storage) {
         let Self {
@@ -59,6 +138,10 @@ impl Workspace {
     }
 
     /// Returns a reference to the workspace's directories.
+    ///
+    /// For [`WorkspaceSpec::FirstDirWithFile`] and
+    /// [`WorkspaceSpec::ParentSearch`], the workspace directory is the
+    /// directory that was found.
     pub fn dirs(&self) -> &WorkspaceDirs {
         &self.dirs
     }
@@ -1,7 +1,4 @@
-use std::{
-    ffi::OsStr,
-    path::{Path, PathBuf},
-};
+use std::{ffi::OsString, path::PathBuf};
 
 use peace_core::AppName;
 use peace_resources::{
@@ -30,14 +27,12 @@ impl WorkspaceDirsBuilder {
                 .map_err(Error::Native)?;
             let workspace_dir = match workspace_spec {
                 WorkspaceSpec::WorkingDir => working_dir,
-                WorkspaceSpec::Path(path) => path,
+                WorkspaceSpec::Path(path) | WorkspaceSpec::PathEncrypted { path, .. } => path,
                 WorkspaceSpec::FirstDirWithFile(file_name) => {
-                    Self::first_dir_with_file(&working_dir, &file_name).ok_or({
-                        Error::Native(NativeError::WorkspaceFileNotFound {
-                            working_dir,
-                            file_name,
-                        })
-                    })?
+                    Self::first_dir_with_file(working_dir, file_name)?
+                }
+                WorkspaceSpec::ParentSearch => {
+                    Self::first_dir_with_file(working_dir, OsString::from(PeaceDir::NAME))?
                 }
             };
 
@@ -50,17 +45,25 @@ impl WorkspaceDirsBuilder {
         Ok(WorkspaceDirs::new(workspace_dir, peace_dir, peace_app_dir))
     }
 
-    fn first_dir_with_file(working_dir: &Path, path: &OsStr) -> Option<PathBuf> {
-        let mut candidate_dir = working_dir.to_path_buf();
+    /// Returns the first directory from the working directory upwards that
+    /// contains a file or directory named `file_name`.
+    fn first_dir_with_file(working_dir: PathBuf, file_name: OsString) -> Result<PathBuf, Error> {
+        let mut searched_dirs = Vec::new();
+        let mut candidate_dir = working_dir.clone();
         loop {
-            let candidate_marker = candidate_dir.join(path);
+            let candidate_marker = candidate_dir.join(&file_name);
             if candidate_marker.exists() {
-                return Some(candidate_dir);
+                return Ok(candidate_dir);
             }
+            searched_dirs.push(candidate_dir.clone());
 
             // pop() returns false if there is no parent dir.
             if !candidate_dir.pop() {
-                return None;
+                return Err(Error::Native(NativeError::WorkspaceFileNotFound {
+                    working_dir,
+                    file_name,
+                    searched_dirs,
+                }));
             }
         }
     }
@@ -7,7 +7,7 @@ use peace_resources::{
     type_reg::untagged::{TypeMapOpt, TypeReg},
 };
 use peace_rt_model_core::{
-    params::{FlowParams, ProfileParams, WorkspaceParams},
+    params::{FlowParams, ProfileParams, WorkspaceParams, WorkspaceParamsRegistry},
     Error, NativeError,
 };
 use serde::{de::DeserializeOwned, Serialize};
@@ -95,7 +95,7 @@ impl WorkspaceInitializer {
                 "workspace_params_deserialize".to_string(),
                 type_reg,
                 workspace_params_file,
-                Error::WorkspaceParamsDeserialize,
+                |error, _| Error::WorkspaceParamsDeserialize(error),
             )
             .await
             .map(|type_map_opt| {
@@ -105,6 +105,49 @@ impl WorkspaceInitializer {
             })
     }
 
+    /// Migrates stored workspace params that are not in their current shape,
+    /// and writes them back to storage.
+    pub async fn workspace_params_migrate<K>(
+        storage: &Storage,
+        workspace_params_registry: &WorkspaceParamsRegistry<K>,
+        workspace_params_file: &WorkspaceParamsFile,
+    ) -> Result<(), Error>
+    where
+        K: Clone + Debug + Eq + Hash + DeserializeOwned + Serialize + Send + Sync + 'static,
+    {
+        let workspace_params = storage
+            .serialized_read_opt::<serde_yaml::Mapping, _>(
+                "workspace_params_migrate".to_string(),
+                workspace_params_file,
+                |error, _| Error::WorkspaceParamsDeserialize(error),
+            )
+            .await?;
+        let Some(mut workspace_params) = workspace_params else {
+            return Ok(());
+        };
+
+        // Migrated params are stored in the workspace params file.
+        let migrated = storage
+            .sensitive_serde_mode(workspace_params_file)
+            .scope(|| workspace_params_registry.migrate(&mut workspace_params))
+            .map_err(|(key, error)| Error::WorkspaceParamMigrate {
+                key: format!("{key:?}"),
+                error,
+            })?;
+        if migrated {
+            storage
+                .serialized_write(
+                    "workspace_params_migrate".to_string(),
+                    workspace_params_file,
+                    &workspace_params,
+                    Error::WorkspaceParamsSerialize,
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
     pub async fn profile_params_serialize<K>(
         storage: &Storage,
         profile_params: &ProfileParams<K>,
@@ -136,7 +179,7 @@ impl WorkspaceInitializer {
                 "profile_params_deserialize".to_string(),
                 type_reg,
                 profile_params_file,
-                Error::ProfileParamsDeserialize,
+                |error, _| Error::ProfileParamsDeserialize(error),
             )
             .await
             .map(|type_map_opt| {
@@ -177,7 +220,7 @@ impl WorkspaceInitializer {
                 "flow_params_deserialize".to_string(),
                 type_reg,
                 flow_params_file,
-                Error::FlowParamsDeserialize,
+                |error, _| Error::FlowParamsDeserialize(error),
             )
             .await
             .map(|type_map_opt| {
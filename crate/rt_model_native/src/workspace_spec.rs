@@ -1,5 +1,7 @@
 use std::{ffi::OsString, path::PathBuf};
 
+use crate::KeyProvider;
+
 /// Describes how to discover the workspace directory.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum WorkspaceSpec {
@@ -19,4 +21,23 @@ pub enum WorkspaceSpec {
     /// The workspace directory is the parent directory that contains a file or
     /// directory with the provided name.
     FirstDirWithFile(OsString),
+    /// Traverse up from the working directory until a `.peace` directory is
+    /// found.
+    ///
+    /// This finds the enclosing workspace when the program is run in a
+    /// subdirectory of it, similar to how `git` finds a repository's `.git`
+    /// directory. The workspace must have been initialized by a previous
+    /// command run in the workspace directory.
+    ParentSearch,
+    /// Use a specified path, and encrypt all files under the `.peace`
+    /// directory.
+    ///
+    /// States and params are encrypted with the key from the `key_provider`,
+    /// so they are not stored in plain text.
+    PathEncrypted {
+        /// Path to the workspace directory.
+        path: PathBuf,
+        /// Provides the key to encrypt files with.
+        key_provider: KeyProvider,
+    },
 }
@@ -7,13 +7,18 @@
 //! **This crate is intended to be used with `#[cfg(target_arch = "wasm32")]`.**
 
 pub use crate::{
-    storage::Storage, workspace::Workspace, workspace_dirs_builder::WorkspaceDirsBuilder,
-    workspace_initializer::WorkspaceInitializer, workspace_spec::WorkspaceSpec,
+    storage::Storage, storage_transaction::StorageTransaction, web_output::WebOutput,
+    web_output_event::WebOutputEvent, workspace::Workspace,
+    workspace_dirs_builder::WorkspaceDirsBuilder, workspace_initializer::WorkspaceInitializer,
+    workspace_spec::WorkspaceSpec,
 };
 
 pub mod workspace;
 
 mod storage;
+mod storage_transaction;
+mod web_output;
+mod web_output_event;
 mod workspace_dirs_builder;
 mod workspace_initializer;
 mod workspace_spec;
@@ -2,24 +2,51 @@ use std::{
     fmt::Debug,
     hash::Hash,
     path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard},
 };
 
 use base64::Engine;
+use peace_cfg::state::SensitiveSerdeMode;
 use peace_resources::type_reg::{
     common::UnknownEntriesSome,
     untagged::{DataTypeWrapper, TypeMapOpt, TypeReg},
 };
-use peace_rt_model_core::{Error, WebError};
+use peace_rt_model_core::{
+    Error, StatesOrdering, StorageCompression, StorageFormat, StorageFormatError, WebError,
+};
 use serde::{de::DeserializeOwned, Serialize};
 use wasm_bindgen::prelude::*;
 
-use crate::WorkspaceSpec;
+use crate::{StorageTransaction, WorkspaceSpec};
 
 /// Wrapper to retrieve `web_sys::Storage` on demand.
+///
+/// The `formatted_*` functions serialize items in the [`StorageFormat`] of
+/// this storage, while the `serialized_*` functions always use YAML. Binary
+/// formats are stored base64 encoded.
+///
+/// Both families of functions compress written items with the
+/// [`StorageCompression`] of this storage. Compressed items are stored base64
+/// encoded, and are detected when read.
+///
+/// Writes to multiple items can be committed together with a
+/// [`StorageTransaction`], see [`Storage::transaction`].
 #[derive(Clone, Debug)]
 pub struct Storage {
     /// Describes how to store peace automation data.
     workspace_spec: WorkspaceSpec,
+    /// Format that the `formatted_*` functions serialize items in.
+    storage_format: StorageFormat,
+    /// Compression applied to written items.
+    storage_compression: StorageCompression,
+    /// Order of entries when states are written.
+    states_ordering: StatesOrdering,
+    /// Values of items written within a transaction.
+    ///
+    /// When this is `Some`, the `serialized_*` and `formatted_*` write
+    /// functions store values here, which are set in the web storage when the
+    /// transaction is committed.
+    staged_items: Option<Arc<Mutex<Vec<(PathBuf, String)>>>>,
 }
 
 #[wasm_bindgen(module = "/js/workspace.js")]
@@ -33,7 +60,106 @@ extern "C" {
 impl Storage {
     /// Returns a new `Storage`.
     pub fn new(workspace_spec: WorkspaceSpec) -> Self {
-        Self { workspace_spec }
+        Self {
+            workspace_spec,
+            storage_format: StorageFormat::default(),
+            storage_compression: StorageCompression::default(),
+            states_ordering: StatesOrdering::default(),
+            staged_items: None,
+        }
+    }
+
+    /// Returns how [`Sensitive`] values are serialized for the given item.
+    ///
+    /// Web storage has no sensitive field key, so `Sensitive` values fail to
+    /// serialize, rather than being stored in plain text.
+    ///
+    /// [`Sensitive`]: peace_cfg::state::Sensitive
+    pub fn sensitive_serde_mode(&self, _path: &Path) -> SensitiveSerdeMode {
+        SensitiveSerdeMode::KeyRequired
+    }
+
+    /// Returns a [`StorageTransaction`] to stage writes to multiple items, and
+    /// commit them together.
+    ///
+    /// Items written through the transaction's storage are kept in memory, and
+    /// only set in the web storage when the transaction is committed.
+    pub fn transaction(&self) -> StorageTransaction {
+        let storage = Self {
+            staged_items: Some(Arc::new(Mutex::new(Vec::new()))),
+            ..self.clone()
+        };
+
+        StorageTransaction::new(storage)
+    }
+
+    /// Returns the items written within this storage's transaction, leaving an
+    /// empty list in its place.
+    pub(crate) fn staged_items_take(&self) -> Vec<(PathBuf, String)> {
+        self.staged_items
+            .as_ref()
+            .map(|staged_items| std::mem::take(&mut *Self::staged_items_lock(staged_items)))
+            .unwrap_or_default()
+    }
+
+    fn staged_items_lock(
+        staged_items: &Mutex<Vec<(PathBuf, String)>>,
+    ) -> MutexGuard<'_, Vec<(PathBuf, String)>> {
+        staged_items
+            .lock()
+            .expect("Expected `Storage` staged items lock to not be poisoned.")
+    }
+
+    /// Sets an item in the web storage, or stages it if this storage is
+    /// within a transaction.
+    fn set_item_or_stage(&self, path: &Path, value: String) -> Result<(), Error> {
+        match self.staged_items.as_ref() {
+            Some(staged_items) => {
+                let mut staged_items = Self::staged_items_lock(staged_items);
+                match staged_items
+                    .iter_mut()
+                    .find(|(staged_path, _)| staged_path == path)
+                {
+                    Some((_, staged_value)) => *staged_value = value,
+                    None => staged_items.push((path.to_path_buf(), value)),
+                }
+                Ok(())
+            }
+            None => self.set_item(path, &value),
+        }
+    }
+
+    /// Sets the format that the `formatted_*` functions serialize items in.
+    pub fn with_storage_format(mut self, storage_format: StorageFormat) -> Self {
+        self.storage_format = storage_format;
+        self
+    }
+
+    /// Returns the format that the `formatted_*` functions serialize items in.
+    pub fn storage_format(&self) -> StorageFormat {
+        self.storage_format
+    }
+
+    /// Sets the compression applied to written items.
+    pub fn with_storage_compression(mut self, storage_compression: StorageCompression) -> Self {
+        self.storage_compression = storage_compression;
+        self
+    }
+
+    /// Returns the compression applied to written items.
+    pub fn storage_compression(&self) -> StorageCompression {
+        self.storage_compression
+    }
+
+    /// Sets the order of entries when states are written.
+    pub fn with_states_ordering(mut self, states_ordering: StatesOrdering) -> Self {
+        self.states_ordering = states_ordering;
+        self
+    }
+
+    /// Returns the order of entries when states are written.
+    pub fn states_ordering(&self) -> StatesOrdering {
+        self.states_ordering
     }
 
     /// Returns the browser storage used for the workspace.
@@ -349,7 +475,8 @@ impl Storage {
     /// # Parameters
     ///
     /// * `path`: Path to read the serialized item.
-    /// * `f_map_err`: Maps the deserialization error (if any) to an [`Error`].
+    /// * `f_map_err`: Maps the deserialization error (if any) to an [`Error`],
+    ///   given the decompressed contents that were parsed.
     pub async fn serialized_read_opt<T, F>(
         &self,
         path: &Path,
@@ -357,10 +484,13 @@ impl Storage {
     ) -> Result<Option<T>, Error>
     where
         T: DeserializeOwned + Send + Sync,
-        F: FnOnce(serde_yaml::Error) -> Error + Send,
+        F: FnOnce(serde_yaml::Error, &[u8]) -> Error + Send,
     {
         self.get_item_opt(path)?
-            .map(|s| serde_yaml::from_str::<T>(&s).map_err(f_map_err))
+            .map(|value| {
+                let bytes = Self::value_bytes(path, value, false)?;
+                serde_yaml::from_slice::<T>(&bytes).map_err(|error| f_map_err(error, &bytes))
+            })
             .transpose()
     }
 
@@ -371,7 +501,8 @@ impl Storage {
     /// * `thread_name`: Name of the thread to use to do the read operation.
     /// * `type_reg`: Type registry with the stateful deserialization mappings.
     /// * `file_path`: Path to the file to read the serialized item.
-    /// * `f_map_err`: Maps the deserialization error (if any) to an [`Error`].
+    /// * `f_map_err`: Maps the deserialization error (if any) to an [`Error`],
+    ///   given the decompressed contents that were parsed.
     pub async fn serialized_typemap_read_opt<K, BoxDT, F>(
         &self,
         type_reg: &TypeReg<K, BoxDT>,
@@ -381,14 +512,15 @@ impl Storage {
     where
         K: Clone + Debug + DeserializeOwned + Eq + Hash + Sync + 'static,
         BoxDT: DataTypeWrapper + 'static,
-        F: FnOnce(serde_yaml::Error) -> Error + Send,
+        F: FnOnce(serde_yaml::Error, &[u8]) -> Error + Send,
     {
         self.get_item_opt(path)?
-            .map(|s| {
-                let deserializer = serde_yaml::Deserializer::from_str(&s);
+            .map(|value| {
+                let bytes = Self::value_bytes(path, value, false)?;
+                let deserializer = serde_yaml::Deserializer::from_slice(&bytes);
                 let type_map_opt = type_reg
                     .deserialize_map_opt_with_unknowns::<'_, serde_yaml::Value, _, _>(deserializer)
-                    .map_err(f_map_err)?;
+                    .map_err(|error| f_map_err(error, &bytes))?;
 
                 Ok(type_map_opt)
             })
@@ -412,9 +544,221 @@ impl Storage {
         T: Serialize + Send + Sync,
         F: FnOnce(serde_yaml::Error) -> Error + Send,
     {
-        self.set_item(path, &serde_yaml::to_string(t).map_err(f_map_err)?)?;
+        let bytes = self
+            .sensitive_serde_mode(path)
+            .scope(|| serde_yaml::to_string(t))
+            .map_err(f_map_err)?
+            .into_bytes();
+        let value = self.value_to_store(path, bytes, false)?;
+        self.set_item_or_stage(path, value)
+    }
 
-        Ok(())
+    /// Writes a serializable item to the given path, replacing the existing
+    /// value atomically.
+    ///
+    /// Web storage items are always replaced atomically, so this is the same
+    /// as [`Storage::serialized_write`].
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Path to store the serialized item.
+    /// * `t`: Item to serialize.
+    /// * `f_map_err`: Maps the serialization error (if any) to an [`Error`].
+    pub async fn serialized_write_atomic<T, F>(
+        &self,
+        path: &Path,
+        t: &T,
+        f_map_err: F,
+    ) -> Result<(), Error>
+    where
+        T: Serialize + Send + Sync,
+        F: FnOnce(serde_yaml::Error) -> Error + Send,
+    {
+        self.serialized_write(path, t, f_map_err).await
+    }
+
+    /// Returns the bytes of a stored value, decompressing it if it was stored
+    /// compressed.
+    ///
+    /// Compressed values are stored base64 encoded, and are detected by their
+    /// leading magic bytes once decoded.
+    fn value_bytes(path: &Path, value: String, is_binary: bool) -> Result<Vec<u8>, Error> {
+        let bytes = match base64::engine::general_purpose::STANDARD.decode(&value) {
+            Ok(bytes) => bytes,
+            Err(error) if is_binary => {
+                return Err(Error::Web(WebError::StorageB64Decode {
+                    path: path.to_path_buf(),
+                    value,
+                    error,
+                }));
+            }
+            Err(_) => return Ok(value.into_bytes()),
+        };
+        if !is_binary && StorageCompression::detect(&bytes) == StorageCompression::None {
+            return Ok(value.into_bytes());
+        }
+
+        StorageCompression::decompress(bytes).map_err(|error| Error::StorageDecompress {
+            path: path.to_path_buf(),
+            error,
+        })
+    }
+
+    /// Returns the value to store for the given serialized bytes, compressed
+    /// with this storage's [`StorageCompression`].
+    fn value_to_store(
+        &self,
+        path: &Path,
+        bytes: Vec<u8>,
+        is_binary: bool,
+    ) -> Result<String, Error> {
+        let storage_compression = self.storage_compression;
+        if storage_compression == StorageCompression::None && !is_binary {
+            // Text formats are always serialized as UTF-8.
+            return Ok(String::from_utf8_lossy(&bytes).into_owned());
+        }
+
+        let bytes = storage_compression
+            .compress(bytes)
+            .map_err(|error| Error::StorageCompress {
+                path: path.to_path_buf(),
+                storage_compression,
+                error,
+            })?;
+        Ok(base64::engine::general_purpose::STANDARD.encode(bytes))
+    }
+
+    /// Returns the bytes of an item stored in this storage's
+    /// [`StorageFormat`], if it exists.
+    fn formatted_bytes_opt(&self, path: &Path) -> Result<Option<Vec<u8>>, Error> {
+        let is_binary = self.storage_format.is_binary();
+        self.get_item_opt(path)?
+            .map(|value| Self::value_bytes(path, value, is_binary))
+            .transpose()
+    }
+
+    /// Reads an item serialized in this storage's [`StorageFormat`] from the
+    /// given key.
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Path to read the serialized item.
+    /// * `f_map_err`: Maps the deserialization error (if any) to an [`Error`],
+    ///   given the decompressed contents that were parsed.
+    pub async fn formatted_read_opt<T, F>(
+        &self,
+        path: &Path,
+        f_map_err: F,
+    ) -> Result<Option<T>, Error>
+    where
+        T: DeserializeOwned + Send + Sync,
+        F: FnOnce(StorageFormatError, &[u8]) -> Error + Send,
+    {
+        let storage_format = self.storage_format;
+        self.formatted_bytes_opt(path)?
+            .map(|bytes| {
+                storage_format
+                    .from_reader::<_, T>(bytes.as_slice())
+                    .map_err(|error| f_map_err(error, &bytes))
+            })
+            .transpose()
+    }
+
+    /// Deserializes a typemap serialized in this storage's [`StorageFormat`]
+    /// from the given path if it exists.
+    ///
+    /// # Parameters
+    ///
+    /// * `type_reg`: Type registry with the stateful deserialization mappings.
+    /// * `path`: Path to read the serialized item.
+    /// * `f_map_err`: Maps the deserialization error (if any) to an [`Error`],
+    ///   given the decompressed contents that were parsed.
+    pub async fn formatted_typemap_read_opt<K, BoxDT, F>(
+        &self,
+        type_reg: &TypeReg<K, BoxDT>,
+        path: &Path,
+        f_map_err: F,
+    ) -> Result<Option<TypeMapOpt<K, BoxDT, UnknownEntriesSome<serde_yaml::Value>>>, Error>
+    where
+        K: Clone + Debug + DeserializeOwned + Eq + Hash + Sync + 'static,
+        BoxDT: DataTypeWrapper + 'static,
+        F: FnOnce(StorageFormatError, &[u8]) -> Error + Send,
+    {
+        let storage_format = self.storage_format;
+        self.formatted_bytes_opt(path)?
+            .map(|bytes| {
+                storage_format
+                    .typemap_from_reader(type_reg, bytes.as_slice())
+                    .map_err(|error| f_map_err(error, &bytes))
+            })
+            .transpose()
+    }
+
+    /// Writes an item to the given path, serialized in this storage's
+    /// [`StorageFormat`].
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Path to store the serialized item.
+    /// * `t`: Item to serialize.
+    /// * `f_map_err`: Maps the serialization error (if any) to an [`Error`].
+    pub async fn formatted_write<T, F>(&self, path: &Path, t: &T, f_map_err: F) -> Result<(), Error>
+    where
+        T: Serialize + Send + Sync,
+        F: FnOnce(StorageFormatError) -> Error + Send,
+    {
+        let bytes = self
+            .sensitive_serde_mode(path)
+            .scope(|| self.storage_format.to_vec(t))
+            .map_err(f_map_err)?;
+        let value = self.value_to_store(path, bytes, self.storage_format.is_binary())?;
+        self.set_item_or_stage(path, value)
+    }
+
+    /// Writes an item to the given path, serialized in this storage's
+    /// [`StorageFormat`], replacing the existing value atomically.
+    ///
+    /// Web storage items are always replaced atomically, so this is the same
+    /// as [`Storage::formatted_write`].
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Path to store the serialized item.
+    /// * `t`: Item to serialize.
+    /// * `f_map_err`: Maps the serialization error (if any) to an [`Error`].
+    pub async fn formatted_write_atomic<T, F>(
+        &self,
+        path: &Path,
+        t: &T,
+        f_map_err: F,
+    ) -> Result<(), Error>
+    where
+        T: Serialize + Send + Sync,
+        F: FnOnce(StorageFormatError) -> Error + Send,
+    {
+        self.formatted_write(path, t, f_map_err).await
+    }
+
+    /// Returns the keys in the web storage that begin with the given path.
+    pub fn keys_with_prefix(&self, prefix: &Path) -> Result<Vec<PathBuf>, Error> {
+        let storage = self.get()?;
+        let keys_list_error = |js_value: JsValue| {
+            Error::Web(WebError::StorageKeysList {
+                prefix: prefix.to_path_buf(),
+                error: crate::stringify_js_value(js_value),
+            })
+        };
+
+        let length = storage.length().map_err(keys_list_error)?;
+        (0..length).try_fold(Vec::new(), |mut keys, index| {
+            if let Some(key) = storage.key(index).map_err(keys_list_error)? {
+                let key = PathBuf::from(key);
+                if key.starts_with(prefix) {
+                    keys.push(key);
+                }
+            }
+            Ok(keys)
+        })
     }
 
     /// Deletes an item from the web storage.
@@ -0,0 +1,52 @@
+use std::ops::Deref;
+
+use peace_rt_model_core::Error;
+
+use crate::Storage;
+
+/// Writes to multiple items through [`Storage`], committed together.
+///
+/// Items written through this transaction are kept in memory, and are set in
+/// the web storage when the transaction is committed. Dropping the transaction
+/// without committing it discards the writes.
+///
+/// Reads through the transaction's storage return the web storage's values,
+/// not the uncommitted writes.
+#[derive(Debug)]
+pub struct StorageTransaction {
+    /// Storage whose writes are staged.
+    storage: Storage,
+}
+
+impl StorageTransaction {
+    /// Returns a new `StorageTransaction`.
+    pub(crate) fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+
+    /// Returns the storage to write items through.
+    pub fn storage(&self) -> &Storage {
+        &self.storage
+    }
+
+    /// Sets each item written in this transaction in the web storage.
+    ///
+    /// Items are set in the order they were first written.
+    pub async fn commit(self) -> Result<(), Error> {
+        let staged_items = self.storage.staged_items_take();
+
+        self.storage.set_items(
+            staged_items
+                .iter()
+                .map(|(path, value)| (path.as_path(), value.as_str())),
+        )
+    }
+}
+
+impl Deref for StorageTransaction {
+    type Target = Storage;
+
+    fn deref(&self) -> &Self::Target {
+        &self.storage
+    }
+}
@@ -0,0 +1,173 @@
+use std::fmt;
+
+use js_sys::Function;
+use peace_cfg::state::SensitiveSerdeMode;
+use peace_fmt::Presentable;
+use peace_rt_model_core::{async_trait, output::OutputWrite, Error, WebError};
+use serde::Serialize;
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+
+use crate::WebOutputEvent;
+
+#[cfg(feature = "output_progress")]
+use peace_core::progress::{ProgressTracker, ProgressUpdateAndId};
+#[cfg(feature = "output_progress")]
+use peace_rt_model_core::CmdProgressTracker;
+
+/// An `OutputWrite` implementation that forwards output to a JS callback.
+///
+/// Each progress update, presentable, and error is passed to the callback as a
+/// [`WebOutputEvent`], so that browser applications are able to render
+/// progress as a command executes.
+///
+/// Rust frontends may receive the `WebOutputEvent`s directly with
+/// [`WebOutput::from_fn`], without converting them to JS values.
+///
+/// Errors thrown by the callback while rendering progress are ignored, as
+/// progress rendering is infallible.
+#[derive(Debug)]
+pub struct WebOutput {
+    /// Function that receives each `WebOutputEvent`.
+    output_fn: WebOutputFn,
+}
+
+/// Function that receives each `WebOutputEvent`.
+enum WebOutputFn {
+    /// JS function that receives each event as a JS object.
+    Js(Function),
+    /// Rust function that receives each event.
+    Rust(Box<dyn FnMut(WebOutputEvent)>),
+}
+
+impl fmt::Debug for WebOutputFn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Js(function) => f.debug_tuple("Js").field(function).finish(),
+            Self::Rust(_) => f.debug_tuple("Rust").field(&"..").finish(),
+        }
+    }
+}
+
+impl WebOutput {
+    /// Returns a new `WebOutput` that passes events to the given JS function.
+    pub fn new(output_fn: Function) -> Self {
+        Self {
+            output_fn: WebOutputFn::Js(output_fn),
+        }
+    }
+
+    /// Returns a new `WebOutput` that passes events to the given closure.
+    ///
+    /// The closure is leaked to the JS side, so that it lives as long as the
+    /// `WebOutput`.
+    pub fn from_closure(closure: Closure<dyn FnMut(JsValue)>) -> Self {
+        let output_fn = closure.into_js_value().unchecked_into::<Function>();
+        Self::new(output_fn)
+    }
+
+    /// Returns a new `WebOutput` that passes events to the given Rust
+    /// function.
+    pub fn from_fn<F>(output_fn: F) -> Self
+    where
+        F: FnMut(WebOutputEvent) + 'static,
+    {
+        Self {
+            output_fn: WebOutputFn::Rust(Box::new(output_fn)),
+        }
+    }
+
+    /// Returns the JS function that receives each `WebOutputEvent`, if this
+    /// `WebOutput` was not created with [`WebOutput::from_fn`].
+    pub fn output_fn(&self) -> Option<&Function> {
+        match &self.output_fn {
+            WebOutputFn::Js(function) => Some(function),
+            WebOutputFn::Rust(_) => None,
+        }
+    }
+
+    /// Passes the event to the output function, serializing it for JS
+    /// functions.
+    fn event_send(&mut self, web_output_event: WebOutputEvent) -> Result<(), WebError> {
+        match &mut self.output_fn {
+            WebOutputFn::Js(function) => {
+                let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+                let event = web_output_event
+                    .serialize(&serializer)
+                    .map_err(|error| WebError::OutputSerialize(error.to_string()))?;
+
+                function
+                    .call1(&JsValue::NULL, &event)
+                    .map(|_| ())
+                    .map_err(|error| WebError::OutputCallback(crate::stringify_js_value(error)))
+            }
+            WebOutputFn::Rust(output_fn) => {
+                output_fn(web_output_event);
+                Ok(())
+            }
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<E> OutputWrite<E> for WebOutput
+where
+    E: std::error::Error + From<Error>,
+{
+    #[cfg(feature = "output_progress")]
+    async fn progress_begin(&mut self, cmd_progress_tracker: &CmdProgressTracker) {
+        let item_ids = cmd_progress_tracker
+            .progress_trackers()
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let _progress_send_result = self.event_send(WebOutputEvent::ProgressBegin { item_ids });
+    }
+
+    #[cfg(feature = "output_progress")]
+    async fn progress_update(
+        &mut self,
+        progress_tracker: &ProgressTracker,
+        progress_update_and_id: &ProgressUpdateAndId,
+    ) {
+        let web_output_event = WebOutputEvent::ProgressUpdate {
+            item_id: progress_update_and_id.item_id.clone(),
+            progress_update: progress_update_and_id.progress_update.clone(),
+            progress_status: progress_tracker.progress_status().clone(),
+            progress_limit: progress_tracker.progress_limit(),
+            units_current: progress_tracker.units_current(),
+            units_total: progress_tracker.units_total(),
+            message: progress_tracker.message().cloned(),
+        };
+
+        let _progress_send_result = self.event_send(web_output_event);
+    }
+
+    #[cfg(feature = "output_progress")]
+    async fn progress_end(&mut self, _cmd_progress_tracker: &CmdProgressTracker) {
+        let _progress_send_result = self.event_send(WebOutputEvent::ProgressEnd);
+    }
+
+    async fn present<P>(&mut self, presentable: P) -> Result<(), E>
+    where
+        P: Presentable,
+    {
+        let presentable = SensitiveSerdeMode::Redact
+            .scope(|| serde_json::to_value(&presentable))
+            .map_err(Error::StatesSerializeJson)?;
+
+        self.event_send(WebOutputEvent::Present { presentable })
+            .map_err(Error::Web)?;
+
+        Ok(())
+    }
+
+    async fn write_err(&mut self, error: &E) -> Result<(), E> {
+        let message = error.to_string();
+
+        self.event_send(WebOutputEvent::Error { message })
+            .map_err(Error::Web)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use serde::Serialize;
+
+#[cfg(feature = "output_progress")]
+use peace_core::{
+    progress::{ProgressLimit, ProgressStatus, ProgressUpdate},
+    ItemId,
+};
+
+/// Event passed to the JS callback of a [`WebOutput`].
+///
+/// This is serialized as a JS object with a `type` field, e.g.:
+///
+/// ```js
+/// { "type": "present", "presentable": { .. } }
+/// ```
+///
+/// [`WebOutput`]: crate::WebOutput
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WebOutputEvent {
+    /// Progress rendering has begun for the given items.
+    #[cfg(feature = "output_progress")]
+    ProgressBegin {
+        /// IDs of the items whose progress is tracked, in flow order.
+        item_ids: Vec<ItemId>,
+    },
+    /// An item's progress has been updated.
+    #[cfg(feature = "output_progress")]
+    ProgressUpdate {
+        /// ID of the item whose progress is updated.
+        item_id: ItemId,
+        /// Delta update that was applied to the progress tracker.
+        progress_update: ProgressUpdate,
+        /// Progress status after the update.
+        progress_status: ProgressStatus,
+        /// Unit of measurement and limit to indicate progress, if known.
+        progress_limit: Option<ProgressLimit>,
+        /// Number of units of progress made.
+        units_current: u64,
+        /// Total number of units of progress, if known.
+        units_total: Option<u64>,
+        /// Progress message, if any.
+        message: Option<String>,
+    },
+    /// Progress rendering has ended.
+    #[cfg(feature = "output_progress")]
+    ProgressEnd,
+    /// Information to present to the user.
+    Present {
+        /// The presentable, serialized as JSON.
+        presentable: serde_json::Value,
+    },
+    /// An error occurred.
+    Error {
+        /// The error's `Display` message.
+        message: String,
+    },
+}
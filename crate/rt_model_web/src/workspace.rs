@@ -9,7 +9,7 @@
 
 use peace_core::AppName;
 use peace_resources::internal::WorkspaceDirs;
-use peace_rt_model_core::Error;
+use peace_rt_model_core::{Error, StatesOrdering, StorageCompression, StorageFormat};
 
 use crate::{Storage, WorkspaceDirsBuilder, WorkspaceSpec};
 
@@ -44,6 +44,33 @@ impl Workspace {
         })
     }
 
+    /// Sets the format that states are stored in.
+    ///
+    /// Defaults to [`StorageFormat::Yaml`]. The same format must be used to
+    /// read the stored states back.
+    pub fn with_storage_format(mut self, storage_format: StorageFormat) -> Self {
+        self.storage = self.storage.with_storage_format(storage_format);
+        self
+    }
+
+    /// Sets the compression applied to stored states and params files.
+    ///
+    /// Defaults to [`StorageCompression::None`]. Compressed files are detected
+    /// when read, so this does not need to match the compression that existing
+    /// files were written with.
+    pub fn with_storage_compression(mut self, storage_compression: StorageCompression) -> Self {
+        self.storage = self.storage.with_storage_compression(storage_compression);
+        self
+    }
+
+    /// Sets the order of entries when states are stored.
+    ///
+    /// Defaults to [`StatesOrdering::Graph`].
+    pub fn with_states_ordering(mut self, states_ordering: StatesOrdering) -> Self {
+        self.storage = self.storage.with_states_ordering(states_ordering);
+        self
+    }
+
     /// Returns the underlying data.
     pub fn into_inner(self) -> (AppName, WorkspaceDirs, Storage) {
         let Self {
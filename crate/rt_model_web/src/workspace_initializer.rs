@@ -5,7 +5,7 @@ use peace_resources::{
     type_reg::untagged::{TypeMapOpt, TypeReg},
 };
 use peace_rt_model_core::{
-    params::{FlowParams, ProfileParams, WorkspaceParams},
+    params::{FlowParams, ProfileParams, WorkspaceParams, WorkspaceParamsRegistry},
     Error,
 };
 use serde::{de::DeserializeOwned, Serialize};
@@ -82,11 +82,9 @@ impl WorkspaceInitializer {
         K: Clone + Debug + Eq + Hash + DeserializeOwned + Send + Sync + 'static,
     {
         storage
-            .serialized_typemap_read_opt(
-                type_reg,
-                workspace_params_file,
-                Error::WorkspaceParamsDeserialize,
-            )
+            .serialized_typemap_read_opt(type_reg, workspace_params_file, |error, _| {
+                Error::WorkspaceParamsDeserialize(error)
+            })
             .await
             .map(|type_map_opt| {
                 type_map_opt
@@ -95,6 +93,46 @@ impl WorkspaceInitializer {
             })
     }
 
+    /// Migrates stored workspace params that are not in their current shape,
+    /// and writes them back to storage.
+    pub async fn workspace_params_migrate<K>(
+        storage: &Storage,
+        workspace_params_registry: &WorkspaceParamsRegistry<K>,
+        workspace_params_file: &WorkspaceParamsFile,
+    ) -> Result<(), Error>
+    where
+        K: Clone + Debug + Eq + Hash + DeserializeOwned + Serialize + Send + Sync + 'static,
+    {
+        let workspace_params = storage
+            .serialized_read_opt::<serde_yaml::Mapping, _>(workspace_params_file, |error, _| {
+                Error::WorkspaceParamsDeserialize(error)
+            })
+            .await?;
+        let Some(mut workspace_params) = workspace_params else {
+            return Ok(());
+        };
+
+        // Migrated params are stored in the workspace params file.
+        let migrated = storage
+            .sensitive_serde_mode(workspace_params_file)
+            .scope(|| workspace_params_registry.migrate(&mut workspace_params))
+            .map_err(|(key, error)| Error::WorkspaceParamMigrate {
+                key: format!("{key:?}"),
+                error,
+            })?;
+        if migrated {
+            storage
+                .serialized_write(
+                    workspace_params_file,
+                    &workspace_params,
+                    Error::WorkspaceParamsSerialize,
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
     pub async fn profile_params_serialize<K>(
         storage: &Storage,
         profile_params: &ProfileParams<K>,
@@ -121,11 +159,9 @@ impl WorkspaceInitializer {
         K: Clone + Debug + Eq + Hash + DeserializeOwned + Send + Sync + 'static,
     {
         storage
-            .serialized_typemap_read_opt(
-                type_reg,
-                profile_params_file,
-                Error::ProfileParamsDeserialize,
-            )
+            .serialized_typemap_read_opt(type_reg, profile_params_file, |error, _| {
+                Error::ProfileParamsDeserialize(error)
+            })
             .await
             .map(|type_map_opt| {
                 type_map_opt
@@ -156,7 +192,9 @@ impl WorkspaceInitializer {
         K: Clone + Debug + Eq + Hash + DeserializeOwned + Send + Sync + 'static,
     {
         storage
-            .serialized_typemap_read_opt(type_reg, flow_params_file, Error::FlowParamsDeserialize)
+            .serialized_typemap_read_opt(type_reg, flow_params_file, |error, _| {
+                Error::FlowParamsDeserialize(error)
+            })
             .await
             .map(|type_map_opt| {
                 type_map_opt
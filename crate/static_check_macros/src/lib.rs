@@ -93,6 +93,50 @@ pub fn item_id(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     ensure_valid_id(&parse_macro_input!(input as LitStrMaybe), "ItemId", None).into()
 }
 
+/// Returns a `const ItemGroupId` validated at compile time.
+///
+/// # Examples
+///
+/// Instantiate a valid `ItemGroupId` at compile time:
+///
+/// ```rust
+/// # use peace_static_check_macros::item_group_id;
+/// // use peace::cfg::{item_group_id, ItemGroupId};
+///
+/// let _my_item_group_id: ItemGroupId = item_group_id!("valid_id"); // Ok!
+///
+/// # struct ItemGroupId(&'static str);
+/// # impl ItemGroupId {
+/// #     fn new_unchecked(s: &'static str) -> Self { Self(s) }
+/// # }
+/// ```
+///
+/// If the ID is invalid, a compilation error is produced:
+///
+/// ```rust,compile_fail
+/// # use peace_static_check_macros::item_group_id;
+/// // use peace::cfg::{item_group_id, ItemGroupId};
+///
+/// let _my_item_group_id: ItemGroupId = item_group_id!("-invalid_id"); // Compile error
+/// //                                   ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+/// // error: "-invalid_id" is not a valid `ItemGroupId`.
+/// //        `ItemGroupId`s must begin with a letter or underscore, and contain only letters, numbers, or underscores.
+/// #
+/// # struct ItemGroupId(&'static str);
+/// # impl ItemGroupId {
+/// #     fn new_unchecked(s: &'static str) -> Self { Self(s) }
+/// # }
+/// ```
+#[proc_macro]
+pub fn item_group_id(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    ensure_valid_id(
+        &parse_macro_input!(input as LitStrMaybe),
+        "ItemGroupId",
+        None,
+    )
+    .into()
+}
+
 /// Returns a `const Profile` validated at compile time.
 ///
 /// # Examples
@@ -1,6 +1,6 @@
 use peace::{
     cfg::{flow_id, profile},
-    rt_model::{InMemoryTextOutput, WorkspaceSpec},
+    rt_model::{InMemoryOutput, WorkspaceSpec},
 };
 use peace_items::file_download::{FileDownloadParams, StorageForm};
 use url::Url;
@@ -53,17 +53,17 @@ pub async fn wasm_init(url: String, name: String) -> Result<WorkspaceAndOutput,
         workspace_and_flow,
         output: _,
     } = workspace_and_output;
-    let mut in_memory_text_output = InMemoryTextOutput::new();
+    let mut in_memory_output = InMemoryOutput::new();
     let _cmd_ctx = cmd_ctx(
         &workspace_and_flow,
         profile!("default"),
-        &mut in_memory_text_output,
+        &mut in_memory_output,
         Some(file_download_params),
     )
     .await
     .map_err(into_js_err_value)?;
 
-    let output = in_memory_text_output.into_inner();
+    let output = in_memory_output.into_inner();
 
     Ok(WorkspaceAndOutput {
         workspace_and_flow,
@@ -79,18 +79,18 @@ pub async fn wasm_fetch(
         workspace_and_flow,
         output: _,
     } = workspace_and_output;
-    let mut in_memory_text_output = InMemoryTextOutput::new();
+    let mut in_memory_output = InMemoryOutput::new();
     let mut cmd_ctx = cmd_ctx(
         &workspace_and_flow,
         profile!("default"),
-        &mut in_memory_text_output,
+        &mut in_memory_output,
         None,
     )
     .await
     .map_err(into_js_err_value)?;
 
     fetch(&mut cmd_ctx).await.map_err(into_js_err_value)?;
-    let output = in_memory_text_output.into_inner();
+    let output = in_memory_output.into_inner();
 
     Ok(WorkspaceAndOutput {
         workspace_and_flow,
@@ -106,18 +106,18 @@ pub async fn wasm_status(
         workspace_and_flow,
         output: _,
     } = workspace_and_output;
-    let mut in_memory_text_output = InMemoryTextOutput::new();
+    let mut in_memory_output = InMemoryOutput::new();
     let mut cmd_ctx = cmd_ctx(
         &workspace_and_flow,
         profile!("default"),
-        &mut in_memory_text_output,
+        &mut in_memory_output,
         None,
     )
     .await
     .map_err(into_js_err_value)?;
 
     status(&mut cmd_ctx).await.map_err(into_js_err_value)?;
-    let output = in_memory_text_output.into_inner();
+    let output = in_memory_output.into_inner();
 
     Ok(WorkspaceAndOutput {
         workspace_and_flow,
@@ -133,18 +133,18 @@ pub async fn wasm_goal(
         workspace_and_flow,
         output: _,
     } = workspace_and_output;
-    let mut in_memory_text_output = InMemoryTextOutput::new();
+    let mut in_memory_output = InMemoryOutput::new();
     let mut cmd_ctx = cmd_ctx(
         &workspace_and_flow,
         profile!("default"),
-        &mut in_memory_text_output,
+        &mut in_memory_output,
         None,
     )
     .await
     .map_err(into_js_err_value)?;
 
     goal(&mut cmd_ctx).await.map_err(into_js_err_value)?;
-    let output = in_memory_text_output.into_inner();
+    let output = in_memory_output.into_inner();
 
     Ok(WorkspaceAndOutput {
         workspace_and_flow,
@@ -160,18 +160,18 @@ pub async fn wasm_diff(
         workspace_and_flow,
         output: _,
     } = workspace_and_output;
-    let mut in_memory_text_output = InMemoryTextOutput::new();
+    let mut in_memory_output = InMemoryOutput::new();
     let mut cmd_ctx = cmd_ctx(
         &workspace_and_flow,
         profile!("default"),
-        &mut in_memory_text_output,
+        &mut in_memory_output,
         None,
     )
     .await
     .map_err(into_js_err_value)?;
 
     diff(&mut cmd_ctx).await.map_err(into_js_err_value)?;
-    let output = in_memory_text_output.into_inner();
+    let output = in_memory_output.into_inner();
 
     Ok(WorkspaceAndOutput {
         workspace_and_flow,
@@ -187,18 +187,18 @@ pub async fn wasm_ensure_dry(
         workspace_and_flow,
         output: _,
     } = workspace_and_output;
-    let mut in_memory_text_output = InMemoryTextOutput::new();
+    let mut in_memory_output = InMemoryOutput::new();
     let mut cmd_ctx = cmd_ctx(
         &workspace_and_flow,
         profile!("default"),
-        &mut in_memory_text_output,
+        &mut in_memory_output,
         None,
     )
     .await
     .map_err(into_js_err_value)?;
 
     ensure_dry(&mut cmd_ctx).await.map_err(into_js_err_value)?;
-    let output = in_memory_text_output.into_inner();
+    let output = in_memory_output.into_inner();
 
     Ok(WorkspaceAndOutput {
         workspace_and_flow,
@@ -214,18 +214,18 @@ pub async fn wasm_ensure(
         workspace_and_flow,
         output: _,
     } = workspace_and_output;
-    let mut in_memory_text_output = InMemoryTextOutput::new();
+    let mut in_memory_output = InMemoryOutput::new();
     let mut cmd_ctx = cmd_ctx(
         &workspace_and_flow,
         profile!("default"),
-        &mut in_memory_text_output,
+        &mut in_memory_output,
         None,
     )
     .await
     .map_err(into_js_err_value)?;
 
     ensure(&mut cmd_ctx).await.map_err(into_js_err_value)?;
-    let output = in_memory_text_output.into_inner();
+    let output = in_memory_output.into_inner();
 
     Ok(WorkspaceAndOutput {
         workspace_and_flow,
@@ -241,18 +241,18 @@ pub async fn wasm_clean_dry(
         workspace_and_flow,
         output: _,
     } = workspace_and_output;
-    let mut in_memory_text_output = InMemoryTextOutput::new();
+    let mut in_memory_output = InMemoryOutput::new();
     let mut cmd_ctx = cmd_ctx(
         &workspace_and_flow,
         profile!("default"),
-        &mut in_memory_text_output,
+        &mut in_memory_output,
         None,
     )
     .await
     .map_err(into_js_err_value)?;
 
     clean_dry(&mut cmd_ctx).await.map_err(into_js_err_value)?;
-    let output = in_memory_text_output.into_inner();
+    let output = in_memory_output.into_inner();
 
     Ok(WorkspaceAndOutput {
         workspace_and_flow,
@@ -268,18 +268,18 @@ pub async fn wasm_clean(
         workspace_and_flow,
         output: _,
     } = workspace_and_output;
-    let mut in_memory_text_output = InMemoryTextOutput::new();
+    let mut in_memory_output = InMemoryOutput::new();
     let mut cmd_ctx = cmd_ctx(
         &workspace_and_flow,
         profile!("default"),
-        &mut in_memory_text_output,
+        &mut in_memory_output,
         None,
     )
     .await
     .map_err(into_js_err_value)?;
 
     clean(&mut cmd_ctx).await.map_err(into_js_err_value)?;
-    let output = in_memory_text_output.into_inner();
+    let output = in_memory_output.into_inner();
 
     Ok(WorkspaceAndOutput {
         workspace_and_flow,
@@ -12,14 +12,12 @@ use peace::{
     params::Params,
     rt_model::{output::OutputWrite, Workspace, WorkspaceSpec},
 };
+use peace_items::aws_s3_bucket::S3BucketState;
 
 use crate::{
     cmds::CmdOpts,
     flows::AppUploadFlow,
-    items::{
-        peace_aws_s3_bucket::S3BucketState,
-        peace_aws_s3_object::{S3ObjectItem, S3ObjectParams},
-    },
+    items::peace_aws_s3_object::{S3ObjectItem, S3ObjectParams},
     model::{EnvManError, EnvType, ProfileParamsKey, WebApp, WorkspaceParamsKey},
     rt_model::{EnvManCmdCtx, EnvmanCmdCtxTypes},
 };
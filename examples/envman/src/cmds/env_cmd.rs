@@ -12,14 +12,12 @@ use peace::{
     params::Params,
     rt_model::{output::OutputWrite, Workspace, WorkspaceSpec},
 };
+use peace_items::aws_iam_role::{IamRoleItem, IamRoleParams};
 
 use crate::{
     cmds::CmdOpts,
     flows::EnvDeployFlow,
-    items::{
-        peace_aws_iam_policy::IamPolicyState,
-        peace_aws_iam_role::{IamRoleItem, IamRoleParams},
-    },
+    items::peace_aws_iam_policy::IamPolicyState,
     model::{EnvManError, EnvType, ProfileParamsKey, WebApp, WorkspaceParamsKey},
     rt_model::{EnvManCmdCtx, EnvmanCmdCtxTypes},
 };
@@ -11,17 +11,16 @@ use peace::{
     rt::cmds::StatesDiscoverCmd,
     rt_model::{output::OutputWrite, Flow, Workspace, WorkspaceSpec},
 };
-use peace_items::{file_download::FileDownloadItem, tar_x::TarXItem};
+use peace_items::{
+    aws_iam_role::IamRoleItem, aws_instance_profile::InstanceProfileItem,
+    aws_s3_bucket::S3BucketItem, file_download::FileDownloadItem, tar_x::TarXItem,
+};
 use semver::Version;
 use url::Url;
 
 use crate::{
     flows::{AppUploadFlow, AppUploadFlowParamsSpecs, EnvDeployFlow, EnvDeployFlowParamsSpecs},
-    items::{
-        peace_aws_iam_policy::IamPolicyItem, peace_aws_iam_role::IamRoleItem,
-        peace_aws_instance_profile::InstanceProfileItem, peace_aws_s3_bucket::S3BucketItem,
-        peace_aws_s3_object::S3ObjectItem,
-    },
+    items::{peace_aws_iam_policy::IamPolicyItem, peace_aws_s3_object::S3ObjectItem},
     model::{
         EnvManError, EnvManFlow, EnvType, ProfileParamsKey, RepoSlug, WebApp, WorkspaceParamsKey,
     },
@@ -181,6 +180,7 @@ impl ProfileInitCmd {
                 cmd_blocks_processed: _,
                 cmd_blocks_not_processed: _,
                 errors,
+                item_log_paths: _,
             } => crate::output::item_errors_present(output, &errors).await?,
         }
 
@@ -5,15 +5,15 @@ use peace::{
     params::{Params, ParamsSpec},
     rt_model::{Flow, ItemGraphBuilder},
 };
-use peace_items::file_download::{FileDownloadItem, FileDownloadParams};
+use peace_items::{
+    aws_s3_bucket::{S3BucketItem, S3BucketParams},
+    file_download::{FileDownloadItem, FileDownloadParams},
+};
 use semver::Version;
 use url::Url;
 
 use crate::{
-    items::{
-        peace_aws_s3_bucket::{S3BucketItem, S3BucketParams},
-        peace_aws_s3_object::{S3ObjectItem, S3ObjectParams},
-    },
+    items::peace_aws_s3_object::{S3ObjectItem, S3ObjectParams},
     model::{EnvManError, RepoSlug, WebApp},
 };
 
@@ -6,6 +6,9 @@ use peace::{
     rt_model::{Flow, ItemGraphBuilder},
 };
 use peace_items::{
+    aws_iam_role::{IamRoleItem, IamRoleParams},
+    aws_instance_profile::{InstanceProfileItem, InstanceProfileParams},
+    aws_s3_bucket::{S3BucketItem, S3BucketParams},
     file_download::{FileDownloadItem, FileDownloadParams},
     tar_x::{TarXItem, TarXParams},
 };
@@ -15,9 +18,6 @@ use url::Url;
 use crate::{
     items::{
         peace_aws_iam_policy::{IamPolicyItem, IamPolicyParams, IamPolicyState},
-        peace_aws_iam_role::{IamRoleItem, IamRoleParams},
-        peace_aws_instance_profile::{InstanceProfileItem, InstanceProfileParams},
-        peace_aws_s3_bucket::{S3BucketItem, S3BucketParams},
         peace_aws_s3_object::{S3ObjectItem, S3ObjectParams},
     },
     model::{EnvManError, RepoSlug, WebApp},
@@ -1,9 +1,6 @@
 //! Umbrella module for items.
 
 pub mod peace_aws_iam_policy;
-pub mod peace_aws_iam_role;
-pub mod peace_aws_instance_profile;
-pub mod peace_aws_s3_bucket;
 pub mod peace_aws_s3_object;
 
 // Hack: need to find a better way to do this.
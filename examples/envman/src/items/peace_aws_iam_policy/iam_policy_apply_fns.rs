@@ -30,12 +30,15 @@ where
                 let apply_check = {
                     #[cfg(not(feature = "output_progress"))]
                     {
-                        ApplyCheck::ExecRequired
+                        ApplyCheck::ExecRequired { reason: None }
                     }
                     #[cfg(feature = "output_progress")]
                     {
                         let progress_limit = ProgressLimit::Steps(3);
-                        ApplyCheck::ExecRequired { progress_limit }
+                        ApplyCheck::ExecRequired {
+                            progress_limit,
+                            reason: None,
+                        }
                     }
                 };
 
@@ -60,12 +63,15 @@ where
                         } else {
                             #[cfg(not(feature = "output_progress"))]
                             {
-                                ApplyCheck::ExecRequired
+                                ApplyCheck::ExecRequired { reason: None }
                             }
                             #[cfg(feature = "output_progress")]
                             {
                                 let progress_limit = ProgressLimit::Steps(steps_required);
-                                ApplyCheck::ExecRequired { progress_limit }
+                                ApplyCheck::ExecRequired {
+                                    progress_limit,
+                                    reason: None,
+                                }
                             }
                         }
                     }
@@ -2,7 +2,7 @@ use std::marker::PhantomData;
 
 use aws_config::BehaviorVersion;
 use peace::{
-    cfg::{async_trait, ApplyCheck, FnCtx, Item, ItemId},
+    cfg::{async_trait, ApplyCheck, ApplyCtx, FnCtx, Item, ItemId},
     params::Params,
     resources::{resources::ts::Empty, Resources},
 };
@@ -127,6 +127,7 @@ where
     }
 
     async fn apply_check(
+        _apply_ctx: ApplyCtx<'_>,
         params: &Self::Params<'_>,
         data: Self::Data<'_>,
         state_current: &Self::State,
@@ -30,12 +30,15 @@ where
                 let apply_check = {
                     #[cfg(not(feature = "output_progress"))]
                     {
-                        ApplyCheck::ExecRequired
+                        ApplyCheck::ExecRequired { reason: None }
                     }
                     #[cfg(feature = "output_progress")]
                     {
                         let progress_limit = ProgressLimit::Steps(1);
-                        ApplyCheck::ExecRequired { progress_limit }
+                        ApplyCheck::ExecRequired {
+                            progress_limit,
+                            reason: None,
+                        }
                     }
                 };
 
@@ -52,13 +55,16 @@ where
                     } => {
                         #[cfg(not(feature = "output_progress"))]
                         {
-                            ApplyCheck::ExecRequired
+                            ApplyCheck::ExecRequired { reason: None }
                         }
                         #[cfg(feature = "output_progress")]
                         {
                             let steps_required = 1;
                             let progress_limit = ProgressLimit::Steps(steps_required);
-                            ApplyCheck::ExecRequired { progress_limit }
+                            ApplyCheck::ExecRequired {
+                                progress_limit,
+                                reason: None,
+                            }
                         }
                     }
                 };
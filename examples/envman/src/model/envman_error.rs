@@ -98,7 +98,7 @@ pub enum EnvManError {
         #[cfg_attr(feature = "error_reporting", diagnostic_source)]
         #[source]
         #[from]
-        crate::items::peace_aws_instance_profile::InstanceProfileError,
+        peace_items::aws_instance_profile::InstanceProfileError,
     ),
     /// An `IamPolicy` item error occurred.
     #[error("An `IamPolicy` item error occurred.")]
@@ -114,7 +114,7 @@ pub enum EnvManError {
         #[cfg_attr(feature = "error_reporting", diagnostic_source)]
         #[source]
         #[from]
-        crate::items::peace_aws_iam_role::IamRoleError,
+        peace_items::aws_iam_role::IamRoleError,
     ),
     /// An `S3Bucket` item error occurred.
     #[error("An `S3Bucket` item error occurred.")]
@@ -122,7 +122,7 @@ pub enum EnvManError {
         #[cfg_attr(feature = "error_reporting", diagnostic_source)]
         #[source]
         #[from]
-        crate::items::peace_aws_s3_bucket::S3BucketError,
+        peace_items::aws_s3_bucket::S3BucketError,
     ),
     /// An `S3Object` item error occurred.
     #[error("An `S3Object` item error occurred.")]
@@ -144,6 +144,7 @@ where
             item_stream_outcome,
             cmd_blocks_processed,
             cmd_blocks_not_processed,
+            deadline_exceeded,
         } => {
             let cmd_blocks_complete = cmd_blocks_processed
                 .iter()
@@ -162,7 +163,11 @@ where
                 .iter()
                 .collect::<Vec<_>>();
 
-            presentln!(output, ["Execution was interrupted."]);
+            if *deadline_exceeded {
+                presentln!(output, ["Execution deadline was exceeded."]);
+            } else {
+                presentln!(output, ["Execution was interrupted."]);
+            }
             presentln!(output, [""]);
 
             presentln!(output, ["`CmdBlock`s completed:"]);
@@ -185,6 +190,7 @@ where
             value: _,
             cmd_blocks_processed,
             cmd_blocks_not_processed,
+            deadline_exceeded,
         } => {
             let cmd_blocks_complete = cmd_blocks_processed
                 .iter()
@@ -195,7 +201,11 @@ where
                 .map(|cmd_block_desc| cmd_block_desc.cmd_block_name())
                 .collect::<Vec<_>>();
 
-            presentln!(output, ["Execution was interrupted."]);
+            if *deadline_exceeded {
+                presentln!(output, ["Execution deadline was exceeded."]);
+            } else {
+                presentln!(output, ["Execution was interrupted."]);
+            }
             presentln!(output, [""]);
 
             presentln!(output, ["`CmdBlock`s completed:"]);
@@ -211,6 +221,7 @@ where
             cmd_blocks_processed: _,
             cmd_blocks_not_processed: _,
             errors,
+            item_log_paths: _,
         } => {
             item_errors_present(output, errors).await?;
             let _ = tokio::fs::write("resources.ron", format!("{resources:#?}")).await;
@@ -4,7 +4,7 @@ use std::marker::PhantomData;
 use peace::cfg::progress::{ProgressLimit, ProgressMsgUpdate, ProgressSender};
 use peace::cfg::{state::Generated, ApplyCheck, FnCtx};
 
-use crate::items::peace_aws_iam_role::{
+use crate::{
     model::RoleIdAndArn, IamRoleData, IamRoleError, IamRoleParams, IamRoleState, IamRoleStateDiff,
 };
 
@@ -33,7 +33,7 @@ impl<Id> IamRoleApplyFns<Id> {
                 let role_path = path.to_string();
 
                 #[cfg(feature = "error_reporting")]
-                let (aws_desc, aws_desc_span) = crate::items::aws_error_desc!(&error);
+                let (aws_desc, aws_desc_span) = crate::aws_error_desc!(&error);
 
                 IamRoleError::ManagedPolicyDetachError {
                     role_name,
@@ -67,12 +67,15 @@ where
                 let apply_check = {
                     #[cfg(not(feature = "output_progress"))]
                     {
-                        ApplyCheck::ExecRequired
+                        ApplyCheck::ExecRequired { reason: None }
                     }
                     #[cfg(feature = "output_progress")]
                     {
                         let progress_limit = ProgressLimit::Steps(2);
-                        ApplyCheck::ExecRequired { progress_limit }
+                        ApplyCheck::ExecRequired {
+                            progress_limit,
+                            reason: None,
+                        }
                     }
                 };
 
@@ -100,12 +103,15 @@ where
                         } else {
                             #[cfg(not(feature = "output_progress"))]
                             {
-                                ApplyCheck::ExecRequired
+                                ApplyCheck::ExecRequired { reason: None }
                             }
                             #[cfg(feature = "output_progress")]
                             {
                                 let progress_limit = ProgressLimit::Steps(steps_required);
-                                ApplyCheck::ExecRequired { progress_limit }
+                                ApplyCheck::ExecRequired {
+                                    progress_limit,
+                                    reason: None,
+                                }
                             }
                         }
                     }
@@ -117,14 +123,17 @@ where
                 let apply_check = {
                     #[cfg(not(feature = "output_progress"))]
                     {
-                        ApplyCheck::ExecRequired
+                        ApplyCheck::ExecRequired { reason: None }
                     }
                     #[cfg(feature = "output_progress")]
                     {
                         // Technically could be 1 or 2, whether we detach an existing before
                         // attaching another, or just attach one.
                         let progress_limit = ProgressLimit::Steps(2);
-                        ApplyCheck::ExecRequired { progress_limit }
+                        ApplyCheck::ExecRequired {
+                            progress_limit,
+                            reason: None,
+                        }
                     }
                 };
 
@@ -194,7 +203,7 @@ where
                             let role_name = name.to_string();
 
                             #[cfg(feature = "error_reporting")]
-                            let (aws_desc, aws_desc_span) = crate::items::aws_error_desc!(&error);
+                            let (aws_desc, aws_desc_span) = crate::aws_error_desc!(&error);
 
                             IamRoleError::RoleCreateError {
                                 role_name,
@@ -230,7 +239,7 @@ where
                         .await
                         .map_err(|error| {
                             #[cfg(feature = "error_reporting")]
-                            let (aws_desc, aws_desc_span) = crate::items::aws_error_desc!(&error);
+                            let (aws_desc, aws_desc_span) = crate::aws_error_desc!(&error);
 
                             IamRoleError::ManagedPolicyAttachError {
                                 role_name: name.clone(),
@@ -302,7 +311,7 @@ where
 
                                     #[cfg(feature = "error_reporting")]
                                     let (aws_desc, aws_desc_span) =
-                                        crate::items::aws_error_desc!(&error);
+                                        crate::aws_error_desc!(&error);
 
                                     IamRoleError::RoleDeleteError {
                                         role_name,
@@ -382,7 +391,7 @@ where
                         .await
                         .map_err(|error| {
                             #[cfg(feature = "error_reporting")]
-                            let (aws_desc, aws_desc_span) = crate::items::aws_error_desc!(&error);
+                            let (aws_desc, aws_desc_span) = crate::aws_error_desc!(&error);
                             IamRoleError::ManagedPolicyAttachError {
                                 role_name: name.clone(),
                                 role_path: path.clone(),
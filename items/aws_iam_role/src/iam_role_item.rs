@@ -2,36 +2,35 @@ use std::marker::PhantomData;
 
 use aws_config::BehaviorVersion;
 use peace::{
-    cfg::{async_trait, ApplyCheck, FnCtx, Item, ItemId},
+    cfg::{async_trait, ApplyCheck, ApplyCtx, DiffSeverity, FnCtx, Item, ItemId},
     params::Params,
     resources::{resources::ts::Empty, Resources},
 };
 
-use crate::items::peace_aws_iam_role::{
+use crate::{
     IamRoleApplyFns, IamRoleData, IamRoleError, IamRoleParams, IamRoleState, IamRoleStateCurrentFn,
     IamRoleStateDiff, IamRoleStateDiffFn, IamRoleStateGoalFn,
 };
 
-/// Item to create an IAM instance profile and IAM role.
+/// Item to create an IAM role.
 ///
 /// In sequence, this will:
 ///
-/// * Create the IAM Role.
-/// * Create the instance profile.
-/// * Add the IAM role to the instance profile.
+/// * Create the IAM role.
+/// * Attach the managed policy to the IAM role.
 ///
-/// The `Id` type parameter is needed for each instance profile params to be a
+/// The `Id` type parameter is needed for each IAM role params to be a
 /// distinct type.
 ///
 /// # Type Parameters
 ///
-/// * `Id`: A zero-sized type used to distinguish different instance profile
+/// * `Id`: A zero-sized type used to distinguish different IAM role
 ///   parameters from each other.
 #[derive(Debug)]
 pub struct IamRoleItem<Id> {
-    /// ID of the instance profile item.
+    /// ID of the IAM role item.
     item_id: ItemId,
-    /// Marker for unique instance profile parameters type.
+    /// Marker for unique IAM role parameters type.
     marker: PhantomData<Id>,
 }
 
@@ -119,6 +118,24 @@ where
         IamRoleStateDiffFn::state_diff(state_current, state_goal).await
     }
 
+    fn diff_severity(
+        _state_current: &Self::State,
+        _state_goal: &Self::State,
+        state_diff: &Self::StateDiff,
+    ) -> DiffSeverity {
+        match state_diff {
+            IamRoleStateDiff::Added | IamRoleStateDiff::ManagedPolicyAttachmentModified { .. } => {
+                DiffSeverity::Additive
+            }
+            IamRoleStateDiff::Removed | IamRoleStateDiff::NameOrPathModified { .. } => {
+                DiffSeverity::Destructive
+            }
+            IamRoleStateDiff::InSyncExists | IamRoleStateDiff::InSyncDoesNotExist => {
+                DiffSeverity::InSync
+            }
+        }
+    }
+
     async fn state_clean(
         _params_partial: &<Self::Params<'_> as Params>::Partial,
         _data: Self::Data<'_>,
@@ -127,6 +144,7 @@ where
     }
 
     async fn apply_check(
+        _apply_ctx: ApplyCtx<'_>,
         params: &Self::Params<'_>,
         data: Self::Data<'_>,
         state_current: &Self::State,
@@ -3,7 +3,7 @@ use std::fmt;
 use peace::cfg::state::Generated;
 use serde::{Deserialize, Serialize};
 
-use crate::items::peace_aws_iam_role::model::{ManagedPolicyAttachment, RoleIdAndArn};
+use crate::model::{ManagedPolicyAttachment, RoleIdAndArn};
 
 /// IAM role state.
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
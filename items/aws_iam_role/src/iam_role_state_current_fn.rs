@@ -6,7 +6,7 @@ use peace::{
     params::Params,
 };
 
-use crate::items::peace_aws_iam_role::{
+use crate::{
     model::{ManagedPolicyAttachment, RoleIdAndArn},
     IamRoleData, IamRoleError, IamRoleParams, IamRoleState,
 };
@@ -81,7 +81,7 @@ where
             }
             Err(error) => {
                 #[cfg(feature = "error_reporting")]
-                let (aws_desc, aws_desc_span) = crate::items::aws_error_desc!(&error);
+                let (aws_desc, aws_desc_span) = crate::aws_error_desc!(&error);
                 match &error {
                     SdkError::ServiceError(service_error) => match service_error.err() {
                         GetRoleError::NoSuchEntityException(_) => None,
@@ -135,7 +135,7 @@ where
                     .await
                     .map_err(|error| {
                         #[cfg(feature = "error_reporting")]
-                        let (aws_desc, aws_desc_span) = crate::items::aws_error_desc!(&error);
+                        let (aws_desc, aws_desc_span) = crate::aws_error_desc!(&error);
 
                         IamRoleError::ManagedPoliciesListError {
                             role_name: name.to_string(),
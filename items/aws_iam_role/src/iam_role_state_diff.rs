@@ -2,7 +2,7 @@ use std::fmt;
 
 use serde::{Deserialize, Serialize};
 
-use crate::items::peace_aws_iam_role::model::ManagedPolicyAttachment;
+use crate::model::ManagedPolicyAttachment;
 
 /// Diff between current (dest) and goal (src) state.
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
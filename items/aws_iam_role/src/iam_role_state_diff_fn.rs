@@ -1,6 +1,6 @@
-use crate::items::peace_aws_iam_role::{IamRoleError, IamRoleState, IamRoleStateDiff};
+use crate::{IamRoleError, IamRoleState, IamRoleStateDiff};
 
-/// Tar extraction status diff function.
+/// IAM role state diff function.
 #[derive(Debug)]
 pub struct IamRoleStateDiffFn;
 
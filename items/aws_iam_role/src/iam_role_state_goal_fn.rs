@@ -5,7 +5,7 @@ use peace::{
     params::Params,
 };
 
-use crate::items::peace_aws_iam_role::{
+use crate::{
     model::ManagedPolicyAttachment, IamRoleData, IamRoleError, IamRoleParams, IamRoleState,
 };
 
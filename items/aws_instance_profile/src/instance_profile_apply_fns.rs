@@ -4,7 +4,7 @@ use std::marker::PhantomData;
 use peace::cfg::progress::{ProgressLimit, ProgressMsgUpdate, ProgressSender};
 use peace::cfg::{state::Generated, ApplyCheck, FnCtx};
 
-use crate::items::peace_aws_instance_profile::{
+use crate::{
     model::InstanceProfileIdAndArn, InstanceProfileData, InstanceProfileError,
     InstanceProfileParams, InstanceProfileState, InstanceProfileStateDiff,
 };
@@ -34,7 +34,7 @@ impl<Id> InstanceProfileApplyFns<Id> {
                 let role_name = name.to_string();
 
                 #[cfg(feature = "error_reporting")]
-                let (aws_desc, aws_desc_span) = crate::items::aws_error_desc!(&error);
+                let (aws_desc, aws_desc_span) = crate::aws_error_desc!(&error);
 
                 InstanceProfileError::InstanceProfileRoleAddError {
                     instance_profile_name,
@@ -72,7 +72,7 @@ impl<Id> InstanceProfileApplyFns<Id> {
                 let instance_profile_path = path.to_string();
 
                 #[cfg(feature = "error_reporting")]
-                let (aws_desc, aws_desc_span) = crate::items::aws_error_desc!(&error);
+                let (aws_desc, aws_desc_span) = crate::aws_error_desc!(&error);
 
                 InstanceProfileError::InstanceProfileRoleRemoveError {
                     instance_profile_name,
@@ -111,13 +111,16 @@ where
                 let apply_check = {
                     #[cfg(not(feature = "output_progress"))]
                     {
-                        ApplyCheck::ExecRequired
+                        ApplyCheck::ExecRequired { reason: None }
                     }
                     #[cfg(feature = "output_progress")]
                     {
                         // Create instance profile, associate role
                         let progress_limit = ProgressLimit::Steps(2);
-                        ApplyCheck::ExecRequired { progress_limit }
+                        ApplyCheck::ExecRequired {
+                            progress_limit,
+                            reason: None,
+                        }
                     }
                 };
 
@@ -145,12 +148,15 @@ where
                         } else {
                             #[cfg(not(feature = "output_progress"))]
                             {
-                                ApplyCheck::ExecRequired
+                                ApplyCheck::ExecRequired { reason: None }
                             }
                             #[cfg(feature = "output_progress")]
                             {
                                 let progress_limit = ProgressLimit::Steps(steps_required);
-                                ApplyCheck::ExecRequired { progress_limit }
+                                ApplyCheck::ExecRequired {
+                                    progress_limit,
+                                    reason: None,
+                                }
                             }
                         }
                     }
@@ -223,7 +229,7 @@ where
                             let instance_profile_path = path.to_string();
 
                             #[cfg(feature = "error_reporting")]
-                            let (aws_desc, aws_desc_span) = crate::items::aws_error_desc!(&error);
+                            let (aws_desc, aws_desc_span) = crate::aws_error_desc!(&error);
 
                             InstanceProfileError::InstanceProfileCreateError {
                                 instance_profile_name,
@@ -311,7 +317,7 @@ where
 
                                 #[cfg(feature = "error_reporting")]
                                 let (aws_desc, aws_desc_span) =
-                                    crate::items::aws_error_desc!(&error);
+                                    crate::aws_error_desc!(&error);
 
                                 InstanceProfileError::InstanceProfileDeleteError {
                                     instance_profile_name,
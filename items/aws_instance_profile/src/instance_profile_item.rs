@@ -2,24 +2,24 @@ use std::marker::PhantomData;
 
 use aws_config::BehaviorVersion;
 use peace::{
-    cfg::{async_trait, ApplyCheck, FnCtx, Item, ItemId},
+    cfg::{async_trait, ApplyCheck, ApplyCtx, DiffSeverity, FnCtx, Item, ItemId},
     params::Params,
     resources::{resources::ts::Empty, Resources},
 };
 
-use crate::items::peace_aws_instance_profile::{
+use crate::{
     InstanceProfileApplyFns, InstanceProfileData, InstanceProfileError, InstanceProfileParams,
     InstanceProfileState, InstanceProfileStateCurrentFn, InstanceProfileStateDiff,
     InstanceProfileStateDiffFn, InstanceProfileStateGoalFn,
 };
 
-/// Item to create an IAM instance profile and IAM role.
+/// Item to create an IAM instance profile.
 ///
 /// In sequence, this will:
 ///
-/// * Create the IAM Role.
 /// * Create the instance profile.
-/// * Add the IAM role to the instance profile.
+/// * Add the IAM role with the same name to the instance profile, if
+///   `role_associate` is set.
 ///
 /// The `Id` type parameter is needed for each instance profile params to be a
 /// distinct type.
@@ -120,6 +120,21 @@ where
         InstanceProfileStateDiffFn::state_diff(state_current, state_goal).await
     }
 
+    fn diff_severity(
+        _state_current: &Self::State,
+        _state_goal: &Self::State,
+        state_diff: &Self::StateDiff,
+    ) -> DiffSeverity {
+        match state_diff {
+            InstanceProfileStateDiff::Added
+            | InstanceProfileStateDiff::RoleAssociatedModified { .. } => DiffSeverity::Additive,
+            InstanceProfileStateDiff::Removed
+            | InstanceProfileStateDiff::NameOrPathModified { .. } => DiffSeverity::Destructive,
+            InstanceProfileStateDiff::InSyncExists
+            | InstanceProfileStateDiff::InSyncDoesNotExist => DiffSeverity::InSync,
+        }
+    }
+
     async fn state_clean(
         _params_partial: &<Self::Params<'_> as Params>::Partial,
         _data: Self::Data<'_>,
@@ -128,6 +143,7 @@ where
     }
 
     async fn apply_check(
+        _apply_ctx: ApplyCtx<'_>,
         params: &Self::Params<'_>,
         data: Self::Data<'_>,
         state_current: &Self::State,
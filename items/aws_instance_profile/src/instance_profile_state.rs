@@ -3,7 +3,7 @@ use std::fmt;
 use peace::cfg::state::Generated;
 use serde::{Deserialize, Serialize};
 
-use crate::items::peace_aws_instance_profile::model::InstanceProfileIdAndArn;
+use crate::model::InstanceProfileIdAndArn;
 
 /// Instance profile state.
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
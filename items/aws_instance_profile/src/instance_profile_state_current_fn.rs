@@ -6,7 +6,7 @@ use peace::{
     params::Params,
 };
 
-use crate::items::peace_aws_instance_profile::{
+use crate::{
     model::InstanceProfileIdAndArn, InstanceProfileData, InstanceProfileError,
     InstanceProfileParams, InstanceProfileState,
 };
@@ -84,7 +84,7 @@ where
                 let instance_profile_id_and_arn =
                     InstanceProfileIdAndArn::new(instance_profile_id, instance_profile_arn);
 
-                let role_associated = instance_profile.roles().first().is_some();
+                let role_associated = !instance_profile.roles().is_empty();
 
                 Some((
                     instance_profile_name,
@@ -100,7 +100,7 @@ where
                 )));
 
                 #[cfg(feature = "error_reporting")]
-                let (aws_desc, aws_desc_span) = crate::items::aws_error_desc!(&error);
+                let (aws_desc, aws_desc_span) = crate::aws_error_desc!(&error);
 
                 match &error {
                     SdkError::ServiceError(service_error) => match service_error.err() {
@@ -1,8 +1,8 @@
-use crate::items::peace_aws_instance_profile::{
+use crate::{
     InstanceProfileError, InstanceProfileState, InstanceProfileStateDiff,
 };
 
-/// Tar extraction status diff function.
+/// Instance profile state diff function.
 #[derive(Debug)]
 pub struct InstanceProfileStateDiffFn;
 
@@ -5,7 +5,7 @@ use peace::{
     params::Params,
 };
 
-use crate::items::peace_aws_instance_profile::{
+use crate::{
     InstanceProfileData, InstanceProfileError, InstanceProfileParams, InstanceProfileState,
 };
 
@@ -0,0 +1,88 @@
+//! Manages an AWS IAM instance profile for the peace framework.
+//!
+//! This item creates an instance profile, and optionally associates the IAM
+//! role with the same name with it, so that EC2 instances launched with the
+//! instance profile may assume the role. The instance profile is deleted when
+//! cleaned, after the role is disassociated.
+//!
+//! The AWS SDK client is created from the environment's AWS configuration
+//! during item setup, unless an `aws_sdk_iam::Client` is already inserted into
+//! `Resources`.
+
+pub use crate::{
+    instance_profile_apply_fns::InstanceProfileApplyFns,
+    instance_profile_data::InstanceProfileData,
+    instance_profile_error::InstanceProfileError,
+    instance_profile_item::InstanceProfileItem,
+    instance_profile_params::{
+        InstanceProfileParams, InstanceProfileParamsFieldWise, InstanceProfileParamsPartial,
+    },
+    instance_profile_state::InstanceProfileState,
+    instance_profile_state_current_fn::InstanceProfileStateCurrentFn,
+    instance_profile_state_diff::InstanceProfileStateDiff,
+    instance_profile_state_diff_fn::InstanceProfileStateDiffFn,
+    instance_profile_state_goal_fn::InstanceProfileStateGoalFn,
+};
+
+pub mod model;
+
+mod instance_profile_apply_fns;
+mod instance_profile_data;
+mod instance_profile_error;
+mod instance_profile_item;
+mod instance_profile_params;
+mod instance_profile_state;
+mod instance_profile_state_current_fn;
+mod instance_profile_state_diff;
+mod instance_profile_state_diff_fn;
+mod instance_profile_state_goal_fn;
+
+// Hack: need to find a better way to do this.
+#[cfg(feature = "error_reporting")]
+macro_rules! aws_error_desc {
+    ($error:expr) => {{
+        use aws_sdk_iam::error::ProvideErrorMetadata;
+
+        let (error_code, desc) = match $error {
+            aws_sdk_iam::error::SdkError::ServiceError(service_error) => {
+                let error_code = service_error.err().code().map(|s| s.to_string());
+                let desc = service_error.err().message().map(|s| s.to_string());
+
+                (error_code, desc)
+            }
+            _ => {
+                // most variants do not `impl Error`, but we can
+                // access the underlying error through
+                // `sdk_error.source()`.
+
+                let mut source = Option::<&dyn std::error::Error>::Some($error);
+                while let Some(source_next) = source.and_then(std::error::Error::source) {
+                    source = Some(source_next);
+                }
+
+                let error_code = None;
+                let desc = source.map(|source| format!("{source}"));
+
+                (error_code, desc)
+            }
+        };
+
+        let mut aws_desc = String::new();
+        let mut desc_span_start = 0;
+        let mut desc_len = 0;
+        if let Some(error_code) = error_code {
+            aws_desc.push_str(&format!("{error_code}: "));
+            desc_span_start = aws_desc.len();
+        }
+        if let Some(desc) = desc {
+            aws_desc.push_str(&desc);
+            desc_len = desc.len();
+        }
+        let aws_desc_span = peace::miette::SourceSpan::from((desc_span_start, desc_len));
+
+        (aws_desc, aws_desc_span)
+    }};
+}
+
+#[cfg(feature = "error_reporting")]
+pub(crate) use aws_error_desc;
@@ -0,0 +1,83 @@
+//! Manages an AWS S3 bucket for the peace framework.
+//!
+//! This item creates an S3 bucket with the given name in the region of the
+//! AWS SDK configuration, and deletes it when cleaned. S3 bucket names are
+//! globally unique, so creating a bucket fails if someone else owns the name.
+//!
+//! The AWS SDK client is created from the environment's AWS configuration
+//! during item setup, unless an `aws_sdk_s3::Client` is already inserted into
+//! `Resources`.
+
+pub use crate::{
+    s3_bucket_apply_fns::S3BucketApplyFns,
+    s3_bucket_data::S3BucketData,
+    s3_bucket_error::S3BucketError,
+    s3_bucket_item::S3BucketItem,
+    s3_bucket_params::{S3BucketParams, S3BucketParamsFieldWise, S3BucketParamsPartial},
+    s3_bucket_state::S3BucketState,
+    s3_bucket_state_current_fn::S3BucketStateCurrentFn,
+    s3_bucket_state_diff::S3BucketStateDiff,
+    s3_bucket_state_diff_fn::S3BucketStateDiffFn,
+    s3_bucket_state_goal_fn::S3BucketStateGoalFn,
+};
+
+mod s3_bucket_apply_fns;
+mod s3_bucket_data;
+mod s3_bucket_error;
+mod s3_bucket_item;
+mod s3_bucket_params;
+mod s3_bucket_state;
+mod s3_bucket_state_current_fn;
+mod s3_bucket_state_diff;
+mod s3_bucket_state_diff_fn;
+mod s3_bucket_state_goal_fn;
+
+// Hack: need to find a better way to do this.
+#[cfg(feature = "error_reporting")]
+macro_rules! aws_error_desc {
+    ($error:expr) => {{
+        use aws_sdk_s3::error::ProvideErrorMetadata;
+
+        let (error_code, desc) = match $error {
+            aws_sdk_s3::error::SdkError::ServiceError(service_error) => {
+                let error_code = service_error.err().code().map(|s| s.to_string());
+                let desc = service_error.err().message().map(|s| s.to_string());
+
+                (error_code, desc)
+            }
+            _ => {
+                // most variants do not `impl Error`, but we can
+                // access the underlying error through
+                // `sdk_error.source()`.
+
+                let mut source = Option::<&dyn std::error::Error>::Some($error);
+                while let Some(source_next) = source.and_then(std::error::Error::source) {
+                    source = Some(source_next);
+                }
+
+                let error_code = None;
+                let desc = source.map(|source| format!("{source}"));
+
+                (error_code, desc)
+            }
+        };
+
+        let mut aws_desc = String::new();
+        let mut desc_span_start = 0;
+        let mut desc_len = 0;
+        if let Some(error_code) = error_code {
+            aws_desc.push_str(&format!("{error_code}: "));
+            desc_span_start = aws_desc.len();
+        }
+        if let Some(desc) = desc {
+            aws_desc.push_str(&desc);
+            desc_len = desc.len();
+        }
+        let aws_desc_span = peace::miette::SourceSpan::from((desc_span_start, desc_len));
+
+        (aws_desc, aws_desc_span)
+    }};
+}
+
+#[cfg(feature = "error_reporting")]
+pub(crate) use aws_error_desc;
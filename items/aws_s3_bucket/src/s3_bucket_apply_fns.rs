@@ -1,6 +1,6 @@
 use std::marker::PhantomData;
 
-use aws_sdk_iam::error::{ProvideErrorMetadata, SdkError};
+use aws_sdk_s3::error::{ProvideErrorMetadata, SdkError};
 use aws_sdk_s3::{
     operation::create_bucket::CreateBucketError,
     types::{BucketLocationConstraint, CreateBucketConfiguration},
@@ -9,7 +9,7 @@ use aws_sdk_s3::{
 use peace::cfg::progress::{ProgressLimit, ProgressMsgUpdate};
 use peace::cfg::{ApplyCheck, FnCtx};
 
-use crate::items::peace_aws_s3_bucket::{
+use crate::{
     S3BucketData, S3BucketError, S3BucketParams, S3BucketState, S3BucketStateDiff,
 };
 
@@ -31,16 +31,19 @@ where
         diff: &S3BucketStateDiff,
     ) -> Result<ApplyCheck, S3BucketError> {
         match diff {
-            S3BucketStateDiff::Added { .. } => {
+            S3BucketStateDiff::Added => {
                 let apply_check = {
                     #[cfg(not(feature = "output_progress"))]
                     {
-                        ApplyCheck::ExecRequired
+                        ApplyCheck::ExecRequired { reason: None }
                     }
                     #[cfg(feature = "output_progress")]
                     {
                         let progress_limit = ProgressLimit::Steps(1);
-                        ApplyCheck::ExecRequired { progress_limit }
+                        ApplyCheck::ExecRequired {
+                            progress_limit,
+                            reason: None,
+                        }
                     }
                 };
 
@@ -55,13 +58,16 @@ where
                     } => {
                         #[cfg(not(feature = "output_progress"))]
                         {
-                            ApplyCheck::ExecRequired
+                            ApplyCheck::ExecRequired { reason: None }
                         }
                         #[cfg(feature = "output_progress")]
                         {
                             let steps_required = 1;
                             let progress_limit = ProgressLimit::Steps(steps_required);
-                            ApplyCheck::ExecRequired { progress_limit }
+                            ApplyCheck::ExecRequired {
+                                progress_limit,
+                                reason: None,
+                            }
                         }
                     }
                 };
@@ -130,7 +136,7 @@ where
                         let s3_bucket_name = name.to_string();
 
                         #[cfg(feature = "error_reporting")]
-                        let (aws_desc, aws_desc_span) = crate::items::aws_error_desc!(&error);
+                        let (aws_desc, aws_desc_span) = crate::aws_error_desc!(&error);
 
                         match &error {
                             SdkError::ServiceError(service_error) => match &service_error.err() {
@@ -211,7 +217,7 @@ where
 
                                 #[cfg(feature = "error_reporting")]
                                 let (aws_desc, aws_desc_span) =
-                                    crate::items::aws_error_desc!(&error);
+                                    crate::aws_error_desc!(&error);
 
                                 let s3_bucket_name = name.to_string();
                                 Err(S3BucketError::S3BucketDeleteError {
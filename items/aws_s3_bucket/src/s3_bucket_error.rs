@@ -60,11 +60,7 @@ pub enum S3BucketError {
         diagnostic(help(
             "Someone else owns the S3 bucket name.\n\
             \n\
-            Please use a different profile name by running:\n\
-            ```\n\
-            ./envman switch <profile_name> --create --type development username/repo <version>\n\
-            ```\n\
-            "
+            S3 bucket names are globally unique, please use a different bucket name."
         ))
     )]
     S3BucketOwnedBySomeoneElseError {
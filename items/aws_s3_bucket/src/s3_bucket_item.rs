@@ -2,23 +2,21 @@ use std::marker::PhantomData;
 
 use aws_config::BehaviorVersion;
 use peace::{
-    cfg::{async_trait, ApplyCheck, FnCtx, Item, ItemId},
+    cfg::{async_trait, ApplyCheck, ApplyCtx, DiffSeverity, FnCtx, Item, ItemId},
     params::Params,
     resources::{resources::ts::Empty, Resources},
 };
 
-use crate::items::peace_aws_s3_bucket::{
+use crate::{
     S3BucketApplyFns, S3BucketData, S3BucketError, S3BucketParams, S3BucketState,
     S3BucketStateCurrentFn, S3BucketStateDiff, S3BucketStateDiffFn, S3BucketStateGoalFn,
 };
 
-/// Item to create an IAM S3 bucket and IAM role.
+/// Item to create an S3 bucket.
 ///
-/// In sequence, this will:
-///
-/// * Create the IAM Role.
-/// * Create the S3 bucket.
-/// * Add the IAM role to the S3 bucket.
+/// The bucket is created in the region of the AWS SDK configuration. S3
+/// bucket names are globally unique, so this fails if another AWS account
+/// already owns a bucket with the same name.
 ///
 /// The `Id` type parameter is needed for each S3 bucket params to be a
 /// distinct type.
@@ -120,6 +118,22 @@ where
         S3BucketStateDiffFn::state_diff(state_current, state_goal).await
     }
 
+    fn diff_severity(
+        _state_current: &Self::State,
+        _state_goal: &Self::State,
+        state_diff: &Self::StateDiff,
+    ) -> DiffSeverity {
+        match state_diff {
+            S3BucketStateDiff::Added => DiffSeverity::Additive,
+            S3BucketStateDiff::Removed | S3BucketStateDiff::NameModified { .. } => {
+                DiffSeverity::Destructive
+            }
+            S3BucketStateDiff::InSyncExists | S3BucketStateDiff::InSyncDoesNotExist => {
+                DiffSeverity::InSync
+            }
+        }
+    }
+
     async fn state_clean(
         _params_partial: &<Self::Params<'_> as Params>::Partial,
         _data: Self::Data<'_>,
@@ -128,6 +142,7 @@ where
     }
 
     async fn apply_check(
+        _apply_ctx: ApplyCtx<'_>,
         params: &Self::Params<'_>,
         data: Self::Data<'_>,
         state_current: &Self::State,
@@ -28,6 +28,7 @@ pub struct S3BucketParams<Id> {
 }
 
 impl<Id> S3BucketParams<Id> {
+    /// Returns new `S3BucketParams`.
     pub fn new(name: String) -> Self {
         Self {
             name,
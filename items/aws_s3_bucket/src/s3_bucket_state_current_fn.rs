@@ -6,7 +6,7 @@ use peace::{
     params::Params,
 };
 
-use crate::items::peace_aws_s3_bucket::{
+use crate::{
     S3BucketData, S3BucketError, S3BucketParams, S3BucketState,
 };
 
@@ -59,7 +59,7 @@ where
         progress_sender.tick(ProgressMsgUpdate::Set(String::from("listing buckets")));
         let list_buckets_output = client.list_buckets().send().await.map_err(|error| {
             #[cfg(feature = "error_reporting")]
-            let (aws_desc, aws_desc_span) = crate::items::aws_error_desc!(&error);
+            let (aws_desc, aws_desc_span) = crate::aws_error_desc!(&error);
 
             S3BucketError::S3BucketListError {
                 s3_bucket_name: name.to_string(),
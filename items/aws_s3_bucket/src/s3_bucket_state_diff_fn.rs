@@ -1,6 +1,6 @@
-use crate::items::peace_aws_s3_bucket::{S3BucketError, S3BucketState, S3BucketStateDiff};
+use crate::{S3BucketError, S3BucketState, S3BucketStateDiff};
 
-/// Tar extraction status diff function.
+/// S3 bucket state diff function.
 #[derive(Debug)]
 pub struct S3BucketStateDiffFn;
 
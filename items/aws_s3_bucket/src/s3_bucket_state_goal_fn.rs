@@ -5,7 +5,7 @@ use peace::{
     params::Params,
 };
 
-use crate::items::peace_aws_s3_bucket::{
+use crate::{
     S3BucketData, S3BucketError, S3BucketParams, S3BucketState,
 };
 
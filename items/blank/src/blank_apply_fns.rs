@@ -26,12 +26,15 @@ where
             BlankStateDiff::Added { .. } | BlankStateDiff::OutOfSync { .. } => {
                 #[cfg(not(feature = "output_progress"))]
                 {
-                    ApplyCheck::ExecRequired
+                    ApplyCheck::ExecRequired { reason: None }
                 }
                 #[cfg(feature = "output_progress")]
                 {
                     let progress_limit = ProgressLimit::Steps(1);
-                    ApplyCheck::ExecRequired { progress_limit }
+                    ApplyCheck::ExecRequired {
+                        progress_limit,
+                        reason: None,
+                    }
                 }
             }
         };
@@ -0,0 +1,160 @@
+use std::marker::PhantomData;
+
+#[cfg(feature = "output_progress")]
+use peace::cfg::progress::ProgressLimit;
+use peace::cfg::{ApplyCheck, FnCtx};
+
+use crate::{
+    CloudInitVmData, CloudInitVmError, CloudInitVmParams, CloudInitVmState,
+    CloudInitVmStateCurrentFn, CloudInitVmStateDiff, VirtCli,
+};
+
+/// ApplyFns for the cloud-init virtual machine.
+#[derive(Debug)]
+pub struct CloudInitVmApplyFns<Id>(PhantomData<Id>);
+
+impl<Id> CloudInitVmApplyFns<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn apply_check(
+        _params: &CloudInitVmParams<Id>,
+        _data: CloudInitVmData<'_, Id>,
+        _state_current: &CloudInitVmState,
+        _state_target: &CloudInitVmState,
+        diff: &CloudInitVmStateDiff,
+    ) -> Result<ApplyCheck, CloudInitVmError> {
+        let apply_check = if diff.is_in_sync() {
+            ApplyCheck::ExecNotRequired
+        } else {
+            #[cfg(not(feature = "output_progress"))]
+            {
+                ApplyCheck::ExecRequired { reason: None }
+            }
+            #[cfg(feature = "output_progress")]
+            {
+                ApplyCheck::ExecRequired {
+                    progress_limit: ProgressLimit::Unknown,
+                    reason: None,
+                }
+            }
+        };
+
+        Ok(apply_check)
+    }
+
+    pub async fn apply_dry(
+        _fn_ctx: FnCtx<'_>,
+        _params: &CloudInitVmParams<Id>,
+        _data: CloudInitVmData<'_, Id>,
+        _state_current: &CloudInitVmState,
+        state_target: &CloudInitVmState,
+        _diff: &CloudInitVmStateDiff,
+    ) -> Result<CloudInitVmState, CloudInitVmError> {
+        Ok(state_target.clone())
+    }
+
+    pub async fn apply(
+        _fn_ctx: FnCtx<'_>,
+        params: &CloudInitVmParams<Id>,
+        _data: CloudInitVmData<'_, Id>,
+        state_current: &CloudInitVmState,
+        state_target: &CloudInitVmState,
+        diff: &CloudInitVmStateDiff,
+    ) -> Result<CloudInitVmState, CloudInitVmError> {
+        if !state_target.vm_exists {
+            if state_current.vm_exists {
+                Self::vm_remove(params).await?;
+            }
+        } else if diff.is_recreate_required() {
+            if state_current.vm_exists {
+                Self::vm_remove(params).await?;
+            }
+            Self::vm_create(params, state_target).await?;
+        }
+
+        CloudInitVmStateCurrentFn::<Id>::state_current_internal(params.vm_name()).await
+    }
+
+    /// Creates and starts the virtual machine with `virt-install`, and records
+    /// the target state in its description.
+    async fn vm_create(
+        params: &CloudInitVmParams<Id>,
+        state_target: &CloudInitVmState,
+    ) -> Result<(), CloudInitVmError> {
+        let disk_dir = params.disk_dir();
+        tokio::fs::create_dir_all(disk_dir).await.map_err(|error| {
+            CloudInitVmError::DiskDirCreate {
+                disk_dir: disk_dir.to_path_buf(),
+                error,
+            }
+        })?;
+
+        let user_data_path = params.user_data_path();
+        tokio::fs::write(&user_data_path, params.user_data())
+            .await
+            .map_err(|error| CloudInitVmError::UserDataWrite {
+                user_data_path: user_data_path.clone(),
+                error,
+            })?;
+
+        let vm_name = params.vm_name();
+        let memory = params.memory_mib().to_string();
+        let vcpus = params.vcpus().to_string();
+        let disk = format!(
+            "path={},format=qcow2,backing_store={},backing_format=qcow2,size={}",
+            params.disk_path().display(),
+            params.image_path().display(),
+            params.disk_size_gib()
+        );
+        let cloud_init = format!("user-data={}", user_data_path.display());
+        VirtCli::exec(
+            "virt-install",
+            &[
+                "--name",
+                vm_name,
+                "--memory",
+                &memory,
+                "--vcpus",
+                &vcpus,
+                "--import",
+                "--disk",
+                &disk,
+                "--cloud-init",
+                &cloud_init,
+                "--osinfo",
+                "detect=on,require=off",
+                "--noautoconsole",
+            ],
+        )
+        .await?;
+
+        let description = state_target.description();
+        VirtCli::exec(
+            "virsh",
+            &["desc", vm_name, "--config", "--new-desc", &description],
+        )
+        .await
+    }
+
+    /// Stops and undefines the virtual machine, removing its disk and user
+    /// data file.
+    async fn vm_remove(params: &CloudInitVmParams<Id>) -> Result<(), CloudInitVmError> {
+        let vm_name = params.vm_name();
+
+        // `destroy` fails when the virtual machine is not running, which is
+        // not an error for removal.
+        VirtCli::query("virsh", &["destroy", vm_name]).await?;
+        VirtCli::exec("virsh", &["undefine", vm_name, "--remove-all-storage"]).await?;
+
+        let user_data_path = params.user_data_path();
+        match tokio::fs::remove_file(&user_data_path).await {
+            Ok(()) => Ok(()),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(error) => Err(CloudInitVmError::UserDataRemove {
+                user_data_path,
+                error,
+            }),
+        }
+    }
+}
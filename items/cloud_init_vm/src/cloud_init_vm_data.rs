@@ -0,0 +1,33 @@
+use std::marker::PhantomData;
+
+use peace::{cfg::accessors::Stored, data::Data};
+
+use crate::CloudInitVmState;
+
+/// Data used to manage a cloud-init virtual machine.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different virtual machine
+///   parameters from each other.
+#[derive(Data, Debug)]
+pub struct CloudInitVmData<'exec, Id>
+where
+    Id: Send + Sync + 'static,
+{
+    /// Stored state of this item's previous execution.
+    state_current_stored: Stored<'exec, CloudInitVmState>,
+
+    /// Marker.
+    marker: PhantomData<Id>,
+}
+
+impl<'exec, Id> CloudInitVmData<'exec, Id>
+where
+    Id: Send + Sync + 'static,
+{
+    /// Returns the stored state of this item's previous execution.
+    pub fn state_current_stored(&self) -> Option<&CloudInitVmState> {
+        self.state_current_stored.get()
+    }
+}
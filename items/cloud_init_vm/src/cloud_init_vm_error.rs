@@ -0,0 +1,102 @@
+use std::path::PathBuf;
+
+#[cfg(feature = "error_reporting")]
+use peace::miette;
+
+/// Error while managing a cloud-init virtual machine.
+#[cfg_attr(feature = "error_reporting", derive(peace::miette::Diagnostic))]
+#[derive(Debug, thiserror::Error)]
+pub enum CloudInitVmError {
+    /// Failed to execute a libvirt command.
+    #[error("Failed to execute `{program} {args}`.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_cloud_init_vm::virt_exec),
+            help("Check that `virsh` and `virt-install` are on the `PATH`.")
+        )
+    )]
+    VirtExec {
+        /// Program that was executed, e.g. `virsh`.
+        program: &'static str,
+        /// Arguments passed to the program.
+        args: String,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// A libvirt command returned a non-zero exit code.
+    #[error("`{program} {args}` failed with exit code {exit_code:?}:\n{stderr}")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_cloud_init_vm::virt_fail),
+            help(
+                "Check that the libvirt daemon is running, and the current user has permission to use it."
+            )
+        )
+    )]
+    VirtFail {
+        /// Program that was executed, e.g. `virsh`.
+        program: &'static str,
+        /// Arguments passed to the program.
+        args: String,
+        /// Exit code of the process, `None` if it was terminated by a signal.
+        exit_code: Option<i32>,
+        /// Output written to stderr.
+        stderr: String,
+    },
+
+    /// Failed to create the directory for the virtual machine's disk.
+    #[error("Failed to create disk directory: `{}`.", disk_dir.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_cloud_init_vm::disk_dir_create))
+    )]
+    DiskDirCreate {
+        /// Path to the directory.
+        disk_dir: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to write the cloud-init user data file.
+    #[error("Failed to write user data file: `{}`.", user_data_path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_cloud_init_vm::user_data_write))
+    )]
+    UserDataWrite {
+        /// Path to the user data file.
+        user_data_path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to remove the cloud-init user data file.
+    #[error("Failed to remove user data file: `{}`.", user_data_path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_cloud_init_vm::user_data_remove))
+    )]
+    UserDataRemove {
+        /// Path to the user data file.
+        user_data_path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    // === Framework errors === //
+    /// A `peace` runtime error occurred.
+    #[error("A `peace` runtime error occurred.")]
+    PeaceRtError(
+        #[cfg_attr(feature = "error_reporting", diagnostic_source)]
+        #[source]
+        #[from]
+        peace::rt_model::Error,
+    ),
+}
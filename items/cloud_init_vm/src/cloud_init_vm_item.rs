@@ -0,0 +1,201 @@
+use std::marker::PhantomData;
+
+use peace::{
+    cfg::{async_trait, ApplyCheck, ApplyCtx, DiffSeverity, FnCtx, Item, ItemId},
+    params::Params,
+    resources::{resources::ts::Empty, Resources},
+};
+
+use crate::{
+    CloudInitVmApplyFns, CloudInitVmData, CloudInitVmError, CloudInitVmParams, CloudInitVmState,
+    CloudInitVmStateCurrentFn, CloudInitVmStateDiff, CloudInitVmStateDiffFn,
+    CloudInitVmStateGoalFn,
+};
+
+/// Item for managing a local cloud-init virtual machine.
+///
+/// The goal state is for a libvirt virtual machine to exist, created from the
+/// given image version, and initialized with the given cloud-init user data.
+/// The virtual machine is recreated when its image version or user data
+/// changes. Cleaning the item destroys the virtual machine and removes its
+/// disk.
+///
+/// The `Id` type parameter is needed for each virtual machine params to be a
+/// distinct type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different virtual machine
+///   parameters from each other.
+#[derive(Debug)]
+pub struct CloudInitVmItem<Id> {
+    /// ID of the item to manage the virtual machine.
+    item_id: ItemId,
+    /// Marker for unique virtual machine parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> Clone for CloudInitVmItem<Id> {
+    fn clone(&self) -> Self {
+        Self {
+            item_id: self.item_id.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<Id> CloudInitVmItem<Id> {
+    /// Returns a new `CloudInitVmItem`.
+    pub fn new(item_id: ItemId) -> Self {
+        Self {
+            item_id,
+            marker: PhantomData,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<Id> Item for CloudInitVmItem<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    type Data<'exec> = CloudInitVmData<'exec, Id>;
+    type Error = CloudInitVmError;
+    type Params<'exec> = CloudInitVmParams<Id>;
+    type State = CloudInitVmState;
+    type StateDiff = CloudInitVmStateDiff;
+
+    fn id(&self) -> &ItemId {
+        &self.item_id
+    }
+
+    async fn setup(&self, _resources: &mut Resources<Empty>) -> Result<(), CloudInitVmError> {
+        Ok(())
+    }
+
+    async fn try_state_current(
+        fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        data: CloudInitVmData<'_, Id>,
+    ) -> Result<Option<Self::State>, CloudInitVmError> {
+        CloudInitVmStateCurrentFn::try_state_current(fn_ctx, params_partial, data).await
+    }
+
+    async fn state_current(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: CloudInitVmData<'_, Id>,
+    ) -> Result<Self::State, CloudInitVmError> {
+        CloudInitVmStateCurrentFn::state_current(fn_ctx, params, data).await
+    }
+
+    async fn try_state_goal(
+        fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        data: CloudInitVmData<'_, Id>,
+    ) -> Result<Option<Self::State>, CloudInitVmError> {
+        CloudInitVmStateGoalFn::try_state_goal(fn_ctx, params_partial, data).await
+    }
+
+    async fn state_goal(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: CloudInitVmData<'_, Id>,
+    ) -> Result<Self::State, CloudInitVmError> {
+        CloudInitVmStateGoalFn::state_goal(fn_ctx, params, data).await
+    }
+
+    async fn state_diff(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_goal: &Self::State,
+    ) -> Result<Self::StateDiff, CloudInitVmError> {
+        CloudInitVmStateDiffFn::state_diff(state_current, state_goal).await
+    }
+
+    fn diff_severity(
+        _state_current: &Self::State,
+        _state_goal: &Self::State,
+        state_diff: &Self::StateDiff,
+    ) -> DiffSeverity {
+        // Recreating an existing virtual machine discards its disk.
+        let vm_replaced = state_diff.vm_exists.is_none() && state_diff.is_recreate_required();
+        let vm_removed = state_diff.vm_exists == Some((true, false));
+
+        if state_diff.is_in_sync() {
+            DiffSeverity::InSync
+        } else if vm_replaced || vm_removed {
+            DiffSeverity::Destructive
+        } else {
+            DiffSeverity::Additive
+        }
+    }
+
+    fn error_help(error: &CloudInitVmError) -> Vec<String> {
+        match error {
+            CloudInitVmError::VirtExec { .. } => vec![String::from(
+                "Check that `virsh` and `virt-install` are on the `PATH`.",
+            )],
+            CloudInitVmError::VirtFail { .. } => vec![String::from(
+                "Check that the libvirt daemon is running, and the current user has permission to use it.",
+            )],
+            CloudInitVmError::DiskDirCreate { .. }
+            | CloudInitVmError::UserDataWrite { .. }
+            | CloudInitVmError::UserDataRemove { .. } => vec![String::from(
+                "Check that the current user has permission to write to the disk directory.",
+            )],
+            CloudInitVmError::PeaceRtError(_) => Vec::new(),
+        }
+    }
+
+    async fn state_clean(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+    ) -> Result<Self::State, CloudInitVmError> {
+        Ok(CloudInitVmState::clean())
+    }
+
+    async fn apply_check(
+        _apply_ctx: ApplyCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<ApplyCheck, Self::Error> {
+        CloudInitVmApplyFns::<Id>::apply_check(params, data, state_current, state_target, diff)
+            .await
+    }
+
+    async fn apply_dry(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        CloudInitVmApplyFns::<Id>::apply_dry(
+            fn_ctx,
+            params,
+            data,
+            state_current,
+            state_target,
+            diff,
+        )
+        .await
+    }
+
+    async fn apply(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        CloudInitVmApplyFns::<Id>::apply(fn_ctx, params, data, state_current, state_target, diff)
+            .await
+    }
+}
@@ -0,0 +1,124 @@
+use std::{
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+use derivative::Derivative;
+use peace::params::Params;
+use serde::{Deserialize, Serialize};
+
+/// Cloud-init virtual machine parameters.
+///
+/// The `Id` type parameter is needed for each virtual machine params to be a
+/// distinct type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different virtual machine
+///   parameters from each other.
+#[derive(Derivative, Params, PartialEq, Eq, Deserialize, Serialize)]
+#[derivative(Clone, Debug)]
+#[serde(bound = "")]
+pub struct CloudInitVmParams<Id> {
+    /// Name of the libvirt domain, e.g. `app_server_test`.
+    vm_name: String,
+    /// Path to the base qcow2 image, e.g. a downloaded Ubuntu cloud image.
+    ///
+    /// The image is not modified, as the virtual machine writes to its own
+    /// copy on write disk.
+    image_path: PathBuf,
+    /// Version of the base image, e.g. `ubuntu-24.04-20240423`.
+    ///
+    /// The virtual machine is recreated when this changes.
+    image_version: String,
+    /// Cloud-init user data, e.g. a `#cloud-config` document.
+    user_data: String,
+    /// Memory to allocate, in MiB.
+    memory_mib: u32,
+    /// Number of virtual CPUs.
+    vcpus: u32,
+    /// Size of the virtual machine's disk, in GiB.
+    disk_size_gib: u32,
+    /// Directory to write the virtual machine's disk and user data file to.
+    disk_dir: PathBuf,
+    /// Marker for unique virtual machine parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> CloudInitVmParams<Id> {
+    /// Returns new `CloudInitVmParams`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        vm_name: String,
+        image_path: PathBuf,
+        image_version: String,
+        user_data: String,
+        memory_mib: u32,
+        vcpus: u32,
+        disk_size_gib: u32,
+        disk_dir: PathBuf,
+    ) -> Self {
+        Self {
+            vm_name,
+            image_path,
+            image_version,
+            user_data,
+            memory_mib,
+            vcpus,
+            disk_size_gib,
+            disk_dir,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the name of the libvirt domain.
+    pub fn vm_name(&self) -> &str {
+        &self.vm_name
+    }
+
+    /// Returns the path to the base qcow2 image.
+    pub fn image_path(&self) -> &Path {
+        &self.image_path
+    }
+
+    /// Returns the version of the base image.
+    pub fn image_version(&self) -> &str {
+        &self.image_version
+    }
+
+    /// Returns the cloud-init user data.
+    pub fn user_data(&self) -> &str {
+        &self.user_data
+    }
+
+    /// Returns the memory to allocate, in MiB.
+    pub fn memory_mib(&self) -> u32 {
+        self.memory_mib
+    }
+
+    /// Returns the number of virtual CPUs.
+    pub fn vcpus(&self) -> u32 {
+        self.vcpus
+    }
+
+    /// Returns the size of the virtual machine's disk, in GiB.
+    pub fn disk_size_gib(&self) -> u32 {
+        self.disk_size_gib
+    }
+
+    /// Returns the directory to write the virtual machine's disk and user
+    /// data file to.
+    pub fn disk_dir(&self) -> &Path {
+        &self.disk_dir
+    }
+
+    /// Returns the path to the virtual machine's copy on write disk.
+    pub fn disk_path(&self) -> PathBuf {
+        self.disk_dir.join(format!("{}.qcow2", self.vm_name))
+    }
+
+    /// Returns the path to the file that the user data is written to.
+    pub fn user_data_path(&self) -> PathBuf {
+        self.disk_dir.join(format!("{}.user-data", self.vm_name))
+    }
+}
@@ -0,0 +1,116 @@
+use std::fmt;
+
+use ring::digest;
+use serde::{Deserialize, Serialize};
+
+/// State of a cloud-init virtual machine.
+///
+/// This is whether the virtual machine exists, the version of the image it was
+/// created from, and a hash of the user data it was initialized with.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct CloudInitVmState {
+    /// Whether the virtual machine exists.
+    pub vm_exists: bool,
+    /// Version of the image the virtual machine was created from, `None` if
+    /// it does not exist, or was not created by this item.
+    pub image_version: Option<String>,
+    /// SHA-256 hash of the user data the virtual machine was initialized with,
+    /// `None` if it does not exist, or was not created by this item.
+    pub cloud_init_hash: Option<String>,
+}
+
+impl CloudInitVmState {
+    /// Key in the virtual machine's description that holds its image version.
+    pub const IMAGE_VERSION_KEY: &'static str = "peace.image_version";
+    /// Key in the virtual machine's description that holds its user data hash.
+    pub const CLOUD_INIT_HASH_KEY: &'static str = "peace.cloud_init_hash";
+
+    /// Returns a new `CloudInitVmState`.
+    pub fn new(
+        vm_exists: bool,
+        image_version: Option<String>,
+        cloud_init_hash: Option<String>,
+    ) -> Self {
+        Self {
+            vm_exists,
+            image_version,
+            cloud_init_hash,
+        }
+    }
+
+    /// Returns the state when the virtual machine does not exist.
+    pub fn clean() -> Self {
+        Self {
+            vm_exists: false,
+            image_version: None,
+            cloud_init_hash: None,
+        }
+    }
+
+    /// Returns the description to record on the virtual machine, so that its
+    /// state can be read back with `virsh desc`.
+    pub fn description(&self) -> String {
+        let image_version = self.image_version.as_deref().unwrap_or_default();
+        let cloud_init_hash = self.cloud_init_hash.as_deref().unwrap_or_default();
+        format!(
+            "{}={image_version}\n{}={cloud_init_hash}",
+            Self::IMAGE_VERSION_KEY,
+            Self::CLOUD_INIT_HASH_KEY
+        )
+    }
+
+    /// Returns the state of an existing virtual machine from the output of
+    /// `virsh desc`.
+    ///
+    /// Values that are not in the description are `None`, e.g. when the
+    /// virtual machine was not created by this item.
+    pub fn from_description(description: &str) -> Self {
+        let value = |key: &str| {
+            description.lines().find_map(|line| {
+                line.trim()
+                    .strip_prefix(key)
+                    .and_then(|value| value.strip_prefix('='))
+                    .map(str::trim)
+                    .filter(|value| !value.is_empty())
+                    .map(String::from)
+            })
+        };
+
+        Self {
+            vm_exists: true,
+            image_version: value(Self::IMAGE_VERSION_KEY),
+            cloud_init_hash: value(Self::CLOUD_INIT_HASH_KEY),
+        }
+    }
+
+    /// Returns the hex encoded SHA-256 hash of the given user data.
+    pub fn cloud_init_hash(user_data: &str) -> String {
+        digest::digest(&digest::SHA256, user_data.as_bytes())
+            .as_ref()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+}
+
+impl fmt::Display for CloudInitVmState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            vm_exists,
+            image_version,
+            cloud_init_hash,
+        } = self;
+
+        if !vm_exists {
+            return write!(f, "vm does not exist");
+        }
+
+        let image_version = image_version.as_deref().unwrap_or("<unknown>");
+        write!(f, "vm running image `{image_version}`")?;
+        if let Some(cloud_init_hash) = cloud_init_hash {
+            let hash_short = cloud_init_hash.get(..12).unwrap_or(cloud_init_hash);
+            write!(f, ", user data `{hash_short}`")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use std::marker::PhantomData;
+
+use peace::{cfg::FnCtx, params::Params};
+
+use crate::{CloudInitVmData, CloudInitVmError, CloudInitVmParams, CloudInitVmState, VirtCli};
+
+/// Reads the current state of the cloud-init virtual machine.
+#[derive(Debug)]
+pub struct CloudInitVmStateCurrentFn<Id>(PhantomData<Id>);
+
+impl<Id> CloudInitVmStateCurrentFn<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn try_state_current(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<CloudInitVmParams<Id> as Params>::Partial,
+        _data: CloudInitVmData<'_, Id>,
+    ) -> Result<Option<CloudInitVmState>, CloudInitVmError> {
+        match params_partial.vm_name() {
+            Some(vm_name) => Self::state_current_internal(vm_name).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn state_current(
+        _fn_ctx: FnCtx<'_>,
+        params: &CloudInitVmParams<Id>,
+        _data: CloudInitVmData<'_, Id>,
+    ) -> Result<CloudInitVmState, CloudInitVmError> {
+        Self::state_current_internal(params.vm_name()).await
+    }
+
+    pub(crate) async fn state_current_internal(
+        vm_name: &str,
+    ) -> Result<CloudInitVmState, CloudInitVmError> {
+        if VirtCli::query("virsh", &["dominfo", vm_name])
+            .await?
+            .is_none()
+        {
+            return Ok(CloudInitVmState::clean());
+        }
+
+        let description = VirtCli::query("virsh", &["desc", vm_name]).await?;
+        Ok(CloudInitVmState::from_description(
+            description.as_deref().unwrap_or_default(),
+        ))
+    }
+}
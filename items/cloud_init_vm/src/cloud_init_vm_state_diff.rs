@@ -0,0 +1,59 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Diff between the current and goal cloud-init virtual machine state.
+///
+/// Each field is `Some((from, to))` when that part of the state differs.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct CloudInitVmStateDiff {
+    /// Change in whether the virtual machine exists.
+    pub vm_exists: Option<(bool, bool)>,
+    /// Change in the image version.
+    pub image_version: Option<(Option<String>, Option<String>)>,
+    /// Change in the user data hash.
+    pub cloud_init_hash: Option<(Option<String>, Option<String>)>,
+}
+
+impl CloudInitVmStateDiff {
+    /// Returns whether the virtual machine's existence, image version, and
+    /// user data are all in sync.
+    pub fn is_in_sync(&self) -> bool {
+        self.vm_exists.is_none() && self.image_version.is_none() && self.cloud_init_hash.is_none()
+    }
+
+    /// Returns whether the virtual machine needs to be created, or recreated
+    /// due to an image or user data change.
+    pub fn is_recreate_required(&self) -> bool {
+        match self.vm_exists {
+            Some((_, false)) => false,
+            Some((false, true)) => true,
+            _ => self.image_version.is_some() || self.cloud_init_hash.is_some(),
+        }
+    }
+}
+
+impl fmt::Display for CloudInitVmStateDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.vm_exists {
+            _ if self.is_in_sync() => return write!(f, "in sync"),
+            Some((false, _)) => return write!(f, "vm will be created"),
+            Some((_, false)) => return write!(f, "vm will be removed"),
+            _ => {}
+        }
+
+        let mut changes = Vec::with_capacity(2);
+        if let Some((image_version_from, image_version_to)) = self.image_version.as_ref() {
+            let image_version_from = image_version_from.as_deref().unwrap_or("<unknown>");
+            let image_version_to = image_version_to.as_deref().unwrap_or("<unknown>");
+            changes.push(format!(
+                "image `{image_version_from}` -> `{image_version_to}`"
+            ));
+        }
+        if self.cloud_init_hash.is_some() {
+            changes.push(String::from("user data will be modified"));
+        }
+
+        write!(f, "vm will be recreated: {}", changes.join(", "))
+    }
+}
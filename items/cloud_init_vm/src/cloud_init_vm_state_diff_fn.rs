@@ -0,0 +1,34 @@
+use crate::{CloudInitVmError, CloudInitVmState, CloudInitVmStateDiff};
+
+/// Cloud-init virtual machine state diff function.
+#[derive(Debug)]
+pub struct CloudInitVmStateDiffFn;
+
+impl CloudInitVmStateDiffFn {
+    pub async fn state_diff(
+        state_current: &CloudInitVmState,
+        state_goal: &CloudInitVmState,
+    ) -> Result<CloudInitVmStateDiff, CloudInitVmError> {
+        let vm_exists = (state_current.vm_exists != state_goal.vm_exists)
+            .then_some((state_current.vm_exists, state_goal.vm_exists));
+        let image_version = (state_current.image_version != state_goal.image_version).then(|| {
+            (
+                state_current.image_version.clone(),
+                state_goal.image_version.clone(),
+            )
+        });
+        let cloud_init_hash =
+            (state_current.cloud_init_hash != state_goal.cloud_init_hash).then(|| {
+                (
+                    state_current.cloud_init_hash.clone(),
+                    state_goal.cloud_init_hash.clone(),
+                )
+            });
+
+        Ok(CloudInitVmStateDiff {
+            vm_exists,
+            image_version,
+            cloud_init_hash,
+        })
+    }
+}
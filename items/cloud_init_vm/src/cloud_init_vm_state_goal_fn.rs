@@ -0,0 +1,49 @@
+use std::marker::PhantomData;
+
+use peace::{cfg::FnCtx, params::Params};
+
+use crate::{CloudInitVmData, CloudInitVmError, CloudInitVmParams, CloudInitVmState};
+
+/// Reads the goal state of the cloud-init virtual machine.
+///
+/// The goal is for the virtual machine to exist, created from the given image
+/// version, and initialized with the given user data.
+#[derive(Debug)]
+pub struct CloudInitVmStateGoalFn<Id>(PhantomData<Id>);
+
+impl<Id> CloudInitVmStateGoalFn<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn try_state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<CloudInitVmParams<Id> as Params>::Partial,
+        _data: CloudInitVmData<'_, Id>,
+    ) -> Result<Option<CloudInitVmState>, CloudInitVmError> {
+        match (params_partial.image_version(), params_partial.user_data()) {
+            (Some(image_version), Some(user_data)) => {
+                Ok(Some(Self::state_goal_internal(image_version, user_data)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    pub async fn state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params: &CloudInitVmParams<Id>,
+        _data: CloudInitVmData<'_, Id>,
+    ) -> Result<CloudInitVmState, CloudInitVmError> {
+        Ok(Self::state_goal_internal(
+            params.image_version(),
+            params.user_data(),
+        ))
+    }
+
+    fn state_goal_internal(image_version: &str, user_data: &str) -> CloudInitVmState {
+        CloudInitVmState::new(
+            true,
+            Some(image_version.to_string()),
+            Some(CloudInitVmState::cloud_init_hash(user_data)),
+        )
+    }
+}
@@ -0,0 +1,44 @@
+//! Manages a local cloud-init virtual machine for the peace framework.
+//!
+//! This item ensures a libvirt virtual machine exists, booted from a copy on
+//! write disk over a given base image, and initialized with the given
+//! cloud-init user data. The virtual machine is recreated when the image
+//! version or user data changes. Cleaning the item destroys the virtual machine
+//! and removes its disk.
+//!
+//! This lets flows that target servers be tested locally, without the cost of
+//! provisioning cloud instances.
+//!
+//! This item is only available on unix platforms, and requires `virsh` and
+//! `virt-install` to be on the `PATH`.
+
+#![cfg(unix)]
+
+pub use crate::{
+    cloud_init_vm_apply_fns::CloudInitVmApplyFns,
+    cloud_init_vm_data::CloudInitVmData,
+    cloud_init_vm_error::CloudInitVmError,
+    cloud_init_vm_item::CloudInitVmItem,
+    cloud_init_vm_params::{
+        CloudInitVmParams, CloudInitVmParamsFieldWise, CloudInitVmParamsPartial,
+    },
+    cloud_init_vm_state::CloudInitVmState,
+    cloud_init_vm_state_current_fn::CloudInitVmStateCurrentFn,
+    cloud_init_vm_state_diff::CloudInitVmStateDiff,
+    cloud_init_vm_state_diff_fn::CloudInitVmStateDiffFn,
+    cloud_init_vm_state_goal_fn::CloudInitVmStateGoalFn,
+};
+
+pub(crate) use crate::virt_cli::VirtCli;
+
+mod cloud_init_vm_apply_fns;
+mod cloud_init_vm_data;
+mod cloud_init_vm_error;
+mod cloud_init_vm_item;
+mod cloud_init_vm_params;
+mod cloud_init_vm_state;
+mod cloud_init_vm_state_current_fn;
+mod cloud_init_vm_state_diff;
+mod cloud_init_vm_state_diff_fn;
+mod cloud_init_vm_state_goal_fn;
+mod virt_cli;
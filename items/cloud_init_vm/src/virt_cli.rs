@@ -0,0 +1,62 @@
+use std::process::{Output, Stdio};
+
+use tokio::process::Command;
+
+use crate::CloudInitVmError;
+
+/// Runs libvirt commands, i.e. `virsh` and `virt-install`.
+#[derive(Debug)]
+pub(crate) struct VirtCli;
+
+impl VirtCli {
+    /// Runs `program` with the given arguments, and returns its trimmed
+    /// stdout, or `None` if it returns a non-zero exit code.
+    ///
+    /// This is used for `virsh dominfo`, which fails when the domain does not
+    /// exist.
+    pub async fn query(
+        program: &'static str,
+        args: &[&str],
+    ) -> Result<Option<String>, CloudInitVmError> {
+        let output = Self::output(program, args).await?;
+
+        if output.status.success() {
+            Ok(Some(
+                String::from_utf8_lossy(&output.stdout).trim().to_string(),
+            ))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Runs `program` with the given arguments, returning an error if it does
+    /// not succeed.
+    pub async fn exec(program: &'static str, args: &[&str]) -> Result<(), CloudInitVmError> {
+        let output = Self::output(program, args).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(CloudInitVmError::VirtFail {
+                program,
+                args: args.join(" "),
+                exit_code: output.status.code(),
+                stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            })
+        }
+    }
+
+    async fn output(program: &'static str, args: &[&str]) -> Result<Output, CloudInitVmError> {
+        Command::new(program)
+            .args(args)
+            .stdin(Stdio::null())
+            .kill_on_drop(true)
+            .output()
+            .await
+            .map_err(|error| CloudInitVmError::VirtExec {
+                program,
+                args: args.join(" "),
+                error,
+            })
+    }
+}
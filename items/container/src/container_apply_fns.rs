@@ -0,0 +1,136 @@
+use std::marker::PhantomData;
+
+#[cfg(feature = "output_progress")]
+use peace::cfg::progress::ProgressLimit;
+use peace::cfg::{ApplyCheck, FnCtx};
+
+use crate::{
+    ContainerData, ContainerError, ContainerParams, ContainerState, ContainerStateCurrentFn,
+    ContainerStateDiff, ContainerStatus, DockerCli,
+};
+
+/// ApplyFns for the container.
+#[derive(Debug)]
+pub struct ContainerApplyFns<Id>(PhantomData<Id>);
+
+impl<Id> ContainerApplyFns<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn apply_check(
+        _params: &ContainerParams<Id>,
+        _data: ContainerData<'_, Id>,
+        _state_current: &ContainerState,
+        _state_target: &ContainerState,
+        diff: &ContainerStateDiff,
+    ) -> Result<ApplyCheck, ContainerError> {
+        let apply_check = if diff.is_in_sync() {
+            ApplyCheck::ExecNotRequired
+        } else {
+            #[cfg(not(feature = "output_progress"))]
+            {
+                ApplyCheck::ExecRequired { reason: None }
+            }
+            #[cfg(feature = "output_progress")]
+            {
+                ApplyCheck::ExecRequired {
+                    progress_limit: ProgressLimit::Unknown,
+                    reason: None,
+                }
+            }
+        };
+
+        Ok(apply_check)
+    }
+
+    pub async fn apply_dry(
+        _fn_ctx: FnCtx<'_>,
+        _params: &ContainerParams<Id>,
+        _data: ContainerData<'_, Id>,
+        _state_current: &ContainerState,
+        state_target: &ContainerState,
+        _diff: &ContainerStateDiff,
+    ) -> Result<ContainerState, ContainerError> {
+        Ok(state_target.clone())
+    }
+
+    pub async fn apply(
+        _fn_ctx: FnCtx<'_>,
+        params: &ContainerParams<Id>,
+        _data: ContainerData<'_, Id>,
+        state_current: &ContainerState,
+        state_target: &ContainerState,
+        diff: &ContainerStateDiff,
+    ) -> Result<ContainerState, ContainerError> {
+        let container_name = params.container_name();
+        let container_exists = state_current.container_status != ContainerStatus::Absent;
+
+        if state_target.container_status == ContainerStatus::Absent {
+            if container_exists {
+                DockerCli::exec(&["stop", container_name]).await?;
+                DockerCli::exec(&["rm", container_name]).await?;
+            }
+        } else if diff.is_recreate_required() {
+            // Only pull when the image is not present locally, so that the
+            // container runs the image that the goal state was discovered with.
+            if state_target.image_digest.is_none() {
+                DockerCli::exec(&["pull", params.image()]).await?;
+            }
+            if container_exists {
+                DockerCli::exec(&["rm", "--force", container_name]).await?;
+            }
+            Self::container_run(params).await?;
+        } else {
+            match diff.container_status {
+                Some((_, ContainerStatus::Running)) => {
+                    DockerCli::exec(&["start", container_name]).await?;
+                }
+                Some((_, ContainerStatus::Stopped)) => {
+                    DockerCli::exec(&["stop", container_name]).await?;
+                }
+                Some((_, ContainerStatus::Absent)) | None => {}
+            }
+        }
+
+        ContainerStateCurrentFn::<Id>::state_current_internal(container_name).await
+    }
+
+    /// Creates and starts the container with `docker run`.
+    async fn container_run(params: &ContainerParams<Id>) -> Result<(), ContainerError> {
+        let config_hash_label = format!(
+            "{}={}",
+            ContainerState::CONFIG_HASH_LABEL,
+            ContainerState::config_hash(
+                params.image(),
+                params.env(),
+                params.ports(),
+                params.args()
+            )
+        );
+        let env_args = params
+            .env()
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>();
+
+        let mut args = vec![
+            "run",
+            "--detach",
+            "--name",
+            params.container_name(),
+            "--label",
+            &config_hash_label,
+        ];
+        env_args
+            .iter()
+            .for_each(|env_arg| args.extend(["--env", env_arg.as_str()]));
+        params
+            .ports()
+            .iter()
+            .for_each(|port| args.extend(["--publish", port.as_str()]));
+        args.push(params.image());
+        args.extend(params.args().iter().map(String::as_str));
+
+        DockerCli::exec(&args).await
+    }
+}
@@ -0,0 +1,33 @@
+use std::marker::PhantomData;
+
+use peace::{cfg::accessors::Stored, data::Data};
+
+use crate::ContainerState;
+
+/// Data used to manage a container.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different container
+///   parameters from each other.
+#[derive(Data, Debug)]
+pub struct ContainerData<'exec, Id>
+where
+    Id: Send + Sync + 'static,
+{
+    /// Stored state of this item's previous execution.
+    state_current_stored: Stored<'exec, ContainerState>,
+
+    /// Marker.
+    marker: PhantomData<Id>,
+}
+
+impl<'exec, Id> ContainerData<'exec, Id>
+where
+    Id: Send + Sync + 'static,
+{
+    /// Returns the stored state of this item's previous execution.
+    pub fn state_current_stored(&self) -> Option<&ContainerState> {
+        self.state_current_stored.get()
+    }
+}
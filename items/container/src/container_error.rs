@@ -0,0 +1,54 @@
+#[cfg(feature = "error_reporting")]
+use peace::miette;
+
+/// Error while managing an OCI container.
+#[cfg_attr(feature = "error_reporting", derive(peace::miette::Diagnostic))]
+#[derive(Debug, thiserror::Error)]
+pub enum ContainerError {
+    /// Failed to execute `docker`.
+    #[error("Failed to execute `docker {args}`.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_container::docker_exec),
+            help("Check that `docker` is on the `PATH`.")
+        )
+    )]
+    DockerExec {
+        /// Arguments passed to `docker`.
+        args: String,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// `docker` returned a non-zero exit code.
+    #[error("`docker {args}` failed with exit code {exit_code:?}:\n{stderr}")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_container::docker_fail),
+            help(
+                "Check that the docker daemon is running, and the current user has permission to use it."
+            )
+        )
+    )]
+    DockerFail {
+        /// Arguments passed to `docker`.
+        args: String,
+        /// Exit code of the process, `None` if it was terminated by a signal.
+        exit_code: Option<i32>,
+        /// Output written to stderr.
+        stderr: String,
+    },
+
+    // === Framework errors === //
+    /// A `peace` runtime error occurred.
+    #[error("A `peace` runtime error occurred.")]
+    PeaceRtError(
+        #[cfg_attr(feature = "error_reporting", diagnostic_source)]
+        #[source]
+        #[from]
+        peace::rt_model::Error,
+    ),
+}
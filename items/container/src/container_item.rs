@@ -0,0 +1,194 @@
+use std::marker::PhantomData;
+
+use peace::{
+    cfg::{async_trait, ApplyCheck, ApplyCtx, DiffSeverity, FnCtx, Item, ItemId},
+    params::Params,
+    resources::{resources::ts::Empty, Resources},
+};
+
+use crate::{
+    ContainerApplyFns, ContainerData, ContainerError, ContainerParams, ContainerState,
+    ContainerStateCurrentFn, ContainerStateDiff, ContainerStateDiffFn, ContainerStateGoalFn,
+    ContainerStatus,
+};
+
+/// Item for managing a container.
+///
+/// The goal state is for the container to be running the given image, with
+/// the given environment variables, published ports, and arguments. The
+/// container is recreated when its image or configuration drifts. Cleaning the
+/// item stops and removes the container.
+///
+/// The `Id` type parameter is needed for each container params to be a
+/// distinct type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different container
+///   parameters from each other.
+#[derive(Debug)]
+pub struct ContainerItem<Id> {
+    /// ID of the item to manage the container.
+    item_id: ItemId,
+    /// Marker for unique container parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> Clone for ContainerItem<Id> {
+    fn clone(&self) -> Self {
+        Self {
+            item_id: self.item_id.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<Id> ContainerItem<Id> {
+    /// Returns a new `ContainerItem`.
+    pub fn new(item_id: ItemId) -> Self {
+        Self {
+            item_id,
+            marker: PhantomData,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<Id> Item for ContainerItem<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    type Data<'exec> = ContainerData<'exec, Id>;
+    type Error = ContainerError;
+    type Params<'exec> = ContainerParams<Id>;
+    type State = ContainerState;
+    type StateDiff = ContainerStateDiff;
+
+    fn id(&self) -> &ItemId {
+        &self.item_id
+    }
+
+    async fn setup(&self, _resources: &mut Resources<Empty>) -> Result<(), ContainerError> {
+        Ok(())
+    }
+
+    async fn try_state_current(
+        fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        data: ContainerData<'_, Id>,
+    ) -> Result<Option<Self::State>, ContainerError> {
+        ContainerStateCurrentFn::try_state_current(fn_ctx, params_partial, data).await
+    }
+
+    async fn state_current(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: ContainerData<'_, Id>,
+    ) -> Result<Self::State, ContainerError> {
+        ContainerStateCurrentFn::state_current(fn_ctx, params, data).await
+    }
+
+    async fn try_state_goal(
+        fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        data: ContainerData<'_, Id>,
+    ) -> Result<Option<Self::State>, ContainerError> {
+        ContainerStateGoalFn::try_state_goal(fn_ctx, params_partial, data).await
+    }
+
+    async fn state_goal(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: ContainerData<'_, Id>,
+    ) -> Result<Self::State, ContainerError> {
+        ContainerStateGoalFn::state_goal(fn_ctx, params, data).await
+    }
+
+    async fn state_diff(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_goal: &Self::State,
+    ) -> Result<Self::StateDiff, ContainerError> {
+        ContainerStateDiffFn::state_diff(state_current, state_goal).await
+    }
+
+    fn diff_severity(
+        _state_current: &Self::State,
+        _state_goal: &Self::State,
+        state_diff: &Self::StateDiff,
+    ) -> DiffSeverity {
+        // Recreating an existing container discards its writable layer.
+        let container_replaced = state_diff.container_status.is_none()
+            && (state_diff.image.is_some() || state_diff.config_hash.is_some());
+        let container_stopped_or_removed = matches!(
+            state_diff.container_status,
+            Some((
+                ContainerStatus::Running,
+                ContainerStatus::Stopped | ContainerStatus::Absent
+            )) | Some((ContainerStatus::Stopped, ContainerStatus::Absent))
+        );
+
+        if state_diff.is_in_sync() {
+            DiffSeverity::InSync
+        } else if container_replaced || container_stopped_or_removed {
+            DiffSeverity::Destructive
+        } else {
+            DiffSeverity::Additive
+        }
+    }
+
+    fn error_help(error: &ContainerError) -> Vec<String> {
+        match error {
+            ContainerError::DockerExec { .. } => {
+                vec![String::from("Check that `docker` is on the `PATH`.")]
+            }
+            ContainerError::DockerFail { .. } => vec![String::from(
+                "Check that the docker daemon is running, and the current user has permission to use it.",
+            )],
+            ContainerError::PeaceRtError(_) => Vec::new(),
+        }
+    }
+
+    async fn state_clean(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+    ) -> Result<Self::State, ContainerError> {
+        Ok(ContainerState::clean())
+    }
+
+    async fn apply_check(
+        _apply_ctx: ApplyCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<ApplyCheck, Self::Error> {
+        ContainerApplyFns::<Id>::apply_check(params, data, state_current, state_target, diff).await
+    }
+
+    async fn apply_dry(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        ContainerApplyFns::<Id>::apply_dry(fn_ctx, params, data, state_current, state_target, diff)
+            .await
+    }
+
+    async fn apply(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        ContainerApplyFns::<Id>::apply(fn_ctx, params, data, state_current, state_target, diff)
+            .await
+    }
+}
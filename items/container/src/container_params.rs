@@ -0,0 +1,77 @@
+use std::{collections::BTreeMap, marker::PhantomData};
+
+use derivative::Derivative;
+use peace::params::Params;
+use serde::{Deserialize, Serialize};
+
+/// Container parameters.
+///
+/// The `Id` type parameter is needed for each container params to be a
+/// distinct type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different container
+///   parameters from each other.
+#[derive(Derivative, Params, PartialEq, Eq, Deserialize, Serialize)]
+#[derivative(Clone, Debug)]
+#[serde(bound = "")]
+pub struct ContainerParams<Id> {
+    /// Name of the container, e.g. `my_app`.
+    container_name: String,
+    /// Image reference to run, e.g. `nginx:1.25`.
+    image: String,
+    /// Environment variables to set in the container.
+    env: BTreeMap<String, String>,
+    /// Ports to publish, e.g. `8080:80`.
+    ports: Vec<String>,
+    /// Arguments passed to the container's entrypoint.
+    args: Vec<String>,
+    /// Marker for unique container parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> ContainerParams<Id> {
+    /// Returns new `ContainerParams`.
+    pub fn new(
+        container_name: String,
+        image: String,
+        env: BTreeMap<String, String>,
+        ports: Vec<String>,
+        args: Vec<String>,
+    ) -> Self {
+        Self {
+            container_name,
+            image,
+            env,
+            ports,
+            args,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the name of the container.
+    pub fn container_name(&self) -> &str {
+        &self.container_name
+    }
+
+    /// Returns the image reference to run.
+    pub fn image(&self) -> &str {
+        &self.image
+    }
+
+    /// Returns the environment variables to set in the container.
+    pub fn env(&self) -> &BTreeMap<String, String> {
+        &self.env
+    }
+
+    /// Returns the ports to publish.
+    pub fn ports(&self) -> &[String] {
+        &self.ports
+    }
+
+    /// Returns the arguments passed to the container's entrypoint.
+    pub fn args(&self) -> &[String] {
+        &self.args
+    }
+}
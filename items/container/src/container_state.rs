@@ -0,0 +1,158 @@
+use std::{collections::BTreeMap, fmt};
+
+use ring::digest;
+use serde::{Deserialize, Serialize};
+
+use crate::ContainerStatus;
+
+/// State of an OCI container.
+///
+/// This is the image the container runs, a hash of its configuration, and
+/// whether it is running.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct ContainerState {
+    /// Image reference of the container, `None` if the container does not
+    /// exist.
+    pub image: Option<String>,
+    /// ID of the image, `None` if it is not known.
+    ///
+    /// For the current state, this is the image the container was created
+    /// from. For the goal state, this is the local image for the reference,
+    /// which is `None` if it has not been pulled.
+    pub image_digest: Option<String>,
+    /// SHA-256 hash of the container's configuration, `None` if the container
+    /// does not exist.
+    pub config_hash: Option<String>,
+    /// Whether the container exists, and is running.
+    pub container_status: ContainerStatus,
+}
+
+impl ContainerState {
+    /// Label on the container that holds its configuration hash.
+    pub const CONFIG_HASH_LABEL: &'static str = "peace.config_hash";
+
+    /// Returns a new `ContainerState`.
+    pub fn new(
+        image: Option<String>,
+        image_digest: Option<String>,
+        config_hash: Option<String>,
+        container_status: ContainerStatus,
+    ) -> Self {
+        Self {
+            image,
+            image_digest,
+            config_hash,
+            container_status,
+        }
+    }
+
+    /// Returns the state when the container does not exist.
+    pub fn clean() -> Self {
+        Self {
+            image: None,
+            image_digest: None,
+            config_hash: None,
+            container_status: ContainerStatus::Absent,
+        }
+    }
+
+    /// Returns the format string passed to `docker inspect` to read the
+    /// container state.
+    pub fn inspect_format() -> String {
+        format!(
+            "{{{{.Config.Image}}}}|{{{{.Image}}}}|{{{{.State.Running}}}}|{{{{index .Config.Labels \"{}\"}}}}",
+            Self::CONFIG_HASH_LABEL
+        )
+    }
+
+    /// Returns the state from the output of `docker inspect` with
+    /// [`Self::inspect_format`], or [`Self::clean`] if the container does not
+    /// exist.
+    pub fn from_inspect_output(output: Option<&str>) -> Self {
+        let Some(output) = output else {
+            return Self::clean();
+        };
+        let mut fields = output.trim().splitn(4, '|');
+        let mut next_field = || {
+            fields
+                .next()
+                .map(str::trim)
+                .filter(|field| !field.is_empty() && *field != "<no value>")
+                .map(String::from)
+        };
+        let image = next_field();
+        let image_digest = next_field();
+        let container_status = match next_field().as_deref() {
+            Some("true") => ContainerStatus::Running,
+            _ => ContainerStatus::Stopped,
+        };
+        let config_hash = next_field();
+
+        Self {
+            image,
+            image_digest,
+            config_hash,
+            container_status,
+        }
+    }
+
+    /// Returns the hex encoded SHA-256 hash of the given container
+    /// configuration.
+    pub fn config_hash(
+        image: &str,
+        env: &BTreeMap<String, String>,
+        ports: &[String],
+        args: &[String],
+    ) -> String {
+        let mut context = digest::Context::new(&digest::SHA256);
+        let mut update = |section: &str, values: &mut dyn Iterator<Item = &str>| {
+            context.update(section.as_bytes());
+            context.update(b"\0");
+            values.for_each(|value| {
+                context.update(value.as_bytes());
+                context.update(b"\0");
+            });
+        };
+        update("image", &mut std::iter::once(image));
+        update(
+            "env",
+            &mut env
+                .iter()
+                .flat_map(|(key, value)| [key.as_str(), value.as_str()]),
+        );
+        update("ports", &mut ports.iter().map(String::as_str));
+        update("args", &mut args.iter().map(String::as_str));
+
+        context
+            .finish()
+            .as_ref()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+}
+
+impl fmt::Display for ContainerState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            image,
+            image_digest,
+            config_hash: _,
+            container_status,
+        } = self;
+
+        match (container_status, image) {
+            (ContainerStatus::Absent, _) => write!(f, "container does not exist"),
+            (_, image) => {
+                let image = image.as_deref().unwrap_or("<unknown>");
+                write!(f, "`{image}`")?;
+                if let Some(image_digest) = image_digest {
+                    let digest_hex = image_digest.strip_prefix("sha256:").unwrap_or(image_digest);
+                    let digest_short = digest_hex.get(..12).unwrap_or(digest_hex);
+                    write!(f, " (`{digest_short}`)")?;
+                }
+                write!(f, ", {container_status}")
+            }
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use std::marker::PhantomData;
+
+use peace::{cfg::FnCtx, params::Params};
+
+use crate::{ContainerData, ContainerError, ContainerParams, ContainerState, DockerCli};
+
+/// Reads the current state of the container.
+#[derive(Debug)]
+pub struct ContainerStateCurrentFn<Id>(PhantomData<Id>);
+
+impl<Id> ContainerStateCurrentFn<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn try_state_current(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<ContainerParams<Id> as Params>::Partial,
+        _data: ContainerData<'_, Id>,
+    ) -> Result<Option<ContainerState>, ContainerError> {
+        match params_partial.container_name() {
+            Some(container_name) => Self::state_current_internal(container_name).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn state_current(
+        _fn_ctx: FnCtx<'_>,
+        params: &ContainerParams<Id>,
+        _data: ContainerData<'_, Id>,
+    ) -> Result<ContainerState, ContainerError> {
+        Self::state_current_internal(params.container_name()).await
+    }
+
+    pub(crate) async fn state_current_internal(
+        container_name: &str,
+    ) -> Result<ContainerState, ContainerError> {
+        let inspect_format = ContainerState::inspect_format();
+        let output = DockerCli::query(&[
+            "inspect",
+            "--type",
+            "container",
+            "--format",
+            &inspect_format,
+            container_name,
+        ])
+        .await?;
+
+        Ok(ContainerState::from_inspect_output(output.as_deref()))
+    }
+}
@@ -0,0 +1,66 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ContainerStatus;
+
+/// Diff between the current and goal container state.
+///
+/// Each field is `Some((from, to))` when that part of the state differs.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct ContainerStateDiff {
+    /// Change in the image, either its reference or its ID.
+    pub image: Option<(Option<String>, Option<String>)>,
+    /// Change in the container's configuration hash.
+    pub config_hash: Option<(Option<String>, Option<String>)>,
+    /// Change in whether the container exists, and is running.
+    pub container_status: Option<(ContainerStatus, ContainerStatus)>,
+}
+
+impl ContainerStateDiff {
+    /// Returns whether the image, configuration, and container status are all
+    /// in sync.
+    pub fn is_in_sync(&self) -> bool {
+        self.image.is_none() && self.config_hash.is_none() && self.container_status.is_none()
+    }
+
+    /// Returns whether the container needs to be created, or recreated due to
+    /// image or configuration drift.
+    pub fn is_recreate_required(&self) -> bool {
+        match self.container_status {
+            Some((_, ContainerStatus::Absent)) => false,
+            Some((ContainerStatus::Absent, _)) => true,
+            _ => self.image.is_some() || self.config_hash.is_some(),
+        }
+    }
+}
+
+impl fmt::Display for ContainerStateDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.container_status {
+            _ if self.is_in_sync() => return write!(f, "in sync"),
+            Some((ContainerStatus::Absent, _)) => return write!(f, "container will be created"),
+            Some((_, ContainerStatus::Absent)) => return write!(f, "container will be removed"),
+            _ => {}
+        }
+
+        let mut changes = Vec::with_capacity(3);
+        if let Some((image_from, image_to)) = self.image.as_ref() {
+            let change = match (image_from, image_to) {
+                (Some(image_from), Some(image_to)) if image_from != image_to => {
+                    format!("image `{image_from}` -> `{image_to}`")
+                }
+                _ => String::from("image will be updated"),
+            };
+            changes.push(change);
+        }
+        if self.config_hash.is_some() {
+            changes.push(String::from("config will be modified"));
+        }
+        if let Some((from, to)) = self.container_status {
+            changes.push(format!("{from} -> {to}"));
+        }
+
+        write!(f, "{}", changes.join(", "))
+    }
+}
@@ -0,0 +1,35 @@
+use crate::{ContainerError, ContainerState, ContainerStateDiff};
+
+/// Container state diff function.
+#[derive(Debug)]
+pub struct ContainerStateDiffFn;
+
+impl ContainerStateDiffFn {
+    pub async fn state_diff(
+        state_current: &ContainerState,
+        state_goal: &ContainerState,
+    ) -> Result<ContainerStateDiff, ContainerError> {
+        // Image IDs are only compared when both are known, as the goal image
+        // may not have been pulled yet.
+        let image_digest_differs = matches!(
+            (&state_current.image_digest, &state_goal.image_digest),
+            (Some(digest_current), Some(digest_goal)) if digest_current != digest_goal
+        );
+        let image = (state_current.image != state_goal.image || image_digest_differs)
+            .then(|| (state_current.image.clone(), state_goal.image.clone()));
+        let config_hash = (state_current.config_hash != state_goal.config_hash).then(|| {
+            (
+                state_current.config_hash.clone(),
+                state_goal.config_hash.clone(),
+            )
+        });
+        let container_status = (state_current.container_status != state_goal.container_status)
+            .then_some((state_current.container_status, state_goal.container_status));
+
+        Ok(ContainerStateDiff {
+            image,
+            config_hash,
+            container_status,
+        })
+    }
+}
@@ -0,0 +1,66 @@
+use std::{collections::BTreeMap, marker::PhantomData};
+
+use peace::{cfg::FnCtx, params::Params};
+
+use crate::{
+    ContainerData, ContainerError, ContainerParams, ContainerState, ContainerStatus, DockerCli,
+};
+
+/// Reads the goal state of the container.
+///
+/// The goal is for the container to be running the local image for the given
+/// reference, with the given configuration.
+#[derive(Debug)]
+pub struct ContainerStateGoalFn<Id>(PhantomData<Id>);
+
+impl<Id> ContainerStateGoalFn<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn try_state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<ContainerParams<Id> as Params>::Partial,
+        _data: ContainerData<'_, Id>,
+    ) -> Result<Option<ContainerState>, ContainerError> {
+        match (
+            params_partial.image(),
+            params_partial.env(),
+            params_partial.ports(),
+            params_partial.args(),
+        ) {
+            (Some(image), Some(env), Some(ports), Some(args)) => {
+                Self::state_goal_internal(image, env, ports, args)
+                    .await
+                    .map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    pub async fn state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params: &ContainerParams<Id>,
+        _data: ContainerData<'_, Id>,
+    ) -> Result<ContainerState, ContainerError> {
+        Self::state_goal_internal(params.image(), params.env(), params.ports(), params.args()).await
+    }
+
+    async fn state_goal_internal(
+        image: &str,
+        env: &BTreeMap<String, String>,
+        ports: &[String],
+        args: &[String],
+    ) -> Result<ContainerState, ContainerError> {
+        // `None` when the image has not been pulled, in which case only the
+        // image reference is compared.
+        let image_digest =
+            DockerCli::query(&["image", "inspect", "--format", "{{.Id}}", image]).await?;
+
+        Ok(ContainerState::new(
+            Some(image.to_string()),
+            image_digest,
+            Some(ContainerState::config_hash(image, env, ports, args)),
+            ContainerStatus::Running,
+        ))
+    }
+}
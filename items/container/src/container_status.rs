@@ -0,0 +1,24 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Whether a container exists, and is running.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum ContainerStatus {
+    /// Container exists and is running.
+    Running,
+    /// Container exists but is not running.
+    Stopped,
+    /// Container does not exist.
+    Absent,
+}
+
+impl fmt::Display for ContainerStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Running => "running".fmt(f),
+            Self::Stopped => "stopped".fmt(f),
+            Self::Absent => "absent".fmt(f),
+        }
+    }
+}
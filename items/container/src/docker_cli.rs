@@ -0,0 +1,57 @@
+use std::process::{Output, Stdio};
+
+use tokio::process::Command;
+
+use crate::ContainerError;
+
+/// Runs `docker` commands.
+#[derive(Debug)]
+pub(crate) struct DockerCli;
+
+impl DockerCli {
+    /// Runs `docker` with the given arguments, and returns its trimmed stdout,
+    /// or `None` if it returns a non-zero exit code.
+    ///
+    /// This is used for `inspect` subcommands, which fail when the object does
+    /// not exist.
+    pub async fn query(args: &[&str]) -> Result<Option<String>, ContainerError> {
+        let output = Self::output(args).await?;
+
+        if output.status.success() {
+            Ok(Some(
+                String::from_utf8_lossy(&output.stdout).trim().to_string(),
+            ))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Runs `docker` with the given arguments, returning an error if it does
+    /// not succeed.
+    pub async fn exec(args: &[&str]) -> Result<(), ContainerError> {
+        let output = Self::output(args).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(ContainerError::DockerFail {
+                args: args.join(" "),
+                exit_code: output.status.code(),
+                stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            })
+        }
+    }
+
+    async fn output(args: &[&str]) -> Result<Output, ContainerError> {
+        Command::new("docker")
+            .args(args)
+            .stdin(Stdio::null())
+            .kill_on_drop(true)
+            .output()
+            .await
+            .map_err(|error| ContainerError::DockerExec {
+                args: args.join(" "),
+                error,
+            })
+    }
+}
@@ -0,0 +1,40 @@
+//! Manages an OCI container for the peace framework.
+//!
+//! This item ensures a container is running with a given image, environment
+//! variables, published ports, and arguments. The container is recreated when
+//! its image or configuration drifts. Cleaning the item stops and removes the
+//! container.
+//!
+//! This item is only available on unix platforms, and requires `docker` (or a
+//! compatible CLI) to be on the `PATH`.
+
+#![cfg(unix)]
+
+pub use crate::{
+    container_apply_fns::ContainerApplyFns,
+    container_data::ContainerData,
+    container_error::ContainerError,
+    container_item::ContainerItem,
+    container_params::{ContainerParams, ContainerParamsFieldWise, ContainerParamsPartial},
+    container_state::ContainerState,
+    container_state_current_fn::ContainerStateCurrentFn,
+    container_state_diff::ContainerStateDiff,
+    container_state_diff_fn::ContainerStateDiffFn,
+    container_state_goal_fn::ContainerStateGoalFn,
+    container_status::ContainerStatus,
+};
+
+pub(crate) use crate::docker_cli::DockerCli;
+
+mod container_apply_fns;
+mod container_data;
+mod container_error;
+mod container_item;
+mod container_params;
+mod container_state;
+mod container_state_current_fn;
+mod container_state_diff;
+mod container_state_diff_fn;
+mod container_state_goal_fn;
+mod container_status;
+mod docker_cli;
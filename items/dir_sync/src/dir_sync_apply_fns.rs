@@ -0,0 +1,141 @@
+use std::{marker::PhantomData, path::Path};
+
+#[cfg(feature = "output_progress")]
+use peace::cfg::progress::{ProgressLimit, ProgressMsgUpdate};
+use peace::cfg::{ApplyCheck, FnCtx};
+
+use crate::{DirSyncData, DirSyncError, DirSyncParams, DirSyncStateDiff, FileHashes};
+
+/// ApplyFns for the directory to sync.
+pub struct DirSyncApplyFns<Id>(PhantomData<Id>);
+
+impl<Id> DirSyncApplyFns<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    #[cfg_attr(not(feature = "output_progress"), allow(unused_variables))]
+    pub async fn apply_check(
+        _params: &DirSyncParams<Id>,
+        _data: DirSyncData<'_, Id>,
+        _state_current: &FileHashes,
+        _state_target: &FileHashes,
+        diff: &DirSyncStateDiff,
+    ) -> Result<ApplyCheck, DirSyncError> {
+        let apply_check = match diff {
+            DirSyncStateDiff::InSync => ApplyCheck::ExecNotRequired,
+            DirSyncStateDiff::OutOfSync {
+                added,
+                modified,
+                removed,
+            } => {
+                #[cfg(not(feature = "output_progress"))]
+                {
+                    ApplyCheck::ExecRequired { reason: None }
+                }
+                #[cfg(feature = "output_progress")]
+                {
+                    let file_count = added.len() + modified.len() + removed.len();
+                    let progress_limit = file_count
+                        .try_into()
+                        .map(ProgressLimit::Steps)
+                        .unwrap_or(ProgressLimit::Unknown);
+                    ApplyCheck::ExecRequired {
+                        progress_limit,
+                        reason: None,
+                    }
+                }
+            }
+        };
+
+        Ok(apply_check)
+    }
+
+    pub async fn apply_dry(
+        _fn_ctx: FnCtx<'_>,
+        _params: &DirSyncParams<Id>,
+        _data: DirSyncData<'_, Id>,
+        _state_current: &FileHashes,
+        state_target: &FileHashes,
+        _diff: &DirSyncStateDiff,
+    ) -> Result<FileHashes, DirSyncError> {
+        Ok(state_target.clone())
+    }
+
+    pub async fn apply(
+        #[cfg(not(feature = "output_progress"))] _fn_ctx: FnCtx<'_>,
+        #[cfg(feature = "output_progress")] fn_ctx: FnCtx<'_>,
+        params: &DirSyncParams<Id>,
+        _data: DirSyncData<'_, Id>,
+        _state_current: &FileHashes,
+        state_target: &FileHashes,
+        diff: &DirSyncStateDiff,
+    ) -> Result<FileHashes, DirSyncError> {
+        let DirSyncStateDiff::OutOfSync {
+            added,
+            modified,
+            removed,
+        } = diff
+        else {
+            return Ok(state_target.clone());
+        };
+
+        let src = params.src();
+        let dest = params.dest();
+
+        for file_hash in added.iter().chain(modified.iter()) {
+            let entry_path = file_hash.path();
+            Self::file_copy(src, dest, entry_path).await?;
+
+            #[cfg(feature = "output_progress")]
+            fn_ctx.progress_sender.inc(
+                1,
+                ProgressMsgUpdate::Set(format!("copied {}", entry_path.display())),
+            );
+        }
+
+        for file_hash in removed.iter() {
+            let entry_path = file_hash.path();
+            tokio::fs::remove_file(dest.join(entry_path))
+                .await
+                .map_err(|error| DirSyncError::DestFileRemove {
+                    dest: dest.to_path_buf(),
+                    entry_path: entry_path.to_path_buf(),
+                    error,
+                })?;
+
+            #[cfg(feature = "output_progress")]
+            fn_ctx.progress_sender.inc(
+                1,
+                ProgressMsgUpdate::Set(format!("removed {}", entry_path.display())),
+            );
+        }
+
+        Ok(state_target.clone())
+    }
+
+    /// Copies the file at `entry_path` from `src` to `dest`, creating parent
+    /// directories as necessary.
+    async fn file_copy(src: &Path, dest: &Path, entry_path: &Path) -> Result<(), DirSyncError> {
+        let src_path = src.join(entry_path);
+        let dest_path = dest.join(entry_path);
+
+        if let Some(dest_parent) = dest_path.parent() {
+            tokio::fs::create_dir_all(dest_parent)
+                .await
+                .map_err(|error| DirSyncError::DestDirCreate {
+                    dir: dest_parent.to_path_buf(),
+                    error,
+                })?;
+        }
+
+        tokio::fs::copy(&src_path, &dest_path)
+            .await
+            .map_err(|error| DirSyncError::FileCopy {
+                src_path,
+                dest_path,
+                error,
+            })?;
+
+        Ok(())
+    }
+}
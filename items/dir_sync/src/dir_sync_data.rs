@@ -0,0 +1,33 @@
+use std::marker::PhantomData;
+
+use peace::{
+    data::{accessors::R, Data},
+    rt_model::Storage,
+};
+
+/// Data used to sync a directory.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different directory sync
+///   parameters from each other.
+#[derive(Data, Debug)]
+pub struct DirSyncData<'exec, Id>
+where
+    Id: Send + Sync + 'static,
+{
+    /// Storage to interact with to read and write files.
+    storage: R<'exec, Storage>,
+
+    /// Marker.
+    marker: PhantomData<Id>,
+}
+
+impl<'exec, Id> DirSyncData<'exec, Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub fn storage(&self) -> &Storage {
+        &self.storage
+    }
+}
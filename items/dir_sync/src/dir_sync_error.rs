@@ -0,0 +1,177 @@
+use std::path::PathBuf;
+
+#[cfg(feature = "error_reporting")]
+use peace::miette;
+
+/// Error while managing directory syncing.
+#[cfg_attr(feature = "error_reporting", derive(peace::miette::Diagnostic))]
+#[derive(Debug, thiserror::Error)]
+pub enum DirSyncError {
+    /// Include or exclude glob is invalid.
+    #[error(r#"Include or exclude glob is invalid: `{glob}`"#)]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_dir_sync::glob_invalid)),
+        help("Make sure the glob is a valid glob pattern.")
+    )]
+    GlobInvalid {
+        /// The glob that failed to parse.
+        glob: String,
+        /// Underlying error.
+        error: globset::Error,
+    },
+
+    /// Include or exclude globs failed to be compiled.
+    #[error("Include or exclude globs failed to be compiled.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_dir_sync::glob_set_build))
+    )]
+    GlobSetBuild {
+        /// Underlying error.
+        error: globset::Error,
+    },
+
+    /// Source directory to sync doesn't exist.
+    #[error(
+        r#"Source directory to sync doesn't exist: `{}`"#,
+        src.display()
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_dir_sync::src_dir_not_exists)),
+        help("Make sure there is an item that creates the source directory.")
+    )]
+    SrcDirNotExists {
+        /// Path to the source directory.
+        src: PathBuf,
+    },
+
+    /// Failed to read directory.
+    #[error(
+        r#"Failed to read directory: `{}`"#,
+        dir.display()
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_dir_sync::dir_read))
+    )]
+    DirRead {
+        /// Path to the directory.
+        dir: PathBuf,
+        /// Underlying error.
+        error: std::io::Error,
+    },
+
+    /// Failed to read directory entry.
+    #[error(
+        r#"Failed to read directory entry in `{}`"#,
+        dir.display()
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_dir_sync::dir_entry_read))
+    )]
+    DirEntryRead {
+        /// Path to the directory.
+        dir: PathBuf,
+        /// Underlying error.
+        error: std::io::Error,
+    },
+
+    /// Failed to read directory entry file type.
+    #[error(
+        r#"Failed to read file type for `{}`"#,
+        entry_path.display()
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_dir_sync::dir_entry_file_type_read))
+    )]
+    DirEntryFileTypeRead {
+        /// Path to the directory entry.
+        entry_path: PathBuf,
+        /// Underlying error.
+        error: std::io::Error,
+    },
+
+    /// Failed to read file to compute its hash.
+    #[error(
+        r#"Failed to read file: `{}`"#,
+        path.display()
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_dir_sync::file_read))
+    )]
+    FileRead {
+        /// Path to the file.
+        path: PathBuf,
+        /// Underlying error.
+        error: std::io::Error,
+    },
+
+    /// Failed to create directory within the destination directory.
+    #[error(
+        r#"Failed to create directory: `{}`"#,
+        dir.display()
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_dir_sync::dest_dir_create))
+    )]
+    DestDirCreate {
+        /// Path to the directory to create.
+        dir: PathBuf,
+        /// Underlying error.
+        error: std::io::Error,
+    },
+
+    /// Failed to copy file to the destination directory.
+    #[error(
+        r#"Failed to copy `{}` to `{}`"#,
+        src_path.display(),
+        dest_path.display()
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_dir_sync::file_copy))
+    )]
+    FileCopy {
+        /// Path to the file in the source directory.
+        src_path: PathBuf,
+        /// Path to the file in the destination directory.
+        dest_path: PathBuf,
+        /// Underlying error.
+        error: std::io::Error,
+    },
+
+    /// Failed to remove file in destination directory.
+    #[error(
+        r#"Failed to remove file `{}` in `{}`"#,
+        entry_path.display(),
+        dest.display()
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_dir_sync::dest_file_remove))
+    )]
+    DestFileRemove {
+        /// Path to the destination directory.
+        dest: PathBuf,
+        /// Path to the file to remove, relative to the destination directory.
+        entry_path: PathBuf,
+        /// Underlying error.
+        error: std::io::Error,
+    },
+
+    // === Framework errors === //
+    /// A `peace` runtime error occurred.
+    #[error("A `peace` runtime error occurred.")]
+    PeaceRtError(
+        #[cfg_attr(feature = "error_reporting", diagnostic_source)]
+        #[source]
+        #[from]
+        peace::rt_model::Error,
+    ),
+}
@@ -0,0 +1,167 @@
+use std::marker::PhantomData;
+
+use peace::{
+    cfg::{async_trait, ApplyCheck, ApplyCtx, DiffSeverity, FnCtx, Item, ItemId},
+    params::Params,
+    resources::{resources::ts::Empty, Resources},
+};
+
+use crate::{
+    DirSyncApplyFns, DirSyncData, DirSyncError, DirSyncParams, DirSyncStateCurrentFn,
+    DirSyncStateDiff, DirSyncStateDiffFn, DirSyncStateGoalFn, FileHashes,
+};
+
+/// Item for syncing a directory to another directory.
+///
+/// The destination directory is made to contain the same files as the source
+/// directory, for files that match the `include` and `exclude` globs.
+///
+/// The `Id` type parameter is needed for each directory sync params to be a
+/// distinct type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different directory sync
+///   parameters from each other.
+#[derive(Debug)]
+pub struct DirSyncItem<Id> {
+    /// ID of the item to sync the directory.
+    item_id: ItemId,
+    /// Marker for unique directory sync parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> Clone for DirSyncItem<Id> {
+    fn clone(&self) -> Self {
+        Self {
+            item_id: self.item_id.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<Id> DirSyncItem<Id> {
+    /// Returns a new `DirSyncItem`.
+    pub fn new(item_id: ItemId) -> Self {
+        Self {
+            item_id,
+            marker: PhantomData,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<Id> Item for DirSyncItem<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    type Data<'exec> = DirSyncData<'exec, Id>;
+    type Error = DirSyncError;
+    type Params<'exec> = DirSyncParams<Id>;
+    type State = FileHashes;
+    type StateDiff = DirSyncStateDiff;
+
+    fn id(&self) -> &ItemId {
+        &self.item_id
+    }
+
+    async fn setup(&self, _resources: &mut Resources<Empty>) -> Result<(), DirSyncError> {
+        Ok(())
+    }
+
+    async fn try_state_current(
+        fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        data: DirSyncData<'_, Id>,
+    ) -> Result<Option<Self::State>, DirSyncError> {
+        DirSyncStateCurrentFn::try_state_current(fn_ctx, params_partial, data).await
+    }
+
+    async fn state_current(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: DirSyncData<'_, Id>,
+    ) -> Result<Self::State, DirSyncError> {
+        DirSyncStateCurrentFn::state_current(fn_ctx, params, data).await
+    }
+
+    async fn try_state_goal(
+        fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        data: DirSyncData<'_, Id>,
+    ) -> Result<Option<Self::State>, DirSyncError> {
+        DirSyncStateGoalFn::try_state_goal(fn_ctx, params_partial, data).await
+    }
+
+    async fn state_goal(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: DirSyncData<'_, Id>,
+    ) -> Result<Self::State, DirSyncError> {
+        DirSyncStateGoalFn::state_goal(fn_ctx, params, data).await
+    }
+
+    async fn state_diff(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_goal: &Self::State,
+    ) -> Result<Self::StateDiff, DirSyncError> {
+        DirSyncStateDiffFn::state_diff(state_current, state_goal).await
+    }
+
+    fn diff_severity(
+        _state_current: &Self::State,
+        _state_goal: &Self::State,
+        state_diff: &Self::StateDiff,
+    ) -> DiffSeverity {
+        match state_diff {
+            DirSyncStateDiff::InSync => DiffSeverity::InSync,
+            DirSyncStateDiff::OutOfSync { removed, .. } if !removed.is_empty() => {
+                DiffSeverity::Destructive
+            }
+            DirSyncStateDiff::OutOfSync { .. } => DiffSeverity::Additive,
+        }
+    }
+
+    async fn state_clean(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+    ) -> Result<Self::State, DirSyncError> {
+        Ok(FileHashes::default())
+    }
+
+    async fn apply_check(
+        _apply_ctx: ApplyCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<ApplyCheck, Self::Error> {
+        DirSyncApplyFns::<Id>::apply_check(params, data, state_current, state_target, diff).await
+    }
+
+    async fn apply_dry(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        DirSyncApplyFns::<Id>::apply_dry(fn_ctx, params, data, state_current, state_target, diff)
+            .await
+    }
+
+    async fn apply(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        DirSyncApplyFns::<Id>::apply(fn_ctx, params, data, state_current, state_target, diff).await
+    }
+}
@@ -0,0 +1,82 @@
+use std::{
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+use derivative::Derivative;
+use peace::params::Params;
+use serde::{Deserialize, Serialize};
+
+/// Directory sync parameters.
+///
+/// The `Id` type parameter is needed for each directory sync params to be a
+/// distinct type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different directory sync
+///   parameters from each other.
+#[derive(Derivative, Params, PartialEq, Eq, Deserialize, Serialize)]
+#[derivative(Clone, Debug)]
+#[serde(bound = "")]
+pub struct DirSyncParams<Id> {
+    /// Directory to copy files from.
+    src: PathBuf,
+    /// Directory to copy files to.
+    dest: PathBuf,
+    /// Globs of files to sync, relative to the source directory.
+    ///
+    /// If this is empty, all files are synced.
+    include: Vec<String>,
+    /// Globs of files not to sync, relative to the source directory.
+    ///
+    /// These take precedence over `include`.
+    exclude: Vec<String>,
+    /// Marker for unique directory sync parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> DirSyncParams<Id> {
+    /// Returns new `DirSyncParams` that syncs all files from `src` to `dest`.
+    pub fn new(src: PathBuf, dest: PathBuf) -> Self {
+        Self {
+            src,
+            dest,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Sets the globs of files to sync, relative to the source directory.
+    pub fn with_include(mut self, include: Vec<String>) -> Self {
+        self.include = include;
+        self
+    }
+
+    /// Sets the globs of files not to sync, relative to the source directory.
+    pub fn with_exclude(mut self, exclude: Vec<String>) -> Self {
+        self.exclude = exclude;
+        self
+    }
+
+    /// Returns the directory to copy files from.
+    pub fn src(&self) -> &Path {
+        &self.src
+    }
+
+    /// Returns the directory to copy files to.
+    pub fn dest(&self) -> &Path {
+        &self.dest
+    }
+
+    /// Returns the globs of files to sync.
+    pub fn include(&self) -> &[String] {
+        &self.include
+    }
+
+    /// Returns the globs of files not to sync.
+    pub fn exclude(&self) -> &[String] {
+        &self.exclude
+    }
+}
@@ -0,0 +1,45 @@
+use std::marker::PhantomData;
+
+use peace::{cfg::FnCtx, params::Params};
+
+use crate::{DirSyncData, DirSyncError, DirSyncParams, DirWalk, FileFilter, FileHashes};
+
+/// Reads the current state of the destination directory.
+#[derive(Debug)]
+pub struct DirSyncStateCurrentFn<Id>(PhantomData<Id>);
+
+impl<Id> DirSyncStateCurrentFn<Id>
+where
+    Id: Send + Sync,
+{
+    pub async fn try_state_current(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<DirSyncParams<Id> as Params>::Partial,
+        _data: DirSyncData<'_, Id>,
+    ) -> Result<Option<FileHashes>, DirSyncError> {
+        match (
+            params_partial.dest(),
+            params_partial.include(),
+            params_partial.exclude(),
+        ) {
+            (Some(dest), Some(include), Some(exclude)) => {
+                let file_filter = FileFilter::new(include, exclude)?;
+                let file_hashes = DirWalk::file_hashes(dest, &file_filter).await?;
+
+                Ok(Some(FileHashes::from(file_hashes)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    pub async fn state_current(
+        _fn_ctx: FnCtx<'_>,
+        params: &DirSyncParams<Id>,
+        _data: DirSyncData<'_, Id>,
+    ) -> Result<FileHashes, DirSyncError> {
+        let file_filter = FileFilter::new(params.include(), params.exclude())?;
+        let file_hashes = DirWalk::file_hashes(params.dest(), &file_filter).await?;
+
+        Ok(FileHashes::from(file_hashes))
+    }
+}
@@ -0,0 +1,46 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::FileHashes;
+
+/// Diff between the source and destination directories.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum DirSyncStateDiff {
+    /// Files in the destination directory are in sync with the source
+    /// directory.
+    InSync,
+    /// Files in the destination directory are not in sync with the source
+    /// directory.
+    OutOfSync {
+        /// Files that exist in the source directory but not the destination
+        /// directory.
+        added: FileHashes,
+        /// Files that exist in both directories, but whose contents differ.
+        modified: FileHashes,
+        /// Files that exist in the destination directory, but not in the
+        /// source directory.
+        removed: FileHashes,
+    },
+}
+
+impl fmt::Display for DirSyncStateDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InSync => write!(f, "files synced and up to date"),
+            Self::OutOfSync {
+                added,
+                modified,
+                removed,
+            } => {
+                let added = added.len();
+                let modified = modified.len();
+                let removed = removed.len();
+                write!(
+                    f,
+                    "directory out of sync: {added} files added, {modified} modified, {removed} removed"
+                )
+            }
+        }
+    }
+}
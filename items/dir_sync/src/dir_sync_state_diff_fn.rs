@@ -0,0 +1,75 @@
+use std::cmp::Ordering;
+
+use crate::{DirSyncError, DirSyncStateDiff, FileHash, FileHashes};
+
+/// Directory sync status diff function.
+#[derive(Debug)]
+pub struct DirSyncStateDiffFn;
+
+impl DirSyncStateDiffFn {
+    pub async fn state_diff(
+        file_hashes_current: &FileHashes,
+        file_hashes_goal: &FileHashes,
+    ) -> Result<DirSyncStateDiff, DirSyncError> {
+        let mut current_hash_iter = file_hashes_current.iter();
+        let mut goal_hash_iter = file_hashes_goal.iter();
+
+        let mut added = Vec::<FileHash>::new();
+        let mut modified = Vec::<FileHash>::new();
+        let mut removed = Vec::<FileHash>::new();
+
+        let mut current_hash_opt = current_hash_iter.next();
+        let mut goal_hash_opt = goal_hash_iter.next();
+        loop {
+            match (current_hash_opt, goal_hash_opt) {
+                (Some(current_hash), Some(goal_hash)) => {
+                    match current_hash.path().cmp(goal_hash.path()) {
+                        Ordering::Less => {
+                            // destination file name is smaller than source file name,
+                            // meaning the destination file is not in the source.
+                            removed.push(current_hash.clone());
+
+                            current_hash_opt = current_hash_iter.next();
+                        }
+                        Ordering::Equal => {
+                            if current_hash.hash() != goal_hash.hash() {
+                                modified.push(goal_hash.clone());
+                            }
+
+                            current_hash_opt = current_hash_iter.next();
+                            goal_hash_opt = goal_hash_iter.next();
+                        }
+                        Ordering::Greater => {
+                            // destination file name is greater than source file name,
+                            // meaning the source file is newly added.
+                            added.push(goal_hash.clone());
+
+                            goal_hash_opt = goal_hash_iter.next();
+                        }
+                    }
+                }
+                (Some(current_hash), None) => {
+                    removed.push(current_hash.clone());
+                    removed.extend(current_hash_iter.cloned());
+                    break;
+                }
+                (None, Some(goal_hash)) => {
+                    added.push(goal_hash.clone());
+                    added.extend(goal_hash_iter.cloned());
+                    break;
+                }
+                (None, None) => break,
+            }
+        }
+
+        if added.is_empty() && modified.is_empty() && removed.is_empty() {
+            Ok(DirSyncStateDiff::InSync)
+        } else {
+            Ok(DirSyncStateDiff::OutOfSync {
+                added: FileHashes::from(added),
+                modified: FileHashes::from(modified),
+                removed: FileHashes::from(removed),
+            })
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use std::marker::PhantomData;
+
+use peace::{cfg::FnCtx, params::Params};
+
+use crate::{DirSyncData, DirSyncError, DirSyncParams, DirWalk, FileFilter, FileHashes};
+
+/// Reads the goal state of the destination directory.
+///
+/// This is the content hashes of the files in the source directory.
+#[derive(Debug)]
+pub struct DirSyncStateGoalFn<Id>(PhantomData<Id>);
+
+impl<Id> DirSyncStateGoalFn<Id>
+where
+    Id: Send + Sync,
+{
+    pub async fn try_state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<DirSyncParams<Id> as Params>::Partial,
+        _data: DirSyncData<'_, Id>,
+    ) -> Result<Option<FileHashes>, DirSyncError> {
+        match (
+            params_partial.src(),
+            params_partial.include(),
+            params_partial.exclude(),
+        ) {
+            (Some(src), Some(include), Some(exclude)) if src.exists() => {
+                let file_filter = FileFilter::new(include, exclude)?;
+                let file_hashes = DirWalk::file_hashes(src, &file_filter).await?;
+
+                Ok(Some(FileHashes::from(file_hashes)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    pub async fn state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params: &DirSyncParams<Id>,
+        _data: DirSyncData<'_, Id>,
+    ) -> Result<FileHashes, DirSyncError> {
+        let src = params.src();
+        if !src.exists() {
+            return Err(DirSyncError::SrcDirNotExists {
+                src: src.to_path_buf(),
+            });
+        }
+
+        let file_filter = FileFilter::new(params.include(), params.exclude())?;
+        let file_hashes = DirWalk::file_hashes(src, &file_filter).await?;
+
+        Ok(FileHashes::from(file_hashes))
+    }
+}
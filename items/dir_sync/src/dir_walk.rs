@@ -0,0 +1,80 @@
+use std::{
+    collections::VecDeque,
+    path::{Path, PathBuf},
+};
+
+use sha2::{Digest, Sha256};
+
+use crate::{DirSyncError, FileFilter, FileHash};
+
+/// Walks a directory, and computes the content hash of each file.
+#[derive(Debug)]
+pub(crate) struct DirWalk;
+
+impl DirWalk {
+    /// Returns the content hashes of the files in `dir` that match the filter.
+    ///
+    /// Paths in the returned `FileHash`es are relative to `dir`. If `dir` does
+    /// not exist, no hashes are returned.
+    pub(crate) async fn file_hashes(
+        dir: &Path,
+        file_filter: &FileFilter,
+    ) -> Result<Vec<FileHash>, DirSyncError> {
+        let mut file_hashes = Vec::new();
+        if !dir.exists() {
+            return Ok(file_hashes);
+        }
+
+        let mut dirs_relative = VecDeque::from([PathBuf::new()]);
+        while let Some(dir_relative) = dirs_relative.pop_front() {
+            let dir_path = dir.join(&dir_relative);
+            let mut read_dir =
+                tokio::fs::read_dir(&dir_path)
+                    .await
+                    .map_err(|error| DirSyncError::DirRead {
+                        dir: dir_path.clone(),
+                        error,
+                    })?;
+
+            while let Some(dir_entry) =
+                read_dir
+                    .next_entry()
+                    .await
+                    .map_err(|error| DirSyncError::DirEntryRead {
+                        dir: dir_path.clone(),
+                        error,
+                    })?
+            {
+                let entry_path = dir_entry.path();
+                let entry_path_relative = dir_relative.join(dir_entry.file_name());
+                let file_type = dir_entry.file_type().await.map_err(|error| {
+                    DirSyncError::DirEntryFileTypeRead {
+                        entry_path: entry_path.clone(),
+                        error,
+                    }
+                })?;
+
+                if file_type.is_dir() {
+                    dirs_relative.push_back(entry_path_relative);
+                } else if file_filter.is_match(&entry_path_relative) {
+                    let hash = Self::file_hash(&entry_path).await?;
+                    file_hashes.push(FileHash::new(entry_path_relative, hash));
+                }
+            }
+        }
+
+        Ok(file_hashes)
+    }
+
+    /// Returns the hex encoded SHA-256 hash of the file's contents.
+    async fn file_hash(path: &Path) -> Result<String, DirSyncError> {
+        let contents = tokio::fs::read(path)
+            .await
+            .map_err(|error| DirSyncError::FileRead {
+                path: path.to_path_buf(),
+                error,
+            })?;
+
+        Ok(format!("{:x}", Sha256::digest(contents)))
+    }
+}
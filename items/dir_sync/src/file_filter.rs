@@ -0,0 +1,58 @@
+use std::path::Path;
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+
+use crate::DirSyncError;
+
+/// Determines which files are synced, based on include and exclude globs.
+#[derive(Clone, Debug)]
+pub(crate) struct FileFilter {
+    /// Files to sync, `None` if all files are synced.
+    include: Option<GlobSet>,
+    /// Files not to sync.
+    exclude: GlobSet,
+}
+
+impl FileFilter {
+    /// Returns a new `FileFilter` from the given include and exclude globs.
+    pub(crate) fn new(include: &[String], exclude: &[String]) -> Result<Self, DirSyncError> {
+        let include = if include.is_empty() {
+            None
+        } else {
+            Some(Self::glob_set_build(include)?)
+        };
+        let exclude = Self::glob_set_build(exclude)?;
+
+        Ok(Self { include, exclude })
+    }
+
+    /// Returns whether the file at the given path should be synced.
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Path of the file, relative to the synced directory.
+    pub(crate) fn is_match(&self, path: &Path) -> bool {
+        let included = self
+            .include
+            .as_ref()
+            .map(|include| include.is_match(path))
+            .unwrap_or(true);
+
+        included && !self.exclude.is_match(path)
+    }
+
+    fn glob_set_build(globs: &[String]) -> Result<GlobSet, DirSyncError> {
+        globs
+            .iter()
+            .try_fold(GlobSetBuilder::new(), |mut glob_set_builder, glob| {
+                let glob = Glob::new(glob).map_err(|error| DirSyncError::GlobInvalid {
+                    glob: glob.clone(),
+                    error,
+                })?;
+                glob_set_builder.add(glob);
+                Result::<_, DirSyncError>::Ok(glob_set_builder)
+            })?
+            .build()
+            .map_err(|error| DirSyncError::GlobSetBuild { error })
+    }
+}
@@ -0,0 +1,30 @@
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// Content hash of a file in a synced directory.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct FileHash {
+    /// Path to the file, relative to either the source or destination
+    /// directory.
+    path: PathBuf,
+    /// Hex encoded SHA-256 hash of the file's contents.
+    hash: String,
+}
+
+impl FileHash {
+    /// Returns a new `FileHash`.
+    pub fn new(path: PathBuf, hash: String) -> Self {
+        Self { path, hash }
+    }
+
+    /// Returns the path of the file, relative to the synced directory.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the hex encoded SHA-256 hash of the file's contents.
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
+}
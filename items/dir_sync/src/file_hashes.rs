@@ -0,0 +1,44 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::FileHash;
+
+/// Content hashes of files in a synced directory.
+///
+/// The `FileHash`es are sorted by their path.
+///
+/// This should be constructed using the `From<Vec<FileHash>>` function.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FileHashes(Vec<FileHash>);
+
+impl FileHashes {
+    /// Returns the inner `Vec<FileHash>`.
+    pub fn into_inner(self) -> Vec<FileHash> {
+        self.0
+    }
+}
+
+impl std::ops::Deref for FileHashes {
+    type Target = Vec<FileHash>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl fmt::Display for FileHashes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let len = self.len();
+        let s = if len == 1 { "" } else { "s" };
+        write!(f, "{len} file{s}")
+    }
+}
+
+impl From<Vec<FileHash>> for FileHashes {
+    fn from(mut file_hashes: Vec<FileHash>) -> Self {
+        file_hashes.sort_by(|file_hash_a, file_hash_b| file_hash_a.path().cmp(file_hash_b.path()));
+
+        Self(file_hashes)
+    }
+}
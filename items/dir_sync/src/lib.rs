@@ -0,0 +1,44 @@
+//! Manages syncing a directory to another directory for the peace framework.
+//!
+//! The destination directory is made to mirror the source directory -- files
+//! that are in the source directory are copied when they are missing or
+//! differ, and files that are only in the destination directory are removed.
+//!
+//! Which files are synced can be limited with `include` and `exclude` globs.
+//! Files that do not match the globs are left untouched in the destination
+//! directory.
+//!
+//! Cleaning this item removes the synced files from the destination
+//! directory.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+pub use crate::{
+    dir_sync_apply_fns::DirSyncApplyFns,
+    dir_sync_data::DirSyncData,
+    dir_sync_error::DirSyncError,
+    dir_sync_item::DirSyncItem,
+    dir_sync_params::{DirSyncParams, DirSyncParamsFieldWise, DirSyncParamsPartial},
+    dir_sync_state_current_fn::DirSyncStateCurrentFn,
+    dir_sync_state_diff::DirSyncStateDiff,
+    dir_sync_state_diff_fn::DirSyncStateDiffFn,
+    dir_sync_state_goal_fn::DirSyncStateGoalFn,
+    file_hash::FileHash,
+    file_hashes::FileHashes,
+};
+
+pub(crate) use crate::{dir_walk::DirWalk, file_filter::FileFilter};
+
+mod dir_sync_apply_fns;
+mod dir_sync_data;
+mod dir_sync_error;
+mod dir_sync_item;
+mod dir_sync_params;
+mod dir_sync_state_current_fn;
+mod dir_sync_state_diff;
+mod dir_sync_state_diff_fn;
+mod dir_sync_state_goal_fn;
+mod dir_walk;
+mod file_filter;
+mod file_hash;
+mod file_hashes;
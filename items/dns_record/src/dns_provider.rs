@@ -0,0 +1,62 @@
+use std::fmt::Debug;
+
+use peace::cfg::async_trait;
+
+use crate::{DnsRecordSet, DnsRecordType};
+
+/// Error returned by a [`DnsProvider`].
+pub type DnsProviderError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// Reads and changes record sets in a DNS service.
+///
+/// Implement this to manage record sets with a DNS service other than
+/// [`Route53DnsProvider`].
+///
+/// Record set names are passed without a trailing `.`, e.g.
+/// `www.example.com`.
+///
+/// [`Route53DnsProvider`]: crate::Route53DnsProvider
+#[async_trait]
+pub trait DnsProvider: Debug + Send + Sync + 'static {
+    /// Returns the record set with the given name and type, if it exists.
+    ///
+    /// # Parameters
+    ///
+    /// * `zone_id`: ID of the zone that contains the record set.
+    /// * `name`: Name of the record set, e.g. `www.example.com`.
+    /// * `record_type`: Type of the record set, e.g. `A`.
+    async fn record_set_get(
+        &self,
+        zone_id: &str,
+        name: &str,
+        record_type: DnsRecordType,
+    ) -> Result<Option<DnsRecordSet>, DnsProviderError>;
+
+    /// Creates the record set, or replaces the existing record set with the
+    /// same name and type.
+    ///
+    /// # Parameters
+    ///
+    /// * `zone_id`: ID of the zone that contains the record set.
+    /// * `record_set`: The record set to create or replace.
+    async fn record_set_upsert(
+        &self,
+        zone_id: &str,
+        record_set: &DnsRecordSet,
+    ) -> Result<(), DnsProviderError>;
+
+    /// Deletes the record set.
+    ///
+    /// # Parameters
+    ///
+    /// * `zone_id`: ID of the zone that contains the record set.
+    /// * `record_set`: The record set to delete, as returned by
+    ///   [`record_set_get`].
+    ///
+    /// [`record_set_get`]: Self::record_set_get
+    async fn record_set_delete(
+        &self,
+        zone_id: &str,
+        record_set: &DnsRecordSet,
+    ) -> Result<(), DnsProviderError>;
+}
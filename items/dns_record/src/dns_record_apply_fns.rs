@@ -0,0 +1,114 @@
+use std::marker::PhantomData;
+
+#[cfg(feature = "output_progress")]
+use peace::cfg::progress::{ProgressLimit, ProgressMsgUpdate};
+use peace::cfg::{ApplyCheck, FnCtx};
+
+use crate::{
+    DnsRecordData, DnsRecordError, DnsRecordParams, DnsRecordState, DnsRecordStateCurrentFn,
+    DnsRecordStateDiff,
+};
+
+/// ApplyFns for the DNS record set.
+#[derive(Debug)]
+pub struct DnsRecordApplyFns<Id>(PhantomData<Id>);
+
+impl<Id> DnsRecordApplyFns<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn apply_check(
+        _params: &DnsRecordParams<Id>,
+        _data: DnsRecordData<'_, Id>,
+        _state_current: &DnsRecordState,
+        _state_target: &DnsRecordState,
+        diff: &DnsRecordStateDiff,
+    ) -> Result<ApplyCheck, DnsRecordError> {
+        let apply_check = if diff.is_in_sync() {
+            ApplyCheck::ExecNotRequired
+        } else {
+            #[cfg(not(feature = "output_progress"))]
+            {
+                ApplyCheck::ExecRequired { reason: None }
+            }
+            #[cfg(feature = "output_progress")]
+            {
+                ApplyCheck::ExecRequired {
+                    progress_limit: ProgressLimit::Steps(1),
+                    reason: None,
+                }
+            }
+        };
+
+        Ok(apply_check)
+    }
+
+    pub async fn apply_dry(
+        _fn_ctx: FnCtx<'_>,
+        _params: &DnsRecordParams<Id>,
+        _data: DnsRecordData<'_, Id>,
+        _state_current: &DnsRecordState,
+        state_target: &DnsRecordState,
+        _diff: &DnsRecordStateDiff,
+    ) -> Result<DnsRecordState, DnsRecordError> {
+        Ok(state_target.clone())
+    }
+
+    pub async fn apply(
+        fn_ctx: FnCtx<'_>,
+        params: &DnsRecordParams<Id>,
+        data: DnsRecordData<'_, Id>,
+        state_current: &DnsRecordState,
+        state_target: &DnsRecordState,
+        _diff: &DnsRecordStateDiff,
+    ) -> Result<DnsRecordState, DnsRecordError> {
+        let dns_provider = data.dns_provider();
+        let zone_id = params.zone_id();
+        #[cfg(not(feature = "output_progress"))]
+        let _fn_ctx = fn_ctx;
+
+        match (state_current.record_set(), state_target.record_set()) {
+            (_, Some(record_set)) => {
+                #[cfg(feature = "output_progress")]
+                fn_ctx
+                    .progress_sender
+                    .tick(ProgressMsgUpdate::Set(String::from("upserting record set")));
+                dns_provider
+                    .record_set_upsert(zone_id, record_set)
+                    .await
+                    .map_err(|error| DnsRecordError::RecordSetUpsert {
+                        zone_id: zone_id.to_string(),
+                        name: record_set.name().to_string(),
+                        record_type: record_set.record_type(),
+                        error,
+                    })?;
+            }
+            (Some(record_set), None) => {
+                #[cfg(feature = "output_progress")]
+                fn_ctx
+                    .progress_sender
+                    .tick(ProgressMsgUpdate::Set(String::from("deleting record set")));
+                dns_provider
+                    .record_set_delete(zone_id, record_set)
+                    .await
+                    .map_err(|error| DnsRecordError::RecordSetDelete {
+                        zone_id: zone_id.to_string(),
+                        name: record_set.name().to_string(),
+                        record_type: record_set.record_type(),
+                        error,
+                    })?;
+            }
+            (None, None) => {}
+        }
+        #[cfg(feature = "output_progress")]
+        fn_ctx.progress_sender.inc(1, ProgressMsgUpdate::NoChange);
+
+        DnsRecordStateCurrentFn::<Id>::state_current_internal(
+            dns_provider,
+            zone_id,
+            params.name(),
+            params.record_type(),
+        )
+        .await
+    }
+}
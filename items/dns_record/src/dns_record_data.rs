@@ -0,0 +1,32 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use peace::data::{accessors::R, Data};
+
+use crate::DnsProvider;
+
+/// Data used to manage a DNS record set.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different DNS record
+///   parameters from each other.
+#[derive(Data, Debug)]
+pub struct DnsRecordData<'exec, Id>
+where
+    Id: Send + Sync + 'static,
+{
+    /// Provider to read and change record sets with.
+    dns_provider: R<'exec, Arc<dyn DnsProvider>>,
+    /// Marker.
+    marker: PhantomData<Id>,
+}
+
+impl<'exec, Id> DnsRecordData<'exec, Id>
+where
+    Id: Send + Sync + 'static,
+{
+    /// Returns the provider to read and change record sets with.
+    pub fn dns_provider(&self) -> &dyn DnsProvider {
+        &**self.dns_provider
+    }
+}
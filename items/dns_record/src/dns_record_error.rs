@@ -0,0 +1,84 @@
+#[cfg(feature = "error_reporting")]
+use peace::miette;
+
+use crate::{DnsProviderError, DnsRecordType};
+
+/// Error while managing a DNS record set.
+#[cfg_attr(feature = "error_reporting", derive(peace::miette::Diagnostic))]
+#[derive(Debug, thiserror::Error)]
+pub enum DnsRecordError {
+    /// Failed to read the record set.
+    #[error("Failed to read `{name}` {record_type} record set in zone `{zone_id}`.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_dns_record::record_set_get),
+            help("Check that the zone exists, and the credentials can read its records.")
+        )
+    )]
+    RecordSetGet {
+        /// ID of the zone that contains the record set.
+        zone_id: String,
+        /// Name of the record set.
+        name: String,
+        /// Type of the record set.
+        record_type: DnsRecordType,
+        /// Underlying provider error.
+        #[source]
+        error: DnsProviderError,
+    },
+
+    /// Failed to create or update the record set.
+    #[error("Failed to upsert `{name}` {record_type} record set in zone `{zone_id}`.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_dns_record::record_set_upsert),
+            help(
+                "Check that the values are valid for the record type, and the credentials can change the zone's records."
+            )
+        )
+    )]
+    RecordSetUpsert {
+        /// ID of the zone that contains the record set.
+        zone_id: String,
+        /// Name of the record set.
+        name: String,
+        /// Type of the record set.
+        record_type: DnsRecordType,
+        /// Underlying provider error.
+        #[source]
+        error: DnsProviderError,
+    },
+
+    /// Failed to delete the record set.
+    #[error("Failed to delete `{name}` {record_type} record set in zone `{zone_id}`.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_dns_record::record_set_delete),
+            help("Check that the credentials can change the zone's records.")
+        )
+    )]
+    RecordSetDelete {
+        /// ID of the zone that contains the record set.
+        zone_id: String,
+        /// Name of the record set.
+        name: String,
+        /// Type of the record set.
+        record_type: DnsRecordType,
+        /// Underlying provider error.
+        #[source]
+        error: DnsProviderError,
+    },
+
+    // === Framework errors === //
+    /// A `peace` runtime error occurred.
+    #[error("A `peace` runtime error occurred.")]
+    PeaceRtError(
+        #[cfg_attr(feature = "error_reporting", diagnostic_source)]
+        #[source]
+        #[from]
+        peace::rt_model::Error,
+    ),
+}
@@ -0,0 +1,210 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use peace::{
+    cfg::{async_trait, ApplyCheck, ApplyCtx, DiffSeverity, FnCtx, Item, ItemId},
+    params::Params,
+    resources::{resources::ts::Empty, Resources},
+};
+
+use crate::{
+    DnsProvider, DnsRecordApplyFns, DnsRecordData, DnsRecordError, DnsRecordParams, DnsRecordState,
+    DnsRecordStateCurrentFn, DnsRecordStateDiff, DnsRecordStateDiffFn, DnsRecordStateGoalFn,
+};
+
+/// Item for managing a DNS record set.
+///
+/// The goal state is for the record set to exist with the given type, TTL,
+/// and values. Cleaning the item deletes the record set.
+///
+/// Record sets are read and changed through the [`DnsProvider`] passed to
+/// [`DnsRecordItem::new`].
+///
+/// The `Id` type parameter is needed for each DNS record params to be a
+/// distinct type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different DNS record
+///   parameters from each other.
+#[derive(Debug)]
+pub struct DnsRecordItem<Id> {
+    /// ID of the item to manage the record set.
+    item_id: ItemId,
+    /// Provider to read and change record sets with.
+    dns_provider: Arc<dyn DnsProvider>,
+    /// Marker for unique DNS record parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> Clone for DnsRecordItem<Id> {
+    fn clone(&self) -> Self {
+        Self {
+            item_id: self.item_id.clone(),
+            dns_provider: Arc::clone(&self.dns_provider),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<Id> DnsRecordItem<Id> {
+    /// Returns a new `DnsRecordItem`.
+    ///
+    /// The provider is inserted into `Resources` when the flow is set up,
+    /// unless a provider has already been inserted, so all `DnsRecordItem`s in
+    /// a flow use the same provider.
+    ///
+    /// # Parameters
+    ///
+    /// * `item_id`: ID of the item.
+    /// * `dns_provider`: Provider to read and change record sets with, e.g.
+    ///   [`Route53DnsProvider`].
+    ///
+    /// [`Route53DnsProvider`]: crate::Route53DnsProvider
+    pub fn new(item_id: ItemId, dns_provider: Arc<dyn DnsProvider>) -> Self {
+        Self {
+            item_id,
+            dns_provider,
+            marker: PhantomData,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<Id> Item for DnsRecordItem<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    type Data<'exec> = DnsRecordData<'exec, Id>;
+    type Error = DnsRecordError;
+    type Params<'exec> = DnsRecordParams<Id>;
+    type State = DnsRecordState;
+    type StateDiff = DnsRecordStateDiff;
+
+    fn id(&self) -> &ItemId {
+        &self.item_id
+    }
+
+    async fn setup(&self, resources: &mut Resources<Empty>) -> Result<(), DnsRecordError> {
+        if !resources.contains::<Arc<dyn DnsProvider>>() {
+            resources.insert(Arc::clone(&self.dns_provider));
+        }
+        Ok(())
+    }
+
+    async fn try_state_current(
+        fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        data: DnsRecordData<'_, Id>,
+    ) -> Result<Option<Self::State>, DnsRecordError> {
+        DnsRecordStateCurrentFn::try_state_current(fn_ctx, params_partial, data).await
+    }
+
+    async fn state_current(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: DnsRecordData<'_, Id>,
+    ) -> Result<Self::State, DnsRecordError> {
+        DnsRecordStateCurrentFn::state_current(fn_ctx, params, data).await
+    }
+
+    async fn try_state_goal(
+        fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        data: DnsRecordData<'_, Id>,
+    ) -> Result<Option<Self::State>, DnsRecordError> {
+        DnsRecordStateGoalFn::try_state_goal(fn_ctx, params_partial, data).await
+    }
+
+    async fn state_goal(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: DnsRecordData<'_, Id>,
+    ) -> Result<Self::State, DnsRecordError> {
+        DnsRecordStateGoalFn::state_goal(fn_ctx, params, data).await
+    }
+
+    async fn state_diff(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_goal: &Self::State,
+    ) -> Result<Self::StateDiff, DnsRecordError> {
+        DnsRecordStateDiffFn::state_diff(state_current, state_goal).await
+    }
+
+    fn diff_severity(
+        _state_current: &Self::State,
+        _state_goal: &Self::State,
+        state_diff: &Self::StateDiff,
+    ) -> DiffSeverity {
+        // Replacing or deleting values of an existing record set redirects
+        // traffic away from those values.
+        let record_set_deleted = state_diff.exists == Some((true, false));
+        let values_replaced = state_diff.exists.is_none() && state_diff.values.is_some();
+
+        if state_diff.is_in_sync() {
+            DiffSeverity::InSync
+        } else if record_set_deleted || values_replaced {
+            DiffSeverity::Destructive
+        } else {
+            DiffSeverity::Additive
+        }
+    }
+
+    fn error_help(error: &DnsRecordError) -> Vec<String> {
+        match error {
+            DnsRecordError::RecordSetGet { .. } => vec![String::from(
+                "Check that the zone exists, and the credentials can read its records.",
+            )],
+            DnsRecordError::RecordSetUpsert { .. } => vec![String::from(
+                "Check that the values are valid for the record type, and the credentials can change the zone's records.",
+            )],
+            DnsRecordError::RecordSetDelete { .. } => vec![String::from(
+                "Check that the credentials can change the zone's records.",
+            )],
+            DnsRecordError::PeaceRtError(_) => Vec::new(),
+        }
+    }
+
+    async fn state_clean(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+    ) -> Result<Self::State, DnsRecordError> {
+        Ok(DnsRecordState::None)
+    }
+
+    async fn apply_check(
+        _apply_ctx: ApplyCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<ApplyCheck, Self::Error> {
+        DnsRecordApplyFns::<Id>::apply_check(params, data, state_current, state_target, diff).await
+    }
+
+    async fn apply_dry(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        DnsRecordApplyFns::<Id>::apply_dry(fn_ctx, params, data, state_current, state_target, diff)
+            .await
+    }
+
+    async fn apply(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        DnsRecordApplyFns::<Id>::apply(fn_ctx, params, data, state_current, state_target, diff)
+            .await
+    }
+}
@@ -0,0 +1,83 @@
+use std::marker::PhantomData;
+
+use derivative::Derivative;
+use peace::params::Params;
+use serde::{Deserialize, Serialize};
+
+use crate::DnsRecordType;
+
+/// DNS record set parameters.
+///
+/// The `Id` type parameter is needed for each DNS record params to be a
+/// distinct type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different DNS record
+///   parameters from each other.
+#[derive(Derivative, Params, PartialEq, Eq, Deserialize, Serialize)]
+#[derivative(Clone, Debug)]
+#[serde(bound = "")]
+pub struct DnsRecordParams<Id> {
+    /// ID of the zone that contains the record set, e.g. a Route 53 hosted
+    /// zone ID.
+    zone_id: String,
+    /// Name of the record set, e.g. `www.example.com`.
+    name: String,
+    /// Type of the record set, e.g. `A`.
+    record_type: DnsRecordType,
+    /// Time to live in seconds.
+    ttl: u32,
+    /// Values of the records, e.g. `["192.0.2.1"]`.
+    ///
+    /// Values are written in the format that the DNS provider accepts, e.g.
+    /// `TXT` values are quoted for Route 53.
+    values: Vec<String>,
+    /// Marker for unique DNS record parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> DnsRecordParams<Id> {
+    /// Returns new `DnsRecordParams`.
+    pub fn new(
+        zone_id: String,
+        name: String,
+        record_type: DnsRecordType,
+        ttl: u32,
+        values: Vec<String>,
+    ) -> Self {
+        Self {
+            zone_id,
+            name,
+            record_type,
+            ttl,
+            values,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the ID of the zone that contains the record set.
+    pub fn zone_id(&self) -> &str {
+        &self.zone_id
+    }
+
+    /// Returns the name of the record set.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the type of the record set.
+    pub fn record_type(&self) -> DnsRecordType {
+        self.record_type
+    }
+
+    /// Returns the time to live in seconds.
+    pub fn ttl(&self) -> u32 {
+        self.ttl
+    }
+
+    /// Returns the values of the records.
+    pub fn values(&self) -> &[String] {
+        &self.values
+    }
+}
@@ -0,0 +1,79 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::DnsRecordType;
+
+/// A DNS record set: the records with the same name and type.
+///
+/// The name is stored without a trailing `.` and in lowercase, and the values
+/// are sorted, so that record sets read from a DNS service can be compared
+/// with the desired record set.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DnsRecordSet {
+    /// Name of the record set, e.g. `www.example.com`.
+    name: String,
+    /// Type of the record set, e.g. `A`.
+    record_type: DnsRecordType,
+    /// Time to live in seconds.
+    ttl: u32,
+    /// Values of the records, e.g. `["192.0.2.1", "192.0.2.2"]`.
+    values: Vec<String>,
+}
+
+impl DnsRecordSet {
+    /// Returns a new `DnsRecordSet`.
+    pub fn new(name: &str, record_type: DnsRecordType, ttl: u32, values: Vec<String>) -> Self {
+        let mut values = values;
+        values.sort();
+        values.dedup();
+
+        Self {
+            name: Self::name_normalize(name),
+            record_type,
+            ttl,
+            values,
+        }
+    }
+
+    /// Returns the name without a trailing `.`, in lowercase.
+    pub fn name_normalize(name: &str) -> String {
+        name.trim_end_matches('.').to_lowercase()
+    }
+
+    /// Returns the name of the record set, e.g. `www.example.com`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the type of the record set, e.g. `A`.
+    pub fn record_type(&self) -> DnsRecordType {
+        self.record_type
+    }
+
+    /// Returns the time to live in seconds.
+    pub fn ttl(&self) -> u32 {
+        self.ttl
+    }
+
+    /// Returns the values of the records, sorted.
+    pub fn values(&self) -> &[String] {
+        &self.values
+    }
+}
+
+impl fmt::Display for DnsRecordSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            name,
+            record_type,
+            ttl,
+            values,
+        } = self;
+        write!(
+            f,
+            "`{name}` {record_type} (ttl {ttl}): {}",
+            values.join(", ")
+        )
+    }
+}
@@ -0,0 +1,35 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::DnsRecordSet;
+
+/// State of a DNS record set.
+///
+/// This is the record set observed in the DNS zone.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum DnsRecordState {
+    /// Record set does not exist.
+    None,
+    /// Record set exists.
+    Some(DnsRecordSet),
+}
+
+impl DnsRecordState {
+    /// Returns the record set if it exists.
+    pub fn record_set(&self) -> Option<&DnsRecordSet> {
+        match self {
+            Self::None => None,
+            Self::Some(record_set) => Some(record_set),
+        }
+    }
+}
+
+impl fmt::Display for DnsRecordState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "record set does not exist"),
+            Self::Some(record_set) => record_set.fmt(f),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use std::marker::PhantomData;
+
+use peace::{cfg::FnCtx, params::Params};
+
+use crate::{
+    DnsProvider, DnsRecordData, DnsRecordError, DnsRecordParams, DnsRecordState, DnsRecordType,
+};
+
+/// Reads the current state of the DNS record set.
+#[derive(Debug)]
+pub struct DnsRecordStateCurrentFn<Id>(PhantomData<Id>);
+
+impl<Id> DnsRecordStateCurrentFn<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn try_state_current(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<DnsRecordParams<Id> as Params>::Partial,
+        data: DnsRecordData<'_, Id>,
+    ) -> Result<Option<DnsRecordState>, DnsRecordError> {
+        match (
+            params_partial.zone_id(),
+            params_partial.name(),
+            params_partial.record_type(),
+        ) {
+            (Some(zone_id), Some(name), Some(record_type)) => {
+                Self::state_current_internal(data.dns_provider(), zone_id, name, *record_type)
+                    .await
+                    .map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    pub async fn state_current(
+        _fn_ctx: FnCtx<'_>,
+        params: &DnsRecordParams<Id>,
+        data: DnsRecordData<'_, Id>,
+    ) -> Result<DnsRecordState, DnsRecordError> {
+        Self::state_current_internal(
+            data.dns_provider(),
+            params.zone_id(),
+            params.name(),
+            params.record_type(),
+        )
+        .await
+    }
+
+    pub(crate) async fn state_current_internal(
+        dns_provider: &dyn DnsProvider,
+        zone_id: &str,
+        name: &str,
+        record_type: DnsRecordType,
+    ) -> Result<DnsRecordState, DnsRecordError> {
+        let record_set = dns_provider
+            .record_set_get(zone_id, name, record_type)
+            .await
+            .map_err(|error| DnsRecordError::RecordSetGet {
+                zone_id: zone_id.to_string(),
+                name: name.to_string(),
+                record_type,
+                error,
+            })?;
+
+        Ok(record_set.map_or(DnsRecordState::None, DnsRecordState::Some))
+    }
+}
@@ -0,0 +1,55 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Diff between the current and goal DNS record set state.
+///
+/// Each field is `Some((from, to))` when that part of the state differs.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct DnsRecordStateDiff {
+    /// Change in whether the record set exists.
+    pub exists: Option<(bool, bool)>,
+    /// Change in the time to live.
+    pub ttl: Option<(Option<u32>, Option<u32>)>,
+    /// Change in the record values.
+    pub values: Option<(Vec<String>, Vec<String>)>,
+}
+
+impl DnsRecordStateDiff {
+    /// Returns whether the record set's existence, TTL, and values are all in
+    /// sync.
+    pub fn is_in_sync(&self) -> bool {
+        self.exists.is_none() && self.ttl.is_none() && self.values.is_none()
+    }
+}
+
+impl fmt::Display for DnsRecordStateDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.exists {
+            _ if self.is_in_sync() => return write!(f, "in sync"),
+            Some((false, _)) => return write!(f, "record set will be created"),
+            Some((_, false)) => return write!(f, "record set will be deleted"),
+            _ => {}
+        }
+
+        let mut changes = Vec::with_capacity(2);
+        if let Some((ttl_from, ttl_to)) = self.ttl {
+            let ttl_from = ttl_from.map(|ttl| ttl.to_string());
+            let ttl_to = ttl_to.map(|ttl| ttl.to_string());
+            changes.push(format!(
+                "ttl {} -> {}",
+                ttl_from.as_deref().unwrap_or("<none>"),
+                ttl_to.as_deref().unwrap_or("<none>")
+            ));
+        }
+        if let Some((values_from, values_to)) = self.values.as_ref() {
+            changes.push(format!(
+                "values [{}] -> [{}]",
+                values_from.join(", "),
+                values_to.join(", ")
+            ));
+        }
+
+        write!(f, "record set will be updated: {}", changes.join(", "))
+    }
+}
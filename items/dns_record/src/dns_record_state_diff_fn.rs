@@ -0,0 +1,37 @@
+use crate::{DnsRecordError, DnsRecordState, DnsRecordStateDiff};
+
+/// DNS record set state diff function.
+#[derive(Debug)]
+pub struct DnsRecordStateDiffFn;
+
+impl DnsRecordStateDiffFn {
+    pub async fn state_diff(
+        state_current: &DnsRecordState,
+        state_goal: &DnsRecordState,
+    ) -> Result<DnsRecordStateDiff, DnsRecordError> {
+        let record_set_current = state_current.record_set();
+        let record_set_goal = state_goal.record_set();
+
+        let exists_current = record_set_current.is_some();
+        let exists_goal = record_set_goal.is_some();
+        let exists = (exists_current != exists_goal).then_some((exists_current, exists_goal));
+
+        let ttl_current = record_set_current.map(|record_set| record_set.ttl());
+        let ttl_goal = record_set_goal.map(|record_set| record_set.ttl());
+        let ttl = (ttl_current != ttl_goal).then_some((ttl_current, ttl_goal));
+
+        let values_current = record_set_current
+            .map(|record_set| record_set.values().to_vec())
+            .unwrap_or_default();
+        let values_goal = record_set_goal
+            .map(|record_set| record_set.values().to_vec())
+            .unwrap_or_default();
+        let values = (values_current != values_goal).then_some((values_current, values_goal));
+
+        Ok(DnsRecordStateDiff {
+            exists,
+            ttl,
+            values,
+        })
+    }
+}
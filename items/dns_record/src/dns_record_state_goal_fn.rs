@@ -0,0 +1,47 @@
+use std::marker::PhantomData;
+
+use peace::{cfg::FnCtx, params::Params};
+
+use crate::{DnsRecordData, DnsRecordError, DnsRecordParams, DnsRecordSet, DnsRecordState};
+
+/// Reads the goal state of the DNS record set.
+///
+/// The goal is for the record set to exist with the given TTL and values.
+#[derive(Debug)]
+pub struct DnsRecordStateGoalFn<Id>(PhantomData<Id>);
+
+impl<Id> DnsRecordStateGoalFn<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn try_state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<DnsRecordParams<Id> as Params>::Partial,
+        _data: DnsRecordData<'_, Id>,
+    ) -> Result<Option<DnsRecordState>, DnsRecordError> {
+        match (
+            params_partial.name(),
+            params_partial.record_type(),
+            params_partial.ttl(),
+            params_partial.values(),
+        ) {
+            (Some(name), Some(record_type), Some(ttl), Some(values)) => Ok(Some(
+                DnsRecordState::Some(DnsRecordSet::new(name, *record_type, *ttl, values.clone())),
+            )),
+            _ => Ok(None),
+        }
+    }
+
+    pub async fn state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params: &DnsRecordParams<Id>,
+        _data: DnsRecordData<'_, Id>,
+    ) -> Result<DnsRecordState, DnsRecordError> {
+        Ok(DnsRecordState::Some(DnsRecordSet::new(
+            params.name(),
+            params.record_type(),
+            params.ttl(),
+            params.values().to_vec(),
+        )))
+    }
+}
@@ -0,0 +1,50 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Type of a DNS record set.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DnsRecordType {
+    /// IPv4 address.
+    A,
+    /// IPv6 address.
+    Aaaa,
+    /// Certificate authorities allowed to issue certificates for the domain.
+    Caa,
+    /// Alias to another domain name.
+    Cname,
+    /// Mail exchange.
+    Mx,
+    /// Name server.
+    Ns,
+    /// Domain name pointer, used for reverse lookups.
+    Ptr,
+    /// Service locator.
+    Srv,
+    /// Text.
+    Txt,
+}
+
+impl DnsRecordType {
+    /// Returns the record type as it is written in a zone file, e.g. `AAAA`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::A => "A",
+            Self::Aaaa => "AAAA",
+            Self::Caa => "CAA",
+            Self::Cname => "CNAME",
+            Self::Mx => "MX",
+            Self::Ns => "NS",
+            Self::Ptr => "PTR",
+            Self::Srv => "SRV",
+            Self::Txt => "TXT",
+        }
+    }
+}
+
+impl fmt::Display for DnsRecordType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,40 @@
+//! Manages a DNS record set for the peace framework.
+//!
+//! This item ensures a record set in a DNS zone has the given type, TTL, and
+//! values. Cleaning the item deletes the record set.
+//!
+//! DNS records are read and changed through a [`DnsProvider`], so that record
+//! sets can be managed with different DNS services. [`Route53DnsProvider`]
+//! manages record sets in AWS Route 53 hosted zones.
+
+pub use crate::{
+    dns_provider::{DnsProvider, DnsProviderError},
+    dns_record_apply_fns::DnsRecordApplyFns,
+    dns_record_data::DnsRecordData,
+    dns_record_error::DnsRecordError,
+    dns_record_item::DnsRecordItem,
+    dns_record_params::{DnsRecordParams, DnsRecordParamsFieldWise, DnsRecordParamsPartial},
+    dns_record_set::DnsRecordSet,
+    dns_record_state::DnsRecordState,
+    dns_record_state_current_fn::DnsRecordStateCurrentFn,
+    dns_record_state_diff::DnsRecordStateDiff,
+    dns_record_state_diff_fn::DnsRecordStateDiffFn,
+    dns_record_state_goal_fn::DnsRecordStateGoalFn,
+    dns_record_type::DnsRecordType,
+    route53_dns_provider::Route53DnsProvider,
+};
+
+mod dns_provider;
+mod dns_record_apply_fns;
+mod dns_record_data;
+mod dns_record_error;
+mod dns_record_item;
+mod dns_record_params;
+mod dns_record_set;
+mod dns_record_state;
+mod dns_record_state_current_fn;
+mod dns_record_state_diff;
+mod dns_record_state_diff_fn;
+mod dns_record_state_goal_fn;
+mod dns_record_type;
+mod route53_dns_provider;
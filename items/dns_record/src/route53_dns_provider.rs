@@ -0,0 +1,132 @@
+use aws_config::BehaviorVersion;
+use aws_sdk_route53::types::{
+    Change, ChangeAction, ChangeBatch, ResourceRecord, ResourceRecordSet, RrType,
+};
+use peace::cfg::async_trait;
+
+use crate::{DnsProvider, DnsProviderError, DnsRecordSet, DnsRecordType};
+
+/// Manages record sets in AWS Route 53 hosted zones.
+///
+/// The zone ID is the hosted zone ID, e.g. `Z0123456789ABCDEFGHIJ`.
+///
+/// Record values are passed to Route 53 as is, so `TXT` values need to be
+/// enclosed in double quotes, e.g. `"\"v=spf1 -all\""`.
+#[derive(Clone, Debug)]
+pub struct Route53DnsProvider {
+    /// Route 53 client to communicate with AWS.
+    client: aws_sdk_route53::Client,
+}
+
+impl Route53DnsProvider {
+    /// Returns a new `Route53DnsProvider` that uses the given client.
+    pub fn new(client: aws_sdk_route53::Client) -> Self {
+        Self { client }
+    }
+
+    /// Returns a new `Route53DnsProvider` with the AWS configuration loaded
+    /// from the environment.
+    pub async fn from_env() -> Self {
+        let sdk_config = aws_config::load_defaults(BehaviorVersion::latest()).await;
+        Self::new(aws_sdk_route53::Client::new(&sdk_config))
+    }
+
+    /// Creates, updates, or deletes a record set.
+    async fn record_set_change(
+        &self,
+        zone_id: &str,
+        action: ChangeAction,
+        record_set: &DnsRecordSet,
+    ) -> Result<(), DnsProviderError> {
+        let resource_records = record_set
+            .values()
+            .iter()
+            .map(|value| ResourceRecord::builder().value(value).build())
+            .collect::<Result<Vec<_>, _>>()?;
+        let resource_record_set = ResourceRecordSet::builder()
+            .name(format!("{}.", record_set.name()))
+            .r#type(RrType::from(record_set.record_type().as_str()))
+            .ttl(i64::from(record_set.ttl()))
+            .set_resource_records(Some(resource_records))
+            .build()?;
+        let change = Change::builder()
+            .action(action)
+            .resource_record_set(resource_record_set)
+            .build()?;
+        let change_batch = ChangeBatch::builder().changes(change).build()?;
+
+        self.client
+            .change_resource_record_sets()
+            .hosted_zone_id(zone_id)
+            .change_batch(change_batch)
+            .send()
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl DnsProvider for Route53DnsProvider {
+    async fn record_set_get(
+        &self,
+        zone_id: &str,
+        name: &str,
+        record_type: DnsRecordType,
+    ) -> Result<Option<DnsRecordSet>, DnsProviderError> {
+        let name = DnsRecordSet::name_normalize(name);
+
+        // Record sets are listed in order starting from the given name and
+        // type, so the first record set is the one we want, if it exists.
+        let list_output = self
+            .client
+            .list_resource_record_sets()
+            .hosted_zone_id(zone_id)
+            .start_record_name(format!("{name}."))
+            .start_record_type(RrType::from(record_type.as_str()))
+            .max_items(1)
+            .send()
+            .await?;
+
+        let record_set = list_output
+            .resource_record_sets()
+            .iter()
+            .find(|resource_record_set| {
+                DnsRecordSet::name_normalize(resource_record_set.name()) == name
+                    && resource_record_set.r#type().as_str() == record_type.as_str()
+            })
+            .map(|resource_record_set| {
+                let ttl = resource_record_set
+                    .ttl()
+                    .and_then(|ttl| u32::try_from(ttl).ok())
+                    .unwrap_or_default();
+                let values = resource_record_set
+                    .resource_records()
+                    .iter()
+                    .map(|resource_record| resource_record.value().to_string())
+                    .collect::<Vec<String>>();
+
+                DnsRecordSet::new(&name, record_type, ttl, values)
+            });
+
+        Ok(record_set)
+    }
+
+    async fn record_set_upsert(
+        &self,
+        zone_id: &str,
+        record_set: &DnsRecordSet,
+    ) -> Result<(), DnsProviderError> {
+        self.record_set_change(zone_id, ChangeAction::Upsert, record_set)
+            .await
+    }
+
+    async fn record_set_delete(
+        &self,
+        zone_id: &str,
+        record_set: &DnsRecordSet,
+    ) -> Result<(), DnsProviderError> {
+        self.record_set_change(zone_id, ChangeAction::Delete, record_set)
+            .await
+    }
+}
@@ -0,0 +1,193 @@
+use std::{collections::BTreeMap, fmt};
+
+/// Contents of a `.env` file.
+///
+/// Each line is either a `KEY=value` entry, or any other line such as a
+/// comment or blank line. Lines that are not changed are written back as they
+/// were read, so unmanaged keys and comments retain their formatting.
+///
+/// Entries may be prefixed with `export `, and values may be wrapped in single
+/// or double quotes. Within double quotes, `\n`, `\"`, and `\\` are unescaped.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EnvFile {
+    /// Lines in the file.
+    lines: Vec<EnvFileLine>,
+}
+
+/// A line in a `.env` file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum EnvFileLine {
+    /// A `KEY=value` entry.
+    Entry {
+        /// Key of the entry.
+        key: String,
+        /// Unquoted value of the entry.
+        value: String,
+        /// Line as it was read, or as it will be written.
+        line: String,
+    },
+    /// A comment, blank line, or line that could not be parsed as an entry.
+    Other(String),
+}
+
+impl EnvFile {
+    /// Parses the contents of a `.env` file.
+    pub fn parse(contents: &str) -> Self {
+        let lines = contents.lines().map(EnvFileLine::parse).collect();
+
+        Self { lines }
+    }
+
+    /// Returns the value of the given key.
+    ///
+    /// If the key is present multiple times, the last value is returned, as
+    /// that is the value most `.env` loaders use.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.lines.iter().rev().find_map(|line| match line {
+            EnvFileLine::Entry {
+                key: line_key,
+                value,
+                line: _,
+            } if line_key == key => Some(value.as_str()),
+            EnvFileLine::Entry { .. } | EnvFileLine::Other(_) => None,
+        })
+    }
+
+    /// Returns the values of the given keys that are present in the file.
+    pub fn entries_get<'key>(
+        &self,
+        keys: impl IntoIterator<Item = &'key String>,
+    ) -> BTreeMap<String, String> {
+        keys.into_iter()
+            .filter_map(|key| self.get(key).map(|value| (key.clone(), value.to_string())))
+            .collect()
+    }
+
+    /// Sets the value of the given key.
+    ///
+    /// Existing entries for the key are updated in place, otherwise the entry
+    /// is appended to the end of the file.
+    pub fn set(&mut self, key: &str, value: &str) {
+        let mut key_found = false;
+        self.lines.iter_mut().for_each(|line| {
+            if let EnvFileLine::Entry {
+                key: line_key,
+                value: line_value,
+                line,
+            } = line
+            {
+                if line_key == key {
+                    key_found = true;
+                    if line_value != value {
+                        *line_value = value.to_string();
+                        *line = EnvFileLine::entry_line(key, value);
+                    }
+                }
+            }
+        });
+
+        if !key_found {
+            self.lines.push(EnvFileLine::Entry {
+                key: key.to_string(),
+                value: value.to_string(),
+                line: EnvFileLine::entry_line(key, value),
+            });
+        }
+    }
+
+    /// Removes all entries for the given key.
+    pub fn remove(&mut self, key: &str) {
+        self.lines.retain(|line| match line {
+            EnvFileLine::Entry { key: line_key, .. } => line_key != key,
+            EnvFileLine::Other(_) => true,
+        });
+    }
+}
+
+impl fmt::Display for EnvFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.lines.iter().try_for_each(|line| match line {
+            EnvFileLine::Entry { line, .. } | EnvFileLine::Other(line) => writeln!(f, "{line}"),
+        })
+    }
+}
+
+impl EnvFileLine {
+    fn parse(line: &str) -> Self {
+        let line_trimmed = line.trim_start();
+        if line_trimmed.is_empty() || line_trimmed.starts_with('#') {
+            return Self::Other(line.to_string());
+        }
+
+        let entry = line_trimmed.strip_prefix("export ").unwrap_or(line_trimmed);
+        match entry.split_once('=') {
+            Some((key, value)) if Self::key_is_valid(key.trim()) => Self::Entry {
+                key: key.trim().to_string(),
+                value: Self::value_unquote(value.trim()),
+                line: line.to_string(),
+            },
+            Some(_) | None => Self::Other(line.to_string()),
+        }
+    }
+
+    /// Returns whether the key only contains ASCII alphanumeric characters,
+    /// `_`, or `.`.
+    fn key_is_valid(key: &str) -> bool {
+        !key.is_empty()
+            && key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+    }
+
+    fn value_unquote(value: &str) -> String {
+        if let Some(value) = value
+            .strip_prefix('"')
+            .and_then(|value| value.strip_suffix('"'))
+        {
+            let mut value_unescaped = String::with_capacity(value.len());
+            let mut chars = value.chars();
+            while let Some(c) = chars.next() {
+                match (c, chars.clone().next()) {
+                    ('\\', Some('n')) => {
+                        value_unescaped.push('\n');
+                        chars.next();
+                    }
+                    ('\\', Some(escaped @ ('"' | '\\'))) => {
+                        value_unescaped.push(escaped);
+                        chars.next();
+                    }
+                    _ => value_unescaped.push(c),
+                }
+            }
+            value_unescaped
+        } else if let Some(value) = value
+            .strip_prefix('\'')
+            .and_then(|value| value.strip_suffix('\''))
+        {
+            value.to_string()
+        } else {
+            // Unquoted values may be followed by a comment.
+            match value.split_once(" #") {
+                Some((value, _comment)) => value.trim_end().to_string(),
+                None => value.to_string(),
+            }
+        }
+    }
+
+    /// Returns the line to write for the given entry, quoting the value if
+    /// necessary.
+    fn entry_line(key: &str, value: &str) -> String {
+        let quote_needed = value
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, '#' | '"' | '\'' | '\\'));
+        if quote_needed {
+            let value_escaped = value
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n");
+            format!("{key}=\"{value_escaped}\"")
+        } else {
+            format!("{key}={value}")
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use std::marker::PhantomData;
+
+#[cfg(feature = "output_progress")]
+use peace::cfg::progress::ProgressLimit;
+use peace::cfg::{ApplyCheck, FnCtx};
+
+use crate::{
+    EnvFileData, EnvFileError, EnvFileParams, EnvFileState, EnvFileStateCurrentFn, EnvFileStateDiff,
+};
+
+/// ApplyFns for the env file.
+#[derive(Debug)]
+pub struct EnvFileApplyFns<Id>(PhantomData<Id>);
+
+impl<Id> EnvFileApplyFns<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn apply_check(
+        _params: &EnvFileParams<Id>,
+        _data: EnvFileData<'_, Id>,
+        _state_current: &EnvFileState,
+        _state_target: &EnvFileState,
+        diff: &EnvFileStateDiff,
+    ) -> Result<ApplyCheck, EnvFileError> {
+        let apply_check = if diff.is_in_sync() {
+            ApplyCheck::ExecNotRequired
+        } else {
+            #[cfg(not(feature = "output_progress"))]
+            {
+                ApplyCheck::ExecRequired { reason: None }
+            }
+            #[cfg(feature = "output_progress")]
+            {
+                let progress_limit = diff
+                    .change_count()
+                    .try_into()
+                    .map(ProgressLimit::Steps)
+                    .unwrap_or(ProgressLimit::Unknown);
+                ApplyCheck::ExecRequired {
+                    progress_limit,
+                    reason: None,
+                }
+            }
+        };
+
+        Ok(apply_check)
+    }
+
+    pub async fn apply_dry(
+        _fn_ctx: FnCtx<'_>,
+        _params: &EnvFileParams<Id>,
+        _data: EnvFileData<'_, Id>,
+        _state_current: &EnvFileState,
+        state_target: &EnvFileState,
+        _diff: &EnvFileStateDiff,
+    ) -> Result<EnvFileState, EnvFileError> {
+        Ok(state_target.clone())
+    }
+
+    pub async fn apply(
+        _fn_ctx: FnCtx<'_>,
+        params: &EnvFileParams<Id>,
+        data: EnvFileData<'_, Id>,
+        _state_current: &EnvFileState,
+        _state_target: &EnvFileState,
+        diff: &EnvFileStateDiff,
+    ) -> Result<EnvFileState, EnvFileError> {
+        let path = params.path();
+
+        // The file is read again so that changes to unmanaged keys since
+        // discovery are not overwritten.
+        let mut env_file = EnvFileStateCurrentFn::<Id>::env_file_read(path).await?;
+        diff.added
+            .iter()
+            .chain(diff.modified.iter().map(|(key, (_from, to))| (key, to)))
+            .for_each(|(key, value)| env_file.set(key, value));
+        diff.removed.keys().for_each(|key| env_file.remove(key));
+
+        if let Some(env_file_dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            tokio::fs::create_dir_all(env_file_dir)
+                .await
+                .map_err(|error| EnvFileError::EnvFileDirCreate {
+                    path: env_file_dir.to_path_buf(),
+                    error,
+                })?;
+        }
+        tokio::fs::write(path, env_file.to_string())
+            .await
+            .map_err(|error| EnvFileError::EnvFileWrite {
+                path: path.to_path_buf(),
+                error,
+            })?;
+
+        let keys = EnvFileStateCurrentFn::<Id>::keys_managed(params.entries().keys(), &data);
+        EnvFileStateCurrentFn::<Id>::state_current_internal(path, &keys).await
+    }
+}
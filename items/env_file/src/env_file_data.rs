@@ -0,0 +1,33 @@
+use std::marker::PhantomData;
+
+use peace::{cfg::accessors::Stored, data::Data};
+
+use crate::EnvFileState;
+
+/// Data used to manage keys in a `.env` file.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different env file
+///   parameters from each other.
+#[derive(Data, Debug)]
+pub struct EnvFileData<'exec, Id>
+where
+    Id: Send + Sync + 'static,
+{
+    /// Stored state of this item's previous execution.
+    state_current_stored: Stored<'exec, EnvFileState>,
+
+    /// Marker.
+    marker: PhantomData<Id>,
+}
+
+impl<'exec, Id> EnvFileData<'exec, Id>
+where
+    Id: Send + Sync + 'static,
+{
+    /// Returns the stored state of this item's previous execution.
+    pub fn state_current_stored(&self) -> Option<&EnvFileState> {
+        self.state_current_stored.get()
+    }
+}
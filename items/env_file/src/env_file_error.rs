@@ -0,0 +1,70 @@
+use std::path::PathBuf;
+
+#[cfg(feature = "error_reporting")]
+use peace::miette;
+
+/// Error while managing keys in a `.env` file.
+#[cfg_attr(feature = "error_reporting", derive(peace::miette::Diagnostic))]
+#[derive(Debug, thiserror::Error)]
+pub enum EnvFileError {
+    /// Failed to read the env file.
+    #[error("Failed to read env file: `{}`.", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_env_file::env_file_read),
+            help("Check that the file is a UTF-8 text file, and is readable by the current user.")
+        )
+    )]
+    EnvFileRead {
+        /// Path to the env file.
+        path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to create the directory to write the env file to.
+    #[error("Failed to create env file directory: `{}`.", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_env_file::env_file_dir_create),
+            help("Check that the current user has permission to write to the directory.")
+        )
+    )]
+    EnvFileDirCreate {
+        /// Path to the directory.
+        path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to write the env file.
+    #[error("Failed to write env file: `{}`.", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_env_file::env_file_write),
+            help("Check that the current user has permission to write to the file.")
+        )
+    )]
+    EnvFileWrite {
+        /// Path to the env file.
+        path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    // === Framework errors === //
+    /// A `peace` runtime error occurred.
+    #[error("A `peace` runtime error occurred.")]
+    PeaceRtError(
+        #[cfg_attr(feature = "error_reporting", diagnostic_source)]
+        #[source]
+        #[from]
+        peace::rt_model::Error,
+    ),
+}
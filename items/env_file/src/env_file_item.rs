@@ -0,0 +1,168 @@
+use std::marker::PhantomData;
+
+use peace::{
+    cfg::{async_trait, ApplyCheck, ApplyCtx, DiffSeverity, FnCtx, Item, ItemId},
+    params::Params,
+    resources::{resources::ts::Empty, Resources},
+};
+
+use crate::{
+    EnvFileApplyFns, EnvFileData, EnvFileError, EnvFileParams, EnvFileState, EnvFileStateCurrentFn,
+    EnvFileStateDiff, EnvFileStateDiffFn, EnvFileStateGoalFn,
+};
+
+/// Item for managing keys in a `.env` file.
+///
+/// The goal state is for each managed key to be present in the file with the
+/// given value. Keys that are not managed by this item are left untouched.
+/// Cleaning the item removes the managed keys from the file.
+///
+/// The `Id` type parameter is needed for each env file params to be a
+/// distinct type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different env file
+///   parameters from each other.
+#[derive(Debug)]
+pub struct EnvFileItem<Id> {
+    /// ID of the item to manage the env file.
+    item_id: ItemId,
+    /// Marker for unique env file parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> Clone for EnvFileItem<Id> {
+    fn clone(&self) -> Self {
+        Self {
+            item_id: self.item_id.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<Id> EnvFileItem<Id> {
+    /// Returns a new `EnvFileItem`.
+    pub fn new(item_id: ItemId) -> Self {
+        Self {
+            item_id,
+            marker: PhantomData,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<Id> Item for EnvFileItem<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    type Data<'exec> = EnvFileData<'exec, Id>;
+    type Error = EnvFileError;
+    type Params<'exec> = EnvFileParams<Id>;
+    type State = EnvFileState;
+    type StateDiff = EnvFileStateDiff;
+
+    fn id(&self) -> &ItemId {
+        &self.item_id
+    }
+
+    async fn setup(&self, _resources: &mut Resources<Empty>) -> Result<(), EnvFileError> {
+        Ok(())
+    }
+
+    async fn try_state_current(
+        fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        data: EnvFileData<'_, Id>,
+    ) -> Result<Option<Self::State>, EnvFileError> {
+        EnvFileStateCurrentFn::try_state_current(fn_ctx, params_partial, data).await
+    }
+
+    async fn state_current(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: EnvFileData<'_, Id>,
+    ) -> Result<Self::State, EnvFileError> {
+        EnvFileStateCurrentFn::state_current(fn_ctx, params, data).await
+    }
+
+    async fn try_state_goal(
+        fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        data: EnvFileData<'_, Id>,
+    ) -> Result<Option<Self::State>, EnvFileError> {
+        EnvFileStateGoalFn::try_state_goal(fn_ctx, params_partial, data).await
+    }
+
+    async fn state_goal(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: EnvFileData<'_, Id>,
+    ) -> Result<Self::State, EnvFileError> {
+        EnvFileStateGoalFn::state_goal(fn_ctx, params, data).await
+    }
+
+    async fn state_diff(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_goal: &Self::State,
+    ) -> Result<Self::StateDiff, EnvFileError> {
+        EnvFileStateDiffFn::state_diff(state_current, state_goal).await
+    }
+
+    fn diff_severity(
+        _state_current: &Self::State,
+        _state_goal: &Self::State,
+        state_diff: &Self::StateDiff,
+    ) -> DiffSeverity {
+        if state_diff.is_in_sync() {
+            DiffSeverity::InSync
+        } else if !state_diff.removed.is_empty() {
+            DiffSeverity::Destructive
+        } else {
+            DiffSeverity::Additive
+        }
+    }
+
+    async fn state_clean(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+    ) -> Result<Self::State, EnvFileError> {
+        Ok(EnvFileState::default())
+    }
+
+    async fn apply_check(
+        _apply_ctx: ApplyCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<ApplyCheck, Self::Error> {
+        EnvFileApplyFns::<Id>::apply_check(params, data, state_current, state_target, diff).await
+    }
+
+    async fn apply_dry(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        EnvFileApplyFns::<Id>::apply_dry(fn_ctx, params, data, state_current, state_target, diff)
+            .await
+    }
+
+    async fn apply(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        EnvFileApplyFns::<Id>::apply(fn_ctx, params, data, state_current, state_target, diff).await
+    }
+}
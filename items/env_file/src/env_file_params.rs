@@ -0,0 +1,51 @@
+use std::{
+    collections::BTreeMap,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+use derivative::Derivative;
+use peace::params::Params;
+use serde::{Deserialize, Serialize};
+
+/// Env file parameters.
+///
+/// The `Id` type parameter is needed for each env file params to be a
+/// distinct type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different env file
+///   parameters from each other.
+#[derive(Derivative, Params, PartialEq, Eq, Deserialize, Serialize)]
+#[derivative(Clone, Debug)]
+#[serde(bound = "")]
+pub struct EnvFileParams<Id> {
+    /// Path to the `.env` file.
+    path: PathBuf,
+    /// Keys and values that are managed by this item.
+    entries: BTreeMap<String, String>,
+    /// Marker for unique env file parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> EnvFileParams<Id> {
+    /// Returns new `EnvFileParams`.
+    pub fn new(path: PathBuf, entries: BTreeMap<String, String>) -> Self {
+        Self {
+            path,
+            entries,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the path to the `.env` file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the keys and values that are managed by this item.
+    pub fn entries(&self) -> &BTreeMap<String, String> {
+        &self.entries
+    }
+}
@@ -0,0 +1,37 @@
+use std::{collections::BTreeMap, fmt};
+
+use serde::{Deserialize, Serialize};
+
+/// State of the managed keys in a `.env` file.
+///
+/// This only contains the keys that are managed by the item, and that are
+/// present in the file. Other keys in the file are not tracked.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct EnvFileState {
+    /// Managed keys and their values.
+    pub entries: BTreeMap<String, String>,
+}
+
+impl EnvFileState {
+    /// Returns a new `EnvFileState`.
+    pub fn new(entries: BTreeMap<String, String>) -> Self {
+        Self { entries }
+    }
+}
+
+impl fmt::Display for EnvFileState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Values are not displayed, as `.env` files commonly contain secrets.
+        if self.entries.is_empty() {
+            write!(f, "no managed keys")
+        } else {
+            let keys = self
+                .entries
+                .keys()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(f, "{keys}")
+        }
+    }
+}
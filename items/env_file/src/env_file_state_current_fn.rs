@@ -0,0 +1,75 @@
+use std::{collections::BTreeSet, io::ErrorKind, marker::PhantomData, path::Path};
+
+use peace::{cfg::FnCtx, params::Params};
+
+use crate::{EnvFile, EnvFileData, EnvFileError, EnvFileParams, EnvFileState};
+
+/// Reads the current state of the managed keys in the `.env` file.
+///
+/// Managed keys are the keys in the params, as well as keys in the stored
+/// state, so that keys which are no longer in the params are still tracked
+/// until they are removed from the file.
+#[derive(Debug)]
+pub struct EnvFileStateCurrentFn<Id>(PhantomData<Id>);
+
+impl<Id> EnvFileStateCurrentFn<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn try_state_current(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<EnvFileParams<Id> as Params>::Partial,
+        data: EnvFileData<'_, Id>,
+    ) -> Result<Option<EnvFileState>, EnvFileError> {
+        match (params_partial.path(), params_partial.entries()) {
+            (Some(path), Some(entries)) => {
+                let keys = Self::keys_managed(entries.keys(), &data);
+                Self::state_current_internal(path, &keys).await.map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    pub async fn state_current(
+        _fn_ctx: FnCtx<'_>,
+        params: &EnvFileParams<Id>,
+        data: EnvFileData<'_, Id>,
+    ) -> Result<EnvFileState, EnvFileError> {
+        let keys = Self::keys_managed(params.entries().keys(), &data);
+        Self::state_current_internal(params.path(), &keys).await
+    }
+
+    pub(crate) async fn state_current_internal(
+        path: &Path,
+        keys: &BTreeSet<String>,
+    ) -> Result<EnvFileState, EnvFileError> {
+        let env_file = Self::env_file_read(path).await?;
+
+        Ok(EnvFileState::new(env_file.entries_get(keys)))
+    }
+
+    /// Returns the keys in the params and in the stored state.
+    pub(crate) fn keys_managed<'key>(
+        keys_params: impl Iterator<Item = &'key String>,
+        data: &EnvFileData<'_, Id>,
+    ) -> BTreeSet<String> {
+        let mut keys = keys_params.cloned().collect::<BTreeSet<String>>();
+        if let Some(state_current_stored) = data.state_current_stored() {
+            keys.extend(state_current_stored.entries.keys().cloned());
+        }
+
+        keys
+    }
+
+    /// Reads the `.env` file, returning an empty file if it does not exist.
+    pub(crate) async fn env_file_read(path: &Path) -> Result<EnvFile, EnvFileError> {
+        match tokio::fs::read_to_string(path).await {
+            Ok(contents) => Ok(EnvFile::parse(&contents)),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(EnvFile::default()),
+            Err(error) => Err(EnvFileError::EnvFileRead {
+                path: path.to_path_buf(),
+                error,
+            }),
+        }
+    }
+}
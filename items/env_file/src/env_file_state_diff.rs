@@ -0,0 +1,46 @@
+use std::{collections::BTreeMap, fmt};
+
+use serde::{Deserialize, Serialize};
+
+/// Diff between the current and goal managed keys in a `.env` file.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct EnvFileStateDiff {
+    /// Keys that will be added, and their values.
+    pub added: BTreeMap<String, String>,
+    /// Keys whose values will change, and their `(from, to)` values.
+    pub modified: BTreeMap<String, (String, String)>,
+    /// Keys that will be removed, and their current values.
+    pub removed: BTreeMap<String, String>,
+}
+
+impl EnvFileStateDiff {
+    /// Returns whether all managed keys are in sync.
+    pub fn is_in_sync(&self) -> bool {
+        self.added.is_empty() && self.modified.is_empty() && self.removed.is_empty()
+    }
+
+    /// Returns the number of keys that will be changed.
+    pub fn change_count(&self) -> usize {
+        self.added.len() + self.modified.len() + self.removed.len()
+    }
+}
+
+impl fmt::Display for EnvFileStateDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_in_sync() {
+            return write!(f, "in sync");
+        }
+
+        // Values are not displayed, as `.env` files commonly contain secrets.
+        let changes = self
+            .added
+            .keys()
+            .map(|key| format!("+{key}"))
+            .chain(self.modified.keys().map(|key| format!("~{key}")))
+            .chain(self.removed.keys().map(|key| format!("-{key}")))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        write!(f, "{changes}")
+    }
+}
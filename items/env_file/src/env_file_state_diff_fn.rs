@@ -0,0 +1,39 @@
+use crate::{EnvFileError, EnvFileState, EnvFileStateDiff};
+
+/// Env file state diff function.
+#[derive(Debug)]
+pub struct EnvFileStateDiffFn;
+
+impl EnvFileStateDiffFn {
+    pub async fn state_diff(
+        state_current: &EnvFileState,
+        state_goal: &EnvFileState,
+    ) -> Result<EnvFileStateDiff, EnvFileError> {
+        let mut state_diff = EnvFileStateDiff::default();
+
+        state_goal.entries.iter().for_each(|(key, value_goal)| {
+            match state_current.entries.get(key) {
+                Some(value_current) if value_current == value_goal => {}
+                Some(value_current) => {
+                    state_diff
+                        .modified
+                        .insert(key.clone(), (value_current.clone(), value_goal.clone()));
+                }
+                None => {
+                    state_diff.added.insert(key.clone(), value_goal.clone());
+                }
+            }
+        });
+        state_current
+            .entries
+            .iter()
+            .filter(|(key, _value)| !state_goal.entries.contains_key(*key))
+            .for_each(|(key, value_current)| {
+                state_diff
+                    .removed
+                    .insert(key.clone(), value_current.clone());
+            });
+
+        Ok(state_diff)
+    }
+}
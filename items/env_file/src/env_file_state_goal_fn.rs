@@ -0,0 +1,34 @@
+use std::marker::PhantomData;
+
+use peace::{cfg::FnCtx, params::Params};
+
+use crate::{EnvFileData, EnvFileError, EnvFileParams, EnvFileState};
+
+/// Reads the goal state of the managed keys in the `.env` file.
+///
+/// The goal is for each managed key to be present with the given value.
+#[derive(Debug)]
+pub struct EnvFileStateGoalFn<Id>(PhantomData<Id>);
+
+impl<Id> EnvFileStateGoalFn<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn try_state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<EnvFileParams<Id> as Params>::Partial,
+        _data: EnvFileData<'_, Id>,
+    ) -> Result<Option<EnvFileState>, EnvFileError> {
+        Ok(params_partial
+            .entries()
+            .map(|entries| EnvFileState::new(entries.clone())))
+    }
+
+    pub async fn state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params: &EnvFileParams<Id>,
+        _data: EnvFileData<'_, Id>,
+    ) -> Result<EnvFileState, EnvFileError> {
+        Ok(EnvFileState::new(params.entries().clone()))
+    }
+}
@@ -0,0 +1,35 @@
+//! Manages keys in a `.env` file for the peace framework.
+//!
+//! This item ensures a `.env` style file contains the given keys and values.
+//! Other keys, comments, and blank lines in the file are left untouched.
+//!
+//! Cleaning this item removes the managed keys from the file. The file itself
+//! is not removed, as it may contain keys that are not managed by this item.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+pub use crate::{
+    env_file::EnvFile,
+    env_file_apply_fns::EnvFileApplyFns,
+    env_file_data::EnvFileData,
+    env_file_error::EnvFileError,
+    env_file_item::EnvFileItem,
+    env_file_params::{EnvFileParams, EnvFileParamsFieldWise, EnvFileParamsPartial},
+    env_file_state::EnvFileState,
+    env_file_state_current_fn::EnvFileStateCurrentFn,
+    env_file_state_diff::EnvFileStateDiff,
+    env_file_state_diff_fn::EnvFileStateDiffFn,
+    env_file_state_goal_fn::EnvFileStateGoalFn,
+};
+
+mod env_file;
+mod env_file_apply_fns;
+mod env_file_data;
+mod env_file_error;
+mod env_file_item;
+mod env_file_params;
+mod env_file_state;
+mod env_file_state_current_fn;
+mod env_file_state_diff;
+mod env_file_state_diff_fn;
+mod env_file_state_goal_fn;
@@ -0,0 +1,32 @@
+#[cfg(feature = "error_reporting")]
+use peace::miette;
+
+use crate::FailPhase;
+
+/// Error while managing the always fail item.
+#[cfg_attr(feature = "error_reporting", derive(peace::miette::Diagnostic))]
+#[derive(Debug, thiserror::Error)]
+pub enum AlwaysFailError {
+    /// The item failed in its configured phase.
+    #[error("Failed in `{fail_phase}` as configured.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_fail::fail_phase_reached),
+            help("This item always fails; change its `fail_phase` parameter to fail in a different phase.")
+        )
+    )]
+    FailPhaseReached {
+        /// Phase in which the item failed.
+        fail_phase: FailPhase,
+    },
+
+    /// A `peace` runtime error occurred.
+    #[error("A `peace` runtime error occurred.")]
+    PeaceRtError(
+        #[cfg_attr(feature = "error_reporting", diagnostic_source)]
+        #[source]
+        #[from]
+        peace::rt_model::Error,
+    ),
+}
@@ -0,0 +1,212 @@
+use std::{marker::PhantomData, time::Duration};
+
+#[cfg(feature = "output_progress")]
+use peace::cfg::progress::ProgressLimit;
+use peace::{
+    cfg::{async_trait, ApplyCheck, ApplyCtx, FnCtx, Item, ItemId},
+    params::Params,
+    resources::{resources::ts::Empty, Resources},
+};
+
+use crate::{AlwaysFailError, AlwaysFailParams, AlwaysFailState, AlwaysFailStateDiff, FailPhase};
+
+/// Item that always fails in a configured phase.
+///
+/// The `Id` type parameter is needed for each always fail params to be a
+/// distinct type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different always fail
+///   parameters from each other.
+#[derive(Debug)]
+pub struct AlwaysFailItem<Id> {
+    /// ID of the always fail item.
+    item_id: ItemId,
+    /// Marker for unique always fail parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> AlwaysFailItem<Id> {
+    /// Returns a new `AlwaysFailItem`.
+    pub fn new(item_id: ItemId) -> Self {
+        Self {
+            item_id,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<Id> Clone for AlwaysFailItem<Id> {
+    fn clone(&self) -> Self {
+        Self {
+            item_id: self.item_id.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<Id> Item for AlwaysFailItem<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    type Data<'exec> = ();
+    type Error = AlwaysFailError;
+    type Params<'exec> = AlwaysFailParams<Id>;
+    type State = AlwaysFailState;
+    type StateDiff = AlwaysFailStateDiff;
+
+    fn id(&self) -> &ItemId {
+        &self.item_id
+    }
+
+    async fn setup(&self, _resources: &mut Resources<Empty>) -> Result<(), AlwaysFailError> {
+        Ok(())
+    }
+
+    async fn try_state_current(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+    ) -> Result<Option<Self::State>, AlwaysFailError> {
+        delay(params_partial.delay.unwrap_or_default()).await;
+        fail_if(params_partial.fail_phase, FailPhase::StateCurrent)?;
+        Ok(Some(AlwaysFailState::Unchanged))
+    }
+
+    async fn state_current(
+        _fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        _data: Self::Data<'_>,
+    ) -> Result<Self::State, AlwaysFailError> {
+        delay(params.delay).await;
+        fail_if(Some(params.fail_phase), FailPhase::StateCurrent)?;
+        Ok(AlwaysFailState::Unchanged)
+    }
+
+    async fn try_state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+    ) -> Result<Option<Self::State>, AlwaysFailError> {
+        delay(params_partial.delay.unwrap_or_default()).await;
+        fail_if(params_partial.fail_phase, FailPhase::StateGoal)?;
+        Ok(Some(AlwaysFailState::Unchanged))
+    }
+
+    async fn state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        _data: Self::Data<'_>,
+    ) -> Result<Self::State, AlwaysFailError> {
+        delay(params.delay).await;
+        fail_if(Some(params.fail_phase), FailPhase::StateGoal)?;
+        Ok(AlwaysFailState::Unchanged)
+    }
+
+    async fn state_diff(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+        _state_current: &AlwaysFailState,
+        _state_goal: &AlwaysFailState,
+    ) -> Result<Self::StateDiff, AlwaysFailError> {
+        Ok(AlwaysFailStateDiff)
+    }
+
+    async fn state_clean(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+    ) -> Result<AlwaysFailState, AlwaysFailError> {
+        Ok(AlwaysFailState::Unchanged)
+    }
+
+    async fn apply_check(
+        _apply_ctx: ApplyCtx<'_>,
+        params: &Self::Params<'_>,
+        _data: Self::Data<'_>,
+        _state_current: &Self::State,
+        _state_target: &Self::State,
+        _diff: &Self::StateDiff,
+    ) -> Result<ApplyCheck, Self::Error> {
+        delay(params.delay).await;
+        fail_if(Some(params.fail_phase), FailPhase::ApplyCheck)?;
+
+        let apply_check = match params.fail_phase {
+            FailPhase::StateCurrent | FailPhase::StateGoal | FailPhase::ApplyCheck => {
+                ApplyCheck::ExecNotRequired
+            }
+            FailPhase::Apply => {
+                #[cfg(not(feature = "output_progress"))]
+                {
+                    ApplyCheck::ExecRequired { reason: None }
+                }
+                #[cfg(feature = "output_progress")]
+                {
+                    let progress_limit = ProgressLimit::Steps(1);
+                    ApplyCheck::ExecRequired {
+                        progress_limit,
+                        reason: None,
+                    }
+                }
+            }
+        };
+
+        Ok(apply_check)
+    }
+
+    async fn apply_dry(
+        _fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        _data: Self::Data<'_>,
+        _state_current: &Self::State,
+        state_target: &Self::State,
+        _diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        delay(params.delay).await;
+        fail_if(Some(params.fail_phase), FailPhase::Apply)?;
+        Ok(*state_target)
+    }
+
+    async fn apply(
+        _fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        _data: Self::Data<'_>,
+        _state_current: &Self::State,
+        state_target: &Self::State,
+        _diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        delay(params.delay).await;
+        fail_if(Some(params.fail_phase), FailPhase::Apply)?;
+        Ok(*state_target)
+    }
+
+    fn error_help(error: &AlwaysFailError) -> Vec<String> {
+        match error {
+            AlwaysFailError::FailPhaseReached { .. } => vec![String::from(
+                "This item always fails; change its `fail_phase` parameter to fail in a \
+                different phase.",
+            )],
+            AlwaysFailError::PeaceRtError(_) => Vec::new(),
+        }
+    }
+}
+
+/// Returns an error if `fail_phase` is the `phase` being executed.
+fn fail_if(fail_phase: Option<FailPhase>, phase: FailPhase) -> Result<(), AlwaysFailError> {
+    if fail_phase == Some(phase) {
+        Err(AlwaysFailError::FailPhaseReached { fail_phase: phase })
+    } else {
+        Ok(())
+    }
+}
+
+/// Waits for the given duration.
+async fn delay(duration: Duration) {
+    #[cfg(not(target_arch = "wasm32"))]
+    if !duration.is_zero() {
+        tokio::time::sleep(duration).await;
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = duration;
+}
@@ -0,0 +1,39 @@
+use std::{marker::PhantomData, time::Duration};
+
+use derivative::Derivative;
+use peace::params::Params;
+use serde::{Deserialize, Serialize};
+
+use crate::FailPhase;
+
+/// Always fail item parameters.
+///
+/// The `Id` type parameter is needed for each always fail params to be a
+/// distinct type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different always fail
+///   parameters from each other.
+#[derive(Derivative, Params, PartialEq, Eq, Deserialize, Serialize)]
+#[derivative(Clone, Debug)]
+#[serde(bound = "")]
+pub struct AlwaysFailParams<Id> {
+    /// Duration to wait in each phase, including before failing.
+    pub delay: Duration,
+    /// Phase in which the item fails.
+    pub fail_phase: FailPhase,
+    /// Marker for unique always fail parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> AlwaysFailParams<Id> {
+    /// Returns new `AlwaysFailParams`.
+    pub fn new(delay: Duration, fail_phase: FailPhase) -> Self {
+        Self {
+            delay,
+            fail_phase,
+            marker: PhantomData,
+        }
+    }
+}
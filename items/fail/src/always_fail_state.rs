@@ -0,0 +1,22 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// State of the always fail item, which never changes.
+///
+/// This is an enum instead of a unit struct, as unit structs are serialized
+/// as `null`, which is read back as the state not being stored.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AlwaysFailState {
+    /// Nothing is changed by this item.
+    Unchanged,
+}
+
+impl fmt::Display for AlwaysFailState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlwaysFailState::Unchanged => "unchanged".fmt(f),
+        }
+    }
+}
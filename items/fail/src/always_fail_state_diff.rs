@@ -0,0 +1,13 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Diff between current and goal always fail states, which are always in sync.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct AlwaysFailStateDiff;
+
+impl fmt::Display for AlwaysFailStateDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        "in sync".fmt(f)
+    }
+}
@@ -0,0 +1,33 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Phase in which an [`AlwaysFailItem`] fails.
+///
+/// [`AlwaysFailItem`]: crate::AlwaysFailItem
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FailPhase {
+    /// Fails when discovering the current state.
+    StateCurrent,
+    /// Fails when discovering the goal state.
+    StateGoal,
+    /// Fails when checking whether the item needs to be applied.
+    ApplyCheck,
+    /// Fails when applying the item, including in dry runs.
+    ///
+    /// The item is always considered to need applying, so that this phase
+    /// is reached.
+    Apply,
+}
+
+impl fmt::Display for FailPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FailPhase::StateCurrent => "state_current".fmt(f),
+            FailPhase::StateGoal => "state_goal".fmt(f),
+            FailPhase::ApplyCheck => "apply_check".fmt(f),
+            FailPhase::Apply => "apply".fmt(f),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//! Item that always fails, for exercising error handling.
+//!
+//! The item fails in the phase configured in its parameters, after waiting
+//! for the configured delay. Functions in phases before the failure phase
+//! succeed, so that the failure is reached with the framework in the same
+//! state as for a real item.
+//!
+//! The delay is not applied when compiled to WASM.
+
+pub use crate::{
+    always_fail_error::AlwaysFailError,
+    always_fail_item::AlwaysFailItem,
+    always_fail_params::{AlwaysFailParams, AlwaysFailParamsFieldWise, AlwaysFailParamsPartial},
+    always_fail_state::AlwaysFailState,
+    always_fail_state_diff::AlwaysFailStateDiff,
+    fail_phase::FailPhase,
+};
+
+mod always_fail_error;
+mod always_fail_item;
+mod always_fail_params;
+mod always_fail_state;
+mod always_fail_state_diff;
+mod fail_phase;
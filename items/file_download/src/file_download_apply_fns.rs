@@ -13,7 +13,10 @@ cfg_if::cfg_if! {
     }
 }
 
-use peace::cfg::{state::FetchedOpt, ApplyCheck, FnCtx, State};
+use peace::{
+    cfg::{state::FetchedOpt, ApplyCheck, ApplyCheckReason, FnCtx, State},
+    diff::Equality,
+};
 use reqwest::header::ETAG;
 
 use crate::{
@@ -249,13 +252,23 @@ where
     ) -> Result<ApplyCheck, FileDownloadError> {
         let apply_check = match diff {
             FileDownloadStateDiff::Change {
-                #[cfg(feature = "output_progress")]
+                path: _,
                 byte_len,
-                ..
+                contents,
             } => {
+                let fields_changed = [
+                    ("byte_len", byte_len.equality()),
+                    ("contents", contents.equality()),
+                ]
+                .into_iter()
+                .filter_map(|(field, equality)| (equality != Equality::Equal).then_some(field));
+                let reason = ApplyCheckReason::new("file_changed").with_fields(fields_changed);
+
                 #[cfg(not(feature = "output_progress"))]
                 {
-                    ApplyCheck::ExecRequired
+                    ApplyCheck::ExecRequired {
+                        reason: Some(reason),
+                    }
                 }
 
                 #[cfg(feature = "output_progress")]
@@ -269,7 +282,10 @@ where
                         Tracked::Unknown => ProgressLimit::Unknown,
                     };
 
-                    ApplyCheck::ExecRequired { progress_limit }
+                    ApplyCheck::ExecRequired {
+                        progress_limit,
+                        reason: Some(reason),
+                    }
                 }
             }
             FileDownloadStateDiff::Deleted { .. } => match file_state_current {
@@ -283,7 +299,7 @@ where
                 } => {
                     #[cfg(not(feature = "output_progress"))]
                     {
-                        ApplyCheck::ExecRequired
+                        ApplyCheck::ExecRequired { reason: None }
                     }
                     #[cfg(feature = "output_progress")]
                     {
@@ -291,6 +307,7 @@ where
                             progress_limit: ProgressLimit::Bytes(
                                 contents.as_bytes().len().try_into().unwrap(),
                             ),
+                            reason: None,
                         }
                     }
                 }
@@ -303,23 +320,25 @@ where
                 } => {
                     #[cfg(not(feature = "output_progress"))]
                     {
-                        ApplyCheck::ExecRequired
+                        ApplyCheck::ExecRequired { reason: None }
                     }
 
                     #[cfg(feature = "output_progress")]
                     ApplyCheck::ExecRequired {
                         progress_limit: ProgressLimit::Bytes(*byte_count),
+                        reason: None,
                     }
                 }
                 FileDownloadState::Unknown { path: _ } => {
                     #[cfg(not(feature = "output_progress"))]
                     {
-                        ApplyCheck::ExecRequired
+                        ApplyCheck::ExecRequired { reason: None }
                     }
 
                     #[cfg(feature = "output_progress")]
                     ApplyCheck::ExecRequired {
                         progress_limit: ProgressLimit::Unknown,
+                        reason: None,
                     }
                 }
             },
@@ -1,7 +1,7 @@
 use std::{marker::PhantomData, path::Path};
 
 use peace::{
-    cfg::{async_trait, state::FetchedOpt, ApplyCheck, FnCtx, Item, ItemId, State},
+    cfg::{async_trait, state::FetchedOpt, ApplyCheck, ApplyCtx, FnCtx, Item, ItemId, State},
     params::Params,
     resources::{resources::ts::Empty, Resources},
 };
@@ -120,6 +120,7 @@ where
     }
 
     async fn apply_check(
+        _apply_ctx: ApplyCtx<'_>,
         params: &Self::Params<'_>,
         data: Self::Data<'_>,
         state_current: &Self::State,
@@ -0,0 +1,103 @@
+use std::marker::PhantomData;
+
+#[cfg(feature = "output_progress")]
+use peace::cfg::progress::{ProgressLimit, ProgressMsgUpdate};
+use peace::cfg::{ApplyCheck, FnCtx};
+
+use crate::{
+    HttpResourceClient, HttpResourceData, HttpResourceError, HttpResourceParams, HttpResourceState,
+    HttpResourceStateDiff,
+};
+
+/// ApplyFns for the HTTP resource.
+#[derive(Debug)]
+pub struct HttpResourceApplyFns<Id>(PhantomData<Id>);
+
+impl<Id> HttpResourceApplyFns<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn apply_check(
+        _params: &HttpResourceParams<Id>,
+        _data: HttpResourceData<'_, Id>,
+        _state_current: &HttpResourceState,
+        _state_target: &HttpResourceState,
+        diff: &HttpResourceStateDiff,
+    ) -> Result<ApplyCheck, HttpResourceError> {
+        let apply_check = match diff {
+            HttpResourceStateDiff::InSync { .. } => ApplyCheck::ExecNotRequired,
+            HttpResourceStateDiff::Added
+            | HttpResourceStateDiff::Removed
+            | HttpResourceStateDiff::Modified { .. } => {
+                #[cfg(not(feature = "output_progress"))]
+                {
+                    ApplyCheck::ExecRequired { reason: None }
+                }
+                #[cfg(feature = "output_progress")]
+                {
+                    ApplyCheck::ExecRequired {
+                        progress_limit: ProgressLimit::Steps(1),
+                        reason: None,
+                    }
+                }
+            }
+        };
+
+        Ok(apply_check)
+    }
+
+    pub async fn apply_dry(
+        _fn_ctx: FnCtx<'_>,
+        _params: &HttpResourceParams<Id>,
+        _data: HttpResourceData<'_, Id>,
+        _state_current: &HttpResourceState,
+        state_target: &HttpResourceState,
+        _diff: &HttpResourceStateDiff,
+    ) -> Result<HttpResourceState, HttpResourceError> {
+        Ok(state_target.clone())
+    }
+
+    pub async fn apply(
+        #[cfg(not(feature = "output_progress"))] _fn_ctx: FnCtx<'_>,
+        #[cfg(feature = "output_progress")] fn_ctx: FnCtx<'_>,
+        params: &HttpResourceParams<Id>,
+        data: HttpResourceData<'_, Id>,
+        _state_current: &HttpResourceState,
+        state_target: &HttpResourceState,
+        _diff: &HttpResourceStateDiff,
+    ) -> Result<HttpResourceState, HttpResourceError> {
+        let client = data.client();
+        let url = params.url();
+        let auth_header = params.auth_header();
+
+        match state_target {
+            HttpResourceState::Exists { body } => {
+                let apply_method = params.apply_method();
+                HttpResourceClient::apply(client, url, auth_header, apply_method, body).await?;
+
+                #[cfg(feature = "output_progress")]
+                fn_ctx
+                    .progress_sender
+                    .inc(1, ProgressMsgUpdate::Set(format!("{apply_method} {url}")));
+
+                // Read the resource back, as the server may add fields to the body.
+                let state = match HttpResourceClient::get(client, url, auth_header).await? {
+                    Some(body) => HttpResourceState::Exists { body },
+                    None => HttpResourceState::NotExists,
+                };
+
+                Ok(state)
+            }
+            HttpResourceState::NotExists => {
+                HttpResourceClient::delete(client, url, auth_header).await?;
+
+                #[cfg(feature = "output_progress")]
+                fn_ctx
+                    .progress_sender
+                    .inc(1, ProgressMsgUpdate::Set(format!("DELETE {url}")));
+
+                Ok(HttpResourceState::NotExists)
+            }
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// HTTP method used to create or update the resource.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub enum HttpResourceApplyMethod {
+    /// Sends a `PUT` request to the resource URL.
+    #[default]
+    Put,
+    /// Sends a `POST` request to the resource URL.
+    Post,
+}
+
+impl HttpResourceApplyMethod {
+    /// Returns the `reqwest::Method` for this apply method.
+    pub fn method(self) -> reqwest::Method {
+        match self {
+            Self::Put => reqwest::Method::PUT,
+            Self::Post => reqwest::Method::POST,
+        }
+    }
+}
+
+impl fmt::Display for HttpResourceApplyMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Put => "PUT".fmt(f),
+            Self::Post => "POST".fmt(f),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Header sent with every request to authenticate with the API.
+///
+/// The header value is not shown in the `Debug` representation.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct HttpResourceAuthHeader {
+    /// Name of the header, e.g. `Authorization`.
+    name: String,
+    /// Value of the header, e.g. `Bearer <token>`.
+    value: String,
+}
+
+impl HttpResourceAuthHeader {
+    /// Returns a new `HttpResourceAuthHeader`.
+    pub fn new(name: String, value: String) -> Self {
+        Self { name, value }
+    }
+
+    /// Returns a new `Authorization: Bearer <token>` header.
+    pub fn bearer(token: &str) -> Self {
+        Self {
+            name: String::from("Authorization"),
+            value: format!("Bearer {token}"),
+        }
+    }
+
+    /// Returns the name of the header.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the value of the header.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Debug for HttpResourceAuthHeader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpResourceAuthHeader")
+            .field("name", &self.name)
+            .field("value", &"..")
+            .finish()
+    }
+}
@@ -0,0 +1,131 @@
+use serde_json::Value;
+use url::Url;
+
+use crate::{HttpResourceApplyMethod, HttpResourceAuthHeader, HttpResourceError};
+
+/// Sends requests to read and write a REST resource.
+#[derive(Debug)]
+pub(crate) struct HttpResourceClient;
+
+impl HttpResourceClient {
+    /// Returns the resource body, or `None` if the server responds with
+    /// `404 Not Found`.
+    pub async fn get(
+        client: &reqwest::Client,
+        url: &Url,
+        auth_header: Option<&HttpResourceAuthHeader>,
+    ) -> Result<Option<Value>, HttpResourceError> {
+        let method = reqwest::Method::GET;
+        let response = Self::request_send(client, method.clone(), url, auth_header, None).await?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let response = Self::response_check(method, url, response).await?;
+        let response_bytes =
+            response
+                .bytes()
+                .await
+                .map_err(|error| HttpResourceError::ResponseRead {
+                    url: url.clone(),
+                    error,
+                })?;
+        let body = serde_json::from_slice::<Value>(&response_bytes).map_err(|error| {
+            HttpResourceError::ResponseBodyDeserialize {
+                url: url.clone(),
+                error,
+            }
+        })?;
+
+        Ok(Some(body))
+    }
+
+    /// Sends the body to the resource URL using the apply method.
+    pub async fn apply(
+        client: &reqwest::Client,
+        url: &Url,
+        auth_header: Option<&HttpResourceAuthHeader>,
+        apply_method: HttpResourceApplyMethod,
+        body: &Value,
+    ) -> Result<(), HttpResourceError> {
+        let body =
+            serde_json::to_vec(body).map_err(|error| HttpResourceError::RequestBodySerialize {
+                url: url.clone(),
+                error,
+            })?;
+        let method = apply_method.method();
+        let response =
+            Self::request_send(client, method.clone(), url, auth_header, Some(body)).await?;
+        Self::response_check(method, url, response).await?;
+
+        Ok(())
+    }
+
+    /// Deletes the resource.
+    ///
+    /// `404 Not Found` is treated as success, as the resource is already
+    /// deleted.
+    pub async fn delete(
+        client: &reqwest::Client,
+        url: &Url,
+        auth_header: Option<&HttpResourceAuthHeader>,
+    ) -> Result<(), HttpResourceError> {
+        let method = reqwest::Method::DELETE;
+        let response = Self::request_send(client, method.clone(), url, auth_header, None).await?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(());
+        }
+        Self::response_check(method, url, response).await?;
+
+        Ok(())
+    }
+
+    async fn request_send(
+        client: &reqwest::Client,
+        method: reqwest::Method,
+        url: &Url,
+        auth_header: Option<&HttpResourceAuthHeader>,
+        body: Option<Vec<u8>>,
+    ) -> Result<reqwest::Response, HttpResourceError> {
+        let mut request_builder = client
+            .request(method.clone(), url.clone())
+            .header(reqwest::header::ACCEPT, "application/json");
+        if let Some(auth_header) = auth_header {
+            request_builder = request_builder.header(auth_header.name(), auth_header.value());
+        }
+        if let Some(body) = body {
+            request_builder = request_builder
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body);
+        }
+
+        request_builder
+            .send()
+            .await
+            .map_err(|error| HttpResourceError::RequestSend {
+                method,
+                url: url.clone(),
+                error,
+            })
+    }
+
+    /// Returns an error if the response status is not successful.
+    async fn response_check(
+        method: reqwest::Method,
+        url: &Url,
+        response: reqwest::Response,
+    ) -> Result<reqwest::Response, HttpResourceError> {
+        let status = response.status();
+        if status.is_success() {
+            Ok(response)
+        } else {
+            let response_body = response.text().await.unwrap_or_default();
+            Err(HttpResourceError::ResponseStatus {
+                method,
+                url: url.clone(),
+                status,
+                response_body,
+            })
+        }
+    }
+}
@@ -0,0 +1,102 @@
+#[cfg(feature = "error_reporting")]
+use peace::miette;
+use url::Url;
+
+/// Error while reading or writing a REST resource.
+#[cfg_attr(feature = "error_reporting", derive(peace::miette::Diagnostic))]
+#[derive(Debug, thiserror::Error)]
+pub enum HttpResourceError {
+    /// Failed to serialize the request body.
+    #[error("Failed to serialize the request body for `{url}`.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_http_resource::request_body_serialize))
+    )]
+    RequestBodySerialize {
+        /// URL of the resource.
+        url: Url,
+        /// Underlying error.
+        #[source]
+        error: serde_json::Error,
+    },
+
+    /// Failed to send a request to the server.
+    #[error("Failed to send `{method}` request to `{url}`.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_http_resource::request_send),
+            help("Check that the URL is reachable.")
+        )
+    )]
+    RequestSend {
+        /// HTTP method of the request.
+        method: reqwest::Method,
+        /// URL of the resource.
+        url: Url,
+        /// Underlying error.
+        #[source]
+        error: reqwest::Error,
+    },
+
+    /// Server responded with an unsuccessful status code.
+    #[error("`{method}` request to `{url}` responded with status {status}.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_http_resource::response_status),
+            help("The response body was:\n{response_body}")
+        )
+    )]
+    ResponseStatus {
+        /// HTTP method of the request.
+        method: reqwest::Method,
+        /// URL of the resource.
+        url: Url,
+        /// Status code of the response.
+        status: reqwest::StatusCode,
+        /// Body of the response.
+        response_body: String,
+    },
+
+    /// Failed to read the response body.
+    #[error("Failed to read the response body from `{url}`.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_http_resource::response_read))
+    )]
+    ResponseRead {
+        /// URL of the resource.
+        url: Url,
+        /// Underlying error.
+        #[source]
+        error: reqwest::Error,
+    },
+
+    /// Response body is not valid JSON.
+    #[error("Response body from `{url}` is not valid JSON.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_http_resource::response_body_deserialize),
+            help("Check that the URL refers to a JSON resource.")
+        )
+    )]
+    ResponseBodyDeserialize {
+        /// URL of the resource.
+        url: Url,
+        /// Underlying error.
+        #[source]
+        error: serde_json::Error,
+    },
+
+    // === Framework errors === //
+    /// A `peace` runtime error occurred.
+    #[error("A `peace` runtime error occurred.")]
+    PeaceRtError(
+        #[cfg_attr(feature = "error_reporting", diagnostic_source)]
+        #[source]
+        #[from]
+        peace::rt_model::Error,
+    ),
+}
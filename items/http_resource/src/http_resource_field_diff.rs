@@ -0,0 +1,59 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A field whose current value differs from its desired value.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct HttpResourceFieldDiff {
+    /// JSON pointer to the field, e.g. `/spec/replicas`.
+    pointer: String,
+    /// Current value of the field, `None` if the field does not exist.
+    value_current: Option<Value>,
+    /// Desired value of the field, `None` if the field does not exist.
+    value_goal: Option<Value>,
+}
+
+impl HttpResourceFieldDiff {
+    /// Returns a new `HttpResourceFieldDiff`.
+    pub fn new(pointer: String, value_current: Option<Value>, value_goal: Option<Value>) -> Self {
+        Self {
+            pointer,
+            value_current,
+            value_goal,
+        }
+    }
+
+    /// Returns the JSON pointer to the field.
+    pub fn pointer(&self) -> &str {
+        &self.pointer
+    }
+
+    /// Returns the current value of the field.
+    pub fn value_current(&self) -> Option<&Value> {
+        self.value_current.as_ref()
+    }
+
+    /// Returns the desired value of the field.
+    pub fn value_goal(&self) -> Option<&Value> {
+        self.value_goal.as_ref()
+    }
+}
+
+impl fmt::Display for HttpResourceFieldDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pointer = if self.pointer.is_empty() {
+            "/"
+        } else {
+            self.pointer.as_str()
+        };
+        match (&self.value_current, &self.value_goal) {
+            (Some(value_current), Some(value_goal)) => {
+                write!(f, "`{pointer}`: {value_current} -> {value_goal}")
+            }
+            (None, Some(value_goal)) => write!(f, "`{pointer}`: (none) -> {value_goal}"),
+            (Some(value_current), None) => write!(f, "`{pointer}`: {value_current} -> (none)"),
+            (None, None) => write!(f, "`{pointer}`: (none)"),
+        }
+    }
+}
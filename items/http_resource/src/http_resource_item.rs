@@ -0,0 +1,176 @@
+use std::marker::PhantomData;
+
+use peace::{
+    cfg::{async_trait, ApplyCheck, ApplyCtx, FnCtx, Item, ItemId},
+    params::Params,
+    resources::{resources::ts::Empty, Resources},
+};
+
+use crate::{
+    HttpResourceApplyFns, HttpResourceData, HttpResourceError, HttpResourceParams,
+    HttpResourceState, HttpResourceStateCurrentFn, HttpResourceStateDiff, HttpResourceStateDiffFn,
+};
+
+/// Item for ensuring a REST resource matches a desired JSON body.
+///
+/// The current state is read with a `GET` request. Ensuring this item sends
+/// the desired body with a `PUT` or `POST` request, and cleaning this item
+/// sends a `DELETE` request.
+///
+/// The `Id` type parameter is needed for each HTTP resource params to be a
+/// distinct type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different HTTP resource
+///   parameters from each other.
+#[derive(Debug)]
+pub struct HttpResourceItem<Id> {
+    /// ID of the item to manage the REST resource.
+    item_id: ItemId,
+    /// Marker for unique HTTP resource parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> Clone for HttpResourceItem<Id> {
+    fn clone(&self) -> Self {
+        Self {
+            item_id: self.item_id.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<Id> HttpResourceItem<Id> {
+    /// Returns a new `HttpResourceItem`.
+    pub fn new(item_id: ItemId) -> Self {
+        Self {
+            item_id,
+            marker: PhantomData,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<Id> Item for HttpResourceItem<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    type Data<'exec> = HttpResourceData<'exec, Id>;
+    type Error = HttpResourceError;
+    type Params<'exec> = HttpResourceParams<Id>;
+    type State = HttpResourceState;
+    type StateDiff = HttpResourceStateDiff;
+
+    fn id(&self) -> &ItemId {
+        &self.item_id
+    }
+
+    async fn setup(&self, resources: &mut Resources<Empty>) -> Result<(), HttpResourceError> {
+        resources.insert::<reqwest::Client>(reqwest::Client::new());
+
+        Ok(())
+    }
+
+    async fn try_state_current(
+        fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        data: HttpResourceData<'_, Id>,
+    ) -> Result<Option<Self::State>, HttpResourceError> {
+        HttpResourceStateCurrentFn::try_state_current(fn_ctx, params_partial, data).await
+    }
+
+    async fn state_current(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: HttpResourceData<'_, Id>,
+    ) -> Result<Self::State, HttpResourceError> {
+        HttpResourceStateCurrentFn::state_current(fn_ctx, params, data).await
+    }
+
+    async fn try_state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: HttpResourceData<'_, Id>,
+    ) -> Result<Option<Self::State>, HttpResourceError> {
+        let state_goal = params_partial
+            .body()
+            .map(|body| HttpResourceState::Exists { body: body.clone() });
+
+        Ok(state_goal)
+    }
+
+    async fn state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        _data: HttpResourceData<'_, Id>,
+    ) -> Result<Self::State, HttpResourceError> {
+        Ok(HttpResourceState::Exists {
+            body: params.body().clone(),
+        })
+    }
+
+    async fn state_diff(
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_goal: &Self::State,
+    ) -> Result<Self::StateDiff, HttpResourceError> {
+        let diff_pointers = params_partial
+            .diff_pointers()
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        HttpResourceStateDiffFn::state_diff(diff_pointers, state_current, state_goal).await
+    }
+
+    async fn state_clean(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+    ) -> Result<Self::State, HttpResourceError> {
+        Ok(HttpResourceState::NotExists)
+    }
+
+    async fn apply_check(
+        _apply_ctx: ApplyCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<ApplyCheck, Self::Error> {
+        HttpResourceApplyFns::<Id>::apply_check(params, data, state_current, state_target, diff)
+            .await
+    }
+
+    async fn apply_dry(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        HttpResourceApplyFns::<Id>::apply_dry(
+            fn_ctx,
+            params,
+            data,
+            state_current,
+            state_target,
+            diff,
+        )
+        .await
+    }
+
+    async fn apply(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        HttpResourceApplyFns::<Id>::apply(fn_ctx, params, data, state_current, state_target, diff)
+            .await
+    }
+}
@@ -0,0 +1,88 @@
+use std::marker::PhantomData;
+
+use derivative::Derivative;
+use peace::params::Params;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use url::Url;
+
+use crate::{HttpResourceApplyMethod, HttpResourceAuthHeader};
+
+/// HTTP resource parameters.
+///
+/// The `Id` type parameter is needed for each HTTP resource params to be a
+/// distinct type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different HTTP resource
+///   parameters from each other.
+#[derive(Derivative, Params, PartialEq, Eq, Deserialize, Serialize)]
+#[derivative(Clone, Debug)]
+#[serde(bound = "")]
+pub struct HttpResourceParams<Id> {
+    /// URL of the resource.
+    ///
+    /// This is used for `GET`, `PUT` / `POST`, and `DELETE` requests.
+    url: Url,
+    /// HTTP method used to create or update the resource.
+    apply_method: HttpResourceApplyMethod,
+    /// Desired JSON body of the resource.
+    body: Value,
+    /// Header sent with every request to authenticate with the API.
+    auth_header: Option<HttpResourceAuthHeader>,
+    /// JSON pointers to the fields to compare, e.g. `/spec/replicas`.
+    ///
+    /// If empty, all fields in the desired body are compared.
+    diff_pointers: Vec<String>,
+    /// Marker for unique HTTP resource parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> HttpResourceParams<Id> {
+    /// Returns new `HttpResourceParams`.
+    pub fn new(
+        url: Url,
+        apply_method: HttpResourceApplyMethod,
+        body: Value,
+        auth_header: Option<HttpResourceAuthHeader>,
+        diff_pointers: Vec<String>,
+    ) -> Self {
+        Self {
+            url,
+            apply_method,
+            body,
+            auth_header,
+            diff_pointers,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the URL of the resource.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the HTTP method used to create or update the resource.
+    pub fn apply_method(&self) -> HttpResourceApplyMethod {
+        self.apply_method
+    }
+
+    /// Returns the desired JSON body of the resource.
+    pub fn body(&self) -> &Value {
+        &self.body
+    }
+
+    /// Returns the header sent with every request to authenticate with the
+    /// API.
+    pub fn auth_header(&self) -> Option<&HttpResourceAuthHeader> {
+        self.auth_header.as_ref()
+    }
+
+    /// Returns the JSON pointers to the fields to compare.
+    ///
+    /// If empty, all fields in the desired body are compared.
+    pub fn diff_pointers(&self) -> &[String] {
+        &self.diff_pointers
+    }
+}
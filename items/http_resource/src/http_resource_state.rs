@@ -0,0 +1,25 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Whether the resource exists, and its JSON body.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum HttpResourceState {
+    /// Resource does not exist.
+    NotExists,
+    /// Resource exists with the given body.
+    Exists {
+        /// JSON body of the resource.
+        body: Value,
+    },
+}
+
+impl fmt::Display for HttpResourceState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotExists => "not exists".fmt(f),
+            Self::Exists { body } => write!(f, "exists: {body}"),
+        }
+    }
+}
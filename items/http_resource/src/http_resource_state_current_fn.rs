@@ -0,0 +1,57 @@
+use std::marker::PhantomData;
+
+use peace::{cfg::FnCtx, params::Params};
+use url::Url;
+
+use crate::{
+    HttpResourceAuthHeader, HttpResourceClient, HttpResourceData, HttpResourceError,
+    HttpResourceParams, HttpResourceState,
+};
+
+/// Reads the current state of the resource.
+///
+/// The resource does not exist if the server responds to the `GET` request
+/// with `404 Not Found`.
+#[derive(Debug)]
+pub struct HttpResourceStateCurrentFn<Id>(PhantomData<Id>);
+
+impl<Id> HttpResourceStateCurrentFn<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn try_state_current(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<HttpResourceParams<Id> as Params>::Partial,
+        data: HttpResourceData<'_, Id>,
+    ) -> Result<Option<HttpResourceState>, HttpResourceError> {
+        match (params_partial.url(), params_partial.auth_header()) {
+            (Some(url), Some(auth_header)) => {
+                let state =
+                    Self::state_current_read(data.client(), url, auth_header.as_ref()).await?;
+                Ok(Some(state))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    pub async fn state_current(
+        _fn_ctx: FnCtx<'_>,
+        params: &HttpResourceParams<Id>,
+        data: HttpResourceData<'_, Id>,
+    ) -> Result<HttpResourceState, HttpResourceError> {
+        Self::state_current_read(data.client(), params.url(), params.auth_header()).await
+    }
+
+    async fn state_current_read(
+        client: &reqwest::Client,
+        url: &Url,
+        auth_header: Option<&HttpResourceAuthHeader>,
+    ) -> Result<HttpResourceState, HttpResourceError> {
+        let state = match HttpResourceClient::get(client, url, auth_header).await? {
+            Some(body) => HttpResourceState::Exists { body },
+            None => HttpResourceState::NotExists,
+        };
+
+        Ok(state)
+    }
+}
@@ -0,0 +1,46 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{HttpResourceFieldDiff, HttpResourceState};
+
+/// Diff between the current and target resource.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum HttpResourceStateDiff {
+    /// Resource matches the target.
+    InSync {
+        /// Current state of the resource.
+        state: HttpResourceState,
+    },
+    /// Resource does not exist, and will be created.
+    Added,
+    /// Resource exists, and will be deleted.
+    Removed,
+    /// Resource exists, but some fields differ from the target.
+    Modified {
+        /// Fields whose current value differs from the target value.
+        field_diffs: Vec<HttpResourceFieldDiff>,
+    },
+}
+
+impl fmt::Display for HttpResourceStateDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InSync { state } => state.fmt(f),
+            Self::Added => write!(f, "will be created"),
+            Self::Removed => write!(f, "will be deleted"),
+            Self::Modified { field_diffs } => {
+                write!(f, "will be updated: ")?;
+                field_diffs
+                    .iter()
+                    .enumerate()
+                    .try_for_each(|(index, field_diff)| {
+                        if index != 0 {
+                            write!(f, ", ")?;
+                        }
+                        field_diff.fmt(f)
+                    })
+            }
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use serde_json::Value;
+
+use crate::{HttpResourceError, HttpResourceFieldDiff, HttpResourceState, HttpResourceStateDiff};
+
+/// HTTP resource state diff function.
+///
+/// Only fields in the target body are compared, so fields that are only in the
+/// current body -- such as server generated IDs -- are ignored.
+#[derive(Debug)]
+pub struct HttpResourceStateDiffFn;
+
+impl HttpResourceStateDiffFn {
+    /// Returns the diff between the current and target resource.
+    ///
+    /// # Parameters
+    ///
+    /// * `diff_pointers`: JSON pointers to the fields to compare. If empty, all
+    ///   fields in the target body are compared.
+    /// * `state_current`: Current state of the resource.
+    /// * `state_target`: Target state of the resource.
+    pub async fn state_diff(
+        diff_pointers: &[String],
+        state_current: &HttpResourceState,
+        state_target: &HttpResourceState,
+    ) -> Result<HttpResourceStateDiff, HttpResourceError> {
+        let state_diff = match (state_current, state_target) {
+            (HttpResourceState::NotExists, HttpResourceState::Exists { .. }) => {
+                HttpResourceStateDiff::Added
+            }
+            (HttpResourceState::Exists { .. }, HttpResourceState::NotExists) => {
+                HttpResourceStateDiff::Removed
+            }
+            (HttpResourceState::NotExists, HttpResourceState::NotExists) => {
+                HttpResourceStateDiff::InSync {
+                    state: state_current.clone(),
+                }
+            }
+            (
+                HttpResourceState::Exists { body: body_current },
+                HttpResourceState::Exists { body: body_target },
+            ) => {
+                let root_pointer = [String::new()];
+                let diff_pointers = if diff_pointers.is_empty() {
+                    &root_pointer[..]
+                } else {
+                    diff_pointers
+                };
+
+                let mut field_diffs = Vec::new();
+                diff_pointers.iter().for_each(|diff_pointer| {
+                    Self::field_diffs_collect(
+                        diff_pointer.clone(),
+                        body_current.pointer(diff_pointer),
+                        body_target.pointer(diff_pointer),
+                        &mut field_diffs,
+                    )
+                });
+
+                if field_diffs.is_empty() {
+                    HttpResourceStateDiff::InSync {
+                        state: state_current.clone(),
+                    }
+                } else {
+                    HttpResourceStateDiff::Modified { field_diffs }
+                }
+            }
+        };
+
+        Ok(state_diff)
+    }
+
+    /// Collects the fields within the target value that differ from the
+    /// current value.
+    fn field_diffs_collect(
+        pointer: String,
+        value_current: Option<&Value>,
+        value_target: Option<&Value>,
+        field_diffs: &mut Vec<HttpResourceFieldDiff>,
+    ) {
+        match (value_current, value_target) {
+            (_, None) => {}
+            (Some(Value::Object(object_current)), Some(Value::Object(object_target))) => {
+                object_target.iter().for_each(|(key, value_target)| {
+                    // Escape the key as per RFC 6901.
+                    let key_escaped = key.replace('~', "~0").replace('/', "~1");
+                    Self::field_diffs_collect(
+                        format!("{pointer}/{key_escaped}"),
+                        object_current.get(key),
+                        Some(value_target),
+                        field_diffs,
+                    )
+                });
+            }
+            (value_current, Some(value_target)) => {
+                if value_current != Some(value_target) {
+                    field_diffs.push(HttpResourceFieldDiff::new(
+                        pointer,
+                        value_current.cloned(),
+                        Some(value_target.clone()),
+                    ));
+                }
+            }
+        }
+    }
+}
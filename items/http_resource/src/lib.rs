@@ -0,0 +1,45 @@
+//! Ensures a REST resource matches a desired JSON body for the peace framework.
+//!
+//! This item is useful for automation targets that are plain REST APIs, where
+//! a resource is read with `GET`, created or updated with `PUT` or `POST`, and
+//! removed with `DELETE`.
+//!
+//! Only the fields in the desired body are compared with the current resource,
+//! so fields generated by the server -- such as IDs and timestamps -- do not
+//! cause the resource to be reapplied. The comparison may be further limited
+//! to particular fields using JSON pointers.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+pub use crate::{
+    http_resource_apply_fns::HttpResourceApplyFns,
+    http_resource_apply_method::HttpResourceApplyMethod,
+    http_resource_auth_header::HttpResourceAuthHeader,
+    http_resource_data::HttpResourceData,
+    http_resource_error::HttpResourceError,
+    http_resource_field_diff::HttpResourceFieldDiff,
+    http_resource_item::HttpResourceItem,
+    http_resource_params::{
+        HttpResourceParams, HttpResourceParamsFieldWise, HttpResourceParamsPartial,
+    },
+    http_resource_state::HttpResourceState,
+    http_resource_state_current_fn::HttpResourceStateCurrentFn,
+    http_resource_state_diff::HttpResourceStateDiff,
+    http_resource_state_diff_fn::HttpResourceStateDiffFn,
+};
+
+pub(crate) use crate::http_resource_client::HttpResourceClient;
+
+mod http_resource_apply_fns;
+mod http_resource_apply_method;
+mod http_resource_auth_header;
+mod http_resource_client;
+mod http_resource_data;
+mod http_resource_error;
+mod http_resource_field_diff;
+mod http_resource_item;
+mod http_resource_params;
+mod http_resource_state;
+mod http_resource_state_current_fn;
+mod http_resource_state_diff;
+mod http_resource_state_diff_fn;
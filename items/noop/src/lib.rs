@@ -0,0 +1,21 @@
+//! Item that does nothing, for exercising flow wiring.
+//!
+//! The item's state is always in sync, so applying it never executes. Each
+//! state discovery waits for the configured delay, which is useful to see how
+//! items are scheduled and how progress is rendered.
+//!
+//! The delay is not applied when compiled to WASM.
+
+pub use crate::{
+    noop_error::NoOpError,
+    noop_item::NoOpItem,
+    noop_params::{NoOpParams, NoOpParamsFieldWise, NoOpParamsPartial},
+    noop_state::NoOpState,
+    noop_state_diff::NoOpStateDiff,
+};
+
+mod noop_error;
+mod noop_item;
+mod noop_params;
+mod noop_state;
+mod noop_state_diff;
@@ -0,0 +1,160 @@
+use std::{marker::PhantomData, time::Duration};
+
+use peace::{
+    cfg::{async_trait, ApplyCheck, ApplyCtx, FnCtx, Item, ItemId},
+    params::Params,
+    resources::{resources::ts::Empty, Resources},
+};
+
+use crate::{NoOpError, NoOpParams, NoOpState, NoOpStateDiff};
+
+/// Item that does nothing.
+///
+/// The `Id` type parameter is needed for each no-op params to be a distinct
+/// type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different no-op parameters
+///   from each other.
+#[derive(Debug)]
+pub struct NoOpItem<Id> {
+    /// ID of the no-op item.
+    item_id: ItemId,
+    /// Marker for unique no-op parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> NoOpItem<Id> {
+    /// Returns a new `NoOpItem`.
+    pub fn new(item_id: ItemId) -> Self {
+        Self {
+            item_id,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<Id> Clone for NoOpItem<Id> {
+    fn clone(&self) -> Self {
+        Self {
+            item_id: self.item_id.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<Id> Item for NoOpItem<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    type Data<'exec> = ();
+    type Error = NoOpError;
+    type Params<'exec> = NoOpParams<Id>;
+    type State = NoOpState;
+    type StateDiff = NoOpStateDiff;
+
+    fn id(&self) -> &ItemId {
+        &self.item_id
+    }
+
+    async fn setup(&self, _resources: &mut Resources<Empty>) -> Result<(), NoOpError> {
+        Ok(())
+    }
+
+    async fn try_state_current(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+    ) -> Result<Option<Self::State>, NoOpError> {
+        delay(params_partial.delay.unwrap_or_default()).await;
+        Ok(Some(NoOpState::Unchanged))
+    }
+
+    async fn state_current(
+        _fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        _data: Self::Data<'_>,
+    ) -> Result<Self::State, NoOpError> {
+        delay(params.delay).await;
+        Ok(NoOpState::Unchanged)
+    }
+
+    async fn try_state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+    ) -> Result<Option<Self::State>, NoOpError> {
+        delay(params_partial.delay.unwrap_or_default()).await;
+        Ok(Some(NoOpState::Unchanged))
+    }
+
+    async fn state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        _data: Self::Data<'_>,
+    ) -> Result<Self::State, NoOpError> {
+        delay(params.delay).await;
+        Ok(NoOpState::Unchanged)
+    }
+
+    async fn state_diff(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+        _state_current: &NoOpState,
+        _state_goal: &NoOpState,
+    ) -> Result<Self::StateDiff, NoOpError> {
+        Ok(NoOpStateDiff)
+    }
+
+    async fn state_clean(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+    ) -> Result<NoOpState, NoOpError> {
+        Ok(NoOpState::Unchanged)
+    }
+
+    async fn apply_check(
+        _apply_ctx: ApplyCtx<'_>,
+        _params: &Self::Params<'_>,
+        _data: Self::Data<'_>,
+        _state_current: &Self::State,
+        _state_target: &Self::State,
+        _diff: &Self::StateDiff,
+    ) -> Result<ApplyCheck, Self::Error> {
+        Ok(ApplyCheck::ExecNotRequired)
+    }
+
+    async fn apply_dry(
+        _fn_ctx: FnCtx<'_>,
+        _params: &Self::Params<'_>,
+        _data: Self::Data<'_>,
+        _state_current: &Self::State,
+        state_target: &Self::State,
+        _diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        Ok(*state_target)
+    }
+
+    async fn apply(
+        _fn_ctx: FnCtx<'_>,
+        _params: &Self::Params<'_>,
+        _data: Self::Data<'_>,
+        _state_current: &Self::State,
+        state_target: &Self::State,
+        _diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        Ok(*state_target)
+    }
+}
+
+/// Waits for the given duration.
+async fn delay(duration: Duration) {
+    #[cfg(not(target_arch = "wasm32"))]
+    if !duration.is_zero() {
+        tokio::time::sleep(duration).await;
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = duration;
+}
@@ -0,0 +1,34 @@
+use std::{marker::PhantomData, time::Duration};
+
+use derivative::Derivative;
+use peace::params::Params;
+use serde::{Deserialize, Serialize};
+
+/// No-op item parameters.
+///
+/// The `Id` type parameter is needed for each no-op params to be a distinct
+/// type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different no-op parameters
+///   from each other.
+#[derive(Derivative, Params, PartialEq, Eq, Deserialize, Serialize)]
+#[derivative(Clone, Debug)]
+#[serde(bound = "")]
+pub struct NoOpParams<Id> {
+    /// Duration to wait in each state discovery.
+    pub delay: Duration,
+    /// Marker for unique no-op parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> NoOpParams<Id> {
+    /// Returns new `NoOpParams`.
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            marker: PhantomData,
+        }
+    }
+}
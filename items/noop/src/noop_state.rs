@@ -0,0 +1,22 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// State of the no-op item, which never changes.
+///
+/// This is an enum instead of a unit struct, as unit structs are serialized
+/// as `null`, which is read back as the state not being stored.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NoOpState {
+    /// Nothing is changed by this item.
+    Unchanged,
+}
+
+impl fmt::Display for NoOpState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NoOpState::Unchanged => "unchanged".fmt(f),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Diff between current and goal no-op states, which are always in sync.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct NoOpStateDiff;
+
+impl fmt::Display for NoOpStateDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        "in sync".fmt(f)
+    }
+}
@@ -0,0 +1,38 @@
+//! Waits for a TCP or HTTP endpoint to be ready for the peace framework.
+//!
+//! This item is useful as a barrier between an item that launches a server,
+//! and successor items that need the server to be up.
+//!
+//! Ensuring this item polls the endpoint until it responds successfully, or
+//! the timeout elapses. Cleaning this item does nothing.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+pub use crate::{
+    readiness_check_apply_fns::ReadinessCheckApplyFns,
+    readiness_check_data::ReadinessCheckData,
+    readiness_check_error::ReadinessCheckError,
+    readiness_check_item::ReadinessCheckItem,
+    readiness_check_params::{
+        ReadinessCheckParams, ReadinessCheckParamsFieldWise, ReadinessCheckParamsPartial,
+    },
+    readiness_check_state::ReadinessCheckState,
+    readiness_check_state_current_fn::ReadinessCheckStateCurrentFn,
+    readiness_check_state_diff::ReadinessCheckStateDiff,
+    readiness_check_state_diff_fn::ReadinessCheckStateDiffFn,
+    readiness_check_target::ReadinessCheckTarget,
+};
+
+pub(crate) use crate::readiness_probe::ReadinessProbe;
+
+mod readiness_check_apply_fns;
+mod readiness_check_data;
+mod readiness_check_error;
+mod readiness_check_item;
+mod readiness_check_params;
+mod readiness_check_state;
+mod readiness_check_state_current_fn;
+mod readiness_check_state_diff;
+mod readiness_check_state_diff_fn;
+mod readiness_check_target;
+mod readiness_probe;
@@ -0,0 +1,104 @@
+use std::marker::PhantomData;
+
+#[cfg(feature = "output_progress")]
+use peace::cfg::progress::{ProgressLimit, ProgressMsgUpdate};
+use peace::cfg::{ApplyCheck, FnCtx};
+use tokio::time::Instant;
+
+use crate::{
+    ReadinessCheckData, ReadinessCheckError, ReadinessCheckParams, ReadinessCheckState,
+    ReadinessCheckStateDiff, ReadinessProbe,
+};
+
+/// ApplyFns for the readiness check.
+#[derive(Debug)]
+pub struct ReadinessCheckApplyFns<Id>(PhantomData<Id>);
+
+impl<Id> ReadinessCheckApplyFns<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn apply_check(
+        _params: &ReadinessCheckParams<Id>,
+        _data: ReadinessCheckData<'_, Id>,
+        _state_current: &ReadinessCheckState,
+        _state_target: &ReadinessCheckState,
+        diff: &ReadinessCheckStateDiff,
+    ) -> Result<ApplyCheck, ReadinessCheckError> {
+        let apply_check = match diff {
+            ReadinessCheckStateDiff::ReadyRequired => {
+                #[cfg(not(feature = "output_progress"))]
+                {
+                    ApplyCheck::ExecRequired { reason: None }
+                }
+                #[cfg(feature = "output_progress")]
+                {
+                    ApplyCheck::ExecRequired {
+                        progress_limit: ProgressLimit::Unknown,
+                        reason: None,
+                    }
+                }
+            }
+            // Clean is a no-op, as the endpoint is not managed by this item.
+            ReadinessCheckStateDiff::InSync { .. } | ReadinessCheckStateDiff::ReadyNotRequired => {
+                ApplyCheck::ExecNotRequired
+            }
+        };
+
+        Ok(apply_check)
+    }
+
+    pub async fn apply_dry(
+        _fn_ctx: FnCtx<'_>,
+        _params: &ReadinessCheckParams<Id>,
+        _data: ReadinessCheckData<'_, Id>,
+        _state_current: &ReadinessCheckState,
+        state_target: &ReadinessCheckState,
+        _diff: &ReadinessCheckStateDiff,
+    ) -> Result<ReadinessCheckState, ReadinessCheckError> {
+        Ok(*state_target)
+    }
+
+    pub async fn apply(
+        #[cfg(not(feature = "output_progress"))] _fn_ctx: FnCtx<'_>,
+        #[cfg(feature = "output_progress")] fn_ctx: FnCtx<'_>,
+        params: &ReadinessCheckParams<Id>,
+        data: ReadinessCheckData<'_, Id>,
+        _state_current: &ReadinessCheckState,
+        state_target: &ReadinessCheckState,
+        _diff: &ReadinessCheckStateDiff,
+    ) -> Result<ReadinessCheckState, ReadinessCheckError> {
+        if *state_target == ReadinessCheckState::NotReady {
+            return Ok(*state_target);
+        }
+
+        let target = params.target();
+        let interval = params.interval();
+        let timeout = params.timeout();
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let not_ready_reason =
+                match ReadinessProbe::probe(data.client(), target, remaining).await {
+                    Ok(()) => return Ok(ReadinessCheckState::Ready),
+                    Err(not_ready_reason) => not_ready_reason,
+                };
+
+            if Instant::now() + interval >= deadline {
+                return Err(ReadinessCheckError::ReadinessTimeout {
+                    target: target.clone(),
+                    timeout,
+                    not_ready_reason,
+                });
+            }
+
+            #[cfg(feature = "output_progress")]
+            fn_ctx
+                .progress_sender
+                .tick(ProgressMsgUpdate::Set(not_ready_reason));
+
+            tokio::time::sleep(interval).await;
+        }
+    }
+}
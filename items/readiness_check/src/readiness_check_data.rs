@@ -0,0 +1,30 @@
+use std::marker::PhantomData;
+
+use peace::data::{accessors::R, Data};
+
+/// Data used to check the readiness of an endpoint.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different readiness check
+///   parameters from each other.
+#[derive(Data, Debug)]
+pub struct ReadinessCheckData<'exec, Id>
+where
+    Id: Send + Sync + 'static,
+{
+    /// Client to make web requests.
+    client: R<'exec, reqwest::Client>,
+
+    /// Marker.
+    marker: PhantomData<Id>,
+}
+
+impl<'exec, Id> ReadinessCheckData<'exec, Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+}
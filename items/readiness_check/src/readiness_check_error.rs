@@ -0,0 +1,42 @@
+use std::time::Duration;
+
+#[cfg(feature = "error_reporting")]
+use peace::miette;
+
+use crate::ReadinessCheckTarget;
+
+/// Error while checking the readiness of an endpoint.
+#[cfg_attr(feature = "error_reporting", derive(peace::miette::Diagnostic))]
+#[derive(Debug, thiserror::Error)]
+pub enum ReadinessCheckError {
+    /// Endpoint was not ready before the timeout elapsed.
+    #[error("`{target}` was not ready within {}s.", timeout.as_secs_f32())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_readiness_check::readiness_timeout),
+            help(
+                "Check that the server is running, or increase the timeout.\n\
+                The last check failed with: {not_ready_reason}"
+            )
+        )
+    )]
+    ReadinessTimeout {
+        /// Endpoint that was checked.
+        target: ReadinessCheckTarget,
+        /// Maximum duration that was waited.
+        timeout: Duration,
+        /// Why the endpoint was not ready on the last check.
+        not_ready_reason: String,
+    },
+
+    // === Framework errors === //
+    /// A `peace` runtime error occurred.
+    #[error("A `peace` runtime error occurred.")]
+    PeaceRtError(
+        #[cfg_attr(feature = "error_reporting", diagnostic_source)]
+        #[source]
+        #[from]
+        peace::rt_model::Error,
+    ),
+}
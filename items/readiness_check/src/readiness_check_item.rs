@@ -0,0 +1,167 @@
+use std::marker::PhantomData;
+
+use peace::{
+    cfg::{async_trait, ApplyCheck, ApplyCtx, FnCtx, Item, ItemId},
+    params::Params,
+    resources::{resources::ts::Empty, Resources},
+};
+
+use crate::{
+    ReadinessCheckApplyFns, ReadinessCheckData, ReadinessCheckError, ReadinessCheckParams,
+    ReadinessCheckState, ReadinessCheckStateCurrentFn, ReadinessCheckStateDiff,
+    ReadinessCheckStateDiffFn,
+};
+
+/// Item for waiting for an endpoint to be ready.
+///
+/// The goal state is for the endpoint to respond successfully within the
+/// timeout. Ensuring this item polls the endpoint at the configured interval
+/// until it is ready. Cleaning this item does nothing, as the endpoint is not
+/// managed by this item.
+///
+/// The `Id` type parameter is needed for each readiness check params to be a
+/// distinct type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different readiness check
+///   parameters from each other.
+#[derive(Debug)]
+pub struct ReadinessCheckItem<Id> {
+    /// ID of the item to check the endpoint readiness.
+    item_id: ItemId,
+    /// Marker for unique readiness check parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> Clone for ReadinessCheckItem<Id> {
+    fn clone(&self) -> Self {
+        Self {
+            item_id: self.item_id.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<Id> ReadinessCheckItem<Id> {
+    /// Returns a new `ReadinessCheckItem`.
+    pub fn new(item_id: ItemId) -> Self {
+        Self {
+            item_id,
+            marker: PhantomData,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<Id> Item for ReadinessCheckItem<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    type Data<'exec> = ReadinessCheckData<'exec, Id>;
+    type Error = ReadinessCheckError;
+    type Params<'exec> = ReadinessCheckParams<Id>;
+    type State = ReadinessCheckState;
+    type StateDiff = ReadinessCheckStateDiff;
+
+    fn id(&self) -> &ItemId {
+        &self.item_id
+    }
+
+    async fn setup(&self, resources: &mut Resources<Empty>) -> Result<(), ReadinessCheckError> {
+        resources.insert::<reqwest::Client>(reqwest::Client::new());
+
+        Ok(())
+    }
+
+    async fn try_state_current(
+        fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        data: ReadinessCheckData<'_, Id>,
+    ) -> Result<Option<Self::State>, ReadinessCheckError> {
+        ReadinessCheckStateCurrentFn::try_state_current(fn_ctx, params_partial, data).await
+    }
+
+    async fn state_current(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: ReadinessCheckData<'_, Id>,
+    ) -> Result<Self::State, ReadinessCheckError> {
+        ReadinessCheckStateCurrentFn::state_current(fn_ctx, params, data).await
+    }
+
+    async fn try_state_goal(
+        _fn_ctx: FnCtx<'_>,
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: ReadinessCheckData<'_, Id>,
+    ) -> Result<Option<Self::State>, ReadinessCheckError> {
+        Ok(Some(ReadinessCheckState::Ready))
+    }
+
+    async fn state_goal(
+        _fn_ctx: FnCtx<'_>,
+        _params: &Self::Params<'_>,
+        _data: ReadinessCheckData<'_, Id>,
+    ) -> Result<Self::State, ReadinessCheckError> {
+        Ok(ReadinessCheckState::Ready)
+    }
+
+    async fn state_diff(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_goal: &Self::State,
+    ) -> Result<Self::StateDiff, ReadinessCheckError> {
+        ReadinessCheckStateDiffFn::state_diff(state_current, state_goal).await
+    }
+
+    async fn state_clean(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+    ) -> Result<Self::State, ReadinessCheckError> {
+        Ok(ReadinessCheckState::NotReady)
+    }
+
+    async fn apply_check(
+        _apply_ctx: ApplyCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<ApplyCheck, Self::Error> {
+        ReadinessCheckApplyFns::<Id>::apply_check(params, data, state_current, state_target, diff)
+            .await
+    }
+
+    async fn apply_dry(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        ReadinessCheckApplyFns::<Id>::apply_dry(
+            fn_ctx,
+            params,
+            data,
+            state_current,
+            state_target,
+            diff,
+        )
+        .await
+    }
+
+    async fn apply(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        ReadinessCheckApplyFns::<Id>::apply(fn_ctx, params, data, state_current, state_target, diff)
+            .await
+    }
+}
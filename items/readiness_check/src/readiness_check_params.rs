@@ -0,0 +1,57 @@
+use std::{marker::PhantomData, time::Duration};
+
+use derivative::Derivative;
+use peace::params::Params;
+use serde::{Deserialize, Serialize};
+
+use crate::ReadinessCheckTarget;
+
+/// Readiness check parameters.
+///
+/// The `Id` type parameter is needed for each readiness check params to be a
+/// distinct type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different readiness check
+///   parameters from each other.
+#[derive(Derivative, Params, PartialEq, Eq, Deserialize, Serialize)]
+#[derivative(Clone, Debug)]
+#[serde(bound = "")]
+pub struct ReadinessCheckParams<Id> {
+    /// Endpoint to check the readiness of.
+    target: ReadinessCheckTarget,
+    /// Duration to wait between checks.
+    interval: Duration,
+    /// Maximum duration to wait for the endpoint to be ready.
+    timeout: Duration,
+    /// Marker for unique readiness check parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> ReadinessCheckParams<Id> {
+    /// Returns new `ReadinessCheckParams`.
+    pub fn new(target: ReadinessCheckTarget, interval: Duration, timeout: Duration) -> Self {
+        Self {
+            target,
+            interval,
+            timeout,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the endpoint to check the readiness of.
+    pub fn target(&self) -> &ReadinessCheckTarget {
+        &self.target
+    }
+
+    /// Returns the duration to wait between checks.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Returns the maximum duration to wait for the endpoint to be ready.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+}
@@ -0,0 +1,21 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Whether the endpoint responds successfully.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum ReadinessCheckState {
+    /// Endpoint responds successfully.
+    Ready,
+    /// Endpoint does not respond, or responds with an error.
+    NotReady,
+}
+
+impl fmt::Display for ReadinessCheckState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ready => "ready".fmt(f),
+            Self::NotReady => "not ready".fmt(f),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use std::marker::PhantomData;
+
+use peace::{cfg::FnCtx, params::Params};
+
+use crate::{
+    ReadinessCheckData, ReadinessCheckError, ReadinessCheckParams, ReadinessCheckState,
+    ReadinessProbe,
+};
+
+/// Checks the current readiness of the endpoint.
+///
+/// The endpoint is checked once, and is not ready if it does not respond
+/// successfully within the timeout.
+#[derive(Debug)]
+pub struct ReadinessCheckStateCurrentFn<Id>(PhantomData<Id>);
+
+impl<Id> ReadinessCheckStateCurrentFn<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn try_state_current(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<ReadinessCheckParams<Id> as Params>::Partial,
+        data: ReadinessCheckData<'_, Id>,
+    ) -> Result<Option<ReadinessCheckState>, ReadinessCheckError> {
+        match (params_partial.target(), params_partial.timeout()) {
+            (Some(target), Some(timeout)) => {
+                let state = match ReadinessProbe::probe(data.client(), target, *timeout).await {
+                    Ok(()) => ReadinessCheckState::Ready,
+                    Err(_not_ready_reason) => ReadinessCheckState::NotReady,
+                };
+                Ok(Some(state))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    pub async fn state_current(
+        _fn_ctx: FnCtx<'_>,
+        params: &ReadinessCheckParams<Id>,
+        data: ReadinessCheckData<'_, Id>,
+    ) -> Result<ReadinessCheckState, ReadinessCheckError> {
+        let state =
+            match ReadinessProbe::probe(data.client(), params.target(), params.timeout()).await {
+                Ok(()) => ReadinessCheckState::Ready,
+                Err(_not_ready_reason) => ReadinessCheckState::NotReady,
+            };
+
+        Ok(state)
+    }
+}
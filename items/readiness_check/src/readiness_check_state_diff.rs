@@ -0,0 +1,31 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ReadinessCheckState;
+
+/// Diff between the current and target endpoint readiness.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum ReadinessCheckStateDiff {
+    /// Endpoint readiness matches the target.
+    InSync {
+        /// Current readiness of the endpoint.
+        state: ReadinessCheckState,
+    },
+    /// Endpoint is not ready, so apply will wait for it to be ready.
+    ReadyRequired,
+    /// Endpoint is ready, but the target is not ready.
+    ///
+    /// This item does not manage the endpoint, so nothing is done.
+    ReadyNotRequired,
+}
+
+impl fmt::Display for ReadinessCheckStateDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InSync { state } => state.fmt(f),
+            Self::ReadyRequired => write!(f, "will wait for endpoint to be ready"),
+            Self::ReadyNotRequired => write!(f, "ready, endpoint is not managed by this item"),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{ReadinessCheckError, ReadinessCheckState, ReadinessCheckStateDiff};
+
+/// Readiness check state diff function.
+#[derive(Debug)]
+pub struct ReadinessCheckStateDiffFn;
+
+impl ReadinessCheckStateDiffFn {
+    pub async fn state_diff(
+        state_current: &ReadinessCheckState,
+        state_target: &ReadinessCheckState,
+    ) -> Result<ReadinessCheckStateDiff, ReadinessCheckError> {
+        let state_diff = match (state_current, state_target) {
+            (ReadinessCheckState::NotReady, ReadinessCheckState::Ready) => {
+                ReadinessCheckStateDiff::ReadyRequired
+            }
+            (ReadinessCheckState::Ready, ReadinessCheckState::NotReady) => {
+                ReadinessCheckStateDiff::ReadyNotRequired
+            }
+            (ReadinessCheckState::Ready, ReadinessCheckState::Ready)
+            | (ReadinessCheckState::NotReady, ReadinessCheckState::NotReady) => {
+                ReadinessCheckStateDiff::InSync {
+                    state: *state_current,
+                }
+            }
+        };
+
+        Ok(state_diff)
+    }
+}
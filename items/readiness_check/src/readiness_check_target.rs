@@ -0,0 +1,28 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Endpoint to check the readiness of.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum ReadinessCheckTarget {
+    /// Ready when a TCP connection can be established.
+    Tcp {
+        /// Address to connect to, e.g. `127.0.0.1:8080`.
+        address: String,
+    },
+    /// Ready when a `GET` request returns a success status code.
+    Http {
+        /// URL to request.
+        url: Url,
+    },
+}
+
+impl fmt::Display for ReadinessCheckTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tcp { address } => write!(f, "tcp://{address}"),
+            Self::Http { url } => url.fmt(f),
+        }
+    }
+}
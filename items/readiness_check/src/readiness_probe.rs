@@ -0,0 +1,47 @@
+use std::time::Duration;
+
+use tokio::net::TcpStream;
+
+use crate::ReadinessCheckTarget;
+
+/// Checks whether an endpoint is ready.
+#[derive(Debug)]
+pub(crate) struct ReadinessProbe;
+
+impl ReadinessProbe {
+    /// Checks the endpoint once, returning why it is not ready if it does not
+    /// respond successfully within `timeout`.
+    pub async fn probe(
+        client: &reqwest::Client,
+        target: &ReadinessCheckTarget,
+        timeout: Duration,
+    ) -> Result<(), String> {
+        let probe = async {
+            match target {
+                ReadinessCheckTarget::Tcp { address } => TcpStream::connect(address.as_str())
+                    .await
+                    .map(|_tcp_stream| ())
+                    .map_err(|error| error.to_string()),
+                ReadinessCheckTarget::Http { url } => {
+                    let response = client
+                        .get(url.clone())
+                        .send()
+                        .await
+                        .map_err(|error| error.to_string())?;
+                    let status = response.status();
+                    if status.is_success() {
+                        Ok(())
+                    } else {
+                        Err(format!("responded with status {status}"))
+                    }
+                }
+            }
+        };
+
+        tokio::time::timeout(timeout, probe)
+            .await
+            .unwrap_or_else(|_elapsed| {
+                Err(format!("did not respond within {}s", timeout.as_secs_f32()))
+            })
+    }
+}
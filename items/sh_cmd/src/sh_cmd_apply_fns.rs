@@ -46,12 +46,13 @@ where
                     Some("true") => {
                         #[cfg(not(feature = "output_progress"))]
                         {
-                            Ok(ApplyCheck::ExecRequired)
+                            Ok(ApplyCheck::ExecRequired { reason: None })
                         }
 
                         #[cfg(feature = "output_progress")]
                         Ok(ApplyCheck::ExecRequired {
                             progress_limit: ProgressLimit::Unknown,
+                            reason: None,
                         })
                     }
                     Some("false") => Ok(ApplyCheck::ExecNotRequired),
@@ -1,7 +1,7 @@
 use std::marker::PhantomData;
 
 use peace::{
-    cfg::{async_trait, ApplyCheck, FnCtx, Item, ItemId, State},
+    cfg::{async_trait, ApplyCheck, ApplyCtx, FnCtx, Item, ItemId, State},
     params::Params,
     resources::{resources::ts::Empty, Resources},
 };
@@ -145,6 +145,7 @@ where
     }
 
     async fn apply_check(
+        _apply_ctx: ApplyCtx<'_>,
         params: &Self::Params<'_>,
         data: Self::Data<'_>,
         state_current: &Self::State,
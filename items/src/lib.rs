@@ -27,11 +27,43 @@
 //! ```
 
 // Re-exports
+#[cfg(feature = "aws_iam_role")]
+pub use peace_item_aws_iam_role as aws_iam_role;
+#[cfg(feature = "aws_instance_profile")]
+pub use peace_item_aws_instance_profile as aws_instance_profile;
+#[cfg(feature = "aws_s3_bucket")]
+pub use peace_item_aws_s3_bucket as aws_s3_bucket;
 #[cfg(feature = "blank")]
 pub use peace_item_blank as blank;
+#[cfg(feature = "cloud_init_vm")]
+pub use peace_item_cloud_init_vm as cloud_init_vm;
+#[cfg(feature = "container")]
+pub use peace_item_container as container;
+#[cfg(feature = "dir_sync")]
+pub use peace_item_dir_sync as dir_sync;
+#[cfg(feature = "dns_record")]
+pub use peace_item_dns_record as dns_record;
+#[cfg(feature = "env_file")]
+pub use peace_item_env_file as env_file;
+#[cfg(feature = "fail")]
+pub use peace_item_fail as fail;
 #[cfg(feature = "file_download")]
 pub use peace_item_file_download as file_download;
+#[cfg(feature = "http_resource")]
+pub use peace_item_http_resource as http_resource;
+#[cfg(feature = "noop")]
+pub use peace_item_noop as noop;
+#[cfg(feature = "readiness_check")]
+pub use peace_item_readiness_check as readiness_check;
 #[cfg(feature = "sh_cmd")]
 pub use peace_item_sh_cmd as sh_cmd;
+#[cfg(feature = "ssh_cmd")]
+pub use peace_item_ssh_cmd as ssh_cmd;
+#[cfg(feature = "systemd_service")]
+pub use peace_item_systemd_service as systemd_service;
 #[cfg(feature = "tar_x")]
 pub use peace_item_tar_x as tar_x;
+#[cfg(feature = "template_render")]
+pub use peace_item_template_render as template_render;
+#[cfg(feature = "tunnel")]
+pub use peace_item_tunnel as tunnel;
@@ -0,0 +1,52 @@
+//! Manages running a command on a remote host over SSH for the peace
+//! framework.
+//!
+//! This item mirrors [`ShCmdItem`], but runs each of its commands on the host
+//! specified by [`SshHost`]:
+//!
+//! * Current state logic, whose stdout defines the current state (`String`).
+//! * Goal state logic, whose stdout defines the goal state (`String`).
+//! * State diff logic, whose stdout defines the state difference.
+//! * Ensure check, whose stdout defines if ensure execution needs to run --
+//!   `true` means execution is required, `false` means execution is not
+//!   required.
+//! * Ensure execution, whose stdout defines state physical.
+//! * Clean execution.
+//!
+//! Commands are run using the system's OpenSSH `ssh` client, and connections
+//! are shared between all `SshCmdItem`s that target the same host through the
+//! [`SshConnPool`].
+//!
+//! This item is only available on unix platforms, as it relies on OpenSSH's
+//! connection multiplexing.
+//!
+//! [`ShCmdItem`]: peace_item_sh_cmd::ShCmdItem
+
+#![cfg(unix)]
+
+pub use peace_item_sh_cmd::{CmdVariant, ShCmd, ShCmdExecutionRecord, ShCmdState, ShCmdStateDiff};
+
+pub use crate::{
+    ssh_auth::SshAuth,
+    ssh_cmd_apply_fns::SshCmdApplyFns,
+    ssh_cmd_data::SshCmdData,
+    ssh_cmd_error::SshCmdError,
+    ssh_cmd_item::SshCmdItem,
+    ssh_cmd_params::{SshCmdParams, SshCmdParamsFieldWise, SshCmdParamsPartial},
+    ssh_cmd_state_diff_fn::SshCmdStateDiffFn,
+    ssh_conn_pool::SshConnPool,
+    ssh_host::SshHost,
+};
+
+pub(crate) use ssh_cmd_executor::SshCmdExecutor;
+
+mod ssh_auth;
+mod ssh_cmd_apply_fns;
+mod ssh_cmd_data;
+mod ssh_cmd_error;
+mod ssh_cmd_executor;
+mod ssh_cmd_item;
+mod ssh_cmd_params;
+mod ssh_cmd_state_diff_fn;
+mod ssh_conn_pool;
+mod ssh_host;
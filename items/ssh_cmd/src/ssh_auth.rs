@@ -0,0 +1,17 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// How to authenticate with an SSH host.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub enum SshAuth {
+    /// Authenticate using the SSH agent, or the identity files in the user's
+    /// SSH configuration.
+    #[default]
+    Agent,
+    /// Authenticate using only the private key at the given path.
+    IdentityFile {
+        /// Path to the private key.
+        path: PathBuf,
+    },
+}
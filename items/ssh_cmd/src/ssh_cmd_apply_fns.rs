@@ -0,0 +1,153 @@
+use std::marker::PhantomData;
+
+use chrono::Utc;
+#[cfg(feature = "output_progress")]
+use peace::cfg::progress::ProgressLimit;
+use peace::cfg::{ApplyCheck, FnCtx, State};
+
+use crate::{
+    ShCmdExecutionRecord, ShCmdState, ShCmdStateDiff, SshCmdData, SshCmdError, SshCmdExecutor,
+    SshCmdParams,
+};
+
+/// ApplyFns for the command to execute over SSH.
+#[derive(Debug)]
+pub struct SshCmdApplyFns<Id>(PhantomData<Id>);
+
+impl<Id> SshCmdApplyFns<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn apply_check(
+        params: &SshCmdParams<Id>,
+        data: SshCmdData<'_, Id>,
+        state_current: &State<ShCmdState<Id>, ShCmdExecutionRecord>,
+        state_goal: &State<ShCmdState<Id>, ShCmdExecutionRecord>,
+        state_diff: &ShCmdStateDiff,
+    ) -> Result<ApplyCheck, SshCmdError> {
+        let state_current_arg = match &state_current.logical {
+            ShCmdState::None => "",
+            ShCmdState::Some { stdout, .. } => stdout.as_ref(),
+        };
+        let state_goal_arg = match &state_goal.logical {
+            ShCmdState::None => "",
+            ShCmdState::Some { stdout, .. } => stdout.as_ref(),
+        };
+        let ssh_host = params.ssh_host();
+        let apply_check_sh_cmd = params
+            .apply_check_sh_cmd()
+            .clone()
+            .arg(state_current_arg)
+            .arg(state_goal_arg)
+            .arg(&**state_diff);
+
+        let state =
+            SshCmdExecutor::<Id>::exec(data.ssh_conn_pool(), ssh_host, &apply_check_sh_cmd).await?;
+        match state.logical {
+            ShCmdState::Some { stdout, .. } => match stdout.trim().lines().next_back() {
+                Some("true") => {
+                    #[cfg(not(feature = "output_progress"))]
+                    {
+                        Ok(ApplyCheck::ExecRequired { reason: None })
+                    }
+
+                    #[cfg(feature = "output_progress")]
+                    Ok(ApplyCheck::ExecRequired {
+                        progress_limit: ProgressLimit::Unknown,
+                        reason: None,
+                    })
+                }
+                Some("false") => Ok(ApplyCheck::ExecNotRequired),
+                _ => Err(SshCmdError::EnsureCheckValueNotBoolean {
+                    ssh_host: ssh_host.clone(),
+                    sh_cmd: apply_check_sh_cmd.clone(),
+                    #[cfg(feature = "error_reporting")]
+                    sh_cmd_string: format!("{apply_check_sh_cmd}"),
+                    stdout: Some(stdout),
+                }),
+            },
+            ShCmdState::None => Err(SshCmdError::EnsureCheckValueNotBoolean {
+                ssh_host: ssh_host.clone(),
+                sh_cmd: apply_check_sh_cmd.clone(),
+                #[cfg(feature = "error_reporting")]
+                sh_cmd_string: format!("{apply_check_sh_cmd}"),
+                stdout: None,
+            }),
+        }
+    }
+
+    /// Returns the state with the command that would be run, without
+    /// connecting to the host.
+    pub async fn apply_dry(
+        _fn_ctx: FnCtx<'_>,
+        params: &SshCmdParams<Id>,
+        _data: SshCmdData<'_, Id>,
+        state_current: &State<ShCmdState<Id>, ShCmdExecutionRecord>,
+        state_goal: &State<ShCmdState<Id>, ShCmdExecutionRecord>,
+        state_diff: &ShCmdStateDiff,
+    ) -> Result<State<ShCmdState<Id>, ShCmdExecutionRecord>, SshCmdError> {
+        let state_current_arg = match &state_current.logical {
+            ShCmdState::None => "",
+            ShCmdState::Some { stdout, .. } => stdout.as_ref(),
+        };
+        let state_goal_arg = match &state_goal.logical {
+            ShCmdState::None => "",
+            ShCmdState::Some { stdout, .. } => stdout.as_ref(),
+        };
+        let apply_exec_sh_cmd = params
+            .apply_exec_sh_cmd()
+            .clone()
+            .arg(state_current_arg)
+            .arg(state_goal_arg)
+            .arg(&**state_diff);
+
+        let datetime = Utc::now();
+        Ok(State::new(
+            ShCmdState::Some {
+                stdout: format!("{apply_exec_sh_cmd}"),
+                stderr: format!("would run on `{}`", params.ssh_host()),
+                marker: PhantomData,
+            },
+            ShCmdExecutionRecord::Some {
+                start_datetime: datetime,
+                end_datetime: datetime,
+                exit_code: Some(0),
+            },
+        ))
+    }
+
+    pub async fn apply(
+        fn_ctx: FnCtx<'_>,
+        params: &SshCmdParams<Id>,
+        data: SshCmdData<'_, Id>,
+        state_current: &State<ShCmdState<Id>, ShCmdExecutionRecord>,
+        state_goal: &State<ShCmdState<Id>, ShCmdExecutionRecord>,
+        state_diff: &ShCmdStateDiff,
+    ) -> Result<State<ShCmdState<Id>, ShCmdExecutionRecord>, SshCmdError> {
+        let state_current_arg = match &state_current.logical {
+            ShCmdState::None => "",
+            ShCmdState::Some { stdout, .. } => stdout.as_ref(),
+        };
+        let state_goal_arg = match &state_goal.logical {
+            ShCmdState::None => "",
+            ShCmdState::Some { stdout, .. } => stdout.as_ref(),
+        };
+        let ssh_conn_pool = data.ssh_conn_pool();
+        let ssh_host = params.ssh_host();
+        let apply_exec_sh_cmd = params
+            .apply_exec_sh_cmd()
+            .clone()
+            .arg(state_current_arg)
+            .arg(state_goal_arg)
+            .arg(&**state_diff);
+
+        SshCmdExecutor::<Id>::exec_with_progress(
+            fn_ctx,
+            ssh_conn_pool,
+            ssh_host,
+            &apply_exec_sh_cmd,
+        )
+        .await?;
+        SshCmdExecutor::<Id>::exec(ssh_conn_pool, ssh_host, params.state_current_sh_cmd()).await
+    }
+}
@@ -0,0 +1,45 @@
+use std::marker::PhantomData;
+
+use peace::{
+    cfg::{accessors::Stored, State},
+    data::{accessors::R, Data},
+};
+
+use crate::{ShCmdExecutionRecord, ShCmdState, SshConnPool};
+
+/// Data used to run a command over SSH.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different command execution
+///   parameters from each other.
+#[derive(Data, Debug)]
+pub struct SshCmdData<'exec, Id>
+where
+    Id: Send + Sync + 'static,
+{
+    /// Connections shared between items that target the same host.
+    ssh_conn_pool: R<'exec, SshConnPool>,
+
+    /// Stored states of this item's previous execution.
+    state_current_stored: Stored<'exec, State<ShCmdState<Id>, ShCmdExecutionRecord>>,
+
+    /// Marker.
+    marker: PhantomData<Id>,
+}
+
+impl<'exec, Id> SshCmdData<'exec, Id>
+where
+    Id: Send + Sync + 'static,
+{
+    /// Returns the connections shared between items that target the same
+    /// host.
+    pub fn ssh_conn_pool(&self) -> &SshConnPool {
+        &self.ssh_conn_pool
+    }
+
+    /// Returns the previous states.
+    pub fn state_current_stored(&self) -> Option<&State<ShCmdState<Id>, ShCmdExecutionRecord>> {
+        self.state_current_stored.get()
+    }
+}
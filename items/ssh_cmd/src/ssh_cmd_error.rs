@@ -0,0 +1,241 @@
+use std::path::PathBuf;
+
+#[cfg(feature = "error_reporting")]
+use peace::miette::{self, SourceSpan};
+
+use crate::{CmdVariant, ShCmd, SshHost};
+
+/// Error while managing command execution over SSH.
+#[cfg_attr(feature = "error_reporting", derive(peace::miette::Diagnostic))]
+#[derive(Debug, thiserror::Error)]
+pub enum SshCmdError {
+    /// A command script was not resolved during execution.
+    ///
+    /// This could be due to it not being provided, or it failed to be looked up
+    /// when needed.
+    #[error("Script not resolved for: `{cmd_variant}`.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_ssh_cmd::cmd_script_not_exists),
+            help("Check if the `{cmd_variant}` is provided in params.")
+        )
+    )]
+    CmdScriptNotResolved {
+        /// The cmd variant that was not existent.
+        cmd_variant: CmdVariant,
+    },
+
+    /// The SSH host was not resolved during execution.
+    ///
+    /// This could be due to it not being provided, or the predecessor item it
+    /// is mapped from not having a state.
+    #[error("SSH host not resolved for: `{cmd_variant}`.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_ssh_cmd::ssh_host_not_resolved),
+            help(
+                "Check if the `ssh_host` is provided in params, \
+                or if the item it is mapped from has been applied."
+            )
+        )
+    )]
+    SshHostNotResolved {
+        /// The cmd variant that the host was needed for.
+        cmd_variant: CmdVariant,
+    },
+
+    /// Failed to create the directory for SSH control sockets.
+    #[error("Failed to create SSH control socket directory: `{}`.", control_dir.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_ssh_cmd::control_dir_create))
+    )]
+    ControlDirCreate {
+        /// Directory that failed to be created.
+        control_dir: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to read the metadata of the SSH control socket directory.
+    #[error(
+        "Failed to read SSH control socket directory metadata: `{}`.",
+        control_dir.display()
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_ssh_cmd::control_dir_metadata_read))
+    )]
+    ControlDirMetadataRead {
+        /// Directory whose metadata failed to be read.
+        control_dir: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// The SSH control socket directory is not private to the current user.
+    #[error(
+        "SSH control socket directory is not private to the current user: `{}`.",
+        control_dir.display()
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_ssh_cmd::control_dir_insecure),
+            help(
+                "The directory must be owned by uid `{uid_current}` and have mode `0700`, \
+                but is owned by uid `{uid}` and has mode `{mode:o}`.\n\
+                Remove the directory, or use a different `control_dir` for the `SshConnPool`."
+            )
+        )
+    )]
+    ControlDirInsecure {
+        /// Directory that other users may access.
+        control_dir: PathBuf,
+        /// Owner of the directory.
+        uid: u32,
+        /// ID of the current user.
+        uid_current: u32,
+        /// Permission bits of the directory.
+        mode: u32,
+    },
+
+    /// Failed to execute `ssh`.
+    #[error("Failed to execute `ssh` for `{ssh_host}`: `{sh_cmd}`")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_ssh_cmd::ssh_exec_fail),
+            help("Check that OpenSSH's `ssh` client is installed and on the `PATH`.")
+        )
+    )]
+    SshExecFail {
+        /// The host that the command was to be run on.
+        ssh_host: SshHost,
+        /// The command that failed to be executed.
+        sh_cmd: ShCmd,
+        /// The command that failed to be executed as a string.
+        #[cfg(feature = "error_reporting")]
+        #[source_code]
+        sh_cmd_string: String,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to connect to the SSH host.
+    ///
+    /// `ssh` exits with code 255 when it fails to connect or authenticate, so
+    /// a remote command that exits with 255 is also reported as this error.
+    #[error("Failed to connect to `{ssh_host}`: {stderr}")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_ssh_cmd::ssh_connect_fail),
+            help(
+                "Check that the host is reachable, and can be logged in to without prompts, \
+                e.g. `ssh -o BatchMode=yes {ssh_host} true`."
+            )
+        )
+    )]
+    SshConnectFail {
+        /// The host that failed to be connected to.
+        ssh_host: SshHost,
+        /// Stderr from `ssh`.
+        stderr: String,
+    },
+
+    /// Command produced non-UTF-8 stdout output.
+    #[error("Command on `{ssh_host}` produced non-UTF-8 stdout output: `{sh_cmd}`")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_ssh_cmd::stdout_non_utf8)),
+        help(
+            "Update the command to something that outputs UTF8: `{}`\n\
+            Perhaps encode the output using `base64`",
+            sh_cmd
+        )
+    )]
+    StdoutNonUtf8 {
+        /// The host that the command was run on.
+        ssh_host: SshHost,
+        /// The command whose stdout is not a valid UTF-8 string.
+        sh_cmd: ShCmd,
+        /// Lossy UTF-8 conversion of stdout.
+        #[cfg_attr(feature = "error_reporting", source_code)]
+        stdout_lossy: String,
+        /// Span where the invalid bytes occur.
+        #[cfg(feature = "error_reporting")]
+        #[label]
+        invalid_span: SourceSpan,
+        /// Underlying Utf8 error.
+        #[source]
+        error: std::str::Utf8Error,
+    },
+
+    /// Command produced non-UTF-8 stderr output.
+    #[error("Command on `{ssh_host}` produced non-UTF-8 stderr output: `{sh_cmd}`")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_ssh_cmd::stderr_non_utf8)),
+        help(
+            "Update the command to something that outputs UTF8: `{}`\n\
+            Perhaps encode the output using `base64`",
+            sh_cmd
+        )
+    )]
+    StderrNonUtf8 {
+        /// The host that the command was run on.
+        ssh_host: SshHost,
+        /// The command whose stderr is not a valid UTF-8 string.
+        sh_cmd: ShCmd,
+        /// Lossy UTF-8 conversion of stderr.
+        #[cfg_attr(feature = "error_reporting", source_code)]
+        stderr_lossy: String,
+        /// Span where the invalid bytes occur.
+        #[cfg(feature = "error_reporting")]
+        #[label]
+        invalid_span: SourceSpan,
+        /// Underlying Utf8 error.
+        #[source]
+        error: std::str::Utf8Error,
+    },
+
+    /// Ensure check command did not output "true" or "false".
+    #[error(
+        r#"Ensure check command on `{ssh_host}` did not return "true" or "false": `{sh_cmd}`"#
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_ssh_cmd::ensure_check_value_not_boolean)),
+        help(
+            r#"Update the command to return "true" if execution is required, or "false" if not."#
+        )
+    )]
+    EnsureCheckValueNotBoolean {
+        /// The host that the command was run on.
+        ssh_host: SshHost,
+        /// The ensure check command.
+        sh_cmd: ShCmd,
+        /// The ensure check command as a string.
+        #[cfg(feature = "error_reporting")]
+        #[source_code]
+        sh_cmd_string: String,
+        /// Stdout.
+        stdout: Option<String>,
+    },
+
+    // === Framework errors === //
+    /// A `peace` runtime error occurred.
+    #[error("A `peace` runtime error occurred.")]
+    PeaceRtError(
+        #[cfg_attr(feature = "error_reporting", diagnostic_source)]
+        #[source]
+        #[from]
+        peace::rt_model::Error,
+    ),
+}
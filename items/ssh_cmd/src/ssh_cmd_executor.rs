@@ -0,0 +1,169 @@
+use std::{marker::PhantomData, process::Stdio};
+
+use chrono::Utc;
+#[cfg(feature = "output_progress")]
+use peace::cfg::progress::ProgressMsgUpdate;
+use peace::cfg::{FnCtx, State};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
+
+use crate::{ShCmd, ShCmdExecutionRecord, ShCmdState, SshCmdError, SshConnPool, SshHost};
+
+/// Exit code that `ssh` returns when it fails to connect.
+const SSH_EXIT_CODE_ERROR: i32 = 255;
+
+/// Common code to run `ShCmd`s over SSH.
+#[derive(Debug)]
+pub(crate) struct SshCmdExecutor<Id>(PhantomData<Id>);
+
+impl<Id> SshCmdExecutor<Id> {
+    /// Executes the provided `ShCmd` on the host and returns execution
+    /// information.
+    pub async fn exec(
+        ssh_conn_pool: &SshConnPool,
+        ssh_host: &SshHost,
+        sh_cmd: &ShCmd,
+    ) -> Result<State<ShCmdState<Id>, ShCmdExecutionRecord>, SshCmdError> {
+        Self::exec_with_stdout_fn(ssh_conn_pool, ssh_host, sh_cmd, |_stdout_line| {}).await
+    }
+
+    /// Executes the provided `ShCmd` on the host, reporting each line of
+    /// stdout as progress.
+    pub async fn exec_with_progress(
+        #[cfg(not(feature = "output_progress"))] _fn_ctx: FnCtx<'_>,
+        #[cfg(feature = "output_progress")] fn_ctx: FnCtx<'_>,
+        ssh_conn_pool: &SshConnPool,
+        ssh_host: &SshHost,
+        sh_cmd: &ShCmd,
+    ) -> Result<State<ShCmdState<Id>, ShCmdExecutionRecord>, SshCmdError> {
+        Self::exec_with_stdout_fn(ssh_conn_pool, ssh_host, sh_cmd, |_stdout_line| {
+            #[cfg(feature = "output_progress")]
+            fn_ctx
+                .progress_sender
+                .tick(ProgressMsgUpdate::Set(_stdout_line.to_string()));
+        })
+        .await
+    }
+
+    async fn exec_with_stdout_fn<F>(
+        ssh_conn_pool: &SshConnPool,
+        ssh_host: &SshHost,
+        sh_cmd: &ShCmd,
+        mut stdout_fn: F,
+    ) -> Result<State<ShCmdState<Id>, ShCmdExecutionRecord>, SshCmdError>
+    where
+        F: FnMut(&str),
+    {
+        ssh_conn_pool.control_dir_ensure().await?;
+
+        let ssh_exec_fail = |error| {
+            #[cfg(feature = "error_reporting")]
+            let sh_cmd_string = format!("{sh_cmd}");
+
+            SshCmdError::SshExecFail {
+                ssh_host: ssh_host.clone(),
+                sh_cmd: sh_cmd.clone(),
+                #[cfg(feature = "error_reporting")]
+                sh_cmd_string,
+                error,
+            }
+        };
+
+        let start_datetime = Utc::now();
+        let mut child = ssh_conn_pool
+            .command(ssh_host, sh_cmd)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(ssh_exec_fail)?;
+        let mut stdout_pipe = BufReader::new(child.stdout.take().expect("stdout is piped."));
+        let mut stderr_pipe = child.stderr.take().expect("stderr is piped.");
+
+        let stdout_read = async {
+            let mut stdout = Vec::new();
+            let mut line = Vec::new();
+            loop {
+                line.clear();
+                if stdout_pipe.read_until(b'\n', &mut line).await? == 0 {
+                    break;
+                }
+                stdout_fn(String::from_utf8_lossy(&line).trim_end());
+                stdout.extend_from_slice(&line);
+            }
+            Ok(stdout)
+        };
+        let stderr_read = async {
+            let mut stderr = Vec::new();
+            stderr_pipe.read_to_end(&mut stderr).await?;
+            Ok(stderr)
+        };
+        let (stdout, stderr) =
+            futures::try_join!(stdout_read, stderr_read).map_err(ssh_exec_fail)?;
+        let exit_status = child.wait().await.map_err(ssh_exec_fail)?;
+        let end_datetime = Utc::now();
+
+        if exit_status.code() == Some(SSH_EXIT_CODE_ERROR) {
+            return Err(SshCmdError::SshConnectFail {
+                ssh_host: ssh_host.clone(),
+                stderr: String::from_utf8_lossy(&stderr).trim().to_string(),
+            });
+        }
+
+        let stdout = String::from_utf8(stdout).map_err(|from_utf8_error| {
+            let stdout_lossy = String::from_utf8_lossy(from_utf8_error.as_bytes()).to_string();
+            let error = from_utf8_error.utf8_error();
+            #[cfg(feature = "error_reporting")]
+            let invalid_span = {
+                let start = error.valid_up_to();
+                let len = error.error_len().unwrap_or(1);
+                peace::miette::SourceSpan::from((start, len))
+            };
+
+            SshCmdError::StdoutNonUtf8 {
+                ssh_host: ssh_host.clone(),
+                sh_cmd: sh_cmd.clone(),
+                stdout_lossy,
+                #[cfg(feature = "error_reporting")]
+                invalid_span,
+                error,
+            }
+        })?;
+
+        let stderr = String::from_utf8(stderr)
+            .map_err(|from_utf8_error| {
+                let stderr_lossy = String::from_utf8_lossy(from_utf8_error.as_bytes()).to_string();
+                let error = from_utf8_error.utf8_error();
+                #[cfg(feature = "error_reporting")]
+                let invalid_span = {
+                    let start = error.valid_up_to();
+                    let len = error.error_len().unwrap_or(1);
+                    peace::miette::SourceSpan::from((start, len))
+                };
+
+                SshCmdError::StderrNonUtf8 {
+                    ssh_host: ssh_host.clone(),
+                    sh_cmd: sh_cmd.clone(),
+                    stderr_lossy,
+                    #[cfg(feature = "error_reporting")]
+                    invalid_span,
+                    error,
+                }
+            })?
+            .trim()
+            .to_string();
+
+        Ok(State::new(
+            ShCmdState::Some {
+                stdout,
+                stderr,
+                marker: PhantomData,
+            },
+            ShCmdExecutionRecord::Some {
+                start_datetime,
+                end_datetime,
+                exit_code: exit_status.code(),
+            },
+        ))
+    }
+}
@@ -0,0 +1,223 @@
+use std::marker::PhantomData;
+
+use peace::{
+    cfg::{async_trait, ApplyCheck, ApplyCtx, FnCtx, Item, ItemId, State},
+    params::Params,
+    resources::{paths::PeaceAppDir, resources::ts::Empty, Resources},
+};
+
+use crate::{
+    CmdVariant, ShCmdExecutionRecord, ShCmdState, ShCmdStateDiff, SshCmdApplyFns, SshCmdData,
+    SshCmdError, SshCmdExecutor, SshCmdParams, SshCmdStateDiffFn, SshConnPool,
+};
+
+/// Item for executing a command on a remote host over SSH.
+///
+/// The `Id` type parameter is needed for each command execution params to be a
+/// distinct type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different command execution
+///   parameters from each other.
+#[derive(Debug)]
+pub struct SshCmdItem<Id> {
+    /// ID to easily tell what the item command is for.
+    item_id: ItemId,
+    /// Marker for unique command execution parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> Clone for SshCmdItem<Id> {
+    fn clone(&self) -> Self {
+        Self {
+            item_id: self.item_id.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<Id> SshCmdItem<Id> {
+    /// Returns a new `SshCmdItem`.
+    ///
+    /// # Parameters
+    ///
+    /// * `item_id`: ID of this `SshCmdItem`.
+    pub fn new(item_id: ItemId) -> Self {
+        Self {
+            item_id,
+            marker: PhantomData,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<Id> Item for SshCmdItem<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    type Data<'exec> = SshCmdData<'exec, Id>;
+    type Error = SshCmdError;
+    type Params<'exec> = SshCmdParams<Id>;
+    type State = State<ShCmdState<Id>, ShCmdExecutionRecord>;
+    type StateDiff = ShCmdStateDiff;
+
+    fn id(&self) -> &ItemId {
+        &self.item_id
+    }
+
+    async fn setup(&self, resources: &mut Resources<Empty>) -> Result<(), SshCmdError> {
+        // Shared by all `SshCmdItem`s, so that connections to the same host are
+        // reused.
+        if !resources.contains::<SshConnPool>() {
+            let control_dir = {
+                let peace_app_dir = resources.borrow::<PeaceAppDir>();
+                SshConnPool::control_dir_default(&peace_app_dir)
+            };
+            resources.insert(SshConnPool::new(control_dir));
+        }
+
+        Ok(())
+    }
+
+    async fn try_state_current(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        data: SshCmdData<'_, Id>,
+    ) -> Result<Option<Self::State>, SshCmdError> {
+        if let (Some(ssh_host), Some(state_current_sh_cmd)) = (
+            params_partial.ssh_host(),
+            params_partial.state_current_sh_cmd(),
+        ) {
+            SshCmdExecutor::exec(data.ssh_conn_pool(), ssh_host, state_current_sh_cmd)
+                .await
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn state_current(
+        _fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: SshCmdData<'_, Id>,
+    ) -> Result<Self::State, SshCmdError> {
+        SshCmdExecutor::exec(
+            data.ssh_conn_pool(),
+            params.ssh_host(),
+            params.state_current_sh_cmd(),
+        )
+        .await
+    }
+
+    async fn try_state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        data: SshCmdData<'_, Id>,
+    ) -> Result<Option<Self::State>, SshCmdError> {
+        if let (Some(ssh_host), Some(state_goal_sh_cmd)) = (
+            params_partial.ssh_host(),
+            params_partial.state_goal_sh_cmd(),
+        ) {
+            SshCmdExecutor::exec(data.ssh_conn_pool(), ssh_host, state_goal_sh_cmd)
+                .await
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: SshCmdData<'_, Id>,
+    ) -> Result<Self::State, SshCmdError> {
+        SshCmdExecutor::exec(
+            data.ssh_conn_pool(),
+            params.ssh_host(),
+            params.state_goal_sh_cmd(),
+        )
+        .await
+    }
+
+    async fn state_diff(
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_goal: &Self::State,
+    ) -> Result<Self::StateDiff, SshCmdError> {
+        let ssh_host = params_partial
+            .ssh_host()
+            .ok_or(SshCmdError::SshHostNotResolved {
+                cmd_variant: CmdVariant::StateDiff,
+            })?;
+        let state_diff_sh_cmd =
+            params_partial
+                .state_diff_sh_cmd()
+                .ok_or(SshCmdError::CmdScriptNotResolved {
+                    cmd_variant: CmdVariant::StateDiff,
+                })?;
+
+        SshCmdStateDiffFn::state_diff(
+            data.ssh_conn_pool(),
+            ssh_host,
+            state_diff_sh_cmd.clone(),
+            state_current,
+            state_goal,
+        )
+        .await
+    }
+
+    async fn state_clean(
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        data: Self::Data<'_>,
+    ) -> Result<Self::State, SshCmdError> {
+        let ssh_host = params_partial
+            .ssh_host()
+            .ok_or(SshCmdError::SshHostNotResolved {
+                cmd_variant: CmdVariant::StateClean,
+            })?;
+        let state_clean_sh_cmd =
+            params_partial
+                .state_clean_sh_cmd()
+                .ok_or(SshCmdError::CmdScriptNotResolved {
+                    cmd_variant: CmdVariant::StateClean,
+                })?;
+
+        SshCmdExecutor::exec(data.ssh_conn_pool(), ssh_host, state_clean_sh_cmd).await
+    }
+
+    async fn apply_check(
+        _apply_ctx: ApplyCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<ApplyCheck, Self::Error> {
+        SshCmdApplyFns::<Id>::apply_check(params, data, state_current, state_target, diff).await
+    }
+
+    async fn apply_dry(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        SshCmdApplyFns::<Id>::apply_dry(fn_ctx, params, data, state_current, state_target, diff)
+            .await
+    }
+
+    async fn apply(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        SshCmdApplyFns::<Id>::apply(fn_ctx, params, data, state_current, state_target, diff).await
+    }
+}
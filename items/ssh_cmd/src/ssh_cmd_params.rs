@@ -0,0 +1,151 @@
+use std::marker::PhantomData;
+
+use derivative::Derivative;
+use peace::params::Params;
+use serde::{Deserialize, Serialize};
+
+use crate::{ShCmd, SshHost};
+
+/// Grouping of commands to run a command idempotently on a remote host.
+///
+/// Each command is run on the `ssh_host`, and has the same semantics as the
+/// corresponding command in [`ShCmdParams`].
+///
+/// The `Id` type parameter is needed for each command execution params to be a
+/// distinct type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different command execution
+///   parameters from each other.
+///
+/// [`ShCmdParams`]: peace_item_sh_cmd::ShCmdParams
+#[derive(Derivative, Params, PartialEq, Eq, Deserialize, Serialize)]
+#[derivative(Clone, Debug)]
+#[serde(bound = "")]
+pub struct SshCmdParams<Id> {
+    /// Host to run the commands on.
+    ///
+    /// This may be mapped from a predecessor item's state, such as the address
+    /// of a server launched earlier in the flow.
+    ssh_host: SshHost,
+    /// Command to run to discover the clean state.
+    ///
+    /// The command's stdout is used as the clean state.
+    ///
+    /// The command's stderr is used as the human readable description of the
+    /// state. This must be output as a single line.
+    state_clean_sh_cmd: ShCmd,
+    /// Command to run to discover the current state.
+    ///
+    /// The command's stdout is used as the current state.
+    ///
+    /// The command's stderr is used as the human readable description of the
+    /// state. This must be output as a single line.
+    state_current_sh_cmd: ShCmd,
+    /// Command to run to discover the goal state.
+    ///
+    /// The command's stdout is used as the goal state.
+    ///
+    /// The command's stderr is used as the human readable description of the
+    /// state. This must be output as a single line.
+    state_goal_sh_cmd: ShCmd,
+    /// Command to run to show the state difference.
+    ///
+    /// The command will be passed the following as two separate arguments:
+    ///
+    /// * Current state string
+    /// * Goal state string
+    ///
+    /// The command's stdout is used as the state difference.
+    ///
+    /// The command's stderr is used as the human readable description of the
+    /// state difference. This must be output as a single line.
+    state_diff_sh_cmd: ShCmd,
+    /// Command to run in `ApplyFns::check`.
+    ///
+    /// The command will be passed the following as three separate arguments:
+    ///
+    /// * Current state string
+    /// * Goal state string
+    /// * State diff string
+    ///
+    /// If the command returns the string `true` as its final line, then it is
+    /// taken to mean `ApplyFns::exec` needs to be run.
+    ///
+    /// If the command returns the string `false` as its final line, then it is
+    /// taken to mean `ApplyFns::exec` does not need to be run.
+    apply_check_sh_cmd: ShCmd,
+    /// Command to run in `ApplyFns::exec`.
+    ///
+    /// The command will be passed the following as three separate arguments:
+    ///
+    /// * Current state string
+    /// * Goal state string
+    /// * State diff string
+    ///
+    /// Each line of the command's stdout is reported as progress.
+    apply_exec_sh_cmd: ShCmd,
+    /// Marker for unique command execution parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> SshCmdParams<Id> {
+    /// Returns new `SshCmdParams`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        ssh_host: SshHost,
+        state_clean_sh_cmd: ShCmd,
+        state_current_sh_cmd: ShCmd,
+        state_goal_sh_cmd: ShCmd,
+        state_diff_sh_cmd: ShCmd,
+        apply_check_sh_cmd: ShCmd,
+        apply_exec_sh_cmd: ShCmd,
+    ) -> Self {
+        Self {
+            ssh_host,
+            state_clean_sh_cmd,
+            state_current_sh_cmd,
+            state_goal_sh_cmd,
+            state_diff_sh_cmd,
+            apply_check_sh_cmd,
+            apply_exec_sh_cmd,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the host to run the commands on.
+    pub fn ssh_host(&self) -> &SshHost {
+        &self.ssh_host
+    }
+
+    /// Returns the command to run to discover the clean state.
+    pub fn state_clean_sh_cmd(&self) -> &ShCmd {
+        &self.state_clean_sh_cmd
+    }
+
+    /// Returns the command to run to discover the current state.
+    pub fn state_current_sh_cmd(&self) -> &ShCmd {
+        &self.state_current_sh_cmd
+    }
+
+    /// Returns the command to run to discover the goal state.
+    pub fn state_goal_sh_cmd(&self) -> &ShCmd {
+        &self.state_goal_sh_cmd
+    }
+
+    /// Returns the command to run to show the state difference.
+    pub fn state_diff_sh_cmd(&self) -> &ShCmd {
+        &self.state_diff_sh_cmd
+    }
+
+    /// Returns the command to run in `ApplyFns::check`.
+    pub fn apply_check_sh_cmd(&self) -> &ShCmd {
+        &self.apply_check_sh_cmd
+    }
+
+    /// Returns the command to run in `ApplyFns::exec`.
+    pub fn apply_exec_sh_cmd(&self) -> &ShCmd {
+        &self.apply_exec_sh_cmd
+    }
+}
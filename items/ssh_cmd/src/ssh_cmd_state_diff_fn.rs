@@ -0,0 +1,45 @@
+use std::marker::PhantomData;
+
+use peace::cfg::State;
+
+use crate::{
+    ShCmd, ShCmdExecutionRecord, ShCmdState, ShCmdStateDiff, SshCmdError, SshCmdExecutor,
+    SshConnPool, SshHost,
+};
+
+/// Runs a command over SSH to obtain the `SshCmd` diff.
+#[derive(Debug)]
+pub struct SshCmdStateDiffFn<Id>(PhantomData<Id>);
+
+impl<Id> SshCmdStateDiffFn<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn state_diff(
+        ssh_conn_pool: &SshConnPool,
+        ssh_host: &SshHost,
+        state_diff_sh_cmd: ShCmd,
+        state_current: &State<ShCmdState<Id>, ShCmdExecutionRecord>,
+        state_goal: &State<ShCmdState<Id>, ShCmdExecutionRecord>,
+    ) -> Result<ShCmdStateDiff, SshCmdError> {
+        let state_current_arg = match &state_current.logical {
+            ShCmdState::None => "",
+            ShCmdState::Some { stdout, .. } => stdout.as_ref(),
+        };
+        let state_goal_arg = match &state_goal.logical {
+            ShCmdState::None => "",
+            ShCmdState::Some { stdout, .. } => stdout.as_ref(),
+        };
+        let state_diff_sh_cmd = state_diff_sh_cmd.arg(state_current_arg).arg(state_goal_arg);
+        SshCmdExecutor::<Id>::exec(ssh_conn_pool, ssh_host, &state_diff_sh_cmd)
+            .await
+            .map(|state| match state.logical {
+                ShCmdState::None => ShCmdStateDiff::new(String::from(""), String::from("")),
+                ShCmdState::Some {
+                    stdout,
+                    stderr,
+                    marker: _,
+                } => ShCmdStateDiff::new(stdout, stderr),
+            })
+    }
+}
@@ -0,0 +1,229 @@
+use std::{
+    ffi::{OsStr, OsString},
+    os::unix::fs::MetadataExt,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use tokio::process::Command;
+
+use crate::{ShCmd, SshAuth, SshCmdError, SshHost};
+
+/// Shares SSH connections between items that target the same host.
+///
+/// Commands are run using OpenSSH's connection multiplexing: the first command
+/// to a host opens a master connection with a control socket in
+/// `control_dir`, and subsequent commands to that host -- from any
+/// `SshCmdItem` -- reuse it. Master connections are closed after they have
+/// been idle for `control_persist`.
+///
+/// The control sockets allow anyone who can access them to run commands over
+/// the master connections, so `control_dir` must be owned by the current user
+/// and have mode `0700`. Commands are not run if it is not.
+///
+/// `SshCmdItem`s insert an `SshConnPool` with the
+/// [`control_dir_default`](Self::control_dir_default) during setup if one does
+/// not already exist. To customize it, insert an `SshConnPool` into the
+/// `Resources` before running commands.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SshConnPool {
+    /// Program to run SSH commands with.
+    ssh_program: OsString,
+    /// Directory to store control sockets in.
+    control_dir: PathBuf,
+    /// Duration that idle master connections are kept open.
+    control_persist: Duration,
+    /// Duration to wait for a connection to be established.
+    connect_timeout: Duration,
+}
+
+impl SshConnPool {
+    /// Default duration to wait for a connection to be established.
+    pub const CONNECT_TIMEOUT_DEFAULT: Duration = Duration::from_secs(10);
+    /// Default duration that idle master connections are kept open.
+    pub const CONTROL_PERSIST_DEFAULT: Duration = Duration::from_secs(60);
+
+    /// Name of the control socket directory within the default parent
+    /// directory.
+    const CONTROL_DIR_NAME: &'static str = "peace_ssh_cmd";
+
+    /// Returns the default directory to store control sockets in.
+    ///
+    /// This is `$XDG_RUNTIME_DIR/peace_ssh_cmd` if `XDG_RUNTIME_DIR` is set, as
+    /// that directory is private to the current user, and short enough for
+    /// control socket paths. Otherwise, it is the `peace_ssh_cmd` directory
+    /// within the given `PeaceAppDir`.
+    pub fn control_dir_default(peace_app_dir: &Path) -> PathBuf {
+        std::env::var_os("XDG_RUNTIME_DIR")
+            .filter(|xdg_runtime_dir| !xdg_runtime_dir.is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| peace_app_dir.to_path_buf())
+            .join(Self::CONTROL_DIR_NAME)
+    }
+
+    /// Returns a new `SshConnPool` which stores control sockets in
+    /// `control_dir`.
+    pub fn new(control_dir: PathBuf) -> Self {
+        Self {
+            ssh_program: OsString::from("ssh"),
+            control_dir,
+            control_persist: Self::CONTROL_PERSIST_DEFAULT,
+            connect_timeout: Self::CONNECT_TIMEOUT_DEFAULT,
+        }
+    }
+
+    /// Sets the program to run SSH commands with, defaults to `ssh`.
+    pub fn with_ssh_program(mut self, ssh_program: impl Into<OsString>) -> Self {
+        self.ssh_program = ssh_program.into();
+        self
+    }
+
+    /// Sets the duration that idle master connections are kept open.
+    pub fn with_control_persist(mut self, control_persist: Duration) -> Self {
+        self.control_persist = control_persist;
+        self
+    }
+
+    /// Sets the duration to wait for a connection to be established.
+    pub fn with_connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = connect_timeout;
+        self
+    }
+
+    /// Returns the program to run SSH commands with.
+    pub fn ssh_program(&self) -> &OsStr {
+        &self.ssh_program
+    }
+
+    /// Returns the directory to store control sockets in.
+    pub fn control_dir(&self) -> &Path {
+        &self.control_dir
+    }
+
+    /// Returns the duration that idle master connections are kept open.
+    pub fn control_persist(&self) -> Duration {
+        self.control_persist
+    }
+
+    /// Returns the duration to wait for a connection to be established.
+    pub fn connect_timeout(&self) -> Duration {
+        self.connect_timeout
+    }
+
+    /// Creates the control socket directory if it does not exist, and checks
+    /// that it is private to the current user.
+    ///
+    /// Returns [`SshCmdError::ControlDirInsecure`] if the directory is not
+    /// owned by the current user, or its mode is not `0700`, as other users
+    /// could otherwise use the control sockets to run commands.
+    pub(crate) async fn control_dir_ensure(&self) -> Result<(), SshCmdError> {
+        let control_dir = self.control_dir.as_path();
+        tokio::fs::DirBuilder::new()
+            .recursive(true)
+            .mode(0o700)
+            .create(control_dir)
+            .await
+            .map_err(|error| SshCmdError::ControlDirCreate {
+                control_dir: control_dir.to_path_buf(),
+                error,
+            })?;
+
+        // `symlink_metadata` is used so that a symlink to another user's
+        // directory is not followed.
+        let metadata = tokio::fs::symlink_metadata(control_dir)
+            .await
+            .map_err(|error| SshCmdError::ControlDirMetadataRead {
+                control_dir: control_dir.to_path_buf(),
+                error,
+            })?;
+        // Safety: `geteuid` is always successful, and does not access memory.
+        let uid_current = unsafe { libc::geteuid() };
+        let uid = metadata.uid();
+        let mode = metadata.mode() & 0o777;
+        if metadata.is_dir() && uid == uid_current && mode == 0o700 {
+            Ok(())
+        } else {
+            Err(SshCmdError::ControlDirInsecure {
+                control_dir: control_dir.to_path_buf(),
+                uid,
+                uid_current,
+                mode,
+            })
+        }
+    }
+
+    /// Returns the `Command` to run `sh_cmd` on the given host.
+    ///
+    /// The program and arguments of `sh_cmd` are quoted, so they are passed
+    /// literally to the program on the remote host, the same way as they would
+    /// be locally.
+    ///
+    /// `BatchMode` is enabled, so the host must be reachable without prompting
+    /// for passwords or confirming host keys.
+    pub fn command(&self, ssh_host: &SshHost, sh_cmd: &ShCmd) -> Command {
+        let mut control_path = OsString::from("ControlPath=");
+        control_path.push(self.control_dir.join("%C"));
+
+        let mut command = Command::new(&self.ssh_program);
+        command
+            .arg("-o")
+            .arg("BatchMode=yes")
+            .arg("-o")
+            .arg("ControlMaster=auto")
+            .arg("-o")
+            .arg(control_path)
+            .arg("-o")
+            .arg(format!("ControlPersist={}", self.control_persist.as_secs()))
+            .arg("-o")
+            .arg(format!("ConnectTimeout={}", self.connect_timeout.as_secs()));
+        if let Some(port) = ssh_host.port() {
+            command.arg("-p").arg(port.to_string());
+        }
+        if let Some(user) = ssh_host.user() {
+            command.arg("-l").arg(user);
+        }
+        match ssh_host.auth() {
+            SshAuth::Agent => {}
+            SshAuth::IdentityFile { path } => {
+                command
+                    .arg("-i")
+                    .arg(path)
+                    .arg("-o")
+                    .arg("IdentitiesOnly=yes");
+            }
+        }
+        command
+            .arg("--")
+            .arg(ssh_host.host())
+            .arg(Self::remote_cmd(sh_cmd));
+
+        command
+    }
+
+    /// Returns the command string for the remote host's shell to run.
+    fn remote_cmd(sh_cmd: &ShCmd) -> String {
+        let command = Command::from(sh_cmd);
+        let command = command.as_std();
+
+        std::iter::once(command.get_program())
+            .chain(command.get_args())
+            .map(Self::shell_quote)
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    /// Quotes `arg` so that it is interpreted literally by a POSIX shell.
+    fn shell_quote(arg: &OsStr) -> String {
+        let arg = arg.to_string_lossy();
+        let is_safe = !arg.is_empty()
+            && arg
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "_-./=:,@+%".contains(c));
+
+        if is_safe {
+            arg.into_owned()
+        } else {
+            format!("'{}'", arg.replace('\'', r"'\''"))
+        }
+    }
+}
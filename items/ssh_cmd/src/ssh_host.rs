@@ -0,0 +1,86 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::SshAuth;
+
+/// Remote host to run commands on over SSH.
+///
+/// When the host is created by a predecessor item, such as a server launched
+/// earlier in the flow, this may be mapped from that item's state using
+/// `SshCmdParams::field_wise_spec().with_ssh_host_from_map(..)`.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub struct SshHost {
+    /// Host name or IP address to connect to.
+    host: String,
+    /// Port to connect to, defaults to the SSH configuration's port.
+    port: Option<u16>,
+    /// User to log in as, defaults to the SSH configuration's user.
+    user: Option<String>,
+    /// How to authenticate with the host.
+    auth: SshAuth,
+}
+
+impl SshHost {
+    /// Returns a new `SshHost` using the SSH configuration's port, user, and
+    /// identities.
+    pub fn new(host: impl Into<String>) -> Self {
+        Self {
+            host: host.into(),
+            port: None,
+            user: None,
+            auth: SshAuth::Agent,
+        }
+    }
+
+    /// Sets the port to connect to.
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Sets the user to log in as.
+    pub fn with_user(mut self, user: impl Into<String>) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+
+    /// Sets how to authenticate with the host.
+    pub fn with_auth(mut self, auth: SshAuth) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Returns the host name or IP address to connect to.
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Returns the port to connect to, if specified.
+    pub fn port(&self) -> Option<u16> {
+        self.port
+    }
+
+    /// Returns the user to log in as, if specified.
+    pub fn user(&self) -> Option<&str> {
+        self.user.as_deref()
+    }
+
+    /// Returns how to authenticate with the host.
+    pub fn auth(&self) -> &SshAuth {
+        &self.auth
+    }
+}
+
+impl fmt::Display for SshHost {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(user) = self.user.as_deref() {
+            write!(f, "{user}@")?;
+        }
+        self.host.fmt(f)?;
+        if let Some(port) = self.port {
+            write!(f, ":{port}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+//! Manages a systemd service for the peace framework.
+//!
+//! This item writes a unit file, and ensures the unit is enabled and started.
+//! Cleaning the item stops and disables the unit, and removes the unit file.
+//!
+//! This item is only available on unix platforms, and requires `systemctl` to
+//! be on the `PATH`.
+
+#![cfg(unix)]
+
+pub use crate::{
+    systemd_active_state::SystemdActiveState,
+    systemd_enabled_state::SystemdEnabledState,
+    systemd_service_apply_fns::SystemdServiceApplyFns,
+    systemd_service_data::SystemdServiceData,
+    systemd_service_error::SystemdServiceError,
+    systemd_service_item::SystemdServiceItem,
+    systemd_service_params::{
+        SystemdServiceParams, SystemdServiceParamsFieldWise, SystemdServiceParamsPartial,
+    },
+    systemd_service_state::SystemdServiceState,
+    systemd_service_state_current_fn::SystemdServiceStateCurrentFn,
+    systemd_service_state_diff::SystemdServiceStateDiff,
+    systemd_service_state_diff_fn::SystemdServiceStateDiffFn,
+    systemd_service_state_goal_fn::SystemdServiceStateGoalFn,
+};
+
+pub(crate) use crate::systemctl::Systemctl;
+
+mod systemctl;
+mod systemd_active_state;
+mod systemd_enabled_state;
+mod systemd_service_apply_fns;
+mod systemd_service_data;
+mod systemd_service_error;
+mod systemd_service_item;
+mod systemd_service_params;
+mod systemd_service_state;
+mod systemd_service_state_current_fn;
+mod systemd_service_state_diff;
+mod systemd_service_state_diff_fn;
+mod systemd_service_state_goal_fn;
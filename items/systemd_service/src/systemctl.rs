@@ -0,0 +1,51 @@
+use std::process::{Output, Stdio};
+
+use tokio::process::Command;
+
+use crate::SystemdServiceError;
+
+/// Runs `systemctl` commands.
+#[derive(Debug)]
+pub(crate) struct Systemctl;
+
+impl Systemctl {
+    /// Runs `systemctl` with the given arguments, and returns its trimmed
+    /// stdout.
+    ///
+    /// The exit code is not checked, as query subcommands such as `is-active`
+    /// return a non-zero exit code to indicate the state.
+    pub async fn query(args: &[&str]) -> Result<String, SystemdServiceError> {
+        let output = Self::output(args).await?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    /// Runs `systemctl` with the given arguments, returning an error if it
+    /// does not succeed.
+    pub async fn exec(args: &[&str]) -> Result<(), SystemdServiceError> {
+        let output = Self::output(args).await?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(SystemdServiceError::SystemctlFail {
+                args: args.join(" "),
+                exit_code: output.status.code(),
+                stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            })
+        }
+    }
+
+    async fn output(args: &[&str]) -> Result<Output, SystemdServiceError> {
+        Command::new("systemctl")
+            .args(args)
+            .stdin(Stdio::null())
+            .kill_on_drop(true)
+            .output()
+            .await
+            .map_err(|error| SystemdServiceError::SystemctlExec {
+                args: args.join(" "),
+                error,
+            })
+    }
+}
@@ -0,0 +1,34 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Whether a systemd unit is running.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum SystemdActiveState {
+    /// Unit is started.
+    Active,
+    /// Unit is stopped, failed, or does not exist.
+    Inactive,
+}
+
+impl SystemdActiveState {
+    /// Returns the active state from the output of `systemctl is-active`.
+    ///
+    /// `reloading` is treated as active, and every other value is treated as
+    /// inactive.
+    pub fn from_is_active_output(output: &str) -> Self {
+        match output.trim() {
+            "active" | "reloading" => Self::Active,
+            _ => Self::Inactive,
+        }
+    }
+}
+
+impl fmt::Display for SystemdActiveState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Active => "active".fmt(f),
+            Self::Inactive => "inactive".fmt(f),
+        }
+    }
+}
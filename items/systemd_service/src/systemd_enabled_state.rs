@@ -0,0 +1,34 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Whether a systemd unit is started on boot.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum SystemdEnabledState {
+    /// Unit is enabled.
+    Enabled,
+    /// Unit is disabled, masked, or does not exist.
+    Disabled,
+}
+
+impl SystemdEnabledState {
+    /// Returns the enabled state from the output of `systemctl is-enabled`.
+    ///
+    /// `enabled` and `enabled-runtime` are treated as enabled, and every
+    /// other value is treated as disabled.
+    pub fn from_is_enabled_output(output: &str) -> Self {
+        match output.trim() {
+            "enabled" | "enabled-runtime" => Self::Enabled,
+            _ => Self::Disabled,
+        }
+    }
+}
+
+impl fmt::Display for SystemdEnabledState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Enabled => "enabled".fmt(f),
+            Self::Disabled => "disabled".fmt(f),
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use std::marker::PhantomData;
+
+#[cfg(feature = "output_progress")]
+use peace::cfg::progress::ProgressLimit;
+use peace::cfg::{ApplyCheck, FnCtx};
+
+use crate::{
+    Systemctl, SystemdActiveState, SystemdEnabledState, SystemdServiceData, SystemdServiceError,
+    SystemdServiceParams, SystemdServiceState, SystemdServiceStateCurrentFn,
+    SystemdServiceStateDiff,
+};
+
+/// ApplyFns for the systemd service.
+#[derive(Debug)]
+pub struct SystemdServiceApplyFns<Id>(PhantomData<Id>);
+
+impl<Id> SystemdServiceApplyFns<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn apply_check(
+        _params: &SystemdServiceParams<Id>,
+        _data: SystemdServiceData<'_, Id>,
+        _state_current: &SystemdServiceState,
+        _state_target: &SystemdServiceState,
+        diff: &SystemdServiceStateDiff,
+    ) -> Result<ApplyCheck, SystemdServiceError> {
+        let apply_check = if diff.is_in_sync() {
+            ApplyCheck::ExecNotRequired
+        } else {
+            #[cfg(not(feature = "output_progress"))]
+            {
+                ApplyCheck::ExecRequired { reason: None }
+            }
+            #[cfg(feature = "output_progress")]
+            {
+                ApplyCheck::ExecRequired {
+                    progress_limit: ProgressLimit::Unknown,
+                    reason: None,
+                }
+            }
+        };
+
+        Ok(apply_check)
+    }
+
+    pub async fn apply_dry(
+        _fn_ctx: FnCtx<'_>,
+        _params: &SystemdServiceParams<Id>,
+        _data: SystemdServiceData<'_, Id>,
+        _state_current: &SystemdServiceState,
+        state_target: &SystemdServiceState,
+        _diff: &SystemdServiceStateDiff,
+    ) -> Result<SystemdServiceState, SystemdServiceError> {
+        Ok(state_target.clone())
+    }
+
+    pub async fn apply(
+        _fn_ctx: FnCtx<'_>,
+        params: &SystemdServiceParams<Id>,
+        _data: SystemdServiceData<'_, Id>,
+        _state_current: &SystemdServiceState,
+        state_target: &SystemdServiceState,
+        diff: &SystemdServiceStateDiff,
+    ) -> Result<SystemdServiceState, SystemdServiceError> {
+        let unit_name = params.unit_name();
+        let unit_file_path = params.unit_file_path();
+
+        // The unit file must exist before the unit can be enabled or started.
+        if diff.unit_file_hash.is_some() && state_target.unit_file_hash.is_some() {
+            let unit_dir = params.unit_dir();
+            tokio::fs::create_dir_all(unit_dir).await.map_err(|error| {
+                SystemdServiceError::UnitDirCreate {
+                    path: unit_dir.to_path_buf(),
+                    error,
+                }
+            })?;
+            tokio::fs::write(&unit_file_path, params.unit_file_content())
+                .await
+                .map_err(|error| SystemdServiceError::UnitFileWrite {
+                    path: unit_file_path.clone(),
+                    error,
+                })?;
+            Systemctl::exec(&["daemon-reload"]).await?;
+        }
+
+        match diff.active_state {
+            Some((_, SystemdActiveState::Active)) => {
+                Systemctl::exec(&["start", unit_name]).await?;
+            }
+            Some((_, SystemdActiveState::Inactive)) => {
+                Systemctl::exec(&["stop", unit_name]).await?;
+            }
+            None => {
+                // Restart the unit so that it runs with the updated unit file.
+                if diff.unit_file_hash.is_some()
+                    && state_target.active_state == SystemdActiveState::Active
+                {
+                    Systemctl::exec(&["restart", unit_name]).await?;
+                }
+            }
+        }
+
+        match diff.enabled_state {
+            Some((_, SystemdEnabledState::Enabled)) => {
+                Systemctl::exec(&["enable", unit_name]).await?;
+            }
+            Some((_, SystemdEnabledState::Disabled)) => {
+                Systemctl::exec(&["disable", unit_name]).await?;
+            }
+            None => {}
+        }
+
+        // The unit file is removed after the unit is stopped and disabled.
+        if diff.unit_file_hash.is_some() && state_target.unit_file_hash.is_none() {
+            tokio::fs::remove_file(&unit_file_path)
+                .await
+                .map_err(|error| SystemdServiceError::UnitFileRemove {
+                    path: unit_file_path.clone(),
+                    error,
+                })?;
+            Systemctl::exec(&["daemon-reload"]).await?;
+        }
+
+        SystemdServiceStateCurrentFn::<Id>::state_current_internal(params.unit_dir(), unit_name)
+            .await
+    }
+}
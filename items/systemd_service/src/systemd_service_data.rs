@@ -0,0 +1,33 @@
+use std::marker::PhantomData;
+
+use peace::{cfg::accessors::Stored, data::Data};
+
+use crate::SystemdServiceState;
+
+/// Data used to manage a systemd service.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different systemd service
+///   parameters from each other.
+#[derive(Data, Debug)]
+pub struct SystemdServiceData<'exec, Id>
+where
+    Id: Send + Sync + 'static,
+{
+    /// Stored state of this item's previous execution.
+    state_current_stored: Stored<'exec, SystemdServiceState>,
+
+    /// Marker.
+    marker: PhantomData<Id>,
+}
+
+impl<'exec, Id> SystemdServiceData<'exec, Id>
+where
+    Id: Send + Sync + 'static,
+{
+    /// Returns the stored state of this item's previous execution.
+    pub fn state_current_stored(&self) -> Option<&SystemdServiceState> {
+        self.state_current_stored.get()
+    }
+}
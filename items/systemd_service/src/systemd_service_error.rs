@@ -0,0 +1,119 @@
+use std::path::PathBuf;
+
+#[cfg(feature = "error_reporting")]
+use peace::miette;
+
+/// Error while managing a systemd service.
+#[cfg_attr(feature = "error_reporting", derive(peace::miette::Diagnostic))]
+#[derive(Debug, thiserror::Error)]
+pub enum SystemdServiceError {
+    /// Failed to read the unit file.
+    #[error("Failed to read unit file: `{}`.", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_systemd_service::unit_file_read))
+    )]
+    UnitFileRead {
+        /// Path to the unit file.
+        path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to create the directory to write the unit file to.
+    #[error("Failed to create unit directory: `{}`.", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_systemd_service::unit_dir_create),
+            help("Check that the current user has permission to write to the directory.")
+        )
+    )]
+    UnitDirCreate {
+        /// Path to the unit directory.
+        path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to write the unit file.
+    #[error("Failed to write unit file: `{}`.", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_systemd_service::unit_file_write),
+            help("Check that the current user has permission to write to the file.")
+        )
+    )]
+    UnitFileWrite {
+        /// Path to the unit file.
+        path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to remove the unit file.
+    #[error("Failed to remove unit file: `{}`.", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_systemd_service::unit_file_remove),
+            help("Check that the current user has permission to remove the file.")
+        )
+    )]
+    UnitFileRemove {
+        /// Path to the unit file.
+        path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to execute `systemctl`.
+    #[error("Failed to execute `systemctl {args}`.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_systemd_service::systemctl_exec),
+            help("Check that `systemctl` is on the `PATH`.")
+        )
+    )]
+    SystemctlExec {
+        /// Arguments passed to `systemctl`.
+        args: String,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// `systemctl` returned a non-zero exit code.
+    #[error("`systemctl {args}` failed with exit code {exit_code:?}:\n{stderr}")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_systemd_service::systemctl_fail),
+            help("Check that the current user has permission to manage the unit.")
+        )
+    )]
+    SystemctlFail {
+        /// Arguments passed to `systemctl`.
+        args: String,
+        /// Exit code of the process, `None` if it was terminated by a signal.
+        exit_code: Option<i32>,
+        /// Output written to stderr.
+        stderr: String,
+    },
+
+    // === Framework errors === //
+    /// A `peace` runtime error occurred.
+    #[error("A `peace` runtime error occurred.")]
+    PeaceRtError(
+        #[cfg_attr(feature = "error_reporting", diagnostic_source)]
+        #[source]
+        #[from]
+        peace::rt_model::Error,
+    ),
+}
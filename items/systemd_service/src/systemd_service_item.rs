@@ -0,0 +1,188 @@
+use std::marker::PhantomData;
+
+use peace::{
+    cfg::{async_trait, ApplyCheck, ApplyCtx, DiffSeverity, FnCtx, Item, ItemId},
+    params::Params,
+    resources::{resources::ts::Empty, Resources},
+};
+
+use crate::{
+    SystemdActiveState, SystemdEnabledState, SystemdServiceApplyFns, SystemdServiceData,
+    SystemdServiceError, SystemdServiceParams, SystemdServiceState, SystemdServiceStateCurrentFn,
+    SystemdServiceStateDiff, SystemdServiceStateDiffFn, SystemdServiceStateGoalFn,
+};
+
+/// Item for managing a systemd service.
+///
+/// The goal state is for the unit file to contain the given contents, and for
+/// the unit to be active and enabled. Cleaning the item stops and disables the
+/// unit, and removes the unit file.
+///
+/// The `Id` type parameter is needed for each systemd service params to be a
+/// distinct type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different systemd service
+///   parameters from each other.
+#[derive(Debug)]
+pub struct SystemdServiceItem<Id> {
+    /// ID of the item to manage the systemd service.
+    item_id: ItemId,
+    /// Marker for unique systemd service parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> Clone for SystemdServiceItem<Id> {
+    fn clone(&self) -> Self {
+        Self {
+            item_id: self.item_id.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<Id> SystemdServiceItem<Id> {
+    /// Returns a new `SystemdServiceItem`.
+    pub fn new(item_id: ItemId) -> Self {
+        Self {
+            item_id,
+            marker: PhantomData,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<Id> Item for SystemdServiceItem<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    type Data<'exec> = SystemdServiceData<'exec, Id>;
+    type Error = SystemdServiceError;
+    type Params<'exec> = SystemdServiceParams<Id>;
+    type State = SystemdServiceState;
+    type StateDiff = SystemdServiceStateDiff;
+
+    fn id(&self) -> &ItemId {
+        &self.item_id
+    }
+
+    async fn setup(&self, _resources: &mut Resources<Empty>) -> Result<(), SystemdServiceError> {
+        Ok(())
+    }
+
+    async fn try_state_current(
+        fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        data: SystemdServiceData<'_, Id>,
+    ) -> Result<Option<Self::State>, SystemdServiceError> {
+        SystemdServiceStateCurrentFn::try_state_current(fn_ctx, params_partial, data).await
+    }
+
+    async fn state_current(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: SystemdServiceData<'_, Id>,
+    ) -> Result<Self::State, SystemdServiceError> {
+        SystemdServiceStateCurrentFn::state_current(fn_ctx, params, data).await
+    }
+
+    async fn try_state_goal(
+        fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        data: SystemdServiceData<'_, Id>,
+    ) -> Result<Option<Self::State>, SystemdServiceError> {
+        SystemdServiceStateGoalFn::try_state_goal(fn_ctx, params_partial, data).await
+    }
+
+    async fn state_goal(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: SystemdServiceData<'_, Id>,
+    ) -> Result<Self::State, SystemdServiceError> {
+        SystemdServiceStateGoalFn::state_goal(fn_ctx, params, data).await
+    }
+
+    async fn state_diff(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_goal: &Self::State,
+    ) -> Result<Self::StateDiff, SystemdServiceError> {
+        SystemdServiceStateDiffFn::state_diff(state_current, state_goal).await
+    }
+
+    fn diff_severity(
+        _state_current: &Self::State,
+        _state_goal: &Self::State,
+        state_diff: &Self::StateDiff,
+    ) -> DiffSeverity {
+        let unit_file_removed = matches!(state_diff.unit_file_hash, Some((Some(_), None)));
+        let unit_stopped = matches!(
+            state_diff.active_state,
+            Some((SystemdActiveState::Active, SystemdActiveState::Inactive))
+        );
+        let unit_disabled = matches!(
+            state_diff.enabled_state,
+            Some((SystemdEnabledState::Enabled, SystemdEnabledState::Disabled))
+        );
+
+        if state_diff.is_in_sync() {
+            DiffSeverity::InSync
+        } else if unit_file_removed || unit_stopped || unit_disabled {
+            DiffSeverity::Destructive
+        } else {
+            DiffSeverity::Additive
+        }
+    }
+
+    async fn state_clean(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+    ) -> Result<Self::State, SystemdServiceError> {
+        Ok(SystemdServiceState::clean())
+    }
+
+    async fn apply_check(
+        _apply_ctx: ApplyCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<ApplyCheck, Self::Error> {
+        SystemdServiceApplyFns::<Id>::apply_check(params, data, state_current, state_target, diff)
+            .await
+    }
+
+    async fn apply_dry(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        SystemdServiceApplyFns::<Id>::apply_dry(
+            fn_ctx,
+            params,
+            data,
+            state_current,
+            state_target,
+            diff,
+        )
+        .await
+    }
+
+    async fn apply(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        SystemdServiceApplyFns::<Id>::apply(fn_ctx, params, data, state_current, state_target, diff)
+            .await
+    }
+}
@@ -0,0 +1,63 @@
+use std::{
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+use derivative::Derivative;
+use peace::params::Params;
+use serde::{Deserialize, Serialize};
+
+/// Systemd service parameters.
+///
+/// The `Id` type parameter is needed for each systemd service params to be a
+/// distinct type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different systemd service
+///   parameters from each other.
+#[derive(Derivative, Params, PartialEq, Eq, Deserialize, Serialize)]
+#[derivative(Clone, Debug)]
+#[serde(bound = "")]
+pub struct SystemdServiceParams<Id> {
+    /// Directory to write the unit file to, e.g. `/etc/systemd/system`.
+    unit_dir: PathBuf,
+    /// Name of the unit, e.g. `my_app.service`.
+    unit_name: String,
+    /// Contents of the unit file.
+    unit_file_content: String,
+    /// Marker for unique systemd service parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> SystemdServiceParams<Id> {
+    /// Returns new `SystemdServiceParams`.
+    pub fn new(unit_dir: PathBuf, unit_name: String, unit_file_content: String) -> Self {
+        Self {
+            unit_dir,
+            unit_name,
+            unit_file_content,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the directory to write the unit file to.
+    pub fn unit_dir(&self) -> &Path {
+        &self.unit_dir
+    }
+
+    /// Returns the name of the unit.
+    pub fn unit_name(&self) -> &str {
+        &self.unit_name
+    }
+
+    /// Returns the contents of the unit file.
+    pub fn unit_file_content(&self) -> &str {
+        &self.unit_file_content
+    }
+
+    /// Returns the path to the unit file.
+    pub fn unit_file_path(&self) -> PathBuf {
+        self.unit_dir.join(&self.unit_name)
+    }
+}
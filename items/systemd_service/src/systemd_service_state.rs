@@ -0,0 +1,75 @@
+use std::fmt;
+
+use ring::digest;
+use serde::{Deserialize, Serialize};
+
+use crate::{SystemdActiveState, SystemdEnabledState};
+
+/// State of a systemd service.
+///
+/// This is the hash of the unit file, along with whether the unit is active
+/// and enabled.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct SystemdServiceState {
+    /// SHA-256 hash of the unit file contents, `None` if the file does not
+    /// exist.
+    pub unit_file_hash: Option<String>,
+    /// Whether the unit is running.
+    pub active_state: SystemdActiveState,
+    /// Whether the unit is started on boot.
+    pub enabled_state: SystemdEnabledState,
+}
+
+impl SystemdServiceState {
+    /// Returns a new `SystemdServiceState`.
+    pub fn new(
+        unit_file_hash: Option<String>,
+        active_state: SystemdActiveState,
+        enabled_state: SystemdEnabledState,
+    ) -> Self {
+        Self {
+            unit_file_hash,
+            active_state,
+            enabled_state,
+        }
+    }
+
+    /// Returns the state when the unit file does not exist, and the unit is
+    /// inactive and disabled.
+    pub fn clean() -> Self {
+        Self {
+            unit_file_hash: None,
+            active_state: SystemdActiveState::Inactive,
+            enabled_state: SystemdEnabledState::Disabled,
+        }
+    }
+
+    /// Returns the hex encoded SHA-256 hash of the given unit file contents.
+    pub fn unit_file_hash(unit_file_content: &[u8]) -> String {
+        digest::digest(&digest::SHA256, unit_file_content)
+            .as_ref()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+}
+
+impl fmt::Display for SystemdServiceState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            unit_file_hash,
+            active_state,
+            enabled_state,
+        } = self;
+
+        match unit_file_hash {
+            Some(unit_file_hash) => {
+                let hash_short = unit_file_hash.get(..8).unwrap_or(unit_file_hash);
+                write!(f, "unit file `{hash_short}`")?;
+            }
+            None => write!(f, "unit file does not exist")?,
+        }
+
+        write!(f, ", {active_state}, {enabled_state}")
+    }
+}
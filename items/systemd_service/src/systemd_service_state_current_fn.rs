@@ -0,0 +1,68 @@
+use std::{io::ErrorKind, marker::PhantomData, path::Path};
+
+use peace::{cfg::FnCtx, params::Params};
+
+use crate::{
+    Systemctl, SystemdActiveState, SystemdEnabledState, SystemdServiceData, SystemdServiceError,
+    SystemdServiceParams, SystemdServiceState,
+};
+
+/// Reads the current state of the systemd service.
+#[derive(Debug)]
+pub struct SystemdServiceStateCurrentFn<Id>(PhantomData<Id>);
+
+impl<Id> SystemdServiceStateCurrentFn<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn try_state_current(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<SystemdServiceParams<Id> as Params>::Partial,
+        _data: SystemdServiceData<'_, Id>,
+    ) -> Result<Option<SystemdServiceState>, SystemdServiceError> {
+        match (params_partial.unit_dir(), params_partial.unit_name()) {
+            (Some(unit_dir), Some(unit_name)) => Self::state_current_internal(unit_dir, unit_name)
+                .await
+                .map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    pub async fn state_current(
+        _fn_ctx: FnCtx<'_>,
+        params: &SystemdServiceParams<Id>,
+        _data: SystemdServiceData<'_, Id>,
+    ) -> Result<SystemdServiceState, SystemdServiceError> {
+        Self::state_current_internal(params.unit_dir(), params.unit_name()).await
+    }
+
+    pub(crate) async fn state_current_internal(
+        unit_dir: &Path,
+        unit_name: &str,
+    ) -> Result<SystemdServiceState, SystemdServiceError> {
+        let unit_file_path = unit_dir.join(unit_name);
+        let unit_file_hash = match tokio::fs::read(&unit_file_path).await {
+            Ok(unit_file_content) => Some(SystemdServiceState::unit_file_hash(&unit_file_content)),
+            Err(error) if error.kind() == ErrorKind::NotFound => None,
+            Err(error) => {
+                return Err(SystemdServiceError::UnitFileRead {
+                    path: unit_file_path,
+                    error,
+                });
+            }
+        };
+
+        let active_state = Systemctl::query(&["is-active", unit_name])
+            .await
+            .map(|output| SystemdActiveState::from_is_active_output(&output))?;
+        let enabled_state = Systemctl::query(&["is-enabled", unit_name])
+            .await
+            .map(|output| SystemdEnabledState::from_is_enabled_output(&output))?;
+
+        Ok(SystemdServiceState::new(
+            unit_file_hash,
+            active_state,
+            enabled_state,
+        ))
+    }
+}
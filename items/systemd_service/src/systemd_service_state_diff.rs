@@ -0,0 +1,52 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{SystemdActiveState, SystemdEnabledState};
+
+/// Diff between the current and goal systemd service state.
+///
+/// Each field is `Some((from, to))` when that part of the state differs.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct SystemdServiceStateDiff {
+    /// Change in the unit file hash.
+    pub unit_file_hash: Option<(Option<String>, Option<String>)>,
+    /// Change in whether the unit is running.
+    pub active_state: Option<(SystemdActiveState, SystemdActiveState)>,
+    /// Change in whether the unit is started on boot.
+    pub enabled_state: Option<(SystemdEnabledState, SystemdEnabledState)>,
+}
+
+impl SystemdServiceStateDiff {
+    /// Returns whether the unit file, active state, and enabled state are all
+    /// in sync.
+    pub fn is_in_sync(&self) -> bool {
+        self.unit_file_hash.is_none() && self.active_state.is_none() && self.enabled_state.is_none()
+    }
+}
+
+impl fmt::Display for SystemdServiceStateDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_in_sync() {
+            return write!(f, "in sync");
+        }
+
+        let mut changes = Vec::with_capacity(3);
+        if let Some(unit_file_hash) = self.unit_file_hash.as_ref() {
+            let change = match unit_file_hash {
+                (None, Some(_)) => "unit file will be created",
+                (Some(_), None) => "unit file will be removed",
+                (Some(_), Some(_)) | (None, None) => "unit file will be modified",
+            };
+            changes.push(change.to_string());
+        }
+        if let Some((from, to)) = self.active_state {
+            changes.push(format!("{from} -> {to}"));
+        }
+        if let Some((from, to)) = self.enabled_state {
+            changes.push(format!("{from} -> {to}"));
+        }
+
+        write!(f, "{}", changes.join(", "))
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{SystemdServiceError, SystemdServiceState, SystemdServiceStateDiff};
+
+/// Systemd service state diff function.
+#[derive(Debug)]
+pub struct SystemdServiceStateDiffFn;
+
+impl SystemdServiceStateDiffFn {
+    pub async fn state_diff(
+        state_current: &SystemdServiceState,
+        state_goal: &SystemdServiceState,
+    ) -> Result<SystemdServiceStateDiff, SystemdServiceError> {
+        let unit_file_hash =
+            (state_current.unit_file_hash != state_goal.unit_file_hash).then(|| {
+                (
+                    state_current.unit_file_hash.clone(),
+                    state_goal.unit_file_hash.clone(),
+                )
+            });
+        let active_state = (state_current.active_state != state_goal.active_state)
+            .then_some((state_current.active_state, state_goal.active_state));
+        let enabled_state = (state_current.enabled_state != state_goal.enabled_state)
+            .then_some((state_current.enabled_state, state_goal.enabled_state));
+
+        Ok(SystemdServiceStateDiff {
+            unit_file_hash,
+            active_state,
+            enabled_state,
+        })
+    }
+}
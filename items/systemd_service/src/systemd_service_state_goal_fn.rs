@@ -0,0 +1,48 @@
+use std::marker::PhantomData;
+
+use peace::{cfg::FnCtx, params::Params};
+
+use crate::{
+    SystemdActiveState, SystemdEnabledState, SystemdServiceData, SystemdServiceError,
+    SystemdServiceParams, SystemdServiceState,
+};
+
+/// Reads the goal state of the systemd service.
+///
+/// The goal is for the unit file to contain the given contents, and for the
+/// unit to be active and enabled.
+#[derive(Debug)]
+pub struct SystemdServiceStateGoalFn<Id>(PhantomData<Id>);
+
+impl<Id> SystemdServiceStateGoalFn<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn try_state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<SystemdServiceParams<Id> as Params>::Partial,
+        _data: SystemdServiceData<'_, Id>,
+    ) -> Result<Option<SystemdServiceState>, SystemdServiceError> {
+        Ok(params_partial
+            .unit_file_content()
+            .map(|unit_file_content| Self::state_goal_internal(unit_file_content)))
+    }
+
+    pub async fn state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params: &SystemdServiceParams<Id>,
+        _data: SystemdServiceData<'_, Id>,
+    ) -> Result<SystemdServiceState, SystemdServiceError> {
+        Ok(Self::state_goal_internal(params.unit_file_content()))
+    }
+
+    fn state_goal_internal(unit_file_content: &str) -> SystemdServiceState {
+        SystemdServiceState::new(
+            Some(SystemdServiceState::unit_file_hash(
+                unit_file_content.as_bytes(),
+            )),
+            SystemdActiveState::Active,
+            SystemdEnabledState::Enabled,
+        )
+    }
+}
@@ -38,7 +38,7 @@ where
             } => {
                 #[cfg(not(feature = "output_progress"))]
                 {
-                    ApplyCheck::ExecRequired
+                    ApplyCheck::ExecRequired { reason: None }
                 }
                 #[cfg(feature = "output_progress")]
                 {
@@ -47,7 +47,10 @@ where
                         .try_into()
                         .map(ProgressLimit::Steps)
                         .unwrap_or(ProgressLimit::Unknown);
-                    ApplyCheck::ExecRequired { progress_limit }
+                    ApplyCheck::ExecRequired {
+                        progress_limit,
+                        reason: None,
+                    }
                 }
             }
         };
@@ -1,7 +1,7 @@
 use std::marker::PhantomData;
 
 use peace::{
-    cfg::{async_trait, ApplyCheck, FnCtx, Item, ItemId},
+    cfg::{async_trait, ApplyCheck, ApplyCtx, DiffSeverity, FnCtx, Item, ItemId},
     params::Params,
     resources::{resources::ts::Empty, Resources},
 };
@@ -113,6 +113,20 @@ where
         TarXStateDiffFn::state_diff(state_current, state_goal).await
     }
 
+    fn diff_severity(
+        _state_current: &Self::State,
+        _state_goal: &Self::State,
+        state_diff: &Self::StateDiff,
+    ) -> DiffSeverity {
+        match state_diff {
+            TarXStateDiff::ExtractionInSync => DiffSeverity::InSync,
+            TarXStateDiff::ExtractionOutOfSync { removed, .. } if !removed.is_empty() => {
+                DiffSeverity::Destructive
+            }
+            TarXStateDiff::ExtractionOutOfSync { .. } => DiffSeverity::Additive,
+        }
+    }
+
     async fn state_clean(
         _params_partial: &<Self::Params<'_> as Params>::Partial,
         _data: Self::Data<'_>,
@@ -121,6 +135,7 @@ where
     }
 
     async fn apply_check(
+        _apply_ctx: ApplyCtx<'_>,
         params: &Self::Params<'_>,
         data: Self::Data<'_>,
         state_current: &Self::State,
@@ -0,0 +1,38 @@
+//! Renders a template into a file for the peace framework.
+//!
+//! This item renders a [Tera] template with the given values, and writes the
+//! rendered content to a destination file. Values may be resolved from other
+//! items' states, so configuration files can be generated from resources that
+//! are created earlier in the flow.
+//!
+//! Cleaning this item removes the destination file.
+//!
+//! [Tera]: https://keats.github.io/tera/
+
+#![cfg(not(target_arch = "wasm32"))]
+
+pub use crate::{
+    template_render_apply_fns::TemplateRenderApplyFns,
+    template_render_data::TemplateRenderData,
+    template_render_error::TemplateRenderError,
+    template_render_item::TemplateRenderItem,
+    template_render_params::{
+        TemplateRenderParams, TemplateRenderParamsFieldWise, TemplateRenderParamsPartial,
+    },
+    template_render_state::TemplateRenderState,
+    template_render_state_current_fn::TemplateRenderStateCurrentFn,
+    template_render_state_diff::TemplateRenderStateDiff,
+    template_render_state_diff_fn::TemplateRenderStateDiffFn,
+    template_render_state_goal_fn::TemplateRenderStateGoalFn,
+};
+
+mod template_render_apply_fns;
+mod template_render_data;
+mod template_render_error;
+mod template_render_item;
+mod template_render_params;
+mod template_render_state;
+mod template_render_state_current_fn;
+mod template_render_state_diff;
+mod template_render_state_diff_fn;
+mod template_render_state_goal_fn;
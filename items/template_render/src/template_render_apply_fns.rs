@@ -0,0 +1,114 @@
+use std::{io::ErrorKind, marker::PhantomData};
+
+#[cfg(feature = "output_progress")]
+use peace::cfg::progress::ProgressLimit;
+use peace::cfg::{ApplyCheck, FnCtx};
+
+use crate::{
+    TemplateRenderData, TemplateRenderError, TemplateRenderParams, TemplateRenderState,
+    TemplateRenderStateDiff, TemplateRenderStateGoalFn,
+};
+
+/// ApplyFns for the rendered template file.
+#[derive(Debug)]
+pub struct TemplateRenderApplyFns<Id>(PhantomData<Id>);
+
+impl<Id> TemplateRenderApplyFns<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn apply_check(
+        _params: &TemplateRenderParams<Id>,
+        _data: TemplateRenderData<'_, Id>,
+        _state_current: &TemplateRenderState,
+        _state_target: &TemplateRenderState,
+        diff: &TemplateRenderStateDiff,
+    ) -> Result<ApplyCheck, TemplateRenderError> {
+        let apply_check = if diff.is_in_sync() {
+            ApplyCheck::ExecNotRequired
+        } else {
+            #[cfg(not(feature = "output_progress"))]
+            {
+                ApplyCheck::ExecRequired { reason: None }
+            }
+            #[cfg(feature = "output_progress")]
+            {
+                ApplyCheck::ExecRequired {
+                    progress_limit: ProgressLimit::Steps(1),
+                    reason: None,
+                }
+            }
+        };
+
+        Ok(apply_check)
+    }
+
+    pub async fn apply_dry(
+        _fn_ctx: FnCtx<'_>,
+        _params: &TemplateRenderParams<Id>,
+        _data: TemplateRenderData<'_, Id>,
+        _state_current: &TemplateRenderState,
+        state_target: &TemplateRenderState,
+        _diff: &TemplateRenderStateDiff,
+    ) -> Result<TemplateRenderState, TemplateRenderError> {
+        Ok(state_target.clone())
+    }
+
+    pub async fn apply(
+        _fn_ctx: FnCtx<'_>,
+        params: &TemplateRenderParams<Id>,
+        _data: TemplateRenderData<'_, Id>,
+        _state_current: &TemplateRenderState,
+        state_target: &TemplateRenderState,
+        _diff: &TemplateRenderStateDiff,
+    ) -> Result<TemplateRenderState, TemplateRenderError> {
+        let dest = params.dest();
+
+        match state_target {
+            TemplateRenderState::None => {
+                match tokio::fs::remove_file(dest).await {
+                    Ok(()) => {}
+                    Err(error) if error.kind() == ErrorKind::NotFound => {}
+                    Err(error) => {
+                        return Err(TemplateRenderError::DestRemove {
+                            path: dest.to_path_buf(),
+                            error,
+                        });
+                    }
+                }
+
+                Ok(TemplateRenderState::None)
+            }
+            TemplateRenderState::Rendered { .. } => {
+                // The template is rendered again, as the target state may have
+                // been read from storage, which does not carry the contents.
+                let template = params.template();
+                let values = params.values();
+                let content = TemplateRenderStateGoalFn::<Id>::render(template, values)?;
+                let params_digest =
+                    TemplateRenderStateGoalFn::<Id>::params_digest(template, values)?;
+
+                if let Some(dest_dir) = dest.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+                    tokio::fs::create_dir_all(dest_dir).await.map_err(|error| {
+                        TemplateRenderError::DestDirCreate {
+                            path: dest_dir.to_path_buf(),
+                            error,
+                        }
+                    })?;
+                }
+                tokio::fs::write(dest, &content).await.map_err(|error| {
+                    TemplateRenderError::DestWrite {
+                        path: dest.to_path_buf(),
+                        error,
+                    }
+                })?;
+
+                Ok(TemplateRenderState::Rendered {
+                    content_hash: TemplateRenderStateGoalFn::<Id>::content_hash(&content),
+                    params_digest: Some(params_digest),
+                    content: Some(content),
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use std::marker::PhantomData;
+
+use peace::{cfg::accessors::Stored, data::Data};
+
+use crate::TemplateRenderState;
+
+/// Data used to render a template into a file.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different template render
+///   parameters from each other.
+#[derive(Data, Debug)]
+pub struct TemplateRenderData<'exec, Id>
+where
+    Id: Send + Sync + 'static,
+{
+    /// Stored state of this item's previous execution.
+    state_current_stored: Stored<'exec, TemplateRenderState>,
+
+    /// Marker.
+    marker: PhantomData<Id>,
+}
+
+impl<'exec, Id> TemplateRenderData<'exec, Id>
+where
+    Id: Send + Sync + 'static,
+{
+    /// Returns the stored state of this item's previous execution.
+    pub fn state_current_stored(&self) -> Option<&TemplateRenderState> {
+        self.state_current_stored.get()
+    }
+}
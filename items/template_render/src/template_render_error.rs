@@ -0,0 +1,116 @@
+use std::path::PathBuf;
+
+#[cfg(feature = "error_reporting")]
+use peace::miette;
+
+/// Error while rendering a template into a file.
+#[cfg_attr(feature = "error_reporting", derive(peace::miette::Diagnostic))]
+#[derive(Debug, thiserror::Error)]
+pub enum TemplateRenderError {
+    /// Failed to render the template.
+    #[error("Failed to render template.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_template_render::template_render),
+            help(
+                "Check that the template syntax is valid, and that every value it uses is provided."
+            )
+        )
+    )]
+    TemplateRender {
+        /// Underlying template error.
+        #[source]
+        error: tera::Error,
+    },
+
+    /// Failed to serialize the template values to compute the params digest.
+    #[error("Failed to serialize template values.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_template_render::template_values_serialize))
+    )]
+    TemplateValuesSerialize {
+        /// Underlying serialization error.
+        #[source]
+        error: serde_json::Error,
+    },
+
+    /// Failed to read the destination file.
+    #[error("Failed to read rendered file: `{}`.", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_template_render::dest_read),
+            help("Check that the file is readable by the current user.")
+        )
+    )]
+    DestRead {
+        /// Path to the destination file.
+        path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to create the directory to write the destination file to.
+    #[error("Failed to create rendered file directory: `{}`.", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_template_render::dest_dir_create),
+            help("Check that the current user has permission to write to the directory.")
+        )
+    )]
+    DestDirCreate {
+        /// Path to the directory.
+        path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to write the destination file.
+    #[error("Failed to write rendered file: `{}`.", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_template_render::dest_write),
+            help("Check that the current user has permission to write to the file.")
+        )
+    )]
+    DestWrite {
+        /// Path to the destination file.
+        path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to remove the destination file.
+    #[error("Failed to remove rendered file: `{}`.", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_template_render::dest_remove),
+            help("Check that the current user has permission to remove the file.")
+        )
+    )]
+    DestRemove {
+        /// Path to the destination file.
+        path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    // === Framework errors === //
+    /// A `peace` runtime error occurred.
+    #[error("A `peace` runtime error occurred.")]
+    PeaceRtError(
+        #[cfg_attr(feature = "error_reporting", diagnostic_source)]
+        #[source]
+        #[from]
+        peace::rt_model::Error,
+    ),
+}
@@ -0,0 +1,181 @@
+use std::marker::PhantomData;
+
+use peace::{
+    cfg::{async_trait, ApplyCheck, ApplyCtx, DiffSeverity, FnCtx, Item, ItemId},
+    params::Params,
+    resources::{resources::ts::Empty, Resources},
+};
+
+use crate::{
+    TemplateRenderApplyFns, TemplateRenderData, TemplateRenderError, TemplateRenderParams,
+    TemplateRenderState, TemplateRenderStateCurrentFn, TemplateRenderStateDiff,
+    TemplateRenderStateDiffFn, TemplateRenderStateGoalFn,
+};
+
+/// Item for rendering a template into a file.
+///
+/// The goal state is for the file to contain the template rendered with the
+/// given values. The state records the hash of the file's contents, and the
+/// digest of the template and values it was rendered from. Cleaning the item
+/// removes the file.
+///
+/// The `Id` type parameter is needed for each template render params to be a
+/// distinct type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different template render
+///   parameters from each other.
+#[derive(Debug)]
+pub struct TemplateRenderItem<Id> {
+    /// ID of the item to render the template.
+    item_id: ItemId,
+    /// Marker for unique template render parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> Clone for TemplateRenderItem<Id> {
+    fn clone(&self) -> Self {
+        Self {
+            item_id: self.item_id.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<Id> TemplateRenderItem<Id> {
+    /// Returns a new `TemplateRenderItem`.
+    pub fn new(item_id: ItemId) -> Self {
+        Self {
+            item_id,
+            marker: PhantomData,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<Id> Item for TemplateRenderItem<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    type Data<'exec> = TemplateRenderData<'exec, Id>;
+    type Error = TemplateRenderError;
+    type Params<'exec> = TemplateRenderParams<Id>;
+    type State = TemplateRenderState;
+    type StateDiff = TemplateRenderStateDiff;
+
+    fn id(&self) -> &ItemId {
+        &self.item_id
+    }
+
+    async fn setup(&self, _resources: &mut Resources<Empty>) -> Result<(), TemplateRenderError> {
+        Ok(())
+    }
+
+    async fn try_state_current(
+        fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        data: TemplateRenderData<'_, Id>,
+    ) -> Result<Option<Self::State>, TemplateRenderError> {
+        TemplateRenderStateCurrentFn::try_state_current(fn_ctx, params_partial, data).await
+    }
+
+    async fn state_current(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: TemplateRenderData<'_, Id>,
+    ) -> Result<Self::State, TemplateRenderError> {
+        TemplateRenderStateCurrentFn::state_current(fn_ctx, params, data).await
+    }
+
+    async fn try_state_goal(
+        fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        data: TemplateRenderData<'_, Id>,
+    ) -> Result<Option<Self::State>, TemplateRenderError> {
+        TemplateRenderStateGoalFn::try_state_goal(fn_ctx, params_partial, data).await
+    }
+
+    async fn state_goal(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: TemplateRenderData<'_, Id>,
+    ) -> Result<Self::State, TemplateRenderError> {
+        TemplateRenderStateGoalFn::state_goal(fn_ctx, params, data).await
+    }
+
+    async fn state_diff(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_goal: &Self::State,
+    ) -> Result<Self::StateDiff, TemplateRenderError> {
+        TemplateRenderStateDiffFn::state_diff(state_current, state_goal).await
+    }
+
+    fn diff_severity(
+        _state_current: &Self::State,
+        _state_goal: &Self::State,
+        state_diff: &Self::StateDiff,
+    ) -> DiffSeverity {
+        // Replacing the contents is additive, as the file is generated from the
+        // template.
+        match state_diff {
+            TemplateRenderStateDiff::InSync => DiffSeverity::InSync,
+            TemplateRenderStateDiff::Added { .. } | TemplateRenderStateDiff::Modified { .. } => {
+                DiffSeverity::Additive
+            }
+            TemplateRenderStateDiff::Removed { .. } => DiffSeverity::Destructive,
+        }
+    }
+
+    async fn state_clean(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+    ) -> Result<Self::State, TemplateRenderError> {
+        Ok(TemplateRenderState::None)
+    }
+
+    async fn apply_check(
+        _apply_ctx: ApplyCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<ApplyCheck, Self::Error> {
+        TemplateRenderApplyFns::<Id>::apply_check(params, data, state_current, state_target, diff)
+            .await
+    }
+
+    async fn apply_dry(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        TemplateRenderApplyFns::<Id>::apply_dry(
+            fn_ctx,
+            params,
+            data,
+            state_current,
+            state_target,
+            diff,
+        )
+        .await
+    }
+
+    async fn apply(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        TemplateRenderApplyFns::<Id>::apply(fn_ctx, params, data, state_current, state_target, diff)
+            .await
+    }
+}
@@ -0,0 +1,65 @@
+use std::{
+    collections::BTreeMap,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+use derivative::Derivative;
+use peace::params::Params;
+use serde::{Deserialize, Serialize};
+
+/// Template render parameters.
+///
+/// The `Id` type parameter is needed for each template render params to be a
+/// distinct type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different template render
+///   parameters from each other.
+#[derive(Derivative, Params, PartialEq, Eq, Deserialize, Serialize)]
+#[derivative(Clone, Debug)]
+#[serde(bound = "")]
+pub struct TemplateRenderParams<Id> {
+    /// [Tera] template to render.
+    ///
+    /// [Tera]: https://keats.github.io/tera/docs/#templates
+    template: String,
+    /// Values to render the template with, accessible by key in the template.
+    values: BTreeMap<String, serde_json::Value>,
+    /// Path of the file to write the rendered content to.
+    dest: PathBuf,
+    /// Marker for unique template render parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> TemplateRenderParams<Id> {
+    /// Returns new `TemplateRenderParams`.
+    pub fn new(
+        template: String,
+        values: BTreeMap<String, serde_json::Value>,
+        dest: PathBuf,
+    ) -> Self {
+        Self {
+            template,
+            values,
+            dest,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the template to render.
+    pub fn template(&self) -> &str {
+        &self.template
+    }
+
+    /// Returns the values to render the template with.
+    pub fn values(&self) -> &BTreeMap<String, serde_json::Value> {
+        &self.values
+    }
+
+    /// Returns the path of the file to write the rendered content to.
+    pub fn dest(&self) -> &Path {
+        &self.dest
+    }
+}
@@ -0,0 +1,59 @@
+use std::fmt;
+
+use derivative::Derivative;
+use serde::{Deserialize, Serialize};
+
+/// State of a rendered template file.
+#[derive(Derivative, Deserialize, Serialize)]
+#[derivative(Clone, Debug, PartialEq, Eq)]
+pub enum TemplateRenderState {
+    /// The file does not exist.
+    None,
+    /// The file exists.
+    Rendered {
+        /// Hex encoded SHA-256 hash of the file's contents.
+        content_hash: String,
+        /// Digest of the template and values that the contents were rendered
+        /// from.
+        ///
+        /// This is `None` if the file was not rendered by this item, or was
+        /// changed after it was rendered.
+        params_digest: Option<String>,
+        /// Contents of the file, used to show the changed lines in the diff.
+        ///
+        /// This is not serialized, so states read from storage do not carry
+        /// contents, and two states are equal if their hashes are equal.
+        #[serde(skip)]
+        #[derivative(PartialEq = "ignore")]
+        content: Option<String>,
+    },
+}
+
+impl TemplateRenderState {
+    /// Returns the hash of the file's contents, if the file exists.
+    pub fn content_hash(&self) -> Option<&str> {
+        match self {
+            Self::None => None,
+            Self::Rendered { content_hash, .. } => Some(content_hash),
+        }
+    }
+}
+
+impl fmt::Display for TemplateRenderState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "not exists"),
+            Self::Rendered {
+                content_hash,
+                params_digest,
+                content: _,
+            } => {
+                write!(f, "rendered, content hash: {content_hash:.8}")?;
+                if params_digest.is_none() {
+                    write!(f, " (not rendered by this item)")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use std::{io::ErrorKind, marker::PhantomData, path::Path};
+
+use peace::{cfg::FnCtx, params::Params};
+
+use crate::{
+    TemplateRenderData, TemplateRenderError, TemplateRenderParams, TemplateRenderState,
+    TemplateRenderStateGoalFn,
+};
+
+/// Reads the current state of the rendered file.
+///
+/// The params digest is carried over from the stored state when the file's
+/// contents are unchanged since they were rendered.
+#[derive(Debug)]
+pub struct TemplateRenderStateCurrentFn<Id>(PhantomData<Id>);
+
+impl<Id> TemplateRenderStateCurrentFn<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn try_state_current(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<TemplateRenderParams<Id> as Params>::Partial,
+        data: TemplateRenderData<'_, Id>,
+    ) -> Result<Option<TemplateRenderState>, TemplateRenderError> {
+        match params_partial.dest() {
+            Some(dest) => Self::state_current_internal(dest, data.state_current_stored())
+                .await
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn state_current(
+        _fn_ctx: FnCtx<'_>,
+        params: &TemplateRenderParams<Id>,
+        data: TemplateRenderData<'_, Id>,
+    ) -> Result<TemplateRenderState, TemplateRenderError> {
+        Self::state_current_internal(params.dest(), data.state_current_stored()).await
+    }
+
+    pub(crate) async fn state_current_internal(
+        dest: &Path,
+        state_current_stored: Option<&TemplateRenderState>,
+    ) -> Result<TemplateRenderState, TemplateRenderError> {
+        let content = match tokio::fs::read_to_string(dest).await {
+            Ok(content) => content,
+            Err(error) if error.kind() == ErrorKind::NotFound => {
+                return Ok(TemplateRenderState::None);
+            }
+            Err(error) => {
+                return Err(TemplateRenderError::DestRead {
+                    path: dest.to_path_buf(),
+                    error,
+                });
+            }
+        };
+
+        let content_hash = TemplateRenderStateGoalFn::<Id>::content_hash(&content);
+        let params_digest = match state_current_stored {
+            Some(TemplateRenderState::Rendered {
+                content_hash: content_hash_stored,
+                params_digest,
+                content: _,
+            }) if *content_hash_stored == content_hash => params_digest.clone(),
+            _ => None,
+        };
+
+        Ok(TemplateRenderState::Rendered {
+            content_hash,
+            params_digest,
+            content: Some(content),
+        })
+    }
+}
@@ -0,0 +1,64 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Diff between the current and goal rendered template file.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum TemplateRenderStateDiff {
+    /// The file's contents match the rendered template, or neither exist.
+    InSync,
+    /// The file will be created.
+    Added {
+        /// Hash of the rendered contents.
+        content_hash: String,
+    },
+    /// The file's contents will be replaced.
+    Modified {
+        /// Hash of the current contents.
+        content_hash_from: String,
+        /// Hash of the rendered contents.
+        content_hash_to: String,
+        /// Unified diff of the current and rendered contents.
+        ///
+        /// This is `None` if either state was read from storage, as stored
+        /// states do not carry the file's contents.
+        content_diff: Option<String>,
+    },
+    /// The file will be removed.
+    Removed {
+        /// Hash of the current contents.
+        content_hash: String,
+    },
+}
+
+impl TemplateRenderStateDiff {
+    /// Returns whether the file is in sync with the rendered template.
+    pub fn is_in_sync(&self) -> bool {
+        matches!(self, Self::InSync)
+    }
+}
+
+impl fmt::Display for TemplateRenderStateDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InSync => write!(f, "in sync"),
+            Self::Added { content_hash } => {
+                write!(f, "file will be created, content hash: {content_hash:.8}")
+            }
+            Self::Modified {
+                content_hash_from,
+                content_hash_to,
+                content_diff,
+            } => match content_diff {
+                Some(content_diff) => write!(f, "{content_diff}"),
+                None => write!(
+                    f,
+                    "content will change from `{content_hash_from:.8}` to `{content_hash_to:.8}`"
+                ),
+            },
+            Self::Removed { content_hash } => {
+                write!(f, "file will be removed, content hash: {content_hash:.8}")
+            }
+        }
+    }
+}
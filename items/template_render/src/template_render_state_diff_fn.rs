@@ -0,0 +1,61 @@
+use crate::{TemplateRenderError, TemplateRenderState, TemplateRenderStateDiff};
+
+/// Template render state diff function.
+#[derive(Debug)]
+pub struct TemplateRenderStateDiffFn;
+
+impl TemplateRenderStateDiffFn {
+    pub async fn state_diff(
+        state_current: &TemplateRenderState,
+        state_goal: &TemplateRenderState,
+    ) -> Result<TemplateRenderStateDiff, TemplateRenderError> {
+        let state_diff = match (state_current, state_goal) {
+            (TemplateRenderState::None, TemplateRenderState::None) => {
+                TemplateRenderStateDiff::InSync
+            }
+            (TemplateRenderState::None, TemplateRenderState::Rendered { content_hash, .. }) => {
+                TemplateRenderStateDiff::Added {
+                    content_hash: content_hash.clone(),
+                }
+            }
+            (TemplateRenderState::Rendered { content_hash, .. }, TemplateRenderState::None) => {
+                TemplateRenderStateDiff::Removed {
+                    content_hash: content_hash.clone(),
+                }
+            }
+            (
+                TemplateRenderState::Rendered {
+                    content_hash: content_hash_from,
+                    params_digest: _,
+                    content: content_from,
+                },
+                TemplateRenderState::Rendered {
+                    content_hash: content_hash_to,
+                    params_digest: _,
+                    content: content_to,
+                },
+            ) => {
+                if content_hash_from == content_hash_to {
+                    TemplateRenderStateDiff::InSync
+                } else {
+                    let content_diff = content_from.as_deref().zip(content_to.as_deref()).map(
+                        |(content_from, content_to)| {
+                            similar::TextDiff::from_lines(content_from, content_to)
+                                .unified_diff()
+                                .header("current", "goal")
+                                .to_string()
+                        },
+                    );
+
+                    TemplateRenderStateDiff::Modified {
+                        content_hash_from: content_hash_from.clone(),
+                        content_hash_to: content_hash_to.clone(),
+                        content_diff,
+                    }
+                }
+            }
+        };
+
+        Ok(state_diff)
+    }
+}
@@ -0,0 +1,80 @@
+use std::{collections::BTreeMap, marker::PhantomData};
+
+use peace::{cfg::FnCtx, params::Params};
+use sha2::{Digest, Sha256};
+
+use crate::{TemplateRenderData, TemplateRenderError, TemplateRenderParams, TemplateRenderState};
+
+/// Renders the template to compute the goal state of the file.
+#[derive(Debug)]
+pub struct TemplateRenderStateGoalFn<Id>(PhantomData<Id>);
+
+impl<Id> TemplateRenderStateGoalFn<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn try_state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<TemplateRenderParams<Id> as Params>::Partial,
+        _data: TemplateRenderData<'_, Id>,
+    ) -> Result<Option<TemplateRenderState>, TemplateRenderError> {
+        match (params_partial.template(), params_partial.values()) {
+            (Some(template), Some(values)) => Self::state_goal_internal(template, values).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    pub async fn state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params: &TemplateRenderParams<Id>,
+        _data: TemplateRenderData<'_, Id>,
+    ) -> Result<TemplateRenderState, TemplateRenderError> {
+        Self::state_goal_internal(params.template(), params.values())
+    }
+
+    pub(crate) fn state_goal_internal(
+        template: &str,
+        values: &BTreeMap<String, serde_json::Value>,
+    ) -> Result<TemplateRenderState, TemplateRenderError> {
+        let content = Self::render(template, values)?;
+        let params_digest = Self::params_digest(template, values)?;
+
+        Ok(TemplateRenderState::Rendered {
+            content_hash: Self::content_hash(&content),
+            params_digest: Some(params_digest),
+            content: Some(content),
+        })
+    }
+
+    /// Renders the template with the given values.
+    pub(crate) fn render(
+        template: &str,
+        values: &BTreeMap<String, serde_json::Value>,
+    ) -> Result<String, TemplateRenderError> {
+        let context = tera::Context::from_serialize(values)
+            .map_err(|error| TemplateRenderError::TemplateRender { error })?;
+
+        // Escaping is for HTML, and would alter characters in configuration files.
+        tera::Tera::one_off(template, &context, false)
+            .map_err(|error| TemplateRenderError::TemplateRender { error })
+    }
+
+    /// Returns the hex encoded SHA-256 hash of the given contents.
+    pub(crate) fn content_hash(content: &str) -> String {
+        format!("{:x}", Sha256::digest(content))
+    }
+
+    /// Returns the hex encoded SHA-256 digest of the template and values.
+    pub(crate) fn params_digest(
+        template: &str,
+        values: &BTreeMap<String, serde_json::Value>,
+    ) -> Result<String, TemplateRenderError> {
+        let values_serialized = serde_json::to_vec(values)
+            .map_err(|error| TemplateRenderError::TemplateValuesSerialize { error })?;
+
+        let mut hasher = Sha256::new();
+        hasher.update(template);
+        hasher.update(values_serialized);
+        Ok(format!("{:x}", hasher.finalize()))
+    }
+}
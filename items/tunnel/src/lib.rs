@@ -0,0 +1,44 @@
+//! Manages a background tunnel process for the peace framework.
+//!
+//! This item ensures a port-forward or tunnel process, such as `ssh -N -L ..`
+//! or a cloud provider's tunnel command, is running in the background and
+//! accepting connections on a local port, so that later items can connect
+//! through the tunnel. The tunnel is restarted when its command or local port
+//! drifts, or when it stops accepting connections. Cleaning the item
+//! terminates the tunnel process.
+//!
+//! This item is only available on unix platforms, and requires `sh` and
+//! `kill` to be on the `PATH`, as well as `ps` on platforms other than Linux.
+
+#![cfg(unix)]
+
+pub use crate::{
+    tunnel_apply_fns::TunnelApplyFns,
+    tunnel_data::TunnelData,
+    tunnel_error::TunnelError,
+    tunnel_item::TunnelItem,
+    tunnel_liveness::TunnelLiveness,
+    tunnel_params::{TunnelParams, TunnelParamsFieldWise, TunnelParamsPartial},
+    tunnel_pid_file::TunnelPidFile,
+    tunnel_state::TunnelState,
+    tunnel_state_current_fn::TunnelStateCurrentFn,
+    tunnel_state_diff::TunnelStateDiff,
+    tunnel_state_diff_fn::TunnelStateDiffFn,
+    tunnel_state_goal_fn::TunnelStateGoalFn,
+};
+
+pub(crate) use crate::tunnel_process::TunnelProcess;
+
+mod tunnel_apply_fns;
+mod tunnel_data;
+mod tunnel_error;
+mod tunnel_item;
+mod tunnel_liveness;
+mod tunnel_params;
+mod tunnel_pid_file;
+mod tunnel_process;
+mod tunnel_state;
+mod tunnel_state_current_fn;
+mod tunnel_state_diff;
+mod tunnel_state_diff_fn;
+mod tunnel_state_goal_fn;
@@ -0,0 +1,120 @@
+use std::{marker::PhantomData, path::Path};
+
+#[cfg(feature = "output_progress")]
+use peace::cfg::progress::ProgressLimit;
+use peace::cfg::{ApplyCheck, FnCtx};
+
+use crate::{
+    TunnelData, TunnelError, TunnelLiveness, TunnelParams, TunnelPidFile, TunnelProcess,
+    TunnelState, TunnelStateCurrentFn, TunnelStateDiff,
+};
+
+/// ApplyFns for the tunnel.
+#[derive(Debug)]
+pub struct TunnelApplyFns<Id>(PhantomData<Id>);
+
+impl<Id> TunnelApplyFns<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn apply_check(
+        _params: &TunnelParams<Id>,
+        _data: TunnelData<'_, Id>,
+        _state_current: &TunnelState,
+        _state_target: &TunnelState,
+        diff: &TunnelStateDiff,
+    ) -> Result<ApplyCheck, TunnelError> {
+        let apply_check = if diff.is_in_sync() {
+            ApplyCheck::ExecNotRequired
+        } else {
+            #[cfg(not(feature = "output_progress"))]
+            {
+                ApplyCheck::ExecRequired { reason: None }
+            }
+            #[cfg(feature = "output_progress")]
+            {
+                ApplyCheck::ExecRequired {
+                    progress_limit: ProgressLimit::Unknown,
+                    reason: None,
+                }
+            }
+        };
+
+        Ok(apply_check)
+    }
+
+    pub async fn apply_dry(
+        _fn_ctx: FnCtx<'_>,
+        _params: &TunnelParams<Id>,
+        _data: TunnelData<'_, Id>,
+        _state_current: &TunnelState,
+        state_target: &TunnelState,
+        _diff: &TunnelStateDiff,
+    ) -> Result<TunnelState, TunnelError> {
+        Ok(state_target.clone())
+    }
+
+    pub async fn apply(
+        _fn_ctx: FnCtx<'_>,
+        params: &TunnelParams<Id>,
+        _data: TunnelData<'_, Id>,
+        state_current: &TunnelState,
+        state_target: &TunnelState,
+        diff: &TunnelStateDiff,
+    ) -> Result<TunnelState, TunnelError> {
+        let pid_file = params.pid_file();
+
+        if state_target.liveness == TunnelLiveness::Stopped {
+            Self::tunnel_terminate(pid_file).await?;
+            TunnelProcess::pid_file_remove(pid_file).await?;
+        } else if state_current.liveness == TunnelLiveness::Stopped || diff.is_restart_required() {
+            Self::tunnel_terminate(pid_file).await?;
+            Self::tunnel_start(params).await?;
+        }
+
+        // Re-read the state, so that the stored state holds the process ID.
+        TunnelStateCurrentFn::<Id>::state_current_internal(pid_file).await
+    }
+
+    /// Terminates the tunnel process recorded in the pid file, if it is still
+    /// running.
+    async fn tunnel_terminate(pid_file: &Path) -> Result<(), TunnelError> {
+        match TunnelProcess::pid_file_read(pid_file).await? {
+            Some(tunnel_pid_file) => TunnelProcess::terminate(&tunnel_pid_file).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Starts the tunnel process, and waits for it to accept connections.
+    async fn tunnel_start(params: &TunnelParams<Id>) -> Result<(), TunnelError> {
+        let command = TunnelState::command_string(params.program(), params.args());
+        let local_port = params.local_port();
+        let startup_timeout = params.startup_timeout();
+
+        let pid = TunnelProcess::spawn(params.program(), params.args(), &command).await?;
+        let Some(start_time) = TunnelProcess::start_time(pid).await? else {
+            return Err(TunnelError::TunnelExited {
+                command,
+                local_port,
+            });
+        };
+        let tunnel_pid_file = TunnelPidFile::new(pid, start_time, local_port, command.clone());
+        TunnelProcess::pid_file_write(params.pid_file(), &tunnel_pid_file).await?;
+
+        match TunnelProcess::wait_until_listening(&tunnel_pid_file, startup_timeout).await? {
+            TunnelLiveness::Alive => Ok(()),
+            TunnelLiveness::Stopped => Err(TunnelError::TunnelExited {
+                command,
+                local_port,
+            }),
+            TunnelLiveness::Unresponsive => {
+                TunnelProcess::terminate(&tunnel_pid_file).await?;
+                Err(TunnelError::TunnelStartTimeout {
+                    command,
+                    local_port,
+                    timeout: startup_timeout,
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use std::marker::PhantomData;
+
+use peace::{cfg::accessors::Stored, data::Data};
+
+use crate::TunnelState;
+
+/// Data used to manage a tunnel.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different tunnel
+///   parameters from each other.
+#[derive(Data, Debug)]
+pub struct TunnelData<'exec, Id>
+where
+    Id: Send + Sync + 'static,
+{
+    /// Stored state of this item's previous execution.
+    state_current_stored: Stored<'exec, TunnelState>,
+
+    /// Marker.
+    marker: PhantomData<Id>,
+}
+
+impl<'exec, Id> TunnelData<'exec, Id>
+where
+    Id: Send + Sync + 'static,
+{
+    /// Returns the stored state of this item's previous execution.
+    pub fn state_current_stored(&self) -> Option<&TunnelState> {
+        self.state_current_stored.get()
+    }
+}
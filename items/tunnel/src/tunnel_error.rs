@@ -0,0 +1,167 @@
+use std::{path::PathBuf, time::Duration};
+
+#[cfg(feature = "error_reporting")]
+use peace::miette;
+
+/// Error while managing a background tunnel process.
+#[cfg_attr(feature = "error_reporting", derive(peace::miette::Diagnostic))]
+#[derive(Debug, thiserror::Error)]
+pub enum TunnelError {
+    /// Failed to start the tunnel process.
+    #[error("Failed to start tunnel `{command}`.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_tunnel::tunnel_spawn),
+            help("Check that `sh` and the tunnel program are on the `PATH`.")
+        )
+    )]
+    TunnelSpawn {
+        /// Command that the tunnel process runs.
+        command: String,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Tunnel process exited before accepting connections.
+    #[error("Tunnel `{command}` exited before accepting connections on port {local_port}.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_tunnel::tunnel_exited),
+            help("Run the tunnel command manually to check that it stays running.")
+        )
+    )]
+    TunnelExited {
+        /// Command that the tunnel process runs.
+        command: String,
+        /// Port on the local host that the tunnel should accept connections
+        /// on.
+        local_port: u16,
+    },
+
+    /// Tunnel process did not accept connections within the startup timeout.
+    #[error(
+        "Tunnel `{command}` did not accept connections on port {local_port} within {}s.",
+        timeout.as_secs_f64()
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_tunnel::tunnel_start_timeout),
+            help(
+                "Check that the tunnel forwards the local port, or increase the startup timeout."
+            )
+        )
+    )]
+    TunnelStartTimeout {
+        /// Command that the tunnel process runs.
+        command: String,
+        /// Port on the local host that the tunnel should accept connections
+        /// on.
+        local_port: u16,
+        /// Duration waited for the tunnel to accept connections.
+        timeout: Duration,
+    },
+
+    /// Failed to execute `kill` to signal the tunnel process.
+    #[error("Failed to signal tunnel process {pid}.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_tunnel::tunnel_signal),
+            help("Check that `kill` is on the `PATH`.")
+        )
+    )]
+    TunnelSignal {
+        /// ID of the tunnel process.
+        pid: u32,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to read the start time of the tunnel process.
+    #[error("Failed to read the start time of tunnel process {pid}.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_tunnel::process_start_time_read))
+    )]
+    ProcessStartTimeRead {
+        /// ID of the tunnel process.
+        pid: u32,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Tunnel process did not exit after being terminated.
+    #[error("Tunnel process {pid} did not exit after being terminated.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_tunnel::tunnel_terminate),
+            help("Check that the current user has permission to terminate the process.")
+        )
+    )]
+    TunnelTerminate {
+        /// ID of the tunnel process.
+        pid: u32,
+    },
+
+    /// Failed to read the tunnel pid file.
+    #[error("Failed to read tunnel pid file: `{}`.", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_tunnel::pid_file_read))
+    )]
+    PidFileRead {
+        /// Path to the pid file.
+        path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to write the tunnel pid file.
+    #[error("Failed to write tunnel pid file: `{}`.", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_item_tunnel::pid_file_write),
+            help("Check that the pid file's directory exists and is writable.")
+        )
+    )]
+    PidFileWrite {
+        /// Path to the pid file.
+        path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to remove the tunnel pid file.
+    #[error("Failed to remove tunnel pid file: `{}`.", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_item_tunnel::pid_file_remove))
+    )]
+    PidFileRemove {
+        /// Path to the pid file.
+        path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    // === Framework errors === //
+    /// A `peace` runtime error occurred.
+    #[error("A `peace` runtime error occurred.")]
+    PeaceRtError(
+        #[cfg_attr(feature = "error_reporting", diagnostic_source)]
+        #[source]
+        #[from]
+        peace::rt_model::Error,
+    ),
+}
@@ -0,0 +1,207 @@
+use std::marker::PhantomData;
+
+use peace::{
+    cfg::{async_trait, ApplyCheck, ApplyCtx, DiffSeverity, FnCtx, Item, ItemId},
+    params::Params,
+    resources::{resources::ts::Empty, Resources},
+};
+
+use crate::{
+    TunnelApplyFns, TunnelData, TunnelError, TunnelLiveness, TunnelParams, TunnelState,
+    TunnelStateCurrentFn, TunnelStateDiff, TunnelStateDiffFn, TunnelStateGoalFn,
+};
+
+/// Item for managing a background tunnel process.
+///
+/// The goal state is for the tunnel process to be running the given command,
+/// and accepting connections on the given local port, so that later items can
+/// connect through the tunnel. The process ID is recorded in a pid file, and
+/// the current state checks that the process is still running, and that the
+/// local port still accepts connections.
+///
+/// The tunnel is restarted when its command or local port drifts, or when it
+/// stops accepting connections. Cleaning the item terminates the tunnel
+/// process and removes the pid file.
+///
+/// The `Id` type parameter is needed for each tunnel params to be a distinct
+/// type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different tunnel parameters
+///   from each other.
+#[derive(Debug)]
+pub struct TunnelItem<Id> {
+    /// ID of the item to manage the tunnel.
+    item_id: ItemId,
+    /// Marker for unique tunnel parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> Clone for TunnelItem<Id> {
+    fn clone(&self) -> Self {
+        Self {
+            item_id: self.item_id.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<Id> TunnelItem<Id> {
+    /// Returns a new `TunnelItem`.
+    pub fn new(item_id: ItemId) -> Self {
+        Self {
+            item_id,
+            marker: PhantomData,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<Id> Item for TunnelItem<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    type Data<'exec> = TunnelData<'exec, Id>;
+    type Error = TunnelError;
+    type Params<'exec> = TunnelParams<Id>;
+    type State = TunnelState;
+    type StateDiff = TunnelStateDiff;
+
+    fn id(&self) -> &ItemId {
+        &self.item_id
+    }
+
+    async fn setup(&self, _resources: &mut Resources<Empty>) -> Result<(), TunnelError> {
+        Ok(())
+    }
+
+    async fn try_state_current(
+        fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        data: TunnelData<'_, Id>,
+    ) -> Result<Option<Self::State>, TunnelError> {
+        TunnelStateCurrentFn::try_state_current(fn_ctx, params_partial, data).await
+    }
+
+    async fn state_current(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: TunnelData<'_, Id>,
+    ) -> Result<Self::State, TunnelError> {
+        TunnelStateCurrentFn::state_current(fn_ctx, params, data).await
+    }
+
+    async fn try_state_goal(
+        fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        data: TunnelData<'_, Id>,
+    ) -> Result<Option<Self::State>, TunnelError> {
+        TunnelStateGoalFn::try_state_goal(fn_ctx, params_partial, data).await
+    }
+
+    async fn state_goal(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: TunnelData<'_, Id>,
+    ) -> Result<Self::State, TunnelError> {
+        TunnelStateGoalFn::state_goal(fn_ctx, params, data).await
+    }
+
+    async fn state_diff(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_goal: &Self::State,
+    ) -> Result<Self::StateDiff, TunnelError> {
+        TunnelStateDiffFn::state_diff(state_current, state_goal).await
+    }
+
+    fn diff_severity(
+        state_current: &Self::State,
+        _state_goal: &Self::State,
+        state_diff: &Self::StateDiff,
+    ) -> DiffSeverity {
+        // Restarting or stopping a live tunnel interrupts connections through it.
+        let tunnel_interrupted = state_current.liveness == TunnelLiveness::Alive
+            && (state_diff.is_restart_required()
+                || matches!(state_diff.liveness, Some((_, TunnelLiveness::Stopped))));
+
+        if state_diff.is_in_sync() {
+            DiffSeverity::InSync
+        } else if tunnel_interrupted {
+            DiffSeverity::Destructive
+        } else {
+            DiffSeverity::Additive
+        }
+    }
+
+    fn error_help(error: &TunnelError) -> Vec<String> {
+        match error {
+            TunnelError::TunnelSpawn { .. } => vec![String::from(
+                "Check that `sh` and the tunnel program are on the `PATH`.",
+            )],
+            TunnelError::TunnelExited { .. } => vec![String::from(
+                "Run the tunnel command manually to check that it stays running.",
+            )],
+            TunnelError::TunnelStartTimeout { .. } => vec![String::from(
+                "Check that the tunnel forwards the local port, or increase the startup timeout.",
+            )],
+            TunnelError::TunnelSignal { .. } => {
+                vec![String::from("Check that `kill` is on the `PATH`.")]
+            }
+            TunnelError::TunnelTerminate { .. } => vec![String::from(
+                "Check that the current user has permission to terminate the process.",
+            )],
+            TunnelError::PidFileWrite { .. } => vec![String::from(
+                "Check that the pid file's directory exists and is writable.",
+            )],
+            TunnelError::PidFileRead { .. }
+            | TunnelError::PidFileRemove { .. }
+            | TunnelError::ProcessStartTimeRead { .. }
+            | TunnelError::PeaceRtError(_) => Vec::new(),
+        }
+    }
+
+    async fn state_clean(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+    ) -> Result<Self::State, TunnelError> {
+        Ok(TunnelState::clean())
+    }
+
+    async fn apply_check(
+        _apply_ctx: ApplyCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<ApplyCheck, Self::Error> {
+        TunnelApplyFns::<Id>::apply_check(params, data, state_current, state_target, diff).await
+    }
+
+    async fn apply_dry(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        TunnelApplyFns::<Id>::apply_dry(fn_ctx, params, data, state_current, state_target, diff)
+            .await
+    }
+
+    async fn apply(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+        state_current: &Self::State,
+        state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        TunnelApplyFns::<Id>::apply(fn_ctx, params, data, state_current, state_target, diff)
+            .await
+    }
+}
@@ -0,0 +1,25 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Whether a tunnel process is running, and accepting connections.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum TunnelLiveness {
+    /// Tunnel process is running and accepting connections on its local port.
+    Alive,
+    /// Tunnel process is running, but not accepting connections on its local
+    /// port.
+    Unresponsive,
+    /// Tunnel process is not running.
+    Stopped,
+}
+
+impl fmt::Display for TunnelLiveness {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Alive => "alive".fmt(f),
+            Self::Unresponsive => "unresponsive".fmt(f),
+            Self::Stopped => "stopped".fmt(f),
+        }
+    }
+}
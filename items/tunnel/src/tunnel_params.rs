@@ -0,0 +1,88 @@
+use std::{
+    marker::PhantomData,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use derivative::Derivative;
+use peace::params::Params;
+use serde::{Deserialize, Serialize};
+
+/// Tunnel parameters.
+///
+/// The `Id` type parameter is needed for each tunnel params to be a distinct
+/// type.
+///
+/// # Type Parameters
+///
+/// * `Id`: A zero-sized type used to distinguish different tunnel parameters
+///   from each other.
+#[derive(Derivative, Params, PartialEq, Eq, Deserialize, Serialize)]
+#[derivative(Clone, Debug)]
+#[serde(bound = "")]
+pub struct TunnelParams<Id> {
+    /// Program that runs the tunnel, e.g. `ssh`.
+    program: String,
+    /// Arguments passed to the program, e.g. `["-N", "-L",
+    /// "15432:db.internal:5432", "bastion"]`.
+    ///
+    /// The program must keep running in the foreground while the tunnel is
+    /// open, e.g. `ssh` must not be passed `-f`.
+    args: Vec<String>,
+    /// Port on the local host that the tunnel accepts connections on.
+    local_port: u16,
+    /// Path to the file that records the tunnel process ID.
+    pid_file: PathBuf,
+    /// Maximum duration to wait for the tunnel to accept connections after it
+    /// is started.
+    startup_timeout: Duration,
+    /// Marker for unique tunnel parameters type.
+    marker: PhantomData<Id>,
+}
+
+impl<Id> TunnelParams<Id> {
+    /// Returns new `TunnelParams`.
+    pub fn new(
+        program: String,
+        args: Vec<String>,
+        local_port: u16,
+        pid_file: PathBuf,
+        startup_timeout: Duration,
+    ) -> Self {
+        Self {
+            program,
+            args,
+            local_port,
+            pid_file,
+            startup_timeout,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the program that runs the tunnel.
+    pub fn program(&self) -> &str {
+        &self.program
+    }
+
+    /// Returns the arguments passed to the program.
+    pub fn args(&self) -> &[String] {
+        &self.args
+    }
+
+    /// Returns the port on the local host that the tunnel accepts connections
+    /// on.
+    pub fn local_port(&self) -> u16 {
+        self.local_port
+    }
+
+    /// Returns the path to the file that records the tunnel process ID.
+    pub fn pid_file(&self) -> &Path {
+        &self.pid_file
+    }
+
+    /// Returns the maximum duration to wait for the tunnel to accept
+    /// connections after it is started.
+    pub fn startup_timeout(&self) -> Duration {
+        self.startup_timeout
+    }
+}
@@ -0,0 +1,77 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Contents of the file that records a running tunnel process.
+///
+/// The file holds the process ID, the process start time, the local port,
+/// and the tunnel command, each on its own line:
+///
+/// ```text
+/// 12345
+/// 8523401
+/// 15432
+/// ssh -N -L 15432:db.internal:5432 bastion
+/// ```
+///
+/// The start time identifies the tunnel process, as the process ID may be
+/// reused by another process after the tunnel process exits.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct TunnelPidFile {
+    /// ID of the tunnel process.
+    pub pid: u32,
+    /// Start time of the tunnel process, as read by the operating system.
+    pub start_time: String,
+    /// Port on the local host that the tunnel accepts connections on.
+    pub local_port: u16,
+    /// Command that the tunnel process was started with.
+    pub command: String,
+}
+
+impl TunnelPidFile {
+    /// Returns a new `TunnelPidFile`.
+    pub fn new(pid: u32, start_time: String, local_port: u16, command: String) -> Self {
+        Self {
+            pid,
+            start_time,
+            local_port,
+            command,
+        }
+    }
+
+    /// Returns the `TunnelPidFile` parsed from the file's contents, or `None`
+    /// if the contents are not in the expected format.
+    pub fn parse(contents: &str) -> Option<Self> {
+        let mut lines = contents.splitn(4, '\n');
+        let pid = lines.next()?.trim().parse::<u32>().ok()?;
+        let start_time = lines.next()?.trim().to_string();
+        if start_time.is_empty() {
+            return None;
+        }
+        let local_port = lines.next()?.trim().parse::<u16>().ok()?;
+        let command = lines.next().unwrap_or_default().trim().to_string();
+
+        Some(Self {
+            pid,
+            start_time,
+            local_port,
+            command,
+        })
+    }
+}
+
+impl fmt::Display for TunnelPidFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            pid,
+            start_time,
+            local_port,
+            command,
+        } = self;
+
+        writeln!(f, "{pid}")?;
+        writeln!(f, "{start_time}")?;
+        writeln!(f, "{local_port}")?;
+        writeln!(f, "{command}")
+    }
+}
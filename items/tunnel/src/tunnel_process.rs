@@ -0,0 +1,237 @@
+use std::{
+    io,
+    net::Ipv4Addr,
+    path::Path,
+    process::{Output, Stdio},
+    time::Duration,
+};
+
+use tokio::{net::TcpStream, process::Command, time::Instant};
+
+use crate::{TunnelError, TunnelLiveness, TunnelPidFile};
+
+/// Starts, checks, and terminates tunnel processes.
+#[derive(Debug)]
+pub(crate) struct TunnelProcess;
+
+impl TunnelProcess {
+    /// Maximum duration to wait for a connection to the local port when
+    /// checking whether the tunnel is accepting connections.
+    const CONNECT_TIMEOUT: Duration = Duration::from_secs(1);
+    /// Duration to wait between checks on the tunnel process.
+    const POLL_INTERVAL: Duration = Duration::from_millis(100);
+    /// Maximum duration to wait for the tunnel process to exit after it is
+    /// signalled.
+    const TERMINATE_TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// Starts the tunnel process in the background, and returns its process
+    /// ID.
+    ///
+    /// `sh` starts the tunnel and exits immediately, so the tunnel process is
+    /// reparented, and keeps running after this process exits.
+    ///
+    /// The process ID should be recorded with the process's
+    /// [`start_time`](Self::start_time), so that the tunnel process can be
+    /// identified after the process ID is reused.
+    pub async fn spawn(program: &str, args: &[String], command: &str) -> Result<u32, TunnelError> {
+        let tunnel_spawn_error = |error| TunnelError::TunnelSpawn {
+            command: command.to_string(),
+            error,
+        };
+        let output = Command::new("sh")
+            .arg("-c")
+            .arg("\"$@\" </dev/null >/dev/null 2>&1 & echo $!")
+            .arg("sh")
+            .arg(program)
+            .args(args)
+            .stdin(Stdio::null())
+            .kill_on_drop(true)
+            .output()
+            .await
+            .map_err(tunnel_spawn_error)?;
+
+        String::from_utf8_lossy(&output.stdout)
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| {
+                tunnel_spawn_error(io::Error::other(
+                    "`sh` did not output the tunnel process ID.",
+                ))
+            })
+    }
+
+    /// Returns the liveness of the tunnel process once it accepts connections,
+    /// exits, or the timeout elapses.
+    pub async fn wait_until_listening(
+        tunnel_pid_file: &TunnelPidFile,
+        timeout: Duration,
+    ) -> Result<TunnelLiveness, TunnelError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if Self::is_listening(tunnel_pid_file.local_port).await {
+                return Ok(TunnelLiveness::Alive);
+            }
+            if !Self::is_running(tunnel_pid_file).await? {
+                return Ok(TunnelLiveness::Stopped);
+            }
+            if Instant::now() >= deadline {
+                return Ok(TunnelLiveness::Unresponsive);
+            }
+            tokio::time::sleep(Self::POLL_INTERVAL).await;
+        }
+    }
+
+    /// Returns whether the tunnel process recorded in the pid file is running.
+    ///
+    /// A process is only treated as the tunnel process if it has the recorded
+    /// start time, as its process ID may have been reused by another process
+    /// after the tunnel process exited.
+    pub async fn is_running(tunnel_pid_file: &TunnelPidFile) -> Result<bool, TunnelError> {
+        Self::start_time(tunnel_pid_file.pid)
+            .await
+            .map(|start_time| start_time.as_deref() == Some(tunnel_pid_file.start_time.as_str()))
+    }
+
+    /// Returns the start time of the process with the given ID, or `None` if
+    /// there is no running process with that ID.
+    ///
+    /// This is the `starttime` field of `/proc/<pid>/stat`, which is the
+    /// number of clock ticks between system boot and when the process started.
+    /// Zombie processes are treated as not running, as they have already
+    /// exited.
+    #[cfg(target_os = "linux")]
+    pub async fn start_time(pid: u32) -> Result<Option<String>, TunnelError> {
+        let stat = match tokio::fs::read_to_string(format!("/proc/{pid}/stat")).await {
+            Ok(stat) => stat,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => return Err(TunnelError::ProcessStartTimeRead { pid, error }),
+        };
+
+        // The process name is in parentheses, and may contain spaces and
+        // parentheses, so fields are read from after the last `)`. The state is
+        // field 3, and `starttime` is field 22.
+        let Some((_pid_and_name, fields)) = stat.rsplit_once(')') else {
+            return Ok(None);
+        };
+        let mut fields = fields.split_whitespace();
+        if matches!(fields.next(), Some("Z" | "X")) {
+            return Ok(None);
+        }
+        Ok(fields.nth(18).map(str::to_string))
+    }
+
+    /// Returns the start time of the process with the given ID, or `None` if
+    /// there is no running process with that ID.
+    ///
+    /// This is the `lstart` column from `ps`.
+    #[cfg(not(target_os = "linux"))]
+    pub async fn start_time(pid: u32) -> Result<Option<String>, TunnelError> {
+        let output = Command::new("ps")
+            .args(["-o", "lstart=", "-p"])
+            .arg(pid.to_string())
+            .stdin(Stdio::null())
+            .stderr(Stdio::null())
+            .kill_on_drop(true)
+            .output()
+            .await
+            .map_err(|error| TunnelError::ProcessStartTimeRead { pid, error })?;
+
+        // `ps` returns a non-zero exit code when the process does not exist.
+        let start_time = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if output.status.success() && !start_time.is_empty() {
+            Ok(Some(start_time))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns whether the local port is accepting connections.
+    pub async fn is_listening(local_port: u16) -> bool {
+        let connect = TcpStream::connect((Ipv4Addr::LOCALHOST, local_port));
+        matches!(
+            tokio::time::timeout(Self::CONNECT_TIMEOUT, connect).await,
+            Ok(Ok(_))
+        )
+    }
+
+    /// Terminates the tunnel process, killing it if it does not exit after
+    /// being terminated.
+    ///
+    /// The process is only signalled while it is still the tunnel process, so
+    /// a process that reused the process ID is not signalled.
+    pub async fn terminate(tunnel_pid_file: &TunnelPidFile) -> Result<(), TunnelError> {
+        let pid = tunnel_pid_file.pid;
+        for signal in ["TERM", "KILL"] {
+            if !Self::is_running(tunnel_pid_file).await? {
+                return Ok(());
+            }
+            Self::kill(pid, &["-s", signal]).await?;
+
+            let deadline = Instant::now() + Self::TERMINATE_TIMEOUT;
+            while Self::is_running(tunnel_pid_file).await? && Instant::now() < deadline {
+                tokio::time::sleep(Self::POLL_INTERVAL).await;
+            }
+        }
+
+        if Self::is_running(tunnel_pid_file).await? {
+            Err(TunnelError::TunnelTerminate { pid })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns the contents of the pid file, or `None` if it does not exist or
+    /// is not in the expected format.
+    pub async fn pid_file_read(pid_file: &Path) -> Result<Option<TunnelPidFile>, TunnelError> {
+        match tokio::fs::read_to_string(pid_file).await {
+            Ok(contents) => Ok(TunnelPidFile::parse(&contents)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(TunnelError::PidFileRead {
+                path: pid_file.to_path_buf(),
+                error,
+            }),
+        }
+    }
+
+    /// Writes the pid file.
+    pub async fn pid_file_write(
+        pid_file: &Path,
+        tunnel_pid_file: &TunnelPidFile,
+    ) -> Result<(), TunnelError> {
+        tokio::fs::write(pid_file, tunnel_pid_file.to_string())
+            .await
+            .map_err(|error| TunnelError::PidFileWrite {
+                path: pid_file.to_path_buf(),
+                error,
+            })
+    }
+
+    /// Removes the pid file, if it exists.
+    pub async fn pid_file_remove(pid_file: &Path) -> Result<(), TunnelError> {
+        match tokio::fs::remove_file(pid_file).await {
+            Ok(()) => Ok(()),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(error) => Err(TunnelError::PidFileRemove {
+                path: pid_file.to_path_buf(),
+                error,
+            }),
+        }
+    }
+
+    /// Runs `kill` with the given arguments followed by the process ID.
+    ///
+    /// `kill` returns a non-zero exit code when the process does not exist,
+    /// so the output is returned instead of an error, as the process may exit
+    /// between checking whether it is running and signalling it.
+    async fn kill(pid: u32, args: &[&str]) -> Result<Output, TunnelError> {
+        Command::new("kill")
+            .args(args)
+            .arg(pid.to_string())
+            .stdin(Stdio::null())
+            .stderr(Stdio::null())
+            .kill_on_drop(true)
+            .output()
+            .await
+            .map_err(|error| TunnelError::TunnelSignal { pid, error })
+    }
+}
@@ -0,0 +1,94 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::TunnelLiveness;
+
+/// State of a background tunnel process.
+///
+/// This is the ID of the tunnel process, the port it accepts connections on,
+/// the command it was started with, and whether it is accepting connections.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct TunnelState {
+    /// ID of the tunnel process, `None` if it is not running.
+    ///
+    /// For the goal state, this is always `None`, as the process ID is only
+    /// known once the tunnel is started.
+    pub pid: Option<u32>,
+    /// Port on the local host that the tunnel accepts connections on, `None`
+    /// if the tunnel is not running.
+    pub local_port: Option<u16>,
+    /// Command that the tunnel process runs, `None` if the tunnel is not
+    /// running.
+    pub command: Option<String>,
+    /// Whether the tunnel process is running, and accepting connections.
+    pub liveness: TunnelLiveness,
+}
+
+impl TunnelState {
+    /// Returns a new `TunnelState`.
+    pub fn new(
+        pid: Option<u32>,
+        local_port: Option<u16>,
+        command: Option<String>,
+        liveness: TunnelLiveness,
+    ) -> Self {
+        Self {
+            pid,
+            local_port,
+            command,
+            liveness,
+        }
+    }
+
+    /// Returns the state when the tunnel process is not running.
+    pub fn clean() -> Self {
+        Self {
+            pid: None,
+            local_port: None,
+            command: None,
+            liveness: TunnelLiveness::Stopped,
+        }
+    }
+
+    /// Returns the tunnel command as it is displayed and recorded, with
+    /// arguments that are empty or contain whitespace quoted.
+    pub fn command_string(program: &str, args: &[String]) -> String {
+        std::iter::once(program)
+            .chain(args.iter().map(String::as_str))
+            .map(|arg| {
+                if arg.is_empty() || arg.contains(char::is_whitespace) {
+                    format!("'{arg}'")
+                } else {
+                    arg.to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl fmt::Display for TunnelState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            pid,
+            local_port,
+            command,
+            liveness,
+        } = self;
+
+        if *liveness == TunnelLiveness::Stopped {
+            return write!(f, "tunnel is not running");
+        }
+
+        let command = command.as_deref().unwrap_or("<unknown>");
+        write!(f, "`{command}`")?;
+        if let Some(local_port) = local_port {
+            write!(f, " on port {local_port}")?;
+        }
+        if let Some(pid) = pid {
+            write!(f, " (pid {pid})")?;
+        }
+        write!(f, ", {liveness}")
+    }
+}
@@ -0,0 +1,74 @@
+use std::{marker::PhantomData, path::Path};
+
+use peace::{cfg::FnCtx, params::Params};
+
+use crate::{
+    TunnelData, TunnelError, TunnelLiveness, TunnelParams, TunnelPidFile, TunnelProcess,
+    TunnelState,
+};
+
+/// Reads the current state of the tunnel.
+///
+/// The process recorded in the pid file is checked to still be running with
+/// the recorded start time, and its local port is checked to still accept connections, each time the state
+/// is read.
+#[derive(Debug)]
+pub struct TunnelStateCurrentFn<Id>(PhantomData<Id>);
+
+impl<Id> TunnelStateCurrentFn<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn try_state_current(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<TunnelParams<Id> as Params>::Partial,
+        _data: TunnelData<'_, Id>,
+    ) -> Result<Option<TunnelState>, TunnelError> {
+        match params_partial.pid_file() {
+            Some(pid_file) => Self::state_current_internal(pid_file).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn state_current(
+        _fn_ctx: FnCtx<'_>,
+        params: &TunnelParams<Id>,
+        _data: TunnelData<'_, Id>,
+    ) -> Result<TunnelState, TunnelError> {
+        Self::state_current_internal(params.pid_file()).await
+    }
+
+    pub(crate) async fn state_current_internal(
+        pid_file: &Path,
+    ) -> Result<TunnelState, TunnelError> {
+        let Some(tunnel_pid_file) = TunnelProcess::pid_file_read(pid_file).await? else {
+            return Ok(TunnelState::clean());
+        };
+
+        // The pid file is left behind when the tunnel process exits by itself,
+        // and its process ID may since have been reused by another process.
+        if !TunnelProcess::is_running(&tunnel_pid_file).await? {
+            return Ok(TunnelState::clean());
+        }
+
+        let TunnelPidFile {
+            pid,
+            start_time: _,
+            local_port,
+            command,
+        } = tunnel_pid_file;
+
+        let liveness = if TunnelProcess::is_listening(local_port).await {
+            TunnelLiveness::Alive
+        } else {
+            TunnelLiveness::Unresponsive
+        };
+
+        Ok(TunnelState::new(
+            Some(pid),
+            Some(local_port),
+            Some(command),
+            liveness,
+        ))
+    }
+}
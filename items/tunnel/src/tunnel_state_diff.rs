@@ -0,0 +1,69 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::TunnelLiveness;
+
+/// Diff between the current and goal tunnel state.
+///
+/// Each field is `Some((from, to))` when that part of the state differs. The
+/// process ID is not compared, as it is not known until the tunnel is
+/// started.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct TunnelStateDiff {
+    /// Change in the port that the tunnel accepts connections on.
+    pub local_port: Option<(Option<u16>, Option<u16>)>,
+    /// Change in the command that the tunnel process runs.
+    pub command: Option<(Option<String>, Option<String>)>,
+    /// Change in whether the tunnel process is running, and accepting
+    /// connections.
+    pub liveness: Option<(TunnelLiveness, TunnelLiveness)>,
+}
+
+impl TunnelStateDiff {
+    /// Returns whether the local port, command, and liveness are all in sync.
+    pub fn is_in_sync(&self) -> bool {
+        self.local_port.is_none() && self.command.is_none() && self.liveness.is_none()
+    }
+
+    /// Returns whether a running tunnel process needs to be restarted, due to
+    /// command or local port drift, or because it is not accepting
+    /// connections.
+    pub fn is_restart_required(&self) -> bool {
+        match self.liveness {
+            Some((TunnelLiveness::Stopped, _)) | Some((_, TunnelLiveness::Stopped)) => false,
+            Some((TunnelLiveness::Unresponsive, TunnelLiveness::Alive)) => true,
+            _ => self.local_port.is_some() || self.command.is_some(),
+        }
+    }
+}
+
+impl fmt::Display for TunnelStateDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.liveness {
+            _ if self.is_in_sync() => return write!(f, "in sync"),
+            Some((TunnelLiveness::Stopped, _)) => return write!(f, "tunnel will be started"),
+            Some((_, TunnelLiveness::Stopped)) => return write!(f, "tunnel will be stopped"),
+            _ => {}
+        }
+
+        let mut changes = Vec::with_capacity(3);
+        if let Some((local_port_from, local_port_to)) = self.local_port {
+            let change = match (local_port_from, local_port_to) {
+                (Some(local_port_from), Some(local_port_to)) => {
+                    format!("local port {local_port_from} -> {local_port_to}")
+                }
+                _ => String::from("local port will be updated"),
+            };
+            changes.push(change);
+        }
+        if self.command.is_some() {
+            changes.push(String::from("command will be modified"));
+        }
+        if let Some((from, to)) = self.liveness {
+            changes.push(format!("{from} -> {to}"));
+        }
+
+        write!(f, "{}", changes.join(", "))
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{TunnelError, TunnelState, TunnelStateDiff};
+
+/// Tunnel state diff function.
+#[derive(Debug)]
+pub struct TunnelStateDiffFn;
+
+impl TunnelStateDiffFn {
+    pub async fn state_diff(
+        state_current: &TunnelState,
+        state_goal: &TunnelState,
+    ) -> Result<TunnelStateDiff, TunnelError> {
+        let local_port = (state_current.local_port != state_goal.local_port)
+            .then_some((state_current.local_port, state_goal.local_port));
+        let command = (state_current.command != state_goal.command)
+            .then(|| (state_current.command.clone(), state_goal.command.clone()));
+        let liveness = (state_current.liveness != state_goal.liveness)
+            .then_some((state_current.liveness, state_goal.liveness));
+
+        Ok(TunnelStateDiff {
+            local_port,
+            command,
+            liveness,
+        })
+    }
+}
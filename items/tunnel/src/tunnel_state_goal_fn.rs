@@ -0,0 +1,55 @@
+use std::marker::PhantomData;
+
+use peace::{cfg::FnCtx, params::Params};
+
+use crate::{TunnelData, TunnelError, TunnelLiveness, TunnelParams, TunnelState};
+
+/// Reads the goal state of the tunnel.
+///
+/// The goal is for the tunnel process to be running the given command, and
+/// accepting connections on the given local port.
+#[derive(Debug)]
+pub struct TunnelStateGoalFn<Id>(PhantomData<Id>);
+
+impl<Id> TunnelStateGoalFn<Id>
+where
+    Id: Send + Sync + 'static,
+{
+    pub async fn try_state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<TunnelParams<Id> as Params>::Partial,
+        _data: TunnelData<'_, Id>,
+    ) -> Result<Option<TunnelState>, TunnelError> {
+        match (
+            params_partial.program(),
+            params_partial.args(),
+            params_partial.local_port(),
+        ) {
+            (Some(program), Some(args), Some(local_port)) => {
+                Ok(Some(Self::state_goal_internal(program, args, *local_port)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    pub async fn state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params: &TunnelParams<Id>,
+        _data: TunnelData<'_, Id>,
+    ) -> Result<TunnelState, TunnelError> {
+        Ok(Self::state_goal_internal(
+            params.program(),
+            params.args(),
+            params.local_port(),
+        ))
+    }
+
+    fn state_goal_internal(program: &str, args: &[String], local_port: u16) -> TunnelState {
+        TunnelState::new(
+            None,
+            Some(local_port),
+            Some(TunnelState::command_string(program, args)),
+            TunnelLiveness::Alive,
+        )
+    }
+}
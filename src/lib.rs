@@ -8,6 +8,8 @@ pub use peace_cfg as cfg;
 #[cfg(feature = "cli")]
 pub use peace_cli as cli;
 #[cfg(feature = "cli")]
+pub use peace_cli_args as cli_args;
+#[cfg(feature = "cli")]
 pub use peace_cli_model as cli_model;
 pub use peace_cmd as cmd;
 pub use peace_cmd_model as cmd_model;
@@ -26,6 +28,8 @@ pub use peace_webi as webi;
 pub use peace_webi_components as webi_components;
 #[cfg(feature = "webi")]
 pub use peace_webi_model as webi_model;
+#[cfg(feature = "item_schema")]
+pub use schemars;
 
 // We still can't build with `--all-features`, even with `indicatif 0.17.4`.
 //
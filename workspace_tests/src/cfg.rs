@@ -1,8 +1,16 @@
 mod app_name;
 mod apply_check;
+mod apply_check_reason;
+mod diff_severity;
 mod flow_id;
+mod item_group_id;
 mod item_id;
 mod item_id_invalid_fmt;
+mod item_inputs;
+mod item_io_type;
+mod item_outputs;
+#[cfg(feature = "item_schema")]
+mod item_schema;
 mod profile;
 mod profile_invalid_fmt;
 #[cfg(feature = "output_progress")]
@@ -1,4 +1,6 @@
-use peace::cfg::ApplyCheck;
+#[cfg(feature = "output_progress")]
+use peace::cfg::progress::ProgressLimit;
+use peace::cfg::{ApplyCheck, ApplyCheckReason};
 
 #[test]
 fn serialize() -> Result<(), serde_yaml::Error> {
@@ -17,3 +19,30 @@ fn deserialize() -> Result<(), serde_yaml::Error> {
     );
     Ok(())
 }
+
+#[test]
+fn reason_returns_reason_when_exec_required() {
+    let reason = ApplyCheckReason::new("content_changed");
+    #[cfg(not(feature = "output_progress"))]
+    let apply_check = ApplyCheck::ExecRequired { reason: None };
+    #[cfg(feature = "output_progress")]
+    let apply_check = ApplyCheck::ExecRequired {
+        progress_limit: ProgressLimit::Unknown,
+        reason: None,
+    };
+
+    assert_eq!(None, apply_check.reason());
+    assert_eq!(
+        Some(&reason),
+        apply_check.with_reason(reason.clone()).reason()
+    );
+}
+
+#[test]
+fn with_reason_does_not_set_reason_when_exec_not_required() {
+    let apply_check =
+        ApplyCheck::ExecNotRequired.with_reason(ApplyCheckReason::new("content_changed"));
+
+    assert_eq!(ApplyCheck::ExecNotRequired, apply_check);
+    assert_eq!(None, apply_check.reason());
+}
@@ -0,0 +1,34 @@
+use peace::cfg::ApplyCheckReason;
+
+#[test]
+fn display_code_only() {
+    assert_eq!(
+        "content_changed",
+        ApplyCheckReason::new("content_changed").to_string()
+    );
+}
+
+#[test]
+fn display_code_and_fields() {
+    let reason = ApplyCheckReason::new("content_changed").with_fields(["byte_len", "contents"]);
+
+    assert_eq!("content_changed: byte_len, contents", reason.to_string());
+}
+
+#[test]
+fn serialize_skips_empty_fields() -> Result<(), serde_yaml::Error> {
+    assert_eq!(
+        "code: content_changed\n",
+        serde_yaml::to_string(&ApplyCheckReason::new("content_changed"))?
+    );
+    Ok(())
+}
+
+#[test]
+fn deserialize() -> Result<(), serde_yaml::Error> {
+    assert_eq!(
+        ApplyCheckReason::new("content_changed").with_fields(["contents"]),
+        serde_yaml::from_str("code: content_changed\nfields: [contents]")?
+    );
+    Ok(())
+}
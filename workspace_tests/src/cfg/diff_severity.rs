@@ -0,0 +1,36 @@
+use peace::cfg::DiffSeverity;
+
+#[test]
+fn ord_is_least_to_most_severe() {
+    assert!(DiffSeverity::InSync < DiffSeverity::Additive);
+    assert!(DiffSeverity::Additive < DiffSeverity::Destructive);
+}
+
+#[test]
+fn detailed_exit_code() {
+    assert_eq!(0, DiffSeverity::InSync.detailed_exit_code());
+    assert_eq!(2, DiffSeverity::Additive.detailed_exit_code());
+    assert_eq!(3, DiffSeverity::Destructive.detailed_exit_code());
+}
+
+#[test]
+fn display() {
+    assert_eq!("in sync", DiffSeverity::InSync.to_string());
+    assert_eq!("additive", DiffSeverity::Additive.to_string());
+    assert_eq!("destructive", DiffSeverity::Destructive.to_string());
+}
+
+#[test]
+fn serialize() -> Result<(), serde_yaml::Error> {
+    assert_eq!(
+        "Destructive\n",
+        serde_yaml::to_string(&DiffSeverity::Destructive)?
+    );
+    Ok(())
+}
+
+#[test]
+fn deserialize() -> Result<(), serde_yaml::Error> {
+    assert_eq!(DiffSeverity::Additive, serde_yaml::from_str("Additive")?);
+    Ok(())
+}
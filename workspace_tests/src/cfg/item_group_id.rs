@@ -0,0 +1,150 @@
+use std::{borrow::Cow, collections::HashMap, str::FromStr};
+
+use peace::{
+    cfg::{ItemGroupId, ItemGroupIdInvalidFmt},
+    fmt::Presentable,
+};
+
+use crate::{FnInvocation, FnTrackerPresenter};
+
+#[test]
+fn from_str_returns_ok_owned_for_valid_id() -> Result<(), ItemGroupIdInvalidFmt<'static>> {
+    let item_group_id = ItemGroupId::from_str("good_id")?;
+
+    assert_eq!("good_id", *item_group_id);
+    Ok(())
+}
+
+#[test]
+fn underscore_is_valid_first_character() -> Result<(), ItemGroupIdInvalidFmt<'static>> {
+    let item_group_id = ItemGroupId::new("_good_id")?;
+
+    assert_eq!("_good_id", *item_group_id);
+    Ok(())
+}
+
+#[test]
+fn new_unchecked_does_not_validate_id() -> Result<(), ItemGroupIdInvalidFmt<'static>> {
+    let item_group_id = ItemGroupId::new_unchecked("!valid");
+
+    assert_eq!("!valid", *item_group_id);
+    Ok(())
+}
+
+#[test]
+fn try_from_str_returns_ok_borrowed_for_valid_id() -> Result<(), ItemGroupIdInvalidFmt<'static>> {
+    let item_group_id = ItemGroupId::try_from("good_id")?;
+
+    assert_eq!("good_id", *item_group_id);
+    Ok(())
+}
+
+#[test]
+fn try_from_string_returns_ok_owned_for_valid_id() -> Result<(), ItemGroupIdInvalidFmt<'static>> {
+    let item_group_id = ItemGroupId::try_from(String::from("good_id"))?;
+
+    assert_eq!("good_id", *item_group_id);
+    Ok(())
+}
+
+#[test]
+fn from_str_returns_err_owned_for_invalid_id() {
+    let error = ItemGroupId::from_str("has space").unwrap_err();
+
+    assert!(matches!(error.value(), Cow::Owned(_)));
+    assert_eq!("has space", error.value());
+}
+
+#[test]
+fn try_from_str_returns_err_borrowed_for_invalid_id() {
+    let error = ItemGroupId::try_from("has space").unwrap_err();
+
+    assert!(matches!(error.value(), Cow::Borrowed(_)));
+    assert_eq!("has space", error.value());
+}
+
+#[test]
+fn try_from_string_returns_err_owned_for_invalid_id() {
+    let error = ItemGroupId::try_from(String::from("has space")).unwrap_err();
+
+    assert!(matches!(error.value(), Cow::Owned(_)));
+    assert_eq!("has space", error.value());
+}
+
+#[test]
+fn display_returns_inner_str() -> Result<(), ItemGroupIdInvalidFmt<'static>> {
+    let item_group_id = ItemGroupId::try_from("good_id")?;
+
+    assert_eq!("good_id", item_group_id.to_string());
+    Ok(())
+}
+
+#[tokio::test]
+async fn present_uses_code_inline() -> Result<(), Box<dyn std::error::Error>> {
+    let mut presenter = FnTrackerPresenter::new();
+    let item_group_id = ItemGroupId::try_from("item_group_id")?;
+
+    item_group_id.present(&mut presenter).await?;
+
+    assert_eq!(
+        vec![FnInvocation::new(
+            "code_inline",
+            vec![Some(r#""item_group_id""#.to_string())]
+        )],
+        presenter.fn_invocations()
+    );
+    Ok(())
+}
+
+#[test]
+fn clone() -> Result<(), ItemGroupIdInvalidFmt<'static>> {
+    let item_group_id_0 = ItemGroupId::new("id")?;
+    #[allow(clippy::redundant_clone)] // https://github.com/rust-lang/rust-clippy/issues/9011
+    let item_group_id_1 = item_group_id_0.clone();
+
+    assert_eq!(item_group_id_0, item_group_id_1);
+    Ok(())
+}
+
+#[test]
+fn debug() -> Result<(), ItemGroupIdInvalidFmt<'static>> {
+    let item_group_id = ItemGroupId::new("id")?;
+
+    assert_eq!(r#"ItemGroupId("id")"#, format!("{item_group_id:?}"));
+    Ok(())
+}
+
+#[test]
+fn hash() -> Result<(), ItemGroupIdInvalidFmt<'static>> {
+    let item_group_id = ItemGroupId::new("item_group_id")?;
+
+    let mut hash_map = HashMap::new();
+    hash_map.insert(item_group_id, ());
+
+    Ok(())
+}
+
+#[test]
+fn partial_eq_ne() -> Result<(), ItemGroupIdInvalidFmt<'static>> {
+    let item_group_id_0 = ItemGroupId::new("id0")?;
+    let item_group_id_1 = ItemGroupId::new("id1")?;
+
+    assert!(item_group_id_0 != item_group_id_1);
+    Ok(())
+}
+
+#[test]
+fn serialize() -> Result<(), Box<dyn std::error::Error>> {
+    let item_group_id = ItemGroupId::new("item_group_id")?;
+
+    assert_eq!("item_group_id\n", serde_yaml::to_string(&item_group_id)?);
+    Ok(())
+}
+
+#[test]
+fn deserialize() -> Result<(), Box<dyn std::error::Error>> {
+    let item_group_id = ItemGroupId::new("item_group_id")?;
+
+    assert_eq!(item_group_id, serde_yaml::from_str("item_group_id")?);
+    Ok(())
+}
@@ -122,3 +122,16 @@ fn partial_eq_ne() -> Result<(), ItemIdInvalidFmt<'static>> {
     assert!(item_id_0 != item_id_1);
     Ok(())
 }
+
+#[test]
+fn instance_appends_instance_key_with_invalid_chars_replaced(
+) -> Result<(), ItemIdInvalidFmt<'static>> {
+    let item_id = ItemId::new("file_download")?;
+
+    assert_eq!(
+        ItemId::new("file_download_app_tar_gz")?,
+        item_id.instance("app.tar.gz")
+    );
+    assert_eq!(ItemId::new("file_download_0")?, item_id.instance("0"));
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use peace::cfg::{ItemInputs, ItemIoType};
+
+#[test]
+fn new_has_no_inputs() {
+    let item_inputs = ItemInputs::new();
+
+    assert!(item_inputs.item_io_types().is_empty());
+}
+
+#[test]
+fn with_input_adds_input() {
+    let item_inputs = ItemInputs::new().with_input::<u32>().with_input::<String>();
+
+    assert_eq!(
+        &[ItemIoType::of::<u32>(), ItemIoType::of::<String>()],
+        item_inputs.item_io_types()
+    );
+    assert_eq!(
+        vec![ItemIoType::of::<u32>(), ItemIoType::of::<String>()],
+        item_inputs.into_inner()
+    );
+}
+
+#[test]
+fn clone() {
+    let item_inputs = ItemInputs::new().with_input::<u32>();
+
+    assert_eq!(item_inputs, Clone::clone(&item_inputs));
+}
+
+#[test]
+fn debug() {
+    let item_inputs = ItemInputs::new().with_input::<u32>();
+
+    assert!(format!("{item_inputs:?}").starts_with("ItemInputs(["));
+}
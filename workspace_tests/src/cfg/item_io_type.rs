@@ -0,0 +1,25 @@
+use std::any::TypeId;
+
+use peace::cfg::ItemIoType;
+
+#[test]
+fn of_returns_type_id_and_type_name() {
+    let item_io_type = ItemIoType::of::<u32>();
+
+    assert_eq!(TypeId::of::<u32>(), item_io_type.type_id());
+    assert_eq!("u32", item_io_type.type_name());
+}
+
+#[test]
+fn clone() {
+    let item_io_type = ItemIoType::of::<u32>();
+
+    assert_eq!(item_io_type, Clone::clone(&item_io_type));
+}
+
+#[test]
+fn debug() {
+    let item_io_type = ItemIoType::of::<u32>();
+
+    assert!(format!("{item_io_type:?}").contains(r#"type_name: "u32""#));
+}
@@ -0,0 +1,62 @@
+use peace::{
+    cfg::{ItemIoType, ItemOutputs},
+    data::ItemOutput,
+    resources::{resources::ts::SetUp, Resources},
+};
+
+#[test]
+fn new_has_no_outputs() {
+    let item_outputs = ItemOutputs::<u8>::new();
+
+    assert_eq!(0, item_outputs.item_io_types().len());
+}
+
+#[test]
+fn with_output_adds_output() {
+    let item_outputs = ItemOutputs::<u8>::new()
+        .with_output(|state: &u8| u32::from(*state))
+        .with_output(|state: &u8| state.to_string());
+
+    assert_eq!(
+        vec![ItemIoType::of::<u32>(), ItemIoType::of::<String>()],
+        item_outputs.item_io_types().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn insert_inserts_none_for_each_output() {
+    let item_outputs = ItemOutputs::<u8>::new()
+        .with_output(|state: &u8| u32::from(*state))
+        .with_output(|state: &u8| state.to_string());
+    let mut resources = Resources::new();
+
+    item_outputs.insert(&mut resources);
+
+    assert_eq!(None, resources.borrow::<ItemOutput<u32>>().0);
+    assert_eq!(None, resources.borrow::<ItemOutput<String>>().0);
+}
+
+#[test]
+fn set_stores_output_computed_from_state() {
+    let item_outputs = ItemOutputs::<u8>::new()
+        .with_output(|state: &u8| u32::from(*state))
+        .with_output(|state: &u8| state.to_string());
+    let mut resources = Resources::new();
+    item_outputs.insert(&mut resources);
+    let resources = Resources::<SetUp>::from(resources);
+
+    item_outputs.set(&123, &resources);
+
+    assert_eq!(Some(123u32), resources.borrow::<ItemOutput<u32>>().0);
+    assert_eq!(
+        Some("123"),
+        resources.borrow::<ItemOutput<String>>().as_deref()
+    );
+}
+
+#[test]
+fn debug() {
+    let item_outputs = ItemOutputs::<u8>::new().with_output(|state: &u8| u32::from(*state));
+
+    assert!(format!("{item_outputs:?}").starts_with("ItemOutputs(["));
+}
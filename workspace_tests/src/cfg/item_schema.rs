@@ -0,0 +1,49 @@
+use peace::{
+    cfg::{Item, ItemSchema},
+    schemars::{gen::SchemaGenerator, schema::Schema},
+};
+
+use crate::{mock_item::MockItem, VecCopyItem};
+
+#[test]
+fn new_references_types_from_generator_definitions() {
+    let mut generator = SchemaGenerator::default();
+
+    let item_schema = ItemSchema::new::<MockItem<()>>(&mut generator);
+
+    assert_eq!(
+        ItemSchema {
+            params: Schema::new_ref(String::from("#/definitions/MockSrc")),
+            state: Schema::new_ref(String::from("#/definitions/MockState")),
+            state_diff: Schema::new_ref(String::from("#/definitions/MockDiff")),
+        },
+        item_schema
+    );
+    assert_eq!(
+        vec!["MockDiff", "MockSrc", "MockState"],
+        generator
+            .definitions()
+            .keys()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn schema_defaults_to_none() {
+    let mut generator = SchemaGenerator::default();
+
+    assert_eq!(None, <VecCopyItem as Item>::schema(&mut generator));
+    assert!(generator.definitions().is_empty());
+}
+
+#[test]
+fn serialize_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let item_schema = ItemSchema::new::<MockItem<()>>(&mut SchemaGenerator::default());
+
+    let serialized = serde_json::to_string(&item_schema)?;
+    let deserialized = serde_json::from_str::<ItemSchema>(&serialized)?;
+
+    assert_eq!(item_schema, deserialized);
+    Ok(())
+}
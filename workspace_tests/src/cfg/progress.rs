@@ -4,5 +4,6 @@ mod progress_limit;
 mod progress_sender;
 mod progress_status;
 mod progress_tracker;
+mod progress_tracker_snapshot;
 mod progress_update;
 mod progress_update_and_id;
@@ -30,10 +30,19 @@ fn serialize() {
 fn is_successful() {
     assert!(ProgressComplete::Success.is_successful());
     assert!(!ProgressComplete::Fail.is_successful());
+    assert!(!ProgressComplete::Skipped.is_successful());
 }
 
 #[test]
 fn is_failure() {
     assert!(!ProgressComplete::Success.is_failure());
     assert!(ProgressComplete::Fail.is_failure());
+    assert!(!ProgressComplete::Skipped.is_failure());
+}
+
+#[test]
+fn is_skipped() {
+    assert!(!ProgressComplete::Success.is_skipped());
+    assert!(!ProgressComplete::Fail.is_skipped());
+    assert!(ProgressComplete::Skipped.is_skipped());
 }
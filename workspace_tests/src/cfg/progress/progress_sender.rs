@@ -2,8 +2,8 @@ use peace::{
     cfg::{
         item_id,
         progress::{
-            CmdProgressUpdate, ProgressDelta, ProgressMsgUpdate, ProgressSender,
-            ProgressUpdateAndId,
+            CmdProgressUpdate, ProgressComplete, ProgressDelta, ProgressLimit, ProgressMsgUpdate,
+            ProgressSender, ProgressUpdateAndId,
         },
     },
     rt_model::ProgressUpdate,
@@ -167,6 +167,68 @@ fn tick_does_not_panic_when_progress_channel_is_closed() -> Result<(), Box<dyn s
     Ok(())
 }
 
+#[test]
+fn sub_step_sends_progress_update_for_sub_step() -> Result<(), Box<dyn std::error::Error>> {
+    let item_id = item_id!("test_item_id");
+    let (progress_tx, mut progress_rx) = mpsc::channel(10);
+    let progress_sender = ProgressSender::new(&item_id, &progress_tx);
+    let download = progress_sender.sub_step("download");
+
+    download.set_progress_limit(ProgressLimit::Bytes(100));
+    download.complete(ProgressComplete::Success, ProgressMsgUpdate::NoChange);
+
+    let progress_update_sub_step = |progress_update| CmdProgressUpdate::Item {
+        progress_update_and_id: ProgressUpdateAndId {
+            item_id: item_id!("test_item_id"),
+            progress_update: ProgressUpdate::SubStep {
+                name: String::from("download"),
+                progress_update: Box::new(progress_update),
+            },
+            msg_update: ProgressMsgUpdate::NoChange,
+        },
+    };
+    assert_eq!(
+        progress_update_sub_step(ProgressUpdate::Limit(ProgressLimit::Bytes(100))),
+        progress_rx.try_recv().unwrap()
+    );
+    assert_eq!(
+        progress_update_sub_step(ProgressUpdate::Complete(ProgressComplete::Success)),
+        progress_rx.try_recv().unwrap()
+    );
+    let error = progress_rx.try_recv().unwrap_err();
+    assert_eq!(TryRecvError::Empty, error);
+    Ok(())
+}
+
+#[test]
+fn sub_step_of_sub_step_sends_nested_progress_update() -> Result<(), Box<dyn std::error::Error>> {
+    let item_id = item_id!("test_item_id");
+    let (progress_tx, mut progress_rx) = mpsc::channel(10);
+    let progress_sender = ProgressSender::new(&item_id, &progress_tx);
+    let extract = progress_sender.sub_step("extract");
+    let verify = extract.sub_step("verify");
+
+    verify.inc(3, ProgressMsgUpdate::Set(String::from("file.txt")));
+
+    assert_eq!(
+        CmdProgressUpdate::Item {
+            progress_update_and_id: ProgressUpdateAndId {
+                item_id: item_id!("test_item_id"),
+                progress_update: ProgressUpdate::SubStep {
+                    name: String::from("extract"),
+                    progress_update: Box::new(ProgressUpdate::SubStep {
+                        name: String::from("verify"),
+                        progress_update: Box::new(ProgressUpdate::Delta(ProgressDelta::Inc(3))),
+                    }),
+                },
+                msg_update: ProgressMsgUpdate::Set(String::from("file.txt")),
+            }
+        },
+        progress_rx.try_recv().unwrap()
+    );
+    Ok(())
+}
+
 #[test]
 fn debug() {
     let item_id = item_id!("test_item_id");
@@ -135,6 +135,35 @@ fn elapsed() {
     assert!(elapsed < Duration::from_millis(500));
 }
 
+#[test]
+fn progress_bar_last_returns_last_nested_sub_tracker_progress_bar() {
+    let mut progress_tracker = ProgressTracker::new(ProgressBar::hidden().with_prefix("item"));
+    assert_eq!("item", progress_tracker.progress_bar_last().prefix());
+
+    let mut download = ProgressTracker::new(ProgressBar::hidden().with_prefix("download"));
+    download.sub_tracker_insert(
+        String::from("verify"),
+        ProgressTracker::new(ProgressBar::hidden().with_prefix("verify")),
+    );
+    progress_tracker.sub_tracker_insert(String::from("download"), download);
+
+    assert_eq!("verify", progress_tracker.progress_bar_last().prefix());
+    assert!(progress_tracker.sub_tracker("download").is_some());
+}
+
+#[test]
+fn reset_removes_sub_trackers() {
+    let mut progress_tracker = ProgressTracker::new(ProgressBar::hidden());
+    progress_tracker.sub_tracker_insert(
+        String::from("download"),
+        ProgressTracker::new(ProgressBar::hidden()),
+    );
+
+    progress_tracker.reset();
+
+    assert!(progress_tracker.sub_trackers().is_empty());
+}
+
 #[test]
 fn debug() {
     let progress_tracker = ProgressTracker::new(ProgressBar::hidden());
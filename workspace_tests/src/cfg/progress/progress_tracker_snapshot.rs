@@ -0,0 +1,62 @@
+use peace::{
+    cfg::progress::{
+        ProgressComplete, ProgressLimit, ProgressStatus, ProgressTracker, ProgressTrackerSnapshot,
+    },
+    rt_model::indicatif::ProgressBar,
+};
+
+#[test]
+fn from_progress_tracker_copies_progress() {
+    let mut progress_tracker = ProgressTracker::new(ProgressBar::hidden());
+    progress_tracker.set_progress_limit(ProgressLimit::Steps(10));
+    progress_tracker.inc(3);
+    progress_tracker.set_message(Some(String::from("uploading")));
+    progress_tracker.set_progress_status(ProgressStatus::Complete(ProgressComplete::Fail));
+    let mut sub_tracker = ProgressTracker::new(ProgressBar::hidden());
+    sub_tracker.set_progress_status(ProgressStatus::Running);
+    progress_tracker.sub_tracker_insert(String::from("compress"), sub_tracker);
+
+    let progress_tracker_snapshot = ProgressTrackerSnapshot::from(&progress_tracker);
+
+    assert_eq!(
+        ProgressStatus::Complete(ProgressComplete::Fail),
+        progress_tracker_snapshot.progress_status
+    );
+    assert_eq!(
+        Some(ProgressLimit::Steps(10)),
+        progress_tracker_snapshot.progress_limit
+    );
+    assert_eq!(3, progress_tracker_snapshot.units_current);
+    assert_eq!(
+        Some("uploading"),
+        progress_tracker_snapshot.message.as_deref()
+    );
+    assert_eq!(
+        progress_tracker.last_update_dt(),
+        progress_tracker_snapshot.last_update_dt
+    );
+    assert_eq!(
+        Some(&ProgressStatus::Running),
+        progress_tracker_snapshot
+            .sub_trackers
+            .get("compress")
+            .map(|sub_tracker_snapshot| &sub_tracker_snapshot.progress_status)
+    );
+}
+
+#[test]
+fn serialize_deserialize_round_trip() -> Result<(), serde_yaml::Error> {
+    let mut progress_tracker = ProgressTracker::new(ProgressBar::hidden());
+    progress_tracker.set_progress_status(ProgressStatus::Complete(ProgressComplete::Success));
+    let progress_tracker_snapshot = ProgressTrackerSnapshot::from(&progress_tracker);
+
+    let serialized = serde_yaml::to_string(&progress_tracker_snapshot)?;
+    let deserialized = serde_yaml::from_str::<ProgressTrackerSnapshot>(&serialized)?;
+
+    assert!(
+        !serialized.contains("sub_trackers"),
+        "Expected empty `sub_trackers` to not be serialized, but was:\n{serialized}"
+    );
+    assert_eq!(progress_tracker_snapshot, deserialized);
+    Ok(())
+}
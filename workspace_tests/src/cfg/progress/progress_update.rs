@@ -41,6 +41,48 @@ fn serialize_json() {
     )
 }
 
+#[test]
+fn deserialize_sub_step() {
+    assert_eq!(
+        ProgressUpdate::SubStep {
+            name: String::from("download"),
+            progress_update: Box::new(ProgressUpdate::Delta(ProgressDelta::Inc(3))),
+        },
+        serde_yaml::from_str(
+            "!SubStep\n\
+            name: download\n\
+            progress_update:\n  Delta:\n    Inc: 3\n"
+        )
+        .unwrap()
+    )
+}
+
+#[test]
+fn serialize_sub_step() {
+    assert_eq!(
+        "!SubStep\n\
+        name: download\n\
+        progress_update:\n  Delta:\n    Inc: 3\n",
+        serde_yaml::to_string(&ProgressUpdate::SubStep {
+            name: String::from("download"),
+            progress_update: Box::new(ProgressUpdate::Delta(ProgressDelta::Inc(3))),
+        })
+        .unwrap()
+    )
+}
+
+#[test]
+fn serialize_json_sub_step() {
+    assert_eq!(
+        r#"{"SubStep":{"name":"download","progress_update":{"Delta":{"Inc":3}}}}"#,
+        serde_json::to_string(&ProgressUpdate::SubStep {
+            name: String::from("download"),
+            progress_update: Box::new(ProgressUpdate::Delta(ProgressDelta::Inc(3))),
+        })
+        .unwrap()
+    )
+}
+
 #[test]
 fn eq() {
     assert_eq!(
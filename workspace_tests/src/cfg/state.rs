@@ -1,3 +1,4 @@
 mod external;
 mod external_opt;
 mod nothing;
+mod sensitive;
@@ -0,0 +1,193 @@
+use std::sync::Arc;
+
+use peace::cfg::state::{Sensitive, SensitiveCipher, SensitiveCipherError, SensitiveSerdeMode};
+
+#[test]
+fn display_is_redacted() {
+    assert_eq!("********", format!("{}", Sensitive::new("hunter2")));
+}
+
+#[test]
+fn debug_is_redacted() {
+    assert_eq!(
+        r#"Sensitive("********")"#,
+        format!("{:?}", Sensitive::new("hunter2"))
+    );
+}
+
+#[test]
+fn clone() {
+    let sensitive = Sensitive::new(String::from("hunter2"));
+
+    assert_eq!(sensitive, Clone::clone(&sensitive));
+}
+
+#[test]
+fn expose_returns_value() {
+    let sensitive = Sensitive::from(String::from("hunter2"));
+
+    assert_eq!("hunter2", sensitive.expose());
+    assert_eq!("hunter2", sensitive.into_inner());
+}
+
+#[test]
+fn serialize_returns_error_by_default() {
+    let error = serde_yaml::to_string(&Sensitive::new(String::from("hunter2"))).unwrap_err();
+
+    assert!(
+        error
+            .to_string()
+            .starts_with("Sensitive value cannot be serialized outside of"),
+        "Expected unscoped error, but was: {error}"
+    );
+}
+
+#[test]
+fn serialize_redacts_in_redact_mode() -> Result<(), serde_yaml::Error> {
+    let serialized = SensitiveSerdeMode::Redact
+        .scope(|| serde_yaml::to_string(&Sensitive::new(String::from("hunter2"))))?;
+
+    assert_eq!("'********'\n", serialized);
+    Ok(())
+}
+
+#[test]
+fn serialize_plain_in_plain_mode() -> Result<(), serde_yaml::Error> {
+    let serialized = SensitiveSerdeMode::Plain
+        .scope(|| serde_yaml::to_string(&Sensitive::new(String::from("hunter2"))))?;
+
+    assert_eq!("hunter2\n", serialized);
+    Ok(())
+}
+
+#[test]
+fn serialize_returns_error_in_key_required_mode() {
+    let error = SensitiveSerdeMode::KeyRequired
+        .scope(|| serde_yaml::to_string(&Sensitive::new(String::from("hunter2"))))
+        .unwrap_err();
+
+    assert!(
+        error
+            .to_string()
+            .starts_with("Sensitive value cannot be stored, as the workspace has no sensitive"),
+        "Expected key required error, but was: {error}"
+    );
+}
+
+#[test]
+fn deserialize_plain_in_key_required_mode() -> Result<(), serde_yaml::Error> {
+    let sensitive =
+        SensitiveSerdeMode::KeyRequired.scope(|| serde_yaml::from_str::<Sensitive<u32>>("123"))?;
+
+    assert_eq!(Sensitive::new(123), sensitive);
+    Ok(())
+}
+
+#[test]
+fn serialize_encrypts_in_encrypt_mode() -> Result<(), serde_yaml::Error> {
+    let serialized = SensitiveSerdeMode::Encrypt(Arc::new(ReverseCipher))
+        .scope(|| serde_yaml::to_string(&Sensitive::new(String::from("hunter2"))))?;
+
+    assert_eq!(
+        "encrypted:\n2retnuh",
+        serde_yaml::from_str::<String>(&serialized)?
+    );
+    Ok(())
+}
+
+#[test]
+fn deserialize_plain() -> Result<(), serde_yaml::Error> {
+    let sensitive = serde_yaml::from_str::<Sensitive<u32>>("123")?;
+
+    assert_eq!(Sensitive::new(123), sensitive);
+    Ok(())
+}
+
+#[test]
+fn deserialize_decrypts_in_encrypt_mode() -> Result<(), serde_yaml::Error> {
+    let sensitive = Sensitive::new(vec![1u32, 2, 3]);
+    let sensitive_deserialized =
+        SensitiveSerdeMode::Encrypt(Arc::new(ReverseCipher)).scope(|| {
+            let serialized = serde_yaml::to_string(&sensitive)?;
+            serde_yaml::from_str::<Sensitive<Vec<u32>>>(&serialized)
+        })?;
+
+    assert_eq!(sensitive, sensitive_deserialized);
+    Ok(())
+}
+
+#[test]
+fn deserialize_returns_error_when_redacted() {
+    let error = serde_yaml::from_str::<Sensitive<String>>("'********'").unwrap_err();
+
+    assert!(
+        error
+            .to_string()
+            .starts_with("Sensitive value was redacted"),
+        "Expected redacted error, but was: {error}"
+    );
+}
+
+#[test]
+fn deserialize_returns_error_when_encrypted_and_no_cipher() {
+    let error = serde_yaml::from_str::<Sensitive<String>>("'encrypted:abc'").unwrap_err();
+
+    assert!(
+        error
+            .to_string()
+            .starts_with("Sensitive value is encrypted, but no sensitive field key"),
+        "Expected no cipher error, but was: {error}"
+    );
+}
+
+#[test]
+fn scope_restores_previous_mode() {
+    SensitiveSerdeMode::Plain.scope(|| {
+        SensitiveSerdeMode::Redact.scope(|| {
+            assert!(matches!(
+                SensitiveSerdeMode::current(),
+                SensitiveSerdeMode::Redact
+            ));
+        });
+
+        assert!(matches!(
+            SensitiveSerdeMode::current(),
+            SensitiveSerdeMode::Plain
+        ));
+    });
+
+    assert!(matches!(
+        SensitiveSerdeMode::current(),
+        SensitiveSerdeMode::Unscoped
+    ));
+}
+
+#[test]
+fn sensitive_serde_mode_debug() {
+    assert_eq!("Unscoped", format!("{:?}", SensitiveSerdeMode::Unscoped));
+    assert_eq!("Redact", format!("{:?}", SensitiveSerdeMode::Redact));
+    assert_eq!(
+        "KeyRequired",
+        format!("{:?}", SensitiveSerdeMode::KeyRequired)
+    );
+    assert_eq!("Plain", format!("{:?}", SensitiveSerdeMode::Plain));
+    assert_eq!(
+        "Encrypt(..)",
+        format!("{:?}", SensitiveSerdeMode::Encrypt(Arc::new(ReverseCipher)))
+    );
+}
+
+/// Cipher that reverses the plaintext, to make assertions readable.
+struct ReverseCipher;
+
+impl SensitiveCipher for ReverseCipher {
+    fn encrypt(&self, plaintext: &[u8]) -> Result<String, SensitiveCipherError> {
+        let mut ciphertext = String::from_utf8(plaintext.to_vec())?;
+        ciphertext = ciphertext.chars().rev().collect();
+        Ok(ciphertext)
+    }
+
+    fn decrypt(&self, ciphertext: &str) -> Result<Vec<u8>, SensitiveCipherError> {
+        Ok(ciphertext.chars().rev().collect::<String>().into_bytes())
+    }
+}
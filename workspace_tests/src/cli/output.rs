@@ -5,9 +5,15 @@ mod cli_output;
 mod cli_output_builder;
 mod cli_output_target;
 
+#[cfg(target_arch = "wasm32")]
+mod web_output;
+
 cfg_if::cfg_if! {
     if #[cfg(feature = "output_progress")] {
         mod cli_progress_format_opt;
         mod cli_progress_format_opt_parse_error;
     }
 }
+
+#[cfg(feature = "tui")]
+mod tui_output;
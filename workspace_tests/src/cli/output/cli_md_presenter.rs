@@ -1027,6 +1027,58 @@ async fn presents_list_bulleted_aligned_padding_color_enabled()
     Ok(())
 }
 
+#[tokio::test]
+async fn presents_table_color_disabled() -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut cli_output = cli_output(&mut buffer, CliColorizeOpt::Never);
+    let mut presenter = CliMdPresenter::new(&mut cli_output);
+
+    presenter
+        .table(
+            &["Item", "Action"],
+            [
+                vec![String::from("short"), String::from("ensure")],
+                vec![String::from("longer_item"), String::from("a|b\nc")],
+            ],
+        )
+        .await?;
+
+    let output = String::from_utf8(buffer)?;
+    assert_eq!(
+        "\
+            | Item        | Action |\n\
+            |-------------|--------|\n\
+            | short       | ensure |\n\
+            | longer_item | a\\|b c |\n\
+        ",
+        output
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn presents_table_color_enabled() -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut cli_output = cli_output(&mut buffer, CliColorizeOpt::Always);
+    let mut presenter = CliMdPresenter::new(&mut cli_output);
+
+    presenter
+        .table(&["Item"], [vec![String::from("an_item")]])
+        .await?;
+
+    let output = String::from_utf8(buffer)?;
+    assert!(output.contains("\u{1b}[1mItem\u{1b}[0m"));
+    assert_eq!(
+        "\
+            | Item    |\n\
+            |---------|\n\
+            | an_item |\n\
+        ",
+        console::strip_ansi_codes(&output)
+    );
+    Ok(())
+}
+
 fn cli_output(buffer: &mut Vec<u8>, colorize: CliColorizeOpt) -> CliOutput<&mut Vec<u8>> {
     CliOutputBuilder::new_with_writer(buffer)
         .with_outcome_format(OutputFormat::Text)
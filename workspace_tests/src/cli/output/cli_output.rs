@@ -6,21 +6,24 @@ use peace::{
         internal::{StateDiffsMut, StatesMut},
         states::{StateDiffs, StatesCurrentStored},
     },
-    rt_model::output::OutputWrite,
+    rt_model::{output::OutputWrite, CmdOutcomeReport, ItemErrorReport},
 };
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "output_progress")] {
         use peace::{
-            cfg::progress::{
-                ProgressComplete,
-                ProgressDelta,
-                ProgressLimit,
-                ProgressMsgUpdate,
-                ProgressStatus,
-                ProgressTracker,
-                ProgressUpdate,
-                ProgressUpdateAndId,
+            cfg::{
+                item_group_id,
+                progress::{
+                    ProgressComplete,
+                    ProgressDelta,
+                    ProgressLimit,
+                    ProgressMsgUpdate,
+                    ProgressStatus,
+                    ProgressTracker,
+                    ProgressUpdate,
+                    ProgressUpdateAndId,
+                },
             },
             cli::output::{CliOutputTarget, CliProgressFormatOpt},
             rt_model::{
@@ -153,6 +156,29 @@ async fn outputs_state_diffs_as_text_colorized() -> Result<(), Box<dyn std::erro
     Ok(())
 }
 
+#[tokio::test]
+async fn outputs_cmd_outcome_report_as_text() -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut cli_output = cli_output(&mut buffer, OutputFormat::Text);
+    let cmd_outcome_report = CmdOutcomeReport::new(vec![ItemErrorReport::new(
+        item_id!("item_0"),
+        &Error::CliOutputTest,
+        Some(String::from("logical, 1.1")),
+    )]);
+    let error = Error::from(peace::rt_model::Error::from(cmd_outcome_report));
+
+    <CliOutput<_> as OutputWrite<Error>>::write_err(&mut cli_output, &error).await?;
+
+    let output = String::from_utf8(buffer)?;
+    assert!(output.contains("1 item failed."), "{output}");
+    assert!(
+        output.contains("`item_0`: CliOutputTest display message."),
+        "{output}"
+    );
+    assert!(output.contains("state reached: logical, 1.1"), "{output}");
+    Ok(())
+}
+
 #[tokio::test]
 async fn outputs_error_as_text_colorized() -> Result<(), Box<dyn std::error::Error>> {
     let mut buffer = Vec::new();
@@ -811,6 +837,139 @@ mod color_never {
         );
     }
 
+    #[tokio::test]
+    async fn progress_update_with_sub_step_renders_indented_progress_bar() {
+        let mut buffer = Vec::new();
+        let mut cli_output = cli_output_progress(
+            &mut buffer,
+            OutputFormat::Text,
+            CliColorizeOpt::Never,
+            CliProgressFormatOpt::ProgressBar,
+        );
+        let (mut cmd_progress_tracker, progress_bar) = cmd_progress_tracker(&cli_output);
+
+        <CliOutput<_> as OutputWrite<Error>>::progress_begin(
+            &mut cli_output,
+            &cmd_progress_tracker,
+        )
+        .await;
+        // Hack: because we enable this in `progress_begin`
+        // Remove when we properly tick progress updates in `ApplyCmd`.
+        progress_bar.disable_steady_tick();
+
+        // Sub-step trackers are inserted by `CmdExecution` when the first update is
+        // received.
+        let sub_progress_bar = cmd_progress_tracker
+            .multi_progress()
+            .insert_after(&progress_bar, ProgressBar::hidden().with_prefix("download"));
+        let progress_trackers = cmd_progress_tracker.progress_trackers_mut();
+        let progress_tracker = progress_trackers
+            .get_mut(&item_id!("test_item_id"))
+            .unwrap();
+        progress_tracker.sub_tracker_insert(
+            String::from("download"),
+            ProgressTracker::new(sub_progress_bar.clone()),
+        );
+        let sub_tracker = progress_tracker.sub_tracker_mut("download").unwrap();
+        sub_tracker.set_progress_status(ProgressStatus::Running);
+        sub_tracker.set_progress_limit(ProgressLimit::Steps(100));
+
+        let progress_update_and_id = ProgressUpdateAndId {
+            item_id: item_id!("test_item_id"),
+            progress_update: ProgressUpdate::SubStep {
+                name: String::from("download"),
+                progress_update: Box::new(ProgressUpdate::Limit(ProgressLimit::Steps(100))),
+            },
+            msg_update: ProgressMsgUpdate::NoChange,
+        };
+        <CliOutput<_> as OutputWrite<Error>>::progress_update(
+            &mut cli_output,
+            progress_tracker,
+            &progress_update_and_id,
+        )
+        .await;
+
+        let CliOutputTarget::InMemory(in_memory_term) = cli_output.progress_target() else {
+            ({
+                #[cfg_attr(coverage_nightly, coverage(off))]
+                || -> ! { unreachable!("This is set in `cli_output_progress`.") }
+            })();
+        };
+        sub_progress_bar.set_position(20);
+        // The sub-step's bar is indented, and aligned with the item's bar.
+        let contents = in_memory_term.contents();
+        assert_eq!(
+            Some(
+                r#"  🔵 download      ▰▰▰▰▰▰▰▰▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱ 20/100 (el: 0s, eta: 0s)"#
+            ),
+            contents.lines().nth(1)
+        );
+    }
+
+    #[tokio::test]
+    async fn progress_update_for_item_group_renders_one_progress_bar() {
+        let mut buffer = Vec::new();
+        let mut cli_output = cli_output_progress(
+            &mut buffer,
+            OutputFormat::Text,
+            CliColorizeOpt::Never,
+            CliProgressFormatOpt::ProgressBar,
+        );
+        let (mut cmd_progress_tracker, progress_bars) = cmd_progress_tracker_grouped(&cli_output);
+
+        <CliOutput<_> as OutputWrite<Error>>::progress_begin(
+            &mut cli_output,
+            &cmd_progress_tracker,
+        )
+        .await;
+        // Hack: because we enable this in `progress_begin`
+        // Remove when we properly tick progress updates in `ApplyCmd`.
+        progress_bars
+            .iter()
+            .for_each(|progress_bar| progress_bar.disable_steady_tick());
+
+        let CliOutputTarget::InMemory(in_memory_term) = cli_output.progress_target() else {
+            ({
+                #[cfg_attr(coverage_nightly, coverage(off))]
+                || -> ! { unreachable!("This is set in `cli_output_progress`.") }
+            })();
+        };
+        let in_memory_term = in_memory_term.clone();
+        // Grouped items are rendered as one progress bar, numbered as one entry.
+        assert_eq!(
+            "⚫ 1. item_group ▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱ 0/2 (el: 0s, eta: 0s)\n\
+             ⚫ 2. item_2     ▰▰▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱ (el: 0s, eta: 0s)",
+            in_memory_term.contents()
+        );
+
+        for item_id in [item_id!("item_0"), item_id!("item_1")] {
+            let progress_tracker = cmd_progress_tracker
+                .progress_trackers_mut()
+                .get_mut(&item_id)
+                .unwrap();
+            let progress_complete = ProgressComplete::Success;
+            progress_tracker
+                .set_progress_status(ProgressStatus::Complete(progress_complete.clone()));
+            let progress_update_and_id = ProgressUpdateAndId {
+                item_id,
+                progress_update: ProgressUpdate::Complete(progress_complete),
+                msg_update: ProgressMsgUpdate::NoChange,
+            };
+            <CliOutput<_> as OutputWrite<Error>>::progress_update(
+                &mut cli_output,
+                progress_tracker,
+                &progress_update_and_id,
+            )
+            .await;
+        }
+
+        assert_eq!(
+            "✅ 1. item_group ▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰▰\n\
+             ⚫ 2. item_2     ▰▰▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱▱ (el: 0s, eta: 0s)",
+            in_memory_term.contents()
+        );
+    }
+
     #[tokio::test]
     async fn progress_update_with_complete_success_finishes_progress_bar() {
         let mut buffer = Vec::new();
@@ -1174,3 +1333,33 @@ fn cmd_progress_tracker(cli_output: &CliOutput<&mut Vec<u8>>) -> (CmdProgressTra
 
     (cmd_progress_tracker, progress_bar)
 }
+
+#[cfg(feature = "output_progress")]
+fn cmd_progress_tracker_grouped(
+    cli_output: &CliOutput<&mut Vec<u8>>,
+) -> (CmdProgressTracker, Vec<ProgressBar>) {
+    let CliOutputTarget::InMemory(in_memory_term) = cli_output.progress_target() else {
+        unreachable!("This is set in `cli_output_progress`.");
+    };
+    let multi_progress = MultiProgress::with_draw_target(ProgressDrawTarget::term_like(Box::new(
+        in_memory_term.clone(),
+    )));
+    let mut progress_trackers = IndexMap::new();
+    let progress_bars = [item_id!("item_0"), item_id!("item_1"), item_id!("item_2")]
+        .into_iter()
+        .map(|item_id| {
+            let progress_bar = multi_progress.add(ProgressBar::hidden());
+            progress_trackers.insert(item_id, ProgressTracker::new(progress_bar.clone()));
+            progress_bar
+        })
+        .collect::<Vec<_>>();
+    let mut item_groups = IndexMap::new();
+    item_groups.insert(
+        item_group_id!("item_group"),
+        vec![item_id!("item_0"), item_id!("item_1")],
+    );
+    let cmd_progress_tracker =
+        CmdProgressTracker::new(multi_progress, progress_trackers).with_item_groups(item_groups);
+
+    (cmd_progress_tracker, progress_bars)
+}
@@ -0,0 +1,188 @@
+use peace::{
+    cfg::{
+        item_id,
+        progress::{
+            ProgressComplete, ProgressDelta, ProgressLimit, ProgressMsgUpdate, ProgressStatus,
+            ProgressTracker, ProgressUpdate, ProgressUpdateAndId,
+        },
+        State,
+    },
+    cli::{
+        output::TuiOutput,
+        ratatui::{backend::TestBackend, Terminal},
+    },
+    resources::{internal::StatesMut, states::StatesCurrentStored},
+    rt_model::{
+        indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget},
+        output::OutputWrite,
+        CmdProgressTracker, IndexMap,
+    },
+};
+
+#[tokio::test]
+async fn progress_begin_renders_gauge_per_item() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tui_output = tui_output()?;
+    let cmd_progress_tracker = cmd_progress_tracker();
+
+    <TuiOutput<_> as OutputWrite<Error>>::progress_begin(&mut tui_output, &cmd_progress_tracker)
+        .await;
+
+    let screen = screen(&tui_output);
+    assert!(screen.contains("Progress"), "screen was:\n{screen}");
+    assert!(screen.contains("item_0"), "screen was:\n{screen}");
+    assert!(screen.contains("item_1"), "screen was:\n{screen}");
+    assert!(screen.contains("initialized"), "screen was:\n{screen}");
+    Ok(())
+}
+
+#[tokio::test]
+async fn progress_update_renders_item_units_and_message() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut tui_output = tui_output()?;
+    let mut cmd_progress_tracker = cmd_progress_tracker();
+    <TuiOutput<_> as OutputWrite<Error>>::progress_begin(&mut tui_output, &cmd_progress_tracker)
+        .await;
+
+    let progress_tracker = cmd_progress_tracker
+        .progress_trackers_mut()
+        .get_mut(&item_id!("item_0"))
+        .expect("Expected `item_0` progress tracker to exist.");
+    progress_tracker.set_progress_limit(ProgressLimit::Steps(4));
+    progress_tracker.set_progress_status(ProgressStatus::Running);
+    progress_tracker.inc(1);
+    progress_tracker.set_message(Some(String::from("downloading")));
+    <TuiOutput<_> as OutputWrite<Error>>::progress_update(
+        &mut tui_output,
+        progress_tracker,
+        &ProgressUpdateAndId {
+            item_id: item_id!("item_0"),
+            progress_update: ProgressUpdate::Delta(ProgressDelta::Inc(1)),
+            msg_update: ProgressMsgUpdate::Set(String::from("downloading")),
+        },
+    )
+    .await;
+
+    let progress_tracker = cmd_progress_tracker
+        .progress_trackers_mut()
+        .get_mut(&item_id!("item_1"))
+        .expect("Expected `item_1` progress tracker to exist.");
+    progress_tracker.set_progress_status(ProgressStatus::Complete(ProgressComplete::Fail));
+    <TuiOutput<_> as OutputWrite<Error>>::progress_update(
+        &mut tui_output,
+        progress_tracker,
+        &ProgressUpdateAndId {
+            item_id: item_id!("item_1"),
+            progress_update: ProgressUpdate::Complete(ProgressComplete::Fail),
+            msg_update: ProgressMsgUpdate::NoChange,
+        },
+    )
+    .await;
+
+    let screen = screen(&tui_output);
+    assert!(
+        screen.contains("running 1/4 downloading"),
+        "screen was:\n{screen}"
+    );
+    assert!(screen.contains("failed"), "screen was:\n{screen}");
+    Ok(())
+}
+
+#[tokio::test]
+async fn present_appends_to_log_pane() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tui_output = tui_output()?;
+    let states_current_stored = {
+        let mut states = StatesMut::new();
+        states.insert(item_id!("item_0"), State::new("logical", 1.1));
+        states.insert(item_id!("item_1"), State::new(1u8, true));
+        StatesCurrentStored::from(states)
+    };
+
+    <TuiOutput<_> as OutputWrite<Error>>::present(&mut tui_output, &states_current_stored).await?;
+
+    assert_eq!(
+        &[
+            String::from("1. `item_0`: logical, 1.1"),
+            String::from("2. `item_1`: 1, true"),
+        ],
+        tui_output.log_lines()
+    );
+    let screen = screen(&tui_output);
+    assert!(screen.contains("Log"), "screen was:\n{screen}");
+    assert!(
+        screen.contains("1. `item_0`: logical, 1.1"),
+        "screen was:\n{screen}"
+    );
+    assert!(!screen.contains("Errors"), "screen was:\n{screen}");
+    Ok(())
+}
+
+#[tokio::test]
+async fn log_pane_scrolls_to_latest_lines() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tui_output = tui_output()?;
+
+    for index in 0..30 {
+        <TuiOutput<_> as OutputWrite<Error>>::present(&mut tui_output, format!("line {index}"))
+            .await?;
+    }
+
+    let screen = screen(&tui_output);
+    assert!(screen.contains("line 29"), "screen was:\n{screen}");
+    assert!(!screen.contains("line 0 "), "screen was:\n{screen}");
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_err_shows_error_pane() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tui_output = tui_output()?;
+    let error = Error::TuiOutputTest;
+
+    <TuiOutput<_> as OutputWrite<Error>>::write_err(&mut tui_output, &error).await?;
+
+    assert_eq!(&[error.to_string()], tui_output.error_lines());
+    let screen = screen(&tui_output);
+    assert!(screen.contains("Errors"), "screen was:\n{screen}");
+    Ok(())
+}
+
+#[derive(Debug, thiserror::Error)]
+enum Error {
+    /// TuiOutputTest display message.
+    #[error("TuiOutputTest display message.")]
+    TuiOutputTest,
+
+    // Framework errors
+    /// A `peace` runtime error occurred.
+    #[error("A `peace` runtime error occurred.")]
+    PeaceRtError(#[from] peace::rt_model::Error),
+}
+
+fn tui_output() -> Result<TuiOutput<TestBackend>, std::io::Error> {
+    let terminal = Terminal::new(TestBackend::new(80, 24))?;
+    Ok(TuiOutput::new_with_terminal(terminal))
+}
+
+fn cmd_progress_tracker() -> CmdProgressTracker {
+    let multi_progress = MultiProgress::with_draw_target(ProgressDrawTarget::hidden());
+    let mut progress_trackers = IndexMap::new();
+    ["item_0", "item_1"].into_iter().for_each(|item_id| {
+        let progress_bar = multi_progress.add(ProgressBar::hidden());
+        let progress_tracker = ProgressTracker::new(progress_bar);
+        progress_trackers.insert(
+            peace::cfg::ItemId::new(item_id).expect("Expected item ID to be valid."),
+            progress_tracker,
+        );
+    });
+    CmdProgressTracker::new(multi_progress, progress_trackers)
+}
+
+/// Returns the rendered screen as text, one line per terminal row.
+fn screen(tui_output: &TuiOutput<TestBackend>) -> String {
+    let buffer = tui_output.terminal().backend().buffer();
+    let width = usize::from(buffer.area.width);
+    buffer
+        .content
+        .chunks(width)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
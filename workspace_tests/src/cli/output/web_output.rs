@@ -0,0 +1,174 @@
+use std::{cell::RefCell, rc::Rc};
+
+use peace::{
+    cfg::{item_id, State},
+    resources::{internal::StatesMut, states::StatesCurrentStored},
+    rt_model::output::OutputWrite,
+};
+use peace_rt_model_web::{WebOutput, WebOutputEvent};
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "output_progress")] {
+        use peace::{
+            cfg::progress::{
+                ProgressDelta,
+                ProgressLimit,
+                ProgressMsgUpdate,
+                ProgressStatus,
+                ProgressTracker,
+                ProgressUpdate,
+                ProgressUpdateAndId,
+            },
+            rt_model::{
+                indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget},
+                CmdProgressTracker,
+                IndexMap,
+            },
+        };
+    }
+}
+
+#[tokio::test]
+async fn present_sends_presentable_as_json() -> Result<(), Box<dyn std::error::Error>> {
+    let (mut web_output, web_output_events) = web_output();
+    let states_current_stored = {
+        let mut states = StatesMut::new();
+        states.insert(item_id!("item_0"), State::new("logical", 1.1));
+        StatesCurrentStored::from(states)
+    };
+
+    <WebOutput as OutputWrite<Error>>::present(&mut web_output, &states_current_stored).await?;
+
+    assert_eq!(
+        vec![WebOutputEvent::Present {
+            presentable: serde_json::to_value(&states_current_stored)?,
+        }],
+        *web_output_events.borrow()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_err_sends_error_message() -> Result<(), Box<dyn std::error::Error>> {
+    let (mut web_output, web_output_events) = web_output();
+    let error = Error::WebOutputTest;
+
+    <WebOutput as OutputWrite<Error>>::write_err(&mut web_output, &error).await?;
+
+    assert_eq!(
+        vec![WebOutputEvent::Error {
+            message: String::from("WebOutputTest display message."),
+        }],
+        *web_output_events.borrow()
+    );
+    Ok(())
+}
+
+#[test]
+fn output_fn_is_none_when_created_from_fn() {
+    let (web_output, _web_output_events) = web_output();
+
+    assert!(web_output.output_fn().is_none());
+}
+
+#[cfg(feature = "output_progress")]
+#[tokio::test]
+async fn progress_begin_sends_item_ids() {
+    let (mut web_output, web_output_events) = web_output();
+    let cmd_progress_tracker = cmd_progress_tracker();
+
+    <WebOutput as OutputWrite<Error>>::progress_begin(&mut web_output, &cmd_progress_tracker).await;
+
+    assert_eq!(
+        vec![WebOutputEvent::ProgressBegin {
+            item_ids: vec![item_id!("test_item_id")],
+        }],
+        *web_output_events.borrow()
+    );
+}
+
+#[cfg(feature = "output_progress")]
+#[tokio::test]
+async fn progress_update_sends_progress_tracker_state() {
+    let (mut web_output, web_output_events) = web_output();
+    let mut cmd_progress_tracker = cmd_progress_tracker();
+    let progress_tracker = cmd_progress_tracker
+        .progress_trackers_mut()
+        .get_mut(&item_id!("test_item_id"))
+        .unwrap();
+    progress_tracker.set_progress_status(ProgressStatus::Running);
+    progress_tracker.set_progress_limit(ProgressLimit::Steps(100));
+    progress_tracker.inc(20);
+    progress_tracker.set_message(Some(String::from("downloading")));
+
+    let progress_update_and_id = ProgressUpdateAndId {
+        item_id: item_id!("test_item_id"),
+        progress_update: ProgressUpdate::Delta(ProgressDelta::Inc(20)),
+        msg_update: ProgressMsgUpdate::NoChange,
+    };
+    <WebOutput as OutputWrite<Error>>::progress_update(
+        &mut web_output,
+        progress_tracker,
+        &progress_update_and_id,
+    )
+    .await;
+
+    assert_eq!(
+        vec![WebOutputEvent::ProgressUpdate {
+            item_id: item_id!("test_item_id"),
+            progress_update: ProgressUpdate::Delta(ProgressDelta::Inc(20)),
+            progress_status: ProgressStatus::Running,
+            progress_limit: Some(ProgressLimit::Steps(100)),
+            units_current: 20,
+            units_total: Some(100),
+            message: Some(String::from("downloading")),
+        }],
+        *web_output_events.borrow()
+    );
+}
+
+#[cfg(feature = "output_progress")]
+#[tokio::test]
+async fn progress_end_sends_progress_end() {
+    let (mut web_output, web_output_events) = web_output();
+    let cmd_progress_tracker = cmd_progress_tracker();
+
+    <WebOutput as OutputWrite<Error>>::progress_end(&mut web_output, &cmd_progress_tracker).await;
+
+    assert_eq!(
+        vec![WebOutputEvent::ProgressEnd],
+        *web_output_events.borrow()
+    );
+}
+
+#[derive(Debug, thiserror::Error)]
+enum Error {
+    /// WebOutputTest display message.
+    #[error("WebOutputTest display message.")]
+    WebOutputTest,
+
+    // Framework errors
+    /// A `peace` runtime error occurred.
+    #[error("A `peace` runtime error occurred.")]
+    PeaceRtError(#[from] peace::rt_model::Error),
+}
+
+fn web_output() -> (WebOutput, Rc<RefCell<Vec<WebOutputEvent>>>) {
+    let web_output_events = Rc::new(RefCell::new(Vec::new()));
+    let web_output = WebOutput::from_fn({
+        let web_output_events = Rc::clone(&web_output_events);
+        move |web_output_event| web_output_events.borrow_mut().push(web_output_event)
+    });
+
+    (web_output, web_output_events)
+}
+
+#[cfg(feature = "output_progress")]
+fn cmd_progress_tracker() -> CmdProgressTracker {
+    let multi_progress = MultiProgress::with_draw_target(ProgressDrawTarget::hidden());
+    let mut progress_trackers = IndexMap::new();
+    let progress_bar = multi_progress.add(ProgressBar::hidden());
+    progress_trackers.insert(item_id!("test_item_id"), ProgressTracker::new(progress_bar));
+
+    CmdProgressTracker::new(multi_progress, progress_trackers)
+}
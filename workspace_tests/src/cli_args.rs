@@ -0,0 +1,3 @@
+mod cli_docs_gen;
+mod cmd_args;
+mod flow_cmd;
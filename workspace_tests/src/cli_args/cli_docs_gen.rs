@@ -0,0 +1,123 @@
+use peace::{
+    cfg::{flow_id, profile},
+    cli_args::{
+        clap::{self, Parser},
+        clap_complete::Shell,
+        CliDocsGen, CmdArgs, FlowCmd,
+    },
+    rt_model::{Flow, ItemGraphBuilder},
+};
+
+use crate::{mock_item::MockItem, PeaceTestError, VecCopyItem};
+
+#[derive(Debug, Parser)]
+#[command(name = "test_app")]
+struct TestArgs {
+    #[command(subcommand)]
+    command: FlowCmd,
+    #[command(flatten)]
+    cmd_args: CmdArgs,
+}
+
+#[test]
+fn command_adds_profiles_and_flows_as_possible_values() {
+    let cmd = cli_docs_gen().command();
+
+    let possible_values = |arg_id: &str| {
+        cmd.get_arguments()
+            .find(|arg| arg.get_id() == arg_id)
+            .map(|arg| {
+                arg.get_possible_values()
+                    .into_iter()
+                    .map(|possible_value| possible_value.get_name().to_string())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default()
+    };
+    assert_eq!(
+        vec!["dev".to_string(), "prod".to_string()],
+        possible_values("profile")
+    );
+    assert_eq!(vec!["test_flow".to_string()], possible_values("flow"));
+}
+
+#[test]
+fn command_adds_flows_section_to_after_long_help() {
+    let cmd = cli_docs_gen().command();
+
+    assert_eq!(
+        Some("Flows:\n  test_flow: vec_copy, mock"),
+        cmd.get_after_long_help()
+            .map(ToString::to_string)
+            .as_deref()
+    );
+}
+
+#[test]
+fn command_is_unchanged_when_no_profiles_or_flows() {
+    let cmd = CliDocsGen::new::<TestArgs>().command();
+
+    let profile_arg = cmd
+        .get_arguments()
+        .find(|arg| arg.get_id() == "profile")
+        .expect("Expected `--profile` argument to exist.");
+    assert!(profile_arg.get_possible_values().is_empty());
+    assert!(cmd.get_after_long_help().is_none());
+}
+
+#[test]
+fn completions_write_includes_profiles_flows_and_subcommands() {
+    let mut buffer = Vec::new();
+    cli_docs_gen().completions_write(Shell::Bash, &mut buffer);
+    let completions = String::from_utf8(buffer).expect("Expected completions to be UTF-8.");
+
+    assert!(completions.contains("dev prod"), "{completions}");
+    assert!(completions.contains("test_flow"), "{completions}");
+    assert!(completions.contains("ensure-dry"), "{completions}");
+}
+
+#[test]
+fn man_page_write_includes_flows_and_item_ids() -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    cli_docs_gen().man_page_write(&mut buffer)?;
+    let man_page = String::from_utf8(buffer)?;
+
+    assert!(man_page.contains("test_app"), "{man_page}");
+    assert!(man_page.contains("test_flow: vec_copy, mock"), "{man_page}");
+    Ok(())
+}
+
+#[test]
+fn generate_to_writes_completions_and_man_pages() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let cli_docs_gen = cli_docs_gen();
+
+    let completions_path = cli_docs_gen.completions_generate_to(Shell::Zsh, tempdir.path())?;
+    cli_docs_gen.man_pages_generate_to(tempdir.path())?;
+
+    assert_eq!(tempdir.path().join("_test_app"), completions_path);
+    assert!(completions_path.exists());
+    assert!(tempdir.path().join("test_app.1").exists());
+    assert!(tempdir.path().join("test_app-ensure.1").exists());
+    Ok(())
+}
+
+fn cli_docs_gen() -> CliDocsGen {
+    let flow = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        Flow::new(flow_id!("test_flow"), graph_builder.build())
+    };
+
+    CliDocsGen::new::<TestArgs>()
+        .with_profiles([profile!("dev"), profile!("prod")])
+        .with_flows(&[flow])
+}
+
+#[test]
+fn debug() {
+    let debug_str = format!("{:?}", CliDocsGen::new::<TestArgs>());
+
+    assert!(debug_str.starts_with("CliDocsGen {"), "{debug_str}");
+}
@@ -0,0 +1,205 @@
+use peace::{
+    cfg::{flow_id, profile},
+    cli::output::CliColorizeOpt,
+    cli_args::{
+        clap::{self, Parser},
+        CmdArgs,
+    },
+    cli_model::OutputFormat,
+    rt_model::{Error, Flow, ItemGraphBuilder},
+};
+
+use crate::PeaceTestError;
+
+#[derive(Debug, Parser)]
+struct TestArgs {
+    #[command(flatten)]
+    cmd_args: CmdArgs,
+}
+
+#[test]
+fn parses_profile_flow_format_and_color() -> Result<(), clap::Error> {
+    let TestArgs { cmd_args } = TestArgs::try_parse_from([
+        "test",
+        "--profile",
+        "test_profile",
+        "--flow",
+        "test_flow",
+        "--format",
+        "json",
+        "--color",
+        "never",
+    ])?;
+
+    assert_eq!(
+        CmdArgs {
+            profile: Some(profile!("test_profile")),
+            flow: Some(flow_id!("test_flow")),
+            format: Some(OutputFormat::Json),
+            color: CliColorizeOpt::Never,
+        },
+        cmd_args
+    );
+    Ok(())
+}
+
+#[test]
+fn parses_defaults_when_no_args_passed() -> Result<(), clap::Error> {
+    let TestArgs { cmd_args } = TestArgs::try_parse_from(["test"])?;
+
+    assert_eq!(CmdArgs::default(), cmd_args);
+    Ok(())
+}
+
+#[test]
+fn returns_error_when_profile_invalid() {
+    let error = TestArgs::try_parse_from(["test", "--profile", "invalid profile"])
+        .expect_err("Expected `invalid profile` to fail to parse.");
+
+    assert_eq!(clap::error::ErrorKind::ValueValidation, error.kind());
+}
+
+#[test]
+fn returns_error_when_flow_id_invalid() {
+    let error = TestArgs::try_parse_from(["test", "--flow", "invalid-flow"])
+        .expect_err("Expected `invalid-flow` to fail to parse.");
+
+    assert_eq!(clap::error::ErrorKind::ValueValidation, error.kind());
+}
+
+#[test]
+fn profile_or_returns_profile_when_passed_in() {
+    let cmd_args = CmdArgs {
+        profile: Some(profile!("test_profile")),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        profile!("test_profile"),
+        cmd_args.profile_or(profile!("default"))
+    );
+}
+
+#[test]
+fn profile_or_returns_default_when_not_passed_in() {
+    let cmd_args = CmdArgs::default();
+
+    assert_eq!(
+        profile!("default"),
+        cmd_args.profile_or(profile!("default"))
+    );
+}
+
+#[test]
+fn flow_select_returns_flow_matching_flow_id() -> Result<(), Box<dyn std::error::Error>> {
+    let flows = flows();
+    let cmd_args = CmdArgs {
+        flow: Some(flow_id!("flow_b")),
+        ..Default::default()
+    };
+
+    let flow = cmd_args.flow_select(&flows)?;
+
+    assert_eq!(&flow_id!("flow_b"), flow.flow_id());
+    Ok(())
+}
+
+#[test]
+fn flow_select_returns_only_flow_when_flow_id_not_passed_in(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let flows = vec![Flow::new(
+        flow_id!("flow_a"),
+        ItemGraphBuilder::<PeaceTestError>::new().build(),
+    )];
+    let cmd_args = CmdArgs::default();
+
+    let flow = cmd_args.flow_select(&flows)?;
+
+    assert_eq!(&flow_id!("flow_a"), flow.flow_id());
+    Ok(())
+}
+
+#[test]
+fn flow_select_returns_error_when_flow_id_not_found() {
+    let flows = flows();
+    let cmd_args = CmdArgs {
+        flow: Some(flow_id!("flow_c")),
+        ..Default::default()
+    };
+
+    let error = cmd_args.flow_select(&flows).unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            Error::FlowIdNotFound { flow_id, flow_ids }
+            if flow_id == &flow_id!("flow_c")
+            && flow_ids == &[flow_id!("flow_a"), flow_id!("flow_b")]
+        ),
+        "Expected `error` to be `Error::FlowIdNotFound {{ .. }}`, but was {error:?}"
+    );
+}
+
+#[test]
+fn flow_select_returns_error_when_flow_id_not_specified_and_multiple_flows() {
+    let flows = flows();
+    let cmd_args = CmdArgs::default();
+
+    let error = cmd_args.flow_select(&flows).unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            Error::FlowIdNotSpecified { flow_ids }
+            if flow_ids == &[flow_id!("flow_a"), flow_id!("flow_b")]
+        ),
+        "Expected `error` to be `Error::FlowIdNotSpecified {{ .. }}`, but was {error:?}"
+    );
+}
+
+#[test]
+fn cli_output_builder_uses_format_and_color() {
+    let cmd_args = CmdArgs {
+        format: Some(OutputFormat::Yaml),
+        color: CliColorizeOpt::Always,
+        ..Default::default()
+    };
+
+    let builder = cmd_args.cli_output_builder();
+
+    assert_eq!(OutputFormat::Yaml, builder.outcome_format());
+    assert_eq!(CliColorizeOpt::Always, builder.colorize());
+}
+
+#[test]
+fn clone() {
+    let cmd_args = CmdArgs {
+        profile: Some(profile!("test_profile")),
+        ..Default::default()
+    };
+
+    assert_eq!(cmd_args, Clone::clone(&cmd_args));
+}
+
+#[test]
+fn debug() {
+    let cmd_args = CmdArgs::default();
+
+    assert_eq!(
+        "CmdArgs { profile: None, flow: None, format: None, color: Auto }",
+        format!("{cmd_args:?}")
+    );
+}
+
+fn flows() -> Vec<Flow<PeaceTestError>> {
+    vec![
+        Flow::new(
+            flow_id!("flow_a"),
+            ItemGraphBuilder::<PeaceTestError>::new().build(),
+        ),
+        Flow::new(
+            flow_id!("flow_b"),
+            ItemGraphBuilder::<PeaceTestError>::new().build(),
+        ),
+    ]
+}
@@ -0,0 +1,182 @@
+use peace::{
+    cfg::{app_name, profile, FlowId},
+    cli_args::{
+        clap::{self, Parser},
+        FlowCmd,
+    },
+    cmd::ctx::CmdCtx,
+    cmd_model::CmdOutcome,
+    rt::cmds::{ApplySummary, StatesCurrentReadCmd},
+    rt_model::{Flow, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+
+use crate::{
+    FnInvocation, FnTrackerOutput, NoOpOutput, PeaceTestError, VecA, VecCopyItem, VecCopyState,
+};
+
+#[derive(Debug, Parser)]
+struct TestArgs {
+    #[command(subcommand)]
+    command: FlowCmd,
+}
+
+#[test]
+fn parses_subcommands() -> Result<(), clap::Error> {
+    [
+        ("discover", FlowCmd::Discover),
+        ("status", FlowCmd::Status),
+        ("goal", FlowCmd::Goal),
+        ("diff", FlowCmd::Diff),
+        ("ensure-dry", FlowCmd::EnsureDry),
+        ("ensure", FlowCmd::Ensure),
+        ("clean-dry", FlowCmd::CleanDry),
+        ("clean", FlowCmd::Clean),
+    ]
+    .into_iter()
+    .try_for_each(|(arg, flow_cmd_expected)| {
+        let TestArgs { command } = TestArgs::try_parse_from(["test", arg])?;
+        assert_eq!(flow_cmd_expected, command);
+        Ok(())
+    })
+}
+
+#[tokio::test]
+async fn run_ensure_presents_states_ensured() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+
+    // Discover current and goal states.
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .await?;
+    let cmd_outcome = FlowCmd::Discover.run(&mut cmd_ctx).await?;
+    assert!(cmd_outcome.is_complete());
+
+    // Ensure states.
+    let mut fn_tracker_output = FnTrackerOutput::new();
+    let mut cmd_ctx =
+        CmdCtx::builder_single_profile_single_flow(&mut fn_tracker_output, &workspace)
+            .with_profile(profile!("test_profile"))
+            .with_flow(&flow)
+            .await?;
+    let cmd_outcome = FlowCmd::Ensure.run(&mut cmd_ctx).await?;
+    assert!(cmd_outcome.is_complete());
+
+    // Re-read states from disk.
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .await?;
+    let CmdOutcome::Complete {
+        value: states_current_stored,
+        cmd_blocks_processed: _,
+    } = StatesCurrentReadCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesCurrentReadCmd::exec` to complete successfully.");
+    };
+
+    assert_eq!(
+        Some(VecCopyState::from(vec![0u8, 1, 2, 3, 4, 5, 6, 7])).as_ref(),
+        states_current_stored.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+    let fn_invocations = fn_tracker_output.fn_invocations();
+    assert_eq!(2, fn_invocations.len());
+    assert_eq!(
+        &FnInvocation::new(
+            "present",
+            vec![Some(serde_yaml::to_string(&*states_current_stored)?)],
+        ),
+        &fn_invocations[0]
+    );
+    let apply_summary = ApplySummary::new(
+        vec![VecCopyItem::ID_DEFAULT.clone()],
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    );
+    assert_eq!(
+        &FnInvocation::new(
+            "present",
+            vec![Some(serde_yaml::to_string(&apply_summary)?)],
+        ),
+        &fn_invocations[1]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn run_status_presents_states_current_stored() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+
+    // Discover current and goal states.
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .await?;
+    FlowCmd::Discover.run(&mut cmd_ctx).await?;
+
+    let mut fn_tracker_output = FnTrackerOutput::new();
+    let mut cmd_ctx =
+        CmdCtx::builder_single_profile_single_flow(&mut fn_tracker_output, &workspace)
+            .with_profile(profile!("test_profile"))
+            .with_flow(&flow)
+            .await?;
+    let cmd_outcome = FlowCmd::Status.run(&mut cmd_ctx).await?;
+    assert!(cmd_outcome.is_complete());
+
+    // Re-read states from disk.
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .await?;
+    let CmdOutcome::Complete {
+        value: states_current_stored,
+        cmd_blocks_processed: _,
+    } = StatesCurrentReadCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesCurrentReadCmd::exec` to complete successfully.");
+    };
+
+    assert_eq!(
+        vec![FnInvocation::new(
+            "present",
+            vec![Some(serde_yaml::to_string(&*states_current_stored)?)],
+        )],
+        fn_tracker_output.fn_invocations()
+    );
+
+    Ok(())
+}
@@ -1 +1,2 @@
 mod ctx;
+mod scopes;
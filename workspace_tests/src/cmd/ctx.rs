@@ -1,2 +1,3 @@
 mod cmd_ctx;
 mod cmd_ctx_builder;
+mod interrupt_handle;
@@ -1,6 +1,7 @@
 use peace::{
     cfg::{app_name, profile},
     cmd::ctx::CmdCtx,
+    rt_model::params::WorkspaceParamsRegistry,
 };
 
 use crate::{no_op_output::NoOpOutput, test_support::workspace, PeaceTestError};
@@ -48,3 +49,106 @@ async fn build_with_workspace_params() -> Result<(), Box<dyn std::error::Error>>
     );
     Ok(())
 }
+
+#[tokio::test]
+async fn build_with_workspace_param_fn() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_no_profile_no_flow"))?;
+
+    let mut output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_no_profile_no_flow::<PeaceTestError, _>(&mut output, &workspace)
+        .with_workspace_param_value(String::from("ws_param_1"), Some("provided".to_string()))
+        .with_workspace_param_fn(String::from("ws_param_1"), || async {
+            Ok::<_, std::io::Error>("computed".to_string())
+        })
+        .with_workspace_param_fn(String::from("ws_param_2"), || async {
+            Ok::<_, std::io::Error>(2u32)
+        })
+        .build()
+        .await?;
+
+    let scope = cmd_ctx.scope();
+    let workspace_params = scope.workspace_params();
+    assert_eq!(
+        Some(&"computed".to_string()),
+        workspace_params.get("ws_param_1")
+    );
+    assert_eq!(Some(&2u32), workspace_params.get("ws_param_2"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_workspace_params_registry_migrates_stored_params(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_no_profile_no_flow"))?;
+
+    let mut output = NoOpOutput;
+    CmdCtx::builder_no_profile_no_flow::<PeaceTestError, _>(&mut output, &workspace)
+        .with_workspace_param_value(String::from("ports"), Some(8080u32))
+        .build()
+        .await?;
+
+    let workspace_params_registry = WorkspaceParamsRegistry::<String>::new()
+        .with_migration::<u32, Vec<u32>, _>(String::from("ports"), |port| vec![port]);
+    let mut output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_no_profile_no_flow::<PeaceTestError, _>(&mut output, &workspace)
+        .with_workspace_params_registry(workspace_params_registry)
+        .build()
+        .await?;
+
+    let scope = cmd_ctx.scope();
+    let workspace_params = scope.workspace_params();
+    assert_eq!(Some(&vec![8080u32]), workspace_params.get("ports"));
+
+    // The migrated param is stored, so it is deserialized without the migration.
+    let workspace_params_registry =
+        WorkspaceParamsRegistry::<String>::new().with_param::<Vec<u32>>(String::from("ports"));
+    let mut output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_no_profile_no_flow::<PeaceTestError, _>(&mut output, &workspace)
+        .with_workspace_params_registry(workspace_params_registry)
+        .build()
+        .await?;
+
+    let scope = cmd_ctx.scope();
+    let workspace_params = scope.workspace_params();
+    assert_eq!(Some(&vec![8080u32]), workspace_params.get("ports"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_workspace_param_fn_returns_error_with_key_when_fn_fails(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_no_profile_no_flow"))?;
+
+    let mut output = NoOpOutput;
+    let cmd_ctx_result =
+        CmdCtx::builder_no_profile_no_flow::<PeaceTestError, _>(&mut output, &workspace)
+            .with_workspace_param_fn(String::from("ws_param_1"), || async {
+                Ok::<_, std::io::Error>("computed".to_string())
+            })
+            .with_workspace_param_fn(String::from("ws_param_2"), || async {
+                Err::<String, _>(std::io::Error::other("account lookup failed"))
+            })
+            .build()
+            .await;
+
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    &cmd_ctx_result,
+                    Err(PeaceTestError::PeaceRt(
+                        peace::rt_model::Error::WorkspaceParamFnError { key, error }
+                    ))
+                    if key == "\"ws_param_2\""
+                    && error.to_string() == "account lookup failed"
+                ),
+                "was {cmd_ctx_result:?}"
+            );
+        }
+    })();
+    Ok(())
+}
@@ -2,6 +2,7 @@ use peace::{
     cfg::{app_name, profile},
     cmd::ctx::CmdCtx,
     resources::paths::{ProfileDir, ProfileHistoryDir},
+    rt_model::params::ParamsLayer,
 };
 
 use crate::{no_op_output::NoOpOutput, test_support::workspace, PeaceTestError};
@@ -223,3 +224,101 @@ async fn build_with_workspace_params_with_profile_params_with_profile_from_param
     assert_eq!(Some(&2u64), profile_params.get("profile_param_1"));
     Ok(())
 }
+
+#[tokio::test]
+async fn build_with_profile_param_overlays_uses_highest_precedence_layer(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_no_flow"))?;
+    let profile = profile!("test_profile");
+
+    let mut output = NoOpOutput;
+    let cmd_ctx =
+        CmdCtx::builder_single_profile_no_flow::<PeaceTestError, _>(&mut output, &workspace)
+            .with_profile_param_value(String::from("profile_param_0"), Some(1u32))
+            .with_profile_param_overlay(
+                ParamsLayer::Workspace,
+                String::from("profile_param_0"),
+                10u32,
+            )
+            .with_profile_param_overlay(
+                ParamsLayer::Workspace,
+                String::from("profile_param_1"),
+                20u32,
+            )
+            .with_profile_param_overlay(
+                ParamsLayer::Override,
+                String::from("profile_param_2"),
+                300u32,
+            )
+            .with_profile_param_overlay(ParamsLayer::Profile, String::from("profile_param_2"), 3u32)
+            .with_profile(profile.clone())
+            .build()
+            .await?;
+
+    let scope = cmd_ctx.scope();
+    let profile_params = scope.profile_params();
+    let profile_params_resolution = scope.profile_params_resolution();
+    assert_eq!(Some(&1u32), profile_params.get("profile_param_0"));
+    assert_eq!(Some(&20u32), profile_params.get("profile_param_1"));
+    assert_eq!(Some(&300u32), profile_params.get("profile_param_2"));
+    assert_eq!(
+        Some(ParamsLayer::Profile),
+        profile_params_resolution.layer(&String::from("profile_param_0"))
+    );
+    assert_eq!(
+        Some(ParamsLayer::Workspace),
+        profile_params_resolution.layer(&String::from("profile_param_1"))
+    );
+    assert_eq!(
+        Some(ParamsLayer::Override),
+        profile_params_resolution.layer(&String::from("profile_param_2"))
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_profile_param_overlays_stores_only_profile_layer(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_no_flow"))?;
+    let profile = profile!("test_profile");
+
+    let mut output = NoOpOutput;
+    let _cmd_ctx =
+        CmdCtx::builder_single_profile_no_flow::<PeaceTestError, _>(&mut output, &workspace)
+            .with_profile_param_overlay(
+                ParamsLayer::Workspace,
+                String::from("profile_param_0"),
+                10u32,
+            )
+            .with_profile_param_overlay(ParamsLayer::Profile, String::from("profile_param_1"), 2u32)
+            .with_profile_param_overlay(
+                ParamsLayer::Override,
+                String::from("profile_param_1"),
+                200u32,
+            )
+            .with_profile(profile.clone())
+            .build()
+            .await?;
+
+    let cmd_ctx =
+        CmdCtx::builder_single_profile_no_flow::<PeaceTestError, _>(&mut output, &workspace)
+            .with_profile_params_k::<String>()
+            .with_profile_param::<u32>(String::from("profile_param_0"))
+            .with_profile_param::<u32>(String::from("profile_param_1"))
+            .with_profile(profile.clone())
+            .build()
+            .await?;
+
+    let scope = cmd_ctx.scope();
+    let profile_params = scope.profile_params();
+    let profile_params_resolution = scope.profile_params_resolution();
+    assert_eq!(None, profile_params.get::<u32, _>("profile_param_0"));
+    assert_eq!(Some(&2u32), profile_params.get("profile_param_1"));
+    assert_eq!(
+        Some(ParamsLayer::Profile),
+        profile_params_resolution.layer(&String::from("profile_param_1"))
+    );
+    Ok(())
+}
@@ -1,18 +1,27 @@
 use peace::{
     cfg::{app_name, flow_id, item_id, profile, Item, Profile},
+    cli::output::{CliColorizeOpt, CliOutputBuilder},
     cmd::ctx::CmdCtx,
-    params::{Params, ParamsSpec, ValueResolutionCtx, ValueResolutionMode, ValueSpec},
+    cmd_model::CmdOutcome,
+    params::{
+        Params, ParamsSpec, ParamsValidationError, ValueResolutionCtx, ValueResolutionMode,
+        ValueSpec,
+    },
     resources::{
         paths::{FlowDir, ProfileDir, ProfileHistoryDir},
         type_reg::untagged::BoxDataTypeDowncast,
     },
-    rt_model::{Flow, ItemGraphBuilder},
+    rt::cmds::{EnsureCmd, StatesDiscoverCmd},
+    rt_model::{
+        params::ParamsLayer, Flow, InMemoryOutput, ItemExecutorConcurrent, ItemExecutorYielding,
+        ItemGraphBuilder, ParamsChangePolicy,
+    },
 };
 
 use crate::{
     no_op_output::NoOpOutput,
     test_support::{assert_flow_params, assert_profile_params, assert_workspace_params, workspace},
-    vec_copy_item::{VecA, VecAFieldWise, VecCopyItem},
+    vec_copy_item::{VecA, VecAFieldWise, VecCopyItem, VecCopyState},
     PeaceTestError,
 };
 
@@ -130,6 +139,41 @@ async fn build_with_profile_params() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[tokio::test]
+async fn build_with_profile_param_overlay_inserts_resolved_value_into_resources(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let flow = Flow::<PeaceTestError>::new(flow_id, ItemGraphBuilder::new().build());
+
+    let mut output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile_param_value(String::from("profile_param_0"), Some(1u32))
+        .with_profile_param_overlay(ParamsLayer::Override, String::from("profile_param_0"), 5u32)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .build()
+        .await?;
+
+    let scope = cmd_ctx.scope();
+    assert_eq!(Some(&5u32), scope.profile_params().get("profile_param_0"));
+    assert_eq!(
+        Some(ParamsLayer::Override),
+        scope
+            .profile_params_resolution()
+            .layer(&String::from("profile_param_0"))
+    );
+
+    let resources = cmd_ctx.resources();
+    assert_eq!(
+        Some(5u32),
+        resources.try_borrow::<u32>().ok().map(|value| *value)
+    );
+    Ok(())
+}
+
 #[tokio::test]
 async fn build_with_flow_params() -> Result<(), Box<dyn std::error::Error>> {
     let tempdir = tempfile::tempdir()?;
@@ -437,6 +481,58 @@ async fn build_with_item_params_returns_ok_when_params_provided()
     Ok(())
 }
 
+#[tokio::test]
+async fn build_with_item_params_for_each_sets_params_per_instance(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let instances = [("a", vec![1u8]), ("b", vec![2u8, 3])];
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::new();
+        item_graph_builder.add_fn_for_each(
+            VecCopyItem::ID_DEFAULT,
+            instances.iter().map(|(instance_key, _)| instance_key),
+            VecCopyItem::new,
+        );
+        item_graph_builder.build()
+    };
+    let flow = Flow::<PeaceTestError>::new(flow_id, item_graph);
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params_for_each::<VecCopyItem, _, _>(
+            VecCopyItem::ID_DEFAULT,
+            instances
+                .iter()
+                .map(|(instance_key, values)| (instance_key, VecA(values.clone()).into())),
+        )
+        .build()
+        .await?;
+
+    let CmdOutcome::Complete {
+        value: states_goal,
+        cmd_blocks_processed: _,
+    } = StatesDiscoverCmd::goal(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::goal` to complete successfully.");
+    };
+
+    assert_eq!(
+        Some(&VecCopyState::from(vec![1u8])),
+        states_goal.get::<VecCopyState, _>(&item_id!("vec_copy_a"))
+    );
+    assert_eq!(
+        Some(&VecCopyState::from(vec![2u8, 3])),
+        states_goal.get::<VecCopyState, _>(&item_id!("vec_copy_b"))
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn build_with_item_params_returns_err_when_params_not_provided_and_not_stored()
 -> Result<(), Box<dyn std::error::Error>> {
@@ -1084,3 +1180,634 @@ async fn build_with_item_params_returns_ok_when_new_item_added_with_params_provi
 
     Ok(())
 }
+
+#[tokio::test]
+async fn build_with_states_from_flow_inserts_other_flow_states_current_stored(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+
+    // Store current states for the other flow.
+    let flow_other = {
+        let item_graph = {
+            let mut item_graph_builder = ItemGraphBuilder::new();
+            item_graph_builder.add_fn(VecCopyItem::default().into());
+            item_graph_builder.build()
+        };
+        Flow::<PeaceTestError>::new(flow_id!("test_flow_other"), item_graph)
+    };
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow_other)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2]).into(),
+        )
+        .build()
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    EnsureCmd::exec(&mut cmd_ctx).await?;
+
+    // Build `cmd_ctx` for this flow, loading the other flow's states.
+    let flow =
+        Flow::<PeaceTestError>::new(flow_id!("test_flow_id"), ItemGraphBuilder::new().build());
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_states_from_flow(&flow_other)
+        .build()
+        .await?;
+
+    let resources = cmd_ctx.resources();
+    assert_eq!(
+        Some(&VecCopyState::from(vec![0, 1, 2])),
+        resources.try_borrow::<VecCopyState>().ok().as_deref()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_states_from_flow_skips_other_flow_when_no_states_current_stored(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+
+    let flow_other = {
+        let item_graph = {
+            let mut item_graph_builder = ItemGraphBuilder::new();
+            item_graph_builder.add_fn(VecCopyItem::default().into());
+            item_graph_builder.build()
+        };
+        Flow::<PeaceTestError>::new(flow_id!("test_flow_other"), item_graph)
+    };
+    let flow =
+        Flow::<PeaceTestError>::new(flow_id!("test_flow_id"), ItemGraphBuilder::new().build());
+    let mut output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_states_from_flow(&flow_other)
+        .build()
+        .await?;
+
+    let resources = cmd_ctx.resources();
+    assert!(resources.try_borrow::<VecCopyState>().is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_returns_error_when_item_params_fail_validation(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::new();
+        item_graph_builder.add_fn(VecCopyItem::default().into());
+        item_graph_builder.build()
+    };
+    let flow = Flow::<PeaceTestError>::new(flow_id, item_graph);
+
+    let mut output = NoOpOutput;
+    let cmd_ctx_result = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            ParamsSpec::from(VecA(vec![0, 1, 2])).with_validation(|vec_a| {
+                if vec_a.0.len() > 3 {
+                    Ok(())
+                } else {
+                    Err(ParamsValidationError::field(
+                        "0",
+                        "Must have more than 3 bytes.",
+                    ))
+                }
+            }),
+        )
+        .build()
+        .await;
+
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    &cmd_ctx_result,
+                    Err(PeaceTestError::PeaceRt(
+                        peace::rt_model::Error::ParamsValidation {
+                            item_params_validation_errors,
+                        }
+                    ))
+                    if item_params_validation_errors.get(VecCopyItem::ID_DEFAULT)
+                        == Some(&vec![ParamsValidationError::field(
+                            "0",
+                            "Must have more than 3 bytes."
+                        )])
+                ),
+                "was {cmd_ctx_result:#?}"
+            );
+        }
+    })();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_profile_params_file() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let flow = Flow::<PeaceTestError>::new(flow_id, ItemGraphBuilder::new().build());
+    let profile_params_file = tempdir.path().join("profile_params.yaml");
+    tokio::fs::write(
+        &profile_params_file,
+        "profile_param_0: 1\n\
+        profile_param_1: 2\n",
+    )
+    .await?;
+
+    let mut output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile_params_k::<String>()
+        .with_profile_param::<u32>(String::from("profile_param_0"))
+        .with_profile_param::<u64>(String::from("profile_param_1"))
+        .with_profile_params_file(&profile_params_file)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .build()
+        .await?;
+
+    let resources = cmd_ctx.resources();
+    assert_profile_params(resources).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_flow_params_file_json() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let flow = Flow::<PeaceTestError>::new(flow_id, ItemGraphBuilder::new().build());
+    let flow_params_file = tempdir.path().join("flow_params.json");
+    tokio::fs::write(
+        &flow_params_file,
+        r#"{ "flow_param_0": true, "flow_param_1": 456 }"#,
+    )
+    .await?;
+
+    let mut output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_flow_params_k::<String>()
+        .with_flow_param::<bool>(String::from("flow_param_0"))
+        .with_flow_param::<u16>(String::from("flow_param_1"))
+        .with_flow_params_file(&flow_params_file)
+        .build()
+        .await?;
+
+    let flow_params = cmd_ctx.scope().flow_params();
+    assert_eq!(Some(true), flow_params.get("flow_param_0").copied());
+    assert_eq!(Some(&456u16), flow_params.get("flow_param_1"));
+
+    let resources = cmd_ctx.resources();
+    assert_flow_params(resources).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_flow_params_file_uses_flow_param_values_provided_over_file_values(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let flow = Flow::<PeaceTestError>::new(flow_id, ItemGraphBuilder::new().build());
+    let flow_params_file = tempdir.path().join("flow_params.yaml");
+    tokio::fs::write(
+        &flow_params_file,
+        "flow_param_0: false\n\
+        flow_param_1: 456\n",
+    )
+    .await?;
+
+    let mut output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_flow_param_value(String::from("flow_param_0"), Some(true))
+        .with_flow_param::<u16>(String::from("flow_param_1"))
+        .with_flow_params_file(&flow_params_file)
+        .build()
+        .await?;
+
+    let resources = cmd_ctx.resources();
+    assert_flow_params(resources).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_flow_params_file_returns_error_when_key_not_registered(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let flow = Flow::<PeaceTestError>::new(flow_id, ItemGraphBuilder::new().build());
+    let flow_params_file = tempdir.path().join("flow_params.yaml");
+    tokio::fs::write(
+        &flow_params_file,
+        "flow_param_0: true\n\
+        flow_param_unknown: 123\n",
+    )
+    .await?;
+
+    let mut output = NoOpOutput;
+    let cmd_ctx_result = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_flow_param_value(String::from("flow_param_0"), Some(true))
+        .with_flow_params_file(&flow_params_file)
+        .build()
+        .await;
+
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    &cmd_ctx_result,
+                    Err(PeaceTestError::PeaceRt(
+                        peace::rt_model::Error::ParamsFileKeysUnknown {
+                            params_file_path,
+                            keys,
+                            ..
+                        }
+                    ))
+                    if params_file_path == &flow_params_file
+                        && keys == &[String::from("flow_param_unknown")]
+                ),
+                "was {cmd_ctx_result:#?}"
+            );
+        }
+    })();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_flow_params_file_returns_error_when_value_invalid(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let flow = Flow::<PeaceTestError>::new(flow_id, ItemGraphBuilder::new().build());
+    let flow_params_file = tempdir.path().join("flow_params.yaml");
+    tokio::fs::write(&flow_params_file, "flow_param_1: not_a_number\n").await?;
+
+    let mut output = NoOpOutput;
+    let cmd_ctx_result = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_flow_params_k::<String>()
+        .with_flow_param::<u16>(String::from("flow_param_1"))
+        .with_flow_params_file(&flow_params_file)
+        .build()
+        .await;
+
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    &cmd_ctx_result,
+                    Err(PeaceTestError::PeaceRt(
+                        peace::rt_model::Error::ParamsFileDeserialize {
+                            params_file_path,
+                            ..
+                        }
+                    ))
+                    if params_file_path == &flow_params_file
+                ),
+                "was {cmd_ctx_result:#?}"
+            );
+        }
+    })();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_warns_when_item_params_changed_since_states_stored(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::new();
+        item_graph_builder.add_fn(VecCopyItem::default().into());
+        item_graph_builder.build()
+    };
+    let flow = Flow::<PeaceTestError>::new(flow_id, item_graph);
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![1]).into())
+        .build()
+        .await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    let mut buffer = Vec::new();
+    let mut output = CliOutputBuilder::new_with_writer(&mut buffer)
+        .with_colorize(CliColorizeOpt::Never)
+        .build();
+    CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![2]).into())
+        .build()
+        .await?;
+
+    let output = String::from_utf8(buffer)?;
+    assert!(
+        output.contains("params changed") && output.contains("vec_copy"),
+        "Expected params changed warning for `vec_copy`, but output was:\n{output}"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_does_not_warn_when_item_params_unchanged() -> Result<(), Box<dyn std::error::Error>>
+{
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::new();
+        item_graph_builder.add_fn(VecCopyItem::default().into());
+        item_graph_builder.build()
+    };
+    let flow = Flow::<PeaceTestError>::new(flow_id, item_graph);
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![1]).into())
+        .build()
+        .await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    let mut output = InMemoryOutput::new();
+    CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![1]).into())
+        .build()
+        .await?;
+
+    assert!(output.events().is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_returns_error_when_item_params_changed_and_policy_is_fail(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::new();
+        item_graph_builder.add_fn(VecCopyItem::default().into());
+        item_graph_builder.build()
+    };
+    let flow = Flow::<PeaceTestError>::new(flow_id, item_graph);
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![1]).into())
+        .build()
+        .await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    let mut output = NoOpOutput;
+    let cmd_ctx_result = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_params_change_policy(ParamsChangePolicy::Fail)
+        .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![2]).into())
+        .build()
+        .await;
+
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    &cmd_ctx_result,
+                    Err(PeaceTestError::PeaceRt(
+                        peace::rt_model::Error::ParamsChanged { item_ids }
+                    ))
+                    if item_ids == &[VecCopyItem::ID_DEFAULT.clone()]
+                ),
+                "was {cmd_ctx_result:#?}"
+            );
+        }
+    })();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_params_at_loads_profile_and_flow_params_from_revision(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let flow = Flow::<PeaceTestError>::new(flow_id, ItemGraphBuilder::new().build());
+
+    for (profile_param_0, flow_param_0) in [(1u32, true), (2u32, false)] {
+        let mut output = NoOpOutput;
+        CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+            .with_profile(profile.clone())
+            .with_flow(&flow)
+            .with_profile_params_k::<String>()
+            .with_profile_param_value(String::from("profile_param_0"), Some(profile_param_0))
+            .with_flow_params_k::<String>()
+            .with_flow_param_value(String::from("flow_param_0"), Some(flow_param_0))
+            .build()
+            .await?;
+    }
+
+    let mut output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_profile_params_k::<String>()
+        .with_profile_param::<u32>(String::from("profile_param_0"))
+        .with_flow_params_k::<String>()
+        .with_flow_param::<bool>(String::from("flow_param_0"))
+        .with_params_at(0)
+        .build()
+        .await?;
+
+    assert_eq!(
+        Some(&1u32),
+        cmd_ctx.scope().profile_params().get("profile_param_0")
+    );
+    assert_eq!(
+        Some(&true),
+        cmd_ctx.scope().flow_params().get("flow_param_0")
+    );
+
+    // The current params are not overwritten by the revision's params.
+    let mut output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_profile_params_k::<String>()
+        .with_profile_param::<u32>(String::from("profile_param_0"))
+        .with_flow_params_k::<String>()
+        .with_flow_param::<bool>(String::from("flow_param_0"))
+        .build()
+        .await?;
+
+    assert_eq!(
+        Some(&2u32),
+        cmd_ctx.scope().profile_params().get("profile_param_0")
+    );
+    assert_eq!(
+        Some(&false),
+        cmd_ctx.scope().flow_params().get("flow_param_0")
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_params_at_returns_error_when_revision_not_found(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let flow = Flow::<PeaceTestError>::new(flow_id, ItemGraphBuilder::new().build());
+
+    let mut output = NoOpOutput;
+    let cmd_ctx_result = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_params_at(3)
+        .build()
+        .await;
+
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    &cmd_ctx_result,
+                    Err(PeaceTestError::PeaceRt(
+                        peace::rt_model::Error::ParamsRevisionNotFound { revision: 3 }
+                    ))
+                ),
+                "was {cmd_ctx_result:#?}"
+            );
+        }
+    })();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_max_concurrency_limits_item_executor() -> Result<(), Box<dyn std::error::Error>>
+{
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let flow = Flow::<PeaceTestError>::new(flow_id, ItemGraphBuilder::new().build());
+
+    let mut output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .build()
+        .await?;
+    assert_eq!(
+        ItemExecutorConcurrent::CONCURRENCY_MAX_DEFAULT,
+        cmd_ctx.scope().item_executor().concurrency_max()
+    );
+
+    // The limit applies regardless of when the item executor is set.
+    let mut output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_max_concurrency(1)
+        .with_item_executor(ItemExecutorYielding::new(8))
+        .build()
+        .await?;
+    assert_eq!(1, cmd_ctx.scope().item_executor().concurrency_max());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_max_concurrency_zero_runs_items_serially(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let flow = Flow::<PeaceTestError>::new(flow_id, {
+        let mut graph_builder = ItemGraphBuilder::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    });
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_max_concurrency(0)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2]).into(),
+        )
+        .build()
+        .await?;
+    assert_eq!(1, cmd_ctx.scope().item_executor().concurrency_max());
+
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    let CmdOutcome::Complete {
+        value: states_ensured,
+        cmd_blocks_processed: _,
+    } = EnsureCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec` to complete successfully.");
+    };
+    assert_eq!(
+        Some(&VecCopyState::from(vec![0, 1, 2])),
+        states_ensured.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+
+    Ok(())
+}
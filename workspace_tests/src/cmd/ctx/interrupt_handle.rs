@@ -0,0 +1,149 @@
+use peace::{
+    cfg::{app_name, profile, FlowId},
+    cmd::{
+        ctx::{CmdCtx, InterruptHandle},
+        interruptible::InterruptStrategy,
+    },
+    cmd_model::CmdOutcome,
+    rt::cmds::{EnsureCmd, StatesDiscoverCmd},
+    rt_model::{Flow, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+
+use crate::{
+    mock_item::{MockItem, MockSrc},
+    NoOpOutput, PeaceTestError, VecA, VecCopyItem,
+};
+
+#[test]
+fn interrupt_returns_true_when_interrupt_already_pending() {
+    let (interrupt_handle, _interruptibility) =
+        InterruptHandle::new(InterruptStrategy::FinishCurrent);
+
+    assert!(interrupt_handle.interrupt());
+    assert!(interrupt_handle.interrupt());
+    assert!(!interrupt_handle.is_closed());
+}
+
+#[test]
+fn interrupt_returns_false_when_interruptibility_dropped() {
+    let (interrupt_handle, interruptibility) =
+        InterruptHandle::new(InterruptStrategy::FinishCurrent);
+    drop(interruptibility);
+
+    assert!(!interrupt_handle.interrupt());
+    assert!(interrupt_handle.is_closed());
+}
+
+#[tokio::test]
+async fn cmd_ctx_interrupt_handle_is_none_when_not_set() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let flow = flow()?;
+    let mut output = NoOpOutput;
+
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+
+    assert!(cmd_ctx.interrupt_handle().is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn cmd_execution_is_interrupted_through_cmd_ctx_interrupt_handle()
+-> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let flow = flow()?;
+    let mut output = NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_interrupt_handle(InterruptStrategy::FinishCurrent)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let interrupt_handle = cmd_ctx
+        .interrupt_handle()
+        .cloned()
+        .expect("Expected `interrupt_handle` to be set.");
+    let interrupt_task = tokio::spawn(async move { interrupt_handle.interrupt() });
+    assert!(interrupt_task.await?);
+
+    let cmd_outcome = EnsureCmd::exec(&mut cmd_ctx).await?;
+
+    assert!(
+        matches!(
+            cmd_outcome,
+            CmdOutcome::ExecutionInterrupted {
+                deadline_exceeded: false,
+                ..
+            }
+        ),
+        "Expected `EnsureCmd::exec` to be interrupted, but was: {cmd_outcome:#?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn interrupt_returns_false_when_cmd_ctx_dropped() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let flow = flow()?;
+    let mut output = NoOpOutput;
+
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_interrupt_handle(InterruptStrategy::FinishCurrent)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    let interrupt_handle = cmd_ctx
+        .interrupt_handle()
+        .cloned()
+        .expect("Expected `interrupt_handle` to be set.");
+    drop(cmd_ctx);
+
+    assert!(!interrupt_handle.interrupt());
+
+    Ok(())
+}
+
+fn flow() -> Result<Flow<PeaceTestError>, Box<dyn std::error::Error>> {
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+
+    Ok(flow)
+}
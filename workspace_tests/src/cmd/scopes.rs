@@ -0,0 +1 @@
+mod single_profile_single_flow;
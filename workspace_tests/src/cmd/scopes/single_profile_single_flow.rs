@@ -0,0 +1,108 @@
+use peace::{
+    cfg::{app_name, flow_id, profile, Item},
+    cmd::ctx::CmdCtx,
+    params::ParamsSpec,
+    resources::states::StatesCurrentStored,
+    rt::cmds::StatesDiscoverCmd,
+    rt_model::{Flow, ItemGraphBuilder},
+};
+
+use crate::{
+    no_op_output::NoOpOutput,
+    test_support::workspace,
+    vec_copy_item::{VecA, VecCopyItem, VecCopyState},
+    PeaceTestError,
+};
+
+#[tokio::test]
+async fn refresh_reads_params_specs_stored_by_another_cmd_ctx(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::new();
+        item_graph_builder.add_fn(VecCopyItem::default().into());
+        item_graph_builder.build()
+    };
+    let flow = Flow::<PeaceTestError>::new(flow_id!("test_flow_id"), item_graph);
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![1u8]).into())
+        .build()
+        .await?;
+
+    // Store different params specs from another `CmdCtx`.
+    let mut output_other = NoOpOutput;
+    let _cmd_ctx_other = CmdCtx::builder_single_profile_single_flow(&mut output_other, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![2u8]).into())
+        .build()
+        .await?;
+
+    cmd_ctx.scope_mut().refresh().await?;
+
+    let vec_a_spec = cmd_ctx
+        .params_specs()
+        .get::<ParamsSpec<<VecCopyItem as Item>::Params<'_>>, _>(VecCopyItem::ID_DEFAULT);
+    assert!(matches!(vec_a_spec,
+        Some(ParamsSpec::Value { value: VecA(value) })
+        if value == &[2u8]
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn refresh_reads_states_current_stored_by_another_cmd_ctx(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::new();
+        item_graph_builder.add_fn(VecCopyItem::default().into());
+        item_graph_builder.build()
+    };
+    let flow = Flow::<PeaceTestError>::new(flow_id!("test_flow_id"), item_graph);
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![1u8]).into())
+        .build()
+        .await?;
+    assert!(cmd_ctx
+        .resources()
+        .try_borrow::<StatesCurrentStored>()
+        .is_err());
+
+    // Discover and store current states from another `CmdCtx`.
+    let mut output_other = NoOpOutput;
+    let mut cmd_ctx_other =
+        CmdCtx::builder_single_profile_single_flow(&mut output_other, &workspace)
+            .with_profile(profile.clone())
+            .with_flow(&flow)
+            .build()
+            .await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx_other).await?;
+
+    cmd_ctx.scope_mut().refresh().await?;
+
+    let resources = cmd_ctx.resources();
+    let states_current_stored = resources.try_borrow::<StatesCurrentStored>().ok();
+    assert_eq!(
+        Some(&VecCopyState::new()),
+        states_current_stored
+            .as_deref()
+            .and_then(|states_current_stored| states_current_stored
+                .get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT))
+    );
+
+    Ok(())
+}
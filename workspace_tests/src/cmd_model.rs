@@ -1,3 +1,5 @@
 mod cmd_block_outcome;
 mod cmd_outcome;
 mod item_stream_outcome;
+#[cfg(feature = "profiling")]
+mod timing_breakdown;
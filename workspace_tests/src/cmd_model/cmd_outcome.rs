@@ -35,6 +35,29 @@ fn is_interrupted() {
     assert!(!cmd_outcome_item_error(123).is_interrupted());
 }
 
+#[test]
+fn is_deadline_exceeded() {
+    let block_interrupted = CmdOutcome::<u32, String>::BlockInterrupted {
+        item_stream_outcome: ItemStreamOutcome::finished_with(123, Vec::new()),
+        cmd_blocks_processed: vec![],
+        cmd_blocks_not_processed: vec![],
+        deadline_exceeded: true,
+    };
+    let execution_interrupted = CmdOutcome::<u32, String>::ExecutionInterrupted {
+        value: Some(123),
+        cmd_blocks_processed: vec![],
+        cmd_blocks_not_processed: vec![],
+        deadline_exceeded: true,
+    };
+
+    assert!(!cmd_outcome_complete(123).is_deadline_exceeded());
+    assert!(!cmd_outcome_block_interrupted(123).is_deadline_exceeded());
+    assert!(!cmd_outcome_execution_interrupted(Some(123)).is_deadline_exceeded());
+    assert!(!cmd_outcome_item_error(123).is_deadline_exceeded());
+    assert!(block_interrupted.is_deadline_exceeded());
+    assert!(execution_interrupted.is_deadline_exceeded());
+}
+
 #[test]
 fn is_err() {
     assert!(!cmd_outcome_complete(123).is_err());
@@ -170,6 +193,7 @@ fn cmd_outcome_block_interrupted<T>(value: T) -> CmdOutcome<T, String> {
         item_stream_outcome: ItemStreamOutcome::finished_with(value, Vec::new()),
         cmd_blocks_processed: vec![],
         cmd_blocks_not_processed: vec![],
+        deadline_exceeded: false,
     }
 }
 
@@ -178,6 +202,7 @@ fn cmd_outcome_execution_interrupted<T>(value: Option<T>) -> CmdOutcome<T, Strin
         value,
         cmd_blocks_processed: vec![],
         cmd_blocks_not_processed: vec![],
+        deadline_exceeded: false,
     }
 }
 
@@ -187,5 +212,6 @@ fn cmd_outcome_item_error<T>(value: T) -> CmdOutcome<T, String> {
         cmd_blocks_processed: vec![],
         cmd_blocks_not_processed: vec![],
         errors: IndexMap::new(),
+        item_log_paths: IndexMap::new(),
     }
 }
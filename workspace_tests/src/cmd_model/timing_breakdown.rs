@@ -0,0 +1,156 @@
+use std::time::Duration;
+
+use peace::{
+    cfg::item_id,
+    cli::output::{CliColorizeOpt, CliMdPresenter, CliOutputBuilder},
+    cmd_model::{CmdBlockDesc, CmdOutcome, ItemFnKind, ItemFnTiming, TimingBreakdown},
+    fmt::Presentable,
+};
+
+#[test]
+fn record_sums_calls_to_the_same_item_fn() {
+    let mut timing_breakdown = TimingBreakdown::new();
+    timing_breakdown.record(&item_id!("a"), ItemFnKind::StateCurrent, ms(100));
+    timing_breakdown.record(&item_id!("a"), ItemFnKind::StateGoal, ms(50));
+    timing_breakdown.record(&item_id!("a"), ItemFnKind::StateCurrent, ms(200));
+
+    assert_eq!(
+        vec![
+            ItemFnTiming {
+                item_id: item_id!("a"),
+                item_fn_kind: ItemFnKind::StateCurrent,
+                duration: ms(300),
+                calls: 2,
+            },
+            ItemFnTiming::new(item_id!("a"), ItemFnKind::StateGoal, ms(50)),
+        ],
+        timing_breakdown.into_inner()
+    );
+}
+
+#[test]
+fn merge_sums_timings_of_the_same_item_fn() {
+    let mut timing_breakdown = TimingBreakdown::new();
+    timing_breakdown.record(&item_id!("a"), ItemFnKind::StateCurrent, ms(100));
+    let mut timing_breakdown_other = TimingBreakdown::new();
+    timing_breakdown_other.record(&item_id!("a"), ItemFnKind::StateCurrent, ms(100));
+    timing_breakdown_other.record(&item_id!("b"), ItemFnKind::ApplyExec, ms(400));
+
+    timing_breakdown.merge(&timing_breakdown_other);
+
+    let item_fn_timing = timing_breakdown
+        .get(&item_id!("a"), ItemFnKind::StateCurrent)
+        .expect("Expected `a` `state_current` timing to exist.");
+    assert_eq!(ms(200), item_fn_timing.duration);
+    assert_eq!(2, item_fn_timing.calls);
+    assert_eq!(ms(600), timing_breakdown.duration_total());
+    assert_eq!(ms(400), timing_breakdown.item_duration(&item_id!("b")));
+}
+
+#[test]
+fn slowest_returns_longest_item_fns_first() {
+    let timing_breakdown = timing_breakdown();
+
+    let slowest = timing_breakdown
+        .slowest(2)
+        .into_iter()
+        .map(|item_fn_timing| (item_fn_timing.item_id.clone(), item_fn_timing.item_fn_kind))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        vec![
+            (item_id!("b"), ItemFnKind::ApplyExec),
+            (item_id!("a"), ItemFnKind::StateCurrent),
+        ],
+        slowest
+    );
+}
+
+#[test]
+fn cmd_outcome_timing_breakdown_merges_cmd_blocks() {
+    let mut timing_breakdown_discover = TimingBreakdown::new();
+    timing_breakdown_discover.record(&item_id!("a"), ItemFnKind::StateCurrent, ms(100));
+    let mut timing_breakdown_apply = TimingBreakdown::new();
+    timing_breakdown_apply.record(&item_id!("a"), ItemFnKind::StateCurrent, ms(100));
+    timing_breakdown_apply.record(&item_id!("a"), ItemFnKind::ApplyExec, ms(300));
+
+    let cmd_outcome = CmdOutcome::<(), ()>::Complete {
+        value: (),
+        cmd_blocks_processed: vec![
+            cmd_block_desc("StatesDiscoverCmdBlock")
+                .with_timing_breakdown(timing_breakdown_discover),
+            cmd_block_desc("ApplyExecCmdBlock").with_timing_breakdown(timing_breakdown_apply),
+        ],
+    };
+    let timing_breakdown = cmd_outcome.timing_breakdown();
+
+    let item_fn_timing = timing_breakdown
+        .get(&item_id!("a"), ItemFnKind::StateCurrent)
+        .expect("Expected `a` `state_current` timing to exist.");
+    assert_eq!(ms(200), item_fn_timing.duration);
+    assert_eq!(2, item_fn_timing.calls);
+    assert_eq!(ms(500), timing_breakdown.duration_total());
+}
+
+#[tokio::test]
+async fn present_renders_slowest_item_fns() -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut cli_output = CliOutputBuilder::new_with_writer(&mut buffer)
+        .with_colorize(CliColorizeOpt::Never)
+        .build();
+    let mut presenter = CliMdPresenter::new(&mut cli_output);
+
+    timing_breakdown().present(&mut presenter).await?;
+
+    let output = String::from_utf8(buffer)?;
+    assert_eq!(
+        "\
+        # Timing breakdown\n\
+        \n\
+        | Item | Function      | Calls | Duration |\n\
+        |------|---------------|-------|----------|\n\
+        | b    | apply_exec    | 1     | 3.000s   |\n\
+        | a    | state_current | 2     | 1.500s   |\n\
+        | a    | state_goal    | 1     | 0.250s   |\n\
+        \n\
+        Total: 4.750s across 3 functions\n\
+        ",
+        output
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn present_renders_message_when_empty() -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut cli_output = CliOutputBuilder::new_with_writer(&mut buffer)
+        .with_colorize(CliColorizeOpt::Never)
+        .build();
+    let mut presenter = CliMdPresenter::new(&mut cli_output);
+
+    TimingBreakdown::new().present(&mut presenter).await?;
+
+    let output = String::from_utf8(buffer)?;
+    assert_eq!(
+        "# Timing breakdown\n\nNo item functions were executed.",
+        output
+    );
+    Ok(())
+}
+
+fn timing_breakdown() -> TimingBreakdown {
+    let mut timing_breakdown = TimingBreakdown::new();
+    timing_breakdown.record(&item_id!("a"), ItemFnKind::StateCurrent, ms(1000));
+    timing_breakdown.record(&item_id!("a"), ItemFnKind::StateGoal, ms(250));
+    timing_breakdown.record(&item_id!("a"), ItemFnKind::StateCurrent, ms(500));
+    timing_breakdown.record(&item_id!("b"), ItemFnKind::ApplyExec, ms(3000));
+    timing_breakdown
+}
+
+fn cmd_block_desc(cmd_block_name: &str) -> CmdBlockDesc {
+    CmdBlockDesc::new(cmd_block_name.to_string(), Vec::new(), Vec::new())
+}
+
+fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
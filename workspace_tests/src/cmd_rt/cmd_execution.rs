@@ -1,16 +1,41 @@
+use std::{
+    fmt,
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
 use peace::{
     cfg::{app_name, profile, FlowId},
-    cmd::ctx::CmdCtx,
-    cmd_model::CmdOutcome,
-    cmd_rt::{CmdBlockRt, CmdBlockWrapper, CmdExecution},
-    resources::states::{
-        ts::{Current, Goal},
-        StateDiffs, StatesCurrent,
+    cmd::{
+        ctx::{CmdCtx, CmdCtxTypesConstrained},
+        scopes::SingleProfileSingleFlowView,
+    },
+    cmd_model::{CmdBlockOutcome, CmdEvent, CmdOutcome},
+    cmd_rt::{async_trait, CmdBlock, CmdBlockRt, CmdBlockWrapper, CmdExecution},
+    resources::{
+        paths::ItemLogsDir,
+        resources::ts::SetUp,
+        states::{
+            ts::{Current, Goal},
+            StateDiffs, StatesCurrent,
+        },
+        ResourceFetchError, Resources,
     },
     rt::cmd_blocks::{DiffCmdBlock, StatesDiscoverCmdBlock},
-    rt_model::{Flow, ItemGraphBuilder, Workspace, WorkspaceSpec},
+    rt_model::{
+        Error, ExecutionDeadline, Flow, ItemGraphBuilder, KeyProvider, Workspace, WorkspaceSpec,
+    },
 };
 use tempfile::TempDir;
+use tokio::sync::mpsc::Receiver;
+use tracing::{
+    field::{Field, Visit},
+    span, Event, Metadata, Subscriber,
+};
 
 use crate::{
     mock_item::{MockItem, MockSrc},
@@ -19,6 +44,18 @@ use crate::{
     VecA, VecCopyItem,
 };
 
+#[cfg(feature = "output_progress")]
+use peace::{
+    cfg::progress::CmdProgressUpdate,
+    resources::states::{ts::Ensured, StatesEnsured},
+    rt::cmd_blocks::ApplyExecCmdBlock,
+};
+#[cfg(feature = "output_progress")]
+use tokio::sync::mpsc::Sender;
+
+#[cfg(feature = "output_progress")]
+use crate::mock_item::MockItemError;
+
 mod cmd_execution_error_builder;
 
 #[tokio::test]
@@ -128,6 +165,644 @@ async fn chains_multiple_cmd_blocks() -> Result<(), PeaceTestError> {
     Ok(())
 }
 
+#[tokio::test]
+async fn records_spans_to_tracing_subscriber() -> Result<(), PeaceTestError> {
+    let mut cmd_execution = CmdExecution::builder()
+        .with_cmd_block(CmdBlockWrapper::new(
+            StatesDiscoverCmdBlock::current(),
+            StatesCurrent::from,
+        ))
+        .build();
+
+    let TestCtx {
+        tempdir: _tempdir,
+        workspace,
+        flow,
+    } = test_ctx_init().await?;
+
+    let span_recorder = SpanRecorder::default();
+    let spans = Arc::clone(&span_recorder.spans);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .with_tracing_subscriber(span_recorder)
+        .await?;
+
+    cmd_execution.exec(&mut cmd_ctx).await?;
+
+    let spans = spans.lock().expect("Expected `spans` lock to not be poisoned.");
+    let flow_id = flow.flow_id();
+    assert_eq!(
+        Some(&format!("cmd_execution flow_id={flow_id} profile=test_profile")),
+        spans.first()
+    );
+    assert!(
+        spans.contains(&String::from(
+            "cmd_block cmd_block_index=0 cmd_block_name=\"StatesDiscoverCmdBlock\""
+        )),
+        "Expected `cmd_block` span, spans were: {spans:?}"
+    );
+    assert!(
+        spans.iter().any(|span| span.ends_with("item_id=vec_copy")),
+        "Expected span for `vec_copy`, spans were: {spans:?}"
+    );
+    assert!(
+        spans.iter().any(|span| span.ends_with("item_id=mock")),
+        "Expected span for `mock`, spans were: {spans:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_inserts_item_logs_dir_when_workspace_is_not_encrypted() -> Result<(), PeaceTestError>
+{
+    let mut cmd_execution = CmdExecution::builder()
+        .with_cmd_block(CmdBlockWrapper::new(
+            StatesDiscoverCmdBlock::current(),
+            StatesCurrent::from,
+        ))
+        .build();
+
+    let TestCtx {
+        tempdir: _tempdir,
+        workspace,
+        flow,
+    } = test_ctx_init().await?;
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+
+    cmd_execution.exec(&mut cmd_ctx).await?;
+
+    let item_logs_dir = cmd_ctx.view().resources.try_borrow::<ItemLogsDir>();
+    assert!(
+        item_logs_dir.is_ok(),
+        "Expected `ItemLogsDir` to be inserted, but was: {item_logs_dir:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_does_not_capture_item_logs_when_workspace_is_encrypted() -> Result<(), PeaceTestError>
+{
+    let mut cmd_execution = CmdExecution::builder()
+        .with_cmd_block(CmdBlockWrapper::new(
+            StatesDiscoverCmdBlock::current(),
+            StatesCurrent::from,
+        ))
+        .build();
+
+    let TestCtx {
+        tempdir,
+        workspace: _,
+        flow,
+    } = test_ctx_init().await?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::PathEncrypted {
+            path: tempdir.path().to_path_buf(),
+            key_provider: KeyProvider::from_key([1u8; 32]),
+        },
+    )?;
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+
+    cmd_execution.exec(&mut cmd_ctx).await?;
+
+    // Item logs are not written through `Storage`, so they would not be
+    // encrypted.
+    let item_logs_dir = cmd_ctx.view().resources.try_borrow::<ItemLogsDir>();
+    assert!(
+        item_logs_dir.is_err(),
+        "Expected `ItemLogsDir` to not be inserted, but was: {item_logs_dir:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_with_events_sends_block_and_item_events() -> Result<(), PeaceTestError> {
+    let states_discover_cmd_block = CmdBlockWrapper::new(
+        StatesDiscoverCmdBlock::current_and_goal(),
+        |_states_current_and_goal_mut| StateDiffs::new(),
+    );
+    let states_discover_cmd_block_desc = states_discover_cmd_block.cmd_block_desc();
+    let diff_cmd_block = CmdBlockWrapper::new(
+        DiffCmdBlock::<_, Current, Goal>::new(),
+        |_state_diffs_ts0_and_ts1| StateDiffs::new(),
+    );
+    let diff_cmd_block_desc = diff_cmd_block.cmd_block_desc();
+    let mut cmd_execution = CmdExecution::<StateDiffs, _>::builder()
+        .with_cmd_block(states_discover_cmd_block)
+        .with_cmd_block(diff_cmd_block)
+        .build();
+
+    let TestCtx {
+        tempdir: _tempdir,
+        workspace,
+        flow,
+    } = test_ctx_init().await?;
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+
+    let (cmd_event_rx, exec_task) = cmd_execution.exec_with_events(&mut cmd_ctx);
+    let (cmd_outcome, cmd_events) = futures::join!(exec_task, cmd_events_collect(cmd_event_rx));
+    let cmd_outcome = cmd_outcome?;
+
+    assert!(cmd_outcome.is_complete());
+    assert_eq!(
+        Some(&CmdEvent::BlockStarted {
+            cmd_block_index: 0,
+            cmd_block_desc: states_discover_cmd_block_desc,
+        }),
+        cmd_events.first()
+    );
+    assert!(cmd_events.contains(&CmdEvent::BlockStarted {
+        cmd_block_index: 1,
+        cmd_block_desc: diff_cmd_block_desc,
+    }));
+    assert_eq!(Some(&CmdEvent::Completed), cmd_events.last());
+    #[cfg(feature = "output_progress")]
+    {
+        [VecCopyItem::ID_DEFAULT.clone(), MockItem::<()>::ID_DEFAULT.clone()]
+            .into_iter()
+            .for_each(|item_id| {
+                let item_started = CmdEvent::ItemStarted {
+                    item_id: item_id.clone(),
+                };
+                let item_state_discovered = CmdEvent::ItemStateDiscovered { item_id };
+                let item_started_index = cmd_events.iter().position(|e| e == &item_started);
+                let item_state_discovered_index =
+                    cmd_events.iter().position(|e| e == &item_state_discovered);
+
+                assert!(
+                    matches!(
+                        (item_started_index, item_state_discovered_index),
+                        (Some(started), Some(discovered)) if started < discovered
+                    ),
+                    "Expected `{item_started:?}` before `{item_state_discovered:?}`, \
+                    events were: {cmd_events:?}"
+                );
+            });
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "output_progress")]
+#[tokio::test]
+async fn exec_with_events_sends_item_applied_for_apply_cmd_block() -> Result<(), PeaceTestError> {
+    let mut cmd_execution = CmdExecution::<StatesEnsured, _>::builder()
+        .with_cmd_block(CmdBlockWrapper::new(
+            StatesDiscoverCmdBlock::current_and_goal(),
+            |_states_current_and_goal_mut| StatesEnsured::new(),
+        ))
+        .with_cmd_block(CmdBlockWrapper::new(
+            ApplyExecCmdBlock::<_, Ensured>::new(),
+            |(_states_previous, states_applied, _states_target)| states_applied,
+        ))
+        .build();
+
+    let TestCtx {
+        tempdir: _tempdir,
+        workspace,
+        flow,
+    } = test_ctx_init().await?;
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+
+    let (cmd_event_rx, exec_task) = cmd_execution.exec_with_events(&mut cmd_ctx);
+    let (cmd_outcome, cmd_events) = futures::join!(exec_task, cmd_events_collect(cmd_event_rx));
+    let cmd_outcome = cmd_outcome?;
+
+    assert!(cmd_outcome.is_complete());
+    let item_applied_vec_copy = CmdEvent::ItemApplied {
+        item_id: VecCopyItem::ID_DEFAULT.clone(),
+    };
+    let block_started_apply_index = cmd_events
+        .iter()
+        .position(|cmd_event| {
+            matches!(
+                cmd_event,
+                CmdEvent::BlockStarted {
+                    cmd_block_index: 1,
+                    ..
+                }
+            )
+        })
+        .expect("Expected `BlockStarted` event for `ApplyExecCmdBlock`.");
+    let item_applied_index = cmd_events
+        .iter()
+        .position(|cmd_event| cmd_event == &item_applied_vec_copy)
+        .expect("Expected `ItemApplied` event for `vec_copy`.");
+    assert!(block_started_apply_index < item_applied_index);
+    assert!(!cmd_events[..block_started_apply_index].contains(&item_applied_vec_copy));
+    assert_eq!(Some(&CmdEvent::Completed), cmd_events.last());
+
+    Ok(())
+}
+
+#[cfg(feature = "output_progress")]
+#[tokio::test]
+async fn exec_with_events_sends_item_failed_when_item_errs() -> Result<(), PeaceTestError> {
+    let mut cmd_execution = CmdExecution::builder()
+        .with_cmd_block(CmdBlockWrapper::new(
+            StatesDiscoverCmdBlock::current(),
+            StatesCurrent::from,
+        ))
+        .build();
+
+    let tempdir = tempfile::tempdir().map_err(PeaceTestError::TempDir)?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(
+            MockItem::<()>::default()
+                .with_try_state_current(|_fn_ctx, _mock_src, _data| {
+                    Err(MockItemError::Synthetic(String::from("state_current_err")))
+                })
+                .into(),
+        );
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+
+    let (cmd_event_rx, exec_task) = cmd_execution.exec_with_events(&mut cmd_ctx);
+    let (cmd_outcome, cmd_events) = futures::join!(exec_task, cmd_events_collect(cmd_event_rx));
+    let cmd_outcome = cmd_outcome?;
+
+    assert!(cmd_outcome.is_err());
+    assert!(
+        cmd_events.iter().any(|cmd_event| matches!(
+            cmd_event,
+            CmdEvent::ItemFailed { item_id, .. }
+            if item_id == MockItem::<()>::ID_DEFAULT
+        )),
+        "Expected `ItemFailed` event for `mock`, events were: {cmd_events:?}"
+    );
+    assert_eq!(Some(&CmdEvent::Completed), cmd_events.last());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_with_events_completes_when_receiver_dropped() -> Result<(), PeaceTestError> {
+    let mut cmd_execution = CmdExecution::builder()
+        .with_cmd_block(CmdBlockWrapper::new(
+            StatesDiscoverCmdBlock::current(),
+            StatesCurrent::from,
+        ))
+        .build();
+
+    let TestCtx {
+        tempdir: _tempdir,
+        workspace,
+        flow,
+    } = test_ctx_init().await?;
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+
+    let (cmd_event_rx, exec_task) = cmd_execution.exec_with_events(&mut cmd_ctx);
+    drop(cmd_event_rx);
+    let cmd_outcome = exec_task.await?;
+
+    assert!(cmd_outcome.is_complete());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_skips_cmd_block_when_predicate_returns_false() -> Result<(), PeaceTestError> {
+    let pending_cmd_block =
+        CmdBlockWrapper::new(PendingCmdBlock::default(), std::convert::identity);
+    let pending_cmd_block_desc = pending_cmd_block.cmd_block_desc();
+    let mut cmd_execution = CmdExecution::<StatesCurrent, _>::builder()
+        .with_cmd_block(CmdBlockWrapper::new(
+            StatesDiscoverCmdBlock::current(),
+            StatesCurrent::from,
+        ))
+        .with_cmd_block_if(|_resources| false, pending_cmd_block)
+        .build();
+
+    let TestCtx {
+        tempdir: _tempdir,
+        workspace,
+        flow,
+    } = test_ctx_init().await?;
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+
+    let (cmd_event_rx, exec_task) = cmd_execution.exec_with_events(&mut cmd_ctx);
+    let (cmd_outcome, cmd_events) = futures::join!(exec_task, cmd_events_collect(cmd_event_rx));
+    let cmd_outcome = cmd_outcome?;
+
+    assert!(cmd_outcome.is_complete());
+    assert!(cmd_events.contains(&CmdEvent::BlockSkipped {
+        cmd_block_index: 1,
+        cmd_block_desc: pending_cmd_block_desc,
+    }));
+    assert!(!cmd_events.iter().any(|cmd_event| matches!(
+        cmd_event,
+        CmdEvent::BlockStarted {
+            cmd_block_index: 1,
+            ..
+        }
+    )));
+    assert_eq!(Some(&CmdEvent::Completed), cmd_events.last());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_runs_cmd_block_when_predicate_returns_true() -> Result<(), PeaceTestError> {
+    let mut cmd_execution = CmdExecution::<StateDiffs, _>::builder()
+        .with_cmd_block(CmdBlockWrapper::new(
+            StatesDiscoverCmdBlock::current_and_goal(),
+            |_states_current_and_goal_mut| StateDiffs::new(),
+        ))
+        .with_cmd_block_if(
+            |resources| resources.contains::<StatesCurrent>(),
+            CmdBlockWrapper::new(
+                DiffCmdBlock::<_, Current, Goal>::new(),
+                |_state_diffs_ts0_and_ts1| StateDiffs::new(),
+            ),
+        )
+        .build();
+
+    let TestCtx {
+        tempdir: _tempdir,
+        workspace,
+        flow,
+    } = test_ctx_init().await?;
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+
+    let (cmd_event_rx, exec_task) = cmd_execution.exec_with_events(&mut cmd_ctx);
+    let (cmd_outcome, cmd_events) = futures::join!(exec_task, cmd_events_collect(cmd_event_rx));
+    let cmd_outcome = cmd_outcome?;
+
+    assert!(cmd_outcome.is_complete());
+    assert!(cmd_events.iter().any(|cmd_event| matches!(
+        cmd_event,
+        CmdEvent::BlockStarted {
+            cmd_block_index: 1,
+            ..
+        }
+    )));
+    assert!(!cmd_events
+        .iter()
+        .any(|cmd_event| matches!(cmd_event, CmdEvent::BlockSkipped { .. })));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn returns_cmd_block_timeout_when_cmd_block_exceeds_timeout() -> Result<(), PeaceTestError> {
+    let mut cmd_execution = CmdExecution::<StatesCurrent, _>::builder()
+        .with_cmd_block(CmdBlockWrapper::new(
+            StatesDiscoverCmdBlock::current(),
+            StatesCurrent::from,
+        ))
+        .with_cmd_block(CmdBlockWrapper::new(
+            PendingCmdBlock::default(),
+            std::convert::identity,
+        ))
+        .with_cmd_block_timeout(Duration::from_millis(50))
+        .build();
+
+    let TestCtx {
+        tempdir: _tempdir,
+        workspace,
+        flow,
+    } = test_ctx_init().await?;
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+
+    let cmd_outcome_result = cmd_execution.exec(&mut cmd_ctx).await;
+
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    &cmd_outcome_result,
+                    Err(PeaceTestError::PeaceRt(Error::CmdBlockTimeout {
+                        cmd_block_name,
+                        input_type_names,
+                        timeout,
+                    }))
+                    if cmd_block_name == "PendingCmdBlock"
+                    && input_type_names == &["States<Current>"]
+                    && *timeout == Duration::from_millis(50)
+                ),
+                "Expected `CmdBlockTimeout` error for `PendingCmdBlock`,\n\
+                but cmd_outcome_result was: {cmd_outcome_result:?}"
+            );
+        }
+    })();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn returns_execution_interrupted_with_deadline_exceeded_when_deadline_passed(
+) -> Result<(), PeaceTestError> {
+    let mut cmd_execution = CmdExecution::<StatesCurrent, _>::builder()
+        .with_cmd_block(CmdBlockWrapper::new(
+            StatesDiscoverCmdBlock::current(),
+            StatesCurrent::from,
+        ))
+        .build();
+
+    let TestCtx {
+        tempdir: _tempdir,
+        workspace,
+        flow,
+    } = test_ctx_init().await?;
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .with_execution_deadline(Instant::now())
+        .await?;
+
+    let cmd_outcome = cmd_execution.exec(&mut cmd_ctx).await?;
+
+    assert!(
+        matches!(
+            &cmd_outcome,
+            CmdOutcome::ExecutionInterrupted {
+                value: None,
+                cmd_blocks_processed,
+                cmd_blocks_not_processed,
+                deadline_exceeded: true,
+            }
+            if cmd_blocks_processed.is_empty()
+            && cmd_blocks_not_processed.len() == 1
+        ),
+        "Expected `ExecutionInterrupted` outcome with `deadline_exceeded`,\n\
+        but cmd_outcome was: {cmd_outcome:?}"
+    );
+    assert!(cmd_outcome.is_deadline_exceeded());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn returns_execution_deadline_exceeded_when_cmd_block_exceeds_grace_period(
+) -> Result<(), PeaceTestError> {
+    let mut cmd_execution = CmdExecution::<StatesCurrent, _>::builder()
+        .with_cmd_block(CmdBlockWrapper::new(
+            StatesDiscoverCmdBlock::current(),
+            StatesCurrent::from,
+        ))
+        .with_cmd_block(CmdBlockWrapper::new(
+            PendingCmdBlock::default(),
+            std::convert::identity,
+        ))
+        .build();
+
+    let TestCtx {
+        tempdir: _tempdir,
+        workspace,
+        flow,
+    } = test_ctx_init().await?;
+
+    let execution_deadline = ExecutionDeadline::new(Instant::now() + Duration::from_millis(200))
+        .with_grace_period(Duration::from_millis(50));
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .with_execution_deadline(execution_deadline)
+        .await?;
+
+    let cmd_outcome_result = cmd_execution.exec(&mut cmd_ctx).await;
+
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    &cmd_outcome_result,
+                    Err(PeaceTestError::PeaceRt(Error::ExecutionDeadlineExceeded {
+                        cmd_block_name,
+                        input_type_names,
+                        grace_period,
+                    }))
+                    if cmd_block_name == "PendingCmdBlock"
+                    && input_type_names == &["States<Current>"]
+                    && *grace_period == Duration::from_millis(50)
+                ),
+                "Expected `ExecutionDeadlineExceeded` error for `PendingCmdBlock`,\n\
+                but cmd_outcome_result was: {cmd_outcome_result:?}"
+            );
+        }
+    })();
+
+    Ok(())
+}
+
 async fn test_ctx_init() -> Result<TestCtx, PeaceTestError> {
     let tempdir = tempfile::tempdir().map_err(PeaceTestError::TempDir)?;
     let workspace = Workspace::new(
@@ -151,8 +826,97 @@ async fn test_ctx_init() -> Result<TestCtx, PeaceTestError> {
     })
 }
 
+async fn cmd_events_collect(mut cmd_event_rx: Receiver<CmdEvent>) -> Vec<CmdEvent> {
+    let mut cmd_events = Vec::new();
+    while let Some(cmd_event) = cmd_event_rx.recv().await {
+        cmd_events.push(cmd_event);
+    }
+    cmd_events
+}
+
 struct TestCtx {
     tempdir: TempDir,
     workspace: Workspace,
     flow: Flow<PeaceTestError>,
 }
+
+/// `CmdBlock` that never completes.
+#[derive(Debug)]
+struct PendingCmdBlock<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> Default for PendingCmdBlock<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[async_trait(?Send)]
+impl<CmdCtxTypesT> CmdBlock for PendingCmdBlock<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    type CmdCtxTypes = CmdCtxTypesT;
+    type InputT = StatesCurrent;
+    type Outcome = StatesCurrent;
+
+    fn input_fetch(
+        &self,
+        resources: &mut Resources<SetUp>,
+    ) -> Result<StatesCurrent, ResourceFetchError> {
+        resources.try_remove::<StatesCurrent>()
+    }
+
+    async fn exec(
+        &self,
+        _input: Self::InputT,
+        _cmd_view: &mut SingleProfileSingleFlowView<'_, Self::CmdCtxTypes>,
+        #[cfg(feature = "output_progress")] _progress_tx: &Sender<CmdProgressUpdate>,
+    ) -> Result<
+        CmdBlockOutcome<Self::Outcome, <Self::CmdCtxTypes as CmdCtxTypesConstrained>::AppError>,
+        <Self::CmdCtxTypes as CmdCtxTypesConstrained>::AppError,
+    > {
+        std::future::pending().await
+    }
+}
+
+/// Records the name and fields of each new span.
+#[derive(Debug, Default)]
+struct SpanRecorder {
+    spans: Arc<Mutex<Vec<String>>>,
+    span_id_next: AtomicU64,
+}
+
+impl Subscriber for SpanRecorder {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, attributes: &span::Attributes<'_>) -> span::Id {
+        let mut span = String::from(attributes.metadata().name());
+        attributes.record(&mut SpanFieldsVisitor(&mut span));
+        self.spans
+            .lock()
+            .expect("Expected `spans` lock to not be poisoned.")
+            .push(span);
+
+        span::Id::from_u64(self.span_id_next.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+
+    fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, _span: &span::Id) {}
+
+    fn exit(&self, _span: &span::Id) {}
+}
+
+struct SpanFieldsVisitor<'s>(&'s mut String);
+
+impl Visit for SpanFieldsVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.push_str(&format!(" {}={value:?}", field.name()));
+    }
+}
@@ -1,4 +1,5 @@
 mod derive;
+mod item_output;
 mod marker;
 mod r_maybe;
 mod w_maybe;
@@ -0,0 +1,18 @@
+use peace::data::ItemOutput;
+
+#[test]
+fn debug() {
+    assert_eq!(
+        "ItemOutput(Some(1))",
+        format!("{:?}", ItemOutput(Some(1u8)))
+    );
+}
+
+#[test]
+fn deref_mut() {
+    let mut item_output = ItemOutput(None);
+
+    *item_output = Some(1u8);
+
+    assert_eq!(Some(1u8), *item_output);
+}
@@ -1,4 +1,6 @@
+mod flow_diff;
 mod flow_info;
+mod flow_spec;
 mod flow_spec_info;
 mod item_info;
 mod item_spec_info;
@@ -0,0 +1,161 @@
+use peace::{
+    cfg::{flow_id, item_id, ItemId},
+    data::fn_graph::{Edge, WouldCycle},
+    flow_model::{FlowDiff, FlowEdge, FlowSpecInfo, ItemRename},
+    rt_model::{Flow, ItemGraph, ItemGraphBuilder},
+};
+use peace_items::blank::BlankItem;
+
+use crate::PeaceTestError;
+
+#[test]
+fn is_empty_when_flows_are_the_same() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_spec_info_old = flow_spec_info(["a", "b", "c"])?;
+    let flow_spec_info_new = flow_spec_info(["a", "b", "c"])?;
+
+    let flow_diff = FlowDiff::new(&flow_spec_info_old, &flow_spec_info_new);
+
+    assert!(flow_diff.is_empty());
+    assert!(flow_diff.states_orphaned().is_empty());
+    assert_eq!("Flows are the same.\n", flow_diff.to_string());
+    Ok(())
+}
+
+#[test]
+fn reports_items_added_and_edges_added() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_spec_info_old = flow_spec_info(["a", "b"])?;
+    let flow_spec_info_new = flow_spec_info(["a", "b", "c"])?;
+
+    let flow_diff = FlowDiff::new(&flow_spec_info_old, &flow_spec_info_new);
+
+    assert_eq!(vec![item_id!("c")], flow_diff.items_added);
+    assert!(flow_diff.items_removed.is_empty());
+    assert!(flow_diff.items_renamed.is_empty());
+    assert!(flow_diff.edges_added.contains(&FlowEdge::new(
+        item_id!("b"),
+        item_id!("c"),
+        Edge::Logic
+    )));
+    assert!(flow_diff.edges_removed.is_empty());
+    assert!(flow_diff.states_orphaned().is_empty());
+    Ok(())
+}
+
+#[test]
+fn reports_items_removed_as_states_orphaned() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_spec_info_old = flow_spec_info(["a", "b", "c"])?;
+    let flow_spec_info_new = flow_spec_info(["a", "b"])?;
+
+    let flow_diff = FlowDiff::new(&flow_spec_info_old, &flow_spec_info_new);
+
+    assert!(flow_diff.items_added.is_empty());
+    assert_eq!(vec![item_id!("c")], flow_diff.items_removed);
+    assert!(flow_diff.edges_removed.contains(&FlowEdge::new(
+        item_id!("b"),
+        item_id!("c"),
+        Edge::Logic
+    )));
+    assert_eq!(vec![&item_id!("c")], flow_diff.states_orphaned());
+    assert!(flow_diff
+        .to_string()
+        .contains("- item `c`: stored state is orphaned"));
+    Ok(())
+}
+
+#[test]
+fn reports_item_renamed_when_neighbours_match() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_spec_info_old = flow_spec_info(["a", "b", "c"])?;
+    let flow_spec_info_new = flow_spec_info(["a", "b_renamed", "c"])?;
+
+    let flow_diff = FlowDiff::new(&flow_spec_info_old, &flow_spec_info_new);
+
+    assert!(flow_diff.items_added.is_empty());
+    assert!(flow_diff.items_removed.is_empty());
+    assert_eq!(
+        vec![ItemRename::new(item_id!("b"), item_id!("b_renamed"))],
+        flow_diff.items_renamed
+    );
+    assert!(flow_diff.edges_added.is_empty());
+    assert!(flow_diff.edges_removed.is_empty());
+    assert_eq!(vec![&item_id!("b")], flow_diff.states_orphaned());
+    assert_eq!(
+        "~ item `b` -> `b_renamed`: migrate stored state from `b` to `b_renamed`\n",
+        flow_diff.to_string()
+    );
+    Ok(())
+}
+
+#[test]
+fn reports_item_renamed_when_unconnected_at_same_position() -> Result<(), Box<dyn std::error::Error>>
+{
+    let flow_spec_info_old = flow_spec_info(["a"])?;
+    let flow_spec_info_new = flow_spec_info(["a_renamed"])?;
+
+    let flow_diff = FlowDiff::new(&flow_spec_info_old, &flow_spec_info_new);
+
+    assert_eq!(
+        vec![ItemRename::new(item_id!("a"), item_id!("a_renamed"))],
+        flow_diff.items_renamed
+    );
+    Ok(())
+}
+
+#[test]
+fn reports_added_and_removed_when_neighbours_differ() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_spec_info_old = flow_spec_info(["a", "b", "c"])?;
+    let flow_spec_info_new = flow_spec_info(["a", "c", "d"])?;
+
+    let flow_diff = FlowDiff::new(&flow_spec_info_old, &flow_spec_info_new);
+
+    assert_eq!(vec![item_id!("d")], flow_diff.items_added);
+    assert_eq!(vec![item_id!("b")], flow_diff.items_removed);
+    assert!(flow_diff.items_renamed.is_empty());
+    assert!(flow_diff.edges_added.contains(&FlowEdge::new(
+        item_id!("a"),
+        item_id!("c"),
+        Edge::Logic
+    )));
+    assert!(flow_diff.edges_removed.contains(&FlowEdge::new(
+        item_id!("a"),
+        item_id!("b"),
+        Edge::Logic
+    )));
+    Ok(())
+}
+
+#[test]
+fn serialize_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_spec_info_old = flow_spec_info(["a", "b"])?;
+    let flow_spec_info_new = flow_spec_info(["a", "b_renamed", "c"])?;
+    let flow_diff = FlowDiff::new(&flow_spec_info_old, &flow_spec_info_new);
+
+    let serialized = serde_yaml::to_string(&flow_diff)?;
+
+    assert_eq!(flow_diff, serde_yaml::from_str::<FlowDiff>(&serialized)?);
+    Ok(())
+}
+
+/// Returns the flow spec info for items chained with logic edges.
+fn flow_spec_info<const N: usize>(
+    item_ids: [&'static str; N],
+) -> Result<FlowSpecInfo, WouldCycle<Edge>> {
+    let flow = Flow::new(flow_id!("flow_id"), chain_graph(item_ids)?);
+    Ok(flow.flow_spec_info())
+}
+
+fn chain_graph<const N: usize>(
+    item_ids: [&'static str; N],
+) -> Result<ItemGraph<PeaceTestError>, WouldCycle<Edge>> {
+    let mut item_graph_builder = ItemGraphBuilder::new();
+    let fn_ids = item_ids.map(|item_id| {
+        let item_id = ItemId::new(item_id).expect("Expected item ID to be valid.");
+        item_graph_builder.add_fn(BlankItem::<()>::new(item_id).into())
+    });
+    fn_ids.windows(2).try_for_each(|fn_id_pair| {
+        item_graph_builder
+            .add_logic_edge(fn_id_pair[0], fn_id_pair[1])
+            .map(|_| ())
+    })?;
+
+    Ok(item_graph_builder.build())
+}
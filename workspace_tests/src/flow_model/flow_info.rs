@@ -123,6 +123,7 @@ fn flow_info() -> Result<FlowInfo, WouldCycle<Edge>> {
     let FlowSpecInfo {
         flow_id,
         graph_info,
+        edges_ordering_only: _,
     } = flow.flow_spec_info();
 
     let mut graph = graph_info.iter_insertion_with_indices().fold(
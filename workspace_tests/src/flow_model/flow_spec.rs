@@ -0,0 +1,57 @@
+use peace::{
+    cfg::{flow_id, item_id},
+    flow_model::{FlowSpec, FlowSpecItem},
+};
+
+#[test]
+fn deserialize_yaml() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_spec = serde_yaml::from_str::<FlowSpec>(
+        r#"flow_id: app_deploy
+items:
+  - id: app_download
+    type: file_download
+  - id: app_extract
+    type: tar_x
+    depends_on: [app_download]
+"#,
+    )?;
+
+    assert_eq!(
+        FlowSpec::new(
+            flow_id!("app_deploy"),
+            vec![
+                FlowSpecItem::new(item_id!("app_download"), String::from("file_download")),
+                FlowSpecItem::new(item_id!("app_extract"), String::from("tar_x"))
+                    .with_depends_on(vec![item_id!("app_download")]),
+            ],
+        ),
+        flow_spec
+    );
+    Ok(())
+}
+
+#[test]
+fn serialize_yaml_skips_empty_depends_on() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_spec = FlowSpec::new(
+        flow_id!("app_deploy"),
+        vec![
+            FlowSpecItem::new(item_id!("app_download"), String::from("file_download")),
+            FlowSpecItem::new(item_id!("app_extract"), String::from("tar_x"))
+                .with_depends_on(vec![item_id!("app_download")]),
+        ],
+    );
+
+    assert_eq!(
+        r#"flow_id: app_deploy
+items:
+- id: app_download
+  type: file_download
+- id: app_extract
+  type: tar_x
+  depends_on:
+  - app_download
+"#,
+        serde_yaml::to_string(&flow_spec)?
+    );
+    Ok(())
+}
@@ -13,7 +13,7 @@ use peace::{
         },
         FlowSpecInfo,
     },
-    rt_model::{Flow, ItemGraph, ItemGraphBuilder},
+    rt_model::{Flow, ItemEdgeKind, ItemGraph, ItemGraphBuilder},
 };
 use peace_items::blank::BlankItem;
 
@@ -109,6 +109,39 @@ fn to_outcome_info_graph() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn to_progress_info_graph_renders_ordering_only_edges_dashed(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let flow_spec_info = {
+        let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        let [fn_id_a, fn_id_b, fn_id_c] = item_graph_builder.add_fns([
+            BlankItem::<()>::new(item_id!("a")).into(),
+            BlankItem::<()>::new(item_id!("b")).into(),
+            BlankItem::<()>::new(item_id!("c")).into(),
+        ]);
+        item_graph_builder.add_edge(fn_id_a, fn_id_b, ItemEdgeKind::OrderingOnly)?;
+        item_graph_builder.add_edge(fn_id_b, fn_id_c, ItemEdgeKind::DataDependency)?;
+
+        Flow::new(flow_id!("flow_id"), item_graph_builder.build()).flow_spec_info()
+    };
+
+    let info_graph = flow_spec_info.to_progress_info_graph();
+    let tailwind_classes = info_graph.tailwind_classes();
+
+    assert_eq!(
+        vec![[item_id!("a"), item_id!("b")]],
+        flow_spec_info.edges_ordering_only
+    );
+    assert!(
+        tailwind_classes
+            .edge_classes(edge_id!("a__b"))
+            .is_some_and(|classes| classes.contains("stroke-dasharray")),
+        "was {tailwind_classes:?}"
+    );
+    assert_eq!(None, tailwind_classes.edge_classes(edge_id!("b__c")));
+    Ok(())
+}
+
 #[test]
 fn clone() -> Result<(), Box<dyn std::error::Error>> {
     let flow_spec_info = flow_spec_info()?;
@@ -126,7 +159,8 @@ fn debug() -> Result<(), Box<dyn std::error::Error>> {
             flow_id: FlowId(\"flow_id\"), \
             graph_info: GraphInfo { \
                 graph: Dag { graph: Graph { Ty: \"Directed\", node_count: 6, edge_count: 9, edges: (0, 1), (0, 2), (1, 4), (2, 3), (3, 4), (5, 4), (1, 2), (5, 1), (0, 5), node weights: {0: ItemSpecInfo { item_id: ItemId(\"a\") }, 1: ItemSpecInfo { item_id: ItemId(\"b\") }, 2: ItemSpecInfo { item_id: ItemId(\"c\") }, 3: ItemSpecInfo { item_id: ItemId(\"d\") }, 4: ItemSpecInfo { item_id: ItemId(\"e\") }, 5: ItemSpecInfo { item_id: ItemId(\"f\") }}, edge weights: {0: Contains, 1: Logic, 2: Logic, 3: Contains, 4: Logic, 5: Logic, 6: Data, 7: Data, 8: Data} }, cycle_state: DfsSpace { dfs: Dfs { stack: [], discovered: FixedBitSet { data: [], length: 0 } } } } \
-            } \
+            }, \
+            edges_ordering_only: [] \
         }",
         format!("{flow_spec_info:?}")
     );
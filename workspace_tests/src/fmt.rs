@@ -4,6 +4,8 @@ use peace::{
 };
 
 mod either;
+mod message;
+mod message_catalog;
 mod presentable;
 
 /// Returns a new `CliOutput` with `OutputFormat::Text`.
@@ -0,0 +1,63 @@
+use peace::fmt::{messages, Message};
+
+#[test]
+fn text_substitutes_args() {
+    let message = Message::new("test-message", "`{ $item_id }` is { $status }.");
+
+    assert_eq!(
+        "`a` is done.",
+        message.text(&[("item_id", "a"), ("status", "done")])
+    );
+}
+
+#[test]
+fn text_leaves_placeables_without_args() {
+    let message = Message::new("test-message", "`{ $item_id }` is { $status }.");
+
+    assert_eq!("`a` is { $status }.", message.text(&[("item_id", "a")]));
+}
+
+#[test]
+fn text_returns_text_default_when_no_placeables() {
+    assert_eq!("done!", messages::PROGRESS_DONE.text(&[]));
+}
+
+#[test]
+fn id_and_text_default() {
+    let message = Message::new("test-message", "{ $count } items");
+
+    assert_eq!("test-message", message.id());
+    assert_eq!("{ $count } items", message.text_default());
+}
+
+#[test]
+fn messages_ids_are_unique() {
+    let mut message_ids = messages::ALL.iter().map(Message::id).collect::<Vec<&str>>();
+    message_ids.sort_unstable();
+    message_ids.dedup();
+
+    assert_eq!(messages::ALL.len(), message_ids.len());
+}
+
+#[test]
+fn help_message_id_replaces_path_separators() {
+    assert_eq!(
+        "peace_rt_model-states_deserialize-help",
+        messages::help_message_id("peace_rt_model::states_deserialize")
+    );
+}
+
+#[test]
+fn clone() {
+    let message = messages::PROGRESS_DONE;
+
+    assert_eq!(message, Clone::clone(&message));
+}
+
+#[test]
+fn debug() {
+    assert_eq!(
+        r#"Message { id: "peace-progress-done", text_default: "done!" }"#,
+        format!("{:?}", messages::PROGRESS_DONE)
+    );
+}
@@ -0,0 +1,105 @@
+use peace::fmt::{messages, MessageCatalog};
+
+#[test]
+fn new_uses_default_locale() {
+    let message_catalog = MessageCatalog::new();
+
+    assert_eq!(MessageCatalog::LOCALE_DEFAULT, message_catalog.locale());
+}
+
+#[test]
+fn with_locale_sets_locale() {
+    let message_catalog = MessageCatalog::new().with_locale("fr-FR");
+
+    assert_eq!("fr-FR", message_catalog.locale());
+}
+
+#[test]
+fn text_returns_default_text_without_translation() {
+    let message_catalog = MessageCatalog::new().with_locale("fr-FR");
+
+    assert_eq!(
+        "blocked by `a`",
+        message_catalog.text(messages::PROGRESS_BLOCKED_BY, &[("item_id", "a")])
+    );
+    assert_eq!(
+        None,
+        message_catalog.translation("peace-progress-done", &[])
+    );
+}
+
+#[cfg(feature = "i18n")]
+#[test]
+fn text_returns_translation_for_locale() -> Result<(), Box<dyn std::error::Error>> {
+    let message_catalog = MessageCatalog::new()
+        .with_translations(
+            "fr-FR",
+            "peace-progress-blocked-by = bloqué par `{ $item_id }`\n",
+        )?
+        .with_locale("fr-FR");
+
+    assert_eq!(
+        "bloqué par `a`",
+        message_catalog.text(messages::PROGRESS_BLOCKED_BY, &[("item_id", "a")])
+    );
+    assert_eq!("done!", message_catalog.text(messages::PROGRESS_DONE, &[]));
+
+    Ok(())
+}
+
+#[cfg(feature = "i18n")]
+#[test]
+fn text_returns_translation_for_locale_language() -> Result<(), Box<dyn std::error::Error>> {
+    let message_catalog = MessageCatalog::new()
+        .with_translations("fr", "peace-progress-done = terminé !\n")?
+        .with_locale("fr-CA");
+
+    assert_eq!(
+        "terminé !",
+        message_catalog.text(messages::PROGRESS_DONE, &[])
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "i18n")]
+#[test]
+fn with_translations_returns_error_when_ftl_source_invalid() {
+    let error = MessageCatalog::new()
+        .with_translations("fr-FR", "peace-progress-done terminé\n")
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        peace::fmt::MessageCatalogError::FtlParse { locale, .. } if locale == "fr-FR"
+    ));
+}
+
+#[cfg(feature = "i18n")]
+#[test]
+fn with_translations_returns_error_when_locale_invalid() {
+    let error = MessageCatalog::new()
+        .with_translations("not a locale", "peace-progress-done = terminé !\n")
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        peace::fmt::MessageCatalogError::LocaleParse { locale, .. } if locale == "not a locale"
+    ));
+}
+
+#[test]
+fn clone() {
+    let message_catalog = MessageCatalog::new().with_locale("fr-FR");
+
+    assert_eq!("fr-FR", Clone::clone(&message_catalog).locale());
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn debug() {
+    assert_eq!(
+        r#"MessageCatalog { locale: "en-US" }"#,
+        format!("{:?}", MessageCatalog::new())
+    );
+}
@@ -207,6 +207,19 @@ impl Presenter<'static> for FnTrackerPresenter {
 
         Ok(())
     }
+
+    async fn table<I, R>(&mut self, header: &[&str], _rows: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = R>,
+        R: AsRef<[String]>,
+    {
+        self.fn_invocations.push(FnInvocation::new(
+            fn_name_short!(),
+            vec![Some(format!("{header:?}")), None],
+        ));
+
+        Ok(())
+    }
 }
 
 #[tokio::test]
@@ -245,6 +258,9 @@ async fn coverage() -> Result<(), std::io::Error> {
     fn_tracker_presenter
         .list_bulleted_aligned_with(std::iter::once(&("abc", "def")), std::convert::identity)
         .await?;
+    fn_tracker_presenter
+        .table(&["a", "b"], [vec![String::from("c"), String::from("d")]])
+        .await?;
 
     [
         FnInvocation::new("heading", vec![Some(String::from("Level1")), None]),
@@ -265,6 +281,7 @@ async fn coverage() -> Result<(), std::io::Error> {
         FnInvocation::new("list_bulleted_with", vec![None, None]),
         FnInvocation::new("list_bulleted_aligned", vec![None]),
         FnInvocation::new("list_bulleted_aligned_with", vec![None, None]),
+        FnInvocation::new("table", vec![Some(String::from("[\"a\", \"b\"]")), None]),
     ]
     .into_iter()
     .zip(fn_tracker_presenter.fn_invocations().iter())
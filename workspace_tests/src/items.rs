@@ -1,2 +1,23 @@
+mod always_fail_item;
+mod aws_iam_role_item;
+mod aws_instance_profile_item;
+mod aws_s3_bucket_item;
+#[cfg(unix)]
+mod cloud_init_vm_item;
+#[cfg(unix)]
+mod container_item;
+mod dir_sync_item;
+mod dns_record_item;
+mod env_file_item;
+mod http_resource_item;
+mod noop_item;
+mod readiness_check_item;
 mod sh_cmd_item;
+#[cfg(unix)]
+mod ssh_cmd_item;
+#[cfg(unix)]
+mod systemd_service_item;
 mod tar_x_item;
+mod template_render_item;
+#[cfg(unix)]
+mod tunnel_item;
@@ -0,0 +1,236 @@
+use std::time::Duration;
+
+use peace::{
+    cfg::{app_name, item_id, profile, FlowId, Item, ItemId, Profile},
+    cmd::ctx::CmdCtx,
+    cmd_model::CmdOutcome,
+    rt::cmds::{EnsureCmd, StatesDiscoverCmd},
+    rt_model::{Flow, InMemoryOutput, ItemGraph, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+use peace_items::fail::{AlwaysFailError, AlwaysFailItem, AlwaysFailParams, FailPhase};
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct AlwaysFailTest;
+
+impl AlwaysFailTest {
+    const ID: &'static ItemId = &item_id!("always_fail_test");
+}
+
+#[test]
+fn clone() {
+    let _item = Clone::clone(&AlwaysFailItem::<()>::new(AlwaysFailTest::ID.clone()));
+}
+
+#[tokio::test]
+async fn state_current_fails_when_fail_phase_is_state_current(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+    } = test_env()?;
+    let flow = Flow::new(flow_id, graph);
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile)
+        .with_flow(&flow)
+        .with_item_params::<AlwaysFailItem<AlwaysFailTest>>(
+            AlwaysFailTest::ID.clone(),
+            AlwaysFailParams::<AlwaysFailTest>::new(Duration::ZERO, FailPhase::StateCurrent).into(),
+        )
+        .await?;
+
+    let cmd_outcome = StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    assert_fail_phase_reached(cmd_outcome, FailPhase::StateCurrent);
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_goal_fails_when_fail_phase_is_state_goal() -> Result<(), Box<dyn std::error::Error>>
+{
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+    } = test_env()?;
+    let flow = Flow::new(flow_id, graph);
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile)
+        .with_flow(&flow)
+        .with_item_params::<AlwaysFailItem<AlwaysFailTest>>(
+            AlwaysFailTest::ID.clone(),
+            AlwaysFailParams::<AlwaysFailTest>::new(Duration::ZERO, FailPhase::StateGoal).into(),
+        )
+        .await?;
+
+    let CmdOutcome::Complete { .. } = StatesDiscoverCmd::current(&mut cmd_ctx).await? else {
+        panic!("Expected `StatesDiscoverCmd::current` to complete successfully.");
+    };
+    let cmd_outcome = StatesDiscoverCmd::goal(&mut cmd_ctx).await?;
+
+    assert_fail_phase_reached(cmd_outcome, FailPhase::StateGoal);
+    Ok(())
+}
+
+#[tokio::test]
+async fn ensure_fails_when_fail_phase_is_apply_check() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+    } = test_env()?;
+    let flow = Flow::new(flow_id, graph);
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<AlwaysFailItem<AlwaysFailTest>>(
+            AlwaysFailTest::ID.clone(),
+            AlwaysFailParams::<AlwaysFailTest>::new(Duration::ZERO, FailPhase::ApplyCheck).into(),
+        )
+        .await?;
+
+    // Write current and goal states to disk.
+    let CmdOutcome::Complete { .. } = StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::current_and_goal` to complete successfully.");
+    };
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile)
+        .with_flow(&flow)
+        .await?;
+
+    let cmd_outcome = EnsureCmd::exec(&mut cmd_ctx).await?;
+
+    assert_fail_phase_reached(cmd_outcome, FailPhase::ApplyCheck);
+    Ok(())
+}
+
+#[tokio::test]
+async fn ensure_fails_when_fail_phase_is_apply() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+    } = test_env()?;
+    let flow = Flow::new(flow_id, graph);
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<AlwaysFailItem<AlwaysFailTest>>(
+            AlwaysFailTest::ID.clone(),
+            AlwaysFailParams::<AlwaysFailTest>::new(Duration::ZERO, FailPhase::Apply).into(),
+        )
+        .await?;
+
+    // Write current and goal states to disk.
+    let CmdOutcome::Complete { .. } = StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::current_and_goal` to complete successfully.");
+    };
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile)
+        .with_flow(&flow)
+        .await?;
+
+    let cmd_outcome = EnsureCmd::exec(&mut cmd_ctx).await?;
+
+    assert_fail_phase_reached(cmd_outcome, FailPhase::Apply);
+    Ok(())
+}
+
+#[test]
+fn error_help_suggests_changing_fail_phase() {
+    let error_help =
+        AlwaysFailItem::<AlwaysFailTest>::error_help(&AlwaysFailError::FailPhaseReached {
+            fail_phase: FailPhase::Apply,
+        });
+
+    assert_eq!(
+        vec![String::from(
+            "This item always fails; change its `fail_phase` parameter to fail in a different phase."
+        )],
+        error_help
+    );
+}
+
+#[test]
+fn fail_phase_display() {
+    assert_eq!("state_current", FailPhase::StateCurrent.to_string());
+    assert_eq!("state_goal", FailPhase::StateGoal.to_string());
+    assert_eq!("apply_check", FailPhase::ApplyCheck.to_string());
+    assert_eq!("apply", FailPhase::Apply.to_string());
+}
+
+fn assert_fail_phase_reached<T>(
+    cmd_outcome: CmdOutcome<T, AlwaysFailError>,
+    fail_phase_expected: FailPhase,
+) where
+    T: std::fmt::Debug,
+{
+    let CmdOutcome::ItemError { errors, .. } = cmd_outcome else {
+        panic!("Expected command to return an item error, but was: {cmd_outcome:?}");
+    };
+
+    let error = errors.get(AlwaysFailTest::ID);
+    assert!(
+        matches!(
+            error,
+            Some(AlwaysFailError::FailPhaseReached { fail_phase })
+            if *fail_phase == fail_phase_expected
+        ),
+        "Expected error to be `FailPhaseReached {{ fail_phase: {fail_phase_expected} }}`, but was: {error:?}"
+    );
+}
+
+fn test_env() -> Result<TestEnv, Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let profile = profile!("test_profile");
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<AlwaysFailError>::new();
+        graph_builder
+            .add_fn(AlwaysFailItem::<AlwaysFailTest>::new(AlwaysFailTest::ID.clone()).into());
+        graph_builder.build()
+    };
+    let output = InMemoryOutput::new();
+
+    Ok(TestEnv {
+        tempdir,
+        workspace,
+        profile,
+        graph,
+        output,
+    })
+}
+
+#[derive(Debug)]
+struct TestEnv {
+    tempdir: TempDir,
+    workspace: Workspace,
+    profile: Profile,
+    graph: ItemGraph<AlwaysFailError>,
+    output: InMemoryOutput,
+}
@@ -0,0 +1,78 @@
+use peace::cfg::{state::Generated, DiffSeverity, Item};
+use peace_items::aws_iam_role::{
+    model::ManagedPolicyAttachment, IamRoleItem, IamRoleState, IamRoleStateDiff, IamRoleStateDiffFn,
+};
+use pretty_assertions::assert_eq;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct IamRoleTest;
+
+#[tokio::test]
+async fn state_diff_detects_managed_policy_attachment_modified(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let role_detached = iam_role_state("role", "/", false);
+    let role_attached = iam_role_state("role", "/", true);
+
+    assert_eq!(
+        IamRoleStateDiff::InSyncExists,
+        IamRoleStateDiffFn::state_diff(&role_attached, &role_attached).await?
+    );
+    assert!(matches!(
+        IamRoleStateDiffFn::state_diff(&role_detached, &role_attached).await?,
+        IamRoleStateDiff::ManagedPolicyAttachmentModified { .. }
+    ));
+    assert_eq!(
+        IamRoleStateDiff::NameOrPathModified {
+            name_diff: None,
+            path_diff: Some((String::from("/"), String::from("/app/"))),
+        },
+        IamRoleStateDiffFn::state_diff(&role_attached, &iam_role_state("role", "/app/", true))
+            .await?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn diff_severity_is_destructive_when_role_removed_or_replaced() {
+    let state = IamRoleState::None;
+    let diff_severity = |state_diff: &IamRoleStateDiff| {
+        <IamRoleItem<IamRoleTest> as Item>::diff_severity(&state, &state, state_diff)
+    };
+
+    assert_eq!(
+        DiffSeverity::InSync,
+        diff_severity(&IamRoleStateDiff::InSyncExists)
+    );
+    assert_eq!(
+        DiffSeverity::Additive,
+        diff_severity(&IamRoleStateDiff::Added)
+    );
+    assert_eq!(
+        DiffSeverity::Additive,
+        diff_severity(&IamRoleStateDiff::ManagedPolicyAttachmentModified {
+            managed_policy_attachment_current: ManagedPolicyAttachment::new(Generated::Tbd, false),
+            managed_policy_attachment_goal: ManagedPolicyAttachment::new(Generated::Tbd, true),
+        })
+    );
+    assert_eq!(
+        DiffSeverity::Destructive,
+        diff_severity(&IamRoleStateDiff::Removed)
+    );
+    assert_eq!(
+        DiffSeverity::Destructive,
+        diff_severity(&IamRoleStateDiff::NameOrPathModified {
+            name_diff: Some((String::from("role_a"), String::from("role_b"))),
+            path_diff: None,
+        })
+    );
+}
+
+fn iam_role_state(name: &str, path: &str, attached: bool) -> IamRoleState {
+    IamRoleState::Some {
+        name: String::from(name),
+        path: String::from(path),
+        role_id_and_arn: Generated::Tbd,
+        managed_policy_attachment: ManagedPolicyAttachment::new(Generated::Tbd, attached),
+    }
+}
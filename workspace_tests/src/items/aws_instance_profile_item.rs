@@ -0,0 +1,70 @@
+use peace::cfg::{state::Generated, DiffSeverity, Item};
+use peace_items::aws_instance_profile::{
+    InstanceProfileItem, InstanceProfileState, InstanceProfileStateDiff, InstanceProfileStateDiffFn,
+};
+use pretty_assertions::assert_eq;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct InstanceProfileTest;
+
+#[tokio::test]
+async fn state_diff_detects_role_associated_modified() -> Result<(), Box<dyn std::error::Error>> {
+    let profile_unassociated = instance_profile_state("profile", false);
+    let profile_associated = instance_profile_state("profile", true);
+
+    assert_eq!(
+        InstanceProfileStateDiff::InSyncExists,
+        InstanceProfileStateDiffFn::state_diff(&profile_associated, &profile_associated).await?
+    );
+    assert_eq!(
+        InstanceProfileStateDiff::RoleAssociatedModified {
+            role_associated_current: false,
+            role_associated_goal: true,
+        },
+        InstanceProfileStateDiffFn::state_diff(&profile_unassociated, &profile_associated).await?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn diff_severity_is_destructive_when_instance_profile_removed_or_replaced() {
+    let state = InstanceProfileState::None;
+    let diff_severity = |state_diff: &InstanceProfileStateDiff| {
+        <InstanceProfileItem<InstanceProfileTest> as Item>::diff_severity(
+            &state, &state, state_diff,
+        )
+    };
+
+    assert_eq!(
+        DiffSeverity::InSync,
+        diff_severity(&InstanceProfileStateDiff::InSyncDoesNotExist)
+    );
+    assert_eq!(
+        DiffSeverity::Additive,
+        diff_severity(&InstanceProfileStateDiff::RoleAssociatedModified {
+            role_associated_current: true,
+            role_associated_goal: false,
+        })
+    );
+    assert_eq!(
+        DiffSeverity::Destructive,
+        diff_severity(&InstanceProfileStateDiff::Removed)
+    );
+    assert_eq!(
+        DiffSeverity::Destructive,
+        diff_severity(&InstanceProfileStateDiff::NameOrPathModified {
+            name_diff: None,
+            path_diff: Some((String::from("/"), String::from("/app/"))),
+        })
+    );
+}
+
+fn instance_profile_state(name: &str, role_associated: bool) -> InstanceProfileState {
+    InstanceProfileState::Some {
+        name: String::from(name),
+        path: String::from("/"),
+        instance_profile_id_and_arn: Generated::Tbd,
+        role_associated,
+    }
+}
@@ -0,0 +1,79 @@
+use peace::cfg::{state::Timestamped, DiffSeverity, Item};
+use peace_items::aws_s3_bucket::{
+    S3BucketItem, S3BucketState, S3BucketStateDiff, S3BucketStateDiffFn,
+};
+use pretty_assertions::assert_eq;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct S3BucketTest;
+
+#[tokio::test]
+async fn state_diff_detects_added_removed_and_renamed() -> Result<(), Box<dyn std::error::Error>> {
+    let bucket_a = s3_bucket_state("bucket-a");
+    let bucket_b = s3_bucket_state("bucket-b");
+
+    assert_eq!(
+        S3BucketStateDiff::InSyncDoesNotExist,
+        S3BucketStateDiffFn::state_diff(&S3BucketState::None, &S3BucketState::None).await?
+    );
+    assert_eq!(
+        S3BucketStateDiff::Added,
+        S3BucketStateDiffFn::state_diff(&S3BucketState::None, &bucket_a).await?
+    );
+    assert_eq!(
+        S3BucketStateDiff::Removed,
+        S3BucketStateDiffFn::state_diff(&bucket_a, &S3BucketState::None).await?
+    );
+    assert_eq!(
+        S3BucketStateDiff::InSyncExists,
+        S3BucketStateDiffFn::state_diff(&bucket_a, &bucket_a).await?
+    );
+    assert_eq!(
+        S3BucketStateDiff::NameModified {
+            s3_bucket_name_current: String::from("bucket-a"),
+            s3_bucket_name_goal: String::from("bucket-b"),
+        },
+        S3BucketStateDiffFn::state_diff(&bucket_a, &bucket_b).await?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn diff_severity_is_destructive_when_bucket_removed_or_renamed() {
+    let state = S3BucketState::None;
+    let diff_severity = |state_diff: &S3BucketStateDiff| {
+        <S3BucketItem<S3BucketTest> as Item>::diff_severity(&state, &state, state_diff)
+    };
+
+    assert_eq!(
+        DiffSeverity::InSync,
+        diff_severity(&S3BucketStateDiff::InSyncExists)
+    );
+    assert_eq!(
+        DiffSeverity::InSync,
+        diff_severity(&S3BucketStateDiff::InSyncDoesNotExist)
+    );
+    assert_eq!(
+        DiffSeverity::Additive,
+        diff_severity(&S3BucketStateDiff::Added)
+    );
+    assert_eq!(
+        DiffSeverity::Destructive,
+        diff_severity(&S3BucketStateDiff::Removed)
+    );
+    assert_eq!(
+        DiffSeverity::Destructive,
+        diff_severity(&S3BucketStateDiff::NameModified {
+            s3_bucket_name_current: String::from("bucket-a"),
+            s3_bucket_name_goal: String::from("bucket-b"),
+        })
+    );
+}
+
+fn s3_bucket_state(name: &str) -> S3BucketState {
+    S3BucketState::Some {
+        name: String::from(name),
+        creation_date: Timestamped::Tbd,
+    }
+}
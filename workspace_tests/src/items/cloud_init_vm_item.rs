@@ -0,0 +1,186 @@
+use peace::cfg::{item_id, DiffSeverity, Item, ItemId};
+use peace_items::cloud_init_vm::{
+    CloudInitVmError, CloudInitVmItem, CloudInitVmState, CloudInitVmStateDiff,
+    CloudInitVmStateDiffFn,
+};
+use pretty_assertions::assert_eq;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct CloudInitVmTest;
+
+impl CloudInitVmTest {
+    const ID: &'static ItemId = &item_id!("cloud_init_vm_test");
+}
+
+const IMAGE_VERSION: &str = "ubuntu-24.04-20240423";
+const USER_DATA: &str = "#cloud-config\npackages:\n  - nginx\n";
+
+fn state_exists(image_version: &str, user_data: &str) -> CloudInitVmState {
+    CloudInitVmState::new(
+        true,
+        Some(String::from(image_version)),
+        Some(CloudInitVmState::cloud_init_hash(user_data)),
+    )
+}
+
+#[test]
+fn clone() {
+    let _item = Clone::clone(&CloudInitVmItem::<()>::new(CloudInitVmTest::ID.clone()));
+}
+
+#[test]
+fn state_from_description_round_trips() {
+    let state = state_exists(IMAGE_VERSION, USER_DATA);
+
+    assert_eq!(
+        state,
+        CloudInitVmState::from_description(&state.description())
+    );
+}
+
+#[test]
+fn state_from_description_without_keys_has_unknown_values() {
+    assert_eq!(
+        CloudInitVmState::new(true, None, None),
+        CloudInitVmState::from_description("")
+    );
+}
+
+#[test]
+fn cloud_init_hash_differs_when_user_data_differs() {
+    assert_eq!(
+        CloudInitVmState::cloud_init_hash(USER_DATA),
+        CloudInitVmState::cloud_init_hash(USER_DATA)
+    );
+    assert_ne!(
+        CloudInitVmState::cloud_init_hash(USER_DATA),
+        CloudInitVmState::cloud_init_hash("#cloud-config\n")
+    );
+}
+
+#[tokio::test]
+async fn state_diff_is_in_sync_when_states_equal() -> Result<(), Box<dyn std::error::Error>> {
+    let state = state_exists(IMAGE_VERSION, USER_DATA);
+
+    let state_diff = CloudInitVmStateDiffFn::state_diff(&state, &state).await?;
+
+    assert!(state_diff.is_in_sync());
+    assert_eq!("in sync", state_diff.to_string());
+    assert_eq!(
+        DiffSeverity::InSync,
+        <CloudInitVmItem<CloudInitVmTest> as Item>::diff_severity(&state, &state, &state_diff)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_diff_returns_image_version_and_user_data_drift(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let state_current = state_exists(IMAGE_VERSION, USER_DATA);
+    let state_goal = state_exists("ubuntu-24.04-20240521", "#cloud-config\n");
+
+    let state_diff = CloudInitVmStateDiffFn::state_diff(&state_current, &state_goal).await?;
+
+    assert_eq!(
+        CloudInitVmStateDiff {
+            vm_exists: None,
+            image_version: Some((
+                Some(String::from(IMAGE_VERSION)),
+                Some(String::from("ubuntu-24.04-20240521"))
+            )),
+            cloud_init_hash: Some((
+                state_current.cloud_init_hash.clone(),
+                state_goal.cloud_init_hash.clone()
+            )),
+        },
+        state_diff
+    );
+    assert!(state_diff.is_recreate_required());
+    assert_eq!(
+        "vm will be recreated: \
+        image `ubuntu-24.04-20240423` -> `ubuntu-24.04-20240521`, \
+        user data will be modified",
+        state_diff.to_string()
+    );
+    assert_eq!(
+        DiffSeverity::Destructive,
+        <CloudInitVmItem<CloudInitVmTest> as Item>::diff_severity(
+            &state_current,
+            &state_goal,
+            &state_diff
+        )
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_diff_from_clean_creates_vm() -> Result<(), Box<dyn std::error::Error>> {
+    let state_current = CloudInitVmState::clean();
+    let state_goal = state_exists(IMAGE_VERSION, USER_DATA);
+
+    let state_diff = CloudInitVmStateDiffFn::state_diff(&state_current, &state_goal).await?;
+
+    assert!(state_diff.is_recreate_required());
+    assert_eq!("vm will be created", state_diff.to_string());
+    assert_eq!(
+        DiffSeverity::Additive,
+        <CloudInitVmItem<CloudInitVmTest> as Item>::diff_severity(
+            &state_current,
+            &state_goal,
+            &state_diff
+        )
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_diff_to_clean_removes_vm() -> Result<(), Box<dyn std::error::Error>> {
+    let state_current = state_exists(IMAGE_VERSION, USER_DATA);
+    let state_clean = CloudInitVmState::clean();
+
+    let state_diff = CloudInitVmStateDiffFn::state_diff(&state_current, &state_clean).await?;
+
+    assert!(!state_diff.is_recreate_required());
+    assert_eq!("vm will be removed", state_diff.to_string());
+    assert_eq!(
+        DiffSeverity::Destructive,
+        <CloudInitVmItem<CloudInitVmTest> as Item>::diff_severity(
+            &state_current,
+            &state_clean,
+            &state_diff
+        )
+    );
+
+    Ok(())
+}
+
+#[test]
+fn state_display() {
+    let state = state_exists(IMAGE_VERSION, USER_DATA);
+    let hash_short = &state.cloud_init_hash.as_deref().unwrap()[..12];
+
+    assert_eq!(
+        format!("vm running image `{IMAGE_VERSION}`, user data `{hash_short}`"),
+        state.to_string()
+    );
+    assert_eq!("vm does not exist", CloudInitVmState::clean().to_string());
+}
+
+#[test]
+fn error_help_suggests_checking_libvirt_when_virt_exec_fails() {
+    let error = CloudInitVmError::VirtExec {
+        program: "virsh",
+        args: String::from("dominfo cloud_init_vm_test"),
+        error: std::io::Error::new(std::io::ErrorKind::NotFound, "not found"),
+    };
+
+    assert_eq!(
+        vec![String::from(
+            "Check that `virsh` and `virt-install` are on the `PATH`."
+        )],
+        CloudInitVmItem::<CloudInitVmTest>::error_help(&error)
+    );
+}
@@ -0,0 +1,238 @@
+use std::collections::BTreeMap;
+
+use peace::cfg::{item_id, DiffSeverity, Item, ItemId};
+use peace_items::container::{
+    ContainerError, ContainerItem, ContainerState, ContainerStateDiff, ContainerStateDiffFn,
+    ContainerStatus,
+};
+use pretty_assertions::assert_eq;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ContainerTest;
+
+impl ContainerTest {
+    const ID: &'static ItemId = &item_id!("container_test");
+}
+
+const IMAGE: &str = "nginx:1.25";
+const IMAGE_DIGEST: &str = "sha256:0123456789abcdef0123456789abcdef";
+
+fn config_hash(image: &str) -> String {
+    ContainerState::config_hash(
+        image,
+        &BTreeMap::from([(String::from("KEY"), String::from("value"))]),
+        &[String::from("8080:80")],
+        &[],
+    )
+}
+
+fn state_running(image: &str, image_digest: &str) -> ContainerState {
+    ContainerState::new(
+        Some(String::from(image)),
+        Some(String::from(image_digest)),
+        Some(config_hash(image)),
+        ContainerStatus::Running,
+    )
+}
+
+#[test]
+fn clone() {
+    let _item = Clone::clone(&ContainerItem::<()>::new(ContainerTest::ID.clone()));
+}
+
+#[test]
+fn state_from_inspect_output() {
+    let config_hash = config_hash(IMAGE);
+    let output = format!("{IMAGE}|{IMAGE_DIGEST}|true|{config_hash}\n");
+
+    assert_eq!(
+        state_running(IMAGE, IMAGE_DIGEST),
+        ContainerState::from_inspect_output(Some(&output))
+    );
+    assert_eq!(
+        ContainerState::new(
+            Some(String::from(IMAGE)),
+            Some(String::from(IMAGE_DIGEST)),
+            None,
+            ContainerStatus::Stopped,
+        ),
+        ContainerState::from_inspect_output(Some(&format!(
+            "{IMAGE}|{IMAGE_DIGEST}|false|<no value>"
+        )))
+    );
+    assert_eq!(
+        ContainerState::clean(),
+        ContainerState::from_inspect_output(None)
+    );
+}
+
+#[test]
+fn config_hash_differs_when_config_differs() {
+    let env = BTreeMap::from([(String::from("KEY"), String::from("value"))]);
+    let ports = [String::from("8080:80")];
+
+    assert_eq!(config_hash(IMAGE), config_hash(IMAGE));
+    assert_ne!(
+        ContainerState::config_hash(IMAGE, &env, &ports, &[]),
+        ContainerState::config_hash(IMAGE, &env, &[], &ports)
+    );
+    assert_ne!(
+        ContainerState::config_hash(IMAGE, &env, &ports, &[]),
+        ContainerState::config_hash(IMAGE, &BTreeMap::new(), &ports, &[])
+    );
+}
+
+#[tokio::test]
+async fn state_diff_is_in_sync_when_states_equal() -> Result<(), Box<dyn std::error::Error>> {
+    let state = state_running(IMAGE, IMAGE_DIGEST);
+
+    let state_diff = ContainerStateDiffFn::state_diff(&state, &state).await?;
+
+    assert!(state_diff.is_in_sync());
+    assert_eq!("in sync", state_diff.to_string());
+    assert_eq!(
+        DiffSeverity::InSync,
+        <ContainerItem<ContainerTest> as Item>::diff_severity(&state, &state, &state_diff)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_diff_is_in_sync_when_goal_image_not_pulled() -> Result<(), Box<dyn std::error::Error>>
+{
+    let state_current = state_running(IMAGE, IMAGE_DIGEST);
+    let state_goal = ContainerState {
+        image_digest: None,
+        ..state_current.clone()
+    };
+
+    let state_diff = ContainerStateDiffFn::state_diff(&state_current, &state_goal).await?;
+
+    assert!(state_diff.is_in_sync());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_diff_returns_image_digest_drift() -> Result<(), Box<dyn std::error::Error>> {
+    let state_current = state_running(IMAGE, IMAGE_DIGEST);
+    let state_goal = state_running(IMAGE, "sha256:fedcba9876543210");
+
+    let state_diff = ContainerStateDiffFn::state_diff(&state_current, &state_goal).await?;
+
+    assert_eq!(
+        ContainerStateDiff {
+            image: Some((Some(String::from(IMAGE)), Some(String::from(IMAGE)))),
+            config_hash: None,
+            container_status: None,
+        },
+        state_diff
+    );
+    assert!(state_diff.is_recreate_required());
+    assert_eq!("image will be updated", state_diff.to_string());
+    assert_eq!(
+        DiffSeverity::Destructive,
+        <ContainerItem<ContainerTest> as Item>::diff_severity(
+            &state_current,
+            &state_goal,
+            &state_diff
+        )
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_diff_returns_image_and_config_drift() -> Result<(), Box<dyn std::error::Error>> {
+    let state_current = ContainerState {
+        container_status: ContainerStatus::Stopped,
+        ..state_running(IMAGE, IMAGE_DIGEST)
+    };
+    let state_goal = state_running("nginx:1.26", IMAGE_DIGEST);
+
+    let state_diff = ContainerStateDiffFn::state_diff(&state_current, &state_goal).await?;
+
+    assert_eq!(
+        ContainerStateDiff {
+            image: Some((Some(String::from(IMAGE)), Some(String::from("nginx:1.26")))),
+            config_hash: Some((Some(config_hash(IMAGE)), Some(config_hash("nginx:1.26")))),
+            container_status: Some((ContainerStatus::Stopped, ContainerStatus::Running)),
+        },
+        state_diff
+    );
+    assert!(state_diff.is_recreate_required());
+    assert_eq!(
+        "image `nginx:1.25` -> `nginx:1.26`, config will be modified, stopped -> running",
+        state_diff.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_diff_from_absent_creates_container() -> Result<(), Box<dyn std::error::Error>> {
+    let state_current = ContainerState::clean();
+    let state_goal = state_running(IMAGE, IMAGE_DIGEST);
+
+    let state_diff = ContainerStateDiffFn::state_diff(&state_current, &state_goal).await?;
+
+    assert!(state_diff.is_recreate_required());
+    assert_eq!("container will be created", state_diff.to_string());
+    assert_eq!(
+        DiffSeverity::Additive,
+        <ContainerItem<ContainerTest> as Item>::diff_severity(
+            &state_current,
+            &state_goal,
+            &state_diff
+        )
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_diff_to_clean_removes_container() -> Result<(), Box<dyn std::error::Error>> {
+    let state_current = state_running(IMAGE, IMAGE_DIGEST);
+    let state_clean = ContainerState::clean();
+
+    let state_diff = ContainerStateDiffFn::state_diff(&state_current, &state_clean).await?;
+
+    assert!(!state_diff.is_recreate_required());
+    assert_eq!("container will be removed", state_diff.to_string());
+    assert_eq!(
+        DiffSeverity::Destructive,
+        <ContainerItem<ContainerTest> as Item>::diff_severity(
+            &state_current,
+            &state_clean,
+            &state_diff
+        )
+    );
+
+    Ok(())
+}
+
+#[test]
+fn state_display() {
+    assert_eq!(
+        "`nginx:1.25` (`0123456789ab`), running",
+        state_running(IMAGE, IMAGE_DIGEST).to_string()
+    );
+    assert_eq!(
+        "container does not exist",
+        ContainerState::clean().to_string()
+    );
+}
+
+#[test]
+fn error_help_suggests_checking_docker_when_docker_exec_fails() {
+    let error = ContainerError::DockerExec {
+        args: String::from("inspect container_test"),
+        error: std::io::Error::new(std::io::ErrorKind::NotFound, "not found"),
+    };
+
+    assert_eq!(
+        vec![String::from("Check that `docker` is on the `PATH`.")],
+        ContainerItem::<ContainerTest>::error_help(&error)
+    );
+}
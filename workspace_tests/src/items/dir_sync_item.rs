@@ -0,0 +1,411 @@
+use std::path::PathBuf;
+
+use peace::{
+    cfg::{app_name, item_id, profile, DiffSeverity, FlowId, Item, ItemId, Profile},
+    cmd::ctx::CmdCtx,
+    cmd_model::CmdOutcome,
+    resources::paths::{FlowDir, ProfileDir},
+    rt::cmds::{CleanCmd, EnsureCmd, StatesDiscoverCmd},
+    rt_model::{Flow, InMemoryOutput, ItemGraph, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+use peace_items::dir_sync::{
+    DirSyncError, DirSyncItem, DirSyncParams, DirSyncStateDiff, FileHash, FileHashes,
+};
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct DirSyncTest;
+
+impl DirSyncTest {
+    const ID: &'static ItemId = &item_id!("dir_sync_test");
+}
+
+/// SHA-256 hash of `"hello"`, the contents of `a` in the source directory.
+const HELLO_SHA256: &str = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+/// SHA-256 hash of `"world"`, the contents of `sub/b` in the source directory.
+const WORLD_SHA256: &str = "486ea46224d1bb4fb680f34f7c9ad96a8f24ec88be73ea8e5a6c65260e9cb8a7";
+
+#[test]
+fn clone() {
+    let _item = Clone::clone(&DirSyncItem::<()>::new(DirSyncTest::ID.clone()));
+}
+
+#[tokio::test]
+async fn state_current_returns_empty_file_hashes_when_dest_not_exists()
+-> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+        src,
+        dest,
+    } = test_env(&flow_id).await?;
+    let flow = Flow::new(flow_id, graph);
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<DirSyncItem<DirSyncTest>>(
+            DirSyncTest::ID.clone(),
+            DirSyncParams::<DirSyncTest>::new(src, dest).into(),
+        )
+        .await?;
+
+    let CmdOutcome::Complete {
+        value: states_current,
+        cmd_blocks_processed: _,
+    } = StatesDiscoverCmd::current(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::current` to complete successfully.");
+    };
+    let state_current = states_current
+        .get::<FileHashes, _>(DirSyncTest::ID)
+        .unwrap();
+
+    assert_eq!(&FileHashes::default(), state_current);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_goal_returns_file_hashes_of_src_files_not_excluded()
+-> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+        src,
+        dest,
+    } = test_env(&flow_id).await?;
+    let flow = Flow::new(flow_id, graph);
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<DirSyncItem<DirSyncTest>>(
+            DirSyncTest::ID.clone(),
+            DirSyncParams::<DirSyncTest>::new(src, dest)
+                .with_exclude(vec![String::from("*.log")])
+                .into(),
+        )
+        .await?;
+
+    let CmdOutcome::Complete {
+        value: states_goal,
+        cmd_blocks_processed: _,
+    } = StatesDiscoverCmd::goal(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::goal` to complete successfully.");
+    };
+    let state_goal = states_goal.get::<FileHashes, _>(DirSyncTest::ID).unwrap();
+
+    assert_eq!(&file_hashes_src(), state_goal);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_goal_returns_file_hashes_of_src_files_included()
+-> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+        src,
+        dest,
+    } = test_env(&flow_id).await?;
+    let flow = Flow::new(flow_id, graph);
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<DirSyncItem<DirSyncTest>>(
+            DirSyncTest::ID.clone(),
+            DirSyncParams::<DirSyncTest>::new(src, dest)
+                .with_include(vec![String::from("sub/**")])
+                .into(),
+        )
+        .await?;
+
+    let CmdOutcome::Complete {
+        value: states_goal,
+        cmd_blocks_processed: _,
+    } = StatesDiscoverCmd::goal(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::goal` to complete successfully.");
+    };
+    let state_goal = states_goal.get::<FileHashes, _>(DirSyncTest::ID).unwrap();
+
+    assert_eq!(
+        &FileHashes::from(vec![FileHash::new(
+            PathBuf::from("sub").join("b"),
+            String::from(WORLD_SHA256),
+        )]),
+        state_goal
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn ensure_copies_files_and_is_idempotent() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+        src,
+        dest,
+    } = test_env(&flow_id).await?;
+    let flow = Flow::new(flow_id, graph);
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<DirSyncItem<DirSyncTest>>(
+            DirSyncTest::ID.clone(),
+            DirSyncParams::<DirSyncTest>::new(src, dest.clone())
+                .with_exclude(vec![String::from("*.log")])
+                .into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let CmdOutcome::Complete {
+        value: states_ensured,
+        cmd_blocks_processed: _,
+    } = EnsureCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec` to complete successfully.");
+    };
+    let state_ensured = states_ensured
+        .get::<FileHashes, _>(DirSyncTest::ID)
+        .unwrap();
+
+    assert_eq!(&file_hashes_src(), state_ensured);
+    assert_eq!("hello", tokio::fs::read_to_string(dest.join("a")).await?);
+    assert_eq!(
+        "world",
+        tokio::fs::read_to_string(dest.join("sub").join("b")).await?
+    );
+    assert!(!dest.join("skip.log").exists());
+
+    // Execute again to check idempotence
+    let CmdOutcome::Complete {
+        value: states_ensured,
+        cmd_blocks_processed: _,
+    } = EnsureCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec` to complete successfully.");
+    };
+    let state_ensured = states_ensured
+        .get::<FileHashes, _>(DirSyncTest::ID)
+        .unwrap();
+
+    assert_eq!(&file_hashes_src(), state_ensured);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn ensure_overwrites_modified_files_and_removes_other_files()
+-> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+        src,
+        dest,
+    } = test_env(&flow_id).await?;
+    let flow = Flow::new(flow_id, graph);
+
+    // Create files in the destination.
+    tokio::fs::create_dir_all(&dest).await?;
+    tokio::fs::write(dest.join("a"), "changed").await?;
+    tokio::fs::write(dest.join("extra"), "extra").await?;
+    tokio::fs::write(dest.join("keep.log"), "keep").await?;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<DirSyncItem<DirSyncTest>>(
+            DirSyncTest::ID.clone(),
+            DirSyncParams::<DirSyncTest>::new(src, dest.clone())
+                .with_exclude(vec![String::from("*.log")])
+                .into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let CmdOutcome::Complete {
+        value: states_ensured,
+        cmd_blocks_processed: _,
+    } = EnsureCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec` to complete successfully.");
+    };
+    let state_ensured = states_ensured
+        .get::<FileHashes, _>(DirSyncTest::ID)
+        .unwrap();
+
+    assert_eq!(&file_hashes_src(), state_ensured);
+    assert_eq!("hello", tokio::fs::read_to_string(dest.join("a")).await?);
+    assert!(!dest.join("extra").exists());
+    // Excluded files in the destination directory are left untouched.
+    assert!(dest.join("keep.log").exists());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn clean_removes_copied_files() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+        src,
+        dest,
+    } = test_env(&flow_id).await?;
+    let flow = Flow::new(flow_id, graph);
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<DirSyncItem<DirSyncTest>>(
+            DirSyncTest::ID.clone(),
+            DirSyncParams::<DirSyncTest>::new(src.clone(), dest.clone()).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    EnsureCmd::exec(&mut cmd_ctx).await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    let CmdOutcome::Complete {
+        value: states_cleaned,
+        cmd_blocks_processed: _,
+    } = CleanCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `CleanCmd::exec` to complete successfully.");
+    };
+    let state_cleaned = states_cleaned
+        .get::<FileHashes, _>(DirSyncTest::ID)
+        .unwrap();
+
+    assert_eq!(&FileHashes::default(), state_cleaned);
+    assert!(!dest.join("a").exists());
+    assert!(!dest.join("sub").join("b").exists());
+    assert!(src.join("a").exists());
+
+    Ok(())
+}
+
+#[test]
+fn diff_severity_is_destructive_when_files_removed() {
+    let state = FileHashes::default();
+    let file_hashes = || {
+        FileHashes::from(vec![FileHash::new(
+            PathBuf::from("a"),
+            String::from(HELLO_SHA256),
+        )])
+    };
+
+    let state_diff_in_sync = DirSyncStateDiff::InSync;
+    let state_diff_added = DirSyncStateDiff::OutOfSync {
+        added: file_hashes(),
+        modified: FileHashes::default(),
+        removed: FileHashes::default(),
+    };
+    let state_diff_removed = DirSyncStateDiff::OutOfSync {
+        added: FileHashes::default(),
+        modified: FileHashes::default(),
+        removed: file_hashes(),
+    };
+
+    assert_eq!(
+        DiffSeverity::InSync,
+        <DirSyncItem<DirSyncTest> as Item>::diff_severity(&state, &state, &state_diff_in_sync)
+    );
+    assert_eq!(
+        DiffSeverity::Additive,
+        <DirSyncItem<DirSyncTest> as Item>::diff_severity(&state, &state, &state_diff_added)
+    );
+    assert_eq!(
+        DiffSeverity::Destructive,
+        <DirSyncItem<DirSyncTest> as Item>::diff_severity(&state, &state, &state_diff_removed)
+    );
+}
+
+/// Returns the hashes of `a` and `sub/b` in the source directory.
+fn file_hashes_src() -> FileHashes {
+    FileHashes::from(vec![
+        FileHash::new(PathBuf::from("a"), String::from(HELLO_SHA256)),
+        FileHash::new(PathBuf::from("sub").join("b"), String::from(WORLD_SHA256)),
+    ])
+}
+
+async fn test_env(flow_id: &FlowId) -> Result<TestEnv, Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let profile = profile!("test_profile");
+    let flow_dir = {
+        let profile_dir = ProfileDir::from((workspace.dirs().peace_app_dir(), &profile));
+        FlowDir::from((&profile_dir, flow_id))
+    };
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<DirSyncError>::new();
+        graph_builder.add_fn(DirSyncItem::<DirSyncTest>::new(DirSyncTest::ID.clone()).into());
+        graph_builder.build()
+    };
+    let output = InMemoryOutput::new();
+    let src = {
+        let src = flow_dir.join("dir_sync_src");
+        tokio::fs::create_dir_all(src.join("sub")).await?;
+        tokio::fs::write(src.join("a"), "hello").await?;
+        tokio::fs::write(src.join("sub").join("b"), "world").await?;
+        tokio::fs::write(src.join("skip.log"), "skip").await?;
+        src
+    };
+    let dest = flow_dir.join("dir_sync_dest");
+
+    Ok(TestEnv {
+        tempdir,
+        workspace,
+        profile,
+        graph,
+        output,
+        src,
+        dest,
+    })
+}
+
+#[derive(Debug)]
+struct TestEnv {
+    tempdir: TempDir,
+    workspace: Workspace,
+    profile: Profile,
+    graph: ItemGraph<DirSyncError>,
+    output: InMemoryOutput,
+    src: PathBuf,
+    dest: PathBuf,
+}
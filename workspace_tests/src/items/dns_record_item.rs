@@ -0,0 +1,404 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+
+use peace::{
+    cfg::{app_name, async_trait, item_id, profile, DiffSeverity, FlowId, Item, ItemId, Profile},
+    cmd::ctx::CmdCtx,
+    cmd_model::CmdOutcome,
+    rt::cmds::{CleanCmd, EnsureCmd, StatesDiscoverCmd},
+    rt_model::{Flow, InMemoryOutput, ItemGraph, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+use peace_items::dns_record::{
+    DnsProvider, DnsProviderError, DnsRecordError, DnsRecordItem, DnsRecordParams, DnsRecordSet,
+    DnsRecordState, DnsRecordStateDiff, DnsRecordType,
+};
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct DnsRecordTest;
+
+impl DnsRecordTest {
+    const ID: &'static ItemId = &item_id!("dns_record_test");
+}
+
+const ZONE_ID: &str = "Z0123456789";
+
+#[test]
+fn clone() {
+    let dns_provider: Arc<dyn DnsProvider> = Arc::new(InMemoryDnsProvider::default());
+    let _item = Clone::clone(&DnsRecordItem::<()>::new(
+        DnsRecordTest::ID.clone(),
+        dns_provider,
+    ));
+}
+
+#[test]
+fn record_set_new_normalizes_name_and_sorts_values() {
+    let record_set = DnsRecordSet::new(
+        "WWW.Example.com.",
+        DnsRecordType::A,
+        300,
+        vec![
+            String::from("192.0.2.2"),
+            String::from("192.0.2.1"),
+            String::from("192.0.2.2"),
+        ],
+    );
+
+    assert_eq!("www.example.com", record_set.name());
+    assert_eq!(
+        &[String::from("192.0.2.1"), String::from("192.0.2.2")],
+        record_set.values()
+    );
+    assert_eq!(
+        "`www.example.com` A (ttl 300): 192.0.2.1, 192.0.2.2",
+        record_set.to_string()
+    );
+}
+
+#[tokio::test]
+async fn ensure_creates_record_set() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let dns_provider = Arc::new(InMemoryDnsProvider::default());
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+    } = test_env(Arc::clone(&dns_provider))?;
+    let flow = Flow::new(flow_id, graph);
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<DnsRecordItem<DnsRecordTest>>(
+            DnsRecordTest::ID.clone(),
+            dns_record_params(300, &["192.0.2.1"]).into(),
+        )
+        .await?;
+
+    let CmdOutcome::Complete {
+        value: (states_current, _states_goal),
+        cmd_blocks_processed: _,
+    } = StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::current_and_goal` to complete successfully.");
+    };
+    assert_eq!(
+        Some(&DnsRecordState::None),
+        states_current.get::<DnsRecordState, _>(DnsRecordTest::ID)
+    );
+
+    let CmdOutcome::Complete {
+        value: states_ensured,
+        cmd_blocks_processed: _,
+    } = EnsureCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec` to complete successfully.");
+    };
+    let record_set_expected = record_set(300, &["192.0.2.1"]);
+
+    assert_eq!(
+        Some(&DnsRecordState::Some(record_set_expected.clone())),
+        states_ensured.get::<DnsRecordState, _>(DnsRecordTest::ID)
+    );
+    assert_eq!(
+        vec![record_set_expected.clone()],
+        dns_provider.record_sets()
+    );
+
+    // Execute again to check idempotence
+    let CmdOutcome::Complete {
+        value: states_ensured,
+        cmd_blocks_processed: _,
+    } = EnsureCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec` to complete successfully.");
+    };
+
+    assert_eq!(
+        Some(&DnsRecordState::Some(record_set_expected.clone())),
+        states_ensured.get::<DnsRecordState, _>(DnsRecordTest::ID)
+    );
+    assert_eq!(1, dns_provider.upsert_count());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn ensure_updates_ttl_and_values_of_existing_record_set(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let dns_provider = Arc::new(InMemoryDnsProvider::default());
+    dns_provider.record_set_insert(ZONE_ID, record_set(3600, &["192.0.2.1"]));
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+    } = test_env(Arc::clone(&dns_provider))?;
+    let flow = Flow::new(flow_id, graph);
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<DnsRecordItem<DnsRecordTest>>(
+            DnsRecordTest::ID.clone(),
+            dns_record_params(60, &["192.0.2.2", "192.0.2.3"]).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let CmdOutcome::Complete {
+        value: states_ensured,
+        cmd_blocks_processed: _,
+    } = EnsureCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec` to complete successfully.");
+    };
+    let record_set_expected = record_set(60, &["192.0.2.2", "192.0.2.3"]);
+
+    assert_eq!(
+        Some(&DnsRecordState::Some(record_set_expected.clone())),
+        states_ensured.get::<DnsRecordState, _>(DnsRecordTest::ID)
+    );
+    assert_eq!(vec![record_set_expected], dns_provider.record_sets());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn clean_deletes_record_set() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let dns_provider = Arc::new(InMemoryDnsProvider::default());
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+    } = test_env(Arc::clone(&dns_provider))?;
+    let flow = Flow::new(flow_id, graph);
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<DnsRecordItem<DnsRecordTest>>(
+            DnsRecordTest::ID.clone(),
+            dns_record_params(300, &["192.0.2.1"]).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    EnsureCmd::exec(&mut cmd_ctx).await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    let CmdOutcome::Complete {
+        value: states_cleaned,
+        cmd_blocks_processed: _,
+    } = CleanCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `CleanCmd::exec` to complete successfully.");
+    };
+
+    assert_eq!(
+        Some(&DnsRecordState::None),
+        states_cleaned.get::<DnsRecordState, _>(DnsRecordTest::ID)
+    );
+    assert_eq!(Vec::<DnsRecordSet>::new(), dns_provider.record_sets());
+
+    Ok(())
+}
+
+#[test]
+fn diff_severity_is_destructive_when_values_replaced_or_record_set_deleted() {
+    let state = DnsRecordState::None;
+    let state_diff_in_sync = DnsRecordStateDiff {
+        exists: None,
+        ttl: None,
+        values: None,
+    };
+    let state_diff_created = DnsRecordStateDiff {
+        exists: Some((false, true)),
+        ttl: Some((None, Some(300))),
+        values: Some((Vec::new(), vec![String::from("192.0.2.1")])),
+    };
+    let state_diff_ttl_changed = DnsRecordStateDiff {
+        exists: None,
+        ttl: Some((Some(3600), Some(300))),
+        values: None,
+    };
+    let state_diff_values_replaced = DnsRecordStateDiff {
+        exists: None,
+        ttl: None,
+        values: Some((
+            vec![String::from("192.0.2.1")],
+            vec![String::from("192.0.2.2")],
+        )),
+    };
+    let state_diff_deleted = DnsRecordStateDiff {
+        exists: Some((true, false)),
+        ttl: Some((Some(300), None)),
+        values: Some((vec![String::from("192.0.2.1")], Vec::new())),
+    };
+
+    [
+        (DiffSeverity::InSync, state_diff_in_sync),
+        (DiffSeverity::Additive, state_diff_created),
+        (DiffSeverity::Additive, state_diff_ttl_changed),
+        (DiffSeverity::Destructive, state_diff_values_replaced),
+        (DiffSeverity::Destructive, state_diff_deleted),
+    ]
+    .into_iter()
+    .for_each(|(diff_severity_expected, state_diff)| {
+        assert_eq!(
+            diff_severity_expected,
+            <DnsRecordItem<DnsRecordTest> as Item>::diff_severity(&state, &state, &state_diff),
+            "state_diff: {state_diff:?}"
+        );
+    });
+}
+
+#[test]
+fn state_diff_display_lists_changed_fields() {
+    let state_diff = DnsRecordStateDiff {
+        exists: None,
+        ttl: Some((Some(3600), Some(300))),
+        values: Some((
+            vec![String::from("192.0.2.1")],
+            vec![String::from("192.0.2.2"), String::from("192.0.2.3")],
+        )),
+    };
+
+    assert_eq!(
+        "record set will be updated: ttl 3600 -> 300, \
+        values [192.0.2.1] -> [192.0.2.2, 192.0.2.3]",
+        state_diff.to_string()
+    );
+}
+
+fn dns_record_params(ttl: u32, values: &[&str]) -> DnsRecordParams<DnsRecordTest> {
+    DnsRecordParams::<DnsRecordTest>::new(
+        String::from(ZONE_ID),
+        String::from("www.example.com"),
+        DnsRecordType::A,
+        ttl,
+        values.iter().map(|value| String::from(*value)).collect(),
+    )
+}
+
+fn record_set(ttl: u32, values: &[&str]) -> DnsRecordSet {
+    DnsRecordSet::new(
+        "www.example.com",
+        DnsRecordType::A,
+        ttl,
+        values.iter().map(|value| String::from(*value)).collect(),
+    )
+}
+
+fn test_env(dns_provider: Arc<InMemoryDnsProvider>) -> Result<TestEnv, Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let profile = profile!("test_profile");
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<DnsRecordError>::new();
+        graph_builder.add_fn(
+            DnsRecordItem::<DnsRecordTest>::new(DnsRecordTest::ID.clone(), dns_provider).into(),
+        );
+        graph_builder.build()
+    };
+    let output = InMemoryOutput::new();
+
+    Ok(TestEnv {
+        tempdir,
+        workspace,
+        profile,
+        graph,
+        output,
+    })
+}
+
+#[derive(Debug)]
+struct TestEnv {
+    tempdir: TempDir,
+    workspace: Workspace,
+    profile: Profile,
+    graph: ItemGraph<DnsRecordError>,
+    output: InMemoryOutput,
+}
+
+/// `DnsProvider` that stores record sets in memory.
+#[derive(Debug, Default)]
+struct InMemoryDnsProvider {
+    /// Record sets by zone ID, name, and type.
+    record_sets: Mutex<BTreeMap<(String, String, DnsRecordType), DnsRecordSet>>,
+    /// Number of times `record_set_upsert` was called.
+    upsert_count: Mutex<usize>,
+}
+
+impl InMemoryDnsProvider {
+    fn record_set_insert(&self, zone_id: &str, record_set: DnsRecordSet) {
+        let key = (
+            zone_id.to_string(),
+            record_set.name().to_string(),
+            record_set.record_type(),
+        );
+        self.record_sets.lock().unwrap().insert(key, record_set);
+    }
+
+    fn record_sets(&self) -> Vec<DnsRecordSet> {
+        self.record_sets.lock().unwrap().values().cloned().collect()
+    }
+
+    fn upsert_count(&self) -> usize {
+        *self.upsert_count.lock().unwrap()
+    }
+}
+
+#[async_trait]
+impl DnsProvider for InMemoryDnsProvider {
+    async fn record_set_get(
+        &self,
+        zone_id: &str,
+        name: &str,
+        record_type: DnsRecordType,
+    ) -> Result<Option<DnsRecordSet>, DnsProviderError> {
+        let key = (
+            zone_id.to_string(),
+            DnsRecordSet::name_normalize(name),
+            record_type,
+        );
+        Ok(self.record_sets.lock().unwrap().get(&key).cloned())
+    }
+
+    async fn record_set_upsert(
+        &self,
+        zone_id: &str,
+        record_set: &DnsRecordSet,
+    ) -> Result<(), DnsProviderError> {
+        self.record_set_insert(zone_id, record_set.clone());
+        *self.upsert_count.lock().unwrap() += 1;
+        Ok(())
+    }
+
+    async fn record_set_delete(
+        &self,
+        zone_id: &str,
+        record_set: &DnsRecordSet,
+    ) -> Result<(), DnsProviderError> {
+        let key = (
+            zone_id.to_string(),
+            record_set.name().to_string(),
+            record_set.record_type(),
+        );
+        self.record_sets.lock().unwrap().remove(&key);
+        Ok(())
+    }
+}
@@ -0,0 +1,322 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use peace::{
+    cfg::{app_name, item_id, profile, DiffSeverity, FlowId, Item, ItemId, Profile},
+    cmd::ctx::CmdCtx,
+    cmd_model::CmdOutcome,
+    rt::cmds::{CleanCmd, EnsureCmd, StatesDiscoverCmd},
+    rt_model::{Flow, InMemoryOutput, ItemGraph, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+use peace_items::env_file::{
+    EnvFile, EnvFileError, EnvFileItem, EnvFileParams, EnvFileState, EnvFileStateDiff,
+};
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct EnvFileTest;
+
+impl EnvFileTest {
+    const ID: &'static ItemId = &item_id!("env_file_test");
+}
+
+const ENV_FILE_CONTENTS: &str = "\
+# Local development settings.
+UNMANAGED=keep
+export DATABASE_URL=postgres://localhost/old
+
+LOG_LEVEL=info # inline comment
+";
+
+#[test]
+fn clone() {
+    let _item = Clone::clone(&EnvFileItem::<()>::new(EnvFileTest::ID.clone()));
+}
+
+#[test]
+fn env_file_parse_reads_entries_and_unquotes_values() {
+    let env_file = EnvFile::parse(
+        "A=1\nexport B='two words'\nC=\"line\\none \\\"quoted\\\"\"\n# D=4\nnot an entry\nA=5\n",
+    );
+
+    assert_eq!(Some("5"), env_file.get("A"));
+    assert_eq!(Some("two words"), env_file.get("B"));
+    assert_eq!(Some("line\none \"quoted\""), env_file.get("C"));
+    assert_eq!(None, env_file.get("D"));
+}
+
+#[test]
+fn env_file_set_and_remove_preserve_other_lines() {
+    let mut env_file = EnvFile::parse(ENV_FILE_CONTENTS);
+
+    env_file.set("DATABASE_URL", "postgres://localhost/new");
+    env_file.set("GREETING", "hello world");
+    env_file.remove("LOG_LEVEL");
+
+    assert_eq!(
+        "\
+# Local development settings.
+UNMANAGED=keep
+DATABASE_URL=postgres://localhost/new
+
+GREETING=\"hello world\"
+",
+        env_file.to_string()
+    );
+    assert_eq!(
+        Some("hello world"),
+        EnvFile::parse(&env_file.to_string()).get("GREETING")
+    );
+}
+
+#[tokio::test]
+async fn state_current_returns_managed_keys_present_in_file(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+        env_file_path,
+    } = test_env().await?;
+    let flow = Flow::new(flow_id, graph);
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<EnvFileItem<EnvFileTest>>(
+            EnvFileTest::ID.clone(),
+            EnvFileParams::<EnvFileTest>::new(env_file_path, entries_managed()).into(),
+        )
+        .await?;
+
+    let CmdOutcome::Complete {
+        value: states_current,
+        cmd_blocks_processed: _,
+    } = StatesDiscoverCmd::current(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::current` to complete successfully.");
+    };
+    let state_current = states_current
+        .get::<EnvFileState, _>(EnvFileTest::ID)
+        .unwrap();
+
+    assert_eq!(
+        &EnvFileState::new(BTreeMap::from([
+            (
+                String::from("DATABASE_URL"),
+                String::from("postgres://localhost/old")
+            ),
+            (String::from("LOG_LEVEL"), String::from("info")),
+        ])),
+        state_current
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn ensure_sets_managed_keys_and_preserves_unmanaged_keys(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+        env_file_path,
+    } = test_env().await?;
+    let flow = Flow::new(flow_id, graph);
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<EnvFileItem<EnvFileTest>>(
+            EnvFileTest::ID.clone(),
+            EnvFileParams::<EnvFileTest>::new(env_file_path.clone(), entries_managed()).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let CmdOutcome::Complete {
+        value: states_ensured,
+        cmd_blocks_processed: _,
+    } = EnsureCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec` to complete successfully.");
+    };
+    let state_ensured = states_ensured
+        .get::<EnvFileState, _>(EnvFileTest::ID)
+        .unwrap();
+
+    assert_eq!(&EnvFileState::new(entries_managed()), state_ensured);
+    assert_eq!(
+        "\
+# Local development settings.
+UNMANAGED=keep
+DATABASE_URL=postgres://localhost/new
+
+LOG_LEVEL=info # inline comment
+API_KEY=\"abc 123\"
+",
+        tokio::fs::read_to_string(&env_file_path).await?
+    );
+
+    // Execute again to check idempotence
+    let CmdOutcome::Complete {
+        value: states_ensured,
+        cmd_blocks_processed: _,
+    } = EnsureCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec` to complete successfully.");
+    };
+    let state_ensured = states_ensured
+        .get::<EnvFileState, _>(EnvFileTest::ID)
+        .unwrap();
+
+    assert_eq!(&EnvFileState::new(entries_managed()), state_ensured);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn clean_removes_managed_keys_only() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+        env_file_path,
+    } = test_env().await?;
+    let flow = Flow::new(flow_id, graph);
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<EnvFileItem<EnvFileTest>>(
+            EnvFileTest::ID.clone(),
+            EnvFileParams::<EnvFileTest>::new(env_file_path.clone(), entries_managed()).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    EnsureCmd::exec(&mut cmd_ctx).await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    let CmdOutcome::Complete {
+        value: states_cleaned,
+        cmd_blocks_processed: _,
+    } = CleanCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `CleanCmd::exec` to complete successfully.");
+    };
+    let state_cleaned = states_cleaned
+        .get::<EnvFileState, _>(EnvFileTest::ID)
+        .unwrap();
+
+    assert_eq!(&EnvFileState::default(), state_cleaned);
+    assert_eq!(
+        "\
+# Local development settings.
+UNMANAGED=keep
+
+",
+        tokio::fs::read_to_string(&env_file_path).await?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn diff_severity_is_destructive_when_keys_removed() {
+    let state = EnvFileState::default();
+    let state_diff_in_sync = EnvFileStateDiff::default();
+    let state_diff_added = EnvFileStateDiff {
+        added: BTreeMap::from([(String::from("A"), String::from("1"))]),
+        ..Default::default()
+    };
+    let state_diff_removed = EnvFileStateDiff {
+        removed: BTreeMap::from([(String::from("A"), String::from("1"))]),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        DiffSeverity::InSync,
+        <EnvFileItem<EnvFileTest> as Item>::diff_severity(&state, &state, &state_diff_in_sync)
+    );
+    assert_eq!(
+        DiffSeverity::Additive,
+        <EnvFileItem<EnvFileTest> as Item>::diff_severity(&state, &state, &state_diff_added)
+    );
+    assert_eq!(
+        DiffSeverity::Destructive,
+        <EnvFileItem<EnvFileTest> as Item>::diff_severity(&state, &state, &state_diff_removed)
+    );
+}
+
+#[test]
+fn state_diff_display_lists_keys_without_values() {
+    let state_diff = EnvFileStateDiff {
+        added: BTreeMap::from([(String::from("A"), String::from("secret_a"))]),
+        modified: BTreeMap::from([(
+            String::from("B"),
+            (String::from("secret_b0"), String::from("secret_b1")),
+        )]),
+        removed: BTreeMap::from([(String::from("C"), String::from("secret_c"))]),
+    };
+
+    assert_eq!("+A, ~B, -C", state_diff.to_string());
+    assert_eq!("in sync", EnvFileStateDiff::default().to_string());
+}
+
+/// Returns the keys managed in tests.
+fn entries_managed() -> BTreeMap<String, String> {
+    BTreeMap::from([
+        (String::from("API_KEY"), String::from("abc 123")),
+        (
+            String::from("DATABASE_URL"),
+            String::from("postgres://localhost/new"),
+        ),
+        (String::from("LOG_LEVEL"), String::from("info")),
+    ])
+}
+
+async fn test_env() -> Result<TestEnv, Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let profile = profile!("test_profile");
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<EnvFileError>::new();
+        graph_builder.add_fn(EnvFileItem::<EnvFileTest>::new(EnvFileTest::ID.clone()).into());
+        graph_builder.build()
+    };
+    let output = InMemoryOutput::new();
+    let env_file_path = tempdir.path().join(".env");
+    tokio::fs::write(&env_file_path, ENV_FILE_CONTENTS).await?;
+
+    Ok(TestEnv {
+        tempdir,
+        workspace,
+        profile,
+        graph,
+        output,
+        env_file_path,
+    })
+}
+
+#[derive(Debug)]
+struct TestEnv {
+    tempdir: TempDir,
+    workspace: Workspace,
+    profile: Profile,
+    graph: ItemGraph<EnvFileError>,
+    output: InMemoryOutput,
+    env_file_path: PathBuf,
+}
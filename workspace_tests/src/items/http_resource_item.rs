@@ -0,0 +1,486 @@
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::get,
+    Json, Router,
+};
+use peace::{
+    cfg::{app_name, item_id, profile, FlowId, ItemId, Profile},
+    cmd::ctx::CmdCtx,
+    cmd_model::CmdOutcome,
+    rt::cmds::{CleanCmd, EnsureCmd, StatesDiscoverCmd},
+    rt_model::{Flow, InMemoryOutput, ItemGraph, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+use peace_items::http_resource::{
+    HttpResourceApplyMethod, HttpResourceAuthHeader, HttpResourceError, HttpResourceFieldDiff,
+    HttpResourceItem, HttpResourceParams, HttpResourceState, HttpResourceStateDiff,
+    HttpResourceStateDiffFn,
+};
+use pretty_assertions::assert_eq;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tokio::net::TcpListener;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct HttpResourceTest;
+
+impl HttpResourceTest {
+    const ID: &'static ItemId = &item_id!("http_resource_test");
+}
+
+const TOKEN: &str = "test_token";
+
+#[test]
+fn clone() {
+    let _item = Clone::clone(&HttpResourceItem::<()>::new(HttpResourceTest::ID.clone()));
+}
+
+#[tokio::test]
+async fn state_current_returns_not_exists_when_resource_not_found(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+    } = test_env()?;
+    let flow = Flow::new(flow_id, graph);
+    let test_server = TestServer::launch(None).await?;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile)
+        .with_flow(&flow)
+        .with_item_params::<HttpResourceItem<HttpResourceTest>>(
+            HttpResourceTest::ID.clone(),
+            http_resource_params(&test_server, json!({ "name": "a" }), Vec::new()).into(),
+        )
+        .await?;
+
+    let CmdOutcome::Complete {
+        value: states_current,
+        cmd_blocks_processed: _,
+    } = StatesDiscoverCmd::current(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::current` to complete successfully.");
+    };
+    let state_current = states_current
+        .get::<HttpResourceState, _>(HttpResourceTest::ID)
+        .unwrap();
+
+    assert_eq!(&HttpResourceState::NotExists, state_current);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn ensure_creates_resource_and_ignores_server_generated_fields(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+    } = test_env()?;
+    let flow = Flow::new(flow_id, graph);
+    let test_server = TestServer::launch(Some(TOKEN)).await?;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile)
+        .with_flow(&flow)
+        .with_item_params::<HttpResourceItem<HttpResourceTest>>(
+            HttpResourceTest::ID.clone(),
+            http_resource_params(&test_server, json!({ "name": "a" }), Vec::new()).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let CmdOutcome::Complete {
+        value: states_ensured,
+        cmd_blocks_processed: _,
+    } = EnsureCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec` to complete successfully.");
+    };
+    let state_ensured = states_ensured
+        .get::<HttpResourceState, _>(HttpResourceTest::ID)
+        .unwrap();
+    assert_eq!(
+        &HttpResourceState::Exists {
+            body: json!({ "id": 1, "name": "a" })
+        },
+        state_ensured
+    );
+
+    // The server generated `id` field does not cause the resource to be
+    // reapplied.
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    EnsureCmd::exec(&mut cmd_ctx).await?;
+
+    let put_count = test_server
+        .requests()
+        .into_iter()
+        .filter(|method| *method == "PUT")
+        .count();
+    assert_eq!(1, put_count);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn ensure_updates_resource_when_field_differs() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+    } = test_env()?;
+    let flow = Flow::new(flow_id, graph);
+    let test_server = TestServer::launch(Some(TOKEN)).await?;
+    test_server.resource_set(json!({ "name": "a", "replicas": 1 }));
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile)
+        .with_flow(&flow)
+        .with_item_params::<HttpResourceItem<HttpResourceTest>>(
+            HttpResourceTest::ID.clone(),
+            http_resource_params(
+                &test_server,
+                json!({ "name": "a", "replicas": 2 }),
+                Vec::new(),
+            )
+            .into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    EnsureCmd::exec(&mut cmd_ctx).await?;
+
+    assert_eq!(
+        Some(json!({ "id": 1, "name": "a", "replicas": 2 })),
+        test_server.resource()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn clean_deletes_resource() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+    } = test_env()?;
+    let flow = Flow::new(flow_id, graph);
+    let test_server = TestServer::launch(Some(TOKEN)).await?;
+    test_server.resource_set(json!({ "name": "a" }));
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile)
+        .with_flow(&flow)
+        .with_item_params::<HttpResourceItem<HttpResourceTest>>(
+            HttpResourceTest::ID.clone(),
+            http_resource_params(&test_server, json!({ "name": "a" }), Vec::new()).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    CleanCmd::exec(&mut cmd_ctx).await?;
+
+    assert_eq!(None, test_server.resource());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_current_returns_response_status_error_when_unauthorized(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+    } = test_env()?;
+    let flow = Flow::new(flow_id, graph);
+    let test_server = TestServer::launch(Some("another_token")).await?;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile)
+        .with_flow(&flow)
+        .with_item_params::<HttpResourceItem<HttpResourceTest>>(
+            HttpResourceTest::ID.clone(),
+            http_resource_params(&test_server, json!({ "name": "a" }), Vec::new()).into(),
+        )
+        .await?;
+
+    let CmdOutcome::ItemError { errors, .. } = StatesDiscoverCmd::current(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::current` to return an item error.");
+    };
+
+    let error = errors.get(HttpResourceTest::ID);
+    assert!(
+        matches!(
+            error,
+            Some(HttpResourceError::ResponseStatus { method, status, .. })
+            if method.as_str() == "GET" && status.as_u16() == StatusCode::UNAUTHORIZED.as_u16()
+        ),
+        "Expected error to be `ResponseStatus`, but was: {error:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_diff_compares_only_fields_at_diff_pointers() -> Result<(), Box<dyn std::error::Error>>
+{
+    let state_current = HttpResourceState::Exists {
+        body: json!({ "metadata": { "created": "today" }, "spec": { "replicas": 1, "image": "a" } }),
+    };
+    let state_goal = HttpResourceState::Exists {
+        body: json!({ "metadata": { "created": "yesterday" }, "spec": { "replicas": 2, "image": "b" } }),
+    };
+
+    let state_diff = HttpResourceStateDiffFn::state_diff(
+        &[String::from("/spec/replicas")],
+        &state_current,
+        &state_goal,
+    )
+    .await?;
+
+    assert_eq!(
+        HttpResourceStateDiff::Modified {
+            field_diffs: vec![HttpResourceFieldDiff::new(
+                String::from("/spec/replicas"),
+                Some(json!(1)),
+                Some(json!(2)),
+            )]
+        },
+        state_diff
+    );
+    assert_eq!(
+        "will be updated: `/spec/replicas`: 1 -> 2",
+        state_diff.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_diff_escapes_field_pointers() -> Result<(), Box<dyn std::error::Error>> {
+    let state_current = HttpResourceState::Exists {
+        body: json!({ "labels": { "app/name": "a" } }),
+    };
+    let state_goal = HttpResourceState::Exists {
+        body: json!({ "labels": { "app/name": "b", "tier~1": "web" } }),
+    };
+
+    let state_diff = HttpResourceStateDiffFn::state_diff(&[], &state_current, &state_goal).await?;
+
+    assert_eq!(
+        HttpResourceStateDiff::Modified {
+            field_diffs: vec![
+                HttpResourceFieldDiff::new(
+                    String::from("/labels/app~1name"),
+                    Some(json!("a")),
+                    Some(json!("b")),
+                ),
+                HttpResourceFieldDiff::new(
+                    String::from("/labels/tier~01"),
+                    None,
+                    Some(json!("web")),
+                ),
+            ]
+        },
+        state_diff
+    );
+
+    Ok(())
+}
+
+#[test]
+fn auth_header_debug_does_not_show_value() {
+    let auth_header = HttpResourceAuthHeader::bearer(TOKEN);
+
+    assert_eq!(
+        r#"HttpResourceAuthHeader { name: "Authorization", value: ".." }"#,
+        format!("{auth_header:?}")
+    );
+}
+
+fn http_resource_params(
+    test_server: &TestServer,
+    body: Value,
+    diff_pointers: Vec<String>,
+) -> HttpResourceParams<HttpResourceTest> {
+    HttpResourceParams::new(
+        format!("http://{}/resource", test_server.address)
+            .parse()
+            .expect("Expected resource URL to be valid."),
+        HttpResourceApplyMethod::Put,
+        body,
+        Some(HttpResourceAuthHeader::bearer(TOKEN)),
+        diff_pointers,
+    )
+}
+
+/// REST API server that stores a single resource.
+#[derive(Clone, Debug)]
+struct TestServer {
+    /// Address the server is listening on.
+    address: SocketAddr,
+    /// State shared with the request handlers.
+    server_state: ServerState,
+}
+
+#[derive(Clone, Debug)]
+struct ServerState {
+    /// Token that requests must be authorized with.
+    token: Option<&'static str>,
+    /// The stored resource, `None` if it does not exist.
+    resource: Arc<Mutex<Option<Value>>>,
+    /// Methods of the requests received.
+    requests: Arc<Mutex<Vec<&'static str>>>,
+}
+
+impl TestServer {
+    async fn launch(token: Option<&'static str>) -> Result<Self, std::io::Error> {
+        let server_state = ServerState {
+            token,
+            resource: Arc::new(Mutex::new(None)),
+            requests: Arc::new(Mutex::new(Vec::new())),
+        };
+        let router = Router::new()
+            .route(
+                "/resource",
+                get(resource_get)
+                    .put(resource_put)
+                    .post(resource_put)
+                    .delete(resource_delete),
+            )
+            .with_state(server_state.clone());
+
+        let tcp_listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = tcp_listener.local_addr()?;
+        tokio::spawn(async move { axum::serve(tcp_listener, router).await });
+
+        Ok(Self {
+            address,
+            server_state,
+        })
+    }
+
+    fn resource(&self) -> Option<Value> {
+        self.server_state.resource.lock().unwrap().clone()
+    }
+
+    fn resource_set(&self, resource: Value) {
+        *self.server_state.resource.lock().unwrap() = Some(resource);
+    }
+
+    fn requests(&self) -> Vec<&'static str> {
+        self.server_state.requests.lock().unwrap().clone()
+    }
+}
+
+impl ServerState {
+    fn request_record(&self, method: &'static str, headers: &HeaderMap) -> Result<(), StatusCode> {
+        self.requests.lock().unwrap().push(method);
+
+        let authorization = headers
+            .get("Authorization")
+            .and_then(|value| value.to_str().ok());
+        match self.token {
+            Some(token) if authorization != Some(format!("Bearer {token}").as_str()) => {
+                Err(StatusCode::UNAUTHORIZED)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+async fn resource_get(
+    State(server_state): State<ServerState>,
+    headers: HeaderMap,
+) -> Result<Json<Value>, StatusCode> {
+    server_state.request_record("GET", &headers)?;
+
+    let resource = server_state.resource.lock().unwrap().clone();
+    match resource {
+        Some(resource) => Ok(Json(resource)),
+        None => Err(StatusCode::NOT_FOUND),
+    }
+}
+
+async fn resource_put(
+    State(server_state): State<ServerState>,
+    headers: HeaderMap,
+    Json(mut body): Json<Value>,
+) -> Result<StatusCode, StatusCode> {
+    server_state.request_record("PUT", &headers)?;
+
+    // Simulate a server generated field.
+    if let Value::Object(object) = &mut body {
+        object.insert(String::from("id"), json!(1));
+    }
+    *server_state.resource.lock().unwrap() = Some(body);
+
+    Ok(StatusCode::OK)
+}
+
+async fn resource_delete(
+    State(server_state): State<ServerState>,
+    headers: HeaderMap,
+) -> Result<StatusCode, StatusCode> {
+    server_state.request_record("DELETE", &headers)?;
+
+    *server_state.resource.lock().unwrap() = None;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+fn test_env() -> Result<TestEnv, Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let profile = profile!("test_profile");
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<HttpResourceError>::new();
+        graph_builder
+            .add_fn(HttpResourceItem::<HttpResourceTest>::new(HttpResourceTest::ID.clone()).into());
+        graph_builder.build()
+    };
+    let output = InMemoryOutput::new();
+
+    Ok(TestEnv {
+        tempdir,
+        workspace,
+        profile,
+        graph,
+        output,
+    })
+}
+
+#[derive(Debug)]
+struct TestEnv {
+    tempdir: TempDir,
+    workspace: Workspace,
+    profile: Profile,
+    graph: ItemGraph<HttpResourceError>,
+    output: InMemoryOutput,
+}
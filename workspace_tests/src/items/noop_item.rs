@@ -0,0 +1,146 @@
+use std::time::Duration;
+
+use peace::{
+    cfg::{app_name, item_id, profile, FlowId, ItemId, Profile},
+    cmd::ctx::CmdCtx,
+    cmd_model::CmdOutcome,
+    rt::cmds::{EnsureCmd, StatesDiscoverCmd},
+    rt_model::{Flow, InMemoryOutput, ItemGraph, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+use peace_items::noop::{NoOpError, NoOpItem, NoOpParams, NoOpState, NoOpStateDiff};
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct NoOpTest;
+
+impl NoOpTest {
+    const ID: &'static ItemId = &item_id!("noop_test");
+}
+
+#[test]
+fn clone() {
+    let _item = Clone::clone(&NoOpItem::<()>::new(NoOpTest::ID.clone()));
+}
+
+#[tokio::test]
+async fn state_current_waits_for_delay() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+    } = test_env()?;
+    let flow = Flow::new(flow_id, graph);
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile)
+        .with_flow(&flow)
+        .with_item_params::<NoOpItem<NoOpTest>>(
+            NoOpTest::ID.clone(),
+            NoOpParams::<NoOpTest>::new(Duration::from_millis(100)).into(),
+        )
+        .await?;
+
+    let start = std::time::Instant::now();
+    let CmdOutcome::Complete {
+        value: states_current,
+        cmd_blocks_processed: _,
+    } = StatesDiscoverCmd::current(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::current` to complete successfully.");
+    };
+
+    assert!(start.elapsed() >= Duration::from_millis(100));
+    assert_eq!(
+        Some(&NoOpState::Unchanged),
+        states_current.get::<NoOpState, _>(NoOpTest::ID)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn ensure_completes_without_changes() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+    } = test_env()?;
+    let flow = Flow::new(flow_id, graph);
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<NoOpItem<NoOpTest>>(
+            NoOpTest::ID.clone(),
+            NoOpParams::<NoOpTest>::new(Duration::ZERO).into(),
+        )
+        .await?;
+
+    // Write current and goal states to disk.
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile)
+        .with_flow(&flow)
+        .await?;
+
+    let CmdOutcome::Complete {
+        value: states_ensured,
+        cmd_blocks_processed: _,
+    } = EnsureCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec` to complete successfully.");
+    };
+
+    assert_eq!(
+        Some(&NoOpState::Unchanged),
+        states_ensured.get::<NoOpState, _>(NoOpTest::ID)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn state_and_state_diff_display() {
+    assert_eq!("unchanged", NoOpState::Unchanged.to_string());
+    assert_eq!("in sync", NoOpStateDiff.to_string());
+}
+
+fn test_env() -> Result<TestEnv, Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let profile = profile!("test_profile");
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<NoOpError>::new();
+        graph_builder.add_fn(NoOpItem::<NoOpTest>::new(NoOpTest::ID.clone()).into());
+        graph_builder.build()
+    };
+    let output = InMemoryOutput::new();
+
+    Ok(TestEnv {
+        tempdir,
+        workspace,
+        profile,
+        graph,
+        output,
+    })
+}
+
+#[derive(Debug)]
+struct TestEnv {
+    tempdir: TempDir,
+    workspace: Workspace,
+    profile: Profile,
+    graph: ItemGraph<NoOpError>,
+    output: InMemoryOutput,
+}
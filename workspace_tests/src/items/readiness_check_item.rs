@@ -0,0 +1,297 @@
+use std::{net::SocketAddr, time::Duration};
+
+use peace::{
+    cfg::{app_name, item_id, profile, FlowId, ItemId, Profile},
+    cmd::ctx::CmdCtx,
+    cmd_model::CmdOutcome,
+    rt::cmds::{EnsureCmd, StatesDiscoverCmd},
+    rt_model::{Flow, InMemoryOutput, ItemGraph, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+use peace_items::readiness_check::{
+    ReadinessCheckError, ReadinessCheckItem, ReadinessCheckParams, ReadinessCheckState,
+    ReadinessCheckStateDiff, ReadinessCheckStateDiffFn, ReadinessCheckTarget,
+};
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+use tokio::net::TcpListener;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ReadinessCheckTest;
+
+impl ReadinessCheckTest {
+    const ID: &'static ItemId = &item_id!("readiness_check_test");
+}
+
+#[test]
+fn clone() {
+    let _item = Clone::clone(&ReadinessCheckItem::<()>::new(
+        ReadinessCheckTest::ID.clone(),
+    ));
+}
+
+#[tokio::test]
+async fn state_current_returns_ready_when_tcp_endpoint_listening(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+    } = test_env()?;
+    let flow = Flow::new(flow_id, graph);
+    let tcp_listener = TcpListener::bind("127.0.0.1:0").await?;
+    let address = tcp_listener.local_addr()?;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile)
+        .with_flow(&flow)
+        .with_item_params::<ReadinessCheckItem<ReadinessCheckTest>>(
+            ReadinessCheckTest::ID.clone(),
+            readiness_check_params(address, Duration::from_secs(5)).into(),
+        )
+        .await?;
+
+    let CmdOutcome::Complete {
+        value: states_current,
+        cmd_blocks_processed: _,
+    } = StatesDiscoverCmd::current(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::current` to complete successfully.");
+    };
+    let state_current = states_current
+        .get::<ReadinessCheckState, _>(ReadinessCheckTest::ID)
+        .unwrap();
+
+    assert_eq!(&ReadinessCheckState::Ready, state_current);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_current_returns_not_ready_when_tcp_endpoint_not_listening(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+    } = test_env()?;
+    let flow = Flow::new(flow_id, graph);
+    let address = unused_address().await?;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile)
+        .with_flow(&flow)
+        .with_item_params::<ReadinessCheckItem<ReadinessCheckTest>>(
+            ReadinessCheckTest::ID.clone(),
+            readiness_check_params(address, Duration::from_secs(5)).into(),
+        )
+        .await?;
+
+    let CmdOutcome::Complete {
+        value: states_current,
+        cmd_blocks_processed: _,
+    } = StatesDiscoverCmd::current(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::current` to complete successfully.");
+    };
+    let state_current = states_current
+        .get::<ReadinessCheckState, _>(ReadinessCheckTest::ID)
+        .unwrap();
+
+    assert_eq!(&ReadinessCheckState::NotReady, state_current);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn ensure_waits_for_endpoint_to_be_ready() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+    } = test_env()?;
+    let flow = Flow::new(flow_id, graph);
+    let address = unused_address().await?;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile)
+        .with_flow(&flow)
+        .with_item_params::<ReadinessCheckItem<ReadinessCheckTest>>(
+            ReadinessCheckTest::ID.clone(),
+            readiness_check_params(address, Duration::from_secs(5)).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let server_launch = tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        let tcp_listener = TcpListener::bind(address).await?;
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        drop(tcp_listener);
+        Ok::<_, std::io::Error>(())
+    });
+
+    let CmdOutcome::Complete {
+        value: states_ensured,
+        cmd_blocks_processed: _,
+    } = EnsureCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec` to complete successfully.");
+    };
+    let state_ensured = states_ensured
+        .get::<ReadinessCheckState, _>(ReadinessCheckTest::ID)
+        .unwrap();
+
+    assert_eq!(&ReadinessCheckState::Ready, state_ensured);
+
+    server_launch.abort();
+    Ok(())
+}
+
+#[tokio::test]
+async fn ensure_returns_readiness_timeout_when_endpoint_not_ready(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+    } = test_env()?;
+    let flow = Flow::new(flow_id, graph);
+    let address = unused_address().await?;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile)
+        .with_flow(&flow)
+        .with_item_params::<ReadinessCheckItem<ReadinessCheckTest>>(
+            ReadinessCheckTest::ID.clone(),
+            readiness_check_params(address, Duration::from_millis(200)).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let CmdOutcome::ItemError {
+        item_stream_outcome: _,
+        cmd_blocks_processed: _,
+        cmd_blocks_not_processed: _,
+        errors,
+        item_log_paths: _,
+    } = EnsureCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec` to return an item error.");
+    };
+
+    let error = errors.get(ReadinessCheckTest::ID);
+    assert!(
+        matches!(
+            error,
+            Some(ReadinessCheckError::ReadinessTimeout { target, timeout, .. })
+            if target == &ReadinessCheckTarget::Tcp { address: address.to_string() }
+                && *timeout == Duration::from_millis(200)
+        ),
+        "Expected error to be `ReadinessTimeout`, but was: {error:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_diff_returns_ready_not_required_when_cleaning_ready_endpoint(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let state_diff = ReadinessCheckStateDiffFn::state_diff(
+        &ReadinessCheckState::Ready,
+        &ReadinessCheckState::NotReady,
+    )
+    .await?;
+
+    assert_eq!(ReadinessCheckStateDiff::ReadyNotRequired, state_diff);
+    assert_eq!(
+        "ready, endpoint is not managed by this item",
+        state_diff.to_string()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn target_display() -> Result<(), Box<dyn std::error::Error>> {
+    assert_eq!(
+        "tcp://127.0.0.1:8080",
+        ReadinessCheckTarget::Tcp {
+            address: String::from("127.0.0.1:8080")
+        }
+        .to_string()
+    );
+    assert_eq!(
+        "http://127.0.0.1:8080/health",
+        ReadinessCheckTarget::Http {
+            url: "http://127.0.0.1:8080/health".parse()?
+        }
+        .to_string()
+    );
+
+    Ok(())
+}
+
+fn readiness_check_params(
+    address: SocketAddr,
+    timeout: Duration,
+) -> ReadinessCheckParams<ReadinessCheckTest> {
+    ReadinessCheckParams::new(
+        ReadinessCheckTarget::Tcp {
+            address: address.to_string(),
+        },
+        Duration::from_millis(50),
+        timeout,
+    )
+}
+
+/// Returns an address that nothing is listening on.
+async fn unused_address() -> Result<SocketAddr, std::io::Error> {
+    let tcp_listener = TcpListener::bind("127.0.0.1:0").await?;
+    tcp_listener.local_addr()
+}
+
+fn test_env() -> Result<TestEnv, Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let profile = profile!("test_profile");
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<ReadinessCheckError>::new();
+        graph_builder.add_fn(
+            ReadinessCheckItem::<ReadinessCheckTest>::new(ReadinessCheckTest::ID.clone()).into(),
+        );
+        graph_builder.build()
+    };
+    let output = InMemoryOutput::new();
+
+    Ok(TestEnv {
+        tempdir,
+        workspace,
+        profile,
+        graph,
+        output,
+    })
+}
+
+#[derive(Debug)]
+struct TestEnv {
+    tempdir: TempDir,
+    workspace: Workspace,
+    profile: Profile,
+    graph: ItemGraph<ReadinessCheckError>,
+    output: InMemoryOutput,
+}
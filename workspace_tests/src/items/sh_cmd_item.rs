@@ -4,7 +4,7 @@ use peace::{
     cmd_model::CmdOutcome,
     data::marker::Clean,
     rt::cmds::{CleanCmd, DiffCmd, EnsureCmd, StatesDiscoverCmd},
-    rt_model::{Flow, InMemoryTextOutput, ItemGraphBuilder, Workspace, WorkspaceSpec},
+    rt_model::{Flow, InMemoryOutput, ItemGraphBuilder, Workspace, WorkspaceSpec},
 };
 use peace_items::sh_cmd::{
     ShCmd, ShCmdError, ShCmdExecutionRecord, ShCmdItem, ShCmdParams, ShCmdState, ShCmdStateDiff,
@@ -137,7 +137,7 @@ async fn state_clean_returns_shell_command_clean_state() -> Result<(), Box<dyn s
         graph_builder.build()
     };
     let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
-    let mut output = InMemoryTextOutput::new();
+    let mut output = InMemoryOutput::new();
     let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
         .with_profile(profile!("test_profile"))
         .with_flow(&flow)
@@ -186,7 +186,7 @@ async fn state_current_returns_shell_command_current_state()
         graph_builder.build()
     };
     let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
-    let mut output = InMemoryTextOutput::new();
+    let mut output = InMemoryOutput::new();
     let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
         .with_profile(profile!("test_profile"))
         .with_flow(&flow)
@@ -236,7 +236,7 @@ async fn state_goal_returns_shell_command_goal_state() -> Result<(), Box<dyn std
         graph_builder.build()
     };
     let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
-    let mut output = InMemoryTextOutput::new();
+    let mut output = InMemoryOutput::new();
     let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
         .with_profile(profile!("test_profile"))
         .with_flow(&flow)
@@ -286,7 +286,7 @@ async fn state_diff_returns_shell_command_state_diff() -> Result<(), Box<dyn std
         graph_builder.build()
     };
     let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
-    let mut output = InMemoryTextOutput::new();
+    let mut output = InMemoryOutput::new();
     let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
         .with_profile(profile!("test_profile"))
         .with_flow(&flow)
@@ -331,7 +331,7 @@ async fn ensure_when_creation_required_executes_apply_exec_shell_command()
         graph_builder.build()
     };
     let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
-    let mut output = InMemoryTextOutput::new();
+    let mut output = InMemoryOutput::new();
     let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
         .with_profile(profile!("test_profile"))
         .with_flow(&flow)
@@ -385,7 +385,7 @@ async fn ensure_when_exists_sync_does_not_reexecute_apply_exec_shell_command()
         graph_builder.build()
     };
     let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
-    let mut output = InMemoryTextOutput::new();
+    let mut output = InMemoryOutput::new();
     let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
         .with_profile(profile!("test_profile"))
         .with_flow(&flow)
@@ -456,7 +456,7 @@ async fn clean_when_exists_sync_executes_shell_command() -> Result<(), Box<dyn s
         graph_builder.build()
     };
     let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
-    let mut output = InMemoryTextOutput::new();
+    let mut output = InMemoryOutput::new();
     let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
         .with_profile(profile!("test_profile"))
         .with_flow(&flow)
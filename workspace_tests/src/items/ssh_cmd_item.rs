@@ -0,0 +1,406 @@
+use std::{os::unix::fs::PermissionsExt, path::Path, time::Duration};
+
+use peace::{
+    cfg::{app_name, item_id, profile, FlowId, ItemId, State},
+    cmd::ctx::CmdCtx,
+    cmd_model::CmdOutcome,
+    resources::paths::PeaceAppDir,
+    rt::cmds::{CleanCmd, EnsureCmd, StatesDiscoverCmd},
+    rt_model::{Flow, InMemoryOutput, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+use peace_items::ssh_cmd::{
+    ShCmd, ShCmdExecutionRecord, ShCmdState, SshAuth, SshCmdError, SshCmdItem, SshCmdParams,
+    SshConnPool, SshHost,
+};
+
+/// Creates a file on the remote host.
+#[derive(Clone, Copy, Debug)]
+pub struct TestFileCreationSshCmdItem;
+
+pub type TestFileCreationSshCmdState =
+    State<ShCmdState<TestFileCreationSshCmdItem>, ShCmdExecutionRecord>;
+
+impl TestFileCreationSshCmdItem {
+    /// ID
+    pub const ID: ItemId = item_id!("test_file_creation");
+
+    /// Returns a new `TestFileCreationSshCmdItem`.
+    pub fn new() -> SshCmdItem<Self> {
+        SshCmdItem::new(Self::ID)
+    }
+
+    fn params() -> SshCmdParams<TestFileCreationSshCmdItem> {
+        let state_clean_sh_cmd = ShCmd::new("bash").arg("-c").arg(include_str!(
+            "sh_cmd_item/unix/test_file_creation_state_clean.sh"
+        ));
+        let state_current_sh_cmd = ShCmd::new("bash").arg("-c").arg(include_str!(
+            "sh_cmd_item/unix/test_file_creation_state_current.sh"
+        ));
+        let state_goal_sh_cmd = ShCmd::new("bash").arg("-c").arg(include_str!(
+            "sh_cmd_item/unix/test_file_creation_state_goal.sh"
+        ));
+        let state_diff_sh_cmd = ShCmd::new("bash").arg("-c").arg(include_str!(
+            "sh_cmd_item/unix/test_file_creation_state_diff.sh"
+        ));
+        let apply_check_sh_cmd = ShCmd::new("bash").arg("-c").arg(include_str!(
+            "sh_cmd_item/unix/test_file_creation_apply_check.sh"
+        ));
+        let apply_exec_sh_cmd = ShCmd::new("bash").arg("-c").arg(include_str!(
+            "sh_cmd_item/unix/test_file_creation_apply_exec.sh"
+        ));
+        SshCmdParams::<TestFileCreationSshCmdItem>::new(
+            SshHost::new("remote.example.com"),
+            state_clean_sh_cmd,
+            state_current_sh_cmd,
+            state_goal_sh_cmd,
+            state_diff_sh_cmd,
+            apply_check_sh_cmd,
+            apply_exec_sh_cmd,
+        )
+    }
+}
+
+/// Fake `ssh` program which runs the remote command locally, in the `remote`
+/// directory next to it.
+const SSH_FAKE: &str = r#"#!/bin/sh
+for arg; do remote_cmd="$arg"; done
+cd "$(dirname "$0")/remote" && exec sh -c "$remote_cmd"
+"#;
+
+/// Fake `ssh` program which fails to connect.
+const SSH_FAKE_CONNECT_FAIL: &str = r#"#!/bin/sh
+echo 'ssh: connect to host remote.example.com port 22: Connection refused' 1>&2
+exit 255
+"#;
+
+#[test]
+fn clone() {
+    let _ssh_cmd_item = Clone::clone(&TestFileCreationSshCmdItem::new());
+}
+
+#[test]
+fn debug() {
+    let ssh_cmd_item = TestFileCreationSshCmdItem::new();
+
+    assert_eq!(
+        "SshCmdItem { \
+        item_id: ItemId(\"test_file_creation\"), \
+        marker: PhantomData<workspace_tests::items::ssh_cmd_item::TestFileCreationSshCmdItem> \
+    }",
+        format!("{ssh_cmd_item:?}")
+    );
+}
+
+#[test]
+fn ssh_host_display() {
+    assert_eq!("host", SshHost::new("host").to_string());
+    assert_eq!(
+        "user@host:2222",
+        SshHost::new("host")
+            .with_user("user")
+            .with_port(2222)
+            .to_string()
+    );
+}
+
+#[test]
+fn ssh_conn_pool_command_uses_shared_control_path_and_quotes_remote_cmd() {
+    let ssh_conn_pool =
+        SshConnPool::new("/tmp/peace_ssh_cmd".into()).with_control_persist(Duration::from_secs(30));
+    let sh_cmd = ShCmd::new("echo").arg("it's").arg("a.txt");
+
+    let command = ssh_conn_pool.command(&SshHost::new("host"), &sh_cmd);
+    let command = command.as_std();
+
+    assert_eq!("ssh", command.get_program());
+    assert_eq!(
+        vec![
+            "-o",
+            "BatchMode=yes",
+            "-o",
+            "ControlMaster=auto",
+            "-o",
+            "ControlPath=/tmp/peace_ssh_cmd/%C",
+            "-o",
+            "ControlPersist=30",
+            "-o",
+            "ConnectTimeout=10",
+            "--",
+            "host",
+            r#"echo 'it'\''s' a.txt"#,
+        ],
+        command.get_args().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn ssh_conn_pool_command_passes_port_user_and_identity_file() {
+    let ssh_conn_pool = SshConnPool::new("/tmp/peace_ssh_cmd".into())
+        .with_ssh_program("/usr/local/bin/ssh")
+        .with_connect_timeout(Duration::from_secs(3));
+    let ssh_host = SshHost::new("host")
+        .with_port(2222)
+        .with_user("user")
+        .with_auth(SshAuth::IdentityFile {
+            path: "/keys/id_ed25519".into(),
+        });
+
+    let command = ssh_conn_pool.command(&ssh_host, &ShCmd::new("true"));
+    let command = command.as_std();
+
+    assert_eq!("/usr/local/bin/ssh", command.get_program());
+    assert_eq!(
+        vec![
+            "-o",
+            "BatchMode=yes",
+            "-o",
+            "ControlMaster=auto",
+            "-o",
+            "ControlPath=/tmp/peace_ssh_cmd/%C",
+            "-o",
+            "ControlPersist=60",
+            "-o",
+            "ConnectTimeout=3",
+            "-p",
+            "2222",
+            "-l",
+            "user",
+            "-i",
+            "/keys/id_ed25519",
+            "-o",
+            "IdentitiesOnly=yes",
+            "--",
+            "host",
+            "true",
+        ],
+        command.get_args().collect::<Vec<_>>()
+    );
+}
+
+#[tokio::test]
+async fn ensure_and_clean_execute_commands_on_remote_host() -> Result<(), Box<dyn std::error::Error>>
+{
+    let tempdir = tempfile::tempdir()?;
+    let ssh_conn_pool = ssh_conn_pool_fake(tempdir.path(), SSH_FAKE).await?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<SshCmdError>::new();
+        graph_builder.add_fn(TestFileCreationSshCmdItem::new().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = InMemoryOutput::new();
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<SshCmdItem<TestFileCreationSshCmdItem>>(
+            TestFileCreationSshCmdItem::ID,
+            TestFileCreationSshCmdItem::params().into(),
+        )
+        .await?;
+    cmd_ctx.resources_mut().insert(ssh_conn_pool);
+
+    let CmdOutcome::Complete {
+        value: (states_current, _states_goal),
+        cmd_blocks_processed: _,
+    } = StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::current_and_goal` to complete successfully.");
+    };
+    let state_current = states_current
+        .get::<TestFileCreationSshCmdState, _>(&TestFileCreationSshCmdItem::ID)
+        .unwrap();
+    assert_state_stdout("not_exists", state_current);
+
+    let CmdOutcome::Complete {
+        value: states_ensured,
+        cmd_blocks_processed: _,
+    } = EnsureCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec` to complete successfully.");
+    };
+    let state_ensured = states_ensured
+        .get::<TestFileCreationSshCmdState, _>(&TestFileCreationSshCmdItem::ID)
+        .unwrap();
+    assert_state_stdout("exists", state_ensured);
+    assert!(tempdir.path().join("remote/test_file").exists());
+
+    let CmdOutcome::Complete {
+        value: states_cleaned,
+        cmd_blocks_processed: _,
+    } = CleanCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `CleanCmd::exec` to complete successfully.");
+    };
+    let state_cleaned = states_cleaned
+        .get::<TestFileCreationSshCmdState, _>(&TestFileCreationSshCmdItem::ID)
+        .unwrap();
+    assert_state_stdout("not_exists", state_cleaned);
+    assert!(!tempdir.path().join("remote/test_file").exists());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_current_returns_err_when_ssh_connect_fails() -> Result<(), Box<dyn std::error::Error>>
+{
+    let tempdir = tempfile::tempdir()?;
+    let ssh_conn_pool = ssh_conn_pool_fake(tempdir.path(), SSH_FAKE_CONNECT_FAIL).await?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<SshCmdError>::new();
+        graph_builder.add_fn(TestFileCreationSshCmdItem::new().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = InMemoryOutput::new();
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<SshCmdItem<TestFileCreationSshCmdItem>>(
+            TestFileCreationSshCmdItem::ID,
+            TestFileCreationSshCmdItem::params().into(),
+        )
+        .await?;
+    cmd_ctx.resources_mut().insert(ssh_conn_pool);
+
+    let CmdOutcome::ItemError { errors, .. } = StatesDiscoverCmd::current(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::current` to return an item error.");
+    };
+
+    let error = errors.get(&TestFileCreationSshCmdItem::ID);
+    assert!(
+        matches!(
+            error,
+            Some(SshCmdError::SshConnectFail { ssh_host, stderr })
+            if ssh_host == &SshHost::new("remote.example.com")
+            && stderr == "ssh: connect to host remote.example.com port 22: Connection refused"
+        ),
+        "Expected error to be `SshConnectFail`, but was: {error:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_current_returns_err_when_control_dir_is_not_private(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let ssh_conn_pool = ssh_conn_pool_fake(tempdir.path(), SSH_FAKE).await?;
+    // e.g. created by another user before the command is run.
+    tokio::fs::create_dir(ssh_conn_pool.control_dir()).await?;
+    tokio::fs::set_permissions(
+        ssh_conn_pool.control_dir(),
+        std::fs::Permissions::from_mode(0o777),
+    )
+    .await?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<SshCmdError>::new();
+        graph_builder.add_fn(TestFileCreationSshCmdItem::new().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = InMemoryOutput::new();
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<SshCmdItem<TestFileCreationSshCmdItem>>(
+            TestFileCreationSshCmdItem::ID,
+            TestFileCreationSshCmdItem::params().into(),
+        )
+        .await?;
+    cmd_ctx.resources_mut().insert(ssh_conn_pool);
+
+    let CmdOutcome::ItemError { errors, .. } = StatesDiscoverCmd::current(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::current` to return an item error.");
+    };
+
+    let error = errors.get(&TestFileCreationSshCmdItem::ID);
+    assert!(
+        matches!(
+            error,
+            Some(SshCmdError::ControlDirInsecure { control_dir, mode, .. })
+            if control_dir == &tempdir.path().join("control")
+            && *mode == 0o777
+        ),
+        "Expected error to be `ControlDirInsecure`, but was: {error:?}"
+    );
+    assert!(!tempdir.path().join("remote/test_file").exists());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn setup_inserts_ssh_conn_pool_with_default_control_dir(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<SshCmdError>::new();
+        graph_builder.add_fn(TestFileCreationSshCmdItem::new().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = InMemoryOutput::new();
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<SshCmdItem<TestFileCreationSshCmdItem>>(
+            TestFileCreationSshCmdItem::ID,
+            TestFileCreationSshCmdItem::params().into(),
+        )
+        .await?;
+
+    let resources = cmd_ctx.resources();
+    let peace_app_dir = resources.borrow::<PeaceAppDir>();
+    let ssh_conn_pool = resources.borrow::<SshConnPool>();
+    assert_eq!(
+        SshConnPool::control_dir_default(&peace_app_dir),
+        ssh_conn_pool.control_dir()
+    );
+    match std::env::var_os("XDG_RUNTIME_DIR").filter(|dir| !dir.is_empty()) {
+        Some(xdg_runtime_dir) => {
+            assert!(ssh_conn_pool.control_dir().starts_with(xdg_runtime_dir))
+        }
+        None => assert!(ssh_conn_pool.control_dir().starts_with(&*peace_app_dir)),
+    }
+
+    Ok(())
+}
+
+/// Writes the fake `ssh` program to `dir`, and returns an `SshConnPool` that
+/// uses it.
+async fn ssh_conn_pool_fake(
+    dir: &Path,
+    ssh_fake: &str,
+) -> Result<SshConnPool, Box<dyn std::error::Error>> {
+    let ssh_program = dir.join("ssh");
+    tokio::fs::write(&ssh_program, ssh_fake).await?;
+    tokio::fs::set_permissions(&ssh_program, std::fs::Permissions::from_mode(0o755)).await?;
+    tokio::fs::create_dir(dir.join("remote")).await?;
+
+    Ok(SshConnPool::new(dir.join("control")).with_ssh_program(ssh_program))
+}
+
+fn assert_state_stdout(stdout_expected: &str, state: &TestFileCreationSshCmdState) {
+    if let ShCmdState::Some { stdout, .. } = &state.logical {
+        assert_eq!(stdout_expected, stdout);
+    } else {
+        panic!("Expected state to be `ShCmdState::Some`, but was: {state:?}");
+    }
+}
@@ -0,0 +1,204 @@
+use peace::{
+    cfg::{app_name, item_id, profile, FlowId, ItemId},
+    cmd::ctx::CmdCtx,
+    cmd_model::CmdOutcome,
+    rt::cmds::StatesDiscoverCmd,
+    rt_model::{Flow, InMemoryOutput, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+use peace_items::systemd_service::{
+    SystemdActiveState, SystemdEnabledState, SystemdServiceError, SystemdServiceItem,
+    SystemdServiceParams, SystemdServiceState, SystemdServiceStateDiff, SystemdServiceStateDiffFn,
+};
+use pretty_assertions::assert_eq;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct SystemdServiceTest;
+
+impl SystemdServiceTest {
+    const ID: &'static ItemId = &item_id!("systemd_service_test");
+}
+
+const UNIT_FILE_CONTENT: &str = "\
+[Unit]
+Description=Test service
+
+[Service]
+ExecStart=/bin/true
+";
+
+#[test]
+fn clone() {
+    let _item = Clone::clone(&SystemdServiceItem::<()>::new(
+        SystemdServiceTest::ID.clone(),
+    ));
+}
+
+#[tokio::test]
+async fn state_goal_returns_unit_file_hash_active_and_enabled(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<SystemdServiceError>::new();
+        graph_builder.add_fn(
+            SystemdServiceItem::<SystemdServiceTest>::new(SystemdServiceTest::ID.clone()).into(),
+        );
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = InMemoryOutput::new();
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<SystemdServiceItem<SystemdServiceTest>>(
+            SystemdServiceTest::ID.clone(),
+            SystemdServiceParams::<SystemdServiceTest>::new(
+                tempdir.path().join("units"),
+                String::from("peace_test.service"),
+                String::from(UNIT_FILE_CONTENT),
+            )
+            .into(),
+        )
+        .await?;
+
+    let CmdOutcome::Complete {
+        value: states_goal,
+        cmd_blocks_processed: _,
+    } = StatesDiscoverCmd::goal(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::goal` to complete successfully.");
+    };
+    let state_goal = states_goal
+        .get::<SystemdServiceState, _>(SystemdServiceTest::ID)
+        .unwrap();
+
+    assert_eq!(
+        &SystemdServiceState::new(
+            Some(SystemdServiceState::unit_file_hash(
+                UNIT_FILE_CONTENT.as_bytes()
+            )),
+            SystemdActiveState::Active,
+            SystemdEnabledState::Enabled,
+        ),
+        state_goal
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_diff_is_in_sync_when_states_equal() -> Result<(), Box<dyn std::error::Error>> {
+    let state = SystemdServiceState::new(
+        Some(SystemdServiceState::unit_file_hash(
+            UNIT_FILE_CONTENT.as_bytes(),
+        )),
+        SystemdActiveState::Active,
+        SystemdEnabledState::Enabled,
+    );
+
+    let state_diff = SystemdServiceStateDiffFn::state_diff(&state, &state).await?;
+
+    assert!(state_diff.is_in_sync());
+    assert_eq!("in sync", state_diff.to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_diff_returns_config_and_activation_drift() -> Result<(), Box<dyn std::error::Error>>
+{
+    let unit_file_hash_current = SystemdServiceState::unit_file_hash(b"[Unit]\n");
+    let unit_file_hash_goal = SystemdServiceState::unit_file_hash(UNIT_FILE_CONTENT.as_bytes());
+    let state_current = SystemdServiceState::new(
+        Some(unit_file_hash_current.clone()),
+        SystemdActiveState::Inactive,
+        SystemdEnabledState::Disabled,
+    );
+    let state_goal = SystemdServiceState::new(
+        Some(unit_file_hash_goal.clone()),
+        SystemdActiveState::Active,
+        SystemdEnabledState::Enabled,
+    );
+
+    let state_diff = SystemdServiceStateDiffFn::state_diff(&state_current, &state_goal).await?;
+
+    assert_eq!(
+        SystemdServiceStateDiff {
+            unit_file_hash: Some((Some(unit_file_hash_current), Some(unit_file_hash_goal))),
+            active_state: Some((SystemdActiveState::Inactive, SystemdActiveState::Active)),
+            enabled_state: Some((SystemdEnabledState::Disabled, SystemdEnabledState::Enabled)),
+        },
+        state_diff
+    );
+    assert_eq!(
+        "unit file will be modified, inactive -> active, disabled -> enabled",
+        state_diff.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_diff_to_clean_removes_unit_file() -> Result<(), Box<dyn std::error::Error>> {
+    let state_current = SystemdServiceState::new(
+        Some(SystemdServiceState::unit_file_hash(
+            UNIT_FILE_CONTENT.as_bytes(),
+        )),
+        SystemdActiveState::Active,
+        SystemdEnabledState::Enabled,
+    );
+
+    let state_diff =
+        SystemdServiceStateDiffFn::state_diff(&state_current, &SystemdServiceState::clean())
+            .await?;
+
+    assert_eq!(
+        "unit file will be removed, active -> inactive, enabled -> disabled",
+        state_diff.to_string()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn state_display() {
+    let state = SystemdServiceState::new(
+        Some(String::from("0123456789abcdef")),
+        SystemdActiveState::Active,
+        SystemdEnabledState::Enabled,
+    );
+
+    assert_eq!("unit file `01234567`, active, enabled", state.to_string());
+    assert_eq!(
+        "unit file does not exist, inactive, disabled",
+        SystemdServiceState::clean().to_string()
+    );
+}
+
+#[test]
+fn active_state_from_is_active_output() {
+    assert_eq!(
+        SystemdActiveState::Active,
+        SystemdActiveState::from_is_active_output("active\n")
+    );
+    assert_eq!(
+        SystemdActiveState::Inactive,
+        SystemdActiveState::from_is_active_output("failed\n")
+    );
+}
+
+#[test]
+fn enabled_state_from_is_enabled_output() {
+    assert_eq!(
+        SystemdEnabledState::Enabled,
+        SystemdEnabledState::from_is_enabled_output("enabled\n")
+    );
+    assert_eq!(
+        SystemdEnabledState::Disabled,
+        SystemdEnabledState::from_is_enabled_output("masked\n")
+    );
+}
@@ -1,14 +1,17 @@
 use std::{io::Cursor, path::PathBuf};
 
 use peace::{
-    cfg::{app_name, item_id, profile, ApplyCheck, FlowId, Item, ItemId, Profile},
+    cfg::{
+        app_name, item_id, profile, ApplyCheck, ApplyCtx, DiffSeverity, FlowId, Item, ItemId,
+        Profile,
+    },
     cmd::{ctx::CmdCtx, scopes::SingleProfileSingleFlowView},
     cmd_model::CmdOutcome,
     data::Data,
     params::{ParamsSpec, ValueResolutionCtx, ValueResolutionMode},
     resources::paths::{FlowDir, ProfileDir},
     rt::cmds::{CleanCmd, DiffCmd, EnsureCmd, StatesDiscoverCmd},
-    rt_model::{Flow, InMemoryTextOutput, ItemGraph, ItemGraphBuilder, Workspace, WorkspaceSpec},
+    rt_model::{Flow, InMemoryOutput, ItemGraph, ItemGraphBuilder, Workspace, WorkspaceSpec},
 };
 use peace_items::tar_x::{
     FileMetadata, FileMetadatas, TarXData, TarXError, TarXItem, TarXParams, TarXStateDiff,
@@ -565,6 +568,7 @@ async fn ensure_check_returns_exec_not_required_when_tar_and_dest_in_sync()
     let state_diff = state_diffs.get::<TarXStateDiff, _>(TarXTest::ID).unwrap();
 
     let SingleProfileSingleFlowView {
+        profile,
         params_specs,
         resources,
         ..
@@ -583,6 +587,7 @@ async fn ensure_check_returns_exec_not_required_when_tar_and_dest_in_sync()
     assert_eq!(
         ApplyCheck::ExecNotRequired,
         <TarXItem::<TarXTest> as Item>::apply_check(
+            ApplyCtx::new(profile, flow.flow_id(), false, None),
             &tar_x_params,
             <TarXData<TarXTest> as Data>::borrow(TarXTest::ID, resources),
             state_current,
@@ -766,6 +771,37 @@ async fn clean_removes_files_in_dest_directory() -> Result<(), Box<dyn std::erro
     Ok(())
 }
 
+#[test]
+fn diff_severity_is_destructive_when_files_removed() {
+    let state = FileMetadatas::default();
+    let file_metadatas = || FileMetadatas::from(vec![FileMetadata::new(PathBuf::from("a"), 0)]);
+
+    let state_diff_in_sync = TarXStateDiff::ExtractionInSync;
+    let state_diff_added = TarXStateDiff::ExtractionOutOfSync {
+        added: file_metadatas(),
+        modified: FileMetadatas::default(),
+        removed: FileMetadatas::default(),
+    };
+    let state_diff_removed = TarXStateDiff::ExtractionOutOfSync {
+        added: FileMetadatas::default(),
+        modified: FileMetadatas::default(),
+        removed: file_metadatas(),
+    };
+
+    assert_eq!(
+        DiffSeverity::InSync,
+        <TarXItem<TarXTest> as Item>::diff_severity(&state, &state, &state_diff_in_sync)
+    );
+    assert_eq!(
+        DiffSeverity::Additive,
+        <TarXItem<TarXTest> as Item>::diff_severity(&state, &state, &state_diff_added)
+    );
+    assert_eq!(
+        DiffSeverity::Destructive,
+        <TarXItem<TarXTest> as Item>::diff_severity(&state, &state, &state_diff_removed)
+    );
+}
+
 async fn test_env(
     flow_id: &FlowId,
     tar_bytes: &[u8],
@@ -785,7 +821,7 @@ async fn test_env(
         graph_builder.add_fn(TarXItem::<TarXTest>::new(TarXTest::ID.clone()).into());
         graph_builder.build()
     };
-    let output = InMemoryTextOutput::new();
+    let output = InMemoryOutput::new();
     let tar_path = {
         let tar_path = flow_dir.join("tar_x.tar");
         tokio::fs::create_dir_all(&flow_dir).await?;
@@ -811,7 +847,7 @@ struct TestEnv {
     workspace: Workspace,
     profile: Profile,
     graph: ItemGraph<TarXError>,
-    output: InMemoryTextOutput,
+    output: InMemoryOutput,
     tar_path: PathBuf,
     dest: PathBuf,
 }
@@ -0,0 +1,366 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use peace::{
+    cfg::{app_name, item_id, profile, DiffSeverity, FlowId, Item, ItemId, Profile},
+    cmd::ctx::CmdCtx,
+    cmd_model::CmdOutcome,
+    rt::cmds::{CleanCmd, EnsureCmd, StatesDiscoverCmd},
+    rt_model::{Flow, InMemoryOutput, ItemGraph, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+use peace_items::template_render::{
+    TemplateRenderError, TemplateRenderItem, TemplateRenderParams, TemplateRenderState,
+    TemplateRenderStateDiff, TemplateRenderStateDiffFn,
+};
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct TemplateRenderTest;
+
+impl TemplateRenderTest {
+    const ID: &'static ItemId = &item_id!("template_render_test");
+}
+
+const TEMPLATE: &str = "\
+[server]
+host = \"{{ host }}\"
+port = {{ port }}
+{% for feature in features %}\
+feature = \"{{ feature }}\"
+{% endfor %}";
+
+#[test]
+fn clone() {
+    let _item = Clone::clone(&TemplateRenderItem::<()>::new(
+        TemplateRenderTest::ID.clone(),
+    ));
+}
+
+#[tokio::test]
+async fn state_current_returns_none_when_dest_not_exists(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+        dest,
+    } = test_env().await?;
+    let flow = Flow::new(flow_id, graph);
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<TemplateRenderItem<TemplateRenderTest>>(
+            TemplateRenderTest::ID.clone(),
+            params(dest, values()).into(),
+        )
+        .await?;
+
+    let CmdOutcome::Complete {
+        value: states_current,
+        cmd_blocks_processed: _,
+    } = StatesDiscoverCmd::current(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::current` to complete successfully.");
+    };
+    let state_current = states_current
+        .get::<TemplateRenderState, _>(TemplateRenderTest::ID)
+        .unwrap();
+
+    assert_eq!(&TemplateRenderState::None, state_current);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn ensure_renders_template_into_dest() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+        dest,
+    } = test_env().await?;
+    let flow = Flow::new(flow_id, graph);
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<TemplateRenderItem<TemplateRenderTest>>(
+            TemplateRenderTest::ID.clone(),
+            params(dest.clone(), values()).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let CmdOutcome::Complete {
+        value: states_ensured,
+        cmd_blocks_processed: _,
+    } = EnsureCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec` to complete successfully.");
+    };
+    let state_ensured = states_ensured
+        .get::<TemplateRenderState, _>(TemplateRenderTest::ID)
+        .unwrap();
+
+    assert_eq!(
+        "\
+[server]
+host = \"localhost\"
+port = 8080
+feature = \"metrics\"
+feature = \"tracing\"
+",
+        tokio::fs::read_to_string(&dest).await?
+    );
+    assert!(
+        matches!(
+            state_ensured,
+            TemplateRenderState::Rendered {
+                params_digest: Some(_),
+                ..
+            }
+        ),
+        "Expected rendered state with params digest, but was: {state_ensured:?}"
+    );
+
+    // Execute again to check idempotence.
+    //
+    // The params digest is carried over from the stored state, so the command
+    // context is rebuilt to read the state stored by the previous execution.
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<TemplateRenderItem<TemplateRenderTest>>(
+            TemplateRenderTest::ID.clone(),
+            params(dest.clone(), values()).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+    let CmdOutcome::Complete {
+        value: states_ensured_again,
+        cmd_blocks_processed: _,
+    } = EnsureCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec` to complete successfully.");
+    };
+    let state_ensured_again = states_ensured_again
+        .get::<TemplateRenderState, _>(TemplateRenderTest::ID)
+        .unwrap();
+
+    assert_eq!(state_ensured, state_ensured_again);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn clean_removes_dest() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        mut output,
+        dest,
+    } = test_env().await?;
+    let flow = Flow::new(flow_id, graph);
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<TemplateRenderItem<TemplateRenderTest>>(
+            TemplateRenderTest::ID.clone(),
+            params(dest.clone(), values()).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    EnsureCmd::exec(&mut cmd_ctx).await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    let CmdOutcome::Complete {
+        value: states_cleaned,
+        cmd_blocks_processed: _,
+    } = CleanCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `CleanCmd::exec` to complete successfully.");
+    };
+    let state_cleaned = states_cleaned
+        .get::<TemplateRenderState, _>(TemplateRenderTest::ID)
+        .unwrap();
+
+    assert_eq!(&TemplateRenderState::None, state_cleaned);
+    assert!(!dest.exists());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_diff_contains_changed_lines_when_contents_known(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let state_current = TemplateRenderState::Rendered {
+        content_hash: String::from("a"),
+        params_digest: None,
+        content: Some(String::from("host = \"localhost\"\nport = 8080\n")),
+    };
+    let state_goal = TemplateRenderState::Rendered {
+        content_hash: String::from("b"),
+        params_digest: Some(String::from("digest")),
+        content: Some(String::from("host = \"localhost\"\nport = 9090\n")),
+    };
+
+    let state_diff = TemplateRenderStateDiffFn::state_diff(&state_current, &state_goal).await?;
+
+    let TemplateRenderStateDiff::Modified {
+        content_hash_from,
+        content_hash_to,
+        content_diff: Some(content_diff),
+    } = &state_diff
+    else {
+        panic!("Expected `Modified` diff with content diff, but was: {state_diff:?}");
+    };
+    assert_eq!("a", content_hash_from);
+    assert_eq!("b", content_hash_to);
+    assert!(content_diff.contains("-port = 8080\n"));
+    assert!(content_diff.contains("+port = 9090\n"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_diff_is_in_sync_when_content_hash_matches() -> Result<(), Box<dyn std::error::Error>>
+{
+    // Stored states do not carry contents, and the params digest may differ
+    // while the rendered contents are the same.
+    let state_current = TemplateRenderState::Rendered {
+        content_hash: String::from("a"),
+        params_digest: None,
+        content: None,
+    };
+    let state_goal = TemplateRenderState::Rendered {
+        content_hash: String::from("a"),
+        params_digest: Some(String::from("digest")),
+        content: Some(String::from("contents")),
+    };
+
+    let state_diff = TemplateRenderStateDiffFn::state_diff(&state_current, &state_goal).await?;
+
+    assert_eq!(TemplateRenderStateDiff::InSync, state_diff);
+
+    Ok(())
+}
+
+#[test]
+fn diff_severity_is_destructive_when_file_removed() {
+    let state = TemplateRenderState::None;
+    let state_diff_modified = TemplateRenderStateDiff::Modified {
+        content_hash_from: String::from("a"),
+        content_hash_to: String::from("b"),
+        content_diff: None,
+    };
+    let state_diff_removed = TemplateRenderStateDiff::Removed {
+        content_hash: String::from("a"),
+    };
+
+    assert_eq!(
+        DiffSeverity::InSync,
+        <TemplateRenderItem<TemplateRenderTest> as Item>::diff_severity(
+            &state,
+            &state,
+            &TemplateRenderStateDiff::InSync
+        )
+    );
+    assert_eq!(
+        DiffSeverity::Additive,
+        <TemplateRenderItem<TemplateRenderTest> as Item>::diff_severity(
+            &state,
+            &state,
+            &state_diff_modified
+        )
+    );
+    assert_eq!(
+        DiffSeverity::Destructive,
+        <TemplateRenderItem<TemplateRenderTest> as Item>::diff_severity(
+            &state,
+            &state,
+            &state_diff_removed
+        )
+    );
+}
+
+#[test]
+fn state_diff_display_uses_content_hashes_when_content_diff_absent() {
+    let state_diff = TemplateRenderStateDiff::Modified {
+        content_hash_from: String::from("0123456789abcdef"),
+        content_hash_to: String::from("fedcba9876543210"),
+        content_diff: None,
+    };
+
+    assert_eq!(
+        "content will change from `01234567` to `fedcba98`",
+        state_diff.to_string()
+    );
+    assert_eq!("in sync", TemplateRenderStateDiff::InSync.to_string());
+}
+
+fn params(
+    dest: PathBuf,
+    values: BTreeMap<String, serde_json::Value>,
+) -> TemplateRenderParams<TemplateRenderTest> {
+    TemplateRenderParams::<TemplateRenderTest>::new(String::from(TEMPLATE), values, dest)
+}
+
+/// Returns the values to render the template with in tests.
+fn values() -> BTreeMap<String, serde_json::Value> {
+    BTreeMap::from([
+        (String::from("host"), serde_json::json!("localhost")),
+        (String::from("port"), serde_json::json!(8080)),
+        (
+            String::from("features"),
+            serde_json::json!(["metrics", "tracing"]),
+        ),
+    ])
+}
+
+async fn test_env() -> Result<TestEnv, Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let profile = profile!("test_profile");
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<TemplateRenderError>::new();
+        graph_builder.add_fn(
+            TemplateRenderItem::<TemplateRenderTest>::new(TemplateRenderTest::ID.clone()).into(),
+        );
+        graph_builder.build()
+    };
+    let output = InMemoryOutput::new();
+    let dest = tempdir.path().join("config").join("server.toml");
+
+    Ok(TestEnv {
+        tempdir,
+        workspace,
+        profile,
+        graph,
+        output,
+        dest,
+    })
+}
+
+#[derive(Debug)]
+struct TestEnv {
+    tempdir: TempDir,
+    workspace: Workspace,
+    profile: Profile,
+    graph: ItemGraph<TemplateRenderError>,
+    output: InMemoryOutput,
+    dest: PathBuf,
+}
@@ -0,0 +1,349 @@
+use std::time::Duration;
+
+use peace::{
+    cfg::{app_name, item_id, profile, DiffSeverity, FlowId, Item, ItemId},
+    cmd::ctx::CmdCtx,
+    cmd_model::CmdOutcome,
+    rt::cmds::{CleanCmd, EnsureCmd, StatesDiscoverCmd},
+    rt_model::{Flow, InMemoryOutput, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+use peace_items::tunnel::{
+    TunnelError, TunnelItem, TunnelLiveness, TunnelParams, TunnelPidFile, TunnelState,
+    TunnelStateDiff, TunnelStateDiffFn,
+};
+use pretty_assertions::assert_eq;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct TunnelTest;
+
+impl TunnelTest {
+    const ID: &'static ItemId = &item_id!("tunnel_test");
+}
+
+const COMMAND: &str = "ssh -N -L 15432:db.internal:5432 bastion";
+const LOCAL_PORT: u16 = 15432;
+const PID: u32 = 12345;
+const START_TIME: &str = "8523401";
+
+fn state_alive(pid: Option<u32>, command: &str) -> TunnelState {
+    TunnelState::new(
+        pid,
+        Some(LOCAL_PORT),
+        Some(String::from(command)),
+        TunnelLiveness::Alive,
+    )
+}
+
+#[test]
+fn clone() {
+    let _item = Clone::clone(&TunnelItem::<()>::new(TunnelTest::ID.clone()));
+}
+
+#[test]
+fn pid_file_round_trips_through_contents() {
+    let tunnel_pid_file = TunnelPidFile::new(
+        PID,
+        String::from(START_TIME),
+        LOCAL_PORT,
+        String::from(COMMAND),
+    );
+    let contents = tunnel_pid_file.to_string();
+
+    assert_eq!(
+        format!("{PID}\n{START_TIME}\n{LOCAL_PORT}\n{COMMAND}\n"),
+        contents
+    );
+    assert_eq!(Some(tunnel_pid_file), TunnelPidFile::parse(&contents));
+}
+
+#[test]
+fn pid_file_parse_returns_none_when_contents_invalid() {
+    assert_eq!(None, TunnelPidFile::parse(""));
+    assert_eq!(None, TunnelPidFile::parse("not_a_pid\n15432\n"));
+    assert_eq!(None, TunnelPidFile::parse(&format!("{PID}\n")));
+    assert_eq!(
+        None,
+        TunnelPidFile::parse(&format!("{PID}\n\n{LOCAL_PORT}\n"))
+    );
+    assert_eq!(
+        None,
+        TunnelPidFile::parse(&format!("{PID}\n{START_TIME}\n99999\n"))
+    );
+}
+
+#[cfg(target_os = "linux")]
+#[tokio::test]
+async fn ensure_terminates_tunnel_process_when_it_does_not_accept_connections(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let pid_file = tempdir.path().join("tunnel.pid");
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<TunnelError>::new();
+        graph_builder.add_fn(TunnelItem::<TunnelTest>::new(TunnelTest::ID.clone()).into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = InMemoryOutput::new();
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<TunnelItem<TunnelTest>>(
+            TunnelTest::ID.clone(),
+            TunnelParams::<TunnelTest>::new(
+                String::from("sleep"),
+                vec![String::from("30")],
+                LOCAL_PORT,
+                pid_file.clone(),
+                Duration::from_millis(200),
+            )
+            .into(),
+        )
+        .await?;
+
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    let CmdOutcome::ItemError { errors, .. } = EnsureCmd::exec(&mut cmd_ctx).await? else {
+        panic!("Expected `EnsureCmd::exec` to return an item error.");
+    };
+    let error = errors.get(TunnelTest::ID);
+    assert!(
+        matches!(error, Some(TunnelError::TunnelStartTimeout { .. })),
+        "Expected error to be `TunnelStartTimeout`, but was: {error:?}"
+    );
+
+    // The tunnel process is identified by its recorded start time, and
+    // terminated.
+    let tunnel_pid_file = TunnelPidFile::parse(&tokio::fs::read_to_string(&pid_file).await?)
+        .expect("Expected pid file to be written.");
+    let process_stat = tokio::fs::read_to_string(format!("/proc/{}/stat", tunnel_pid_file.pid))
+        .await
+        .unwrap_or_default();
+    assert!(
+        process_stat.is_empty() || process_stat.contains(") Z "),
+        "Expected tunnel process to be terminated, but was: {process_stat}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_current_is_clean_and_process_is_not_signalled_when_start_time_differs(
+) -> Result<(), Box<dyn std::error::Error>> {
+    // A process that reused the tunnel process's ID.
+    let mut process_other = std::process::Command::new("sleep").arg("30").spawn()?;
+    let tempdir = tempfile::tempdir()?;
+    let pid_file = tempdir.path().join("tunnel.pid");
+    let tunnel_pid_file = TunnelPidFile::new(
+        process_other.id(),
+        String::from("0"),
+        LOCAL_PORT,
+        String::from(COMMAND),
+    );
+    tokio::fs::write(&pid_file, tunnel_pid_file.to_string()).await?;
+
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<TunnelError>::new();
+        graph_builder.add_fn(TunnelItem::<TunnelTest>::new(TunnelTest::ID.clone()).into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = InMemoryOutput::new();
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<TunnelItem<TunnelTest>>(
+            TunnelTest::ID.clone(),
+            TunnelParams::<TunnelTest>::new(
+                String::from("ssh"),
+                vec![String::from("-N")],
+                LOCAL_PORT,
+                pid_file,
+                Duration::from_secs(1),
+            )
+            .into(),
+        )
+        .await?;
+
+    let CmdOutcome::Complete {
+        value: states_current,
+        cmd_blocks_processed: _,
+    } = StatesDiscoverCmd::current(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::current` to complete successfully.");
+    };
+    assert_eq!(
+        Some(&TunnelState::clean()),
+        states_current.get::<TunnelState, _>(TunnelTest::ID)
+    );
+
+    CleanCmd::exec(&mut cmd_ctx).await?;
+    let process_other_exit_status = process_other.try_wait()?;
+    process_other.kill()?;
+    process_other.wait()?;
+    assert_eq!(None, process_other_exit_status);
+
+    Ok(())
+}
+
+#[test]
+fn command_string_quotes_args_with_whitespace() {
+    let args = [
+        String::from("-c"),
+        String::from("exec ssh -N bastion"),
+        String::new(),
+    ];
+
+    assert_eq!(
+        "sh -c 'exec ssh -N bastion' ''",
+        TunnelState::command_string("sh", &args)
+    );
+}
+
+#[tokio::test]
+async fn state_diff_is_in_sync_when_only_pid_differs() -> Result<(), Box<dyn std::error::Error>> {
+    let state_current = state_alive(Some(PID), COMMAND);
+    let state_goal = state_alive(None, COMMAND);
+
+    let state_diff = TunnelStateDiffFn::state_diff(&state_current, &state_goal).await?;
+
+    assert!(state_diff.is_in_sync());
+    assert_eq!("in sync", state_diff.to_string());
+    assert_eq!(
+        DiffSeverity::InSync,
+        <TunnelItem<TunnelTest> as Item>::diff_severity(&state_current, &state_goal, &state_diff)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_diff_from_stopped_starts_tunnel() -> Result<(), Box<dyn std::error::Error>> {
+    let state_current = TunnelState::clean();
+    let state_goal = state_alive(None, COMMAND);
+
+    let state_diff = TunnelStateDiffFn::state_diff(&state_current, &state_goal).await?;
+
+    assert!(!state_diff.is_restart_required());
+    assert_eq!("tunnel will be started", state_diff.to_string());
+    assert_eq!(
+        DiffSeverity::Additive,
+        <TunnelItem<TunnelTest> as Item>::diff_severity(&state_current, &state_goal, &state_diff)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_diff_to_clean_stops_tunnel() -> Result<(), Box<dyn std::error::Error>> {
+    let state_current = state_alive(Some(PID), COMMAND);
+    let state_clean = TunnelState::clean();
+
+    let state_diff = TunnelStateDiffFn::state_diff(&state_current, &state_clean).await?;
+
+    assert!(!state_diff.is_restart_required());
+    assert_eq!("tunnel will be stopped", state_diff.to_string());
+    assert_eq!(
+        DiffSeverity::Destructive,
+        <TunnelItem<TunnelTest> as Item>::diff_severity(&state_current, &state_clean, &state_diff)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_diff_returns_command_and_local_port_drift() -> Result<(), Box<dyn std::error::Error>>
+{
+    let state_current = state_alive(Some(PID), COMMAND);
+    let state_goal = TunnelState::new(
+        None,
+        Some(15433),
+        Some(String::from("ssh -N -L 15433:db.internal:5432 bastion")),
+        TunnelLiveness::Alive,
+    );
+
+    let state_diff = TunnelStateDiffFn::state_diff(&state_current, &state_goal).await?;
+
+    assert_eq!(
+        TunnelStateDiff {
+            local_port: Some((Some(LOCAL_PORT), Some(15433))),
+            command: Some((
+                Some(String::from(COMMAND)),
+                Some(String::from("ssh -N -L 15433:db.internal:5432 bastion"))
+            )),
+            liveness: None,
+        },
+        state_diff
+    );
+    assert!(state_diff.is_restart_required());
+    assert_eq!(
+        "local port 15432 -> 15433, command will be modified",
+        state_diff.to_string()
+    );
+    assert_eq!(
+        DiffSeverity::Destructive,
+        <TunnelItem<TunnelTest> as Item>::diff_severity(&state_current, &state_goal, &state_diff)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_diff_restarts_unresponsive_tunnel() -> Result<(), Box<dyn std::error::Error>> {
+    let state_current = TunnelState {
+        liveness: TunnelLiveness::Unresponsive,
+        ..state_alive(Some(PID), COMMAND)
+    };
+    let state_goal = state_alive(None, COMMAND);
+
+    let state_diff = TunnelStateDiffFn::state_diff(&state_current, &state_goal).await?;
+
+    assert!(state_diff.is_restart_required());
+    assert_eq!("unresponsive -> alive", state_diff.to_string());
+    assert_eq!(
+        DiffSeverity::Additive,
+        <TunnelItem<TunnelTest> as Item>::diff_severity(&state_current, &state_goal, &state_diff)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn state_display() {
+    assert_eq!(
+        "`ssh -N -L 15432:db.internal:5432 bastion` on port 15432 (pid 12345), alive",
+        state_alive(Some(PID), COMMAND).to_string()
+    );
+    assert_eq!(
+        "`ssh -N -L 15432:db.internal:5432 bastion` on port 15432, alive",
+        state_alive(None, COMMAND).to_string()
+    );
+    assert_eq!("tunnel is not running", TunnelState::clean().to_string());
+}
+
+#[test]
+fn error_help_suggests_increasing_timeout_when_tunnel_start_times_out() {
+    let error = TunnelError::TunnelStartTimeout {
+        command: String::from(COMMAND),
+        local_port: LOCAL_PORT,
+        timeout: Duration::from_secs(10),
+    };
+
+    assert_eq!(
+        "Tunnel `ssh -N -L 15432:db.internal:5432 bastion` did not accept connections on port \
+        15432 within 10s.",
+        error.to_string()
+    );
+    assert_eq!(
+        vec![String::from(
+            "Check that the tunnel forwards the local port, or increase the startup timeout."
+        )],
+        TunnelItem::<TunnelTest>::error_help(&error)
+    );
+}
@@ -9,15 +9,20 @@ pub(crate) use crate::{
     no_op_output::NoOpOutput,
     peace_test_error::PeaceTestError,
     vec_copy_item::{
-        VecA, VecB, VecCopyDiff, VecCopyError, VecCopyItem, VecCopyItemWrapper, VecCopyState,
+        VecA, VecB, VecCopyDiff, VecCopyError, VecCopyItem, VecCopyItemWrapper, VecCopyOutput,
+        VecCopyState,
     },
 };
 
 pub(crate) mod mock_item;
+#[cfg(feature = "telemetry")]
+pub(crate) mod recording_meter_provider;
+pub(crate) mod token_item;
 
 // `peace` test modules
 mod cfg;
 mod cli;
+mod cli_args;
 mod cmd;
 mod cmd_model;
 mod cmd_rt;
@@ -1,4 +1,5 @@
 use std::{
+    any::TypeId,
     fmt,
     fmt::Debug,
     marker::PhantomData,
@@ -8,7 +9,10 @@ use std::{
 #[cfg(feature = "output_progress")]
 use peace::cfg::progress::{ProgressLimit, ProgressMsgUpdate};
 use peace::{
-    cfg::{async_trait, item_id, ApplyCheck, FnCtx, Item, ItemId},
+    cfg::{
+        async_trait, item_id, profile, ApplyCheck, ApplyCtx, FnCtx, Item, ItemId, ItemInputs,
+        Profile,
+    },
     data::{
         accessors::{RMaybe, R, W},
         Data,
@@ -18,6 +22,12 @@ use peace::{
 };
 use serde::{Deserialize, Serialize};
 
+use crate::VecCopyOutput;
+
+/// `Id` for a `MockItem` that reads `VecCopyItem`'s `VecCopyOutput`.
+#[derive(Clone, Debug, Default)]
+pub struct MockReadsVecCopyOutput;
+
 /// Copies a number from `MockSrc` to `MockDest`.
 ///
 /// This also allows each item function to be overridden.
@@ -44,6 +54,7 @@ type FnStateClean<Id> =
 type FnState<Id> = fn(FnCtx<'_>, &MockSrc, MockData<'_, Id>) -> Result<MockState, MockItemError>;
 
 type FnApplyCheck<Id> = fn(
+    ApplyCtx<'_>,
     &MockSrc,
     MockData<'_, Id>,
     &MockState,
@@ -91,6 +102,8 @@ where
     Id: Clone + Debug + Default + Send + Sync + 'static,
 {
     pub const ID_DEFAULT: &'static ItemId = &item_id!("mock");
+    /// Profile that this item is not applicable to.
+    pub const PROFILE_NOT_APPLICABLE: &'static Profile = &profile!("mock_not_applicable");
 
     pub fn new(id: ItemId) -> Self {
         Self {
@@ -199,6 +212,32 @@ where
         &self.id
     }
 
+    fn applicable(_params_partial: &<MockSrc as Params>::Partial, profile: &Profile) -> bool {
+        profile != Self::PROFILE_NOT_APPLICABLE
+    }
+
+    fn inputs() -> ItemInputs {
+        if TypeId::of::<Id>() == TypeId::of::<MockReadsVecCopyOutput>() {
+            ItemInputs::new().with_input::<VecCopyOutput>()
+        } else {
+            ItemInputs::new()
+        }
+    }
+
+    fn error_help(error: &MockItemError) -> Vec<String> {
+        match error {
+            MockItemError::Synthetic(_) => vec![String::from("Synthetic errors can be retried.")],
+            MockItemError::PeaceRtError(_) => Vec::new(),
+        }
+    }
+
+    #[cfg(feature = "item_schema")]
+    fn schema(
+        generator: &mut peace::schemars::gen::SchemaGenerator,
+    ) -> Option<peace::cfg::ItemSchema> {
+        Some(peace::cfg::ItemSchema::new::<Self>(generator))
+    }
+
     async fn state_clean(
         params_partial: &<Self::Params<'_> as Params>::Partial,
         data: Self::Data<'_>,
@@ -272,6 +311,7 @@ where
     }
 
     async fn apply_check(
+        apply_ctx: ApplyCtx<'_>,
         params: &Self::Params<'_>,
         data: Self::Data<'_>,
         state_current: &Self::State,
@@ -279,7 +319,7 @@ where
         diff: &Self::StateDiff,
     ) -> Result<ApplyCheck, Self::Error> {
         if let Some(apply_check) = data.mock_fns().apply_check.as_ref() {
-            apply_check(params, data, state_current, state_target, diff)
+            apply_check(apply_ctx, params, data, state_current, state_target, diff)
         } else {
             let apply_check = if diff.0 == 0 {
                 ApplyCheck::ExecNotRequired
@@ -288,7 +328,7 @@ where
                 {
                     let _state_current = state_current;
                     let _state_target = state_target;
-                    ApplyCheck::ExecRequired
+                    ApplyCheck::ExecRequired { reason: None }
                 }
                 #[cfg(feature = "output_progress")]
                 {
@@ -297,7 +337,10 @@ where
                         ProgressLimit::Bytes(byte_count)
                     };
 
-                    ApplyCheck::ExecRequired { progress_limit }
+                    ApplyCheck::ExecRequired {
+                        progress_limit,
+                        reason: None,
+                    }
                 }
             };
             Ok(apply_check)
@@ -415,6 +458,11 @@ where
     }
 }
 
+#[cfg_attr(
+    feature = "item_schema",
+    derive(peace::schemars::JsonSchema),
+    schemars(crate = "peace::schemars")
+)]
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Params)]
 pub struct MockSrc(pub u8);
 
@@ -461,6 +509,11 @@ impl fmt::Display for MockDest {
     }
 }
 
+#[cfg_attr(
+    feature = "item_schema",
+    derive(peace::schemars::JsonSchema),
+    schemars(crate = "peace::schemars")
+)]
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MockState(pub u8);
 
@@ -491,6 +544,11 @@ impl fmt::Display for MockState {
     }
 }
 
+#[cfg_attr(
+    feature = "item_schema",
+    derive(peace::schemars::JsonSchema),
+    schemars(crate = "peace::schemars")
+)]
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MockDiff(pub i16);
 
@@ -2,13 +2,18 @@ mod any_spec_rt;
 mod any_spec_rt_boxed;
 mod derive;
 mod field_name_and_type;
+mod mapping_expr;
 mod mapping_fn_impl;
+mod params_resolve_error;
 mod params_spec;
 mod params_spec_de;
 mod params_spec_fieldless;
 mod params_spec_fieldless_de;
 mod params_specs;
+mod params_validation_error;
+mod value_resolution;
 mod value_resolution_ctx;
 mod value_resolution_mode;
+mod value_resolution_source;
 mod value_spec;
 mod value_spec_de;
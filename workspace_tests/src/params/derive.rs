@@ -71,7 +71,9 @@ mod struct_params {
 
     use peace::{
         cfg::item_id,
-        params::{Params, ParamsSpec, ValueResolutionCtx, ValueResolutionMode, ValueSpec},
+        params::{
+            MappingExpr, Params, ParamsSpec, ValueResolutionCtx, ValueResolutionMode, ValueSpec,
+        },
         resources::{resources::ts::SetUp, Resources},
     };
 
@@ -157,6 +159,80 @@ mod struct_params {
         ));
     }
 
+    #[test]
+    fn field_wise_from_field_wise_builder_params_by_key() {
+        let field_wise = StructParams::field_wise_spec()
+            .with_src_from_workspace_param(&String::from("src"))
+            .with_dest_from_profile_param(&String::from("dest"))
+            .build();
+
+        assert!(matches!(
+            field_wise,
+            ParamsSpec::FieldWise {
+                field_wise_spec: StructParamsFieldWise {
+                    src: ValueSpec::FromWorkspaceParam(src_key),
+                    dest: ValueSpec::FromProfileParam(dest_key),
+                }
+            }
+            if src_key == "src"
+            && dest_key == "dest"
+        ));
+    }
+
+    #[test]
+    fn field_wise_from_field_wise_builder_expr() {
+        let field_wise = StructParams::field_wise_spec()
+            .with_src_from_expr(MappingExpr::literal("a"))
+            .with_dest(String::from("b"))
+            .build();
+
+        assert!(matches!(
+            field_wise,
+            ParamsSpec::FieldWise {
+                field_wise_spec: StructParamsFieldWise {
+                    src: ValueSpec::MappingExpr { expr, .. },
+                    dest: ValueSpec::Value { value: dest_value },
+                }
+            }
+            if expr == MappingExpr::literal("a")
+            && dest_value == "b"
+        ));
+    }
+
+    #[test]
+    fn field_wise_from_spec_builder_typed_setters() {
+        let field_wise = StructParamsSpecBuilder::default()
+            .with_dest_spec(|_: &u32| Some(String::from("b")))
+            .with_src_spec(String::from("a"))
+            .build();
+        let resources: Resources<SetUp> = {
+            let mut resources = Resources::new();
+            resources.insert(1u32);
+            Resources::from(resources)
+        };
+        let mut value_resolution_ctx = ValueResolutionCtx::new(
+            ValueResolutionMode::ApplyDry,
+            item_id!("field_wise_from_spec_builder_typed_setters"),
+            String::from("StructParams"),
+        );
+
+        assert!(matches!(
+            field_wise,
+            ParamsSpec::FieldWise {
+                field_wise_spec: StructParamsFieldWise {
+                    src: ValueSpec::Value { value: src_value },
+                    dest: ValueSpec::MappingFn(mapping_fn),
+                }
+            }
+            if src_value == "a"
+            && matches!(
+                mapping_fn.map(&resources, &mut value_resolution_ctx),
+                Ok(dest_mapped)
+                if dest_mapped == "b"
+            )
+        ));
+    }
+
     #[test]
     fn spec_debug() {
         assert_eq!(
@@ -368,6 +444,26 @@ mod struct_with_type_params {
         ));
     }
 
+    #[test]
+    fn field_wise_from_spec_builder_typed_setters() {
+        let field_wise = StructWithTypeParamsSpecBuilder::<()>::default()
+            .with_src_spec(ValueSpec::<String>::InMemory)
+            .with_dest_spec(String::from("b"))
+            .build();
+
+        assert!(matches!(
+            field_wise,
+            ParamsSpec::FieldWise {
+                field_wise_spec: StructWithTypeParamsFieldWise {
+                    src: ValueSpec::InMemory,
+                    dest: ValueSpec::Value { value: dest_value },
+                    marker: PhantomData,
+                }
+            }
+            if dest_value == "b"
+        ));
+    }
+
     #[test]
     fn spec_debug() {
         assert_eq!(
@@ -859,6 +955,132 @@ mod tuple_with_type_params {
     }
 }
 
+mod tuple_named_params {
+    use std::any::TypeId;
+
+    use serde::{Deserialize, Serialize};
+
+    use peace::{
+        cfg::item_id,
+        params::{
+            Params, ParamsResolveError, ParamsSpec, ValueResolutionCtx, ValueResolutionMode,
+            ValueSpec, ValueSpecRt,
+        },
+        resources::{resources::ts::SetUp, Resources},
+    };
+
+    #[derive(Clone, Debug, Params, Serialize, Deserialize)]
+    pub struct TupleNamedParams(
+        /// Source / goal value for the state.
+        #[params(field_name = "src")]
+        String,
+        /// Destination storage for the state.
+        #[params(field_name = "dest")]
+        String,
+    );
+
+    super::params_tests!(
+        TupleNamedParams,
+        TupleNamedParamsFieldWise,
+        TupleNamedParamsPartial,
+        []
+    );
+
+    #[test]
+    fn field_wise_from_field_wise_builder() {
+        let field_wise = TupleNamedParams::field_wise_spec()
+            .with_src_in_memory()
+            .with_dest_from_map(|_: &u32| Some(String::from("b")))
+            .build();
+        let resources: Resources<SetUp> = {
+            let mut resources = Resources::new();
+            resources.insert(1u32);
+            Resources::from(resources)
+        };
+        let mut value_resolution_ctx = ValueResolutionCtx::new(
+            ValueResolutionMode::ApplyDry,
+            item_id!("field_wise_from_field_wise_builder"),
+            String::from("TupleNamedParams"),
+        );
+
+        assert!(matches!(
+            field_wise,
+            ParamsSpec::FieldWise {
+                field_wise_spec: TupleNamedParamsFieldWise(
+                    ValueSpec::InMemory,
+                    ValueSpec::MappingFn(mapping_fn),
+                )
+            }
+            if matches!(
+                mapping_fn.map(&resources, &mut value_resolution_ctx),
+                Ok(dest_mapped)
+                if dest_mapped == "b"
+            )
+        ));
+    }
+
+    #[test]
+    fn field_wise_from_spec_builder_typed_setters() {
+        let field_wise = TupleNamedParams::field_wise_spec()
+            .with_src(String::from("a"))
+            .with_dest_spec(ValueSpec::<String>::InMemory)
+            .build();
+
+        assert!(matches!(
+            field_wise,
+            ParamsSpec::FieldWise {
+                field_wise_spec: TupleNamedParamsFieldWise(
+                    ValueSpec::Value { value: src_value },
+                    ValueSpec::InMemory,
+                )
+            }
+            if src_value == "a"
+        ));
+    }
+
+    #[test]
+    fn field_wise_and_partial_getters_use_field_names() {
+        let field_wise = TupleNamedParamsFieldWise::new(
+            ValueSpec::Value {
+                value: String::from("a"),
+            },
+            ValueSpec::InMemory,
+        );
+        let params_partial = TupleNamedParamsPartial::new(Some(String::from("a")), None);
+
+        assert!(matches!(
+            field_wise.src(),
+            ValueSpec::Value { value } if value == "a"
+        ));
+        assert!(matches!(field_wise.dest(), ValueSpec::InMemory));
+        assert_eq!(Some("a"), params_partial.src());
+        assert_eq!(None, params_partial.dest());
+    }
+
+    #[test]
+    fn resolve_error_field_path_uses_field_name() {
+        let field_wise = TupleNamedParams::field_wise_spec()
+            .with_src(String::from("a"))
+            .with_dest_in_memory()
+            .build();
+        let resources = Resources::<SetUp>::from(Resources::new());
+        let mut value_resolution_ctx = ValueResolutionCtx::new(
+            ValueResolutionMode::Current,
+            item_id!("resolve_error_field_path_uses_field_name"),
+            String::from("TupleNamedParams"),
+        );
+
+        let params_result =
+            ValueSpecRt::resolve(&field_wise, &resources, &mut value_resolution_ctx);
+
+        assert!(matches!(
+            &params_result,
+            Err(params_resolve_error @ ParamsResolveError::InMemory { .. })
+            if params_resolve_error.field_path() == "dest"
+        ));
+    }
+}
+
 mod enum_params {
     use std::{any::TypeId, marker::PhantomData};
 
@@ -2588,6 +2810,179 @@ mod external_fields {
     }
 }
 
+mod struct_validated {
+    use std::{any::TypeId, fmt::Debug};
+
+    use serde::{Deserialize, Serialize};
+
+    use peace::params::{Params, ParamsSpec, ParamsValidationError};
+
+    #[derive(Clone, Debug, Params, PartialEq, Eq, Serialize, Deserialize)]
+    #[params(validate = "struct_validated_validate")]
+    pub struct StructValidated {
+        /// Port to listen on.
+        port: u16,
+    }
+
+    fn struct_validated_validate(params: &StructValidated) -> Result<(), ParamsValidationError> {
+        if params.port >= 1024 {
+            Ok(())
+        } else {
+            Err(ParamsValidationError::field(
+                "port",
+                "Must be 1024 or above.",
+            ))
+        }
+    }
+
+    super::params_tests!(
+        StructValidated,
+        StructValidatedFieldWise,
+        StructValidatedPartial,
+        []
+    );
+
+    #[test]
+    fn params_validate_runs_validate_fn() {
+        assert_eq!(Ok(()), StructValidated { port: 8080 }.validate());
+        assert_eq!(
+            Err(ParamsValidationError::field(
+                "port",
+                "Must be 1024 or above."
+            )),
+            StructValidated { port: 80 }.validate()
+        );
+    }
+
+    #[test]
+    fn params_spec_validate_runs_validate_fn() {
+        let params_spec = ParamsSpec::from(StructValidated { port: 80 });
+
+        assert_eq!(
+            vec![ParamsValidationError::field(
+                "port",
+                "Must be 1024 or above."
+            )],
+            params_spec.validate(&StructValidated { port: 80 })
+        );
+    }
+}
+
+mod struct_constrained {
+    use std::{any::TypeId, fmt::Debug};
+
+    use serde::{Deserialize, Serialize};
+
+    use peace::params::{Params, ParamsSpec, ParamsValidationError};
+
+    #[derive(Clone, Debug, Params, PartialEq, Eq, Serialize, Deserialize)]
+    #[params(validate = "max_above_zero")]
+    #[params(validate_with = "min_not_above_max")]
+    #[params(validate_with = "self::tls_exclusive")]
+    pub struct StructConstrained {
+        /// Minimum number of replicas.
+        min: u16,
+        /// Maximum number of replicas.
+        max: u16,
+        /// Path to the TLS certificate.
+        tls_cert_path: Option<String>,
+        /// Whether to generate a self signed TLS certificate.
+        tls_self_signed: bool,
+    }
+
+    fn max_above_zero(params: &StructConstrained) -> Result<(), ParamsValidationError> {
+        if params.max > 0 {
+            Ok(())
+        } else {
+            Err(ParamsValidationError::field("max", "Must be above 0."))
+        }
+    }
+
+    fn min_not_above_max(params: &StructConstrained) -> Result<(), ParamsValidationError> {
+        if params.min <= params.max {
+            Ok(())
+        } else {
+            Err(ParamsValidationError::fields(
+                ["min", "max"],
+                "`min` must not be greater than `max`.",
+            ))
+        }
+    }
+
+    fn tls_exclusive(params: &StructConstrained) -> Result<(), ParamsValidationError> {
+        if params.tls_cert_path.is_some() && params.tls_self_signed {
+            Err(ParamsValidationError::fields(
+                ["tls_cert_path", "tls_self_signed"],
+                "Only one of these may be set.",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn struct_constrained(min: u16, max: u16, tls_self_signed: bool) -> StructConstrained {
+        StructConstrained {
+            min,
+            max,
+            tls_cert_path: Some(String::from("cert.pem")),
+            tls_self_signed,
+        }
+    }
+
+    super::params_tests!(
+        StructConstrained,
+        StructConstrainedFieldWise,
+        StructConstrainedPartial,
+        []
+    );
+
+    #[test]
+    fn params_validate_constraints_returns_no_errors_when_constraints_met() {
+        assert_eq!(
+            Vec::<ParamsValidationError>::new(),
+            struct_constrained(1, 3, false).validate_constraints()
+        );
+    }
+
+    #[test]
+    fn params_validate_constraints_runs_each_validate_with_fn() {
+        assert_eq!(
+            vec![
+                ParamsValidationError::fields(
+                    ["min", "max"],
+                    "`min` must not be greater than `max`."
+                ),
+                ParamsValidationError::fields(
+                    ["tls_cert_path", "tls_self_signed"],
+                    "Only one of these may be set."
+                ),
+            ],
+            struct_constrained(3, 1, true).validate_constraints()
+        );
+    }
+
+    #[test]
+    fn params_spec_validate_returns_validate_and_validate_with_errors() {
+        let params = struct_constrained(3, 0, true);
+        let params_spec = ParamsSpec::from(params.clone());
+
+        assert_eq!(
+            vec![
+                ParamsValidationError::field("max", "Must be above 0."),
+                ParamsValidationError::fields(
+                    ["min", "max"],
+                    "`min` must not be greater than `max`."
+                ),
+                ParamsValidationError::fields(
+                    ["tls_cert_path", "tls_self_signed"],
+                    "Only one of these may be set."
+                ),
+            ],
+            params_spec.validate(&params)
+        );
+    }
+}
+
 macro_rules! params_tests {
     (
         $params_ty:ident,
@@ -0,0 +1,170 @@
+use peace::{
+    params::{MappingExpr, MappingExprError, ProfileParamsSerialized, WorkspaceParamsSerialized},
+    resources::{resources::ts::SetUp, Resources},
+};
+use serde_yaml::Value;
+
+#[test]
+fn eval_literal() -> Result<(), MappingExprError> {
+    let resources = resources();
+
+    let value = MappingExpr::literal("app.tar").eval(&resources)?;
+
+    assert_eq!(Value::String(String::from("app.tar")), value);
+    Ok(())
+}
+
+#[test]
+fn eval_workspace_param_with_pointer() -> Result<(), MappingExprError> {
+    let resources = resources();
+
+    let value = MappingExpr::workspace_param(&String::from("repo"), "/path").eval(&resources)?;
+
+    assert_eq!(Value::String(String::from("/workspace/repo")), value);
+    Ok(())
+}
+
+#[test]
+fn eval_profile_param_with_sequence_pointer() -> Result<(), MappingExprError> {
+    let resources = resources();
+
+    let value = MappingExpr::profile_param(&String::from("regions"), "/1").eval(&resources)?;
+
+    assert_eq!(Value::String(String::from("us-east-1")), value);
+    Ok(())
+}
+
+#[test]
+fn eval_concat_with_string_ops() -> Result<(), MappingExprError> {
+    let resources = resources();
+
+    let value = MappingExpr::concat(vec![
+        MappingExpr::workspace_param(&String::from("repo"), "/path"),
+        MappingExpr::literal("/target/"),
+        MappingExpr::profile_param(&String::from("profile"), "")
+            .trim()
+            .replace("_", "-")
+            .lowercase(),
+        MappingExpr::literal("/"),
+        MappingExpr::workspace_param(&String::from("repo"), "/version"),
+        MappingExpr::literal(".tar"),
+    ])
+    .eval(&resources)?;
+
+    assert_eq!(
+        Value::String(String::from("/workspace/repo/target/demo-env/3.tar")),
+        value
+    );
+    Ok(())
+}
+
+#[test]
+fn eval_returns_err_when_workspace_param_not_found() {
+    let resources = resources();
+
+    let value_result = MappingExpr::workspace_param(&String::from("unknown"), "").eval(&resources);
+
+    assert!(
+        matches!(
+            &value_result,
+            Err(MappingExprError::WorkspaceParamNotFound { key })
+            if key == "unknown"
+        ),
+        "was {value_result:?}"
+    );
+}
+
+#[test]
+fn eval_returns_err_when_pointer_not_found() {
+    let resources = resources();
+
+    let value_result = MappingExpr::workspace_param(&String::from("repo"), "/url").eval(&resources);
+
+    assert!(
+        matches!(
+            &value_result,
+            Err(MappingExprError::PointerNotFound { key, pointer })
+            if key == "repo" && pointer == "/url"
+        ),
+        "was {value_result:?}"
+    );
+}
+
+#[test]
+fn eval_returns_err_when_string_op_on_mapping() {
+    let resources = resources();
+
+    let value_result = MappingExpr::workspace_param(&String::from("repo"), "")
+        .uppercase()
+        .eval(&resources);
+
+    assert!(
+        matches!(
+            &value_result,
+            Err(MappingExprError::ValueNotScalar { value: Value::Mapping(_) })
+        ),
+        "was {value_result:?}"
+    );
+}
+
+#[test]
+fn display() {
+    let expr = MappingExpr::concat(vec![
+        MappingExpr::workspace_param(&String::from("repo"), "/path"),
+        MappingExpr::profile_param(&String::from("profile"), "")
+            .replace("_", "-")
+            .uppercase(),
+    ]);
+
+    assert_eq!(
+        "concat(\
+            workspace_param(\"repo\")/path, \
+            uppercase(replace(profile_param(\"profile\"), \"_\", \"-\"))\
+        )",
+        expr.to_string()
+    );
+}
+
+#[test]
+fn serialize_deserialize_round_trip() -> Result<(), serde_yaml::Error> {
+    let expr = MappingExpr::concat(vec![
+        MappingExpr::workspace_param(&String::from("repo"), "/path"),
+        MappingExpr::literal("/app.tar"),
+    ]);
+
+    let serialized = serde_yaml::to_string(&expr)?;
+    assert_eq!(
+        r#"!Concat
+- !WorkspaceParam
+  key: repo
+  pointer: /path
+- !Literal /app.tar
+"#,
+        serialized
+    );
+    assert_eq!(expr, serde_yaml::from_str::<MappingExpr>(&serialized)?);
+
+    Ok(())
+}
+
+fn resources() -> Resources<SetUp> {
+    let mut workspace_params_serialized = WorkspaceParamsSerialized::new();
+    workspace_params_serialized.insert(
+        String::from("repo"),
+        serde_yaml::from_str("{ path: /workspace/repo, version: 3 }").unwrap(),
+    );
+    let mut profile_params_serialized = ProfileParamsSerialized::new();
+    profile_params_serialized.insert(
+        String::from("profile"),
+        Value::String(String::from(" Demo_Env ")),
+    );
+    profile_params_serialized.insert(
+        String::from("regions"),
+        serde_yaml::from_str("[ap-southeast-2, us-east-1]").unwrap(),
+    );
+
+    let mut resources = Resources::new();
+    resources.insert(workspace_params_serialized);
+    resources.insert(profile_params_serialized);
+    Resources::<SetUp>::from(resources)
+}
@@ -143,9 +143,10 @@ macro_rules! mapping_tests {
                         assert!(
                             matches!(
                                 &sum_result,
-                                Err(ParamsResolveError::FromMap {
+                                Err(ParamsResolveError::FromMapMarkerNone {
                                     value_resolution_ctx,
-                                    from_type_name
+                                    from_type_name,
+                                    marker_type_name,
                                 })
                                 if matches!(
                                     value_resolution_ctx,
@@ -157,10 +158,14 @@ macro_rules! mapping_tests {
                                     && value_resolution_ctx.params_type_name() == crate::fn_name_short!()
                                     && value_resolution_ctx.resolution_chain() == []
                                 )
-                                && from_type_name == "u64" // u64 is missing from `resources`
+                                && from_type_name == "u64" // u64 is `None` in `resources`
+                                && marker_type_name == concat!(
+                                    stringify!($value_resolution_mode),
+                                    "<u64>"
+                                )
                             ),
                             "expected `sum_result` to be \
-                            `Err(ParamsResolveError::FromMap {{ .. }}`,\n\
+                            `Err(ParamsResolveError::FromMapMarkerNone {{ .. }}`,\n\
                             but was {sum_result:?}"
                         );
                     }
@@ -228,6 +233,56 @@ macro_rules! mapping_tests {
                 Ok(())
             }
 
+            #[test]
+            fn mapping_fn_map_returns_err_when_mapping_fn_returns_none()
+            -> Result<(), ParamsResolveError> {
+                let mapping_fn_impl =
+                    MappingFnImpl::from((Some(String::from("field_name")), |a: &u32, b: &u64| {
+                        let a = u16::try_from(*a).ok()?;
+                        let b = u16::try_from(*b).ok()?;
+                        a.checked_add(b)
+                    }));
+                let resources = {
+                    let mut resources = Resources::new();
+                    resources.insert($value_resolution_mode(Some(1u32)));
+                    resources.insert($value_resolution_mode(Some(u64::MAX)));
+                    Resources::<SetUp>::from(resources)
+                };
+                let mut value_resolution_ctx = ValueResolutionCtx::new(
+                    ValueResolutionMode::$value_resolution_mode,
+                    item_id!("mapping_fn_map"),
+                    String::from(crate::fn_name_short!()),
+                );
+
+                let sum_result = MappingFn::map(
+                    &mapping_fn_impl,
+                    &resources,
+                    &mut value_resolution_ctx,
+                );
+
+                ({
+                    #[cfg_attr(coverage_nightly, coverage(off))]
+                    || {
+                        assert!(
+                            matches!(
+                                &sum_result,
+                                Err(ParamsResolveError::FromMapFnNone {
+                                    value_resolution_ctx,
+                                    from_type_name
+                                })
+                                if value_resolution_ctx.item_id() == &item_id!("mapping_fn_map")
+                                && from_type_name == "(u32, u64)"
+                            ),
+                            "expected `sum_result` to be \
+                            `Err(ParamsResolveError::FromMapFnNone {{ .. }}`,\n\
+                            but was {sum_result:?}"
+                        );
+                    }
+                })();
+
+                Ok(())
+            }
+
             #[test]
             fn mapping_fn_try_map_returns_ok_some_when_referenced_values_are_present()
             -> Result<(), ParamsResolveError> {
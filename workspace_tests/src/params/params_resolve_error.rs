@@ -0,0 +1,78 @@
+use peace::{
+    cfg::item_id,
+    params::{FieldNameAndType, ParamsResolveError, ValueResolutionCtx, ValueResolutionMode},
+};
+
+use crate::mock_item::MockSrc;
+
+fn value_resolution_ctx() -> ValueResolutionCtx {
+    let mut value_resolution_ctx = ValueResolutionCtx::new(
+        ValueResolutionMode::Goal,
+        item_id!("item_id"),
+        tynm::type_name::<MockSrc>(),
+    );
+    value_resolution_ctx.push(FieldNameAndType::new(
+        String::from("src"),
+        String::from("Src"),
+    ));
+    value_resolution_ctx.push(FieldNameAndType::new(
+        String::from("port"),
+        tynm::type_name::<u16>(),
+    ));
+    value_resolution_ctx
+}
+
+#[test]
+fn accessors_return_resolution_details() {
+    let params_resolve_error = ParamsResolveError::FromMapMarkerNone {
+        value_resolution_ctx: value_resolution_ctx(),
+        from_type_name: String::from("ServerPort"),
+        marker_type_name: String::from("Goal<ServerPort>"),
+    };
+
+    assert_eq!(&item_id!("item_id"), params_resolve_error.item_id());
+    assert_eq!("src.port", params_resolve_error.field_path());
+    assert_eq!("u16", params_resolve_error.field_type_name());
+    assert_eq!(
+        ValueResolutionMode::Goal,
+        params_resolve_error.value_resolution_mode()
+    );
+    assert_eq!(
+        Some("Goal<ServerPort>"),
+        params_resolve_error.missing_type_name()
+    );
+}
+
+#[test]
+fn missing_type_name_is_none_for_mapping_fn_none() {
+    let params_resolve_error = ParamsResolveError::FromMapFnNone {
+        value_resolution_ctx: value_resolution_ctx(),
+        from_type_name: String::from("(ServerPort,)"),
+    };
+
+    assert_eq!(None, params_resolve_error.missing_type_name());
+}
+
+#[test]
+fn display_from_map_marker_none_includes_item_id_and_marker() {
+    let params_resolve_error = ParamsResolveError::FromMapMarkerNone {
+        value_resolution_ctx: value_resolution_ctx(),
+        from_type_name: String::from("ServerPort"),
+        marker_type_name: String::from("Goal<ServerPort>"),
+    };
+
+    assert_eq!(
+        r#"`Goal<ServerPort>` is `None` when resolving `u16` for item `item_id` to populate:
+
+```rust
+MockSrc {
+    src: Src {
+        port: u16,
+        ..
+    },
+    ..
+}
+```"#,
+        params_resolve_error.to_string()
+    );
+}
@@ -1,8 +1,11 @@
+use std::any::TypeId;
+
 use peace::{
     cfg::item_id,
     params::{
         AnySpecRt, AnySpecRtBoxed, FieldNameAndType, FieldWiseSpecRt, Params, ParamsResolveError,
-        ParamsSpec, ValueResolutionCtx, ValueResolutionMode, ValueSpec, ValueSpecRt,
+        ParamsSpec, ParamsValidationError, ValueResolutionCtx, ValueResolutionMode, ValueSpec,
+        ValueSpecRt,
     },
     resources::{resources::ts::SetUp, Resources},
 };
@@ -401,6 +404,43 @@ field_wise_spec: !MappingFn
     Ok(())
 }
 
+#[test]
+fn mapping_fn_arg_type_ids_returns_empty_when_not_mapping_fn() {
+    assert!(ParamsSpec::<VecA>::Stored
+        .mapping_fn_arg_type_ids()
+        .is_empty());
+    assert!(ParamsSpec::<VecA>::InMemory
+        .mapping_fn_arg_type_ids()
+        .is_empty());
+    assert!(ParamsSpec::<VecA>::Value {
+        value: VecA::default()
+    }
+    .mapping_fn_arg_type_ids()
+    .is_empty());
+}
+
+#[test]
+fn mapping_fn_arg_type_ids_returns_mapping_fn_arg_type_ids() {
+    let params_spec = ParamsSpec::<VecA>::from_map(None, |_: &bool, _: &u16| None);
+
+    assert_eq!(
+        vec![TypeId::of::<bool>(), TypeId::of::<u16>()],
+        params_spec.mapping_fn_arg_type_ids()
+    );
+}
+
+#[test]
+fn mapping_fn_arg_type_ids_returns_field_wise_mapping_fn_arg_type_ids() {
+    let params_spec: <VecA as Params>::Spec = VecA::field_wise_spec()
+        .with_0_from_map(|n: &u32| Some(vec![u8::try_from(*n).ok()?]))
+        .build();
+
+    assert_eq!(
+        vec![TypeId::of::<u32>()],
+        params_spec.mapping_fn_arg_type_ids()
+    );
+}
+
 #[test]
 fn resolve_stored_param() -> Result<(), ParamsResolveError> {
     let resources = {
@@ -1029,3 +1069,104 @@ fn merge_field_wise_with_field_wise_deep_merges() {
         if matches!(f0, ValueSpec::InMemory)
     ));
 }
+
+#[test]
+fn serialize_validated_serializes_inner_params_spec() -> Result<(), serde_yaml::Error> {
+    let vec_a_spec: <VecA as Params>::Spec =
+        ParamsSpec::from(VecA(vec![1u8])).with_validation(|_vec_a| Ok(()));
+    assert_eq!(
+        r#"!Value
+value:
+- 1
+"#,
+        serde_yaml::to_string(&vec_a_spec)?,
+    );
+
+    Ok(())
+}
+
+#[test]
+fn is_usable_returns_inner_params_spec_usability_for_validated() {
+    let params_spec = ParamsSpec::<MockSrc>::InMemory.with_validation(|_mock_src| Ok(()));
+    assert!(params_spec.is_usable());
+
+    let params_spec = ParamsSpec::<MockSrc>::Stored.with_validation(|_mock_src| Ok(()));
+    assert!(!params_spec.is_usable());
+}
+
+#[test]
+fn resolve_validated_resolves_inner_params_spec() -> Result<(), ParamsResolveError> {
+    let resources = {
+        let mut resources = Resources::new();
+        resources.insert(MockSrc(1));
+        Resources::<SetUp>::from(resources)
+    };
+    let mut value_resolution_ctx = ValueResolutionCtx::new(
+        ValueResolutionMode::Current,
+        item_id!("resolve_validated_resolves_inner_params_spec"),
+        tynm::type_name::<MockSrc>(),
+    );
+    let mock_src_spec = ParamsSpec::<MockSrc>::InMemory.with_validation(|_mock_src| {
+        Err(ParamsValidationError::new(
+            "Resolution does not run validation.",
+        ))
+    });
+
+    let mock_src = ValueSpecRt::resolve(&mock_src_spec, &resources, &mut value_resolution_ctx)?;
+
+    assert_eq!(MockSrc(1), mock_src);
+    Ok(())
+}
+
+#[test]
+fn validate_returns_empty_when_no_validation_fns() {
+    let params_spec = ParamsSpec::<MockSrc>::Value { value: MockSrc(1) };
+
+    assert!(params_spec.validate(&MockSrc(1)).is_empty());
+}
+
+#[test]
+fn validate_returns_errors_from_each_validation_fn() {
+    let params_spec = ParamsSpec::<MockSrc>::Value { value: MockSrc(1) }
+        .with_validation(|mock_src| {
+            if mock_src.0 > 10 {
+                Ok(())
+            } else {
+                Err(ParamsValidationError::field(
+                    "0",
+                    "Must be greater than 10.",
+                ))
+            }
+        })
+        .with_validation(|mock_src| {
+            if mock_src.0 % 2 == 0 {
+                Ok(())
+            } else {
+                Err(ParamsValidationError::new("Must be even."))
+            }
+        })
+        .with_validation(|_mock_src| Ok(()));
+
+    assert_eq!(
+        vec![
+            ParamsValidationError::new("Must be even."),
+            ParamsValidationError::field("0", "Must be greater than 10."),
+        ],
+        params_spec.validate(&MockSrc(1))
+    );
+    assert!(params_spec.validate(&MockSrc(12)).is_empty());
+}
+
+#[test]
+fn merge_validated_with_stored_merges_inner_params_spec() {
+    let mut params_spec_a = ParamsSpec::<MockSrc>::Stored.with_validation(|_mock_src| Ok(()));
+    let params_spec_b = AnySpecRtBoxed::new(ParamsSpec::<MockSrc>::Value { value: MockSrc(1) });
+
+    params_spec_a.merge(&*params_spec_b);
+
+    assert!(matches!(
+        &params_spec_a,
+        ParamsSpec::<MockSrc>::Validated { params_spec, .. }
+        if matches!(params_spec.as_ref(), ParamsSpec::Value { value: MockSrc(1) })
+    ));
+}
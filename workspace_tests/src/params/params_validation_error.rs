@@ -0,0 +1,40 @@
+use peace::params::ParamsValidationError;
+
+#[test]
+fn display_without_field_name() {
+    let params_validation_error = ParamsValidationError::new("Port must be 1024 or above.");
+
+    assert_eq!(None, params_validation_error.field_name());
+    assert_eq!(
+        "Port must be 1024 or above.",
+        params_validation_error.to_string()
+    );
+}
+
+#[test]
+fn display_with_field_name() {
+    let params_validation_error = ParamsValidationError::field("port", "Must be 1024 or above.");
+
+    assert_eq!(Some("port"), params_validation_error.field_name());
+    assert_eq!("Must be 1024 or above.", params_validation_error.message());
+    assert_eq!(
+        "`port`: Must be 1024 or above.",
+        params_validation_error.to_string()
+    );
+}
+
+#[test]
+fn display_with_field_names() {
+    let params_validation_error =
+        ParamsValidationError::fields(["min", "max"], "`min` must not be greater than `max`.");
+
+    assert_eq!(Some("min"), params_validation_error.field_name());
+    assert_eq!(
+        &[String::from("min"), String::from("max")],
+        params_validation_error.field_names()
+    );
+    assert_eq!(
+        "`min`, `max`: `min` must not be greater than `max`.",
+        params_validation_error.to_string()
+    );
+}
@@ -0,0 +1,48 @@
+use peace::params::{FieldNameAndType, ValueResolution, ValueResolutionSource};
+
+#[test]
+fn field_path_joins_field_names() {
+    let value_resolution = ValueResolution::new(
+        vec![
+            FieldNameAndType::new(String::from("outer"), String::from("Outer")),
+            FieldNameAndType::new(String::from("inner"), tynm::type_name::<u8>()),
+        ],
+        ValueResolutionSource::Value,
+        Some(String::from("1")),
+    );
+
+    assert_eq!("outer.inner", value_resolution.field_path());
+}
+
+#[test]
+fn field_path_is_empty_for_empty_field_chain() {
+    let value_resolution = ValueResolution::new(Vec::new(), ValueResolutionSource::Value, None);
+
+    assert_eq!("", value_resolution.field_path());
+}
+
+#[test]
+fn display_with_value() {
+    let value_resolution = ValueResolution::new(
+        Vec::new(),
+        ValueResolutionSource::Stored {
+            type_name: String::from("u8"),
+        },
+        Some(String::from("1")),
+    );
+
+    assert_eq!("stored `u8`: 1", value_resolution.to_string());
+}
+
+#[test]
+fn display_without_value() {
+    let value_resolution = ValueResolution::new(
+        Vec::new(),
+        ValueResolutionSource::InMemory {
+            type_name: String::from("u8"),
+        },
+        None,
+    );
+
+    assert_eq!("in memory `u8`: no value", value_resolution.to_string());
+}
@@ -1,6 +1,9 @@
 use peace::{
     cfg::item_id,
-    params::{FieldNameAndType, ValueResolutionCtx, ValueResolutionMode},
+    params::{
+        FieldNameAndType, ValueResolution, ValueResolutionCtx, ValueResolutionMode,
+        ValueResolutionSource,
+    },
 };
 
 use crate::mock_item::MockSrc;
@@ -18,7 +21,8 @@ fn debug() {
             value_resolution_mode: Current, \
             item_id: ItemId(\"item_id\"), \
             params_type_name: \"MockSrc\", \
-            resolution_chain: [] \
+            resolution_chain: [], \
+            value_resolutions: [] \
         }",
         format!("{value_resolution_ctx:?}")
     );
@@ -80,3 +84,77 @@ fn display_with_resolution_chain() {
         format!("{value_resolution_ctx}")
     );
 }
+
+#[test]
+fn value_resolution_push_records_resolution_chain_and_value() {
+    let mut value_resolution_ctx = ValueResolutionCtx::new(
+        ValueResolutionMode::Current,
+        item_id!("item_id"),
+        tynm::type_name::<MockSrc>(),
+    );
+    value_resolution_ctx.push(FieldNameAndType::new(
+        String::from("inner"),
+        tynm::type_name::<u8>(),
+    ));
+    value_resolution_ctx.value_resolution_push(ValueResolutionSource::Value, Some(&1u8));
+    value_resolution_ctx.pop();
+    value_resolution_ctx.value_resolution_push::<MockSrc>(
+        ValueResolutionSource::InMemory {
+            type_name: tynm::type_name::<MockSrc>(),
+        },
+        None,
+    );
+
+    assert_eq!(
+        &[
+            ValueResolution::new(
+                vec![FieldNameAndType::new(
+                    String::from("inner"),
+                    tynm::type_name::<u8>(),
+                )],
+                ValueResolutionSource::Value,
+                Some(String::from("1")),
+            ),
+            ValueResolution::new(
+                Vec::new(),
+                ValueResolutionSource::InMemory {
+                    type_name: String::from("MockSrc"),
+                },
+                None,
+            ),
+        ],
+        value_resolution_ctx.value_resolutions()
+    );
+}
+
+#[test]
+fn field_path_and_field_type_name_with_resolution_chain() {
+    let mut value_resolution_ctx = ValueResolutionCtx::new(
+        ValueResolutionMode::Current,
+        item_id!("item_id"),
+        tynm::type_name::<MockSrc>(),
+    );
+    value_resolution_ctx.push(FieldNameAndType::new(
+        String::from("intermediate"),
+        String::from("Something"),
+    ));
+    value_resolution_ctx.push(FieldNameAndType::new(
+        String::from("inner"),
+        tynm::type_name::<u8>(),
+    ));
+
+    assert_eq!("intermediate.inner", value_resolution_ctx.field_path());
+    assert_eq!("u8", value_resolution_ctx.field_type_name());
+}
+
+#[test]
+fn field_path_and_field_type_name_no_resolution_chain() {
+    let value_resolution_ctx = ValueResolutionCtx::new(
+        ValueResolutionMode::Current,
+        item_id!("item_id"),
+        tynm::type_name::<MockSrc>(),
+    );
+
+    assert_eq!("", value_resolution_ctx.field_path());
+    assert_eq!("MockSrc", value_resolution_ctx.field_type_name());
+}
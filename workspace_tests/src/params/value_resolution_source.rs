@@ -0,0 +1,72 @@
+use peace::params::ValueResolutionSource;
+
+#[test]
+fn display_value() {
+    assert_eq!("value", ValueResolutionSource::Value.to_string());
+}
+
+#[test]
+fn display_stored() {
+    let value_resolution_source = ValueResolutionSource::Stored {
+        type_name: String::from("u8"),
+    };
+
+    assert_eq!("stored `u8`", value_resolution_source.to_string());
+}
+
+#[test]
+fn display_in_memory() {
+    let value_resolution_source = ValueResolutionSource::InMemory {
+        type_name: String::from("u8"),
+    };
+
+    assert_eq!("in memory `u8`", value_resolution_source.to_string());
+}
+
+#[test]
+fn display_workspace_param() {
+    let value_resolution_source = ValueResolutionSource::WorkspaceParam {
+        key: String::from("username"),
+    };
+
+    assert_eq!(
+        "workspace param `username`",
+        value_resolution_source.to_string()
+    );
+}
+
+#[test]
+fn display_profile_param() {
+    let value_resolution_source = ValueResolutionSource::ProfileParam {
+        key: String::from("env_type"),
+    };
+
+    assert_eq!(
+        "profile param `env_type`",
+        value_resolution_source.to_string()
+    );
+}
+
+#[test]
+fn display_mapping_fn() {
+    let value_resolution_source = ValueResolutionSource::MappingFn {
+        from_type_names: vec![String::from("u8"), String::from("u16")],
+    };
+
+    assert_eq!(
+        "mapping fn from (`u8`, `u16`)",
+        value_resolution_source.to_string()
+    );
+}
+
+#[test]
+fn display_mapping_expr() {
+    let value_resolution_source = ValueResolutionSource::MappingExpr {
+        expr: String::from("lowercase(\"App\")"),
+    };
+
+    assert_eq!(
+        "mapping expr `lowercase(\"App\")`",
+        value_resolution_source.to_string()
+    );
+}
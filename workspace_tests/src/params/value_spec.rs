@@ -1,10 +1,17 @@
+use std::path::{Path, PathBuf};
+
 use peace::{
     cfg::item_id,
     params::{
-        AnySpecRt, AnySpecRtBoxed, ParamsResolveError, ValueResolutionCtx, ValueResolutionMode,
-        ValueSpec, ValueSpecRt,
+        AnySpecRt, AnySpecRtBoxed, MappingExpr, MappingExprError, ParamsResolveError,
+        ProfileParamsByKey, ValueResolutionCtx, ValueResolutionMode, ValueResolutionSource,
+        ValueSpec, ValueSpecRt, WorkspaceParamsByKey, WorkspaceParamsSerialized,
+    },
+    resources::{
+        paths::{WorkspaceDir, WorkspacePath},
+        resources::ts::SetUp,
+        Resources,
     },
-    resources::{resources::ts::SetUp, Resources},
 };
 
 use crate::mock_item::MockSrc;
@@ -236,6 +243,70 @@ fn resolve_in_memory() -> Result<(), ParamsResolveError> {
     Ok(())
 }
 
+#[test]
+fn resolve_workspace_path_relative_to_workspace_dir() -> Result<(), ParamsResolveError> {
+    let resources = {
+        let mut resources = Resources::new();
+        resources.insert(WorkspaceDir::new(PathBuf::from("/workspace")));
+        Resources::<SetUp>::from(resources)
+    };
+    let mut value_resolution_ctx = ValueResolutionCtx::new(
+        ValueResolutionMode::Current,
+        item_id!("resolve_workspace_path_relative_to_workspace_dir"),
+        tynm::type_name::<WorkspacePath>(),
+    );
+    let workspace_path_spec = ValueSpec::<WorkspacePath>::Value {
+        value: WorkspacePath::new(PathBuf::from("web_app.tar")),
+    };
+
+    let workspace_path =
+        ValueSpecRt::resolve(&workspace_path_spec, &resources, &mut value_resolution_ctx)?;
+
+    assert_eq!(Path::new("/workspace/web_app.tar"), workspace_path.path());
+    Ok(())
+}
+
+#[test]
+fn resolve_workspace_path_returns_err_when_outside_workspace_dir() {
+    let resources = {
+        let mut resources = Resources::new();
+        resources.insert(WorkspaceDir::new(PathBuf::from("/workspace")));
+        Resources::<SetUp>::from(resources)
+    };
+    let mut value_resolution_ctx = ValueResolutionCtx::new(
+        ValueResolutionMode::Current,
+        item_id!("resolve_workspace_path_returns_err_when_outside_workspace_dir"),
+        tynm::type_name::<WorkspacePath>(),
+    );
+    let workspace_path_spec = ValueSpec::<WorkspacePath>::Value {
+        value: WorkspacePath::new(PathBuf::from("../etc/passwd")),
+    };
+
+    let workspace_path_result =
+        ValueSpecRt::resolve(&workspace_path_spec, &resources, &mut value_resolution_ctx);
+
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    &workspace_path_result,
+                    Err(ParamsResolveError::WorkspacePathOutsideWorkspace {
+                        value_resolution_ctx: _,
+                        workspace_path,
+                        workspace_dir,
+                    })
+                    if workspace_path == Path::new("../etc/passwd")
+                    && workspace_dir == Path::new("/workspace")
+                ),
+                "Expected `workspace_path_result` to be \
+                `Err(ParamsResolveError::WorkspacePathOutsideWorkspace {{ .. }})`,\n\
+                but was `{workspace_path_result:?}`",
+            );
+        }
+    })();
+}
+
 #[test]
 fn resolve_in_memory_returns_err_when_not_found() -> Result<(), ParamsResolveError> {
     let resources = Resources::<SetUp>::from(Resources::new());
@@ -619,3 +690,273 @@ fn merge_mapping_fn_with_other_no_change() {
 
     assert!(matches!(&value_spec_a, ValueSpec::<MockSrc>::MappingFn(_)));
 }
+
+#[test]
+fn from_workspace_param_uses_key_name() {
+    assert!(matches!(
+        ValueSpec::<MockSrc>::from_workspace_param(&ParamsKey::Src),
+        ValueSpec::<MockSrc>::FromWorkspaceParam(key)
+        if key == "Src"
+    ));
+    assert!(matches!(
+        ValueSpec::<MockSrc>::from_profile_param(&String::from("src")),
+        ValueSpec::<MockSrc>::FromProfileParam(key)
+        if key == "src"
+    ));
+}
+
+#[test]
+fn serialize_from_workspace_param() -> Result<(), serde_yaml::Error> {
+    let u8_spec = ValueSpec::<u8>::from_workspace_param(&ParamsKey::Src);
+    assert_eq!(
+        r#"!FromWorkspaceParam Src
+"#,
+        serde_yaml::to_string(&u8_spec)?,
+    );
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_from_profile_param() -> Result<(), serde_yaml::Error> {
+    assert!(matches!(
+        serde_yaml::from_str(
+            r#"!FromProfileParam Src
+"#
+        )?,
+        ValueSpec::<u8>::FromProfileParam(key)
+        if key == "Src"
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn resolve_from_workspace_param() -> Result<(), ParamsResolveError> {
+    let resources = {
+        let mut workspace_params_by_key = WorkspaceParamsByKey::new();
+        workspace_params_by_key.insert(String::from("Src"), Box::new(MockSrc(1)));
+
+        let mut resources = Resources::new();
+        resources.insert(workspace_params_by_key);
+        Resources::<SetUp>::from(resources)
+    };
+    let mut value_resolution_ctx = ValueResolutionCtx::new(
+        ValueResolutionMode::Current,
+        item_id!("resolve_from_workspace_param"),
+        tynm::type_name::<MockSrc>(),
+    );
+    let mock_src_spec = ValueSpec::<MockSrc>::from_workspace_param(&ParamsKey::Src);
+
+    let mock_src = ValueSpecRt::resolve(&mock_src_spec, &resources, &mut value_resolution_ctx)?;
+
+    assert_eq!(MockSrc(1), mock_src);
+    assert_eq!(
+        Some(&ValueResolutionSource::WorkspaceParam {
+            key: String::from("Src")
+        }),
+        value_resolution_ctx
+            .value_resolutions()
+            .last()
+            .map(|value_resolution| value_resolution.source())
+    );
+    Ok(())
+}
+
+#[test]
+fn resolve_from_profile_param_returns_err_when_type_mismatch() {
+    let resources = {
+        let mut profile_params_by_key = ProfileParamsByKey::new();
+        profile_params_by_key.insert(String::from("Src"), Box::new(1u8));
+
+        let mut resources = Resources::new();
+        resources.insert(profile_params_by_key);
+        Resources::<SetUp>::from(resources)
+    };
+    let mut value_resolution_ctx = ValueResolutionCtx::new(
+        ValueResolutionMode::Current,
+        item_id!("resolve_from_profile_param_returns_err_when_type_mismatch"),
+        tynm::type_name::<MockSrc>(),
+    );
+    let mock_src_spec = ValueSpec::<MockSrc>::from_profile_param(&ParamsKey::Src);
+
+    let mock_src_result =
+        ValueSpecRt::resolve(&mock_src_spec, &resources, &mut value_resolution_ctx);
+
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    &mock_src_result,
+                    Err(ParamsResolveError::FromProfileParam {
+                        value_resolution_ctx: _,
+                        key,
+                        value_type_name,
+                    })
+                    if key == "Src" && value_type_name == "MockSrc"
+                ),
+                "Expected `mock_src_result` to be \
+                `Err(ParamsResolveError::FromProfileParam {{ .. }})`,\n\
+                but was `{mock_src_result:?}`",
+            );
+        }
+    })();
+}
+
+#[test]
+fn try_resolve_from_workspace_param_returns_none_when_not_found()
+-> Result<(), ParamsResolveError> {
+    let resources = {
+        let mut resources = Resources::new();
+        resources.insert(WorkspaceParamsByKey::new());
+        Resources::<SetUp>::from(resources)
+    };
+    let mut value_resolution_ctx = ValueResolutionCtx::new(
+        ValueResolutionMode::Current,
+        item_id!("try_resolve_from_workspace_param_returns_none_when_not_found"),
+        tynm::type_name::<MockSrc>(),
+    );
+    let mock_src_spec = ValueSpec::<MockSrc>::from_workspace_param(&ParamsKey::Src);
+
+    let mock_src =
+        ValueSpecRt::try_resolve(&mock_src_spec, &resources, &mut value_resolution_ctx)?;
+
+    assert_eq!(None, mock_src);
+    Ok(())
+}
+
+#[test]
+fn serialize_mapping_expr() -> Result<(), serde_yaml::Error> {
+    let u8_spec = ValueSpec::<u8>::from_expr(MappingExpr::workspace_param(&ParamsKey::Src, ""));
+    assert_eq!(
+        r#"!MappingExpr
+expr: !WorkspaceParam
+  key: Src
+  pointer: ''
+"#,
+        serde_yaml::to_string(&u8_spec)?,
+    );
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_mapping_expr() -> Result<(), serde_yaml::Error> {
+    let deserialized = serde_yaml::from_str(
+        r#"!MappingExpr
+expr: !Literal '1'
+"#,
+    )?;
+
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    &deserialized,
+                    ValueSpec::<u8>::MappingExpr { expr, .. }
+                    if expr == &MappingExpr::literal("1")
+                    && deserialized.is_usable()
+                ),
+                "was {deserialized:?}"
+            );
+        }
+    })();
+
+    Ok(())
+}
+
+#[test]
+fn resolve_mapping_expr() -> Result<(), ParamsResolveError> {
+    let resources = {
+        let mut workspace_params_serialized = WorkspaceParamsSerialized::new();
+        workspace_params_serialized.insert(
+            String::from("Src"),
+            serde_yaml::Value::String(String::from("src")),
+        );
+
+        let mut resources = Resources::new();
+        resources.insert(workspace_params_serialized);
+        Resources::<SetUp>::from(resources)
+    };
+    let mut value_resolution_ctx = ValueResolutionCtx::new(
+        ValueResolutionMode::Current,
+        item_id!("resolve_mapping_expr"),
+        tynm::type_name::<String>(),
+    );
+    let expr = MappingExpr::concat(vec![
+        MappingExpr::workspace_param(&ParamsKey::Src, ""),
+        MappingExpr::literal("/app.tar"),
+    ]);
+    let string_spec = ValueSpec::<String>::from_expr(expr.clone());
+
+    let value = ValueSpecRt::resolve(&string_spec, &resources, &mut value_resolution_ctx)?;
+
+    assert_eq!("src/app.tar", value);
+    assert_eq!(
+        Some(&ValueResolutionSource::MappingExpr {
+            expr: expr.to_string()
+        }),
+        value_resolution_ctx
+            .value_resolutions()
+            .last()
+            .map(|value_resolution| value_resolution.source())
+    );
+    Ok(())
+}
+
+#[test]
+fn resolve_mapping_expr_returns_err_when_value_deserialize_fails() {
+    let resources = Resources::<SetUp>::from(Resources::new());
+    let mut value_resolution_ctx = ValueResolutionCtx::new(
+        ValueResolutionMode::Current,
+        item_id!("resolve_mapping_expr_returns_err_when_value_deserialize_fails"),
+        tynm::type_name::<u8>(),
+    );
+    let u8_spec = ValueSpec::<u8>::from_expr(MappingExpr::literal("one"));
+
+    let u8_result = ValueSpecRt::resolve(&u8_spec, &resources, &mut value_resolution_ctx);
+
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    &u8_result,
+                    Err(ParamsResolveError::MappingExpr {
+                        value_resolution_ctx: _,
+                        expr,
+                        error: MappingExprError::ValueDeserialize { type_name, .. },
+                    })
+                    if expr == &MappingExpr::literal("one") && type_name == "u8"
+                ),
+                "Expected `u8_result` to be \
+                `Err(ParamsResolveError::MappingExpr {{ .. }})`,\n\
+                but was `{u8_result:?}`",
+            );
+        }
+    })();
+}
+
+#[test]
+fn try_resolve_mapping_expr_returns_none_when_param_not_found() -> Result<(), ParamsResolveError> {
+    let resources = Resources::<SetUp>::from(Resources::new());
+    let mut value_resolution_ctx = ValueResolutionCtx::new(
+        ValueResolutionMode::Current,
+        item_id!("try_resolve_mapping_expr_returns_none_when_param_not_found"),
+        tynm::type_name::<String>(),
+    );
+    let string_spec =
+        ValueSpec::<String>::from_expr(MappingExpr::profile_param(&ParamsKey::Src, ""));
+
+    let value = ValueSpecRt::try_resolve(&string_spec, &resources, &mut value_resolution_ctx)?;
+
+    assert_eq!(None, value);
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, serde::Serialize)]
+enum ParamsKey {
+    Src,
+}
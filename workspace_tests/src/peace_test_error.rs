@@ -40,6 +40,14 @@ pub enum PeaceTestError {
         #[from]
         crate::mock_item::MockItemError,
     ),
+    /// A Token item error occurred.
+    #[error("A Token item error occurred.")]
+    Token(
+        #[cfg_attr(feature = "error_reporting", diagnostic_source)]
+        #[source]
+        #[from]
+        crate::token_item::TokenItemError,
+    ),
 
     /// A Blank item error occurred.
     #[error("A Blank item error occurred.")]
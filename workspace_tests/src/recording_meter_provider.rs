@@ -0,0 +1,116 @@
+use std::sync::{Arc, Mutex};
+
+use opentelemetry::{
+    metrics::{
+        Counter, Histogram, HistogramBuilder, InstrumentBuilder, InstrumentProvider, Meter,
+        MeterProvider, SyncInstrument,
+    },
+    InstrumentationScope, KeyValue,
+};
+
+/// `MeterProvider` that records counter and histogram measurements in memory.
+#[derive(Clone, Debug, Default)]
+pub struct RecordingMeterProvider {
+    /// Measurements recorded by all instruments.
+    measurements: Arc<Mutex<Vec<Measurement>>>,
+}
+
+impl RecordingMeterProvider {
+    /// Returns a new `RecordingMeterProvider`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the measurements recorded so far.
+    pub fn measurements(&self) -> Vec<Measurement> {
+        self.measurements
+            .lock()
+            .expect("Expected to lock measurements.")
+            .clone()
+    }
+
+    /// Returns the measurements recorded so far for the given instrument.
+    pub fn measurements_named(&self, name: &str) -> Vec<Measurement> {
+        self.measurements()
+            .into_iter()
+            .filter(|measurement| measurement.name == name)
+            .collect()
+    }
+}
+
+impl MeterProvider for RecordingMeterProvider {
+    fn meter_with_scope(&self, _scope: InstrumentationScope) -> Meter {
+        Meter::new(Arc::new(self.clone()))
+    }
+}
+
+impl InstrumentProvider for RecordingMeterProvider {
+    fn u64_counter(&self, builder: InstrumentBuilder<'_, Counter<u64>>) -> Counter<u64> {
+        Counter::new(Arc::new(RecordingInstrument {
+            name: builder.name.to_string(),
+            measurements: self.measurements.clone(),
+        }))
+    }
+
+    fn f64_histogram(&self, builder: HistogramBuilder<'_, Histogram<f64>>) -> Histogram<f64> {
+        Histogram::new(Arc::new(RecordingInstrument {
+            name: builder.name.to_string(),
+            measurements: self.measurements.clone(),
+        }))
+    }
+}
+
+/// A measurement recorded by a `RecordingMeterProvider` instrument.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Measurement {
+    /// Name of the instrument.
+    pub name: String,
+    /// Measured value.
+    pub value: f64,
+    /// Attribute keys and values, in the order they were recorded.
+    pub attributes: Vec<(String, String)>,
+}
+
+impl Measurement {
+    /// Returns the value of the attribute with the given key.
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attribute_key, _)| attribute_key == key)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+struct RecordingInstrument {
+    name: String,
+    measurements: Arc<Mutex<Vec<Measurement>>>,
+}
+
+impl RecordingInstrument {
+    fn record(&self, value: f64, attributes: &[KeyValue]) {
+        let attributes = attributes
+            .iter()
+            .map(|key_value| (key_value.key.to_string(), key_value.value.to_string()))
+            .collect::<Vec<_>>();
+        self.measurements
+            .lock()
+            .expect("Expected to lock measurements.")
+            .push(Measurement {
+                name: self.name.clone(),
+                value,
+                attributes,
+            });
+    }
+}
+
+impl SyncInstrument<u64> for RecordingInstrument {
+    fn measure(&self, measurement: u64, attributes: &[KeyValue]) {
+        self.record(measurement as f64, attributes);
+    }
+}
+
+impl SyncInstrument<f64> for RecordingInstrument {
+    fn measure(&self, measurement: f64, attributes: &[KeyValue]) {
+        self.record(measurement, attributes);
+    }
+}
@@ -1,3 +1,4 @@
+mod diff_summary;
 mod dir;
 mod internal;
 #[allow(clippy::module_inception)]
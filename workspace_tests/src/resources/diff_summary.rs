@@ -0,0 +1,73 @@
+use peace::{
+    cfg::{item_id, DiffSeverity},
+    cli::output::{CliColorizeOpt, CliMdPresenter, CliOutputBuilder},
+    cli_model::OutputFormat,
+    fmt::Presentable,
+    resources::states::DiffSummary,
+};
+
+#[test]
+fn with_capacity_reserves_enough_capacity() {
+    let diff_summary = DiffSummary::with_capacity(100);
+    assert!(diff_summary.capacity() >= 100);
+}
+
+#[test]
+fn counts() {
+    let diff_summary = test_diff_summary();
+
+    assert_eq!(1, diff_summary.in_sync_count());
+    assert_eq!(2, diff_summary.additive_count());
+    assert_eq!(1, diff_summary.destructive_count());
+}
+
+#[test]
+fn severity_max_returns_most_severe() {
+    let diff_summary = test_diff_summary();
+
+    assert_eq!(DiffSeverity::Destructive, diff_summary.severity_max());
+    assert_eq!(3, diff_summary.detailed_exit_code());
+}
+
+#[test]
+fn severity_max_returns_in_sync_when_empty() {
+    let diff_summary = DiffSummary::new();
+
+    assert_eq!(DiffSeverity::InSync, diff_summary.severity_max());
+    assert_eq!(0, diff_summary.detailed_exit_code());
+}
+
+#[tokio::test]
+async fn present() -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut cli_output = CliOutputBuilder::new_with_writer(&mut buffer)
+        .with_outcome_format(OutputFormat::Text)
+        .with_colorize(CliColorizeOpt::Never)
+        .build();
+    let mut presenter = CliMdPresenter::new(&mut cli_output);
+
+    test_diff_summary().present(&mut presenter).await?;
+
+    let output = String::from_utf8(buffer)?;
+    assert_eq!("1 in sync, 2 additive, 1 destructive", output);
+    Ok(())
+}
+
+#[test]
+fn serialize() -> Result<(), serde_yaml::Error> {
+    let mut diff_summary = DiffSummary::new();
+    diff_summary.insert(item_id!("a"), DiffSeverity::Additive);
+
+    assert_eq!("a: Additive\n", serde_yaml::to_string(&diff_summary)?);
+    Ok(())
+}
+
+fn test_diff_summary() -> DiffSummary {
+    let mut diff_summary = DiffSummary::new();
+    diff_summary.insert(item_id!("a"), DiffSeverity::InSync);
+    diff_summary.insert(item_id!("b"), DiffSeverity::Additive);
+    diff_summary.insert(item_id!("c"), DiffSeverity::Destructive);
+    diff_summary.insert(item_id!("d"), DiffSeverity::Additive);
+
+    diff_summary
+}
@@ -1,6 +1,11 @@
+mod execution_progress_file;
+mod execution_states_dir;
+mod execution_timeline_file;
 mod peace_dir;
 mod profile_dir;
 mod profile_history_dir;
 mod states_current_file;
 mod states_goal_file;
+mod states_timestamps_file;
 mod workspace_dir;
+mod workspace_path;
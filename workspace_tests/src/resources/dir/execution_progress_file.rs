@@ -0,0 +1,37 @@
+use std::path::{Path, PathBuf};
+
+use peace::{
+    cfg::{app_name, flow_id, profile},
+    resources::paths::{ExecutionProgressFile, FlowDir, PeaceAppDir, PeaceDir, ProfileDir},
+};
+
+#[test]
+pub fn debug() {
+    let execution_progress_file =
+        ExecutionProgressFile::from(Path::new("execution_progress.yaml").to_path_buf());
+
+    assert_eq!(
+        r#"ExecutionProgressFile("execution_progress.yaml")"#,
+        format!("{execution_progress_file:?}")
+    );
+}
+
+#[test]
+pub fn from_flow_dir_relative() {
+    let app_name = app_name!();
+    let peace_dir = PeaceDir::from(Path::new(".").to_path_buf());
+    let profile = profile!("test_profile");
+    let peace_app_dir = PeaceAppDir::from((&peace_dir, &app_name));
+    let profile_dir = ProfileDir::from((&peace_app_dir, &profile));
+    let flow_dir = FlowDir::from((&profile_dir, &flow_id!("test_flow")));
+    let execution_progress_file = ExecutionProgressFile::from(&flow_dir);
+
+    let path = PathBuf::from_iter([
+        ".",
+        &**app_name!(),
+        "test_profile",
+        "test_flow",
+        "execution_progress.yaml",
+    ]);
+    assert_eq!(path, &*execution_progress_file);
+}
@@ -0,0 +1,49 @@
+use std::path::{Path, PathBuf};
+
+use peace::{
+    cfg::{app_name, flow_id, profile},
+    resources::paths::{
+        ExecutionStatesDir, FlowDir, PeaceAppDir, PeaceDir, ProfileDir, StatesCurrentFile,
+        StatesGoalFile,
+    },
+};
+
+#[test]
+pub fn debug() {
+    let execution_states_dir =
+        ExecutionStatesDir::from(Path::new("executions/00000000").to_path_buf());
+
+    assert_eq!(
+        r#"ExecutionStatesDir("executions/00000000")"#,
+        format!("{execution_states_dir:?}")
+    );
+}
+
+#[test]
+pub fn from_flow_dir_relative() {
+    let app_name = app_name!();
+    let peace_dir = PeaceDir::from(Path::new(".").to_path_buf());
+    let profile = profile!("test_profile");
+    let peace_app_dir = PeaceAppDir::from((&peace_dir, &app_name));
+    let profile_dir = ProfileDir::from((&peace_app_dir, &profile));
+    let flow_dir = FlowDir::from((&profile_dir, &flow_id!("test_flow")));
+    let execution_states_dir = ExecutionStatesDir::from((&flow_dir, 12));
+
+    let path = PathBuf::from_iter([
+        ".",
+        &**app_name!(),
+        "test_profile",
+        "test_flow",
+        "executions",
+        "00000012",
+    ]);
+    assert_eq!(path, &*execution_states_dir);
+    assert_eq!(
+        path.join("states_current.yaml"),
+        &*StatesCurrentFile::from(&execution_states_dir)
+    );
+    assert_eq!(
+        path.join("states_goal.yaml"),
+        &*StatesGoalFile::from(&execution_states_dir)
+    );
+}
@@ -0,0 +1,40 @@
+use std::path::{Path, PathBuf};
+
+use peace::{
+    cfg::{app_name, flow_id, profile},
+    resources::paths::{
+        FlowDir, PeaceAppDir, PeaceDir, ProfileDir, StatesCurrentFile, StatesTimestampsFile,
+    },
+};
+
+#[test]
+pub fn debug() {
+    let states_timestamps_file =
+        StatesTimestampsFile::new(Path::new("states_current_timestamps.yaml").to_path_buf());
+
+    assert_eq!(
+        r#"StatesTimestampsFile("states_current_timestamps.yaml")"#,
+        format!("{states_timestamps_file:?}")
+    );
+}
+
+#[test]
+pub fn from_states_file_path() {
+    let app_name = app_name!();
+    let peace_dir = PeaceDir::from(Path::new(".").to_path_buf());
+    let profile = profile!("test_profile");
+    let peace_app_dir = PeaceAppDir::from((&peace_dir, &app_name));
+    let profile_dir = ProfileDir::from((&peace_app_dir, &profile));
+    let flow_dir = FlowDir::from((&profile_dir, &flow_id!("test_flow")));
+    let states_current_file = StatesCurrentFile::from(&flow_dir);
+    let states_timestamps_file = StatesTimestampsFile::from(&*states_current_file);
+
+    let path = PathBuf::from_iter([
+        ".",
+        &**app_name!(),
+        "test_profile",
+        "test_flow",
+        "states_current_timestamps.yaml",
+    ]);
+    assert_eq!(path, &*states_timestamps_file);
+}
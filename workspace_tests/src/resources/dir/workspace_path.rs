@@ -0,0 +1,77 @@
+use std::path::{Path, PathBuf};
+
+use peace::resources::paths::{WorkspaceDir, WorkspacePath};
+
+#[test]
+fn resolve_joins_relative_path_to_workspace_dir() {
+    let workspace_dir = WorkspaceDir::new(PathBuf::from("/workspace"));
+    let workspace_path = WorkspacePath::new(PathBuf::from("./app/../web_app.tar"));
+
+    let workspace_path = workspace_path.resolve(&workspace_dir);
+
+    assert_eq!(
+        Some(Path::new("/workspace/web_app.tar")),
+        workspace_path.as_ref().map(WorkspacePath::path)
+    );
+}
+
+#[test]
+fn resolve_returns_same_path_when_already_resolved() {
+    let workspace_dir = WorkspaceDir::new(PathBuf::from("/workspace"));
+    let workspace_path = WorkspacePath::new(PathBuf::from("/workspace/web_app.tar"));
+
+    let workspace_path_resolved = workspace_path.resolve(&workspace_dir);
+
+    assert_eq!(Some(workspace_path), workspace_path_resolved);
+}
+
+#[test]
+fn resolve_returns_none_when_path_traverses_outside_workspace_dir() {
+    let workspace_dir = WorkspaceDir::new(PathBuf::from("/workspace"));
+
+    assert_eq!(
+        None,
+        WorkspacePath::new(PathBuf::from("../etc/passwd")).resolve(&workspace_dir)
+    );
+    assert_eq!(
+        None,
+        WorkspacePath::new(PathBuf::from("app/../../workspace_other")).resolve(&workspace_dir)
+    );
+    assert_eq!(
+        None,
+        WorkspacePath::new(PathBuf::from("/etc/passwd")).resolve(&workspace_dir)
+    );
+}
+
+#[test]
+fn resolve_returns_path_outside_workspace_dir_when_external() {
+    let workspace_dir = WorkspaceDir::new(PathBuf::from("/workspace"));
+    let workspace_path = WorkspacePath::external(PathBuf::from("../shared/web_app.tar"));
+
+    let workspace_path = workspace_path.resolve(&workspace_dir);
+
+    assert_eq!(
+        Some(Path::new("/shared/web_app.tar")),
+        workspace_path.as_ref().map(WorkspacePath::path)
+    );
+    assert_eq!(
+        Some(true),
+        workspace_path.as_ref().map(WorkspacePath::is_external)
+    );
+}
+
+#[test]
+fn serialize_omits_external_when_false() -> Result<(), serde_yaml::Error> {
+    let workspace_path = WorkspacePath::new(PathBuf::from("web_app.tar"));
+    let workspace_path_external = WorkspacePath::external(PathBuf::from("/shared"));
+
+    assert_eq!(
+        "path: web_app.tar\n",
+        serde_yaml::to_string(&workspace_path)?
+    );
+    assert_eq!(
+        "path: /shared\nexternal: true\n",
+        serde_yaml::to_string(&workspace_path_external)?
+    );
+    Ok(())
+}
@@ -1,6 +1,13 @@
+use std::time::Duration;
+
+use chrono::{TimeZone, Utc};
 use peace::{
     cfg::item_id,
-    resources::{internal::StatesMut, states::StatesCurrent, type_reg::untagged::TypeMap},
+    resources::{
+        internal::StatesMut,
+        states::{ts::Current, StatesCurrent, StatesTimestamps},
+        type_reg::untagged::TypeMap,
+    },
 };
 
 mod states_cleaned;
@@ -12,6 +19,7 @@ mod states_ensured_dry;
 mod states_goal;
 mod states_goal_stored;
 mod states_previous;
+mod states_view;
 mod ts;
 
 #[test]
@@ -64,6 +72,83 @@ fn debug() {
     );
 }
 
+#[test]
+fn from_states_mut_timestamps_states_without_recorded_timestamp() {
+    let timestamp = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let mut states_mut = StatesMut::<Current>::new();
+    states_mut.insert(item_id!("reused"), 1i32);
+    states_mut.insert(item_id!("discovered"), 2i32);
+    states_mut.timestamp_insert(item_id!("reused"), timestamp);
+
+    let states = StatesCurrent::from(states_mut);
+
+    assert_eq!(Some(timestamp), states.freshness(&item_id!("reused")));
+    assert!(
+        states
+            .freshness(&item_id!("discovered"))
+            .is_some_and(|discovered_at| discovered_at > timestamp)
+    );
+    assert_eq!(None, states.freshness(&item_id!("unknown")));
+}
+
+#[test]
+fn from_type_map_has_no_timestamps() {
+    let mut type_map = TypeMap::new_typed();
+    type_map.insert(item_id!("key"), 123i32);
+
+    let states = StatesCurrent::from(type_map);
+
+    assert_eq!(None, states.freshness(&item_id!("key")));
+    assert!(states.timestamps().is_empty());
+}
+
+#[test]
+fn with_timestamps_ignores_items_without_state() {
+    let timestamp = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let timestamps = [
+        (item_id!("key"), timestamp),
+        (item_id!("removed"), timestamp),
+    ]
+    .into_iter()
+    .collect::<StatesTimestamps>();
+
+    let states = StatesCurrent::from(test_states().into_inner()).with_timestamps(timestamps);
+
+    assert_eq!(Some(timestamp), states.freshness(&item_id!("key")));
+    assert_eq!(None, states.freshness(&item_id!("removed")));
+    assert_eq!(1, states.timestamps().len());
+}
+
+#[test]
+fn item_ids_older_than_returns_items_exceeding_max_staleness() {
+    let now = Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
+    let mut type_map = TypeMap::new_typed();
+    type_map.insert(item_id!("stale"), 1i32);
+    type_map.insert(item_id!("fresh"), 2i32);
+    type_map.insert(item_id!("unknown"), 3i32);
+    let timestamps = [
+        (item_id!("stale"), now - chrono::Duration::hours(2)),
+        (item_id!("fresh"), now - chrono::Duration::minutes(30)),
+    ]
+    .into_iter()
+    .collect::<StatesTimestamps>();
+
+    let states = StatesCurrent::from(type_map).with_timestamps(timestamps);
+
+    assert_eq!(
+        vec![item_id!("stale")],
+        states.item_ids_older_than(Duration::from_secs(3600), now)
+    );
+}
+
+#[test]
+fn clone_keeps_timestamps() {
+    let states = test_states();
+    let states_clone = Clone::clone(&states);
+
+    assert_eq!(states.timestamps(), states_clone.timestamps());
+}
+
 fn test_states() -> StatesCurrent {
     let mut states = StatesMut::new();
     states.insert(item_id!("key"), 123i32);
@@ -0,0 +1,74 @@
+use peace::{
+    cfg::item_id,
+    resources::{
+        internal::StatesMut,
+        states::{ts::Current, StatesCurrent, StatesViewError},
+        states_view,
+    },
+};
+
+states_view! {
+    /// States of the items in the test flow.
+    pub struct TestFlowStates {
+        /// Number of replicas.
+        pub replicas: i32 = item_id!("replicas"),
+        /// Name of the server.
+        pub server_name: String = item_id!("server_name"),
+    }
+}
+
+#[test]
+fn view_returns_typed_state_for_each_item() -> Result<(), StatesViewError> {
+    let states = test_states();
+
+    let test_flow_states = states.view::<TestFlowStates>()?;
+
+    assert_eq!(&3, test_flow_states.replicas);
+    assert_eq!("server", test_flow_states.server_name);
+    Ok(())
+}
+
+#[test]
+fn view_returns_error_with_each_item_missing_or_of_different_type() {
+    let mut states_mut = StatesMut::<Current>::new();
+    states_mut.insert(item_id!("server_name"), 123i32);
+    let states = StatesCurrent::from(states_mut);
+
+    let states_view_error = states
+        .view::<TestFlowStates>()
+        .expect_err("Expected `view` to fail when states are missing.");
+
+    assert_eq!(
+        &[item_id!("replicas"), item_id!("server_name")],
+        states_view_error.item_ids_missing()
+    );
+    assert_eq!(
+        "States are missing for items: `replicas`, `server_name`",
+        states_view_error.to_string()
+    );
+}
+
+#[test]
+fn try_get_returns_state_when_present() -> Result<(), StatesViewError> {
+    let states = test_states();
+
+    assert_eq!(&3, states.try_get::<i32>(&item_id!("replicas"))?);
+    Ok(())
+}
+
+#[test]
+fn try_get_returns_error_when_state_absent() {
+    let states = test_states();
+
+    assert_eq!(
+        Err(StatesViewError::new(vec![item_id!("absent")])),
+        states.try_get::<i32>(&item_id!("absent"))
+    );
+}
+
+fn test_states() -> StatesCurrent {
+    let mut states_mut = StatesMut::<Current>::new();
+    states_mut.insert(item_id!("replicas"), 3i32);
+    states_mut.insert(item_id!("server_name"), String::from("server"));
+    StatesCurrent::from(states_mut)
+}
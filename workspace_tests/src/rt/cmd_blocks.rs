@@ -1,6 +1,7 @@
 mod apply_exec_cmd_block;
 mod apply_state_sync_check_cmd_block;
 mod diff_cmd_block;
+mod preflight_check_cmd_block;
 mod states_clean_insertion_cmd_block;
 mod states_current_read_cmd_block;
 mod states_discover_cmd_block;
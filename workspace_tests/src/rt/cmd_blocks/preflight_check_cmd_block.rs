@@ -0,0 +1,162 @@
+use peace::{
+    cfg::{app_name, profile, FlowId},
+    cmd::ctx::CmdCtx,
+    cmd_rt::CmdBlock,
+    resources::paths::StatesCurrentFile,
+    rt::{
+        cmd_blocks::PreflightCheckCmdBlock,
+        cmds::{EnsureCmd, StatesDiscoverCmd},
+    },
+    rt_model::{
+        Error as PeaceRtError, Flow, ItemGraphBuilder, PreflightCheck, PreflightCheckFailure,
+        Workspace, WorkspaceSpec,
+    },
+};
+
+use crate::{
+    mock_item::{MockItem, MockSrc, MockState},
+    peace_cmd_ctx_types::PeaceCmdCtxTypes,
+    NoOpOutput, PeaceTestError, VecA, VecCopyItem, VecCopyState,
+};
+
+#[test]
+fn input_type_names_is_empty() {
+    let cmd_block = PreflightCheckCmdBlock::<PeaceCmdCtxTypes>::new();
+
+    assert!(cmd_block.input_type_names().is_empty());
+}
+
+#[test]
+fn outcome_type_names_is_empty() {
+    let cmd_block = PreflightCheckCmdBlock::<PeaceCmdCtxTypes>::new();
+
+    assert!(cmd_block.outcome_type_names().is_empty());
+}
+
+#[tokio::test]
+async fn states_discover_returns_all_preflight_check_failures()
+-> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let flow = flow()?
+        .with_preflight_check(PreflightCheck::new("disk space", || {
+            Box::pin(async { Err(String::from("Less than 1 GiB free.")) })
+        }))
+        .with_preflight_check(PreflightCheck::new("docker installed", || {
+            Box::pin(async { Ok(()) })
+        }))
+        .with_preflight_check(PreflightCheck::new("credentials valid", || {
+            Box::pin(async { Err(String::from("Credentials expired.")) })
+        }));
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    let states_current_file = StatesCurrentFile::from(cmd_ctx.flow_dir());
+
+    let error = StatesDiscoverCmd::current(&mut cmd_ctx)
+        .await
+        .expect_err("Expected `StatesDiscoverCmd::current` to fail preflight checks.");
+
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    &error,
+                    PeaceTestError::PeaceRt(PeaceRtError::PreflightChecksFailed {
+                        preflight_check_failures,
+                    })
+                    if preflight_check_failures == &[
+                        PreflightCheckFailure::new(
+                            String::from("disk space"),
+                            String::from("Less than 1 GiB free."),
+                        ),
+                        PreflightCheckFailure::new(
+                            String::from("credentials valid"),
+                            String::from("Credentials expired."),
+                        ),
+                    ]
+                ),
+                "Expected `error` to be \
+                `PeaceTestError::PeaceRt(PeaceRtError::PreflightChecksFailed {{ .. }})`,\n\
+                but was `{error:?}`",
+            );
+        }
+    })();
+    assert!(!states_current_file.exists());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn ensure_applies_items_when_preflight_checks_pass() -> Result<(), Box<dyn std::error::Error>>
+{
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let flow = flow()?.with_preflight_checks([
+        PreflightCheck::new("docker installed", || Box::pin(async { Ok(()) })),
+        PreflightCheck::new("credentials valid", || Box::pin(async { Ok(()) })),
+    ]);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let states_ensured_outcome = EnsureCmd::exec(&mut cmd_ctx).await?;
+    let states_ensured = states_ensured_outcome
+        .value()
+        .expect("Expected `EnsureCmd::exec` to complete successfully.");
+
+    assert_eq!(
+        Some(VecCopyState::from(vec![0, 1, 2, 3])).as_ref(),
+        states_ensured.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+    assert_eq!(
+        Some(MockState(1)).as_ref(),
+        states_ensured.get::<MockState, _>(MockItem::<()>::ID_DEFAULT)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn debug() {
+    let cmd_block = PreflightCheckCmdBlock::<PeaceCmdCtxTypes>::new();
+
+    assert_eq!(
+        r#"PreflightCheckCmdBlock(PhantomData<workspace_tests::peace_cmd_ctx_types::PeaceCmdCtxTypes>)"#,
+        format!("{cmd_block:?}")
+    );
+}
+
+fn flow() -> Result<Flow<PeaceTestError>, Box<dyn std::error::Error>> {
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+
+    Ok(flow)
+}
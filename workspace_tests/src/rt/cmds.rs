@@ -1,9 +1,31 @@
+mod apply_error_policy;
 mod apply_stored_state_sync;
+mod clean_check_cmd;
 mod clean_cmd;
 mod diff_cmd;
 mod ensure_cmd;
+mod execution_report_cmd;
+#[cfg(feature = "output_progress")]
+mod execution_progress_read_cmd;
+mod flow_info_cmd;
+#[cfg(feature = "item_schema")]
+mod flow_schema_cmd;
+mod multi_workspace_cmd;
+mod params_explain_cmd;
+mod params_history_cmd;
+mod params_reconcile_cmd;
+mod profile_diff_matrix_cmd;
+mod profile_list_cmd;
+mod states_checkout_cmd;
+mod states_clean_display_cmd;
 mod states_current_read_cmd;
 mod states_current_stored_display_cmd;
 mod states_discover_cmd;
+mod states_export_cmd;
 mod states_goal_display_cmd;
 mod states_goal_read_cmd;
+mod states_prune_cmd;
+mod states_import_cmd;
+mod states_reserialize_cmd;
+mod states_watch_cmd;
+mod workspace_doctor_cmd;
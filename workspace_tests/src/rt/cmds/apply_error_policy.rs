@@ -0,0 +1,19 @@
+use peace::rt::cmds::ApplyErrorPolicy;
+
+#[test]
+fn clone() {
+    let _apply_error_policy = Clone::clone(&ApplyErrorPolicy::ContinueOnError);
+}
+
+#[test]
+fn debug() {
+    assert_eq!(
+        "ContinueOnError",
+        format!("{:?}", ApplyErrorPolicy::ContinueOnError)
+    );
+}
+
+#[test]
+fn default_is_abort() {
+    assert_eq!(ApplyErrorPolicy::Abort, ApplyErrorPolicy::default());
+}
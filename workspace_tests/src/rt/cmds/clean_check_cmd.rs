@@ -0,0 +1,184 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use peace::{
+    cfg::{app_name, profile, FlowId},
+    cmd::ctx::CmdCtx,
+    cmd_model::CmdOutcome,
+    rt::cmds::{CleanCheckCmd, CleanCmd, EnsureCmd, StatesDiscoverCmd},
+    rt_model::{Flow, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+
+use crate::{
+    mock_item::{MockItem, MockSrc, MockState},
+    peace_cmd_ctx_types::PeaceCmdCtxTypes,
+    NoOpOutput, PeaceTestError, VecA, VecCopyItem,
+};
+
+#[tokio::test]
+async fn exec_returns_no_resources_dangling_when_state_cleaned()
+-> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    EnsureCmd::exec(&mut cmd_ctx).await?;
+    CleanCmd::exec(&mut cmd_ctx).await?;
+
+    let CmdOutcome::Complete {
+        value: resources_dangling,
+        cmd_blocks_processed: _,
+    } = CleanCheckCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `CleanCheckCmd::exec` to complete successfully.");
+    };
+
+    assert!(resources_dangling.is_empty());
+    assert_eq!(0, resources_dangling.item_ids().count());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_resources_dangling_when_state_current_not_cleaned()
+-> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        // The resource is never deleted, regardless of what clean reports.
+        graph_builder.add_fn(
+            MockItem::<()>::default()
+                .with_try_state_current(|_, _, _| Ok(Some(MockState(1))))
+                .with_state_current(|_, _, _| Ok(MockState(1)))
+                .into(),
+        );
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    EnsureCmd::exec(&mut cmd_ctx).await?;
+    CleanCmd::exec(&mut cmd_ctx).await?;
+
+    let CmdOutcome::Complete {
+        value: resources_dangling,
+        cmd_blocks_processed: _,
+    } = CleanCheckCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `CleanCheckCmd::exec` to complete successfully.");
+    };
+
+    assert!(!resources_dangling.is_empty());
+    assert_eq!(
+        vec![MockItem::<()>::ID_DEFAULT],
+        resources_dangling.item_ids().collect::<Vec<_>>()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_with_retry_cleans_resources_dangling_again()
+-> Result<(), Box<dyn std::error::Error>> {
+    // The resource is only deleted by the second clean.
+    static APPLY_COUNT: AtomicU8 = AtomicU8::new(0);
+
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(
+            MockItem::<()>::default()
+                .with_try_state_current(|_, _, data| {
+                    if APPLY_COUNT.load(Ordering::SeqCst) < 2 {
+                        Ok(Some(MockState(1)))
+                    } else {
+                        Ok(Some(MockState(data.dest().0)))
+                    }
+                })
+                .with_state_current(|_, _, data| {
+                    if APPLY_COUNT.load(Ordering::SeqCst) < 2 {
+                        Ok(MockState(1))
+                    } else {
+                        Ok(MockState(data.dest().0))
+                    }
+                })
+                .with_apply(|_, _, mut data, _, state_target, _| {
+                    APPLY_COUNT.fetch_add(1, Ordering::SeqCst);
+                    data.dest_mut().0 = state_target.0;
+                    Ok(state_target.clone())
+                })
+                .into(),
+        );
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    CleanCmd::exec(&mut cmd_ctx).await?;
+
+    let CmdOutcome::Complete {
+        value: resources_dangling,
+        cmd_blocks_processed: _,
+    } = CleanCheckCmd::exec_with_retry(&mut cmd_ctx, 1).await?
+    else {
+        panic!("Expected `CleanCheckCmd::exec_with_retry` to complete successfully.");
+    };
+
+    assert!(resources_dangling.is_empty());
+    assert_eq!(2, APPLY_COUNT.load(Ordering::SeqCst));
+
+    Ok(())
+}
+
+#[test]
+fn debug() {
+    let debug_str = format!("{:?}", CleanCheckCmd::<PeaceCmdCtxTypes>::default());
+    assert_eq!(
+        r#"CleanCheckCmd(PhantomData<workspace_tests::peace_cmd_ctx_types::PeaceCmdCtxTypes>)"#,
+        debug_str,
+    );
+}
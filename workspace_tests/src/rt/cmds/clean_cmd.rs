@@ -4,12 +4,12 @@ use peace::{
     cmd_model::CmdOutcome,
     resources::type_reg::untagged::BoxDataTypeDowncast,
     rt::cmds::{
-        ApplyStoredStateSync, CleanCmd, EnsureCmd, StatesCurrentReadCmd, StatesDiscoverCmd,
-        StatesGoalReadCmd,
+        ApplyPlanAction, ApplyStoredStateSync, CleanCmd, EnsureCmd, StatesCurrentReadCmd,
+        StatesDiscoverCmd, StatesGoalReadCmd,
     },
     rt_model::{
-        ApplyCmdError, Error as PeaceRtError, Flow, ItemGraphBuilder, StateStoredAndDiscovered,
-        Workspace, WorkspaceSpec,
+        ApplyCmdError, CleanOrderingOverride, Error as PeaceRtError, Flow, ItemGraphBuilder,
+        StateStoredAndDiscovered, Workspace, WorkspaceSpec,
     },
 };
 
@@ -803,6 +803,7 @@ async fn states_current_not_serialized_on_states_clean_insert_cmd_block_fail()
         cmd_blocks_processed: _,
         cmd_blocks_not_processed: _,
         errors,
+        item_log_paths: _,
     } = CleanCmd::exec(&mut cmd_ctx).await?
     else {
         panic!("Expected `CleanCmd::exec` to complete with item error.");
@@ -926,6 +927,7 @@ async fn states_current_not_serialized_on_states_discover_cmd_block_fail()
         cmd_blocks_processed: _,
         cmd_blocks_not_processed: _,
         errors,
+        item_log_paths: _,
     } = CleanCmd::exec(&mut cmd_ctx).await?
     else {
         panic!("Expected `CleanCmd::exec` to complete with item error.");
@@ -977,6 +979,125 @@ async fn states_current_not_serialized_on_states_discover_cmd_block_fail()
     Ok(())
 }
 
+#[tokio::test]
+async fn plan_returns_apply_plan_in_clean_order() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    EnsureCmd::exec(&mut cmd_ctx).await?;
+
+    let CmdOutcome::Complete {
+        value: apply_plan,
+        cmd_blocks_processed: _,
+    } = CleanCmd::plan(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `CleanCmd::plan` to complete successfully.");
+    };
+
+    assert_eq!(
+        vec![MockItem::<()>::ID_DEFAULT, VecCopyItem::ID_DEFAULT],
+        apply_plan.keys().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![ApplyPlanAction::Clean, ApplyPlanAction::Clean],
+        apply_plan
+            .values()
+            .map(|item_apply_plan| item_apply_plan.action)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        Some(VecCopyState::new().to_string()).as_deref(),
+        apply_plan
+            .get(VecCopyItem::ID_DEFAULT)
+            .map(|item_apply_plan| item_apply_plan.state_target.as_str())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn plan_returns_apply_plan_in_clean_ordering_override_order(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph)
+        .with_clean_ordering_overrides([CleanOrderingOverride::new(
+            VecCopyItem::ID_DEFAULT.clone(),
+            MockItem::<()>::ID_DEFAULT.clone(),
+        )]);
+    let mut output = NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    EnsureCmd::exec(&mut cmd_ctx).await?;
+
+    let CmdOutcome::Complete {
+        value: apply_plan,
+        cmd_blocks_processed: _,
+    } = CleanCmd::plan(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `CleanCmd::plan` to complete successfully.");
+    };
+
+    assert_eq!(
+        vec![VecCopyItem::ID_DEFAULT, MockItem::<()>::ID_DEFAULT],
+        apply_plan.keys().collect::<Vec<_>>()
+    );
+
+    // Cleaning also succeeds with the override.
+    let CmdOutcome::Complete {
+        value: states_cleaned,
+        cmd_blocks_processed: _,
+    } = CleanCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `CleanCmd::exec` to complete successfully.");
+    };
+    assert_eq!(
+        Some(VecCopyState::new()).as_ref(),
+        states_cleaned.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+
+    Ok(())
+}
+
 #[test]
 fn debug() {
     let debug_str = format!("{:?}", CleanCmd::<PeaceCmdCtxTypes>::default());
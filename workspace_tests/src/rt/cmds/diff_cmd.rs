@@ -1,6 +1,6 @@
 use diff::{VecDiff, VecDiffType};
 use peace::{
-    cfg::{app_name, profile, FlowId},
+    cfg::{app_name, profile, DiffSeverity, FlowId},
     cli::output::CliOutput,
     cmd::ctx::CmdCtx,
     cmd_model::CmdOutcome,
@@ -21,6 +21,7 @@ use crate::{
 
 mod diff_info_spec;
 mod diff_state_spec;
+mod state_diffs_stored;
 
 #[tokio::test]
 async fn diff_stored_contains_state_diff_for_each_item() -> Result<(), Box<dyn std::error::Error>> {
@@ -93,6 +94,164 @@ async fn diff_stored_contains_state_diff_for_each_item() -> Result<(), Box<dyn s
     Ok(())
 }
 
+#[tokio::test]
+async fn stored_diffs_stored_states_without_discovery() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+
+    // Discover current and goal states.
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    // Diff stored states in a new `CmdCtx`.
+    let mut buffer = Vec::with_capacity(256);
+    let mut output = CliOutput::new_with_writer(&mut buffer);
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .await?;
+    let CmdOutcome::Complete {
+        value: state_diffs_stored,
+        cmd_blocks_processed: _,
+    } = DiffCmd::stored(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `DiffCmd::stored` to complete successfully.");
+    };
+    <_ as OutputWrite<PeaceTestError>>::present(cmd_ctx.output_mut(), &state_diffs_stored)
+        .await?;
+
+    assert_eq!(
+        Some(VecCopyDiff::from(VecDiff(vec![VecDiffType::Inserted {
+            index: 0,
+            changes: vec![0u8, 1, 2, 3]
+        }])))
+        .as_ref(),
+        state_diffs_stored.get::<VecCopyDiff, _>(VecCopyItem::ID_DEFAULT)
+    );
+    assert!(!cmd_ctx.resources().contains::<StatesCurrent>());
+    assert!(!cmd_ctx.resources().contains::<StatesGoal>());
+    assert_eq!(
+        "# State diffs from stored states (may be stale)\n\
+        \n\
+        1. `vec_copy`: [(+)0;0, 1, 2, 3, ] (current: 0s old, goal: 0s old)\n",
+        String::from_utf8(buffer)?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn stored_returns_err_when_states_not_discovered() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .await?;
+
+    let exec_result = DiffCmd::stored(&mut cmd_ctx).await;
+
+    assert!(
+        matches!(
+            &exec_result,
+            Err(PeaceTestError::PeaceRt(
+                peace::rt_model::Error::StatesCurrentDiscoverRequired
+            ))
+        ),
+        "was {exec_result:?}"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn diff_stored_summary_contains_diff_severity_for_each_item(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+
+    // Discover current and goal states.
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(0).into())
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    // Diff current and goal states.
+    let CmdOutcome::Complete {
+        value: (state_diffs, diff_summary),
+        cmd_blocks_processed: _,
+    } = DiffCmd::diff_stored_summary(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `DiffCmd::diff_stored_summary` to complete successfully.");
+    };
+
+    assert_eq!(2, state_diffs.len());
+    assert_eq!(
+        Some(DiffSeverity::Additive).as_ref(),
+        diff_summary.get(VecCopyItem::ID_DEFAULT)
+    );
+    assert_eq!(
+        Some(DiffSeverity::InSync).as_ref(),
+        diff_summary.get(MockItem::<()>::ID_DEFAULT)
+    );
+    assert_eq!(1, diff_summary.in_sync_count());
+    assert_eq!(1, diff_summary.additive_count());
+    assert_eq!(2, diff_summary.detailed_exit_code());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn diff_discover_current_on_demand() -> Result<(), Box<dyn std::error::Error>> {
     let tempdir = tempfile::tempdir()?;
@@ -0,0 +1,32 @@
+use peace::{
+    cfg::item_id,
+    resources::{states::StateDiffs, type_reg::untagged::TypeMap},
+    rt::cmds::StateDiffsStored,
+};
+
+#[test]
+fn deref_returns_state_diffs() {
+    let mut type_map = TypeMap::new_typed();
+    type_map.insert(item_id!("key"), 123u32);
+    let state_diffs_stored = StateDiffsStored::from(StateDiffs::from(type_map));
+
+    assert_eq!(
+        Some(123),
+        state_diffs_stored.get::<u32, _>(&item_id!("key")).copied()
+    );
+}
+
+#[test]
+fn into_inner() {
+    let state_diffs_stored = StateDiffsStored::new(StateDiffs::new());
+
+    assert!(state_diffs_stored.into_inner().is_empty());
+}
+
+#[test]
+fn debug() {
+    assert_eq!(
+        "StateDiffsStored(StateDiffs({}))",
+        format!("{:?}", StateDiffsStored::default())
+    );
+}
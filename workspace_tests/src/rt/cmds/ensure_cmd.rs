@@ -1,27 +1,42 @@
+use std::sync::{Arc, Mutex};
+
 use peace::{
-    cfg::{app_name, profile, FlowId},
+    cfg::{
+        app_name, item_group_id, item_id, profile, state::Sensitive, ApplyCheck, ApplyCheckReason,
+        DiffSeverity, FlowId,
+    },
+    cli::output::{CliColorizeOpt, CliMdPresenter, CliOutputBuilder},
     cmd::{
         ctx::CmdCtx,
         interruptible::{InterruptSignal, InterruptStrategy, Interruptibility},
     },
     cmd_model::{CmdBlockDesc, CmdOutcome},
+    fmt::Presentable,
     resources::{
         paths::{StatesCurrentFile, StatesGoalFile},
         type_reg::untagged::BoxDataTypeDowncast,
     },
-    rt::cmds::{ApplyStoredStateSync, EnsureCmd, StatesCurrentReadCmd, StatesDiscoverCmd},
+    rt::cmds::{
+        ApplyPlanAction, ApplyStoredStateSync, ApplySummary, EnsureCmd, StatesCurrentReadCmd,
+        StatesDiscoverCmd,
+    },
     rt_model::{
-        ApplyCmdError, Error as PeaceRtError, Flow, ItemGraphBuilder, StateStoredAndDiscovered,
-        Workspace, WorkspaceSpec,
+        ApplyCmdError, ApplyHookOutcome, ApprovalPolicy, Approver, Error as PeaceRtError, Flow,
+        HeartbeatPolicy, InMemoryOutput, ItemGraphBuilder, ItemsDisabled, KeyProvider,
+        StateStoredAndDiscovered, Workspace, WorkspaceSpec,
     },
 };
 use tokio::sync::mpsc;
 
+#[cfg(feature = "output_progress")]
+use peace::cfg::progress::ProgressLimit;
+
 use crate::{
-    mock_item::{MockItem, MockItemError, MockSrc, MockState},
+    mock_item::{MockItem, MockItemError, MockReadsVecCopyOutput, MockSrc, MockState},
     peace_cmd_ctx_types::PeaceCmdCtxTypes,
+    token_item::{TokenItem, TokenSrc, TokenState},
     vec_copy_item::VecB,
-    NoOpOutput, PeaceTestError, VecA, VecCopyItem, VecCopyState,
+    FnInvocation, FnTrackerOutput, NoOpOutput, PeaceTestError, VecA, VecCopyItem, VecCopyState,
 };
 
 #[tokio::test]
@@ -188,6 +203,193 @@ async fn resources_ensured_contains_state_ensured_for_each_item_when_state_not_y
     Ok(())
 }
 
+#[tokio::test]
+async fn exec_skips_items_not_applicable_to_profile() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+
+    // Write current and goal states to disk.
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(MockItem::<()>::PROFILE_NOT_APPLICABLE.clone())
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    let CmdOutcome::Complete {
+        value: (states_current, states_goal),
+        cmd_blocks_processed: _,
+    } = StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::current_and_goal` to complete successfully.");
+    };
+
+    let CmdOutcome::Complete {
+        value: states_ensured,
+        cmd_blocks_processed: _,
+    } = EnsureCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec` to complete successfully.");
+    };
+
+    assert!(states_current
+        .get::<MockState, _>(MockItem::<()>::ID_DEFAULT)
+        .is_none());
+    assert!(states_goal
+        .get::<MockState, _>(MockItem::<()>::ID_DEFAULT)
+        .is_none());
+    assert_eq!(
+        Some(VecCopyState::from(vec![0u8, 1, 2, 3, 4, 5, 6, 7])).as_ref(),
+        states_ensured.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+    assert!(states_ensured
+        .get::<MockState, _>(MockItem::<()>::ID_DEFAULT)
+        .is_none());
+
+    Ok(())
+}
+
+#[cfg(feature = "telemetry")]
+#[tokio::test]
+async fn exec_records_item_metrics_to_meter_provider() -> Result<(), Box<dyn std::error::Error>> {
+    use crate::recording_meter_provider::RecordingMeterProvider;
+
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+    let meter_provider = RecordingMeterProvider::new();
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_meter_provider(&meter_provider)
+        .with_profile(MockItem::<()>::PROFILE_NOT_APPLICABLE.clone())
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    EnsureCmd::exec(&mut cmd_ctx).await?;
+
+    let flow_id = flow.flow_id().to_string();
+    let item_discover_duration = meter_provider.measurements_named("peace.item.discover.duration");
+    assert!(!item_discover_duration.is_empty());
+    assert!(item_discover_duration.iter().all(|measurement| {
+        measurement.attribute("item_id") == Some("vec_copy")
+            && measurement.attribute("flow_id") == Some(flow_id.as_str())
+            && measurement.attribute("profile") == Some("mock_not_applicable")
+    }));
+
+    let items_applied = meter_provider.measurements_named("peace.items.applied");
+    assert_eq!(1, items_applied.len());
+    assert_eq!(Some("vec_copy"), items_applied[0].attribute("item_id"));
+    let items_skipped = meter_provider.measurements_named("peace.items.skipped");
+    assert_eq!(1, items_skipped.len());
+    assert_eq!(Some("mock"), items_skipped[0].attribute("item_id"));
+    assert!(meter_provider
+        .measurements_named("peace.items.failed")
+        .is_empty());
+    assert_eq!(
+        1,
+        meter_provider
+            .measurements_named("peace.item.apply.duration")
+            .len()
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "profiling")]
+#[tokio::test]
+async fn exec_records_timing_breakdown_for_each_item_fn() -> Result<(), Box<dyn std::error::Error>>
+{
+    use peace::cmd_model::ItemFnKind;
+
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let cmd_outcome = EnsureCmd::exec(&mut cmd_ctx).await?;
+    let timing_breakdown = cmd_outcome.timing_breakdown();
+
+    [
+        ItemFnKind::StateCurrent,
+        ItemFnKind::StateGoal,
+        ItemFnKind::StateDiff,
+        ItemFnKind::ApplyCheck,
+        ItemFnKind::ApplyExec,
+    ]
+    .into_iter()
+    .for_each(|item_fn_kind| {
+        assert!(
+            timing_breakdown
+                .get(VecCopyItem::ID_DEFAULT, item_fn_kind)
+                .is_some(),
+            "Expected `{item_fn_kind}` timing to be recorded."
+        );
+    });
+    assert_eq!(
+        Some(1),
+        timing_breakdown
+            .get(VecCopyItem::ID_DEFAULT, ItemFnKind::ApplyExec)
+            .map(|item_fn_timing| item_fn_timing.calls)
+    );
+    let CmdOutcome::Complete {
+        value: _,
+        cmd_blocks_processed,
+    } = cmd_outcome
+    else {
+        panic!("Expected `EnsureCmd::exec` to complete successfully.");
+    };
+    assert!(cmd_blocks_processed
+        .iter()
+        .any(|cmd_block_desc| !cmd_block_desc.timing_breakdown().is_empty()));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn resources_ensured_contains_state_ensured_for_each_item_when_state_already_ensured()
 -> Result<(), Box<dyn std::error::Error>> {
@@ -824,6 +1026,7 @@ async fn exec_dry_returns_item_error_when_item_discover_current_returns_error()
         cmd_blocks_processed: _,
         cmd_blocks_not_processed: _,
         errors,
+        item_log_paths: _,
     } = EnsureCmd::exec_dry_with(&mut cmd_ctx, ApplyStoredStateSync::Current).await?
     else {
         panic!("Expected `EnsureCmd::exec_dry_with` to complete with item error.");
@@ -932,6 +1135,7 @@ async fn exec_dry_returns_item_error_when_item_discover_goal_returns_error()
         cmd_blocks_processed: _,
         cmd_blocks_not_processed: _,
         errors,
+        item_log_paths: _,
     } = EnsureCmd::exec_dry_with(&mut cmd_ctx, ApplyStoredStateSync::Current).await?
     else {
         panic!("Expected `EnsureCmd::exec_dry_with` to complete with item error.");
@@ -1016,7 +1220,7 @@ async fn exec_dry_returns_item_error_when_item_apply_check_returns_error()
         graph_builder.add_fn(VecCopyItem::default().into());
         graph_builder.add_fn(
             MockItem::<()>::default()
-                .with_apply_check(|_, _, _, _, _| {
+                .with_apply_check(|_, _, _, _, _, _| {
                     Err(MockItemError::Synthetic(String::from("apply_check_err")))
                 })
                 .into(),
@@ -1040,6 +1244,7 @@ async fn exec_dry_returns_item_error_when_item_apply_check_returns_error()
         cmd_blocks_processed: _,
         cmd_blocks_not_processed: _,
         errors,
+        item_log_paths: _,
     } = EnsureCmd::exec_dry_with(&mut cmd_ctx, ApplyStoredStateSync::Current).await?
     else {
         panic!("Expected `EnsureCmd::exec_dry_with` to complete with item error.");
@@ -1148,6 +1353,7 @@ async fn exec_dry_returns_item_error_when_item_apply_dry_returns_error()
         cmd_blocks_processed: _,
         cmd_blocks_not_processed: _,
         errors,
+        item_log_paths: _,
     } = EnsureCmd::exec_dry_with(&mut cmd_ctx, ApplyStoredStateSync::Current).await?
     else {
         panic!("Expected `EnsureCmd::exec_dry_with` to complete with item error.");
@@ -1256,6 +1462,7 @@ async fn exec_returns_item_error_when_item_apply_returns_error()
         cmd_blocks_processed: _,
         cmd_blocks_not_processed: _,
         errors,
+        item_log_paths: _,
     } = EnsureCmd::exec_with(&mut cmd_ctx, ApplyStoredStateSync::Current).await?
     else {
         panic!("Expected `EnsureCmd::exec_with` to complete with item error.");
@@ -1295,6 +1502,181 @@ async fn exec_returns_item_error_when_item_apply_returns_error()
     Ok(())
 }
 
+#[tokio::test]
+async fn exec_continue_on_error_applies_items_not_dependent_on_failed_item()
+-> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        let [mock_fail_id, vec_copy_id, mock_blocked_id] = graph_builder.add_fns([
+            MockItem::<()>::new(item_id!("mock_fail"))
+                .with_apply(|_, _, _, _, _, _| {
+                    Err(MockItemError::Synthetic(String::from("apply_err")))
+                })
+                .into(),
+            VecCopyItem::default().into(),
+            MockItem::<MockReadsVecCopyOutput>::new(item_id!("mock_blocked")).into(),
+        ]);
+        graph_builder.add_logic_edge(mock_fail_id, mock_blocked_id)?;
+        graph_builder.add_logic_edge(vec_copy_id, mock_blocked_id)?;
+        graph_builder.try_build()?
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<MockItem<()>>(item_id!("mock_fail"), MockSrc(1).into())
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .with_item_params::<MockItem<MockReadsVecCopyOutput>>(
+            item_id!("mock_blocked"),
+            MockSrc(1).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let CmdOutcome::ItemError {
+        item_stream_outcome,
+        cmd_blocks_processed: _,
+        cmd_blocks_not_processed: _,
+        errors,
+        item_log_paths: _,
+    } = EnsureCmd::exec_continue_on_error(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec_continue_on_error` to complete with item error.");
+    };
+    let states_ensured = item_stream_outcome.value();
+
+    assert_eq!(
+        Some(VecCopyState::from(vec![0, 1, 2, 3])).as_ref(),
+        states_ensured.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+    assert_eq!(
+        Some(MockState(0)).as_ref(),
+        states_ensured.get::<MockState, _>(&item_id!("mock_blocked"))
+    );
+    assert_eq!(2, errors.len());
+    let mock_fail_error = errors.get(&item_id!("mock_fail"));
+    let mock_blocked_error = errors.get(&item_id!("mock_blocked"));
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    mock_fail_error,
+                    Some(PeaceTestError::Mock(MockItemError::Synthetic(s)))
+                    if s == "apply_err"
+                ),
+                "Expected `mock_fail_error` to be \
+                `Err(.. {{ MockItemError::Synthetic {{ \"apply_err\" }} }})`,\n\
+                but was `{mock_fail_error:?}`",
+            );
+            assert!(
+                matches!(
+                    mock_blocked_error,
+                    Some(PeaceTestError::PeaceRt(PeaceRtError::ItemApplyBlocked {
+                        item_id,
+                        item_id_blocking,
+                    }))
+                    if item_id == &item_id!("mock_blocked")
+                    && item_id_blocking == &item_id!("mock_fail")
+                ),
+                "Expected `mock_blocked_error` to be \
+                `Err(.. {{ PeaceRtError::ItemApplyBlocked {{ .. }} }})`,\n\
+                but was `{mock_blocked_error:?}`",
+            );
+        }
+    })();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_rolls_back_applied_items_in_atomic_group_when_item_in_group_fails(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fns([
+            MockItem::<()>::new(item_id!("mock_fail"))
+                .with_apply(|_, _, _, _, _, _| {
+                    Err(MockItemError::Synthetic(String::from("apply_err")))
+                })
+                .into(),
+            VecCopyItem::default().into(),
+        ]);
+        graph_builder.try_build()?
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph).with_atomic_group(
+        item_group_id!("atomic_group"),
+        [item_id!("mock_fail"), VecCopyItem::ID_DEFAULT.clone()],
+    );
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<MockItem<()>>(item_id!("mock_fail"), MockSrc(1).into())
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let CmdOutcome::ItemError {
+        item_stream_outcome,
+        cmd_blocks_processed: _,
+        cmd_blocks_not_processed: _,
+        errors,
+        item_log_paths: _,
+    } = EnsureCmd::exec_continue_on_error(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec_continue_on_error` to complete with item error.");
+    };
+    let states_ensured = item_stream_outcome.value();
+
+    assert_eq!(
+        Some(VecCopyState::new()).as_ref(),
+        states_ensured.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+    assert_eq!(2, errors.len());
+    let vec_copy_error = errors.get(VecCopyItem::ID_DEFAULT);
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    vec_copy_error,
+                    Some(PeaceTestError::PeaceRt(PeaceRtError::ItemApplyRolledBack {
+                        item_id,
+                        item_group_id,
+                        item_id_failed,
+                    }))
+                    if item_id == VecCopyItem::ID_DEFAULT
+                    && item_group_id == &item_group_id!("atomic_group")
+                    && item_id_failed == &item_id!("mock_fail")
+                ),
+                "Expected `vec_copy_error` to be \
+                `Err(.. {{ PeaceRtError::ItemApplyRolledBack {{ .. }} }})`,\n\
+                but was `{vec_copy_error:?}`",
+            );
+        }
+    })();
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn states_current_not_serialized_on_states_current_read_cmd_block_interrupt()
 -> Result<(), Box<dyn std::error::Error>> {
@@ -1344,6 +1726,7 @@ async fn states_current_not_serialized_on_states_current_read_cmd_block_interrup
         value,
         cmd_blocks_processed,
         cmd_blocks_not_processed,
+        deadline_exceeded: false,
     } = cmd_outcome
     else {
         panic!(
@@ -1430,6 +1813,7 @@ async fn states_current_not_serialized_on_states_goal_read_cmd_block_interrupt()
         value,
         cmd_blocks_processed,
         cmd_blocks_not_processed,
+        deadline_exceeded: false,
     } = cmd_outcome
     else {
         panic!(
@@ -1534,6 +1918,7 @@ async fn states_current_not_serialized_on_states_discover_cmd_block_fail()
         cmd_blocks_processed,
         cmd_blocks_not_processed,
         errors,
+        item_log_paths: _,
     } = EnsureCmd::exec(&mut cmd_ctx).await?
     else {
         panic!("Expected `EnsureCmd::exec` to complete with item error.");
@@ -1646,6 +2031,7 @@ async fn states_current_not_serialized_on_apply_state_sync_check_cmd_block_inter
         value,
         cmd_blocks_processed,
         cmd_blocks_not_processed,
+        deadline_exceeded: false,
     } = cmd_outcome
     else {
         panic!(
@@ -1747,6 +2133,7 @@ async fn states_current_is_serialized_on_apply_exec_cmd_block_interrupt()
         item_stream_outcome,
         cmd_blocks_processed,
         cmd_blocks_not_processed,
+        deadline_exceeded: false,
     } = cmd_outcome
     else {
         panic!(
@@ -1829,6 +2216,1044 @@ mock: 1
     Ok(())
 }
 
+#[tokio::test]
+async fn exec_invokes_pre_and_post_apply_hooks_for_each_item(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+    let hook_invocations = Arc::new(Mutex::new(Vec::<String>::new()));
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .with_pre_apply_hook({
+            let hook_invocations = Arc::clone(&hook_invocations);
+            move |item_id, _state_diff| {
+                hook_invocations
+                    .lock()
+                    .expect("Expected lock to not be poisoned.")
+                    .push(format!("pre: {item_id}"));
+                Ok(())
+            }
+        })
+        .with_post_apply_hook({
+            let hook_invocations = Arc::clone(&hook_invocations);
+            move |item_id, _state_diff, apply_hook_outcome| {
+                let outcome = match apply_hook_outcome {
+                    ApplyHookOutcome::Success { .. } => "success",
+                    ApplyHookOutcome::Fail { .. } => "fail",
+                };
+                hook_invocations
+                    .lock()
+                    .expect("Expected lock to not be poisoned.")
+                    .push(format!("post: {item_id} {outcome}"));
+            }
+        })
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    // Dry runs do not apply anything, so no hooks are invoked.
+    EnsureCmd::exec_dry(&mut cmd_ctx).await?;
+    assert!(hook_invocations
+        .lock()
+        .expect("Expected lock to not be poisoned.")
+        .is_empty());
+
+    EnsureCmd::exec(&mut cmd_ctx).await?;
+
+    let mut hook_invocations_ensure = std::mem::take(
+        &mut *hook_invocations
+            .lock()
+            .expect("Expected lock to not be poisoned."),
+    );
+    hook_invocations_ensure.sort();
+    assert_eq!(
+        vec![
+            String::from("post: mock success"),
+            String::from("post: vec_copy success"),
+            String::from("pre: mock"),
+            String::from("pre: vec_copy"),
+        ],
+        hook_invocations_ensure
+    );
+
+    // Items are already ensured, so no hooks are invoked.
+    EnsureCmd::exec(&mut cmd_ctx).await?;
+    assert!(hook_invocations
+        .lock()
+        .expect("Expected lock to not be poisoned.")
+        .is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_item_error_when_pre_apply_hook_returns_error(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+    let post_apply_outcomes = Arc::new(Mutex::new(Vec::<String>::new()));
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .with_pre_apply_hook(|item_id, _state_diff| {
+            if item_id == MockItem::<()>::ID_DEFAULT {
+                Err(PeaceTestError::Mock(MockItemError::Synthetic(
+                    String::from("pre_apply_hook_err"),
+                )))
+            } else {
+                Ok(())
+            }
+        })
+        .with_post_apply_hook({
+            let post_apply_outcomes = Arc::clone(&post_apply_outcomes);
+            move |item_id, _state_diff, apply_hook_outcome| {
+                let outcome = match apply_hook_outcome {
+                    ApplyHookOutcome::Success { .. } => "success",
+                    ApplyHookOutcome::Fail { .. } => "fail",
+                };
+                post_apply_outcomes
+                    .lock()
+                    .expect("Expected lock to not be poisoned.")
+                    .push(format!("{item_id} {outcome}"));
+            }
+        })
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let CmdOutcome::ItemError {
+        item_stream_outcome,
+        cmd_blocks_processed: _,
+        cmd_blocks_not_processed: _,
+        errors,
+        item_log_paths: _,
+    } = EnsureCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec` to complete with item error.");
+    };
+    let states_ensured = item_stream_outcome.value();
+
+    assert_eq!(
+        Some(VecCopyState::from(vec![0, 1, 2, 3])).as_ref(),
+        states_ensured.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+    assert_eq!(
+        Some(MockState(0)).as_ref(),
+        states_ensured.get::<MockState, _>(MockItem::<()>::ID_DEFAULT)
+    );
+    let mock_error = errors.get(MockItem::<()>::ID_DEFAULT);
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    mock_error,
+                    Some(PeaceTestError::Mock(MockItemError::Synthetic(s)))
+                    if s == "pre_apply_hook_err"
+                ),
+                "Expected `mock_error` to be \
+                `Err(.. {{ MockItemError::Synthetic {{ \"pre_apply_hook_err\" }} }})`,\n\
+                but was `{mock_error:?}`",
+            );
+        }
+    })();
+    // The mock item was not applied, so post apply hooks are not invoked for it.
+    assert_eq!(
+        vec![String::from("vec_copy success")],
+        *post_apply_outcomes
+            .lock()
+            .expect("Expected lock to not be poisoned.")
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_item_error_when_approval_required_and_no_approver(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .with_approval_policy(ApprovalPolicy::new().with_item_id(VecCopyItem::ID_DEFAULT.clone()))
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    // Dry runs do not require approval.
+    let states_ensured_dry_outcome = EnsureCmd::exec_dry(&mut cmd_ctx).await?;
+    assert!(states_ensured_dry_outcome.is_complete());
+
+    let CmdOutcome::ItemError {
+        item_stream_outcome,
+        cmd_blocks_processed: _,
+        cmd_blocks_not_processed: _,
+        errors,
+        item_log_paths: _,
+    } = EnsureCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec` to complete with item error.");
+    };
+    let states_ensured = item_stream_outcome.value();
+
+    assert_eq!(
+        Some(VecCopyState::new()).as_ref(),
+        states_ensured.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+    assert_eq!(
+        Some(MockState(1)).as_ref(),
+        states_ensured.get::<MockState, _>(MockItem::<()>::ID_DEFAULT)
+    );
+    let vec_copy_error = errors.get(VecCopyItem::ID_DEFAULT);
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    vec_copy_error,
+                    Some(PeaceTestError::PeaceRt(PeaceRtError::ApprovalRequired {
+                        item_id,
+                        diff_severity: _,
+                    }))
+                    if item_id == VecCopyItem::ID_DEFAULT
+                ),
+                "Expected `vec_copy_error` to be \
+                `Some(PeaceTestError::PeaceRt(PeaceRtError::ApprovalRequired {{ .. }}))`,\n\
+                but was `{vec_copy_error:?}`",
+            );
+        }
+    })();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_does_not_invoke_apply_hooks_when_approver_denies(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+    let hook_invocations = Arc::new(Mutex::new(Vec::<String>::new()));
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .with_approval_policy(
+            ApprovalPolicy::new()
+                .with_diff_severity_min(DiffSeverity::Additive)
+                .with_approver(Approver::new(|_approval_request| Box::pin(async { false }))),
+        )
+        .with_pre_apply_hook({
+            let hook_invocations = Arc::clone(&hook_invocations);
+            move |item_id, _state_diff| {
+                hook_invocations
+                    .lock()
+                    .expect("Expected lock to not be poisoned.")
+                    .push(format!("pre: {item_id}"));
+                Ok(())
+            }
+        })
+        .with_post_apply_hook({
+            let hook_invocations = Arc::clone(&hook_invocations);
+            move |item_id, _state_diff, _apply_hook_outcome| {
+                hook_invocations
+                    .lock()
+                    .expect("Expected lock to not be poisoned.")
+                    .push(format!("post: {item_id}"));
+            }
+        })
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let CmdOutcome::ItemError {
+        item_stream_outcome,
+        cmd_blocks_processed: _,
+        cmd_blocks_not_processed: _,
+        errors,
+        item_log_paths: _,
+    } = EnsureCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec` to complete with item error.");
+    };
+    let states_ensured = item_stream_outcome.value();
+
+    assert_eq!(
+        Some(VecCopyState::new()).as_ref(),
+        states_ensured.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+    assert!(errors.contains_key(VecCopyItem::ID_DEFAULT));
+    // The item was not applied, so neither pre nor post apply hooks are invoked.
+    assert!(hook_invocations
+        .lock()
+        .expect("Expected lock to not be poisoned.")
+        .is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_applies_item_when_approver_approves() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+    let approval_requests = Arc::new(Mutex::new(Vec::<String>::new()));
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .with_approval_policy(
+            ApprovalPolicy::new()
+                .with_diff_severity_min(DiffSeverity::Additive)
+                .with_approver(Approver::new({
+                    let approval_requests = Arc::clone(&approval_requests);
+                    move |approval_request| {
+                        approval_requests
+                            .lock()
+                            .expect("Expected lock to not be poisoned.")
+                            .push(approval_request.item_id().to_string());
+                        Box::pin(async { true })
+                    }
+                })),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let states_ensured_outcome = EnsureCmd::exec(&mut cmd_ctx).await?;
+    let states_ensured = states_ensured_outcome
+        .value()
+        .expect("Expected `EnsureCmd::exec` to complete successfully.");
+
+    assert_eq!(
+        Some(VecCopyState::from(vec![0, 1, 2, 3])).as_ref(),
+        states_ensured.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+    assert_eq!(
+        vec![String::from("vec_copy")],
+        *approval_requests
+            .lock()
+            .expect("Expected lock to not be poisoned.")
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_applies_items_that_complete_within_heartbeat_interval(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .with_heartbeat_policy(
+            HeartbeatPolicy::new()
+                .with_interval(std::time::Duration::from_secs(60))
+                .with_cancel_on_stall(true),
+        )
+        .await?;
+    assert_eq!(
+        Some(std::time::Duration::from_secs(60)),
+        cmd_ctx.scope().heartbeat_policy().interval()
+    );
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let states_ensured_outcome = EnsureCmd::exec(&mut cmd_ctx).await?;
+    let states_ensured = states_ensured_outcome
+        .value()
+        .expect("Expected `EnsureCmd::exec` to complete successfully.");
+
+    assert_eq!(
+        Some(VecCopyState::from(vec![0, 1, 2, 3])).as_ref(),
+        states_ensured.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+    assert_eq!(
+        Some(MockState(1)).as_ref(),
+        states_ensured.get::<MockState, _>(MockItem::<()>::ID_DEFAULT)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_skips_items_disabled_for_profile() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .with_items_disabled(ItemsDisabled::from_iter([
+            MockItem::<()>::ID_DEFAULT.clone()
+        ]))
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let states_ensured_outcome = EnsureCmd::exec(&mut cmd_ctx).await?;
+    let states_ensured = states_ensured_outcome
+        .value()
+        .expect("Expected `EnsureCmd::exec` to complete successfully.");
+
+    assert_eq!(
+        Some(VecCopyState::from(vec![0, 1, 2, 3])).as_ref(),
+        states_ensured.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+    assert_eq!(
+        None,
+        states_ensured.get::<MockState, _>(MockItem::<()>::ID_DEFAULT)
+    );
+
+    // Disabled items are stored for the profile, and used in subsequent builds.
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .await?;
+    let items_disabled = cmd_ctx.resources().borrow::<ItemsDisabled>();
+    assert!(items_disabled.contains(MockItem::<()>::ID_DEFAULT));
+    assert!(!items_disabled.contains(VecCopyItem::ID_DEFAULT));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_passes_apply_ctx_to_item() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(
+            MockItem::<()>::default()
+                .with_apply_check(|apply_ctx, _, _, _, _, _| {
+                    if *apply_ctx.profile() != profile!("test_profile") {
+                        return Err(MockItemError::Synthetic(format!("{apply_ctx:?}")));
+                    }
+                    Ok(ApplyCheck::ExecRequired {
+                        #[cfg(feature = "output_progress")]
+                        progress_limit: ProgressLimit::Unknown,
+                        reason: None,
+                    })
+                })
+                .with_apply_dry(
+                    |fn_ctx, _, _, _, state_target, _| match fn_ctx.apply_ctx() {
+                        Some(apply_ctx) if apply_ctx.dry_run() => Ok(state_target.clone()),
+                        apply_ctx => Err(MockItemError::Synthetic(format!("{apply_ctx:?}"))),
+                    },
+                )
+                .with_apply(
+                    |fn_ctx, _, _, _, state_target, _| match fn_ctx.apply_ctx() {
+                        Some(apply_ctx)
+                            if !apply_ctx.dry_run()
+                                && *apply_ctx.profile() == profile!("test_profile")
+                                && apply_ctx.execution_id().is_some() =>
+                        {
+                            Ok(state_target.clone())
+                        }
+                        apply_ctx => Err(MockItemError::Synthetic(format!("{apply_ctx:?}"))),
+                    },
+                )
+                .into(),
+        );
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let CmdOutcome::Complete {
+        value: states_ensured_dry,
+        cmd_blocks_processed: _,
+    } = EnsureCmd::exec_dry(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec_dry` to complete successfully.");
+    };
+    let CmdOutcome::Complete {
+        value: states_ensured,
+        cmd_blocks_processed: _,
+    } = EnsureCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec` to complete successfully.");
+    };
+
+    assert_eq!(
+        Some(MockState(1)).as_ref(),
+        states_ensured_dry.get::<MockState, _>(MockItem::<()>::ID_DEFAULT)
+    );
+    assert_eq!(
+        Some(MockState(1)).as_ref(),
+        states_ensured.get::<MockState, _>(MockItem::<()>::ID_DEFAULT)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn plan_returns_apply_plan_for_each_item_and_presents_it(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut fn_tracker_output = FnTrackerOutput::new();
+
+    let mut cmd_ctx =
+        CmdCtx::builder_single_profile_single_flow(&mut fn_tracker_output, &workspace)
+            .with_profile(profile!("test_profile"))
+            .with_flow(&flow)
+            .with_item_params::<VecCopyItem>(
+                VecCopyItem::ID_DEFAULT.clone(),
+                VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+            )
+            .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+            .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let CmdOutcome::Complete {
+        value: apply_plan,
+        cmd_blocks_processed: _,
+    } = EnsureCmd::plan(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::plan` to complete successfully.");
+    };
+
+    assert_eq!(
+        vec![VecCopyItem::ID_DEFAULT, MockItem::<()>::ID_DEFAULT],
+        apply_plan.keys().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![ApplyPlanAction::Ensure, ApplyPlanAction::Ensure],
+        apply_plan
+            .values()
+            .map(|item_apply_plan| item_apply_plan.action)
+            .collect::<Vec<_>>()
+    );
+    assert!(apply_plan
+        .values()
+        .all(|item_apply_plan| item_apply_plan.apply_check != ApplyCheck::ExecNotRequired));
+    assert_eq!(
+        Some(VecCopyState::from(vec![0u8, 1, 2, 3, 4, 5, 6, 7]).to_string()).as_deref(),
+        apply_plan
+            .get(VecCopyItem::ID_DEFAULT)
+            .map(|item_apply_plan| item_apply_plan.state_target.as_str())
+    );
+    assert_eq!(
+        Some("vec_changed"),
+        apply_plan
+            .get(VecCopyItem::ID_DEFAULT)
+            .and_then(|item_apply_plan| item_apply_plan.apply_check.reason())
+            .map(ApplyCheckReason::code)
+    );
+    assert_eq!(
+        vec![FnInvocation::new(
+            "present",
+            vec![Some(serde_yaml::to_string(&apply_plan)?)],
+        )],
+        cmd_ctx.output().fn_invocations()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn plan_returns_no_action_for_items_already_ensured() -> Result<(), Box<dyn std::error::Error>>
+{
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    EnsureCmd::exec(&mut cmd_ctx).await?;
+
+    let CmdOutcome::Complete {
+        value: apply_plan,
+        cmd_blocks_processed: _,
+    } = EnsureCmd::plan(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::plan` to complete successfully.");
+    };
+
+    assert_eq!(2, apply_plan.len());
+    assert_eq!(0, apply_plan.items_to_apply().count());
+    assert!(apply_plan
+        .values()
+        .all(|item_apply_plan| item_apply_plan.apply_check == ApplyCheck::ExecNotRequired));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_presents_apply_summary_of_added_and_unchanged_items(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = InMemoryOutput::new();
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    EnsureCmd::exec(&mut cmd_ctx).await?;
+    EnsureCmd::exec(&mut cmd_ctx).await?;
+
+    let item_ids = vec![
+        VecCopyItem::ID_DEFAULT.clone(),
+        MockItem::<()>::ID_DEFAULT.clone(),
+    ];
+    assert_eq!(
+        vec![
+            ApplySummary::new(item_ids.clone(), Vec::new(), Vec::new(), Vec::new()),
+            ApplySummary::new(Vec::new(), Vec::new(), item_ids, Vec::new()),
+        ],
+        output.presented::<ApplySummary>()?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_presents_apply_summary_of_changed_items() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![0, 1]).into())
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    EnsureCmd::exec(&mut cmd_ctx).await?;
+
+    // Change the vec copy params, so that its goal state differs from its current state.
+    let mut output = InMemoryOutput::new();
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![2, 3]).into())
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    let CmdOutcome::Complete {
+        value: states_ensured,
+        cmd_blocks_processed: _,
+    } = EnsureCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec` to complete successfully.");
+    };
+
+    assert_eq!(
+        Some(VecCopyState::from(vec![2, 3])).as_ref(),
+        states_ensured.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+    assert_eq!(
+        Some(ApplySummary::new(
+            Vec::new(),
+            vec![VecCopyItem::ID_DEFAULT.clone()],
+            vec![MockItem::<()>::ID_DEFAULT.clone()],
+            Vec::new(),
+        )),
+        output.presented_last::<ApplySummary>()?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_presents_apply_summary_with_item_changed_when_state_clean_fails(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(
+            MockItem::<()>::default()
+                .with_state_clean(|_, _| {
+                    Err(MockItemError::Synthetic(String::from("state_clean_err")))
+                })
+                .into(),
+        );
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = InMemoryOutput::new();
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![0, 1]).into())
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let (cmd_outcome, apply_summary) = EnsureCmd::exec_with_summary(&mut cmd_ctx).await?;
+
+    assert!(cmd_outcome.is_complete(), "{cmd_outcome:?}");
+    assert_eq!(
+        Some(ApplySummary::new(
+            vec![VecCopyItem::ID_DEFAULT.clone()],
+            vec![MockItem::<()>::ID_DEFAULT.clone()],
+            Vec::new(),
+            Vec::new(),
+        )),
+        apply_summary
+    );
+    assert_eq!(None, output.presented_last::<ApplySummary>()?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_continue_on_error_presents_apply_summary_of_failed_items(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        let [mock_fail_id, vec_copy_id, mock_blocked_id] = graph_builder.add_fns([
+            MockItem::<()>::new(item_id!("mock_fail"))
+                .with_apply(|_, _, _, _, _, _| {
+                    Err(MockItemError::Synthetic(String::from("apply_err")))
+                })
+                .into(),
+            VecCopyItem::default().into(),
+            MockItem::<MockReadsVecCopyOutput>::new(item_id!("mock_blocked")).into(),
+        ]);
+        graph_builder.add_logic_edge(mock_fail_id, mock_blocked_id)?;
+        graph_builder.add_logic_edge(vec_copy_id, mock_blocked_id)?;
+        graph_builder.try_build()?
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = InMemoryOutput::new();
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<MockItem<()>>(item_id!("mock_fail"), MockSrc(1).into())
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .with_item_params::<MockItem<MockReadsVecCopyOutput>>(
+            item_id!("mock_blocked"),
+            MockSrc(1).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let cmd_outcome = EnsureCmd::exec_continue_on_error(&mut cmd_ctx).await?;
+    assert!(cmd_outcome.is_err());
+
+    let apply_summary = output
+        .presented_last::<ApplySummary>()?
+        .expect("Expected `ApplySummary` to be presented.");
+    assert_eq!(
+        &[VecCopyItem::ID_DEFAULT.clone()],
+        apply_summary.item_ids_added()
+    );
+    assert!(apply_summary.item_ids_changed().is_empty());
+    assert!(apply_summary.item_ids_unchanged().is_empty());
+    assert_eq!(2, apply_summary.item_ids_failed().len());
+    assert!(apply_summary
+        .item_ids_failed()
+        .contains(&item_id!("mock_fail")));
+    assert!(apply_summary
+        .item_ids_failed()
+        .contains(&item_id!("mock_blocked")));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn apply_summary_present_renders_item_ids_grouped_by_outcome(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut cli_output = CliOutputBuilder::new_with_writer(&mut buffer)
+        .with_colorize(CliColorizeOpt::Never)
+        .build();
+    let mut presenter = CliMdPresenter::new(&mut cli_output);
+
+    let apply_summary = ApplySummary::new(
+        vec![item_id!("a")],
+        vec![item_id!("b"), item_id!("c")],
+        Vec::new(),
+        vec![item_id!("d")],
+    );
+    apply_summary.present(&mut presenter).await?;
+
+    let output = String::from_utf8(buffer)?;
+    assert_eq!(
+        "\
+        # Apply summary\n\
+        \n\
+        Apply finished with errors! Items: 1 added, 2 changed, 0 unchanged, 1 failed.\n\
+        \n\
+        ## Added\n\
+        \n\
+        * `a`\n\
+        \n\
+        ## Changed\n\
+        \n\
+        * `b`\n\
+        * `c`\n\
+        \n\
+        ## Failed\n\
+        \n\
+        * `d`\n\
+        \n\
+        ",
+        output
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_stores_sensitive_state_fields_encrypted_and_reads_them_back(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?
+    .with_sensitive_key_provider(KeyProvider::from_key([1u8; 32]))?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(TokenItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .with_item_params::<TokenItem>(
+            TokenItem::ID_DEFAULT.clone(),
+            TokenSrc(String::from("hunter2")).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    let CmdOutcome::Complete { .. } = EnsureCmd::exec(&mut cmd_ctx).await? else {
+        panic!("Expected `EnsureCmd::exec` to complete successfully.");
+    };
+    let states_current_contents =
+        tokio::fs::read_to_string(StatesCurrentFile::from(cmd_ctx.flow_dir())).await?;
+
+    // Re-read states from disk.
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .with_item_params::<TokenItem>(
+            TokenItem::ID_DEFAULT.clone(),
+            TokenSrc(String::from("hunter2")).into(),
+        )
+        .await?;
+    let CmdOutcome::Complete {
+        value: states_current_stored,
+        cmd_blocks_processed: _,
+    } = StatesCurrentReadCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesCurrentReadCmd::exec` to complete successfully.");
+    };
+
+    assert!(
+        states_current_contents.contains("token: encrypted:")
+            && !states_current_contents.contains("hunter2")
+            && !states_current_contents.contains(Sensitive::<String>::REDACTED),
+        "Expected sensitive fields to be encrypted, but contents were:\n\
+        {states_current_contents}"
+    );
+    assert_eq!(
+        Some(&TokenState {
+            token: Some(Sensitive::new(String::from("hunter2"))),
+        }),
+        states_current_stored.get::<TokenState, _>(TokenItem::ID_DEFAULT)
+    );
+
+    Ok(())
+}
+
 #[test]
 fn debug() {
     let debug_str = format!("{:?}", EnsureCmd::<PeaceCmdCtxTypes>::default());
@@ -0,0 +1,84 @@
+use peace::{
+    cfg::{
+        app_name, profile,
+        progress::{ProgressComplete, ProgressStatus},
+        FlowId,
+    },
+    cmd::ctx::CmdCtx,
+    resources::paths::ExecutionProgressFile,
+    rt::cmds::{ExecutionProgressReadCmd, StatesCurrentReadCmd, StatesDiscoverCmd},
+    rt_model::{Flow, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+
+use crate::{NoOpOutput, PeaceTestError, VecA, VecCopyItem};
+
+#[tokio::test]
+async fn returns_none_when_no_command_executed() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .await?;
+
+    let execution_progress = ExecutionProgressReadCmd::exec(&mut cmd_ctx).await?;
+
+    assert_eq!(None, execution_progress);
+    Ok(())
+}
+
+#[tokio::test]
+async fn returns_progress_of_last_command_that_renders_progress(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .await?;
+
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+    // Read commands don't render progress, so they don't replace the stored
+    // progress.
+    StatesCurrentReadCmd::exec(&mut cmd_ctx).await?;
+    let execution_progress = ExecutionProgressReadCmd::exec(&mut cmd_ctx)
+        .await?
+        .expect("Expected execution progress to be stored.");
+
+    assert!(ExecutionProgressFile::from(cmd_ctx.flow_dir()).exists());
+    assert_eq!(
+        Some(&ProgressStatus::Complete(ProgressComplete::Success)),
+        execution_progress
+            .get(VecCopyItem::ID_DEFAULT)
+            .map(|progress_tracker_snapshot| &progress_tracker_snapshot.progress_status)
+    );
+    Ok(())
+}
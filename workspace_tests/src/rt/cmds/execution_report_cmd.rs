@@ -0,0 +1,84 @@
+use peace::{
+    cfg::{app_name, profile, FlowId},
+    cmd::ctx::CmdCtx,
+    rt::cmds::{ExecutionReportCmd, StatesDiscoverCmd},
+    rt_model::{ExecutionPhase, Flow, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+
+use crate::{peace_cmd_ctx_types::PeaceCmdCtxTypes, NoOpOutput, PeaceTestError, VecA, VecCopyItem};
+
+#[tokio::test]
+async fn exec_returns_timeline_of_last_execution() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    let execution_timeline = ExecutionReportCmd::exec(&mut cmd_ctx)
+        .await?
+        .expect("Expected execution timeline to be stored.");
+
+    assert_eq!(1, execution_timeline.len());
+    let span = &execution_timeline[0];
+    assert_eq!(VecCopyItem::ID_DEFAULT, &span.item_id);
+    assert_eq!(ExecutionPhase::Discover, span.phase);
+    assert!(span.start <= span.end);
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_none_when_nothing_executed() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .await?;
+
+    let execution_timeline = ExecutionReportCmd::exec(&mut cmd_ctx).await?;
+
+    assert!(execution_timeline.is_none());
+    Ok(())
+}
+
+#[test]
+fn debug() {
+    let debug_str = format!("{:?}", ExecutionReportCmd::<PeaceCmdCtxTypes>::default());
+    assert_eq!(
+        r#"ExecutionReportCmd(PhantomData<workspace_tests::peace_cmd_ctx_types::PeaceCmdCtxTypes>)"#,
+        debug_str,
+    );
+}
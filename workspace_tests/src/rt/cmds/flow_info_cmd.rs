@@ -0,0 +1,62 @@
+use peace::{
+    cfg::{app_name, profile, FlowId},
+    cmd::ctx::CmdCtx,
+    rt::cmds::FlowInfoCmd,
+    rt_model::{Flow, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+
+use crate::{
+    mock_item::{MockItem, MockSrc},
+    peace_cmd_ctx_types::PeaceCmdCtxTypes,
+    FnInvocation, FnTrackerOutput, PeaceTestError, VecA, VecCopyItem,
+};
+
+#[tokio::test]
+async fn presents_flow_metadata() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut fn_tracker_output = FnTrackerOutput::new();
+
+    let mut cmd_ctx =
+        CmdCtx::builder_single_profile_single_flow(&mut fn_tracker_output, &workspace)
+            .with_profile(profile!("test_profile"))
+            .with_flow(&flow)
+            .with_item_params::<VecCopyItem>(
+                VecCopyItem::ID_DEFAULT.clone(),
+                VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+            )
+            .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+            .await?;
+    let flow_metadata = FlowInfoCmd::exec(&mut cmd_ctx).await?;
+    let fn_tracker_output = cmd_ctx.output();
+
+    assert_eq!(flow.metadata(), flow_metadata);
+    assert_eq!(2, flow_metadata.items.len());
+    assert_eq!(
+        vec![FnInvocation::new(
+            "present",
+            vec![Some(serde_yaml::to_string(&flow_metadata)?)],
+        )],
+        fn_tracker_output.fn_invocations()
+    );
+    Ok(())
+}
+
+#[test]
+fn debug() {
+    let debug_str = format!("{:?}", FlowInfoCmd::<PeaceCmdCtxTypes>::default());
+    assert_eq!(
+        r#"FlowInfoCmd(PhantomData<workspace_tests::peace_cmd_ctx_types::PeaceCmdCtxTypes>)"#,
+        debug_str,
+    );
+}
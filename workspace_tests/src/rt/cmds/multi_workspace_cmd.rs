@@ -0,0 +1,185 @@
+use peace::{
+    cfg::{app_name, profile, FlowId},
+    cmd::ctx::CmdCtx,
+    cmd_model::CmdOutcome,
+    rt::cmds::{MultiWorkspaceCmd, StatesDiscoverCmd, WorkspaceSetProgress, WorkspaceStatus},
+    rt_model::{Error, Flow, ItemGraphBuilder, Workspace, WorkspaceSet, WorkspaceSpec},
+};
+use tempfile::TempDir;
+
+use crate::{NoOpOutput, PeaceTestError, VecA, VecCopyItem, VecCopyState};
+
+#[tokio::test]
+async fn exec_runs_cmd_in_each_workspace() -> Result<(), PeaceTestError> {
+    let (_tempdirs, workspace_set) = workspace_set_init(&["us-west-2", "ap-southeast-2"])?;
+    let flow = flow_init()?;
+
+    let multi_workspace_cmd_outcome =
+        MultiWorkspaceCmd::exec(&workspace_set, |_workspace_name, workspace| {
+            let flow = &flow;
+            async move {
+                let mut output = NoOpOutput;
+                let mut cmd_ctx =
+                    CmdCtx::builder_single_profile_single_flow(&mut output, workspace)
+                        .with_profile(profile!("test_profile"))
+                        .with_flow(flow)
+                        .with_item_params::<VecCopyItem>(
+                            VecCopyItem::ID_DEFAULT.clone(),
+                            VecA(vec![0, 1, 2, 3]).into(),
+                        )
+                        .await?;
+
+                StatesDiscoverCmd::current(&mut cmd_ctx).await
+            }
+        })
+        .await;
+
+    assert!(multi_workspace_cmd_outcome.is_complete());
+    assert_eq!(
+        vec!["us-west-2", "ap-southeast-2"],
+        multi_workspace_cmd_outcome
+            .keys()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+    );
+    multi_workspace_cmd_outcome
+        .values()
+        .for_each(|cmd_outcome_result| {
+            let Ok(CmdOutcome::Complete {
+                value: states_current,
+                cmd_blocks_processed: _,
+            }) = cmd_outcome_result
+            else {
+                panic!("Expected `StatesDiscoverCmd::current` to complete successfully.");
+            };
+            assert_eq!(
+                Some(VecCopyState::new()).as_ref(),
+                states_current.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+            );
+        });
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_continues_other_workspaces_when_one_errs() -> Result<(), PeaceTestError> {
+    let (_tempdirs, workspace_set) = workspace_set_init(&["tenant_a", "tenant_b"])?;
+    let flow = flow_init()?;
+
+    let multi_workspace_cmd_outcome =
+        MultiWorkspaceCmd::exec(&workspace_set, |workspace_name, workspace| {
+            let flow = &flow;
+            async move {
+                if workspace_name == "tenant_a" {
+                    return Err(PeaceTestError::PeaceRt(
+                        Error::StatesCurrentDiscoverRequired,
+                    ));
+                }
+
+                let mut output = NoOpOutput;
+                let mut cmd_ctx =
+                    CmdCtx::builder_single_profile_single_flow(&mut output, workspace)
+                        .with_profile(profile!("test_profile"))
+                        .with_flow(flow)
+                        .with_item_params::<VecCopyItem>(
+                            VecCopyItem::ID_DEFAULT.clone(),
+                            VecA(vec![0, 1, 2, 3]).into(),
+                        )
+                        .await?;
+
+                StatesDiscoverCmd::current(&mut cmd_ctx).await
+            }
+        })
+        .await;
+
+    assert!(!multi_workspace_cmd_outcome.is_complete());
+    assert_eq!(
+        vec!["tenant_a"],
+        multi_workspace_cmd_outcome.workspace_names_errored()
+    );
+    assert!(matches!(
+        multi_workspace_cmd_outcome.get("tenant_b"),
+        Some(Ok(CmdOutcome::Complete { .. }))
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_with_progress_updates_workspace_statuses() -> Result<(), PeaceTestError> {
+    let (_tempdirs, workspace_set) = workspace_set_init(&["tenant_a", "tenant_b"])?;
+    let workspace_set_progress = WorkspaceSetProgress::new(&workspace_set);
+
+    assert_eq!(
+        Some(WorkspaceStatus::Pending),
+        workspace_set_progress.status("tenant_a")
+    );
+    assert!(!workspace_set_progress.is_finished());
+
+    let _multi_workspace_cmd_outcome = MultiWorkspaceCmd::exec_with_progress(
+        &workspace_set,
+        &workspace_set_progress,
+        |workspace_name, _workspace| {
+            let workspace_set_progress = workspace_set_progress.clone();
+            async move {
+                assert_eq!(
+                    Some(WorkspaceStatus::Running),
+                    workspace_set_progress.status(workspace_name)
+                );
+
+                if workspace_name == "tenant_a" {
+                    Ok(CmdOutcome::Complete {
+                        value: (),
+                        cmd_blocks_processed: Vec::new(),
+                    })
+                } else {
+                    Err(PeaceTestError::PeaceRt(
+                        Error::StatesCurrentDiscoverRequired,
+                    ))
+                }
+            }
+        },
+    )
+    .await;
+
+    assert_eq!(
+        vec![
+            (String::from("tenant_a"), WorkspaceStatus::Complete),
+            (String::from("tenant_b"), WorkspaceStatus::Error),
+        ],
+        workspace_set_progress
+            .statuses()
+            .into_iter()
+            .collect::<Vec<_>>()
+    );
+    assert!(workspace_set_progress.is_finished());
+    Ok(())
+}
+
+fn workspace_set_init(
+    workspace_names: &[&str],
+) -> Result<(Vec<TempDir>, WorkspaceSet), PeaceTestError> {
+    workspace_names.iter().try_fold(
+        (Vec::new(), WorkspaceSet::new()),
+        |(mut tempdirs, workspace_set), workspace_name| {
+            let tempdir = tempfile::tempdir().map_err(PeaceTestError::TempDir)?;
+            let workspace = Workspace::new(
+                app_name!(),
+                WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+            )?;
+            tempdirs.push(tempdir);
+
+            Ok((
+                tempdirs,
+                workspace_set.with_workspace(*workspace_name, workspace),
+            ))
+        },
+    )
+}
+
+fn flow_init() -> Result<Flow<PeaceTestError>, PeaceTestError> {
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    Ok(Flow::new(FlowId::new(crate::fn_name_short!())?, graph))
+}
@@ -0,0 +1,102 @@
+use peace::{
+    cfg::{app_name, profile, FlowId},
+    cmd::ctx::CmdCtx,
+    params::{ValueResolution, ValueResolutionMode, ValueResolutionSource},
+    rt::cmds::ParamsExplainCmd,
+    rt_model::{Flow, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+
+use crate::{
+    peace_cmd_ctx_types::PeaceCmdCtxTypes, FnInvocation, FnTrackerOutput, NoOpOutput,
+    PeaceTestError, VecA, VecCopyItem,
+};
+
+#[tokio::test]
+async fn returns_params_explanation_for_each_item() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .await?;
+
+    let params_explanations = ParamsExplainCmd::exec(&mut cmd_ctx).await?;
+
+    assert_eq!(1, params_explanations.len());
+    let item_params_explanation = &params_explanations[0];
+    assert_eq!(VecCopyItem::ID_DEFAULT, item_params_explanation.item_id());
+    assert!(item_params_explanation.params_complete());
+    assert_eq!(
+        &[ValueResolution::new(
+            Vec::new(),
+            ValueResolutionSource::Value,
+            Some(String::from("VecA([0, 1, 2, 3, 4, 5, 6, 7])")),
+        )],
+        item_params_explanation.value_resolutions()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn presents_params_explanations() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut fn_tracker_output = FnTrackerOutput::new();
+    let mut cmd_ctx =
+        CmdCtx::builder_single_profile_single_flow(&mut fn_tracker_output, &workspace)
+            .with_profile(profile!("test_profile"))
+            .with_flow(&flow)
+            .with_item_params::<VecCopyItem>(
+                VecCopyItem::ID_DEFAULT.clone(),
+                VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+            )
+            .await?;
+
+    let params_explanations = ParamsExplainCmd::exec_with_value_resolution_mode(
+        &mut cmd_ctx,
+        ValueResolutionMode::Current,
+    )
+    .await?;
+    let fn_tracker_output = cmd_ctx.output();
+
+    assert_eq!(
+        vec![FnInvocation::new(
+            "present",
+            vec![Some(serde_yaml::to_string(&params_explanations)?)],
+        )],
+        fn_tracker_output.fn_invocations()
+    );
+    Ok(())
+}
+
+#[test]
+fn debug() {
+    let debug_str = format!("{:?}", ParamsExplainCmd::<PeaceCmdCtxTypes>::default());
+    assert_eq!(
+        r#"ParamsExplainCmd(PhantomData<workspace_tests::peace_cmd_ctx_types::PeaceCmdCtxTypes>)"#,
+        debug_str,
+    );
+}
@@ -0,0 +1,133 @@
+use peace::{
+    cfg::{app_name, flow_id, profile, Profile},
+    cmd::ctx::CmdCtx,
+    rt::cmds::ParamsHistoryCmd,
+    rt_model::{
+        Error, Flow, ItemGraphBuilder, ParamChange, ParamsKind, ParamsRevisionDiff, Workspace,
+    },
+};
+
+use crate::{test_support::workspace, FnInvocation, FnTrackerOutput, NoOpOutput, PeaceTestError};
+
+#[tokio::test]
+async fn list_returns_revisions_recorded_when_params_change(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_params_history_cmd"))?;
+    let profile = profile!("test_profile");
+    params_record(&workspace, &profile, 1).await?;
+    params_record(&workspace, &profile, 1).await?;
+    params_record(&workspace, &profile, 2).await?;
+
+    let mut fn_tracker_output = FnTrackerOutput::new();
+    let mut cmd_ctx = CmdCtx::builder_single_profile_no_flow::<PeaceTestError, _>(
+        &mut fn_tracker_output,
+        &workspace,
+    )
+    .with_profile(profile.clone())
+    .with_profile_params_k::<String>()
+    .with_profile_param::<u32>(String::from("profile_param_0"))
+    .build()
+    .await?;
+    let params_history = ParamsHistoryCmd::list(&mut cmd_ctx).await?;
+    let fn_tracker_output = cmd_ctx.output();
+
+    assert_eq!(
+        vec![
+            (0, Some(flow_id!("test_flow_id"))),
+            (1, Some(flow_id!("test_flow_id"))),
+            (2, None),
+        ],
+        params_history
+            .iter()
+            .map(|params_revision| (params_revision.revision, params_revision.flow_id.clone()))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![FnInvocation::new(
+            "present",
+            vec![Some(serde_yaml::to_string(&params_history)?)],
+        )],
+        fn_tracker_output.fn_invocations()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn diff_returns_params_changed_between_revisions() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_params_history_cmd"))?;
+    let profile = profile!("test_profile");
+    params_record(&workspace, &profile, 1).await?;
+    params_record(&workspace, &profile, 2).await?;
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx =
+        CmdCtx::builder_single_profile_no_flow::<PeaceTestError, _>(&mut output, &workspace)
+            .with_profile(profile.clone())
+            .build()
+            .await?;
+    let params_revision_diff = ParamsHistoryCmd::diff(&mut cmd_ctx, 0, 1).await?;
+
+    assert_eq!(
+        ParamsRevisionDiff::new(
+            0,
+            1,
+            vec![ParamChange {
+                params_kind: ParamsKind::Profile,
+                key: String::from("profile_param_0"),
+                value_from: Some(serde_yaml::Value::from(1)),
+                value_to: Some(serde_yaml::Value::from(2)),
+            }]
+        ),
+        params_revision_diff
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn diff_returns_error_when_revision_not_found() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_params_history_cmd"))?;
+    let profile = profile!("test_profile");
+    params_record(&workspace, &profile, 1).await?;
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx =
+        CmdCtx::builder_single_profile_no_flow::<PeaceTestError, _>(&mut output, &workspace)
+            .with_profile(profile.clone())
+            .build()
+            .await?;
+    let result = ParamsHistoryCmd::diff(&mut cmd_ctx, 0, 5).await;
+
+    assert!(
+        matches!(
+            result,
+            Err(PeaceTestError::PeaceRt(Error::ParamsRevisionNotFound {
+                revision: 5
+            }))
+        ),
+        "Expected `ParamsRevisionNotFound`, but was {result:?}"
+    );
+    Ok(())
+}
+
+/// Builds a command context with the given profile param value, which records
+/// a params revision if the value changed.
+async fn params_record(
+    workspace: &Workspace,
+    profile: &Profile,
+    profile_param_0: u32,
+) -> Result<(), PeaceTestError> {
+    let flow =
+        Flow::<PeaceTestError>::new(flow_id!("test_flow_id"), ItemGraphBuilder::new().build());
+    let mut output = NoOpOutput;
+    CmdCtx::builder_single_profile_single_flow(&mut output, workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_profile_params_k::<String>()
+        .with_profile_param_value(String::from("profile_param_0"), Some(profile_param_0))
+        .build()
+        .await?;
+    Ok(())
+}
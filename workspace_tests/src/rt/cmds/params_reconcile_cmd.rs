@@ -0,0 +1,337 @@
+use peace::{
+    cfg::{app_name, item_id, profile, FlowId, ItemId},
+    cmd::ctx::CmdCtx,
+    rt::cmds::{
+        ParamsReconcileCmd, ParamsReconcileOutcome, ParamsReconciler, ParamsReconciliation,
+        ParamsSpecOrphanResolution,
+    },
+    rt_model::{Error, Flow, IndexMap, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+
+use crate::{FnInvocation, FnTrackerOutput, NoOpOutput, PeaceTestError, VecA, VecCopyItem};
+
+#[tokio::test]
+async fn exec_renames_item_id_from_reconciliation() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir)?;
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    params_specs_store(&workspace, &flow_id, &[item_id!("vec_old")]).await?;
+    let flow = flow(&flow_id, &[item_id!("vec_new")]);
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx =
+        CmdCtx::builder_single_profile_no_flow::<PeaceTestError, _>(&mut output, &workspace)
+            .with_profile(profile!("test_profile"))
+            .build()
+            .await?;
+    let params_reconciliation =
+        ParamsReconciliation::new().with_item_id_rename(item_id!("vec_old"), item_id!("vec_new"));
+    let params_reconcile_outcome =
+        ParamsReconcileCmd::exec(&mut cmd_ctx, &flow, &params_reconciliation).await?;
+
+    assert_eq!(
+        ParamsReconcileOutcome {
+            item_ids_renamed: IndexMap::from([(item_id!("vec_old"), item_id!("vec_new"))]),
+            ..Default::default()
+        },
+        params_reconcile_outcome
+    );
+    assert!(params_reconcile_outcome.is_reconciled());
+
+    // The flow's command context can be built without providing params.
+    let mut output = NoOpOutput;
+    let mut cmd_ctx =
+        CmdCtx::builder_single_profile_single_flow::<PeaceTestError, _>(&mut output, &workspace)
+            .with_profile(profile!("test_profile"))
+            .with_flow(&flow)
+            .await?;
+    assert!(cmd_ctx
+        .view()
+        .params_specs
+        .get::<<VecA as peace::params::Params>::Spec, _>(&item_id!("vec_new"))
+        .is_some());
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_renames_item_id_from_params_reconciler() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir)?;
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    params_specs_store(&workspace, &flow_id, &[item_id!("vec_old")]).await?;
+    let flow = flow(&flow_id, &[item_id!("vec_new")]);
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx =
+        CmdCtx::builder_single_profile_no_flow::<PeaceTestError, _>(&mut output, &workspace)
+            .with_profile(profile!("test_profile"))
+            .build()
+            .await?;
+    let params_reconciler = ParamsReconciler::new(|params_spec_orphan| {
+        Box::pin(async move {
+            assert_eq!(item_id!("vec_old"), params_spec_orphan.item_id);
+            assert_eq!(
+                vec![item_id!("vec_new")],
+                params_spec_orphan.item_ids_missing
+            );
+            ParamsSpecOrphanResolution::Rename(params_spec_orphan.item_ids_missing[0].clone())
+        })
+    });
+    let params_reconciliation =
+        ParamsReconciliation::new().with_params_reconciler(params_reconciler);
+    let params_reconcile_outcome =
+        ParamsReconcileCmd::exec(&mut cmd_ctx, &flow, &params_reconciliation).await?;
+
+    assert_eq!(
+        ParamsReconcileOutcome {
+            item_ids_renamed: IndexMap::from([(item_id!("vec_old"), item_id!("vec_new"))]),
+            ..Default::default()
+        },
+        params_reconcile_outcome
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_drops_orphans_and_fills_missing_params_specs(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir)?;
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    params_specs_store(&workspace, &flow_id, &[item_id!("vec_old")]).await?;
+    let flow = flow(&flow_id, &[item_id!("vec_new")]);
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx =
+        CmdCtx::builder_single_profile_no_flow::<PeaceTestError, _>(&mut output, &workspace)
+            .with_profile(profile!("test_profile"))
+            .build()
+            .await?;
+    let params_reconciliation = ParamsReconciliation::new()
+        .with_orphans_drop(true)
+        .with_item_params::<VecCopyItem>(item_id!("vec_new"), VecA(vec![1, 2]).into());
+    let params_reconcile_outcome =
+        ParamsReconcileCmd::exec(&mut cmd_ctx, &flow, &params_reconciliation).await?;
+
+    assert_eq!(
+        ParamsReconcileOutcome {
+            item_ids_dropped: vec![item_id!("vec_old")],
+            item_ids_filled: vec![item_id!("vec_new")],
+            ..Default::default()
+        },
+        params_reconcile_outcome
+    );
+
+    let mut output = NoOpOutput;
+    CmdCtx::builder_single_profile_single_flow::<PeaceTestError, _>(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_without_reconciliation_presents_mismatches_and_does_not_write(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir)?;
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    params_specs_store(&workspace, &flow_id, &[item_id!("vec_old")]).await?;
+    let flow = flow(&flow_id, &[item_id!("vec_new")]);
+
+    let mut fn_tracker_output = FnTrackerOutput::new();
+    let mut cmd_ctx = CmdCtx::builder_single_profile_no_flow::<PeaceTestError, _>(
+        &mut fn_tracker_output,
+        &workspace,
+    )
+    .with_profile(profile!("test_profile"))
+    .build()
+    .await?;
+    let params_reconcile_outcome =
+        ParamsReconcileCmd::exec(&mut cmd_ctx, &flow, &ParamsReconciliation::new()).await?;
+    let fn_tracker_output = cmd_ctx.output();
+
+    assert_eq!(
+        ParamsReconcileOutcome {
+            item_ids_orphaned: vec![item_id!("vec_old")],
+            item_ids_missing: vec![item_id!("vec_new")],
+            ..Default::default()
+        },
+        params_reconcile_outcome
+    );
+    assert!(!params_reconcile_outcome.is_changed());
+    assert_eq!(
+        vec![FnInvocation::new(
+            "present",
+            vec![Some(serde_yaml::to_string(&params_reconcile_outcome)?)],
+        )],
+        fn_tracker_output.fn_invocations()
+    );
+
+    // The stored params specs are unchanged.
+    let flow_old = self::flow(&flow_id, &[item_id!("vec_old")]);
+    let mut output = NoOpOutput;
+    CmdCtx::builder_single_profile_single_flow::<PeaceTestError, _>(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow_old)
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_error_when_rename_target_not_in_flow(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir)?;
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    params_specs_store(&workspace, &flow_id, &[item_id!("vec_old")]).await?;
+    let flow = flow(&flow_id, &[item_id!("vec_new")]);
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx =
+        CmdCtx::builder_single_profile_no_flow::<PeaceTestError, _>(&mut output, &workspace)
+            .with_profile(profile!("test_profile"))
+            .build()
+            .await?;
+    let params_reconciliation = ParamsReconciliation::new()
+        .with_item_id_rename(item_id!("vec_old"), item_id!("vec_unknown"));
+    let result = ParamsReconcileCmd::exec(&mut cmd_ctx, &flow, &params_reconciliation).await;
+
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    &result,
+                    Err(PeaceTestError::PeaceRt(Error::ParamsReconcileItemIdNotInFlow {
+                        item_id,
+                    }))
+                    if item_id == &item_id!("vec_unknown")
+                ),
+                "Expected `ParamsReconcileItemIdNotInFlow`, but was {result:?}"
+            );
+        }
+    })();
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_error_when_renames_conflict() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir)?;
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    params_specs_store(
+        &workspace,
+        &flow_id,
+        &[item_id!("vec_old_0"), item_id!("vec_old_1")],
+    )
+    .await?;
+    let flow = flow(&flow_id, &[item_id!("vec_new")]);
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx =
+        CmdCtx::builder_single_profile_no_flow::<PeaceTestError, _>(&mut output, &workspace)
+            .with_profile(profile!("test_profile"))
+            .build()
+            .await?;
+    let params_reconciliation = ParamsReconciliation::new()
+        .with_item_id_rename(item_id!("vec_old_0"), item_id!("vec_new"))
+        .with_item_id_rename(item_id!("vec_old_1"), item_id!("vec_new"));
+    let result = ParamsReconcileCmd::exec(&mut cmd_ctx, &flow, &params_reconciliation).await;
+
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    &result,
+                    Err(PeaceTestError::PeaceRt(Error::ParamsReconcileRenameConflict {
+                        item_id_from,
+                        item_id_to,
+                    }))
+                    if item_id_from == &item_id!("vec_old_1")
+                        && item_id_to == &item_id!("vec_new")
+                ),
+                "Expected `ParamsReconcileRenameConflict`, but was {result:?}"
+            );
+        }
+    })();
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_renames_item_id_before_params_reconciler_and_provided_params(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir)?;
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    params_specs_store(&workspace, &flow_id, &[item_id!("vec_old")]).await?;
+    let flow = flow(&flow_id, &[item_id!("vec_new")]);
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx =
+        CmdCtx::builder_single_profile_no_flow::<PeaceTestError, _>(&mut output, &workspace)
+            .with_profile(profile!("test_profile"))
+            .build()
+            .await?;
+    let params_reconciliation = ParamsReconciliation::new()
+        .with_item_id_rename(item_id!("vec_old"), item_id!("vec_new"))
+        .with_item_params::<VecCopyItem>(item_id!("vec_new"), VecA(vec![1, 2]).into());
+    let params_reconciliation =
+        params_reconciliation.with_params_reconciler(ParamsReconciler::new(|_| {
+            Box::pin(async { ParamsSpecOrphanResolution::Keep })
+        }));
+    let params_reconcile_outcome =
+        ParamsReconcileCmd::exec(&mut cmd_ctx, &flow, &params_reconciliation).await?;
+
+    // The rename takes precedence over the params reconciler, and the provided
+    // params spec is not used as `vec_new` receives the renamed params spec.
+    assert_eq!(
+        ParamsReconcileOutcome {
+            item_ids_renamed: IndexMap::from([(item_id!("vec_old"), item_id!("vec_new"))]),
+            ..Default::default()
+        },
+        params_reconcile_outcome
+    );
+    Ok(())
+}
+
+fn workspace(tempdir: &tempfile::TempDir) -> Result<Workspace, PeaceTestError> {
+    Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )
+    .map_err(PeaceTestError::from)
+}
+
+fn flow(flow_id: &FlowId, item_ids: &[ItemId]) -> Flow<PeaceTestError> {
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        item_ids.iter().for_each(|item_id| {
+            graph_builder.add_fn(VecCopyItem::new(item_id.clone()).into());
+        });
+        graph_builder.build()
+    };
+    Flow::new(flow_id.clone(), graph)
+}
+
+/// Stores params specs for a flow with the given items.
+async fn params_specs_store(
+    workspace: &Workspace,
+    flow_id: &FlowId,
+    item_ids: &[ItemId],
+) -> Result<(), PeaceTestError> {
+    let flow = flow(flow_id, item_ids);
+    let mut output = NoOpOutput;
+    let cmd_ctx_builder = CmdCtx::builder_single_profile_single_flow(&mut output, workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow);
+    item_ids
+        .iter()
+        .fold(cmd_ctx_builder, |cmd_ctx_builder, item_id| {
+            cmd_ctx_builder
+                .with_item_params::<VecCopyItem>(item_id.clone(), VecA(vec![0, 1, 2]).into())
+        })
+        .await?;
+    Ok(())
+}
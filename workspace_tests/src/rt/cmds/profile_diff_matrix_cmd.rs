@@ -0,0 +1,200 @@
+use peace::{
+    cfg::{app_name, profile, DiffSeverity, FlowId, Profile},
+    cmd::ctx::CmdCtx,
+    rt::cmds::{ProfileDiffMatrixCmd, StatesDiscoverCmd},
+    rt_model::{Flow, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+
+use crate::{
+    mock_item::{MockDest, MockItem, MockSrc},
+    NoOpOutput, PeaceTestError, VecA, VecB, VecCopyItem,
+};
+
+#[tokio::test]
+async fn exec_diffs_each_profile_against_baseline() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let flow = flow()?;
+    let mut output = NoOpOutput;
+
+    let profile_0 = profile!("test_profile_0");
+    let profile_1 = profile!("test_profile_1");
+    let profile_2 = profile!("test_profile_2");
+    profile_discover(&mut output, &workspace, &flow, &profile_0, None, 1).await?;
+    profile_discover(&mut output, &workspace, &flow, &profile_1, None, 1).await?;
+    profile_discover(
+        &mut output,
+        &workspace,
+        &flow,
+        &profile_2,
+        Some(VecB(vec![0, 1, 2, 3])),
+        3,
+    )
+    .await?;
+
+    let mut cmd_ctx_multi = CmdCtx::builder_multi_profile_single_flow(&mut output, &workspace)
+        .with_flow(&flow)
+        .await?;
+    let profile_diff_matrix = ProfileDiffMatrixCmd::exec(&mut cmd_ctx_multi, &profile_0).await?;
+
+    assert_eq!(&profile_0, profile_diff_matrix.baseline());
+    assert_eq!(
+        vec![profile_1.clone(), profile_2.clone()],
+        profile_diff_matrix
+            .profile_to_diff_summary()
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        Some(DiffSeverity::InSync),
+        profile_diff_matrix.diff_severity(VecCopyItem::ID_DEFAULT, &profile_1)
+    );
+    assert_eq!(
+        Some(DiffSeverity::InSync),
+        profile_diff_matrix.diff_severity(MockItem::<()>::ID_DEFAULT, &profile_1)
+    );
+    assert_eq!(
+        Some(DiffSeverity::Additive),
+        profile_diff_matrix.diff_severity(VecCopyItem::ID_DEFAULT, &profile_2)
+    );
+    assert_eq!(
+        Some(DiffSeverity::Additive),
+        profile_diff_matrix.diff_severity(MockItem::<()>::ID_DEFAULT, &profile_2)
+    );
+    assert_eq!(
+        vec![&profile_2],
+        profile_diff_matrix.profiles_out_of_sync().collect::<Vec<_>>()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_includes_profiles_without_discovered_states()
+-> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let flow = flow()?;
+    let mut output = NoOpOutput;
+
+    let profile_0 = profile!("test_profile_0");
+    let profile_1 = profile!("test_profile_1");
+    profile_discover(&mut output, &workspace, &flow, &profile_0, None, 1).await?;
+    let mut cmd_ctx_1 = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile_1.clone())
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    StatesDiscoverCmd::goal(&mut cmd_ctx_1).await?;
+
+    let mut cmd_ctx_multi = CmdCtx::builder_multi_profile_single_flow(&mut output, &workspace)
+        .with_flow(&flow)
+        .await?;
+    let profile_diff_matrix = ProfileDiffMatrixCmd::exec(&mut cmd_ctx_multi, &profile_0).await?;
+
+    assert_eq!(
+        Some(&None),
+        profile_diff_matrix
+            .profile_to_diff_summary()
+            .get(&profile_1)
+    );
+    assert_eq!(
+        None,
+        profile_diff_matrix.diff_severity(VecCopyItem::ID_DEFAULT, &profile_1)
+    );
+    assert_eq!(0, profile_diff_matrix.profiles_out_of_sync().count());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_err_when_baseline_states_not_discovered()
+-> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let flow = flow()?;
+    let mut output = NoOpOutput;
+
+    let profile_0 = profile!("test_profile_0");
+    let profile_1 = profile!("test_profile_1");
+    let mut cmd_ctx_0 = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile_0.clone())
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    StatesDiscoverCmd::goal(&mut cmd_ctx_0).await?;
+    profile_discover(&mut output, &workspace, &flow, &profile_1, None, 1).await?;
+
+    let mut cmd_ctx_multi = CmdCtx::builder_multi_profile_single_flow(&mut output, &workspace)
+        .with_flow(&flow)
+        .await?;
+    let profile_diff_matrix_result =
+        ProfileDiffMatrixCmd::exec(&mut cmd_ctx_multi, &profile_0).await;
+
+    assert!(matches!(
+            profile_diff_matrix_result,
+            Err(PeaceTestError::PeaceRt(
+                peace::rt_model::Error::ProfileStatesCurrentNotDiscovered { profile }
+            ))
+            if profile == profile_0));
+
+    Ok(())
+}
+
+fn flow() -> Result<Flow<PeaceTestError>, Box<dyn std::error::Error>> {
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+
+    Ok(flow)
+}
+
+/// Discovers the current states for the given profile.
+async fn profile_discover(
+    output: &mut NoOpOutput,
+    workspace: &Workspace,
+    flow: &Flow<PeaceTestError>,
+    profile: &Profile,
+    vec_b: Option<VecB>,
+    mock_dest: u8,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(output, workspace)
+        .with_profile(profile.clone())
+        .with_flow(flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    let resources = cmd_ctx.resources_mut();
+    if let Some(vec_b) = vec_b {
+        resources.insert(vec_b);
+    }
+    resources.insert(MockDest(mock_dest));
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    Ok(())
+}
@@ -0,0 +1,193 @@
+use peace::{
+    cfg::{app_name, flow_id, profile},
+    cmd::ctx::CmdCtx,
+    resources::paths::{ExecutionHistoryFile, StatesCurrentFile},
+    rt::cmds::{ProfileListCmd, StatesDiscoverCmd},
+    rt_model::{Error as PeaceRtError, ExecutionHistorySerializer, Flow, ItemGraphBuilder},
+};
+
+use crate::{
+    test_support::workspace_with, FnInvocation, FnTrackerOutput, NoOpOutput, PeaceTestError, VecA,
+    VecCopyItem,
+};
+
+#[tokio::test]
+async fn lists_profiles_with_params_and_no_last_execution_when_no_states_stored(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let profile = profile!("test_profile");
+    let profile_other = profile!("test_profile_other");
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!("test_profile_list_cmd"),
+        &[profile.clone(), profile_other.clone()],
+        None,
+    )
+    .await?;
+
+    let mut fn_tracker_output = FnTrackerOutput::new();
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, _>(
+        &mut fn_tracker_output,
+        &workspace,
+    )
+    .with_workspace_params_k::<String>()
+    .with_workspace_param::<String>(String::from("ws_param_1"))
+    .with_profile_params_k::<String>()
+    .with_profile_param::<u32>(String::from("profile_param_0"))
+    .build()
+    .await?;
+    let profile_infos = ProfileListCmd::exec(&mut cmd_ctx).await?;
+    let fn_tracker_output = cmd_ctx.output();
+
+    assert_eq!(
+        Some(&String::from("ws_param_1_value")),
+        profile_infos.workspace_params().get("ws_param_1")
+    );
+    assert_eq!(
+        vec![&profile, &profile_other],
+        profile_infos
+            .iter()
+            .map(|profile_info| &profile_info.profile)
+            .collect::<Vec<_>>()
+    );
+    profile_infos.iter().for_each(|profile_info| {
+        assert_eq!(
+            Some(&1u32),
+            profile_info.profile_params.get("profile_param_0")
+        );
+        assert_eq!(None, profile_info.last_execution);
+    });
+    assert_eq!(
+        vec![FnInvocation::new(
+            "present",
+            vec![Some(serde_yaml::to_string(&profile_infos)?)],
+        )],
+        fn_tracker_output.fn_invocations()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn lists_profiles_with_last_execution_when_states_current_stored(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let profile = profile!("test_profile");
+    let profile_other = profile!("test_profile_other");
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!("test_profile_list_cmd"),
+        &[profile.clone(), profile_other.clone()],
+        None,
+    )
+    .await?;
+    let flow = {
+        let item_graph = {
+            let mut item_graph_builder = ItemGraphBuilder::new();
+            item_graph_builder.add_fn(VecCopyItem::default().into());
+            item_graph_builder.build()
+        };
+        Flow::<PeaceTestError>::new(flow_id!("test_flow_id"), item_graph)
+    };
+
+    // Store current states for `profile_other`.
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile_other.clone())
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2]).into(),
+        )
+        .build()
+        .await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    let mut cmd_ctx =
+        CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, _>(&mut output, &workspace)
+            .build()
+            .await?;
+    let profile_infos = ProfileListCmd::exec(&mut cmd_ctx).await?;
+
+    let profile_info = &profile_infos[0];
+    assert_eq!(profile, profile_info.profile);
+    assert_eq!(None, profile_info.last_execution);
+
+    let profile_other_info = &profile_infos[1];
+    assert_eq!(profile_other, profile_other_info.profile);
+    assert_eq!(
+        Some(&flow_id!("test_flow_id")),
+        profile_other_info
+            .last_execution
+            .as_ref()
+            .map(|last_execution| &last_execution.flow_id)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn lists_profiles_with_last_execution_time_from_execution_history(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let profile = profile!("test_profile");
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!("test_profile_list_cmd"),
+        &[profile.clone()],
+        None,
+    )
+    .await?;
+    let flow = {
+        let item_graph = {
+            let mut item_graph_builder = ItemGraphBuilder::new();
+            item_graph_builder.add_fn(VecCopyItem::default().into());
+            item_graph_builder.build()
+        };
+        Flow::<PeaceTestError>::new(flow_id!("test_flow_id"), item_graph)
+    };
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile.clone())
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2]).into(),
+        )
+        .build()
+        .await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+    let flow_dir = cmd_ctx.flow_dir().clone();
+
+    // The last execution time does not depend on the states file, which may
+    // have been copied or removed.
+    tokio::fs::remove_file(StatesCurrentFile::from(&flow_dir)).await?;
+    let execution_history = ExecutionHistorySerializer::<PeaceRtError>::deserialize_opt(
+        workspace.storage(),
+        &ExecutionHistoryFile::from(&flow_dir),
+    )
+    .await?;
+    let recorded_at = execution_history
+        .as_ref()
+        .and_then(|execution_history| execution_history.last())
+        .map(|execution_record| execution_record.recorded_at);
+
+    let mut cmd_ctx =
+        CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, _>(&mut output, &workspace)
+            .build()
+            .await?;
+    let profile_infos = ProfileListCmd::exec(&mut cmd_ctx).await?;
+
+    let profile_info = &profile_infos[0];
+    assert!(recorded_at.is_some());
+    assert_eq!(
+        recorded_at,
+        profile_info
+            .last_execution
+            .as_ref()
+            .map(|last_execution| last_execution.executed_at)
+    );
+
+    Ok(())
+}
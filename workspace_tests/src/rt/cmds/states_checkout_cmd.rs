@@ -0,0 +1,173 @@
+use diff::{VecDiff, VecDiffType};
+use peace::{
+    cfg::{app_name, profile, FlowId},
+    cmd::ctx::CmdCtx,
+    cmd_model::CmdOutcome,
+    resources::states::StatesCurrentStored,
+    rt::cmds::{DiffCmd, EnsureCmd, StatesCheckoutCmd, StatesDiscoverCmd},
+    rt_model::{Error as PeaceRtError, Flow, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+
+use crate::{NoOpOutput, PeaceTestError, VecA, VecCopyDiff, VecCopyItem, VecCopyState};
+
+#[tokio::test]
+async fn list_returns_executions_that_wrote_states() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir)?;
+    let flow = flow(FlowId::new(crate::fn_name_short!())?);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2]).into(),
+        )
+        .await?;
+
+    let execution_history = StatesCheckoutCmd::list(&mut cmd_ctx).await?;
+    assert!(execution_history.is_empty());
+
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    EnsureCmd::exec(&mut cmd_ctx).await?;
+    // Dry runs do not write states, so they are not recorded.
+    EnsureCmd::exec_dry(&mut cmd_ctx).await?;
+
+    let execution_history = StatesCheckoutCmd::list(&mut cmd_ctx).await?;
+    assert_eq!(
+        vec![0, 1],
+        execution_history
+            .iter()
+            .map(|execution_record| execution_record.execution_id)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![VecCopyItem::ID_DEFAULT.clone()],
+        execution_history[1].item_ids
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_restores_states_recorded_at_execution() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir)?;
+    let flow = flow(FlowId::new(crate::fn_name_short!())?);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2]).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    EnsureCmd::exec(&mut cmd_ctx).await?;
+
+    let states_checkout_outcome = StatesCheckoutCmd::exec(&mut cmd_ctx, 0).await?;
+
+    assert_eq!(0, states_checkout_outcome.execution_record.execution_id);
+    assert_eq!(
+        Some(VecCopyState::new()).as_ref(),
+        states_checkout_outcome
+            .states_current_stored
+            .get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+    assert_eq!(
+        Some(VecCopyState::from(vec![0, 1, 2])).as_ref(),
+        states_checkout_outcome
+            .states_goal_stored
+            .get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+
+    // Checking out an execution does not record a new execution.
+    let execution_history = StatesCheckoutCmd::list(&mut cmd_ctx).await?;
+    assert_eq!(2, execution_history.len());
+
+    // Diffs are computed from the checked out states.
+    let CmdOutcome::Complete {
+        value: state_diffs,
+        cmd_blocks_processed: _,
+    } = DiffCmd::diff_stored(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `DiffCmd::diff_stored` to complete successfully.");
+    };
+    assert_eq!(
+        Some(VecCopyDiff::from(VecDiff(vec![VecDiffType::Inserted {
+            index: 0,
+            changes: vec![0u8, 1, 2]
+        }])))
+        .as_ref(),
+        state_diffs.get::<VecCopyDiff, _>(VecCopyItem::ID_DEFAULT)
+    );
+
+    // The flow's current states file is restored.
+    let mut output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2]).into(),
+        )
+        .await?;
+    let resources = cmd_ctx.resources();
+    assert_eq!(
+        Some(VecCopyState::new()).as_ref(),
+        resources
+            .borrow::<StatesCurrentStored>()
+            .get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_error_when_execution_not_found() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir)?;
+    let flow = flow(FlowId::new(crate::fn_name_short!())?);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2]).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let error = StatesCheckoutCmd::exec(&mut cmd_ctx, 1).await.unwrap_err();
+
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    &error,
+                    PeaceTestError::PeaceRt(PeaceRtError::ExecutionNotFound { execution_id: 1 })
+                ),
+                "was {error:?}"
+            );
+        }
+    })();
+    Ok(())
+}
+
+fn workspace(tempdir: &tempfile::TempDir) -> Result<Workspace, PeaceTestError> {
+    Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )
+    .map_err(PeaceTestError::from)
+}
+
+fn flow(flow_id: FlowId) -> Flow<PeaceTestError> {
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    Flow::new(flow_id, graph)
+}
@@ -0,0 +1,75 @@
+use peace::{
+    cfg::{app_name, profile, FlowId},
+    cmd::ctx::CmdCtx,
+    cmd_model::CmdOutcome,
+    resources::paths::{FlowDir, ProfileDir, StatesCurrentFile, StatesGoalFile},
+    rt::cmds::StatesCleanDisplayCmd,
+    rt_model::{Flow, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+
+use crate::{
+    peace_cmd_ctx_types::PeaceCmdCtxTypes, FnInvocation, FnTrackerOutput, PeaceTestError, VecA,
+    VecCopyItem, VecCopyState,
+};
+
+#[tokio::test]
+async fn displays_states_clean_without_storing_states() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut fn_tracker_output = FnTrackerOutput::new();
+
+    let mut cmd_ctx =
+        CmdCtx::builder_single_profile_single_flow(&mut fn_tracker_output, &workspace)
+            .with_profile(profile!("test_profile"))
+            .with_flow(&flow)
+            .with_item_params::<VecCopyItem>(
+                VecCopyItem::ID_DEFAULT.clone(),
+                VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+            )
+            .await?;
+    let CmdOutcome::Complete {
+        value: states_clean,
+        cmd_blocks_processed: _,
+    } = StatesCleanDisplayCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesCleanDisplayCmd::exec` to complete successfully.");
+    };
+    let fn_tracker_output = cmd_ctx.output();
+
+    assert_eq!(
+        Some(VecCopyState::new()).as_ref(),
+        states_clean.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+    assert_eq!(
+        vec![FnInvocation::new(
+            "present",
+            vec![Some(serde_yaml::to_string(&states_clean)?)],
+        )],
+        fn_tracker_output.fn_invocations()
+    );
+
+    let profile_dir =
+        ProfileDir::from((workspace.dirs().peace_app_dir(), &profile!("test_profile")));
+    let flow_dir = FlowDir::from((&profile_dir, flow.flow_id()));
+    assert!(!StatesCurrentFile::from(&flow_dir).exists());
+    assert!(!StatesGoalFile::from(&flow_dir).exists());
+    Ok(())
+}
+
+#[test]
+fn debug() {
+    let debug_str = format!("{:?}", StatesCleanDisplayCmd::<PeaceCmdCtxTypes>::default());
+    assert_eq!(
+        r#"StatesCleanDisplayCmd(PhantomData<workspace_tests::peace_cmd_ctx_types::PeaceCmdCtxTypes>)"#,
+        debug_str,
+    );
+}
@@ -2,8 +2,9 @@ use peace::{
     cfg::{app_name, profile, FlowId},
     cmd::ctx::CmdCtx,
     cmd_model::CmdOutcome,
-    rt::cmds::{StatesCurrentReadCmd, StatesDiscoverCmd},
-    rt_model::{Error, Flow, ItemGraphBuilder, Workspace, WorkspaceSpec},
+    resources::states_view,
+    rt::cmds::{EnsureCmd, StatesCurrentReadCmd, StatesDiscoverCmd},
+    rt_model::{Error, Flow, ItemGraphBuilder, StateQuery, Workspace, WorkspaceSpec},
 };
 
 use crate::{
@@ -113,3 +114,126 @@ fn debug() {
         debug_str,
     );
 }
+
+#[tokio::test]
+async fn get_returns_item_state_current_stored() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    let CmdOutcome::Complete {
+        value: vec_copy_state,
+        cmd_blocks_processed: _,
+    } = StatesCurrentReadCmd::get::<VecCopyState>(&mut cmd_ctx, VecCopyItem::ID_DEFAULT).await?
+    else {
+        panic!("Expected `StatesCurrentReadCmd::get` to complete successfully.");
+    };
+
+    assert_eq!(Some(VecCopyState::new()), vec_copy_state);
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_states_current_stored_viewable_as_typed_states(
+) -> Result<(), Box<dyn std::error::Error>> {
+    states_view! {
+        struct VecCopyFlowStates {
+            vec_copy: VecCopyState = VecCopyItem::ID_DEFAULT,
+        }
+    }
+
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    EnsureCmd::exec(&mut cmd_ctx).await?;
+
+    let CmdOutcome::Complete {
+        value: states_current_stored,
+        cmd_blocks_processed: _,
+    } = StatesCurrentReadCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesCurrentReadCmd::exec` to complete successfully.");
+    };
+    let vec_copy_flow_states = states_current_stored.view::<VecCopyFlowStates>()?;
+
+    assert_eq!(
+        &VecCopyState::from(vec![0u8, 1, 2, 3, 4, 5, 6, 7]),
+        vec_copy_flow_states.vec_copy
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn query_returns_item_state_current_stored_field_as_json(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    EnsureCmd::exec(&mut cmd_ctx).await?;
+
+    let state_query = StateQuery::new(VecCopyItem::ID_DEFAULT.clone()).with_field("2");
+    let CmdOutcome::Complete {
+        value: state_field,
+        cmd_blocks_processed: _,
+    } = StatesCurrentReadCmd::query(&mut cmd_ctx, &state_query).await?
+    else {
+        panic!("Expected `StatesCurrentReadCmd::query` to complete successfully.");
+    };
+
+    assert_eq!(serde_json::json!(2), state_field);
+    Ok(())
+}
@@ -7,8 +7,13 @@ use peace::{
         states::{StatesCurrentStored, StatesGoal},
         type_reg::untagged::{BoxDtDisplay, TypeReg},
     },
-    rt::cmds::{EnsureCmd, StatesCurrentReadCmd, StatesDiscoverCmd, StatesGoalReadCmd},
-    rt_model::{Flow, ItemGraphBuilder, Workspace, WorkspaceSpec},
+    rt::cmds::{
+        EnsureCmd, StatesCurrentReadCmd, StatesDiscoverCmd, StatesDiscoverIncremental,
+        StatesGoalReadCmd,
+    },
+    rt_model::{
+        Flow, ItemExecutorYielding, ItemGraphBuilder, StateFreshness, Workspace, WorkspaceSpec,
+    },
 };
 
 use crate::{
@@ -275,6 +280,7 @@ async fn current_returns_error_when_try_state_current_returns_error()
         cmd_blocks_processed: _,
         cmd_blocks_not_processed: _,
         errors,
+        item_log_paths: _,
     } = StatesDiscoverCmd::current(&mut cmd_ctx).await?
     else {
         panic!("Expected `StatesDiscoverCmd::current` to complete with item error.");
@@ -351,6 +357,7 @@ async fn goal_returns_error_when_try_state_goal_returns_error()
         cmd_blocks_processed: _,
         cmd_blocks_not_processed: _,
         errors,
+        item_log_paths: _,
     } = StatesDiscoverCmd::goal(&mut cmd_ctx).await?
     else {
         panic!("Expected `StatesDiscoverCmd::goal` to complete with item error.");
@@ -430,6 +437,7 @@ async fn current_and_goal_returns_error_when_try_state_current_returns_error()
         cmd_blocks_processed: _,
         cmd_blocks_not_processed: _,
         errors,
+        item_log_paths: _,
     } = StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?
     else {
         panic!("Expected `StatesDiscoverCmd::current_and_goal` to complete with item error.");
@@ -534,6 +542,7 @@ async fn current_and_goal_returns_error_when_try_state_goal_returns_error()
         cmd_blocks_processed: _,
         cmd_blocks_not_processed: _,
         errors,
+        item_log_paths: _,
     } = StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?
     else {
         panic!("Expected `StatesDiscoverCmd::current_and_goal` to complete with item error.");
@@ -640,6 +649,7 @@ async fn current_and_goal_returns_current_error_when_both_try_state_current_and_
         cmd_blocks_processed: _,
         cmd_blocks_not_processed: _,
         errors,
+        item_log_paths: _,
     } = StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?
     else {
         panic!("Expected `StatesDiscoverCmd::current_and_goal` to complete with item error.");
@@ -959,6 +969,69 @@ async fn current_with_sets_progress_complete_for_successful_items()
     Ok(())
 }
 
+#[cfg(feature = "output_progress")]
+#[tokio::test]
+async fn current_with_sets_progress_skipped_for_items_not_applicable()
+-> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(MockItem::<()>::PROFILE_NOT_APPLICABLE.clone())
+        .with_flow(&flow)
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+
+    let CmdOutcome::Complete {
+        value: states_current,
+        cmd_blocks_processed: _,
+    } = StatesDiscoverCmd::<_>::current_with(&mut cmd_ctx, false).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::current_with` to complete successfully.");
+    };
+
+    let cmd_progress_tracker = cmd_ctx.cmd_progress_tracker();
+    let mock_progress_tracker = cmd_progress_tracker
+        .progress_trackers()
+        .get(MockItem::<()>::ID_DEFAULT)
+        .unwrap_or_else(
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            || {
+                panic!(
+                    "Expected `progress_tracker` to exist for {}",
+                    MockItem::<()>::ID_DEFAULT
+                )
+            },
+        );
+    let mock_progress_status = mock_progress_tracker.progress_status();
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    mock_progress_status,
+                    ProgressStatus::Complete(ProgressComplete::Skipped),
+                ),
+                "expected `mock_progress_status` to be `Complete(Skipped)`, but was {mock_progress_status:?}"
+            );
+        }
+    })();
+    assert!(states_current
+        .get::<MockState, _>(MockItem::<()>::ID_DEFAULT)
+        .is_none());
+
+    Ok(())
+}
+
 #[cfg(feature = "output_progress")]
 #[tokio::test]
 async fn goal_with_sets_progress_complete_for_successful_items()
@@ -1081,3 +1154,210 @@ fn debug() {
         debug_str
     );
 }
+
+#[tokio::test]
+async fn current_and_goal_discovers_states_with_item_executor_yielding(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_executor(ItemExecutorYielding::new(1))
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+
+    assert_eq!(1, cmd_ctx.view().item_executor.concurrency_max());
+
+    let CmdOutcome::Complete {
+        value: (states_current, states_goal),
+        cmd_blocks_processed: _,
+    } = StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::current_and_goal` to complete successfully.");
+    };
+
+    assert_eq!(
+        Some(VecCopyState::new()).as_ref(),
+        states_current.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+    assert_eq!(
+        Some(VecCopyState::from(vec![0, 1, 2, 3, 4, 5, 6, 7])).as_ref(),
+        states_goal.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+    assert_eq!(
+        Some(MockState(1)).as_ref(),
+        states_goal.get::<MockState, _>(MockItem::<()>::ID_DEFAULT)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn current_incremental_uses_stored_states_for_items_not_rediscovered()
+-> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(flow_id.clone(), graph);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    // Discovering the mock item's current state fails, so its stored state must be used.
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(
+            MockItem::<()>::default()
+                .with_try_state_current(|_fn_ctx, _params_partial, _data| {
+                    Err(MockItemError::Synthetic(String::from("synthetic")))
+                })
+                .into(),
+        );
+        graph_builder.build()
+    };
+    let flow = Flow::new(flow_id, graph);
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    let CmdOutcome::Complete {
+        value: (states_current, items_state_freshness),
+        cmd_blocks_processed: _,
+    } = StatesDiscoverCmd::current_incremental(
+        &mut cmd_ctx,
+        StatesDiscoverIncremental::ItemIds(vec![VecCopyItem::ID_DEFAULT.clone()]),
+    )
+    .await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::current_incremental` to complete successfully.");
+    };
+
+    assert_eq!(
+        Some(VecCopyState::new()).as_ref(),
+        states_current.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+    assert!(states_current.get_raw(MockItem::<()>::ID_DEFAULT).is_some());
+    assert_eq!(
+        Some(&StateFreshness::Discovered),
+        items_state_freshness.get(VecCopyItem::ID_DEFAULT)
+    );
+    assert_eq!(
+        Some(&StateFreshness::Stored),
+        items_state_freshness.get(MockItem::<()>::ID_DEFAULT)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn current_incremental_rediscovers_items_whose_params_changed()
+-> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(flow_id.clone(), graph);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(
+            MockItem::<()>::default()
+                .with_try_state_current(|_fn_ctx, _params_partial, _data| {
+                    Err(MockItemError::Synthetic(String::from("synthetic")))
+                })
+                .into(),
+        );
+        graph_builder.build()
+    };
+    let flow = Flow::new(flow_id, graph);
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![1, 2, 3]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    let CmdOutcome::Complete {
+        value: (_states_current, items_state_freshness),
+        cmd_blocks_processed: _,
+    } = StatesDiscoverCmd::current_incremental(
+        &mut cmd_ctx,
+        StatesDiscoverIncremental::ParamsChanged,
+    )
+    .await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::current_incremental` to complete successfully.");
+    };
+
+    assert_eq!(
+        Some(&StateFreshness::Discovered),
+        items_state_freshness.get(VecCopyItem::ID_DEFAULT)
+    );
+    assert_eq!(
+        Some(&StateFreshness::Stored),
+        items_state_freshness.get(MockItem::<()>::ID_DEFAULT)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use peace::{
+    cfg::{app_name, profile, FlowId},
+    cmd::ctx::CmdCtx,
+    rt::cmds::{StatesArchive, StatesDiscoverCmd, StatesExportCmd},
+    rt_model::{Flow, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+
+use crate::{
+    peace_cmd_ctx_types::PeaceCmdCtxTypes, NoOpOutput, PeaceTestError, VecA, VecCopyItem,
+    VecCopyState,
+};
+
+#[tokio::test]
+async fn exports_states_and_params_specs_to_archive() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let archive_path = tempdir.path().join("states_archive.yaml");
+    let states_archive = StatesExportCmd::exec(&mut cmd_ctx, &archive_path).await?;
+
+    assert_eq!(flow.flow_id(), &states_archive.flow_id);
+    assert_eq!(profile!("test_profile"), states_archive.profile);
+    assert!(states_archive.executed_at.is_some());
+    assert_eq!(
+        Some(std::any::type_name::<VecCopyState>()),
+        states_archive
+            .item_state_types
+            .get(VecCopyItem::ID_DEFAULT)
+            .map(String::as_str)
+    );
+    assert_eq!(
+        Some(serde_yaml::from_str::<serde_yaml::Value>("vec_copy: []")?),
+        states_archive.states_current
+    );
+    assert_eq!(
+        Some(serde_yaml::from_str::<serde_yaml::Value>(
+            "vec_copy: [0, 1, 2, 3]"
+        )?),
+        states_archive.states_goal
+    );
+    assert!(states_archive.params_specs.is_some());
+    let states_archive_read =
+        serde_yaml::from_str::<StatesArchive>(&tokio::fs::read_to_string(&archive_path).await?)?;
+    assert_eq!(states_archive, states_archive_read);
+    Ok(())
+}
+
+#[tokio::test]
+async fn exports_absent_states_when_not_discovered() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .await?;
+
+    let archive_path = tempdir.path().join("states_archive.yaml");
+    let states_archive = StatesExportCmd::exec(&mut cmd_ctx, &archive_path).await?;
+
+    assert!(states_archive.executed_at.is_none());
+    assert!(states_archive.states_current.is_none());
+    assert!(states_archive.states_goal.is_none());
+    // Params specs are stored when the `CmdCtx` is built.
+    assert!(states_archive.params_specs.is_some());
+    Ok(())
+}
+
+#[test]
+fn debug() {
+    let debug_str = format!("{:?}", StatesExportCmd::<PeaceCmdCtxTypes>::default());
+    assert_eq!(
+        r#"StatesExportCmd(PhantomData<workspace_tests::peace_cmd_ctx_types::PeaceCmdCtxTypes>)"#,
+        debug_str,
+    );
+}
@@ -0,0 +1,302 @@
+use std::path::Path;
+
+use peace::{
+    cfg::{app_name, flow_id, item_id, profile, state::Sensitive, FlowId},
+    cmd::ctx::CmdCtx,
+    cmd_model::CmdOutcome,
+    resources::paths::{StatesCurrentFile, StatesGoalFile},
+    rt::cmds::{
+        StatesArchive, StatesCurrentReadCmd, StatesDiscoverCmd, StatesExportCmd, StatesGoalReadCmd,
+        StatesImportCmd,
+    },
+    rt_model::{Error, Flow, ItemGraphBuilder, KeyProvider, Workspace, WorkspaceSpec},
+};
+
+use crate::{
+    mock_item::{MockItem, MockSrc},
+    peace_cmd_ctx_types::PeaceCmdCtxTypes,
+    token_item::{TokenItem, TokenSrc, TokenState},
+    NoOpOutput, PeaceTestError, VecA, VecCopyItem, VecCopyState,
+};
+
+#[tokio::test]
+async fn imports_states_into_another_workspace() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let flow = vec_copy_flow(crate::fn_name_short!())?;
+    let archive_path = tempdir.path().join("states_archive.yaml");
+    states_archive_export(&tempdir.path().join("from"), &flow, &archive_path).await?;
+
+    let workspace = Workspace::new(app_name!(), WorkspaceSpec::Path(tempdir.path().join("to")))?;
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("other_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .await?;
+    let states_archive = StatesImportCmd::exec(&mut cmd_ctx, &archive_path).await?;
+
+    assert_eq!(profile!("test_profile"), states_archive.profile);
+    assert!(tokio::fs::try_exists(StatesGoalFile::from(cmd_ctx.flow_dir())).await?);
+
+    // Re-read imported states from disk.
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("other_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .await?;
+    let CmdOutcome::Complete {
+        value: states_current_stored,
+        cmd_blocks_processed: _,
+    } = StatesCurrentReadCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesCurrentReadCmd::exec` to complete successfully.");
+    };
+    assert_eq!(
+        Some(&VecCopyState::new()),
+        states_current_stored.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+    let CmdOutcome::Complete {
+        value: states_goal,
+        cmd_blocks_processed: _,
+    } = StatesGoalReadCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesGoalReadCmd::exec` to complete successfully.");
+    };
+    assert_eq!(
+        Some(&VecCopyState::from(vec![0, 1, 2, 3])),
+        states_goal.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn imports_sensitive_state_fields_into_workspace_with_same_key(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(TokenItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let archive_path = tempdir.path().join("states_archive.yaml");
+
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().join("from")),
+    )?
+    .with_sensitive_key_provider(KeyProvider::from_key([1u8; 32]))?;
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<TokenItem>(
+            TokenItem::ID_DEFAULT.clone(),
+            TokenSrc(String::from("hunter2")).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    StatesExportCmd::exec(&mut cmd_ctx, &archive_path).await?;
+    let archive_contents = tokio::fs::read_to_string(&archive_path).await?;
+
+    let workspace = Workspace::new(app_name!(), WorkspaceSpec::Path(tempdir.path().join("to")))?
+        .with_sensitive_key_provider(KeyProvider::from_key([1u8; 32]))?;
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<TokenItem>(
+            TokenItem::ID_DEFAULT.clone(),
+            TokenSrc(String::from("hunter2")).into(),
+        )
+        .await?;
+    StatesImportCmd::exec(&mut cmd_ctx, &archive_path).await?;
+    let CmdOutcome::Complete {
+        value: states_goal,
+        cmd_blocks_processed: _,
+    } = StatesGoalReadCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesGoalReadCmd::exec` to complete successfully.");
+    };
+
+    assert!(
+        archive_contents.contains("token: encrypted:")
+            && !archive_contents.contains(Sensitive::<String>::REDACTED),
+        "Expected sensitive fields to be encrypted, but archive was:\n{archive_contents}"
+    );
+    assert_eq!(
+        Some(&TokenState {
+            token: Some(Sensitive::new(String::from("hunter2"))),
+        }),
+        states_goal.get::<TokenState, _>(TokenItem::ID_DEFAULT)
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn returns_err_when_flow_id_differs() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let flow_from = vec_copy_flow("flow_from")?;
+    let archive_path = tempdir.path().join("states_archive.yaml");
+    states_archive_export(&tempdir.path().join("from"), &flow_from, &archive_path).await?;
+
+    let workspace = Workspace::new(app_name!(), WorkspaceSpec::Path(tempdir.path().join("to")))?;
+    let flow = vec_copy_flow("flow_to")?;
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .await?;
+    let exec_result = StatesImportCmd::exec(&mut cmd_ctx, &archive_path).await;
+
+    assert!(
+        matches!(
+            &exec_result,
+            Err(PeaceTestError::PeaceRt(Error::StatesArchiveFlowIdMismatch {
+                flow_id_archive,
+                flow_id,
+                ..
+            }))
+            if flow_id_archive == &flow_id!("flow_from") && flow_id == &flow_id!("flow_to")
+        ),
+        "was {exec_result:?}"
+    );
+    let states_current_file = StatesCurrentFile::from(cmd_ctx.flow_dir());
+    assert!(!tokio::fs::try_exists(&states_current_file).await?);
+    Ok(())
+}
+
+#[tokio::test]
+async fn returns_err_when_item_not_in_flow() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let flow_from = vec_copy_flow(crate::fn_name_short!())?;
+    let archive_path = tempdir.path().join("states_archive.yaml");
+    states_archive_export(&tempdir.path().join("from"), &flow_from, &archive_path).await?;
+
+    let workspace = Workspace::new(app_name!(), WorkspaceSpec::Path(tempdir.path().join("to")))?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(flow_from.flow_id().clone(), graph);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    let exec_result = StatesImportCmd::exec(&mut cmd_ctx, &archive_path).await;
+
+    assert!(
+        matches!(
+            &exec_result,
+            Err(PeaceTestError::PeaceRt(Error::StatesArchiveIncompatible {
+                item_ids_unknown,
+                item_ids_state_type_mismatch,
+                ..
+            }))
+            if item_ids_unknown == &[item_id!("vec_copy")]
+            && item_ids_state_type_mismatch.is_empty()
+        ),
+        "was {exec_result:?}"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn returns_err_when_state_type_differs() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let flow = vec_copy_flow(crate::fn_name_short!())?;
+    let archive_path = tempdir.path().join("states_archive.yaml");
+    let mut states_archive =
+        states_archive_export(&tempdir.path().join("from"), &flow, &archive_path).await?;
+    states_archive.item_state_types.insert(
+        VecCopyItem::ID_DEFAULT.clone(),
+        String::from("other_app::VecCopyState"),
+    );
+    tokio::fs::write(&archive_path, serde_yaml::to_string(&states_archive)?).await?;
+
+    let workspace = Workspace::new(app_name!(), WorkspaceSpec::Path(tempdir.path().join("to")))?;
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .await?;
+    let exec_result = StatesImportCmd::exec(&mut cmd_ctx, &archive_path).await;
+
+    assert!(
+        matches!(
+            &exec_result,
+            Err(PeaceTestError::PeaceRt(Error::StatesArchiveIncompatible {
+                item_ids_unknown,
+                item_ids_state_type_mismatch,
+                ..
+            }))
+            if item_ids_unknown.is_empty()
+            && item_ids_state_type_mismatch == &[item_id!("vec_copy")]
+        ),
+        "was {exec_result:?}"
+    );
+    Ok(())
+}
+
+#[test]
+fn debug() {
+    let debug_str = format!("{:?}", StatesImportCmd::<PeaceCmdCtxTypes>::default());
+    assert_eq!(
+        r#"StatesImportCmd(PhantomData<workspace_tests::peace_cmd_ctx_types::PeaceCmdCtxTypes>)"#,
+        debug_str,
+    );
+}
+
+fn vec_copy_flow(
+    flow_id: &'static str,
+) -> Result<Flow<PeaceTestError>, Box<dyn std::error::Error>> {
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    Ok(Flow::new(FlowId::new(flow_id)?, graph))
+}
+
+/// Discovers states in a workspace at `workspace_dir`, and exports them to
+/// `archive_path`.
+async fn states_archive_export(
+    workspace_dir: &Path,
+    flow: &Flow<PeaceTestError>,
+    archive_path: &Path,
+) -> Result<StatesArchive, Box<dyn std::error::Error>> {
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(workspace_dir.to_path_buf()),
+    )?;
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    Ok(StatesExportCmd::exec(&mut cmd_ctx, archive_path).await?)
+}
@@ -0,0 +1,224 @@
+use peace::{
+    cfg::{app_name, item_id, profile, FlowId},
+    cmd::ctx::CmdCtx,
+    params::{ParamsSpec, ParamsSpecs},
+    rt::cmds::{StatesDiscoverCmd, StatesPruneCmd},
+    rt_model::{Flow, ItemGraph, ItemGraphBuilder, StatesPruneOutcome, Workspace, WorkspaceSpec},
+};
+
+use crate::{
+    mock_item::{MockItem, MockSrc, MockState},
+    FnInvocation, FnTrackerOutput, NoOpOutput, PeaceTestError, VecA, VecCopyItem,
+};
+
+#[tokio::test]
+async fn exec_prunes_stored_states_of_removed_items_that_are_cleaned_up(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir)?;
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    states_store(&workspace, &flow_id).await?;
+
+    let item_graph_removed = item_graph_removed(MockItem::<()>::default());
+    let outcome = states_prune(&workspace, &flow_id, &item_graph_removed).await?;
+    assert_eq!(
+        StatesPruneOutcome {
+            item_ids_pruned: vec![MockItem::<()>::ID_DEFAULT.clone()],
+            ..Default::default()
+        },
+        outcome
+    );
+
+    // Pruning again finds no stored states for removed items.
+    let outcome = states_prune(&workspace, &flow_id, &item_graph_removed).await?;
+    assert!(outcome.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_keeps_stored_states_of_removed_items_that_still_exist(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir)?;
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    states_store(&workspace, &flow_id).await?;
+
+    let item_graph_removed = item_graph_removed(
+        MockItem::<()>::default().with_try_state_current(|_, _, _| Ok(Some(MockState(1)))),
+    );
+    let outcome = states_prune(&workspace, &flow_id, &item_graph_removed).await?;
+    assert_eq!(
+        StatesPruneOutcome {
+            item_ids_exist: vec![MockItem::<()>::ID_DEFAULT.clone()],
+            ..Default::default()
+        },
+        outcome
+    );
+    assert!(outcome.has_warnings());
+
+    // The stored state is kept.
+    let outcome = states_prune(&workspace, &flow_id, &item_graph_removed).await?;
+    assert_eq!(
+        vec![MockItem::<()>::ID_DEFAULT.clone()],
+        outcome.item_ids_exist
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_keeps_stored_states_of_removed_items_that_are_not_provided(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir)?;
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    states_store(&workspace, &flow_id).await?;
+
+    let item_graph_removed = ItemGraphBuilder::<PeaceTestError>::new().build();
+    let outcome = states_prune(&workspace, &flow_id, &item_graph_removed).await?;
+    assert_eq!(
+        StatesPruneOutcome {
+            item_ids_unconfirmed: vec![MockItem::<()>::ID_DEFAULT.clone()],
+            ..Default::default()
+        },
+        outcome
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_presents_states_prune_outcome() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir)?;
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    states_store(&workspace, &flow_id).await?;
+
+    let flow = flow(&flow_id, false);
+    let item_graph_removed = item_graph_removed(MockItem::<()>::default());
+    let mut fn_tracker_output = FnTrackerOutput::new();
+    let mut cmd_ctx =
+        CmdCtx::builder_single_profile_single_flow(&mut fn_tracker_output, &workspace)
+            .with_profile(profile!("test_profile"))
+            .with_flow(&flow)
+            .await?;
+    let outcome =
+        StatesPruneCmd::exec(&mut cmd_ctx, &item_graph_removed, &params_specs_removed()).await?;
+    let fn_tracker_output = cmd_ctx.output();
+
+    assert_eq!(
+        vec![FnInvocation::new(
+            "present",
+            vec![Some(serde_yaml::to_string(&outcome)?)],
+        )],
+        fn_tracker_output.fn_invocations()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_states_prune_prunes_stored_states_of_removed_items(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir)?;
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    states_store(&workspace, &flow_id).await?;
+
+    let flow = flow(&flow_id, false);
+    let item_graph_removed = item_graph_removed(MockItem::<()>::default());
+    let mut fn_tracker_output = FnTrackerOutput::new();
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut fn_tracker_output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_states_prune(&item_graph_removed)
+        .await?;
+    let fn_tracker_output = cmd_ctx.output();
+
+    let outcome_expected = StatesPruneOutcome {
+        item_ids_pruned: vec![item_id!("mock")],
+        ..Default::default()
+    };
+    assert_eq!(
+        vec![FnInvocation::new(
+            "present",
+            vec![Some(serde_yaml::to_string(&outcome_expected)?)],
+        )],
+        fn_tracker_output.fn_invocations()
+    );
+
+    // Nothing is presented when there are no stored states for removed items.
+    let mut fn_tracker_output = FnTrackerOutput::new();
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut fn_tracker_output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_states_prune(&item_graph_removed)
+        .await?;
+    assert!(cmd_ctx.output().fn_invocations().is_empty());
+    Ok(())
+}
+
+fn workspace(tempdir: &tempfile::TempDir) -> Result<Workspace, PeaceTestError> {
+    Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )
+    .map_err(PeaceTestError::from)
+}
+
+fn flow(flow_id: &FlowId, with_mock_item: bool) -> Flow<PeaceTestError> {
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        if with_mock_item {
+            graph_builder.add_fn(MockItem::<()>::default().into());
+        }
+        graph_builder.build()
+    };
+    Flow::new(flow_id.clone(), graph)
+}
+
+fn item_graph_removed(mock_item: MockItem<()>) -> ItemGraph<PeaceTestError> {
+    let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+    graph_builder.add_fn(mock_item.into());
+    graph_builder.build()
+}
+
+/// Stores current states for a flow with a `VecCopyItem` and a `MockItem`.
+async fn states_store(workspace: &Workspace, flow_id: &FlowId) -> Result<(), PeaceTestError> {
+    let flow = flow(flow_id, true);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+        .await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+    Ok(())
+}
+
+/// Prunes stored states for the flow with the `MockItem` removed.
+async fn states_prune(
+    workspace: &Workspace,
+    flow_id: &FlowId,
+    item_graph_removed: &ItemGraph<PeaceTestError>,
+) -> Result<StatesPruneOutcome, PeaceTestError> {
+    let flow = flow(flow_id, false);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .await?;
+    StatesPruneCmd::exec(&mut cmd_ctx, item_graph_removed, &params_specs_removed()).await
+}
+
+/// Returns the params specs of the removed `MockItem`.
+fn params_specs_removed() -> ParamsSpecs {
+    let mut params_specs = ParamsSpecs::new();
+    params_specs.insert(
+        MockItem::<()>::ID_DEFAULT.clone(),
+        ParamsSpec::<MockSrc>::from(MockSrc(1)),
+    );
+    params_specs
+}
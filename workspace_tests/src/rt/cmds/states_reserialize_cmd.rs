@@ -0,0 +1,107 @@
+use peace::{
+    cfg::{app_name, item_id, profile, FlowId},
+    cmd::ctx::CmdCtx,
+    resources::paths::{StatesCurrentFile, StatesGoalFile},
+    rt::cmds::{StatesReserializeCmd, StatesReserializeOutcome},
+    rt_model::{Flow, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+
+use crate::{peace_cmd_ctx_types::PeaceCmdCtxTypes, NoOpOutput, PeaceTestError, VecA, VecCopyItem};
+
+#[tokio::test]
+async fn removes_entries_for_items_not_in_flow() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .await?;
+
+    // Write states files with an entry for an item that is no longer in the flow.
+    let flow_dir = cmd_ctx.flow_dir();
+    let states_content = "\
+        vec_copy: [0, 1, 2, 3]\n\
+        removed_item: 123\n\
+    ";
+    let states_current_file = StatesCurrentFile::from(flow_dir);
+    let states_goal_file = StatesGoalFile::from(flow_dir);
+    tokio::fs::write(&states_current_file, states_content.as_bytes()).await?;
+    tokio::fs::write(&states_goal_file, states_content.as_bytes()).await?;
+
+    let states_reserialize_outcome = StatesReserializeCmd::exec(&mut cmd_ctx).await?;
+
+    assert_eq!(
+        &[item_id!("removed_item")],
+        states_reserialize_outcome.states_current_item_ids_removed()
+    );
+    assert_eq!(
+        &[item_id!("removed_item")],
+        states_reserialize_outcome.states_goal_item_ids_removed()
+    );
+    assert!(states_reserialize_outcome
+        .params_specs_item_ids_removed()
+        .is_empty());
+    let states_current_content = tokio::fs::read_to_string(&states_current_file).await?;
+    let states_goal_content = tokio::fs::read_to_string(&states_goal_file).await?;
+    assert_eq!("vec_copy:\n- 0\n- 1\n- 2\n- 3\n", states_current_content);
+    assert_eq!("vec_copy:\n- 0\n- 1\n- 2\n- 3\n", states_goal_content);
+    Ok(())
+}
+
+#[tokio::test]
+async fn returns_empty_outcome_when_states_not_on_disk() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .await?;
+
+    let states_reserialize_outcome = StatesReserializeCmd::exec(&mut cmd_ctx).await?;
+
+    assert_eq!(
+        StatesReserializeOutcome::default(),
+        states_reserialize_outcome
+    );
+    assert!(states_reserialize_outcome.is_empty());
+    let states_current_file = StatesCurrentFile::from(cmd_ctx.flow_dir());
+    assert!(!tokio::fs::try_exists(&states_current_file).await?);
+    Ok(())
+}
+
+#[test]
+fn debug() {
+    let debug_str = format!("{:?}", StatesReserializeCmd::<PeaceCmdCtxTypes>::default());
+    assert_eq!(
+        r#"StatesReserializeCmd(PhantomData<workspace_tests::peace_cmd_ctx_types::PeaceCmdCtxTypes>)"#,
+        debug_str,
+    );
+}
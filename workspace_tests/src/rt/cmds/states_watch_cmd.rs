@@ -0,0 +1,127 @@
+use std::time::Duration;
+
+use peace::{
+    cfg::{app_name, profile, DiffSeverity, FlowId},
+    cmd::ctx::CmdCtx,
+    cmd_model::CmdOutcome,
+    rt::cmds::{EnsureCmd, StatesDiscoverCmd, StatesWatchCmd, StatesWatchOpts},
+    rt_model::{Flow, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+
+use crate::{
+    peace_cmd_ctx_types::PeaceCmdCtxTypes, NoOpOutput, PeaceTestError, VecA, VecCopyDiff,
+    VecCopyItem,
+};
+
+#[tokio::test]
+async fn sends_drift_each_iteration_until_iteration_limit() -> Result<(), Box<dyn std::error::Error>>
+{
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::goal(&mut cmd_ctx).await?;
+
+    let (drift_tx, mut drift_rx) = tokio::sync::mpsc::channel(4);
+    let states_watch_opts = StatesWatchOpts::new(Duration::from_millis(1))
+        .with_iteration_limit(2)
+        .with_drift_tx(drift_tx);
+    let cmd_outcome = StatesWatchCmd::exec(&mut cmd_ctx, states_watch_opts).await?;
+
+    assert!(cmd_outcome.is_complete());
+    for iteration in 1..=2 {
+        let states_drift = drift_rx
+            .recv()
+            .await
+            .expect("Expected `StatesDrift` to be sent for each iteration.");
+        assert_eq!(iteration, states_drift.iteration());
+        assert_ne!(
+            Some(&DiffSeverity::InSync),
+            states_drift.diff_summary().get(VecCopyItem::ID_DEFAULT)
+        );
+        assert!(states_drift
+            .state_diffs()
+            .get::<VecCopyDiff, _>(VecCopyItem::ID_DEFAULT)
+            .is_some());
+    }
+    assert!(drift_rx.recv().await.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn does_not_send_drift_when_states_in_sync() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    EnsureCmd::exec(&mut cmd_ctx).await?;
+
+    let (drift_tx, mut drift_rx) = tokio::sync::mpsc::channel(4);
+    let states_watch_opts = StatesWatchOpts::new(Duration::from_millis(1))
+        .with_iteration_limit(1)
+        .with_drift_tx(drift_tx);
+    let cmd_outcome = StatesWatchCmd::exec(&mut cmd_ctx, states_watch_opts).await?;
+
+    let CmdOutcome::Complete {
+        value: (_state_diffs, diff_summary),
+        cmd_blocks_processed: _,
+    } = cmd_outcome
+    else {
+        panic!("Expected `StatesWatchCmd::exec` to complete successfully.");
+    };
+    assert_eq!(DiffSeverity::InSync, diff_summary.severity_max());
+    assert!(drift_rx.recv().await.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn debug() {
+    let debug_str = format!("{:?}", StatesWatchCmd::<PeaceCmdCtxTypes>::default());
+    assert_eq!(
+        r#"StatesWatchCmd(PhantomData<workspace_tests::peace_cmd_ctx_types::PeaceCmdCtxTypes>)"#,
+        debug_str,
+    );
+}
+
+#[test]
+fn states_watch_opts_defaults_to_no_iteration_limit() {
+    let states_watch_opts = StatesWatchOpts::new(Duration::from_secs(5));
+
+    assert_eq!(Duration::from_secs(5), states_watch_opts.interval());
+    assert_eq!(None, states_watch_opts.iteration_limit());
+}
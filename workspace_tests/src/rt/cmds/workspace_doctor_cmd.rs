@@ -0,0 +1,259 @@
+use peace::{
+    cfg::{app_name, item_id, profile, FlowId},
+    cmd::ctx::CmdCtx,
+    resources::paths::{ParamsSpecsFile, StatesCurrentFile, StatesGoalFile},
+    rt::cmds::{StatesDiscoverCmd, WorkspaceDoctorCmd, WorkspaceIssue},
+    rt_model::{Flow, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+
+use crate::{
+    peace_cmd_ctx_types::PeaceCmdCtxTypes, FnInvocation, FnTrackerOutput, NoOpOutput,
+    PeaceTestError, VecA, VecCopyItem,
+};
+
+#[tokio::test]
+async fn exec_returns_no_issues_after_discover() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let flow = flow(FlowId::new(crate::fn_name_short!())?);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let workspace_doctor_report = WorkspaceDoctorCmd::exec(&mut cmd_ctx).await?;
+
+    assert!(
+        workspace_doctor_report.is_empty(),
+        "Expected no issues, but got: {workspace_doctor_report:?}"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_tmp_file_leftover_and_exec_with_fix_removes_it(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let flow = flow(FlowId::new(crate::fn_name_short!())?);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    // Simulate a write that was interrupted before the rename.
+    let tmp_file_path = cmd_ctx.flow_dir().join("states_current.yaml.tmp");
+    tokio::fs::write(&tmp_file_path, b"vec_copy: [0, 1").await?;
+
+    let workspace_doctor_report = WorkspaceDoctorCmd::exec(&mut cmd_ctx).await?;
+    assert_eq!(
+        &[WorkspaceIssue::TmpFileLeftover {
+            path: tmp_file_path.clone()
+        }],
+        workspace_doctor_report.issues()
+    );
+    assert!(tokio::fs::try_exists(&tmp_file_path).await?);
+
+    let workspace_doctor_report = WorkspaceDoctorCmd::exec_with_fix(&mut cmd_ctx).await?;
+    assert!(workspace_doctor_report.issues().is_empty());
+    assert_eq!(
+        &[WorkspaceIssue::TmpFileLeftover {
+            path: tmp_file_path.clone()
+        }],
+        workspace_doctor_report.issues_fixed()
+    );
+    assert!(!tokio::fs::try_exists(&tmp_file_path).await?);
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_states_goal_outdated_when_goal_not_discovered(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let flow = flow(FlowId::new(crate::fn_name_short!())?);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    let workspace_doctor_report = WorkspaceDoctorCmd::exec_with_fix(&mut cmd_ctx).await?;
+
+    let states_goal_file = StatesGoalFile::from(cmd_ctx.flow_dir());
+    assert_eq!(
+        &[WorkspaceIssue::StatesGoalOutdated {
+            path: states_goal_file.to_path_buf()
+        }],
+        workspace_doctor_report.issues()
+    );
+    assert!(workspace_doctor_report.issues_fixed().is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_with_fix_removes_entries_for_items_not_in_flow(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let flow = flow(FlowId::new(crate::fn_name_short!())?);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .await?;
+
+    let flow_dir = cmd_ctx.flow_dir();
+    let states_content = "\
+        vec_copy: [0, 1, 2, 3]\n\
+        removed_item: 123\n\
+    ";
+    let states_current_file = StatesCurrentFile::from(flow_dir);
+    let states_goal_file = StatesGoalFile::from(flow_dir);
+    tokio::fs::write(&states_current_file, states_content.as_bytes()).await?;
+    tokio::fs::write(&states_goal_file, states_content.as_bytes()).await?;
+
+    let workspace_doctor_report = WorkspaceDoctorCmd::exec(&mut cmd_ctx).await?;
+    let issues_expected = [
+        WorkspaceIssue::ItemIdsUnknown {
+            path: states_current_file.to_path_buf(),
+            item_ids: vec![item_id!("removed_item")],
+        },
+        WorkspaceIssue::ItemIdsUnknown {
+            path: states_goal_file.to_path_buf(),
+            item_ids: vec![item_id!("removed_item")],
+        },
+    ];
+    assert_eq!(&issues_expected, workspace_doctor_report.issues());
+
+    let workspace_doctor_report = WorkspaceDoctorCmd::exec_with_fix(&mut cmd_ctx).await?;
+    assert!(workspace_doctor_report.issues().is_empty());
+    assert_eq!(&issues_expected, workspace_doctor_report.issues_fixed());
+    let states_current_content = tokio::fs::read_to_string(&states_current_file).await?;
+    assert_eq!("vec_copy:\n- 0\n- 1\n- 2\n- 3\n", states_current_content);
+
+    let workspace_doctor_report = WorkspaceDoctorCmd::exec(&mut cmd_ctx).await?;
+    assert!(workspace_doctor_report.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_stored_file_invalid_when_file_cannot_be_deserialized(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let flow = flow(FlowId::new(crate::fn_name_short!())?);
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3]).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let params_specs_file = ParamsSpecsFile::from(cmd_ctx.flow_dir());
+    tokio::fs::write(&params_specs_file, b"vec_copy: [").await?;
+
+    let workspace_doctor_report = WorkspaceDoctorCmd::exec_with_fix(&mut cmd_ctx).await?;
+
+    let [WorkspaceIssue::StoredFileInvalid { path, error }] = workspace_doctor_report.issues()
+    else {
+        panic!(
+            "Expected `StoredFileInvalid` issue, but got: {:?}",
+            workspace_doctor_report.issues()
+        );
+    };
+    assert_eq!(&params_specs_file.to_path_buf(), path);
+    assert!(!error.is_empty());
+    assert!(workspace_doctor_report.issues_fixed().is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_presents_report() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let flow = flow(FlowId::new(crate::fn_name_short!())?);
+    let mut fn_tracker_output = FnTrackerOutput::new();
+    let mut cmd_ctx =
+        CmdCtx::builder_single_profile_single_flow(&mut fn_tracker_output, &workspace)
+            .with_profile(profile!("test_profile"))
+            .with_flow(&flow)
+            .with_item_params::<VecCopyItem>(
+                VecCopyItem::ID_DEFAULT.clone(),
+                VecA(vec![0, 1, 2, 3]).into(),
+            )
+            .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let workspace_doctor_report = WorkspaceDoctorCmd::exec(&mut cmd_ctx).await?;
+
+    assert_eq!(
+        vec![FnInvocation::new(
+            "present",
+            vec![Some(serde_yaml::to_string(&workspace_doctor_report)?)],
+        )],
+        fn_tracker_output.fn_invocations()
+    );
+    Ok(())
+}
+
+#[test]
+fn debug() {
+    let debug_str = format!("{:?}", WorkspaceDoctorCmd::<PeaceCmdCtxTypes>::default());
+    assert_eq!(
+        r#"WorkspaceDoctorCmd(PhantomData<workspace_tests::peace_cmd_ctx_types::PeaceCmdCtxTypes>)"#,
+        debug_str,
+    );
+}
+
+fn flow(flow_id: FlowId) -> Flow<PeaceTestError> {
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    Flow::new(flow_id, graph)
+}
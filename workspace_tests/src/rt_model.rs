@@ -1,11 +1,42 @@
 #[cfg(feature = "error_reporting")]
 mod error;
+mod apply_hooks;
+mod approval_policy;
+#[cfg(feature = "telemetry")]
+mod cmd_metrics;
+mod cmd_outcome_reporter;
+mod execution_deadline;
+mod execution_history;
+#[cfg(feature = "output_progress")]
+mod execution_progress;
+#[cfg(feature = "output_progress")]
+mod execution_progress_serializer;
+mod execution_timeline;
+mod flow_loader;
+mod flow_metadata;
+mod heartbeat_policy;
+#[cfg(feature = "item_schema")]
+mod flow_schema;
+mod in_memory_output;
 mod item_boxed;
+mod item_executor;
 mod item_graph;
 mod item_graph_builder;
+mod item_log_layer;
 mod item_wrapper;
+mod items_disabled;
 mod native;
 mod outcomes;
+mod params_digests;
+mod params_file_deserializer;
+mod params_history;
+mod preflight_check;
+mod state_query;
 mod states_serializer;
 mod storage;
+mod storage_transaction;
+mod storage_compression;
+mod storage_format;
 mod workspace_dirs_builder;
+mod workspace_params_registry;
+mod workspace_set;
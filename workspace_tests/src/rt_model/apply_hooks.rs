@@ -0,0 +1,82 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use peace::{
+    cfg::item_id,
+    resources::type_reg::untagged::BoxDtDisplay,
+    rt_model::{ApplyHookOutcome, ApplyHooks, PostApplyHook, PreApplyHook},
+};
+
+#[test]
+fn is_empty_returns_false_when_any_hook_registered() {
+    let mut apply_hooks = ApplyHooks::<()>::new();
+    assert!(apply_hooks.is_empty());
+
+    apply_hooks.post_apply_hook_push(PostApplyHook::new(|_, _, _| {}));
+    assert!(!apply_hooks.is_empty());
+}
+
+#[test]
+fn pre_apply_stops_at_first_error() {
+    let invocation_count = Arc::new(AtomicUsize::new(0));
+    let mut apply_hooks = ApplyHooks::<&'static str>::new();
+    apply_hooks.pre_apply_hook_push(PreApplyHook::new({
+        let invocation_count = Arc::clone(&invocation_count);
+        move |_, _| {
+            invocation_count.fetch_add(1, Ordering::SeqCst);
+            Err("gated")
+        }
+    }));
+    apply_hooks.pre_apply_hook_push(PreApplyHook::new({
+        let invocation_count = Arc::clone(&invocation_count);
+        move |_, _| {
+            invocation_count.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }));
+
+    let result = apply_hooks.pre_apply(&item_id!("item"), &BoxDtDisplay::new(1u8));
+
+    assert_eq!(Err("gated"), result);
+    assert_eq!(1, invocation_count.load(Ordering::SeqCst));
+}
+
+#[test]
+fn post_apply_invokes_each_hook() {
+    let invocation_count = Arc::new(AtomicUsize::new(0));
+    let mut apply_hooks = ApplyHooks::<()>::new();
+    (0..2).for_each(|_| {
+        let invocation_count = Arc::clone(&invocation_count);
+        apply_hooks.post_apply_hook_push(PostApplyHook::new(move |_, _, apply_hook_outcome| {
+            if let ApplyHookOutcome::Success { .. } = apply_hook_outcome {
+                invocation_count.fetch_add(1, Ordering::SeqCst);
+            }
+        }));
+    });
+
+    apply_hooks.post_apply(
+        &item_id!("item"),
+        &BoxDtDisplay::new(1u8),
+        &ApplyHookOutcome::Success {
+            state_applied: None,
+        },
+    );
+
+    assert_eq!(2, invocation_count.load(Ordering::SeqCst));
+}
+
+#[test]
+fn debug() {
+    let mut apply_hooks = ApplyHooks::<()>::new();
+    apply_hooks.pre_apply_hook_push(PreApplyHook::new(|_, _| Ok(())));
+
+    assert_eq!(
+        "ApplyHooks { \
+            pre_apply_hooks: [PreApplyHook(\"Fn(&ItemId, &BoxDtDisplay) -> Result<(), E>\")], \
+            post_apply_hooks: [] \
+        }",
+        format!("{apply_hooks:?}")
+    );
+}
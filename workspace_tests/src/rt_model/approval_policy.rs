@@ -0,0 +1,105 @@
+use peace::{
+    cfg::{item_id, DiffSeverity},
+    resources::type_reg::untagged::BoxDtDisplay,
+    rt_model::{ApprovalPolicy, Approver, Error},
+};
+
+#[test]
+fn is_empty_returns_false_when_item_id_or_diff_severity_min_set() {
+    assert!(ApprovalPolicy::new().is_empty());
+    assert!(!ApprovalPolicy::new()
+        .with_item_id(item_id!("item"))
+        .is_empty());
+    assert!(!ApprovalPolicy::new()
+        .with_diff_severity_min(DiffSeverity::Destructive)
+        .is_empty());
+}
+
+#[test]
+fn approval_required_for_listed_item_ids_and_severe_diffs() {
+    let approval_policy = ApprovalPolicy::new()
+        .with_item_id(item_id!("database"))
+        .with_diff_severity_min(DiffSeverity::Destructive);
+
+    assert!(approval_policy.approval_required(&item_id!("database"), DiffSeverity::Additive));
+    assert!(approval_policy.approval_required(&item_id!("server"), DiffSeverity::Destructive));
+    assert!(!approval_policy.approval_required(&item_id!("server"), DiffSeverity::Additive));
+}
+
+#[tokio::test]
+async fn approve_returns_ok_when_approval_not_required() {
+    let approval_policy = ApprovalPolicy::new().with_diff_severity_min(DiffSeverity::Destructive);
+
+    let result = approval_policy
+        .approve(
+            &item_id!("server"),
+            DiffSeverity::Additive,
+            &BoxDtDisplay::new(1u8),
+        )
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn approve_returns_approval_required_error_when_no_approver() {
+    let approval_policy = ApprovalPolicy::new().with_item_id(item_id!("database"));
+
+    let result = approval_policy
+        .approve(
+            &item_id!("database"),
+            DiffSeverity::Destructive,
+            &BoxDtDisplay::new(1u8),
+        )
+        .await;
+
+    assert!(
+        matches!(
+            &result,
+            Err(Error::ApprovalRequired {
+                item_id,
+                diff_severity: DiffSeverity::Destructive,
+            })
+            if item_id == &item_id!("database")
+        ),
+        "Expected `result` to be `Err(Error::ApprovalRequired {{ .. }})`, but was `{result:?}`."
+    );
+}
+
+#[tokio::test]
+async fn approve_returns_result_from_approver() {
+    let approval_policy = ApprovalPolicy::new()
+        .with_diff_severity_min(DiffSeverity::Additive)
+        .with_approver(Approver::new(|approval_request| {
+            Box::pin(async move { approval_request.item_id() == &item_id!("server") })
+        }));
+
+    let result_server = approval_policy
+        .approve(
+            &item_id!("server"),
+            DiffSeverity::Additive,
+            &BoxDtDisplay::new(1u8),
+        )
+        .await;
+    let result_database = approval_policy
+        .approve(
+            &item_id!("database"),
+            DiffSeverity::Additive,
+            &BoxDtDisplay::new(1u8),
+        )
+        .await;
+
+    assert!(result_server.is_ok());
+    assert!(
+        matches!(
+            &result_database,
+            Err(Error::ApprovalDenied {
+                item_id,
+                diff_severity: DiffSeverity::Additive,
+            })
+            if item_id == &item_id!("database")
+        ),
+        "Expected `result_database` to be `Err(Error::ApprovalDenied {{ .. }})`, \
+        but was `{result_database:?}`."
+    );
+}
@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use opentelemetry::metrics::MeterProvider;
+use peace::{
+    cfg::{flow_id, item_id, profile},
+    rt_model::CmdMetrics,
+};
+
+use crate::recording_meter_provider::RecordingMeterProvider;
+
+#[test]
+fn item_applied_records_count_and_duration_with_attributes() {
+    let meter_provider = RecordingMeterProvider::new();
+    let meter = meter_provider.meter(CmdMetrics::METER_NAME);
+    let cmd_metrics = CmdMetrics::new(Some(&meter), &flow_id!("test_flow"), &profile!("test"));
+
+    cmd_metrics.item_applied(&item_id!("item_a"), Duration::from_millis(1500));
+
+    let items_applied = meter_provider.measurements_named("peace.items.applied");
+    assert_eq!(1, items_applied.len());
+    assert_eq!(1.0, items_applied[0].value);
+    assert_eq!(Some("test_flow"), items_applied[0].attribute("flow_id"));
+    assert_eq!(Some("test"), items_applied[0].attribute("profile"));
+    assert_eq!(Some("item_a"), items_applied[0].attribute("item_id"));
+
+    let item_apply_duration = meter_provider.measurements_named("peace.item.apply.duration");
+    assert_eq!(1, item_apply_duration.len());
+    assert_eq!(1.5, item_apply_duration[0].value);
+}
+
+#[test]
+fn item_apply_failed_records_count_and_duration() {
+    let meter_provider = RecordingMeterProvider::new();
+    let meter = meter_provider.meter(CmdMetrics::METER_NAME);
+    let cmd_metrics = CmdMetrics::new(Some(&meter), &flow_id!("test_flow"), &profile!("test"));
+
+    cmd_metrics.item_apply_failed(&item_id!("item_a"), Duration::from_secs(2));
+
+    assert!(meter_provider
+        .measurements_named("peace.items.applied")
+        .is_empty());
+    let items_failed = meter_provider.measurements_named("peace.items.failed");
+    assert_eq!(1, items_failed.len());
+    assert_eq!(Some("item_a"), items_failed[0].attribute("item_id"));
+    let item_apply_duration = meter_provider.measurements_named("peace.item.apply.duration");
+    assert_eq!(2.0, item_apply_duration[0].value);
+}
+
+#[test]
+fn item_skipped_records_count_without_duration() {
+    let meter_provider = RecordingMeterProvider::new();
+    let meter = meter_provider.meter(CmdMetrics::METER_NAME);
+    let cmd_metrics = CmdMetrics::new(Some(&meter), &flow_id!("test_flow"), &profile!("test"));
+
+    cmd_metrics.item_skipped(&item_id!("item_a"));
+
+    assert_eq!(
+        1,
+        meter_provider
+            .measurements_named("peace.items.skipped")
+            .len()
+    );
+    assert!(meter_provider
+        .measurements_named("peace.item.apply.duration")
+        .is_empty());
+}
+
+#[test]
+fn item_discovered_records_duration() {
+    let meter_provider = RecordingMeterProvider::new();
+    let meter = meter_provider.meter(CmdMetrics::METER_NAME);
+    let cmd_metrics = CmdMetrics::new(Some(&meter), &flow_id!("test_flow"), &profile!("test"));
+
+    cmd_metrics.item_discovered(&item_id!("item_a"), Duration::from_millis(250));
+
+    let item_discover_duration = meter_provider.measurements_named("peace.item.discover.duration");
+    assert_eq!(1, item_discover_duration.len());
+    assert_eq!(0.25, item_discover_duration[0].value);
+    assert_eq!(
+        Some("item_a"),
+        item_discover_duration[0].attribute("item_id")
+    );
+}
+
+#[test]
+fn records_nothing_when_meter_is_none() {
+    let cmd_metrics = CmdMetrics::new(None, &flow_id!("test_flow"), &profile!("test"));
+
+    cmd_metrics.item_applied(&item_id!("item_a"), Duration::from_secs(1));
+    cmd_metrics.item_discovered(&item_id!("item_a"), Duration::from_secs(1));
+}
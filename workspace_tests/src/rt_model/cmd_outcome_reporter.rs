@@ -0,0 +1,202 @@
+use peace::{
+    cfg::item_id,
+    cmd_model::{CmdOutcome, ItemStreamOutcome},
+    resources::{internal::StatesMut, states::StatesEnsured},
+    rt_model::{CmdOutcomeReporter, IndexMap},
+};
+
+#[test]
+fn report_returns_none_when_cmd_outcome_is_complete() {
+    let cmd_outcome = CmdOutcome::<StatesEnsured, ItemError>::Complete {
+        value: states_ensured(),
+        cmd_blocks_processed: Vec::new(),
+    };
+
+    assert!(CmdOutcomeReporter::report(&cmd_outcome).is_none());
+}
+
+#[test]
+fn report_includes_item_id_error_chain_and_state_reached() {
+    let cmd_outcome = cmd_outcome_item_error();
+
+    let cmd_outcome_report = CmdOutcomeReporter::report(&cmd_outcome).unwrap();
+
+    assert_eq!("2 items failed.", cmd_outcome_report.to_string());
+    let item_error_reports = cmd_outcome_report.item_error_reports();
+    assert_eq!(2, item_error_reports.len());
+
+    let item_error_report_0 = &item_error_reports[0];
+    assert_eq!(&item_id!("item_0"), item_error_report_0.item_id());
+    assert_eq!("Failed to upload file.", item_error_report_0.message());
+    assert_eq!(
+        Some("connection reset"),
+        item_error_report_0
+            .cause()
+            .map(|error_cause| error_cause.message())
+    );
+    assert_eq!(Some("uploading"), item_error_report_0.state());
+
+    let item_error_report_1 = &item_error_reports[1];
+    assert_eq!(&item_id!("item_1"), item_error_report_1.item_id());
+    assert!(item_error_report_1.cause().is_none());
+    assert_eq!(None, item_error_report_1.state());
+}
+
+#[test]
+fn report_with_uses_state_fn() {
+    let cmd_outcome = cmd_outcome_item_error().map(|states_ensured| (states_ensured, 1u8));
+
+    let cmd_outcome_report =
+        CmdOutcomeReporter::report_with(&cmd_outcome, |(states_ensured, _), item_id| {
+            states_ensured
+                .get_raw(item_id)
+                .map(|state| format!("custom: {state}"))
+        })
+        .unwrap();
+
+    assert_eq!(
+        Some("custom: uploading"),
+        cmd_outcome_report.item_error_reports()[0].state()
+    );
+}
+
+#[test]
+fn fmt_text_writes_each_item_error() -> Result<(), Box<dyn std::error::Error>> {
+    let cmd_outcome = cmd_outcome_item_error();
+    let cmd_outcome_report = CmdOutcomeReporter::report(&cmd_outcome).unwrap();
+
+    let mut text = String::new();
+    cmd_outcome_report.fmt_text(&mut text)?;
+
+    assert_eq!(
+        "\
+        2 items failed.\n\
+        \n\
+        `item_0`: Failed to upload file.\n  \
+          caused by: connection reset\n  \
+          state reached: uploading\n\
+        \n\
+        `item_1`: Item is misconfigured.\n",
+        text
+    );
+    Ok(())
+}
+
+#[cfg(feature = "i18n")]
+#[test]
+fn fmt_text_localized_uses_message_catalog_translations() -> Result<(), Box<dyn std::error::Error>>
+{
+    let cmd_outcome = cmd_outcome_item_error();
+    let cmd_outcome_report = CmdOutcomeReporter::report(&cmd_outcome).unwrap();
+    let message_catalog = peace::fmt::MessageCatalog::new()
+        .with_translations(
+            "fr",
+            "\
+            peace-report-items-failed = { $count } éléments ont échoué.\n\
+            peace-report-caused-by = causé par : { $cause }\n\
+            ",
+        )?
+        .with_locale("fr-FR");
+
+    let mut text = String::new();
+    cmd_outcome_report.fmt_text_localized(&mut text, &message_catalog)?;
+
+    assert_eq!(
+        "\
+        2 éléments ont échoué.\n\
+        \n\
+        `item_0`: Failed to upload file.\n  \
+          causé par : connection reset\n  \
+          state reached: uploading\n\
+        \n\
+        `item_1`: Item is misconfigured.\n",
+        text
+    );
+    Ok(())
+}
+
+#[test]
+fn report_with_error_help_includes_help_from_error_help_fn() {
+    let cmd_outcome = cmd_outcome_item_error();
+
+    let cmd_outcome_report =
+        CmdOutcomeReporter::report_with_error_help(&cmd_outcome, |_item_id, error| match error {
+            ItemError::Upload(_) => vec![String::from("Check your network connection.")],
+            ItemError::Misconfigured => Vec::new(),
+        })
+        .unwrap();
+
+    let item_error_reports = cmd_outcome_report.item_error_reports();
+    assert_eq!(
+        &[String::from("Check your network connection.")],
+        item_error_reports[0].help()
+    );
+    assert_eq!(Some("uploading"), item_error_reports[0].state());
+    assert!(item_error_reports[1].help().is_empty());
+}
+
+#[test]
+fn fmt_text_writes_error_help() -> Result<(), Box<dyn std::error::Error>> {
+    let cmd_outcome = cmd_outcome_item_error();
+    let cmd_outcome_report =
+        CmdOutcomeReporter::report_with_error_help(&cmd_outcome, |_item_id, error| match error {
+            ItemError::Upload(_) => vec![String::from("Check your network connection.")],
+            ItemError::Misconfigured => vec![String::from("Fix the item's params.")],
+        })
+        .unwrap();
+
+    let mut text = String::new();
+    cmd_outcome_report.fmt_text(&mut text)?;
+
+    assert_eq!(
+        "\
+        2 items failed.\n\
+        \n\
+        `item_0`: Failed to upload file.\n  \
+          caused by: connection reset\n  \
+          help: Check your network connection.\n  \
+          state reached: uploading\n\
+        \n\
+        `item_1`: Item is misconfigured.\n  \
+          help: Fix the item's params.\n",
+        text
+    );
+    Ok(())
+}
+
+fn cmd_outcome_item_error() -> CmdOutcome<StatesEnsured, ItemError> {
+    let mut errors = IndexMap::new();
+    errors.insert(
+        item_id!("item_0"),
+        ItemError::Upload(std::io::Error::new(
+            std::io::ErrorKind::ConnectionReset,
+            "connection reset",
+        )),
+    );
+    errors.insert(item_id!("item_1"), ItemError::Misconfigured);
+
+    CmdOutcome::ItemError {
+        item_stream_outcome: ItemStreamOutcome::finished_with(
+            states_ensured(),
+            vec![item_id!("item_0"), item_id!("item_1")],
+        ),
+        cmd_blocks_processed: Vec::new(),
+        cmd_blocks_not_processed: Vec::new(),
+        errors,
+        item_log_paths: IndexMap::new(),
+    }
+}
+
+fn states_ensured() -> StatesEnsured {
+    let mut states = StatesMut::new();
+    states.insert(item_id!("item_0"), String::from("uploading"));
+    StatesEnsured::from(states)
+}
+
+#[derive(Debug, thiserror::Error)]
+enum ItemError {
+    #[error("Failed to upload file.")]
+    Upload(#[source] std::io::Error),
+    #[error("Item is misconfigured.")]
+    Misconfigured,
+}
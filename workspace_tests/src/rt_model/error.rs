@@ -73,6 +73,9 @@ fn params_specs_mismatch_display_with_all_error_cases() -> fmt::Result {
         * params_spec_stored_with_no_item_0
         * params_spec_stored_with_no_item_1
         
+        If an item was renamed, use `ParamsReconcileCmd` to move its params spec
+        to the new item ID, or to drop params specs for removed items.
+        
         The following items either have not had a params spec provided previously,
         or had contained a mapping function, which cannot be loaded from disk.
         
@@ -0,0 +1,59 @@
+use std::time::{Duration, Instant};
+
+use peace::rt_model::ExecutionDeadline;
+
+#[test]
+fn from_instant_uses_default_grace_period() {
+    let deadline = Instant::now();
+    let execution_deadline = ExecutionDeadline::from(deadline);
+
+    assert_eq!(deadline, execution_deadline.deadline());
+    assert_eq!(
+        ExecutionDeadline::GRACE_PERIOD_DEFAULT,
+        execution_deadline.grace_period()
+    );
+}
+
+#[test]
+fn grace_period_end_is_deadline_plus_grace_period() {
+    let deadline = Instant::now();
+    let execution_deadline =
+        ExecutionDeadline::new(deadline).with_grace_period(Duration::from_secs(5));
+
+    assert_eq!(
+        Some(deadline + Duration::from_secs(5)),
+        execution_deadline.grace_period_end()
+    );
+}
+
+#[test]
+fn grace_period_end_is_none_when_grace_period_overflows() {
+    let execution_deadline =
+        ExecutionDeadline::new(Instant::now()).with_grace_period(Duration::MAX);
+
+    assert_eq!(None, execution_deadline.grace_period_end());
+}
+
+#[test]
+fn is_exceeded_returns_whether_deadline_has_passed() {
+    let passed = ExecutionDeadline::new(Instant::now());
+    let future = ExecutionDeadline::new(Instant::now() + Duration::from_secs(60));
+
+    assert!(passed.is_exceeded());
+    assert!(!future.is_exceeded());
+}
+
+#[test]
+fn clone() {
+    let execution_deadline = ExecutionDeadline::new(Instant::now());
+
+    assert_eq!(execution_deadline, Clone::clone(&execution_deadline));
+}
+
+#[test]
+fn debug() {
+    let execution_deadline =
+        ExecutionDeadline::new(Instant::now()).with_grace_period(Duration::from_secs(5));
+
+    assert!(format!("{execution_deadline:?}").starts_with("ExecutionDeadline {"));
+}
@@ -0,0 +1,63 @@
+use peace::{
+    cfg::item_id,
+    rt_model::{ExecutionHistory, ExecutionRecord},
+};
+
+#[test]
+fn execution_id_next_is_zero_when_empty() {
+    let execution_history = ExecutionHistory::new();
+
+    assert_eq!(0, execution_history.execution_id_next());
+    assert_eq!(None, execution_history.execution(0));
+}
+
+#[test]
+fn execution_returns_record_with_id() {
+    let execution_history = ExecutionHistory::from_iter([execution_record(3), execution_record(4)]);
+
+    assert_eq!(5, execution_history.execution_id_next());
+    assert_eq!(
+        Some(4),
+        execution_history
+            .execution(4)
+            .map(|execution_record| execution_record.execution_id)
+    );
+    assert_eq!(None, execution_history.execution(2));
+}
+
+#[test]
+fn push_prunes_oldest_executions() {
+    let mut execution_history = ExecutionHistory::new();
+    (0..ExecutionHistory::EXECUTIONS_MAX as u64).for_each(|execution_id| {
+        let execution_records_pruned = execution_history.push(execution_record(execution_id));
+        assert!(execution_records_pruned.is_empty());
+    });
+
+    let execution_records_pruned =
+        execution_history.push(execution_record(ExecutionHistory::EXECUTIONS_MAX as u64));
+
+    assert_eq!(vec![execution_record(0)], execution_records_pruned);
+    assert_eq!(ExecutionHistory::EXECUTIONS_MAX, execution_history.len());
+    assert_eq!(
+        Some(1),
+        execution_history
+            .first()
+            .map(|execution_record| execution_record.execution_id)
+    );
+}
+
+#[test]
+fn execution_record_display() {
+    assert_eq!(
+        "3: 1970-01-01T00:00:00+00:00 (1 item)",
+        execution_record(3).to_string()
+    );
+}
+
+fn execution_record(execution_id: u64) -> ExecutionRecord {
+    ExecutionRecord {
+        execution_id,
+        recorded_at: chrono::DateTime::UNIX_EPOCH,
+        item_ids: vec![item_id!("item_0")],
+    }
+}
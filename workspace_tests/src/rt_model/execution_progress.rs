@@ -0,0 +1,141 @@
+use std::time::Duration;
+
+use peace::{
+    cfg::{
+        item_id,
+        progress::{
+            ProgressComplete, ProgressLimit, ProgressStatus, ProgressTracker,
+            ProgressTrackerSnapshot,
+        },
+    },
+    cli::output::{CliColorizeOpt, CliMdPresenter, CliOutputBuilder},
+    fmt::Presentable,
+    rt_model::{
+        indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget},
+        CmdProgressTracker, ExecutionProgress, IndexMap,
+    },
+};
+
+#[test]
+fn from_cmd_progress_tracker_snapshots_each_item_in_order() {
+    let mut progress_tracker_b = ProgressTracker::new(ProgressBar::hidden());
+    progress_tracker_b.set_progress_status(ProgressStatus::Complete(ProgressComplete::Success));
+    let progress_tracker_a = ProgressTracker::new(ProgressBar::hidden());
+    let cmd_progress_tracker = CmdProgressTracker::new(
+        MultiProgress::with_draw_target(ProgressDrawTarget::hidden()),
+        IndexMap::from([
+            (item_id!("b"), progress_tracker_b),
+            (item_id!("a"), progress_tracker_a),
+        ]),
+    );
+
+    let execution_progress = ExecutionProgress::from(&cmd_progress_tracker);
+
+    assert_eq!(
+        vec![item_id!("b"), item_id!("a")],
+        execution_progress.keys().cloned().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        Some(&ProgressStatus::Complete(ProgressComplete::Success)),
+        execution_progress
+            .get(&item_id!("b"))
+            .map(|snapshot| &snapshot.progress_status)
+    );
+    assert_eq!(
+        Some(&ProgressStatus::Initialized),
+        execution_progress
+            .get(&item_id!("a"))
+            .map(|snapshot| &snapshot.progress_status)
+    );
+}
+
+#[tokio::test]
+async fn present_renders_table_of_items() -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut cli_output = CliOutputBuilder::new_with_writer(&mut buffer)
+        .with_colorize(CliColorizeOpt::Never)
+        .build();
+    let mut presenter = CliMdPresenter::new(&mut cli_output);
+
+    let execution_progress = ExecutionProgress::from(IndexMap::from([
+        (
+            item_id!("upload"),
+            progress_tracker_snapshot(
+                ProgressStatus::Complete(ProgressComplete::Fail),
+                Some(ProgressLimit::Bytes(100)),
+                40,
+                Some("connection reset"),
+            ),
+        ),
+        (
+            item_id!("notify"),
+            progress_tracker_snapshot(ProgressStatus::ExecPending, None, 0, None),
+        ),
+    ]));
+    execution_progress.present(&mut presenter).await?;
+
+    let output = String::from_utf8(buffer)?;
+    assert_eq!(
+        "\
+        # Last execution\n\
+        \n\
+        | Item   | Status  | Progress | Elapsed   | Message          |\n\
+        |--------|---------|----------|-----------|------------------|\n\
+        | upload | failed  | 40/100   | 2 seconds | connection reset |\n\
+        | notify | pending | 0        | 2 seconds |                  |\n\
+        ",
+        output
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn present_renders_message_when_empty() -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut cli_output = CliOutputBuilder::new_with_writer(&mut buffer)
+        .with_colorize(CliColorizeOpt::Never)
+        .build();
+    let mut presenter = CliMdPresenter::new(&mut cli_output);
+
+    ExecutionProgress::new().present(&mut presenter).await?;
+
+    let output = String::from_utf8(buffer)?;
+    assert_eq!("# Last execution\n\nNo items were executed.", output);
+    Ok(())
+}
+
+#[test]
+fn serialize_deserialize_round_trip() -> Result<(), serde_yaml::Error> {
+    let execution_progress = ExecutionProgress::from(IndexMap::from([(
+        item_id!("upload"),
+        progress_tracker_snapshot(
+            ProgressStatus::Complete(ProgressComplete::Success),
+            Some(ProgressLimit::Steps(3)),
+            3,
+            None,
+        ),
+    )]));
+
+    let serialized = serde_yaml::to_string(&execution_progress)?;
+    let deserialized = serde_yaml::from_str::<ExecutionProgress>(&serialized)?;
+
+    assert_eq!(execution_progress, deserialized);
+    Ok(())
+}
+
+fn progress_tracker_snapshot(
+    progress_status: ProgressStatus,
+    progress_limit: Option<ProgressLimit>,
+    units_current: u64,
+    message: Option<&str>,
+) -> ProgressTrackerSnapshot {
+    ProgressTrackerSnapshot {
+        progress_status,
+        progress_limit,
+        units_current,
+        elapsed: Duration::from_secs(2),
+        message: message.map(String::from),
+        last_update_dt: ProgressTracker::new(ProgressBar::hidden()).last_update_dt(),
+        sub_trackers: IndexMap::new(),
+    }
+}
@@ -0,0 +1,85 @@
+use peace::{
+    cfg::{
+        item_id,
+        progress::{ProgressComplete, ProgressStatus, ProgressTracker, ProgressTrackerSnapshot},
+    },
+    resources::paths::ExecutionProgressFile,
+    rt_model::{
+        indicatif::ProgressBar, Error, ExecutionProgress, ExecutionProgressSerializer, IndexMap,
+        Storage,
+    },
+};
+
+use crate::PeaceTestError;
+
+#[tokio::test]
+async fn serialize_then_deserialize_opt_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let storage = Storage::new();
+    let execution_progress_file =
+        ExecutionProgressFile::new(tempdir.path().join("execution_progress.yaml"));
+    let mut progress_tracker = ProgressTracker::new(ProgressBar::hidden());
+    progress_tracker.set_progress_status(ProgressStatus::Complete(ProgressComplete::Success));
+    let execution_progress = ExecutionProgress::from(IndexMap::from([(
+        item_id!("a"),
+        ProgressTrackerSnapshot::from(&progress_tracker),
+    )]));
+
+    ExecutionProgressSerializer::<PeaceTestError>::serialize(
+        &storage,
+        &execution_progress,
+        &execution_progress_file,
+    )
+    .await?;
+    let execution_progress_deserialized =
+        ExecutionProgressSerializer::<PeaceTestError>::deserialize_opt(
+            &storage,
+            &execution_progress_file,
+        )
+        .await?;
+
+    assert_eq!(Some(execution_progress), execution_progress_deserialized);
+    Ok(())
+}
+
+#[tokio::test]
+async fn deserialize_opt_returns_none_when_file_not_exists(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let execution_progress_file =
+        ExecutionProgressFile::new(tempdir.path().join("execution_progress.yaml"));
+
+    let execution_progress = ExecutionProgressSerializer::<PeaceTestError>::deserialize_opt(
+        &Storage::new(),
+        &execution_progress_file,
+    )
+    .await?;
+
+    assert_eq!(None, execution_progress);
+    Ok(())
+}
+
+#[tokio::test]
+async fn deserialize_opt_returns_error_when_file_invalid() -> Result<(), Box<dyn std::error::Error>>
+{
+    let tempdir = tempfile::tempdir()?;
+    let execution_progress_file =
+        ExecutionProgressFile::new(tempdir.path().join("execution_progress.yaml"));
+    tokio::fs::write(&execution_progress_file, "a: 1\n").await?;
+
+    let error = ExecutionProgressSerializer::<PeaceTestError>::deserialize_opt(
+        &Storage::new(),
+        &execution_progress_file,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            PeaceTestError::PeaceRt(Error::ExecutionProgressDeserialize(_))
+        ),
+        "Expected error to be `Error::ExecutionProgressDeserialize(_)`, but was {error:?}"
+    );
+    Ok(())
+}
@@ -0,0 +1,161 @@
+use chrono::{DateTime, TimeZone, Utc};
+use peace::{
+    cfg::{item_id, ItemId},
+    cli::output::{CliColorizeOpt, CliMdPresenter, CliOutputBuilder},
+    fmt::Presentable,
+    rt_model::{ExecutionPhase, ExecutionTimeline, ItemExecutionSpan},
+};
+
+#[test]
+fn duration_is_from_first_start_to_last_end() {
+    let execution_timeline = execution_timeline();
+
+    assert_eq!(Some(at_ms(0)), execution_timeline.start());
+    assert_eq!(Some(at_ms(5000)), execution_timeline.end());
+    assert_eq!(
+        std::time::Duration::from_secs(5),
+        execution_timeline.duration()
+    );
+}
+
+#[test]
+fn max_parallelism_counts_overlapping_spans() {
+    let execution_timeline = execution_timeline();
+
+    assert_eq!(2, execution_timeline.max_parallelism());
+}
+
+#[test]
+fn max_parallelism_does_not_count_adjacent_spans() {
+    let execution_timeline = ExecutionTimeline::from(vec![
+        span(item_id!("a"), ExecutionPhase::Apply, 0, 1000),
+        span(item_id!("b"), ExecutionPhase::Apply, 1000, 2000),
+    ]);
+
+    assert_eq!(1, execution_timeline.max_parallelism());
+}
+
+#[test]
+fn critical_path_follows_spans_that_finished_before_each_start() {
+    let execution_timeline = execution_timeline();
+
+    let critical_path = execution_timeline
+        .critical_path()
+        .into_iter()
+        .map(|span| span.item_id.clone())
+        .collect::<Vec<_>>();
+
+    assert_eq!(vec![item_id!("a"), item_id!("c")], critical_path);
+}
+
+#[test]
+fn slowest_returns_longest_spans_first() {
+    let execution_timeline = execution_timeline();
+
+    let slowest = execution_timeline
+        .slowest(2)
+        .into_iter()
+        .map(|span| span.item_id.clone())
+        .collect::<Vec<_>>();
+
+    assert_eq!(vec![item_id!("c"), item_id!("a")], slowest);
+}
+
+#[test]
+fn to_html_positions_span_bars() {
+    let execution_timeline = execution_timeline();
+
+    let html = execution_timeline.to_html();
+
+    assert!(html.starts_with("<table class=\"execution_timeline\">"));
+    assert!(
+        html.contains(
+            "<td>c</td><td>apply</td>\
+            <td><div style=\"position: relative; height: 1em;\">\
+            <div style=\"position: absolute; left: 40.0%; width: 60.0%;"
+        ),
+        "html was:\n{html}"
+    );
+}
+
+#[tokio::test]
+async fn present_renders_timeline() -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut cli_output = CliOutputBuilder::new_with_writer(&mut buffer)
+        .with_colorize(CliColorizeOpt::Never)
+        .build();
+    let mut presenter = CliMdPresenter::new(&mut cli_output);
+
+    execution_timeline().present(&mut presenter).await?;
+
+    let output = String::from_utf8(buffer)?;
+    assert_eq!(
+        "\
+        # Execution timeline\n\
+        \n\
+        | Item | Phase    | Timeline                                 | Duration |\n\
+        |------|----------|------------------------------------------|----------|\n\
+        | b    | discover | ████████████···························· | 1.500s   |\n\
+        | a    | discover | ████████████████························ | 2.000s   |\n\
+        | c    | apply    | ················████████████████████████ | 3.000s   |\n\
+        \n\
+        Total: 5.000s, max parallelism: 2\n\
+        \n\
+        ## Critical path\n\
+        \n\
+        1. a discover (2.000s)\n\
+        2. c apply (3.000s)\n\
+        \n\
+        ## Slowest items\n\
+        \n\
+        1. c apply (3.000s)\n\
+        2. a discover (2.000s)\n\
+        3. b discover (1.500s)\n\
+        ",
+        output
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn present_renders_message_when_empty() -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut cli_output = CliOutputBuilder::new_with_writer(&mut buffer)
+        .with_colorize(CliColorizeOpt::Never)
+        .build();
+    let mut presenter = CliMdPresenter::new(&mut cli_output);
+
+    ExecutionTimeline::new().present(&mut presenter).await?;
+
+    let output = String::from_utf8(buffer)?;
+    assert_eq!("# Execution timeline\n\nNo items were executed.", output);
+    Ok(())
+}
+
+#[test]
+fn serialize_deserialize_round_trip() -> Result<(), serde_yaml::Error> {
+    let execution_timeline = execution_timeline();
+
+    let serialized = serde_yaml::to_string(&execution_timeline)?;
+    let deserialized = serde_yaml::from_str::<ExecutionTimeline>(&serialized)?;
+
+    assert_eq!(execution_timeline, deserialized);
+    Ok(())
+}
+
+/// `a` and `b` run in parallel, then `c` runs after `a`.
+fn execution_timeline() -> ExecutionTimeline {
+    ExecutionTimeline::from(vec![
+        span(item_id!("b"), ExecutionPhase::Discover, 0, 1500),
+        span(item_id!("a"), ExecutionPhase::Discover, 0, 2000),
+        span(item_id!("c"), ExecutionPhase::Apply, 2000, 5000),
+    ])
+}
+
+fn span(item_id: ItemId, phase: ExecutionPhase, start_ms: i64, end_ms: i64) -> ItemExecutionSpan {
+    ItemExecutionSpan::new(item_id, phase, at_ms(start_ms), at_ms(end_ms))
+}
+
+fn at_ms(ms: i64) -> DateTime<Utc> {
+    Utc.timestamp_millis_opt(1_700_000_000_000 + ms).unwrap()
+}
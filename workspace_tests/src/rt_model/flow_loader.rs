@@ -0,0 +1,151 @@
+use peace::{
+    cfg::{flow_id, item_id, ItemId},
+    flow_model::{FlowSpec, FlowSpecItem},
+    rt_model::{Error, FlowLoader, ItemRegistry},
+};
+
+use crate::{mock_item::MockItem, PeaceTestError, VecCopyItem};
+
+fn flow_loader() -> FlowLoader<PeaceTestError> {
+    let mut item_registry = ItemRegistry::<PeaceTestError>::new();
+    item_registry.register("vec_copy", |item_id| VecCopyItem::new(item_id).into());
+    item_registry.register("mock", |item_id| MockItem::<()>::new(item_id).into());
+
+    FlowLoader::new(item_registry)
+}
+
+#[test]
+fn load_yaml_builds_graph_with_dependencies() -> Result<(), Box<dyn std::error::Error>> {
+    let flow = flow_loader().load_yaml(
+        r#"flow_id: test_flow
+items:
+  - id: mock
+    type: mock
+    depends_on: [vec_copy]
+  - id: vec_copy
+    type: vec_copy
+"#,
+    )?;
+
+    let item_ids = flow
+        .graph()
+        .iter()
+        .map(|item| item.id().clone())
+        .collect::<Vec<ItemId>>();
+    assert_eq!(&flow_id!("test_flow"), flow.flow_id());
+    assert_eq!(vec![item_id!("vec_copy"), item_id!("mock")], item_ids);
+    Ok(())
+}
+
+#[test]
+fn load_returns_error_when_item_type_not_registered() {
+    let flow_spec = FlowSpec::new(
+        flow_id!("test_flow"),
+        vec![FlowSpecItem::new(
+            item_id!("download"),
+            String::from("file_download"),
+        )],
+    );
+
+    let error = flow_loader().load(&flow_spec).unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            Error::FlowSpecItemTypeNotRegistered {
+                item_id,
+                item_type,
+                item_types_registered,
+            }
+            if item_id == &item_id!("download")
+            && item_type == "file_download"
+            && item_types_registered == &["vec_copy", "mock"]
+        ),
+        "was {error:?}"
+    );
+}
+
+#[test]
+fn load_returns_error_when_dependency_not_found() {
+    let flow_spec = FlowSpec::new(
+        flow_id!("test_flow"),
+        vec![FlowSpecItem::new(item_id!("mock"), String::from("mock"))
+            .with_depends_on(vec![item_id!("vec_copy")])],
+    );
+
+    let error = flow_loader().load(&flow_spec).unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            Error::FlowSpecItemDependencyNotFound {
+                item_id,
+                dependency_id,
+            }
+            if item_id == &item_id!("mock")
+            && dependency_id == &item_id!("vec_copy")
+        ),
+        "was {error:?}"
+    );
+}
+
+#[test]
+fn load_returns_error_when_dependencies_cycle() {
+    let flow_spec = FlowSpec::new(
+        flow_id!("test_flow"),
+        vec![
+            FlowSpecItem::new(item_id!("mock"), String::from("mock"))
+                .with_depends_on(vec![item_id!("vec_copy")]),
+            FlowSpecItem::new(item_id!("vec_copy"), String::from("vec_copy"))
+                .with_depends_on(vec![item_id!("mock")]),
+        ],
+    );
+
+    let error = flow_loader().load(&flow_spec).unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            Error::FlowSpecItemDependencyCycle {
+                item_id,
+                dependency_id,
+            }
+            if item_id == &item_id!("vec_copy")
+            && dependency_id == &item_id!("mock")
+        ),
+        "was {error:?}"
+    );
+}
+
+#[test]
+fn load_returns_error_when_item_id_duplicate() {
+    let flow_spec = FlowSpec::new(
+        flow_id!("test_flow"),
+        vec![
+            FlowSpecItem::new(item_id!("mock"), String::from("mock")),
+            FlowSpecItem::new(item_id!("mock"), String::from("vec_copy")),
+        ],
+    );
+
+    let error = flow_loader().load(&flow_spec).unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            Error::FlowSpecItemIdDuplicate { item_id } if item_id == &item_id!("mock")
+        ),
+        "was {error:?}"
+    );
+}
+
+#[test]
+fn load_yaml_returns_error_when_spec_invalid() {
+    let error = flow_loader()
+        .load_yaml("flow_id: test_flow\nitems: 123\n")
+        .unwrap_err();
+
+    assert!(
+        matches!(&error, Error::FlowSpecDeserialize(_)),
+        "was {error:?}"
+    );
+}
@@ -0,0 +1,79 @@
+use peace::{
+    cfg::{flow_id, ItemId},
+    data::fn_graph::Edge,
+    flow_model::FlowEdge,
+    rt_model::{Flow, ItemGraphBuilder},
+};
+
+use crate::{mock_item::MockItem, PeaceTestError, VecCopyItem};
+
+#[test]
+fn metadata_contains_item_ids_type_names_and_edges() -> Result<(), Box<dyn std::error::Error>> {
+    let flow = test_flow()?;
+
+    let flow_metadata = flow.metadata();
+
+    assert_eq!(&flow_id!("test_flow"), &flow_metadata.flow_id);
+    assert_eq!(
+        vec![
+            (VecCopyItem::ID_DEFAULT.clone(), String::from("VecCopyItem")),
+            (MockItem::<()>::ID_DEFAULT.clone(), String::from("MockItem<()>")),
+        ],
+        flow_metadata
+            .items
+            .iter()
+            .map(|item_metadata| {
+                (item_metadata.item_id.clone(), item_metadata.item_type_name.clone())
+            })
+            .collect::<Vec<(ItemId, String)>>()
+    );
+    assert!(flow_metadata.edges.contains(&FlowEdge::new(
+        VecCopyItem::ID_DEFAULT.clone(),
+        MockItem::<()>::ID_DEFAULT.clone(),
+        Edge::Logic,
+    )));
+    assert!(flow_metadata.edges_ordering_only.is_empty());
+
+    Ok(())
+}
+
+#[cfg(feature = "item_schema")]
+#[test]
+fn metadata_contains_params_schema_for_items_that_provide_one()
+-> Result<(), Box<dyn std::error::Error>> {
+    let flow = test_flow()?;
+
+    let flow_metadata = flow.metadata();
+
+    let mock_item_metadata = flow_metadata
+        .items
+        .iter()
+        .find(|item_metadata| &item_metadata.item_id == MockItem::<()>::ID_DEFAULT)
+        .expect("Expected `MockItem` to be in the flow metadata.");
+    assert!(mock_item_metadata.params_schema.is_some());
+
+    Ok(())
+}
+
+#[test]
+fn flow_serializes_as_metadata() -> Result<(), Box<dyn std::error::Error>> {
+    let flow = test_flow()?;
+
+    assert_eq!(serde_yaml::to_string(&flow.metadata())?, serde_yaml::to_string(&flow)?);
+
+    Ok(())
+}
+
+fn test_flow() -> Result<Flow<PeaceTestError>, Box<dyn std::error::Error>> {
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        let [fn_id_vec_copy, fn_id_mock] = graph_builder.add_fns([
+            VecCopyItem::default().into(),
+            MockItem::<()>::default().into(),
+        ]);
+        graph_builder.add_logic_edge(fn_id_vec_copy, fn_id_mock)?;
+        graph_builder.build()
+    };
+
+    Ok(Flow::new(flow_id!("test_flow"), graph))
+}
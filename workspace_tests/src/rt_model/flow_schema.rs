@@ -0,0 +1,94 @@
+use peace::{
+    cfg::{flow_id, ItemSchema},
+    rt_model::{Flow, ItemGraphBuilder},
+    schemars::schema::Schema,
+};
+use serde_json::json;
+
+use crate::{mock_item::MockItem, PeaceTestError, VecCopyItem};
+
+#[test]
+fn serializes_as_combined_schema_document() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_schema = test_flow().schema();
+
+    assert_eq!(
+        json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "title": "test_flow",
+            "type": "object",
+            "properties": {
+                "mock": {
+                    "type": "object",
+                    "required": ["params", "state", "state_diff"],
+                    "properties": {
+                        "params": { "$ref": "#/definitions/MockSrc" },
+                        "state": { "$ref": "#/definitions/MockState" },
+                        "state_diff": { "$ref": "#/definitions/MockDiff" }
+                    }
+                },
+                "vec_copy": true
+            },
+            "definitions": {
+                "MockDiff": { "type": "integer", "format": "int16" },
+                "MockSrc": { "type": "integer", "format": "uint8", "minimum": 0.0 },
+                "MockState": { "type": "integer", "format": "uint8", "minimum": 0.0 }
+            }
+        }),
+        serde_json::to_value(&flow_schema)?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn item_schema_returns_schema_for_items_that_provide_one() {
+    let flow_schema = test_flow().schema();
+
+    assert_eq!(
+        Some(&ItemSchema {
+            params: schema_ref("MockSrc"),
+            state: schema_ref("MockState"),
+            state_diff: schema_ref("MockDiff"),
+        }),
+        flow_schema.item_schema(MockItem::<()>::ID_DEFAULT)
+    );
+}
+
+#[test]
+fn item_schema_returns_none_for_items_that_do_not_provide_one() {
+    let flow_schema = test_flow().schema();
+
+    assert!(flow_schema
+        .item_schemas()
+        .contains_key(VecCopyItem::ID_DEFAULT));
+    assert_eq!(None, flow_schema.item_schema(VecCopyItem::ID_DEFAULT));
+}
+
+#[test]
+fn flow_id_and_definitions() {
+    let flow_schema = test_flow().schema();
+
+    assert_eq!(&flow_id!("test_flow"), flow_schema.flow_id());
+    assert_eq!(
+        vec!["MockDiff", "MockSrc", "MockState"],
+        flow_schema
+            .definitions()
+            .keys()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+    );
+}
+
+fn schema_ref(name: &str) -> Schema {
+    Schema::new_ref(format!("#/definitions/{name}"))
+}
+
+fn test_flow() -> Flow<PeaceTestError> {
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    Flow::new(flow_id!("test_flow"), graph)
+}
@@ -0,0 +1,46 @@
+use std::time::Duration;
+
+use peace::rt_model::HeartbeatPolicy;
+
+#[test]
+fn new_does_not_require_heartbeats() {
+    let heartbeat_policy = HeartbeatPolicy::new();
+
+    assert_eq!(None, heartbeat_policy.interval());
+    assert!(!heartbeat_policy.cancel_on_stall());
+    assert_eq!(HeartbeatPolicy::default(), heartbeat_policy);
+}
+
+#[test]
+fn with_interval_sets_interval() {
+    let heartbeat_policy = HeartbeatPolicy::new().with_interval(Duration::from_secs(30));
+
+    assert_eq!(Some(Duration::from_secs(30)), heartbeat_policy.interval());
+    assert!(!heartbeat_policy.cancel_on_stall());
+}
+
+#[test]
+fn with_cancel_on_stall_sets_cancel_on_stall() {
+    let heartbeat_policy = HeartbeatPolicy::new()
+        .with_interval(Duration::from_secs(30))
+        .with_cancel_on_stall(true);
+
+    assert!(heartbeat_policy.cancel_on_stall());
+}
+
+#[test]
+fn clone() {
+    let heartbeat_policy = HeartbeatPolicy::new().with_interval(Duration::from_secs(30));
+
+    assert_eq!(heartbeat_policy, Clone::clone(&heartbeat_policy));
+}
+
+#[test]
+fn debug() {
+    let heartbeat_policy = HeartbeatPolicy::new().with_interval(Duration::from_secs(30));
+
+    assert_eq!(
+        "HeartbeatPolicy { interval: Some(30s), cancel_on_stall: false }",
+        format!("{heartbeat_policy:?}")
+    );
+}
@@ -0,0 +1,111 @@
+use peace::{
+    cfg::{item_id, State},
+    resources::{internal::StatesMut, states::StatesCurrentStored},
+    rt_model::{output::OutputWrite, InMemoryOutput, InMemoryOutputEvent},
+};
+
+use crate::PeaceTestError;
+
+#[tokio::test]
+async fn present_records_presented_event_and_text() -> Result<(), PeaceTestError> {
+    let mut output = InMemoryOutput::new();
+
+    <_ as OutputWrite<PeaceTestError>>::present(&mut output, String::from("hello")).await?;
+
+    assert_eq!("hello\n", output.text());
+    assert_eq!(
+        &[InMemoryOutputEvent::Presented {
+            type_name: String::from("alloc::string::String"),
+            value: serde_yaml::Value::String(String::from("hello")),
+        }],
+        output.events()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn presented_returns_values_of_requested_type_only() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut output = InMemoryOutput::new();
+    let states_current_stored = {
+        let mut states = StatesMut::new();
+        states.insert(item_id!("item_0"), State::new(1u8, true));
+        StatesCurrentStored::from(states)
+    };
+
+    <_ as OutputWrite<PeaceTestError>>::present(&mut output, String::from("first")).await?;
+    <_ as OutputWrite<PeaceTestError>>::present(&mut output, &states_current_stored).await?;
+    <_ as OutputWrite<PeaceTestError>>::present(&mut output, String::from("second")).await?;
+
+    assert_eq!(
+        vec![String::from("first"), String::from("second")],
+        output.presented::<String>()?
+    );
+    assert_eq!(
+        Some(String::from("second")),
+        output.presented_last::<String>()?
+    );
+    assert_eq!(1, output.presented_values::<StatesCurrentStored>().count());
+    assert_eq!("second\n", output.into_inner());
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_err_records_error_event_and_text() -> Result<(), PeaceTestError> {
+    let mut output = InMemoryOutput::new();
+    let error = PeaceTestError::from(peace::rt_model::Error::StatesCurrentDiscoverRequired);
+
+    <_ as OutputWrite<PeaceTestError>>::write_err(&mut output, &error).await?;
+
+    let message = error.to_string();
+    assert_eq!(vec![message.as_str()], output.errors().collect::<Vec<_>>());
+    assert_eq!(format!("{message}\n"), output.text());
+    assert_eq!(0, output.presented_values::<String>().count());
+    Ok(())
+}
+
+#[cfg(feature = "output_progress")]
+#[tokio::test]
+async fn progress_records_begin_update_and_end_events() {
+    use peace::{
+        cfg::progress::{
+            ProgressDelta, ProgressMsgUpdate, ProgressTracker, ProgressUpdate, ProgressUpdateAndId,
+        },
+        rt_model::{
+            indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget},
+            CmdProgressTracker, IndexMap,
+        },
+    };
+
+    let mut output = InMemoryOutput::new();
+    let multi_progress = MultiProgress::with_draw_target(ProgressDrawTarget::hidden());
+    let progress_tracker = ProgressTracker::new(multi_progress.add(ProgressBar::hidden()));
+    let progress_update_and_id = ProgressUpdateAndId {
+        item_id: item_id!("item_0"),
+        progress_update: ProgressUpdate::Delta(ProgressDelta::Inc(1)),
+        msg_update: ProgressMsgUpdate::NoChange,
+    };
+    let cmd_progress_tracker = CmdProgressTracker::new(multi_progress, IndexMap::new());
+
+    <_ as OutputWrite<PeaceTestError>>::progress_begin(&mut output, &cmd_progress_tracker).await;
+    <_ as OutputWrite<PeaceTestError>>::progress_update(
+        &mut output,
+        &progress_tracker,
+        &progress_update_and_id,
+    )
+    .await;
+    <_ as OutputWrite<PeaceTestError>>::progress_end(&mut output, &cmd_progress_tracker).await;
+
+    assert_eq!(
+        vec![&progress_update_and_id],
+        output.progress_updates().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        &[
+            InMemoryOutputEvent::ProgressBegin,
+            InMemoryOutputEvent::ProgressUpdate(progress_update_and_id.clone()),
+            InMemoryOutputEvent::ProgressEnd,
+        ],
+        output.events()
+    );
+}
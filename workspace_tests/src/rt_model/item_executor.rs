@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use peace::rt_model::{
+    ItemExecutor, ItemExecutorConcurrent, ItemExecutorLimited, ItemExecutorYielding,
+};
+
+#[test]
+fn concurrent_concurrency_max_defaults_to_concurrency_max_default() {
+    assert_eq!(
+        ItemExecutorConcurrent::CONCURRENCY_MAX_DEFAULT,
+        ItemExecutorConcurrent::default().concurrency_max()
+    );
+}
+
+#[test]
+fn concurrent_concurrency_max_zero_is_treated_as_one() {
+    assert_eq!(1, ItemExecutorConcurrent::new(0).concurrency_max());
+    assert_eq!(3, ItemExecutorConcurrent::new(3).concurrency_max());
+}
+
+#[tokio::test]
+async fn yielding_item_begin_completes() {
+    let item_executor = ItemExecutorYielding::default();
+
+    item_executor.item_begin().await;
+
+    assert_eq!(
+        ItemExecutorConcurrent::CONCURRENCY_MAX_DEFAULT,
+        item_executor.concurrency_max()
+    );
+}
+
+#[test]
+fn yielding_item_begin_is_pending_once() {
+    let item_executor = ItemExecutorYielding::default();
+    let mut item_begin = item_executor.item_begin();
+    let waker = futures::task::noop_waker();
+    let mut cx = std::task::Context::from_waker(&waker);
+
+    assert!(item_begin.as_mut().poll(&mut cx).is_pending());
+    assert!(item_begin.as_mut().poll(&mut cx).is_ready());
+}
+
+#[test]
+fn limited_concurrency_max_overrides_item_executor() {
+    let item_executor = ItemExecutorLimited::new(Arc::new(ItemExecutorYielding::new(8)), 2);
+
+    assert_eq!(2, item_executor.concurrency_max());
+    assert_eq!(8, item_executor.item_executor().concurrency_max());
+    assert_eq!(
+        1,
+        ItemExecutorLimited::new(Arc::new(ItemExecutorConcurrent::default()), 0).concurrency_max()
+    );
+}
+
+#[test]
+fn limited_item_begin_uses_item_executor() {
+    let item_executor = ItemExecutorLimited::new(Arc::new(ItemExecutorYielding::default()), 1);
+    let mut item_begin = item_executor.item_begin();
+    let waker = futures::task::noop_waker();
+    let mut cx = std::task::Context::from_waker(&waker);
+
+    assert!(item_begin.as_mut().poll(&mut cx).is_pending());
+    assert!(item_begin.as_mut().poll(&mut cx).is_ready());
+}
@@ -1,14 +1,17 @@
 use peace::{
     cfg::item_id,
+    params::{ParamsSpec, ParamsSpecs},
     resources::{
         internal::StatesMut,
         states::{StatesCurrent, StatesSerde},
     },
-    rt_model::{fn_graph::FnGraph, ItemBoxed, ItemGraph, ItemGraphBuilder},
+    rt_model::{
+        fn_graph::FnGraph, CleanOrderingOverride, Error, ItemBoxed, ItemGraph, ItemGraphBuilder,
+    },
 };
 
 use crate::{
-    mock_item::{MockItem, MockState},
+    mock_item::{MockItem, MockSrc, MockState},
     vec_copy_item::VecCopyState,
     PeaceTestError, VecCopyItem,
 };
@@ -146,3 +149,238 @@ fn states_serde_from_item_graph_and_states() {
     assert_eq!(Some(&three), states_serde_keys.next());
     assert_eq!(None, states_serde_keys.next());
 }
+
+#[test]
+fn params_specs_mapping_fns_validate_returns_ok_when_mapped_from_predecessor(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        let [fn_id_vec_copy, fn_id_mock] = item_graph_builder.add_fns([
+            VecCopyItem::default().into(),
+            MockItem::<()>::default().into(),
+        ]);
+        item_graph_builder.add_logic_edge(fn_id_vec_copy, fn_id_mock)?;
+        item_graph_builder.build()
+    };
+
+    item_graph.params_specs_mapping_fns_validate(&params_specs_mock_from_vec_copy_state())?;
+
+    Ok(())
+}
+
+#[test]
+fn params_specs_mapping_fns_validate_returns_ok_when_no_item_has_mapped_state(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        item_graph_builder.add_fn(MockItem::<()>::default().into());
+        item_graph_builder.build()
+    };
+
+    item_graph.params_specs_mapping_fns_validate(&params_specs_mock_from_vec_copy_state())?;
+
+    Ok(())
+}
+
+#[test]
+fn params_specs_mapping_fns_validate_returns_err_when_edge_missing() {
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        item_graph_builder.add_fns([
+            VecCopyItem::default().into(),
+            MockItem::<()>::default().into(),
+        ]);
+        item_graph_builder.build()
+    };
+
+    let error = item_graph
+        .params_specs_mapping_fns_validate(&params_specs_mock_from_vec_copy_state())
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            Error::ParamsMappingFnEdgeMissing { item_id, item_id_from, state_type }
+            if item_id == MockItem::<()>::ID_DEFAULT
+            && item_id_from == VecCopyItem::ID_DEFAULT
+            && state_type == std::any::type_name::<VecCopyState>()
+        ),
+        "was {error:?}"
+    );
+}
+
+#[test]
+fn params_specs_mapping_fns_validate_returns_err_when_mapped_from_successor(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        let [fn_id_mock, fn_id_vec_copy] = item_graph_builder.add_fns([
+            MockItem::<()>::default().into(),
+            VecCopyItem::default().into(),
+        ]);
+        item_graph_builder.add_logic_edge(fn_id_mock, fn_id_vec_copy)?;
+        item_graph_builder.build()
+    };
+
+    let error = item_graph
+        .params_specs_mapping_fns_validate(&params_specs_mock_from_vec_copy_state())
+        .unwrap_err();
+
+    assert!(
+        matches!(&error, Error::ParamsMappingFnEdgeMissing { .. }),
+        "was {error:?}"
+    );
+    Ok(())
+}
+
+#[test]
+fn item_ids_with_successors_returns_item_ids_and_transitive_successors(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        let [fn_id_vec_copy, fn_id_mock_0, fn_id_mock_1] = item_graph_builder.add_fns([
+            VecCopyItem::default().into(),
+            MockItem::<()>::new(item_id!("mock_0")).into(),
+            MockItem::<()>::new(item_id!("mock_1")).into(),
+        ]);
+        item_graph_builder.add_logic_edges([
+            (fn_id_vec_copy, fn_id_mock_0),
+            (fn_id_mock_0, fn_id_mock_1),
+        ])?;
+        item_graph_builder.build()
+    };
+
+    assert_eq!(
+        vec![item_id!("mock_0"), item_id!("mock_1")],
+        item_graph.item_ids_with_successors(&[item_id!("mock_0")])
+    );
+    assert_eq!(
+        vec![VecCopyItem::ID_DEFAULT.clone(), item_id!("mock_0"), item_id!("mock_1")],
+        item_graph.item_ids_with_successors(&[VecCopyItem::ID_DEFAULT.clone()])
+    );
+    assert!(item_graph.item_ids_with_successors(&[item_id!("non_existent")]).is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn graph_clean_build_reverses_overridden_edges() -> Result<(), Box<dyn std::error::Error>> {
+    let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+    let [fn_id_vec_copy, fn_id_mock_0, fn_id_mock_1] = item_graph_builder.add_fns([
+        VecCopyItem::default().into(),
+        MockItem::<()>::new(item_id!("mock_0")).into(),
+        MockItem::<()>::new(item_id!("mock_1")).into(),
+    ]);
+    item_graph_builder.add_logic_edges([
+        (fn_id_vec_copy, fn_id_mock_0),
+        (fn_id_vec_copy, fn_id_mock_1),
+    ])?;
+    let item_graph = item_graph_builder.build();
+
+    let graph_clean = item_graph.graph_clean_build(&[CleanOrderingOverride::new(
+        VecCopyItem::ID_DEFAULT.clone(),
+        item_id!("mock_0"),
+    )])?;
+
+    assert!(graph_clean.graph.find_edge(fn_id_vec_copy, fn_id_mock_0).is_none());
+    assert!(graph_clean.graph.find_edge(fn_id_mock_0, fn_id_vec_copy).is_some());
+    assert!(graph_clean.graph.find_edge(fn_id_vec_copy, fn_id_mock_1).is_some());
+    // The flow's graph is unchanged.
+    assert!(item_graph.graph.find_edge(fn_id_vec_copy, fn_id_mock_0).is_some());
+
+    Ok(())
+}
+
+#[test]
+fn graph_clean_build_reverses_overridden_data_edges() -> Result<(), Box<dyn std::error::Error>> {
+    // Both items write to `VecB`, so they are ordered by a data edge.
+    let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+    let [fn_id_vec_copy_0, fn_id_vec_copy_1] = item_graph_builder.add_fns([
+        VecCopyItem::new(item_id!("vec_copy_0")).into(),
+        VecCopyItem::new(item_id!("vec_copy_1")).into(),
+    ]);
+    let item_graph = item_graph_builder.build();
+    assert!(item_graph.graph.find_edge(fn_id_vec_copy_0, fn_id_vec_copy_1).is_some());
+
+    let graph_clean = item_graph.graph_clean_build(&[CleanOrderingOverride::new(
+        item_id!("vec_copy_0"),
+        item_id!("vec_copy_1"),
+    )])?;
+
+    assert!(graph_clean.graph.find_edge(fn_id_vec_copy_0, fn_id_vec_copy_1).is_none());
+    assert!(graph_clean.graph.find_edge(fn_id_vec_copy_1, fn_id_vec_copy_0).is_some());
+    assert_eq!(1, graph_clean.graph.edge_count());
+
+    Ok(())
+}
+
+#[test]
+fn graph_clean_build_ignores_overrides_for_items_not_in_graph(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+    let [fn_id_vec_copy, fn_id_mock] = item_graph_builder.add_fns([
+        VecCopyItem::default().into(),
+        MockItem::<()>::default().into(),
+    ]);
+    item_graph_builder.add_logic_edge(fn_id_vec_copy, fn_id_mock)?;
+    let item_graph = item_graph_builder.build();
+
+    let graph_clean = item_graph.graph_clean_build(&[CleanOrderingOverride::new(
+        VecCopyItem::ID_DEFAULT.clone(),
+        item_id!("non_existent"),
+    )])?;
+
+    assert!(graph_clean.graph.find_edge(fn_id_vec_copy, fn_id_mock).is_some());
+    assert_eq!(1, graph_clean.graph.edge_count());
+
+    Ok(())
+}
+
+#[test]
+fn graph_clean_build_returns_err_when_override_contradicts_other_edges(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        let [fn_id_vec_copy, fn_id_mock_0, fn_id_mock_1] = item_graph_builder.add_fns([
+            VecCopyItem::default().into(),
+            MockItem::<()>::new(item_id!("mock_0")).into(),
+            MockItem::<()>::new(item_id!("mock_1")).into(),
+        ]);
+        item_graph_builder.add_logic_edges([
+            (fn_id_vec_copy, fn_id_mock_0),
+            (fn_id_mock_0, fn_id_mock_1),
+        ])?;
+        item_graph_builder.build()
+    };
+
+    let error = item_graph
+        .graph_clean_build(&[CleanOrderingOverride::new(
+            VecCopyItem::ID_DEFAULT.clone(),
+            item_id!("mock_1"),
+        )])
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            Error::CleanOrderingOverrideCyclic { item_id_before, item_id_after }
+            if item_id_before == VecCopyItem::ID_DEFAULT
+            && item_id_after == &item_id!("mock_1")
+        ),
+        "was {error:?}"
+    );
+    Ok(())
+}
+
+/// Returns params specs where `MockItem`'s params are mapped from
+/// `VecCopyItem`'s state.
+fn params_specs_mock_from_vec_copy_state() -> ParamsSpecs {
+    let mut params_specs = ParamsSpecs::new();
+    params_specs.insert(
+        MockItem::<()>::ID_DEFAULT.clone(),
+        ParamsSpec::<MockSrc>::from_map(None, |vec_copy_state: &VecCopyState| {
+            u8::try_from(vec_copy_state.len()).ok().map(MockSrc)
+        }),
+    );
+    params_specs
+}
@@ -1,4 +1,15 @@
-use peace::rt_model::{fn_graph::FnGraphBuilder, Error, ItemBoxed, ItemGraphBuilder};
+use peace::{
+    cfg::item_id,
+    rt_model::{
+        fn_graph::{Edge, FnGraphBuilder},
+        Error, ItemBoxed, ItemEdgeKind, ItemGraphBuilder,
+    },
+};
+
+use crate::{
+    mock_item::{MockItem, MockReadsVecCopyOutput},
+    PeaceTestError, VecCopyItem,
+};
 
 #[test]
 fn debug() {
@@ -31,3 +42,177 @@ fn from() {
     let fn_graph_builder = FnGraphBuilder::<ItemBoxed<Error>>::new();
     let _builder = ItemGraphBuilder::<Error>::from(fn_graph_builder);
 }
+
+#[test]
+fn try_build_returns_ok_when_inputs_output_by_predecessor()
+-> Result<(), Box<dyn std::error::Error>> {
+    let mut builder = ItemGraphBuilder::<PeaceTestError>::new();
+    let [fn_id_vec_copy, fn_id_mock] = builder.add_fns([
+        VecCopyItem::default().into(),
+        MockItem::<MockReadsVecCopyOutput>::default().into(),
+    ]);
+    builder.add_logic_edge(fn_id_vec_copy, fn_id_mock)?;
+
+    let item_graph = builder.try_build()?;
+
+    assert_eq!(2, item_graph.iter_insertion().len());
+    Ok(())
+}
+
+#[test]
+fn try_build_returns_ok_when_inputs_output_by_transitive_predecessor()
+-> Result<(), Box<dyn std::error::Error>> {
+    let mut builder = ItemGraphBuilder::<PeaceTestError>::new();
+    let [fn_id_vec_copy, fn_id_mock_0, fn_id_mock_1] = builder.add_fns([
+        VecCopyItem::default().into(),
+        MockItem::<()>::new(item_id!("mock_0")).into(),
+        MockItem::<MockReadsVecCopyOutput>::new(item_id!("mock_1")).into(),
+    ]);
+    builder.add_logic_edges([(fn_id_vec_copy, fn_id_mock_0), (fn_id_mock_0, fn_id_mock_1)])?;
+
+    let item_graph = builder.try_build()?;
+
+    assert_eq!(3, item_graph.iter_insertion().len());
+    Ok(())
+}
+
+#[test]
+fn try_build_returns_err_when_inputs_not_output() {
+    let mut builder = ItemGraphBuilder::<PeaceTestError>::new();
+    builder.add_fn(MockItem::<MockReadsVecCopyOutput>::default().into());
+
+    let error = builder.try_build().unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            Error::ItemInputNotProvided { item_id, input_type }
+            if item_id == MockItem::<MockReadsVecCopyOutput>::ID_DEFAULT
+            && input_type == std::any::type_name::<crate::VecCopyOutput>()
+        ),
+        "was {error:?}"
+    );
+}
+
+#[test]
+fn try_build_returns_err_when_inputs_output_by_non_predecessor() {
+    let mut builder = ItemGraphBuilder::<PeaceTestError>::new();
+    builder.add_fns([
+        VecCopyItem::default().into(),
+        MockItem::<MockReadsVecCopyOutput>::default().into(),
+    ]);
+
+    let error = builder.try_build().unwrap_err();
+
+    assert!(
+        matches!(&error, Error::ItemInputNotProvided { .. }),
+        "was {error:?}"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Failed to build item graph")]
+fn build_panics_when_inputs_not_output() {
+    let mut builder = ItemGraphBuilder::<PeaceTestError>::new();
+    builder.add_fn(MockItem::<MockReadsVecCopyOutput>::default().into());
+
+    let _item_graph = builder.build();
+}
+
+#[test]
+fn add_fn_for_each_adds_item_per_instance_key() {
+    let mut builder = ItemGraphBuilder::<PeaceTestError>::new();
+    let fn_ids = builder.add_fn_for_each(VecCopyItem::ID_DEFAULT, ["a", "b.c"], VecCopyItem::new);
+
+    let item_graph = builder.build();
+    let item_ids = item_graph
+        .iter_insertion()
+        .map(|item| item.id().clone())
+        .collect::<Vec<_>>();
+
+    assert_eq!(2, fn_ids.len());
+    assert_eq!(
+        vec![item_id!("vec_copy_a"), item_id!("vec_copy_b_c")],
+        item_ids
+    );
+}
+
+#[test]
+fn add_edge_ordering_only_is_excluded_from_graph_discover() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut builder = ItemGraphBuilder::<PeaceTestError>::new();
+    let [fn_id_a, fn_id_b, fn_id_c] = builder.add_fns([
+        MockItem::<()>::new(item_id!("a")).into(),
+        MockItem::<()>::new(item_id!("b")).into(),
+        MockItem::<()>::new(item_id!("c")).into(),
+    ]);
+    builder.add_edge(fn_id_a, fn_id_b, ItemEdgeKind::OrderingOnly)?;
+    builder.add_edge(fn_id_b, fn_id_c, ItemEdgeKind::DataDependency)?;
+
+    let item_graph = builder.try_build()?;
+    let graph_discover = item_graph.graph_discover();
+    let edge_weight = |graph: &peace::rt_model::fn_graph::FnGraph<_>, fn_id_from, fn_id_to| {
+        graph
+            .find_edge(fn_id_from, fn_id_to)
+            .map(|edge_index| graph[edge_index])
+    };
+
+    assert_eq!(&[(fn_id_a, fn_id_b)], item_graph.edges_ordering_only());
+    assert_eq!(
+        Some(Edge::Logic),
+        edge_weight(&item_graph, fn_id_a, fn_id_b)
+    );
+    assert_eq!(
+        Some(Edge::Logic),
+        edge_weight(&item_graph, fn_id_b, fn_id_c)
+    );
+    assert_ne!(
+        Some(Edge::Logic),
+        edge_weight(graph_discover, fn_id_a, fn_id_b)
+    );
+    assert_eq!(
+        Some(Edge::Logic),
+        edge_weight(graph_discover, fn_id_b, fn_id_c)
+    );
+    assert_eq!(3, graph_discover.iter_insertion().len());
+    Ok(())
+}
+
+#[test]
+fn add_edge_retains_last_edge_kind() -> Result<(), Box<dyn std::error::Error>> {
+    let mut builder = ItemGraphBuilder::<PeaceTestError>::new();
+    let [fn_id_a, fn_id_b] = builder.add_fns([
+        MockItem::<()>::new(item_id!("a")).into(),
+        MockItem::<()>::new(item_id!("b")).into(),
+    ]);
+    builder.add_edge(fn_id_a, fn_id_b, ItemEdgeKind::OrderingOnly)?;
+    builder.add_edge(fn_id_a, fn_id_b, ItemEdgeKind::DataDependency)?;
+
+    let item_graph = builder.try_build()?;
+
+    assert!(item_graph.edges_ordering_only().is_empty());
+    assert!(item_graph
+        .graph_discover()
+        .find_edge(fn_id_a, fn_id_b)
+        .is_some());
+    Ok(())
+}
+
+#[test]
+fn try_build_returns_err_when_inputs_output_by_ordering_only_predecessor(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut builder = ItemGraphBuilder::<PeaceTestError>::new();
+    let [fn_id_vec_copy, fn_id_mock] = builder.add_fns([
+        VecCopyItem::default().into(),
+        MockItem::<MockReadsVecCopyOutput>::default().into(),
+    ]);
+    builder.add_edge(fn_id_vec_copy, fn_id_mock, ItemEdgeKind::OrderingOnly)?;
+
+    let error = builder.try_build().unwrap_err();
+
+    assert!(
+        matches!(&error, Error::ItemInputNotProvided { .. }),
+        "was {error:?}"
+    );
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use peace::{
+    cfg::item_id,
+    resources::paths::{FlowDir, ItemLogsDir},
+    rt_model::ItemLogLayer,
+};
+use tempfile::TempDir;
+use tracing_subscriber::layer::SubscriberExt;
+
+#[test]
+fn writes_event_within_item_span_to_item_log_file() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let item_logs_dir = item_logs_dir(&tempdir);
+    let subscriber = tracing_subscriber::registry().with(ItemLogLayer::new());
+
+    tracing::subscriber::with_default(subscriber, || {
+        let cmd_execution_span =
+            tracing::info_span!("cmd_execution", item_logs_dir = %item_logs_dir.display());
+        let _cmd_execution_guard = cmd_execution_span.enter();
+        let item_span = tracing::info_span!("state_current", item_id = "item_0");
+        let _item_guard = item_span.enter();
+
+        tracing::info!(attempt = 1, "connecting to server");
+    });
+
+    let item_log = std::fs::read_to_string(item_logs_dir.item_log_file(&item_id!("item_0")))?;
+    assert!(
+        item_log.ends_with(
+            " INFO workspace_tests::rt_model::item_log_layer: connecting to server attempt=1\n"
+        ),
+        "Expected item log to contain event, item log was: {item_log}"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn writes_event_when_item_logs_dir_is_recorded_later() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let item_logs_dir = item_logs_dir(&tempdir);
+    let subscriber = tracing_subscriber::registry().with(ItemLogLayer::new());
+
+    tracing::subscriber::with_default(subscriber, || {
+        let cmd_execution_span =
+            tracing::info_span!("cmd_execution", item_logs_dir = tracing::field::Empty);
+        cmd_execution_span.record(
+            ItemLogLayer::FIELD_ITEM_LOGS_DIR,
+            tracing::field::display(item_logs_dir.display()),
+        );
+        let _cmd_execution_guard = cmd_execution_span.enter();
+        let item_span = tracing::info_span!("apply_exec", item_id = "item_0");
+        let _item_guard = item_span.enter();
+
+        tracing::warn!("retrying");
+    });
+
+    let item_log = std::fs::read_to_string(item_logs_dir.item_log_file(&item_id!("item_0")))?;
+    assert!(
+        item_log.ends_with(" WARN workspace_tests::rt_model::item_log_layer: retrying\n"),
+        "Expected item log to contain event, item log was: {item_log}"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn does_not_write_event_outside_item_span() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let item_logs_dir = item_logs_dir(&tempdir);
+    let subscriber = tracing_subscriber::registry().with(ItemLogLayer::new());
+
+    tracing::subscriber::with_default(subscriber, || {
+        let cmd_execution_span =
+            tracing::info_span!("cmd_execution", item_logs_dir = %item_logs_dir.display());
+        let _cmd_execution_guard = cmd_execution_span.enter();
+
+        tracing::info!("not within an item");
+    });
+
+    assert!(!item_logs_dir.exists());
+
+    Ok(())
+}
+
+#[test]
+fn does_not_write_event_outside_cmd_execution() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let item_logs_dir = item_logs_dir(&tempdir);
+    let subscriber = tracing_subscriber::registry().with(ItemLogLayer::new());
+
+    tracing::subscriber::with_default(subscriber, || {
+        let item_span = tracing::info_span!("state_current", item_id = "item_0");
+        let _item_guard = item_span.enter();
+
+        tracing::info!("not within a command execution");
+    });
+
+    assert!(!item_logs_dir.exists());
+
+    Ok(())
+}
+
+#[test]
+fn does_not_write_event_when_item_logs_dir_cannot_be_created(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let item_logs_dir = item_logs_dir(&tempdir);
+    // A file in place of the flow directory prevents the logs directory from
+    // being created.
+    std::fs::write(tempdir.path().join("flow_dir"), "")?;
+    let subscriber = tracing_subscriber::registry().with(ItemLogLayer::new());
+
+    tracing::subscriber::with_default(subscriber, || {
+        let cmd_execution_span =
+            tracing::info_span!("cmd_execution", item_logs_dir = %item_logs_dir.display());
+        let _cmd_execution_guard = cmd_execution_span.enter();
+        let item_span = tracing::info_span!("state_current", item_id = "item_0");
+        let _item_guard = item_span.enter();
+
+        tracing::info!("connecting to server");
+        tracing::info!("retrying");
+    });
+
+    assert!(!item_logs_dir.exists());
+
+    Ok(())
+}
+
+#[test]
+fn item_logs_dir_is_within_flow_dir_logs() {
+    let flow_dir = FlowDir::new("flow_dir".into());
+    let item_logs_dir = ItemLogsDir::from((&flow_dir, "2022-08-21T20_50_43.123"));
+
+    assert_eq!(
+        std::path::Path::new("flow_dir/logs/2022-08-21T20_50_43.123/item_0.log"),
+        item_logs_dir.item_log_file(&item_id!("item_0"))
+    );
+}
+
+fn item_logs_dir(tempdir: &TempDir) -> ItemLogsDir {
+    let flow_dir = FlowDir::new(tempdir.path().join("flow_dir"));
+    ItemLogsDir::from((&flow_dir, "2022-08-21T20_50_43.123"))
+}
@@ -1,8 +1,13 @@
 use diff::{VecDiff, VecDiffType};
 use peace::{
-    cfg::{item_id, ApplyCheck, FnCtx},
-    data::marker::{ApplyDry, Clean, Current, Goal},
-    params::{ParamsSpec, ParamsSpecs},
+    cfg::{flow_id, item_id, profile, ApplyCheck, ApplyCheckReason, ApplyCtx, FnCtx, ItemIoType},
+    data::{
+        marker::{ApplyDry, Clean, Current, Goal},
+        ItemOutput,
+    },
+    params::{
+        ParamsSpec, ParamsSpecs, ValueResolution, ValueResolutionMode, ValueResolutionSource,
+    },
     resources::{
         internal::StatesMut,
         resources::ts::SetUp,
@@ -21,8 +26,9 @@ cfg_if::cfg_if! {
 }
 
 use crate::{
+    mock_item::{MockItem, MockItemError, MockReadsVecCopyOutput},
     PeaceTestError, VecA, VecB, VecCopyDiff, VecCopyError, VecCopyItem, VecCopyItemWrapper,
-    VecCopyState,
+    VecCopyOutput, VecCopyState,
 };
 
 #[test]
@@ -192,6 +198,27 @@ fn state_eq_returns_err_both_when_both_fail_downcast() {
     })();
 }
 
+#[test]
+fn error_help_returns_item_error_help() {
+    let item_wrapper = ItemWrapper::<_, PeaceTestError>::from(MockItem::<()>::default());
+    let error = PeaceTestError::from(MockItemError::Synthetic(String::from("synthetic")));
+
+    assert_eq!(
+        vec![String::from("Synthetic errors can be retried.")],
+        item_wrapper.error_help(&error)
+    );
+}
+
+#[test]
+fn error_help_returns_empty_when_error_is_not_from_item() {
+    let item_wrapper = ItemWrapper::<_, PeaceTestError>::from(MockItem::<()>::default());
+    let error = PeaceTestError::from(PeaceRtError::ParamsSpecNotFound {
+        item_id: item_id!("mock"),
+    });
+
+    assert!(item_wrapper.error_help(&error).is_empty());
+}
+
 #[tokio::test]
 async fn deref_to_dyn_item_rt() {
     let vec_copy_item = VecCopyItem::default();
@@ -224,7 +251,119 @@ async fn setup() -> Result<(), Box<dyn std::error::Error>> {
     assert!(resources.borrow::<Current<VecCopyState>>().is_none());
     assert!(resources.try_borrow::<Goal<VecCopyState>>().is_ok());
     assert!(resources.borrow::<Goal<VecCopyState>>().is_none());
+    // Automatic `ItemOutput<T>` insertion.
+    assert!(resources.try_borrow::<ItemOutput<VecCopyOutput>>().is_ok());
+    assert!(resources.borrow::<ItemOutput<VecCopyOutput>>().is_none());
+
+    Ok(())
+}
+
+#[test]
+fn output_types_returns_item_outputs() {
+    let item_wrapper = ItemWrapper::<_, PeaceTestError>::from(VecCopyItem::default());
+
+    assert_eq!(
+        vec![ItemIoType::of::<VecCopyOutput>()],
+        <dyn ItemRt<_>>::output_types(&item_wrapper)
+    );
+    assert!(<dyn ItemRt<_>>::input_types(&item_wrapper).is_empty());
+}
+
+#[test]
+fn input_types_returns_item_inputs() {
+    let item_wrapper =
+        ItemWrapper::<_, PeaceTestError>::from(MockItem::<MockReadsVecCopyOutput>::default());
+
+    assert_eq!(
+        vec![ItemIoType::of::<VecCopyOutput>()],
+        <dyn ItemRt<_>>::input_types(&item_wrapper)
+    );
+    assert!(<dyn ItemRt<_>>::output_types(&item_wrapper).is_empty());
+}
+
+#[tokio::test]
+async fn applicable_returns_true_by_default() -> Result<(), Box<dyn std::error::Error>> {
+    let item_wrapper = ItemWrapper::<_, VecCopyError>::from(VecCopyItem::default());
+    let (params_specs, mut resources) = resources_set_up(&item_wrapper).await?;
+    resources.insert(profile!("test_profile"));
+
+    let applicable = <dyn ItemRt<_>>::applicable(&item_wrapper, &params_specs, &resources)?;
+
+    assert!(applicable);
+    Ok(())
+}
+
+#[tokio::test]
+async fn applicable_returns_true_when_profile_not_in_resources()
+-> Result<(), Box<dyn std::error::Error>> {
+    let item_wrapper = ItemWrapper::<_, VecCopyError>::from(VecCopyItem::default());
+    let (params_specs, resources) = resources_set_up(&item_wrapper).await?;
+
+    let applicable = <dyn ItemRt<_>>::applicable(&item_wrapper, &params_specs, &resources)?;
+
+    assert!(applicable);
+    Ok(())
+}
+
+#[tokio::test]
+async fn params_explain_records_value_resolutions() -> Result<(), Box<dyn std::error::Error>> {
+    let item_wrapper = ItemWrapper::<_, VecCopyError>::from(VecCopyItem::default());
+    let (params_specs, resources) = resources_set_up(&item_wrapper).await?;
+
+    let item_params_explanation = <dyn ItemRt<_>>::params_explain(
+        &item_wrapper,
+        &params_specs,
+        &resources,
+        ValueResolutionMode::Goal,
+    )?;
+
+    assert_eq!(VecCopyItem::ID_DEFAULT, item_params_explanation.item_id());
+    assert_eq!("VecA", item_params_explanation.params_type_name());
+    assert_eq!(
+        "VecA([0, 1, 2, 3, 4, 5, 6, 7])",
+        item_params_explanation.params()
+    );
+    assert!(item_params_explanation.params_complete());
+    assert_eq!(
+        &[ValueResolution::new(
+            Vec::new(),
+            ValueResolutionSource::Value,
+            Some(String::from("VecA([0, 1, 2, 3, 4, 5, 6, 7])")),
+        )],
+        item_params_explanation.value_resolutions()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn params_explain_records_unresolved_value_as_incomplete()
+-> Result<(), Box<dyn std::error::Error>> {
+    let item_wrapper = ItemWrapper::<_, VecCopyError>::from(VecCopyItem::default());
+    let (_params_specs, resources) = resources_set_up(&item_wrapper).await?;
+    let mut params_specs = ParamsSpecs::new();
+    params_specs.insert(
+        VecCopyItem::ID_DEFAULT.clone(),
+        ParamsSpec::<VecA>::InMemory,
+    );
 
+    let item_params_explanation = <dyn ItemRt<_>>::params_explain(
+        &item_wrapper,
+        &params_specs,
+        &resources,
+        ValueResolutionMode::Goal,
+    )?;
+
+    assert!(!item_params_explanation.params_complete());
+    assert_eq!(
+        &[ValueResolution::new(
+            Vec::new(),
+            ValueResolutionSource::InMemory {
+                type_name: String::from("VecA"),
+            },
+            None,
+        )],
+        item_params_explanation.value_resolutions()
+    );
     Ok(())
 }
 
@@ -262,6 +401,11 @@ async fn state_current_try_exec() -> Result<(), Box<dyn std::error::Error>> {
         Some(VecCopyState::new()).as_ref(),
         resources.borrow::<Current<VecCopyState>>().as_ref()
     );
+    // Automatic `ItemOutput<T>` insertion.
+    assert_eq!(
+        Some(VecCopyOutput(0)).as_ref(),
+        resources.borrow::<ItemOutput<VecCopyOutput>>().as_ref()
+    );
 
     Ok(())
 }
@@ -341,6 +485,9 @@ async fn ensure_prepare() -> Result<(), VecCopyError> {
     let vec_copy_item = VecCopyItem::default();
     let item_wrapper = ItemWrapper::<_, VecCopyError>::from(vec_copy_item);
     let (params_specs, resources) = resources_set_up(&item_wrapper).await?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow");
+    let apply_ctx = ApplyCtx::new(&profile, &flow_id, false, None);
     cfg_if::cfg_if! {
         if #[cfg(feature = "output_progress")] {
             let (progress_tx, _progress_rx) = mpsc::channel(10);
@@ -356,14 +503,28 @@ async fn ensure_prepare() -> Result<(), VecCopyError> {
         progress_sender,
     );
 
-    match <dyn ItemRt<_>>::ensure_prepare(&item_wrapper, &params_specs, &resources, fn_ctx).await {
+    match <dyn ItemRt<_>>::ensure_prepare(
+        &item_wrapper,
+        &params_specs,
+        &resources,
+        fn_ctx,
+        apply_ctx,
+    )
+    .await
+    {
         Ok(item_apply) => {
             #[cfg(not(feature = "output_progress"))]
-            assert_eq!(ApplyCheck::ExecRequired, item_apply.apply_check());
+            assert_eq!(
+                ApplyCheck::ExecRequired {
+                    reason: Some(ApplyCheckReason::new("vec_changed")),
+                },
+                item_apply.apply_check()
+            );
             #[cfg(feature = "output_progress")]
             assert_eq!(
                 ApplyCheck::ExecRequired {
-                    progress_limit: ProgressLimit::Bytes(8)
+                    progress_limit: ProgressLimit::Bytes(8),
+                    reason: Some(ApplyCheckReason::new("vec_changed")),
                 },
                 item_apply.apply_check()
             );
@@ -379,6 +540,9 @@ async fn apply_exec_dry_for_ensure() -> Result<(), VecCopyError> {
     let vec_copy_item = VecCopyItem::default();
     let item_wrapper = ItemWrapper::<_, VecCopyError>::from(vec_copy_item);
     let (params_specs, resources) = resources_set_up(&item_wrapper).await?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow");
+    let apply_ctx = ApplyCtx::new(&profile, &flow_id, true, None);
     cfg_if::cfg_if! {
         if #[cfg(feature = "output_progress")] {
             let (progress_tx, _progress_rx) = mpsc::channel(10);
@@ -394,10 +558,15 @@ async fn apply_exec_dry_for_ensure() -> Result<(), VecCopyError> {
         progress_sender,
     );
 
-    let mut item_apply_boxed =
-        <dyn ItemRt<_>>::ensure_prepare(&item_wrapper, &params_specs, &resources, fn_ctx)
-            .await
-            .map_err(|(error, _)| error)?;
+    let mut item_apply_boxed = <dyn ItemRt<_>>::ensure_prepare(
+        &item_wrapper,
+        &params_specs,
+        &resources,
+        fn_ctx,
+        apply_ctx,
+    )
+    .await
+    .map_err(|(error, _)| error)?;
     cfg_if::cfg_if! {
         if #[cfg(feature = "output_progress")] {
             let (progress_tx, _progress_rx) = mpsc::channel(10);
@@ -449,6 +618,9 @@ async fn apply_exec_for_ensure() -> Result<(), VecCopyError> {
     let vec_copy_item = VecCopyItem::default();
     let item_wrapper = ItemWrapper::<_, VecCopyError>::from(vec_copy_item);
     let (params_specs, resources) = resources_set_up(&item_wrapper).await?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow");
+    let apply_ctx = ApplyCtx::new(&profile, &flow_id, false, None);
     cfg_if::cfg_if! {
         if #[cfg(feature = "output_progress")] {
             let (progress_tx, _progress_rx) = mpsc::channel(10);
@@ -464,10 +636,15 @@ async fn apply_exec_for_ensure() -> Result<(), VecCopyError> {
         progress_sender,
     );
 
-    let mut item_apply_boxed =
-        <dyn ItemRt<_>>::ensure_prepare(&item_wrapper, &params_specs, &resources, fn_ctx)
-            .await
-            .map_err(|(error, _)| error)?;
+    let mut item_apply_boxed = <dyn ItemRt<_>>::ensure_prepare(
+        &item_wrapper,
+        &params_specs,
+        &resources,
+        fn_ctx,
+        apply_ctx,
+    )
+    .await
+    .map_err(|(error, _)| error)?;
     cfg_if::cfg_if! {
         if #[cfg(feature = "output_progress")] {
             let (progress_tx, _progress_rx) = mpsc::channel(10);
@@ -505,6 +682,11 @@ async fn apply_exec_for_ensure() -> Result<(), VecCopyError> {
         Some(VecCopyState::from(vec![0u8, 1, 2, 3, 4, 5, 6, 7])).as_ref(),
         resources.borrow::<Goal<VecCopyState>>().as_ref()
     );
+    // Automatic `ItemOutput<T>` insertion.
+    assert_eq!(
+        Some(VecCopyOutput(8)).as_ref(),
+        resources.borrow::<ItemOutput<VecCopyOutput>>().as_ref()
+    );
 
     Ok(())
 }
@@ -515,17 +697,32 @@ async fn clean_prepare() -> Result<(), VecCopyError> {
     let item_wrapper = ItemWrapper::<_, VecCopyError>::from(vec_copy_item);
     let (params_specs, resources, states_current) =
         resources_set_up_with_pre_stored_state(&item_wrapper).await?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow");
+    let apply_ctx = ApplyCtx::new(&profile, &flow_id, false, None);
 
-    match <dyn ItemRt<_>>::clean_prepare(&item_wrapper, &states_current, &params_specs, &resources)
-        .await
+    match <dyn ItemRt<_>>::clean_prepare(
+        &item_wrapper,
+        &states_current,
+        &params_specs,
+        &resources,
+        apply_ctx,
+    )
+    .await
     {
         Ok(item_apply) => {
             #[cfg(not(feature = "output_progress"))]
-            assert_eq!(ApplyCheck::ExecRequired, item_apply.apply_check());
+            assert_eq!(
+                ApplyCheck::ExecRequired {
+                    reason: Some(ApplyCheckReason::new("vec_changed")),
+                },
+                item_apply.apply_check()
+            );
             #[cfg(feature = "output_progress")]
             assert_eq!(
                 ApplyCheck::ExecRequired {
-                    progress_limit: ProgressLimit::Bytes(8)
+                    progress_limit: ProgressLimit::Bytes(8),
+                    reason: Some(ApplyCheckReason::new("vec_changed")),
                 },
                 item_apply.apply_check()
             );
@@ -542,11 +739,19 @@ async fn apply_exec_dry_for_clean() -> Result<(), VecCopyError> {
     let item_wrapper = ItemWrapper::<_, VecCopyError>::from(vec_copy_item);
     let (params_specs, resources, states_current) =
         resources_set_up_with_pre_stored_state(&item_wrapper).await?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow");
+    let apply_ctx = ApplyCtx::new(&profile, &flow_id, true, None);
 
-    let mut item_apply_boxed =
-        <dyn ItemRt<_>>::clean_prepare(&item_wrapper, &states_current, &params_specs, &resources)
-            .await
-            .map_err(|(error, _)| error)?;
+    let mut item_apply_boxed = <dyn ItemRt<_>>::clean_prepare(
+        &item_wrapper,
+        &states_current,
+        &params_specs,
+        &resources,
+        apply_ctx,
+    )
+    .await
+    .map_err(|(error, _)| error)?;
     cfg_if::cfg_if! {
         if #[cfg(feature = "output_progress")] {
             let (progress_tx, _progress_rx) = mpsc::channel(10);
@@ -594,11 +799,19 @@ async fn apply_exec_for_clean() -> Result<(), VecCopyError> {
     let item_wrapper = ItemWrapper::<_, VecCopyError>::from(vec_copy_item);
     let (params_specs, resources, states_current) =
         resources_set_up_with_pre_stored_state(&item_wrapper).await?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow");
+    let apply_ctx = ApplyCtx::new(&profile, &flow_id, false, None);
 
-    let mut item_apply_boxed =
-        <dyn ItemRt<_>>::clean_prepare(&item_wrapper, &states_current, &params_specs, &resources)
-            .await
-            .map_err(|(error, _)| error)?;
+    let mut item_apply_boxed = <dyn ItemRt<_>>::clean_prepare(
+        &item_wrapper,
+        &states_current,
+        &params_specs,
+        &resources,
+        apply_ctx,
+    )
+    .await
+    .map_err(|(error, _)| error)?;
     cfg_if::cfg_if! {
         if #[cfg(feature = "output_progress")] {
             let (progress_tx, _progress_rx) = mpsc::channel(10);
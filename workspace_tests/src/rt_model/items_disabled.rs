@@ -0,0 +1,38 @@
+use peace::{cfg::item_id, rt_model::ItemsDisabled};
+
+#[test]
+fn from_iter_contains_item_ids() {
+    let items_disabled = ItemsDisabled::from_iter([item_id!("item_0"), item_id!("item_1")]);
+
+    assert!(items_disabled.contains(&item_id!("item_0")));
+    assert!(items_disabled.contains(&item_id!("item_1")));
+    assert!(!items_disabled.contains(&item_id!("item_2")));
+}
+
+#[test]
+fn serialize_round_trip() -> Result<(), serde_yaml::Error> {
+    let items_disabled = ItemsDisabled::from_iter([item_id!("item_0"), item_id!("item_1")]);
+
+    let serialized = serde_yaml::to_string(&items_disabled)?;
+    assert_eq!("- item_0\n- item_1\n", serialized);
+    assert_eq!(items_disabled, serde_yaml::from_str(&serialized)?);
+    Ok(())
+}
+
+#[test]
+fn clone() {
+    let mut items_disabled = ItemsDisabled::new();
+    items_disabled.insert(item_id!("item_0"));
+
+    assert_eq!(items_disabled, Clone::clone(&items_disabled));
+}
+
+#[test]
+fn debug() {
+    let items_disabled = ItemsDisabled::from_iter([item_id!("item_0")]);
+
+    assert_eq!(
+        r#"ItemsDisabled({ItemId("item_0")})"#,
+        format!("{items_disabled:?}")
+    );
+}
@@ -1 +1,6 @@
+mod encrypted_storage;
+mod key_provider;
+mod sensitive_field_cipher;
+mod storage_codec;
+mod workspace;
 mod workspace_spec;
@@ -0,0 +1,152 @@
+use peace::{
+    resources::paths::PeaceDir,
+    rt_model::{EncryptedStorage, Error, NativeError, Storage, StorageCodec},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+struct TestStruct {
+    secret: String,
+}
+
+#[tokio::test]
+async fn serialized_write_encrypts_and_serialized_read_decrypts(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let peace_dir = PeaceDir::new(tempdir.path().join(".peace"));
+    tokio::fs::create_dir_all(&peace_dir).await?;
+    let file_path = peace_dir.join("t.yaml");
+    let encrypted_storage = EncryptedStorage::new(peace_dir, &[1u8; 32]);
+
+    let test_struct = TestStruct {
+        secret: String::from("hunter2"),
+    };
+    encrypted_storage
+        .serialized_write(&file_path, &test_struct, Error::StatesSerialize)
+        .await?;
+    let test_struct_read = encrypted_storage
+        .serialized_read::<TestStruct, _>(&file_path, Error::StatesSerialize)
+        .await?;
+
+    let contents = tokio::fs::read(&file_path).await?;
+    assert!(
+        !contents
+            .windows(b"hunter2".len())
+            .any(|window| window == b"hunter2"),
+        "Expected file contents to be encrypted."
+    );
+    assert_eq!(test_struct, test_struct_read);
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_returns_error_when_key_does_not_match() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let peace_dir = PeaceDir::new(tempdir.path().join(".peace"));
+    tokio::fs::create_dir_all(&peace_dir).await?;
+    let file_path = peace_dir.join("t.yaml");
+    EncryptedStorage::new(peace_dir.clone(), &[1u8; 32])
+        .write(&file_path, b"secret: hunter2\n")
+        .await?;
+
+    let error = EncryptedStorage::new(peace_dir, &[2u8; 32])
+        .read(&file_path)
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            Error::Native(NativeError::FileDecrypt { path })
+            if path == &file_path
+        ),
+        "Expected `error` to be `NativeError::FileDecrypt {{ .. }}`, but was {error:?}"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_returns_error_when_file_is_plain_text() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let peace_dir = PeaceDir::new(tempdir.path().join(".peace"));
+    tokio::fs::create_dir_all(&peace_dir).await?;
+    let file_path = peace_dir.join("t.yaml");
+    tokio::fs::write(&file_path, "a").await?;
+
+    let error = EncryptedStorage::new(peace_dir, &[1u8; 32])
+        .read(&file_path)
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            Error::Native(NativeError::FileDecrypt { path })
+            if path == &file_path
+        ),
+        "Expected `error` to be `NativeError::FileDecrypt {{ .. }}`, but was {error:?}"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn storage_encrypts_files_under_peace_dir_only() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let peace_dir = PeaceDir::new(tempdir.path().join(".peace"));
+    tokio::fs::create_dir_all(&peace_dir).await?;
+    let file_path_encrypted = peace_dir.join("t.yaml");
+    let file_path_plain = tempdir.path().join("t.yaml");
+    let storage = Storage::new().with_storage_codec(
+        StorageCodec::new().with_encrypted_storage(EncryptedStorage::new(peace_dir, &[1u8; 32])),
+    );
+
+    let test_struct = TestStruct {
+        secret: String::from("hunter2"),
+    };
+    for file_path in [&file_path_encrypted, &file_path_plain] {
+        storage
+            .serialized_write(
+                crate::fn_name_short!().to_string(),
+                file_path,
+                &test_struct,
+                Error::StatesSerialize,
+            )
+            .await?;
+    }
+    let test_struct_read = storage
+        .serialized_read_opt::<TestStruct, _>(
+            crate::fn_name_short!().to_string(),
+            &file_path_encrypted,
+            |error, _| Error::ProfileParamsDeserialize(error),
+        )
+        .await?;
+
+    assert_ne!(
+        "secret: hunter2\n",
+        String::from_utf8_lossy(&tokio::fs::read(&file_path_encrypted).await?)
+    );
+    assert_eq!(
+        "secret: hunter2\n",
+        tokio::fs::read_to_string(&file_path_plain).await?
+    );
+    assert_eq!(Some(test_struct), test_struct_read);
+    Ok(())
+}
+
+#[test]
+fn is_encrypted() {
+    let encrypted_storage = EncryptedStorage::new(PeaceDir::new(".peace".into()), &[1u8; 32]);
+
+    assert!(encrypted_storage.is_encrypted(".peace/app/profile/states_current.yaml".as_ref()));
+    assert!(!encrypted_storage.is_encrypted("file.tar".as_ref()));
+}
+
+#[test]
+fn debug_does_not_include_key() {
+    let encrypted_storage = EncryptedStorage::new(PeaceDir::new(".peace".into()), &[1u8; 32]);
+
+    assert_eq!(
+        r#"EncryptedStorage { peace_dir: PeaceDir(".peace"), .. }"#,
+        format!("{encrypted_storage:?}")
+    );
+}
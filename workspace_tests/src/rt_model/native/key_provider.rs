@@ -0,0 +1,71 @@
+use base64::Engine;
+use peace::rt_model::KeyProvider;
+
+#[test]
+fn from_key_provides_key() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let key_provider = KeyProvider::from_key([1u8; 32]);
+
+    assert_eq!([1u8; 32], key_provider.key()?);
+    Ok(())
+}
+
+#[test]
+fn new_provides_key_from_fn() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let key_provider = KeyProvider::new(|| Ok([2u8; 32]));
+
+    assert_eq!([2u8; 32], key_provider.key()?);
+    Ok(())
+}
+
+#[test]
+fn new_returns_error_from_fn() {
+    let key_provider = KeyProvider::new(|| Err("key not found".into()));
+
+    let error = key_provider.key().unwrap_err();
+
+    assert_eq!("key not found", error.to_string());
+}
+
+#[test]
+fn from_env_provides_key_decoded_from_base64(
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    std::env::set_var(
+        "PEACE_TEST_KEY_PROVIDER_FROM_ENV",
+        base64::engine::general_purpose::STANDARD.encode([3u8; 32]),
+    );
+    let key_provider = KeyProvider::from_env("PEACE_TEST_KEY_PROVIDER_FROM_ENV");
+
+    assert_eq!([3u8; 32], key_provider.key()?);
+    Ok(())
+}
+
+#[test]
+fn from_env_returns_error_when_key_length_invalid() {
+    std::env::set_var(
+        "PEACE_TEST_KEY_PROVIDER_FROM_ENV_INVALID",
+        base64::engine::general_purpose::STANDARD.encode([3u8; 16]),
+    );
+    let key_provider = KeyProvider::from_env("PEACE_TEST_KEY_PROVIDER_FROM_ENV_INVALID");
+
+    let error = key_provider.key().unwrap_err();
+
+    assert_eq!(
+        "Expected `PEACE_TEST_KEY_PROVIDER_FROM_ENV_INVALID` to contain a 32 byte key, \
+        but it contained 16 bytes.",
+        error.to_string()
+    );
+}
+
+#[test]
+fn from_env_returns_error_when_env_var_not_set() {
+    let key_provider = KeyProvider::from_env("PEACE_TEST_KEY_PROVIDER_FROM_ENV_NOT_SET");
+
+    assert!(key_provider.key().is_err());
+}
+
+#[test]
+fn debug() {
+    let key_provider = KeyProvider::from_key([1u8; 32]);
+
+    assert_eq!(r#"KeyProvider("..")"#, format!("{key_provider:?}"));
+}
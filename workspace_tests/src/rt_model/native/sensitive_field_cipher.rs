@@ -0,0 +1,79 @@
+use peace::{cfg::state::SensitiveCipher, rt_model::SensitiveFieldCipher};
+
+#[test]
+fn encrypt_and_decrypt_round_trip() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let sensitive_field_cipher = SensitiveFieldCipher::new(&[1u8; 32]);
+
+    let ciphertext = sensitive_field_cipher.encrypt(b"hunter2")?;
+    let plaintext = sensitive_field_cipher.decrypt(&ciphertext)?;
+
+    assert!(!ciphertext.contains("hunter2"));
+    assert_eq!(b"hunter2".as_slice(), plaintext.as_slice());
+    Ok(())
+}
+
+#[test]
+fn encrypt_returns_same_ciphertext_for_same_value(
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let ciphertext_0 = SensitiveFieldCipher::new(&[1u8; 32]).encrypt(b"hunter2")?;
+    let ciphertext_1 = SensitiveFieldCipher::new(&[1u8; 32]).encrypt(b"hunter2")?;
+
+    assert_eq!(ciphertext_0, ciphertext_1);
+    Ok(())
+}
+
+#[test]
+fn encrypt_returns_different_ciphertext_for_different_values(
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let sensitive_field_cipher = SensitiveFieldCipher::new(&[1u8; 32]);
+
+    let ciphertext_0 = sensitive_field_cipher.encrypt(b"hunter2")?;
+    let ciphertext_1 = sensitive_field_cipher.encrypt(b"hunter3")?;
+
+    assert_ne!(ciphertext_0[..16], ciphertext_1[..16]);
+    Ok(())
+}
+
+#[test]
+fn encrypt_returns_different_ciphertext_for_different_keys(
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let ciphertext_0 = SensitiveFieldCipher::new(&[1u8; 32]).encrypt(b"hunter2")?;
+    let ciphertext_1 = SensitiveFieldCipher::new(&[2u8; 32]).encrypt(b"hunter2")?;
+
+    assert_ne!(ciphertext_0, ciphertext_1);
+    Ok(())
+}
+
+#[test]
+fn decrypt_returns_error_when_key_does_not_match(
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let ciphertext = SensitiveFieldCipher::new(&[1u8; 32]).encrypt(b"hunter2")?;
+
+    let error = SensitiveFieldCipher::new(&[2u8; 32])
+        .decrypt(&ciphertext)
+        .unwrap_err();
+
+    assert!(error
+        .to_string()
+        .starts_with("Failed to decrypt sensitive value."));
+    Ok(())
+}
+
+#[test]
+fn decrypt_returns_error_when_ciphertext_too_short() {
+    let error = SensitiveFieldCipher::new(&[1u8; 32])
+        .decrypt("AAEC")
+        .unwrap_err();
+
+    assert!(error
+        .to_string()
+        .starts_with("Failed to decrypt sensitive value."));
+}
+
+#[test]
+fn debug_does_not_include_key() {
+    assert_eq!(
+        "SensitiveFieldCipher { .. }",
+        format!("{:?}", SensitiveFieldCipher::new(&[1u8; 32]))
+    );
+}
@@ -0,0 +1,86 @@
+use std::path::Path;
+
+use peace::{
+    cfg::state::SensitiveSerdeMode,
+    resources::paths::PeaceDir,
+    rt_model::{
+        EncryptedStorage, SensitiveFieldCipher, Storage, StorageCodec, StorageCompression,
+        StorageFormat,
+    },
+};
+
+#[test]
+fn new_stores_yaml_in_plain_text() {
+    let storage_codec = StorageCodec::new();
+
+    assert!(storage_codec.encrypted_storage().is_none());
+    assert!(storage_codec.sensitive_field_cipher().is_none());
+    assert_eq!(StorageFormat::Yaml, storage_codec.storage_format());
+    assert_eq!(
+        StorageCompression::None,
+        storage_codec.storage_compression()
+    );
+}
+
+#[test]
+fn with_methods_set_codec_settings() {
+    let storage_codec = StorageCodec::new()
+        .with_encrypted_storage(EncryptedStorage::new(
+            PeaceDir::new(".peace".into()),
+            &[1u8; 32],
+        ))
+        .with_sensitive_field_cipher(SensitiveFieldCipher::new(&[1u8; 32]))
+        .with_storage_format(StorageFormat::Json)
+        .with_storage_compression(StorageCompression::Gzip);
+
+    assert!(storage_codec.encrypted_storage().is_some());
+    assert!(storage_codec.sensitive_field_cipher().is_some());
+    assert_eq!(StorageFormat::Json, storage_codec.storage_format());
+    assert_eq!(
+        StorageCompression::Gzip,
+        storage_codec.storage_compression()
+    );
+}
+
+#[test]
+fn storage_uses_storage_codec_format() {
+    let storage = Storage::new()
+        .with_storage_codec(StorageCodec::new().with_storage_format(StorageFormat::Cbor));
+
+    assert_eq!(StorageFormat::Cbor, storage.storage_format());
+    assert_eq!(
+        StorageFormat::Cbor,
+        storage.storage_codec().storage_format()
+    );
+}
+
+#[test]
+fn sensitive_serde_mode_returns_mode_for_file() {
+    let peace_dir = PeaceDir::new(".peace".into());
+    let file_path_in_peace_dir = peace_dir.join("states_current.yaml");
+    let file_path_outside_peace_dir = Path::new("states_archive.yaml");
+
+    let storage_codec = StorageCodec::new();
+    assert!(matches!(
+        storage_codec.sensitive_serde_mode(&file_path_in_peace_dir),
+        SensitiveSerdeMode::KeyRequired
+    ));
+
+    let storage_codec =
+        StorageCodec::new().with_encrypted_storage(EncryptedStorage::new(peace_dir, &[1u8; 32]));
+    assert!(matches!(
+        storage_codec.sensitive_serde_mode(&file_path_in_peace_dir),
+        SensitiveSerdeMode::Plain
+    ));
+    assert!(matches!(
+        storage_codec.sensitive_serde_mode(file_path_outside_peace_dir),
+        SensitiveSerdeMode::KeyRequired
+    ));
+
+    let storage_codec =
+        StorageCodec::new().with_sensitive_field_cipher(SensitiveFieldCipher::new(&[1u8; 32]));
+    assert!(matches!(
+        storage_codec.sensitive_serde_mode(file_path_outside_peace_dir),
+        SensitiveSerdeMode::Encrypt(_)
+    ));
+}
@@ -0,0 +1,403 @@
+use peace::{
+    cfg::{app_name, profile, state::Sensitive, FlowId},
+    cmd::ctx::CmdCtx,
+    cmd_model::CmdOutcome,
+    resources::paths::{FlowDir, ProfileDir, StatesCurrentFile},
+    rt::cmds::{StatesCurrentReadCmd, StatesDiscoverCmd},
+    rt_model::{
+        Error, Flow, ItemGraphBuilder, KeyProvider, NativeError, StorageCompression,
+        StorageFormat, Workspace, WorkspaceSpec,
+    },
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{NoOpOutput, PeaceTestError, VecA, VecCopyItem, VecCopyState};
+
+#[tokio::test]
+async fn path_encrypted_stores_states_encrypted() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::PathEncrypted {
+            path: tempdir.path().to_path_buf(),
+            key_provider: KeyProvider::from_key([1u8; 32]),
+        },
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    // Re-read states from disk.
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .await?;
+    let CmdOutcome::Complete {
+        value: states_current_stored,
+        cmd_blocks_processed: _,
+    } = StatesCurrentReadCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesCurrentReadCmd::exec` to complete successfully.");
+    };
+
+    let states_current_file = {
+        let profile_dir =
+            ProfileDir::from((workspace.dirs().peace_app_dir(), &profile!("test_profile")));
+        let flow_dir = FlowDir::from((&profile_dir, flow.flow_id()));
+        StatesCurrentFile::from(&flow_dir)
+    };
+    let states_current_contents = tokio::fs::read(&states_current_file).await?;
+    assert!(
+        serde_yaml::from_slice::<serde_yaml::Value>(&states_current_contents).is_err(),
+        "Expected states file to be encrypted."
+    );
+    assert_eq!(
+        Some(VecCopyState::new()).as_ref(),
+        states_current_stored.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+    Ok(())
+}
+
+#[test]
+fn new_returns_error_when_key_provider_fails() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+
+    let error = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::PathEncrypted {
+            path: tempdir.path().to_path_buf(),
+            key_provider: KeyProvider::new(|| Err("key not found".into())),
+        },
+    )
+    .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            Error::Native(NativeError::EncryptionKeyProvide { error })
+            if error.to_string() == "key not found"
+        ),
+        "Expected `error` to be `NativeError::EncryptionKeyProvide {{ .. }}`, but was {error:?}"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn with_sensitive_key_provider_stores_sensitive_fields_encrypted(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?
+    .with_sensitive_key_provider(KeyProvider::from_key([1u8; 32]))?;
+    let file_path = tempdir.path().join("server_state.yaml");
+
+    let server_state = ServerState {
+        host: String::from("example.com"),
+        api_token: Sensitive::new(String::from("hunter2")),
+    };
+    workspace
+        .storage()
+        .serialized_write(
+            crate::fn_name_short!().to_string(),
+            &file_path,
+            &server_state,
+            Error::StatesSerialize,
+        )
+        .await?;
+    let server_state_read = workspace
+        .storage()
+        .serialized_read::<ServerState, _>(
+            crate::fn_name_short!().to_string(),
+            &file_path,
+            |error, _| Error::StatesSerialize(error),
+        )
+        .await?;
+
+    let contents = tokio::fs::read_to_string(&file_path).await?;
+    assert!(
+        contents.contains("host: example.com"),
+        "Expected non-sensitive fields to be stored in plain text, but contents were:\n{contents}"
+    );
+    assert!(
+        contents.contains("api_token: encrypted:") && !contents.contains("hunter2"),
+        "Expected sensitive fields to be encrypted, but contents were:\n{contents}"
+    );
+    assert_eq!(server_state, server_state_read);
+    Ok(())
+}
+
+#[tokio::test]
+async fn with_sensitive_key_provider_stores_unchanged_sensitive_fields_unchanged(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?
+    .with_sensitive_key_provider(KeyProvider::from_key([1u8; 32]))?;
+    let file_path = tempdir.path().join("server_state.yaml");
+
+    let server_state = ServerState {
+        host: String::from("example.com"),
+        api_token: Sensitive::new(String::from("hunter2")),
+    };
+    workspace
+        .storage()
+        .serialized_write(
+            crate::fn_name_short!().to_string(),
+            &file_path,
+            &server_state,
+            Error::StatesSerialize,
+        )
+        .await?;
+    let contents_0 = tokio::fs::read_to_string(&file_path).await?;
+    workspace
+        .storage()
+        .serialized_write(
+            crate::fn_name_short!().to_string(),
+            &file_path,
+            &server_state,
+            Error::StatesSerialize,
+        )
+        .await?;
+    let contents_1 = tokio::fs::read_to_string(&file_path).await?;
+
+    assert_eq!(contents_0, contents_1);
+    Ok(())
+}
+
+#[test]
+fn with_sensitive_key_provider_returns_error_when_key_provider_fails(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+
+    let error = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?
+    .with_sensitive_key_provider(KeyProvider::new(|| Err("key not found".into())))
+    .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            Error::Native(NativeError::EncryptionKeyProvide { error })
+            if error.to_string() == "key not found"
+        ),
+        "Expected `error` to be `NativeError::EncryptionKeyProvide {{ .. }}`, but was {error:?}"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn with_storage_format_stores_states_in_format() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?
+    .with_storage_format(StorageFormat::Json);
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+    let CmdOutcome::Complete {
+        value: states_current_stored,
+        cmd_blocks_processed: _,
+    } = StatesCurrentReadCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesCurrentReadCmd::exec` to complete successfully.");
+    };
+
+    let states_current_file = {
+        let profile_dir =
+            ProfileDir::from((workspace.dirs().peace_app_dir(), &profile!("test_profile")));
+        let flow_dir = FlowDir::from((&profile_dir, flow.flow_id()));
+        StatesCurrentFile::from(&flow_dir)
+    };
+    let states_current_contents = tokio::fs::read(&states_current_file).await?;
+    assert!(
+        serde_json::from_slice::<serde_json::Value>(&states_current_contents).is_ok(),
+        "Expected states file to be JSON, but contents were:\n{}",
+        String::from_utf8_lossy(&states_current_contents)
+    );
+    assert_eq!(StorageFormat::Json, workspace.storage().storage_format());
+    assert_eq!(
+        Some(VecCopyState::new()).as_ref(),
+        states_current_stored.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn with_storage_compression_stores_states_compressed(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?
+    .with_storage_compression(StorageCompression::Gzip);
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    let states_current_file = {
+        let profile_dir =
+            ProfileDir::from((workspace.dirs().peace_app_dir(), &profile!("test_profile")));
+        let flow_dir = FlowDir::from((&profile_dir, flow.flow_id()));
+        StatesCurrentFile::from(&flow_dir)
+    };
+    let states_current_contents = tokio::fs::read(&states_current_file).await?;
+    assert_eq!(
+        StorageCompression::Gzip,
+        StorageCompression::detect(&states_current_contents)
+    );
+
+    // Compression is detected on read, so an uncompressed workspace reads the
+    // compressed states.
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .await?;
+    let CmdOutcome::Complete {
+        value: states_current_stored,
+        cmd_blocks_processed: _,
+    } = StatesCurrentReadCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesCurrentReadCmd::exec` to complete successfully.");
+    };
+
+    assert_eq!(
+        Some(VecCopyState::new()).as_ref(),
+        states_current_stored.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn reads_states_compressed_with_zstd() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+
+    let mut output = NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    // Files compressed with zstd by other tools are read.
+    let states_current_file = {
+        let profile_dir =
+            ProfileDir::from((workspace.dirs().peace_app_dir(), &profile!("test_profile")));
+        let flow_dir = FlowDir::from((&profile_dir, flow.flow_id()));
+        StatesCurrentFile::from(&flow_dir)
+    };
+    let states_current_contents = tokio::fs::read(&states_current_file).await?;
+    tokio::fs::write(
+        &states_current_file,
+        zstd_raw_frame(&states_current_contents),
+    )
+    .await?;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow(&mut output, &workspace)
+        .with_profile(profile!("test_profile"))
+        .with_flow(&flow)
+        .await?;
+    let CmdOutcome::Complete {
+        value: states_current_stored,
+        cmd_blocks_processed: _,
+    } = StatesCurrentReadCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesCurrentReadCmd::exec` to complete successfully.");
+    };
+
+    assert_eq!(
+        Some(VecCopyState::new()).as_ref(),
+        states_current_stored.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+    Ok(())
+}
+
+/// Returns a zstd frame that stores the bytes in a single uncompressed block.
+fn zstd_raw_frame(bytes: &[u8]) -> Vec<u8> {
+    let bytes_len = u32::try_from(bytes.len()).expect("Expected bytes to fit in one block.");
+    // Single segment, with a 4 byte frame content size.
+    let frame_header_descriptor = 0b1010_0000u8;
+    // Last block, of type raw.
+    let block_header = (bytes_len << 3) | 1;
+
+    let mut frame = vec![0x28, 0xb5, 0x2f, 0xfd, frame_header_descriptor];
+    frame.extend_from_slice(&bytes_len.to_le_bytes());
+    frame.extend_from_slice(&block_header.to_le_bytes()[..3]);
+    frame.extend_from_slice(bytes);
+    frame
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+struct ServerState {
+    host: String,
+    api_token: Sensitive<String>,
+}
@@ -1,4 +1,4 @@
-use peace::rt_model::WorkspaceSpec;
+use peace::rt_model::{KeyProvider, WorkspaceSpec};
 
 #[test]
 fn clone() {
@@ -21,4 +21,34 @@ fn partial_eq() {
         WorkspaceSpec::FirstDirWithFile(".git".into()),
         WorkspaceSpec::FirstDirWithFile(".peace".into())
     );
+    assert_ne!(
+        WorkspaceSpec::ParentSearch,
+        WorkspaceSpec::FirstDirWithFile(".peace".into())
+    );
+}
+
+#[test]
+fn partial_eq_path_encrypted() {
+    let key_provider = KeyProvider::from_key([1u8; 32]);
+
+    assert_eq!(
+        WorkspaceSpec::PathEncrypted {
+            path: ".".into(),
+            key_provider: key_provider.clone(),
+        },
+        WorkspaceSpec::PathEncrypted {
+            path: ".".into(),
+            key_provider,
+        }
+    );
+    assert_ne!(
+        WorkspaceSpec::PathEncrypted {
+            path: ".".into(),
+            key_provider: KeyProvider::from_key([1u8; 32]),
+        },
+        WorkspaceSpec::PathEncrypted {
+            path: ".".into(),
+            key_provider: KeyProvider::from_key([1u8; 32]),
+        }
+    );
 }
@@ -0,0 +1,40 @@
+use peace::{cfg::item_id, rt_model::ParamsDigests};
+
+#[test]
+fn digest_is_deterministic_sha256_hex() {
+    let digest = ParamsDigests::digest(b"abc");
+
+    assert_eq!(
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        digest
+    );
+    assert_eq!(digest, ParamsDigests::digest(b"abc"));
+}
+
+#[test]
+fn items_changed_returns_items_with_different_digests() {
+    let params_digests = ParamsDigests::from_iter([
+        (item_id!("item_0"), ParamsDigests::digest(b"0")),
+        (item_id!("item_1"), ParamsDigests::digest(b"1")),
+        (item_id!("item_2"), ParamsDigests::digest(b"2")),
+    ]);
+    let params_digests_stored = ParamsDigests::from_iter([
+        (item_id!("item_0"), ParamsDigests::digest(b"0")),
+        (item_id!("item_1"), ParamsDigests::digest(b"one")),
+    ]);
+
+    assert_eq!(
+        vec![item_id!("item_1")],
+        params_digests.items_changed(&params_digests_stored)
+    );
+}
+
+#[test]
+fn serialize_round_trip() -> Result<(), serde_yaml::Error> {
+    let params_digests = ParamsDigests::from_iter([(item_id!("item_0"), String::from("abcd"))]);
+
+    let serialized = serde_yaml::to_string(&params_digests)?;
+    assert_eq!("item_0: abcd\n", serialized);
+    assert_eq!(params_digests, serde_yaml::from_str(&serialized)?);
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use peace::{
+    resources::type_reg::untagged::TypeReg,
+    rt_model::{Error, ParamsFileDeserializer, Storage},
+};
+
+#[tokio::test]
+async fn deserialize_returns_params_in_file() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let params_file_path = tempdir.path().join("params.yaml");
+    tokio::fs::write(&params_file_path, "a: 1\nb: true\n").await?;
+    let mut type_reg = TypeReg::<String>::new_typed();
+    type_reg.register::<u32>(String::from("a"));
+    type_reg.register::<bool>(String::from("b"));
+
+    let params =
+        ParamsFileDeserializer::deserialize(&Storage::new(), &type_reg, &params_file_path).await?;
+
+    assert_eq!(Some(1u32), params.get::<u32, _>("a").copied());
+    assert_eq!(Some(true), params.get::<bool, _>("b").copied());
+    Ok(())
+}
+
+#[tokio::test]
+async fn deserialize_returns_error_when_file_not_exists() -> Result<(), Box<dyn std::error::Error>>
+{
+    let tempdir = tempfile::tempdir()?;
+    let params_file_path = tempdir.path().join("params.yaml");
+    let type_reg = TypeReg::<String>::new_typed();
+
+    let error = ParamsFileDeserializer::deserialize(&Storage::new(), &type_reg, &params_file_path)
+        .await
+        .expect_err("Expected deserialization to fail when the file does not exist.");
+
+    assert!(
+        matches!(
+            &error,
+            Error::Native(peace::rt_model::NativeError::FileOpen { path, .. })
+            if path == &params_file_path
+        ),
+        "was {error:#?}"
+    );
+    Ok(())
+}
+
+#[cfg(feature = "error_reporting")]
+#[tokio::test]
+async fn deserialize_returns_error_with_key_spans_when_keys_unknown(
+) -> Result<(), Box<dyn std::error::Error>> {
+    use peace::miette::Diagnostic;
+
+    let tempdir = tempfile::tempdir()?;
+    let params_file_path = tempdir.path().join("params.json");
+    let file_contents = r#"{ "a": 1, "unknown": 2 }"#;
+    tokio::fs::write(&params_file_path, file_contents).await?;
+    let mut type_reg = TypeReg::<String>::new_typed();
+    type_reg.register::<u32>(String::from("a"));
+
+    let error = ParamsFileDeserializer::deserialize(&Storage::new(), &type_reg, &params_file_path)
+        .await
+        .expect_err("Expected deserialization to fail when a key is unknown.");
+
+    assert!(
+        matches!(
+            &error,
+            Error::ParamsFileKeysUnknown { keys, .. }
+            if keys == &[String::from("unknown")]
+        ),
+        "was {error:#?}"
+    );
+    let labels = error
+        .labels()
+        .expect("Expected labels to exist.")
+        .collect::<Vec<_>>();
+    assert_eq!(1, labels.len());
+    assert_eq!(file_contents.find(r#""unknown""#), Some(labels[0].offset()));
+    assert_eq!(r#""unknown""#.len(), labels[0].len());
+    Ok(())
+}
@@ -0,0 +1,175 @@
+use peace::{
+    cfg::{flow_id, FlowId},
+    rt_model::{ParamChange, ParamsHistory, ParamsKind, ParamsRevision, ParamsRevisionDiff},
+};
+
+#[test]
+fn revision_next_is_zero_when_empty() {
+    let params_history = ParamsHistory::new();
+
+    assert_eq!(0, params_history.revision_next());
+    assert_eq!(None, params_history.revision_latest(None));
+}
+
+#[test]
+fn revision_latest_returns_most_recent_revision_for_flow() {
+    let params_history = ParamsHistory::from_iter([
+        params_revision(0, Some(flow_id!("flow_a"))),
+        params_revision(1, Some(flow_id!("flow_b"))),
+        params_revision(2, Some(flow_id!("flow_a"))),
+        params_revision(3, None),
+    ]);
+
+    assert_eq!(4, params_history.revision_next());
+    assert_eq!(
+        Some(2),
+        params_history
+            .revision_latest(Some(&flow_id!("flow_a")))
+            .map(|params_revision| params_revision.revision)
+    );
+    assert_eq!(
+        Some(1),
+        params_history
+            .revision_latest(Some(&flow_id!("flow_b")))
+            .map(|params_revision| params_revision.revision)
+    );
+    assert_eq!(
+        Some(3),
+        params_history
+            .revision_latest(None)
+            .map(|params_revision| params_revision.revision)
+    );
+    assert_eq!(
+        Some(1),
+        params_history
+            .revision(1)
+            .map(|params_revision| params_revision.revision)
+    );
+    assert_eq!(None, params_history.revision(4));
+}
+
+#[test]
+fn push_prunes_oldest_revisions_except_latest_for_each_flow() {
+    let mut params_history =
+        ParamsHistory::from_iter([params_revision(0, Some(flow_id!("flow_b")))]);
+    (1..ParamsHistory::REVISIONS_MAX as u64).for_each(|revision| {
+        let params_revisions_pruned =
+            params_history.push(params_revision(revision, Some(flow_id!("flow_a"))));
+        assert!(params_revisions_pruned.is_empty());
+    });
+
+    let params_revisions_pruned = params_history.push(params_revision(
+        ParamsHistory::REVISIONS_MAX as u64,
+        Some(flow_id!("flow_a")),
+    ));
+
+    assert_eq!(
+        vec![1],
+        params_revisions_pruned
+            .iter()
+            .map(|params_revision| params_revision.revision)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(ParamsHistory::REVISIONS_MAX, params_history.len());
+    assert_eq!(
+        Some(0),
+        params_history
+            .revision_latest(Some(&flow_id!("flow_b")))
+            .map(|params_revision| params_revision.revision)
+    );
+}
+
+#[test]
+fn params_digests_eq_compares_all_digests() {
+    let params_revision = params_revision(0, None);
+
+    assert!(params_revision.params_digests_eq("ws", "profile", "flow"));
+    assert!(!params_revision.params_digests_eq("ws", "profile", "flow_other"));
+}
+
+#[test]
+fn param_changes_lists_changed_removed_and_added_params() -> Result<(), serde_yaml::Error> {
+    let params_from = serde_yaml::from_str("a: 1\nb: 2\nc: 3\n")?;
+    let params_to = serde_yaml::from_str("a: 1\nb: 20\nd: 4\n")?;
+
+    let param_changes = ParamChange::changes(ParamsKind::Profile, &params_from, &params_to);
+
+    assert_eq!(
+        vec![
+            ParamChange {
+                params_kind: ParamsKind::Profile,
+                key: String::from("b"),
+                value_from: Some(serde_yaml::Value::from(2)),
+                value_to: Some(serde_yaml::Value::from(20)),
+            },
+            ParamChange {
+                params_kind: ParamsKind::Profile,
+                key: String::from("c"),
+                value_from: Some(serde_yaml::Value::from(3)),
+                value_to: None,
+            },
+            ParamChange {
+                params_kind: ParamsKind::Profile,
+                key: String::from("d"),
+                value_from: None,
+                value_to: Some(serde_yaml::Value::from(4)),
+            },
+        ],
+        param_changes
+    );
+    Ok(())
+}
+
+#[test]
+fn params_revision_diff_display() -> Result<(), serde_yaml::Error> {
+    let params_from = serde_yaml::from_str("a: 1\nb: 2\n")?;
+    let params_to = serde_yaml::from_str("a: 10\nc: x\n")?;
+    let params_revision_diff = ParamsRevisionDiff::new(
+        0,
+        1,
+        ParamChange::changes(ParamsKind::Flow, &params_from, &params_to),
+    );
+
+    assert_eq!(
+        "Params changes from revision 0 to 1:\n\
+        ~ flow.a: 1 -> 10\n\
+        - flow.b: 2\n\
+        + flow.c: \"x\"\n",
+        params_revision_diff.to_string()
+    );
+    assert!(!params_revision_diff.is_empty());
+    Ok(())
+}
+
+#[test]
+fn params_revision_diff_display_no_changes() {
+    let params_revision_diff = ParamsRevisionDiff::new(0, 1, Vec::new());
+
+    assert_eq!(
+        "Params changes from revision 0 to 1:\nNo changes.\n",
+        params_revision_diff.to_string()
+    );
+    assert!(params_revision_diff.is_empty());
+}
+
+#[test]
+fn serialize_round_trip() -> Result<(), serde_yaml::Error> {
+    let params_history = ParamsHistory::from_iter([params_revision(0, Some(flow_id!("flow_a")))]);
+
+    let serialized = serde_yaml::to_string(&params_history)?;
+
+    assert_eq!(params_history, serde_yaml::from_str(&serialized)?);
+    Ok(())
+}
+
+fn params_revision(revision: u64, flow_id: Option<FlowId>) -> ParamsRevision {
+    ParamsRevision {
+        revision,
+        recorded_at: chrono::DateTime::UNIX_EPOCH,
+        recorded_by: Some(String::from("tester")),
+        flow_id,
+        workspace_params_digest: String::from("ws"),
+        profile_params_digest: String::from("profile"),
+        flow_params_digest: String::from("flow"),
+    }
+}
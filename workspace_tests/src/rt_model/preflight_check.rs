@@ -0,0 +1,60 @@
+use peace::rt_model::{PreflightCheck, PreflightCheckFailure};
+
+#[tokio::test]
+async fn check_returns_ok_when_check_passes() {
+    let preflight_check = PreflightCheck::new("docker installed", || Box::pin(async { Ok(()) }));
+
+    assert_eq!("docker installed", preflight_check.name());
+    assert_eq!(Ok(()), preflight_check.check().await);
+}
+
+#[tokio::test]
+async fn check_returns_failure_with_check_name_when_check_fails() {
+    let preflight_check = PreflightCheck::new("disk space", || {
+        Box::pin(async { Err(String::from("Less than 1 GiB free.")) })
+    });
+
+    let preflight_check_failure = preflight_check
+        .check()
+        .await
+        .expect_err("Expected `disk space` check to fail.");
+
+    assert_eq!("disk space", preflight_check_failure.check_name());
+    assert_eq!("Less than 1 GiB free.", preflight_check_failure.reason());
+    assert_eq!(
+        "disk space: Less than 1 GiB free.",
+        preflight_check_failure.to_string()
+    );
+}
+
+#[tokio::test]
+async fn binary_exists_fails_when_binary_not_in_path() {
+    let preflight_check = PreflightCheck::binary_exists("peace_preflight_check_binary_missing");
+
+    let preflight_check_failure = preflight_check.check().await;
+
+    assert_eq!(
+        Err(PreflightCheckFailure::new(
+            String::from("`peace_preflight_check_binary_missing` installed"),
+            String::from("`peace_preflight_check_binary_missing` was not found in `PATH`."),
+        )),
+        preflight_check_failure
+    );
+}
+
+#[test]
+fn clone_eq() {
+    let preflight_check = PreflightCheck::new("docker installed", || Box::pin(async { Ok(()) }));
+
+    assert_eq!(preflight_check, Clone::clone(&preflight_check));
+}
+
+#[test]
+fn debug() {
+    let preflight_check = PreflightCheck::new("docker installed", || Box::pin(async { Ok(()) }));
+
+    assert_eq!(
+        r#"PreflightCheck { name: "docker installed", check_fn: "Fn() -> BoxFuture<'static, Result<(), String>>" }"#,
+        format!("{preflight_check:?}")
+    );
+}
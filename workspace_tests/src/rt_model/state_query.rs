@@ -0,0 +1,127 @@
+use peace::{
+    cfg::{item_id, state::Sensitive},
+    resources::{
+        internal::StatesMut,
+        states::{ts::Current, StatesCurrent},
+    },
+    rt_model::{Error, StateQuery},
+};
+use serde::Serialize;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+struct ServerState {
+    ip_addr: String,
+    ports: Vec<u16>,
+}
+
+impl std::fmt::Display for ServerState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.ip_addr)
+    }
+}
+
+#[test]
+fn with_field_prepends_slash_when_absent() {
+    let state_query = StateQuery::new(item_id!("server")).with_field("ip_addr");
+    assert_eq!(Some("/ip_addr"), state_query.field_pointer());
+
+    let state_query = StateQuery::new(item_id!("server")).with_field("/ports/0");
+    assert_eq!(Some("/ports/0"), state_query.field_pointer());
+}
+
+#[test]
+fn query_returns_whole_state_when_no_field() -> Result<(), Box<dyn std::error::Error>> {
+    let states = states_current();
+
+    let state = StateQuery::new(item_id!("server")).query(&states)?;
+
+    assert_eq!(
+        serde_json::json!({
+            "ip_addr": "10.0.0.1",
+            "ports": [80, 443],
+        }),
+        state
+    );
+    Ok(())
+}
+
+#[test]
+fn query_returns_state_field_when_field_specified() -> Result<(), Box<dyn std::error::Error>> {
+    let states = states_current();
+
+    let ip_addr = StateQuery::new(item_id!("server"))
+        .with_field("ip_addr")
+        .query(&states)?;
+    let port = StateQuery::new(item_id!("server"))
+        .with_field("/ports/1")
+        .query(&states)?;
+
+    assert_eq!(serde_json::json!("10.0.0.1"), ip_addr);
+    assert_eq!(serde_json::json!(443), port);
+    Ok(())
+}
+
+#[test]
+fn query_redacts_sensitive_fields() -> Result<(), Box<dyn std::error::Error>> {
+    let mut states_mut = StatesMut::<Current>::new();
+    states_mut.insert(
+        item_id!("api_token"),
+        Sensitive::new(String::from("hunter2")),
+    );
+    let states = StatesCurrent::from(states_mut);
+
+    let api_token = StateQuery::new(item_id!("api_token")).query(&states)?;
+
+    assert_eq!(serde_json::json!("********"), api_token);
+    Ok(())
+}
+
+#[test]
+fn query_returns_error_when_item_has_no_state() {
+    let states = states_current();
+
+    let error = StateQuery::new(item_id!("other"))
+        .query(&states)
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            Error::StateQueryItemNotFound { item_id }
+            if item_id == &item_id!("other")
+        ),
+        "was {error:?}"
+    );
+}
+
+#[test]
+fn query_returns_error_when_field_does_not_exist() {
+    let states = states_current();
+
+    let error = StateQuery::new(item_id!("server"))
+        .with_field("hostname")
+        .query(&states)
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            Error::StateQueryFieldNotFound { item_id, field_pointer }
+            if item_id == &item_id!("server")
+            && field_pointer == "/hostname"
+        ),
+        "was {error:?}"
+    );
+}
+
+fn states_current() -> StatesCurrent {
+    let mut states_mut = StatesMut::<Current>::new();
+    states_mut.insert(
+        item_id!("server"),
+        ServerState {
+            ip_addr: String::from("10.0.0.1"),
+            ports: vec![80, 443],
+        },
+    );
+    StatesCurrent::from(states_mut)
+}
@@ -1,12 +1,19 @@
+use std::fmt;
+
+use chrono::{TimeZone, Utc};
 use peace::{
     cfg::{flow_id, item_id},
     resources::{
         internal::StatesMut, paths::StatesCurrentFile, states::StatesCurrentStored,
         type_reg::untagged::TypeReg,
     },
-    rt_model::{Error, ItemGraphBuilder, StatesSerializer, Storage},
+    rt_model::{
+        Error, IndexMap, ItemGraphBuilder, StatesOrdering, StatesSerializer, Storage, StorageCodec,
+        StorageFormat,
+    },
 };
 use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     mock_item::{MockItem, MockState},
@@ -17,7 +24,7 @@ use crate::{
 #[tokio::test]
 async fn serialize() -> Result<(), Box<dyn std::error::Error>> {
     let tempdir = tempfile::tempdir()?;
-    let storage = Storage;
+    let storage = Storage::new();
     let states_current_file = StatesCurrentFile::new(tempdir.path().join("states_current.yaml"));
 
     let item_one = item_id!("one");
@@ -60,11 +67,326 @@ async fn serialize() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[tokio::test]
+async fn serialize_lexicographic_sorts_items_and_state_keys(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let storage = Storage::new().with_states_ordering(StatesOrdering::Lexicographic);
+    let states_current_file = StatesCurrentFile::new(tempdir.path().join("states_current.yaml"));
+
+    let item_one = item_id!("one");
+    let item_two = item_id!("two");
+    let item_three = item_id!("three");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        item_graph_builder.add_fns([
+            VecCopyItem::new(item_one.clone()).into(),
+            MockItem::<()>::new(item_two.clone()).into(),
+            MockItem::<()>::new(item_three.clone()).into(),
+        ]);
+        item_graph_builder.build()
+    };
+    let states = {
+        let mut states_mut = StatesMut::new();
+        states_mut.insert(item_one.clone(), VecCopyState::from(vec![1u8]));
+        states_mut.insert(
+            item_two.clone(),
+            MapState(IndexMap::from([
+                (String::from("c"), 3u8),
+                (String::from("a"), 1u8),
+                (String::from("b"), 2u8),
+            ])),
+        );
+        StatesCurrentStored::from(states_mut)
+    };
+    StatesSerializer::<PeaceTestError>::serialize(
+        &storage,
+        &item_graph,
+        &states,
+        &states_current_file,
+    )
+    .await?;
+
+    let serialized = tokio::fs::read_to_string(states_current_file).await?;
+    assert_eq!(
+        "\
+        one:\n\
+          - 1\n\
+        three: null\n\
+        two:\n  \
+          a: 1\n  \
+          b: 2\n  \
+          c: 3\n\
+        ",
+        serialized
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn serialize_graph_ordering_keeps_state_keys_order() -> Result<(), Box<dyn std::error::Error>>
+{
+    let tempdir = tempfile::tempdir()?;
+    let storage = Storage::new().with_states_ordering(StatesOrdering::Graph);
+    let states_current_file = StatesCurrentFile::new(tempdir.path().join("states_current.yaml"));
+
+    let item_two = item_id!("two");
+    let item_one = item_id!("one");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        item_graph_builder.add_fns([
+            MockItem::<()>::new(item_two.clone()).into(),
+            VecCopyItem::new(item_one.clone()).into(),
+        ]);
+        item_graph_builder.build()
+    };
+    let states = {
+        let mut states_mut = StatesMut::new();
+        states_mut.insert(item_one.clone(), VecCopyState::from(vec![1u8]));
+        states_mut.insert(
+            item_two.clone(),
+            MapState(IndexMap::from([
+                (String::from("b"), 2u8),
+                (String::from("a"), 1u8),
+            ])),
+        );
+        StatesCurrentStored::from(states_mut)
+    };
+    StatesSerializer::<PeaceTestError>::serialize(
+        &storage,
+        &item_graph,
+        &states,
+        &states_current_file,
+    )
+    .await?;
+
+    let serialized = tokio::fs::read_to_string(states_current_file).await?;
+    assert_eq!(
+        "\
+        two:\n  \
+          b: 2\n  \
+          a: 1\n\
+        one:\n\
+          - 1\n\
+        ",
+        serialized
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn serialize_delta_writes_changed_states_and_keeps_unchanged_states(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let storage = Storage::new();
+    let states_current_file = StatesCurrentFile::new(tempdir.path().join("states_current.yaml"));
+
+    let item_one = item_id!("one");
+    let item_two = item_id!("two");
+    let item_three = item_id!("three");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        item_graph_builder.add_fns([
+            VecCopyItem::new(item_one.clone()).into(),
+            MockItem::<()>::new(item_two.clone()).into(),
+            MockItem::<()>::new(item_three.clone()).into(),
+        ]);
+        item_graph_builder.build()
+    };
+    let states = {
+        let mut states_mut = StatesMut::new();
+        states_mut.insert(item_one.clone(), VecCopyState::from(vec![1u8]));
+        states_mut.insert(item_two.clone(), MockState(3u8));
+        states_mut.insert(item_three.clone(), MockState(4u8));
+        StatesCurrentStored::from(states_mut)
+    };
+    tokio::fs::write(
+        &states_current_file,
+        "\
+        one:\n\
+          - 1\n\
+        two: 2\n\
+        three: null\n\
+        ",
+    )
+    .await?;
+
+    StatesSerializer::<PeaceTestError>::serialize_delta(
+        &storage,
+        &item_graph,
+        &states,
+        &states_current_file,
+    )
+    .await?;
+
+    let serialized = tokio::fs::read_to_string(&states_current_file).await?;
+    assert_eq!(
+        "\
+        one:\n\
+          - 1\n\
+        two: 3\n\
+        three: 4\n\
+        ",
+        serialized
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn serialize_delta_compares_states_with_file_contents_when_written(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let storage = Storage::new();
+    let states_current_file = StatesCurrentFile::new(tempdir.path().join("states_current.yaml"));
+
+    let item_one = item_id!("one");
+    let item_two = item_id!("two");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        item_graph_builder.add_fns([
+            VecCopyItem::new(item_one.clone()).into(),
+            MockItem::<()>::new(item_two.clone()).into(),
+        ]);
+        item_graph_builder.build()
+    };
+    let states = {
+        let mut states_mut = StatesMut::new();
+        states_mut.insert(item_one.clone(), VecCopyState::from(vec![1u8]));
+        states_mut.insert(item_two.clone(), MockState(3u8));
+        StatesCurrentStored::from(states_mut)
+    };
+    StatesSerializer::<PeaceTestError>::serialize(
+        &storage,
+        &item_graph,
+        &states,
+        &states_current_file,
+    )
+    .await?;
+    // Another command rewrites the file after `states` were read.
+    tokio::fs::write(
+        &states_current_file,
+        "\
+        one:\n\
+          - 9\n\
+        two: 2\n\
+        ",
+    )
+    .await?;
+
+    StatesSerializer::<PeaceTestError>::serialize_delta(
+        &storage,
+        &item_graph,
+        &states,
+        &states_current_file,
+    )
+    .await?;
+
+    let serialized = tokio::fs::read_to_string(&states_current_file).await?;
+    assert_eq!(
+        "\
+        one:\n\
+          - 1\n\
+        two: 3\n\
+        ",
+        serialized
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn serialize_delta_does_not_write_when_no_states_changed(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let storage = Storage::new();
+    let states_current_file = StatesCurrentFile::new(tempdir.path().join("states_current.yaml"));
+
+    let item_one = item_id!("one");
+    let item_two = item_id!("two");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        item_graph_builder.add_fns([
+            VecCopyItem::new(item_one.clone()).into(),
+            MockItem::<()>::new(item_two.clone()).into(),
+        ]);
+        item_graph_builder.build()
+    };
+    let states = {
+        let mut states_mut = StatesMut::new();
+        states_mut.insert(item_one.clone(), VecCopyState::from(vec![1u8]));
+        states_mut.insert(item_two.clone(), MockState(2u8));
+        StatesCurrentStored::from(states_mut)
+    };
+    let file_contents = "# not rewritten\none: [1]\ntwo: 2\n";
+    tokio::fs::write(&states_current_file, file_contents).await?;
+
+    StatesSerializer::<PeaceTestError>::serialize_delta(
+        &storage,
+        &item_graph,
+        &states,
+        &states_current_file,
+    )
+    .await?;
+
+    let serialized = tokio::fs::read_to_string(&states_current_file).await?;
+    assert_eq!(file_contents, serialized);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn serialize_delta_serializes_all_states_when_file_not_exists(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let storage = Storage::new();
+    let states_current_file = StatesCurrentFile::new(tempdir.path().join("states_current.yaml"));
+
+    let item_one = item_id!("one");
+    let item_two = item_id!("two");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        item_graph_builder.add_fns([
+            VecCopyItem::new(item_one.clone()).into(),
+            MockItem::<()>::new(item_two.clone()).into(),
+        ]);
+        item_graph_builder.build()
+    };
+    let states = {
+        let mut states_mut = StatesMut::new();
+        states_mut.insert(item_one.clone(), VecCopyState::from(vec![1u8]));
+        states_mut.insert(item_two.clone(), MockState(2u8));
+        StatesCurrentStored::from(states_mut)
+    };
+
+    StatesSerializer::<PeaceTestError>::serialize_delta(
+        &storage,
+        &item_graph,
+        &states,
+        &states_current_file,
+    )
+    .await?;
+
+    let serialized = tokio::fs::read_to_string(&states_current_file).await?;
+    assert_eq!(
+        "\
+        one:\n\
+          - 1\n\
+        two: 2\n\
+        ",
+        serialized
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn deserialize_stored() -> Result<(), Box<dyn std::error::Error>> {
     let tempdir = tempfile::tempdir()?;
     let flow_id = flow_id!("test_flow");
-    let storage = Storage;
+    let storage = Storage::new();
     let states_current_file = StatesCurrentFile::new(tempdir.path().join("states_current.yaml"));
 
     let item_one = item_id!("one");
@@ -125,11 +447,68 @@ async fn deserialize_stored() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[tokio::test]
+async fn deserialize_stored_restores_when_states_were_discovered(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let flow_id = flow_id!("test_flow");
+    let storage = Storage::new();
+    let states_current_file = StatesCurrentFile::new(tempdir.path().join("states_current.yaml"));
+
+    let item_one = item_id!("one");
+    let item_two = item_id!("two");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        item_graph_builder.add_fns([
+            VecCopyItem::new(item_one.clone()).into(),
+            MockItem::<()>::new(item_two.clone()).into(),
+        ]);
+        item_graph_builder.build()
+    };
+    let discovered_at = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let states = {
+        let mut states_mut = StatesMut::new();
+        states_mut.insert(item_one.clone(), VecCopyState::from(vec![1u8]));
+        states_mut.insert(item_two.clone(), MockState(2u8));
+        states_mut.timestamp_insert(item_one.clone(), discovered_at);
+        StatesCurrentStored::from(states_mut)
+    };
+    let mut states_type_reg = TypeReg::new_typed();
+    states_type_reg.register::<VecCopyState>(item_one.clone());
+    states_type_reg.register::<MockState>(item_two.clone());
+    StatesSerializer::<PeaceTestError>::serialize(
+        &storage,
+        &item_graph,
+        &states,
+        &states_current_file,
+    )
+    .await?;
+
+    assert!(
+        tempdir
+            .path()
+            .join("states_current_timestamps.yaml")
+            .exists()
+    );
+    let states_deserialized = StatesSerializer::<PeaceTestError>::deserialize_stored(
+        &flow_id,
+        &storage,
+        &states_type_reg,
+        &states_current_file,
+    )
+    .await?;
+
+    assert_eq!(Some(discovered_at), states_deserialized.freshness(&item_one));
+    assert_eq!(states.freshness(&item_two), states_deserialized.freshness(&item_two));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn deserialize_stored_error_maps_byte_indices() -> Result<(), Box<dyn std::error::Error>> {
     let tempdir = tempfile::tempdir()?;
     let flow_id = flow_id!("test_flow");
-    let storage = Storage;
+    let storage = Storage::new();
     let item_id = item_id!("a");
     let mut states_type_reg = TypeReg::new_typed();
     states_type_reg.register::<u32>(item_id.clone());
@@ -187,3 +566,236 @@ async fn deserialize_stored_error_maps_byte_indices() -> Result<(), Box<dyn std:
 
     Ok(())
 }
+
+#[tokio::test]
+async fn serialize_json() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let storage =
+        Storage::new().with_storage_codec(StorageCodec::new().with_storage_format(StorageFormat::Json));
+    let states_current_file = StatesCurrentFile::new(tempdir.path().join("states_current.yaml"));
+
+    let item_one = item_id!("one");
+    let item_two = item_id!("two");
+    let item_three = item_id!("three");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        item_graph_builder.add_fns([
+            VecCopyItem::new(item_one.clone()).into(),
+            MockItem::<()>::new(item_two.clone()).into(),
+            MockItem::<()>::new(item_three.clone()).into(),
+        ]);
+        item_graph_builder.build()
+    };
+    let states = {
+        let mut states_mut = StatesMut::new();
+        states_mut.insert(item_one.clone(), VecCopyState::from(vec![1u8]));
+        states_mut.insert(item_two.clone(), MockState(2u8));
+        StatesCurrentStored::from(states_mut)
+    };
+    StatesSerializer::<PeaceTestError>::serialize(
+        &storage,
+        &item_graph,
+        &states,
+        &states_current_file,
+    )
+    .await?;
+
+    let serialized = tokio::fs::read_to_string(states_current_file).await?;
+    assert_eq!(r#"{"one":[1],"two":2,"three":null}"#, serialized);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn deserialize_stored_json() -> Result<(), Box<dyn std::error::Error>> {
+    deserialize_stored_in_format(StorageFormat::Json).await
+}
+
+#[tokio::test]
+async fn deserialize_stored_cbor() -> Result<(), Box<dyn std::error::Error>> {
+    deserialize_stored_in_format(StorageFormat::Cbor).await
+}
+
+#[tokio::test]
+async fn serialize_delta_cbor_keeps_unchanged_states() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let flow_id = flow_id!("test_flow");
+    let storage =
+        Storage::new().with_storage_codec(StorageCodec::new().with_storage_format(StorageFormat::Cbor));
+    let states_current_file = StatesCurrentFile::new(tempdir.path().join("states_current.yaml"));
+
+    let item_one = item_id!("one");
+    let item_two = item_id!("two");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        item_graph_builder.add_fns([
+            VecCopyItem::new(item_one.clone()).into(),
+            MockItem::<()>::new(item_two.clone()).into(),
+        ]);
+        item_graph_builder.build()
+    };
+    let states_stored = {
+        let mut states_mut = StatesMut::new();
+        states_mut.insert(item_one.clone(), VecCopyState::from(vec![1u8]));
+        states_mut.insert(item_two.clone(), MockState(2u8));
+        StatesCurrentStored::from(states_mut)
+    };
+    let states = {
+        let mut states_mut = StatesMut::new();
+        states_mut.insert(item_one.clone(), VecCopyState::from(vec![1u8]));
+        states_mut.insert(item_two.clone(), MockState(3u8));
+        StatesCurrentStored::from(states_mut)
+    };
+    let mut states_type_reg = TypeReg::new_typed();
+    states_type_reg.register::<VecCopyState>(item_one.clone());
+    states_type_reg.register::<MockState>(item_two.clone());
+    StatesSerializer::<PeaceTestError>::serialize(
+        &storage,
+        &item_graph,
+        &states_stored,
+        &states_current_file,
+    )
+    .await?;
+
+    StatesSerializer::<PeaceTestError>::serialize_delta(
+        &storage,
+        &item_graph,
+        &states,
+        &states_current_file,
+    )
+    .await?;
+
+    let states_deserialized = StatesSerializer::<PeaceTestError>::deserialize_stored(
+        &flow_id,
+        &storage,
+        &states_type_reg,
+        &states_current_file,
+    )
+    .await?;
+    assert_eq!(
+        Some(VecCopyState::from(vec![1u8])),
+        states_deserialized
+            .get::<VecCopyState, _>(&item_one)
+            .cloned()
+    );
+    assert_eq!(
+        Some(MockState(3u8)),
+        states_deserialized.get::<MockState, _>(&item_two).cloned()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn deserialize_stored_returns_states_deserialize_format_error_when_format_mismatched(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let flow_id = flow_id!("test_flow");
+    let storage =
+        Storage::new().with_storage_codec(StorageCodec::new().with_storage_format(StorageFormat::Json));
+    let item_id = item_id!("a");
+    let mut states_type_reg = TypeReg::new_typed();
+    states_type_reg.register::<u32>(item_id.clone());
+    let states_current_file = StatesCurrentFile::new(tempdir.path().join("states_current.yaml"));
+    tokio::fs::write(&states_current_file, "a: 123\n").await?;
+
+    let error = StatesSerializer::<PeaceTestError>::deserialize_stored(
+        &flow_id,
+        &storage,
+        &states_type_reg,
+        &states_current_file,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            PeaceTestError::PeaceRt(Error::StatesDeserializeFormat {
+                flow_id: flow_id_actual,
+                storage_format: StorageFormat::Json,
+                error: _,
+            })
+            if &flow_id == flow_id_actual
+        ),
+        "Expected error to be `Error::StatesDeserializeFormat {{ .. }}`, but was {error:?}"
+    );
+
+    Ok(())
+}
+
+async fn deserialize_stored_in_format(
+    storage_format: StorageFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let flow_id = flow_id!("test_flow");
+    let storage =
+        Storage::new().with_storage_codec(StorageCodec::new().with_storage_format(storage_format));
+    let states_current_file = StatesCurrentFile::new(tempdir.path().join("states_current.yaml"));
+
+    let item_one = item_id!("one");
+    let item_two = item_id!("two");
+    let item_three = item_id!("three");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        item_graph_builder.add_fns([
+            VecCopyItem::new(item_one.clone()).into(),
+            MockItem::<()>::new(item_two.clone()).into(),
+            MockItem::<()>::new(item_three.clone()).into(),
+        ]);
+        item_graph_builder.build()
+    };
+    let states = {
+        let mut states_mut = StatesMut::new();
+        states_mut.insert(item_one.clone(), VecCopyState::from(vec![1u8]));
+        states_mut.insert(item_two.clone(), MockState(2u8));
+        StatesCurrentStored::from(states_mut)
+    };
+    let mut states_type_reg = TypeReg::new_typed();
+    states_type_reg.register::<VecCopyState>(item_one.clone());
+    states_type_reg.register::<MockState>(item_two.clone());
+    states_type_reg.register::<MockState>(item_three.clone());
+    StatesSerializer::<PeaceTestError>::serialize(
+        &storage,
+        &item_graph,
+        &states,
+        &states_current_file,
+    )
+    .await?;
+
+    let states_deserialized = StatesSerializer::<PeaceTestError>::deserialize_stored(
+        &flow_id,
+        &storage,
+        &states_type_reg,
+        &states_current_file,
+    )
+    .await?;
+
+    assert_eq!(
+        Some(VecCopyState::from(vec![1u8])),
+        states_deserialized
+            .get::<VecCopyState, _>(&item_one)
+            .cloned()
+    );
+    assert_eq!(
+        Some(MockState(2u8)),
+        states_deserialized.get::<MockState, _>(&item_two).cloned()
+    );
+    assert_eq!(
+        None,
+        states_deserialized
+            .get::<MockState, _>(&item_three)
+            .cloned()
+    );
+
+    Ok(())
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+struct MapState(IndexMap<String, u8>);
+
+impl fmt::Display for MapState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
@@ -1,4 +1,5 @@
 use peace::{
+    cfg::state::Sensitive,
     resources::type_reg::untagged::{TypeMapOpt, TypeReg},
     rt_model::{params::WorkspaceParams, Error, Storage},
 };
@@ -11,13 +12,25 @@ struct TestStruct {
 
 #[test]
 fn clone() {
-    let _ = Clone::clone(&Storage);
+    let _ = Clone::clone(&Storage::new());
     let _ = Clone::clone(&TestStruct { a: 1 });
 }
 
 #[test]
 fn debug() {
-    assert_eq!("Storage", format!("{Storage:?}"));
+    assert_eq!(
+        "Storage { \
+            storage_codec: StorageCodec { \
+                encrypted_storage: None, \
+                sensitive_field_cipher: None, \
+                storage_format: Yaml, \
+                storage_compression: None \
+            }, \
+            states_ordering: Graph, \
+            staged_file_paths: None \
+        }",
+        format!("{:?}", Storage::new())
+    );
     assert_eq!("TestStruct { a: 1 }", format!("{:?}", TestStruct { a: 1 }));
 }
 
@@ -39,12 +52,12 @@ async fn serialized_read_returns_t_when_path_exists() -> Result<(), Box<dyn std:
     let file_path = tempdir.path().join("t.yaml");
     tokio::fs::write(&file_path, br#"a: 1"#).await?;
 
-    let test_struct = Storage
+    let test_struct = Storage::new()
         .serialized_read::<TestStruct, _>(
             crate::fn_name_short!().to_string(),
             &file_path,
             #[cfg_attr(coverage_nightly, coverage(off))]
-            |_error| panic!("Expected `test_struct` to be deserialized."),
+            |_error, _| panic!("Expected `test_struct` to be deserialized."),
         )
         .await?;
 
@@ -59,12 +72,12 @@ async fn serialized_read_returns_error_when_path_not_exists()
     let tempdir = tempfile::tempdir()?;
     let file_path = tempdir.path().join("t.yaml");
 
-    let error = Storage
+    let error = Storage::new()
         .serialized_read::<TestStruct, _>(
             crate::fn_name_short!().to_string(),
             &file_path,
             #[cfg_attr(coverage_nightly, coverage(off))]
-            |_error| panic!("Expected `Error::ItemNotExists` to be returned."),
+            |_error, _| panic!("Expected `Error::ItemNotExists` to be returned."),
         )
         .await
         .unwrap_err();
@@ -81,12 +94,12 @@ async fn serialized_read_opt_returns_t_when_path_exists() -> Result<(), Box<dyn
     let file_path = tempdir.path().join("t.yaml");
     tokio::fs::write(&file_path, br#"a: 1"#).await?;
 
-    let test_struct = Storage
+    let test_struct = Storage::new()
         .serialized_read_opt::<TestStruct, _>(
             crate::fn_name_short!().to_string(),
             &file_path,
             #[cfg_attr(coverage_nightly, coverage(off))]
-            |_error| panic!("Expected `test_struct` to be deserialized."),
+            |_error, _| panic!("Expected `test_struct` to be deserialized."),
         )
         .await?;
 
@@ -101,12 +114,12 @@ async fn serialized_read_opt_returns_none_when_path_not_exists()
     let tempdir = tempfile::tempdir()?;
     let file_path = tempdir.path().join("t.yaml");
 
-    let test_struct = Storage
+    let test_struct = Storage::new()
         .serialized_read_opt::<TestStruct, _>(
             crate::fn_name_short!().to_string(),
             &file_path,
             #[cfg_attr(coverage_nightly, coverage(off))]
-            |_error| panic!("Expected `None` to be returned."),
+            |_error, _| panic!("Expected `None` to be returned."),
         )
         .await?;
 
@@ -124,13 +137,13 @@ async fn serialized_typemap_read_opt_returns_typemap_when_path_exists()
     let mut type_reg = TypeReg::new();
     type_reg.register::<TestStruct>(0);
 
-    let workspace_params: WorkspaceParams<u32> = Storage
+    let workspace_params: WorkspaceParams<u32> = Storage::new()
         .serialized_typemap_read_opt(
             crate::fn_name_short!().to_string(),
             &type_reg,
             &file_path,
             #[cfg_attr(coverage_nightly, coverage(off))]
-            |_error| panic!("Expected `workspace_params` to be deserialized."),
+            |_error, _| panic!("Expected `workspace_params` to be deserialized."),
         )
         .await?
         .map(TypeMapOpt::into_type_map)
@@ -150,13 +163,13 @@ async fn serialized_typemap_read_opt_returns_none_when_path_not_exists()
     let mut type_reg = TypeReg::new();
     type_reg.register::<TestStruct>(0);
 
-    let workspace_params: Option<WorkspaceParams<u32>> = Storage
+    let workspace_params: Option<WorkspaceParams<u32>> = Storage::new()
         .serialized_typemap_read_opt(
             crate::fn_name_short!().to_string(),
             &type_reg,
             &file_path,
             #[cfg_attr(coverage_nightly, coverage(off))]
-            |_error| panic!("Expected `None` to be returned."),
+            |_error, _| panic!("Expected `None` to be returned."),
         )
         .await?
         .map(TypeMapOpt::into_type_map)
@@ -173,7 +186,7 @@ async fn serialized_write_serializes_t() -> Result<(), Box<dyn std::error::Error
     let file_path = tempdir.path().join("t.yaml");
 
     let test_struct = TestStruct { a: 1 };
-    Storage
+    Storage::new()
         .serialized_write(
             crate::fn_name_short!().to_string(),
             &file_path,
@@ -189,3 +202,108 @@ async fn serialized_write_serializes_t() -> Result<(), Box<dyn std::error::Error
 
     Ok(())
 }
+
+#[tokio::test]
+async fn serialized_write_atomic_serializes_t() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let file_path = tempdir.path().join("t.yaml");
+    tokio::fs::write(&file_path, "a: 0\n").await?;
+
+    let test_struct = TestStruct { a: 1 };
+    Storage::new()
+        .serialized_write_atomic(
+            crate::fn_name_short!().to_string(),
+            &file_path,
+            &test_struct,
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |_error| panic!("Expected `test_struct` to be serialized."),
+        )
+        .await?;
+
+    let serialized = tokio::fs::read_to_string(&file_path).await?;
+
+    assert_eq!("a: 1\n", serialized);
+    assert_eq!(vec![String::from("t.yaml")], file_names(tempdir.path())?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn serialized_write_atomic_concurrent_writes_do_not_clobber_each_other(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let file_path = tempdir.path().join("t.yaml");
+    let storage = Storage::new();
+
+    let (test_struct_1, test_struct_2) = (TestStruct { a: 1 }, TestStruct { a: 2 });
+    let write = |test_struct| {
+        storage.serialized_write_atomic(
+            crate::fn_name_short!().to_string(),
+            &file_path,
+            test_struct,
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |_error| panic!("Expected `test_struct` to be serialized."),
+        )
+    };
+    let (result_1, result_2) = tokio::join!(write(&test_struct_1), write(&test_struct_2));
+    result_1?;
+    result_2?;
+
+    let serialized = tokio::fs::read_to_string(&file_path).await?;
+
+    assert!(
+        serialized == "a: 1\n" || serialized == "a: 2\n",
+        "Expected file to contain one of the writes, but was:\n{serialized}"
+    );
+    assert_eq!(vec![String::from("t.yaml")], file_names(tempdir.path())?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn serialized_write_returns_error_for_sensitive_fields_when_no_cipher(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let file_path = tempdir.path().join("t.yaml");
+
+    let error = Storage::new()
+        .serialized_write(
+            crate::fn_name_short!().to_string(),
+            &file_path,
+            &SensitiveStruct {
+                a: 1,
+                token: Sensitive::new(String::from("hunter2")),
+            },
+            Error::StatesSerialize,
+        )
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            Error::StatesSerialize(error)
+            if error.to_string().contains("the workspace has no sensitive field key")
+        ),
+        "Expected error to be `Error::StatesSerialize` for a missing sensitive field key, \
+        but was {error:?}"
+    );
+    assert!(!file_path.exists());
+    Ok(())
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct SensitiveStruct {
+    a: u32,
+    token: Sensitive<String>,
+}
+
+/// Returns the sorted names of the files in the given directory.
+pub(crate) fn file_names(dir: &std::path::Path) -> Result<Vec<String>, std::io::Error> {
+    let mut file_names = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.file_name().to_string_lossy().into_owned()))
+        .collect::<Result<Vec<_>, _>>()?;
+    file_names.sort();
+
+    Ok(file_names)
+}
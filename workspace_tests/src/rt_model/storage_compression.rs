@@ -0,0 +1,116 @@
+use std::io::Read;
+
+use peace::rt_model::StorageCompression;
+
+/// `"a: 1\n"` compressed with the `zstd` command line tool.
+const ZSTD_COMPRESSED: &[u8] = &[
+    0x28, 0xb5, 0x2f, 0xfd, 0x04, 0x58, 0x29, 0x00, 0x00, 0x61, 0x3a, 0x20, 0x31, 0x0a, 0xe6, 0xea,
+    0xe5, 0x83,
+];
+
+#[test]
+fn default_is_none() {
+    assert_eq!(StorageCompression::None, StorageCompression::default());
+}
+
+#[test]
+fn compress_roundtrips_each_compression() -> Result<(), std::io::Error> {
+    let bytes = b"states:\n  item: value\n".repeat(16);
+
+    [StorageCompression::None, StorageCompression::Gzip]
+        .into_iter()
+        .try_for_each(|storage_compression| {
+            let compressed = storage_compression.compress(bytes.clone())?;
+            let decompressed = StorageCompression::decompress(compressed.clone())?;
+
+            assert_eq!(
+                storage_compression,
+                StorageCompression::detect(&compressed),
+                "{storage_compression}"
+            );
+            assert_eq!(bytes, decompressed, "{storage_compression}");
+            Ok(())
+        })
+}
+
+#[test]
+fn compress_returns_unsupported_error_for_zstd() {
+    let error = StorageCompression::Zstd
+        .compress(b"a: 1\n".to_vec())
+        .unwrap_err();
+
+    assert_eq!(std::io::ErrorKind::Unsupported, error.kind());
+}
+
+#[test]
+fn decompress_detects_and_decompresses_zstd() -> Result<(), std::io::Error> {
+    assert_eq!(
+        StorageCompression::Zstd,
+        StorageCompression::detect(ZSTD_COMPRESSED)
+    );
+    assert_eq!(
+        b"a: 1\n".to_vec(),
+        StorageCompression::decompress(ZSTD_COMPRESSED.to_vec())?
+    );
+    Ok(())
+}
+
+#[test]
+fn decompressing_reader_reads_zstd_bytes() -> Result<(), std::io::Error> {
+    let mut decompressed = String::new();
+    StorageCompression::decompressing_reader(ZSTD_COMPRESSED)?.read_to_string(&mut decompressed)?;
+
+    assert_eq!("a: 1\n", decompressed);
+    Ok(())
+}
+
+#[test]
+fn compress_reduces_size_of_repetitive_bytes() -> Result<(), std::io::Error> {
+    let bytes = b"manifest: value\n".repeat(256);
+
+    let gzip_compressed = StorageCompression::Gzip.compress(bytes.clone())?;
+
+    assert!(gzip_compressed.len() < bytes.len());
+    Ok(())
+}
+
+#[test]
+fn decompressing_reader_reads_uncompressed_bytes_unchanged() -> Result<(), std::io::Error> {
+    let mut decompressed = String::new();
+    StorageCompression::decompressing_reader(b"a: 1\n".as_slice())?
+        .read_to_string(&mut decompressed)?;
+
+    assert_eq!("a: 1\n", decompressed);
+    Ok(())
+}
+
+#[test]
+fn decompressing_reader_reads_short_input() -> Result<(), std::io::Error> {
+    let mut decompressed = Vec::new();
+    StorageCompression::decompressing_reader(b"a".as_slice())?.read_to_end(&mut decompressed)?;
+
+    assert_eq!(b"a".to_vec(), decompressed);
+    Ok(())
+}
+
+#[test]
+fn decompress_returns_error_for_corrupt_gzip() {
+    let mut bytes = StorageCompression::Gzip
+        .compress(b"a: 1\n".to_vec())
+        .expect("Expected gzip compression to succeed.");
+    bytes.truncate(4);
+
+    assert!(StorageCompression::decompress(bytes).is_err());
+}
+
+#[test]
+fn decompress_returns_error_for_corrupt_zstd() {
+    assert!(StorageCompression::decompress(ZSTD_COMPRESSED[..8].to_vec()).is_err());
+}
+
+#[test]
+fn display() {
+    assert_eq!("none", StorageCompression::None.to_string());
+    assert_eq!("gzip", StorageCompression::Gzip.to_string());
+    assert_eq!("zstd", StorageCompression::Zstd.to_string());
+}
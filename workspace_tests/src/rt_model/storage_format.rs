@@ -0,0 +1,128 @@
+use peace::{
+    cfg::{item_id, ItemId},
+    resources::type_reg::untagged::{BoxDtDisplay, TypeReg},
+    rt_model::{StorageFormat, StorageFormatError},
+};
+
+#[test]
+fn default_is_yaml() {
+    assert_eq!(StorageFormat::Yaml, StorageFormat::default());
+}
+
+#[test]
+fn is_binary() {
+    assert!(!StorageFormat::Yaml.is_binary());
+    assert!(!StorageFormat::Json.is_binary());
+    assert!(StorageFormat::Cbor.is_binary());
+}
+
+#[test]
+fn to_vec_serializes_in_format() -> Result<(), StorageFormatError> {
+    let value = vec![1u8, 2];
+
+    assert_eq!(b"- 1\n- 2\n".to_vec(), StorageFormat::Yaml.to_vec(&value)?);
+    assert_eq!(b"[1,2]".to_vec(), StorageFormat::Json.to_vec(&value)?);
+    assert_eq!(vec![0x82, 0x01, 0x02], StorageFormat::Cbor.to_vec(&value)?);
+    Ok(())
+}
+
+#[test]
+fn from_reader_roundtrips_each_format() -> Result<(), StorageFormatError> {
+    let value = vec![String::from("a"), String::from("b")];
+
+    [
+        StorageFormat::Yaml,
+        StorageFormat::Json,
+        StorageFormat::Cbor,
+    ]
+    .into_iter()
+    .try_for_each(|storage_format| {
+        let bytes = storage_format.to_vec(&value)?;
+        let value_deserialized = storage_format.from_reader::<_, Vec<String>>(bytes.as_slice())?;
+
+        assert_eq!(value, value_deserialized, "{storage_format}");
+        Ok(())
+    })
+}
+
+#[test]
+fn from_reader_returns_error_for_format() {
+    let bytes = b"{not valid".as_slice();
+
+    assert!(matches!(
+        StorageFormat::Yaml.from_reader::<_, Vec<u8>>(bytes),
+        Err(StorageFormatError::Yaml(_))
+    ));
+    assert!(matches!(
+        StorageFormat::Json.from_reader::<_, Vec<u8>>(bytes),
+        Err(StorageFormatError::Json(_))
+    ));
+    assert!(matches!(
+        StorageFormat::Cbor.from_reader::<_, Vec<u8>>(bytes),
+        Err(StorageFormatError::CborDeserialize(_))
+    ));
+}
+
+#[test]
+fn typemap_from_reader_includes_unknown_entries() -> Result<(), StorageFormatError> {
+    let mut type_reg = TypeReg::<ItemId, BoxDtDisplay>::new_typed();
+    type_reg.register::<u32>(item_id!("known"));
+    let mut map = serde_yaml::Mapping::new();
+    map.insert("known".into(), 1u32.into());
+    map.insert("unknown".into(), "value".into());
+
+    [
+        StorageFormat::Yaml,
+        StorageFormat::Json,
+        StorageFormat::Cbor,
+    ]
+    .into_iter()
+    .try_for_each(|storage_format| {
+        let bytes = storage_format.to_vec(&map)?;
+        let type_map_opt = storage_format.typemap_from_reader(&type_reg, bytes.as_slice())?;
+
+        assert_eq!(
+            Some(Some(&1u32)),
+            type_map_opt.get::<u32, _>(&item_id!("known")),
+            "{storage_format}"
+        );
+        assert_eq!(
+            Some(&Some(serde_yaml::Value::from("value"))),
+            type_map_opt.unknown_entries().get(&item_id!("unknown")),
+            "{storage_format}"
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn typemap_from_reader_cbor_returns_error_when_value_invalid() -> Result<(), StorageFormatError> {
+    let mut type_reg = TypeReg::<ItemId, BoxDtDisplay>::new_typed();
+    type_reg.register::<u32>(item_id!("known"));
+    let mut map = serde_yaml::Mapping::new();
+    map.insert("known".into(), "not a number".into());
+    let bytes = StorageFormat::Cbor.to_vec(&map)?;
+
+    assert!(matches!(
+        StorageFormat::Cbor.typemap_from_reader(&type_reg, bytes.as_slice()),
+        Err(StorageFormatError::CborDeserialize(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn display() {
+    assert_eq!("YAML", StorageFormat::Yaml.to_string());
+    assert_eq!("JSON", StorageFormat::Json.to_string());
+    assert_eq!("CBOR", StorageFormat::Cbor.to_string());
+}
+
+#[test]
+fn serialize_deserialize() -> Result<(), serde_yaml::Error> {
+    assert_eq!("Cbor\n", serde_yaml::to_string(&StorageFormat::Cbor)?);
+    assert_eq!(
+        StorageFormat::Json,
+        serde_yaml::from_str::<StorageFormat>("Json")?
+    );
+    Ok(())
+}
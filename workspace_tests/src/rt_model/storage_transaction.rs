@@ -0,0 +1,126 @@
+use peace::rt_model::Storage;
+use serde::{Deserialize, Serialize};
+
+use crate::rt_model::storage::file_names;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+struct TestStruct {
+    a: u32,
+}
+
+#[tokio::test]
+async fn commit_replaces_files_written_in_transaction() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let file_path_a = tempdir.path().join("a.yaml");
+    let file_path_b = tempdir.path().join("b.yaml");
+    tokio::fs::write(&file_path_a, "a: 0\n").await?;
+    tokio::fs::write(&file_path_b, "a: 0\n").await?;
+
+    let storage_transaction = Storage::new().transaction();
+    storage_transaction
+        .serialized_write(
+            crate::fn_name_short!().to_string(),
+            &file_path_a,
+            &TestStruct { a: 1 },
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |_error| panic!("Expected `TestStruct` to be serialized."),
+        )
+        .await?;
+    storage_transaction
+        .formatted_write(
+            crate::fn_name_short!().to_string(),
+            &file_path_b,
+            &TestStruct { a: 2 },
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |_error| panic!("Expected `TestStruct` to be serialized."),
+        )
+        .await?;
+
+    // Files are not replaced until the transaction is committed.
+    assert_eq!("a: 0\n", tokio::fs::read_to_string(&file_path_a).await?);
+    assert_eq!("a: 0\n", tokio::fs::read_to_string(&file_path_b).await?);
+
+    storage_transaction.commit().await?;
+
+    assert_eq!("a: 1\n", tokio::fs::read_to_string(&file_path_a).await?);
+    assert_eq!("a: 2\n", tokio::fs::read_to_string(&file_path_b).await?);
+    assert_eq!(
+        vec![String::from("a.yaml"), String::from("b.yaml")],
+        file_names(tempdir.path())?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn drop_without_commit_leaves_files_unchanged() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let file_path = tempdir.path().join("a.yaml");
+    tokio::fs::write(&file_path, "a: 0\n").await?;
+
+    let storage_transaction = Storage::new().transaction();
+    storage_transaction
+        .serialized_write_atomic(
+            crate::fn_name_short!().to_string(),
+            &file_path,
+            &TestStruct { a: 1 },
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |_error| panic!("Expected `TestStruct` to be serialized."),
+        )
+        .await?;
+    drop(storage_transaction);
+
+    assert_eq!("a: 0\n", tokio::fs::read_to_string(&file_path).await?);
+    assert_eq!(vec![String::from("a.yaml")], file_names(tempdir.path())?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn commit_replaces_file_with_last_write_when_written_twice(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let file_path = tempdir.path().join("a.yaml");
+
+    let storage_transaction = Storage::new().transaction();
+    for a in [1, 2] {
+        storage_transaction
+            .serialized_write(
+                crate::fn_name_short!().to_string(),
+                &file_path,
+                &TestStruct { a },
+                #[cfg_attr(coverage_nightly, coverage(off))]
+                |_error| panic!("Expected `TestStruct` to be serialized."),
+            )
+            .await?;
+    }
+    storage_transaction.commit().await?;
+
+    assert_eq!("a: 2\n", tokio::fs::read_to_string(&file_path).await?);
+    assert_eq!(vec![String::from("a.yaml")], file_names(tempdir.path())?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn storage_writes_are_not_staged_outside_transaction(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let file_path = tempdir.path().join("a.yaml");
+
+    let storage = Storage::new();
+    let _storage_transaction = storage.transaction();
+    storage
+        .serialized_write(
+            crate::fn_name_short!().to_string(),
+            &file_path,
+            &TestStruct { a: 1 },
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |_error| panic!("Expected `TestStruct` to be serialized."),
+        )
+        .await?;
+
+    assert_eq!("a: 1\n", tokio::fs::read_to_string(&file_path).await?);
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use peace::{
     cfg::app_name,
@@ -59,11 +59,63 @@ fn returns_workspace_file_not_found_when_workspace_root_file_does_not_exist()
         Err(Error::Native(NativeError::WorkspaceFileNotFound {
             working_dir: _,
             file_name,
+            searched_dirs: _,
         })) if file_name == Path::new("non_existent_file")
     ));
     Ok(())
 }
 
+#[test]
+fn returns_workspace_file_not_found_with_searched_dirs_from_working_dir_to_root()
+-> Result<(), Box<dyn std::error::Error>> {
+    let workspace_dirs_result = WorkspaceDirsBuilder::build(
+        &app_name!(),
+        WorkspaceSpec::FirstDirWithFile("non_existent_file".into()),
+    );
+
+    match workspace_dirs_result {
+        Err(Error::Native(NativeError::WorkspaceFileNotFound {
+            working_dir,
+            file_name: _,
+            searched_dirs,
+        })) => {
+            assert_eq!(
+                working_dir.ancestors().collect::<Vec<&Path>>(),
+                searched_dirs
+                    .iter()
+                    .map(PathBuf::as_path)
+                    .collect::<Vec<&Path>>()
+            );
+        }
+        _ => panic!("Expected `WorkspaceFileNotFound`, but got `{workspace_dirs_result:?}`."),
+    }
+    Ok(())
+}
+
+#[test]
+fn returns_workspace_dir_from_parent_search() -> Result<(), Box<dyn std::error::Error>> {
+    let workspace_dirs_result =
+        WorkspaceDirsBuilder::build(&app_name!(), WorkspaceSpec::ParentSearch);
+
+    // The `.peace` directory may not exist if no command has been run in the
+    // repository, in which case every parent directory is searched.
+    match workspace_dirs_result {
+        Ok(workspace_dirs) => {
+            assert!(workspace_dirs.workspace_dir().join(".peace").is_dir());
+        }
+        Err(Error::Native(NativeError::WorkspaceFileNotFound {
+            working_dir,
+            file_name,
+            searched_dirs,
+        })) => {
+            assert_eq!(".peace", file_name);
+            assert_eq!(working_dir.ancestors().count(), searched_dirs.len());
+        }
+        Err(error) => return Err(error.into()),
+    }
+    Ok(())
+}
+
 #[test]
 fn returns_workspace_dir_from_path() -> Result<(), Box<dyn std::error::Error>> {
     let tempdir = tempfile::tempdir()?;
@@ -0,0 +1,95 @@
+use peace::{
+    resources::type_reg::untagged::{BoxDt, TypeReg},
+    rt_model::params::WorkspaceParamsRegistry,
+};
+
+#[test]
+fn migrate_converts_param_in_older_shape() -> Result<(), Box<dyn std::error::Error>> {
+    let workspace_params_registry = WorkspaceParamsRegistry::<String>::new()
+        .with_migration::<u32, Vec<u32>, _>(String::from("ports"), |port| vec![port]);
+    let mut params = serde_yaml::from_str::<serde_yaml::Mapping>("ports: 8080\n")?;
+
+    let migrated = workspace_params_registry
+        .migrate(&mut params)
+        .map_err(|(_key, error)| error)?;
+
+    assert!(migrated);
+    assert_eq!(
+        serde_yaml::from_str::<serde_yaml::Mapping>("ports: [8080]\n")?,
+        params
+    );
+    Ok(())
+}
+
+#[test]
+fn migrate_leaves_param_in_current_shape_unchanged() -> Result<(), Box<dyn std::error::Error>> {
+    let workspace_params_registry = WorkspaceParamsRegistry::<String>::new()
+        .with_migration::<u32, Vec<u32>, _>(String::from("ports"), |port| vec![port]);
+    let mut params = serde_yaml::from_str::<serde_yaml::Mapping>("ports: [8080, 8443]\n")?;
+
+    let migrated = workspace_params_registry
+        .migrate(&mut params)
+        .map_err(|(_key, error)| error)?;
+
+    assert!(!migrated);
+    assert_eq!(
+        serde_yaml::from_str::<serde_yaml::Mapping>("ports: [8080, 8443]\n")?,
+        params
+    );
+    Ok(())
+}
+
+#[test]
+fn migrate_uses_first_migration_whose_older_shape_matches(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let workspace_params_registry = WorkspaceParamsRegistry::<String>::new()
+        .with_param::<Vec<u32>>(String::from("ports"))
+        .with_migration::<u32, Vec<u32>, _>(String::from("ports"), |port| vec![port])
+        .with_migration::<String, Vec<u32>, _>(String::from("ports"), |ports| {
+            ports
+                .split(',')
+                .filter_map(|port| port.trim().parse().ok())
+                .collect()
+        });
+    let mut params = serde_yaml::from_str::<serde_yaml::Mapping>("ports: '8080, 8443'\n")?;
+
+    let migrated = workspace_params_registry
+        .migrate(&mut params)
+        .map_err(|(_key, error)| error)?;
+
+    assert!(migrated);
+    assert_eq!(
+        serde_yaml::from_str::<serde_yaml::Mapping>("ports: [8080, 8443]\n")?,
+        params
+    );
+    Ok(())
+}
+
+#[test]
+fn register_types_registers_each_param_type() -> Result<(), Box<dyn std::error::Error>> {
+    let workspace_params_registry = WorkspaceParamsRegistry::<String>::new()
+        .with_param::<Vec<u32>>(String::from("ports"))
+        .with_param::<String>(String::from("host"));
+    let mut type_reg = TypeReg::<String, BoxDt>::new();
+
+    workspace_params_registry.register_types(&mut type_reg);
+    let type_map = type_reg.deserialize_map(serde_yaml::Deserializer::from_str(
+        "ports: [8080]\nhost: localhost\n",
+    ))?;
+
+    assert_eq!(2, workspace_params_registry.len());
+    assert_eq!(Some(&vec![8080u32]), type_map.get::<Vec<u32>, _>("ports"));
+    assert_eq!(
+        Some(&String::from("localhost")),
+        type_map.get::<String, _>("host")
+    );
+    Ok(())
+}
+
+#[test]
+fn debug() {
+    let workspace_params_registry =
+        WorkspaceParamsRegistry::<String>::new().with_param::<Vec<u32>>(String::from("ports"));
+
+    assert_eq!(r#"["ports"]"#, format!("{workspace_params_registry:?}"));
+}
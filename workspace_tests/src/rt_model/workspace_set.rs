@@ -0,0 +1,62 @@
+use peace::{
+    cfg::app_name,
+    rt_model::{Workspace, WorkspaceSet, WorkspaceSpec},
+};
+
+use crate::PeaceTestError;
+
+#[test]
+fn with_workspace_keeps_insertion_order() -> Result<(), PeaceTestError> {
+    let tempdir = tempfile::tempdir().map_err(PeaceTestError::TempDir)?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+
+    let workspace_set = WorkspaceSet::new()
+        .with_workspace("us-west-2", workspace.clone())
+        .with_workspace("ap-southeast-2", workspace.clone())
+        .with_workspace("eu-central-1", workspace);
+
+    assert_eq!(
+        vec!["us-west-2", "ap-southeast-2", "eu-central-1"],
+        workspace_set.keys().map(String::as_str).collect::<Vec<_>>()
+    );
+    Ok(())
+}
+
+#[test]
+fn insert_returns_workspace_with_same_name() -> Result<(), PeaceTestError> {
+    let tempdir = tempfile::tempdir().map_err(PeaceTestError::TempDir)?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+
+    let mut workspace_set = WorkspaceSet::new();
+
+    assert!(workspace_set
+        .insert("tenant_a", workspace.clone())
+        .is_none());
+    assert!(workspace_set.insert("tenant_a", workspace).is_some());
+    assert_eq!(1, workspace_set.len());
+    Ok(())
+}
+
+#[test]
+fn from_iter_collects_workspaces() -> Result<(), PeaceTestError> {
+    let tempdir = tempfile::tempdir().map_err(PeaceTestError::TempDir)?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+
+    let workspace_set = ["tenant_a", "tenant_b"]
+        .into_iter()
+        .map(|workspace_name| (workspace_name.to_string(), workspace.clone()))
+        .collect::<WorkspaceSet>();
+
+    assert_eq!(2, workspace_set.len());
+    assert!(workspace_set.get("tenant_b").is_some());
+    Ok(())
+}
@@ -44,7 +44,7 @@ pub(crate) async fn workspace_with(
     workspace_params.insert(String::from("profile"), profiles_existing[0].clone());
     workspace_params.insert(String::from("ws_param_1"), String::from("ws_param_1_value"));
 
-    Storage
+    Storage::new()
         .serialized_write(
             crate::fn_name_short!().to_string(),
             &workspace_params_file,
@@ -66,7 +66,7 @@ pub(crate) async fn workspace_with(
             profile_params.insert(String::from("profile_param_0"), 1u32);
             profile_params.insert(String::from("profile_param_1"), 2u64);
 
-            Storage
+            Storage::new()
                 .serialized_write(
                     crate::fn_name_short!().to_string(),
                     &profile_params_file,
@@ -84,7 +84,7 @@ pub(crate) async fn workspace_with(
                 flow_params.insert(String::from("flow_param_0"), true);
                 flow_params.insert(String::from("flow_param_1"), 456u16);
 
-                Storage
+                Storage::new()
                     .serialized_write(
                         crate::fn_name_short!().to_string(),
                         &flow_params_file,
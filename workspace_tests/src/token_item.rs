@@ -0,0 +1,260 @@
+use std::fmt;
+
+#[cfg(feature = "output_progress")]
+use peace::cfg::progress::{ProgressLimit, ProgressMsgUpdate};
+use peace::{
+    cfg::{
+        async_trait, item_id, state::Sensitive, ApplyCheck, ApplyCheckReason, ApplyCtx, FnCtx,
+        Item, ItemId,
+    },
+    data::{
+        accessors::{RMaybe, W},
+        Data,
+    },
+    params::Params,
+    resources::{resources::ts::Empty, states::StatesCurrentStored, Resources},
+};
+use serde::{Deserialize, Serialize};
+
+/// Copies a token from `TokenSrc` to `TokenDest`.
+///
+/// The token is held in a [`Sensitive`] field of the item's state.
+#[derive(Clone, Debug)]
+pub struct TokenItem {
+    /// ID of the item.
+    id: ItemId,
+}
+
+impl TokenItem {
+    pub const ID_DEFAULT: &'static ItemId = &item_id!("token");
+
+    pub fn new(id: ItemId) -> Self {
+        Self { id }
+    }
+
+    fn state_current_internal(data: TokenData<'_>) -> TokenState {
+        TokenState {
+            token: data.dest().0.clone().map(Sensitive::new),
+        }
+    }
+
+    fn state_goal_internal(token_src: &str) -> TokenState {
+        TokenState {
+            token: Some(Sensitive::new(token_src.to_string())),
+        }
+    }
+}
+
+impl Default for TokenItem {
+    fn default() -> Self {
+        Self::new(Self::ID_DEFAULT.clone())
+    }
+}
+
+#[async_trait(?Send)]
+impl Item for TokenItem {
+    type Data<'exec> = TokenData<'exec>;
+    type Error = TokenItemError;
+    type Params<'exec> = TokenSrc;
+    type State = TokenState;
+    type StateDiff = TokenDiff;
+
+    fn id(&self) -> &ItemId {
+        &self.id
+    }
+
+    async fn try_state_current(
+        _fn_ctx: FnCtx<'_>,
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        data: Self::Data<'_>,
+    ) -> Result<Option<Self::State>, TokenItemError> {
+        Ok(Some(Self::state_current_internal(data)))
+    }
+
+    async fn state_current(
+        _fn_ctx: FnCtx<'_>,
+        _params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+    ) -> Result<Self::State, TokenItemError> {
+        Ok(Self::state_current_internal(data))
+    }
+
+    async fn try_state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+    ) -> Result<Option<Self::State>, TokenItemError> {
+        Ok(params_partial.0.as_deref().map(Self::state_goal_internal))
+    }
+
+    async fn state_goal(
+        _fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        _data: Self::Data<'_>,
+    ) -> Result<Self::State, TokenItemError> {
+        Ok(Self::state_goal_internal(&params.0))
+    }
+
+    async fn state_diff(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: TokenData<'_>,
+        state_current: &TokenState,
+        state_goal: &TokenState,
+    ) -> Result<Self::StateDiff, TokenItemError> {
+        if state_current == state_goal {
+            Ok(TokenDiff::Unchanged)
+        } else {
+            Ok(TokenDiff::Changed)
+        }
+    }
+
+    async fn state_clean(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+    ) -> Result<Self::State, TokenItemError> {
+        Ok(TokenState { token: None })
+    }
+
+    async fn apply_check(
+        _apply_ctx: ApplyCtx<'_>,
+        _params: &Self::Params<'_>,
+        _data: Self::Data<'_>,
+        _state_current: &Self::State,
+        _state_target: &Self::State,
+        diff: &Self::StateDiff,
+    ) -> Result<ApplyCheck, Self::Error> {
+        let apply_check = match diff {
+            TokenDiff::Unchanged => ApplyCheck::ExecNotRequired,
+            TokenDiff::Changed => ApplyCheck::ExecRequired {
+                #[cfg(feature = "output_progress")]
+                progress_limit: ProgressLimit::Steps(1),
+                reason: Some(ApplyCheckReason::new("token_changed")),
+            },
+        };
+        Ok(apply_check)
+    }
+
+    async fn apply_dry(
+        _fn_ctx: FnCtx<'_>,
+        _params: &Self::Params<'_>,
+        _data: Self::Data<'_>,
+        _state_current: &Self::State,
+        state_target: &Self::State,
+        _diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        Ok(state_target.clone())
+    }
+
+    async fn apply(
+        fn_ctx: FnCtx<'_>,
+        _params: &Self::Params<'_>,
+        mut data: Self::Data<'_>,
+        _state_current: &Self::State,
+        state_target: &Self::State,
+        _diff: &Self::StateDiff,
+    ) -> Result<Self::State, Self::Error> {
+        data.dest_mut().0 = state_target
+            .token
+            .as_ref()
+            .map(|token| token.expose().clone());
+
+        #[cfg(not(feature = "output_progress"))]
+        let _fn_ctx = fn_ctx;
+        #[cfg(feature = "output_progress")]
+        fn_ctx.progress_sender().inc(1, ProgressMsgUpdate::NoChange);
+
+        Ok(state_target.clone())
+    }
+
+    async fn setup(&self, resources: &mut Resources<Empty>) -> Result<(), TokenItemError> {
+        let token_dest = {
+            let states_current_stored =
+                <RMaybe<'_, StatesCurrentStored> as Data>::borrow(Self::ID_DEFAULT, resources);
+            let token_state_current_stored: Option<&'_ TokenState> = states_current_stored
+                .as_ref()
+                .and_then(|states_current_stored| states_current_stored.get(self.id()));
+            TokenDest(
+                token_state_current_stored
+                    .and_then(|token_state| token_state.token.as_ref())
+                    .map(|token| token.expose().clone()),
+            )
+        };
+        resources.insert(token_dest);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "error_reporting")]
+use peace::miette;
+
+/// Error while executing a `TokenItem`.
+#[cfg_attr(feature = "error_reporting", derive(peace::miette::Diagnostic))]
+#[derive(Debug, thiserror::Error)]
+pub enum TokenItemError {
+    /// A `peace` runtime error occurred.
+    #[error("A `peace` runtime error occurred.")]
+    PeaceRtError(
+        #[cfg_attr(feature = "error_reporting", diagnostic_source)]
+        #[source]
+        #[from]
+        peace::rt_model::Error,
+    ),
+}
+
+#[derive(Data, Debug)]
+pub struct TokenData<'exec> {
+    /// Destination to write the token to.
+    dest: W<'exec, TokenDest>,
+}
+
+impl<'exec> TokenData<'exec> {
+    pub fn dest(&self) -> &TokenDest {
+        &self.dest
+    }
+
+    pub fn dest_mut(&mut self) -> &mut TokenDest {
+        &mut self.dest
+    }
+}
+
+#[derive(Clone, Debug, Default, Params, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenSrc(pub String);
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenDest(pub Option<String>);
+
+/// State of the token.
+///
+/// This is a struct rather than a newtype, so that a missing token is not
+/// serialized as `null`, which is read back as no state.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenState {
+    /// The token, if it exists.
+    pub token: Option<Sensitive<String>>,
+}
+
+impl fmt::Display for TokenState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.token.as_ref() {
+            Some(token) => write!(f, "token: {token}"),
+            None => f.write_str("no token"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TokenDiff {
+    /// The token is unchanged.
+    Unchanged,
+    /// The token differs.
+    Changed,
+}
+
+impl fmt::Display for TokenDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unchanged => f.write_str("token unchanged"),
+            Self::Changed => f.write_str("token changed"),
+        }
+    }
+}
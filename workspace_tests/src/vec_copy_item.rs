@@ -7,7 +7,10 @@ use diff::{Diff, VecDiff, VecDiffType};
 #[cfg(feature = "output_progress")]
 use peace::cfg::progress::{ProgressLimit, ProgressMsgUpdate};
 use peace::{
-    cfg::{async_trait, item_id, ApplyCheck, FnCtx, Item, ItemId},
+    cfg::{
+        async_trait, item_id, ApplyCheck, ApplyCheckReason, ApplyCtx, FnCtx, Item, ItemId,
+        ItemOutputs,
+    },
     data::{
         accessors::{RMaybe, W},
         Data,
@@ -91,6 +94,10 @@ impl Item for VecCopyItem {
         &self.id
     }
 
+    fn outputs() -> ItemOutputs<Self::State> {
+        ItemOutputs::new().with_output(|state: &VecCopyState| VecCopyOutput(state.0.len()))
+    }
+
     async fn try_state_current(
         fn_ctx: FnCtx<'_>,
         _params_partial: &<Self::Params<'_> as Params>::Partial,
@@ -144,6 +151,7 @@ impl Item for VecCopyItem {
     }
 
     async fn apply_check(
+        _apply_ctx: ApplyCtx<'_>,
         _params: &Self::Params<'_>,
         _data: Self::Data<'_>,
         state_current: &Self::State,
@@ -157,7 +165,9 @@ impl Item for VecCopyItem {
             {
                 let _state_current = state_current;
                 let _state_target = state_target;
-                ApplyCheck::ExecRequired
+                ApplyCheck::ExecRequired {
+                    reason: Some(ApplyCheckReason::new("vec_changed")),
+                }
             }
             #[cfg(feature = "output_progress")]
             {
@@ -166,7 +176,10 @@ impl Item for VecCopyItem {
                         .map(ProgressLimit::Bytes)
                         .unwrap_or(ProgressLimit::Unknown);
 
-                ApplyCheck::ExecRequired { progress_limit }
+                ApplyCheck::ExecRequired {
+                    progress_limit,
+                    reason: Some(ApplyCheckReason::new("vec_changed")),
+                }
             }
         };
         Ok(apply_check)
@@ -263,6 +276,10 @@ pub struct VecA(pub Vec<u8>);
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct VecB(pub Vec<u8>);
 
+/// Number of bytes copied, output for successor items.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VecCopyOutput(pub usize);
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct VecCopyState(Vec<u8>);
 